@@ -0,0 +1,77 @@
+//! Compares the lookups of the const-built `StaticSpellTree` with the HashMap based `SpellTree`
+//! on the pinyin of every syllable in the test corpus.
+//! Run it with `cargo bench --bench rime_trees`
+use bpmf_py::bpmf::data::*;
+use bpmf_py::bpmf::spell_tree::SpellTree;
+use bpmf_py::bpmf::Syllable;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[path = "../src/tests/resources.rs"]
+mod resources;
+use resources::MANDARIN_SOUNDS;
+
+const ROUNDS: usize = 2000;
+
+fn measure<F: FnMut() -> usize>(name: &str, terms: usize, mut f: F) -> Duration {
+    //warm up
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<28} {:>8.1} ns/lookup",
+        elapsed.as_nanos() as f64 / (ROUNDS * terms) as f64
+    );
+    elapsed
+}
+
+fn main() {
+    //strip the initials, the trees only hold the rimes
+    let rime = |txt: &str| -> String {
+        let pos = txt.find(|ch: char| !"bpmfdtnlgkhjqxzcsrwy".contains(ch));
+        txt[pos.unwrap_or(0)..].to_owned()
+    };
+    let toned_terms: Vec<String> = MANDARIN_SOUNDS.iter().map(|(_, py)| rime(py)).collect();
+    let untoned_terms: Vec<String> = MANDARIN_SOUNDS
+        .iter()
+        .map(|(bpmf, _)| rime(&bpmf.parse::<Syllable>().unwrap().to_ascii_pinyin()))
+        .collect();
+
+    let start = Instant::now();
+    let mut toned = SpellTree::new();
+    for (term, meaning) in PINYIN_TONED_RIMES {
+        toned.insert(term, meaning);
+    }
+    let mut untoned = SpellTree::new();
+    for (term, meaning) in PINYIN_UNTONED_RIMES {
+        untoned.insert(term, meaning);
+    }
+    println!("building the SpellTrees took {:?}\n", start.elapsed());
+
+    let hash_toned = measure("toned rimes, SpellTree", toned_terms.len(), || {
+        toned_terms.iter().filter_map(|t| toned.find(t)).count()
+    });
+    let static_toned = measure("toned rimes, static", toned_terms.len(), || {
+        toned_terms
+            .iter()
+            .filter_map(|t| PINYIN_TONED_RIME_TREE.find(t))
+            .count()
+    });
+    let hash_untoned = measure("untoned rimes, SpellTree", untoned_terms.len(), || {
+        untoned_terms.iter().filter_map(|t| untoned.find(t)).count()
+    });
+    let static_untoned = measure("untoned rimes, static", untoned_terms.len(), || {
+        untoned_terms
+            .iter()
+            .filter_map(|t| PINYIN_UNTONED_RIME_TREE.find(t))
+            .count()
+    });
+    println!(
+        "\nspeedup: toned {:.2}x, untoned {:.2}x",
+        hash_toned.as_secs_f64() / static_toned.as_secs_f64(),
+        hash_untoned.as_secs_f64() / static_untoned.as_secs_f64()
+    );
+}
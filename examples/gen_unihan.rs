@@ -0,0 +1,207 @@
+//! Generates `src/unihan/table.rs`, the reading table behind the `unihan` feature.
+//!
+//! It reads `Unihan_Readings.txt` from Unihan.zip (https://www.unicode.org/Public/UCD/latest/ucd/)
+//! and merges the kMandarin, kXHC1983 and kHanyuPinyin fields, in that order, so the most
+//! customary reading comes first. Files in the format of pinyin-data
+//! (`U+4E2D: zhōng,zhòng  # 中`), which are already merged, are accepted as well.
+//!
+//! cargo run --example gen_unihan -- Unihan_Readings.txt > src/unihan/table.rs
+//!
+//! The table in the repository comes from pinyin-data 0.13.0 and is reproduced byte for byte by
+//!
+//! cargo run --example gen_unihan -- pinyin-data/pinyin.txt > src/unihan/table.rs
+use bpmf_py::bpmf::Syllable;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::{env, fs, process};
+
+const FIELDS: [&str; 3] = ["kMandarin", "kXHC1983", "kHanyuPinyin"];
+
+fn main() {
+    let path = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: gen_unihan <Unihan_Readings.txt | pinyin.txt>");
+        process::exit(1)
+    });
+    let src = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("cannot read {path}: {e}");
+        process::exit(1)
+    });
+
+    //the readings of each char as they are found in every field
+    let mut fields: BTreeMap<char, [Vec<String>; 3]> = BTreeMap::new();
+    let mut source_notes = vec![];
+    let mut is_pinyin_data = false;
+    for line in src.lines() {
+        if let Some(comment) = line.strip_prefix('#') {
+            //keep the version of the source
+            if source_notes.len() < 2 && !comment.trim().is_empty() {
+                source_notes.push(comment.trim().to_owned());
+            }
+            continue;
+        }
+        if let Some((ch, field, value)) = split_unihan(line) {
+            if let Some(idx) = FIELDS.iter().position(|f| *f == field) {
+                fields.entry(ch).or_default()[idx].extend(unihan_readings(field, value));
+            }
+        } else if let Some((ch, value)) = split_pinyin_data(line) {
+            is_pinyin_data = true;
+            fields.entry(ch).or_default()[0].extend(value.split(',').map(str::to_owned));
+        }
+    }
+
+    let mut skipped: BTreeMap<String, usize> = BTreeMap::new();
+    //the distinct lists of readings and the index of the list of each char
+    let mut lists: Vec<Vec<Syllable>> = vec![];
+    let mut list_ids: BTreeMap<Vec<Syllable>, usize> = BTreeMap::new();
+    let mut chars: Vec<(char, usize)> = vec![];
+    for (ch, readings) in fields {
+        let mut syllables = vec![];
+        for reading in readings.iter().flatten() {
+            match Syllable::parse_pinyin(reading) {
+                Ok((syl, "")) => {
+                    if !syllables.contains(&syl) {
+                        syllables.push(syl)
+                    }
+                }
+                //syllabic nasals like ḿ, ňg are not handled by the crate
+                _ => *skipped.entry(reading.clone()).or_default() += 1,
+            }
+        }
+        if syllables.is_empty() {
+            continue;
+        }
+        let id = *list_ids.entry(syllables.clone()).or_insert_with(|| {
+            lists.push(syllables);
+            lists.len() - 1
+        });
+        chars.push((ch, id));
+    }
+    source_notes.push(if is_pinyin_data {
+        "Derived from pinyin-data, a merge of the Unihan kMandarin, kHanyuPinyin and kXHC1983\n\
+         //! fields with a few others, MIT License, Copyright (c) 2016 mozillazg"
+            .to_owned()
+    } else {
+        "Derived from the Unicode Han Database, see https://www.unicode.org/license.txt".to_owned()
+    });
+    for (reading, count) in &skipped {
+        eprintln!("skipped '{reading}' x{count}");
+    }
+
+    let total: usize = lists.iter().map(Vec::len).sum();
+    assert!(total <= u16::MAX as usize && lists.len() <= u16::MAX as usize);
+    eprintln!(
+        "{} chars, {} distinct lists, {} syllables",
+        chars.len(),
+        lists.len(),
+        total
+    );
+    print!("{}", render(&source_notes, &chars, &lists, total));
+}
+
+/// "U+4E2D\tkMandarin\tzhōng"
+fn split_unihan(line: &str) -> Option<(char, &str, &str)> {
+    let mut parts = line.split('\t');
+    let ch = parse_code_point(parts.next()?)?;
+    Some((ch, parts.next()?, parts.next()?))
+}
+
+/// "U+4E2D: zhōng,zhòng  # 中"
+fn split_pinyin_data(line: &str) -> Option<(char, &str)> {
+    let (code, rest) = line.split_once(':')?;
+    let value = rest.split('#').next()?.trim();
+    Some((parse_code_point(code)?, value))
+}
+
+fn parse_code_point(code: &str) -> Option<char> {
+    char::from_u32(u32::from_str_radix(code.trim().strip_prefix("U+")?, 16).ok()?)
+}
+
+fn unihan_readings(field: &str, value: &str) -> Vec<String> {
+    let mut list = vec![];
+    for item in value.split_whitespace() {
+        match field {
+            //"zhōng"
+            "kMandarin" => list.push(item.to_owned()),
+            //"1512.040:zhōng" and "10015.030:zhōng,zhòng"
+            _ => {
+                if let Some((_, readings)) = item.split_once(':') {
+                    list.extend(readings.split(',').map(str::to_owned))
+                }
+            }
+        }
+    }
+    list
+}
+
+fn raw(syl: &Syllable) -> String {
+    format!(
+        "s({}, {}, {}, {})",
+        syl.init() as u8,
+        syl.med() as u8,
+        syl.rime() as u8,
+        syl.tone() as u8
+    )
+}
+
+fn render(
+    notes: &[String],
+    chars: &[(char, usize)],
+    lists: &[Vec<Syllable>],
+    total: usize,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "//! Generated by `cargo run --example gen_unihan`, do not edit.\n//!"
+    );
+    for note in notes {
+        let _ = writeln!(out, "//! {note}");
+    }
+    out.push_str(
+        "#![cfg_attr(rustfmt, rustfmt_skip)]\nuse super::s;\nuse crate::bpmf::Syllable;\n\n",
+    );
+
+    let _ = writeln!(out, "pub(super) static CHARS: [char; {}] = [", chars.len());
+    for row in chars.chunks(16) {
+        let cells: Vec<String> = row.iter().map(|(ch, _)| format!("{ch:?}")).collect();
+        let _ = writeln!(out, "    {},", cells.join(", "));
+    }
+    out.push_str("];\n\n");
+
+    let _ = writeln!(
+        out,
+        "pub(super) static LIST_IDS: [u16; {}] = [",
+        chars.len()
+    );
+    for row in chars.chunks(16) {
+        let cells: Vec<String> = row.iter().map(|(_, id)| id.to_string()).collect();
+        let _ = writeln!(out, "    {},", cells.join(", "));
+    }
+    out.push_str("];\n\n");
+
+    //list i spans LISTS[i]..LISTS[i + 1] of READINGS
+    let _ = writeln!(
+        out,
+        "pub(super) static LISTS: [u16; {}] = [",
+        lists.len() + 1
+    );
+    let mut start = 0;
+    let mut offsets = vec![0];
+    for list in lists {
+        start += list.len();
+        offsets.push(start);
+    }
+    for row in offsets.chunks(16) {
+        let cells: Vec<String> = row.iter().map(usize::to_string).collect();
+        let _ = writeln!(out, "    {},", cells.join(", "));
+    }
+    out.push_str("];\n\n");
+
+    let _ = writeln!(out, "pub(super) static READINGS: [Syllable; {total}] = [");
+    for list in lists {
+        let cells: Vec<String> = list.iter().map(raw).collect();
+        let _ = writeln!(out, "    {},", cells.join(", "));
+    }
+    out.push_str("];\n");
+    out
+}
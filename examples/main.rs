@@ -66,8 +66,8 @@ fn main() {
         syl.to_pinyin()
     ); //outputs:'qiú'
 
-    //FromStr trait is implemented, both bopomofo and pinyin(standard form)
-    //are recognized
+    //FromStr trait is implemented, bopomofo, pinyin(standard form) and ascii pinyin
+    //are all recognized, but the text must be exactly one syllable
     txt = "ㄑㄧㄠˇ";
     syl = txt.parse().unwrap();
     println!("Bopomofo '{txt}' recognized as '{}'", syl); //outputs: 'ㄑㄧㄠˇ'
@@ -76,6 +76,25 @@ fn main() {
     syl = txt.parse().unwrap();
     println!("Pinyin {txt} recognized as '{}'", syl); //also outputs: 'ㄑㄧㄠˇ'
 
+    assert!("qiǎo?".parse::<Syllable>().is_err());
+
+    //If you need to know which notation was used, call parse_any (or parse_any_exact)
+    let notation;
+    (syl, notation, _) = Syllable::parse_any("qiao3").unwrap();
+    println!("'{syl}' was written in {:?}", notation); //outputs: ... in AsciiPinyin
+
+    //Formatting without allocating: pinyin(), ascii_pinyin(), bopomofo() and display_as()
+    //return adapters implementing Display, which write into any fmt::Write or io::Write
+    println!(
+        "{} = {} = {}",
+        syl.bopomofo(),
+        syl.pinyin(),
+        syl.ascii_pinyin()
+    );
+    let mut buf = String::new();
+    syl.write_pinyin_to(&mut buf);
+    assert_eq!(buf, "qiǎo");
+
     //The Syllable object implemented Eq and Ord
     syl = Syllable::new(Ri, Wu, Ang, Dip); // a fabricated sound
     let (syl2, _) = Syllable::parse_ascii_pinyin("ruang3").unwrap();
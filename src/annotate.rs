@@ -0,0 +1,179 @@
+//! Annotates Chinese text with syllables word by word, so heteronyms get the reading of the word
+//! they belong to, e.g. 行 is háng in 銀行 but xíng in 行走.
+//!
+//! The text is segmented by maximum matching over a dictionary of words, loaded from CEDICT or
+//! from a TSV file of "詞\tci2". The chars out of the dictionary fall back to a table of chars
+//! (and to the Unihan readings with the `unihan` feature).
+use crate::bpmf::spell_tree::SpellTree;
+use crate::bpmf::Syllable;
+use crate::cedict::{parse_cedict_pinyin, CedictError, Entry};
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Clone)]
+pub struct Annotator {
+    words: SpellTree<Vec<Syllable>>,
+    //the user's entries, they win over the words of the same length
+    overrides: SpellTree<Vec<Syllable>>,
+    chars: HashMap<char, Syllable>,
+}
+
+/// What a loader did with the entries of the file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadReport {
+    /// The entries added (or already there)
+    pub entries: usize,
+    /// The lines (counting from 1) of the entries skipped because the number of syllables
+    /// doesn't match the number of chars
+    pub skipped: Vec<usize>,
+}
+
+/// A word of the text or a char out of the dictionary, there's one syllable for each char.
+/// The chars without a known reading get an empty `Syllable`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment<'a> {
+    pub text: &'a str,
+    pub syllables: Vec<Syllable>,
+}
+
+impl Annotator {
+    pub fn new() -> Annotator {
+        Annotator::default()
+    }
+
+    pub fn from_cedict(src: &str) -> Result<Annotator, CedictError> {
+        let mut annotator = Annotator::new();
+        annotator.load_cedict(src)?;
+        Ok(annotator)
+    }
+
+    //#region loading
+    /// Adds the words of a CEDICT dictionary, both the traditional and the simplified forms.
+    /// When a word has several entries the first one is kept.
+    /// It stops at the first malformed line, the entries whose syllables don't match the chars
+    /// are skipped and reported
+    pub fn load_cedict(&mut self, src: &str) -> Result<LoadReport, CedictError> {
+        let mut report = LoadReport::default();
+        for (idx, line) in src.lines().enumerate() {
+            let entry =
+                Entry::parse(line).map_err(|reason| CedictError { line: idx + 1, reason })?;
+            if let Some(entry) = entry {
+                let traditional = self._add_word(&entry.traditional, &entry.syllables);
+                let simplified = self._add_word(&entry.simplified, &entry.syllables);
+                if traditional && simplified {
+                    report.entries += 1;
+                } else {
+                    report.skipped.push(idx + 1);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Adds the words of lines like "銀行\tyin2 hang2", the pinyin is read as in CEDICT.
+    /// Blank lines and the lines starting with '#' are ignored, the rest goes as in `load_cedict`
+    pub fn load_tsv(&mut self, src: &str) -> Result<LoadReport, CedictError> {
+        let mut report = LoadReport::default();
+        for (idx, line) in src.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |reason| CedictError { line: idx + 1, reason };
+            let (word, pinyin) = line.split_once('\t').ok_or(err("missing tab"))?;
+            let syllables = parse_cedict_pinyin(pinyin).map_err(err)?;
+            if self._add_word(word, &syllables) {
+                report.entries += 1;
+            } else {
+                report.skipped.push(idx + 1);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Words with as many syllables as chars only, so each char gets its own.
+    /// It returns false if the word doesn't fit, a word already there is kept and counts as added
+    fn _add_word(&mut self, word: &str, syllables: &[Syllable]) -> bool {
+        if word.is_empty() || syllables.len() != word.chars().count() {
+            return false;
+        }
+        if !self.words.contains(word) {
+            self.words.insert(word, syllables.to_vec());
+        }
+        true
+    }
+
+    /// Adds or replaces a word of the dictionary, it's ignored unless there's one syllable
+    /// for each char
+    pub fn insert_word(&mut self, word: &str, syllables: &[Syllable]) {
+        if !word.is_empty() && syllables.len() == word.chars().count() {
+            self.words.insert(word, syllables.to_vec());
+        }
+    }
+
+    /// Adds a user's entry, which wins over the dictionary words of the same length or shorter
+    pub fn insert_override(&mut self, word: &str, syllables: &[Syllable]) {
+        if !word.is_empty() && syllables.len() == word.chars().count() {
+            self.overrides.insert(word, syllables.to_vec());
+        }
+    }
+
+    /// Sets the reading of a char out of the dictionary
+    pub fn insert_char(&mut self, ch: char, syllable: Syllable) {
+        self.chars.insert(ch, syllable);
+    }
+    //#endregion
+
+    /// The reading of a single char out of the dictionary
+    pub fn char_reading(&self, ch: char) -> Option<Syllable> {
+        let found = self.chars.get(&ch).copied();
+        #[cfg(feature = "unihan")]
+        let found = found.or_else(|| crate::unihan::reading(ch));
+        found
+    }
+
+    /// Splits the text into the longest words found in the dictionary (or the overrides)
+    /// and single chars
+    pub fn segment<'a>(&self, text: &'a str) -> Vec<Segment<'a>> {
+        let mut list = vec![];
+        let mut rest = text;
+        while let Some(ch) = rest.chars().next() {
+            let found = match (
+                self.overrides.longest_match(rest),
+                self.words.longest_match(rest),
+            ) {
+                (Some(o), Some(w)) if w.1.len() < o.1.len() => Some(w),
+                (Some(o), _) => Some(o),
+                (None, w) => w,
+            };
+            let (syllables, remainder) = match found {
+                Some((syllables, remainder)) => (syllables.clone(), remainder),
+                None => (
+                    vec![self.char_reading(ch).unwrap_or_default()],
+                    &rest[ch.len_utf8()..],
+                ),
+            };
+            list.push(Segment {
+                text: &rest[..rest.len() - remainder.len()],
+                syllables,
+            });
+            rest = remainder;
+        }
+        list
+    }
+
+    /// The syllable of each char of the text, None for the chars without a known reading
+    pub fn annotate(&self, text: &str) -> Vec<(char, Option<Syllable>)> {
+        let mut list = vec![];
+        for segment in self.segment(text) {
+            for (ch, syl) in segment.text.chars().zip(segment.syllables) {
+                //unknown syllables in the dictionary, like xx5 of CEDICT
+                let syl = if syl.is_empty() {
+                    self.char_reading(ch)
+                } else {
+                    Some(syl)
+                };
+                list.push((ch, syl))
+            }
+        }
+        list
+    }
+}
@@ -0,0 +1,266 @@
+//! bpmf, converts text between bopomofo, pinyin, ascii pinyin, braille and Palladius line by
+//! line, it also writes Hangul and katakana.
+//!
+//! echo "ni3 hao3" | bpmf --to bopomofo
+//! bpmf --check notes.txt
+use bpmf_py::bpmf::{Notation, Syllable};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: bpmf [OPTIONS] [TEXT]...
+
+Converts the syllables in TEXT, or in the files given with --file, or else in the lines
+read from stdin; TEXT and --file can't be given together. The text that isn't syllables
+is kept as is.
+
+Options:
+  -f, --from <NOTATION>    notation of the input, 'auto' detects it syllable by syllable
+                           [default: auto]
+  -t, --to <NOTATION>      notation of the output [default: pinyin]
+  -s, --separator <SEP>    written between adjacent syllables [default: ' ']
+  -i, --file <FILE>        reads the text from the file, '-' for stdin, can be repeated
+      --strict             stops at the first word that isn't made of syllables
+      --check              only reports the words that aren't made of syllables as
+                           SOURCE:LINE:COLUMN, exits with 1 if any
+  -h, --help               prints this help
+
+Notations: bopomofo (bpmf, zhuyin), pinyin (py), ascii-pinyin (ascii, numbered),
+           braille-mainland (mainland-braille), braille-taiwan (taiwan-braille),
+           palladius (cyrillic), and for --to only hangul (korean), katakana (japanese)
+
+Exit status: 0 on success, 1 if --check has found invalid words, 2 on errors, --strict included";
+
+struct Options {
+    from: Option<Notation>,
+    to: Notation,
+    separator: String,
+    strict: bool,
+    check: bool,
+    files: Vec<String>,
+    texts: Vec<String>,
+}
+
+/// A word that isn't made of syllables, the column counts chars from 1
+struct Invalid {
+    line: usize,
+    column: usize,
+    word: String,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("bpmf: {msg}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("bpmf: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// None for --help
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        from: None,
+        to: Notation::Pinyin,
+        separator: " ".to_owned(),
+        strict: false,
+        check: false,
+        files: vec![],
+        texts: vec![],
+    };
+    while let Some(arg) = args.next() {
+        //--name=value is the same as --name value
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name.to_owned(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let mut value = || match inline_value {
+            Some(value) => Ok(value.to_owned()),
+            None => args
+                .next()
+                .ok_or_else(|| format!("missing the value of {name}")),
+        };
+        match name.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--from" => {
+                options.from = match value()?.as_str() {
+                    "auto" => None,
+                    name => match name.parse() {
+                        Ok(notation) if Notation::ALL.contains(&notation) => Some(notation),
+                        Ok(_) => return Err(format!("{name} can't be read, only written")),
+                        Err(_) => return Err(format!("unknown notation {name}")),
+                    },
+                }
+            }
+            "-t" | "--to" => {
+                let name = value()?;
+                options.to = name
+                    .parse()
+                    .map_err(|_| format!("unknown notation {name}"))?
+            }
+            "-s" | "--separator" => options.separator = value()?,
+            "-i" | "--file" => options.files.push(value()?),
+            "--strict" => options.strict = true,
+            "--check" => options.check = true,
+            "--" => options.texts.extend(args.by_ref()),
+            _ if arg.starts_with('-') && arg != "-" => return Err(format!("unknown option {arg}")),
+            _ => options.texts.push(arg),
+        }
+    }
+    if !options.texts.is_empty() && !options.files.is_empty() {
+        return Err("TEXT and --file can't be given together".to_owned());
+    }
+    Ok(Some(options))
+}
+
+/// Returns false if --check has found any invalid word
+fn run(options: &Options) -> Result<bool, String> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut all_valid = true;
+    let mut process = |source: &str, line_no: usize, line: &str| -> Result<(), String> {
+        let mut converted = String::new();
+        let invalid = convert_line(line, line_no, options, &mut converted);
+        all_valid &= !options.check || invalid.is_empty();
+        let report = |Invalid { line, column, word }: &Invalid| {
+            format!("{source}:{line}:{column}: invalid syllable '{word}'")
+        };
+        if options.check {
+            for item in &invalid {
+                writeln!(out, "{}", report(item)).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        } else if let (true, Some(item)) = (options.strict, invalid.first()) {
+            Err(report(item))
+        } else {
+            writeln!(out, "{converted}").map_err(|e| e.to_string())
+        }
+    };
+
+    if !options.texts.is_empty() {
+        for (idx, text) in options.texts.iter().enumerate() {
+            process("<arg>", idx + 1, text)?;
+        }
+    } else if !options.files.is_empty() {
+        for file in &options.files {
+            if file == "-" {
+                read_lines("<stdin>", io::stdin().lock(), &mut process)?;
+            } else {
+                let src = fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?;
+                read_lines(file, src.as_bytes(), &mut process)?;
+            }
+        }
+    } else {
+        read_lines("<stdin>", io::stdin().lock(), &mut process)?;
+    }
+    Ok(all_valid)
+}
+
+fn read_lines<R, F>(source: &str, reader: R, process: &mut F) -> Result<(), String>
+where
+    R: BufRead,
+    F: FnMut(&str, usize, &str) -> Result<(), String>,
+{
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("{source}: {e}"))?;
+        process(source, idx + 1, &line)?;
+    }
+    Ok(())
+}
+
+/// Converts the words made of syllables and keeps the rest. The whitespaces and the apostrophes
+/// between two syllables are replaced by the separator
+fn convert_line(line: &str, line_no: usize, options: &Options, out: &mut String) -> Vec<Invalid> {
+    //latin letters (with diacritics), digits, bopomofo, tone marks, braille cells and
+    //cyrillic letters, so Han chars are kept
+    let is_word = |ch: char| {
+        ch.is_ascii_alphanumeric()
+            || ('\u{c0}'..='\u{24f}').contains(&ch)
+            || ('ㄅ'..='ㄯ').contains(&ch)
+            || "ˉˊˇˋ˙".contains(ch)
+            || ('\u{2800}'..='\u{28ff}').contains(&ch)
+            || ('\u{400}'..='\u{4ff}').contains(&ch)
+    };
+    let mut invalid = vec![];
+    //the pending gap after the last syllable, written once we know what comes next
+    let mut gap: Option<&str> = None;
+    let mut after_syllable = false;
+    let mut rest = line;
+    while !rest.is_empty() {
+        let in_word = rest.starts_with(is_word);
+        let end = rest
+            .find(|ch: char| is_word(ch) != in_word)
+            .unwrap_or(rest.len());
+        let (run, remainder) = rest.split_at(end);
+        let column = line[..line.len() - rest.len()].chars().count() + 1;
+        rest = remainder;
+
+        if !in_word {
+            if after_syllable && run.chars().all(|ch| ch.is_whitespace() || ch == '\'') {
+                gap = Some(run);
+            } else {
+                out.push_str(run);
+                after_syllable = false;
+            }
+            continue;
+        }
+        match split_syllables(run, options.from) {
+            Some(syllables) => {
+                if gap.take().is_some() {
+                    out.push_str(&options.separator);
+                }
+                for (idx, syl) in syllables.iter().enumerate() {
+                    if idx > 0 {
+                        out.push_str(&options.separator);
+                    }
+                    out.push_str(&syl.display_as(options.to).to_string());
+                }
+                after_syllable = true;
+            }
+            None => {
+                if let Some(gap) = gap.take() {
+                    out.push_str(gap);
+                }
+                out.push_str(run);
+                after_syllable = false;
+                invalid.push(Invalid {
+                    line: line_no,
+                    column,
+                    word: run.to_owned(),
+                });
+            }
+        }
+    }
+    if let Some(gap) = gap {
+        out.push_str(gap);
+    }
+    invalid
+}
+
+/// The syllables making up the word, None if it's not all syllables
+fn split_syllables(mut word: &str, from: Option<Notation>) -> Option<Vec<Syllable>> {
+    let mut list = vec![];
+    while !word.is_empty() {
+        let (syl, remainder) = match from {
+            Some(notation) => notation.parse(word).ok()?,
+            None => Syllable::parse_any(word).map(|(syl, _, r)| (syl, r)).ok()?,
+        };
+        list.push(syl);
+        word = remainder;
+    }
+    Some(list)
+}
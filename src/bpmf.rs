@@ -1,97 +1,219 @@
 use self::data::{
-    get_init_pinyin_to_bopomofo_map, get_pinyin_toned_rime_tree, get_pinyin_untoned_rime_tree,
+    get_pinyin_toned_rime_tree, get_pinyin_untoned_rime_tree, init_pinyin_to_bopomofo,
     PINYIN_NUC_CODAS, PINYIN_TONED_NUCS,
 };
 
 use super::bpmf_chars as bc;
-use std::cmp::Ordering;
-use std::fmt::{Display, Write};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Write};
 
-use std::mem::transmute;
-use std::str::FromStr;
+use core::str::FromStr;
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+pub mod braille;
+pub mod collation;
 pub mod data;
+pub mod display;
 pub mod enums;
+#[cfg(feature = "alloc")]
+pub mod fuzzy;
+pub mod hangul;
+pub mod katakana;
+pub mod literal;
+pub mod notation;
+pub mod palladius;
+#[cfg(feature = "alloc")]
+pub mod pattern;
+#[cfg(feature = "alloc")]
+pub mod query;
+#[cfg(feature = "alloc")]
 pub mod spell_tree;
+pub mod static_tree;
 
+pub use braille::BrailleSystem;
+pub use collation::Collation;
+#[cfg(feature = "alloc")]
+pub use collation::{decode_sort_key, sort_key};
+pub use display::{AsciiPinyinDisplay, BopomofoDisplay, NotationDisplay, PinyinDisplay};
 pub use enums::*;
-
-#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+#[cfg(feature = "alloc")]
+pub use fuzzy::FuzzyRules;
+pub use notation::Notation;
+#[cfg(feature = "alloc")]
+pub use palladius::palladius_word;
+#[cfg(feature = "alloc")]
+pub use pattern::SyllablePattern;
+#[cfg(feature = "alloc")]
+pub use query::{PinyinQuery, QueryPart};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct Syllable {
-    init: u8,
-    med: u8,
-    rime: u8,
-    tone: u8,
+    init: Init,
+    med: Med,
+    rime: Rime,
+    tone: Tone,
 }
 
 impl Syllable {
-    pub fn new(init: Init, med: Med, rime: Rime, tone: Tone) -> Syllable {
-        Syllable {
-            init: init as u8,
-            med: med as u8,
-            rime: rime as u8,
-            tone: tone as u8,
+    pub const fn new(init: Init, med: Med, rime: Rime, tone: Tone) -> Syllable {
+        Syllable { init, med, rime, tone }
+    }
+
+    /// Builds the syllable from the initial and the final as in the syllable charts, e.g.
+    /// (Zhi, Ueng) is zhong. Like `new` it doesn't check that the syllable exists
+    pub const fn from_initial_final(init: Init, fin: Final, tone: Tone) -> Syllable {
+        let (med, rime) = fin.parts();
+        Syllable { init, med, rime, tone }
+    }
+
+    /// Like `from_initial_final` but it fails if Mandarin has no such syllable, e.g. (Bo, V)
+    pub const fn from_initial_final_checked(
+        init: Init,
+        fin: Final,
+        tone: Tone,
+    ) -> Result<Syllable, &'static str> {
+        let syl = Syllable::from_initial_final(init, fin, tone);
+        if syl.is_valid() {
+            Ok(syl)
+        } else {
+            Err("No such syllable in Mandarin")
+        }
+    }
+
+    /// Builds the syllable from the discriminants of its parts, e.g. read from a file.
+    /// It fails if any of them is out of the range of its enum
+    pub fn from_parts_checked(
+        init: u8,
+        med: u8,
+        rime: u8,
+        tone: u8,
+    ) -> Result<Syllable, &'static str> {
+        Ok(Syllable::new(
+            init.try_into()?,
+            med.try_into()?,
+            rime.try_into()?,
+            tone.try_into()?,
+        ))
+    }
+
+    /// For the tables generated into the crate, the parts must be in range.
+    /// It panics otherwise, at compile time in a const
+    pub(crate) const fn _from_raw(init: u8, med: u8, rime: u8, tone: u8) -> Syllable {
+        match Syllable::_try_from_raw(init, med, rime, tone) {
+            Some(syl) => syl,
+            None => panic!("Syllable part out of range"),
+        }
+    }
+
+    /// For the parsers, None if a part is out of range
+    const fn _try_from_raw(init: u8, med: u8, rime: u8, tone: u8) -> Option<Syllable> {
+        match (
+            Init::_from_u8(init),
+            Med::_from_u8(med),
+            Rime::_from_u8(rime),
+            Tone::_from_u8(tone),
+        ) {
+            (Some(init), Some(med), Some(rime), Some(tone)) => {
+                Some(Syllable { init, med, rime, tone })
+            }
+            _ => None,
         }
     }
 
     //#region PROPS
 
-    pub fn init(&self) -> Init {
-        unsafe { transmute(self.init) }
+    pub const fn init(&self) -> Init {
+        self.init
     }
-    pub fn med(&self) -> Med {
-        unsafe { transmute(self.med) }
+    pub const fn med(&self) -> Med {
+        self.med
     }
-    pub fn rime(&self) -> Rime {
-        unsafe { transmute(self.rime) }
+    pub const fn rime(&self) -> Rime {
+        self.rime
     }
-    pub fn tone(&self) -> Tone {
-        unsafe { transmute(self.tone) }
+    pub const fn tone(&self) -> Tone {
+        self.tone
     }
-    pub fn init_char(&self) -> char {
-        _bpmf_part_to_char(self.init, bc::BEFORE_BO)
+    /// The medial and the rime as a unit, None if Mandarin has no such final
+    pub const fn final_(&self) -> Option<Final> {
+        Final::from_parts(self.med, self.rime)
     }
-    pub fn med_char(&self) -> char {
-        _bpmf_part_to_char(self.med, bc::ER)
+    pub const fn init_char(&self) -> char {
+        _bpmf_part_to_char(self.init as u8, bc::BEFORE_BO)
     }
-    pub fn rime_char(&self) -> char {
-        _bpmf_part_to_char(self.rime, bc::S)
+    pub const fn med_char(&self) -> char {
+        _bpmf_part_to_char(self.med as u8, bc::ER)
     }
-    pub fn tone_char(&self) -> char {
+    pub const fn rime_char(&self) -> char {
+        _bpmf_part_to_char(self.rime as u8, bc::S)
+    }
+    pub const fn tone_char(&self) -> char {
         match self.tone {
-            0 => '\0',
-            2 => bc::TONE_2,
-            3 => bc::TONE_3,
-            4 => bc::TONE_4,
-            5 => bc::TONE_5,
-            _ => bc::TONE_1,
+            Tone::NoTone => '\0',
+            Tone::Rise => bc::TONE_2,
+            Tone::Dip => bc::TONE_3,
+            Tone::Fall => bc::TONE_4,
+            Tone::Neut => bc::TONE_5,
+            Tone::Level => bc::TONE_1,
+        }
+    }
+
+    pub const fn ord(&self) -> u32 {
+        let init = match self.init {
+            Init::NoInit => 40,
+            init => init as u32,
+        };
+        self.tone as u32 + self.rime as u32 * 41 + self.med as u32 * 41 * 41 + init * 41 * 41 * 41
+    }
+
+    /// The inverse of `ord`, None if the number isn't the ord of any syllable
+    pub fn from_ord(ord: u32) -> Option<Syllable> {
+        let (tone, rime) = (ord % 41, ord / 41 % 41);
+        let (med, init) = (ord / (41 * 41) % 41, ord / (41 * 41 * 41));
+        let init = match init {
+            40 => 0,
+            0 => return None,
+            _ => init,
+        };
+        if tone > Tone::Neut as u32
+            || rime > Rime::Er as u32
+            || med > Med::Yu as u32
+            || init > Init::Si as u32
+        {
+            return None;
         }
+        Some(Syllable::_from_raw(
+            init as u8, med as u8, rime as u8, tone as u8,
+        ))
     }
 
-    pub fn ord(&self) -> u32 {
-        self.tone as u32
-            + self.rime as u32 * 41
-            + self.med as u32 * 41 * 41
-            + (if 0 == self.init { 40 } else { self.init as u32 }) * 41 * 41 * 41
+    /// Whether the syllable exists in Mandarin, the tone aside
+    pub const fn is_valid(&self) -> bool {
+        let idx = self.init as usize * 4 + self.med as usize;
+        idx < data::VALID_RIMES.len() && data::VALID_RIMES[idx] & 1 << self.rime as u8 != 0
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.init == 0 && self.med == 0 && self.rime == 0 && self.tone == 0
+    pub const fn is_empty(&self) -> bool {
+        matches!(
+            (self.init, self.med, self.rime, self.tone),
+            (Init::NoInit, Med::NoMed, Rime::NoRime, Tone::NoTone)
+        )
     }
 
-    pub fn byte_len(&self) -> usize {
-        ((self.init > 0) as usize) * 3
-            + ((self.med > 0) as usize) * 3
-            + ((self.rime > 0) as usize) * 3
-            + if self.tone > 1 { 2 } else { 0 }
+    pub const fn byte_len(&self) -> usize {
+        ((self.init as u8 > 0) as usize) * 3
+            + ((self.med as u8 > 0) as usize) * 3
+            + ((self.rime as u8 > 0) as usize) * 3
+            + if self.tone as u8 > 1 { 2 } else { 0 }
     }
 
     //#endregion
 
     //#region PARSERS
     fn _skip_whitespaces(slice: &str) -> &str {
-        let mut chars = slice.char_indices();
-        while let Some((idx, ch)) = chars.next() {
+        for (idx, ch) in slice.char_indices() {
             if !ch.is_whitespace() && ch != '\'' {
                 return &slice[idx..];
             }
@@ -101,188 +223,293 @@ impl Syllable {
     /// This function parses the &str from the start. if succeeded it returns the Syllable and
     /// the maining part of the string (minus the consumed part); if failed it returns an error
     pub fn parse_bopomofo(mut slice: &str) -> Result<(Syllable, &str), ParseBopomofoError> {
-        let (mut init, mut med, mut rime, mut tone) = (0, 0, 0, 0);
         slice = Self::_skip_whitespaces(slice);
+        match Self::_parse_bopomofo_prefix(slice) {
+            Some((syl, len)) => Ok((syl, &slice[len..])),
+            None => Err(ParseBopomofoError),
+        }
+    }
+
+    /// The syllable at the start of the text and its length in bytes. It's a `const fn` so
+    /// `parse_literal` shares it
+    const fn _parse_bopomofo_prefix(txt: &str) -> Option<(Syllable, usize)> {
+        let (mut init, mut med, mut rime, mut tone) = (0, 0, 0, 0);
+        let bytes = txt.as_bytes();
         let mut offset = 0;
-        let mut chars = slice.chars();
-        let mut next = chars.next();
+        let mut next = _char_at(bytes, offset);
 
         //Does 輕聲MARK exist?
-        if let Some(bc::TONE_5) = next {
+        if next == bc::TONE_5 {
             tone = 5u8;
             offset += 2;
-            next = chars.next();
+            next = _char_at(bytes, offset);
         }
 
         //Initial 聲母
-        if let Some(ch @ 'ㄅ'..='ㄙ') = next {
+        if let ch @ 'ㄅ'..='ㄙ' = next {
             init = (ch as u32 - bc::BEFORE_BO as u32) as u8;
             offset += 3;
-            next = chars.next();
+            next = _char_at(bytes, offset);
         }
 
         //Medial 介音
-        if let Some(ch @ 'ㄧ'..='ㄩ') = next {
+        if let ch @ 'ㄧ'..='ㄩ' = next {
             med = (ch as u32 - bc::ER as u32) as u8;
             offset += 3;
-            next = chars.next();
+            next = _char_at(bytes, offset);
         }
 
         //Rime 韻基
-        if let Some(ch @ 'ㄚ'..='ㄦ') = next {
+        if let ch @ 'ㄚ'..='ㄦ' = next {
             rime = (ch as u32 - bc::S as u32) as u8;
             offset += 3;
-            next = chars.next();
+            next = _char_at(bytes, offset);
         }
 
         //Ohter tone marks at the end其他聲調
         if tone == 0 {
-            tone = match next.and_then(|ch| "ˉˊˇˋ˙".find(ch)) {
-                //all those marks are 2 bytes long
-                Some(idx) => {
-                    offset += 2;
-                    (idx / 2 + 1) as u8
-                }
-                None => 1,
+            tone = match next {
+                bc::TONE_1 => 1,
+                bc::TONE_2 => 2,
+                bc::TONE_3 => 3,
+                bc::TONE_4 => 4,
+                bc::TONE_5 => 5,
+                _ => 0,
             };
+            //all those marks are 2 bytes long
+            if tone > 0 {
+                offset += 2;
+            } else {
+                tone = 1;
+            }
         }
 
-        if med > 0 || rime > 0 || init >= Init::Zhi as u8 && init <= Init::Si as u8 {
-            Ok((Syllable { init, med, rime, tone }, &slice[offset..]))
-        } else {
-            Err(ParseBopomofoError)
+        if med == 0 && rime == 0 && (init < Init::Zhi as u8 || init > Init::Si as u8) {
+            return None;
+        }
+        match Syllable::_try_from_raw(init, med, rime, tone) {
+            Some(syl) => Some((syl, offset)),
+            None => None,
         }
     }
 
-    ///I won't fail! if no match is found it returs 0
-    fn _parse_pinyin_initial(mut txt: &str) -> (u8, &str) {
-        txt = Self::_skip_whitespaces(txt);
-        let mut chars = (txt).chars();
-        if let Some(first_ch) = chars.next() {
-            //try to match first letter by looking up the table
-            if let Some(&i) = get_init_pinyin_to_bopomofo_map().get(&first_ch) {
-                let init = (i as u32 - bc::BEFORE_BO as u32) as u8;
-                return match init {
-                    19..=21 if chars.next() == Some('h') => (init - 4, &txt[2..]), //ㄗ~ㄙ -> ㄓ~ㄕ
-                    _ => (init, &txt[1..]),
-                };
-            } else if let 'w' | 'y' = first_ch {
-                //w,y are returned as special cases
-                return (first_ch as u8, &txt[1..]);
-            }
+    ///I won't fail! if no match is found it returs 0. It returns the initial and its length
+    const fn _parse_pinyin_initial(bytes: &[u8]) -> (u8, usize) {
+        if bytes.is_empty() {
+            return (0, 0);
+        }
+        //try to match first letter by looking up the table, a non-ascii first byte matches none
+        match init_pinyin_to_bopomofo(bytes[0] as char) {
+            Some(i) => match (i as u32 - bc::BEFORE_BO as u32) as u8 {
+                //ㄗ~ㄙ -> ㄓ~ㄕ
+                init @ 19..=21 if bytes.len() > 1 && bytes[1] == b'h' => (init - 4, 2),
+                init => (init, 1),
+            },
+            //w,y are returned as special cases
+            None if bytes[0] == b'w' || bytes[0] == b'y' => (bytes[0], 1),
+            None => (0, 0),
         }
-        (0, txt)
     }
 
-    fn _adjust_pinyin_parts(init: &mut u8, med: &mut Med, rime: Rime) {
+    const fn _adjust_pinyin_parts(init: &mut u8, med: &mut Med, rime: &mut Rime) {
         let (is_w, is_y) = (*init == b'w', *init == b'y');
         if is_w || is_y {
             *init = 0;
         }
-        if *med == Med::Wu && (*init >= Init::Ji as u8 && *init <= Init::Xi as u8 || is_y) {
+        if matches!(*med, Med::Wu) && (*init >= Init::Ji as u8 && *init <= Init::Xi as u8 || is_y) {
             //(j|q|x|y)u_ -> (ㄐ|ㄑ|ㄒ|_)ㄩ_
             *med = Med::Yu;
-        } else if *med == Med::Yi
+        } else if matches!(*med, Med::Yi)
             && *init >= Init::Zhi as u8
             && *init <= Init::Si as u8
-            && rime == Rime::NoRime
+            && matches!(*rime, Rime::NoRime)
         {
             //zh,ch,sh,r,z,c,s + i -> ㄓㄔㄕㄖㄗㄘㄙ + ㄭ
             *med = Med::NoMed;
-        } else if (is_w || is_y) && *med != Med::Yu {
+        } else if (is_w || is_y) && !matches!(*med, Med::Yu) {
+            if is_y && matches!(*med, Med::NoMed) && matches!(*rime, Rime::E) {
+                //ye -> ㄧㄝ
+                *rime = Rime::Eh
+            }
             *med = if is_w { Med::Wu } else { Med::Yi }
         }
     }
 
     pub fn parse_pinyin(mut txt: &str) -> Result<(Syllable, &str), ParseBopomofoError> {
-        let mut init;
-        (init, txt) = Self::_parse_pinyin_initial(txt);
-
-        if !txt.is_empty() {
-            if let Some(((mut med, rime, tone), remainder)) = get_pinyin_toned_rime_tree().find(txt)
-            {
-                Self::_adjust_pinyin_parts(&mut init, &mut med, rime);
-
-                return Ok((
-                    Syllable {
-                        init,
-                        med: med as u8,
-                        rime: rime as u8,
-                        tone,
-                    },
-                    remainder,
-                ));
-            }
+        txt = Self::_skip_whitespaces(txt);
+        match Self::_parse_pinyin_prefix(txt, true) {
+            Some((syl, len)) => Ok((syl, &txt[len..])),
+            None => Err(ParseBopomofoError),
         }
-        Err(ParseBopomofoError)
     }
     pub fn parse_ascii_pinyin(mut txt: &str) -> Result<(Syllable, &str), ParseBopomofoError> {
-        let mut init;
-        (init, txt) = Self::_parse_pinyin_initial(txt);
-        if !txt.is_empty() {
-            if let Some(((mut med, rime), mut remainder)) = get_pinyin_untoned_rime_tree().find(txt)
-            {
-                Self::_adjust_pinyin_parts(&mut init, &mut med, rime);
-                let mut chars = remainder.chars();
-                let tone = match chars.next() {
-                    Some(ch @ '1'..='5') => {
-                        remainder = chars.as_str();
-                        (ch as u32 - '0' as u32) as u8
-                    }
-                    _ => 0u8,
-                };
-                return Ok((
-                    Syllable {
-                        init,
-                        med: med as u8,
-                        rime: rime as u8,
-                        tone,
-                    },
-                    remainder,
-                ));
+        txt = Self::_skip_whitespaces(txt);
+        match Self::_parse_pinyin_prefix(txt, false) {
+            Some((syl, len)) => Ok((syl, &txt[len..])),
+            None => Err(ParseBopomofoError),
+        }
+    }
+
+    /// The syllable at the start of the text in pinyin (toned) or ascii pinyin and its length
+    /// in bytes, shared with `parse_literal` like `_parse_bopomofo_prefix`
+    const fn _parse_pinyin_prefix(txt: &str, toned: bool) -> Option<(Syllable, usize)> {
+        let (mut init, len) = Self::_parse_pinyin_initial(txt.as_bytes());
+        let rest = txt.split_at(len).1;
+        if rest.is_empty() {
+            return None;
+        }
+        let (mut med, mut rime, tone, remainder) = if toned {
+            match get_pinyin_toned_rime_tree().find(rest) {
+                Some(((med, rime, tone), remainder)) => (med, rime, tone, remainder.as_bytes()),
+                None => return None,
+            }
+        } else {
+            match get_pinyin_untoned_rime_tree().find(rest) {
+                Some(((med, rime), remainder)) => match remainder.as_bytes() {
+                    [digit @ b'1'..=b'5', remainder @ ..] => (med, rime, *digit - b'0', remainder),
+                    remainder => (med, rime, 0, remainder),
+                },
+                None => return None,
+            }
+        };
+        Self::_adjust_pinyin_parts(&mut init, &mut med, &mut rime);
+        match Syllable::_try_from_raw(init, med as u8, rime as u8, tone) {
+            Some(syl) => Some((syl, txt.len() - remainder.len())),
+            None => None,
+        }
+    }
+
+    /// It tries every notation on the start of the text and keeps the one that consumes the most,
+    /// so "ma3" is taken as ascii pinyin while "ma" stays pinyin (neutral tone).
+    /// It returns the syllable, the notation it was written in and the remaining part of the text
+    pub fn parse_any(txt: &str) -> Result<(Syllable, Notation, &str), ParseBopomofoError> {
+        let mut best: Option<(Syllable, Notation, &str)> = None;
+        for notation in Notation::ALL {
+            if let Ok((syl, remainder)) = notation.parse(txt) {
+                match best {
+                    Some((_, _, r)) if r.len() <= remainder.len() => {}
+                    _ => best = Some((syl, notation, remainder)),
+                }
             }
         }
-        Err(ParseBopomofoError)
+        best.ok_or(ParseBopomofoError)
+    }
+
+    /// Like `parse_any` but the whole text must be a single syllable, only surrounding
+    /// whitespaces are allowed
+    pub fn parse_any_exact(txt: &str) -> Result<(Syllable, Notation), ParseBopomofoError> {
+        match Self::parse_any(txt)? {
+            (syl, notation, remainder) if remainder.trim().is_empty() => Ok((syl, notation)),
+            _ => Err(ParseBopomofoError),
+        }
     }
     //#endregion
 
     //#region to_xxx
 
+    /// A `Display` adapter writing the syllable in pinyin without allocating,
+    /// e.g. `write!(out, "{}", syl.pinyin())` works for both `fmt::Write` and `io::Write`
+    pub fn pinyin(&self) -> PinyinDisplay {
+        PinyinDisplay(*self)
+    }
+    /// A `Display` adapter writing the syllable in ascii pinyin without allocating
+    pub fn ascii_pinyin(&self) -> AsciiPinyinDisplay {
+        AsciiPinyinDisplay(*self)
+    }
+    /// A `Display` adapter writing the syllable in bopomofo, same as the `Display` of `Syllable`
+    pub fn bopomofo(&self) -> BopomofoDisplay {
+        BopomofoDisplay(*self)
+    }
+    /// A `Display` adapter writing the syllable in the notation chosen at runtime
+    pub fn display_as(&self, notation: Notation) -> NotationDisplay {
+        NotationDisplay(*self, notation)
+    }
+
+    /// Appends the pinyin to the buffer
+    #[cfg(feature = "alloc")]
+    pub fn write_pinyin_to(&self, s: &mut String) {
+        //writing to a String never fails
+        let _ = self._write_pinyin(s);
+    }
+    /// Appends the ascii pinyin to the buffer
+    #[cfg(feature = "alloc")]
+    pub fn write_ascii_pinyin_to(&self, s: &mut String) {
+        let _ = self._write_ascii_pinyin(s);
+    }
+    /// Appends the bopomofo to the buffer
+    #[cfg(feature = "alloc")]
+    pub fn write_bopomofo_to(&self, s: &mut String) {
+        let _ = self._write_bopomofo(s);
+    }
+
+    #[cfg(feature = "alloc")]
     pub fn to_pinyin(&self) -> String {
         let mut s = String::with_capacity(7);
+        self.write_pinyin_to(&mut s);
+        s
+    }
+    #[cfg(feature = "alloc")]
+    pub fn to_ascii_pinyin(&self) -> String {
+        let mut s = String::with_capacity(8);
+        self.write_ascii_pinyin_to(&mut s);
+        s
+    }
 
-        s.push_str(self._pinyin_inital());
+    fn _write_pinyin<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        //the empty syllable stands for an unknown reading, it has no spelling
+        if self.is_empty() {
+            return Ok(());
+        }
+        w.write_str(self._pinyin_inital())?;
 
         let (med, mut nuc, coda) = self._get_rime_parts();
         if med > '\0' {
-            s.push(med);
+            w.write_char(med)?;
         }
         nuc = PINYIN_TONED_NUCS
             .iter()
             .find(|(ch, _)| *ch == nuc)
             .map(|(_, letters)| letters.chars().nth(self.tone as usize).unwrap())
             .unwrap();
-        s.push(nuc);
-        if !coda.is_empty() {
-            s.push_str(coda);
-        }
-        s
+        w.write_char(nuc)?;
+        w.write_str(coda)
     }
-    pub fn to_ascii_pinyin(&self) -> String {
-        let mut s = String::with_capacity(8);
-        s.push_str(self._pinyin_inital());
+    fn _write_ascii_pinyin<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        w.write_str(self._pinyin_inital())?;
         let (med, nuc, coda) = self._get_rime_parts();
         if med > '\0' {
-            s.push(med)
+            //lüe, nüe
+            w.write_char(if med == 'ü' { 'v' } else { med })?
         }
-        s.push(if nuc == 'ü' { 'v' } else { nuc });
-        if !coda.is_empty() {
-            s.push_str(coda);
+        w.write_char(if nuc == 'ü' { 'v' } else { nuc })?;
+        w.write_str(coda)?;
+        if self.tone != Tone::NoTone {
+            w.write_char(unsafe { char::from_u32_unchecked('0' as u32 + self.tone as u32) })?
         }
-        if self.tone > 0 {
-            s.push(unsafe { char::from_u32_unchecked('0' as u32 + self.tone as u32) })
+        Ok(())
+    }
+    fn _write_bopomofo<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        if self.tone == Tone::Neut {
+            w.write_char(bc::TONE_5)?
         }
-        s
+
+        if self.init != Init::NoInit {
+            w.write_char(self.init_char())?
+        }
+        if self.med != Med::NoMed {
+            w.write_char(self.med_char())?
+        }
+        if self.rime != Rime::NoRime {
+            w.write_char(self.rime_char())?
+        }
+        if let Tone::Rise | Tone::Dip | Tone::Fall = self.tone {
+            w.write_char(self.tone_char())?
+        }
+
+        Ok(())
     }
 
     /// It return the pinyin inital corresponding to the bopomofo initial
@@ -291,10 +518,10 @@ impl Syllable {
     fn _pinyin_inital(&self) -> &str {
         data::PINYIN_INITIALS[
             // med as inital 21 + 1|2|3, 0inital as ""
-            if self.init == 0 && self.med != 0 {
-                self.med + Init::Si as u8
+            if self.init == Init::NoInit && self.med != Med::NoMed {
+                self.med as u8 + Init::Si as u8
             } else {
-                self.init
+                self.init as u8
             } as usize// or inital
         ]
     }
@@ -312,11 +539,12 @@ impl Syllable {
         };
 
         //look the nucleus and the coda up from the table array.
-        let (mut nuc, mut coda) = PINYIN_NUC_CODAS[if self.rime == 0 && self.med != 0 {
-            self.med + Rime::Er as u8
+        let idx = if self.rime == Rime::NoRime && self.med != Med::NoMed {
+            self.med as usize + Rime::Er as usize
         } else {
-            self.rime
-        } as usize];
+            self.rime as usize
+        };
+        let (mut nuc, mut coda) = PINYIN_NUC_CODAS[idx];
 
         if med == nuc {
             //eliminate .?uu_ .?ii_ .?üü_
@@ -324,8 +552,8 @@ impl Syllable {
         }
 
         //Is it zero initial w~ or y~
-        let is_w = self.init == 0 && med_char == 'ㄨ';
-        let is_y = self.init == 0 && self.med > 0 && !is_w;
+        let is_w = self.init == Init::NoInit && med_char == 'ㄨ';
+        let is_y = self.init == Init::NoInit && self.med != Med::NoMed && !is_w;
 
         //adjust the parts according to the quirks of pinyin
         //note that the tone marks can be only added on the nucleus
@@ -337,7 +565,7 @@ impl Syllable {
             (nuc, coda) = ('u', "");
         }
 
-        if !is_w && self.med > 0 && (rime_char == 'ㄣ' || rime_char == 'ㄥ') {
+        if !is_w && self.med != Med::NoMed && (rime_char == 'ㄣ' || rime_char == 'ㄥ') {
             //ien ieng uen ueng üen üeng -> in ing un ong ün iong
             med = '\0';
             match med_char {
@@ -347,7 +575,7 @@ impl Syllable {
             }
         }
 
-        if is_y || init_char >= 'ㄐ' && init_char <= 'ㄒ' {
+        if is_y || ('ㄐ'..='ㄒ').contains(&init_char) {
             if med_char == 'ㄩ' {
                 if nuc == 'ü' || rime_char == 'ㄣ' {
                     //(j|q|x|y)ün? -> (j|q|x|y)un?
@@ -378,13 +606,19 @@ impl Ord for Syllable {
     }
 }
 
+impl Default for Syllable {
+    fn default() -> Self {
+        Syllable::new(Init::NoInit, Med::NoMed, Rime::NoRime, Tone::NoTone)
+    }
+}
+
 impl PartialOrd for Syllable {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-fn _bpmf_part_to_char(part: u8, lower_bound: char) -> char {
+const fn _bpmf_part_to_char(part: u8, lower_bound: char) -> char {
     if part == 0 {
         '\0'
     } else {
@@ -392,34 +626,35 @@ fn _bpmf_part_to_char(part: u8, lower_bound: char) -> char {
     }
 }
 
-impl Display for Syllable {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            return f.write_str("");
-        }
-
-        if self.tone == 5 {
-            f.write_char(bc::TONE_5)?
-        }
-
-        if self.init > 0 {
-            f.write_char(_bpmf_part_to_char(self.init, bc::BEFORE_BO))?
-        }
-        if self.med > 0 {
-            f.write_char(_bpmf_part_to_char(self.med, bc::ER))?
-        }
-        if self.rime > 0 {
-            f.write_char(_bpmf_part_to_char(self.rime, bc::S))?
-        }
-        if self.tone > 1 && self.tone < 5 {
-            f.write_char(match self.tone {
-                2 => bc::TONE_2,
-                3 => bc::TONE_3,
-                _ => bc::TONE_4,
-            })?
-        }
+/// The char starting at `pos`, '\0' at the end of the text
+const fn _char_at(bytes: &[u8], pos: usize) -> char {
+    if pos >= bytes.len() {
+        return '\0';
+    }
+    let lead = bytes[pos] as u32;
+    let (mut code, len) = if lead < 0x80 {
+        (lead, 1)
+    } else if lead < 0xE0 {
+        (lead & 0x1F, 2)
+    } else if lead < 0xF0 {
+        (lead & 0x0F, 3)
+    } else {
+        (lead & 0x07, 4)
+    };
+    let mut i = 1;
+    while i < len {
+        code = code << 6 | (bytes[pos + i] & 0x3F) as u32;
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(ch) => ch,
+        None => '\0',
+    }
+}
 
-        Ok(())
+impl Display for Syllable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.bopomofo(), f)
     }
 }
 
@@ -429,10 +664,10 @@ pub struct ParseBopomofoError;
 impl FromStr for Syllable {
     type Err = ParseBopomofoError;
 
+    /// Bopomofo, pinyin and ascii pinyin are all recognized, but the text must hold exactly
+    /// one syllable
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Syllable::parse_bopomofo(s)
-            .map(|x| x.0)
-            .or_else(|_| Syllable::parse_pinyin(s).map(|x| x.0))
+        Syllable::parse_any_exact(s).map(|x| x.0)
     }
 }
 
@@ -440,33 +675,39 @@ impl FromStr for Syllable {
 
 //#region convenient functions
 
+#[cfg(feature = "alloc")]
 pub fn pinyin_to_bopomofo(txt: &str) -> Option<String> {
     Syllable::parse_pinyin(txt)
         .ok()
         .map(|res| res.0.to_string())
 }
 
+#[cfg(feature = "alloc")]
 pub fn bopomofo_to_pinyin(txt: &str) -> Option<String> {
     Syllable::parse_bopomofo(txt)
         .ok()
         .map(|res| res.0.to_pinyin())
 }
 
+#[cfg(feature = "alloc")]
 pub fn ascii_pinyin_to_bopomofo(txt: &str) -> Option<String> {
     Syllable::parse_ascii_pinyin(txt)
         .ok()
         .map(|res| res.0.to_string())
 }
+#[cfg(feature = "alloc")]
 pub fn ascii_pinyin_to_pinyin(txt: &str) -> Option<String> {
     Syllable::parse_ascii_pinyin(txt)
         .ok()
         .map(|res| res.0.to_pinyin())
 }
+#[cfg(feature = "alloc")]
 pub fn bopomofo_to_ascii_pinyin(txt: &str) -> Option<String> {
     Syllable::parse_bopomofo(txt)
         .ok()
         .map(|res| res.0.to_ascii_pinyin())
 }
+#[cfg(feature = "alloc")]
 pub fn pinyin_to_ascii_pinyin(txt: &str) -> Option<String> {
     Syllable::parse_pinyin(txt)
         .ok()
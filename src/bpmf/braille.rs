@@ -0,0 +1,328 @@
+//! Braille for blind readers, in the two systems in use:
+//! - Mainland Chinese Braille (现行盲文): a cell for the initial, a cell for the final as
+//!   spelt in pinyin, then a cell for the tone. j q x share the cells of g k h and o shares
+//!   the cell of e. The -i of zhi, chi, shi, ri, zi, ci, si isn't written;
+//! - Taiwanese Braille (國語點字): a cell for the initial, a cell for the medial and rime
+//!   together (or one cell each for the few pairs without their own cell), then a cell for
+//!   the tone. j q x share the cells of g c s.
+//!
+//! The tones follow the rules of each system: the mainland one writes no cell for the neutral
+//! tone, so a syllable without tone cell is read as neutral, while the Taiwanese one always
+//! ends a syllable with its tone cell, the first tone included.
+//! ```
+//! use bpmf_py::bpmf::{BrailleSystem, Syllable};
+//! let zhong: Syllable = "zhong1".parse().unwrap();
+//! assert_eq!(zhong.to_braille(BrailleSystem::Mainland).unwrap(), "⠌⠲⠁");
+//! assert_eq!(zhong.to_braille(BrailleSystem::Taiwan).unwrap(), "⠁⠯⠄");
+//! let (syl, _) = Syllable::parse_braille("⠌⠲⠁", BrailleSystem::Mainland).unwrap();
+//! assert_eq!(syl, zhong);
+//! ```
+use super::{Init, Med, ParseBopomofoError, Rime, Syllable, Tone};
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BrailleSystem {
+    /// 现行盲文, the Current Braille of mainland China
+    Mainland,
+    /// 國語點字, the Bopomofo-based Braille of Taiwan
+    Taiwan,
+}
+
+pub const MAINLAND_INITIALS: [(Init, char); 21] = [
+    (Init::Bo, '⠃'),
+    (Init::Po, '⠏'),
+    (Init::Mo, '⠍'),
+    (Init::Fo, '⠋'),
+    (Init::De, '⠙'),
+    (Init::Te, '⠞'),
+    (Init::Ne, '⠝'),
+    (Init::Le, '⠇'),
+    (Init::Ge, '⠛'),
+    (Init::Ke, '⠅'),
+    (Init::He, '⠓'),
+    (Init::Ji, '⠛'),
+    (Init::Qi, '⠅'),
+    (Init::Xi, '⠓'),
+    (Init::Zhi, '⠌'),
+    (Init::Chi, '⠟'),
+    (Init::Shi, '⠱'),
+    (Init::Ri, '⠚'),
+    (Init::Zi, '⠵'),
+    (Init::Ci, '⠉'),
+    (Init::Si, '⠎'),
+];
+
+/// The finals of pinyin as medial and rime, e.g. ong is (Wu, Eng) and ui is (Wu, Ei)
+pub const MAINLAND_FINALS: [(Med, Rime, char); 35] = [
+    (Med::NoMed, Rime::A, '⠔'),
+    (Med::NoMed, Rime::O, '⠢'),
+    (Med::NoMed, Rime::E, '⠢'),
+    (Med::NoMed, Rime::Ai, '⠪'),
+    (Med::NoMed, Rime::Ei, '⠮'),
+    (Med::NoMed, Rime::Ao, '⠖'),
+    (Med::NoMed, Rime::Ou, '⠷'),
+    (Med::NoMed, Rime::An, '⠧'),
+    (Med::NoMed, Rime::En, '⠴'),
+    (Med::NoMed, Rime::Ang, '⠦'),
+    (Med::NoMed, Rime::Eng, '⠼'),
+    (Med::NoMed, Rime::Er, '⠗'),
+    (Med::Yi, Rime::NoRime, '⠊'),
+    (Med::Yi, Rime::A, '⠫'),
+    (Med::Yi, Rime::Eh, '⠑'),
+    (Med::Yi, Rime::Ao, '⠜'),
+    (Med::Yi, Rime::Ou, '⠳'),
+    (Med::Yi, Rime::An, '⠩'),
+    (Med::Yi, Rime::En, '⠣'),
+    (Med::Yi, Rime::Ang, '⠭'),
+    (Med::Yi, Rime::Eng, '⠡'),
+    (Med::Wu, Rime::NoRime, '⠥'),
+    (Med::Wu, Rime::A, '⠿'),
+    (Med::Wu, Rime::O, '⠕'),
+    (Med::Wu, Rime::Ai, '⠽'),
+    (Med::Wu, Rime::Ei, '⠺'),
+    (Med::Wu, Rime::An, '⠻'),
+    (Med::Wu, Rime::En, '⠒'),
+    (Med::Wu, Rime::Ang, '⠶'),
+    (Med::Wu, Rime::Eng, '⠲'),
+    (Med::Yu, Rime::NoRime, '⠬'),
+    (Med::Yu, Rime::Eh, '⠾'),
+    (Med::Yu, Rime::An, '⠯'),
+    (Med::Yu, Rime::En, '⠸'),
+    (Med::Yu, Rime::Eng, '⠹'),
+];
+
+/// No cell for the neutral tone
+pub const MAINLAND_TONES: [(Tone, char); 4] = [
+    (Tone::Level, '⠁'),
+    (Tone::Rise, '⠂'),
+    (Tone::Dip, '⠄'),
+    (Tone::Fall, '⠆'),
+];
+
+pub const TAIWAN_INITIALS: [(Init, char); 21] = [
+    (Init::Bo, '⠕'),
+    (Init::Po, '⠏'),
+    (Init::Mo, '⠍'),
+    (Init::Fo, '⠟'),
+    (Init::De, '⠙'),
+    (Init::Te, '⠋'),
+    (Init::Ne, '⠝'),
+    (Init::Le, '⠉'),
+    (Init::Ge, '⠅'),
+    (Init::Ke, '⠇'),
+    (Init::He, '⠗'),
+    (Init::Ji, '⠅'),
+    (Init::Qi, '⠚'),
+    (Init::Xi, '⠑'),
+    (Init::Zhi, '⠁'),
+    (Init::Chi, '⠃'),
+    (Init::Shi, '⠊'),
+    (Init::Ri, '⠛'),
+    (Init::Zi, '⠓'),
+    (Init::Ci, '⠚'),
+    (Init::Si, '⠑'),
+];
+
+/// The single symbols and the medial + rime pairs having their own cell
+pub const TAIWAN_FINALS: [(Med, Rime, char); 36] = [
+    (Med::NoMed, Rime::A, '⠜'),
+    (Med::NoMed, Rime::O, '⠣'),
+    (Med::NoMed, Rime::E, '⠮'),
+    (Med::NoMed, Rime::Eh, '⠢'),
+    (Med::NoMed, Rime::Ai, '⠺'),
+    (Med::NoMed, Rime::Ei, '⠴'),
+    (Med::NoMed, Rime::Ao, '⠩'),
+    (Med::NoMed, Rime::Ou, '⠷'),
+    (Med::NoMed, Rime::An, '⠧'),
+    (Med::NoMed, Rime::En, '⠥'),
+    (Med::NoMed, Rime::Ang, '⠭'),
+    (Med::NoMed, Rime::Eng, '⠵'),
+    (Med::NoMed, Rime::Er, '⠱'),
+    (Med::Yi, Rime::NoRime, '⠡'),
+    (Med::Yi, Rime::A, '⠾'),
+    (Med::Yi, Rime::Eh, '⠬'),
+    (Med::Yi, Rime::Ao, '⠪'),
+    (Med::Yi, Rime::Ou, '⠎'),
+    (Med::Yi, Rime::An, '⠞'),
+    (Med::Yi, Rime::En, '⠹'),
+    (Med::Yi, Rime::Ang, '⠨'),
+    (Med::Yi, Rime::Eng, '⠽'),
+    (Med::Wu, Rime::NoRime, '⠌'),
+    (Med::Wu, Rime::A, '⠔'),
+    (Med::Wu, Rime::O, '⠒'),
+    (Med::Wu, Rime::Ai, '⠶'),
+    (Med::Wu, Rime::Ei, '⠫'),
+    (Med::Wu, Rime::An, '⠻'),
+    (Med::Wu, Rime::En, '⠿'),
+    (Med::Wu, Rime::Ang, '⠸'),
+    (Med::Wu, Rime::Eng, '⠯'),
+    (Med::Yu, Rime::NoRime, '⠳'),
+    (Med::Yu, Rime::Eh, '⠦'),
+    (Med::Yu, Rime::An, '⠘'),
+    (Med::Yu, Rime::En, '⠲'),
+    (Med::Yu, Rime::Eng, '⠖'),
+];
+
+pub const TAIWAN_TONES: [(Tone, char); 5] = [
+    (Tone::Level, '⠄'),
+    (Tone::Rise, '⠂'),
+    (Tone::Dip, '⠈'),
+    (Tone::Fall, '⠐'),
+    (Tone::Neut, '⠁'),
+];
+
+type Tables = (
+    &'static [(Init, char)],
+    &'static [(Med, Rime, char)],
+    &'static [(Tone, char)],
+);
+
+impl BrailleSystem {
+    fn _tables(self) -> Tables {
+        match self {
+            BrailleSystem::Mainland => (&MAINLAND_INITIALS, &MAINLAND_FINALS, &MAINLAND_TONES),
+            BrailleSystem::Taiwan => (&TAIWAN_INITIALS, &TAIWAN_FINALS, &TAIWAN_TONES),
+        }
+    }
+}
+
+impl Syllable {
+    /// The Unicode Braille cells of the syllable, None if the system has no cells for it,
+    /// like ê or yo in the mainland system
+    #[cfg(feature = "alloc")]
+    pub fn to_braille(&self, system: BrailleSystem) -> Option<String> {
+        Some(self._braille_cells(system)?.into_iter().flatten().collect())
+    }
+
+    /// Writes nothing if the system has no cells for the syllable
+    pub(super) fn _write_braille<W: Write + ?Sized>(
+        &self,
+        system: BrailleSystem,
+        w: &mut W,
+    ) -> fmt::Result {
+        for cell in self._braille_cells(system).into_iter().flatten().flatten() {
+            w.write_char(cell)?
+        }
+        Ok(())
+    }
+
+    /// The cells of the initial, the final (one or two) and the tone, each one may be absent
+    fn _braille_cells(&self, system: BrailleSystem) -> Option<[Option<char>; 4]> {
+        let (inits, finals, tones) = system._tables();
+        let final_cell = |med: Med, rime: Rime| {
+            finals
+                .iter()
+                .find(|(m, r, _)| (*m, *r) == (med, rime))
+                .map(|(_, _, cell)| *cell)
+        };
+        let mut cells = [None; 4];
+        if self.init != Init::NoInit {
+            let (_, cell) = inits.iter().find(|(init, _)| *init == self.init())?;
+            cells[0] = Some(*cell);
+        }
+        match (self.med(), self.rime()) {
+            //zhi, chi, shi, ri, zi, ci, si
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zhi => {}
+            (med, rime) => match final_cell(med, rime) {
+                Some(cell) => cells[1] = Some(cell),
+                //the medial and the rime apart
+                None if system == BrailleSystem::Taiwan && med != Med::NoMed => {
+                    cells[1] = Some(final_cell(med, Rime::NoRime)?);
+                    cells[2] = Some(final_cell(Med::NoMed, rime)?);
+                }
+                None => return None,
+            },
+        }
+        cells[3] = tones
+            .iter()
+            .find(|(tone, _)| *tone == self.tone())
+            .map(|(_, cell)| *cell);
+        Some(cells)
+    }
+
+    /// Parses the leading syllable of `txt` written in Braille, it returns the syllable and
+    /// the remaining part of the text like the other parsers.
+    /// The shared cells are told apart by the final: j q x go before i and ü, and in the
+    /// mainland system o goes after b p m f, e elsewhere (so "lo" is read "le").
+    /// Without tone cell the syllable is neutral in the mainland system and has no tone in
+    /// the Taiwanese one
+    pub fn parse_braille(
+        txt: &str,
+        system: BrailleSystem,
+    ) -> Result<(Syllable, &str), ParseBopomofoError> {
+        let (inits, finals, tones) = system._tables();
+        let txt = Self::_skip_whitespaces(txt);
+        let mut offset = 0;
+        let mut chars = txt.chars();
+        let mut next = chars.next();
+
+        //the cell of the initial, the initial itself depends on the final
+        let mut init_cell = None;
+        if let Some(ch) = next.filter(|ch| inits.iter().any(|(_, cell)| cell == ch)) {
+            init_cell = Some(ch);
+            //all the Braille patterns are 3 bytes long
+            offset += 3;
+            next = chars.next();
+        }
+
+        let (mut med, mut rime) = (Med::NoMed, Rime::NoRime);
+        if let Some((m, r, _)) = next.and_then(|ch| finals.iter().find(|(_, _, cell)| *cell == ch))
+        {
+            (med, rime) = (*m, *r);
+            offset += 3;
+            next = chars.next();
+            //a medial followed by a rime in their own cells
+            if system == BrailleSystem::Taiwan && med != Med::NoMed && rime == Rime::NoRime {
+                let found = next.and_then(|ch| {
+                    finals
+                        .iter()
+                        .find(|(m, r, cell)| *cell == ch && *m == Med::NoMed && *r != Rime::Er)
+                });
+                if let Some((_, r, _)) = found {
+                    rime = *r;
+                    offset += 3;
+                    next = chars.next();
+                }
+            }
+        }
+
+        let palatal = med == Med::Yi || med == Med::Yu;
+        let init = match init_cell {
+            Some(ch) => {
+                let mut candidates = inits.iter().filter(|(_, cell)| *cell == ch);
+                let first = candidates.clone().next().map(|(init, _)| *init);
+                candidates
+                    .find(|(init, _)| (Init::Ji..=Init::Xi).contains(init) == palatal)
+                    .map(|(init, _)| *init)
+                    .or(first)
+                    .ok_or(ParseBopomofoError)?
+            }
+            None => Init::NoInit,
+        };
+        if system == BrailleSystem::Mainland
+            && (med, rime) == (Med::NoMed, Rime::O)
+            && !(Init::Bo..=Init::Fo).contains(&init)
+        {
+            rime = Rime::E;
+        }
+
+        let tone = match next.and_then(|ch| tones.iter().find(|(_, cell)| *cell == ch)) {
+            Some((tone, _)) => {
+                offset += 3;
+                *tone
+            }
+            None if system == BrailleSystem::Mainland => Tone::Neut,
+            None => Tone::NoTone,
+        };
+
+        let syl = Syllable::new(init, med, rime, tone);
+        if syl.is_valid() {
+            Ok((syl, &txt[offset..]))
+        } else {
+            Err(ParseBopomofoError)
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Orders of syllables and of words (slices of syllables).
+//!
+//! `Ord` of `Syllable` follows the bopomofo order (b p m f ... i u ü), mainland dictionaries
+//! like Xinhua sort by the spelling of pinyin instead:
+//! ```
+//! use bpmf_py::bpmf::{Collation, Syllable};
+//! let mut list: Vec<Syllable> = ["lü4", "lu4", "luo1", "lu2"].iter().map(|s| s.parse().unwrap()).collect();
+//! Collation::PinyinAlphabetic.sort(&mut list);
+//! let sorted: Vec<String> = list.iter().map(Syllable::to_ascii_pinyin).collect();
+//! assert_eq!(sorted, ["lu2", "lu4", "luo1", "lv4"]);
+//! ```
+use super::{Med, Rime, Syllable, Tone};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Collation {
+    /// The order of `Ord`: by initials, medials, rimes in the bopomofo order, then by tones
+    #[default]
+    Bopomofo,
+    /// Alphabetical by the toneless pinyin with ü right after u, then by tones 1 to 4,
+    /// the neutral tone and the syllables without tone last
+    PinyinAlphabetic,
+    /// By the tones first (in the order above), then alphabetical by the toneless pinyin
+    ToneFirst,
+}
+
+/// The letters of pinyin in order, the rank of a letter is its index + 1 so 0 stands for
+/// the end of the spelling and prefixes go first. ê goes right after e, as ü after u
+const ALPHABET: [char; 27] = [
+    'a', 'b', 'c', 'd', 'e', 'ê', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
+    's', 't', 'u', 'ü', 'w', 'x', 'y', 'z',
+];
+const BASE: u32 = ALPHABET.len() as u32 + 1;
+/// No pinyin is longer than "zhuang"
+const MAX_LETTERS: u32 = 6;
+
+impl Collation {
+    /// A key that sorts the syllables in this order. Syllables spelt the same in pinyin are
+    /// told apart by the bopomofo order, so the key is unique to each syllable
+    pub fn key(self, syl: &Syllable) -> u64 {
+        let primary = match self {
+            Collation::Bopomofo => 0,
+            Collation::PinyinAlphabetic => _spelling_rank(syl) * 8 + _tone_rank(syl.tone()),
+            Collation::ToneFirst => {
+                _tone_rank(syl.tone()) * BASE.pow(MAX_LETTERS) + _spelling_rank(syl)
+            }
+        };
+        (primary as u64) << 32 | syl.ord() as u64
+    }
+
+    pub fn compare(self, a: &Syllable, b: &Syllable) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Compares the words syllable by syllable, a word goes before the longer words it starts
+    pub fn compare_words(self, a: &[Syllable], b: &[Syllable]) -> Ordering {
+        a.iter()
+            .map(|syl| self.key(syl))
+            .cmp(b.iter().map(|syl| self.key(syl)))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn sort(self, list: &mut [Syllable]) {
+        list.sort_by_cached_key(|syl| self.key(syl))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn sort_words<W: AsRef<[Syllable]>>(self, list: &mut [W]) {
+        list.sort_by(|a, b| self.compare_words(a.as_ref(), b.as_ref()))
+    }
+}
+
+/// A byte key of the word, comparing the keys with memcmp (e.g. BLOB columns of SQLite) gives
+/// the same order as `Collation::compare_words`. Each syllable takes 8 bytes
+#[cfg(feature = "alloc")]
+pub fn sort_key(word: &[Syllable], collation: Collation) -> Vec<u8> {
+    let mut key = Vec::with_capacity(word.len() * 8);
+    for syl in word {
+        key.extend_from_slice(&collation.key(syl).to_be_bytes());
+    }
+    key
+}
+
+/// Gets the word back from a key made by `sort_key` with any collation.
+/// None if the length or any of the syllables is wrong
+#[cfg(feature = "alloc")]
+pub fn decode_sort_key(key: &[u8]) -> Option<Vec<Syllable>> {
+    if key.len() % 8 != 0 {
+        return None;
+    }
+    key.chunks_exact(8)
+        .map(|chunk| {
+            //the lower half is the ord of the syllable
+            let ord = u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+            Syllable::from_ord(ord)
+        })
+        .collect()
+}
+
+/// 1 to 4 for the four tones, then 5 for the neutral tone and 6 for no tone
+fn _tone_rank(tone: Tone) -> u32 {
+    match tone {
+        Tone::NoTone => 6,
+        tone => tone as u32,
+    }
+}
+
+/// The toneless pinyin as a number in base 28, padded to the maximum length
+fn _spelling_rank(syl: &Syllable) -> u32 {
+    let (med, nuc, coda) = syl._get_rime_parts();
+    //ㄝ alone is spelt with the nucleus e, while its pinyin is ê
+    let nuc = match (syl.med(), syl.rime()) {
+        (Med::NoMed, Rime::Eh) => 'ê',
+        _ => nuc,
+    };
+    let letters = syl
+        ._pinyin_inital()
+        .chars()
+        .chain([med, nuc].into_iter().filter(|ch| *ch != '\0'))
+        .chain(coda.chars());
+    let mut rank = 0;
+    let mut count = 0;
+    for letter in letters.take(MAX_LETTERS as usize) {
+        let idx = ALPHABET.iter().position(|ch| *ch == letter).unwrap_or(0) as u32;
+        rank = rank * BASE + idx + 1;
+        count += 1;
+    }
+    rank * BASE.pow(MAX_LETTERS - count)
+}
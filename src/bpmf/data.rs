@@ -1,15 +1,17 @@
-use super::{enums::*, spell_tree::SpellTree};
-use once_cell::sync::OnceCell;
-use std::collections::HashMap;
-use sugars::hmap;
+use super::{
+    enums::*,
+    static_tree::{count_nodes, StaticSpellTree},
+};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, sync::OnceLock};
 
-pub const PINYIN_INITIALS: [&'static str; 25] = [
+pub const PINYIN_INITIALS: [&str; 25] = [
     "", //0 initial
     "b", "p", "m", "f", "d", "t", "n", "l", "g", "k", "h", //11th
     "j", "q", "x", "zh", "ch", "sh", "r", "z", "c", "s", //21th
     "y", "w", "y", // yi wu yu
 ];
-pub const PINYIN_NUC_CODAS: [(char, &'static str); 17] = [
+pub const PINYIN_NUC_CODAS: [(char, &str); 17] = [
     ('i', ""), //ㄭ with no rime no medial
     ('a', ""),
     ('o', ""),
@@ -39,49 +41,165 @@ pub const PINYIN_TONED_NUCS: [(char, &str); 6] = [
 ];
 
 
-static INIT_PINYIN_TO_BOPOMOFO_MAP: OnceCell<HashMap<char, char>> = OnceCell::new();
+/// The bopomofo initials and their pinyin
+pub const INIT_BOPOMOFO_TO_PINYIN: [(char, &str); 22] = [
+    ('\0', ""),
+    ('ㄅ', "b"),
+    ('ㄆ', "p"),
+    ('ㄇ', "m"),
+    ('ㄈ', "f"),
+    ('ㄉ', "d"),
+    ('ㄊ', "t"),
+    ('ㄋ', "n"),
+    ('ㄌ', "l"),
+    ('ㄍ', "g"),
+    ('ㄎ', "k"),
+    ('ㄏ', "h"),
+    ('ㄐ', "j"),
+    ('ㄑ', "q"),
+    ('ㄒ', "x"),
+    ('ㄓ', "zh"),
+    ('ㄔ', "ch"),
+    ('ㄕ', "sh"),
+    ('ㄖ', "r"),
+    ('ㄗ', "z"),
+    ('ㄘ', "c"),
+    ('ㄙ', "s"),
+];
+
+/// The bopomofo initial spelt with a single letter in pinyin, so z, c, s give ㄗ, ㄘ, ㄙ
+/// and zh, ch, sh must be told apart by the caller
+pub const fn init_pinyin_to_bopomofo(letter: char) -> Option<char> {
+    let mut i = 0;
+    while i < INIT_BOPOMOFO_TO_PINYIN.len() {
+        let (bpmf, pinyin) = INIT_BOPOMOFO_TO_PINYIN[i];
+        if pinyin.len() == 1 && pinyin.as_bytes()[0] as char == letter {
+            return Some(bpmf);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The old runtime map of `init_pinyin_to_bopomofo`, built once on first use
+#[cfg(feature = "std")]
+#[deprecated(note = "use init_pinyin_to_bopomofo instead")]
 pub fn get_init_pinyin_to_bopomofo_map() -> &'static HashMap<char, char> {
-    INIT_PINYIN_TO_BOPOMOFO_MAP.get_or_init(|| {
-        HashMap::from_iter(
-            get_init_bopomofo_to_pinyin_map()
-                .iter()
-                .filter(|(_, v)| v.len() == 1)
-                .map(|(k, v)| (v.chars().next().unwrap(), *k)),
-        )
+    static MAP: OnceLock<HashMap<char, char>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        INIT_BOPOMOFO_TO_PINYIN
+            .iter()
+            .filter(|(_, v)| v.len() == 1)
+            .map(|(k, v)| (v.chars().next().unwrap(), *k))
+            .collect()
     })
 }
 
-
-static PINYIN_INIT_MAP: OnceCell<HashMap<char, &'static str>> = OnceCell::new();
+/// The old runtime map of `INIT_BOPOMOFO_TO_PINYIN`, built once on first use
+#[cfg(feature = "std")]
+#[deprecated(note = "use INIT_BOPOMOFO_TO_PINYIN instead")]
 pub fn get_init_bopomofo_to_pinyin_map() -> &'static HashMap<char, &'static str> {
-    PINYIN_INIT_MAP.get_or_init(|| {
-        hmap! {
-            '\0'=>"",
-            'ㄅ'=>"b",
-            'ㄆ'=>"p",
-            'ㄇ'=>"m",
-            'ㄈ'=>"f",
-            'ㄉ'=>"d",
-            'ㄊ'=>"t",
-            'ㄋ'=>"n",
-            'ㄌ'=>"l",
-            'ㄍ'=>"g",
-            'ㄎ'=>"k",
-            'ㄏ'=>"h",
-            'ㄐ'=>"j",
-            'ㄑ'=>"q",
-            'ㄒ'=>"x",
-            'ㄓ'=>"zh",
-            'ㄔ'=>"ch",
-            'ㄕ'=>"sh",
-            'ㄖ'=>"r",
-            'ㄗ'=>"z",
-            'ㄘ'=>"c",
-            'ㄙ'=>"s",
-        }
-    })
+    static MAP: OnceLock<HashMap<char, &'static str>> = OnceLock::new();
+    MAP.get_or_init(|| INIT_BOPOMOFO_TO_PINYIN.iter().copied().collect())
 }
 
+//#region phonotactics
+/// The rimes each (initial, medial) can take in Mandarin, bit n stands for the rime n.
+/// Indexed by init * 4 + med, the columns are NoMed, ㄧ, ㄨ, ㄩ.
+/// It holds the syllables of `MANDARIN_SOUNDS` in src/tests/resources.rs with the tones
+/// dropped, plus ê (ㄝ) alone which the list lacks; a test rebuilds it from the list
+pub const VALID_RIMES: [u16; 88] = [
+    0b11111111111110,
+    0b01111110110111,
+    0b01111001100111,
+    0b01011000010001, //∅
+    0b01111011100110,
+    0b01011010010001,
+    0b00000000000001,
+    0b00000000000000, //ㄅ
+    0b01111111100110,
+    0b01011010010011,
+    0b00000000000001,
+    0b00000000000000, //ㄆ
+    0b01111111101110,
+    0b01011110010001,
+    0b00000000000001,
+    0b00000000000000, //ㄇ
+    0b01111101000110,
+    0b00000000000000,
+    0b00000000000001,
+    0b00000000000000, //ㄈ
+    0b01111111101010,
+    0b01001110010001,
+    0b01011001000101,
+    0b00000000000000, //ㄉ
+    0b01101110101010,
+    0b01001010010001,
+    0b01011001000101,
+    0b00000000000000, //ㄊ
+    0b01111111101010,
+    0b01111110010001,
+    0b01011000000101,
+    0b00000000010001, //ㄋ
+    0b01101111101110,
+    0b01111110010011,
+    0b01011000000101,
+    0b00001000010001, //ㄌ
+    0b01111111101010,
+    0b00000000000000,
+    0b01111001100111,
+    0b00000000000000, //ㄍ
+    0b01111110101010,
+    0b00000000000000,
+    0b01111001100111,
+    0b00000000000000, //ㄎ
+    0b01111111101010,
+    0b00000000000000,
+    0b01111001100111,
+    0b00000000000000, //ㄏ
+    0b00000000000000,
+    0b01111110010011,
+    0b00000000000000,
+    0b01011000010001, //ㄐ
+    0b00000000000000,
+    0b01111110010011,
+    0b00000000000000,
+    0b01011000010001, //ㄑ
+    0b00000000000000,
+    0b01111110010011,
+    0b00000000000000,
+    0b01011000010001, //ㄒ
+    0b01111111101011,
+    0b00000000000000,
+    0b01111001100111,
+    0b00000000000000, //ㄓ
+    0b01111110101011,
+    0b00000000000000,
+    0b01111001100111,
+    0b00000000000000, //ㄔ
+    0b01111111101011,
+    0b00000000000000,
+    0b00111001100111,
+    0b00000000000000, //ㄕ
+    0b01111110001001,
+    0b00000000000000,
+    0b01011001000101,
+    0b00000000000000, //ㄖ
+    0b01111111101011,
+    0b00000000000000,
+    0b01011001000101,
+    0b00000000000000, //ㄗ
+    0b01111110101011,
+    0b00000000000000,
+    0b01011001000101,
+    0b00000000000000, //ㄘ
+    0b01111111101011,
+    0b00000000000000,
+    0b01011001000101,
+    0b00000000000000, //ㄙ
+];
+//#endregion
+
 //#region rime trees
 use Med::*;
 use Rime::*;
@@ -135,15 +253,12 @@ pub const PINYIN_UNTONED_RIMES: [(&str, (Med, Rime)); 46] = [
     ("ün", (Yu, En)),
 ];
 
-static PINYIN_UNTONED_RIME_TREE: OnceCell<SpellTree<(Med, Rime)>> = OnceCell::new();
-pub fn get_pinyin_untoned_rime_tree() -> &'static SpellTree<(Med, Rime)> {
-    PINYIN_UNTONED_RIME_TREE.get_or_init(|| {
-        let mut tree = SpellTree::new();
-        for (term, med_rime) in PINYIN_UNTONED_RIMES {
-            tree.insert(term, med_rime);
-        }
-        tree
-    })
+pub const PINYIN_UNTONED_RIME_NODES: usize = count_nodes(&PINYIN_UNTONED_RIMES);
+pub static PINYIN_UNTONED_RIME_TREE: StaticSpellTree<(Med, Rime), PINYIN_UNTONED_RIME_NODES> =
+    StaticSpellTree::build(&PINYIN_UNTONED_RIMES);
+pub const fn get_pinyin_untoned_rime_tree(
+) -> &'static StaticSpellTree<(Med, Rime), PINYIN_UNTONED_RIME_NODES> {
+    &PINYIN_UNTONED_RIME_TREE
 }
 
 pub const PINYIN_TONED_RIMES: [(&str, (Med, Rime, u8)); 190] = [
@@ -339,14 +454,77 @@ pub const PINYIN_TONED_RIMES: [(&str, (Med, Rime, u8)); 190] = [
     ("iong", (Yu, Eng, 5)),
 ];
 
-static PINYIN_TONED_RIME_TREE: OnceCell<SpellTree<(Med, Rime, u8)>> = OnceCell::new();
-pub fn get_pinyin_toned_rime_tree() -> &'static SpellTree<(Med, Rime, u8)> {
-    PINYIN_TONED_RIME_TREE.get_or_init(|| {
-        let mut tree = SpellTree::new();
-        for (term, fin) in PINYIN_TONED_RIMES {
-            tree.insert(term, fin);
-        }
-        tree
-    })
+pub const PINYIN_TONED_RIME_NODES: usize = count_nodes(&PINYIN_TONED_RIMES);
+pub static PINYIN_TONED_RIME_TREE: StaticSpellTree<(Med, Rime, u8), PINYIN_TONED_RIME_NODES> =
+    StaticSpellTree::build(&PINYIN_TONED_RIMES);
+pub const fn get_pinyin_toned_rime_tree(
+) -> &'static StaticSpellTree<(Med, Rime, u8), PINYIN_TONED_RIME_NODES> {
+    &PINYIN_TONED_RIME_TREE
+}
+
+/// The toneless ascii pinyin of every syllable of Mandarin, a row for each initial in the
+/// order of bopomofo. ê alone is spelt "e" like ㄜ, so it's not listed twice
+#[rustfmt::skip]
+pub const PINYIN_ASCII_SYLLABLES: [&str; 412] = [
+    "a", "o", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er", "yi", "ya", "yo", "ye",
+    "yai", "yao", "you", "yan", "yin", "yang", "ying", "wu", "wa", "wo", "wai", "wei", "wan",
+    "wen", "wang", "weng", "yu", "yue", "yuan", "yun", "yong",
+    "ba", "bo", "bai", "bei", "bao", "ban", "ben", "bang", "beng", "bi", "bie", "biao", "bian",
+    "bin", "bing", "bu",
+    "pa", "po", "pai", "pei", "pao", "pou", "pan", "pen", "pang", "peng", "pi", "pia", "pie",
+    "piao", "pian", "pin", "ping", "pu",
+    "ma", "mo", "me", "mai", "mei", "mao", "mou", "man", "men", "mang", "meng", "mi", "mie",
+    "miao", "miu", "mian", "min", "ming", "mu",
+    "fa", "fo", "fei", "fou", "fan", "fen", "fang", "feng", "fu",
+    "da", "de", "dai", "dei", "dao", "dou", "dan", "den", "dang", "deng", "di", "die", "diao",
+    "diu", "dian", "ding", "du", "duo", "dui", "duan", "dun", "dong",
+    "ta", "te", "tai", "tao", "tou", "tan", "tang", "teng", "ti", "tie", "tiao", "tian", "ting",
+    "tu", "tuo", "tui", "tuan", "tun", "tong",
+    "na", "ne", "nai", "nei", "nao", "nou", "nan", "nen", "nang", "neng", "ni", "nie", "niao",
+    "niu", "nian", "nin", "niang", "ning", "nu", "nuo", "nuan", "nun", "nong", "nv", "nve",
+    "la", "lo", "le", "lai", "lei", "lao", "lou", "lan", "lang", "leng", "li", "lia", "lie",
+    "liao", "liu", "lian", "lin", "liang", "ling", "lu", "luo", "luan", "lun", "long", "lv", "lve",
+    "lvan",
+    "ga", "ge", "gai", "gei", "gao", "gou", "gan", "gen", "gang", "geng", "gu", "gua", "guo",
+    "guai", "gui", "guan", "gun", "guang", "gong",
+    "ka", "ke", "kai", "kao", "kou", "kan", "ken", "kang", "keng", "ku", "kua", "kuo", "kuai",
+    "kui", "kuan", "kun", "kuang", "kong",
+    "ha", "he", "hai", "hei", "hao", "hou", "han", "hen", "hang", "heng", "hu", "hua", "huo",
+    "huai", "hui", "huan", "hun", "huang", "hong",
+    "ji", "jia", "jie", "jiao", "jiu", "jian", "jin", "jiang", "jing", "ju", "jue", "juan", "jun",
+    "jiong",
+    "qi", "qia", "qie", "qiao", "qiu", "qian", "qin", "qiang", "qing", "qu", "que", "quan", "qun",
+    "qiong",
+    "xi", "xia", "xie", "xiao", "xiu", "xian", "xin", "xiang", "xing", "xu", "xue", "xuan", "xun",
+    "xiong",
+    "zhi", "zha", "zhe", "zhai", "zhei", "zhao", "zhou", "zhan", "zhen", "zhang", "zheng", "zhu",
+    "zhua", "zhuo", "zhuai", "zhui", "zhuan", "zhun", "zhuang", "zhong",
+    "chi", "cha", "che", "chai", "chao", "chou", "chan", "chen", "chang", "cheng", "chu", "chua",
+    "chuo", "chuai", "chui", "chuan", "chun", "chuang", "chong",
+    "shi", "sha", "she", "shai", "shei", "shao", "shou", "shan", "shen", "shang", "sheng", "shu",
+    "shua", "shuo", "shuai", "shui", "shuan", "shun", "shuang",
+    "ri", "re", "rao", "rou", "ran", "ren", "rang", "reng", "ru", "ruo", "rui", "ruan", "run",
+    "rong",
+    "zi", "za", "ze", "zai", "zei", "zao", "zou", "zan", "zen", "zang", "zeng", "zu", "zuo", "zui",
+    "zuan", "zun", "zong",
+    "ci", "ca", "ce", "cai", "cao", "cou", "can", "cen", "cang", "ceng", "cu", "cuo", "cui",
+    "cuan", "cun", "cong",
+    "si", "sa", "se", "sai", "sei", "sao", "sou", "san", "sen", "sang", "seng", "su", "suo", "sui",
+    "suan", "sun", "song",
+];
+
+pub const PINYIN_ASCII_SYLLABLE_NODES: usize = count_nodes(&_no_meanings(PINYIN_ASCII_SYLLABLES));
+/// For the queries, which look up the beginnings of syllables
+pub static PINYIN_ASCII_SYLLABLE_TREE: StaticSpellTree<(), PINYIN_ASCII_SYLLABLE_NODES> =
+    StaticSpellTree::build(&_no_meanings(PINYIN_ASCII_SYLLABLES));
+
+const fn _no_meanings<const N: usize>(list: [&'static str; N]) -> [(&'static str, ()); N] {
+    let mut pairs = [("", ()); N];
+    let mut i = 0;
+    while i < N {
+        pairs[i].0 = list[i];
+        i += 1;
+    }
+    pairs
 }
 //#endregion
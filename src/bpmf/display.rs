@@ -0,0 +1,79 @@
+//! Zero-allocation `Display` adapters, created by `Syllable::pinyin()`, `Syllable::ascii_pinyin()`,
+//! `Syllable::bopomofo()` and `Syllable::display_as()`. Width, fill, alignment and precision
+//! apply as they do to `str`
+use super::{Notation, Syllable};
+use core::fmt::{self, Display, Formatter, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PinyinDisplay(pub(super) Syllable);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsciiPinyinDisplay(pub(super) Syllable);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BopomofoDisplay(pub(super) Syllable);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotationDisplay(pub(super) Syllable, pub(super) Notation);
+
+impl Display for PinyinDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        _pad(f, |w| self.0._write_pinyin(w))
+    }
+}
+
+impl Display for AsciiPinyinDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        _pad(f, |w| self.0._write_ascii_pinyin(w))
+    }
+}
+
+impl Display for BopomofoDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        _pad(f, |w| self.0._write_bopomofo(w))
+    }
+}
+
+impl Display for NotationDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        _pad(f, |w| match self.1 {
+            Notation::Bopomofo => self.0._write_bopomofo(w),
+            Notation::Pinyin => self.0._write_pinyin(w),
+            Notation::AsciiPinyin => self.0._write_ascii_pinyin(w),
+            Notation::Braille(system) => self.0._write_braille(system, w),
+            Notation::Palladius => self.0._write_palladius(w),
+            Notation::Hangul => self.0._write_hangul(w),
+            Notation::Katakana => self.0._write_katakana(w),
+        })
+    }
+}
+
+/// Without width or precision the spelling goes straight into the formatter, otherwise it's
+/// spelt into a buffer on the stack first so `Formatter::pad` can fill, align and truncate it
+fn _pad(f: &mut Formatter<'_>, write: impl Fn(&mut dyn Write) -> fmt::Result) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return write(f);
+    }
+    let mut buf = _PadBuf { bytes: [0; 32], len: 0 };
+    write(&mut buf)?;
+    //only whole strs are copied in, so the bytes are valid utf-8
+    f.pad(core::str::from_utf8(&buf.bytes[..buf.len]).map_err(|_| fmt::Error)?)
+}
+
+/// Long enough for a syllable in any notation, "˙ㄓㄨㄤ" takes 11 bytes
+struct _PadBuf {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Write for _PadBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use super::super::bpmf_chars as bc;
-use std::mem::transmute;
+use super::data::PINYIN_INITIALS;
 // #region ENUMS
 
 #[repr(u8)]
@@ -29,13 +29,75 @@ pub enum Init {
     Si,
 }
 
+impl Init {
+    /// All the variants in the order of the discriminants, the empty one first
+    pub const ALL: [Init; 22] = [
+        Init::NoInit,
+        Init::Bo,
+        Init::Po,
+        Init::Mo,
+        Init::Fo,
+        Init::De,
+        Init::Te,
+        Init::Ne,
+        Init::Le,
+        Init::Ge,
+        Init::Ke,
+        Init::He,
+        Init::Ji,
+        Init::Qi,
+        Init::Xi,
+        Init::Zhi,
+        Init::Chi,
+        Init::Shi,
+        Init::Ri,
+        Init::Zi,
+        Init::Ci,
+        Init::Si,
+    ];
+
+    pub(crate) const fn _from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The spelling in pinyin, e.g. "zh", "" for NoInit
+    pub const fn pinyin(&self) -> &'static str {
+        PINYIN_INITIALS[*self as usize]
+    }
+
+    /// The inverse of `pinyin`
+    pub fn from_pinyin(txt: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|init| init.pinyin() == txt)
+    }
+
+    /// The name of the variant in lower case, i.e. the name of the bopomofo letter, e.g. "zhi"
+    pub const fn ascii_name(&self) -> &'static str {
+        [
+            "noinit", "bo", "po", "mo", "fo", "de", "te", "ne", "le", "ge", "ke", "he", "ji", "qi",
+            "xi", "zhi", "chi", "shi", "ri", "zi", "ci", "si",
+        ][*self as usize]
+    }
+}
+
 impl TryFrom<u8> for Init {
     type Error = &'static str;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::_from_u8(value).ok_or("Invalid value for bopomofo initial")
+    }
+}
+
+impl TryFrom<char> for Init {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            0..=21 => unsafe { Ok(transmute(value)) },
-            _ => Err("Invalid value for bopomofo initial"),
+            'ㄅ'..='ㄙ' => Ok(Self::ALL[(value as u32 - bc::BEFORE_BO as u32) as usize]),
+            _ => Err("Invalid char for bopomofo initial"),
         }
     }
 }
@@ -63,13 +125,52 @@ pub enum Med {
     Yu,
 }
 
+impl Med {
+    /// All the variants in the order of the discriminants, the empty one first
+    pub const ALL: [Med; 4] = [Med::NoMed, Med::Yi, Med::Wu, Med::Yu];
+
+    pub(crate) const fn _from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The spelling in pinyin before a rime: "i", "u" or "ü", "" for NoMed
+    pub const fn pinyin(&self) -> &'static str {
+        ["", "i", "u", "ü"][*self as usize]
+    }
+
+    /// The inverse of `pinyin`, "v" is taken for "ü" as in ascii pinyin
+    pub fn from_pinyin(txt: &str) -> Option<Self> {
+        match txt {
+            "v" => Some(Med::Yu),
+            _ => Self::ALL.into_iter().find(|med| med.pinyin() == txt),
+        }
+    }
+
+    /// The name of the variant in lower case, e.g. "yi"
+    pub const fn ascii_name(&self) -> &'static str {
+        ["nomed", "yi", "wu", "yu"][*self as usize]
+    }
+}
+
 impl TryFrom<u8> for Med {
     type Error = &'static str;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::_from_u8(value).ok_or("Invalid value for bopomofo medial")
+    }
+}
+
+impl TryFrom<char> for Med {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            0..=3 => unsafe { Ok(transmute(value)) },
-            _ => Err("Invalid value for bopomofo medial"),
+            'ㄧ'..='ㄩ' => Ok(Self::ALL[(value as u32 - bc::ER as u32) as usize]),
+            _ => Err("Invalid char for bopomofo medial"),
         }
     }
 }
@@ -98,13 +199,68 @@ pub enum Rime {
     Eng,
     Er,
 }
+impl Rime {
+    /// All the variants in the order of the discriminants, the empty one first
+    pub const ALL: [Rime; 14] = [
+        Rime::NoRime,
+        Rime::A,
+        Rime::O,
+        Rime::E,
+        Rime::Eh,
+        Rime::Ai,
+        Rime::Ei,
+        Rime::Ao,
+        Rime::Ou,
+        Rime::An,
+        Rime::En,
+        Rime::Ang,
+        Rime::Eng,
+        Rime::Er,
+    ];
+
+    pub(crate) const fn _from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The spelling in pinyin without medial, e.g. "ang", "ê" for Eh and "" for NoRime
+    pub const fn pinyin(&self) -> &'static str {
+        [
+            "", "a", "o", "e", "ê", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er",
+        ][*self as usize]
+    }
+
+    /// The inverse of `pinyin`
+    pub fn from_pinyin(txt: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rime| rime.pinyin() == txt)
+    }
+
+    /// The name of the variant in lower case, e.g. "eh"
+    pub const fn ascii_name(&self) -> &'static str {
+        [
+            "norime", "a", "o", "e", "eh", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er",
+        ][*self as usize]
+    }
+}
+
 impl TryFrom<u8> for Rime {
     type Error = &'static str;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::_from_u8(value).ok_or("Invalid value for bopomofo rime")
+    }
+}
+
+impl TryFrom<char> for Rime {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            0..=13 => unsafe { Ok(transmute(value)) },
-            _ => Err("Invalid value for bopomofo rime"),
+            'ㄚ'..='ㄦ' => Ok(Self::ALL[(value as u32 - bc::S as u32) as usize]),
+            _ => Err("Invalid char for bopomofo rime"),
         }
     }
 }
@@ -124,13 +280,65 @@ pub enum Tone {
     Fall,
     Neut,
 }
+impl Tone {
+    /// All the variants in the order of the discriminants, the empty one first
+    pub const ALL: [Tone; 6] = [
+        Tone::NoTone,
+        Tone::Level,
+        Tone::Rise,
+        Tone::Dip,
+        Tone::Fall,
+        Tone::Neut,
+    ];
+
+    pub(crate) const fn _from_u8(value: u8) -> Option<Self> {
+        if (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The combining tone mark of pinyin, e.g. "\u{301}" for Rise (á is a + U+0301).
+    /// "" for NoTone and Neut, which pinyin leaves unmarked
+    pub const fn pinyin(&self) -> &'static str {
+        ["", "\u{304}", "\u{301}", "\u{30C}", "\u{300}", ""][*self as usize]
+    }
+
+    /// The inverse of `pinyin`, it also takes the numbers of ascii pinyin, "1" to "5".
+    /// "" gives NoTone
+    pub fn from_pinyin(txt: &str) -> Option<Self> {
+        match txt.as_bytes() {
+            [digit @ b'1'..=b'5'] => Some(Self::ALL[(digit - b'0') as usize]),
+            _ => Self::ALL.into_iter().find(|tone| tone.pinyin() == txt),
+        }
+    }
+
+    /// The name of the variant in lower case, e.g. "rise"
+    pub const fn ascii_name(&self) -> &'static str {
+        ["notone", "level", "rise", "dip", "fall", "neut"][*self as usize]
+    }
+}
+
 impl TryFrom<u8> for Tone {
     type Error = &'static str;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::_from_u8(value).ok_or("Invalid value for bopomofo tone")
+    }
+}
+
+impl TryFrom<char> for Tone {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            0..=5 => unsafe { Ok(transmute(value)) },
-            _ => Err("Invalid value for bopomofo tone"),
+            bc::TONE_1 => Ok(Tone::Level),
+            bc::TONE_2 => Ok(Tone::Rise),
+            bc::TONE_3 => Ok(Tone::Dip),
+            bc::TONE_4 => Ok(Tone::Fall),
+            bc::TONE_5 => Ok(Tone::Neut),
+            _ => Err("Invalid char for bopomofo tone"),
         }
     }
 }
@@ -147,4 +355,209 @@ impl From<Tone> for char {
         }
     }
 }
+
+/// The finals (韻母) of Mandarin, a medial and a rime taken as a unit. They are named after
+/// their full pinyin (Iou, Uei, Uen, Ueng) with v for ü, in the order of bopomofo.
+///
+/// They follow bopomofo rather than the pinyin tables, one final per pair of medial and rime:
+/// - Ueng is ㄨㄥ, spelt weng alone and -ong after an initial (zhong), there's no Ong;
+/// - Io (ㄧㄛ, yo) and Iai (ㄧㄞ, yai) are listed, though most pinyin tables leave them out;
+/// - Ii is the single empty final of both zhi, chi, shi, ri and zi, ci, si.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Final {
+    Ii,
+    A,
+    O,
+    E,
+    Eh,
+    Ai,
+    Ei,
+    Ao,
+    Ou,
+    An,
+    En,
+    Ang,
+    Eng,
+    Er,
+    I,
+    Ia,
+    Io,
+    Ie,
+    Iai,
+    Iao,
+    Iou,
+    Ian,
+    In,
+    Iang,
+    Ing,
+    U,
+    Ua,
+    Uo,
+    Uai,
+    Uei,
+    Uan,
+    Uen,
+    Uang,
+    Ueng,
+    V,
+    Ve,
+    Van,
+    Vn,
+    Iong,
+}
+
+impl Final {
+    /// All the variants in the order of the discriminants
+    pub const ALL: [Final; 39] = [
+        Final::Ii,
+        Final::A,
+        Final::O,
+        Final::E,
+        Final::Eh,
+        Final::Ai,
+        Final::Ei,
+        Final::Ao,
+        Final::Ou,
+        Final::An,
+        Final::En,
+        Final::Ang,
+        Final::Eng,
+        Final::Er,
+        Final::I,
+        Final::Ia,
+        Final::Io,
+        Final::Ie,
+        Final::Iai,
+        Final::Iao,
+        Final::Iou,
+        Final::Ian,
+        Final::In,
+        Final::Iang,
+        Final::Ing,
+        Final::U,
+        Final::Ua,
+        Final::Uo,
+        Final::Uai,
+        Final::Uei,
+        Final::Uan,
+        Final::Uen,
+        Final::Uang,
+        Final::Ueng,
+        Final::V,
+        Final::Ve,
+        Final::Van,
+        Final::Vn,
+        Final::Iong,
+    ];
+
+    const PARTS: [(Med, Rime); 39] = [
+        (Med::NoMed, Rime::NoRime),
+        (Med::NoMed, Rime::A),
+        (Med::NoMed, Rime::O),
+        (Med::NoMed, Rime::E),
+        (Med::NoMed, Rime::Eh),
+        (Med::NoMed, Rime::Ai),
+        (Med::NoMed, Rime::Ei),
+        (Med::NoMed, Rime::Ao),
+        (Med::NoMed, Rime::Ou),
+        (Med::NoMed, Rime::An),
+        (Med::NoMed, Rime::En),
+        (Med::NoMed, Rime::Ang),
+        (Med::NoMed, Rime::Eng),
+        (Med::NoMed, Rime::Er),
+        (Med::Yi, Rime::NoRime),
+        (Med::Yi, Rime::A),
+        (Med::Yi, Rime::O),
+        (Med::Yi, Rime::Eh),
+        (Med::Yi, Rime::Ai),
+        (Med::Yi, Rime::Ao),
+        (Med::Yi, Rime::Ou),
+        (Med::Yi, Rime::An),
+        (Med::Yi, Rime::En),
+        (Med::Yi, Rime::Ang),
+        (Med::Yi, Rime::Eng),
+        (Med::Wu, Rime::NoRime),
+        (Med::Wu, Rime::A),
+        (Med::Wu, Rime::O),
+        (Med::Wu, Rime::Ai),
+        (Med::Wu, Rime::Ei),
+        (Med::Wu, Rime::An),
+        (Med::Wu, Rime::En),
+        (Med::Wu, Rime::Ang),
+        (Med::Wu, Rime::Eng),
+        (Med::Yu, Rime::NoRime),
+        (Med::Yu, Rime::Eh),
+        (Med::Yu, Rime::An),
+        (Med::Yu, Rime::En),
+        (Med::Yu, Rime::Eng),
+    ];
+
+    pub const fn parts(&self) -> (Med, Rime) {
+        Self::PARTS[*self as usize]
+    }
+
+    /// The final made of the medial and the rime, None if Mandarin has no such final
+    pub const fn from_parts(med: Med, rime: Rime) -> Option<Final> {
+        let mut idx = 0;
+        while idx < Self::PARTS.len() {
+            let (m, r) = Self::PARTS[idx];
+            if m as u8 == med as u8 && r as u8 == rime as u8 {
+                return Some(Self::ALL[idx]);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    /// The spelling in pinyin after an initial, e.g. "iu", "ui", "ong". It's "i" for Ii as in
+    /// zhi, and the ü is kept, as after n and l: j, q, x write it u (ju, que)
+    pub const fn pinyin(&self) -> &'static str {
+        [
+            "i", "a", "o", "e", "ê", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er", "i",
+            "ia", "io", "ie", "iai", "iao", "iu", "ian", "in", "iang", "ing", "u", "ua", "uo",
+            "uai", "ui", "uan", "un", "uang", "ong", "ü", "üe", "üan", "ün", "iong",
+        ][*self as usize]
+    }
+
+    /// The spelling in pinyin with the zero initial, e.g. "you", "wei", "yong".
+    /// None for Ii, which always follows an initial
+    pub const fn pinyin_alone(&self) -> Option<&'static str> {
+        match self {
+            Final::Ii => None,
+            _ => Some(
+                [
+                    "", "a", "o", "e", "ê", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er",
+                    "yi", "ya", "yo", "ye", "yai", "yao", "you", "yan", "yin", "yang", "ying",
+                    "wu", "wa", "wo", "wai", "wei", "wan", "wen", "wang", "weng", "yu", "yue",
+                    "yuan", "yun", "yong",
+                ][*self as usize],
+            ),
+        }
+    }
+
+    /// The spelling in bopomofo, the same with and without initial. Ii is left unwritten ("")
+    pub const fn bopomofo(&self) -> &'static str {
+        [
+            "", "ㄚ", "ㄛ", "ㄜ", "ㄝ", "ㄞ", "ㄟ", "ㄠ", "ㄡ", "ㄢ", "ㄣ", "ㄤ", "ㄥ", "ㄦ", "ㄧ",
+            "ㄧㄚ", "ㄧㄛ", "ㄧㄝ", "ㄧㄞ", "ㄧㄠ", "ㄧㄡ", "ㄧㄢ", "ㄧㄣ", "ㄧㄤ", "ㄧㄥ", "ㄨ",
+            "ㄨㄚ", "ㄨㄛ", "ㄨㄞ", "ㄨㄟ", "ㄨㄢ", "ㄨㄣ", "ㄨㄤ", "ㄨㄥ", "ㄩ", "ㄩㄝ", "ㄩㄢ",
+            "ㄩㄣ", "ㄩㄥ",
+        ][*self as usize]
+    }
+}
+
+impl TryFrom<(Med, Rime)> for Final {
+    type Error = &'static str;
+
+    fn try_from((med, rime): (Med, Rime)) -> Result<Self, Self::Error> {
+        Final::from_parts(med, rime).ok_or("No such final in Mandarin")
+    }
+}
+
+impl From<Final> for (Med, Rime) {
+    fn from(value: Final) -> Self {
+        value.parts()
+    }
+}
 //#endregion
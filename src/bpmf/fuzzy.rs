@@ -0,0 +1,144 @@
+//! Fuzzy matching of syllables, for the sounds that speakers from southern China often mix up,
+//! as the fuzzy pinyin options of the IMEs do.
+//! ```
+//! use bpmf_py::bpmf::{FuzzyRules, Syllable};
+//! let rules = FuzzyRules::Z_ZH | FuzzyRules::IN_ING;
+//! let zhong: Syllable = "zhong1".parse().unwrap();
+//! assert!(zhong.fuzzy_eq(&"zong1".parse().unwrap(), rules));
+//! assert!(!zhong.fuzzy_eq(&"zong3".parse().unwrap(), rules));
+//! ```
+use super::{Init, Med, Rime, Syllable};
+use alloc::{vec, vec::Vec};
+use core::ops::{BitOr, BitOrAssign};
+
+/// A set of rules, each of them can be toggled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FuzzyRules(u16);
+
+impl FuzzyRules {
+    pub const NONE: FuzzyRules = FuzzyRules(0);
+    pub const Z_ZH: FuzzyRules = FuzzyRules(1);
+    pub const C_CH: FuzzyRules = FuzzyRules(1 << 1);
+    pub const S_SH: FuzzyRules = FuzzyRules(1 << 2);
+    pub const N_L: FuzzyRules = FuzzyRules(1 << 3);
+    pub const F_H: FuzzyRules = FuzzyRules(1 << 4);
+    pub const R_L: FuzzyRules = FuzzyRules(1 << 5);
+    /// an/ang, ian/iang and uan/uang
+    pub const AN_ANG: FuzzyRules = FuzzyRules(1 << 6);
+    /// en/eng and wen/weng, but neither in/ing nor un/ong (ㄨㄣ/ㄨㄥ after an initial)
+    pub const EN_ENG: FuzzyRules = FuzzyRules(1 << 7);
+    pub const IN_ING: FuzzyRules = FuzzyRules(1 << 8);
+    pub const ALL: FuzzyRules = FuzzyRules((1 << 9) - 1);
+
+    pub fn contains(self, rules: FuzzyRules) -> bool {
+        self.0 & rules.0 == rules.0
+    }
+
+    pub fn insert(&mut self, rules: FuzzyRules) {
+        self.0 |= rules.0
+    }
+
+    pub fn remove(&mut self, rules: FuzzyRules) {
+        self.0 &= !rules.0
+    }
+
+    pub fn toggle(&mut self, rules: FuzzyRules) {
+        self.0 ^= rules.0
+    }
+
+    pub fn set(&mut self, rules: FuzzyRules, on: bool) {
+        if on {
+            self.insert(rules)
+        } else {
+            self.remove(rules)
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The initials the initial can be taken for, the initial itself first.
+    /// The rules don't chain: with N_L and R_L, n is taken for l but not for r
+    pub fn initials(self, init: Init) -> Vec<Init> {
+        use Init::*;
+        let mut list = vec![init];
+        for (rule, a, b) in [
+            (FuzzyRules::Z_ZH, Zi, Zhi),
+            (FuzzyRules::C_CH, Ci, Chi),
+            (FuzzyRules::S_SH, Si, Shi),
+            (FuzzyRules::N_L, Ne, Le),
+            (FuzzyRules::F_H, Fo, He),
+            (FuzzyRules::R_L, Ri, Le),
+        ] {
+            if self.contains(rule) {
+                if init == a {
+                    list.push(b)
+                } else if init == b {
+                    list.push(a)
+                }
+            }
+        }
+        list
+    }
+
+    /// The rimes the rime can be taken for after the initial and the medial, the rime itself
+    /// first. The initial matters for ㄨㄣ/ㄨㄥ only: wen/weng are fuzzy, un/ong are not
+    pub fn rimes(self, init: Init, med: Med, rime: Rime) -> Vec<Rime> {
+        let mut list = vec![rime];
+        let (rule, a, b) = match (init, med, rime) {
+            (_, _, Rime::An | Rime::Ang) => (FuzzyRules::AN_ANG, Rime::An, Rime::Ang),
+            (_, Med::Yi, Rime::En | Rime::Eng) => (FuzzyRules::IN_ING, Rime::En, Rime::Eng),
+            (_, Med::NoMed, Rime::En | Rime::Eng)
+            | (Init::NoInit, Med::Wu, Rime::En | Rime::Eng) => {
+                (FuzzyRules::EN_ENG, Rime::En, Rime::Eng)
+            }
+            _ => return list,
+        };
+        if self.contains(rule) {
+            list.push(if rime == a { b } else { a })
+        }
+        list
+    }
+}
+
+impl BitOr for FuzzyRules {
+    type Output = FuzzyRules;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        FuzzyRules(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FuzzyRules {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs)
+    }
+}
+
+impl Syllable {
+    /// Equal but for the differences the rules allow, the medials and the tones must be the same
+    pub fn fuzzy_eq(&self, other: &Syllable, rules: FuzzyRules) -> bool {
+        self.med == other.med
+            && self.tone == other.tone
+            && rules.initials(self.init()).contains(&other.init())
+            && rules
+                .rimes(self.init(), self.med(), self.rime())
+                .contains(&other.rime())
+    }
+
+    /// All the syllables the rules take it for, the syllable itself first.
+    /// The ones that don't exist in Mandarin, like fong for hong, are left out
+    pub fn fuzzy_variants(&self, rules: FuzzyRules) -> Vec<Syllable> {
+        let mut list = vec![*self];
+        for init in rules.initials(self.init()) {
+            for rime in rules.rimes(init, self.med(), self.rime()) {
+                let syl = Syllable::new(init, self.med(), rime, self.tone());
+                if syl != *self && syl.is_valid() {
+                    list.push(syl)
+                }
+            }
+        }
+        list
+    }
+}
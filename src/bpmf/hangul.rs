@@ -0,0 +1,139 @@
+//! Hangul after the transcription of Chinese of the National Institute of Korean Language
+//! (외래어 표기법, 중국어 표기): 중궈 for zhongguo.
+//!
+//! The finals are written as syllables without initial (ㅇ), the initial then takes the place
+//! of the ㅇ. After ㅈ ㅉ ㅊ the vowels ㅑ ㅖ ㅛ ㅠ lose their y, so jia is 자, not 쟈. No tones.
+//! ```
+//! use bpmf_py::bpmf::Syllable;
+//! let word: String = ["zhong1", "guo2"]
+//!     .iter()
+//!     .map(|s| s.parse::<Syllable>().unwrap().to_hangul())
+//!     .collect();
+//! assert_eq!(word, "중궈");
+//! ```
+use super::{Init, Med, Rime, Syllable};
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Hangul compatibility jamo
+pub const HANGUL_INITIALS: [(Init, char); 21] = [
+    (Init::Bo, 'ㅂ'),
+    (Init::Po, 'ㅍ'),
+    (Init::Mo, 'ㅁ'),
+    (Init::Fo, 'ㅍ'),
+    (Init::De, 'ㄷ'),
+    (Init::Te, 'ㅌ'),
+    (Init::Ne, 'ㄴ'),
+    (Init::Le, 'ㄹ'),
+    (Init::Ge, 'ㄱ'),
+    (Init::Ke, 'ㅋ'),
+    (Init::He, 'ㅎ'),
+    (Init::Ji, 'ㅈ'),
+    (Init::Qi, 'ㅊ'),
+    (Init::Xi, 'ㅅ'),
+    (Init::Zhi, 'ㅈ'),
+    (Init::Chi, 'ㅊ'),
+    (Init::Shi, 'ㅅ'),
+    (Init::Ri, 'ㄹ'),
+    (Init::Zi, 'ㅉ'),
+    (Init::Ci, 'ㅊ'),
+    (Init::Si, 'ㅆ'),
+];
+
+/// The finals as medial and rime, spelt after an initial and without initial.
+/// The final of zhi, chi, shi, ri, zi, ci, si is 으
+pub const HANGUL_FINALS: [(Med, Rime, &str, &str); 38] = [
+    (Med::NoMed, Rime::A, "아", "아"),
+    (Med::NoMed, Rime::O, "오", "오"),
+    (Med::NoMed, Rime::E, "어", "어"),
+    (Med::NoMed, Rime::Eh, "에", "에"),
+    (Med::NoMed, Rime::Ai, "아이", "아이"),
+    (Med::NoMed, Rime::Ei, "에이", "에이"),
+    (Med::NoMed, Rime::Ao, "아오", "아오"),
+    (Med::NoMed, Rime::Ou, "어우", "어우"),
+    (Med::NoMed, Rime::An, "안", "안"),
+    (Med::NoMed, Rime::En, "언", "언"),
+    (Med::NoMed, Rime::Ang, "앙", "앙"),
+    (Med::NoMed, Rime::Eng, "엉", "엉"),
+    (Med::NoMed, Rime::Er, "얼", "얼"),
+    (Med::Yi, Rime::NoRime, "이", "이"),
+    (Med::Yi, Rime::A, "야", "야"),
+    (Med::Yi, Rime::O, "요", "요"),
+    (Med::Yi, Rime::Eh, "예", "예"),
+    (Med::Yi, Rime::Ai, "야이", "야이"),
+    (Med::Yi, Rime::Ao, "야오", "야오"),
+    (Med::Yi, Rime::Ou, "유", "유"),
+    (Med::Yi, Rime::An, "옌", "옌"),
+    (Med::Yi, Rime::En, "인", "인"),
+    (Med::Yi, Rime::Ang, "양", "양"),
+    (Med::Yi, Rime::Eng, "잉", "잉"),
+    (Med::Wu, Rime::NoRime, "우", "우"),
+    (Med::Wu, Rime::A, "와", "와"),
+    (Med::Wu, Rime::O, "워", "워"),
+    (Med::Wu, Rime::Ai, "와이", "와이"),
+    (Med::Wu, Rime::Ei, "우이", "웨이"),
+    (Med::Wu, Rime::An, "완", "완"),
+    (Med::Wu, Rime::En, "운", "원"),
+    (Med::Wu, Rime::Ang, "왕", "왕"),
+    (Med::Wu, Rime::Eng, "웅", "웡"),
+    (Med::Yu, Rime::NoRime, "위", "위"),
+    (Med::Yu, Rime::Eh, "웨", "웨"),
+    (Med::Yu, Rime::An, "위안", "위안"),
+    (Med::Yu, Rime::En, "윈", "윈"),
+    (Med::Yu, Rime::Eng, "융", "융"),
+];
+
+//the jamo in the order of the Hangul syllables block
+const LEADS: &str = "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ";
+const HANGUL_BASE: u32 = 0xAC00;
+const VOWELS: u32 = 21;
+const TAILS: u32 = 28;
+
+impl Syllable {
+    /// The Hangul spelling, the tone is left out.
+    /// Empty if the syllable has no spelling, like a lone initial other than zh ch sh r z c s
+    #[cfg(feature = "alloc")]
+    pub fn to_hangul(&self) -> String {
+        let mut out = String::new();
+        //writing to a String never fails
+        let _ = self._write_hangul(&mut out);
+        out
+    }
+
+    pub(super) fn _write_hangul<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let init = HANGUL_INITIALS
+            .iter()
+            .find(|(init, _)| *init == self.init())
+            .map(|(_, jamo)| *jamo);
+        let fin = match (self.med(), self.rime()) {
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zhi => Some("으"),
+            (med, rime) => HANGUL_FINALS
+                .iter()
+                .find(|(m, r, _, _)| (*m, *r) == (med, rime))
+                .map(|(_, _, after, alone)| if init.is_some() { *after } else { *alone }),
+        };
+        let Some(fin) = fin else {
+            return Ok(());
+        };
+        let init = init.unwrap_or('ㅇ');
+        let mut chars = fin.chars();
+        let first = chars.next().unwrap() as u32 - HANGUL_BASE;
+        let (mut vowel, tail) = (first / TAILS % VOWELS, first % TAILS);
+        if "ㅈㅉㅊ".contains(init) {
+            //ㅑ ㅖ ㅛ ㅠ to ㅏ ㅔ ㅗ ㅜ
+            vowel = match vowel {
+                2 => 0,
+                7 => 5,
+                12 => 8,
+                17 => 13,
+                _ => vowel,
+            };
+        }
+        let lead = LEADS.chars().position(|jamo| jamo == init).unwrap() as u32;
+        let block = HANGUL_BASE + (lead * VOWELS + vowel) * TAILS + tail;
+        w.write_char(char::from_u32(block).unwrap())?;
+        w.write_str(chars.as_str())
+    }
+}
@@ -0,0 +1,207 @@
+//! Katakana after the usual Japanese transcription of Mandarin: チョンクオ for zhongguo.
+//!
+//! The aspirated and unaspirated initials are both voiceless (b and p are パ行, g and k are
+//! カ行), as in ペイチン for Beijing. The finals are written as without initial, the initial
+//! then replaces the first kana by the one of its row, e.g. x turns ヤオ into シャオ. No tones.
+//! ```
+//! use bpmf_py::bpmf::Syllable;
+//! let word: String = ["zhong1", "guo2"]
+//!     .iter()
+//!     .map(|s| s.parse::<Syllable>().unwrap().to_katakana())
+//!     .collect();
+//! assert_eq!(word, "チョンクオ");
+//! ```
+use super::{Init, Med, Rime, Syllable};
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The first kana of the finals, in the order of the rows below
+pub const KATAKANA_HEADS: [char; 8] = ['ア', 'イ', 'ウ', 'エ', 'オ', 'ヤ', 'ユ', 'ヨ'];
+
+/// The row of each initial, the kana taking the place of each of `KATAKANA_HEADS`
+pub const KATAKANA_INITIALS: [(Init, [&str; 8]); 21] = [
+    (
+        Init::Bo,
+        ["パ", "ピ", "プ", "ペ", "ポ", "ピャ", "ピュ", "ピョ"],
+    ),
+    (
+        Init::Po,
+        ["パ", "ピ", "プ", "ペ", "ポ", "ピャ", "ピュ", "ピョ"],
+    ),
+    (
+        Init::Mo,
+        ["マ", "ミ", "ム", "メ", "モ", "ミャ", "ミュ", "ミョ"],
+    ),
+    (
+        Init::Fo,
+        ["ファ", "フィ", "フ", "フェ", "フォ", "フャ", "フュ", "フョ"],
+    ),
+    (
+        Init::De,
+        [
+            "タ",
+            "ティ",
+            "トゥ",
+            "テ",
+            "ト",
+            "ティア",
+            "ティウ",
+            "ティオ",
+        ],
+    ),
+    (
+        Init::Te,
+        [
+            "タ",
+            "ティ",
+            "トゥ",
+            "テ",
+            "ト",
+            "ティア",
+            "ティウ",
+            "ティオ",
+        ],
+    ),
+    (
+        Init::Ne,
+        ["ナ", "ニ", "ヌ", "ネ", "ノ", "ニャ", "ニュ", "ニョ"],
+    ),
+    (
+        Init::Le,
+        ["ラ", "リ", "ル", "レ", "ロ", "リャ", "リュ", "リョ"],
+    ),
+    (
+        Init::Ge,
+        ["カ", "キ", "ク", "ケ", "コ", "キャ", "キュ", "キョ"],
+    ),
+    (
+        Init::Ke,
+        ["カ", "キ", "ク", "ケ", "コ", "キャ", "キュ", "キョ"],
+    ),
+    (
+        Init::He,
+        ["ハ", "ヒ", "フ", "ヘ", "ホ", "ヒャ", "ヒュ", "ヒョ"],
+    ),
+    (
+        Init::Ji,
+        ["チャ", "チ", "チュ", "チェ", "チョ", "チャ", "チュ", "チョ"],
+    ),
+    (
+        Init::Qi,
+        ["チャ", "チ", "チュ", "チェ", "チョ", "チャ", "チュ", "チョ"],
+    ),
+    (
+        Init::Xi,
+        ["シャ", "シ", "シュ", "シェ", "ショ", "シャ", "シュ", "ショ"],
+    ),
+    (
+        Init::Zhi,
+        ["チャ", "チ", "チュ", "チェ", "チョ", "チャ", "チュ", "チョ"],
+    ),
+    (
+        Init::Chi,
+        ["チャ", "チ", "チュ", "チェ", "チョ", "チャ", "チュ", "チョ"],
+    ),
+    (
+        Init::Shi,
+        ["シャ", "シ", "シュ", "シェ", "ショ", "シャ", "シュ", "ショ"],
+    ),
+    (
+        Init::Ri,
+        ["ラ", "リ", "ル", "レ", "ロ", "リャ", "リュ", "リョ"],
+    ),
+    (
+        Init::Zi,
+        ["ツァ", "ツィ", "ツ", "ツェ", "ツォ", "ツャ", "ツュ", "ツョ"],
+    ),
+    (
+        Init::Ci,
+        ["ツァ", "ツィ", "ツ", "ツェ", "ツォ", "ツャ", "ツュ", "ツョ"],
+    ),
+    (
+        Init::Si,
+        ["サ", "スィ", "ス", "セ", "ソ", "シャ", "シュ", "ショ"],
+    ),
+];
+
+/// The finals as medial and rime, spelt after an initial and without initial.
+/// The final of zhi, chi, shi, ri is イー and the one of zi, ci, si is ウー
+pub const KATAKANA_FINALS: [(Med, Rime, &str, &str); 38] = [
+    (Med::NoMed, Rime::A, "アー", "アー"),
+    (Med::NoMed, Rime::O, "オー", "オー"),
+    (Med::NoMed, Rime::E, "オー", "オー"),
+    (Med::NoMed, Rime::Eh, "エ", "エ"),
+    (Med::NoMed, Rime::Ai, "アイ", "アイ"),
+    (Med::NoMed, Rime::Ei, "エイ", "エイ"),
+    (Med::NoMed, Rime::Ao, "アオ", "アオ"),
+    (Med::NoMed, Rime::Ou, "オウ", "オウ"),
+    (Med::NoMed, Rime::An, "アン", "アン"),
+    (Med::NoMed, Rime::En, "エン", "エン"),
+    (Med::NoMed, Rime::Ang, "アン", "アン"),
+    (Med::NoMed, Rime::Eng, "オン", "オン"),
+    (Med::NoMed, Rime::Er, "アル", "アル"),
+    (Med::Yi, Rime::NoRime, "イー", "イー"),
+    (Med::Yi, Rime::A, "ヤー", "ヤー"),
+    (Med::Yi, Rime::O, "ヨー", "ヨー"),
+    (Med::Yi, Rime::Eh, "イエ", "イエ"),
+    (Med::Yi, Rime::Ai, "ヤイ", "ヤイ"),
+    (Med::Yi, Rime::Ao, "ヤオ", "ヤオ"),
+    (Med::Yi, Rime::Ou, "イウ", "ヨウ"),
+    (Med::Yi, Rime::An, "イエン", "イエン"),
+    (Med::Yi, Rime::En, "イン", "イン"),
+    (Med::Yi, Rime::Ang, "ヤン", "ヤン"),
+    (Med::Yi, Rime::Eng, "イン", "イン"),
+    (Med::Wu, Rime::NoRime, "ウー", "ウー"),
+    (Med::Wu, Rime::A, "ウア", "ワー"),
+    (Med::Wu, Rime::O, "ウオ", "ウオ"),
+    (Med::Wu, Rime::Ai, "ウアイ", "ワイ"),
+    (Med::Wu, Rime::Ei, "ウイ", "ウェイ"),
+    (Med::Wu, Rime::An, "ウアン", "ワン"),
+    (Med::Wu, Rime::En, "ウン", "ウェン"),
+    (Med::Wu, Rime::Ang, "ウアン", "ワン"),
+    (Med::Wu, Rime::Eng, "オン", "ウォン"),
+    (Med::Yu, Rime::NoRime, "ユイ", "ユイ"),
+    (Med::Yu, Rime::Eh, "ユエ", "ユエ"),
+    (Med::Yu, Rime::An, "ユアン", "ユアン"),
+    (Med::Yu, Rime::En, "ユン", "ユン"),
+    (Med::Yu, Rime::Eng, "ヨン", "ヨン"),
+];
+
+impl Syllable {
+    /// The katakana spelling, the tone is left out.
+    /// Empty if the syllable has no spelling, like a lone initial other than zh ch sh r z c s
+    #[cfg(feature = "alloc")]
+    pub fn to_katakana(&self) -> String {
+        let mut out = String::new();
+        //writing to a String never fails
+        let _ = self._write_katakana(&mut out);
+        out
+    }
+
+    pub(super) fn _write_katakana<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let row = KATAKANA_INITIALS
+            .iter()
+            .find(|(init, _)| *init == self.init())
+            .map(|(_, row)| row);
+        let fin = match (self.med(), self.rime()) {
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zi => Some("ウー"),
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zhi => Some("イー"),
+            (med, rime) => KATAKANA_FINALS
+                .iter()
+                .find(|(m, r, _, _)| (*m, *r) == (med, rime))
+                .map(|(_, _, after, alone)| if row.is_some() { *after } else { *alone }),
+        };
+        let Some(fin) = fin else {
+            return Ok(());
+        };
+        let mut chars = fin.chars();
+        if let Some(row) = row {
+            let head = chars.next().unwrap();
+            let idx = KATAKANA_HEADS.iter().position(|ch| *ch == head).unwrap();
+            w.write_str(row[idx])?;
+        }
+        w.write_str(chars.as_str())
+    }
+}
@@ -0,0 +1,63 @@
+//! Syllable literals parsed at compile time, see the `syl!` macro.
+//!
+//! The parsers of `Syllable` share their `const fn` core with `parse_literal`, so the
+//! literals are parsed exactly as at runtime.
+//! ```
+//! use bpmf_py::{bpmf::Syllable, syl};
+//! const ZHONG: Syllable = syl!("ㄓㄨㄥ");
+//! assert_eq!(ZHONG, syl!(zhong1));
+//! assert_eq!(ZHONG, syl!("zhōng"));
+//! ```
+use super::{ParseBopomofoError, Syllable};
+
+/// A syllable of Mandarin written in bopomofo, pinyin or ascii pinyin, parsed at compile
+/// time. The text may be quoted or not: `syl!("ㄓㄨㄥ")`, `syl!("zhōng")`, `syl!(zhong1)`.
+/// Invalid input fails the build:
+/// ```compile_fail
+/// const ZHONG: bpmf_py::bpmf::Syllable = bpmf_py::syl!(zhongg1);
+/// ```
+#[macro_export]
+macro_rules! syl {
+    ($txt:literal) => {{
+        const SYL: $crate::bpmf::Syllable = match $crate::bpmf::Syllable::parse_literal($txt) {
+            Ok(syl) => syl,
+            Err(_) => panic!(concat!("Invalid syllable: ", $txt)),
+        };
+        SYL
+    }};
+    ($txt:ident) => {{
+        const SYL: $crate::bpmf::Syllable =
+            match $crate::bpmf::Syllable::parse_literal(stringify!($txt)) {
+                Ok(syl) => syl,
+                Err(_) => panic!(concat!("Invalid syllable: ", stringify!($txt))),
+            };
+        SYL
+    }};
+}
+
+impl Syllable {
+    /// Parses the whole text as a single syllable that exists in Mandarin, in bopomofo, pinyin
+    /// or ascii pinyin (tried in this order), surrounding ascii whitespaces aside. It's a
+    /// `const fn` for the `syl!` macro. Unlike `parse_any_exact` it doesn't take braille nor
+    /// Palladius, other whitespaces nor syllables missing from Mandarin like ㄅㄧㄜ; for the
+    /// rest the two give the same syllables
+    pub const fn parse_literal(txt: &str) -> Result<Syllable, ParseBopomofoError> {
+        let txt = txt.trim_ascii();
+        //the prefix parsers of `parse_bopomofo`, `parse_pinyin` and `parse_ascii_pinyin`
+        let syl = match Self::_parse_bopomofo_prefix(txt) {
+            Some((syl, len)) if len == txt.len() => syl,
+            _ => match Self::_parse_pinyin_prefix(txt, true) {
+                Some((syl, len)) if len == txt.len() => syl,
+                _ => match Self::_parse_pinyin_prefix(txt, false) {
+                    Some((syl, len)) if len == txt.len() => syl,
+                    _ => return Err(ParseBopomofoError),
+                },
+            },
+        };
+        if syl.is_valid() {
+            Ok(syl)
+        } else {
+            Err(ParseBopomofoError)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::{BrailleSystem, ParseBopomofoError, Syllable};
+use core::str::FromStr;
+
+/// The phonetic notations a [`Syllable`] can be written in.
+/// More schemes may be added in the future, so matches on it need a wildcard arm.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// 注音符號, e.g. ㄓㄨㄥ
+    Bopomofo,
+    /// Hanyu Pinyin with tone marks, e.g. zhōng
+    Pinyin,
+    /// Pinyin with 'v' for 'ü' and a trailing tone number, e.g. zhong1
+    AsciiPinyin,
+    /// Unicode Braille cells in one of the two systems, e.g. ⠌⠲⠁
+    Braille(BrailleSystem),
+    /// The Cyrillic transcription used in Russian, without tones, e.g. чжун
+    Palladius,
+    /// The Korean transcription, without tones, e.g. 중. It's written only, never parsed
+    Hangul,
+    /// The Japanese transcription in katakana, without tones, e.g. チョン. It's written only,
+    /// never parsed
+    Katakana,
+}
+
+impl Notation {
+    /// All the notations that can be parsed, in the order they are tried when detecting the
+    /// notation of a text. Hangul and katakana are left out
+    pub const ALL: [Notation; 6] = [
+        Notation::Bopomofo,
+        Notation::Pinyin,
+        Notation::AsciiPinyin,
+        Notation::Braille(BrailleSystem::Mainland),
+        Notation::Braille(BrailleSystem::Taiwan),
+        Notation::Palladius,
+    ];
+
+    /// Parses the leading syllable of `txt` written in this notation. It always fails for
+    /// Hangul and katakana, which lose too much of the syllable to be read back
+    pub fn parse(self, txt: &str) -> Result<(Syllable, &str), ParseBopomofoError> {
+        match self {
+            Notation::Bopomofo => Syllable::parse_bopomofo(txt),
+            Notation::Pinyin => Syllable::parse_pinyin(txt),
+            Notation::AsciiPinyin => Syllable::parse_ascii_pinyin(txt),
+            Notation::Braille(system) => Syllable::parse_braille(txt, system),
+            Notation::Palladius => Syllable::parse_palladius(txt),
+            Notation::Hangul | Notation::Katakana => Err(ParseBopomofoError),
+        }
+    }
+}
+
+impl FromStr for Notation {
+    type Err = &'static str;
+
+    /// The names are case-insensitive: "bopomofo" (or "bpmf", "zhuyin"), "pinyin" (or "py")
+    /// "ascii-pinyin" (or "ascii", "numbered"), "braille-mainland" (or "mainland-braille"),
+    /// "braille-taiwan" (or "taiwan-braille"), "palladius" (or "cyrillic"), "hangul" (or
+    /// "korean") and "katakana" (or "japanese")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is = |names: &[&str]| names.iter().any(|name| name.eq_ignore_ascii_case(s));
+        if is(&["bopomofo", "bpmf", "zhuyin"]) {
+            Ok(Notation::Bopomofo)
+        } else if is(&["pinyin", "py"]) {
+            Ok(Notation::Pinyin)
+        } else if is(&["ascii-pinyin", "ascii_pinyin", "ascii", "numbered"]) {
+            Ok(Notation::AsciiPinyin)
+        } else if is(&["braille-mainland", "mainland-braille"]) {
+            Ok(Notation::Braille(BrailleSystem::Mainland))
+        } else if is(&["braille-taiwan", "taiwan-braille"]) {
+            Ok(Notation::Braille(BrailleSystem::Taiwan))
+        } else if is(&["palladius", "cyrillic"]) {
+            Ok(Notation::Palladius)
+        } else if is(&["hangul", "korean"]) {
+            Ok(Notation::Hangul)
+        } else if is(&["katakana", "japanese"]) {
+            Ok(Notation::Katakana)
+        } else {
+            Err("Unknown notation")
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! The Palladius system, the Cyrillic transcription of Chinese used in Russian: чжун, сюэ, жэнь.
+//!
+//! It has no tones. In a word, a syllable ending in н (the -ng of pinyin) takes a hard sign
+//! before a syllable starting with a vowel, as in Чанъань (Chang'an), while the soft sign of
+//! нь (the -n of pinyin) already parts the syllables, as in Яньань (Yan'an).
+//! ```
+//! use bpmf_py::bpmf::{palladius_word, Syllable};
+//! let xue: Syllable = "xue2".parse().unwrap();
+//! assert_eq!(xue.to_palladius(), "сюэ");
+//! let word: Vec<Syllable> = ["chang2", "an1"].iter().map(|s| s.parse().unwrap()).collect();
+//! assert_eq!(palladius_word(&word), "чанъань");
+//! ```
+use super::{Init, Med, ParseBopomofoError, Rime, Syllable, Tone};
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+pub const PALLADIUS_INITIALS: [(Init, &str); 21] = [
+    (Init::Bo, "б"),
+    (Init::Po, "п"),
+    (Init::Mo, "м"),
+    (Init::Fo, "ф"),
+    (Init::De, "д"),
+    (Init::Te, "т"),
+    (Init::Ne, "н"),
+    (Init::Le, "л"),
+    (Init::Ge, "г"),
+    (Init::Ke, "к"),
+    (Init::He, "х"),
+    (Init::Ji, "цз"),
+    (Init::Qi, "ц"),
+    (Init::Xi, "с"),
+    (Init::Zhi, "чж"),
+    (Init::Chi, "ч"),
+    (Init::Shi, "ш"),
+    (Init::Ri, "ж"),
+    (Init::Zi, "цз"),
+    (Init::Ci, "ц"),
+    (Init::Si, "с"),
+];
+
+/// The finals as medial and rime, spelt after an initial and without initial.
+/// The finals of zhi, chi, shi, ri (и) and of zi, ci, si (ы) aren't listed
+pub const PALLADIUS_FINALS: [(Med, Rime, &str, &str); 38] = [
+    (Med::NoMed, Rime::A, "а", "а"),
+    (Med::NoMed, Rime::O, "о", "о"),
+    (Med::NoMed, Rime::E, "э", "э"),
+    (Med::NoMed, Rime::Eh, "э", "э"),
+    (Med::NoMed, Rime::Ai, "ай", "ай"),
+    (Med::NoMed, Rime::Ei, "эй", "эй"),
+    (Med::NoMed, Rime::Ao, "ао", "ао"),
+    (Med::NoMed, Rime::Ou, "оу", "оу"),
+    (Med::NoMed, Rime::An, "ань", "ань"),
+    (Med::NoMed, Rime::En, "энь", "энь"),
+    (Med::NoMed, Rime::Ang, "ан", "ан"),
+    (Med::NoMed, Rime::Eng, "эн", "эн"),
+    (Med::NoMed, Rime::Er, "эр", "эр"),
+    (Med::Yi, Rime::NoRime, "и", "и"),
+    (Med::Yi, Rime::A, "я", "я"),
+    (Med::Yi, Rime::O, "йо", "йо"),
+    (Med::Yi, Rime::Eh, "е", "е"),
+    (Med::Yi, Rime::Ai, "яй", "яй"),
+    (Med::Yi, Rime::Ao, "яо", "яо"),
+    (Med::Yi, Rime::Ou, "ю", "ю"),
+    (Med::Yi, Rime::An, "янь", "янь"),
+    (Med::Yi, Rime::En, "инь", "инь"),
+    (Med::Yi, Rime::Ang, "ян", "ян"),
+    (Med::Yi, Rime::Eng, "ин", "ин"),
+    (Med::Wu, Rime::NoRime, "у", "у"),
+    (Med::Wu, Rime::A, "уа", "ва"),
+    (Med::Wu, Rime::O, "о", "во"),
+    (Med::Wu, Rime::Ai, "уай", "вай"),
+    (Med::Wu, Rime::Ei, "уй", "вэй"),
+    (Med::Wu, Rime::An, "уань", "вань"),
+    (Med::Wu, Rime::En, "унь", "вэнь"),
+    (Med::Wu, Rime::Ang, "уан", "ван"),
+    (Med::Wu, Rime::Eng, "ун", "вэн"),
+    (Med::Yu, Rime::NoRime, "юй", "юй"),
+    (Med::Yu, Rime::Eh, "юэ", "юэ"),
+    (Med::Yu, Rime::An, "юань", "юань"),
+    (Med::Yu, Rime::En, "юнь", "юнь"),
+    (Med::Yu, Rime::Eng, "юн", "юн"),
+];
+
+/// Spelt the same as other syllables, the parser gives those instead: lo is luo, ê is e
+const PALLADIUS_SHADOWED: [(Init, Med, Rime); 2] = [
+    (Init::Le, Med::NoMed, Rime::O),
+    (Init::NoInit, Med::NoMed, Rime::Eh),
+];
+
+impl Syllable {
+    /// The Palladius spelling in lower case, the tone is left out.
+    /// Empty if the syllable has no spelling, like a lone initial other than zh ch sh r z c s
+    #[cfg(feature = "alloc")]
+    pub fn to_palladius(&self) -> String {
+        let mut out = String::new();
+        //writing to a String never fails
+        let _ = self._write_palladius(&mut out);
+        out
+    }
+
+    pub(super) fn _write_palladius<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let init = PALLADIUS_INITIALS
+            .iter()
+            .find(|(init, _)| *init == self.init())
+            .map(|(_, spelling)| *spelling);
+        let fin = match (self.med(), self.rime()) {
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zi => Some("ы"),
+            (Med::NoMed, Rime::NoRime) if self.init() >= Init::Zhi => Some("и"),
+            (med, rime) => PALLADIUS_FINALS
+                .iter()
+                .find(|(m, r, _, _)| (*m, *r) == (med, rime))
+                .map(|(_, _, after, alone)| if init.is_some() { *after } else { *alone }),
+        };
+        match fin {
+            Some(fin) => {
+                w.write_str(init.unwrap_or(""))?;
+                w.write_str(fin)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Parses the leading syllable of `txt` written in Palladius, it returns the syllable
+    /// (without tone) and the remaining part of the text. The upper case is accepted and a
+    /// leading hard sign is skipped. The longest spelling wins, so "хуань" is huan, not hu'an
+    pub fn parse_palladius(txt: &str) -> Result<(Syllable, &str), ParseBopomofoError> {
+        let txt = Self::_skip_whitespaces(txt);
+        let txt = txt.strip_prefix(['ъ', 'Ъ']).unwrap_or(txt);
+        //every spelling starts with a Cyrillic letter
+        if !txt.starts_with(|ch| ('\u{400}'..='\u{4ff}').contains(&ch)) {
+            return Err(ParseBopomofoError);
+        }
+        let mut best: Option<(Syllable, &str)> = None;
+        for init in Init::ALL {
+            for med in Med::ALL {
+                for rime in Rime::ALL {
+                    let syl = Syllable::new(init, med, rime, Tone::NoTone);
+                    if !syl.is_valid() || PALLADIUS_SHADOWED.contains(&(init, med, rime)) {
+                        continue;
+                    }
+                    let mut matcher = _PrefixMatcher { txt, end: 0 };
+                    if syl._write_palladius(&mut matcher).is_err() || matcher.end == 0 {
+                        continue;
+                    }
+                    let rest = &txt[matcher.end..];
+                    if best.is_none_or(|(_, best_rest)| rest.len() < best_rest.len()) {
+                        best = Some((syl, rest));
+                    }
+                }
+            }
+        }
+        best.ok_or(ParseBopomofoError)
+    }
+}
+
+/// Checks that what's written is the lower case of the start of `txt`, `end` is the length of
+/// the part matched so far
+struct _PrefixMatcher<'a> {
+    txt: &'a str,
+    end: usize,
+}
+
+impl Write for _PrefixMatcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for expected in s.chars() {
+            match self.txt[self.end..].chars().next() {
+                Some(ch) if ch.to_lowercase().eq([expected]) => self.end += ch.len_utf8(),
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The Palladius spelling of a word, with the hard signs between the syllables
+#[cfg(feature = "alloc")]
+pub fn palladius_word(word: &[Syllable]) -> String {
+    let mut out = String::new();
+    for syl in word {
+        let start = out.len();
+        let _ = syl._write_palladius(&mut out);
+        let starts_with_vowel = out[start..].starts_with(['а', 'о', 'у', 'э', 'я', 'е', 'ю', 'и']);
+        if starts_with_vowel && out[..start].ends_with('н') {
+            out.insert(start, 'ъ');
+        }
+    }
+    out
+}
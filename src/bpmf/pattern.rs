@@ -0,0 +1,279 @@
+//! Patterns of syllables for dictionary searches, like "zh?ng4", "*ang" or "ㄓ*ˇ".
+//!
+//! A pattern is the set of (initial, medial, rime, tone) it accepts, so it can also be made of
+//! any subset of the parts with `from_parts`, and patterns can be merged with `|`. The text form is a glob over
+//! the toneless spelling, in pinyin (ascii or toned) or in bopomofo:
+//! - `?` is any one letter (or bopomofo symbol), `*` is any letters, even none;
+//! - `[ae]` is one of the letters, e.g. "zh[ae]ng";
+//! - `|` separates whole alternatives, each with its own tones, e.g. "zhang1|zheng4";
+//! - the tone is a trailing number (or a class of them like `[34]`), a tone mark on a vowel,
+//!   or a bopomofo tone mark (or a class of them). Without it any tone is accepted.
+//! ```
+//! use bpmf_py::bpmf::{Syllable, SyllablePattern};
+//! let pattern: SyllablePattern = "zh?ng4".parse().unwrap();
+//! assert!(pattern.matches(&"zhong4".parse().unwrap()));
+//! assert!(pattern.matches(&"ㄓㄤˋ".parse().unwrap()));
+//! assert!(!pattern.matches(&"zhong1".parse().unwrap()));
+//! ```
+use super::{data::PINYIN_TONED_NUCS, Init, Med, Rime, Syllable, Tone};
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::BitOr;
+use core::str::FromStr;
+
+const INITS: usize = Init::Si as usize + 1;
+const MEDS: usize = Med::Yu as usize + 1;
+const RIMES: usize = Rime::Er as usize + 1;
+const BASES: usize = INITS * MEDS * RIMES;
+const TONES: usize = Tone::Neut as usize + 1;
+const ALL_TONES: u8 = (1 << TONES) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SyllablePattern {
+    //for each tone, a bit for each (initial, medial, rime)
+    bases: [[u64; BASES.div_ceil(64)]; TONES],
+}
+
+impl SyllablePattern {
+    /// Accepts every syllable
+    pub fn any() -> SyllablePattern {
+        SyllablePattern {
+            bases: [[u64::MAX; BASES.div_ceil(64)]; TONES],
+        }
+    }
+
+    /// Accepts nothing
+    pub fn none() -> SyllablePattern {
+        SyllablePattern {
+            bases: [[0; BASES.div_ceil(64)]; TONES],
+        }
+    }
+
+    /// Accepts the syllables having one of the initials, one of the medials, one of the rimes
+    /// and one of the tones. An empty slice stands for any
+    pub fn from_parts(inits: &[Init], meds: &[Med], rimes: &[Rime], tones: &[Tone]) -> Self {
+        let mut pattern = SyllablePattern::none();
+        let tones = _tone_bits(tones);
+        for (init, med, rime) in _bases() {
+            if (inits.is_empty() || inits.contains(&init))
+                && (meds.is_empty() || meds.contains(&med))
+                && (rimes.is_empty() || rimes.contains(&rime))
+            {
+                pattern._insert_base(init, med, rime, tones);
+            }
+        }
+        pattern
+    }
+
+    pub fn matches(&self, syl: &Syllable) -> bool {
+        let idx = _base_index(syl.init(), syl.med(), syl.rime());
+        self.bases[syl.tone() as usize][idx / 64] & 1 << (idx % 64) != 0
+    }
+
+    /// Parses the patterns separated by whitespaces, e.g. "zh?ng* guo2"
+    pub fn parse_seq(txt: &str) -> Result<Vec<SyllablePattern>, &'static str> {
+        txt.split_whitespace().map(str::parse).collect()
+    }
+
+    /// Whether the word has as many syllables as the patterns and each one matches
+    pub fn matches_seq(patterns: &[SyllablePattern], word: &[Syllable]) -> bool {
+        patterns.len() == word.len() && patterns.iter().zip(word).all(|(p, syl)| p.matches(syl))
+    }
+
+    /// Inserts the (initial, medial, rime) with each tone of the bits
+    fn _insert_base(&mut self, init: Init, med: Med, rime: Rime, tones: u8) {
+        let idx = _base_index(init, med, rime);
+        for (tone, bases) in self.bases.iter_mut().enumerate() {
+            if tones & 1 << tone != 0 {
+                bases[idx / 64] |= 1 << (idx % 64);
+            }
+        }
+    }
+
+    /// A single alternative, without '|'
+    fn _parse_glob(txt: &str) -> Result<SyllablePattern, &'static str> {
+        let is_bopomofo = txt
+            .chars()
+            .any(|ch| ('ㄅ'..='ㄩ').contains(&ch) || Tone::try_from(ch).is_ok());
+        let mut glob = _tokenize(txt)?;
+        //the tones given at the end, or by the marks on the vowels
+        let mut tones = 0;
+        let tone_of = |ch: char| -> Option<u8> {
+            if is_bopomofo {
+                Tone::try_from(ch).ok().map(|tone| tone as u8)
+            } else {
+                ch.to_digit(10).filter(|d| *d <= 5).map(|d| d as u8)
+            }
+        };
+        //a leading neutral tone mark of bopomofo
+        if is_bopomofo && glob.first() == Some(&Token::Char('˙')) {
+            glob.remove(0);
+            tones |= 1 << Tone::Neut as u8;
+        }
+        match glob.last() {
+            Some(Token::Char(ch)) if tone_of(*ch).is_some() => {
+                tones |= 1 << tone_of(*ch).unwrap();
+                glob.pop();
+            }
+            Some(Token::Class(chars)) if chars.iter().all(|ch| tone_of(*ch).is_some()) => {
+                tones |= chars
+                    .iter()
+                    .fold(0, |bits, ch| bits | 1 << tone_of(*ch).unwrap());
+                glob.pop();
+            }
+            _ => {}
+        }
+        if !is_bopomofo {
+            for token in glob.iter_mut() {
+                if let Token::Char(ch) = token {
+                    if let Some((nuc, tone)) = _untone(*ch) {
+                        *ch = nuc;
+                        tones |= 1 << tone;
+                    }
+                }
+            }
+        }
+
+        let mut pattern = SyllablePattern::none();
+        let tones = if tones == 0 { ALL_TONES } else { tones };
+        let mut spelling = String::new();
+        for (init, med, rime) in _bases() {
+            spelling.clear();
+            let syl = Syllable::new(init, med, rime, Tone::NoTone);
+            if is_bopomofo {
+                syl.write_bopomofo_to(&mut spelling);
+            } else {
+                syl.write_ascii_pinyin_to(&mut spelling);
+            }
+            let letters: Vec<char> = spelling.chars().collect();
+            if _glob_matches(&glob, &letters) {
+                pattern._insert_base(init, med, rime, tones);
+            }
+        }
+        Ok(pattern)
+    }
+}
+
+impl BitOr for SyllablePattern {
+    type Output = SyllablePattern;
+
+    /// Accepts what either of the patterns accepts
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        for (bases, other) in self.bases.iter_mut().zip(rhs.bases) {
+            for (bits, other) in bases.iter_mut().zip(other) {
+                *bits |= other
+            }
+        }
+        self
+    }
+}
+
+impl FromStr for SyllablePattern {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Empty pattern");
+        }
+        let mut pattern = SyllablePattern::none();
+        for alternative in s.split('|') {
+            pattern = pattern | SyllablePattern::_parse_glob(alternative.trim())?;
+        }
+        Ok(pattern)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    Class(Vec<char>),
+    One,
+    Many,
+}
+
+fn _tokenize(txt: &str) -> Result<Vec<Token>, &'static str> {
+    let mut list = vec![];
+    let mut chars = txt.chars();
+    while let Some(ch) = chars.next() {
+        list.push(match ch {
+            '?' => Token::One,
+            '*' => Token::Many,
+            '[' => {
+                let mut class = vec![];
+                loop {
+                    match chars.next().ok_or("Unclosed '['")? {
+                        ']' => break,
+                        ch => class.push(_normalize(ch)),
+                    }
+                }
+                Token::Class(class)
+            }
+            ch if ch.is_whitespace() => return Err("Whitespace in a pattern"),
+            ch => Token::Char(_normalize(ch)),
+        })
+    }
+    Ok(list)
+}
+
+/// Lower case and v for ü, as in ascii pinyin
+fn _normalize(ch: char) -> char {
+    match ch.to_ascii_lowercase() {
+        'ü' => 'v',
+        ch => ch,
+    }
+}
+
+/// The vowel without the tone mark and the tone
+fn _untone(ch: char) -> Option<(char, u8)> {
+    PINYIN_TONED_NUCS.iter().find_map(|(nuc, toned)| {
+        let tone = toned
+            .chars()
+            .position(|c| c == ch)
+            .filter(|t| (1..5).contains(t))?;
+        Some((if *nuc == 'ü' { 'v' } else { *nuc }, tone as u8))
+    })
+}
+
+fn _glob_matches(glob: &[Token], letters: &[char]) -> bool {
+    match glob.split_first() {
+        None => letters.is_empty(),
+        Some((Token::Many, rest)) => {
+            (0..=letters.len()).any(|n| _glob_matches(rest, &letters[n..]))
+        }
+        Some((token, rest)) => match letters.split_first() {
+            Some((ch, letters)) => {
+                let ok = match token {
+                    Token::Char(c) => c == ch,
+                    Token::Class(class) => class.contains(ch),
+                    _ => true,
+                };
+                ok && _glob_matches(rest, letters)
+            }
+            None => false,
+        },
+    }
+}
+
+fn _base_index(init: Init, med: Med, rime: Rime) -> usize {
+    (init as usize * MEDS + med as usize) * RIMES + rime as usize
+}
+
+fn _tone_bits(tones: &[Tone]) -> u8 {
+    if tones.is_empty() {
+        ALL_TONES
+    } else {
+        tones.iter().fold(0, |bits, tone| bits | 1 << *tone as u8)
+    }
+}
+
+/// All the (initial, medial, rime) but the empty one
+fn _bases() -> impl Iterator<Item = (Init, Med, Rime)> {
+    (1..BASES).map(|idx| {
+        let (init, rest) = (idx / (MEDS * RIMES), idx % (MEDS * RIMES));
+        (
+            Init::try_from(init as u8).unwrap(),
+            Med::try_from((rest / RIMES) as u8).unwrap(),
+            Rime::try_from((rest % RIMES) as u8).unwrap(),
+        )
+    })
+}
@@ -0,0 +1,169 @@
+//! Abbreviated pinyin queries, as typed in the search boxes of Chinese apps: "bj" for 北京,
+//! "zhg" or "zhongg" for 中国.
+//!
+//! A query is split into parts, each one an initial or the beginning of a syllable,
+//! and a part matches the syllables whose toneless ascii pinyin starts with it. So the
+//! zero-initial syllables are matched by y, w or their first vowel, e.g. "a" for 安.
+//! Every split of the letters into parts is tried, so "xian" finds 先 as well as 西安.
+//! ```
+//! use bpmf_py::bpmf::{PinyinQuery, Syllable};
+//! let zhongguo: Vec<Syllable> = ["zhong1", "guo2"].iter().map(|s| s.parse().unwrap()).collect();
+//! let query = PinyinQuery::parse("zhg").unwrap();
+//! assert_eq!(query.parts().len(), 2);
+//! assert_eq!(query.matches(&zhongguo), Some(37));
+//! assert_eq!(PinyinQuery::parse("zhongguo").unwrap().matches(&zhongguo), Some(100));
+//! ```
+use super::data::{PINYIN_ASCII_SYLLABLE_TREE, PINYIN_TONED_NUCS};
+use super::{Syllable, Tone};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+
+/// An initial or the beginning of a syllable, in toneless ascii pinyin, with an optional tone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryPart {
+    pub prefix: String,
+    pub tone: Option<Tone>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinyinQuery {
+    parts: Vec<QueryPart>,
+    groups: Vec<LetterGroup>,
+}
+
+/// The letters between two splits of the query, with the tones marked on them (the position
+/// after the marked letter) and the tone number that ends them
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LetterGroup {
+    letters: String,
+    marks: Vec<(usize, u8)>,
+    tone: Option<Tone>,
+}
+
+impl QueryPart {
+    pub fn matches(&self, syl: &Syllable) -> bool {
+        self.tone.is_none_or(|tone| tone == syl.tone())
+            && _toneless_spelling(syl).starts_with(&self.prefix)
+    }
+}
+
+impl PinyinQuery {
+    /// Splits the query into the longest parts that can start a syllable. Spaces and
+    /// apostrophes split the parts too, ü can be written as v or u:, and the tones can be
+    /// given with marks or numbers, e.g. "zhong1g".
+    /// It fails if some letter can't start any syllable, like "i" or "v"
+    pub fn parse(query: &str) -> Result<PinyinQuery, &'static str> {
+        let mut groups = vec![];
+        let mut group = LetterGroup {
+            letters: String::new(),
+            marks: vec![],
+            tone: None,
+        };
+        let query = query.to_lowercase().replace("u:", "v");
+        for ch in query.chars().chain([' ']) {
+            match ch {
+                //a tone number ends a syllable
+                '1'..='5' | ' ' | '\'' | '\t' => {
+                    if !group.letters.is_empty() {
+                        group.tone = ch.to_digit(10).and_then(|d| Tone::try_from(d as u8).ok());
+                        groups.push(group.clone());
+                    }
+                    group.letters.clear();
+                    group.marks.clear();
+                }
+                'ü' => group.letters.push('v'),
+                _ if ch.is_ascii_lowercase() => group.letters.push(ch),
+                _ => {
+                    let (nuc, tone) = PINYIN_TONED_NUCS
+                        .iter()
+                        .find_map(|(nuc, toned)| Some((*nuc, toned.chars().position(|c| c == ch)?)))
+                        .ok_or("Not pinyin")?;
+                    group.letters.push(if nuc == 'ü' { 'v' } else { nuc });
+                    group.marks.push((group.letters.len(), tone as u8));
+                }
+            }
+        }
+        let mut parts = vec![];
+        for group in &groups {
+            group._split_longest(&mut parts)?;
+        }
+        Ok(PinyinQuery { parts, groups })
+    }
+
+    /// The parts of the longest split, e.g. "xian" is one part.
+    /// `matches` tries the other splits as well, so "xian" matches 西安 as "xi" "an"
+    pub fn parts(&self) -> &[QueryPart] {
+        &self.parts
+    }
+
+    /// Whether the word starts with syllables matched by the parts one by one, for any split
+    /// of the query into parts. The score is the percentage of the letters of the word spelt
+    /// out in the query, 100 for a full match
+    pub fn matches(&self, word: &[Syllable]) -> Option<u32> {
+        if self.groups.is_empty() || !_match_groups(&self.groups, 0, word) {
+            return None;
+        }
+        let typed: usize = self.groups.iter().map(|group| group.letters.len()).sum();
+        let total: usize = word.iter().map(|syl| _toneless_spelling(syl).len()).sum();
+        Some((typed * 100 / total) as u32)
+    }
+}
+
+impl LetterGroup {
+    /// Splits the letters into the longest parts that can start a syllable
+    fn _split_longest(&self, parts: &mut Vec<QueryPart>) -> Result<(), &'static str> {
+        let mut start = 0;
+        while start < self.letters.len() {
+            let rest = &self.letters[start..];
+            let len = (1..=rest.len())
+                .rev()
+                .find(|len| PINYIN_ASCII_SYLLABLE_TREE.has_prefix(&rest[..*len]))
+                .ok_or("Not pinyin")?;
+            parts.push(QueryPart {
+                prefix: rest[..len].to_owned(),
+                tone: self._tone(start, start + len),
+            });
+            start += len;
+        }
+        Ok(())
+    }
+
+    /// The tone of the part from start to end, marked on one of its letters or given by the
+    /// number after the last part
+    fn _tone(&self, start: usize, end: usize) -> Option<Tone> {
+        match self.tone {
+            Some(tone) if end == self.letters.len() => Some(tone),
+            _ => self
+                .marks
+                .iter()
+                .find(|(pos, _)| *pos > start && *pos <= end)
+                .and_then(|(_, tone)| Tone::try_from(*tone).ok()),
+        }
+    }
+}
+
+/// Whether the letters of the groups from `start` in the first one match the word syllable by
+/// syllable. Each syllable takes a part, the longest parts are tried first
+fn _match_groups(groups: &[LetterGroup], start: usize, word: &[Syllable]) -> bool {
+    let Some(group) = groups.first() else {
+        return true;
+    };
+    if start == group.letters.len() {
+        return _match_groups(&groups[1..], 0, word);
+    }
+    let Some((syl, word)) = word.split_first() else {
+        return false;
+    };
+    let spelling = _toneless_spelling(syl);
+    let rest = &group.letters[start..];
+    (1..=rest.len().min(spelling.len())).rev().any(|len| {
+        spelling.starts_with(&rest[..len])
+            && group
+                ._tone(start, start + len)
+                .is_none_or(|tone| tone == syl.tone())
+            && _match_groups(groups, start + len, word)
+    })
+}
+
+fn _toneless_spelling(syl: &Syllable) -> String {
+    Syllable::new(syl.init(), syl.med(), syl.rime(), Tone::NoTone).to_ascii_pinyin()
+}
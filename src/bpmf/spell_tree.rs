@@ -1,68 +1,163 @@
-use core::fmt;
-use std::{
-    collections::{binary_heap::Iter, HashMap},
-    fmt::{Display, Formatter},
+use alloc::{
+    borrow::ToOwned,
+    collections::{btree_map, BTreeMap},
+    string::String,
+    vec,
+    vec::Vec,
 };
+use core::fmt::{self, Display, Formatter};
 
+/// A trie keyed by chars which can be used as a map from terms to meanings.
+/// The branches are kept in order so the entries are always visited in the order of their terms
+#[derive(Clone, Debug)]
 pub struct SpellTree<T> {
     meaning: Option<T>,
-    branches: HashMap<char, Self>,
+    branches: BTreeMap<char, Self>,
+    //number of meanings in the tree, the node's own included
+    len: usize,
 }
 
 impl<T> SpellTree<T> {
     pub fn new() -> SpellTree<T> {
         SpellTree {
             meaning: None,
-            branches: HashMap::new(),
+            branches: BTreeMap::new(),
+            len: 0,
         }
     }
 
-    pub fn insert(&mut self, term: &str, meaning: T) {
-        if let Some(ch) = term.chars().next() {
-            let ch_len = ch.len_utf8();
-            let node = self.branches.entry(ch).or_insert(SpellTree::new());
-            if ch_len == term.len() {
-                //reaches the end
-                node.meaning = Some(meaning)
-            } else {
-                //insert it in a descendant branch
-                node.insert(&term[ch_len..], meaning)
+    /// Inserts the meaning of the term, if the term was already there the former meaning
+    /// is returned (0.1.0 returned nothing, see CHANGELOG.md)
+    pub fn insert(&mut self, term: &str, meaning: T) -> Option<T> {
+        let former = match term.chars().next() {
+            //reaches the end
+            None => self.meaning.replace(meaning),
+            //insert it in a descendant branch
+            Some(ch) => self
+                .branches
+                .entry(ch)
+                .or_default()
+                .insert(&term[ch.len_utf8()..], meaning),
+        };
+        if former.is_none() {
+            self.len += 1;
+        }
+        former
+    }
+
+    /// Removes the term and returns its meaning, the branches left empty are cut off
+    pub fn remove(&mut self, term: &str) -> Option<T> {
+        let removed = match term.chars().next() {
+            None => self.meaning.take(),
+            Some(ch) => {
+                let node = self.branches.get_mut(&ch)?;
+                let removed = node.remove(&term[ch.len_utf8()..]);
+                if node.len == 0 {
+                    self.branches.remove(&ch);
+                }
+                removed
             }
+        };
+        if removed.is_some() {
+            self.len -= 1;
         }
+        removed
     }
 
-    pub fn build_from(list: Iter<(&str, T)>) -> SpellTree<T>
+    pub fn build_from<K, I>(list: I) -> SpellTree<T>
     where
-        T: Copy,
+        K: AsRef<str>,
+        I: IntoIterator<Item = (K, T)>,
     {
         let mut root = SpellTree::new();
-        for (term, meaning) in list {
-            root.insert(term, *meaning)
-        }
+        root.extend(list);
         root
     }
 
-    pub fn find<'a>(&self, term: &'a str) -> Option<(T, &'a str)>
-    where
-        T: Copy,
-    {
+    /// The meaning of the exact term
+    pub fn get(&self, term: &str) -> Option<&T> {
+        self._node(term)?.meaning.as_ref()
+    }
+
+    pub fn get_mut(&mut self, term: &str) -> Option<&mut T> {
+        let mut node = self;
+        for ch in term.chars() {
+            node = node.branches.get_mut(&ch)?;
+        }
+        node.meaning.as_mut()
+    }
+
+    pub fn contains(&self, term: &str) -> bool {
+        self.get(term).is_some()
+    }
+
+    /// It walks down as long as a branch exists and returns the meaning of the last node reached
+    /// along with the remaining part of the term.
+    /// The meaning is borrowed, 0.1.0 copied it out (see CHANGELOG.md)
+    pub fn find<'a>(&self, term: &'a str) -> Option<(&T, &'a str)> {
         let mut chars = term.chars();
         if let Some(ch) = chars.next() {
             if let Some(node) = self.branches.get(&ch) {
                 let remainder = chars.as_str();
                 return if remainder.is_empty() {
-                    node.meaning.map(|x| (x, remainder)) //reaches the end
+                    node.meaning.as_ref().map(|x| (x, remainder)) //reaches the end
                 } else {
                     node.find(remainder)
                 };
             }
         }
-        self.meaning.map(|m| (m, term))
+        self.meaning.as_ref().map(|m| (m, term))
+    }
+
+    fn _node(&self, term: &str) -> Option<&Self> {
+        let mut node = self;
+        for ch in term.chars() {
+            node = node.branches.get(&ch)?;
+        }
+        Some(node)
+    }
+
+    /// Unlike `find` it falls back to the longest prefix that has a meaning, e.g. with "u" and
+    /// "uang" in the tree "uanx" gives "u" where `find` gives nothing
+    pub fn longest_match<'a>(&self, term: &'a str) -> Option<(&T, &'a str)> {
+        self.all_prefix_matches(term).last()
+    }
+
+    /// Iterates over the meanings of all the prefixes of the term, shortest first,
+    /// along with the remaining part of the term
+    pub fn all_prefix_matches<'t, 'a>(&'t self, term: &'a str) -> PrefixMatches<'t, 'a, T> {
+        PrefixMatches {
+            node: Some(self),
+            term,
+            pos: 0,
+        }
+    }
+
+    /// Iterates over all the (term, meaning) pairs in the order of the terms
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self, String::new())
+    }
+
+    /// Iterates over the entries whose terms start with the prefix, in the order of the terms
+    pub fn entries_with_prefix(&self, prefix: &str) -> Iter<'_, T> {
+        match self._node(prefix) {
+            Some(node) => Iter::new(node, prefix.to_owned()),
+            None => Iter::empty(),
+        }
     }
 
     //#region props
+    /// Number of the terms in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub fn is_leaf(&self) -> bool {
-        self.branches.len() == 0
+        self.branches.is_empty()
     }
 
     fn _show_nodes(&self, f: &mut Formatter<'_>, tabs: usize) -> fmt::Result {
@@ -76,20 +171,149 @@ impl<T> SpellTree<T> {
         Ok(())
     }
 
+    /// Number of all the nodes, the root included
     pub fn total_nodes(&self) -> usize {
-        self.branches.values().fold(0, |agg, node| {
-            agg + if node.is_leaf() {
-                1
-            } else {
-                node.total_nodes()
-            }
-        })
+        1 + self
+            .branches
+            .values()
+            .map(SpellTree::total_nodes)
+            .sum::<usize>()
+    }
+
+    fn _drain_into(self, key: &mut String, list: &mut Vec<(String, T)>) {
+        if let Some(meaning) = self.meaning {
+            list.push((key.clone(), meaning))
+        }
+        for (ch, node) in self.branches {
+            key.push(ch);
+            node._drain_into(key, list);
+            key.pop();
+        }
     }
     //#endregion
 }
 
+impl<T> Default for SpellTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: AsRef<str>, T> Extend<(K, T)> for SpellTree<T> {
+    fn extend<I: IntoIterator<Item = (K, T)>>(&mut self, iter: I) {
+        for (term, meaning) in iter {
+            self.insert(term.as_ref(), meaning);
+        }
+    }
+}
+
+impl<K: AsRef<str>, T> FromIterator<(K, T)> for SpellTree<T> {
+    fn from_iter<I: IntoIterator<Item = (K, T)>>(iter: I) -> Self {
+        Self::build_from(iter)
+    }
+}
+
+impl<'t, T> IntoIterator for &'t SpellTree<T> {
+    type Item = (String, &'t T);
+    type IntoIter = Iter<'t, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for SpellTree<T> {
+    type Item = (String, T);
+    type IntoIter = vec::IntoIter<(String, T)>;
+
+    /// The entries are moved out in the order of their terms
+    fn into_iter(self) -> Self::IntoIter {
+        let mut list = Vec::with_capacity(self.len);
+        self._drain_into(&mut String::new(), &mut list);
+        list.into_iter()
+    }
+}
+
 impl<T> Display for SpellTree<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self._show_nodes(f, 0)
     }
 }
+
+/// The iterator returned by `SpellTree::all_prefix_matches`
+pub struct PrefixMatches<'t, 'a, T> {
+    node: Option<&'t SpellTree<T>>,
+    term: &'a str,
+    pos: usize,
+}
+
+impl<'t, 'a, T> Iterator for PrefixMatches<'t, 'a, T> {
+    type Item = (&'t T, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.node {
+            let remainder = &self.term[self.pos..];
+            self.node = remainder.chars().next().and_then(|ch| {
+                self.pos += ch.len_utf8();
+                node.branches.get(&ch)
+            });
+            if let Some(meaning) = &node.meaning {
+                return Some((meaning, remainder));
+            }
+        }
+        None
+    }
+}
+
+/// The iterator returned by `SpellTree::iter` and `SpellTree::entries_with_prefix`
+pub struct Iter<'t, T> {
+    //the meaning of the node it starts from
+    first: Option<&'t T>,
+    //a stack of the branches being walked, the key is the path to the top of it
+    stack: Vec<btree_map::Iter<'t, char, SpellTree<T>>>,
+    key: String,
+}
+
+impl<'t, T> Iter<'t, T> {
+    fn new(node: &'t SpellTree<T>, key: String) -> Self {
+        Iter {
+            first: node.meaning.as_ref(),
+            stack: vec![node.branches.iter()],
+            key,
+        }
+    }
+    fn empty() -> Self {
+        Iter {
+            first: None,
+            stack: vec![],
+            key: String::new(),
+        }
+    }
+}
+
+impl<'t, T> Iterator for Iter<'t, T> {
+    type Item = (String, &'t T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(meaning) = self.first.take() {
+            return Some((self.key.clone(), meaning));
+        }
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some((&ch, node)) => {
+                    self.key.push(ch);
+                    self.stack.push(node.branches.iter());
+                    if let Some(meaning) = &node.meaning {
+                        return Some((self.key.clone(), meaning));
+                    }
+                }
+                None => {
+                    //the branches of the node are done, go back to its parent
+                    if self.stack.pop().is_some() && !self.stack.is_empty() {
+                        self.key.pop();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! A read-only counterpart of `SpellTree`, laid out in a flat array and built by `const fn`s,
+//! so it can live in a `static` and needs neither hashing nor initialization at runtime.
+//!
+//! The tree is walked byte by byte, the nodes of a multibyte char are simply chained.
+//! A tree with the size computed by `count_nodes` is built like this:
+//! ```
+//! use bpmf_py::bpmf::static_tree::{count_nodes, StaticSpellTree};
+//! const WORDS: [(&str, u8); 3] = [("a", 1), ("ab", 2), ("ü", 3)];
+//! static TREE: StaticSpellTree<u8, { count_nodes(&WORDS) }> = StaticSpellTree::build(&WORDS);
+//! assert_eq!(TREE.find("abc"), Some((2, "c")));
+//! ```
+
+#[derive(Debug, Clone, Copy)]
+struct Node<T> {
+    byte: u8,
+    //index of the first child and the next sibling, 0 stands for none since the root
+    //can't be anyone's child or sibling
+    child: u16,
+    sibling: u16,
+    meaning: Option<T>,
+}
+
+pub struct StaticSpellTree<T, const N: usize> {
+    nodes: [Node<T>; N],
+}
+
+/// Counts the nodes (including the root) of the tree built from the list
+pub const fn count_nodes<T>(list: &[(&str, T)]) -> usize {
+    let mut count = 1;
+    let mut i = 0;
+    while i < list.len() {
+        //the nodes shared with the terms before have already been counted
+        let term = list[i].0.as_bytes();
+        let mut shared = 0;
+        let mut j = 0;
+        while j < i {
+            let other = list[j].0.as_bytes();
+            let mut k = 0;
+            while k < term.len() && k < other.len() && term[k] == other[k] {
+                k += 1;
+            }
+            if k > shared {
+                shared = k;
+            }
+            j += 1;
+        }
+        count += term.len() - shared;
+        i += 1;
+    }
+    count
+}
+
+impl<T: Copy, const N: usize> StaticSpellTree<T, N> {
+    /// Builds the tree at compile time, N must be `count_nodes(list)`.
+    /// As with `SpellTree::insert` a duplicated term overrides the former one
+    pub const fn build(list: &[(&str, T)]) -> Self {
+        assert!(N == count_nodes(list), "N must be the number of nodes");
+        assert!(N <= u16::MAX as usize, "Too many nodes");
+        let empty = Node {
+            byte: 0,
+            child: 0,
+            sibling: 0,
+            meaning: None,
+        };
+        let mut nodes = [empty; N];
+        let mut len = 1;
+        let mut i = 0;
+        while i < list.len() {
+            let term = list[i].0.as_bytes();
+            let mut node = 0;
+            let mut k = 0;
+            while k < term.len() {
+                //look for the branch among the children
+                let mut child = nodes[node].child as usize;
+                let mut last = 0;
+                while child != 0 && nodes[child].byte != term[k] {
+                    last = child;
+                    child = nodes[child].sibling as usize;
+                }
+                if child == 0 {
+                    //append a new branch
+                    child = len;
+                    len += 1;
+                    nodes[child].byte = term[k];
+                    if last == 0 {
+                        nodes[node].child = child as u16;
+                    } else {
+                        nodes[last].sibling = child as u16;
+                    }
+                }
+                node = child;
+                k += 1;
+            }
+            nodes[node].meaning = Some(list[i].1);
+            i += 1;
+        }
+        StaticSpellTree { nodes }
+    }
+
+    /// It gives the same result as `SpellTree::find`: it walks down as long as a branch exists and
+    /// returns the meaning of the last node reached along with the remaining part of the term.
+    /// It's a `const fn` so the parsers of syllable literals can use it
+    pub const fn find<'a>(&self, term: &'a str) -> Option<(T, &'a str)> {
+        let bytes = term.as_bytes();
+        let (mut node, mut pos) = (0, 0);
+        //the last node reached at the end of a whole char
+        let (mut found, mut found_pos) = (0, 0);
+        while pos < bytes.len() {
+            match self._branch(node, bytes[pos]) {
+                Some(child) => {
+                    node = child;
+                    pos += 1;
+                    if term.is_char_boundary(pos) {
+                        (found, found_pos) = (node, pos);
+                    }
+                }
+                None => break,
+            }
+        }
+        match self.nodes[found].meaning {
+            Some(m) => Some((m, term.split_at(found_pos).1)),
+            None => None,
+        }
+    }
+
+    /// Whether some term of the tree starts with the prefix, the empty prefix included
+    pub const fn has_prefix(&self, prefix: &str) -> bool {
+        let bytes = prefix.as_bytes();
+        let (mut node, mut pos) = (0, 0);
+        while pos < bytes.len() {
+            match self._branch(node, bytes[pos]) {
+                Some(child) => node = child,
+                None => return false,
+            }
+            pos += 1;
+        }
+        true
+    }
+
+    const fn _branch(&self, node: usize, byte: u8) -> Option<usize> {
+        let mut child = self.nodes[node].child as usize;
+        while child != 0 {
+            if self.nodes[child].byte == byte {
+                return Some(child);
+            }
+            child = self.nodes[child].sibling as usize;
+        }
+        None
+    }
+
+    //#region props
+    pub fn total_nodes(&self) -> usize {
+        N
+    }
+    //#endregion
+}
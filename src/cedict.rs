@@ -0,0 +1,120 @@
+//! A loader of dictionaries in the CC-CEDICT format, one entry per line:
+//! ```text
+//! 中國 中国 [Zhong1 guo2] /China/
+//! ```
+//! The pinyin field is ascii pinyin with a few quirks, they are handled by `parse_cedict_pinyin`
+use crate::bpmf::{Init, Med, Rime, Syllable, Tone};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub traditional: String,
+    pub simplified: String,
+    pub syllables: Vec<Syllable>,
+    pub glosses: Vec<String>,
+}
+
+/// A malformed line, the line number counts from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CedictError {
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl Display for CedictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl core::error::Error for CedictError {}
+
+impl Entry {
+    /// Parses a line of CEDICT, it returns `Ok(None)` for comments and blank lines
+    pub fn parse(line: &str) -> Result<Option<Entry>, &'static str> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let (traditional, rest) = line.split_once(' ').ok_or("missing simplified form")?;
+        let (simplified, rest) = rest.split_once(' ').ok_or("missing pinyin")?;
+        let rest = rest.trim_start().strip_prefix('[').ok_or("missing '['")?;
+        let (pinyin, rest) = rest.split_once(']').ok_or("missing ']'")?;
+        let glosses = rest
+            .trim_start()
+            .strip_prefix('/')
+            .ok_or("missing glosses")?;
+        Ok(Some(Entry {
+            traditional: traditional.to_owned(),
+            simplified: simplified.to_owned(),
+            syllables: parse_cedict_pinyin(pinyin)?,
+            glosses: glosses
+                .split('/')
+                .filter(|g| !g.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        }))
+    }
+}
+
+/// Parses the pinyin field of CEDICT, e.g. "Zhong1 guo2", with its quirks:
+/// - "u:" stands for "ü" and proper nouns are capitalized;
+/// - "r5" is the erhua suffix, it's taken as a neutral ㄦ;
+/// - "xx5", latin letters without tone numbers (as in "卡拉OK") and syllabic nasals like "m2",
+///   which the crate can't represent, give an empty `Syllable` so the syllables still line up
+///   with the chars. Every notation spells it as "";
+/// - punctuations like "," and "·" are skipped.
+pub fn parse_cedict_pinyin(txt: &str) -> Result<Vec<Syllable>, &'static str> {
+    let mut list = vec![];
+    for token in txt.split_whitespace() {
+        if !token.chars().any(|ch| ch.is_ascii_alphanumeric()) {
+            continue;
+        }
+        let token = token.to_ascii_lowercase().replace("u:", "v");
+        let (spelling, tone) = match token.as_bytes() {
+            [.., d @ b'1'..=b'5'] => (&token[..token.len() - 1], d - b'0'),
+            _ => (&token[..], 0),
+        };
+        let syl = match (spelling, tone) {
+            ("r", 1..) => Syllable::new(Init::NoInit, Med::NoMed, Rime::Er, Tone::Neut),
+            //unknown readings and letters
+            ("xx", _) | (_, 0) => Syllable::default(),
+            ("m" | "n" | "ng" | "hm" | "hng", _) => Syllable::default(),
+            _ => match Syllable::parse_ascii_pinyin(&token) {
+                Ok((syl, "")) => syl,
+                _ => return Err("invalid pinyin"),
+            },
+        };
+        list.push(syl);
+    }
+    Ok(list)
+}
+
+/// Iterates over the entries of a whole dictionary, skipping comments and blank lines
+pub fn entries(src: &str) -> Entries<'_> {
+    Entries {
+        lines: src.lines().enumerate(),
+    }
+}
+
+/// The iterator returned by `entries`, a malformed line gives an error and the iteration
+/// can go on with the next line
+pub struct Entries<'a> {
+    lines: core::iter::Enumerate<core::str::Lines<'a>>,
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = Result<Entry, CedictError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (idx, line) in self.lines.by_ref() {
+            match Entry::parse(line) {
+                Ok(Some(entry)) => return Some(Ok(entry)),
+                Ok(None) => {}
+                Err(reason) => return Some(Err(CedictError { line: idx + 1, reason })),
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,203 @@
+//! The syllable chart of Mandarin, initials by finals, as in the pinyin and zhuyin tables of
+//! the textbooks. It's derived from `Syllable::is_valid`, so it lists exactly the syllables
+//! the crate knows.
+//!
+//! The rows are the initials, the zero initial (∅) first. The columns are the finals grouped
+//! by medial in the order of bopomofo, the empty final of zhi, chi, shi... (-i, ㄭ) first.
+//! ```
+//! use bpmf_py::bpmf::{Final, Init, Notation};
+//! use bpmf_py::chart::{ChartOptions, SyllableChart};
+//! let chart = SyllableChart::new();
+//! let zhong = chart.cell(Init::Zhi, Final::Ueng).unwrap();
+//! assert_eq!(zhong.to_pinyin(), "zhong");
+//! assert!(chart.cell(Init::Bo, Final::V).is_none());
+//! let options = ChartOptions { header: Notation::Bopomofo, cells: Notation::Pinyin };
+//! assert!(chart.to_csv(&options).starts_with(",ㄭ,ㄚ,ㄛ,ㄜ,ㄝ,"));
+//! ```
+use crate::bpmf::{Final, Init, Notation, Syllable, Tone};
+use crate::ruby::_escape_html;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+/// The label of the zero initial
+pub const ZERO_INITIAL: &str = "∅";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyllableChart {
+    /// The rows
+    pub initials: Vec<Init>,
+    /// The columns
+    pub finals: Vec<Final>,
+    /// The cells by row then column, the toneless syllable or None if it doesn't exist
+    pub cells: Vec<Vec<Option<Syllable>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChartOptions {
+    /// The notation of the initials and the finals in the headers
+    pub header: Notation,
+    /// The notation of the syllables in the cells
+    pub cells: Notation,
+}
+
+impl Default for ChartOptions {
+    fn default() -> Self {
+        ChartOptions {
+            header: Notation::Pinyin,
+            cells: Notation::Pinyin,
+        }
+    }
+}
+
+impl SyllableChart {
+    /// The whole chart, every initial and every final taking part in at least one syllable
+    pub fn new() -> SyllableChart {
+        let exists = |init, fin| {
+            Syllable::from_initial_final_checked(init, fin, Tone::NoTone)
+                .ok()
+                .filter(|syl| !syl.is_empty())
+        };
+        let finals: Vec<Final> = Final::ALL
+            .into_iter()
+            .filter(|fin| {
+                Init::ALL
+                    .into_iter()
+                    .any(|init| exists(init, *fin).is_some())
+            })
+            .collect();
+        let initials: Vec<Init> = Init::ALL
+            .into_iter()
+            .filter(|init| finals.iter().any(|fin| exists(*init, *fin).is_some()))
+            .collect();
+        let cells = initials
+            .iter()
+            .map(|init| finals.iter().map(|fin| exists(*init, *fin)).collect())
+            .collect();
+        SyllableChart { initials, finals, cells }
+    }
+
+    /// The syllable of the initial and the final, None if it isn't in the chart
+    pub fn cell(&self, init: Init, fin: Final) -> Option<Syllable> {
+        let row = self.initials.iter().position(|i| *i == init)?;
+        let column = self.finals.iter().position(|f| *f == fin)?;
+        self.cells[row][column]
+    }
+
+    /// A `<table class="syllable-chart">` with a header row and a header column
+    pub fn to_html(&self, options: &ChartOptions) -> String {
+        let mut out = String::from("<table class=\"syllable-chart\">\n<thead><tr><th></th>");
+        for label in self._final_labels(options.header) {
+            out.push_str("<th>");
+            _escape_html(&mut out, &label);
+            out.push_str("</th>");
+        }
+        out.push_str("</tr></thead>\n<tbody>\n");
+        for (label, row) in self._rows(options) {
+            out.push_str("<tr><th>");
+            _escape_html(&mut out, &label);
+            out.push_str("</th>");
+            for cell in row {
+                out.push_str("<td>");
+                _escape_html(&mut out, &cell);
+                out.push_str("</td>");
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>\n");
+        out
+    }
+
+    /// A table of GitHub flavored Markdown, the empty cells are left blank
+    pub fn to_markdown(&self, options: &ChartOptions) -> String {
+        let mut out = String::from("|");
+        for label in self._final_labels(options.header) {
+            write!(out, " | {}", label).unwrap();
+        }
+        out.push_str(" |\n|---");
+        out.push_str(&"|---".repeat(self.finals.len()));
+        out.push_str("|\n");
+        for (label, row) in self._rows(options) {
+            write!(out, "| {}", label).unwrap();
+            for cell in row {
+                write!(out, " | {}", cell).unwrap();
+            }
+            out.push_str(" |\n");
+        }
+        out
+    }
+
+    /// Comma separated values with a header row and a header column, the corner is empty.
+    /// None of the spellings has a comma or a quote, so nothing is quoted
+    pub fn to_csv(&self, options: &ChartOptions) -> String {
+        let mut out = String::new();
+        for label in self._final_labels(options.header) {
+            write!(out, ",{}", label).unwrap();
+        }
+        out.push('\n');
+        for (label, row) in self._rows(options) {
+            out.push_str(&label);
+            for cell in row {
+                write!(out, ",{}", cell).unwrap();
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn _final_labels(&self, notation: Notation) -> impl Iterator<Item = String> + '_ {
+        self.finals
+            .iter()
+            .map(move |fin| _final_label(*fin, notation))
+    }
+
+    /// The label of each row and the spellings of its cells, "" for the empty ones
+    fn _rows(&self, options: &ChartOptions) -> impl Iterator<Item = (String, Vec<String>)> + '_ {
+        let options = *options;
+        self.initials
+            .iter()
+            .zip(&self.cells)
+            .map(move |(init, row)| {
+                let label = match (init, options.header) {
+                    (Init::NoInit, _) => ZERO_INITIAL.to_string(),
+                    (_, Notation::Bopomofo) => char::from(*init).to_string(),
+                    _ => init.pinyin().to_string(),
+                };
+                let cells = row
+                    .iter()
+                    .map(|cell| match cell {
+                        Some(syl) => syl.display_as(options.cells).to_string(),
+                        None => String::new(),
+                    })
+                    .collect();
+                (label, cells)
+            })
+    }
+}
+
+impl Default for SyllableChart {
+    fn default() -> Self {
+        SyllableChart::new()
+    }
+}
+
+/// The final as it's named in the charts, with the full forms iou, uei, uen
+fn _final_label(fin: Final, notation: Notation) -> String {
+    let label = match (fin, notation) {
+        (Final::Ii, Notation::Bopomofo) => "ㄭ",
+        (_, Notation::Bopomofo) => fin.bopomofo(),
+        (Final::Ii, _) => "-i",
+        (Final::Iou, _) => "iou",
+        (Final::Uei, _) => "uei",
+        (Final::Uen, _) => "uen",
+        _ => fin.pinyin(),
+    };
+    if notation == Notation::AsciiPinyin {
+        //ê is written eh, so it stays apart from e
+        label.replace('ü', "v").replace('ê', "eh")
+    } else {
+        label.to_string()
+    }
+}
@@ -0,0 +1,147 @@
+//! Colors syllables by their tones, as Pleco and MDBG do, for terminals (ANSI escape codes)
+//! and for HTML (spans).
+//!
+//! Single syllables and sequences of them are written in any notation, and `colorize_text`
+//! colors the syllables found in a whole converted sentence, leaving the rest as is.
+use crate::bpmf::{Notation, Syllable, Tone};
+use crate::ruby::{_escape_html, tone_class};
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// The color of each tone, the default one is the MDBG palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub level: Rgb,
+    pub rise: Rgb,
+    pub dip: Rgb,
+    pub fall: Rgb,
+    pub neut: Rgb,
+    /// For the syllables without tone, like ascii pinyin without a tone number
+    pub no_tone: Rgb,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            level: Rgb(0xe3, 0x00, 0x00),
+            rise: Rgb(0xe0, 0x80, 0x00),
+            dip: Rgb(0x00, 0xa0, 0x00),
+            fall: Rgb(0x00, 0x00, 0xe0),
+            neut: Rgb(0x80, 0x80, 0x80),
+            no_tone: Rgb(0x80, 0x80, 0x80),
+        }
+    }
+}
+
+impl Palette {
+    pub fn color(&self, tone: Tone) -> Rgb {
+        match tone {
+            Tone::NoTone => self.no_tone,
+            Tone::Level => self.level,
+            Tone::Rise => self.rise,
+            Tone::Dip => self.dip,
+            Tone::Fall => self.fall,
+            Tone::Neut => self.neut,
+        }
+    }
+}
+
+/// Where the colored text goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorTarget {
+    /// 24-bit ANSI escape codes
+    Ansi,
+    /// `<span class="tone2" style="color:#e08000">`, the class is the one of `ruby::tone_class`
+    Html,
+}
+
+/// Appends the spelling colored by the tone of the syllable, the spelling is escaped for HTML
+pub fn write_colored(
+    out: &mut String,
+    spelling: &str,
+    tone: Tone,
+    palette: &Palette,
+    target: ColorTarget,
+) {
+    let Rgb(r, g, b) = palette.color(tone);
+    match target {
+        ColorTarget::Ansi => {
+            let _ = write!(out, "\x1b[38;2;{r};{g};{b}m{spelling}\x1b[39m");
+        }
+        ColorTarget::Html => {
+            out.push_str("<span ");
+            if tone != Tone::NoTone {
+                let _ = write!(out, "class=\"{}\" ", tone_class(tone));
+            }
+            let _ = write!(out, "style=\"color:#{r:02x}{g:02x}{b:02x}\">");
+            _escape_html(out, spelling);
+            out.push_str("</span>");
+        }
+    }
+}
+
+/// Writes the syllables in the notation, each one colored, with the separator between them
+pub fn colorize<I>(
+    syllables: I,
+    notation: Notation,
+    separator: &str,
+    palette: &Palette,
+    target: ColorTarget,
+) -> String
+where
+    I: IntoIterator<Item = Syllable>,
+{
+    let mut out = String::new();
+    let mut spelling = String::new();
+    for (idx, syl) in syllables.into_iter().enumerate() {
+        if idx > 0 {
+            out.push_str(separator);
+        }
+        spelling.clear();
+        let _ = write!(spelling, "{}", syl.display_as(notation));
+        write_colored(&mut out, &spelling, syl.tone(), palette, target);
+    }
+    out
+}
+
+/// Colors the syllables found in a text like "nǐ hǎo, shì jiè!", they can be in any notation.
+/// A word is colored only if it's made of syllables entirely, so English words like "hello"
+/// are left alone. With the HTML target the rest of the text is escaped
+pub fn colorize_text(text: &str, palette: &Palette, target: ColorTarget) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        //a run of letters, digits and tone marks, or a run of anything else
+        let is_word = |ch: char| ch.is_alphanumeric() || "ˉˊˇˋ˙".contains(ch);
+        let in_word = rest.starts_with(is_word);
+        let end = rest
+            .find(|ch: char| is_word(ch) != in_word)
+            .unwrap_or(rest.len());
+        let (run, remainder) = rest.split_at(end);
+        match _split_syllables(run) {
+            Some(list) if in_word => {
+                for (spelling, tone) in list {
+                    write_colored(&mut out, spelling, tone, palette, target)
+                }
+            }
+            _ if target == ColorTarget::Html => _escape_html(&mut out, run),
+            _ => out.push_str(run),
+        }
+        rest = remainder;
+    }
+    out
+}
+
+/// The spellings and tones of the syllables making up the word, None if it's not all syllables
+fn _split_syllables(mut word: &str) -> Option<Vec<(&str, Tone)>> {
+    let mut list = vec![];
+    while !word.is_empty() {
+        let (syl, _, remainder) = Syllable::parse_any(word).ok()?;
+        list.push((&word[..word.len() - remainder.len()], syl.tone()));
+        word = remainder;
+    }
+    Some(list)
+}
@@ -1,9 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod annotate;
 pub mod bpmf;
 pub mod bpmf_chars;
+#[cfg(feature = "alloc")]
+pub mod cedict;
+#[cfg(feature = "alloc")]
+pub mod chart;
+#[cfg(feature = "alloc")]
+pub mod color;
+#[cfg(feature = "alloc")]
+pub mod ruby;
+#[cfg(feature = "unihan")]
+pub mod unihan;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
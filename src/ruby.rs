@@ -0,0 +1,136 @@
+//! Renders annotated text as HTML `<ruby>`, one `<rt>` for each char.
+//!
+//! Pinyin is written as is. Bopomofo follows the Taiwanese typography: the symbols stand in
+//! a vertical column, the tone marks ˊˇˋ go to the side of it and the neutral-tone dot ˙ on top
+//! of it, while the level tone is left unmarked. The parts are wrapped in spans to be laid out
+//! by CSS, `BOPOMOFO_CSS` is a stylesheet that does it:
+//! ```html
+//! <rt class="bpmf"><span class="bpmf-body">ㄏㄤ</span><span class="bpmf-tone">ˊ</span></rt>
+//! <rt class="bpmf"><span class="bpmf-neutral">˙</span><span class="bpmf-body">ㄇㄚ</span></rt>
+//! ```
+use crate::bpmf::{Notation, Syllable, Tone};
+use alloc::{string::String, vec};
+use core::fmt::Write;
+
+/// A stylesheet laying out the Bopomofo annotations vertically
+pub const BOPOMOFO_CSS: &str = "\
+ruby { ruby-position: inter-character; }
+rt.bpmf { display: inline-flex; flex-direction: column; align-items: center; position: relative;
+    writing-mode: vertical-rl; text-orientation: upright; font-size: 0.3em; line-height: 1; }
+rt.bpmf .bpmf-body { writing-mode: vertical-rl; text-orientation: upright; }
+rt.bpmf .bpmf-tone { position: absolute; left: 100%; top: 50%; transform: translateY(-50%); }
+rt.bpmf .bpmf-neutral { display: block; text-align: center; }
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RubyOptions {
+    pub notation: Notation,
+    /// Adds `<rp>(</rp>` and `<rp>)</rp>` around the annotations for the browsers without ruby
+    pub parentheses: bool,
+    /// Adds the classes "tone1" to "tone5" to the `<rt>`s, by the tone of their syllables
+    pub tone_classes: bool,
+}
+
+impl Default for RubyOptions {
+    fn default() -> Self {
+        RubyOptions {
+            notation: Notation::Bopomofo,
+            parentheses: false,
+            tone_classes: false,
+        }
+    }
+}
+
+/// Renders (text, syllables) pairs like the segments of `Annotator::segment`, each pair in
+/// a `<ruby>` of its own. The chars get the syllables in order, the ones with empty syllables
+/// get empty annotations, and a pair without any syllable is written as plain text
+pub fn ruby_html<I, S, V>(pairs: I, options: &RubyOptions) -> String
+where
+    I: IntoIterator<Item = (S, V)>,
+    S: AsRef<str>,
+    V: AsRef<[Syllable]>,
+{
+    let mut out = String::new();
+    for (text, syllables) in pairs {
+        let (text, syllables) = (text.as_ref(), syllables.as_ref());
+        if syllables.iter().all(Syllable::is_empty) {
+            _escape_html(&mut out, text);
+            continue;
+        }
+        out.push_str("<ruby>");
+        let mut syllables = syllables.iter();
+        for ch in text.chars() {
+            _escape_html(&mut out, ch.encode_utf8(&mut [0; 4]));
+            if options.parentheses {
+                out.push_str("<rp>(</rp>");
+            }
+            match syllables.next() {
+                Some(syl) if !syl.is_empty() => _write_rt(&mut out, syl, options),
+                _ => out.push_str("<rt></rt>"),
+            }
+            if options.parentheses {
+                out.push_str("<rp>)</rp>");
+            }
+        }
+        out.push_str("</ruby>");
+    }
+    out
+}
+
+fn _write_rt(out: &mut String, syl: &Syllable, options: &RubyOptions) {
+    let mut classes = vec![];
+    if options.notation == Notation::Bopomofo {
+        classes.push("bpmf");
+    }
+    if options.tone_classes && syl.tone() != Tone::NoTone {
+        classes.push(tone_class(syl.tone()));
+    }
+    out.push_str("<rt");
+    if !classes.is_empty() {
+        let _ = write!(out, " class=\"{}\"", classes.join(" "));
+    }
+    out.push('>');
+    if options.notation == Notation::Bopomofo {
+        let tone = syl.tone();
+        if tone == Tone::Neut {
+            out.push_str("<span class=\"bpmf-neutral\">˙</span>");
+        }
+        out.push_str("<span class=\"bpmf-body\">");
+        for ch in [syl.init_char(), syl.med_char(), syl.rime_char()] {
+            if ch != '\0' {
+                out.push(ch)
+            }
+        }
+        out.push_str("</span>");
+        if let Tone::Rise | Tone::Dip | Tone::Fall = tone {
+            let _ = write!(out, "<span class=\"bpmf-tone\">{}</span>", syl.tone_char());
+        }
+    } else {
+        let _ = write!(out, "{}", syl.display_as(options.notation));
+    }
+    out.push_str("</rt>");
+}
+
+/// The CSS class of the tone, "" for the syllables without tone
+pub fn tone_class(tone: Tone) -> &'static str {
+    match tone {
+        Tone::NoTone => "",
+        Tone::Level => "tone1",
+        Tone::Rise => "tone2",
+        Tone::Dip => "tone3",
+        Tone::Fall => "tone4",
+        Tone::Neut => "tone5",
+    }
+}
+
+pub(crate) fn _escape_html(out: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use self::bpmf::{Init, Med, ParseBopomofoError, Rime, Syllable, Tone};
+use self::bpmf::{
+    BrailleSystem, Collation, Final, FuzzyRules, Init, Med, Notation, ParseBopomofoError,
+    PinyinQuery, Rime, Syllable, SyllablePattern, Tone,
+};
 use super::*;
 use Init::*;
 use Med::*;
@@ -49,11 +52,11 @@ fn syllable_can_parse_bopomofo() {
     let s_fu1 = "ㄈㄨ奧 ";
     let s_ger4 = "ㄍㄦˋ";
     let s_shi5 = "˙ㄕa";
-    assert_eq!(s_chuang3.parse::<Syllable>().unwrap(), chuang3);
-    assert_eq!(s_ei2.parse::<Syllable>().unwrap(), ei2);
-    assert_eq!(s_fu1.parse::<Syllable>().unwrap(), fu1);
-    assert_eq!(s_ger4.parse::<Syllable>().unwrap(), ger4);
-    assert_eq!(s_shi5.parse::<Syllable>().unwrap(), shi5);
+    assert_eq!(Syllable::parse_bopomofo(s_chuang3).unwrap().0, chuang3);
+    assert_eq!(Syllable::parse_bopomofo(s_ei2).unwrap().0, ei2);
+    assert_eq!(Syllable::parse_bopomofo(s_fu1).unwrap().0, fu1);
+    assert_eq!(Syllable::parse_bopomofo(s_ger4).unwrap().0, ger4);
+    assert_eq!(Syllable::parse_bopomofo(s_shi5).unwrap().0, shi5);
 
     for (bpmf, _) in MANDARIN_SOUNDS {
         let res = bpmf.parse::<Syllable>();
@@ -99,7 +102,7 @@ fn syllable_can_parse_pinyin() {
     for (bpmf, py) in MANDARIN_SOUNDS {
         let b_syl = bpmf.parse::<Syllable>().unwrap();
         let res = Syllable::parse_pinyin(py);
-        if let Err(_) = res {
+        if res.is_err() {
             println!("Failed at parsing {bpmf} : {py}");
         }
         let (p_syl, _) = res.unwrap();
@@ -107,6 +110,17 @@ fn syllable_can_parse_pinyin() {
     }
 }
 
+#[test]
+fn syllable_parses_ye_as_yi_eh() {
+    let ye4 = Syllable::new(NoInit, Yi, Eh, Fall);
+    assert_eq!(Syllable::parse_pinyin("yè").unwrap().0, ye4);
+    assert_eq!(Syllable::parse_ascii_pinyin("ye4").unwrap().0, ye4);
+    assert_eq!(
+        Syllable::parse_pinyin("yè").unwrap().0,
+        "ㄧㄝˋ".parse().unwrap()
+    );
+}
+
 #[test]
 fn syllable_can_convert_to_ascii_pinyin() {
     let chuang3 = Syllable::new(Chi, Wu, Ang, Dip);
@@ -130,16 +144,1365 @@ fn syllable_can_convert_to_ascii_pinyin() {
     );
 }
 
+#[test]
+fn ascii_pinyin_has_no_u_umlaut() {
+    assert_eq!(Syllable::new(Le, Yu, Eh, Fall).to_ascii_pinyin(), "lve4");
+    assert_eq!(Syllable::new(Ne, Yu, Eh, Fall).to_ascii_pinyin(), "nve4");
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        let ascii = bpmf.parse::<Syllable>().unwrap().to_ascii_pinyin();
+        assert!(ascii.is_ascii(), "{bpmf} : {ascii}");
+    }
+}
+
 #[test]
 fn syllable_can_parse_ascii_pinyin() {
     for (bpmf, py) in MANDARIN_SOUNDS {
         let b_syl = bpmf.parse::<Syllable>().unwrap();
         let ascii = b_syl.to_ascii_pinyin();
         let res = Syllable::parse_ascii_pinyin(&ascii);
-        if let Err(_) = res {
+        if res.is_err() {
             println!("Failed at parsing {bpmf} : {py} : {ascii}");
         }
         let (p_syl, _) = res.unwrap();
         assert_eq!(p_syl.to_pinyin(), b_syl.to_pinyin());
     }
 }
+
+#[test]
+fn syllable_can_detect_notation() {
+    let zhong1 = Syllable::new(Zhi, Wu, Eng, Level);
+    assert_eq!(
+        Syllable::parse_any("ㄓㄨㄥ中").unwrap(),
+        (zhong1, Notation::Bopomofo, "中")
+    );
+    assert_eq!(
+        Syllable::parse_any("zhōngguó").unwrap(),
+        (zhong1, Notation::Pinyin, "guó")
+    );
+    assert_eq!(
+        Syllable::parse_any("zhong1guo2").unwrap(),
+        (zhong1, Notation::AsciiPinyin, "guo2")
+    );
+    assert_eq!(
+        Syllable::parse_any("⠌⠲⠁⠛⠕⠂").unwrap(),
+        (zhong1, Notation::Braille(BrailleSystem::Mainland), "⠛⠕⠂")
+    );
+    assert_eq!(
+        Syllable::parse_any("⠁⠯⠄").unwrap(),
+        (zhong1, Notation::Braille(BrailleSystem::Taiwan), "")
+    );
+    assert_eq!(
+        Syllable::parse_any("Чжунго").unwrap(),
+        (
+            Syllable::new(Zhi, Wu, Eng, NoTone),
+            Notation::Palladius,
+            "го"
+        )
+    );
+    //a bare toneless syllable is taken as pinyin in neutral tone
+    let (ma, notation, _) = Syllable::parse_any("ma").unwrap();
+    assert_eq!((ma.tone(), notation), (Neut, Notation::Pinyin));
+    assert!(Syllable::parse_any("万X尢").is_err());
+
+    for (bpmf, py) in MANDARIN_SOUNDS {
+        let syl = bpmf.parse::<Syllable>().unwrap();
+        assert_eq!(
+            Syllable::parse_any_exact(bpmf).unwrap(),
+            (syl, Notation::Bopomofo)
+        );
+        let (p_syl, notation) = Syllable::parse_any_exact(py).unwrap();
+        assert_eq!(p_syl.to_pinyin(), py);
+        assert_eq!(notation, Notation::Pinyin);
+        let ascii = syl.to_ascii_pinyin();
+        if syl.tone() != Neut {
+            assert_eq!(
+                Syllable::parse_any_exact(&ascii).unwrap(),
+                (syl, Notation::AsciiPinyin)
+            );
+        }
+    }
+}
+
+#[test]
+fn syllable_from_str_rejects_trailing_garbage() {
+    assert_eq!(
+        "ma3".parse::<Syllable>().unwrap(),
+        Syllable::new(Mo, NoMed, A, Dip)
+    );
+    assert_eq!(
+        " lve4\n".parse::<Syllable>().unwrap(),
+        Syllable::new(Le, Yu, Eh, Fall)
+    );
+    assert!("ㄇㄚˇxyz".parse::<Syllable>().is_err());
+    assert!("ma3xyz".parse::<Syllable>().is_err());
+    assert!("mǎ mǎ".parse::<Syllable>().is_err());
+    assert!("".parse::<Syllable>().is_err());
+}
+
+#[test]
+fn syllable_display_adapters_match_string_conversions() {
+    use std::fmt::Write as _;
+    use std::io::Write as _;
+
+    let mut buf = String::new();
+    let mut bytes: Vec<u8> = vec![];
+    for (bpmf, py) in MANDARIN_SOUNDS {
+        let syl = bpmf.parse::<Syllable>().unwrap();
+        assert_eq!(syl.pinyin().to_string(), py);
+        assert_eq!(syl.bopomofo().to_string(), bpmf);
+        assert_eq!(syl.ascii_pinyin().to_string(), syl.to_ascii_pinyin());
+        assert_eq!(syl.display_as(Notation::Pinyin).to_string(), py);
+
+        buf.clear();
+        write!(buf, "{} ", syl.ascii_pinyin()).unwrap();
+        syl.write_pinyin_to(&mut buf);
+        assert_eq!(buf, format!("{} {}", syl.to_ascii_pinyin(), py));
+
+        bytes.clear();
+        write!(bytes, "{}|{}", syl.bopomofo(), syl.pinyin()).unwrap();
+        assert_eq!(String::from_utf8_lossy(&bytes), format!("{bpmf}|{py}"));
+    }
+}
+
+#[test]
+fn syllable_display_adapters_pad_like_str() {
+    let zhuang4 = Syllable::new(Zhi, Wu, Ang, Fall);
+    assert_eq!(format!("[{:>8}]", zhuang4.pinyin()), "[  zhuàng]");
+    assert_eq!(format!("[{:-^9}]", zhuang4.ascii_pinyin()), "[-zhuang4-]");
+    assert_eq!(format!("[{:<6}]", zhuang4.bopomofo()), "[ㄓㄨㄤˋ  ]");
+    assert_eq!(format!("[{:6}]", zhuang4), "[ㄓㄨㄤˋ  ]");
+    assert_eq!(
+        format!("[{:.3}]", zhuang4.display_as(Notation::Pinyin)),
+        "[zhu]"
+    );
+    assert_eq!(format!("[{:2}]", zhuang4.pinyin()), "[zhuàng]");
+    for (bpmf, py) in MANDARIN_SOUNDS {
+        let syl = bpmf.parse::<Syllable>().unwrap();
+        assert_eq!(format!("{:>8}", syl.pinyin()), format!("{py:>8}"));
+        assert_eq!(format!("{:>8}", syl), format!("{bpmf:>8}"));
+    }
+}
+
+#[test]
+fn static_spell_tree_finds_the_same_as_spell_tree() {
+    use self::bpmf::data::*;
+    use self::bpmf::spell_tree::SpellTree;
+
+    let mut toned = SpellTree::new();
+    for (term, meaning) in PINYIN_TONED_RIMES {
+        toned.insert(term, meaning);
+    }
+    let mut untoned = SpellTree::new();
+    for (term, meaning) in PINYIN_UNTONED_RIMES {
+        untoned.insert(term, meaning);
+    }
+
+    for (_, py) in MANDARIN_SOUNDS {
+        let ascii = py.parse::<Syllable>().unwrap().to_ascii_pinyin();
+        //every suffix of the syllables plus some garbage after them
+        for txt in [py.to_string(), format!("{py}ㄅ"), format!("{py}ã"), ascii] {
+            for (idx, _) in txt.char_indices() {
+                let term = &txt[idx..];
+                assert_eq!(
+                    get_pinyin_toned_rime_tree().find(term),
+                    toned.find(term).map(|(m, r)| (*m, r))
+                );
+                assert_eq!(
+                    get_pinyin_untoned_rime_tree().find(term),
+                    untoned.find(term).map(|(m, r)| (*m, r))
+                );
+            }
+        }
+    }
+    assert_eq!(get_pinyin_toned_rime_tree().find(""), None);
+    assert_eq!(
+        get_pinyin_toned_rime_tree().find("iã"),
+        Some(((Yi, NoRime, 5), "ã"))
+    );
+}
+
+#[test]
+fn spell_tree_supports_backtracking_lookups() {
+    use self::bpmf::spell_tree::SpellTree;
+
+    let mut tree = SpellTree::new();
+    for (term, meaning) in [
+        ("i", 1),
+        ("ian", 2),
+        ("iang", 3),
+        ("in", 4),
+        ("u", 5),
+        ("uang", 6),
+    ] {
+        tree.insert(term, meaning);
+    }
+    //find stops at "uan" which has no meaning of its own
+    assert_eq!(tree.find("uanx"), None);
+    assert_eq!(tree.longest_match("uanx"), Some((&5, "anx")));
+    assert_eq!(tree.longest_match("iangu"), Some((&3, "u")));
+    assert_eq!(tree.longest_match("iangx"), Some((&3, "x")));
+    assert_eq!(tree.longest_match("ia"), Some((&1, "a")));
+    assert_eq!(tree.longest_match("a"), None);
+    assert_eq!(
+        tree.all_prefix_matches("iangx").collect::<Vec<_>>(),
+        vec![(&1, "angx"), (&2, "gx"), (&3, "x")]
+    );
+    assert_eq!(tree.all_prefix_matches("").count(), 0);
+
+    let entries: Vec<(String, i32)> = tree.iter().map(|(k, v)| (k, *v)).collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("i".to_owned(), 1),
+            ("ian".to_owned(), 2),
+            ("iang".to_owned(), 3),
+            ("in".to_owned(), 4),
+            ("u".to_owned(), 5),
+            ("uang".to_owned(), 6)
+        ]
+    );
+    let keys = |prefix| -> Vec<String> { tree.entries_with_prefix(prefix).map(|e| e.0).collect() };
+    assert_eq!(keys("ia"), vec!["ian", "iang"]);
+    assert_eq!(keys("i").len(), 4);
+    assert_eq!(keys("iang"), vec!["iang"]);
+    assert!(keys("o").is_empty());
+    assert_eq!(keys("").len(), 6);
+}
+
+#[test]
+fn spell_tree_works_as_a_map() {
+    use self::bpmf::spell_tree::SpellTree;
+
+    //the meanings need not be Copy
+    let mut tree: SpellTree<String> = [("zhong", "中"), ("zhongguo", "中國"), ("guo", "國")]
+        .into_iter()
+        .map(|(k, v)| (k, v.to_owned()))
+        .collect();
+    assert_eq!(tree.len(), 3);
+    // root + z h o n g g u o + g u o
+    assert_eq!(tree.total_nodes(), 12);
+    assert_eq!(tree.get("zhong").map(String::as_str), Some("中"));
+    assert_eq!(tree.get("zhon"), None);
+    assert!(tree.contains("guo") && !tree.contains("gu"));
+    assert_eq!(
+        tree.find("zhongx").map(|(m, r)| (m.as_str(), r)),
+        Some(("中", "x"))
+    );
+
+    assert_eq!(tree.insert("guo", "果".to_owned()), Some("國".to_owned()));
+    assert_eq!(tree.len(), 3);
+    tree.get_mut("guo").unwrap().push('子');
+    assert_eq!(tree.get("guo").unwrap(), "果子");
+
+    assert_eq!(tree.remove("zhongguo"), Some("中國".to_owned()));
+    assert_eq!(tree.remove("zhongguo"), None);
+    assert_eq!(tree.remove("zho"), None);
+    assert_eq!(tree.len(), 2);
+    //the dangling "guo" branch under "zhong" is cut off
+    assert_eq!(tree.total_nodes(), 9);
+    assert!(tree.entries_with_prefix("zhongg").next().is_none());
+
+    //the empty term is a valid key too
+    tree.insert("", "?".to_owned());
+    assert_eq!(tree.get("").unwrap(), "?");
+    assert_eq!(tree.len(), 3);
+
+    let borrowed: Vec<(String, &String)> = (&tree).into_iter().collect();
+    assert_eq!(borrowed.len(), 3);
+    assert_eq!(borrowed[0].0, "");
+    let owned: Vec<(String, String)> = tree.into_iter().collect();
+    assert_eq!(
+        owned,
+        vec![
+            ("".to_owned(), "?".to_owned()),
+            ("guo".to_owned(), "果子".to_owned()),
+            ("zhong".to_owned(), "中".to_owned())
+        ]
+    );
+
+    let mut tree = SpellTree::build_from(vec![("a".to_owned(), 1), ("ab".to_owned(), 2)]);
+    tree.extend([("abc", 3)]);
+    assert_eq!(tree.len(), 3);
+    for term in ["a", "ab", "abc"] {
+        tree.remove(term);
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.total_nodes(), 1);
+}
+
+#[cfg(feature = "unihan")]
+#[test]
+fn unihan_gives_the_readings_of_han_chars() {
+    use crate::unihan::{reading, readings};
+
+    assert_eq!(reading('中'), Some(Syllable::new(Zhi, Wu, Eng, Level)));
+    assert_eq!(
+        readings('中'),
+        &[
+            Syllable::new(Zhi, Wu, Eng, Level),
+            Syllable::new(Zhi, Wu, Eng, Fall)
+        ]
+    );
+    let hang2 = Syllable::new(He, NoMed, Ang, Rise);
+    assert!(readings('行').contains(&hang2));
+    assert_eq!(reading('行').unwrap().to_pinyin(), "xíng");
+    assert_eq!(reading('好').unwrap().to_pinyin(), "hǎo");
+    assert!(readings('a').is_empty());
+    assert!(readings('ㄅ').is_empty());
+    assert_eq!(reading('龜').map(|s| s.to_ascii_pinyin()).unwrap(), "gui1");
+}
+
+#[test]
+fn cedict_lines_are_parsed_with_their_quirks() {
+    use crate::cedict::{entries, parse_cedict_pinyin, CedictError, Entry};
+
+    let src = "# CC-CEDICT\n\
+               #! version=1\n\
+               \n\
+               中國 中国 [Zhong1 guo2] /China/Middle Kingdom/\n\
+               女兒 女儿 [nu:3 r5] /daughter/\n\
+               卡拉OK 卡拉OK [ka3 la1 O K] /karaoke/\n\
+               阿拉伯 阿拉伯 [A1 la1 bo2 · xx5] /Arab/\n\
+               壞 坏 huai4 /bad/\n\
+               呣 呣 [m2] /interjection/\n\
+               略 略 [lu:e4] /brief/\n";
+    let list: Vec<_> = entries(src).collect();
+    assert_eq!(list.len(), 7);
+
+    let zhongguo = list[0].as_ref().unwrap();
+    assert_eq!(zhongguo.traditional, "中國");
+    assert_eq!(zhongguo.simplified, "中国");
+    assert_eq!(zhongguo.glosses, ["China", "Middle Kingdom"]);
+    assert_eq!(
+        zhongguo.syllables,
+        [
+            Syllable::new(Zhi, Wu, Eng, Level),
+            Syllable::new(Ge, Wu, O, Rise)
+        ]
+    );
+    assert_eq!(
+        list[1].as_ref().unwrap().syllables,
+        [
+            Syllable::new(Ne, Yu, NoRime, Dip),
+            Syllable::new(NoInit, NoMed, Er, Neut)
+        ]
+    );
+    let ok = &list[2].as_ref().unwrap().syllables;
+    assert_eq!(ok.len(), 4);
+    assert!(ok[2].is_empty() && ok[3].is_empty());
+    let arab = &list[3].as_ref().unwrap().syllables;
+    assert_eq!(arab.len(), 4);
+    assert_eq!(arab[0], Syllable::new(NoInit, NoMed, A, Level));
+    assert!(arab[3].is_empty());
+    //the unknown reading has no spelling, in any notation
+    let spelt: Vec<String> = arab.iter().map(Syllable::to_ascii_pinyin).collect();
+    assert_eq!(spelt, ["a1", "la1", "bo2", ""]);
+    for notation in Notation::ALL {
+        assert_eq!(
+            arab[3].display_as(notation).to_string(),
+            "",
+            "{:?}",
+            notation
+        );
+    }
+    assert_eq!(arab[3].to_pinyin(), "");
+    let query = PinyinQuery::parse("alabo").unwrap();
+    assert_eq!(query.matches(arab), Some(100));
+
+    assert_eq!(
+        list[4],
+        Err(CedictError {
+            line: 8,
+            reason: "missing '['"
+        })
+    );
+    assert!(list[5].as_ref().unwrap().syllables[0].is_empty());
+    assert_eq!(
+        list[6].as_ref().unwrap().syllables,
+        [Syllable::new(Le, Yu, Eh, Fall)]
+    );
+
+    assert!(parse_cedict_pinyin("zhong1 guox2").is_err());
+    assert_eq!(Entry::parse("  # comment"), Ok(None));
+    assert!(Entry::parse("中 中 [zhong1]").is_err());
+}
+
+#[test]
+fn annotator_reads_heteronyms_by_words() {
+    use crate::annotate::Annotator;
+
+    let pinyin = |annotator: &Annotator, txt: &str| {
+        annotator
+            .annotate(txt)
+            .into_iter()
+            .map(|(ch, syl)| syl.map_or(ch.to_string(), |s| s.to_pinyin()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut annotator = Annotator::from_cedict(
+        "銀行 银行 [yin2 hang2] /bank/\n\
+         行走 行走 [xing2 zou3] /to walk/\n\
+         行 行 [xing2] /to walk/\n\
+         行 行 [hang2] /row/\n\
+         銀 银 [yin2] /silver/\n",
+    )
+    .unwrap();
+    assert_eq!(pinyin(&annotator, "銀行行走"), "yín háng xíng zǒu");
+    assert_eq!(pinyin(&annotator, "银行，行"), "yín háng ， xíng");
+
+    let segments = annotator.segment("銀行行");
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].text, "銀行");
+    assert_eq!(segments[1].syllables, [Syllable::new(Xi, Yi, Eng, Rise)]);
+
+    //out of the dictionary
+    annotator.insert_char('我', Syllable::new(NoInit, Wu, O, Dip));
+    assert_eq!(pinyin(&annotator, "我行"), "wǒ xíng");
+
+    //the user's entries win
+    annotator.insert_override("行", &[Syllable::new(He, NoMed, Ang, Rise)]);
+    assert_eq!(pinyin(&annotator, "行走行"), "xíng zǒu háng");
+    annotator.insert_override(
+        "銀行行",
+        &[
+            Syllable::new(NoInit, Yi, En, Rise),
+            Syllable::new(He, NoMed, Ang, Rise),
+            Syllable::new(He, NoMed, Ang, Rise),
+        ],
+    );
+    assert_eq!(pinyin(&annotator, "銀行行"), "yín háng háng");
+
+    let mut annotator = Annotator::new();
+    let report = annotator.load_tsv("# words\n長大\tzhang3 da4\n\n").unwrap();
+    assert_eq!((report.entries, report.skipped), (1, vec![]));
+    assert_eq!(pinyin(&annotator, "長大"), "zhǎng dà");
+    let err = annotator
+        .load_tsv("長\tzhang3\n長大 zhang3 da4")
+        .unwrap_err();
+    assert_eq!(err.line, 2);
+}
+
+#[test]
+fn annotator_loaders_report_the_entries_they_skip() {
+    use crate::annotate::{Annotator, LoadReport};
+
+    let mut annotator = Annotator::new();
+    let report = annotator
+        .load_cedict(
+            "# CC-CEDICT\n\
+             銀行 银行 [yin2 hang2] /bank/\n\
+             長大 长大 [zhang3] /to grow up/\n\
+             行 行 [xing2] /to walk/\n",
+        )
+        .unwrap();
+    assert_eq!(
+        report,
+        LoadReport {
+            entries: 2,
+            skipped: vec![3]
+        }
+    );
+    assert!(annotator
+        .segment("長大")
+        .iter()
+        .all(|seg| seg.text != "長大"));
+
+    //the same rules for the tsv files
+    let report = annotator
+        .load_tsv("長大\tzhang3\n\n長大\tzhang3 da4\n")
+        .unwrap();
+    assert_eq!(
+        report,
+        LoadReport {
+            entries: 1,
+            skipped: vec![1]
+        }
+    );
+    assert_eq!(annotator.segment("長大")[0].text, "長大");
+
+    let err = annotator
+        .load_cedict("銀行 银行 [yin2 hang2] /bank/\n銀行")
+        .unwrap_err();
+    assert_eq!(err.line, 2);
+}
+
+#[test]
+fn ruby_html_places_bopomofo_tones() {
+    use crate::ruby::{ruby_html, RubyOptions};
+
+    let hang2 = Syllable::new(He, NoMed, Ang, Rise);
+    let ma5 = Syllable::new(Mo, NoMed, A, Neut);
+    let yin2 = Syllable::new(NoInit, Yi, En, Rise);
+    let pairs = vec![
+        ("銀行", vec![yin2, hang2]),
+        ("！", vec![Syllable::default()]),
+    ];
+
+    let mut options = RubyOptions::default();
+    assert_eq!(
+        ruby_html(pairs.clone(), &options),
+        "<ruby>銀<rt class=\"bpmf\"><span class=\"bpmf-body\">ㄧㄣ</span>\
+         <span class=\"bpmf-tone\">ˊ</span></rt>\
+         行<rt class=\"bpmf\"><span class=\"bpmf-body\">ㄏㄤ</span>\
+         <span class=\"bpmf-tone\">ˊ</span></rt></ruby>！"
+    );
+    assert_eq!(
+        ruby_html([("嗎", [ma5])], &options),
+        "<ruby>嗎<rt class=\"bpmf\"><span class=\"bpmf-neutral\">˙</span>\
+         <span class=\"bpmf-body\">ㄇㄚ</span></rt></ruby>"
+    );
+
+    options.notation = Notation::Pinyin;
+    options.parentheses = true;
+    options.tone_classes = true;
+    assert_eq!(
+        ruby_html([("行<", vec![hang2])], &options),
+        "<ruby>行<rp>(</rp><rt class=\"tone2\">háng</rt><rp>)</rp>\
+         &lt;<rp>(</rp><rt></rt><rp>)</rp></ruby>"
+    );
+}
+
+#[test]
+fn syllables_are_colored_by_tones() {
+    use crate::color::{colorize, colorize_text, ColorTarget, Palette, Rgb};
+
+    let palette = Palette::default();
+    let ni3hao3 = [
+        Syllable::new(Ne, Yi, NoRime, Dip),
+        Syllable::new(He, NoMed, Ao, Dip),
+    ];
+    assert_eq!(
+        colorize(ni3hao3, Notation::Pinyin, " ", &palette, ColorTarget::Ansi),
+        "\x1b[38;2;0;160;0mnǐ\x1b[39m \x1b[38;2;0;160;0mhǎo\x1b[39m"
+    );
+    let palette = Palette {
+        dip: Rgb(1, 2, 3),
+        ..palette
+    };
+    assert_eq!(
+        colorize(ni3hao3, Notation::Bopomofo, "", &palette, ColorTarget::Html),
+        "<span class=\"tone3\" style=\"color:#010203\">ㄋㄧˇ</span>\
+         <span class=\"tone3\" style=\"color:#010203\">ㄏㄠˇ</span>"
+    );
+
+    assert_eq!(
+        colorize_text("ni3hao <b>, hello", &palette, ColorTarget::Html),
+        "<span class=\"tone3\" style=\"color:#010203\">ni3</span>\
+         <span class=\"tone5\" style=\"color:#808080\">hao</span> &lt;b&gt;, hello"
+    );
+    assert_eq!(
+        colorize_text("˙ㄇㄚㄇㄚˇ!", &palette, ColorTarget::Ansi),
+        "\x1b[38;2;128;128;128m˙ㄇㄚ\x1b[39m\x1b[38;2;1;2;3mㄇㄚˇ\x1b[39m!"
+    );
+}
+
+#[test]
+fn notations_are_parsed_from_names() {
+    assert_eq!("Bopomofo".parse(), Ok(Notation::Bopomofo));
+    assert_eq!("zhuyin".parse(), Ok(Notation::Bopomofo));
+    assert_eq!("py".parse(), Ok(Notation::Pinyin));
+    assert_eq!("ascii-pinyin".parse(), Ok(Notation::AsciiPinyin));
+    assert_eq!(
+        "Braille-Mainland".parse(),
+        Ok(Notation::Braille(BrailleSystem::Mainland))
+    );
+    assert_eq!(
+        "taiwan-braille".parse(),
+        Ok(Notation::Braille(BrailleSystem::Taiwan))
+    );
+    assert_eq!("palladius".parse(), Ok(Notation::Palladius));
+    assert_eq!("Cyrillic".parse(), Ok(Notation::Palladius));
+    assert_eq!("hangul".parse(), Ok(Notation::Hangul));
+    assert_eq!("japanese".parse(), Ok(Notation::Katakana));
+    assert!("wade-giles".parse::<Notation>().is_err());
+}
+
+#[test]
+fn collations_sort_syllables_and_words() {
+    let parse =
+        |list: &[&str]| -> Vec<Syllable> { list.iter().map(|s| s.parse().unwrap()).collect() };
+    let spell =
+        |list: &[Syllable]| -> Vec<String> { list.iter().map(Syllable::to_ascii_pinyin).collect() };
+
+    let mut orig = parse(&["zhuan4", "lve4", "lu5", "an3", "an1", "luan2", "lu4", "lv2"]);
+    orig.push(Syllable::new(NoInit, NoMed, A, NoTone));
+    let mut list = orig.clone();
+    Collation::PinyinAlphabetic.sort(&mut list);
+    assert_eq!(
+        spell(&list),
+        ["a", "an1", "an3", "lu4", "lu5", "luan2", "lv2", "lve4", "zhuan4"]
+    );
+    Collation::ToneFirst.sort(&mut list);
+    assert_eq!(
+        spell(&list),
+        ["an1", "luan2", "lv2", "an3", "lu4", "lve4", "zhuan4", "lu5", "a"]
+    );
+    Collation::Bopomofo.sort(&mut list);
+    let mut by_ord = orig.clone();
+    by_ord.sort();
+    assert_eq!(list, by_ord);
+
+    //every syllable has a key of its own
+    let mut keys: Vec<u64> = MANDARIN_SOUNDS
+        .iter()
+        .map(|(bpmf, _)| Collation::PinyinAlphabetic.key(&bpmf.parse().unwrap()))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), MANDARIN_SOUNDS.len());
+
+    let mut words = vec![
+        parse(&["zhong1", "guo2"]),
+        parse(&["zhong1"]),
+        parse(&["zhong4"]),
+        parse(&["zhong1", "wen2"]),
+        parse(&["zhi1", "dao4"]),
+    ];
+    Collation::PinyinAlphabetic.sort_words(&mut words);
+    let words: Vec<String> = words.iter().map(|w| spell(w).join(" ")).collect();
+    assert_eq!(
+        words,
+        [
+            "zhi1 dao4",
+            "zhong1",
+            "zhong1 guo2",
+            "zhong1 wen2",
+            "zhong4"
+        ]
+    );
+}
+
+#[test]
+fn collations_sort_e_circumflex_after_e() {
+    let e1 = Syllable::new(NoInit, NoMed, E, Level);
+    let eh1 = Syllable::new(NoInit, NoMed, Eh, Level);
+    let ei1 = Syllable::new(NoInit, NoMed, Ei, Level);
+    let a1 = Syllable::new(NoInit, NoMed, A, Level);
+    let mut list = vec![ei1, eh1, a1, e1];
+    //ê is a letter of its own, so it follows ei as lü follows luo
+    Collation::PinyinAlphabetic.sort(&mut list);
+    assert_eq!(list, [a1, e1, ei1, eh1]);
+    Collation::ToneFirst.sort(&mut list);
+    assert_eq!(list, [a1, e1, ei1, eh1]);
+
+    //ê comes after e whatever the tones
+    let parse =
+        |list: &[&str]| -> Vec<Syllable> { list.iter().map(|s| s.parse().unwrap()).collect() };
+    let mut list = parse(&["ㄝ", "ㄜ", "ㄝˊ", "ㄜˊ", "ㄜˋ", "ㄝˋ"]);
+    Collation::PinyinAlphabetic.sort(&mut list);
+    assert_eq!(list, parse(&["ㄜ", "ㄜˊ", "ㄜˋ", "ㄝ", "ㄝˊ", "ㄝˋ"]));
+    Collation::ToneFirst.sort(&mut list);
+    assert_eq!(list, parse(&["ㄜ", "ㄝ", "ㄜˊ", "ㄝˊ", "ㄜˋ", "ㄝˋ"]));
+}
+
+#[test]
+fn sort_keys_keep_the_order_of_words() {
+    let parse =
+        |txt: &str| -> Vec<Syllable> { txt.split(' ').map(|s| s.parse().unwrap()).collect() };
+    let words = [
+        parse("zhong1 guo2"),
+        parse("zhong1"),
+        parse("lv4 se4"),
+        parse("lu4"),
+        parse("a1 yi2"),
+        parse("zhi1 dao4 le5"),
+        vec![],
+    ];
+    for collation in [
+        Collation::Bopomofo,
+        Collation::PinyinAlphabetic,
+        Collation::ToneFirst,
+    ] {
+        for a in &words {
+            let key = bpmf::sort_key(a, collation);
+            assert_eq!(key.len(), a.len() * 8);
+            assert_eq!(bpmf::decode_sort_key(&key).as_ref(), Some(a));
+            for b in &words {
+                assert_eq!(
+                    key.cmp(&bpmf::sort_key(b, collation)),
+                    collation.compare_words(a, b)
+                );
+            }
+        }
+    }
+    assert_eq!(bpmf::decode_sort_key(&[0; 7]), None);
+    assert_eq!(bpmf::decode_sort_key(&[0; 8]), None);
+
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        assert_eq!(Syllable::from_ord(syl.ord()), Some(syl));
+    }
+}
+
+#[test]
+fn fuzzy_rules_match_and_expand_syllables() {
+    let syl = |txt: &str| -> Syllable { txt.parse().unwrap() };
+    let mut rules = FuzzyRules::NONE;
+    assert!(!syl("zhang1").fuzzy_eq(&syl("zan1"), rules));
+    rules |= FuzzyRules::Z_ZH | FuzzyRules::AN_ANG;
+    assert!(syl("zhang1").fuzzy_eq(&syl("zan1"), rules));
+    assert!(syl("zan1").fuzzy_eq(&syl("zhang1"), rules));
+    assert!(syl("qiang2").fuzzy_eq(&syl("qian2"), rules));
+    assert!(!syl("zhang1").fuzzy_eq(&syl("zan2"), rules));
+    assert!(!syl("zhang1").fuzzy_eq(&syl("can1"), rules));
+
+    //in/ing and en/eng are told apart
+    rules.insert(FuzzyRules::EN_ENG);
+    assert!(syl("shen1").fuzzy_eq(&syl("sheng1"), rules));
+    assert!(!syl("xin1").fuzzy_eq(&syl("xing1"), rules));
+    rules.toggle(FuzzyRules::IN_ING);
+    assert!(syl("xin1").fuzzy_eq(&syl("xing1"), rules));
+    rules.set(FuzzyRules::IN_ING, false);
+    assert!(!rules.contains(FuzzyRules::IN_ING));
+
+    let spell = |list: Vec<Syllable>| -> Vec<String> {
+        list.iter().map(Syllable::to_ascii_pinyin).collect()
+    };
+    assert_eq!(
+        spell(syl("zhan4").fuzzy_variants(FuzzyRules::ALL)),
+        ["zhan4", "zhang4", "zan4", "zang4"]
+    );
+    assert_eq!(
+        spell(syl("nong2").fuzzy_variants(FuzzyRules::ALL)),
+        ["nong2", "long2"]
+    );
+    //un/ong aren't mixed up, only wen/weng are
+    assert!(!syl("lun2").fuzzy_eq(&syl("long2"), FuzzyRules::ALL));
+    assert!(!syl("dun4").fuzzy_eq(&syl("dong4"), FuzzyRules::EN_ENG));
+    assert!(syl("wen1").fuzzy_eq(&syl("weng1"), FuzzyRules::EN_ENG));
+    assert!(!syl("wen1").fuzzy_eq(&syl("weng1"), FuzzyRules::IN_ING));
+    assert!(!syl("jun1").fuzzy_eq(&syl("jiong1"), FuzzyRules::ALL));
+    assert_eq!(
+        spell(syl("wen3").fuzzy_variants(FuzzyRules::ALL)),
+        ["wen3", "weng3"]
+    );
+    //fong doesn't exist
+    assert_eq!(
+        spell(syl("hong2").fuzzy_variants(FuzzyRules::F_H)),
+        ["hong2"]
+    );
+    assert_eq!(
+        spell(syl("hu2").fuzzy_variants(FuzzyRules::F_H)),
+        ["hu2", "fu2"]
+    );
+    assert_eq!(
+        spell(syl("lv4").fuzzy_variants(FuzzyRules::ALL)),
+        ["lv4", "nv4"]
+    );
+
+    assert!(MANDARIN_SOUNDS.iter().all(|(bpmf, _)| syl(bpmf).is_valid()));
+    assert!(!syl("fong2").is_valid());
+}
+
+#[test]
+fn valid_rimes_equal_the_syllables_of_mandarin() {
+    use self::bpmf::data::VALID_RIMES;
+
+    let mut table = [0u16; 88];
+    let mut set = |syl: Syllable| {
+        table[syl.init() as usize * 4 + syl.med() as usize] |= 1 << syl.rime() as u8
+    };
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        set(bpmf.parse().unwrap());
+    }
+    //ê alone isn't in the list
+    set(Syllable::new(NoInit, NoMed, Eh, NoTone));
+    assert_eq!(table, VALID_RIMES);
+}
+
+#[test]
+fn abbreviated_pinyin_queries_match_words() {
+    let word =
+        |txt: &str| -> Vec<Syllable> { txt.split(' ').map(|s| s.parse().unwrap()).collect() };
+    let prefixes = |query: &str| -> Vec<String> {
+        let query = PinyinQuery::parse(query).unwrap();
+        query.parts().iter().map(|p| p.prefix.clone()).collect()
+    };
+    assert_eq!(prefixes("bj"), ["b", "j"]);
+    assert_eq!(prefixes("zhg"), ["zh", "g"]);
+    assert_eq!(prefixes("zhongg"), ["zhong", "g"]);
+    assert_eq!(prefixes("xian"), ["xian"]);
+    assert_eq!(prefixes("xi'an"), ["xi", "an"]);
+    assert_eq!(prefixes("xi1an"), ["xi", "an"]);
+    assert_eq!(prefixes("Lü"), ["lv"]);
+    assert!(PinyinQuery::parse("iv").is_err());
+
+    let beijing = word("bei3 jing1");
+    let score = |query: &str, word: &[Syllable]| PinyinQuery::parse(query).unwrap().matches(word);
+    assert_eq!(score("bj", &beijing), Some(28));
+    assert_eq!(score("beij", &beijing), Some(57));
+    assert_eq!(score("beijing", &beijing), Some(100));
+    assert_eq!(score("b", &beijing), Some(14));
+    assert_eq!(score("bjx", &beijing), None);
+    assert_eq!(score("jb", &beijing), None);
+
+    //zero initials
+    assert!(score("ax", &word("an1 xin1")).is_some());
+    assert!(score("yw", &word("yi4 wai4")).is_some());
+    assert!(score("eg", &word("e4 gui3")).is_some());
+
+    //tones
+    assert!(score("bei3j", &beijing).is_some());
+    assert!(score("bei4j", &beijing).is_none());
+    assert!(score("běij", &beijing).is_some());
+    assert!(score("bjīng", &beijing).is_some());
+    assert!(score("bjíng", &beijing).is_none());
+}
+
+#[test]
+fn pinyin_queries_try_every_split() {
+    use self::bpmf::data::{PINYIN_ASCII_SYLLABLES, PINYIN_ASCII_SYLLABLE_TREE};
+
+    let word =
+        |txt: &str| -> Vec<Syllable> { txt.split(' ').map(|s| s.parse().unwrap()).collect() };
+    let score = |query: &str, word: &[Syllable]| PinyinQuery::parse(query).unwrap().matches(word);
+    //the longest split is "xian", yet 西安 is found as "xi" "an"
+    assert_eq!(score("xian", &word("xi1 an1")), Some(100));
+    assert_eq!(score("xian", &word("xian1")), Some(100));
+    assert_eq!(score("xian", &word("xi1 an1 shi4")), Some(57));
+    assert_eq!(score("xi'an", &word("xian1")), None);
+    assert_eq!(score("xian1", &word("xi1 an1")), Some(100));
+    assert_eq!(score("xian4", &word("xi1 an1")), None);
+    assert_eq!(score("xīan", &word("xi1 an1")), Some(100));
+    assert_eq!(score("xian", &word("xin1 an1")), Some(80));
+    assert_eq!(score("xian", &word("xu1 an1")), None);
+    //"fangan" is fang'an or fan'gan
+    assert!(score("fangan", &word("fang1 an4")).is_some());
+    assert!(score("fangan", &word("fan3 gan3")).is_some());
+    assert!(score("fangan", &word("fang1")).is_none());
+
+    //the static tree knows the beginning of every syllable
+    let mut spellings: Vec<String> = MANDARIN_SOUNDS
+        .iter()
+        .map(|(bpmf, _)| {
+            let syl: Syllable = bpmf.parse().unwrap();
+            Syllable::new(syl.init(), syl.med(), syl.rime(), NoTone).to_ascii_pinyin()
+        })
+        .collect();
+    spellings.sort();
+    spellings.dedup();
+    let mut listed = PINYIN_ASCII_SYLLABLES.map(str::to_owned).to_vec();
+    listed.sort();
+    assert_eq!(listed, spellings);
+    for spelling in PINYIN_ASCII_SYLLABLES {
+        let tree = &PINYIN_ASCII_SYLLABLE_TREE;
+        assert!((0..=spelling.len()).all(|len| tree.has_prefix(&spelling[..len])));
+    }
+    assert!(!PINYIN_ASCII_SYLLABLE_TREE.has_prefix("fi"));
+    assert!(!PINYIN_ASCII_SYLLABLE_TREE.has_prefix("zhuangg"));
+}
+
+#[test]
+fn syllable_patterns_match_with_wildcards() {
+    let syl = |txt: &str| -> Syllable { txt.parse().unwrap() };
+    let matches = |pattern: &str, txt: &str| {
+        pattern
+            .parse::<SyllablePattern>()
+            .unwrap()
+            .matches(&syl(txt))
+    };
+    assert!(matches("zh?ng4", "zhong4"));
+    assert!(matches("zh?ng4", "zheng4"));
+    assert!(!matches("zh?ng4", "zhuang4"));
+    assert!(!matches("zh?ng4", "zhong1"));
+    assert!(matches("*ang", "zhuang1"));
+    assert!(matches("*ang", "ang2"));
+    assert!(!matches("*ang", "an2"));
+    assert!(matches("zh[ae]ng[34]", "zheng3"));
+    assert!(!matches("zh[ae]ng[34]", "zhong3"));
+    assert!(matches("zhāng|zhōng", "zhong1"));
+    assert!(!matches("zhāng|zhōng", "zhong2"));
+    assert!(matches("lü?", "lve4"));
+    assert!(matches("LV", "lv3"));
+    assert!(matches("*", "er5"));
+
+    //bopomofo
+    assert!(matches("ㄓ*ˇ", "zhuang3"));
+    assert!(matches("ㄓ*ˇ", "zhi3"));
+    assert!(!matches("ㄓ*ˇ", "zhi4"));
+    assert!(matches("?ㄨ?", "guo4"));
+    assert!(!matches("?ㄨ?", "gu4"));
+    assert!(matches("˙ㄇ?", "ma5"));
+    assert!(!matches("˙ㄇ?", "ma1"));
+    assert!(matches("ㄇㄚ[ˉˊ]", "ma2"));
+
+    //each alternative keeps its own tones
+    assert!(matches("zhang1|zheng4", "zhang1"));
+    assert!(matches("zhang1|zheng4", "zheng4"));
+    assert!(!matches("zhang1|zheng4", "zhang4"));
+    assert!(!matches("zhang1|zheng4", "zheng1"));
+    assert!(matches("ㄓㄤ|zh?ng4", "zhang3"));
+    assert!(!matches("ㄓㄤ|zh?ng4", "zhong3"));
+    let either =
+        "zhang1".parse::<SyllablePattern>().unwrap() | "zheng4".parse::<SyllablePattern>().unwrap();
+    assert_eq!(either, "zheng4 | zhang1".parse().unwrap());
+    assert!("zh[ang".parse::<SyllablePattern>().is_err());
+
+    let pattern = SyllablePattern::from_parts(&[Zhi, Chi, Shi], &[], &[Ang], &[Fall]);
+    assert!(pattern.matches(&syl("shuang4")));
+    assert!(!pattern.matches(&syl("shuang3")));
+    assert!(!pattern.matches(&syl("sang4")));
+    assert!(SyllablePattern::any().matches(&syl("a")));
+    assert!(!SyllablePattern::none().matches(&syl("a")));
+
+    let seq = SyllablePattern::parse_seq("zh?ng1 g*").unwrap();
+    let word = [syl("zhong1"), syl("guo2")];
+    assert!(SyllablePattern::matches_seq(&seq, &word));
+    assert!(!SyllablePattern::matches_seq(&seq, &word[..1]));
+}
+
+#[test]
+fn braille_round_trips() {
+    //o and e share a cell in the mainland system
+    let shared = ["˙ㄇㄜ", "˙ㄌㄛ", "ㄛ", "ㄛˊ", "ㄛˇ"];
+    for system in [BrailleSystem::Mainland, BrailleSystem::Taiwan] {
+        for (bpmf, _) in MANDARIN_SOUNDS {
+            let syl: Syllable = bpmf.parse().unwrap();
+            let shown = syl.display_as(Notation::Braille(system)).to_string();
+            let cells = match syl.to_braille(system) {
+                Some(cells) => cells,
+                //yo and yai have no cells
+                None if system == BrailleSystem::Mainland && syl.med() == Yi => {
+                    assert_eq!(shown, "");
+                    continue;
+                }
+                None => panic!("no cells for {}", bpmf),
+            };
+            assert_eq!(shown, cells);
+            let (back, rest) = Syllable::parse_braille(&cells, system).unwrap();
+            assert_eq!(rest, "");
+            if system == BrailleSystem::Taiwan || !shared.contains(&bpmf) {
+                assert_eq!(back, syl, "{} {}", bpmf, cells);
+            }
+        }
+    }
+
+    let parse_word = |mut txt: &str, system| {
+        let mut word = vec![];
+        while !txt.is_empty() {
+            let (syl, rest) = Syllable::parse_braille(txt, system).unwrap();
+            word.push(syl.to_ascii_pinyin());
+            txt = rest;
+        }
+        word
+    };
+    //the neutral tone has no cell in the mainland system
+    assert_eq!(
+        parse_word("⠌⠲⠁⠛⠕⠂⠙⠢", BrailleSystem::Mainland),
+        ["zhong1", "guo2", "de5"]
+    );
+    assert_eq!(
+        parse_word("⠁⠯⠄⠅⠒⠂⠙⠮⠁", BrailleSystem::Taiwan),
+        ["zhong1", "guo2", "de5"]
+    );
+    let xue = Syllable::parse_braille("⠓⠾⠂", BrailleSystem::Mainland)
+        .unwrap()
+        .0;
+    assert_eq!(xue.to_ascii_pinyin(), "xue2");
+    assert!(Syllable::parse_braille("⠃⠲", BrailleSystem::Mainland).is_err());
+    assert!(Syllable::parse_braille("zhong1", BrailleSystem::Mainland).is_err());
+}
+
+#[test]
+fn palladius_round_trips() {
+    use self::bpmf::palladius_word;
+    let toneless = |syl: Syllable| Syllable::new(syl.init(), syl.med(), syl.rime(), NoTone);
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        let spelling = syl.to_palladius();
+        assert_eq!(syl.display_as(Notation::Palladius).to_string(), spelling);
+        let (back, rest) = Syllable::parse_palladius(&spelling).unwrap();
+        assert_eq!(rest, "");
+        //lo is spelt as luo
+        if bpmf != "˙ㄌㄛ" {
+            assert_eq!(back, toneless(syl), "{} {}", bpmf, spelling);
+        }
+    }
+
+    let word =
+        |txt: &str| -> Vec<Syllable> { txt.split(' ').map(|s| s.parse().unwrap()).collect() };
+    assert_eq!(palladius_word(&word("zhong1 guo2")), "чжунго");
+    assert_eq!(palladius_word(&word("ren2 min2")), "жэньминь");
+    assert_eq!(palladius_word(&word("chang2 an1")), "чанъань");
+    assert_eq!(palladius_word(&word("yan2 an1")), "яньань");
+    assert_eq!(palladius_word(&word("xi1 an1")), "сиань");
+    assert_eq!(palladius_word(&word("zi4 ji3")), "цзыцзи");
+
+    let mut txt = "Чанъань";
+    let mut syllables = vec![];
+    while !txt.is_empty() {
+        let (syl, rest) = Syllable::parse_palladius(txt).unwrap();
+        syllables.push(syl.to_ascii_pinyin());
+        txt = rest;
+    }
+    assert_eq!(syllables, ["chang", "an"]);
+    assert!(Syllable::parse_palladius("zhong").is_err());
+    assert!(Syllable::parse_palladius("ъ").is_err());
+}
+
+#[test]
+fn hangul_and_katakana_transcriptions() {
+    let word = |txt: &str, to: fn(&Syllable) -> String| -> String {
+        txt.split(' ').map(|s| to(&s.parse().unwrap())).collect()
+    };
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        assert!(!syl.to_hangul().is_empty(), "{}", bpmf);
+        assert!(!syl.to_katakana().is_empty(), "{}", bpmf);
+        let hangul = syl.display_as(Notation::Hangul).to_string();
+        let katakana = syl.display_as(Notation::Katakana).to_string();
+        assert_eq!((hangul, katakana), (syl.to_hangul(), syl.to_katakana()));
+    }
+    assert!(Syllable::default().to_hangul().is_empty());
+    //they are written only
+    assert!(Notation::Hangul.parse("중").is_err());
+    assert!(Notation::Katakana.parse("チョン").is_err());
+    assert!(!Notation::ALL.contains(&Notation::Hangul));
+
+    assert_eq!(word("zhong1 guo2", Syllable::to_hangul), "중궈");
+    assert_eq!(word("bei3 jing1", Syllable::to_hangul), "베이징");
+    assert_eq!(word("si4 chuan1", Syllable::to_hangul), "쓰촨");
+    assert_eq!(word("mao2 ze2 dong1", Syllable::to_hangul), "마오쩌둥");
+    assert_eq!(word("tian1 an1 men2", Syllable::to_hangul), "톈안먼");
+    //no y after ㅈ ㅉ ㅊ
+    assert_eq!(
+        word("jia1 jie2 jiu3 jiong3", Syllable::to_hangul),
+        "자제주중"
+    );
+    assert_eq!(
+        word("xue2 yuan4 wei4 gui4", Syllable::to_hangul),
+        "쉐위안웨이구이"
+    );
+
+    assert_eq!(word("zhong1 guo2", Syllable::to_katakana), "チョンクオ");
+    assert_eq!(word("bei3 jing1", Syllable::to_katakana), "ペイチン");
+    assert_eq!(
+        word("mao2 ze2 dong1", Syllable::to_katakana),
+        "マオツォートン"
+    );
+    assert_eq!(
+        word("deng4 xiao3 ping2", Syllable::to_katakana),
+        "トンシャオピン"
+    );
+    assert_eq!(
+        word("zhou1 en1 lai2", Syllable::to_katakana),
+        "チョウエンライ"
+    );
+    assert_eq!(word("shang4 hai3", Syllable::to_katakana), "シャンハイ");
+}
+
+#[test]
+fn syllable_literals_are_parsed_at_compile_time() {
+    const CHINA: [Syllable; 2] = [syl!("ㄓㄨㄥ"), syl!(guo2)];
+    const NEW: Syllable = Syllable::new(Zhi, Wu, Eng, Level);
+    const INIT: Init = CHINA[1].init();
+    assert_eq!(CHINA[0], NEW);
+    assert_eq!(INIT, Ge);
+    assert_eq!(syl!("zhōng"), NEW);
+    assert_eq!(syl!(zhong1), NEW);
+    assert_eq!(syl!(" ˙ㄇㄚ "), syl!(ma));
+    assert_eq!(syl!(ma), Syllable::new(Mo, NoMed, A, Neut));
+    assert_eq!(syl!(lv3), "lǚ".parse().unwrap());
+
+    for (bpmf, py) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        for txt in [bpmf.to_string(), py.to_string(), syl.to_ascii_pinyin()] {
+            let exact = Syllable::parse_any_exact(&txt).unwrap().0;
+            assert_eq!(Syllable::parse_literal(&txt).unwrap(), exact, "{}", txt);
+        }
+    }
+    for txt in ["", "zhongg1", "zhong6", "ㄅㄧㄜ", "ㄓ ㄨ", "ㄅ", "bio", "x"] {
+        assert!(Syllable::parse_literal(txt).is_err(), "{}", txt);
+    }
+    //what parse_any_exact takes on top
+    for txt in ["ㄅㄧㄜ", "⠌⠲⠁", "чжун", "\u{3000}zhong1"] {
+        assert!(Syllable::parse_any_exact(txt).is_ok(), "{}", txt);
+        assert!(Syllable::parse_literal(txt).is_err(), "{}", txt);
+    }
+}
+
+#[test]
+fn syllables_are_checked_when_built_from_raw_parts() {
+    assert_eq!(core::mem::size_of::<Syllable>(), 4);
+    assert_eq!(core::mem::size_of::<Option<Syllable>>(), 4);
+    assert_eq!(
+        Syllable::from_parts_checked(15, 2, 12, 1),
+        Ok(Syllable::new(Zhi, Wu, Eng, Level))
+    );
+    for value in 0..=u8::MAX {
+        let ok = Syllable::from_parts_checked(value, 0, 0, 0).is_ok();
+        assert_eq!(ok, value <= Si as u8, "{}", value);
+        let ok = Syllable::from_parts_checked(0, value, 0, 0).is_ok();
+        assert_eq!(ok, value <= Yu as u8, "{}", value);
+        let ok = Syllable::from_parts_checked(0, 0, value, 0).is_ok();
+        assert_eq!(ok, value <= Er as u8, "{}", value);
+        let ok = Syllable::from_parts_checked(0, 0, 0, value).is_ok();
+        assert_eq!(ok, value <= Neut as u8, "{}", value);
+    }
+}
+
+#[test]
+fn no_invalid_syllable_comes_out_of_the_public_api() {
+    let in_range = |syl: Syllable| {
+        let raw = (
+            syl.init() as u8,
+            syl.med() as u8,
+            syl.rime() as u8,
+            syl.tone() as u8,
+        );
+        assert_eq!(
+            Syllable::from_parts_checked(raw.0, raw.1, raw.2, raw.3),
+            Ok(syl)
+        );
+        assert_eq!(Syllable::from_ord(syl.ord()), Some(syl));
+    };
+    fn parse(txt: &str, notation: Notation) -> Option<(String, &str)> {
+        let (syl, rest) = notation.parse(txt).ok()?;
+        Some((syl.to_ascii_pinyin(), rest))
+    }
+
+    //every prefix and suffix of the spellings of the syllables of Mandarin
+    let mut inputs: Vec<String> = vec![];
+    for (bpmf, py) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        let ascii = syl.to_ascii_pinyin();
+        for (txt, notation) in [
+            (bpmf, Notation::Bopomofo),
+            (py, Notation::Pinyin),
+            (&ascii, Notation::AsciiPinyin),
+        ] {
+            assert_eq!(notation.parse(txt).ok(), Some((syl, "")), "{}", txt);
+        }
+        let mut spellings = vec![bpmf.to_owned(), py.to_owned(), ascii, syl.to_palladius()];
+        spellings.extend(syl.to_braille(BrailleSystem::Mainland));
+        spellings.extend(syl.to_braille(BrailleSystem::Taiwan));
+        for spelling in spellings {
+            for (idx, _) in spelling.char_indices().skip(1) {
+                inputs.push(spelling[..idx].to_owned());
+                inputs.push(spelling[idx..].to_owned());
+            }
+        }
+    }
+    for txt in &inputs {
+        for notation in Notation::ALL {
+            if let Ok((syl, rest)) = notation.parse(txt) {
+                in_range(syl);
+                assert!(txt.ends_with(rest));
+            }
+        }
+        if let Ok(syl) = Syllable::parse_literal(txt) {
+            in_range(syl);
+        }
+    }
+
+    //junk, with the exact results
+    let none = None::<(&str, &str)>;
+    let cases = [
+        ("", Notation::Bopomofo, none),
+        (" '", Notation::Pinyin, none),
+        ("ㄅ", Notation::Bopomofo, none),
+        ("ㄭ", Notation::Bopomofo, none),
+        ("˙", Notation::Bopomofo, none),
+        ("ˊㄇㄚ", Notation::Bopomofo, none),
+        ("ㄇa", Notation::Bopomofo, none),
+        ("ㄓ5", Notation::Bopomofo, Some(("zhi1", "5"))),
+        ("ㄇㄚˊˊ", Notation::Bopomofo, Some(("ma2", "ˊ"))),
+        ("ㄧㄧ", Notation::Bopomofo, Some(("yi1", "ㄧ"))),
+        ("zh", Notation::Pinyin, none),
+        ("ng", Notation::Pinyin, none),
+        ("m2", Notation::AsciiPinyin, none),
+        ("w", Notation::AsciiPinyin, none),
+        ("ma˙", Notation::Pinyin, Some(("ma5", "˙"))),
+        ("yi6", Notation::Pinyin, Some(("yi5", "6"))),
+        ("zhi9", Notation::AsciiPinyin, Some(("zhi", "9"))),
+        ("v", Notation::AsciiPinyin, Some(("yu", ""))),
+        ("üe", Notation::Pinyin, Some(("yue5", ""))),
+        ("⠀", Notation::Braille(BrailleSystem::Mainland), none),
+        (
+            "⠁",
+            Notation::Braille(BrailleSystem::Taiwan),
+            Some(("zhi", "")),
+        ),
+        ("ъъ", Notation::Palladius, none),
+        ("Ы", Notation::Palladius, none),
+    ];
+    for (txt, notation, expected) in cases {
+        let expected = expected.map(|(syl, rest)| (syl.to_owned(), rest));
+        assert_eq!(parse(txt, notation), expected, "{} {:?}", txt, notation);
+        if expected.is_none() {
+            assert!(Syllable::parse_literal(txt).is_err(), "{}", txt);
+        }
+    }
+
+    for ord in 0..41 * 41 * 41 * 42 {
+        if let Some(syl) = Syllable::from_ord(ord) {
+            in_range(syl);
+        }
+    }
+    for init in 0..32 {
+        for med in 0..8 {
+            for rime in 0..16 {
+                for tone in 0..8 {
+                    if let Ok(syl) = Syllable::from_parts_checked(init, med, rime, tone) {
+                        in_range(syl);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn syllable_parts_convert_from_chars_and_names() {
+    assert_eq!(Init::ALL.len(), 22);
+    assert_eq!(
+        (Med::ALL[0], Rime::ALL[13], Tone::ALL[5]),
+        (NoMed, Er, Neut)
+    );
+    for (idx, init) in Init::ALL.into_iter().enumerate() {
+        assert_eq!(init as usize, idx);
+        assert_eq!(Init::from_pinyin(init.pinyin()), Some(init));
+        if init != NoInit {
+            assert_eq!(Init::try_from(char::from(init)), Ok(init));
+        }
+    }
+    for med in Med::ALL.into_iter().skip(1) {
+        assert_eq!(Med::try_from(char::from(med)), Ok(med));
+        assert_eq!(Med::from_pinyin(med.pinyin()), Some(med));
+    }
+    for rime in Rime::ALL.into_iter().skip(1) {
+        assert_eq!(Rime::try_from(char::from(rime)), Ok(rime));
+        assert_eq!(Rime::from_pinyin(rime.pinyin()), Some(rime));
+    }
+    for tone in [Level, Rise, Dip, Fall, Neut] {
+        assert_eq!(Tone::try_from(char::from(tone)), Ok(tone));
+        assert_eq!(Tone::from_pinyin(&(tone as u8).to_string()), Some(tone));
+    }
+    assert_eq!(Init::try_from('ㄓ'), Ok(Zhi));
+    assert!(Init::try_from('ㄧ').is_err());
+    assert!(Rime::try_from('a').is_err());
+    assert_eq!(Init::from_pinyin("zh"), Some(Zhi));
+    assert_eq!(Init::from_pinyin("y"), None);
+    assert_eq!((Zhi.pinyin(), Zhi.ascii_name()), ("zh", "zhi"));
+    assert_eq!(
+        (Yu.pinyin(), Yu.ascii_name(), Med::from_pinyin("v")),
+        ("ü", "yu", Some(Yu))
+    );
+    assert_eq!((Eh.pinyin(), Eh.ascii_name()), ("ê", "eh"));
+    assert_eq!((Ang.pinyin(), Rime::from_pinyin("ang")), ("ang", Some(Ang)));
+    assert_eq!(
+        (format!("a{}", Dip.pinyin()), Dip.ascii_name()),
+        ("a\u{30C}".into(), "dip")
+    );
+    assert_eq!(Tone::from_pinyin("\u{301}"), Some(Rise));
+}
+
+#[test]
+fn syllable_chart_lists_every_syllable_once() {
+    use chart::{ChartOptions, SyllableChart};
+    let chart = SyllableChart::new();
+    assert_eq!((chart.initials.len(), chart.finals.len()), (22, 39));
+    let mut toneless: Vec<Syllable> = MANDARIN_SOUNDS
+        .iter()
+        .map(|(bpmf, _)| {
+            let syl: Syllable = bpmf.parse().unwrap();
+            Syllable::new(syl.init(), syl.med(), syl.rime(), NoTone)
+        })
+        .collect();
+    toneless.sort();
+    toneless.dedup();
+    let cells: Vec<Syllable> = chart.cells.iter().flatten().flatten().copied().collect();
+    //ê alone isn't in the resources
+    assert_eq!(cells.len(), toneless.len() + 1);
+    assert!(cells
+        .iter()
+        .all(|syl| syl.is_valid() && syl.tone() == NoTone));
+    for syl in toneless {
+        assert_eq!(chart.cell(syl.init(), syl.final_().unwrap()), Some(syl));
+    }
+    assert_eq!(chart.cell(Bo, Final::V), None);
+
+    let options = ChartOptions::default();
+    let csv = chart.to_csv(&options);
+    assert!(csv.starts_with(",-i,a,o,e,ê,ai,"));
+    assert!(csv.contains("\nzh,zhi,zha,,zhe,"));
+    assert_eq!(csv.lines().count(), 23);
+    assert!(csv.lines().all(|line| line.split(',').count() == 40));
+    let options = ChartOptions {
+        header: Notation::AsciiPinyin,
+        cells: Notation::AsciiPinyin,
+    };
+    assert!(chart.to_csv(&options).starts_with(",-i,a,o,e,eh,ai,"));
+    let options = ChartOptions {
+        header: Notation::Bopomofo,
+        cells: Notation::AsciiPinyin,
+    };
+    let markdown = chart.to_markdown(&options);
+    assert!(markdown.starts_with("| | ㄭ | ㄚ |"));
+    assert!(markdown.contains("\n| ㄌ |  | la | lo | le |"));
+    assert!(markdown.contains(" lv | lve | lvan |"));
+    let html = chart.to_html(&options);
+    assert!(html.starts_with("<table class=\"syllable-chart\">\n<thead><tr><th></th><th>ㄭ</th>"));
+    assert!(html.contains("<tr><th>ㄓ</th><td>zhi</td><td>zha</td><td></td><td>zhe</td>"));
+    assert!(html.ends_with("</tbody>\n</table>\n"));
+}
+
+#[test]
+fn finals_convert_to_parts_and_spellings() {
+    for (idx, fin) in Final::ALL.into_iter().enumerate() {
+        assert_eq!(fin as usize, idx);
+        let (med, rime) = fin.parts();
+        assert_eq!(Final::try_from((med, rime)), Ok(fin));
+        assert_eq!(<(Med, Rime)>::from(fin), (med, rime));
+    }
+    assert_eq!(Final::from_parts(Yu, A), None);
+    assert!(Final::try_from((Wu, Eh)).is_err());
+
+    for (bpmf, _) in MANDARIN_SOUNDS {
+        let syl: Syllable = bpmf.parse().unwrap();
+        let syl = Syllable::new(syl.init(), syl.med(), syl.rime(), NoTone);
+        let fin = syl.final_().unwrap();
+        assert_eq!(Syllable::from_initial_final(syl.init(), fin, NoTone), syl);
+        assert_eq!(
+            Syllable::from_initial_final_checked(syl.init(), fin, NoTone),
+            Ok(syl)
+        );
+        let pinyin = syl.to_pinyin();
+        match syl.init() {
+            NoInit => assert_eq!(fin.pinyin_alone(), Some(pinyin.as_str())),
+            //ü is written u after j, q, x
+            Ji | Qi | Xi if syl.med() == Yu => {}
+            init => assert_eq!([init.pinyin(), fin.pinyin()].concat(), pinyin),
+        }
+        let bopomofo = syl.to_string();
+        assert_eq!(bopomofo.trim_start_matches(syl.init_char()), fin.bopomofo());
+    }
+    assert!(Syllable::from_initial_final_checked(Bo, Final::V, Level).is_err());
+    assert!(Syllable::from_initial_final_checked(NoInit, Final::Ii, Level).is_err());
+    assert_eq!(Final::Ii.pinyin_alone(), None);
+    assert_eq!(
+        (Final::Ueng.pinyin(), Final::Ueng.pinyin_alone()),
+        ("ong", Some("weng"))
+    );
+    assert_eq!((Final::Iou.pinyin(), Final::Iou.bopomofo()), ("iu", "ㄧㄡ"));
+    assert_eq!(syl!(jue2).final_(), Some(Final::Ve));
+    assert_eq!(Syllable::new(Bo, Yu, A, Level).final_(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[allow(deprecated)]
+fn deprecated_initial_maps_agree_with_the_const_tables() {
+    use self::bpmf::data::*;
+    let to_pinyin = get_init_bopomofo_to_pinyin_map();
+    assert_eq!(to_pinyin.len(), INIT_BOPOMOFO_TO_PINYIN.len());
+    assert_eq!(to_pinyin[&'ㄓ'], "zh");
+    let to_bopomofo = get_init_pinyin_to_bopomofo_map();
+    assert_eq!(to_bopomofo.len(), 18);
+    for letter in 'a'..='z' {
+        assert_eq!(
+            to_bopomofo.get(&letter).copied(),
+            init_pinyin_to_bopomofo(letter)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 
-pub const MANDARIN_SOUNDS: [(&str, &str); 1335] = [
+pub static MANDARIN_SOUNDS: [(&str, &str); 1335] = [
     ("ㄅㄚ", "bā"),
     ("ㄅㄚˊ", "bá"),
     ("ㄅㄚˇ", "bǎ"),
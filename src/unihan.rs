@@ -0,0 +1,34 @@
+//! Readings of Han characters, looked up in a table generated by `examples/gen_unihan.rs`.
+//! The table is embedded in the crate, it needs no file nor network access.
+//!
+//! The table shipped is generated from `pinyin.txt` of pinyin-data 0.13.0, which merges the
+//! Unihan kMandarin, kHanyuPinyin and kXHC1983 fields with other sources, see the header of
+//! `src/unihan/table.rs`. So it isn't Unihan as such: it has readings Unihan lacks and the
+//! readings are in the order of pinyin-data. Generated from `Unihan_Readings.txt` instead,
+//! the readings of kMandarin come first, then those of kXHC1983 and kHanyuPinyin.
+use crate::bpmf::Syllable;
+
+mod table;
+use table::{CHARS, LISTS, LIST_IDS, READINGS};
+
+/// Builds the syllables of the generated table
+const fn s(init: u8, med: u8, rime: u8, tone: u8) -> Syllable {
+    Syllable::_from_raw(init, med, rime, tone)
+}
+
+/// All the known readings of the char in the order of the source of the table (see above),
+/// which puts the customary reading first. Empty for the chars that aren't in the table
+pub fn readings(ch: char) -> &'static [Syllable] {
+    match CHARS.binary_search(&ch) {
+        Ok(idx) => {
+            let list = LIST_IDS[idx] as usize;
+            &READINGS[LISTS[list] as usize..LISTS[list + 1] as usize]
+        }
+        Err(_) => &[],
+    }
+}
+
+/// The first of `readings`, the customary reading of the char
+pub fn reading(ch: char) -> Option<Syllable> {
+    readings(ch).first().copied()
+}
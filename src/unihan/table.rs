@@ -0,0 +1,14325 @@
+//! Generated by `cargo run --example gen_unihan`, do not edit.
+//!
+//! version: 0.13.0
+//! source: https://github.com/mozillazg/pinyin-data
+//! Derived from pinyin-data, a merge of the Unihan kMandarin, kHanyuPinyin and kXHC1983
+//! fields with a few others, MIT License, Copyright (c) 2016 mozillazg
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use super::s;
+use crate::bpmf::Syllable;
+
+pub(super) static CHARS: [char; 41494] = [
+    '〇', '㐀', '㐁', '㐄', '㐅', '㐆', '㐌', '㐖', '㐜', '㐡', '㐤', '㐨', '㐩', '㐫', '㐬', '㐭',
+    '㐮', '㐯', '㐰', '㐱', '㐲', '㐳', '㐴', '㐵', '㐷', '㐸', '㐹', '㐺', '㐻', '㐼', '㐽', '㑁',
+    '㑂', '㑃', '㑄', '㑅', '㑇', '㑈', '㑉', '㑊', '㑋', '㑌', '㑍', '㑎', '㑏', '㑐', '㑔', '㑗',
+    '㑘', '㑙', '㑚', '㑛', '㑜', '㑝', '㑞', '㑟', '㑣', '㑤', '㑥', '㑦', '㑧', '㑨', '㑩', '㑪',
+    '㑮', '㑯', '㑰', '㑱', '㑳', '㑵', '㑶', '㑷', '㑸', '㑹', '㑺', '㑻', '㑼', '㑽', '㑾', '㑿',
+    '㒀', '㒁', '㒂', '㒃', '㒄', '㒅', '㒆', '㒇', '㒈', '㒉', '㒊', '㒋', '㒌', '㒍', '㒎', '㒑',
+    '㒒', '㒓', '㒔', '㒕', '㒖', '㒗', '㒙', '㒚', '㒛', '㒜', '㒝', '㒞', '㒟', '㒠', '㒡', '㒤',
+    '㒥', '㒦', '㒧', '㒩', '㒫', '㒰', '㒲', '㒳', '㒴', '㒵', '㒷', '㒸', '㒻', '㒼', '㒽', '㒾',
+    '㒿', '㓁', '㓂', '㓃', '㓄', '㓅', '㓈', '㓉', '㓊', '㓋', '㓌', '㓎', '㓏', '㓐', '㓑', '㓓',
+    '㓔', '㓖', '㓗', '㓘', '㓚', '㓜', '㓝', '㓞', '㓟', '㓠', '㓡', '㓢', '㓣', '㓤', '㓥', '㓦',
+    '㓧', '㓨', '㓩', '㓪', '㓭', '㓮', '㓯', '㓰', '㓱', '㓲', '㓳', '㓴', '㓵', '㓶', '㓷', '㓸',
+    '㓹', '㓺', '㓼', '㓽', '㓾', '㔀', '㔁', '㔂', '㔃', '㔄', '㔅', '㔆', '㔉', '㔊', '㔋', '㔌',
+    '㔍', '㔎', '㔏', '㔑', '㔒', '㔓', '㔕', '㔗', '㔘', '㔙', '㔚', '㔜', '㔝', '㔞', '㔠', '㔡',
+    '㔢', '㔣', '㔤', '㔥', '㔦', '㔧', '㔨', '㔩', '㔪', '㔭', '㔮', '㔯', '㔰', '㔱', '㔳', '㔴',
+    '㔵', '㔶', '㔷', '㔸', '㔹', '㔺', '㔼', '㔽', '㔾', '㔿', '㕁', '㕂', '㕃', '㕄', '㕅', '㕆',
+    '㕇', '㕈', '㕉', '㕊', '㕋', '㕌', '㕎', '㕏', '㕐', '㕑', '㕒', '㕕', '㕖', '㕗', '㕙', '㕚',
+    '㕛', '㕜', '㕞', '㕟', '㕠', '㕡', '㕢', '㕣', '㕤', '㕥', '㕦', '㕧', '㕨', '㕩', '㕪', '㕫',
+    '㕬', '㕭', '㕮', '㕯', '㕰', '㕱', '㕲', '㕴', '㕵', '㕷', '㕸', '㕹', '㕺', '㕻', '㕽', '㖀',
+    '㖁', '㖂', '㖃', '㖅', '㖆', '㖇', '㖊', '㖏', '㖐', '㖑', '㖒', '㖓', '㖔', '㖕', '㖖', '㖗',
+    '㖘', '㖞', '㖟', '㖠', '㖡', '㖢', '㖣', '㖤', '㖥', '㖦', '㖧', '㖨', '㖩', '㖪', '㖫', '㖭',
+    '㖮', '㖵', '㖶', '㖷', '㖸', '㖹', '㖺', '㖻', '㖼', '㖽', '㖾', '㖿', '㗀', '㗁', '㗂', '㗃',
+    '㗄', '㗅', '㗆', '㗇', '㗈', '㗉', '㗊', '㗋', '㗌', '㗏', '㗑', '㗒', '㗓', '㗔', '㗕', '㗖',
+    '㗗', '㗘', '㗙', '㗚', '㗛', '㗜', '㗢', '㗣', '㗤', '㗥', '㗦', '㗧', '㗨', '㗩', '㗫', '㗬',
+    '㗭', '㗰', '㗱', '㗲', '㗳', '㗴', '㗵', '㗶', '㗷', '㗻', '㗼', '㗽', '㗾', '㗿', '㘀', '㘁',
+    '㘂', '㘄', '㘅', '㘆', '㘇', '㘈', '㘉', '㘊', '㘋', '㘌', '㘍', '㘎', '㘐', '㘑', '㘓', '㘔',
+    '㘕', '㘖', '㘗', '㘙', '㘚', '㘛', '㘜', '㘝', '㘞', '㘠', '㘡', '㘢', '㘣', '㘤', '㘥', '㘦',
+    '㘧', '㘨', '㘩', '㘪', '㘫', '㘬', '㘭', '㘰', '㘱', '㘲', '㘴', '㘵', '㘶', '㘷', '㘸', '㘹',
+    '㘺', '㘿', '㙀', '㙁', '㙂', '㙃', '㙄', '㙅', '㙇', '㙈', '㙉', '㙊', '㙍', '㙎', '㙏', '㙐',
+    '㙑', '㙒', '㙓', '㙔', '㙕', '㙗', '㙘', '㙙', '㙚', '㙛', '㙜', '㙝', '㙞', '㙟', '㙠', '㙡',
+    '㙢', '㙣', '㙤', '㙥', '㙦', '㙨', '㙩', '㙪', '㙫', '㙬', '㙮', '㙯', '㙰', '㙱', '㙲', '㙳',
+    '㙴', '㙵', '㙶', '㙷', '㙸', '㙹', '㙺', '㙻', '㙼', '㙾', '㚀', '㚁', '㚂', '㚃', '㚅', '㚆',
+    '㚇', '㚉', '㚊', '㚋', '㚌', '㚍', '㚏', '㚐', '㚒', '㚓', '㚔', '㚕', '㚖', '㚗', '㚘', '㚙',
+    '㚚', '㚛', '㚜', '㚝', '㚞', '㚟', '㚠', '㚡', '㚢', '㚣', '㚤', '㚥', '㚦', '㚧', '㚨', '㚩',
+    '㚪', '㚫', '㚬', '㚭', '㚯', '㚰', '㚱', '㚲', '㚴', '㚵', '㚶', '㚷', '㚹', '㚺', '㚻', '㚽',
+    '㚾', '㚿', '㛀', '㛁', '㛂', '㛃', '㛄', '㛅', '㛆', '㛊', '㛍', '㛎', '㛏', '㛐', '㛑', '㛒',
+    '㛓', '㛔', '㛕', '㛖', '㛗', '㛘', '㛙', '㛚', '㛛', '㛜', '㛝', '㛞', '㛣', '㛤', '㛥', '㛦',
+    '㛧', '㛨', '㛩', '㛪', '㛫', '㛬', '㛭', '㛮', '㛯', '㛱', '㛲', '㛳', '㛴', '㛵', '㛶', '㛷',
+    '㛸', '㛹', '㛺', '㛻', '㛼', '㛾', '㛿', '㜂', '㜃', '㜄', '㜅', '㜆', '㜇', '㜈', '㜉', '㜊',
+    '㜋', '㜌', '㜍', '㜎', '㜏', '㜐', '㜑', '㜒', '㜓', '㜕', '㜖', '㜗', '㜘', '㜙', '㜚', '㜛',
+    '㜜', '㜝', '㜞', '㜠', '㜡', '㜣', '㜤', '㜥', '㜦', '㜧', '㜨', '㜩', '㜪', '㜫', '㜮', '㜯',
+    '㜰', '㜱', '㜲', '㜳', '㜴', '㜵', '㜶', '㜷', '㜸', '㜹', '㜺', '㜻', '㜼', '㜽', '㜾', '㜿',
+    '㝀', '㝁', '㝂', '㝃', '㝄', '㝅', '㝇', '㝈', '㝉', '㝊', '㝋', '㝌', '㝍', '㝎', '㝏', '㝐',
+    '㝑', '㝓', '㝔', '㝕', '㝖', '㝗', '㝘', '㝙', '㝚', '㝛', '㝝', '㝞', '㝟', '㝠', '㝡', '㝢',
+    '㝣', '㝤', '㝥', '㝦', '㝧', '㝩', '㝪', '㝫', '㝭', '㝮', '㝯', '㝰', '㝱', '㝲', '㝴', '㝵',
+    '㝶', '㝸', '㝹', '㝺', '㝻', '㝼', '㝽', '㝾', '㝿', '㞀', '㞁', '㞂', '㞃', '㞄', '㞅', '㞆',
+    '㞇', '㞈', '㞉', '㞊', '㞋', '㞌', '㞎', '㞏', '㞐', '㞑', '㞒', '㞓', '㞔', '㞕', '㞖', '㞗',
+    '㞘', '㞙', '㞚', '㞛', '㞜', '㞞', '㞟', '㞠', '㞡', '㞤', '㞥', '㞦', '㞧', '㞨', '㞩', '㞪',
+    '㞫', '㞬', '㞭', '㞯', '㞰', '㞱', '㞲', '㞳', '㞴', '㞶', '㞷', '㞸', '㞹', '㞺', '㞻', '㞼',
+    '㞽', '㞾', '㞿', '㟀', '㟁', '㟂', '㟃', '㟄', '㟅', '㟆', '㟇', '㟈', '㟉', '㟊', '㟋', '㟌',
+    '㟍', '㟎', '㟏', '㟐', '㟑', '㟒', '㟓', '㟔', '㟖', '㟗', '㟘', '㟙', '㟚', '㟛', '㟜', '㟝',
+    '㟞', '㟟', '㟠', '㟢', '㟤', '㟦', '㟧', '㟨', '㟩', '㟪', '㟫', '㟬', '㟭', '㟮', '㟰', '㟱',
+    '㟲', '㟳', '㟴', '㟵', '㟶', '㟷', '㟹', '㟺', '㟻', '㟼', '㟽', '㟾', '㟿', '㠀', '㠂', '㠄',
+    '㠅', '㠆', '㠇', '㠈', '㠉', '㠊', '㠋', '㠌', '㠍', '㠎', '㠏', '㠐', '㠑', '㠒', '㠓', '㠔',
+    '㠕', '㠖', '㠗', '㠘', '㠙', '㠚', '㠛', '㠜', '㠝', '㠟', '㠠', '㠡', '㠢', '㠣', '㠥', '㠦',
+    '㠧', '㠨', '㠪', '㠭', '㠮', '㠯', '㠱', '㠲', '㠴', '㠵', '㠶', '㠷', '㠸', '㠹', '㠺', '㠼',
+    '㠽', '㠾', '㠿', '㡀', '㡁', '㡂', '㡃', '㡄', '㡅', '㡇', '㡈', '㡉', '㡊', '㡋', '㡌', '㡏',
+    '㡑', '㡒', '㡓', '㡔', '㡕', '㡖', '㡗', '㡘', '㡙', '㡚', '㡛', '㡜', '㡝', '㡞', '㡟', '㡠',
+    '㡡', '㡢', '㡣', '㡥', '㡦', '㡧', '㡨', '㡩', '㡪', '㡫', '㡭', '㡮', '㡯', '㡰', '㡱', '㡲',
+    '㡳', '㡴', '㡵', '㡶', '㡷', '㡸', '㡹', '㡺', '㡻', '㡼', '㡽', '㡾', '㡿', '㢀', '㢁', '㢂',
+    '㢃', '㢄', '㢅', '㢆', '㢈', '㢉', '㢊', '㢋', '㢍', '㢎', '㢏', '㢑', '㢒', '㢓', '㢔', '㢖',
+    '㢗', '㢘', '㢙', '㢚', '㢛', '㢜', '㢝', '㢞', '㢟', '㢠', '㢡', '㢣', '㢥', '㢧', '㢨', '㢩',
+    '㢬', '㢮', '㢯', '㢰', '㢲', '㢳', '㢵', '㢶', '㢸', '㢺', '㢻', '㢼', '㢽', '㢾', '㣀', '㣁',
+    '㣂', '㣃', '㣄', '㣅', '㣆', '㣇', '㣈', '㣌', '㣍', '㣎', '㣏', '㣐', '㣑', '㣒', '㣓', '㣔',
+    '㣙', '㣚', '㣛', '㣜', '㣝', '㣞', '㣟', '㣠', '㣢', '㣣', '㣤', '㣥', '㣦', '㣧', '㣪', '㣫',
+    '㣬', '㣭', '㣯', '㣰', '㣱', '㣲', '㣵', '㣶', '㣷', '㣺', '㣻', '㣼', '㣽', '㣾', '㣿', '㤁',
+    '㤂', '㤃', '㤄', '㤅', '㤆', '㤇', '㤈', '㤉', '㤊', '㤋', '㤌', '㤍', '㤎', '㤏', '㤐', '㤑',
+    '㤒', '㤓', '㤔', '㤕', '㤖', '㤘', '㤚', '㤛', '㤜', '㤝', '㤞', '㤟', '㤠', '㤡', '㤢', '㤤',
+    '㤥', '㤦', '㤧', '㤨', '㤩', '㤪', '㤫', '㤬', '㤮', '㤯', '㤰', '㤱', '㤲', '㤳', '㤴', '㤵',
+    '㤶', '㤷', '㤸', '㤹', '㤺', '㤽', '㤾', '㤿', '㥀', '㥁', '㥂', '㥃', '㥄', '㥅', '㥆', '㥇',
+    '㥈', '㥉', '㥊', '㥋', '㥌', '㥍', '㥎', '㥏', '㥐', '㥒', '㥓', '㥔', '㥕', '㥖', '㥚', '㥛',
+    '㥜', '㥝', '㥞', '㥟', '㥠', '㥡', '㥢', '㥣', '㥥', '㥦', '㥧', '㥨', '㥩', '㥪', '㥬', '㥭',
+    '㥮', '㥯', '㥰', '㥱', '㥲', '㥳', '㥴', '㥵', '㥶', '㥷', '㥸', '㥹', '㥺', '㥼', '㥽', '㥾',
+    '㥿', '㦀', '㦁', '㦂', '㦃', '㦄', '㦅', '㦆', '㦇', '㦉', '㦊', '㦋', '㦌', '㦍', '㦎', '㦏',
+    '㦐', '㦑', '㦒', '㦓', '㦔', '㦕', '㦖', '㦗', '㦘', '㦚', '㦛', '㦜', '㦝', '㦞', '㦟', '㦡',
+    '㦢', '㦣', '㦤', '㦥', '㦦', '㦧', '㦨', '㦩', '㦪', '㦫', '㦬', '㦭', '㦮', '㦯', '㦰', '㦱',
+    '㦴', '㦵', '㦶', '㦷', '㦸', '㦹', '㦺', '㦻', '㦼', '㦽', '㦾', '㦿', '㧀', '㧁', '㧂', '㧃',
+    '㧄', '㧅', '㧆', '㧇', '㧈', '㧉', '㧊', '㧋', '㧌', '㧍', '㧎', '㧏', '㧐', '㧑', '㧒', '㧓',
+    '㧔', '㧕', '㧖', '㧗', '㧘', '㧙', '㧚', '㧜', '㧟', '㧡', '㧢', '㧣', '㧤', '㧥', '㧦', '㧨',
+    '㧩', '㧪', '㧫', '㧬', '㧭', '㧮', '㧯', '㧰', '㧱', '㧲', '㧳', '㧴', '㧵', '㧶', '㧷', '㧸',
+    '㧹', '㧺', '㧻', '㧼', '㧽', '㧾', '㨀', '㨁', '㨂', '㨃', '㨄', '㨅', '㨆', '㨇', '㨈', '㨉',
+    '㨊', '㨋', '㨌', '㨍', '㨎', '㨏', '㨐', '㨑', '㨒', '㨓', '㨔', '㨕', '㨖', '㨗', '㨘', '㨙',
+    '㨚', '㨛', '㨜', '㨝', '㨞', '㨟', '㨠', '㨡', '㨢', '㨤', '㨥', '㨦', '㨧', '㨨', '㨩', '㨪',
+    '㨫', '㨭', '㨮', '㨯', '㨰', '㨱', '㨲', '㨳', '㨴', '㨵', '㨶', '㨷', '㨸', '㨹', '㨺', '㨻',
+    '㨼', '㨽', '㨾', '㨿', '㩀', '㩁', '㩃', '㩄', '㩆', '㩇', '㩈', '㩉', '㩊', '㩋', '㩌', '㩍',
+    '㩎', '㩐', '㩒', '㩓', '㩔', '㩖', '㩗', '㩘', '㩙', '㩚', '㩛', '㩜', '㩝', '㩞', '㩟', '㩠',
+    '㩡', '㩢', '㩤', '㩦', '㩧', '㩨', '㩩', '㩪', '㩭', '㩮', '㩯', '㩰', '㩱', '㩲', '㩳', '㩴',
+    '㩵', '㩶', '㩷', '㩸', '㩹', '㩻', '㩼', '㩽', '㩾', '㩿', '㪀', '㪁', '㪂', '㪃', '㪄', '㪅',
+    '㪆', '㪇', '㪈', '㪉', '㪊', '㪋', '㪌', '㪍', '㪎', '㪏', '㪐', '㪑', '㪒', '㪓', '㪔', '㪕',
+    '㪖', '㪗', '㪘', '㪙', '㪚', '㪛', '㪜', '㪝', '㪞', '㪠', '㪡', '㪢', '㪣', '㪤', '㪥', '㪦',
+    '㪧', '㪨', '㪩', '㪪', '㪫', '㪬', '㪭', '㪮', '㪯', '㪱', '㪴', '㪵', '㪶', '㪷', '㪹', '㪺',
+    '㪻', '㪼', '㪽', '㪾', '㪿', '㫀', '㫁', '㫂', '㫃', '㫄', '㫅', '㫊', '㫍', '㫎', '㫏', '㫐',
+    '㫑', '㫒', '㫓', '㫔', '㫗', '㫘', '㫙', '㫚', '㫛', '㫜', '㫝', '㫟', '㫢', '㫤', '㫥', '㫦',
+    '㫧', '㫨', '㫫', '㫯', '㫰', '㫱', '㫲', '㫳', '㫵', '㫶', '㫷', '㫸', '㫹', '㫻', '㫼', '㫽',
+    '㬂', '㬃', '㬄', '㬅', '㬆', '㬇', '㬈', '㬉', '㬊', '㬋', '㬌', '㬍', '㬎', '㬏', '㬐', '㬒',
+    '㬓', '㬔', '㬕', '㬗', '㬘', '㬙', '㬚', '㬛', '㬜', '㬝', '㬞', '㬟', '㬠', '㬡', '㬣', '㬤',
+    '㬥', '㬦', '㬧', '㬩', '㬪', '㬫', '㬬', '㬭', '㬮', '㬯', '㬰', '㬱', '㬲', '㬳', '㬴', '㬵',
+    '㬶', '㬸', '㬹', '㬻', '㬼', '㬿', '㭁', '㭂', '㭄', '㭇', '㭈', '㭉', '㭋', '㭌', '㭎', '㭏',
+    '㭑', '㭒', '㭓', '㭔', '㭕', '㭘', '㭙', '㭚', '㭛', '㭜', '㭝', '㭞', '㭟', '㭠', '㭡', '㭢',
+    '㭤', '㭨', '㭩', '㭪', '㭫', '㭬', '㭭', '㭮', '㭯', '㭰', '㭱', '㭲', '㭴', '㭸', '㭹', '㭺',
+    '㭻', '㭼', '㭽', '㭾', '㭿', '㮀', '㮁', '㮂', '㮃', '㮄', '㮅', '㮆', '㮇', '㮈', '㮋', '㮌',
+    '㮏', '㮐', '㮑', '㮒', '㮓', '㮔', '㮕', '㮖', '㮗', '㮘', '㮙', '㮚', '㮛', '㮜', '㮝', '㮞',
+    '㮟', '㮡', '㮢', '㮣', '㮥', '㮦', '㮧', '㮨', '㮩', '㮪', '㮫', '㮬', '㮭', '㮮', '㮯', '㮰',
+    '㮱', '㮲', '㮳', '㮵', '㮶', '㮷', '㮸', '㮹', '㮺', '㮾', '㮿', '㯀', '㯁', '㯂', '㯃', '㯄',
+    '㯅', '㯆', '㯇', '㯈', '㯉', '㯊', '㯋', '㯌', '㯍', '㯎', '㯏', '㯐', '㯓', '㯔', '㯕', '㯖',
+    '㯗', '㯘', '㯙', '㯚', '㯛', '㯜', '㯝', '㯞', '㯟', '㯠', '㯡', '㯢', '㯤', '㯥', '㯦', '㯩',
+    '㯪', '㯫', '㯬', '㯭', '㯮', '㯯', '㯰', '㯱', '㯲', '㯳', '㯶', '㯷', '㯸', '㯹', '㯺', '㯻',
+    '㯽', '㯾', '㯿', '㰀', '㰁', '㰂', '㰃', '㰄', '㰅', '㰆', '㰈', '㰊', '㰋', '㰌', '㰍', '㰎',
+    '㰐', '㰑', '㰒', '㰔', '㰖', '㰗', '㰘', '㰙', '㰚', '㰛', '㰝', '㰞', '㰟', '㰠', '㰡', '㰢',
+    '㰣', '㰤', '㰥', '㰦', '㰧', '㰨', '㰩', '㰪', '㰫', '㰬', '㰭', '㰮', '㰯', '㰰', '㰱', '㰲',
+    '㰳', '㰴', '㰵', '㰶', '㰷', '㰸', '㰹', '㰺', '㰻', '㰼', '㰽', '㰾', '㰿', '㱀', '㱁', '㱂',
+    '㱃', '㱄', '㱅', '㱆', '㱇', '㱈', '㱉', '㱊', '㱋', '㱌', '㱍', '㱎', '㱏', '㱔', '㱖', '㱗',
+    '㱘', '㱙', '㱚', '㱛', '㱜', '㱞', '㱟', '㱠', '㱡', '㱢', '㱣', '㱤', '㱥', '㱦', '㱧', '㱨',
+    '㱩', '㱪', '㱫', '㱬', '㱭', '㱮', '㱯', '㱰', '㱱', '㱲', '㱳', '㱴', '㱵', '㱶', '㱸', '㱹',
+    '㱺', '㱻', '㱼', '㱽', '㱾', '㱿', '㲀', '㲁', '㲂', '㲃', '㲄', '㲅', '㲆', '㲈', '㲉', '㲊',
+    '㲋', '㲌', '㲍', '㲎', '㲏', '㲒', '㲓', '㲔', '㲕', '㲖', '㲗', '㲘', '㲚', '㲛', '㲜', '㲝',
+    '㲞', '㲟', '㲠', '㲡', '㲢', '㲤', '㲥', '㲦', '㲧', '㲨', '㲪', '㲫', '㲬', '㲭', '㲯', '㲰',
+    '㲱', '㲲', '㲳', '㲴', '㲶', '㲷', '㲸', '㲹', '㲺', '㲻', '㲼', '㲽', '㲾', '㲿', '㳀', '㳁',
+    '㳂', '㳃', '㳄', '㳅', '㳆', '㳇', '㳈', '㳊', '㳋', '㳌', '㳍', '㳎', '㳏', '㳐', '㳑', '㳒',
+    '㳔', '㳕', '㳖', '㳗', '㳘', '㳙', '㳚', '㳛', '㳜', '㳠', '㳡', '㳥', '㳦', '㳧', '㳨', '㳩',
+    '㳪', '㳫', '㳬', '㳭', '㳮', '㳴', '㳵', '㳶', '㳷', '㳸', '㳹', '㳺', '㳻', '㳼', '㳽', '㳾',
+    '㳿', '㴀', '㴁', '㴂', '㴃', '㴄', '㴆', '㴇', '㴈', '㴉', '㴋', '㴎', '㴏', '㴐', '㴑', '㴒',
+    '㴓', '㴔', '㴕', '㴖', '㴗', '㴘', '㴙', '㴚', '㴛', '㴜', '㴝', '㴥', '㴦', '㴧', '㴨', '㴩',
+    '㴪', '㴫', '㴬', '㴭', '㴮', '㴯', '㴰', '㴲', '㴳', '㴴', '㴵', '㴶', '㴷', '㴸', '㴼', '㴽',
+    '㴾', '㴿', '㵀', '㵂', '㵃', '㵄', '㵅', '㵆', '㵊', '㵋', '㵌', '㵍', '㵎', '㵏', '㵐', '㵑',
+    '㵒', '㵓', '㵔', '㵕', '㵖', '㵗', '㵘', '㵝', '㵞', '㵠', '㵡', '㵢', '㵣', '㵤', '㵥', '㵦',
+    '㵧', '㵨', '㵩', '㵪', '㵫', '㵬', '㵭', '㵮', '㵯', '㵰', '㵱', '㵲', '㵳', '㵴', '㵵', '㵶',
+    '㵷', '㵸', '㵹', '㵺', '㵻', '㵽', '㵾', '㵿', '㶀', '㶁', '㶄', '㶅', '㶆', '㶇', '㶈', '㶉',
+    '㶌', '㶍', '㶎', '㶏', '㶑', '㶒', '㶓', '㶔', '㶕', '㶖', '㶗', '㶘', '㶚', '㶛', '㶞', '㶟',
+    '㶠', '㶡', '㶣', '㶤', '㶥', '㶦', '㶧', '㶫', '㶬', '㶭', '㶯', '㶰', '㶲', '㶳', '㶴', '㶵',
+    '㶶', '㶹', '㶺', '㶼', '㶽', '㶾', '㶿', '㷀', '㷂', '㷃', '㷄', '㷅', '㷆', '㷇', '㷈', '㷉',
+    '㷊', '㷋', '㷍', '㷎', '㷏', '㷐', '㷒', '㷓', '㷔', '㷕', '㷖', '㷗', '㷘', '㷟', '㷠', '㷡',
+    '㷢', '㷣', '㷤', '㷦', '㷪', '㷫', '㷬', '㷮', '㷯', '㷰', '㷳', '㷴', '㷵', '㷶', '㷷', '㷸',
+    '㷹', '㷻', '㷼', '㷾', '㷿', '㸀', '㸁', '㸃', '㸄', '㸅', '㸆', '㸇', '㸉', '㸊', '㸋', '㸌',
+    '㸍', '㸎', '㸏', '㸐', '㸑', '㸒', '㸓', '㸕', '㸖', '㸗', '㸘', '㸙', '㸚', '㸛', '㸜', '㸝',
+    '㸞', '㸟', '㸠', '㸡', '㸢', '㸣', '㸤', '㸥', '㸦', '㸧', '㸨', '㸩', '㸪', '㸫', '㸬', '㸭',
+    '㸮', '㸯', '㸰', '㸱', '㸲', '㸳', '㸵', '㸶', '㸷', '㸸', '㸹', '㸺', '㸻', '㸽', '㸾', '㸿',
+    '㹀', '㹁', '㹂', '㹃', '㹄', '㹅', '㹆', '㹇', '㹈', '㹉', '㹊', '㹋', '㹌', '㹍', '㹎', '㹏',
+    '㹐', '㹑', '㹒', '㹓', '㹔', '㹕', '㹖', '㹗', '㹘', '㹙', '㹚', '㹛', '㹜', '㹝', '㹞', '㹟',
+    '㹠', '㹡', '㹢', '㹣', '㹤', '㹥', '㹦', '㹨', '㹫', '㹬', '㹭', '㹮', '㹱', '㹲', '㹳', '㹴',
+    '㹵', '㹶', '㹷', '㹸', '㹹', '㹺', '㹻', '㹼', '㹽', '㹾', '㹿', '㺀', '㺁', '㺂', '㺃', '㺄',
+    '㺅', '㺇', '㺈', '㺉', '㺊', '㺋', '㺌', '㺍', '㺎', '㺏', '㺐', '㺑', '㺒', '㺓', '㺔', '㺕',
+    '㺖', '㺗', '㺘', '㺚', '㺛', '㺜', '㺝', '㺞', '㺟', '㺠', '㺡', '㺢', '㺣', '㺤', '㺥', '㺦',
+    '㺨', '㺩', '㺪', '㺫', '㺬', '㺭', '㺮', '㺱', '㺲', '㺳', '㺴', '㺵', '㺷', '㺸', '㺹', '㺺',
+    '㺿', '㻀', '㻂', '㻃', '㻄', '㻅', '㻉', '㻊', '㻋', '㻌', '㻍', '㻎', '㻏', '㻑', '㻒', '㻓',
+    '㻔', '㻕', '㻖', '㻗', '㻝', '㻞', '㻟', '㻠', '㻡', '㻧', '㻪', '㻫', '㻬', '㻭', '㻮', '㻯',
+    '㻰', '㻱', '㻲', '㻵', '㻶', '㻷', '㻸', '㻹', '㻺', '㻽', '㻾', '㻿', '㼂', '㼇', '㼈', '㼉',
+    '㼊', '㼋', '㼌', '㼍', '㼎', '㼏', '㼐', '㼑', '㼒', '㼓', '㼔', '㼕', '㼖', '㼗', '㼘', '㼙',
+    '㼚', '㼛', '㼜', '㼝', '㼞', '㼟', '㼠', '㼡', '㼢', '㼣', '㼤', '㼥', '㼦', '㼧', '㼨', '㼩',
+    '㼪', '㼫', '㼬', '㼭', '㼮', '㼯', '㼰', '㼱', '㼲', '㼳', '㼴', '㼵', '㼶', '㼷', '㼸', '㼹',
+    '㼺', '㼻', '㼼', '㼽', '㼾', '㼿', '㽀', '㽁', '㽂', '㽃', '㽄', '㽆', '㽇', '㽈', '㽉', '㽊',
+    '㽋', '㽌', '㽎', '㽏', '㽑', '㽕', '㽖', '㽘', '㽙', '㽚', '㽛', '㽜', '㽝', '㽞', '㽟', '㽠',
+    '㽡', '㽢', '㽣', '㽤', '㽥', '㽦', '㽧', '㽨', '㽩', '㽪', '㽫', '㽬', '㽭', '㽯', '㽰', '㽱',
+    '㽲', '㽳', '㽴', '㽷', '㽸', '㽹', '㽺', '㽻', '㽽', '㽾', '㾀', '㾁', '㾂', '㾃', '㾄', '㾅',
+    '㾆', '㾇', '㾈', '㾉', '㾊', '㾋', '㾌', '㾍', '㾎', '㾏', '㾐', '㾑', '㾒', '㾓', '㾔', '㾕',
+    '㾖', '㾗', '㾘', '㾙', '㾚', '㾛', '㾜', '㾝', '㾞', '㾟', '㾠', '㾡', '㾢', '㾣', '㾤', '㾥',
+    '㾦', '㾧', '㾨', '㾩', '㾪', '㾫', '㾭', '㾮', '㾯', '㾰', '㾱', '㾴', '㾵', '㾶', '㾷', '㾸',
+    '㾹', '㾺', '㾻', '㾼', '㾽', '㾾', '㾿', '㿃', '㿄', '㿅', '㿆', '㿇', '㿉', '㿊', '㿋', '㿌',
+    '㿍', '㿎', '㿏', '㿑', '㿒', '㿓', '㿔', '㿕', '㿖', '㿗', '㿘', '㿙', '㿚', '㿛', '㿜', '㿞',
+    '㿟', '㿠', '㿢', '㿣', '㿤', '㿥', '㿦', '㿧', '㿨', '㿩', '㿪', '㿫', '㿬', '㿭', '㿮', '㿯',
+    '㿰', '㿱', '㿳', '㿴', '㿵', '㿶', '㿷', '㿸', '㿹', '㿺', '㿻', '㿼', '㿽', '㿾', '㿿', '䀀',
+    '䀁', '䀂', '䀃', '䀄', '䀅', '䀆', '䀇', '䀈', '䀉', '䀊', '䀋', '䀌', '䀍', '䀎', '䀏', '䀐',
+    '䀑', '䀒', '䀓', '䀔', '䀕', '䀖', '䀗', '䀘', '䀙', '䀚', '䀛', '䀜', '䀞', '䀟', '䀠', '䀡',
+    '䀢', '䀣', '䀤', '䀥', '䀦', '䀧', '䀨', '䀩', '䀪', '䀫', '䀬', '䀭', '䀮', '䀯', '䀰', '䀱',
+    '䀲', '䀳', '䀴', '䀵', '䀶', '䀷', '䀸', '䀹', '䀺', '䀻', '䀼', '䀽', '䀾', '䀿', '䁁', '䁂',
+    '䁃', '䁄', '䁅', '䁆', '䁇', '䁈', '䁉', '䁊', '䁋', '䁌', '䁍', '䁎', '䁏', '䁐', '䁑', '䁒',
+    '䁓', '䁔', '䁕', '䁖', '䁗', '䁘', '䁙', '䁚', '䁛', '䁜', '䁝', '䁞', '䁟', '䁠', '䁢', '䁣',
+    '䁤', '䁥', '䁦', '䁧', '䁨', '䁩', '䁪', '䁫', '䁬', '䁭', '䁮', '䁯', '䁰', '䁱', '䁲', '䁳',
+    '䁴', '䁵', '䁶', '䁷', '䁸', '䁹', '䁺', '䁻', '䁼', '䁽', '䁾', '䁿', '䂀', '䂁', '䂂', '䂃',
+    '䂄', '䂅', '䂆', '䂇', '䂈', '䂉', '䂊', '䂌', '䂍', '䂎', '䂏', '䂐', '䂑', '䂒', '䂔', '䂕',
+    '䂖', '䂗', '䂙', '䂚', '䂛', '䂜', '䂝', '䂞', '䂟', '䂠', '䂡', '䂢', '䂣', '䂤', '䂥', '䂦',
+    '䂧', '䂩', '䂪', '䂫', '䂬', '䂭', '䂮', '䂯', '䂰', '䂱', '䂲', '䂳', '䂴', '䂵', '䂶', '䂷',
+    '䂸', '䂹', '䂺', '䂻', '䂼', '䂽', '䂾', '䂿', '䃀', '䃁', '䃂', '䃅', '䃇', '䃈', '䃉', '䃊',
+    '䃋', '䃌', '䃍', '䃎', '䃏', '䃑', '䃒', '䃓', '䃔', '䃕', '䃖', '䃗', '䃘', '䃙', '䃚', '䃛',
+    '䃜', '䃝', '䃞', '䃠', '䃡', '䃢', '䃣', '䃤', '䃥', '䃦', '䃧', '䃩', '䃪', '䃫', '䃬', '䃭',
+    '䃮', '䃯', '䃰', '䃱', '䃲', '䃳', '䃴', '䃵', '䃶', '䃷', '䃸', '䃹', '䃺', '䃻', '䃽', '䃾',
+    '䃿', '䄀', '䄁', '䄂', '䄃', '䄄', '䄅', '䄆', '䄇', '䄈', '䄉', '䄋', '䄌', '䄍', '䄎', '䄏',
+    '䄐', '䄑', '䄒', '䄓', '䄔', '䄕', '䄘', '䄙', '䄚', '䄛', '䄜', '䄝', '䄟', '䄠', '䄡', '䄢',
+    '䄤', '䄥', '䄦', '䄧', '䄨', '䄩', '䄪', '䄫', '䄬', '䄭', '䄮', '䄯', '䄰', '䄱', '䄲', '䄳',
+    '䄶', '䄷', '䄸', '䄹', '䄺', '䄻', '䄼', '䄽', '䄾', '䄿', '䅀', '䅁', '䅂', '䅃', '䅄', '䅅',
+    '䅆', '䅇', '䅈', '䅉', '䅊', '䅋', '䅌', '䅍', '䅎', '䅏', '䅐', '䅑', '䅒', '䅓', '䅔', '䅕',
+    '䅖', '䅗', '䅘', '䅙', '䅚', '䅛', '䅜', '䅝', '䅞', '䅟', '䅠', '䅡', '䅢', '䅣', '䅤', '䅥',
+    '䅦', '䅧', '䅩', '䅪', '䅬', '䅭', '䅮', '䅯', '䅰', '䅱', '䅲', '䅳', '䅴', '䅵', '䅶', '䅷',
+    '䅸', '䅹', '䅺', '䅻', '䅼', '䅽', '䅾', '䅿', '䆀', '䆁', '䆂', '䆃', '䆄', '䆅', '䆈', '䆉',
+    '䆊', '䆋', '䆍', '䆎', '䆏', '䆐', '䆑', '䆒', '䆓', '䆔', '䆕', '䆖', '䆗', '䆘', '䆙', '䆚',
+    '䆛', '䆜', '䆝', '䆞', '䆟', '䆠', '䆡', '䆢', '䆣', '䆦', '䆨', '䆩', '䆪', '䆫', '䆬', '䆭',
+    '䆮', '䆯', '䆰', '䆱', '䆲', '䆳', '䆵', '䆶', '䆷', '䆸', '䆹', '䆺', '䆻', '䆽', '䆾', '䆿',
+    '䇀', '䇁', '䇂', '䇃', '䇅', '䇇', '䇈', '䇋', '䇌', '䇍', '䇎', '䇏', '䇐', '䇑', '䇒', '䇓',
+    '䇔', '䇖', '䇗', '䇘', '䇙', '䇛', '䇜', '䇞', '䇟', '䇠', '䇡', '䇢', '䇣', '䇤', '䇥', '䇦',
+    '䇧', '䇨', '䇩', '䇪', '䇫', '䇬', '䇭', '䇮', '䇯', '䇰', '䇱', '䇲', '䇵', '䇶', '䇷', '䇸',
+    '䇹', '䇺', '䇻', '䇼', '䇽', '䇾', '䈀', '䈁', '䈂', '䈃', '䈄', '䈅', '䈆', '䈇', '䈈', '䈉',
+    '䈊', '䈋', '䈌', '䈍', '䈎', '䈏', '䈐', '䈑', '䈒', '䈓', '䈕', '䈖', '䈗', '䈘', '䈙', '䈚',
+    '䈛', '䈜', '䈝', '䈞', '䈟', '䈠', '䈡', '䈢', '䈣', '䈤', '䈥', '䈦', '䈧', '䈪', '䈫', '䈬',
+    '䈭', '䈮', '䈯', '䈰', '䈱', '䈲', '䈳', '䈴', '䈵', '䈶', '䈷', '䈸', '䈹', '䈺', '䈻', '䈼',
+    '䈽', '䈾', '䈿', '䉀', '䉁', '䉂', '䉃', '䉄', '䉅', '䉆', '䉈', '䉉', '䉊', '䉋', '䉌', '䉍',
+    '䉎', '䉏', '䉐', '䉑', '䉒', '䉓', '䉔', '䉕', '䉖', '䉗', '䉘', '䉙', '䉚', '䉛', '䉜', '䉝',
+    '䉞', '䉟', '䉠', '䉡', '䉢', '䉣', '䉤', '䉥', '䉦', '䉧', '䉨', '䉪', '䉫', '䉬', '䉭', '䉮',
+    '䉯', '䉰', '䉱', '䉲', '䉳', '䉴', '䉵', '䉶', '䉷', '䉸', '䉹', '䉺', '䉻', '䉼', '䉽', '䉾',
+    '䉿', '䊀', '䊂', '䊃', '䊄', '䊅', '䊆', '䊇', '䊈', '䊉', '䊊', '䊍', '䊎', '䊐', '䊑', '䊓',
+    '䊔', '䊖', '䊗', '䊘', '䊙', '䊛', '䊜', '䊝', '䊞', '䊟', '䊠', '䊡', '䊣', '䊤', '䊥', '䊦',
+    '䊧', '䊨', '䊩', '䊪', '䊫', '䊬', '䊭', '䊮', '䊯', '䊰', '䊱', '䊲', '䊳', '䊴', '䊵', '䊶',
+    '䊺', '䊻', '䊼', '䊽', '䊾', '䊿', '䋀', '䋁', '䋂', '䋃', '䋄', '䋈', '䋉', '䋊', '䋋', '䋌',
+    '䋎', '䋏', '䋐', '䋑', '䋓', '䋔', '䋕', '䋖', '䋘', '䋙', '䋚', '䋛', '䋜', '䋞', '䋟', '䋠',
+    '䋢', '䋣', '䋤', '䋥', '䋦', '䋧', '䋨', '䋩', '䋪', '䋫', '䋬', '䋭', '䋮', '䋯', '䋰', '䋱',
+    '䋲', '䋳', '䋴', '䋵', '䋶', '䋷', '䋸', '䋹', '䋻', '䋼', '䋽', '䋾', '䋿', '䌀', '䌁', '䌂',
+    '䌃', '䌄', '䌅', '䌆', '䌇', '䌈', '䌊', '䌋', '䌌', '䌍', '䌎', '䌏', '䌐', '䌑', '䌒', '䌓',
+    '䌔', '䌕', '䌖', '䌗', '䌘', '䌙', '䌚', '䌛', '䌜', '䌝', '䌞', '䌟', '䌠', '䌡', '䌢', '䌣',
+    '䌤', '䌥', '䌦', '䌧', '䌨', '䌩', '䌪', '䌫', '䌬', '䌭', '䌮', '䌯', '䌰', '䌱', '䌳', '䌴',
+    '䌵', '䌷', '䌸', '䌹', '䌺', '䌻', '䌼', '䌽', '䌾', '䌿', '䍀', '䍁', '䍂', '䍃', '䍄', '䍅',
+    '䍆', '䍇', '䍈', '䍉', '䍊', '䍋', '䍌', '䍍', '䍎', '䍐', '䍑', '䍒', '䍓', '䍔', '䍕', '䍖',
+    '䍗', '䍘', '䍙', '䍚', '䍛', '䍜', '䍝', '䍞', '䍟', '䍠', '䍡', '䍢', '䍣', '䍤', '䍥', '䍦',
+    '䍨', '䍩', '䍪', '䍫', '䍬', '䍮', '䍯', '䍱', '䍲', '䍳', '䍴', '䍵', '䍶', '䍷', '䍸', '䍺',
+    '䍻', '䍼', '䍽', '䍾', '䍿', '䎀', '䎁', '䎂', '䎃', '䎄', '䎅', '䎆', '䎇', '䎈', '䎉', '䎊',
+    '䎋', '䎌', '䎎', '䎏', '䎐', '䎑', '䎒', '䎓', '䎔', '䎕', '䎖', '䎗', '䎘', '䎙', '䎚', '䎜',
+    '䎝', '䎟', '䎠', '䎡', '䎢', '䎣', '䎤', '䎦', '䎧', '䎨', '䎩', '䎪', '䎫', '䎬', '䎭', '䎮',
+    '䎯', '䎰', '䎱', '䎲', '䎳', '䎴', '䎵', '䎶', '䎷', '䎸', '䎹', '䎺', '䎻', '䎼', '䎽', '䎾',
+    '䎿', '䏀', '䏁', '䏂', '䏃', '䏄', '䏅', '䏆', '䏇', '䏈', '䏉', '䏊', '䏋', '䏌', '䏍', '䏎',
+    '䏏', '䏐', '䏑', '䏒', '䏓', '䏔', '䏕', '䏖', '䏗', '䏘', '䏙', '䏚', '䏛', '䏜', '䏝', '䏞',
+    '䏟', '䏠', '䏡', '䏢', '䏣', '䏤', '䏦', '䏧', '䏨', '䏩', '䏪', '䏫', '䏬', '䏮', '䏯', '䏰',
+    '䏱', '䏲', '䏳', '䏴', '䏵', '䏶', '䏷', '䏸', '䏹', '䏺', '䏻', '䏼', '䏽', '䏿', '䐀', '䐁',
+    '䐂', '䐃', '䐄', '䐅', '䐆', '䐇', '䐈', '䐉', '䐊', '䐋', '䐌', '䐍', '䐎', '䐏', '䐐', '䐑',
+    '䐒', '䐓', '䐔', '䐕', '䐖', '䐗', '䐘', '䐙', '䐚', '䐛', '䐜', '䐝', '䐞', '䐟', '䐠', '䐡',
+    '䐢', '䐣', '䐤', '䐥', '䐦', '䐧', '䐨', '䐩', '䐪', '䐫', '䐬', '䐭', '䐮', '䐯', '䐰', '䐱',
+    '䐲', '䐳', '䐴', '䐵', '䐶', '䐷', '䐸', '䐹', '䐺', '䐻', '䐼', '䐽', '䐾', '䐿', '䑀', '䑁',
+    '䑂', '䑃', '䑄', '䑅', '䑆', '䑇', '䑈', '䑉', '䑊', '䑋', '䑌', '䑍', '䑎', '䑏', '䑐', '䑑',
+    '䑓', '䑔', '䑕', '䑗', '䑘', '䑙', '䑚', '䑛', '䑜', '䑝', '䑞', '䑟', '䑠', '䑣', '䑤', '䑥',
+    '䑦', '䑧', '䑨', '䑪', '䑫', '䑬', '䑭', '䑯', '䑰', '䑱', '䑲', '䑳', '䑴', '䑵', '䑶', '䑸',
+    '䑹', '䑻', '䑼', '䑽', '䑿', '䒀', '䒁', '䒂', '䒃', '䒄', '䒅', '䒆', '䒇', '䒈', '䒉', '䒊',
+    '䒋', '䒌', '䒍', '䒎', '䒏', '䒐', '䒑', '䒒', '䒓', '䒔', '䒕', '䒖', '䒗', '䒚', '䒛', '䒜',
+    '䒝', '䒞', '䒟', '䒠', '䒡', '䒢', '䒣', '䒤', '䒥', '䒦', '䒧', '䒨', '䒩', '䒪', '䒫', '䒬',
+    '䒭', '䒰', '䒲', '䒳', '䒴', '䒵', '䒷', '䒸', '䒹', '䒺', '䒻', '䒼', '䒽', '䒾', '䒿', '䓂',
+    '䓃', '䓄', '䓅', '䓆', '䓇', '䓈', '䓉', '䓊', '䓋', '䓌', '䓍', '䓎', '䓏', '䓐', '䓑', '䓒',
+    '䓓', '䓕', '䓖', '䓗', '䓘', '䓙', '䓚', '䓛', '䓜', '䓝', '䓞', '䓟', '䓠', '䓡', '䓢', '䓣',
+    '䓤', '䓥', '䓦', '䓧', '䓨', '䓫', '䓬', '䓭', '䓮', '䓯', '䓰', '䓱', '䓲', '䓳', '䓴', '䓵',
+    '䓶', '䓷', '䓸', '䓹', '䓺', '䓼', '䓽', '䓾', '䓿', '䔁', '䔂', '䔃', '䔄', '䔅', '䔆', '䔇',
+    '䔈', '䔉', '䔊', '䔋', '䔎', '䔏', '䔐', '䔑', '䔒', '䔓', '䔔', '䔕', '䔖', '䔗', '䔘', '䔙',
+    '䔚', '䔛', '䔜', '䔝', '䔞', '䔟', '䔠', '䔡', '䔢', '䔣', '䔤', '䔧', '䔪', '䔬', '䔭', '䔮',
+    '䔯', '䔰', '䔱', '䔲', '䔳', '䔴', '䔶', '䔷', '䔸', '䔹', '䔺', '䔻', '䔼', '䔽', '䔾', '䔿',
+    '䕀', '䕁', '䕃', '䕅', '䕆', '䕇', '䕈', '䕉', '䕊', '䕋', '䕌', '䕍', '䕎', '䕏', '䕑', '䕒',
+    '䕓', '䕔', '䕕', '䕗', '䕘', '䕙', '䕚', '䕛', '䕝', '䕞', '䕟', '䕠', '䕡', '䕢', '䕣', '䕤',
+    '䕥', '䕦', '䕧', '䕨', '䕩', '䕪', '䕫', '䕭', '䕮', '䕯', '䕰', '䕱', '䕲', '䕳', '䕴', '䕵',
+    '䕶', '䕷', '䕸', '䕹', '䕺', '䕻', '䕼', '䕽', '䕾', '䕿', '䖁', '䖂', '䖃', '䖄', '䖅', '䖆',
+    '䖇', '䖈', '䖊', '䖋', '䖌', '䖍', '䖎', '䖏', '䖐', '䖑', '䖒', '䖓', '䖔', '䖕', '䖖', '䖗',
+    '䖘', '䖙', '䖚', '䖛', '䖜', '䖝', '䖞', '䖟', '䖠', '䖡', '䖢', '䖣', '䖤', '䖥', '䖦', '䖧',
+    '䖨', '䖩', '䖪', '䖫', '䖭', '䖮', '䖯', '䖰', '䖱', '䖲', '䖳', '䖴', '䖵', '䖶', '䖷', '䖸',
+    '䖹', '䖺', '䖻', '䖼', '䖽', '䖿', '䗀', '䗁', '䗂', '䗃', '䗄', '䗅', '䗆', '䗇', '䗈', '䗉',
+    '䗊', '䗋', '䗌', '䗍', '䗎', '䗏', '䗐', '䗑', '䗒', '䗓', '䗔', '䗕', '䗖', '䗗', '䗘', '䗙',
+    '䗚', '䗛', '䗜', '䗝', '䗞', '䗟', '䗠', '䗡', '䗢', '䗣', '䗤', '䗥', '䗧', '䗨', '䗩', '䗪',
+    '䗫', '䗮', '䗯', '䗰', '䗱', '䗲', '䗴', '䗵', '䗶', '䗷', '䗸', '䗹', '䗺', '䗻', '䗼', '䗽',
+    '䗾', '䗿', '䘀', '䘁', '䘃', '䘄', '䘅', '䘆', '䘇', '䘈', '䘉', '䘊', '䘋', '䘌', '䘍', '䘎',
+    '䘏', '䘐', '䘑', '䘒', '䘓', '䘔', '䘕', '䘘', '䘙', '䘚', '䘝', '䘟', '䘠', '䘡', '䘢', '䘣',
+    '䘤', '䘥', '䘦', '䘨', '䘩', '䘪', '䘫', '䘬', '䘭', '䘮', '䘰', '䘱', '䘳', '䘵', '䘶', '䘷',
+    '䘸', '䘹', '䘺', '䘻', '䘼', '䘽', '䘾', '䘿', '䙀', '䙁', '䙃', '䙄', '䙅', '䙆', '䙇', '䙈',
+    '䙉', '䙊', '䙌', '䙎', '䙏', '䙐', '䙑', '䙒', '䙓', '䙔', '䙕', '䙗', '䙘', '䙙', '䙚', '䙛',
+    '䙜', '䙝', '䙞', '䙟', '䙠', '䙡', '䙢', '䙣', '䙤', '䙥', '䙦', '䙨', '䙩', '䙪', '䙬', '䙮',
+    '䙯', '䙰', '䙱', '䙳', '䙴', '䙵', '䙷', '䙸', '䙹', '䙺', '䙼', '䙽', '䙾', '䙿', '䚂', '䚃',
+    '䚄', '䚅', '䚆', '䚇', '䚈', '䚉', '䚊', '䚋', '䚍', '䚏', '䚐', '䚑', '䚒', '䚓', '䚔', '䚕',
+    '䚖', '䚗', '䚘', '䚙', '䚚', '䚛', '䚜', '䚝', '䚞', '䚟', '䚠', '䚡', '䚢', '䚣', '䚥', '䚦',
+    '䚧', '䚨', '䚩', '䚪', '䚫', '䚬', '䚭', '䚮', '䚯', '䚰', '䚱', '䚲', '䚳', '䚴', '䚵', '䚶',
+    '䚷', '䚸', '䚹', '䚺', '䚻', '䚼', '䚽', '䚾', '䚿', '䛀', '䛁', '䛂', '䛃', '䛄', '䛅', '䛆',
+    '䛇', '䛈', '䛉', '䛊', '䛋', '䛌', '䛍', '䛎', '䛏', '䛐', '䛑', '䛒', '䛔', '䛕', '䛖', '䛗',
+    '䛘', '䛙', '䛚', '䛛', '䛜', '䛝', '䛞', '䛟', '䛠', '䛡', '䛢', '䛣', '䛤', '䛥', '䛦', '䛧',
+    '䛨', '䛩', '䛪', '䛫', '䛬', '䛭', '䛮', '䛯', '䛰', '䛱', '䛲', '䛳', '䛴', '䛵', '䛶', '䛷',
+    '䛸', '䛹', '䛺', '䛻', '䛼', '䛽', '䛾', '䛿', '䜀', '䜁', '䜃', '䜄', '䜅', '䜆', '䜇', '䜈',
+    '䜉', '䜊', '䜋', '䜌', '䜍', '䜎', '䜏', '䜐', '䜑', '䜒', '䜓', '䜔', '䜕', '䜖', '䜗', '䜘',
+    '䜙', '䜚', '䜛', '䜜', '䜝', '䜞', '䜟', '䜠', '䜡', '䜢', '䜣', '䜧', '䜩', '䜪', '䜫', '䜬',
+    '䜭', '䜮', '䜯', '䜱', '䜲', '䜴', '䜵', '䜶', '䜷', '䜸', '䜹', '䜺', '䜻', '䜼', '䜽', '䜾',
+    '䝀', '䝁', '䝂', '䝃', '䝄', '䝅', '䝈', '䝊', '䝋', '䝌', '䝍', '䝎', '䝏', '䝐', '䝑', '䝒',
+    '䝓', '䝕', '䝖', '䝘', '䝙', '䝚', '䝛', '䝜', '䝝', '䝞', '䝟', '䝠', '䝡', '䝢', '䝣', '䝤',
+    '䝥', '䝦', '䝧', '䝨', '䝩', '䝪', '䝫', '䝬', '䝭', '䝮', '䝯', '䝰', '䝱', '䝲', '䝳', '䝵',
+    '䝶', '䝷', '䝸', '䝹', '䝺', '䝻', '䝼', '䝽', '䝾', '䝿', '䞀', '䞁', '䞂', '䞃', '䞄', '䞅',
+    '䞆', '䞇', '䞈', '䞉', '䞊', '䞋', '䞌', '䞍', '䞐', '䞑', '䞒', '䞓', '䞔', '䞕', '䞖', '䞗',
+    '䞘', '䞙', '䞚', '䞛', '䞜', '䞝', '䞞', '䞟', '䞠', '䞡', '䞢', '䞣', '䞤', '䞥', '䞦', '䞧',
+    '䞨', '䞩', '䞪', '䞫', '䞬', '䞭', '䞮', '䞯', '䞰', '䞱', '䞳', '䞴', '䞵', '䞶', '䞷', '䞸',
+    '䞹', '䞺', '䞻', '䞼', '䞽', '䞾', '䞿', '䟀', '䟁', '䟂', '䟃', '䟄', '䟅', '䟆', '䟇', '䟈',
+    '䟉', '䟊', '䟋', '䟌', '䟍', '䟏', '䟐', '䟑', '䟒', '䟓', '䟔', '䟕', '䟖', '䟗', '䟘', '䟙',
+    '䟚', '䟛', '䟜', '䟝', '䟞', '䟟', '䟠', '䟡', '䟢', '䟣', '䟤', '䟥', '䟦', '䟧', '䟨', '䟩',
+    '䟪', '䟫', '䟬', '䟭', '䟮', '䟯', '䟰', '䟴', '䟵', '䟶', '䟷', '䟸', '䟹', '䟺', '䟻', '䟼',
+    '䟾', '䟿', '䠀', '䠂', '䠃', '䠄', '䠅', '䠆', '䠇', '䠈', '䠉', '䠊', '䠋', '䠍', '䠎', '䠏',
+    '䠐', '䠑', '䠒', '䠓', '䠔', '䠕', '䠗', '䠘', '䠙', '䠚', '䠛', '䠜', '䠝', '䠞', '䠟', '䠠',
+    '䠡', '䠢', '䠣', '䠤', '䠥', '䠦', '䠧', '䠨', '䠩', '䠪', '䠫', '䠬', '䠭', '䠮', '䠯', '䠰',
+    '䠱', '䠲', '䠳', '䠴', '䠵', '䠶', '䠷', '䠸', '䠹', '䠻', '䠼', '䠽', '䠾', '䠿', '䡀', '䡁',
+    '䡂', '䡃', '䡅', '䡆', '䡇', '䡈', '䡉', '䡊', '䡋', '䡌', '䡍', '䡎', '䡏', '䡐', '䡑', '䡒',
+    '䡓', '䡔', '䡕', '䡖', '䡗', '䡘', '䡙', '䡚', '䡛', '䡜', '䡝', '䡞', '䡟', '䡡', '䡢', '䡣',
+    '䡤', '䡥', '䡦', '䡧', '䡨', '䡩', '䡪', '䡫', '䡬', '䡭', '䡯', '䡰', '䡱', '䡲', '䡳', '䡴',
+    '䡵', '䡶', '䡷', '䡹', '䡺', '䡻', '䡼', '䡽', '䡾', '䡿', '䢀', '䢁', '䢃', '䢄', '䢅', '䢇',
+    '䢈', '䢉', '䢊', '䢋', '䢌', '䢍', '䢐', '䢑', '䢒', '䢓', '䢔', '䢕', '䢖', '䢗', '䢙', '䢚',
+    '䢛', '䢝', '䢞', '䢟', '䢠', '䢡', '䢢', '䢣', '䢤', '䢥', '䢦', '䢧', '䢨', '䢩', '䢪', '䢫',
+    '䢭', '䢮', '䢯', '䢰', '䢱', '䢲', '䢳', '䢵', '䢸', '䢹', '䢺', '䢻', '䢼', '䢽', '䢾', '䢿',
+    '䣀', '䣁', '䣂', '䣄', '䣅', '䣆', '䣇', '䣈', '䣊', '䣋', '䣌', '䣍', '䣎', '䣐', '䣑', '䣓',
+    '䣔', '䣕', '䣖', '䣘', '䣙', '䣚', '䣛', '䣜', '䣝', '䣞', '䣟', '䣠', '䣡', '䣢', '䣣', '䣤',
+    '䣥', '䣦', '䣧', '䣨', '䣩', '䣪', '䣫', '䣬', '䣭', '䣮', '䣯', '䣰', '䣱', '䣲', '䣴', '䣵',
+    '䣶', '䣷', '䣸', '䣹', '䣺', '䣻', '䣼', '䣽', '䣾', '䣿', '䤁', '䤂', '䤃', '䤄', '䤅', '䤆',
+    '䤉', '䤊', '䤋', '䤌', '䤍', '䤎', '䤏', '䤐', '䤑', '䤒', '䤓', '䤔', '䤕', '䤖', '䤗', '䤘',
+    '䤙', '䤚', '䤛', '䤜', '䤝', '䤞', '䤟', '䤠', '䤡', '䤣', '䤤', '䤥', '䤦', '䤧', '䤨', '䤩',
+    '䤪', '䤫', '䤬', '䤭', '䤮', '䤯', '䤰', '䤱', '䤲', '䤳', '䤴', '䤵', '䤶', '䤷', '䤸', '䤹',
+    '䤺', '䤻', '䤼', '䤽', '䤾', '䤿', '䥀', '䥁', '䥂', '䥃', '䥄', '䥅', '䥆', '䥇', '䥈', '䥉',
+    '䥋', '䥌', '䥍', '䥎', '䥏', '䥐', '䥑', '䥒', '䥓', '䥔', '䥕', '䥖', '䥗', '䥘', '䥙', '䥛',
+    '䥜', '䥝', '䥞', '䥟', '䥡', '䥢', '䥣', '䥤', '䥥', '䥧', '䥨', '䥩', '䥪', '䥫', '䥬', '䥭',
+    '䥮', '䥯', '䥰', '䥱', '䥲', '䥳', '䥵', '䥶', '䥷', '䥸', '䥺', '䥽', '䥾', '䦂', '䦃', '䦅',
+    '䦆', '䦇', '䦈', '䦊', '䦋', '䦌', '䦍', '䦎', '䦏', '䦐', '䦑', '䦒', '䦓', '䦔', '䦕', '䦖',
+    '䦗', '䦘', '䦙', '䦚', '䦛', '䦜', '䦝', '䦞', '䦟', '䦠', '䦡', '䦢', '䦣', '䦤', '䦥', '䦦',
+    '䦧', '䦨', '䦪', '䦫', '䦬', '䦭', '䦮', '䦯', '䦱', '䦲', '䦳', '䦴', '䦵', '䦶', '䦷', '䦹',
+    '䦺', '䦻', '䦼', '䦽', '䦾', '䧁', '䧂', '䧃', '䧄', '䧅', '䧆', '䧇', '䧉', '䧊', '䧋', '䧌',
+    '䧍', '䧎', '䧑', '䧒', '䧓', '䧔', '䧕', '䧖', '䧗', '䧘', '䧙', '䧚', '䧛', '䧜', '䧝', '䧞',
+    '䧟', '䧠', '䧡', '䧢', '䧤', '䧥', '䧦', '䧧', '䧨', '䧪', '䧫', '䧬', '䧮', '䧯', '䧰', '䧲',
+    '䧳', '䧴', '䧵', '䧶', '䧷', '䧹', '䧺', '䧼', '䧽', '䧿', '䨀', '䨁', '䨂', '䨄', '䨅', '䨆',
+    '䨈', '䨊', '䨋', '䨌', '䨍', '䨎', '䨏', '䨐', '䨑', '䨒', '䨓', '䨔', '䨖', '䨗', '䨘', '䨙',
+    '䨚', '䨛', '䨜', '䨝', '䨞', '䨟', '䨠', '䨡', '䨢', '䨣', '䨤', '䨥', '䨦', '䨨', '䨩', '䨪',
+    '䨫', '䨬', '䨭', '䨮', '䨯', '䨰', '䨱', '䨲', '䨳', '䨴', '䨵', '䨶', '䨷', '䨸', '䨹', '䨺',
+    '䨻', '䨼', '䨽', '䨾', '䨿', '䩀', '䩁', '䩂', '䩃', '䩄', '䩅', '䩆', '䩇', '䩈', '䩉', '䩊',
+    '䩋', '䩌', '䩍', '䩏', '䩐', '䩑', '䩒', '䩓', '䩔', '䩕', '䩗', '䩘', '䩙', '䩚', '䩛', '䩜',
+    '䩝', '䩞', '䩟', '䩡', '䩢', '䩣', '䩤', '䩥', '䩦', '䩧', '䩨', '䩩', '䩪', '䩫', '䩬', '䩮',
+    '䩯', '䩰', '䩱', '䩲', '䩳', '䩴', '䩵', '䩶', '䩷', '䩸', '䩹', '䩺', '䩻', '䩼', '䩽', '䩾',
+    '䩿', '䪀', '䪁', '䪂', '䪃', '䪄', '䪅', '䪆', '䪇', '䪈', '䪉', '䪊', '䪋', '䪌', '䪍', '䪎',
+    '䪏', '䪐', '䪑', '䪒', '䪓', '䪔', '䪕', '䪖', '䪗', '䪘', '䪙', '䪚', '䪛', '䪜', '䪝', '䪞',
+    '䪤', '䪥', '䪦', '䪧', '䪨', '䪩', '䪫', '䪬', '䪭', '䪮', '䪯', '䪰', '䪱', '䪲', '䪳', '䪴',
+    '䪵', '䪶', '䪷', '䪸', '䪹', '䪺', '䪻', '䪼', '䪽', '䪾', '䪿', '䫀', '䫁', '䫂', '䫃', '䫄',
+    '䫅', '䫆', '䫇', '䫈', '䫉', '䫊', '䫋', '䫌', '䫍', '䫎', '䫏', '䫐', '䫑', '䫒', '䫓', '䫔',
+    '䫕', '䫖', '䫗', '䫘', '䫙', '䫚', '䫛', '䫜', '䫝', '䫞', '䫟', '䫠', '䫡', '䫢', '䫣', '䫤',
+    '䫥', '䫦', '䫨', '䫩', '䫪', '䫫', '䫬', '䫭', '䫮', '䫰', '䫱', '䫲', '䫳', '䫴', '䫵', '䫶',
+    '䫷', '䫸', '䫹', '䫺', '䫻', '䫼', '䫽', '䫾', '䫿', '䬀', '䬁', '䬂', '䬃', '䬄', '䬅', '䬆',
+    '䬇', '䬈', '䬉', '䬊', '䬋', '䬌', '䬍', '䬎', '䬏', '䬐', '䬑', '䬒', '䬓', '䬔', '䬕', '䬖',
+    '䬗', '䬘', '䬙', '䬛', '䬝', '䬞', '䬟', '䬡', '䬣', '䬤', '䬥', '䬦', '䬧', '䬨', '䬪', '䬫',
+    '䬬', '䬮', '䬯', '䬰', '䬱', '䬲', '䬳', '䬴', '䬵', '䬶', '䬷', '䬹', '䬺', '䬻', '䬼', '䬽',
+    '䬾', '䬿', '䭀', '䭁', '䭂', '䭃', '䭄', '䭅', '䭆', '䭇', '䭈', '䭉', '䭊', '䭋', '䭌', '䭍',
+    '䭎', '䭐', '䭑', '䭒', '䭓', '䭔', '䭕', '䭖', '䭗', '䭘', '䭙', '䭚', '䭛', '䭝', '䭞', '䭟',
+    '䭠', '䭡', '䭢', '䭣', '䭤', '䭥', '䭦', '䭧', '䭨', '䭩', '䭪', '䭫', '䭬', '䭭', '䭮', '䭯',
+    '䭰', '䭱', '䭲', '䭳', '䭴', '䭵', '䭶', '䭷', '䭸', '䭹', '䭺', '䭻', '䭼', '䭽', '䭾', '䭿',
+    '䮀', '䮁', '䮂', '䮄', '䮇', '䮈', '䮉', '䮊', '䮋', '䮍', '䮎', '䮏', '䮑', '䮒', '䮓', '䮔',
+    '䮕', '䮖', '䮗', '䮘', '䮙', '䮚', '䮛', '䮜', '䮝', '䮞', '䮟', '䮠', '䮡', '䮢', '䮤', '䮥',
+    '䮧', '䮨', '䮩', '䮪', '䮫', '䮬', '䮭', '䮮', '䮯', '䮰', '䮱', '䮲', '䮳', '䮴', '䮵', '䮷',
+    '䮸', '䮹', '䮺', '䮻', '䮼', '䮽', '䮾', '䮿', '䯀', '䯁', '䯂', '䯄', '䯅', '䯆', '䯇', '䯈',
+    '䯉', '䯊', '䯋', '䯌', '䯍', '䯎', '䯏', '䯐', '䯑', '䯒', '䯓', '䯔', '䯕', '䯖', '䯗', '䯘',
+    '䯙', '䯚', '䯛', '䯜', '䯝', '䯞', '䯟', '䯠', '䯡', '䯢', '䯣', '䯤', '䯥', '䯦', '䯧', '䯨',
+    '䯪', '䯫', '䯬', '䯭', '䯮', '䯯', '䯰', '䯱', '䯲', '䯳', '䯴', '䯵', '䯶', '䯷', '䯸', '䯹',
+    '䯺', '䯼', '䯽', '䯾', '䯿', '䰀', '䰁', '䰂', '䰃', '䰄', '䰆', '䰇', '䰈', '䰉', '䰊', '䰋',
+    '䰌', '䰍', '䰎', '䰏', '䰐', '䰒', '䰓', '䰔', '䰕', '䰖', '䰗', '䰘', '䰙', '䰚', '䰛', '䰜',
+    '䰝', '䰞', '䰟', '䰠', '䰡', '䰢', '䰣', '䰤', '䰥', '䰦', '䰧', '䰨', '䰩', '䰪', '䰫', '䰬',
+    '䰭', '䰯', '䰰', '䰱', '䰲', '䰴', '䰵', '䰷', '䰸', '䰹', '䰺', '䰻', '䰼', '䰽', '䰾', '䰿',
+    '䱀', '䱁', '䱂', '䱃', '䱄', '䱅', '䱆', '䱇', '䱈', '䱉', '䱊', '䱋', '䱌', '䱍', '䱎', '䱏',
+    '䱐', '䱑', '䱒', '䱓', '䱔', '䱕', '䱖', '䱗', '䱘', '䱙', '䱚', '䱛', '䱜', '䱝', '䱞', '䱟',
+    '䱠', '䱡', '䱢', '䱣', '䱤', '䱥', '䱨', '䱫', '䱬', '䱭', '䱮', '䱯', '䱰', '䱱', '䱲', '䱳',
+    '䱴', '䱵', '䱶', '䱷', '䱸', '䱹', '䱺', '䱻', '䱼', '䱽', '䱾', '䱿', '䲀', '䲁', '䲂', '䲃',
+    '䲄', '䲅', '䲆', '䲉', '䲊', '䲋', '䲌', '䲍', '䲎', '䲏', '䲐', '䲑', '䲒', '䲓', '䲔', '䲕',
+    '䲖', '䲗', '䲘', '䲙', '䲚', '䲛', '䲜', '䲞', '䲟', '䲠', '䲡', '䲢', '䲣', '䲦', '䲧', '䲨',
+    '䲪', '䲬', '䲮', '䲯', '䲰', '䲱', '䲲', '䲳', '䲴', '䲵', '䲸', '䲹', '䲺', '䲻', '䲼', '䲽',
+    '䲾', '䲿', '䳀', '䳁', '䳂', '䳃', '䳄', '䳅', '䳆', '䳇', '䳈', '䳉', '䳊', '䳋', '䳍', '䳎',
+    '䳏', '䳐', '䳑', '䳒', '䳓', '䳔', '䳕', '䳖', '䳗', '䳘', '䳙', '䳚', '䳛', '䳜', '䳝', '䳞',
+    '䳟', '䳠', '䳡', '䳢', '䳣', '䳤', '䳦', '䳧', '䳨', '䳩', '䳪', '䳫', '䳬', '䳭', '䳮', '䳯',
+    '䳰', '䳱', '䳲', '䳳', '䳴', '䳵', '䳶', '䳷', '䳸', '䳹', '䳺', '䳻', '䳽', '䳾', '䳿', '䴀',
+    '䴁', '䴂', '䴃', '䴄', '䴅', '䴆', '䴇', '䴈', '䴉', '䴊', '䴋', '䴌', '䴍', '䴎', '䴏', '䴐',
+    '䴑', '䴒', '䴓', '䴔', '䴕', '䴖', '䴗', '䴘', '䴙', '䴚', '䴛', '䴜', '䴝', '䴞', '䴟', '䴠',
+    '䴡', '䴢', '䴣', '䴤', '䴥', '䴦', '䴧', '䴩', '䴪', '䴫', '䴬', '䴭', '䴮', '䴯', '䴰', '䴱',
+    '䴲', '䴳', '䴴', '䴵', '䴶', '䴷', '䴸', '䴹', '䴺', '䴻', '䴼', '䴽', '䴾', '䴿', '䵀', '䵁',
+    '䵂', '䵃', '䵄', '䵅', '䵆', '䵇', '䵈', '䵉', '䵊', '䵋', '䵌', '䵎', '䵏', '䵐', '䵑', '䵒',
+    '䵓', '䵔', '䵕', '䵖', '䵗', '䵘', '䵙', '䵚', '䵛', '䵜', '䵝', '䵞', '䵟', '䵠', '䵡', '䵢',
+    '䵣', '䵤', '䵥', '䵦', '䵧', '䵨', '䵩', '䵪', '䵫', '䵬', '䵭', '䵮', '䵯', '䵰', '䵱', '䵲',
+    '䵳', '䵴', '䵵', '䵶', '䵷', '䵹', '䵺', '䵻', '䵼', '䵽', '䵾', '䵿', '䶀', '䶁', '䶂', '䶃',
+    '䶄', '䶅', '䶆', '䶇', '䶈', '䶉', '䶊', '䶋', '䶌', '䶍', '䶎', '䶏', '䶐', '䶑', '䶒', '䶓',
+    '䶔', '䶕', '䶖', '䶗', '䶘', '䶙', '䶚', '䶛', '䶜', '䶝', '䶞', '䶟', '䶠', '䶡', '䶢', '䶣',
+    '䶤', '䶥', '䶦', '䶧', '䶨', '䶩', '䶪', '䶫', '䶬', '䶮', '䶰', '䶱', '䶲', '䶳', '䶵', '一',
+    '丁', '丂', '七', '丄', '丅', '丆', '万', '丈', '三', '上', '下', '丌', '不', '与', '丏', '丐',
+    '丑', '丒', '专', '且', '丕', '世', '丗', '丘', '丙', '业', '丛', '东', '丝', '丞', '丟', '丠',
+    '両', '丢', '丣', '两', '严', '並', '丧', '丨', '丩', '个', '丫', '丬', '中', '丮', '丯', '丰',
+    '丱', '串', '丳', '临', '丵', '丶', '丷', '丸', '丹', '为', '主', '丼', '丽', '举', '丿', '乀',
+    '乁', '乂', '乃', '乄', '久', '乆', '乇', '么', '义', '乊', '之', '乌', '乍', '乎', '乏', '乐',
+    '乑', '乒', '乓', '乔', '乕', '乖', '乗', '乘', '乙', '乚', '乛', '乜', '九', '乞', '也', '习',
+    '乡', '乢', '乣', '乤', '乥', '书', '乧', '乨', '乩', '乪', '乫', '乬', '乭', '乮', '乯', '买',
+    '乱', '乲', '乳', '乴', '乵', '乶', '乷', '乸', '乹', '乺', '乻', '乼', '乽', '乾', '乿', '亀',
+    '亁', '亂', '亃', '亄', '亅', '了', '亇', '予', '争', '亊', '事', '二', '亍', '于', '亏', '亐',
+    '云', '互', '亓', '五', '井', '亖', '亗', '亘', '亙', '亚', '些', '亜', '亝', '亞', '亟', '亠',
+    '亡', '亢', '亣', '交', '亥', '亦', '产', '亨', '亩', '亪', '享', '京', '亭', '亮', '亯', '亰',
+    '亱', '亲', '亳', '亴', '亵', '亶', '亷', '亸', '亹', '人', '亻', '亼', '亽', '亾', '亿', '什',
+    '仁', '仂', '仃', '仄', '仅', '仆', '仇', '仈', '仉', '今', '介', '仌', '仍', '从', '仏', '仐',
+    '仑', '仒', '仓', '仔', '仕', '他', '仗', '付', '仙', '仚', '仛', '仜', '仝', '仞', '仟', '仠',
+    '仡', '仢', '代', '令', '以', '仦', '仧', '仨', '仩', '仪', '仫', '们', '仭', '仮', '仯', '仰',
+    '仱', '仲', '仳', '仴', '仵', '件', '价', '仸', '仹', '仺', '任', '仼', '份', '仾', '仿', '伀',
+    '企', '伂', '伃', '伄', '伅', '伆', '伇', '伈', '伉', '伊', '伋', '伌', '伍', '伎', '伏', '伐',
+    '休', '伒', '伓', '伔', '伕', '伖', '众', '优', '伙', '会', '伛', '伜', '伝', '伞', '伟', '传',
+    '伡', '伢', '伣', '伤', '伥', '伦', '伧', '伨', '伩', '伪', '伫', '伬', '伭', '伮', '伯', '估',
+    '伱', '伲', '伳', '伴', '伵', '伶', '伷', '伸', '伹', '伺', '伻', '似', '伽', '伾', '伿', '佀',
+    '佁', '佂', '佃', '佄', '佅', '但', '佇', '佈', '佉', '佊', '佋', '佌', '位', '低', '住', '佐',
+    '佑', '佒', '体', '佔', '何', '佖', '佗', '佘', '余', '佚', '佛', '作', '佝', '佞', '佟', '你',
+    '佡', '佢', '佣', '佤', '佥', '佦', '佧', '佨', '佩', '佪', '佫', '佬', '佭', '佮', '佯', '佰',
+    '佱', '佲', '佳', '佴', '併', '佶', '佷', '佸', '佹', '佺', '佻', '佼', '佽', '佾', '使', '侀',
+    '侁', '侂', '侃', '侄', '侅', '來', '侇', '侈', '侉', '侊', '例', '侌', '侍', '侎', '侏', '侐',
+    '侑', '侒', '侓', '侔', '侕', '侖', '侗', '侘', '侙', '侚', '供', '侜', '依', '侞', '侟', '侠',
+    '価', '侢', '侣', '侤', '侥', '侦', '侧', '侨', '侩', '侪', '侫', '侬', '侭', '侮', '侯', '侰',
+    '侱', '侲', '侳', '侴', '侵', '侶', '侷', '侸', '侹', '侺', '侻', '侼', '侽', '侾', '便', '俀',
+    '俁', '係', '促', '俄', '俅', '俆', '俇', '俈', '俉', '俊', '俋', '俌', '俍', '俎', '俏', '俐',
+    '俑', '俒', '俓', '俔', '俕', '俖', '俗', '俘', '俙', '俚', '俛', '俜', '保', '俞', '俟', '俠',
+    '信', '俢', '俣', '俤', '俥', '俦', '俧', '俨', '俩', '俪', '俫', '俬', '俭', '修', '俯', '俰',
+    '俱', '俲', '俳', '俴', '俵', '俶', '俷', '俸', '俹', '俺', '俻', '俼', '俽', '俾', '俿', '倀',
+    '倁', '倂', '倃', '倄', '倅', '倆', '倇', '倈', '倉', '倊', '個', '倌', '倍', '倎', '倏', '倐',
+    '們', '倒', '倓', '倔', '倕', '倖', '倗', '倘', '候', '倚', '倛', '倜', '倝', '倞', '借', '倠',
+    '倡', '倢', '倣', '値', '倥', '倦', '倧', '倨', '倩', '倪', '倫', '倬', '倭', '倮', '倯', '倰',
+    '倱', '倲', '倳', '倴', '倵', '倶', '倷', '倸', '倹', '债', '倻', '值', '倽', '倾', '倿', '偀',
+    '偁', '偂', '偃', '偄', '偅', '偆', '假', '偈', '偉', '偊', '偋', '偌', '偍', '偎', '偏', '偐',
+    '偑', '偒', '偓', '偔', '偕', '偖', '偗', '偘', '偙', '做', '偛', '停', '偝', '偞', '偟', '偠',
+    '偡', '偢', '偣', '偤', '健', '偦', '偧', '偨', '偩', '偪', '偫', '偬', '偭', '偮', '偯', '偰',
+    '偱', '偲', '偳', '側', '偵', '偶', '偷', '偸', '偹', '偺', '偻', '偼', '偽', '偾', '偿', '傀',
+    '傁', '傂', '傃', '傄', '傅', '傆', '傇', '傈', '傉', '傊', '傋', '傌', '傍', '傎', '傏', '傐',
+    '傑', '傒', '傓', '傔', '傕', '傖', '傗', '傘', '備', '傚', '傛', '傜', '傝', '傞', '傟', '傠',
+    '傡', '傢', '傣', '傤', '傥', '傦', '傧', '储', '傩', '傪', '傫', '催', '傭', '傮', '傯', '傰',
+    '傱', '傲', '傳', '傴', '債', '傶', '傷', '傸', '傹', '傺', '傻', '傼', '傽', '傾', '傿', '僀',
+    '僁', '僂', '僃', '僄', '僅', '僆', '僇', '僈', '僉', '僊', '僋', '僌', '働', '僎', '像', '僐',
+    '僑', '僒', '僓', '僔', '僕', '僖', '僗', '僘', '僙', '僚', '僛', '僜', '僝', '僞', '僟', '僠',
+    '僡', '僢', '僣', '僤', '僥', '僦', '僧', '僨', '僩', '僪', '僫', '僬', '僭', '僮', '僯', '僰',
+    '僱', '僲', '僳', '僴', '僵', '僶', '僷', '僸', '價', '僺', '僻', '僼', '僽', '僾', '僿', '儀',
+    '儁', '儂', '儃', '億', '儅', '儆', '儇', '儈', '儉', '儊', '儋', '儌', '儍', '儎', '儏', '儐',
+    '儑', '儒', '儓', '儔', '儕', '儖', '儗', '儘', '儙', '儚', '儛', '儜', '儝', '儞', '償', '儠',
+    '儡', '儢', '儣', '儤', '儥', '儦', '儧', '儨', '儩', '優', '儫', '儬', '儭', '儮', '儯', '儰',
+    '儱', '儲', '儳', '儴', '儵', '儶', '儷', '儸', '儹', '儺', '儻', '儼', '儽', '儾', '儿', '兀',
+    '允', '兂', '元', '兄', '充', '兆', '兇', '先', '光', '兊', '克', '兌', '免', '兎', '兏', '児',
+    '兑', '兒', '兓', '兔', '兕', '兖', '兗', '兘', '党', '兛', '兜', '兝', '兞', '兟', '兠', '兢',
+    '兣', '兤', '入', '兦', '內', '全', '兩', '兪', '八', '公', '六', '兮', '兯', '兰', '共', '兲',
+    '关', '兴', '兵', '其', '具', '典', '兹', '兺', '养', '兼', '兽', '兾', '兿', '冀', '冁', '冂',
+    '冃', '冄', '内', '円', '冇', '冈', '冉', '冊', '冋', '册', '再', '冎', '冏', '冐', '冑', '冒',
+    '冓', '冔', '冕', '冖', '冗', '冘', '写', '冚', '军', '农', '冝', '冞', '冟', '冠', '冡', '冢',
+    '冣', '冤', '冥', '冦', '冧', '冨', '冩', '冪', '冫', '冬', '冭', '冮', '冯', '冰', '冱', '冲',
+    '决', '冴', '况', '冶', '冷', '冸', '冹', '冺', '冻', '冼', '冽', '冾', '冿', '净', '凁', '凂',
+    '凃', '凄', '凅', '准', '凇', '凈', '凉', '凊', '凋', '凌', '凍', '凎', '减', '凐', '凑', '凒',
+    '凓', '凔', '凕', '凖', '凗', '凘', '凙', '凚', '凛', '凜', '凝', '凞', '凟', '几', '凡', '凢',
+    '凣', '凤', '凥', '処', '凧', '凨', '凩', '凪', '凫', '凬', '凭', '凮', '凯', '凰', '凱', '凲',
+    '凳', '凴', '凵', '凶', '凷', '凸', '凹', '出', '击', '凼', '函', '凾', '凿', '刀', '刁', '刂',
+    '刃', '刄', '刅', '分', '切', '刈', '刉', '刊', '刋', '刌', '刍', '刎', '刏', '刐', '刑', '划',
+    '刓', '刔', '刕', '刖', '列', '刘', '则', '刚', '创', '刜', '初', '刞', '刟', '删', '刡', '刢',
+    '刣', '判', '別', '刦', '刧', '刨', '利', '刪', '别', '刬', '刭', '刮', '刯', '到', '刱', '刲',
+    '刳', '刴', '刵', '制', '刷', '券', '刹', '刺', '刻', '刼', '刽', '刾', '刿', '剀', '剁', '剂',
+    '剃', '剄', '剅', '剆', '則', '剈', '剉', '削', '剋', '剌', '前', '剎', '剏', '剐', '剑', '剒',
+    '剓', '剔', '剕', '剖', '剗', '剘', '剙', '剚', '剛', '剜', '剝', '剞', '剟', '剠', '剡', '剢',
+    '剣', '剤', '剥', '剦', '剧', '剨', '剩', '剪', '剫', '剬', '剭', '剮', '副', '剰', '剱', '割',
+    '剳', '剴', '創', '剶', '剷', '剸', '剹', '剺', '剻', '剼', '剽', '剾', '剿', '劀', '劁', '劂',
+    '劃', '劄', '劅', '劆', '劇', '劈', '劉', '劊', '劋', '劌', '劍', '劎', '劏', '劐', '劑', '劒',
+    '劓', '劔', '劕', '劖', '劗', '劘', '劙', '劚', '力', '劜', '劝', '办', '功', '加', '务', '劢',
+    '劣', '劤', '劥', '劦', '劧', '动', '助', '努', '劫', '劬', '劭', '劮', '劯', '劰', '励', '劲',
+    '劳', '労', '劵', '劶', '劷', '劸', '効', '劺', '劻', '劼', '劽', '劾', '势', '勀', '勁', '勂',
+    '勃', '勄', '勅', '勆', '勇', '勈', '勉', '勊', '勋', '勌', '勍', '勎', '勏', '勐', '勑', '勒',
+    '勓', '勔', '動', '勖', '勗', '勘', '務', '勚', '勛', '勜', '勝', '勞', '募', '勠', '勡', '勢',
+    '勣', '勤', '勥', '勦', '勧', '勨', '勩', '勪', '勫', '勬', '勭', '勮', '勯', '勰', '勱', '勲',
+    '勳', '勴', '勵', '勶', '勷', '勸', '勹', '勺', '勻', '勼', '勽', '勾', '勿', '匀', '匁', '匂',
+    '匃', '匄', '包', '匆', '匇', '匈', '匉', '匊', '匋', '匌', '匍', '匎', '匏', '匐', '匑', '匒',
+    '匓', '匔', '匕', '化', '北', '匘', '匙', '匚', '匛', '匜', '匝', '匞', '匟', '匠', '匡', '匢',
+    '匣', '匤', '匥', '匦', '匧', '匨', '匩', '匪', '匫', '匬', '匭', '匮', '匯', '匰', '匱', '匲',
+    '匳', '匴', '匵', '匶', '匷', '匸', '匹', '区', '医', '匼', '匽', '匾', '匿', '區', '十', '卂',
+    '千', '卄', '卅', '卆', '升', '午', '卉', '半', '卋', '卌', '卍', '华', '协', '卐', '卑', '卒',
+    '卓', '協', '单', '卖', '南', '単', '卙', '博', '卛', '卜', '卝', '卞', '卟', '占', '卡', '卢',
+    '卣', '卤', '卥', '卦', '卧', '卨', '卩', '卪', '卫', '卬', '卭', '卮', '卯', '印', '危', '卲',
+    '即', '却', '卵', '卶', '卷', '卸', '卹', '卺', '卻', '卼', '卽', '卾', '卿', '厀', '厁', '厂',
+    '厃', '厄', '厅', '历', '厇', '厈', '厉', '厊', '压', '厌', '厍', '厎', '厏', '厐', '厑', '厒',
+    '厓', '厔', '厕', '厖', '厗', '厘', '厙', '厚', '厛', '厜', '厝', '厞', '原', '厠', '厡', '厢',
+    '厣', '厤', '厥', '厦', '厧', '厨', '厩', '厪', '厫', '厬', '厭', '厮', '厯', '厰', '厱', '厲',
+    '厳', '厴', '厵', '厶', '厷', '厸', '厹', '厺', '去', '厼', '厽', '厾', '县', '叀', '叁', '参',
+    '參', '叄', '叅', '叆', '叇', '又', '叉', '及', '友', '双', '反', '収', '叏', '叐', '发', '叒',
+    '叓', '叔', '叕', '取', '受', '变', '叙', '叚', '叛', '叜', '叝', '叞', '叟', '叠', '叡', '叢',
+    '口', '古', '句', '另', '叧', '叨', '叩', '只', '叫', '召', '叭', '叮', '可', '台', '叱', '史',
+    '右', '叴', '叵', '叶', '号', '司', '叹', '叺', '叻', '叼', '叽', '叾', '叿', '吀', '吁', '吂',
+    '吃', '各', '吅', '吆', '吇', '合', '吉', '吊', '吋', '同', '名', '后', '吏', '吐', '向', '吒',
+    '吓', '吔', '吕', '吖', '吗', '吘', '吙', '吚', '君', '吜', '吝', '吞', '吟', '吠', '吡', '吢',
+    '吣', '吤', '吥', '否', '吧', '吨', '吩', '吪', '含', '听', '吭', '吮', '启', '吰', '吱', '吲',
+    '吳', '吴', '吵', '吶', '吷', '吸', '吹', '吺', '吻', '吼', '吽', '吾', '吿', '呀', '呁', '呂',
+    '呃', '呄', '呅', '呆', '呇', '呈', '呉', '告', '呋', '呌', '呍', '呎', '呏', '呐', '呑', '呒',
+    '呓', '呔', '呕', '呖', '呗', '员', '呙', '呚', '呛', '呜', '呝', '呞', '呟', '呠', '呡', '呢',
+    '呣', '呤', '呥', '呦', '呧', '周', '呩', '呪', '呫', '呬', '呭', '呮', '呯', '呰', '呱', '呲',
+    '味', '呴', '呵', '呶', '呷', '呸', '呹', '呺', '呻', '呼', '命', '呾', '呿', '咀', '咁', '咂',
+    '咃', '咄', '咅', '咆', '咇', '咈', '咉', '咊', '咋', '和', '咍', '咎', '咏', '咐', '咑', '咒',
+    '咓', '咔', '咕', '咖', '咗', '咘', '咙', '咚', '咛', '咜', '咝', '咞', '咟', '咠', '咡', '咢',
+    '咣', '咤', '咥', '咦', '咧', '咨', '咩', '咪', '咫', '咬', '咭', '咮', '咯', '咰', '咱', '咲',
+    '咳', '咴', '咵', '咶', '咷', '咸', '咹', '咺', '咻', '咼', '咽', '咾', '咿', '哀', '品', '哂',
+    '哃', '哄', '哅', '哆', '哇', '哈', '哉', '哊', '哋', '哌', '响', '哎', '哏', '哐', '哑', '哒',
+    '哓', '哔', '哕', '哖', '哗', '哘', '哙', '哚', '哛', '哜', '哝', '哞', '哟', '哠', '員', '哢',
+    '哣', '哤', '哥', '哦', '哧', '哨', '哩', '哪', '哫', '哬', '哭', '哮', '哯', '哰', '哱', '哲',
+    '哳', '哴', '哵', '哶', '哷', '哸', '哹', '哺', '哻', '哼', '哽', '哾', '哿', '唀', '唁', '唂',
+    '唃', '唄', '唅', '唆', '唇', '唈', '唉', '唊', '唋', '唌', '唍', '唎', '唏', '唐', '唑', '唒',
+    '唓', '唔', '唕', '唖', '唗', '唘', '唙', '唚', '唛', '唜', '唝', '唞', '唟', '唠', '唡', '唢',
+    '唣', '唤', '唥', '唦', '唧', '唨', '唩', '唪', '唫', '唬', '唭', '售', '唯', '唰', '唱', '唲',
+    '唳', '唴', '唵', '唶', '唷', '唸', '唹', '唺', '唻', '唼', '唽', '唾', '唿', '啀', '啁', '啂',
+    '啃', '啄', '啅', '商', '啇', '啈', '啉', '啊', '啋', '啌', '啍', '啎', '問', '啐', '啑', '啒',
+    '啓', '啔', '啕', '啖', '啗', '啘', '啙', '啚', '啛', '啜', '啝', '啞', '啟', '啠', '啡', '啢',
+    '啣', '啤', '啥', '啦', '啧', '啨', '啩', '啪', '啫', '啬', '啭', '啮', '啯', '啰', '啱', '啲',
+    '啳', '啴', '啵', '啶', '啷', '啸', '啹', '啺', '啻', '啼', '啽', '啾', '啿', '喀', '喁', '喂',
+    '喃', '善', '喅', '喆', '喇', '喈', '喉', '喊', '喋', '喌', '喍', '喎', '喏', '喐', '喑', '喒',
+    '喓', '喔', '喕', '喖', '喗', '喘', '喙', '喚', '喛', '喜', '喝', '喞', '喟', '喠', '喡', '喢',
+    '喣', '喤', '喥', '喦', '喧', '喨', '喩', '喪', '喫', '喬', '喭', '單', '喯', '喰', '喱', '喲',
+    '喳', '喴', '喵', '営', '喷', '喸', '喹', '喺', '喻', '喼', '喽', '喾', '喿', '嗀', '嗁', '嗂',
+    '嗃', '嗄', '嗅', '嗆', '嗇', '嗈', '嗉', '嗊', '嗋', '嗌', '嗍', '嗎', '嗏', '嗐', '嗑', '嗒',
+    '嗓', '嗔', '嗕', '嗖', '嗗', '嗘', '嗙', '嗚', '嗛', '嗜', '嗝', '嗞', '嗟', '嗠', '嗡', '嗢',
+    '嗣', '嗤', '嗥', '嗦', '嗨', '嗩', '嗪', '嗫', '嗬', '嗭', '嗮', '嗰', '嗱', '嗲', '嗳', '嗴',
+    '嗵', '嗶', '嗷', '嗸', '嗹', '嗺', '嗻', '嗼', '嗽', '嗾', '嗿', '嘀', '嘁', '嘂', '嘃', '嘄',
+    '嘅', '嘆', '嘇', '嘈', '嘉', '嘊', '嘋', '嘌', '嘍', '嘎', '嘏', '嘐', '嘑', '嘒', '嘓', '嘔',
+    '嘕', '嘖', '嘗', '嘘', '嘙', '嘚', '嘛', '嘜', '嘝', '嘞', '嘟', '嘠', '嘡', '嘢', '嘣', '嘤',
+    '嘥', '嘦', '嘧', '嘨', '嘩', '嘪', '嘫', '嘬', '嘭', '嘮', '嘯', '嘰', '嘱', '嘲', '嘳', '嘴',
+    '嘵', '嘶', '嘷', '嘸', '嘹', '嘺', '嘻', '嘼', '嘽', '嘾', '嘿', '噀', '噁', '噂', '噃', '噄',
+    '噅', '噆', '噇', '噈', '噉', '噊', '噋', '噌', '噍', '噎', '噏', '噐', '噑', '噒', '噓', '噔',
+    '噕', '噖', '噗', '噘', '噙', '噚', '噛', '噜', '噝', '噞', '噟', '噠', '噡', '噢', '噣', '噤',
+    '噥', '噦', '噧', '器', '噩', '噪', '噫', '噬', '噭', '噮', '噯', '噰', '噱', '噲', '噳', '噴',
+    '噵', '噶', '噷', '噸', '噹', '噺', '噻', '噼', '噽', '噾', '噿', '嚀', '嚁', '嚂', '嚃', '嚄',
+    '嚅', '嚆', '嚇', '嚈', '嚉', '嚊', '嚋', '嚌', '嚍', '嚎', '嚏', '嚐', '嚑', '嚒', '嚓', '嚔',
+    '嚕', '嚖', '嚗', '嚘', '嚙', '嚚', '嚛', '嚜', '嚝', '嚞', '嚟', '嚠', '嚡', '嚢', '嚣', '嚤',
+    '嚥', '嚦', '嚧', '嚨', '嚩', '嚪', '嚫', '嚬', '嚭', '嚮', '嚯', '嚰', '嚱', '嚲', '嚳', '嚴',
+    '嚵', '嚶', '嚷', '嚸', '嚹', '嚺', '嚻', '嚼', '嚽', '嚾', '嚿', '囀', '囁', '囂', '囃', '囄',
+    '囅', '囆', '囇', '囈', '囉', '囊', '囋', '囌', '囍', '囎', '囏', '囐', '囑', '囒', '囓', '囔',
+    '囕', '囖', '囗', '囘', '囙', '囚', '四', '囜', '囝', '回', '囟', '因', '囡', '团', '団', '囤',
+    '囥', '囦', '囧', '囨', '囩', '囪', '囫', '囬', '园', '囮', '囯', '困', '囱', '囲', '図', '围',
+    '囵', '囶', '囷', '囸', '囹', '固', '囻', '囼', '国', '图', '囿', '圀', '圁', '圂', '圃', '圄',
+    '圅', '圆', '圇', '圈', '圉', '圊', '國', '圌', '圍', '圎', '圏', '圐', '圑', '園', '圓', '圔',
+    '圕', '圖', '圗', '團', '圙', '圚', '圛', '圜', '圝', '圞', '土', '圠', '圡', '圢', '圣', '圤',
+    '圥', '圦', '圧', '在', '圩', '圪', '圫', '圬', '圭', '圮', '圯', '地', '圱', '圲', '圳', '圴',
+    '圵', '圶', '圷', '圸', '圹', '场', '圻', '圼', '圽', '圾', '圿', '址', '坁', '坂', '坃', '坄',
+    '坅', '坆', '均', '坈', '坉', '坊', '坋', '坌', '坍', '坎', '坏', '坐', '坑', '坒', '坓', '坔',
+    '坕', '坖', '块', '坘', '坙', '坚', '坛', '坜', '坝', '坞', '坟', '坠', '坡', '坢', '坣', '坤',
+    '坥', '坦', '坧', '坨', '坩', '坪', '坫', '坬', '坭', '坮', '坯', '坰', '坱', '坲', '坳', '坴',
+    '坵', '坶', '坷', '坸', '坹', '坺', '坻', '坼', '坽', '坾', '坿', '垀', '垁', '垂', '垃', '垄',
+    '垅', '垆', '垇', '垈', '垉', '垊', '型', '垌', '垍', '垎', '垏', '垐', '垑', '垒', '垓', '垔',
+    '垕', '垖', '垗', '垘', '垙', '垚', '垛', '垜', '垝', '垞', '垟', '垠', '垡', '垢', '垣', '垤',
+    '垥', '垦', '垧', '垨', '垩', '垪', '垫', '垬', '垭', '垮', '垯', '垰', '垱', '垲', '垳', '垴',
+    '垵', '垶', '垷', '垸', '垹', '垺', '垻', '垼', '垽', '垾', '垿', '埀', '埁', '埂', '埃', '埄',
+    '埅', '埆', '埇', '埈', '埉', '埊', '埋', '埌', '埍', '城', '埏', '埐', '埑', '埒', '埓', '埔',
+    '埕', '埖', '埗', '埘', '埙', '埚', '埛', '埜', '埝', '埞', '域', '埠', '埡', '埢', '埣', '埤',
+    '埥', '埦', '埧', '埨', '埩', '埪', '埫', '埬', '埭', '埮', '埯', '埰', '埱', '埲', '埳', '埴',
+    '埵', '埶', '執', '埸', '培', '基', '埻', '埼', '埽', '埾', '埿', '堀', '堁', '堂', '堃', '堄',
+    '堅', '堆', '堇', '堈', '堉', '堊', '堋', '堌', '堍', '堎', '堏', '堐', '堑', '堒', '堓', '堔',
+    '堕', '堖', '堗', '堘', '堙', '堚', '堛', '堜', '堝', '堞', '堟', '堠', '堡', '堢', '堣', '堤',
+    '堥', '堦', '堧', '堨', '堩', '堪', '堫', '堬', '堭', '堮', '堯', '堰', '報', '堲', '堳', '場',
+    '堵', '堶', '堷', '堸', '堹', '堺', '堻', '堼', '堽', '堾', '堿', '塀', '塁', '塂', '塃', '塄',
+    '塅', '塆', '塇', '塈', '塉', '塊', '塋', '塌', '塍', '塎', '塏', '塐', '塑', '塒', '塓', '塔',
+    '塕', '塖', '塗', '塘', '塙', '塚', '塛', '塜', '塝', '塞', '塟', '塠', '塡', '塢', '塣', '塤',
+    '塥', '塦', '塧', '塨', '塩', '塪', '填', '塬', '塭', '塮', '塯', '塰', '塱', '塲', '塳', '塴',
+    '塵', '塶', '塷', '塸', '塹', '塺', '塻', '塼', '塽', '塾', '塿', '墀', '墁', '墂', '境', '墄',
+    '墅', '墆', '墇', '墈', '墉', '墊', '墋', '墌', '墍', '墎', '墏', '墐', '墑', '墒', '墓', '墔',
+    '墕', '墖', '増', '墘', '墙', '墚', '墛', '墜', '墝', '增', '墟', '墠', '墡', '墢', '墣', '墤',
+    '墥', '墦', '墧', '墨', '墩', '墪', '墫', '墬', '墭', '墮', '墯', '墰', '墱', '墲', '墳', '墴',
+    '墵', '墶', '墷', '墸', '墹', '墺', '墻', '墼', '墽', '墾', '墿', '壀', '壁', '壂', '壃', '壄',
+    '壅', '壆', '壇', '壈', '壉', '壊', '壋', '壌', '壍', '壎', '壏', '壐', '壑', '壒', '壓', '壔',
+    '壕', '壖', '壗', '壘', '壙', '壚', '壛', '壜', '壝', '壞', '壟', '壠', '壡', '壢', '壣', '壤',
+    '壥', '壦', '壧', '壨', '壩', '壪', '士', '壬', '壭', '壮', '壯', '声', '壱', '売', '壳', '壴',
+    '壵', '壶', '壷', '壸', '壹', '壺', '壻', '壼', '壽', '壾', '壿', '夀', '夁', '夂', '夃', '处',
+    '夅', '夆', '备', '夈', '変', '夊', '夋', '夌', '复', '夎', '夏', '夐', '夑', '夒', '夓', '夔',
+    '夕', '外', '夗', '夘', '夙', '多', '夛', '夜', '夝', '夞', '够', '夠', '夡', '夢', '夣', '夤',
+    '夥', '夦', '大', '夨', '天', '太', '夫', '夬', '夭', '央', '夯', '夰', '失', '夲', '夳', '头',
+    '夵', '夶', '夷', '夸', '夹', '夺', '夻', '夼', '夽', '夾', '夿', '奀', '奁', '奂', '奃', '奄',
+    '奅', '奆', '奇', '奈', '奉', '奊', '奋', '奌', '奍', '奎', '奏', '奐', '契', '奒', '奓', '奔',
+    '奕', '奖', '套', '奘', '奙', '奚', '奛', '奜', '奝', '奞', '奟', '奠', '奡', '奢', '奣', '奤',
+    '奥', '奦', '奧', '奨', '奩', '奪', '奫', '奬', '奭', '奮', '奯', '奰', '奱', '奲', '女', '奴',
+    '奵', '奶', '奷', '奸', '她', '奺', '奻', '奼', '好', '奾', '奿', '妀', '妁', '如', '妃', '妄',
+    '妅', '妆', '妇', '妈', '妉', '妊', '妋', '妌', '妍', '妎', '妏', '妐', '妑', '妒', '妓', '妔',
+    '妕', '妖', '妗', '妘', '妙', '妚', '妛', '妜', '妝', '妞', '妟', '妠', '妡', '妢', '妣', '妤',
+    '妥', '妦', '妧', '妨', '妩', '妪', '妫', '妬', '妭', '妮', '妯', '妰', '妱', '妲', '妳', '妴',
+    '妵', '妶', '妷', '妸', '妹', '妺', '妻', '妼', '妽', '妾', '妿', '姀', '姁', '姂', '姃', '姄',
+    '姅', '姆', '姇', '姈', '姉', '姊', '始', '姌', '姍', '姎', '姏', '姐', '姑', '姒', '姓', '委',
+    '姕', '姖', '姗', '姘', '姙', '姚', '姛', '姜', '姝', '姞', '姟', '姠', '姡', '姢', '姣', '姤',
+    '姥', '姦', '姧', '姨', '姩', '姪', '姫', '姬', '姭', '姮', '姯', '姰', '姱', '姲', '姳', '姴',
+    '姵', '姶', '姷', '姸', '姹', '姺', '姻', '姼', '姽', '姾', '姿', '娀', '威', '娂', '娃', '娄',
+    '娅', '娆', '娇', '娈', '娉', '娊', '娋', '娌', '娍', '娎', '娏', '娐', '娑', '娒', '娓', '娔',
+    '娕', '娖', '娗', '娘', '娙', '娚', '娛', '娜', '娝', '娞', '娟', '娠', '娡', '娢', '娣', '娤',
+    '娥', '娦', '娧', '娨', '娩', '娪', '娫', '娬', '娭', '娮', '娯', '娰', '娱', '娲', '娳', '娴',
+    '娵', '娶', '娷', '娸', '娹', '娺', '娻', '娼', '娽', '娾', '娿', '婀', '婁', '婂', '婃', '婄',
+    '婅', '婆', '婇', '婈', '婉', '婊', '婋', '婌', '婍', '婎', '婏', '婐', '婑', '婒', '婓', '婔',
+    '婕', '婖', '婗', '婘', '婙', '婚', '婛', '婜', '婝', '婞', '婟', '婠', '婡', '婢', '婣', '婤',
+    '婥', '婦', '婧', '婨', '婩', '婪', '婫', '婬', '婭', '婮', '婯', '婰', '婱', '婲', '婳', '婴',
+    '婵', '婶', '婷', '婸', '婹', '婺', '婻', '婼', '婽', '婾', '婿', '媀', '媁', '媂', '媃', '媄',
+    '媅', '媆', '媇', '媈', '媉', '媊', '媋', '媌', '媍', '媎', '媏', '媐', '媑', '媒', '媓', '媔',
+    '媕', '媖', '媗', '媘', '媙', '媚', '媛', '媜', '媝', '媞', '媟', '媠', '媡', '媢', '媣', '媤',
+    '媥', '媦', '媧', '媨', '媩', '媪', '媫', '媬', '媭', '媮', '媯', '媰', '媱', '媲', '媳', '媴',
+    '媵', '媶', '媷', '媸', '媹', '媺', '媻', '媼', '媽', '媾', '媿', '嫀', '嫁', '嫂', '嫃', '嫄',
+    '嫅', '嫆', '嫇', '嫈', '嫉', '嫊', '嫋', '嫌', '嫍', '嫎', '嫏', '嫐', '嫑', '嫒', '嫓', '嫔',
+    '嫕', '嫖', '嫗', '嫘', '嫙', '嫚', '嫛', '嫜', '嫝', '嫞', '嫟', '嫠', '嫡', '嫢', '嫣', '嫤',
+    '嫥', '嫦', '嫧', '嫨', '嫩', '嫪', '嫫', '嫬', '嫭', '嫮', '嫯', '嫰', '嫱', '嫲', '嫳', '嫴',
+    '嫵', '嫶', '嫷', '嫸', '嫹', '嫺', '嫻', '嫼', '嫽', '嫾', '嫿', '嬀', '嬁', '嬂', '嬃', '嬄',
+    '嬅', '嬆', '嬇', '嬈', '嬉', '嬊', '嬋', '嬌', '嬍', '嬎', '嬏', '嬐', '嬑', '嬒', '嬓', '嬔',
+    '嬕', '嬖', '嬗', '嬘', '嬙', '嬚', '嬛', '嬜', '嬝', '嬞', '嬟', '嬠', '嬡', '嬢', '嬣', '嬤',
+    '嬥', '嬦', '嬧', '嬨', '嬩', '嬪', '嬫', '嬬', '嬭', '嬮', '嬯', '嬰', '嬱', '嬲', '嬳', '嬴',
+    '嬵', '嬶', '嬷', '嬸', '嬹', '嬺', '嬻', '嬼', '嬽', '嬾', '嬿', '孀', '孁', '孂', '孃', '孄',
+    '孅', '孆', '孇', '孈', '孉', '孊', '孋', '孌', '孍', '孎', '孏', '子', '孑', '孒', '孓', '孔',
+    '孕', '孖', '字', '存', '孙', '孚', '孛', '孜', '孝', '孞', '孟', '孠', '孡', '孢', '季', '孤',
+    '孥', '学', '孧', '孨', '孩', '孪', '孫', '孬', '孭', '孮', '孯', '孰', '孱', '孲', '孳', '孴',
+    '孵', '孶', '孷', '學', '孹', '孺', '孻', '孼', '孽', '孾', '孿', '宀', '宁', '宂', '它', '宄',
+    '宅', '宆', '宇', '守', '安', '宊', '宋', '完', '宍', '宎', '宏', '宐', '宑', '宒', '宓', '宔',
+    '宕', '宖', '宗', '官', '宙', '定', '宛', '宜', '宝', '实', '実', '宠', '审', '客', '宣', '室',
+    '宥', '宦', '宧', '宨', '宩', '宪', '宫', '宬', '宭', '宮', '宯', '宰', '宱', '宲', '害', '宴',
+    '宵', '家', '宷', '宸', '容', '宺', '宻', '宼', '宽', '宾', '宿', '寀', '寁', '寂', '寃', '寄',
+    '寅', '密', '寇', '寈', '寉', '寊', '寋', '富', '寍', '寎', '寏', '寐', '寑', '寒', '寓', '寔',
+    '寕', '寖', '寗', '寘', '寙', '寚', '寛', '寜', '寝', '寞', '察', '寠', '寡', '寢', '寣', '寤',
+    '寥', '實', '寧', '寨', '審', '寪', '寫', '寬', '寭', '寮', '寯', '寰', '寱', '寲', '寳', '寴',
+    '寵', '寶', '寷', '寸', '对', '寺', '寻', '导', '寽', '対', '寿', '尀', '封', '専', '尃', '射',
+    '尅', '将', '將', '專', '尉', '尊', '尋', '尌', '對', '導', '小', '尐', '少', '尒', '尓', '尔',
+    '尕', '尖', '尗', '尘', '尙', '尚', '尛', '尜', '尝', '尞', '尟', '尠', '尡', '尢', '尣', '尤',
+    '尥', '尦', '尧', '尨', '尩', '尪', '尫', '尬', '尭', '尮', '尯', '尰', '就', '尲', '尳', '尴',
+    '尵', '尶', '尷', '尸', '尹', '尺', '尻', '尼', '尽', '尾', '尿', '局', '屁', '层', '屃', '屄',
+    '居', '屆', '屇', '屈', '屉', '届', '屋', '屌', '屍', '屎', '屏', '屐', '屑', '屒', '屓', '屔',
+    '展', '屖', '屗', '屘', '屙', '屚', '屛', '屜', '屝', '属', '屟', '屠', '屡', '屢', '屣', '層',
+    '履', '屦', '屧', '屨', '屩', '屪', '屫', '屬', '屭', '屮', '屯', '屰', '山', '屲', '屳', '屴',
+    '屵', '屶', '屷', '屸', '屹', '屺', '屻', '屼', '屽', '屾', '屿', '岀', '岁', '岂', '岃', '岄',
+    '岅', '岆', '岇', '岈', '岉', '岊', '岋', '岌', '岍', '岎', '岏', '岐', '岑', '岒', '岓', '岔',
+    '岕', '岖', '岗', '岘', '岙', '岚', '岛', '岜', '岝', '岞', '岟', '岠', '岡', '岢', '岣', '岤',
+    '岥', '岦', '岧', '岨', '岩', '岪', '岫', '岬', '岭', '岮', '岯', '岰', '岱', '岲', '岳', '岴',
+    '岵', '岶', '岷', '岸', '岹', '岺', '岻', '岼', '岽', '岾', '岿', '峀', '峁', '峂', '峃', '峄',
+    '峅', '峆', '峇', '峈', '峉', '峊', '峋', '峌', '峍', '峎', '峏', '峐', '峑', '峒', '峓', '峔',
+    '峕', '峖', '峗', '峘', '峙', '峚', '峛', '峜', '峝', '峞', '峟', '峠', '峡', '峢', '峣', '峤',
+    '峥', '峦', '峧', '峨', '峩', '峪', '峫', '峬', '峭', '峮', '峯', '峰', '峱', '峲', '峳', '峴',
+    '峵', '島', '峷', '峸', '峹', '峺', '峻', '峼', '峽', '峾', '峿', '崀', '崁', '崂', '崃', '崄',
+    '崅', '崆', '崇', '崈', '崉', '崊', '崋', '崌', '崍', '崎', '崏', '崐', '崑', '崒', '崓', '崔',
+    '崕', '崖', '崗', '崘', '崙', '崚', '崛', '崜', '崝', '崞', '崟', '崠', '崡', '崢', '崣', '崤',
+    '崥', '崦', '崧', '崨', '崩', '崪', '崫', '崬', '崭', '崮', '崯', '崰', '崱', '崲', '崳', '崴',
+    '崵', '崶', '崷', '崸', '崹', '崺', '崻', '崼', '崽', '崾', '崿', '嵀', '嵁', '嵂', '嵃', '嵄',
+    '嵅', '嵆', '嵇', '嵈', '嵉', '嵊', '嵋', '嵌', '嵍', '嵎', '嵏', '嵐', '嵑', '嵒', '嵓', '嵔',
+    '嵕', '嵖', '嵗', '嵘', '嵙', '嵚', '嵛', '嵜', '嵝', '嵞', '嵟', '嵠', '嵡', '嵢', '嵣', '嵤',
+    '嵥', '嵦', '嵧', '嵨', '嵩', '嵪', '嵫', '嵬', '嵭', '嵮', '嵯', '嵰', '嵱', '嵲', '嵳', '嵴',
+    '嵵', '嵶', '嵷', '嵸', '嵹', '嵺', '嵻', '嵼', '嵽', '嵾', '嵿', '嶀', '嶁', '嶂', '嶃', '嶄',
+    '嶅', '嶆', '嶇', '嶈', '嶉', '嶊', '嶋', '嶌', '嶍', '嶎', '嶏', '嶐', '嶑', '嶒', '嶓', '嶔',
+    '嶕', '嶖', '嶗', '嶘', '嶙', '嶚', '嶛', '嶜', '嶝', '嶞', '嶟', '嶠', '嶡', '嶢', '嶣', '嶤',
+    '嶥', '嶦', '嶧', '嶨', '嶩', '嶪', '嶫', '嶬', '嶭', '嶮', '嶯', '嶰', '嶱', '嶲', '嶳', '嶴',
+    '嶵', '嶶', '嶷', '嶸', '嶹', '嶺', '嶻', '嶼', '嶽', '嶾', '嶿', '巀', '巁', '巂', '巃', '巄',
+    '巅', '巆', '巇', '巈', '巉', '巊', '巋', '巌', '巍', '巎', '巏', '巐', '巑', '巒', '巓', '巔',
+    '巕', '巖', '巗', '巘', '巙', '巚', '巛', '巜', '川', '州', '巟', '巠', '巡', '巢', '巣', '巤',
+    '工', '左', '巧', '巨', '巩', '巪', '巫', '巬', '巭', '差', '巯', '巰', '己', '已', '巳', '巴',
+    '巵', '巶', '巷', '巸', '巹', '巺', '巻', '巼', '巽', '巾', '巿', '帀', '币', '市', '布', '帄',
+    '帅', '帆', '帇', '师', '帉', '帊', '帋', '希', '帍', '帎', '帏', '帐', '帑', '帒', '帓', '帔',
+    '帕', '帖', '帗', '帘', '帙', '帚', '帛', '帜', '帝', '帞', '帟', '帠', '帡', '帢', '帣', '帤',
+    '帥', '带', '帧', '帨', '帩', '帪', '師', '帬', '席', '帮', '帯', '帰', '帱', '帲', '帳', '帴',
+    '帵', '帶', '帷', '常', '帹', '帺', '帻', '帼', '帽', '帾', '帿', '幀', '幁', '幂', '幃', '幄',
+    '幅', '幆', '幇', '幈', '幉', '幊', '幋', '幌', '幍', '幎', '幏', '幐', '幑', '幒', '幓', '幔',
+    '幕', '幖', '幗', '幘', '幙', '幚', '幛', '幜', '幝', '幞', '幟', '幠', '幡', '幢', '幣', '幤',
+    '幥', '幦', '幧', '幨', '幩', '幪', '幫', '幬', '幭', '幮', '幯', '幰', '幱', '干', '平', '年',
+    '幵', '并', '幷', '幸', '幹', '幺', '幻', '幼', '幽', '幾', '广', '庀', '庁', '庂', '広', '庄',
+    '庅', '庆', '庇', '庈', '庉', '床', '庋', '庌', '庍', '庎', '序', '庐', '庑', '庒', '库', '应',
+    '底', '庖', '店', '庘', '庙', '庚', '庛', '府', '庝', '庞', '废', '庠', '庡', '庢', '庣', '庤',
+    '庥', '度', '座', '庨', '庩', '庪', '庫', '庬', '庭', '庮', '庯', '庰', '庱', '庲', '庳', '庴',
+    '庵', '庶', '康', '庸', '庹', '庺', '庻', '庼', '庽', '庾', '庿', '廀', '廁', '廂', '廃', '廄',
+    '廅', '廆', '廇', '廈', '廉', '廊', '廋', '廌', '廍', '廎', '廏', '廐', '廑', '廒', '廓', '廔',
+    '廕', '廖', '廗', '廘', '廙', '廚', '廛', '廜', '廝', '廞', '廟', '廠', '廡', '廢', '廣', '廤',
+    '廥', '廦', '廧', '廨', '廩', '廪', '廫', '廬', '廭', '廮', '廯', '廰', '廱', '廲', '廳', '廴',
+    '廵', '延', '廷', '廸', '廹', '建', '廻', '廼', '廽', '廾', '廿', '开', '弁', '异', '弃', '弄',
+    '弅', '弆', '弇', '弈', '弉', '弊', '弋', '弌', '弍', '弎', '式', '弐', '弑', '弒', '弓', '弔',
+    '引', '弖', '弗', '弘', '弙', '弚', '弛', '弜', '弝', '弞', '弟', '张', '弡', '弢', '弣', '弤',
+    '弥', '弦', '弧', '弨', '弩', '弪', '弫', '弬', '弭', '弮', '弯', '弰', '弱', '弲', '弳', '弴',
+    '張', '弶', '強', '弸', '弹', '强', '弻', '弼', '弽', '弾', '弿', '彀', '彁', '彂', '彃', '彄',
+    '彅', '彆', '彇', '彈', '彉', '彊', '彋', '彌', '彍', '彎', '彏', '彐', '彑', '归', '当', '彔',
+    '录', '彖', '彗', '彘', '彙', '彚', '彛', '彜', '彝', '彞', '彟', '彠', '彡', '形', '彣', '彤',
+    '彥', '彦', '彧', '彨', '彩', '彪', '彫', '彬', '彭', '彮', '彯', '彰', '影', '彲', '彳', '彴',
+    '彵', '彶', '彷', '彸', '役', '彺', '彻', '彼', '彽', '彾', '彿', '往', '征', '徂', '徃', '径',
+    '待', '徆', '徇', '很', '徉', '徊', '律', '後', '徍', '徎', '徏', '徐', '徑', '徒', '従', '徔',
+    '徕', '徖', '得', '徘', '徙', '徚', '徛', '徜', '徝', '從', '徟', '徠', '御', '徢', '徣', '徤',
+    '徥', '徦', '徧', '徨', '復', '循', '徫', '徬', '徭', '微', '徯', '徰', '徱', '徲', '徳', '徴',
+    '徵', '徶', '德', '徸', '徹', '徺', '徻', '徼', '徽', '徾', '徿', '忀', '忁', '忂', '心', '忄',
+    '必', '忆', '忇', '忈', '忉', '忊', '忋', '忌', '忍', '忎', '忏', '忐', '忑', '忒', '忓', '忔',
+    '忕', '忖', '志', '忘', '忙', '忚', '忛', '応', '忝', '忞', '忟', '忠', '忡', '忢', '忣', '忤',
+    '忥', '忦', '忧', '忨', '忩', '忪', '快', '忬', '忭', '忮', '忯', '忰', '忱', '忲', '忳', '忴',
+    '念', '忶', '忷', '忸', '忹', '忺', '忻', '忼', '忽', '忾', '忿', '怀', '态', '怂', '怃', '怄',
+    '怅', '怆', '怇', '怈', '怉', '怊', '怋', '怌', '怍', '怎', '怏', '怐', '怑', '怒', '怓', '怔',
+    '怕', '怖', '怗', '怘', '怙', '怚', '怛', '怜', '思', '怞', '怟', '怠', '怡', '怢', '怣', '怤',
+    '急', '怦', '性', '怨', '怩', '怪', '怫', '怬', '怭', '怮', '怯', '怰', '怱', '怲', '怳', '怴',
+    '怵', '怶', '怷', '怸', '怹', '怺', '总', '怼', '怽', '怾', '怿', '恀', '恁', '恂', '恃', '恄',
+    '恅', '恆', '恇', '恈', '恉', '恊', '恋', '恌', '恍', '恎', '恏', '恐', '恑', '恒', '恓', '恔',
+    '恕', '恖', '恗', '恘', '恙', '恚', '恛', '恜', '恝', '恞', '恟', '恠', '恡', '恢', '恣', '恤',
+    '恥', '恦', '恧', '恨', '恩', '恪', '恫', '恬', '恭', '恮', '息', '恰', '恱', '恲', '恳', '恴',
+    '恵', '恶', '恷', '恸', '恹', '恺', '恻', '恼', '恽', '恾', '恿', '悀', '悁', '悂', '悃', '悄',
+    '悅', '悆', '悇', '悈', '悉', '悊', '悋', '悌', '悍', '悎', '悏', '悐', '悑', '悒', '悓', '悔',
+    '悕', '悖', '悗', '悘', '悙', '悚', '悛', '悜', '悝', '悞', '悟', '悠', '悡', '悢', '患', '悤',
+    '悥', '悦', '悧', '您', '悩', '悪', '悫', '悬', '悭', '悮', '悯', '悰', '悱', '悲', '悳', '悴',
+    '悵', '悶', '悷', '悸', '悹', '悺', '悻', '悼', '悽', '悾', '悿', '惀', '惁', '惂', '惃', '惄',
+    '情', '惆', '惇', '惈', '惉', '惊', '惋', '惌', '惍', '惎', '惏', '惐', '惑', '惒', '惓', '惔',
+    '惕', '惖', '惗', '惘', '惙', '惚', '惛', '惜', '惝', '惞', '惟', '惠', '惡', '惢', '惣', '惤',
+    '惥', '惦', '惧', '惨', '惩', '惪', '惫', '惬', '惭', '惮', '惯', '惰', '惱', '惲', '想', '惴',
+    '惵', '惶', '惷', '惸', '惹', '惺', '惻', '惼', '惽', '惾', '惿', '愀', '愁', '愂', '愃', '愄',
+    '愅', '愆', '愇', '愈', '愉', '愊', '愋', '愌', '愍', '愎', '意', '愐', '愑', '愒', '愓', '愔',
+    '愕', '愖', '愗', '愘', '愙', '愚', '愛', '愜', '愝', '愞', '感', '愠', '愡', '愢', '愣', '愤',
+    '愥', '愦', '愧', '愨', '愩', '愪', '愫', '愬', '愭', '愮', '愯', '愰', '愱', '愲', '愳', '愴',
+    '愵', '愶', '愷', '愸', '愹', '愺', '愻', '愼', '愽', '愾', '愿', '慀', '慁', '慂', '慃', '慄',
+    '慅', '慆', '慇', '慈', '慉', '慊', '態', '慌', '慍', '慎', '慏', '慐', '慑', '慒', '慓', '慔',
+    '慕', '慖', '慗', '慘', '慙', '慚', '慛', '慜', '慝', '慞', '慟', '慠', '慡', '慢', '慣', '慤',
+    '慥', '慦', '慧', '慨', '慩', '慪', '慫', '慬', '慭', '慮', '慯', '慰', '慱', '慲', '慳', '慴',
+    '慵', '慶', '慷', '慸', '慹', '慺', '慻', '慼', '慽', '慾', '慿', '憀', '憁', '憂', '憃', '憄',
+    '憅', '憆', '憇', '憈', '憉', '憊', '憋', '憌', '憍', '憎', '憏', '憐', '憑', '憒', '憓', '憔',
+    '憕', '憖', '憗', '憘', '憙', '憚', '憛', '憜', '憝', '憞', '憟', '憠', '憡', '憢', '憣', '憤',
+    '憥', '憦', '憧', '憨', '憩', '憪', '憫', '憬', '憭', '憮', '憯', '憰', '憱', '憲', '憳', '憴',
+    '憵', '憶', '憷', '憸', '憹', '憺', '憻', '憼', '憽', '憾', '憿', '懀', '懁', '懂', '懃', '懄',
+    '懅', '懆', '懇', '懈', '應', '懊', '懋', '懌', '懍', '懎', '懏', '懐', '懑', '懒', '懓', '懔',
+    '懕', '懖', '懗', '懘', '懙', '懚', '懛', '懜', '懝', '懞', '懟', '懠', '懡', '懢', '懣', '懤',
+    '懥', '懦', '懧', '懨', '懩', '懪', '懫', '懬', '懭', '懮', '懯', '懰', '懱', '懲', '懳', '懴',
+    '懵', '懶', '懷', '懸', '懹', '懺', '懻', '懼', '懽', '懾', '懿', '戀', '戁', '戂', '戃', '戄',
+    '戅', '戆', '戇', '戈', '戉', '戊', '戋', '戌', '戍', '戎', '戏', '成', '我', '戒', '戓', '戔',
+    '戕', '或', '戗', '战', '戙', '戚', '戛', '戜', '戝', '戞', '戟', '戠', '戡', '戢', '戣', '戤',
+    '戥', '戦', '戧', '戨', '戩', '截', '戫', '戬', '戭', '戮', '戯', '戰', '戱', '戲', '戳', '戴',
+    '戵', '戶', '户', '戸', '戹', '戺', '戻', '戼', '戽', '戾', '房', '所', '扁', '扂', '扃', '扄',
+    '扅', '扆', '扇', '扈', '扉', '扊', '手', '扌', '才', '扎', '扏', '扐', '扑', '扒', '打', '扔',
+    '払', '扖', '扗', '托', '扙', '扚', '扛', '扜', '扝', '扞', '扟', '扠', '扡', '扢', '扣', '扤',
+    '扥', '扦', '执', '扨', '扩', '扪', '扫', '扬', '扭', '扮', '扯', '扰', '扱', '扲', '扳', '扴',
+    '扵', '扶', '扷', '扸', '批', '扺', '扻', '扼', '扽', '找', '承', '技', '抁', '抂', '抃', '抄',
+    '抅', '抆', '抇', '抈', '抉', '把', '抋', '抌', '抍', '抎', '抏', '抐', '抑', '抒', '抓', '抔',
+    '投', '抖', '抗', '折', '抙', '抚', '抛', '抜', '抝', '択', '抟', '抠', '抡', '抢', '抣', '护',
+    '报', '抦', '抧', '抨', '抩', '抪', '披', '抬', '抭', '抮', '抯', '抰', '抱', '抲', '抳', '抴',
+    '抵', '抶', '抷', '抸', '抹', '抺', '抻', '押', '抽', '抾', '抿', '拀', '拁', '拂', '拃', '拄',
+    '担', '拆', '拇', '拈', '拉', '拊', '拋', '拌', '拍', '拎', '拏', '拐', '拑', '拒', '拓', '拔',
+    '拕', '拖', '拗', '拘', '拙', '拚', '招', '拜', '拝', '拞', '拟', '拠', '拡', '拢', '拣', '拤',
+    '拥', '拦', '拧', '拨', '择', '拪', '拫', '括', '拭', '拮', '拯', '拰', '拱', '拲', '拳', '拴',
+    '拵', '拶', '拷', '拸', '拹', '拺', '拻', '拼', '拽', '拾', '拿', '挀', '持', '挂', '挃', '挄',
+    '挅', '挆', '指', '挈', '按', '挊', '挋', '挌', '挍', '挎', '挏', '挐', '挑', '挒', '挓', '挔',
+    '挕', '挖', '挗', '挘', '挙', '挚', '挛', '挜', '挝', '挞', '挟', '挠', '挡', '挢', '挣', '挤',
+    '挥', '挦', '挧', '挨', '挩', '挪', '挫', '挬', '挭', '挮', '振', '挰', '挱', '挲', '挳', '挴',
+    '挵', '挶', '挷', '挸', '挹', '挺', '挻', '挼', '挽', '挾', '挿', '捀', '捁', '捂', '捃', '捄',
+    '捅', '捆', '捇', '捈', '捉', '捊', '捋', '捌', '捍', '捎', '捏', '捐', '捑', '捒', '捓', '捔',
+    '捕', '捖', '捗', '捘', '捙', '捚', '捛', '捜', '捝', '捞', '损', '捠', '捡', '换', '捣', '捤',
+    '捥', '捦', '捧', '捨', '捩', '捪', '捫', '捬', '捭', '据', '捯', '捰', '捱', '捲', '捳', '捴',
+    '捵', '捶', '捷', '捸', '捹', '捺', '捻', '捼', '捽', '捾', '捿', '掀', '掁', '掂', '掃', '掄',
+    '掅', '掆', '掇', '授', '掉', '掊', '掋', '掌', '掍', '掎', '掏', '掐', '掑', '排', '掓', '掔',
+    '掕', '掖', '掗', '掘', '掙', '掚', '掛', '掜', '掝', '掞', '掟', '掠', '採', '探', '掣', '掤',
+    '接', '掦', '控', '推', '掩', '措', '掫', '掬', '掭', '掮', '掯', '掰', '掱', '掲', '掳', '掴',
+    '掵', '掶', '掷', '掸', '掹', '掺', '掻', '掼', '掽', '掾', '掿', '揀', '揁', '揂', '揃', '揄',
+    '揅', '揆', '揇', '揈', '揉', '揊', '揋', '揌', '揍', '揎', '描', '提', '揑', '插', '揓', '揔',
+    '揕', '揖', '揗', '揘', '揙', '揚', '換', '揜', '揝', '揞', '揟', '揠', '握', '揢', '揣', '揤',
+    '揥', '揦', '揧', '揨', '揩', '揪', '揫', '揬', '揭', '揮', '揯', '揰', '揱', '揲', '揳', '援',
+    '揵', '揶', '揷', '揸', '揹', '揺', '揻', '揼', '揽', '揾', '揿', '搀', '搁', '搂', '搃', '搄',
+    '搅', '搆', '搇', '搈', '搉', '搊', '搋', '搌', '損', '搎', '搏', '搐', '搑', '搒', '搓', '搔',
+    '搕', '搖', '搗', '搘', '搙', '搚', '搛', '搜', '搝', '搞', '搟', '搠', '搡', '搢', '搣', '搤',
+    '搥', '搦', '搧', '搨', '搩', '搪', '搫', '搬', '搭', '搮', '搯', '搰', '搱', '搲', '搳', '搴',
+    '搵', '搶', '搷', '搸', '搹', '携', '搻', '搼', '搽', '搾', '搿', '摀', '摁', '摂', '摃', '摄',
+    '摅', '摆', '摇', '摈', '摉', '摊', '摋', '摌', '摍', '摎', '摏', '摐', '摑', '摒', '摓', '摔',
+    '摕', '摖', '摗', '摘', '摙', '摚', '摛', '摜', '摝', '摞', '摟', '摠', '摡', '摢', '摣', '摤',
+    '摥', '摦', '摧', '摨', '摩', '摪', '摫', '摬', '摭', '摮', '摯', '摰', '摱', '摲', '摳', '摴',
+    '摵', '摶', '摷', '摸', '摹', '摺', '摻', '摼', '摽', '摾', '摿', '撀', '撁', '撂', '撃', '撄',
+    '撅', '撆', '撇', '撈', '撉', '撊', '撋', '撌', '撍', '撎', '撏', '撐', '撑', '撒', '撓', '撔',
+    '撕', '撖', '撗', '撘', '撙', '撚', '撛', '撜', '撝', '撞', '撟', '撠', '撡', '撢', '撣', '撤',
+    '撥', '撦', '撧', '撨', '撩', '撪', '撫', '撬', '播', '撮', '撯', '撰', '撱', '撲', '撳', '撴',
+    '撵', '撶', '撷', '撸', '撹', '撺', '撻', '撼', '撽', '撾', '撿', '擀', '擁', '擂', '擃', '擄',
+    '擅', '擆', '擇', '擈', '擉', '擊', '擋', '擌', '操', '擎', '擏', '擐', '擑', '擒', '擓', '擔',
+    '擕', '擖', '擗', '擘', '擙', '據', '擛', '擜', '擝', '擞', '擟', '擠', '擡', '擢', '擣', '擤',
+    '擥', '擦', '擧', '擨', '擩', '擪', '擫', '擬', '擭', '擮', '擯', '擰', '擱', '擲', '擳', '擴',
+    '擵', '擶', '擷', '擸', '擹', '擺', '擻', '擼', '擽', '擾', '擿', '攀', '攁', '攂', '攃', '攄',
+    '攅', '攆', '攇', '攈', '攉', '攊', '攋', '攌', '攍', '攎', '攏', '攐', '攑', '攒', '攓', '攔',
+    '攕', '攖', '攗', '攘', '攙', '攚', '攛', '攜', '攝', '攞', '攟', '攠', '攡', '攢', '攣', '攤',
+    '攥', '攦', '攧', '攨', '攩', '攪', '攫', '攬', '攭', '攮', '支', '攰', '攱', '攲', '攳', '攴',
+    '攵', '收', '攷', '攸', '改', '攺', '攻', '攼', '攽', '放', '政', '敀', '敁', '敂', '敃', '敄',
+    '故', '敆', '敇', '效', '敉', '敊', '敋', '敌', '敍', '敎', '敏', '敐', '救', '敒', '敓', '敔',
+    '敕', '敖', '敗', '敘', '教', '敚', '敛', '敜', '敝', '敞', '敟', '敠', '敡', '敢', '散', '敤',
+    '敥', '敦', '敧', '敨', '敩', '敪', '敫', '敬', '敭', '敮', '敯', '数', '敱', '敲', '敳', '整',
+    '敵', '敶', '敷', '數', '敹', '敺', '敻', '敼', '敽', '敾', '敿', '斀', '斁', '斂', '斃', '斄',
+    '斅', '斆', '文', '斈', '斉', '斊', '斋', '斌', '斍', '斎', '斏', '斐', '斑', '斒', '斓', '斔',
+    '斕', '斖', '斗', '斘', '料', '斚', '斛', '斜', '斝', '斞', '斟', '斠', '斡', '斢', '斣', '斤',
+    '斥', '斦', '斧', '斨', '斩', '斪', '斫', '斬', '断', '斮', '斯', '新', '斱', '斲', '斳', '斴',
+    '斵', '斶', '斷', '斸', '方', '斺', '斻', '於', '施', '斾', '斿', '旀', '旁', '旂', '旃', '旄',
+    '旅', '旆', '旇', '旈', '旉', '旊', '旋', '旌', '旍', '旎', '族', '旐', '旑', '旒', '旓', '旔',
+    '旕', '旖', '旗', '旘', '旙', '旚', '旛', '旜', '旝', '旞', '旟', '无', '旡', '既', '旣', '旤',
+    '日', '旦', '旧', '旨', '早', '旪', '旫', '旬', '旭', '旮', '旯', '旰', '旱', '旲', '旳', '旴',
+    '旵', '时', '旷', '旸', '旹', '旺', '旻', '旼', '旽', '旾', '旿', '昀', '昁', '昂', '昃', '昄',
+    '昅', '昆', '昇', '昈', '昉', '昊', '昋', '昌', '昍', '明', '昏', '昐', '昑', '昒', '易', '昔',
+    '昕', '昖', '昗', '昘', '昙', '昚', '昛', '昜', '昝', '昞', '星', '映', '昡', '昢', '昣', '昤',
+    '春', '昦', '昧', '昨', '昩', '昪', '昫', '昬', '昭', '昮', '是', '昰', '昱', '昲', '昳', '昴',
+    '昵', '昶', '昷', '昸', '昹', '昺', '昻', '昼', '昽', '显', '昿', '晀', '晁', '時', '晃', '晄',
+    '晅', '晆', '晇', '晈', '晉', '晊', '晋', '晌', '晍', '晎', '晏', '晐', '晑', '晒', '晓', '晔',
+    '晕', '晖', '晗', '晘', '晙', '晚', '晛', '晜', '晝', '晞', '晟', '晠', '晡', '晢', '晣', '晤',
+    '晥', '晦', '晧', '晨', '晩', '晪', '晫', '晬', '晭', '普', '景', '晰', '晱', '晲', '晳', '晴',
+    '晵', '晶', '晷', '晸', '晹', '智', '晻', '晼', '晽', '晾', '晿', '暀', '暁', '暂', '暃', '暄',
+    '暅', '暆', '暇', '暈', '暉', '暊', '暋', '暌', '暍', '暎', '暏', '暐', '暑', '暒', '暓', '暔',
+    '暕', '暖', '暗', '暘', '暙', '暚', '暛', '暜', '暝', '暞', '暟', '暠', '暡', '暢', '暣', '暤',
+    '暥', '暦', '暧', '暨', '暩', '暪', '暫', '暬', '暭', '暮', '暯', '暰', '暱', '暲', '暳', '暴',
+    '暵', '暶', '暷', '暸', '暹', '暺', '暻', '暼', '暽', '暾', '暿', '曀', '曁', '曂', '曃', '曄',
+    '曅', '曆', '曇', '曈', '曉', '曊', '曋', '曌', '曍', '曎', '曏', '曐', '曑', '曒', '曓', '曔',
+    '曕', '曖', '曗', '曘', '曙', '曚', '曛', '曜', '曝', '曞', '曟', '曠', '曡', '曢', '曣', '曤',
+    '曥', '曦', '曧', '曨', '曩', '曪', '曫', '曬', '曭', '曮', '曯', '曰', '曱', '曲', '曳', '更',
+    '曵', '曶', '曷', '書', '曹', '曺', '曻', '曼', '曽', '曾', '替', '最', '朁', '朂', '會', '朄',
+    '朅', '朆', '朇', '月', '有', '朊', '朋', '朌', '服', '朎', '朏', '朐', '朑', '朒', '朓', '朔',
+    '朕', '朖', '朗', '朘', '朙', '朚', '望', '朜', '朝', '朞', '期', '朠', '朡', '朢', '朣', '朤',
+    '朥', '朦', '朧', '木', '朩', '未', '末', '本', '札', '朮', '术', '朰', '朱', '朲', '朳', '朴',
+    '朵', '朶', '朷', '朸', '朹', '机', '朻', '朼', '朽', '朾', '朿', '杀', '杁', '杂', '权', '杄',
+    '杅', '杆', '杇', '杈', '杉', '杊', '杋', '杌', '杍', '李', '杏', '材', '村', '杒', '杓', '杔',
+    '杕', '杖', '杗', '杘', '杙', '杚', '杛', '杜', '杝', '杞', '束', '杠', '条', '杢', '杣', '杤',
+    '来', '杦', '杧', '杨', '杩', '杪', '杫', '杬', '杭', '杮', '杯', '杰', '東', '杲', '杳', '杴',
+    '杵', '杶', '杷', '杸', '杹', '杺', '杻', '杼', '杽', '松', '板', '枀', '极', '枂', '枃', '构',
+    '枅', '枆', '枇', '枈', '枉', '枊', '枋', '枌', '枍', '枎', '枏', '析', '枑', '枒', '枓', '枔',
+    '枕', '枖', '林', '枘', '枙', '枚', '枛', '果', '枝', '枞', '枟', '枠', '枡', '枢', '枣', '枤',
+    '枥', '枦', '枧', '枨', '枩', '枪', '枫', '枬', '枭', '枮', '枯', '枰', '枱', '枲', '枳', '枴',
+    '枵', '架', '枷', '枸', '枹', '枺', '枻', '枼', '枽', '枾', '枿', '柀', '柁', '柂', '柃', '柄',
+    '柅', '柆', '柇', '柈', '柉', '柊', '柋', '柌', '柍', '柎', '柏', '某', '柑', '柒', '染', '柔',
+    '柕', '柖', '柗', '柘', '柙', '柚', '柛', '柜', '柝', '柞', '柟', '柠', '柡', '柢', '柣', '柤',
+    '查', '柦', '柧', '柨', '柩', '柪', '柫', '柬', '柭', '柮', '柯', '柰', '柱', '柲', '柳', '柴',
+    '柵', '柶', '柷', '柸', '柹', '柺', '査', '柼', '柽', '柾', '柿', '栀', '栁', '栂', '栃', '栄',
+    '栅', '栆', '标', '栈', '栉', '栊', '栋', '栌', '栍', '栎', '栏', '栐', '树', '栒', '栓', '栔',
+    '栕', '栖', '栗', '栘', '栙', '栚', '栛', '栜', '栝', '栞', '栟', '栠', '校', '栢', '栣', '栤',
+    '栥', '栦', '栧', '栨', '栩', '株', '栫', '栬', '栭', '栮', '栯', '栰', '栱', '栲', '栳', '栴',
+    '栵', '栶', '样', '核', '根', '栺', '栻', '格', '栽', '栾', '栿', '桀', '桁', '桂', '桃', '桄',
+    '桅', '框', '桇', '案', '桉', '桊', '桋', '桌', '桍', '桎', '桏', '桐', '桑', '桒', '桓', '桔',
+    '桕', '桖', '桗', '桘', '桙', '桚', '桜', '桝', '桞', '桟', '桠', '桡', '桢', '档', '桤', '桥',
+    '桦', '桧', '桨', '桩', '桪', '桫', '桬', '桭', '桮', '桯', '桰', '桱', '桲', '桳', '桴', '桵',
+    '桶', '桷', '桸', '桹', '桺', '桻', '桼', '桽', '桾', '桿', '梀', '梁', '梂', '梃', '梄', '梅',
+    '梆', '梇', '梈', '梉', '梊', '梋', '梌', '梍', '梎', '梏', '梐', '梑', '梒', '梓', '梔', '梕',
+    '梖', '梗', '梘', '梙', '梚', '梛', '梜', '條', '梞', '梟', '梠', '梡', '梢', '梣', '梤', '梥',
+    '梦', '梧', '梨', '梩', '梪', '梫', '梬', '梭', '梮', '梯', '械', '梱', '梲', '梳', '梴', '梵',
+    '梶', '梷', '梸', '梹', '梺', '梻', '梼', '梽', '梾', '梿', '检', '棁', '棂', '棃', '棄', '棅',
+    '棆', '棇', '棈', '棉', '棊', '棋', '棌', '棍', '棎', '棏', '棐', '棑', '棒', '棓', '棔', '棕',
+    '棖', '棗', '棘', '棙', '棚', '棛', '棜', '棝', '棞', '棟', '棠', '棡', '棢', '棣', '棤', '棥',
+    '棦', '棧', '棨', '棩', '棪', '棫', '棬', '棭', '森', '棯', '棰', '棱', '棲', '棳', '棴', '棵',
+    '棶', '棷', '棸', '棹', '棺', '棻', '棼', '棽', '棾', '棿', '椀', '椁', '椂', '椃', '椄', '椅',
+    '椆', '椇', '椈', '椉', '椊', '椋', '椌', '植', '椎', '椏', '椐', '椑', '椒', '椓', '椔', '椕',
+    '椖', '椗', '椘', '椙', '椚', '椛', '検', '椝', '椞', '椟', '椠', '椡', '椢', '椣', '椤', '椥',
+    '椦', '椧', '椨', '椩', '椪', '椫', '椬', '椭', '椮', '椯', '椰', '椱', '椲', '椳', '椴', '椵',
+    '椶', '椷', '椸', '椹', '椺', '椻', '椼', '椽', '椾', '椿', '楀', '楁', '楂', '楃', '楄', '楅',
+    '楆', '楇', '楈', '楉', '楊', '楋', '楌', '楍', '楎', '楏', '楐', '楑', '楒', '楓', '楔', '楕',
+    '楖', '楗', '楘', '楙', '楚', '楛', '楜', '楝', '楞', '楟', '楠', '楡', '楢', '楣', '楤', '楥',
+    '楦', '楧', '楨', '楩', '楪', '楫', '楬', '業', '楮', '楯', '楰', '楱', '楲', '楳', '楴', '極',
+    '楶', '楷', '楸', '楹', '楺', '楻', '楼', '楽', '楾', '楿', '榀', '榁', '概', '榃', '榄', '榅',
+    '榆', '榇', '榈', '榉', '榊', '榋', '榌', '榍', '榎', '榏', '榐', '榑', '榒', '榓', '榔', '榕',
+    '榖', '榗', '榘', '榙', '榚', '榛', '榜', '榝', '榞', '榟', '榠', '榡', '榢', '榣', '榤', '榥',
+    '榦', '榧', '榨', '榩', '榪', '榫', '榬', '榭', '榮', '榯', '榰', '榱', '榲', '榳', '榴', '榵',
+    '榶', '榷', '榸', '榹', '榺', '榻', '榼', '榽', '榾', '榿', '槀', '槁', '槂', '槃', '槄', '槅',
+    '槆', '槇', '槈', '槉', '槊', '構', '槌', '槍', '槎', '槏', '槐', '槑', '槒', '槓', '槔', '槕',
+    '槖', '槗', '様', '槙', '槚', '槛', '槜', '槝', '槞', '槟', '槠', '槡', '槢', '槣', '槤', '槥',
+    '槦', '槧', '槨', '槩', '槪', '槫', '槬', '槭', '槮', '槯', '槰', '槱', '槲', '槳', '槴', '槵',
+    '槶', '槷', '槸', '槹', '槺', '槻', '槼', '槽', '槾', '槿', '樀', '樁', '樂', '樃', '樄', '樅',
+    '樆', '樇', '樈', '樉', '樊', '樋', '樌', '樍', '樎', '樏', '樐', '樑', '樒', '樓', '樔', '樕',
+    '樖', '樗', '樘', '標', '樚', '樛', '樜', '樝', '樞', '樟', '樠', '模', '樢', '樣', '樤', '樥',
+    '樦', '樧', '樨', '権', '横', '樫', '樬', '樭', '樮', '樯', '樰', '樱', '樲', '樳', '樴', '樵',
+    '樶', '樷', '樸', '樹', '樺', '樻', '樼', '樽', '樾', '樿', '橀', '橁', '橂', '橃', '橄', '橅',
+    '橆', '橇', '橈', '橉', '橊', '橋', '橌', '橍', '橎', '橏', '橐', '橑', '橒', '橓', '橔', '橕',
+    '橖', '橗', '橘', '橙', '橚', '橛', '橜', '橝', '橞', '機', '橠', '橡', '橢', '橣', '橤', '橥',
+    '橦', '橧', '橨', '橩', '橪', '橫', '橬', '橭', '橮', '橯', '橰', '橱', '橲', '橳', '橴', '橵',
+    '橶', '橷', '橸', '橹', '橺', '橻', '橼', '橽', '橾', '橿', '檀', '檁', '檂', '檃', '檄', '檅',
+    '檆', '檇', '檈', '檉', '檊', '檋', '檌', '檍', '檎', '檏', '檐', '檑', '檒', '檓', '檔', '檕',
+    '檖', '檗', '檘', '檙', '檚', '檛', '檜', '檝', '檞', '檟', '檠', '檡', '檢', '檣', '檤', '檥',
+    '檦', '檧', '檨', '檩', '檪', '檫', '檬', '檭', '檮', '檯', '檰', '檱', '檲', '檳', '檴', '檵',
+    '檶', '檷', '檸', '檹', '檺', '檻', '檼', '檽', '檾', '檿', '櫀', '櫁', '櫂', '櫃', '櫄', '櫅',
+    '櫆', '櫇', '櫈', '櫉', '櫊', '櫋', '櫌', '櫍', '櫎', '櫏', '櫐', '櫑', '櫒', '櫓', '櫔', '櫕',
+    '櫖', '櫗', '櫘', '櫙', '櫚', '櫛', '櫜', '櫝', '櫞', '櫟', '櫠', '櫡', '櫢', '櫣', '櫤', '櫥',
+    '櫦', '櫧', '櫨', '櫩', '櫪', '櫫', '櫬', '櫭', '櫮', '櫯', '櫰', '櫱', '櫲', '櫳', '櫴', '櫵',
+    '櫶', '櫷', '櫸', '櫹', '櫺', '櫻', '櫼', '櫽', '櫾', '櫿', '欀', '欁', '欂', '欃', '欄', '欅',
+    '欆', '欇', '欈', '欉', '權', '欋', '欌', '欍', '欎', '欏', '欐', '欑', '欒', '欓', '欔', '欕',
+    '欖', '欗', '欘', '欙', '欚', '欛', '欜', '欝', '欞', '欟', '欠', '次', '欢', '欣', '欤', '欥',
+    '欦', '欧', '欨', '欩', '欪', '欫', '欬', '欭', '欮', '欯', '欰', '欱', '欲', '欳', '欴', '欵',
+    '欶', '欷', '欸', '欹', '欺', '欻', '欼', '欽', '款', '欿', '歀', '歁', '歂', '歃', '歄', '歅',
+    '歆', '歇', '歈', '歉', '歊', '歋', '歌', '歍', '歎', '歏', '歐', '歑', '歒', '歓', '歔', '歕',
+    '歖', '歗', '歘', '歙', '歚', '歛', '歜', '歝', '歞', '歟', '歠', '歡', '止', '正', '此', '步',
+    '武', '歧', '歨', '歩', '歪', '歫', '歬', '歭', '歮', '歯', '歰', '歱', '歲', '歳', '歴', '歵',
+    '歶', '歷', '歸', '歹', '歺', '死', '歼', '歽', '歾', '歿', '殀', '殁', '殂', '殃', '殄', '殅',
+    '殆', '殇', '殈', '殉', '殊', '残', '殌', '殍', '殎', '殏', '殐', '殑', '殒', '殓', '殔', '殕',
+    '殖', '殗', '殘', '殙', '殚', '殛', '殜', '殝', '殞', '殟', '殠', '殡', '殢', '殣', '殤', '殥',
+    '殦', '殧', '殨', '殩', '殪', '殫', '殬', '殭', '殮', '殯', '殰', '殱', '殲', '殳', '殴', '段',
+    '殶', '殷', '殸', '殹', '殺', '殻', '殼', '殽', '殾', '殿', '毀', '毁', '毂', '毃', '毄', '毅',
+    '毆', '毇', '毈', '毉', '毊', '毋', '毌', '母', '毎', '每', '毐', '毑', '毒', '毓', '比', '毕',
+    '毖', '毗', '毘', '毙', '毚', '毛', '毜', '毝', '毞', '毟', '毠', '毡', '毢', '毣', '毤', '毥',
+    '毦', '毧', '毨', '毩', '毪', '毫', '毬', '毭', '毮', '毯', '毰', '毱', '毲', '毳', '毴', '毵',
+    '毶', '毷', '毸', '毹', '毺', '毻', '毼', '毽', '毾', '毿', '氀', '氁', '氂', '氃', '氄', '氅',
+    '氆', '氇', '氈', '氉', '氊', '氋', '氌', '氍', '氎', '氏', '氐', '民', '氒', '氓', '气', '氕',
+    '氖', '気', '氘', '氙', '氚', '氛', '氜', '氝', '氞', '氟', '氠', '氡', '氢', '氣', '氤', '氥',
+    '氦', '氧', '氨', '氩', '氪', '氫', '氬', '氭', '氮', '氯', '氰', '氱', '氲', '氳', '水', '氵',
+    '氶', '氷', '永', '氹', '氺', '氻', '氼', '氽', '氾', '氿', '汀', '汁', '求', '汃', '汄', '汅',
+    '汆', '汇', '汈', '汉', '汊', '汋', '汌', '汍', '汎', '汏', '汐', '汑', '汒', '汓', '汔', '汕',
+    '汖', '汗', '汘', '汙', '汚', '汛', '汜', '汝', '汞', '江', '池', '污', '汢', '汣', '汤', '汥',
+    '汦', '汧', '汨', '汩', '汪', '汫', '汬', '汭', '汮', '汯', '汰', '汱', '汲', '汳', '汴', '汵',
+    '汶', '汷', '汸', '汹', '決', '汻', '汼', '汽', '汾', '汿', '沀', '沁', '沂', '沃', '沄', '沅',
+    '沆', '沇', '沈', '沉', '沊', '沋', '沌', '沍', '沎', '沏', '沐', '沑', '沒', '沓', '沔', '沕',
+    '沖', '沗', '沘', '沙', '沚', '沛', '沜', '沝', '沞', '沟', '沠', '没', '沢', '沣', '沤', '沥',
+    '沦', '沧', '沨', '沩', '沪', '沫', '沬', '沭', '沮', '沯', '沰', '沱', '沲', '河', '沴', '沵',
+    '沶', '沷', '沸', '油', '沺', '治', '沼', '沽', '沾', '沿', '泀', '況', '泂', '泃', '泄', '泅',
+    '泆', '泇', '泈', '泉', '泊', '泋', '泌', '泍', '泎', '泏', '泐', '泑', '泒', '泓', '泔', '法',
+    '泖', '泗', '泘', '泙', '泚', '泛', '泜', '泝', '泞', '泟', '泠', '泡', '波', '泣', '泤', '泥',
+    '泦', '泧', '注', '泩', '泪', '泫', '泬', '泭', '泮', '泯', '泰', '泱', '泲', '泳', '泴', '泵',
+    '泶', '泷', '泸', '泹', '泺', '泻', '泼', '泽', '泾', '泿', '洀', '洁', '洂', '洃', '洄', '洅',
+    '洆', '洇', '洈', '洉', '洊', '洋', '洌', '洍', '洎', '洏', '洐', '洑', '洒', '洓', '洔', '洕',
+    '洖', '洗', '洘', '洙', '洚', '洛', '洜', '洝', '洞', '洟', '洠', '洡', '洢', '洣', '洤', '津',
+    '洦', '洧', '洨', '洩', '洪', '洫', '洬', '洭', '洮', '洯', '洰', '洱', '洲', '洳', '洴', '洵',
+    '洶', '洷', '洸', '洹', '洺', '活', '洼', '洽', '派', '洿', '浀', '流', '浂', '浃', '浄', '浅',
+    '浆', '浇', '浈', '浉', '浊', '测', '浌', '浍', '济', '浏', '浐', '浑', '浒', '浓', '浔', '浕',
+    '浖', '浗', '浘', '浙', '浚', '浛', '浜', '浝', '浞', '浟', '浠', '浡', '浢', '浣', '浤', '浥',
+    '浦', '浧', '浨', '浩', '浪', '浫', '浬', '浭', '浮', '浯', '浰', '浱', '浲', '浳', '浴', '浵',
+    '浶', '海', '浸', '浹', '浺', '浻', '浼', '浽', '浾', '浿', '涀', '涁', '涂', '涃', '涄', '涅',
+    '涆', '涇', '消', '涉', '涊', '涋', '涌', '涍', '涎', '涏', '涐', '涑', '涒', '涓', '涔', '涕',
+    '涖', '涗', '涘', '涙', '涚', '涛', '涜', '涝', '涞', '涟', '涠', '涡', '涢', '涣', '涤', '涥',
+    '润', '涧', '涨', '涩', '涪', '涫', '涬', '涭', '涮', '涯', '涰', '涱', '液', '涳', '涴', '涵',
+    '涶', '涷', '涸', '涹', '涺', '涻', '涼', '涽', '涾', '涿', '淀', '淁', '淂', '淃', '淄', '淅',
+    '淆', '淇', '淈', '淉', '淊', '淋', '淌', '淍', '淎', '淏', '淐', '淑', '淒', '淓', '淔', '淕',
+    '淖', '淗', '淘', '淙', '淚', '淛', '淜', '淝', '淞', '淟', '淠', '淡', '淢', '淣', '淤', '淥',
+    '淦', '淧', '淨', '淩', '淪', '淫', '淬', '淭', '淮', '淯', '淰', '深', '淲', '淳', '淴', '淵',
+    '淶', '混', '淸', '淹', '淺', '添', '淼', '淽', '淾', '淿', '渀', '渁', '渂', '渃', '渄', '清',
+    '渆', '渇', '済', '渉', '渊', '渋', '渌', '渍', '渎', '渏', '渐', '渑', '渒', '渓', '渔', '渕',
+    '渖', '渗', '渘', '渙', '渚', '減', '渜', '渝', '渞', '渟', '渠', '渡', '渢', '渣', '渤', '渥',
+    '渦', '渧', '渨', '温', '渪', '渫', '測', '渭', '渮', '港', '渰', '渱', '渲', '渳', '渴', '渵',
+    '渶', '渷', '游', '渹', '渺', '渻', '渼', '渽', '渾', '渿', '湀', '湁', '湂', '湃', '湄', '湅',
+    '湆', '湇', '湈', '湉', '湊', '湋', '湌', '湍', '湎', '湏', '湐', '湑', '湒', '湓', '湔', '湕',
+    '湖', '湗', '湘', '湙', '湚', '湛', '湜', '湝', '湞', '湟', '湠', '湡', '湢', '湣', '湤', '湥',
+    '湦', '湧', '湨', '湩', '湪', '湫', '湬', '湭', '湮', '湯', '湰', '湱', '湲', '湳', '湴', '湵',
+    '湶', '湷', '湸', '湹', '湺', '湻', '湼', '湽', '湾', '湿', '満', '溁', '溂', '溃', '溄', '溅',
+    '溆', '溇', '溈', '溉', '溊', '溋', '溌', '溍', '溎', '溏', '源', '溑', '溒', '溓', '溔', '溕',
+    '準', '溗', '溘', '溙', '溚', '溛', '溜', '溝', '溞', '溟', '溠', '溡', '溢', '溣', '溤', '溥',
+    '溦', '溧', '溨', '溩', '溪', '溫', '溬', '溭', '溮', '溯', '溰', '溱', '溲', '溳', '溴', '溵',
+    '溶', '溷', '溸', '溹', '溺', '溻', '溼', '溽', '溾', '溿', '滀', '滁', '滂', '滃', '滄', '滅',
+    '滆', '滇', '滈', '滉', '滊', '滋', '滌', '滍', '滎', '滏', '滐', '滑', '滒', '滓', '滔', '滕',
+    '滖', '滗', '滘', '滙', '滚', '滛', '滜', '滝', '滞', '滟', '滠', '满', '滢', '滣', '滤', '滥',
+    '滦', '滧', '滨', '滩', '滪', '滫', '滬', '滭', '滮', '滯', '滰', '滱', '滲', '滳', '滴', '滵',
+    '滶', '滷', '滸', '滹', '滺', '滻', '滼', '滽', '滾', '滿', '漀', '漁', '漂', '漃', '漄', '漅',
+    '漆', '漇', '漈', '漉', '漊', '漋', '漌', '漍', '漎', '漏', '漐', '漑', '漒', '漓', '演', '漕',
+    '漖', '漗', '漘', '漙', '漚', '漛', '漜', '漝', '漞', '漟', '漠', '漡', '漢', '漣', '漤', '漥',
+    '漦', '漧', '漨', '漩', '漪', '漫', '漬', '漭', '漮', '漯', '漰', '漱', '漲', '漳', '漴', '漵',
+    '漶', '漷', '漸', '漹', '漺', '漻', '漼', '漽', '漾', '漿', '潀', '潁', '潂', '潃', '潄', '潅',
+    '潆', '潇', '潈', '潉', '潊', '潋', '潌', '潍', '潎', '潏', '潐', '潑', '潒', '潓', '潔', '潕',
+    '潖', '潗', '潘', '潙', '潚', '潛', '潜', '潝', '潞', '潟', '潠', '潡', '潢', '潣', '潤', '潥',
+    '潦', '潧', '潨', '潩', '潪', '潫', '潬', '潭', '潮', '潯', '潰', '潱', '潲', '潳', '潴', '潵',
+    '潶', '潷', '潸', '潹', '潺', '潻', '潼', '潽', '潾', '潿', '澀', '澁', '澂', '澃', '澄', '澅',
+    '澆', '澇', '澈', '澉', '澊', '澋', '澌', '澍', '澎', '澏', '澐', '澑', '澒', '澓', '澔', '澕',
+    '澖', '澗', '澘', '澙', '澚', '澛', '澜', '澝', '澞', '澟', '澠', '澡', '澢', '澣', '澤', '澥',
+    '澦', '澧', '澨', '澩', '澪', '澫', '澬', '澭', '澮', '澯', '澰', '澱', '澲', '澳', '澴', '澵',
+    '澶', '澷', '澸', '澹', '澺', '澻', '澼', '澽', '澾', '澿', '激', '濁', '濂', '濃', '濄', '濅',
+    '濆', '濇', '濈', '濉', '濊', '濋', '濌', '濍', '濎', '濏', '濐', '濑', '濒', '濓', '濔', '濕',
+    '濖', '濗', '濘', '濙', '濚', '濛', '濜', '濝', '濞', '濟', '濠', '濡', '濢', '濣', '濤', '濥',
+    '濦', '濧', '濨', '濩', '濪', '濫', '濬', '濭', '濮', '濯', '濰', '濱', '濲', '濳', '濴', '濵',
+    '濶', '濷', '濸', '濹', '濺', '濻', '濼', '濽', '濾', '濿', '瀀', '瀁', '瀂', '瀃', '瀄', '瀅',
+    '瀆', '瀇', '瀈', '瀉', '瀊', '瀋', '瀌', '瀍', '瀎', '瀏', '瀐', '瀑', '瀒', '瀓', '瀔', '瀕',
+    '瀖', '瀗', '瀘', '瀙', '瀚', '瀛', '瀜', '瀝', '瀞', '瀟', '瀠', '瀡', '瀢', '瀣', '瀤', '瀥',
+    '瀦', '瀧', '瀨', '瀩', '瀪', '瀫', '瀬', '瀭', '瀮', '瀯', '瀰', '瀱', '瀲', '瀳', '瀴', '瀵',
+    '瀶', '瀷', '瀸', '瀹', '瀺', '瀻', '瀼', '瀽', '瀾', '瀿', '灀', '灁', '灂', '灃', '灄', '灅',
+    '灆', '灇', '灈', '灉', '灊', '灋', '灌', '灍', '灎', '灏', '灐', '灑', '灒', '灓', '灔', '灕',
+    '灖', '灗', '灘', '灙', '灚', '灛', '灜', '灝', '灞', '灟', '灠', '灡', '灢', '灣', '灤', '灥',
+    '灦', '灧', '灨', '灩', '灪', '火', '灬', '灭', '灮', '灯', '灰', '灱', '灲', '灳', '灴', '灵',
+    '灶', '灷', '灸', '灹', '灺', '灻', '灼', '災', '灾', '灿', '炀', '炁', '炂', '炃', '炄', '炅',
+    '炆', '炇', '炈', '炉', '炊', '炋', '炌', '炍', '炎', '炏', '炐', '炑', '炒', '炓', '炔', '炕',
+    '炖', '炗', '炘', '炙', '炚', '炛', '炜', '炝', '炞', '炟', '炠', '炡', '炢', '炣', '炤', '炥',
+    '炦', '炧', '炨', '炩', '炪', '炫', '炬', '炭', '炮', '炯', '炰', '炱', '炲', '炳', '炴', '炵',
+    '炶', '炷', '炸', '点', '為', '炻', '炼', '炽', '炾', '炿', '烀', '烁', '烂', '烃', '烄', '烅',
+    '烆', '烇', '烈', '烉', '烊', '烋', '烌', '烍', '烎', '烏', '烐', '烑', '烒', '烓', '烔', '烕',
+    '烖', '烗', '烘', '烙', '烚', '烛', '烜', '烝', '烞', '烟', '烠', '烡', '烢', '烣', '烤', '烥',
+    '烦', '烧', '烨', '烩', '烫', '烬', '热', '烮', '烯', '烰', '烱', '烲', '烳', '烴', '烵', '烶',
+    '烷', '烸', '烹', '烺', '烻', '烼', '烽', '烾', '烿', '焀', '焁', '焂', '焃', '焄', '焅', '焆',
+    '焇', '焈', '焉', '焊', '焋', '焌', '焍', '焎', '焏', '焐', '焑', '焒', '焓', '焔', '焕', '焖',
+    '焗', '焘', '焙', '焚', '焛', '焜', '焝', '焞', '焟', '焠', '無', '焢', '焣', '焤', '焥', '焦',
+    '焧', '焨', '焩', '焪', '焫', '焬', '焭', '焮', '焯', '焰', '焱', '焲', '焳', '焴', '焵', '然',
+    '焷', '焸', '焹', '焺', '焻', '焼', '焽', '焾', '焿', '煀', '煁', '煂', '煃', '煄', '煅', '煆',
+    '煇', '煈', '煉', '煊', '煋', '煌', '煍', '煎', '煏', '煐', '煑', '煒', '煓', '煔', '煕', '煖',
+    '煗', '煘', '煙', '煚', '煛', '煜', '煝', '煞', '煟', '煠', '煡', '煢', '煣', '煤', '煥', '煦',
+    '照', '煨', '煩', '煪', '煫', '煬', '煭', '煮', '煯', '煰', '煱', '煲', '煳', '煴', '煵', '煶',
+    '煷', '煸', '煹', '煺', '煻', '煼', '煽', '煾', '煿', '熀', '熁', '熂', '熃', '熄', '熅', '熆',
+    '熇', '熈', '熉', '熊', '熋', '熌', '熍', '熎', '熏', '熐', '熑', '熒', '熓', '熔', '熕', '熖',
+    '熗', '熘', '熙', '熚', '熛', '熜', '熝', '熞', '熟', '熠', '熡', '熢', '熣', '熤', '熥', '熦',
+    '熧', '熨', '熩', '熪', '熫', '熬', '熭', '熮', '熯', '熰', '熱', '熲', '熳', '熴', '熵', '熶',
+    '熷', '熸', '熹', '熺', '熻', '熼', '熽', '熾', '熿', '燀', '燁', '燂', '燃', '燄', '燅', '燆',
+    '燇', '燈', '燉', '燊', '燋', '燌', '燍', '燎', '燏', '燐', '燑', '燒', '燓', '燔', '燕', '燖',
+    '燗', '燘', '燙', '燚', '燛', '燜', '燝', '燞', '營', '燠', '燡', '燢', '燣', '燤', '燥', '燦',
+    '燧', '燨', '燩', '燪', '燫', '燬', '燭', '燮', '燯', '燰', '燱', '燲', '燳', '燴', '燵', '燶',
+    '燷', '燸', '燹', '燺', '燻', '燼', '燽', '燾', '燿', '爀', '爁', '爂', '爃', '爄', '爅', '爆',
+    '爇', '爈', '爉', '爊', '爋', '爌', '爍', '爎', '爏', '爐', '爑', '爒', '爓', '爔', '爕', '爖',
+    '爗', '爘', '爙', '爚', '爛', '爜', '爝', '爞', '爟', '爠', '爡', '爢', '爣', '爤', '爥', '爦',
+    '爧', '爨', '爩', '爪', '爫', '爬', '爭', '爮', '爯', '爰', '爱', '爲', '爳', '爴', '爵', '父',
+    '爷', '爸', '爹', '爺', '爻', '爼', '爽', '爾', '爿', '牀', '牁', '牂', '牃', '牄', '牅', '牆',
+    '片', '版', '牉', '牊', '牋', '牌', '牍', '牎', '牏', '牐', '牑', '牒', '牓', '牔', '牕', '牖',
+    '牗', '牘', '牙', '牚', '牛', '牜', '牝', '牞', '牟', '牠', '牡', '牢', '牣', '牤', '牥', '牦',
+    '牧', '牨', '物', '牪', '牫', '牬', '牭', '牮', '牯', '牰', '牱', '牲', '牳', '牴', '牵', '牶',
+    '牷', '牸', '特', '牺', '牻', '牼', '牽', '牾', '牿', '犀', '犁', '犂', '犃', '犄', '犅', '犆',
+    '犇', '犈', '犉', '犊', '犋', '犌', '犍', '犎', '犏', '犐', '犑', '犒', '犓', '犔', '犕', '犖',
+    '犗', '犘', '犙', '犚', '犛', '犜', '犝', '犞', '犟', '犠', '犡', '犢', '犣', '犤', '犥', '犦',
+    '犧', '犨', '犩', '犪', '犫', '犬', '犭', '犮', '犯', '犰', '犱', '犲', '犳', '犴', '犵', '状',
+    '犷', '犸', '犹', '犺', '犻', '犼', '犽', '犾', '犿', '狀', '狁', '狂', '狃', '狄', '狅', '狆',
+    '狇', '狈', '狉', '狊', '狋', '狌', '狍', '狎', '狏', '狐', '狑', '狒', '狓', '狔', '狕', '狖',
+    '狗', '狘', '狙', '狚', '狛', '狜', '狝', '狞', '狟', '狠', '狡', '狢', '狣', '狤', '狥', '狦',
+    '狧', '狨', '狩', '狪', '狫', '独', '狭', '狮', '狯', '狰', '狱', '狲', '狳', '狴', '狵', '狶',
+    '狷', '狸', '狹', '狺', '狻', '狼', '狽', '狾', '狿', '猀', '猁', '猂', '猃', '猄', '猅', '猆',
+    '猇', '猈', '猉', '猊', '猋', '猌', '猍', '猎', '猏', '猐', '猑', '猒', '猓', '猔', '猕', '猖',
+    '猗', '猘', '猙', '猚', '猛', '猜', '猝', '猞', '猟', '猠', '猡', '猢', '猣', '猤', '猥', '猦',
+    '猧', '猨', '猩', '猪', '猫', '猬', '猭', '献', '猯', '猰', '猱', '猲', '猳', '猴', '猵', '猶',
+    '猷', '猸', '猹', '猺', '猻', '猼', '猽', '猾', '猿', '獀', '獁', '獂', '獃', '獄', '獅', '獆',
+    '獇', '獈', '獉', '獊', '獋', '獌', '獍', '獎', '獏', '獐', '獑', '獒', '獓', '獔', '獕', '獖',
+    '獗', '獘', '獙', '獚', '獛', '獜', '獝', '獞', '獟', '獠', '獡', '獢', '獣', '獤', '獥', '獦',
+    '獧', '獨', '獩', '獪', '獫', '獬', '獭', '獮', '獯', '獰', '獱', '獲', '獳', '獴', '獵', '獶',
+    '獷', '獸', '獹', '獺', '獻', '獼', '獽', '獾', '獿', '玀', '玁', '玂', '玃', '玄', '玅', '玆',
+    '率', '玈', '玉', '玊', '王', '玌', '玍', '玎', '玏', '玐', '玑', '玒', '玓', '玔', '玕', '玖',
+    '玗', '玘', '玙', '玚', '玛', '玜', '玝', '玞', '玟', '玠', '玡', '玢', '玣', '玤', '玥', '玦',
+    '玧', '玨', '玩', '玪', '玫', '玬', '玭', '玮', '环', '现', '玱', '玲', '玳', '玴', '玵', '玶',
+    '玷', '玸', '玹', '玺', '玻', '玼', '玽', '玾', '玿', '珀', '珁', '珂', '珃', '珄', '珅', '珆',
+    '珇', '珈', '珉', '珊', '珋', '珌', '珍', '珎', '珏', '珐', '珑', '珒', '珓', '珔', '珕', '珖',
+    '珗', '珘', '珙', '珚', '珛', '珜', '珝', '珞', '珟', '珠', '珡', '珢', '珣', '珤', '珥', '珦',
+    '珧', '珨', '珩', '珪', '珫', '珬', '班', '珮', '珯', '珰', '珱', '珲', '珳', '珴', '珵', '珶',
+    '珷', '珸', '珹', '珺', '珻', '珼', '珽', '現', '珿', '琀', '琁', '琂', '球', '琄', '琅', '理',
+    '琇', '琈', '琉', '琊', '琋', '琌', '琍', '琎', '琏', '琐', '琑', '琒', '琓', '琔', '琕', '琖',
+    '琗', '琘', '琙', '琚', '琛', '琜', '琝', '琞', '琟', '琠', '琡', '琢', '琣', '琤', '琥', '琦',
+    '琧', '琨', '琩', '琪', '琫', '琬', '琭', '琮', '琯', '琰', '琱', '琲', '琳', '琴', '琵', '琶',
+    '琷', '琸', '琹', '琺', '琻', '琼', '琽', '琾', '琿', '瑀', '瑁', '瑂', '瑃', '瑄', '瑅', '瑆',
+    '瑇', '瑈', '瑉', '瑊', '瑋', '瑌', '瑍', '瑎', '瑏', '瑐', '瑑', '瑒', '瑓', '瑔', '瑕', '瑖',
+    '瑗', '瑘', '瑙', '瑚', '瑛', '瑜', '瑝', '瑞', '瑟', '瑠', '瑡', '瑢', '瑣', '瑤', '瑥', '瑦',
+    '瑧', '瑨', '瑩', '瑪', '瑫', '瑬', '瑭', '瑮', '瑯', '瑰', '瑱', '瑲', '瑳', '瑴', '瑵', '瑶',
+    '瑷', '瑸', '瑹', '瑺', '瑻', '瑼', '瑽', '瑾', '瑿', '璀', '璁', '璂', '璃', '璄', '璅', '璆',
+    '璇', '璈', '璉', '璊', '璋', '璌', '璍', '璎', '璏', '璐', '璑', '璒', '璓', '璔', '璕', '璖',
+    '璗', '璘', '璙', '璚', '璛', '璜', '璝', '璞', '璟', '璠', '璡', '璢', '璣', '璤', '璥', '璦',
+    '璧', '璨', '璩', '璪', '璫', '璬', '璭', '璮', '璯', '環', '璱', '璲', '璳', '璴', '璵', '璶',
+    '璷', '璸', '璹', '璺', '璻', '璼', '璽', '璾', '璿', '瓀', '瓁', '瓂', '瓃', '瓄', '瓅', '瓆',
+    '瓇', '瓈', '瓉', '瓊', '瓋', '瓌', '瓍', '瓎', '瓏', '瓐', '瓑', '瓒', '瓓', '瓔', '瓕', '瓖',
+    '瓗', '瓘', '瓙', '瓚', '瓛', '瓜', '瓝', '瓞', '瓟', '瓠', '瓡', '瓢', '瓣', '瓤', '瓥', '瓦',
+    '瓨', '瓩', '瓪', '瓫', '瓬', '瓭', '瓮', '瓯', '瓲', '瓳', '瓴', '瓵', '瓶', '瓷', '瓸', '瓹',
+    '瓺', '瓻', '瓽', '瓾', '瓿', '甀', '甁', '甂', '甃', '甄', '甆', '甇', '甈', '甉', '甊', '甋',
+    '甌', '甍', '甎', '甏', '甐', '甑', '甒', '甓', '甔', '甕', '甖', '甗', '甘', '甙', '甚', '甛',
+    '甜', '甝', '甞', '生', '甠', '甡', '產', '産', '甤', '甥', '甦', '甧', '用', '甩', '甪', '甫',
+    '甬', '甭', '甮', '甯', '田', '由', '甲', '申', '甴', '电', '甶', '男', '甸', '甹', '町', '画',
+    '甼', '甽', '甾', '甿', '畀', '畁', '畂', '畃', '畄', '畅', '畆', '畇', '畈', '畉', '畊', '畋',
+    '界', '畍', '畎', '畏', '畐', '畑', '畒', '畓', '畔', '畕', '畖', '畗', '畘', '留', '畚', '畛',
+    '畜', '畝', '畞', '畟', '畠', '畡', '畢', '畣', '畤', '略', '畦', '畧', '畨', '畩', '番', '畫',
+    '畬', '畭', '畮', '畯', '異', '畱', '畲', '畳', '畴', '畵', '當', '畷', '畸', '畹', '畺', '畻',
+    '畼', '畽', '畾', '畿', '疀', '疁', '疂', '疃', '疄', '疅', '疆', '疇', '疈', '疉', '疊', '疋',
+    '疌', '疍', '疎', '疏', '疐', '疑', '疒', '疓', '疔', '疕', '疖', '疗', '疘', '疙', '疚', '疛',
+    '疜', '疝', '疞', '疟', '疠', '疡', '疢', '疣', '疤', '疥', '疦', '疧', '疨', '疩', '疪', '疫',
+    '疬', '疭', '疮', '疯', '疰', '疱', '疲', '疳', '疴', '疵', '疶', '疷', '疸', '疹', '疺', '疻',
+    '疼', '疽', '疾', '疿', '痀', '痁', '痂', '痃', '痄', '病', '痆', '症', '痈', '痉', '痊', '痋',
+    '痌', '痍', '痎', '痏', '痐', '痑', '痒', '痓', '痔', '痕', '痖', '痗', '痘', '痙', '痚', '痛',
+    '痜', '痝', '痞', '痟', '痠', '痡', '痢', '痣', '痤', '痥', '痦', '痧', '痨', '痩', '痪', '痫',
+    '痬', '痭', '痮', '痯', '痰', '痱', '痲', '痳', '痴', '痵', '痶', '痷', '痸', '痹', '痺', '痻',
+    '痼', '痽', '痾', '痿', '瘀', '瘁', '瘂', '瘃', '瘄', '瘅', '瘆', '瘇', '瘈', '瘉', '瘊', '瘋',
+    '瘌', '瘍', '瘎', '瘏', '瘐', '瘑', '瘒', '瘓', '瘔', '瘕', '瘖', '瘗', '瘘', '瘙', '瘚', '瘛',
+    '瘜', '瘝', '瘞', '瘟', '瘠', '瘡', '瘢', '瘣', '瘤', '瘥', '瘦', '瘧', '瘨', '瘩', '瘪', '瘫',
+    '瘬', '瘭', '瘮', '瘯', '瘰', '瘱', '瘲', '瘳', '瘴', '瘵', '瘶', '瘷', '瘸', '瘹', '瘺', '瘻',
+    '瘼', '瘽', '瘾', '瘿', '癀', '癁', '療', '癃', '癄', '癅', '癆', '癇', '癈', '癉', '癊', '癋',
+    '癌', '癍', '癎', '癏', '癐', '癑', '癒', '癓', '癔', '癕', '癖', '癗', '癘', '癙', '癚', '癛',
+    '癜', '癝', '癞', '癟', '癠', '癡', '癢', '癣', '癤', '癥', '癦', '癧', '癨', '癩', '癪', '癫',
+    '癬', '癭', '癮', '癯', '癰', '癱', '癲', '癳', '癴', '癵', '癶', '癷', '癸', '癹', '発', '登',
+    '發', '白', '百', '癿', '皀', '皁', '皂', '皃', '的', '皅', '皆', '皇', '皈', '皉', '皊', '皋',
+    '皌', '皍', '皎', '皏', '皐', '皑', '皒', '皓', '皔', '皕', '皖', '皗', '皘', '皙', '皚', '皛',
+    '皜', '皝', '皞', '皟', '皠', '皡', '皢', '皣', '皤', '皥', '皦', '皧', '皨', '皩', '皪', '皫',
+    '皬', '皭', '皮', '皯', '皰', '皱', '皲', '皳', '皴', '皵', '皶', '皷', '皸', '皹', '皺', '皻',
+    '皼', '皽', '皾', '皿', '盀', '盁', '盂', '盃', '盄', '盅', '盆', '盇', '盈', '盉', '益', '盋',
+    '盌', '盍', '盎', '盏', '盐', '监', '盒', '盓', '盔', '盕', '盖', '盗', '盘', '盙', '盚', '盛',
+    '盜', '盝', '盞', '盟', '盠', '盡', '盢', '監', '盤', '盥', '盦', '盧', '盨', '盩', '盪', '盫',
+    '盬', '盭', '目', '盯', '盰', '盱', '盲', '盳', '直', '盵', '盶', '盷', '相', '盹', '盺', '盻',
+    '盼', '盽', '盾', '盿', '眀', '省', '眂', '眃', '眄', '眅', '眆', '眇', '眈', '眉', '眊', '看',
+    '県', '眍', '眎', '眏', '眐', '眑', '眒', '眓', '眔', '眕', '眖', '眗', '眘', '眙', '眚', '眛',
+    '眜', '眝', '眞', '真', '眠', '眡', '眢', '眣', '眤', '眥', '眦', '眧', '眨', '眩', '眪', '眫',
+    '眬', '眭', '眮', '眯', '眰', '眱', '眲', '眳', '眴', '眵', '眶', '眷', '眸', '眹', '眺', '眻',
+    '眼', '眽', '眾', '眿', '着', '睁', '睂', '睃', '睄', '睅', '睆', '睇', '睈', '睉', '睊', '睋',
+    '睌', '睍', '睎', '睏', '睐', '睑', '睒', '睓', '睔', '睕', '睖', '睗', '睘', '睙', '睚', '睛',
+    '睜', '睝', '睞', '睟', '睠', '睡', '睢', '督', '睤', '睥', '睦', '睧', '睨', '睩', '睪', '睫',
+    '睬', '睭', '睮', '睯', '睰', '睱', '睲', '睳', '睴', '睵', '睶', '睷', '睸', '睹', '睺', '睻',
+    '睼', '睽', '睾', '睿', '瞀', '瞁', '瞂', '瞃', '瞄', '瞅', '瞆', '瞇', '瞈', '瞉', '瞊', '瞋',
+    '瞌', '瞍', '瞎', '瞏', '瞐', '瞑', '瞒', '瞓', '瞔', '瞕', '瞖', '瞗', '瞘', '瞙', '瞚', '瞛',
+    '瞜', '瞝', '瞞', '瞟', '瞠', '瞡', '瞢', '瞣', '瞤', '瞥', '瞦', '瞧', '瞨', '瞩', '瞪', '瞫',
+    '瞬', '瞭', '瞮', '瞯', '瞰', '瞱', '瞲', '瞳', '瞴', '瞵', '瞶', '瞷', '瞸', '瞹', '瞺', '瞻',
+    '瞼', '瞽', '瞾', '瞿', '矀', '矁', '矂', '矃', '矄', '矅', '矆', '矇', '矈', '矉', '矊', '矋',
+    '矌', '矍', '矎', '矏', '矐', '矑', '矒', '矓', '矔', '矕', '矖', '矗', '矘', '矙', '矚', '矛',
+    '矜', '矝', '矞', '矟', '矠', '矡', '矢', '矣', '矤', '知', '矦', '矧', '矨', '矩', '矪', '矫',
+    '矬', '短', '矮', '矯', '矰', '矱', '矲', '石', '矴', '矵', '矶', '矷', '矸', '矹', '矺', '矻',
+    '矼', '矽', '矾', '矿', '砀', '码', '砂', '砃', '砄', '砅', '砆', '砇', '砈', '砉', '砊', '砋',
+    '砌', '砍', '砎', '砏', '砐', '砑', '砒', '砓', '研', '砕', '砖', '砗', '砘', '砙', '砚', '砛',
+    '砜', '砝', '砞', '砟', '砠', '砡', '砢', '砣', '砤', '砥', '砦', '砧', '砨', '砩', '砪', '砫',
+    '砬', '砭', '砮', '砯', '砰', '砱', '砲', '砳', '破', '砵', '砶', '砷', '砸', '砹', '砺', '砻',
+    '砼', '砽', '砾', '砿', '础', '硁', '硂', '硃', '硄', '硅', '硆', '硇', '硈', '硉', '硊', '硋',
+    '硌', '硍', '硎', '硏', '硐', '硑', '硒', '硓', '硔', '硕', '硖', '硗', '硘', '硙', '硚', '硛',
+    '硜', '硝', '硞', '硟', '硠', '硡', '硢', '硣', '硤', '硥', '硦', '硧', '硨', '硩', '硪', '硫',
+    '硬', '硭', '确', '硯', '硰', '硱', '硲', '硳', '硴', '硵', '硶', '硷', '硸', '硹', '硺', '硻',
+    '硼', '硽', '硾', '硿', '碀', '碁', '碂', '碃', '碄', '碅', '碆', '碇', '碈', '碉', '碊', '碋',
+    '碌', '碍', '碎', '碏', '碐', '碑', '碒', '碓', '碔', '碕', '碖', '碗', '碘', '碙', '碚', '碛',
+    '碜', '碝', '碞', '碟', '碠', '碡', '碢', '碣', '碤', '碥', '碦', '碧', '碨', '碩', '碪', '碫',
+    '碬', '碭', '碮', '碯', '碰', '碱', '碲', '碳', '碴', '碵', '碶', '碷', '碸', '碹', '確', '碻',
+    '碼', '碽', '碾', '碿', '磀', '磁', '磂', '磃', '磄', '磅', '磆', '磇', '磈', '磉', '磊', '磋',
+    '磌', '磍', '磎', '磏', '磐', '磑', '磒', '磓', '磔', '磕', '磖', '磗', '磘', '磙', '磚', '磛',
+    '磜', '磝', '磞', '磟', '磠', '磡', '磢', '磣', '磤', '磥', '磦', '磧', '磨', '磩', '磪', '磫',
+    '磬', '磭', '磮', '磯', '磰', '磱', '磲', '磳', '磴', '磵', '磶', '磷', '磸', '磹', '磺', '磻',
+    '磼', '磽', '磾', '磿', '礀', '礁', '礂', '礃', '礄', '礅', '礆', '礇', '礈', '礉', '礊', '礋',
+    '礌', '礍', '礎', '礏', '礐', '礑', '礒', '礓', '礔', '礕', '礖', '礗', '礘', '礙', '礚', '礛',
+    '礜', '礝', '礞', '礟', '礠', '礡', '礢', '礣', '礤', '礥', '礦', '礧', '礨', '礩', '礪', '礫',
+    '礬', '礭', '礮', '礯', '礰', '礱', '礲', '礳', '礴', '礵', '礶', '礷', '礸', '礹', '示', '礻',
+    '礼', '礽', '社', '礿', '祀', '祁', '祂', '祃', '祄', '祅', '祆', '祇', '祈', '祉', '祊', '祋',
+    '祌', '祍', '祎', '祏', '祐', '祑', '祒', '祓', '祔', '祕', '祖', '祗', '祘', '祙', '祚', '祛',
+    '祜', '祝', '神', '祟', '祠', '祡', '祢', '祣', '祤', '祥', '祦', '祧', '票', '祩', '祪', '祫',
+    '祬', '祭', '祮', '祯', '祰', '祱', '祲', '祳', '祴', '祵', '祶', '祷', '祸', '祹', '祺', '祻',
+    '祼', '祽', '祾', '祿', '禀', '禁', '禂', '禃', '禄', '禅', '禆', '禇', '禈', '禉', '禊', '禋',
+    '禌', '禍', '禎', '福', '禐', '禑', '禒', '禓', '禔', '禕', '禖', '禗', '禘', '禙', '禚', '禛',
+    '禜', '禝', '禞', '禟', '禠', '禡', '禢', '禣', '禤', '禥', '禦', '禧', '禨', '禩', '禪', '禫',
+    '禬', '禭', '禮', '禯', '禰', '禱', '禲', '禳', '禴', '禵', '禶', '禷', '禸', '禹', '禺', '离',
+    '禼', '禽', '禾', '禿', '秀', '私', '秂', '秃', '秄', '秅', '秆', '秇', '秈', '秉', '秊', '秋',
+    '秌', '种', '秎', '秏', '秐', '科', '秒', '秓', '秔', '秕', '秖', '秗', '秘', '秙', '秚', '秛',
+    '秜', '秝', '秞', '租', '秠', '秡', '秢', '秣', '秤', '秥', '秦', '秧', '秨', '秩', '秪', '秫',
+    '秬', '秭', '秮', '积', '称', '秱', '秲', '秳', '秴', '秵', '秶', '秷', '秸', '秹', '秺', '移',
+    '秼', '秽', '秾', '秿', '稀', '稁', '稂', '稃', '稄', '稅', '稆', '稇', '稈', '稉', '稊', '程',
+    '稌', '稍', '税', '稏', '稐', '稑', '稒', '稓', '稔', '稕', '稖', '稗', '稘', '稙', '稚', '稛',
+    '稜', '稝', '稞', '稟', '稠', '稡', '稢', '稣', '稤', '稥', '稦', '稧', '稨', '稩', '稪', '稫',
+    '稬', '稭', '種', '稯', '稰', '稱', '稲', '稳', '稴', '稵', '稶', '稷', '稸', '稹', '稺', '稻',
+    '稼', '稽', '稾', '稿', '穀', '穁', '穂', '穃', '穄', '穅', '穆', '穇', '穈', '穉', '穊', '穋',
+    '穌', '積', '穎', '穏', '穐', '穑', '穒', '穓', '穔', '穕', '穖', '穗', '穘', '穙', '穚', '穛',
+    '穜', '穝', '穞', '穟', '穠', '穡', '穢', '穣', '穤', '穥', '穦', '穧', '穨', '穩', '穪', '穫',
+    '穬', '穭', '穮', '穯', '穰', '穱', '穲', '穳', '穴', '穵', '究', '穷', '穸', '穹', '空', '穻',
+    '穼', '穽', '穾', '穿', '窀', '突', '窂', '窃', '窄', '窅', '窆', '窇', '窈', '窉', '窊', '窋',
+    '窌', '窍', '窎', '窏', '窐', '窑', '窒', '窓', '窔', '窕', '窖', '窗', '窘', '窙', '窚', '窛',
+    '窜', '窝', '窞', '窟', '窠', '窡', '窢', '窣', '窤', '窥', '窦', '窧', '窨', '窩', '窪', '窫',
+    '窬', '窭', '窮', '窯', '窰', '窱', '窲', '窳', '窴', '窵', '窶', '窷', '窸', '窹', '窺', '窻',
+    '窼', '窽', '窾', '窿', '竀', '竁', '竂', '竃', '竄', '竅', '竆', '竇', '竈', '竉', '竊', '立',
+    '竌', '竍', '竎', '竏', '竐', '竑', '竒', '竓', '竔', '竕', '竖', '竗', '竘', '站', '竚', '竛',
+    '竜', '竝', '竞', '竟', '章', '竡', '竢', '竣', '竤', '童', '竦', '竧', '竨', '竩', '竪', '竫',
+    '竬', '竭', '竮', '端', '竰', '竱', '竲', '竳', '竴', '竵', '競', '竷', '竸', '竹', '竺', '竻',
+    '竼', '竽', '竾', '竿', '笀', '笁', '笂', '笃', '笄', '笅', '笆', '笇', '笈', '笉', '笊', '笋',
+    '笌', '笍', '笎', '笏', '笐', '笑', '笒', '笓', '笔', '笕', '笖', '笗', '笘', '笙', '笚', '笛',
+    '笜', '笝', '笞', '笟', '笠', '笡', '笢', '笣', '笤', '笥', '符', '笧', '笨', '笩', '笪', '笫',
+    '第', '笭', '笮', '笯', '笰', '笱', '笲', '笳', '笴', '笵', '笶', '笷', '笸', '笹', '笺', '笻',
+    '笼', '笽', '笾', '笿', '筀', '筁', '筂', '筃', '筄', '筅', '筆', '筇', '筈', '等', '筊', '筋',
+    '筌', '筍', '筎', '筏', '筐', '筑', '筒', '筓', '答', '筕', '策', '筗', '筘', '筙', '筚', '筛',
+    '筜', '筝', '筞', '筟', '筠', '筡', '筢', '筣', '筤', '筥', '筦', '筧', '筨', '筩', '筪', '筫',
+    '筬', '筭', '筮', '筯', '筰', '筱', '筲', '筳', '筴', '筵', '筶', '筷', '筸', '筹', '筺', '筻',
+    '筼', '筽', '签', '筿', '简', '箁', '箂', '箃', '箄', '箅', '箆', '箇', '箈', '箉', '箊', '箋',
+    '箌', '箍', '箎', '箏', '箐', '箑', '箒', '箓', '箔', '箕', '箖', '算', '箘', '箙', '箚', '箛',
+    '箜', '箝', '箞', '箟', '箠', '管', '箢', '箣', '箤', '箥', '箦', '箧', '箨', '箩', '箪', '箫',
+    '箬', '箭', '箮', '箯', '箰', '箱', '箲', '箳', '箴', '箵', '箶', '箷', '箸', '箹', '箺', '箻',
+    '箼', '箽', '箾', '箿', '節', '篁', '篂', '篃', '範', '篅', '篆', '篇', '篈', '築', '篊', '篋',
+    '篌', '篍', '篎', '篏', '篐', '篑', '篒', '篓', '篔', '篕', '篖', '篗', '篘', '篙', '篚', '篛',
+    '篜', '篝', '篞', '篟', '篠', '篡', '篢', '篣', '篤', '篥', '篦', '篧', '篨', '篩', '篪', '篫',
+    '篬', '篭', '篮', '篯', '篰', '篱', '篲', '篳', '篴', '篵', '篶', '篷', '篸', '篹', '篺', '篻',
+    '篼', '篽', '篾', '篿', '簀', '簁', '簂', '簃', '簄', '簅', '簆', '簇', '簈', '簉', '簊', '簋',
+    '簌', '簍', '簎', '簏', '簐', '簑', '簒', '簓', '簔', '簕', '簖', '簗', '簘', '簙', '簚', '簛',
+    '簜', '簝', '簞', '簟', '簠', '簡', '簢', '簣', '簤', '簥', '簦', '簧', '簨', '簩', '簪', '簫',
+    '簬', '簭', '簮', '簯', '簰', '簱', '簲', '簳', '簴', '簵', '簶', '簷', '簸', '簹', '簺', '簻',
+    '簼', '簽', '簾', '簿', '籀', '籁', '籂', '籃', '籄', '籅', '籆', '籇', '籈', '籉', '籊', '籋',
+    '籌', '籍', '籎', '籏', '籐', '籑', '籒', '籓', '籔', '籕', '籖', '籗', '籘', '籙', '籚', '籛',
+    '籜', '籝', '籞', '籟', '籠', '籡', '籢', '籣', '籤', '籥', '籦', '籧', '籨', '籩', '籪', '籫',
+    '籬', '籭', '籮', '籯', '籰', '籱', '籲', '米', '籴', '籵', '籶', '籷', '籸', '籹', '籺', '类',
+    '籼', '籽', '籾', '籿', '粀', '粁', '粂', '粃', '粄', '粅', '粆', '粇', '粈', '粉', '粊', '粋',
+    '粌', '粍', '粎', '粏', '粐', '粑', '粒', '粓', '粔', '粕', '粖', '粗', '粘', '粙', '粚', '粛',
+    '粜', '粝', '粞', '粟', '粠', '粡', '粢', '粣', '粤', '粥', '粦', '粧', '粨', '粩', '粪', '粫',
+    '粬', '粭', '粮', '粯', '粰', '粱', '粲', '粳', '粴', '粵', '粶', '粷', '粸', '粹', '粺', '粻',
+    '粼', '粽', '精', '粿', '糀', '糁', '糂', '糃', '糄', '糅', '糆', '糇', '糈', '糉', '糊', '糋',
+    '糌', '糍', '糎', '糏', '糐', '糑', '糒', '糓', '糔', '糕', '糖', '糗', '糘', '糙', '糚', '糛',
+    '糜', '糝', '糞', '糟', '糠', '糡', '糢', '糣', '糤', '糥', '糦', '糧', '糨', '糩', '糪', '糫',
+    '糬', '糭', '糮', '糯', '糰', '糱', '糲', '糳', '糴', '糵', '糶', '糷', '糸', '糹', '糺', '系',
+    '糼', '糽', '糾', '糿', '紀', '紁', '紂', '紃', '約', '紅', '紆', '紇', '紈', '紉', '紊', '紋',
+    '紌', '納', '紎', '紏', '紐', '紑', '紒', '紓', '純', '紕', '紖', '紗', '紘', '紙', '級', '紛',
+    '紜', '紝', '紞', '紟', '素', '紡', '索', '紣', '紤', '紥', '紦', '紧', '紨', '紩', '紪', '紫',
+    '紬', '紭', '紮', '累', '細', '紱', '紲', '紳', '紴', '紵', '紶', '紷', '紸', '紹', '紺', '紻',
+    '紼', '紽', '紾', '紿', '絀', '絁', '終', '絃', '組', '絅', '絆', '絇', '絈', '絉', '絊', '絋',
+    '経', '絍', '絎', '絏', '結', '絑', '絒', '絓', '絔', '絕', '絖', '絗', '絘', '絙', '絚', '絛',
+    '絜', '絝', '絞', '絟', '絠', '絡', '絢', '絣', '絤', '絥', '給', '絧', '絨', '絩', '絪', '絫',
+    '絬', '絭', '絮', '絯', '絰', '統', '絲', '絳', '絴', '絵', '絶', '絷', '絸', '絹', '絺', '絻',
+    '絼', '絽', '絾', '絿', '綀', '綁', '綂', '綃', '綄', '綅', '綆', '綇', '綈', '綉', '綊', '綋',
+    '綌', '綍', '綎', '綏', '綐', '綑', '綒', '經', '綔', '綕', '綖', '綗', '綘', '継', '続', '綛',
+    '綜', '綝', '綞', '綟', '綠', '綡', '綢', '綣', '綤', '綥', '綦', '綧', '綨', '綩', '綪', '綫',
+    '綬', '維', '綮', '綯', '綰', '綱', '網', '綳', '綴', '綵', '綶', '綷', '綸', '綹', '綺', '綻',
+    '綼', '綽', '綾', '綿', '緀', '緁', '緂', '緃', '緄', '緅', '緆', '緇', '緈', '緉', '緊', '緋',
+    '緌', '緍', '緎', '総', '緐', '緑', '緒', '緓', '緔', '緕', '緖', '緗', '緘', '緙', '線', '緛',
+    '緜', '緝', '緞', '緟', '締', '緡', '緢', '緣', '緤', '緥', '緦', '緧', '編', '緩', '緪', '緫',
+    '緬', '緭', '緮', '緯', '緰', '緱', '緲', '緳', '練', '緵', '緶', '緷', '緸', '緹', '緺', '緻',
+    '緼', '緽', '緾', '緿', '縀', '縁', '縂', '縃', '縄', '縅', '縆', '縇', '縈', '縉', '縊', '縋',
+    '縌', '縍', '縎', '縏', '縐', '縑', '縒', '縓', '縔', '縕', '縖', '縗', '縘', '縙', '縚', '縛',
+    '縜', '縝', '縞', '縟', '縠', '縡', '縢', '縣', '縤', '縥', '縦', '縧', '縨', '縩', '縪', '縫',
+    '縬', '縭', '縮', '縯', '縰', '縱', '縲', '縳', '縴', '縵', '縶', '縷', '縸', '縹', '縺', '縻',
+    '縼', '總', '績', '縿', '繀', '繁', '繂', '繃', '繄', '繅', '繆', '繇', '繈', '繉', '繊', '繋',
+    '繌', '繍', '繎', '繏', '繐', '繑', '繒', '繓', '織', '繕', '繖', '繗', '繘', '繙', '繚', '繛',
+    '繜', '繝', '繞', '繟', '繠', '繡', '繢', '繣', '繤', '繥', '繦', '繧', '繨', '繩', '繪', '繫',
+    '繬', '繭', '繮', '繯', '繰', '繱', '繲', '繳', '繴', '繵', '繶', '繷', '繸', '繹', '繺', '繻',
+    '繼', '繽', '繾', '繿', '纀', '纁', '纂', '纃', '纄', '纅', '纆', '纇', '纈', '纉', '纊', '纋',
+    '續', '纍', '纎', '纏', '纐', '纑', '纒', '纓', '纔', '纕', '纖', '纗', '纘', '纙', '纚', '纛',
+    '纜', '纝', '纞', '纟', '纠', '纡', '红', '纣', '纤', '纥', '约', '级', '纨', '纩', '纪', '纫',
+    '纬', '纭', '纮', '纯', '纰', '纱', '纲', '纳', '纴', '纵', '纶', '纷', '纸', '纹', '纺', '纻',
+    '纼', '纽', '纾', '线', '绀', '绁', '绂', '练', '组', '绅', '细', '织', '终', '绉', '绊', '绋',
+    '绌', '绍', '绎', '经', '绐', '绑', '绒', '结', '绔', '绕', '绖', '绗', '绘', '给', '绚', '绛',
+    '络', '绝', '绞', '统', '绠', '绡', '绢', '绣', '绤', '绥', '绦', '继', '绨', '绩', '绪', '绫',
+    '绬', '续', '绮', '绯', '绰', '绱', '绲', '绳', '维', '绵', '绶', '绷', '绸', '绹', '绺', '绻',
+    '综', '绽', '绾', '绿', '缀', '缁', '缂', '缃', '缄', '缅', '缆', '缇', '缈', '缉', '缊', '缋',
+    '缌', '缍', '缎', '缏', '缐', '缑', '缒', '缓', '缔', '缕', '编', '缗', '缘', '缙', '缚', '缛',
+    '缜', '缝', '缞', '缟', '缠', '缡', '缢', '缣', '缤', '缥', '缦', '缧', '缨', '缩', '缪', '缫',
+    '缬', '缭', '缮', '缯', '缰', '缱', '缲', '缳', '缴', '缵', '缶', '缷', '缸', '缹', '缺', '缻',
+    '缼', '缽', '缾', '缿', '罀', '罁', '罂', '罃', '罄', '罅', '罆', '罇', '罈', '罉', '罊', '罋',
+    '罌', '罍', '罎', '罏', '罐', '网', '罒', '罓', '罔', '罕', '罖', '罗', '罘', '罙', '罚', '罛',
+    '罜', '罝', '罞', '罟', '罠', '罡', '罢', '罣', '罤', '罥', '罦', '罧', '罨', '罩', '罪', '罫',
+    '罬', '罭', '置', '罯', '罰', '罱', '署', '罳', '罴', '罵', '罶', '罷', '罸', '罹', '罺', '罻',
+    '罼', '罽', '罾', '罿', '羀', '羁', '羂', '羃', '羄', '羅', '羆', '羇', '羈', '羉', '羊', '羋',
+    '羌', '羍', '美', '羏', '羐', '羑', '羒', '羓', '羔', '羕', '羖', '羗', '羘', '羙', '羚', '羛',
+    '羜', '羝', '羞', '羟', '羠', '羡', '羢', '羣', '群', '羥', '羦', '羧', '羨', '義', '羪', '羫',
+    '羬', '羭', '羮', '羯', '羰', '羱', '羲', '羳', '羴', '羵', '羶', '羷', '羸', '羹', '羺', '羻',
+    '羼', '羽', '羾', '羿', '翀', '翁', '翂', '翃', '翄', '翅', '翆', '翇', '翈', '翉', '翊', '翋',
+    '翌', '翍', '翎', '翏', '翐', '翑', '習', '翓', '翔', '翕', '翖', '翗', '翘', '翙', '翚', '翛',
+    '翜', '翝', '翞', '翟', '翠', '翡', '翢', '翣', '翤', '翥', '翦', '翧', '翨', '翩', '翪', '翫',
+    '翬', '翭', '翮', '翯', '翰', '翱', '翲', '翳', '翴', '翵', '翶', '翷', '翸', '翹', '翺', '翻',
+    '翼', '翽', '翾', '翿', '耀', '老', '耂', '考', '耄', '者', '耆', '耇', '耈', '耉', '耊', '耋',
+    '而', '耍', '耎', '耏', '耐', '耑', '耒', '耓', '耔', '耕', '耖', '耗', '耘', '耙', '耚', '耛',
+    '耜', '耝', '耞', '耟', '耠', '耡', '耢', '耣', '耤', '耥', '耦', '耧', '耨', '耩', '耪', '耫',
+    '耬', '耭', '耮', '耯', '耰', '耱', '耲', '耳', '耴', '耵', '耶', '耷', '耸', '耹', '耺', '耻',
+    '耼', '耽', '耾', '耿', '聀', '聁', '聂', '聃', '聄', '聅', '聆', '聇', '聈', '聉', '聊', '聋',
+    '职', '聍', '聎', '聏', '聐', '聑', '聒', '聓', '联', '聕', '聖', '聗', '聘', '聙', '聚', '聛',
+    '聜', '聝', '聞', '聟', '聠', '聡', '聢', '聣', '聤', '聥', '聦', '聧', '聨', '聩', '聪', '聫',
+    '聬', '聭', '聮', '聯', '聰', '聱', '聲', '聳', '聴', '聵', '聶', '職', '聸', '聹', '聺', '聻',
+    '聼', '聽', '聾', '聿', '肀', '肁', '肂', '肃', '肄', '肅', '肆', '肇', '肈', '肉', '肊', '肋',
+    '肌', '肍', '肎', '肏', '肐', '肑', '肒', '肓', '肔', '肕', '肖', '肗', '肘', '肙', '肚', '肛',
+    '肜', '肝', '肞', '肟', '肠', '股', '肢', '肣', '肤', '肥', '肦', '肧', '肨', '肩', '肪', '肫',
+    '肬', '肭', '肮', '肯', '肰', '肱', '育', '肳', '肴', '肵', '肶', '肷', '肸', '肹', '肺', '肻',
+    '肼', '肽', '肾', '肿', '胀', '胁', '胂', '胃', '胄', '胅', '胆', '胇', '胈', '胉', '胊', '胋',
+    '背', '胍', '胎', '胏', '胐', '胑', '胒', '胓', '胔', '胕', '胖', '胗', '胘', '胙', '胚', '胛',
+    '胜', '胝', '胞', '胟', '胠', '胡', '胢', '胣', '胤', '胥', '胦', '胧', '胨', '胩', '胪', '胫',
+    '胬', '胭', '胮', '胯', '胰', '胱', '胲', '胳', '胴', '胵', '胶', '胷', '胸', '胹', '胺', '胻',
+    '胼', '能', '胾', '胿', '脀', '脁', '脂', '脃', '脄', '脅', '脆', '脇', '脈', '脉', '脊', '脋',
+    '脌', '脍', '脎', '脏', '脐', '脑', '脒', '脓', '脔', '脕', '脖', '脗', '脘', '脙', '脚', '脛',
+    '脜', '脝', '脞', '脟', '脠', '脡', '脢', '脣', '脤', '脥', '脦', '脧', '脨', '脩', '脪', '脫',
+    '脬', '脭', '脮', '脯', '脰', '脱', '脲', '脳', '脴', '脵', '脶', '脷', '脸', '脹', '脺', '脻',
+    '脼', '脽', '脾', '脿', '腀', '腁', '腂', '腃', '腄', '腅', '腆', '腇', '腈', '腉', '腊', '腋',
+    '腌', '腍', '腎', '腏', '腐', '腑', '腒', '腓', '腔', '腕', '腖', '腗', '腘', '腙', '腚', '腛',
+    '腜', '腝', '腞', '腟', '腠', '腡', '腢', '腣', '腤', '腥', '腦', '腧', '腨', '腩', '腪', '腫',
+    '腬', '腭', '腮', '腯', '腰', '腱', '腲', '腳', '腴', '腵', '腶', '腷', '腸', '腹', '腺', '腻',
+    '腼', '腽', '腾', '腿', '膀', '膁', '膂', '膃', '膄', '膅', '膆', '膇', '膈', '膉', '膊', '膋',
+    '膌', '膍', '膎', '膏', '膐', '膑', '膒', '膓', '膔', '膕', '膖', '膗', '膘', '膙', '膚', '膛',
+    '膜', '膝', '膞', '膟', '膠', '膡', '膢', '膣', '膤', '膥', '膦', '膧', '膨', '膩', '膪', '膫',
+    '膬', '膭', '膮', '膯', '膰', '膱', '膲', '膳', '膴', '膵', '膶', '膷', '膸', '膹', '膺', '膻',
+    '膼', '膽', '膾', '膿', '臀', '臁', '臂', '臃', '臄', '臅', '臆', '臇', '臈', '臉', '臊', '臋',
+    '臌', '臍', '臎', '臏', '臐', '臑', '臒', '臓', '臔', '臕', '臖', '臗', '臘', '臙', '臚', '臛',
+    '臜', '臝', '臞', '臟', '臠', '臡', '臢', '臣', '臤', '臥', '臦', '臧', '臨', '臩', '自', '臫',
+    '臬', '臭', '臮', '臯', '臰', '臱', '臲', '至', '致', '臵', '臶', '臷', '臸', '臹', '臺', '臻',
+    '臼', '臽', '臾', '臿', '舀', '舁', '舂', '舃', '舄', '舅', '舆', '與', '興', '舉', '舊', '舋',
+    '舌', '舍', '舎', '舏', '舐', '舑', '舒', '舓', '舔', '舕', '舖', '舗', '舘', '舙', '舚', '舛',
+    '舜', '舝', '舞', '舟', '舠', '舡', '舢', '舣', '舤', '舥', '舦', '舧', '舨', '舩', '航', '舫',
+    '般', '舭', '舮', '舯', '舰', '舱', '舲', '舳', '舴', '舵', '舶', '舷', '舸', '船', '舺', '舻',
+    '舼', '舽', '舾', '舿', '艀', '艁', '艂', '艃', '艄', '艅', '艆', '艇', '艈', '艉', '艊', '艋',
+    '艌', '艍', '艎', '艏', '艐', '艑', '艒', '艓', '艔', '艕', '艖', '艗', '艘', '艙', '艚', '艛',
+    '艜', '艝', '艞', '艟', '艠', '艡', '艢', '艣', '艤', '艥', '艦', '艧', '艨', '艩', '艪', '艫',
+    '艬', '艭', '艮', '良', '艰', '艱', '色', '艳', '艴', '艵', '艶', '艷', '艸', '艹', '艺', '艻',
+    '艼', '艽', '艾', '艿', '芀', '芁', '节', '芃', '芄', '芅', '芆', '芇', '芈', '芉', '芊', '芋',
+    '芌', '芍', '芎', '芏', '芐', '芑', '芒', '芓', '芔', '芕', '芖', '芗', '芘', '芙', '芚', '芛',
+    '芜', '芝', '芞', '芟', '芠', '芡', '芢', '芣', '芤', '芥', '芦', '芧', '芨', '芩', '芪', '芫',
+    '芬', '芭', '芮', '芯', '芰', '花', '芲', '芳', '芴', '芵', '芶', '芷', '芸', '芹', '芺', '芻',
+    '芼', '芽', '芾', '芿', '苀', '苁', '苂', '苃', '苄', '苅', '苆', '苇', '苈', '苉', '苊', '苋',
+    '苌', '苍', '苎', '苏', '苐', '苑', '苒', '苓', '苔', '苕', '苖', '苗', '苘', '苙', '苚', '苛',
+    '苜', '苝', '苞', '苟', '苠', '苡', '苢', '苣', '苤', '若', '苦', '苧', '苨', '苩', '苪', '苫',
+    '苬', '苭', '苮', '苯', '苰', '英', '苲', '苳', '苴', '苵', '苶', '苷', '苸', '苹', '苺', '苻',
+    '苼', '苽', '苾', '苿', '茀', '茁', '茂', '范', '茄', '茅', '茆', '茇', '茈', '茉', '茊', '茋',
+    '茌', '茍', '茎', '茏', '茐', '茑', '茒', '茓', '茔', '茕', '茖', '茗', '茘', '茙', '茚', '茛',
+    '茜', '茝', '茞', '茟', '茠', '茡', '茢', '茣', '茤', '茥', '茦', '茧', '茨', '茩', '茪', '茫',
+    '茬', '茭', '茮', '茯', '茰', '茱', '茲', '茳', '茴', '茵', '茶', '茷', '茸', '茹', '茺', '茻',
+    '茼', '茽', '茾', '茿', '荀', '荁', '荂', '荃', '荄', '荅', '荆', '荇', '荈', '草', '荊', '荋',
+    '荌', '荍', '荎', '荏', '荐', '荑', '荒', '荓', '荔', '荕', '荖', '荗', '荘', '荙', '荚', '荛',
+    '荜', '荝', '荞', '荟', '荠', '荡', '荢', '荣', '荤', '荥', '荦', '荧', '荨', '荩', '荪', '荫',
+    '荬', '荭', '荮', '药', '荰', '荱', '荲', '荳', '荴', '荵', '荶', '荷', '荸', '荹', '荺', '荻',
+    '荼', '荽', '荾', '荿', '莀', '莁', '莂', '莃', '莄', '莅', '莆', '莇', '莈', '莉', '莊', '莋',
+    '莌', '莍', '莎', '莏', '莐', '莑', '莒', '莓', '莔', '莕', '莖', '莗', '莘', '莙', '莚', '莛',
+    '莜', '莝', '莞', '莟', '莠', '莡', '莢', '莣', '莤', '莥', '莦', '莧', '莨', '莩', '莪', '莫',
+    '莬', '莭', '莮', '莯', '莰', '莱', '莲', '莳', '莴', '莵', '莶', '获', '莸', '莹', '莺', '莻',
+    '莼', '莽', '莾', '莿', '菀', '菁', '菂', '菃', '菄', '菅', '菆', '菇', '菈', '菉', '菊', '菋',
+    '菌', '菍', '菎', '菏', '菐', '菑', '菒', '菓', '菔', '菕', '菖', '菗', '菘', '菙', '菚', '菛',
+    '菜', '菝', '菞', '菟', '菠', '菡', '菢', '菣', '菤', '菥', '菦', '菧', '菨', '菩', '菪', '菫',
+    '菬', '菭', '菮', '華', '菰', '菱', '菲', '菳', '菴', '菵', '菶', '菷', '菸', '菹', '菺', '菻',
+    '菼', '菽', '菾', '菿', '萀', '萁', '萂', '萃', '萄', '萅', '萆', '萇', '萈', '萉', '萊', '萋',
+    '萌', '萍', '萎', '萏', '萐', '萑', '萒', '萓', '萔', '萕', '萖', '萗', '萘', '萙', '萚', '萛',
+    '萜', '萝', '萞', '萟', '萠', '萡', '萢', '萣', '萤', '营', '萦', '萧', '萨', '萩', '萪', '萫',
+    '萬', '萭', '萮', '萯', '萰', '萱', '萲', '萳', '萴', '萵', '萶', '萷', '萸', '萹', '萺', '萻',
+    '萼', '落', '萾', '萿', '葀', '葁', '葂', '葃', '葄', '葅', '葆', '葇', '葈', '葉', '葊', '葋',
+    '葌', '葍', '葎', '葏', '葐', '葑', '葒', '葓', '葔', '葕', '葖', '著', '葘', '葙', '葚', '葛',
+    '葜', '葝', '葞', '葟', '葠', '葡', '葢', '董', '葤', '葥', '葦', '葧', '葨', '葩', '葪', '葫',
+    '葬', '葭', '葮', '葯', '葰', '葱', '葲', '葳', '葴', '葵', '葶', '葷', '葸', '葹', '葺', '葻',
+    '葼', '葽', '葾', '葿', '蒀', '蒁', '蒂', '蒃', '蒄', '蒅', '蒆', '蒇', '蒈', '蒉', '蒊', '蒋',
+    '蒌', '蒍', '蒎', '蒏', '蒐', '蒑', '蒒', '蒓', '蒔', '蒕', '蒖', '蒗', '蒘', '蒙', '蒚', '蒛',
+    '蒜', '蒝', '蒞', '蒟', '蒠', '蒡', '蒢', '蒣', '蒤', '蒥', '蒦', '蒧', '蒨', '蒩', '蒪', '蒫',
+    '蒬', '蒭', '蒮', '蒯', '蒰', '蒱', '蒲', '蒳', '蒴', '蒵', '蒶', '蒷', '蒸', '蒹', '蒺', '蒻',
+    '蒼', '蒽', '蒾', '蒿', '蓀', '蓁', '蓂', '蓃', '蓄', '蓅', '蓆', '蓇', '蓈', '蓉', '蓊', '蓋',
+    '蓌', '蓍', '蓎', '蓏', '蓐', '蓑', '蓒', '蓓', '蓔', '蓕', '蓖', '蓗', '蓘', '蓙', '蓚', '蓛',
+    '蓜', '蓝', '蓞', '蓟', '蓠', '蓡', '蓢', '蓣', '蓤', '蓥', '蓦', '蓧', '蓨', '蓩', '蓪', '蓫',
+    '蓬', '蓭', '蓮', '蓯', '蓰', '蓱', '蓲', '蓳', '蓴', '蓵', '蓶', '蓷', '蓸', '蓹', '蓺', '蓻',
+    '蓼', '蓽', '蓾', '蓿', '蔀', '蔁', '蔂', '蔃', '蔄', '蔅', '蔆', '蔇', '蔈', '蔉', '蔊', '蔋',
+    '蔌', '蔍', '蔎', '蔏', '蔐', '蔑', '蔒', '蔓', '蔔', '蔕', '蔖', '蔗', '蔘', '蔙', '蔚', '蔛',
+    '蔜', '蔝', '蔞', '蔟', '蔠', '蔡', '蔢', '蔣', '蔤', '蔥', '蔦', '蔧', '蔨', '蔩', '蔪', '蔫',
+    '蔬', '蔭', '蔮', '蔯', '蔰', '蔱', '蔲', '蔳', '蔴', '蔵', '蔶', '蔷', '蔸', '蔹', '蔺', '蔻',
+    '蔼', '蔽', '蔾', '蔿', '蕀', '蕁', '蕂', '蕃', '蕄', '蕅', '蕆', '蕇', '蕈', '蕉', '蕊', '蕋',
+    '蕌', '蕍', '蕎', '蕏', '蕐', '蕑', '蕒', '蕓', '蕔', '蕕', '蕖', '蕗', '蕘', '蕙', '蕚', '蕛',
+    '蕜', '蕝', '蕞', '蕟', '蕠', '蕡', '蕢', '蕣', '蕤', '蕥', '蕦', '蕧', '蕨', '蕩', '蕪', '蕫',
+    '蕬', '蕭', '蕮', '蕯', '蕰', '蕱', '蕲', '蕳', '蕴', '蕵', '蕶', '蕷', '蕸', '蕹', '蕺', '蕻',
+    '蕼', '蕽', '蕾', '蕿', '薀', '薁', '薂', '薃', '薄', '薅', '薆', '薇', '薈', '薉', '薊', '薋',
+    '薌', '薍', '薎', '薏', '薐', '薑', '薒', '薓', '薔', '薕', '薖', '薗', '薘', '薙', '薚', '薛',
+    '薜', '薝', '薞', '薟', '薠', '薡', '薢', '薣', '薤', '薥', '薦', '薧', '薨', '薩', '薪', '薫',
+    '薬', '薭', '薮', '薯', '薰', '薱', '薲', '薳', '薴', '薵', '薶', '薷', '薸', '薹', '薺', '薻',
+    '薼', '薽', '薾', '薿', '藀', '藁', '藂', '藃', '藄', '藅', '藆', '藇', '藈', '藉', '藊', '藋',
+    '藌', '藍', '藎', '藏', '藐', '藑', '藒', '藓', '藔', '藕', '藖', '藗', '藘', '藙', '藚', '藛',
+    '藜', '藝', '藞', '藟', '藠', '藡', '藢', '藣', '藤', '藥', '藦', '藧', '藨', '藩', '藪', '藫',
+    '藬', '藭', '藮', '藯', '藰', '藱', '藲', '藳', '藴', '藵', '藶', '藷', '藸', '藹', '藺', '藻',
+    '藼', '藽', '藾', '藿', '蘀', '蘁', '蘂', '蘃', '蘄', '蘅', '蘆', '蘇', '蘈', '蘉', '蘊', '蘋',
+    '蘌', '蘍', '蘎', '蘏', '蘐', '蘑', '蘒', '蘓', '蘔', '蘕', '蘖', '蘗', '蘘', '蘙', '蘚', '蘛',
+    '蘜', '蘝', '蘞', '蘟', '蘠', '蘡', '蘢', '蘣', '蘤', '蘥', '蘦', '蘧', '蘨', '蘩', '蘪', '蘫',
+    '蘬', '蘭', '蘮', '蘯', '蘰', '蘱', '蘲', '蘳', '蘴', '蘵', '蘶', '蘷', '蘸', '蘹', '蘺', '蘻',
+    '蘼', '蘽', '蘾', '蘿', '虀', '虁', '虂', '虃', '虄', '虅', '虆', '虇', '虈', '虉', '虊', '虋',
+    '虌', '虍', '虎', '虏', '虐', '虑', '虒', '虓', '虔', '處', '虖', '虗', '虘', '虙', '虚', '虛',
+    '虜', '虝', '虞', '號', '虠', '虡', '虢', '虣', '虤', '虥', '虦', '虧', '虨', '虩', '虪', '虫',
+    '虬', '虭', '虮', '虯', '虰', '虱', '虲', '虳', '虴', '虵', '虶', '虷', '虸', '虹', '虺', '虻',
+    '虼', '虽', '虾', '虿', '蚀', '蚁', '蚂', '蚃', '蚄', '蚅', '蚆', '蚇', '蚈', '蚉', '蚊', '蚋',
+    '蚌', '蚍', '蚎', '蚏', '蚐', '蚑', '蚒', '蚓', '蚔', '蚕', '蚖', '蚗', '蚘', '蚙', '蚚', '蚛',
+    '蚜', '蚝', '蚞', '蚟', '蚠', '蚡', '蚢', '蚣', '蚤', '蚥', '蚦', '蚧', '蚨', '蚩', '蚪', '蚫',
+    '蚬', '蚭', '蚮', '蚯', '蚰', '蚱', '蚲', '蚳', '蚴', '蚵', '蚶', '蚷', '蚸', '蚹', '蚺', '蚻',
+    '蚼', '蚽', '蚾', '蚿', '蛀', '蛁', '蛂', '蛃', '蛄', '蛅', '蛆', '蛇', '蛈', '蛉', '蛊', '蛋',
+    '蛌', '蛍', '蛎', '蛏', '蛐', '蛑', '蛒', '蛓', '蛔', '蛕', '蛖', '蛗', '蛘', '蛙', '蛚', '蛛',
+    '蛜', '蛝', '蛞', '蛟', '蛠', '蛡', '蛢', '蛣', '蛤', '蛥', '蛦', '蛧', '蛨', '蛩', '蛪', '蛫',
+    '蛬', '蛭', '蛮', '蛯', '蛰', '蛱', '蛲', '蛳', '蛴', '蛵', '蛶', '蛷', '蛸', '蛹', '蛺', '蛻',
+    '蛼', '蛽', '蛾', '蛿', '蜀', '蜁', '蜂', '蜃', '蜄', '蜅', '蜆', '蜇', '蜈', '蜉', '蜊', '蜋',
+    '蜌', '蜍', '蜎', '蜏', '蜐', '蜑', '蜒', '蜓', '蜔', '蜕', '蜖', '蜗', '蜘', '蜙', '蜚', '蜛',
+    '蜜', '蜝', '蜞', '蜟', '蜠', '蜡', '蜢', '蜣', '蜤', '蜥', '蜦', '蜧', '蜨', '蜩', '蜪', '蜫',
+    '蜬', '蜭', '蜮', '蜯', '蜰', '蜱', '蜲', '蜳', '蜴', '蜵', '蜶', '蜷', '蜸', '蜹', '蜺', '蜻',
+    '蜼', '蜽', '蜾', '蜿', '蝀', '蝁', '蝂', '蝃', '蝄', '蝅', '蝆', '蝇', '蝈', '蝉', '蝊', '蝋',
+    '蝌', '蝍', '蝎', '蝏', '蝐', '蝑', '蝒', '蝓', '蝔', '蝕', '蝖', '蝗', '蝘', '蝙', '蝚', '蝛',
+    '蝜', '蝝', '蝞', '蝟', '蝠', '蝡', '蝢', '蝣', '蝤', '蝥', '蝦', '蝧', '蝨', '蝩', '蝪', '蝫',
+    '蝬', '蝭', '蝮', '蝯', '蝰', '蝱', '蝲', '蝳', '蝴', '蝵', '蝶', '蝷', '蝸', '蝹', '蝺', '蝻',
+    '蝼', '蝽', '蝾', '蝿', '螀', '螁', '螂', '螃', '螄', '螅', '螆', '螇', '螈', '螉', '螊', '螋',
+    '螌', '融', '螎', '螏', '螐', '螑', '螒', '螓', '螔', '螕', '螖', '螗', '螘', '螙', '螚', '螛',
+    '螜', '螝', '螞', '螟', '螠', '螡', '螢', '螣', '螤', '螥', '螦', '螧', '螨', '螩', '螪', '螫',
+    '螬', '螭', '螮', '螯', '螰', '螱', '螲', '螳', '螴', '螵', '螶', '螷', '螸', '螹', '螺', '螻',
+    '螼', '螽', '螾', '螿', '蟀', '蟁', '蟂', '蟃', '蟄', '蟅', '蟆', '蟇', '蟈', '蟉', '蟊', '蟋',
+    '蟌', '蟍', '蟎', '蟏', '蟐', '蟑', '蟒', '蟓', '蟔', '蟕', '蟖', '蟗', '蟘', '蟙', '蟚', '蟛',
+    '蟜', '蟝', '蟞', '蟟', '蟠', '蟡', '蟢', '蟣', '蟤', '蟥', '蟦', '蟧', '蟨', '蟩', '蟪', '蟫',
+    '蟬', '蟭', '蟮', '蟯', '蟰', '蟱', '蟲', '蟳', '蟴', '蟵', '蟶', '蟷', '蟸', '蟹', '蟺', '蟻',
+    '蟼', '蟽', '蟾', '蟿', '蠀', '蠁', '蠂', '蠃', '蠄', '蠅', '蠆', '蠇', '蠈', '蠉', '蠊', '蠋',
+    '蠌', '蠍', '蠎', '蠏', '蠐', '蠑', '蠒', '蠓', '蠔', '蠕', '蠖', '蠗', '蠘', '蠙', '蠚', '蠛',
+    '蠜', '蠝', '蠞', '蠟', '蠠', '蠡', '蠢', '蠣', '蠤', '蠥', '蠦', '蠧', '蠨', '蠩', '蠪', '蠫',
+    '蠬', '蠭', '蠮', '蠯', '蠰', '蠱', '蠲', '蠳', '蠴', '蠵', '蠶', '蠷', '蠸', '蠹', '蠺', '蠻',
+    '蠼', '蠽', '蠾', '蠿', '血', '衁', '衂', '衃', '衄', '衅', '衆', '衇', '衈', '衉', '衊', '衋',
+    '行', '衍', '衎', '衏', '衐', '衑', '衒', '術', '衔', '衕', '衖', '街', '衘', '衙', '衚', '衛',
+    '衜', '衝', '衞', '衟', '衠', '衡', '衢', '衣', '衤', '补', '衦', '衧', '表', '衩', '衪', '衫',
+    '衬', '衭', '衮', '衯', '衰', '衱', '衲', '衳', '衴', '衵', '衶', '衷', '衸', '衹', '衺', '衻',
+    '衼', '衽', '衾', '衿', '袀', '袁', '袂', '袃', '袄', '袅', '袆', '袇', '袈', '袉', '袊', '袋',
+    '袌', '袍', '袎', '袏', '袐', '袑', '袒', '袓', '袔', '袕', '袖', '袗', '袘', '袙', '袚', '袛',
+    '袜', '袝', '袞', '袟', '袠', '袡', '袢', '袣', '袤', '袥', '袦', '袧', '袨', '袩', '袪', '被',
+    '袬', '袭', '袮', '袯', '袰', '袱', '袲', '袳', '袴', '袵', '袶', '袷', '袸', '袹', '袺', '袻',
+    '袼', '袽', '袾', '袿', '裀', '裁', '裂', '裃', '裄', '装', '裆', '裇', '裈', '裉', '裊', '裋',
+    '裌', '裍', '裎', '裏', '裐', '裑', '裒', '裓', '裔', '裕', '裖', '裗', '裘', '裙', '裚', '裛',
+    '補', '裝', '裞', '裟', '裠', '裡', '裢', '裣', '裤', '裥', '裦', '裧', '裨', '裩', '裪', '裫',
+    '裬', '裭', '裮', '裯', '裰', '裱', '裲', '裳', '裴', '裵', '裶', '裷', '裸', '裹', '裺', '裻',
+    '裼', '製', '裾', '裿', '褀', '褁', '褂', '褃', '褄', '褅', '褆', '複', '褈', '褉', '褊', '褋',
+    '褌', '褍', '褎', '褏', '褐', '褑', '褒', '褓', '褔', '褕', '褖', '褗', '褘', '褙', '褚', '褛',
+    '褜', '褝', '褞', '褟', '褠', '褡', '褢', '褣', '褤', '褥', '褦', '褧', '褨', '褩', '褪', '褫',
+    '褬', '褭', '褮', '褯', '褰', '褱', '褲', '褳', '褴', '褵', '褶', '褷', '褸', '褹', '褺', '褻',
+    '褼', '褽', '褾', '褿', '襀', '襁', '襂', '襃', '襄', '襅', '襆', '襇', '襈', '襉', '襊', '襋',
+    '襌', '襍', '襎', '襏', '襐', '襑', '襒', '襓', '襔', '襕', '襖', '襗', '襘', '襙', '襚', '襛',
+    '襜', '襝', '襞', '襟', '襠', '襡', '襢', '襣', '襤', '襥', '襦', '襧', '襨', '襩', '襪', '襫',
+    '襬', '襭', '襮', '襯', '襰', '襱', '襲', '襳', '襴', '襵', '襶', '襷', '襸', '襹', '襺', '襻',
+    '襼', '襽', '襾', '西', '覀', '要', '覂', '覃', '覄', '覅', '覆', '覇', '覈', '覉', '覊', '見',
+    '覌', '覍', '覎', '規', '覐', '覑', '覒', '覓', '覔', '覕', '視', '覗', '覘', '覙', '覚', '覛',
+    '覜', '覝', '覞', '覟', '覠', '覡', '覢', '覣', '覤', '覥', '覦', '覧', '覨', '覩', '親', '覫',
+    '覬', '覭', '覮', '覯', '覰', '覱', '覲', '観', '覴', '覵', '覶', '覷', '覸', '覹', '覺', '覻',
+    '覼', '覽', '覾', '覿', '觀', '见', '观', '觃', '规', '觅', '视', '觇', '览', '觉', '觊', '觋',
+    '觌', '觍', '觎', '觏', '觐', '觑', '角', '觓', '觔', '觕', '觖', '觗', '觘', '觙', '觚', '觛',
+    '觜', '觝', '觞', '觟', '觠', '觡', '觢', '解', '觤', '觥', '触', '觧', '觨', '觩', '觪', '觫',
+    '觬', '觭', '觮', '觯', '觰', '觱', '觲', '觳', '觴', '觵', '觶', '觷', '觸', '觹', '觺', '觻',
+    '觼', '觽', '觾', '觿', '言', '訁', '訂', '訃', '訄', '訅', '訆', '訇', '計', '訉', '訊', '訋',
+    '訌', '訍', '討', '訏', '訐', '訑', '訒', '訓', '訔', '訕', '訖', '託', '記', '訙', '訚', '訛',
+    '訜', '訝', '訞', '訟', '訠', '訡', '訢', '訣', '訤', '訥', '訦', '訧', '訨', '訩', '訪', '訫',
+    '訬', '設', '訮', '訯', '訰', '許', '訲', '訳', '訴', '訵', '訶', '訷', '訸', '訹', '診', '註',
+    '証', '訽', '訾', '訿', '詀', '詁', '詂', '詃', '詄', '詅', '詆', '詇', '詈', '詉', '詊', '詋',
+    '詌', '詍', '詎', '詏', '詐', '詑', '詒', '詓', '詔', '評', '詖', '詗', '詘', '詙', '詚', '詛',
+    '詜', '詝', '詞', '詟', '詠', '詡', '詢', '詣', '詤', '詥', '試', '詧', '詨', '詩', '詪', '詫',
+    '詬', '詭', '詮', '詯', '詰', '話', '該', '詳', '詴', '詵', '詶', '詷', '詸', '詹', '詺', '詻',
+    '詼', '詽', '詾', '詿', '誀', '誁', '誂', '誃', '誄', '誅', '誆', '誇', '誈', '誉', '誊', '誋',
+    '誌', '認', '誎', '誏', '誐', '誑', '誒', '誓', '誔', '誕', '誖', '誗', '誘', '誙', '誚', '誛',
+    '誜', '誝', '語', '誟', '誠', '誡', '誢', '誣', '誤', '誥', '誦', '誧', '誨', '誩', '說', '誫',
+    '説', '読', '誮', '誯', '誰', '誱', '課', '誳', '誴', '誵', '誶', '誷', '誸', '誹', '誺', '誻',
+    '誼', '誽', '誾', '調', '諀', '諁', '諂', '諃', '諄', '諅', '諆', '談', '諈', '諉', '諊', '請',
+    '諌', '諍', '諎', '諏', '諐', '諑', '諒', '諓', '諔', '諕', '論', '諗', '諘', '諙', '諚', '諛',
+    '諜', '諝', '諞', '諟', '諠', '諡', '諢', '諣', '諤', '諥', '諦', '諧', '諨', '諩', '諪', '諫',
+    '諬', '諭', '諮', '諯', '諰', '諱', '諲', '諳', '諴', '諵', '諶', '諷', '諸', '諹', '諺', '諻',
+    '諼', '諽', '諾', '諿', '謀', '謁', '謂', '謃', '謄', '謅', '謆', '謇', '謈', '謉', '謊', '謋',
+    '謌', '謍', '謎', '謏', '謐', '謑', '謒', '謓', '謔', '謕', '謖', '謗', '謘', '謙', '謚', '講',
+    '謜', '謝', '謞', '謟', '謠', '謡', '謢', '謣', '謤', '謥', '謦', '謧', '謨', '謩', '謪', '謫',
+    '謬', '謭', '謮', '謯', '謰', '謱', '謲', '謳', '謴', '謵', '謶', '謷', '謸', '謹', '謺', '謻',
+    '謼', '謽', '謾', '謿', '譀', '譁', '譂', '譃', '譄', '譅', '譆', '譇', '譈', '證', '譊', '譋',
+    '譌', '譍', '譎', '譏', '譐', '譑', '譒', '譓', '譔', '譕', '譖', '譗', '識', '譙', '譚', '譛',
+    '譜', '譝', '譞', '譟', '譠', '譡', '譢', '譣', '譤', '譥', '警', '譧', '譨', '譩', '譪', '譫',
+    '譬', '譭', '譮', '譯', '議', '譱', '譲', '譳', '譴', '譵', '譶', '護', '譸', '譹', '譺', '譻',
+    '譼', '譽', '譾', '譿', '讀', '讁', '讂', '讃', '讄', '讅', '讆', '讇', '讈', '讉', '變', '讋',
+    '讌', '讍', '讎', '讏', '讐', '讑', '讒', '讓', '讔', '讕', '讖', '讗', '讘', '讙', '讚', '讛',
+    '讜', '讝', '讞', '讟', '讠', '计', '订', '讣', '认', '讥', '讦', '讧', '讨', '让', '讪', '讫',
+    '讬', '训', '议', '讯', '记', '讱', '讲', '讳', '讴', '讵', '讶', '讷', '许', '讹', '论', '讻',
+    '讼', '讽', '设', '访', '诀', '证', '诂', '诃', '评', '诅', '识', '诇', '诈', '诉', '诊', '诋',
+    '诌', '词', '诎', '诏', '诐', '译', '诒', '诓', '诔', '试', '诖', '诗', '诘', '诙', '诚', '诛',
+    '诜', '话', '诞', '诟', '诠', '诡', '询', '诣', '诤', '该', '详', '诧', '诨', '诩', '诪', '诫',
+    '诬', '语', '诮', '误', '诰', '诱', '诲', '诳', '说', '诵', '诶', '请', '诸', '诹', '诺', '读',
+    '诼', '诽', '课', '诿', '谀', '谁', '谂', '调', '谄', '谅', '谆', '谇', '谈', '谉', '谊', '谋',
+    '谌', '谍', '谎', '谏', '谐', '谑', '谒', '谓', '谔', '谕', '谖', '谗', '谘', '谙', '谚', '谛',
+    '谜', '谝', '谞', '谟', '谠', '谡', '谢', '谣', '谤', '谥', '谦', '谧', '谨', '谩', '谪', '谫',
+    '谬', '谭', '谮', '谯', '谰', '谱', '谲', '谳', '谴', '谵', '谶', '谷', '谸', '谹', '谺', '谻',
+    '谼', '谽', '谾', '谿', '豀', '豁', '豂', '豃', '豄', '豅', '豆', '豇', '豈', '豉', '豊', '豋',
+    '豌', '豍', '豎', '豏', '豐', '豑', '豒', '豓', '豔', '豕', '豖', '豗', '豘', '豙', '豚', '豛',
+    '豜', '豝', '豞', '豟', '豠', '象', '豢', '豣', '豤', '豥', '豦', '豧', '豨', '豩', '豪', '豫',
+    '豬', '豭', '豮', '豯', '豰', '豱', '豲', '豳', '豴', '豵', '豶', '豷', '豸', '豹', '豺', '豻',
+    '豼', '豽', '豾', '豿', '貀', '貁', '貂', '貃', '貄', '貅', '貆', '貇', '貈', '貉', '貊', '貋',
+    '貌', '貍', '貎', '貏', '貐', '貑', '貒', '貓', '貔', '貕', '貖', '貗', '貘', '貙', '貚', '貛',
+    '貜', '貝', '貞', '貟', '負', '財', '貢', '貣', '貤', '貥', '貦', '貧', '貨', '販', '貪', '貫',
+    '責', '貭', '貮', '貯', '貰', '貱', '貲', '貳', '貴', '貵', '貶', '買', '貸', '貹', '貺', '費',
+    '貼', '貽', '貾', '貿', '賀', '賁', '賂', '賃', '賄', '賅', '賆', '資', '賈', '賉', '賊', '賋',
+    '賌', '賍', '賎', '賏', '賐', '賑', '賒', '賓', '賔', '賕', '賖', '賗', '賘', '賙', '賚', '賛',
+    '賜', '賝', '賞', '賟', '賠', '賡', '賢', '賣', '賤', '賥', '賦', '賧', '賨', '賩', '質', '賫',
+    '賬', '賭', '賮', '賯', '賰', '賱', '賲', '賳', '賴', '賵', '賶', '賷', '賸', '賹', '賺', '賻',
+    '購', '賽', '賾', '賿', '贀', '贁', '贂', '贃', '贄', '贅', '贆', '贇', '贈', '贉', '贊', '贋',
+    '贌', '贍', '贎', '贏', '贐', '贑', '贒', '贓', '贔', '贕', '贖', '贗', '贘', '贙', '贚', '贛',
+    '贜', '贝', '贞', '负', '贠', '贡', '财', '责', '贤', '败', '账', '货', '质', '贩', '贪', '贫',
+    '贬', '购', '贮', '贯', '贰', '贱', '贲', '贳', '贴', '贵', '贶', '贷', '贸', '费', '贺', '贻',
+    '贼', '贽', '贾', '贿', '赀', '赁', '赂', '赃', '资', '赅', '赆', '赇', '赈', '赉', '赊', '赋',
+    '赌', '赍', '赎', '赏', '赐', '赑', '赒', '赓', '赔', '赕', '赖', '赗', '赘', '赙', '赚', '赛',
+    '赜', '赝', '赞', '赟', '赠', '赡', '赢', '赣', '赤', '赥', '赦', '赧', '赨', '赩', '赪', '赫',
+    '赬', '赭', '赮', '赯', '走', '赱', '赲', '赳', '赴', '赵', '赶', '起', '赸', '赹', '赺', '赻',
+    '赼', '赽', '赾', '赿', '趀', '趁', '趂', '趃', '趄', '超', '趆', '趇', '趈', '趉', '越', '趋',
+    '趌', '趍', '趎', '趏', '趐', '趑', '趒', '趓', '趔', '趕', '趖', '趗', '趘', '趙', '趚', '趛',
+    '趜', '趝', '趞', '趟', '趠', '趡', '趢', '趣', '趤', '趥', '趦', '趧', '趨', '趩', '趪', '趫',
+    '趬', '趭', '趮', '趯', '趰', '趱', '趲', '足', '趴', '趵', '趶', '趷', '趸', '趹', '趺', '趻',
+    '趼', '趽', '趾', '趿', '跀', '跁', '跂', '跃', '跄', '跅', '跆', '跇', '跈', '跉', '跊', '跋',
+    '跌', '跍', '跎', '跏', '跐', '跑', '跒', '跓', '跔', '跕', '跖', '跗', '跘', '跙', '跚', '跛',
+    '跜', '距', '跞', '跟', '跠', '跡', '跢', '跣', '跤', '跥', '跦', '跧', '跨', '跩', '跪', '跫',
+    '跬', '跭', '跮', '路', '跰', '跱', '跲', '跳', '跴', '践', '跶', '跷', '跸', '跹', '跺', '跻',
+    '跼', '跽', '跾', '跿', '踀', '踁', '踂', '踃', '踄', '踅', '踆', '踇', '踈', '踉', '踊', '踋',
+    '踌', '踍', '踎', '踏', '踐', '踑', '踒', '踓', '踔', '踕', '踖', '踗', '踘', '踙', '踚', '踛',
+    '踜', '踝', '踞', '踟', '踠', '踡', '踢', '踣', '踤', '踥', '踦', '踧', '踨', '踩', '踪', '踫',
+    '踬', '踭', '踮', '踯', '踰', '踱', '踲', '踳', '踴', '踵', '踶', '踷', '踸', '踹', '踺', '踻',
+    '踼', '踽', '踾', '踿', '蹀', '蹁', '蹂', '蹃', '蹄', '蹅', '蹆', '蹇', '蹈', '蹉', '蹊', '蹋',
+    '蹌', '蹍', '蹎', '蹏', '蹐', '蹑', '蹒', '蹓', '蹔', '蹕', '蹖', '蹗', '蹘', '蹙', '蹚', '蹛',
+    '蹜', '蹝', '蹞', '蹟', '蹠', '蹡', '蹢', '蹣', '蹤', '蹥', '蹦', '蹧', '蹨', '蹩', '蹪', '蹫',
+    '蹬', '蹭', '蹮', '蹯', '蹰', '蹱', '蹲', '蹳', '蹴', '蹵', '蹶', '蹷', '蹸', '蹹', '蹺', '蹻',
+    '蹼', '蹽', '蹾', '蹿', '躀', '躁', '躂', '躃', '躄', '躅', '躆', '躇', '躈', '躉', '躊', '躋',
+    '躌', '躍', '躎', '躏', '躐', '躑', '躒', '躓', '躔', '躕', '躖', '躗', '躘', '躙', '躚', '躛',
+    '躜', '躝', '躞', '躟', '躠', '躡', '躢', '躣', '躤', '躥', '躦', '躧', '躨', '躩', '躪', '身',
+    '躬', '躭', '躮', '躯', '躰', '躱', '躲', '躳', '躴', '躵', '躶', '躷', '躸', '躹', '躺', '躻',
+    '躼', '躽', '躾', '躿', '軀', '軁', '軂', '軃', '軄', '軅', '軆', '軇', '軈', '軉', '車', '軋',
+    '軌', '軍', '軎', '軏', '軐', '軑', '軒', '軓', '軔', '軕', '軖', '軗', '軘', '軙', '軚', '軛',
+    '軜', '軝', '軞', '軟', '軠', '軡', '転', '軣', '軤', '軥', '軦', '軧', '軨', '軩', '軪', '軫',
+    '軬', '軭', '軮', '軯', '軰', '軱', '軲', '軳', '軴', '軵', '軶', '軷', '軸', '軹', '軺', '軻',
+    '軼', '軽', '軾', '軿', '輀', '輁', '輂', '較', '輄', '輅', '輆', '輇', '輈', '載', '輊', '輋',
+    '輌', '輍', '輎', '輏', '輐', '輑', '輒', '輓', '輔', '輕', '輖', '輗', '輘', '輙', '輚', '輛',
+    '輜', '輝', '輞', '輟', '輠', '輡', '輢', '輣', '輤', '輥', '輦', '輧', '輨', '輩', '輪', '輫',
+    '輬', '輭', '輮', '輯', '輰', '輱', '輲', '輳', '輴', '輵', '輶', '輷', '輸', '輹', '輺', '輻',
+    '輼', '輽', '輾', '輿', '轀', '轁', '轂', '轃', '轄', '轅', '轆', '轇', '轈', '轉', '轊', '轋',
+    '轌', '轍', '轎', '轏', '轐', '轑', '轒', '轓', '轔', '轕', '轖', '轗', '轘', '轙', '轚', '轛',
+    '轜', '轝', '轞', '轟', '轠', '轡', '轢', '轣', '轤', '轥', '车', '轧', '轨', '轩', '轪', '轫',
+    '转', '轭', '轮', '软', '轰', '轱', '轲', '轳', '轴', '轵', '轶', '轷', '轸', '轹', '轺', '轻',
+    '轼', '载', '轾', '轿', '辀', '辁', '辂', '较', '辄', '辅', '辆', '辇', '辈', '辉', '辊', '辋',
+    '辌', '辍', '辎', '辏', '辐', '辑', '辒', '输', '辔', '辕', '辖', '辗', '辘', '辙', '辚', '辛',
+    '辜', '辝', '辞', '辟', '辠', '辡', '辢', '辣', '辤', '辥', '辦', '辧', '辨', '辩', '辪', '辫',
+    '辬', '辭', '辮', '辯', '辰', '辱', '農', '辳', '辴', '辵', '辶', '辷', '辸', '边', '辺', '辻',
+    '込', '辽', '达', '辿', '迀', '迁', '迂', '迃', '迄', '迅', '迆', '过', '迈', '迉', '迊', '迋',
+    '迌', '迍', '迎', '迏', '运', '近', '迒', '迓', '返', '迕', '迖', '迗', '还', '这', '迚', '进',
+    '远', '违', '连', '迟', '迠', '迡', '迢', '迣', '迤', '迥', '迦', '迧', '迨', '迩', '迪', '迫',
+    '迬', '迭', '迮', '迯', '述', '迱', '迲', '迳', '迴', '迵', '迶', '迷', '迸', '迹', '迺', '迻',
+    '迼', '追', '迾', '迿', '退', '送', '适', '逃', '逄', '逅', '逆', '逇', '逈', '选', '逊', '逋',
+    '逌', '逍', '逎', '透', '逐', '逑', '递', '逓', '途', '逕', '逖', '逗', '逘', '這', '通', '逛',
+    '逜', '逝', '逞', '速', '造', '逡', '逢', '連', '逤', '逥', '逦', '逧', '逨', '逩', '逪', '逫',
+    '逬', '逭', '逮', '逯', '逰', '週', '進', '逳', '逴', '逵', '逶', '逷', '逸', '逹', '逺', '逻',
+    '逼', '逽', '逾', '逿', '遀', '遁', '遂', '遃', '遄', '遅', '遆', '遇', '遈', '遉', '遊', '運',
+    '遌', '遍', '過', '遏', '遐', '遑', '遒', '道', '達', '違', '遖', '遗', '遘', '遙', '遚', '遛',
+    '遜', '遝', '遞', '遟', '遠', '遡', '遢', '遣', '遤', '遥', '遦', '遧', '遨', '適', '遪', '遫',
+    '遬', '遭', '遮', '遯', '遰', '遱', '遲', '遳', '遴', '遵', '遶', '遷', '選', '遹', '遺', '遻',
+    '遼', '遽', '遾', '避', '邀', '邁', '邂', '邃', '還', '邅', '邆', '邇', '邈', '邉', '邊', '邋',
+    '邌', '邍', '邎', '邏', '邐', '邑', '邒', '邓', '邔', '邕', '邖', '邗', '邘', '邙', '邚', '邛',
+    '邜', '邝', '邞', '邟', '邠', '邡', '邢', '那', '邤', '邥', '邦', '邧', '邨', '邩', '邪', '邫',
+    '邬', '邭', '邮', '邯', '邰', '邱', '邲', '邳', '邴', '邵', '邶', '邷', '邸', '邹', '邺', '邻',
+    '邼', '邽', '邾', '邿', '郀', '郁', '郂', '郃', '郄', '郅', '郆', '郇', '郈', '郉', '郊', '郋',
+    '郌', '郍', '郎', '郏', '郐', '郑', '郒', '郓', '郔', '郕', '郖', '郗', '郘', '郙', '郚', '郛',
+    '郜', '郝', '郞', '郟', '郠', '郡', '郢', '郣', '郤', '郥', '郦', '郧', '部', '郩', '郪', '郫',
+    '郬', '郭', '郮', '郯', '郰', '郱', '郲', '郳', '郴', '郵', '郶', '郷', '郸', '郹', '郺', '郻',
+    '郼', '都', '郾', '郿', '鄀', '鄁', '鄂', '鄃', '鄄', '鄅', '鄆', '鄇', '鄈', '鄉', '鄊', '鄋',
+    '鄌', '鄍', '鄎', '鄏', '鄐', '鄑', '鄒', '鄓', '鄔', '鄕', '鄖', '鄗', '鄘', '鄙', '鄚', '鄛',
+    '鄜', '鄝', '鄞', '鄟', '鄠', '鄡', '鄢', '鄣', '鄤', '鄥', '鄦', '鄧', '鄨', '鄩', '鄪', '鄫',
+    '鄬', '鄭', '鄮', '鄯', '鄰', '鄱', '鄲', '鄳', '鄴', '鄵', '鄶', '鄷', '鄸', '鄹', '鄺', '鄻',
+    '鄼', '鄽', '鄾', '鄿', '酀', '酁', '酂', '酃', '酄', '酅', '酆', '酇', '酈', '酉', '酊', '酋',
+    '酌', '配', '酎', '酏', '酐', '酑', '酒', '酓', '酔', '酕', '酖', '酗', '酘', '酙', '酚', '酛',
+    '酜', '酝', '酞', '酟', '酠', '酡', '酢', '酣', '酤', '酥', '酦', '酧', '酨', '酩', '酪', '酫',
+    '酬', '酭', '酮', '酯', '酰', '酱', '酲', '酳', '酴', '酵', '酶', '酷', '酸', '酹', '酺', '酻',
+    '酼', '酽', '酾', '酿', '醀', '醁', '醂', '醃', '醄', '醅', '醆', '醇', '醈', '醉', '醊', '醋',
+    '醌', '醍', '醎', '醏', '醐', '醑', '醒', '醓', '醔', '醕', '醖', '醗', '醘', '醙', '醚', '醛',
+    '醜', '醝', '醞', '醟', '醠', '醡', '醢', '醣', '醤', '醥', '醦', '醧', '醨', '醩', '醪', '醫',
+    '醬', '醭', '醮', '醯', '醰', '醱', '醲', '醳', '醴', '醵', '醶', '醷', '醸', '醹', '醺', '醻',
+    '醼', '醽', '醾', '醿', '釀', '釁', '釂', '釃', '釄', '釅', '釆', '采', '釈', '釉', '释', '釋',
+    '里', '重', '野', '量', '釐', '金', '釒', '釓', '釔', '釕', '釖', '釗', '釘', '釙', '釚', '釛',
+    '釜', '針', '釞', '釟', '釠', '釡', '釢', '釣', '釤', '釥', '釦', '釧', '釨', '釩', '釪', '釫',
+    '釬', '釭', '釮', '釯', '釰', '釱', '釲', '釳', '釴', '釵', '釶', '釷', '釸', '釹', '釺', '釻',
+    '釼', '釽', '釾', '釿', '鈀', '鈁', '鈂', '鈃', '鈄', '鈅', '鈆', '鈇', '鈈', '鈉', '鈊', '鈋',
+    '鈌', '鈍', '鈎', '鈏', '鈐', '鈑', '鈒', '鈓', '鈔', '鈕', '鈖', '鈗', '鈘', '鈙', '鈚', '鈛',
+    '鈜', '鈝', '鈞', '鈟', '鈠', '鈡', '鈢', '鈣', '鈤', '鈥', '鈦', '鈧', '鈨', '鈩', '鈪', '鈫',
+    '鈬', '鈭', '鈮', '鈯', '鈰', '鈱', '鈲', '鈳', '鈴', '鈵', '鈶', '鈷', '鈸', '鈹', '鈺', '鈻',
+    '鈼', '鈽', '鈾', '鈿', '鉀', '鉁', '鉂', '鉃', '鉄', '鉅', '鉆', '鉇', '鉈', '鉉', '鉊', '鉋',
+    '鉌', '鉍', '鉎', '鉏', '鉐', '鉑', '鉒', '鉓', '鉔', '鉕', '鉖', '鉗', '鉘', '鉙', '鉚', '鉛',
+    '鉜', '鉝', '鉞', '鉟', '鉠', '鉡', '鉢', '鉣', '鉤', '鉥', '鉦', '鉧', '鉨', '鉩', '鉪', '鉫',
+    '鉬', '鉭', '鉮', '鉯', '鉰', '鉱', '鉲', '鉳', '鉴', '鉵', '鉶', '鉷', '鉸', '鉹', '鉺', '鉻',
+    '鉼', '鉽', '鉾', '鉿', '銀', '銁', '銂', '銃', '銄', '銅', '銆', '銇', '銈', '銉', '銊', '銋',
+    '銌', '銍', '銎', '銏', '銐', '銑', '銒', '銓', '銔', '銕', '銖', '銗', '銘', '銙', '銚', '銛',
+    '銜', '銝', '銞', '銟', '銠', '銡', '銢', '銣', '銤', '銥', '銦', '銧', '銨', '銩', '銪', '銫',
+    '銬', '銭', '銮', '銯', '銰', '銱', '銲', '銳', '銴', '銵', '銶', '銷', '銸', '銹', '銺', '銻',
+    '銼', '銽', '銾', '銿', '鋀', '鋁', '鋂', '鋃', '鋄', '鋅', '鋆', '鋇', '鋈', '鋉', '鋊', '鋋',
+    '鋌', '鋍', '鋎', '鋏', '鋐', '鋑', '鋒', '鋓', '鋔', '鋕', '鋖', '鋗', '鋘', '鋙', '鋚', '鋛',
+    '鋜', '鋝', '鋞', '鋟', '鋠', '鋡', '鋢', '鋣', '鋤', '鋥', '鋦', '鋧', '鋨', '鋩', '鋪', '鋫',
+    '鋬', '鋭', '鋮', '鋯', '鋰', '鋱', '鋲', '鋳', '鋴', '鋵', '鋶', '鋷', '鋸', '鋹', '鋺', '鋻',
+    '鋼', '鋽', '鋾', '鋿', '錀', '錁', '錂', '錃', '錄', '錅', '錆', '錇', '錈', '錉', '錊', '錋',
+    '錌', '錍', '錎', '錏', '錐', '錑', '錒', '錓', '錔', '錕', '錖', '錗', '錘', '錙', '錚', '錛',
+    '錜', '錝', '錞', '錟', '錠', '錡', '錢', '錣', '錤', '錥', '錦', '錧', '錨', '錩', '錪', '錫',
+    '錬', '錭', '錮', '錯', '錰', '錱', '録', '錳', '錴', '錵', '錶', '錷', '錸', '錹', '錺', '錻',
+    '錼', '錽', '錾', '錿', '鍀', '鍁', '鍂', '鍃', '鍄', '鍅', '鍆', '鍇', '鍈', '鍉', '鍊', '鍋',
+    '鍌', '鍍', '鍎', '鍏', '鍐', '鍑', '鍒', '鍓', '鍔', '鍕', '鍖', '鍗', '鍘', '鍙', '鍚', '鍛',
+    '鍜', '鍝', '鍞', '鍟', '鍠', '鍡', '鍢', '鍣', '鍤', '鍥', '鍦', '鍧', '鍨', '鍩', '鍪', '鍫',
+    '鍬', '鍭', '鍮', '鍯', '鍰', '鍱', '鍲', '鍳', '鍴', '鍵', '鍶', '鍷', '鍸', '鍹', '鍺', '鍻',
+    '鍼', '鍽', '鍾', '鍿', '鎀', '鎁', '鎂', '鎃', '鎄', '鎅', '鎆', '鎇', '鎈', '鎉', '鎊', '鎋',
+    '鎌', '鎍', '鎎', '鎏', '鎐', '鎑', '鎒', '鎓', '鎔', '鎕', '鎖', '鎗', '鎘', '鎙', '鎚', '鎛',
+    '鎜', '鎝', '鎞', '鎟', '鎠', '鎡', '鎢', '鎣', '鎤', '鎥', '鎦', '鎧', '鎨', '鎩', '鎪', '鎫',
+    '鎬', '鎭', '鎮', '鎯', '鎰', '鎱', '鎲', '鎳', '鎴', '鎵', '鎶', '鎷', '鎸', '鎹', '鎺', '鎻',
+    '鎼', '鎽', '鎾', '鎿', '鏀', '鏁', '鏂', '鏃', '鏄', '鏅', '鏆', '鏇', '鏈', '鏉', '鏊', '鏋',
+    '鏌', '鏍', '鏎', '鏏', '鏐', '鏑', '鏒', '鏓', '鏔', '鏕', '鏖', '鏗', '鏘', '鏙', '鏚', '鏛',
+    '鏜', '鏝', '鏞', '鏟', '鏠', '鏡', '鏢', '鏣', '鏤', '鏥', '鏦', '鏧', '鏨', '鏩', '鏪', '鏫',
+    '鏬', '鏭', '鏮', '鏯', '鏰', '鏱', '鏲', '鏳', '鏴', '鏵', '鏶', '鏷', '鏸', '鏹', '鏺', '鏻',
+    '鏼', '鏽', '鏾', '鏿', '鐀', '鐁', '鐂', '鐃', '鐄', '鐅', '鐆', '鐇', '鐈', '鐉', '鐊', '鐋',
+    '鐌', '鐍', '鐎', '鐏', '鐐', '鐑', '鐒', '鐓', '鐔', '鐕', '鐖', '鐗', '鐘', '鐙', '鐚', '鐛',
+    '鐜', '鐝', '鐞', '鐟', '鐠', '鐡', '鐢', '鐣', '鐤', '鐥', '鐦', '鐧', '鐨', '鐩', '鐪', '鐫',
+    '鐬', '鐭', '鐮', '鐯', '鐰', '鐱', '鐲', '鐳', '鐴', '鐵', '鐶', '鐷', '鐸', '鐹', '鐺', '鐻',
+    '鐼', '鐽', '鐾', '鐿', '鑀', '鑁', '鑂', '鑃', '鑄', '鑅', '鑆', '鑇', '鑈', '鑉', '鑊', '鑋',
+    '鑌', '鑍', '鑎', '鑏', '鑐', '鑑', '鑒', '鑓', '鑔', '鑕', '鑖', '鑗', '鑘', '鑙', '鑚', '鑛',
+    '鑜', '鑝', '鑞', '鑟', '鑠', '鑡', '鑢', '鑣', '鑤', '鑥', '鑦', '鑧', '鑨', '鑩', '鑪', '鑫',
+    '鑬', '鑭', '鑮', '鑯', '鑰', '鑱', '鑲', '鑳', '鑴', '鑵', '鑶', '鑷', '鑸', '鑹', '鑺', '鑻',
+    '鑼', '鑽', '鑾', '鑿', '钀', '钁', '钂', '钃', '钄', '钅', '钆', '钇', '针', '钉', '钊', '钋',
+    '钌', '钍', '钎', '钏', '钐', '钑', '钒', '钓', '钔', '钕', '钖', '钗', '钘', '钙', '钚', '钛',
+    '钜', '钝', '钞', '钟', '钠', '钡', '钢', '钣', '钤', '钥', '钦', '钧', '钨', '钩', '钪', '钫',
+    '钬', '钭', '钮', '钯', '钰', '钱', '钲', '钳', '钴', '钵', '钶', '钷', '钸', '钹', '钺', '钻',
+    '钼', '钽', '钾', '钿', '铀', '铁', '铂', '铃', '铄', '铅', '铆', '铇', '铈', '铉', '铊', '铋',
+    '铌', '铍', '铎', '铏', '铐', '铑', '铒', '铓', '铔', '铕', '铖', '铗', '铘', '铙', '铚', '铛',
+    '铜', '铝', '铞', '铟', '铠', '铡', '铢', '铣', '铤', '铥', '铦', '铧', '铨', '铩', '铪', '铫',
+    '铬', '铭', '铮', '铯', '铰', '铱', '铲', '铳', '铴', '铵', '银', '铷', '铸', '铹', '铺', '铻',
+    '铼', '铽', '链', '铿', '销', '锁', '锂', '锃', '锄', '锅', '锆', '锇', '锈', '锉', '锊', '锋',
+    '锌', '锍', '锎', '锏', '锐', '锑', '锒', '锓', '锔', '锕', '锖', '锗', '锘', '错', '锚', '锛',
+    '锜', '锝', '锞', '锟', '锠', '锡', '锢', '锣', '锤', '锥', '锦', '锧', '锨', '锩', '锪', '锫',
+    '锬', '锭', '键', '锯', '锰', '锱', '锲', '锳', '锴', '锵', '锶', '锷', '锸', '锹', '锺', '锻',
+    '锼', '锽', '锾', '锿', '镀', '镁', '镂', '镃', '镄', '镅', '镆', '镇', '镈', '镉', '镊', '镋',
+    '镌', '镍', '镎', '镏', '镐', '镑', '镒', '镓', '镔', '镕', '镖', '镗', '镘', '镙', '镚', '镛',
+    '镜', '镝', '镞', '镟', '镠', '镡', '镢', '镣', '镤', '镥', '镦', '镧', '镨', '镩', '镪', '镫',
+    '镬', '镭', '镮', '镯', '镰', '镱', '镲', '镳', '镴', '镵', '镶', '長', '镸', '镹', '镺', '镻',
+    '镼', '镽', '镾', '长', '門', '閁', '閂', '閃', '閄', '閅', '閆', '閇', '閈', '閉', '閊', '開',
+    '閌', '閍', '閎', '閏', '閐', '閑', '閒', '間', '閔', '閕', '閖', '閗', '閘', '閙', '閚', '閛',
+    '閜', '閝', '閞', '閟', '閠', '閡', '関', '閣', '閤', '閥', '閦', '閧', '閨', '閩', '閪', '閫',
+    '閬', '閭', '閮', '閯', '閰', '閱', '閲', '閳', '閴', '閵', '閶', '閷', '閸', '閹', '閺', '閻',
+    '閼', '閽', '閾', '閿', '闀', '闁', '闂', '闃', '闄', '闅', '闆', '闇', '闈', '闉', '闊', '闋',
+    '闌', '闍', '闎', '闏', '闐', '闑', '闒', '闓', '闔', '闕', '闖', '闗', '闘', '闙', '闚', '闛',
+    '關', '闝', '闞', '闟', '闠', '闡', '闢', '闣', '闤', '闥', '闦', '闧', '门', '闩', '闪', '闫',
+    '闬', '闭', '问', '闯', '闰', '闱', '闲', '闳', '间', '闵', '闶', '闷', '闸', '闹', '闺', '闻',
+    '闼', '闽', '闾', '闿', '阀', '阁', '阂', '阃', '阄', '阅', '阆', '阇', '阈', '阉', '阊', '阋',
+    '阌', '阍', '阎', '阏', '阐', '阑', '阒', '阓', '阔', '阕', '阖', '阗', '阘', '阙', '阚', '阛',
+    '阜', '阝', '阞', '队', '阠', '阡', '阢', '阣', '阤', '阥', '阦', '阧', '阨', '阩', '阪', '阫',
+    '阬', '阭', '阮', '阯', '阰', '阱', '防', '阳', '阴', '阵', '阶', '阷', '阸', '阹', '阺', '阻',
+    '阼', '阽', '阾', '阿', '陀', '陁', '陂', '陃', '附', '际', '陆', '陇', '陈', '陉', '陊', '陋',
+    '陌', '降', '陎', '陏', '限', '陑', '陒', '陓', '陔', '陕', '陖', '陗', '陘', '陙', '陚', '陛',
+    '陜', '陝', '陞', '陟', '陠', '陡', '院', '陣', '除', '陥', '陦', '陧', '陨', '险', '陪', '陫',
+    '陬', '陭', '陮', '陯', '陰', '陱', '陲', '陳', '陴', '陵', '陶', '陷', '陸', '陹', '険', '陻',
+    '陼', '陽', '陾', '陿', '隀', '隁', '隂', '隃', '隄', '隅', '隆', '隇', '隈', '隉', '隊', '隋',
+    '隌', '隍', '階', '随', '隐', '隑', '隒', '隓', '隔', '隕', '隖', '隗', '隘', '隙', '隚', '際',
+    '障', '隝', '隞', '隟', '隠', '隡', '隢', '隣', '隤', '隥', '隦', '隧', '隨', '隩', '險', '隫',
+    '隬', '隭', '隮', '隯', '隰', '隱', '隲', '隳', '隴', '隵', '隶', '隷', '隸', '隹', '隺', '隻',
+    '隼', '隽', '难', '隿', '雀', '雁', '雂', '雃', '雄', '雅', '集', '雇', '雈', '雉', '雊', '雋',
+    '雌', '雍', '雎', '雏', '雐', '雑', '雒', '雓', '雔', '雕', '雖', '雗', '雘', '雙', '雚', '雛',
+    '雜', '雝', '雞', '雟', '雠', '雡', '離', '難', '雤', '雥', '雦', '雧', '雨', '雩', '雪', '雫',
+    '雬', '雭', '雮', '雯', '雰', '雱', '雲', '雳', '雴', '雵', '零', '雷', '雸', '雹', '雺', '電',
+    '雼', '雽', '雾', '雿', '需', '霁', '霂', '霃', '霄', '霅', '霆', '震', '霈', '霉', '霊', '霋',
+    '霌', '霍', '霎', '霏', '霐', '霑', '霒', '霓', '霔', '霕', '霖', '霗', '霘', '霙', '霚', '霛',
+    '霜', '霝', '霞', '霟', '霠', '霡', '霢', '霣', '霤', '霥', '霦', '霧', '霨', '霩', '霪', '霫',
+    '霬', '霭', '霮', '霯', '霰', '霱', '露', '霳', '霴', '霵', '霶', '霷', '霸', '霹', '霺', '霻',
+    '霼', '霽', '霾', '霿', '靀', '靁', '靂', '靃', '靄', '靅', '靆', '靇', '靈', '靉', '靊', '靋',
+    '靌', '靍', '靎', '靏', '靐', '靑', '青', '靓', '靔', '靕', '靖', '靗', '靘', '静', '靚', '靛',
+    '靜', '靝', '非', '靟', '靠', '靡', '面', '靣', '靤', '靥', '靦', '靧', '靨', '革', '靪', '靫',
+    '靬', '靭', '靮', '靯', '靰', '靱', '靲', '靳', '靴', '靵', '靶', '靷', '靸', '靹', '靺', '靻',
+    '靼', '靽', '靾', '靿', '鞀', '鞁', '鞂', '鞃', '鞄', '鞅', '鞆', '鞇', '鞈', '鞉', '鞊', '鞋',
+    '鞌', '鞍', '鞎', '鞏', '鞐', '鞑', '鞒', '鞓', '鞔', '鞕', '鞖', '鞗', '鞘', '鞙', '鞚', '鞛',
+    '鞜', '鞝', '鞞', '鞟', '鞠', '鞡', '鞢', '鞣', '鞤', '鞥', '鞦', '鞧', '鞨', '鞩', '鞪', '鞫',
+    '鞬', '鞭', '鞮', '鞯', '鞰', '鞱', '鞲', '鞳', '鞴', '鞵', '鞶', '鞷', '鞸', '鞹', '鞺', '鞻',
+    '鞼', '鞽', '鞾', '鞿', '韀', '韁', '韂', '韃', '韄', '韅', '韆', '韇', '韈', '韉', '韊', '韋',
+    '韌', '韍', '韎', '韏', '韐', '韑', '韒', '韓', '韔', '韕', '韖', '韗', '韘', '韙', '韚', '韛',
+    '韜', '韝', '韞', '韟', '韠', '韡', '韢', '韣', '韤', '韥', '韦', '韧', '韨', '韩', '韪', '韫',
+    '韬', '韭', '韮', '韯', '韰', '韱', '韲', '音', '韴', '韵', '韶', '韷', '韸', '韹', '韺', '韻',
+    '韼', '韽', '韾', '響', '頀', '頁', '頂', '頃', '頄', '項', '順', '頇', '須', '頉', '頊', '頋',
+    '頌', '頍', '頎', '頏', '預', '頑', '頒', '頓', '頔', '頕', '頖', '頗', '領', '頙', '頚', '頛',
+    '頜', '頝', '頞', '頟', '頠', '頡', '頢', '頣', '頤', '頥', '頦', '頧', '頨', '頩', '頪', '頫',
+    '頬', '頭', '頮', '頯', '頰', '頱', '頲', '頳', '頴', '頵', '頶', '頷', '頸', '頹', '頺', '頻',
+    '頼', '頽', '頾', '頿', '顀', '顁', '顂', '顃', '顄', '顅', '顆', '顇', '顈', '顉', '顊', '顋',
+    '題', '額', '顎', '顏', '顐', '顑', '顒', '顓', '顔', '顕', '顖', '顗', '願', '顙', '顚', '顛',
+    '顜', '顝', '類', '顟', '顠', '顡', '顢', '顣', '顤', '顥', '顦', '顧', '顨', '顩', '顪', '顫',
+    '顬', '顭', '顮', '顯', '顰', '顱', '顲', '顳', '顴', '页', '顶', '顷', '顸', '项', '顺', '须',
+    '顼', '顽', '顾', '顿', '颀', '颁', '颂', '颃', '预', '颅', '领', '颇', '颈', '颉', '颊', '颋',
+    '颌', '颍', '颎', '颏', '颐', '频', '颒', '颓', '颔', '颕', '颖', '颗', '题', '颙', '颚', '颛',
+    '颜', '额', '颞', '颟', '颠', '颡', '颢', '颣', '颤', '颥', '颦', '颧', '風', '颩', '颪', '颫',
+    '颬', '颭', '颮', '颯', '颰', '颱', '颲', '颳', '颴', '颵', '颶', '颷', '颸', '颹', '颺', '颻',
+    '颼', '颽', '颾', '颿', '飀', '飁', '飂', '飃', '飄', '飅', '飆', '飇', '飈', '飉', '飊', '飋',
+    '飌', '飍', '风', '飏', '飐', '飑', '飒', '飓', '飔', '飕', '飖', '飗', '飘', '飙', '飚', '飛',
+    '飜', '飝', '飞', '食', '飠', '飡', '飢', '飣', '飤', '飥', '飦', '飧', '飨', '飩', '飪', '飫',
+    '飬', '飭', '飮', '飯', '飰', '飱', '飲', '飳', '飴', '飵', '飶', '飷', '飸', '飹', '飺', '飻',
+    '飼', '飽', '飾', '飿', '餀', '餁', '餂', '餃', '餄', '餅', '餆', '餇', '餈', '餉', '養', '餋',
+    '餌', '餍', '餎', '餏', '餐', '餑', '餒', '餓', '餔', '餕', '餖', '餗', '餘', '餙', '餚', '餛',
+    '餜', '餝', '餞', '餟', '餠', '餡', '餢', '餣', '餤', '餥', '餦', '餧', '館', '餩', '餪', '餫',
+    '餬', '餭', '餮', '餯', '餰', '餱', '餲', '餳', '餴', '餵', '餶', '餷', '餸', '餹', '餺', '餻',
+    '餼', '餽', '餾', '餿', '饀', '饁', '饂', '饃', '饄', '饅', '饆', '饇', '饈', '饉', '饊', '饋',
+    '饌', '饍', '饎', '饏', '饐', '饑', '饒', '饓', '饔', '饕', '饖', '饗', '饘', '饙', '饚', '饛',
+    '饜', '饝', '饞', '饟', '饠', '饡', '饢', '饣', '饤', '饥', '饦', '饧', '饨', '饩', '饪', '饫',
+    '饬', '饭', '饮', '饯', '饰', '饱', '饲', '饳', '饴', '饵', '饶', '饷', '饸', '饹', '饺', '饻',
+    '饼', '饽', '饾', '饿', '馀', '馁', '馂', '馃', '馄', '馅', '馆', '馇', '馈', '馉', '馊', '馋',
+    '馌', '馍', '馎', '馏', '馐', '馑', '馒', '馓', '馔', '馕', '首', '馗', '馘', '香', '馚', '馛',
+    '馜', '馝', '馞', '馟', '馠', '馡', '馢', '馣', '馤', '馥', '馦', '馧', '馨', '馩', '馪', '馫',
+    '馬', '馭', '馮', '馯', '馰', '馱', '馲', '馳', '馴', '馵', '馶', '馷', '馸', '馹', '馺', '馻',
+    '馼', '馽', '馾', '馿', '駀', '駁', '駂', '駃', '駄', '駅', '駆', '駇', '駈', '駉', '駊', '駋',
+    '駌', '駍', '駎', '駏', '駐', '駑', '駒', '駓', '駔', '駕', '駖', '駗', '駘', '駙', '駚', '駛',
+    '駜', '駝', '駞', '駟', '駠', '駡', '駢', '駣', '駤', '駥', '駦', '駧', '駨', '駩', '駪', '駫',
+    '駬', '駭', '駮', '駯', '駰', '駱', '駲', '駳', '駴', '駵', '駶', '駷', '駸', '駹', '駺', '駻',
+    '駼', '駽', '駾', '駿', '騀', '騁', '騂', '騃', '騄', '騅', '騆', '騇', '騈', '騉', '騊', '騋',
+    '騌', '騍', '騎', '騏', '騐', '騑', '騒', '験', '騔', '騕', '騖', '騗', '騘', '騙', '騚', '騛',
+    '騜', '騝', '騞', '騟', '騠', '騡', '騢', '騣', '騤', '騥', '騦', '騧', '騨', '騩', '騪', '騫',
+    '騬', '騭', '騮', '騯', '騰', '騱', '騲', '騳', '騴', '騵', '騶', '騷', '騸', '騹', '騺', '騻',
+    '騼', '騽', '騾', '騿', '驀', '驁', '驂', '驃', '驄', '驅', '驆', '驇', '驈', '驉', '驊', '驋',
+    '驌', '驍', '驎', '驏', '驐', '驑', '驒', '驓', '驔', '驕', '驖', '驗', '驘', '驙', '驚', '驛',
+    '驜', '驝', '驞', '驟', '驠', '驡', '驢', '驣', '驤', '驥', '驦', '驧', '驨', '驩', '驪', '驫',
+    '马', '驭', '驮', '驯', '驰', '驱', '驲', '驳', '驴', '驵', '驶', '驷', '驸', '驹', '驺', '驻',
+    '驼', '驽', '驾', '驿', '骀', '骁', '骂', '骃', '骄', '骅', '骆', '骇', '骈', '骉', '骊', '骋',
+    '验', '骍', '骎', '骏', '骐', '骑', '骒', '骓', '骔', '骕', '骖', '骗', '骘', '骙', '骚', '骛',
+    '骜', '骝', '骞', '骟', '骠', '骡', '骢', '骣', '骤', '骥', '骦', '骧', '骨', '骩', '骪', '骫',
+    '骬', '骭', '骮', '骯', '骰', '骱', '骲', '骳', '骴', '骵', '骶', '骷', '骸', '骹', '骺', '骻',
+    '骼', '骽', '骾', '骿', '髀', '髁', '髂', '髃', '髄', '髅', '髆', '髇', '髈', '髉', '髊', '髋',
+    '髌', '髍', '髎', '髏', '髐', '髑', '髒', '髓', '體', '髕', '髖', '髗', '高', '髙', '髚', '髛',
+    '髜', '髝', '髞', '髟', '髠', '髡', '髢', '髣', '髤', '髥', '髦', '髧', '髨', '髩', '髪', '髫',
+    '髬', '髭', '髮', '髯', '髰', '髱', '髲', '髳', '髴', '髵', '髶', '髷', '髸', '髹', '髺', '髻',
+    '髼', '髽', '髾', '髿', '鬀', '鬁', '鬂', '鬃', '鬄', '鬅', '鬆', '鬇', '鬈', '鬉', '鬊', '鬋',
+    '鬌', '鬍', '鬎', '鬏', '鬐', '鬑', '鬒', '鬓', '鬔', '鬕', '鬖', '鬗', '鬘', '鬙', '鬚', '鬛',
+    '鬜', '鬝', '鬞', '鬟', '鬠', '鬡', '鬢', '鬣', '鬤', '鬥', '鬦', '鬧', '鬨', '鬩', '鬪', '鬫',
+    '鬬', '鬭', '鬮', '鬯', '鬰', '鬱', '鬲', '鬳', '鬴', '鬵', '鬶', '鬷', '鬸', '鬹', '鬺', '鬻',
+    '鬼', '鬽', '鬾', '鬿', '魀', '魁', '魂', '魃', '魄', '魅', '魆', '魇', '魈', '魉', '魊', '魋',
+    '魌', '魍', '魎', '魏', '魐', '魑', '魒', '魓', '魔', '魕', '魖', '魗', '魘', '魙', '魚', '魛',
+    '魜', '魝', '魞', '魟', '魠', '魡', '魢', '魣', '魤', '魥', '魦', '魧', '魨', '魩', '魪', '魫',
+    '魬', '魭', '魮', '魯', '魰', '魱', '魲', '魳', '魴', '魵', '魶', '魷', '魸', '魹', '魺', '魻',
+    '魼', '魽', '魾', '魿', '鮀', '鮁', '鮂', '鮃', '鮄', '鮅', '鮆', '鮇', '鮈', '鮉', '鮊', '鮋',
+    '鮌', '鮍', '鮎', '鮏', '鮐', '鮑', '鮒', '鮓', '鮔', '鮕', '鮖', '鮗', '鮘', '鮙', '鮚', '鮛',
+    '鮜', '鮝', '鮞', '鮟', '鮠', '鮡', '鮢', '鮣', '鮤', '鮥', '鮦', '鮧', '鮨', '鮩', '鮪', '鮫',
+    '鮬', '鮭', '鮮', '鮯', '鮰', '鮱', '鮲', '鮳', '鮴', '鮵', '鮶', '鮷', '鮸', '鮹', '鮺', '鮻',
+    '鮼', '鮽', '鮾', '鮿', '鯀', '鯁', '鯂', '鯃', '鯄', '鯅', '鯆', '鯇', '鯈', '鯉', '鯊', '鯋',
+    '鯌', '鯍', '鯎', '鯏', '鯐', '鯑', '鯒', '鯓', '鯔', '鯕', '鯖', '鯗', '鯘', '鯙', '鯚', '鯛',
+    '鯜', '鯝', '鯞', '鯟', '鯠', '鯡', '鯢', '鯣', '鯤', '鯥', '鯦', '鯧', '鯨', '鯩', '鯪', '鯫',
+    '鯬', '鯭', '鯮', '鯯', '鯰', '鯱', '鯲', '鯳', '鯴', '鯵', '鯶', '鯷', '鯸', '鯹', '鯺', '鯻',
+    '鯼', '鯽', '鯾', '鯿', '鰀', '鰁', '鰂', '鰃', '鰄', '鰅', '鰆', '鰇', '鰈', '鰉', '鰊', '鰋',
+    '鰌', '鰍', '鰎', '鰏', '鰐', '鰑', '鰒', '鰓', '鰔', '鰕', '鰖', '鰗', '鰘', '鰙', '鰚', '鰛',
+    '鰜', '鰝', '鰞', '鰟', '鰠', '鰡', '鰢', '鰣', '鰤', '鰥', '鰦', '鰧', '鰨', '鰩', '鰪', '鰫',
+    '鰬', '鰭', '鰮', '鰯', '鰰', '鰱', '鰲', '鰳', '鰴', '鰵', '鰶', '鰷', '鰸', '鰹', '鰺', '鰻',
+    '鰼', '鰽', '鰾', '鰿', '鱀', '鱁', '鱂', '鱃', '鱄', '鱅', '鱆', '鱇', '鱈', '鱉', '鱊', '鱋',
+    '鱌', '鱍', '鱎', '鱏', '鱐', '鱑', '鱒', '鱓', '鱔', '鱕', '鱖', '鱗', '鱘', '鱙', '鱚', '鱛',
+    '鱜', '鱝', '鱞', '鱟', '鱠', '鱡', '鱢', '鱣', '鱤', '鱥', '鱦', '鱧', '鱨', '鱩', '鱪', '鱫',
+    '鱬', '鱭', '鱮', '鱯', '鱰', '鱱', '鱲', '鱳', '鱴', '鱵', '鱶', '鱷', '鱸', '鱹', '鱺', '鱻',
+    '鱼', '鱽', '鱾', '鱿', '鲀', '鲁', '鲂', '鲃', '鲄', '鲅', '鲆', '鲇', '鲈', '鲉', '鲊', '鲋',
+    '鲌', '鲍', '鲎', '鲏', '鲐', '鲑', '鲒', '鲓', '鲔', '鲕', '鲖', '鲗', '鲘', '鲙', '鲚', '鲛',
+    '鲜', '鲝', '鲞', '鲟', '鲠', '鲡', '鲢', '鲣', '鲤', '鲥', '鲦', '鲧', '鲨', '鲩', '鲪', '鲫',
+    '鲬', '鲭', '鲮', '鲯', '鲰', '鲱', '鲲', '鲳', '鲴', '鲵', '鲶', '鲷', '鲸', '鲹', '鲺', '鲻',
+    '鲼', '鲽', '鲾', '鲿', '鳀', '鳁', '鳂', '鳃', '鳄', '鳅', '鳆', '鳇', '鳈', '鳉', '鳊', '鳋',
+    '鳌', '鳍', '鳎', '鳏', '鳐', '鳑', '鳒', '鳓', '鳔', '鳕', '鳖', '鳗', '鳘', '鳙', '鳚', '鳛',
+    '鳜', '鳝', '鳞', '鳟', '鳠', '鳡', '鳢', '鳣', '鳤', '鳥', '鳦', '鳧', '鳨', '鳩', '鳪', '鳫',
+    '鳬', '鳭', '鳮', '鳯', '鳰', '鳱', '鳲', '鳳', '鳴', '鳵', '鳶', '鳷', '鳸', '鳹', '鳺', '鳻',
+    '鳼', '鳽', '鳾', '鳿', '鴀', '鴁', '鴂', '鴃', '鴄', '鴅', '鴆', '鴇', '鴈', '鴉', '鴊', '鴋',
+    '鴌', '鴍', '鴎', '鴏', '鴐', '鴑', '鴒', '鴓', '鴔', '鴕', '鴖', '鴗', '鴘', '鴙', '鴚', '鴛',
+    '鴜', '鴝', '鴞', '鴟', '鴠', '鴡', '鴢', '鴣', '鴤', '鴥', '鴦', '鴧', '鴨', '鴩', '鴪', '鴫',
+    '鴬', '鴭', '鴮', '鴯', '鴰', '鴱', '鴲', '鴳', '鴴', '鴵', '鴶', '鴷', '鴸', '鴹', '鴺', '鴻',
+    '鴼', '鴽', '鴾', '鴿', '鵀', '鵁', '鵂', '鵃', '鵄', '鵅', '鵆', '鵇', '鵈', '鵉', '鵊', '鵋',
+    '鵌', '鵍', '鵎', '鵏', '鵐', '鵑', '鵒', '鵓', '鵔', '鵕', '鵖', '鵗', '鵘', '鵙', '鵚', '鵛',
+    '鵜', '鵝', '鵞', '鵟', '鵠', '鵡', '鵢', '鵣', '鵤', '鵥', '鵦', '鵧', '鵨', '鵩', '鵪', '鵫',
+    '鵬', '鵭', '鵮', '鵯', '鵰', '鵱', '鵲', '鵳', '鵴', '鵵', '鵶', '鵷', '鵸', '鵹', '鵺', '鵻',
+    '鵼', '鵽', '鵾', '鵿', '鶀', '鶁', '鶂', '鶃', '鶄', '鶅', '鶆', '鶇', '鶈', '鶉', '鶊', '鶋',
+    '鶌', '鶍', '鶎', '鶏', '鶐', '鶑', '鶒', '鶓', '鶔', '鶕', '鶖', '鶗', '鶘', '鶙', '鶚', '鶛',
+    '鶜', '鶝', '鶞', '鶟', '鶠', '鶡', '鶢', '鶣', '鶤', '鶥', '鶦', '鶧', '鶨', '鶩', '鶪', '鶫',
+    '鶬', '鶭', '鶮', '鶯', '鶰', '鶱', '鶲', '鶳', '鶴', '鶵', '鶶', '鶷', '鶸', '鶹', '鶺', '鶻',
+    '鶼', '鶽', '鶾', '鶿', '鷀', '鷁', '鷂', '鷃', '鷄', '鷅', '鷆', '鷇', '鷈', '鷉', '鷊', '鷋',
+    '鷌', '鷍', '鷎', '鷏', '鷐', '鷑', '鷒', '鷓', '鷔', '鷕', '鷖', '鷗', '鷘', '鷙', '鷚', '鷛',
+    '鷜', '鷝', '鷞', '鷟', '鷠', '鷡', '鷢', '鷣', '鷤', '鷥', '鷦', '鷧', '鷨', '鷩', '鷪', '鷫',
+    '鷬', '鷭', '鷮', '鷯', '鷰', '鷱', '鷲', '鷳', '鷴', '鷵', '鷶', '鷷', '鷸', '鷹', '鷺', '鷻',
+    '鷼', '鷽', '鷾', '鷿', '鸀', '鸁', '鸂', '鸃', '鸄', '鸅', '鸆', '鸇', '鸈', '鸉', '鸊', '鸋',
+    '鸌', '鸍', '鸎', '鸏', '鸐', '鸑', '鸒', '鸓', '鸔', '鸕', '鸖', '鸗', '鸘', '鸙', '鸚', '鸛',
+    '鸜', '鸝', '鸞', '鸟', '鸠', '鸡', '鸢', '鸣', '鸤', '鸥', '鸦', '鸧', '鸨', '鸩', '鸪', '鸫',
+    '鸬', '鸭', '鸮', '鸯', '鸰', '鸱', '鸲', '鸳', '鸴', '鸵', '鸶', '鸷', '鸸', '鸹', '鸺', '鸻',
+    '鸼', '鸽', '鸾', '鸿', '鹀', '鹁', '鹂', '鹃', '鹄', '鹅', '鹆', '鹇', '鹈', '鹉', '鹊', '鹋',
+    '鹌', '鹍', '鹎', '鹏', '鹐', '鹑', '鹒', '鹓', '鹔', '鹕', '鹖', '鹗', '鹘', '鹙', '鹚', '鹛',
+    '鹜', '鹝', '鹞', '鹟', '鹠', '鹡', '鹢', '鹣', '鹤', '鹥', '鹦', '鹧', '鹨', '鹩', '鹪', '鹫',
+    '鹬', '鹭', '鹮', '鹯', '鹰', '鹱', '鹲', '鹳', '鹴', '鹵', '鹶', '鹷', '鹸', '鹹', '鹺', '鹻',
+    '鹼', '鹽', '鹾', '鹿', '麀', '麁', '麂', '麃', '麄', '麅', '麆', '麇', '麈', '麉', '麊', '麋',
+    '麌', '麍', '麎', '麏', '麐', '麑', '麒', '麓', '麔', '麕', '麖', '麗', '麘', '麙', '麚', '麛',
+    '麜', '麝', '麞', '麟', '麠', '麡', '麢', '麣', '麤', '麥', '麦', '麧', '麨', '麩', '麪', '麫',
+    '麬', '麭', '麮', '麯', '麰', '麱', '麲', '麳', '麴', '麵', '麶', '麷', '麸', '麹', '麺', '麻',
+    '麼', '麽', '麾', '麿', '黀', '黁', '黂', '黃', '黄', '黅', '黆', '黇', '黈', '黉', '黊', '黋',
+    '黌', '黍', '黎', '黏', '黐', '黑', '黒', '黓', '黔', '黕', '黖', '黗', '默', '黙', '黚', '黛',
+    '黜', '黝', '點', '黟', '黠', '黡', '黢', '黣', '黤', '黥', '黦', '黧', '黨', '黩', '黪', '黫',
+    '黬', '黭', '黮', '黯', '黰', '黱', '黲', '黳', '黴', '黵', '黶', '黷', '黸', '黹', '黺', '黻',
+    '黼', '黽', '黾', '黿', '鼀', '鼁', '鼂', '鼃', '鼄', '鼅', '鼆', '鼇', '鼈', '鼉', '鼊', '鼋',
+    '鼌', '鼍', '鼎', '鼏', '鼐', '鼑', '鼒', '鼓', '鼔', '鼕', '鼖', '鼗', '鼘', '鼙', '鼚', '鼛',
+    '鼜', '鼝', '鼞', '鼟', '鼠', '鼡', '鼢', '鼣', '鼤', '鼥', '鼦', '鼧', '鼨', '鼩', '鼪', '鼫',
+    '鼬', '鼭', '鼮', '鼯', '鼰', '鼱', '鼲', '鼳', '鼴', '鼵', '鼶', '鼷', '鼸', '鼹', '鼺', '鼻',
+    '鼼', '鼽', '鼾', '鼿', '齀', '齁', '齂', '齃', '齄', '齅', '齆', '齇', '齈', '齉', '齊', '齋',
+    '齌', '齍', '齎', '齏', '齐', '齑', '齒', '齓', '齔', '齕', '齖', '齗', '齘', '齙', '齚', '齛',
+    '齜', '齝', '齞', '齟', '齠', '齡', '齢', '齣', '齤', '齥', '齦', '齧', '齨', '齩', '齪', '齫',
+    '齬', '齭', '齮', '齯', '齰', '齱', '齲', '齳', '齴', '齵', '齶', '齷', '齸', '齹', '齺', '齻',
+    '齼', '齽', '齾', '齿', '龀', '龁', '龂', '龃', '龄', '龅', '龆', '龇', '龈', '龉', '龊', '龋',
+    '龌', '龍', '龎', '龏', '龐', '龑', '龒', '龓', '龔', '龕', '龖', '龗', '龘', '龙', '龚', '龛',
+    '龜', '龝', '龞', '龟', '龠', '龡', '龢', '龣', '龤', '龥', '鿃', '鿍', '鿎', '鿏', '鿔', '鿕',
+    '鿫', '鿬', '鿭', '\u{e815}', '\u{e816}', '\u{e818}', '\u{e81a}', '\u{e81b}', '\u{e81d}', '\u{e81f}', '\u{e820}', '\u{e821}', '\u{e824}', '\u{e825}', '\u{e826}', '\u{e827}',
+    '\u{e828}', '\u{e829}', '\u{e82a}', '\u{e82b}', '\u{e82c}', '\u{e82d}', '\u{e82e}', '\u{e82f}', '\u{e830}', '\u{e831}', '\u{e832}', '\u{e834}', '\u{e835}', '\u{e836}', '\u{e837}', '\u{e838}',
+    '\u{e83a}', '\u{e83b}', '\u{e83c}', '\u{e83d}', '\u{e83f}', '\u{e840}', '\u{e841}', '\u{e842}', '\u{e843}', '\u{e844}', '\u{e845}', '\u{e846}', '\u{e847}', '\u{e849}', '\u{e84a}', '\u{e84b}',
+    '\u{e84c}', '\u{e84d}', '\u{e84e}', '\u{e84f}', '\u{e850}', '\u{e851}', '\u{e852}', '\u{e853}', '\u{e854}', '\u{e855}', '\u{e856}', '\u{e857}', '\u{e858}', '\u{e859}', '\u{e85a}', '\u{e85b}',
+    '\u{e85c}', '\u{e85d}', '\u{e85e}', '\u{e85f}', '\u{e860}', '\u{e861}', '\u{e862}', '\u{e863}', '\u{e864}', '礼', '𠀀', '𠀁', '𠀃', '𠀅', '𠀉', '𠀊',
+    '𠀍', '𠀓', '𠀔', '𠀛', '𠀝', '𠀢', '𠀦', '𠀱', '𠀷', '𠀸', '𠀹', '𠀼', '𠀽', '𠁁', '𠁉', '𠁗',
+    '𠁠', '𠁥', '𠁭', '𠁷', '𠂄', '𠂆', '𠂇', '𠂈', '𠂑', '𠂔', '𠂝', '𠂟', '𠂢', '𠂤', '𠂥', '𠂧',
+    '𠂸', '𠃊', '𠃌', '𠃖', '𠃫', '𠃺', '𠄅', '𠄉', '𠄌', '𠄍', '𠄏', '𠄑', '𠄒', '𠄖', '𠄚', '𠄣',
+    '𠄮', '𠄱', '𠄿', '𠅂', '𠅌', '𠅗', '𠅚', '𠅤', '𠅬', '𠅱', '𠅹', '𠆌', '𠆐', '𠆙', '𠆛', '𠆟',
+    '𠆣', '𠆩', '𠆮', '𠆱', '𠆲', '𠆵', '𠆶', '𠇋', '𠇏', '𠇒', '𠇗', '𠇘', '𠇯', '𠇱', '𠇳', '𠇵',
+    '𠇷', '𠈁', '𠈅', '𠈊', '𠈤', '𠈥', '𠈬', '𠈭', '𠈰', '𠈱', '𠈵', '𠈶', '𠈸', '𠈺', '𠉂', '𠉗',
+    '𠉢', '𠉣', '𠉤', '𠉧', '𠉩', '𠉬', '𠊉', '𠊌', '𠊎', '𠊏', '𠊒', '𠊔', '𠊟', '𠊣', '𠊤', '𠊥',
+    '𠊦', '𠊧', '𠊩', '𠊪', '𠊫', '𠊬', '𠊭', '𠊰', '𠊲', '𠊶', '𠊷', '𠊾', '𠋆', '𠋖', '𠋗', '𠋜',
+    '𠋝', '𠋞', '𠋟', '𠋡', '𠋢', '𠋤', '𠋧', '𠋩', '𠋬', '𠋭', '𠋯', '𠋲', '𠋵', '𠋶', '𠋷', '𠋺',
+    '𠋻', '𠌕', '𠌖', '𠌚', '𠌞', '𠌟', '𠌠', '𠌪', '𠌭', '𠌮', '𠌯', '𠌰', '𠌱', '𠌲', '𠌴', '𠌽',
+    '𠍃', '𠍄', '𠍗', '𠍚', '𠍛', '𠍜', '𠍨', '𠍩', '𠍪', '𠍫', '𠍯', '𠍲', '𠍳', '𠍵', '𠍹', '𠍽',
+    '𠎙', '𠎮', '𠎰', '𠎳', '𠎶', '𠎷', '𠎸', '𠎻', '𠎿', '𠏀', '𠏃', '𠏇', '𠏈', '𠏓', '𠏕', '𠏖',
+    '𠏚', '𠏛', '𠏡', '𠏢', '𠏤', '𠏧', '𠏨', '𠏩', '𠏫', '𠏭', '𠏮', '𠏰', '𠏿', '𠐀', '𠐁', '𠐈',
+    '𠐉', '𠐊', '𠐋', '𠐌', '𠐍', '𠐑', '𠐡', '𠐢', '𠐧', '𠐩', '𠐵', '𠐺', '𠐻', '𠐼', '𠐽', '𠐾',
+    '𠐿', '𠑃', '𠑄', '𠑅', '𠑆', '𠑐', '𠑑', '𠑘', '𠑙', '𠑚', '𠑛', '𠑞', '𠑟', '𠑡', '𠑪', '𠑯',
+    '𠑰', '𠑱', '𠑲', '𠑴', '𠑹', '𠒄', '𠒜', '𠒝', '𠒢', '𠒰', '𠒵', '𠒸', '𠒾', '𠒿', '𠓊', '𠓋',
+    '𠓌', '𠓖', '𠓗', '𠓙', '𠓜', '𠓝', '𠓞', '𠓢', '𠓣', '𠓤', '𠓪', '𠓫', '𠓭', '𠓮', '𠓶', '𠓷',
+    '𠓸', '𠓽', '𠓿', '𠔂', '𠔃', '𠔉', '𠔋', '𠔍', '𠔎', '𠔑', '𠔕', '𠔠', '𠔨', '𠔯', '𠔲', '𠔶',
+    '𠔺', '𠔻', '𠔼', '𠕁', '𠕄', '𠕊', '𠕌', '𠕕', '𠕖', '𠕟', '𠕠', '𠕣', '𠕦', '𠕧', '𠕭', '𠕳',
+    '𠕴', '𠕸', '𠕻', '𠕿', '𠖂', '𠖄', '𠖆', '𠖋', '𠖓', '𠖛', '𠖟', '𠖥', '𠖫', '𠖬', '𠖶', '𠖷',
+    '𠖹', '𠗂', '𠗆', '𠗈', '𠗉', '𠗊', '𠗋', '𠗌', '𠗏', '𠗘', '𠗚', '𠗛', '𠗝', '𠗥', '𠗦', '𠗨',
+    '𠗩', '𠗳', '𠗴', '𠗵', '𠗶', '𠗺', '𠗻', '𠗼', '𠗽', '𠗾', '𠗿', '𠘁', '𠘂', '𠘅', '𠘆', '𠘊',
+    '𠘋', '𠘌', '𠘕', '𠘖', '𠘝', '𠘞', '𠘟', '𠘢', '𠘣', '𠘥', '𠘧', '𠘪', '𠘱', '𠘳', '𠘺', '𠘻',
+    '𠙁', '𠙂', '𠙆', '𠙎', '𠙔', '𠙕', '𠙞', '𠙤', '𠙬', '𠙶', '𠙼', '𠚃', '𠚄', '𠚅', '𠚆', '𠚉',
+    '𠚊', '𠚓', '𠚔', '𠚜', '𠚠', '𠚥', '𠚧', '𠚨', '𠚭', '𠚮', '𠚯', '𠚱', '𠚳', '𠚴', '𠚵', '𠚹',
+    '𠚺', '𠚻', '𠚼', '𠚽', '𠛀', '𠛃', '𠛅', '𠛆', '𠛊', '𠛋', '𠛎', '𠛑', '𠛒', '𠛓', '𠛔', '𠛕',
+    '𠛖', '𠛘', '𠛙', '𠛚', '𠛡', '𠛥', '𠛦', '𠛩', '𠛪', '𠛫', '𠛭', '𠛮', '𠛱', '𠛳', '𠛵', '𠛶',
+    '𠛸', '𠜁', '𠜃', '𠜄', '𠜅', '𠜈', '𠜉', '𠜋', '𠜎', '𠜐', '𠜑', '𠜖', '𠜗', '𠜘', '𠜙', '𠜤',
+    '𠜱', '𠜲', '𠜳', '𠜴', '𠜵', '𠜹', '𠜼', '𠜾', '𠝄', '𠝐', '𠝑', '𠝘', '𠝚', '𠝛', '𠝝', '𠝞',
+    '𠝟', '𠝢', '𠝤', '𠝨', '𠝪', '𠝬', '𠝲', '𠝳', '𠝽', '𠝿', '𠞀', '𠞃', '𠞄', '𠞆', '𠞈', '𠞉',
+    '𠞊', '𠞕', '𠞖', '𠞗', '𠞙', '𠞞', '𠞤', '𠞧', '𠞩', '𠞬', '𠞭', '𠞮', '𠞯', '𠞰', '𠞱', '𠞵',
+    '𠞶', '𠞾', '𠞿', '𠟂', '𠟃', '𠟅', '𠟈', '𠟉', '𠟊', '𠟋', '𠟌', '𠟍', '𠟓', '𠟗', '𠟣', '𠟦',
+    '𠟧', '𠟨', '𠟩', '𠟪', '𠟰', '𠟶', '𠟺', '𠟻', '𠟼', '𠠁', '𠠃', '𠠄', '𠠋', '𠠎', '𠠏', '𠠐',
+    '𠠔', '𠠗', '𠠜', '𠠝', '𠠠', '𠠢', '𠠪', '𠠯', '𠠳', '𠠵', '𠠶', '𠠷', '𠠹', '𠡀', '𠡂', '𠡄',
+    '𠡊', '𠡑', '𠡒', '𠡔', '𠡜', '𠡝', '𠡞', '𠡡', '𠡣', '𠡤', '𠡥', '𠡭', '𠡮', '𠡱', '𠡶', '𠡷',
+    '𠡸', '𠡻', '𠢃', '𠢆', '𠢇', '𠢌', '𠢍', '𠢓', '𠢔', '𠢕', '𠢙', '𠢚', '𠢛', '𠢠', '𠢡', '𠢢',
+    '𠢣', '𠢤', '𠢥', '𠢩', '𠢪', '𠢱', '𠢲', '𠢳', '𠢴', '𠢵', '𠢹', '𠣄', '𠣇', '𠣉', '𠣊', '𠣐',
+    '𠣑', '𠣒', '𠣕', '𠣖', '𠣘', '𠣠', '𠣡', '𠣪', '𠣫', '𠣬', '𠣰', '𠣲', '𠣳', '𠣺', '𠣾', '𠣿',
+    '𠤀', '𠤄', '𠤊', '𠤍', '𠤎', '𠤏', '𠤕', '𠤗', '𠤘', '𠤝', '𠤦', '𠤫', '𠤮', '𠤰', '𠤴', '𠤹',
+    '𠤺', '𠤼', '𠤾', '𠥇', '𠥍', '𠥎', '𠥐', '𠥑', '𠥕', '𠥖', '𠥘', '𠥙', '𠥜', '𠥝', '𠥞', '𠥢',
+    '𠥦', '𠥨', '𠥰', '𠥴', '𠥿', '𠦃', '𠦈', '𠦊', '𠦋', '𠦌', '𠦎', '𠦏', '𠦐', '𠦒', '𠦗', '𠦜',
+    '𠦧', '𠦨', '𠦪', '𠦫', '𠦬', '𠦲', '𠧀', '𠧅', '𠧇', '𠧒', '𠧕', '𠧖', '𠧙', '𠧚', '𠧛', '𠧟',
+    '𠧠', '𠧤', '𠧩', '𠧪', '𠧫', '𠧰', '𠧴', '𠧵', '𠧿', '𠨃', '𠨆', '𠨊', '𠨌', '𠨍', '𠨎', '𠨕',
+    '𠨘', '𠨚', '𠨠', '𠨢', '𠨥', '𠨦', '𠨭', '𠨲', '𠨵', '𠨸', '𠨻', '𠨾', '𠨿', '𠩂', '𠩄', '𠩆',
+    '𠩈', '𠩉', '𠩏', '𠩔', '𠩗', '𠩘', '𠩠', '𠩥', '𠩧', '𠩪', '𠩫', '𠩵', '𠩷', '𠩺', '𠩿', '𠪂',
+    '𠪃', '𠪄', '𠪇', '𠪐', '𠪑', '𠪒', '𠪗', '𠪙', '𠪚', '𠪟', '𠪣', '𠪧', '𠪮', '𠪶', '𠪷', '𠪺',
+    '𠪻', '𠫃', '𠫄', '𠫉', '𠫌', '𠫓', '𠫘', '𠫛', '𠫜', '𠫝', '𠫣', '𠫨', '𠫭', '𠫮', '𠫶', '𠫷',
+    '𠫸', '𠫹', '𠫺', '𠬆', '𠬈', '𠬋', '𠬓', '𠬙', '𠬛', '𠬝', '𠬢', '𠬣', '𠬪', '𠬬', '𠬮', '𠬶',
+    '𠬾', '𠭈', '𠭉', '𠭋', '𠭗', '𠭥', '𠭯', '𠭰', '𠭴', '𠭹', '𠭿', '𠮃', '𠮆', '𠮊', '𠮌', '𠮑',
+    '𠮙', '𠮜', '𠮟', '𠮠', '𠮡', '𠮨', '𠮫', '𠮭', '𠮯', '𠮰', '𠮳', '𠮴', '𠮵', '𠮽', '𠮿', '𠯀',
+    '𠯂', '𠯃', '𠯄', '𠯈', '𠯉', '𠯋', '𠯍', '𠯐', '𠯑', '𠯓', '𠯔', '𠯗', '𠯘', '𠯙', '𠯜', '𠯟',
+    '𠯠', '𠯩', '𠯪', '𠯫', '𠯭', '𠯯', '𠯰', '𠯱', '𠯲', '𠯳', '𠯴', '𠯷', '𠯹', '𠰄', '𠰅', '𠰇',
+    '𠰈', '𠰉', '𠰋', '𠰍', '𠰏', '𠰐', '𠰑', '𠰓', '𠰕', '𠰖', '𠰚', '𠰛', '𠰜', '𠰴', '𠰹', '𠰺',
+    '𠰻', '𠰼', '𠰽', '𠰾', '𠱀', '𠱐', '𠱑', '𠱓', '𠱔', '𠱗', '𠱘', '𠱙', '𠱚', '𠱛', '𠱜', '𠱝',
+    '𠱞', '𠱠', '𠱡', '𠱤', '𠱥', '𠱧', '𠱨', '𠱫', '𠱯', '𠱲', '𠱳', '𠲊', '𠲋', '𠲌', '𠲍', '𠲎',
+    '𠲏', '𠲑', '𠲒', '𠲓', '𠲔', '𠲖', '𠲙', '𠲚', '𠲛', '𠲜', '𠲮', '𠲱', '𠲴', '𠲵', '𠲷', '𠲹',
+    '𠲺', '𠲻', '𠲽', '𠲾', '𠲿', '𠳀', '𠳁', '𠳂', '𠳃', '𠳇', '𠳊', '𠳋', '𠳌', '𠳎', '𠳐', '𠳬',
+    '𠳭', '𠳳', '𠳴', '𠳶', '𠳹', '𠳼', '𠳽', '𠳿', '𠴂', '𠴄', '𠴇', '𠴡', '𠴢', '𠴣', '𠴦', '𠴨',
+    '𠴩', '𠴫', '𠴭', '𠴯', '𠴰', '𠴱', '𠴲', '𠴳', '𠴵', '𠴸', '𠴹', '𠴺', '𠴻', '𠴼', '𠴾', '𠵠',
+    '𠵣', '𠵦', '𠵧', '𠵨', '𠵩', '𠵫', '𠵬', '𠵭', '𠵮', '𠵯', '𠵳', '𠵷', '𠵸', '𠵹', '𠵺', '𠵻',
+    '𠵼', '𠵾', '𠶧', '𠶨', '𠶫', '𠶬', '𠶰', '𠶱', '𠶲', '𠶷', '𠶸', '𠶹', '𠶻', '𠶾', '𠶿', '𠷀',
+    '𠷁', '𠷂', '𠷃', '𠷄', '𠷅', '𠷇', '𠷈', '𠷉', '𠷊', '𠷋', '𠷌', '𠷍', '𠷎', '𠷏', '𠷐', '𠷑',
+    '𠷒', '𠷓', '𠷖', '𠷙', '𠷞', '𠷟', '𠷢', '𠷸', '𠷺', '𠷻', '𠷿', '𠸁', '𠸂', '𠸄', '𠸮', '𠸱',
+    '𠸲', '𠸳', '𠸴', '𠸸', '𠸹', '𠸺', '𠹀', '𠹁', '𠹃', '𠹄', '𠹅', '𠹆', '𠹈', '𠹊', '𠹋', '𠹌',
+    '𠹍', '𠹎', '𠹐', '𠹑', '𠹓', '𠹔', '𠹕', '𠹖', '𠹘', '𠹛', '𠹝', '𠹟', '𠹠', '𠹤', '𠹥', '𠹦',
+    '𠺐', '𠺒', '𠺔', '𠺕', '𠺖', '𠺗', '𠺘', '𠺙', '𠺚', '𠺝', '𠺟', '𠺢', '𠺣', '𠻍', '𠻏', '𠻐',
+    '𠻗', '𠻙', '𠻚', '𠻜', '𠻝', '𠻞', '𠻟', '𠻠', '𠻡', '𠻤', '𠻥', '𠻦', '𠻧', '𠻨', '𠻪', '𠻫',
+    '𠻬', '𠻱', '𠻴', '𠼖', '𠼗', '𠼘', '𠼝', '𠼞', '𠼟', '𠼠', '𠼡', '𠼢', '𠼤', '𠼩', '𠼪', '𠼬',
+    '𠼻', '𠽜', '𠽝', '𠽡', '𠽣', '𠽦', '𠽨', '𠽩', '𠽪', '𠽫', '𠽬', '𠽰', '𠽱', '𠽲', '𠽵', '𠽶',
+    '𠽷', '𠽹', '𠽻', '𠽼', '𠽾', '𠽿', '𠾀', '𠾆', '𠾇', '𠾉', '𠾋', '𠾌', '𠾏', '𠾐', '𠾢', '𠾤',
+    '𠾥', '𠾨', '𠾩', '𠾫', '𠾬', '𠾮', '𠾲', '𠿆', '𠿇', '𠿈', '𠿉', '𠿋', '𠿏', '𠿑', '𠿓', '𠿔',
+    '𠿕', '𠿗', '𠿘', '𠿛', '𠿜', '𠿝', '𠿞', '𠿟', '𠿠', '𠿡', '𠿢', '𠿣', '𠿤', '𠿥', '𡀑', '𡀔',
+    '𡀖', '𡀗', '𡀘', '𡀙', '𡀹', '𡀺', '𡀽', '𡀾', '𡀿', '𡁀', '𡁁', '𡁆', '𡁇', '𡁈', '𡁉', '𡁊',
+    '𡁋', '𡁌', '𡁍', '𡁎', '𡁑', '𡁓', '𡁔', '𡁕', '𡁧', '𡁨', '𡁪', '𡁮', '𡁰', '𡁱', '𡁳', '𡁴',
+    '𡂊', '𡂏', '𡂒', '𡂓', '𡂕', '𡂖', '𡂗', '𡂘', '𡂙', '𡂚', '𡂛', '𡂝', '𡂟', '𡂠', '𡂡', '𡂣',
+    '𡂩', '𡂫', '𡂭', '𡂿', '𡃂', '𡃃', '𡃄', '𡃅', '𡃆', '𡃖', '𡃝', '𡃡', '𡃢', '𡃦', '𡃩', '𡃳',
+    '𡃷', '𡃸', '𡄇', '𡄑', '𡄒', '𡄓', '𡄔', '𡄕', '𡄖', '𡄟', '𡄡', '𡄢', '𡄣', '𡄤', '𡄱', '𡄲',
+    '𡄳', '𡄴', '𡄵', '𡄷', '𡄹', '𡄻', '𡅂', '𡅃', '𡅄', '𡅅', '𡅆', '𡅇', '𡅓', '𡅕', '𡅖', '𡅘',
+    '𡅙', '𡅡', '𡅥', '𡅭', '𡅱', '𡅵', '𡅶', '𡅹', '𡅺', '𡅻', '𡆅', '𡆆', '𡆏', '𡆕', '𡆣', '𡆤',
+    '𡆥', '𡆦', '𡆩', '𡆪', '𡆮', '𡆯', '𡆰', '𡆱', '𡆲', '𡆸', '𡆽', '𡇀', '𡇂', '𡇄', '𡇈', '𡇑',
+    '𡇒', '𡇓', '𡇖', '𡇚', '𡇜', '𡇝', '𡇞', '𡇟', '𡇠', '𡇤', '𡇦', '𡇧', '𡇩', '𡇬', '𡇭', '𡇮',
+    '𡇯', '𡇰', '𡇱', '𡇳', '𡇵', '𡇶', '𡇷', '𡇸', '𡇺', '𡇼', '𡇿', '𡈀', '𡈅', '𡈆', '𡈉', '𡈊',
+    '𡈍', '𡈎', '𡈏', '𡈐', '𡈒', '𡈓', '𡈗', '𡈙', '𡈞', '𡈠', '𡈣', '𡈦', '𡈧', '𡈨', '𡈮', '𡈯',
+    '𡈰', '𡈲', '𡈴', '𡈵', '𡈶', '𡈼', '𡈿', '𡉄', '𡉅', '𡉆', '𡉉', '𡉎', '𡉐', '𡉑', '𡉒', '𡉓',
+    '𡉗', '𡉚', '𡉛', '𡉩', '𡉪', '𡉭', '𡉴', '𡉷', '𡉸', '𡉺', '𡊁', '𡊄', '𡊅', '𡊉', '𡊍', '𡊛',
+    '𡊝', '𡊞', '𡊟', '𡊠', '𡊡', '𡊣', '𡊧', '𡊨', '𡊫', '𡊭', '𡊶', '𡊸', '𡊻', '𡊼', '𡋙', '𡋚',
+    '𡋟', '𡋧', '𡋨', '𡋩', '𡋪', '𡋬', '𡋭', '𡋯', '𡌔', '𡌚', '𡌜', '𡌞', '𡌣', '𡌤', '𡌦', '𡌩',
+    '𡌪', '𡌭', '𡌲', '𡌴', '𡍌', '𡍍', '𡍐', '𡍒', '𡍡', '𡍤', '𡍥', '𡍦', '𡍨', '𡍪', '𡍫', '𡍮',
+    '𡍲', '𡍶', '𡍷', '𡍻', '𡎉', '𡎍', '𡎑', '𡎒', '𡎔', '𡎘', '𡎚', '𡎫', '𡎬', '𡎮', '𡎳', '𡎺',
+    '𡎻', '𡎾', '𡎿', '𡏀', '𡏁', '𡏘', '𡏚', '𡏛', '𡏞', '𡏩', '𡏪', '𡏭', '𡏮', '𡏯', '𡏵', '𡏼',
+    '𡐋', '𡐌', '𡐏', '𡐒', '𡐓', '𡐔', '𡐖', '𡐝', '𡐞', '𡐠', '𡐤', '𡐥', '𡐿', '𡑀', '𡑄', '𡑆',
+    '𡑈', '𡑍', '𡑎', '𡑘', '𡑚', '𡑞', '𡑟', '𡑣', '𡑯', '𡑶', '𡑻', '𡑽', '𡑾', '𡒁', '𡒃', '𡒄',
+    '𡒊', '𡒌', '𡒍', '𡒏', '𡒒', '𡒓', '𡒖', '𡒢', '𡒧', '𡒯', '𡒱', '𡒳', '𡒾', '𡓉', '𡓍', '𡓒',
+    '𡓓', '𡓔', '𡓖', '𡓘', '𡓣', '𡓦', '𡓭', '𡓰', '𡓷', '𡓿', '𡔇', '𡔕', '𡔛', '𡔜', '𡔞', '𡔣',
+    '𡔨', '𡔪', '𡔱', '𡔴', '𡕁', '𡕉', '𡕏', '𡕐', '𡕒', '𡕖', '𡕗', '𡕢', '𡕧', '𡕪', '𡕮', '𡕰',
+    '𡖉', '𡖌', '𡖎', '𡖐', '𡖑', '𡖒', '𡖓', '𡖔', '𡖛', '𡖝', '𡖞', '𡖢', '𡖣', '𡖤', '𡖧', '𡖪',
+    '𡖫', '𡖬', '𡖯', '𡖲', '𡖳', '𡖺', '𡖻', '𡖾', '𡗁', '𡗆', '𡗈', '𡗍', '𡗏', '𡗑', '𡗗', '𡗞',
+    '𡗢', '𡗤', '𡗥', '𡗦', '𡗲', '𡗳', '𡗵', '𡗷', '𡗸', '𡗹', '𡗼', '𡘍', '𡘏', '𡘐', '𡘛', '𡘝',
+    '𡘡', '𡘧', '𡘪', '𡘭', '𡘰', '𡘴', '𡙀', '𡙅', '𡙋', '𡙎', '𡙐', '𡙑', '𡙒', '𡙓', '𡙘', '𡙛',
+    '𡙣', '𡙪', '𡙬', '𡙭', '𡙶', '𡚁', '𡚅', '𡚇', '𡚈', '𡚊', '𡚌', '𡚑', '𡚔', '𡚗', '𡚙', '𡚛',
+    '𡚜', '𡚟', '𡚠', '𡚨', '𡚭', '𡚮', '𡚷', '𡚹', '𡚻', '𡚼', '𡚽', '𡛌', '𡛗', '𡛘', '𡛙', '𡛜',
+    '𡛝', '𡛞', '𡛟', '𡛠', '𡛡', '𡛵', '𡛶', '𡛽', '𡜀', '𡜁', '𡜂', '𡜃', '𡜉', '𡜊', '𡜋', '𡜚',
+    '𡜢', '𡜥', '𡜦', '𡜧', '𡜨', '𡜫', '𡜬', '𡜯', '𡜱', '𡜲', '𡜳', '𡜵', '𡝈', '𡝉', '𡝊', '𡝋',
+    '𡝍', '𡝐', '𡝒', '𡝓', '𡝙', '𡝚', '𡝛', '𡝜', '𡝝', '𡝟', '𡝠', '𡝦', '𡝧', '𡝩', '𡝫', '𡞘',
+    '𡞙', '𡞚', '𡞜', '𡞞', '𡞟', '𡞠', '𡞢', '𡞣', '𡞥', '𡞦', '𡞧', '𡞪', '𡞫', '𡞭', '𡞯', '𡞾',
+    '𡟍', '𡟑', '𡟓', '𡟕', '𡟞', '𡟟', '𡟠', '𡟢', '𡟣', '𡟤', '𡟥', '𡟨', '𡟩', '𡟫', '𡟬', '𡟭',
+    '𡟯', '𡟳', '𡠆', '𡠊', '𡠋', '𡠏', '𡠒', '𡠖', '𡠗', '𡠘', '𡠙', '𡠚', '𡠜', '𡠞', '𡠥', '𡠹',
+    '𡠼', '𡠿', '𡡀', '𡡂', '𡡄', '𡡈', '𡡉', '𡡊', '𡡎', '𡡏', '𡡐', '𡡑', '𡡒', '𡡔', '𡡕', '𡡖',
+    '𡡗', '𡡥', '𡡺', '𡢀', '𡢈', '𡢊', '𡢒', '𡢕', '𡢖', '𡢘', '𡢚', '𡢜', '𡢱', '𡢳', '𡢹', '𡣀',
+    '𡣁', '𡣂', '𡣈', '𡣋', '𡣔', '𡣠', '𡣢', '𡣪', '𡣬', '𡣮', '𡣯', '𡣹', '𡣽', '𡣾', '𡤋', '𡤌',
+    '𡤎', '𡤗', '𡤙', '𡤛', '𡤞', '𡤢', '𡤫', '𡤶', '𡤽', '𡤿', '𡥁', '𡥆', '𡥈', '𡥋', '𡥍', '𡥎',
+    '𡥞', '𡥦', '𡥨', '𡥩', '𡥶', '𡥸', '𡥽', '𡥿', '𡦁', '𡦃', '𡦄', '𡦆', '𡦊', '𡦍', '𡦎', '𡦔',
+    '𡦕', '𡦛', '𡦜', '𡦝', '𡦟', '𡦨', '𡦪', '𡦳', '𡦷', '𡦻', '𡦼', '𡧍', '𡧒', '𡧔', '𡧕', '𡧖',
+    '𡧗', '𡧙', '𡧣', '𡧥', '𡧦', '𡧨', '𡧩', '𡧭', '𡧹', '𡧼', '𡧽', '𡨀', '𡨄', '𡨅', '𡨇', '𡨖',
+    '𡨗', '𡨛', '𡨢', '𡨣', '𡨩', '𡨲', '𡨳', '𡨵', '𡨻', '𡨽', '𡨿', '𡩂', '𡩃', '𡩄', '𡩆', '𡩇',
+    '𡩘', '𡩚', '𡩡', '𡩣', '𡩤', '𡩥', '𡩩', '𡩶', '𡩸', '𡩽', '𡩾', '𡪁', '𡪅', '𡪑', '𡪒', '𡪗',
+    '𡪙', '𡪞', '𡪠', '𡪰', '𡪱', '𡪳', '𡪵', '𡪺', '𡫀', '𡫁', '𡫂', '𡫃', '𡫟', '𡫥', '𡫦', '𡫧',
+    '𡫬', '𡫯', '𡫵', '𡫸', '𡫹', '𡫺', '𡫽', '𡬁', '𡬂', '𡬄', '𡬆', '𡬇', '𡬉', '𡬊', '𡬋', '𡬌',
+    '𡬍', '𡬎', '𡬐', '𡬓', '𡬕', '𡬖', '𡬗', '𡬘', '𡬙', '𡬜', '𡬞', '𡬥', '𡬫', '𡬯', '𡬲', '𡬳',
+    '𡬵', '𡬸', '𡭂', '𡭇', '𡭋', '𡭐', '𡭘', '𡭛', '𡭞', '𡭢', '𡭣', '𡭥', '𡭭', '𡭮', '𡭳', '𡭷',
+    '𡭸', '𡭾', '𡭿', '𡮎', '𡮙', '𡮚', '𡮞', '𡮦', '𡮰', '𡮻', '𡮿', '𡯁', '𡯄', '𡯇', '𡯈', '𡯉',
+    '𡯋', '𡯏', '𡯐', '𡯑', '𡯒', '𡯘', '𡯙', '𡯢', '𡯥', '𡯨', '𡯩', '𡯫', '𡯰', '𡯱', '𡯳', '𡯴',
+    '𡯵', '𡯷', '𡯽', '𡯿', '𡰀', '𡰅', '𡰆', '𡰋', '𡰌', '𡰎', '𡰐', '𡰑', '𡰒', '𡰖', '𡰚', '𡰝',
+    '𡰞', '𡰠', '𡰢', '𡰥', '𡰨', '𡰪', '𡰫', '𡰯', '𡰲', '𡰹', '𡰽', '𡰾', '𡰿', '𡱁', '𡱅', '𡱆',
+    '𡱇', '𡱉', '𡱊', '𡱌', '𡱐', '𡱑', '𡱔', '𡱜', '𡱣', '𡱯', '𡱱', '𡱳', '𡱷', '𡱺', '𡱼', '𡱽',
+    '𡱾', '𡲀', '𡲍', '𡲕', '𡲗', '𡲚', '𡲣', '𡲪', '𡲭', '𡲮', '𡲰', '𡲼', '𡲿', '𡳂', '𡳄', '𡳆',
+    '𡳏', '𡳑', '𡳘', '𡳚', '𡳞', '𡳭', '𡳮', '𡳴', '𡳸', '𡳾', '𡴅', '𡴆', '𡴎', '𡴔', '𡴭', '𡴯',
+    '𡴱', '𡵀', '𡵉', '𡵌', '𡵑', '𡵒', '𡵓', '𡵕', '𡵖', '𡵗', '𡵘', '𡵙', '𡵚', '𡵛', '𡵜', '𡵞',
+    '𡵬', '𡵶', '𡵷', '𡵻', '𡶂', '𡶃', '𡶄', '𡶆', '𡶈', '𡶉', '𡶋', '𡶌', '𡶎', '𡶏', '𡶐', '𡶑',
+    '𡶢', '𡶣', '𡶤', '𡶥', '𡶦', '𡶪', '𡶫', '𡶭', '𡶯', '𡶱', '𡶲', '𡶴', '𡷈', '𡷋', '𡷍', '𡷎',
+    '𡷏', '𡷐', '𡷓', '𡷕', '𡷖', '𡷗', '𡷘', '𡷛', '𡷜', '𡷝', '𡷞', '𡷠', '𡷡', '𡷢', '𡷤', '𡷥',
+    '𡷨', '𡷪', '𡷸', '𡷺', '𡷻', '𡷼', '𡷽', '𡷿', '𡸂', '𡸃', '𡸈', '𡸉', '𡸌', '𡸎', '𡸑', '𡸔',
+    '𡸕', '𡸗', '𡸘', '𡸛', '𡸜', '𡸞', '𡸟', '𡸡', '𡸣', '𡸤', '𡸥', '𡸦', '𡸨', '𡸪', '𡹄', '𡹇',
+    '𡹉', '𡹎', '𡹓', '𡹕', '𡹖', '𡹘', '𡹙', '𡹢', '𡹣', '𡹥', '𡹨', '𡹩', '𡹪', '𡹬', '𡹭', '𡹯',
+    '𡹰', '𡹲', '𡹵', '𡹶', '𡹷', '𡹸', '𡹹', '𡹼', '𡹾', '𡺇', '𡺐', '𡺑', '𡺒', '𡺓', '𡺔', '𡺕',
+    '𡺖', '𡺗', '𡺘', '𡺛', '𡺜', '𡺟', '𡺡', '𡺧', '𡺫', '𡺭', '𡺮', '𡺯', '𡺴', '𡺵', '𡺷', '𡺸',
+    '𡺺', '𡺽', '𡻈', '𡻌', '𡻎', '𡻐', '𡻑', '𡻕', '𡻘', '𡻙', '𡻚', '𡻝', '𡻞', '𡻟', '𡻠', '𡻡',
+    '𡻢', '𡻣', '𡻤', '𡻧', '𡻨', '𡻩', '𡻪', '𡻫', '𡻬', '𡻭', '𡻮', '𡻯', '𡻰', '𡻱', '𡼁', '𡼃',
+    '𡼄', '𡼊', '𡼎', '𡼓', '𡼖', '𡼗', '𡼘', '𡼚', '𡼛', '𡼜', '𡼬', '𡼱', '𡼻', '𡼼', '𡼽', '𡼾',
+    '𡼿', '𡽁', '𡽂', '𡽅', '𡽆', '𡽉', '𡽊', '𡽖', '𡽛', '𡽜', '𡽝', '𡽠', '𡽢', '𡽣', '𡽧', '𡽱',
+    '𡽲', '𡽳', '𡽴', '𡽶', '𡽻', '𡽼', '𡾂', '𡾅', '𡾇', '𡾉', '𡾋', '𡾌', '𡾒', '𡾓', '𡾕', '𡾖',
+    '𡾙', '𡾛', '𡾜', '𡾥', '𡾦', '𡾨', '𡾮', '𡾰', '𡾱', '𡾲', '𡾻', '𡾼', '𡾽', '𡾾', '𡿂', '𡿄',
+    '𡿉', '𡿊', '𡿋', '𡿎', '𡿏', '𡿓', '𡿖', '𡿗', '𡿙', '𡿛', '𡿝', '𡿠', '𡿡', '𡿤', '𡿥', '𡿧',
+    '𡿨', '𡿩', '𡿯', '𡿰', '𡿺', '𡿼', '𡿾', '𡿿', '𢀁', '𢀊', '𢀋', '𢀌', '𢀍', '𢀕', '𢀖', '𢀗',
+    '𢀘', '𢀙', '𢀜', '𢀡', '𢀪', '𢀵', '𢀼', '𢁀', '𢁁', '𢁂', '𢁏', '𢁒', '𢁓', '𢁕', '𢁖', '𢁗',
+    '𢁘', '𢁝', '𢁞', '𢁠', '𢁢', '𢁤', '𢁧', '𢁩', '𢁪', '𢁬', '𢁮', '𢁱', '𢁶', '𢁷', '𢁹', '𢁻',
+    '𢁼', '𢁽', '𢁾', '𢁿', '𢂀', '𢂁', '𢂃', '𢂄', '𢂆', '𢂊', '𢂍', '𢂏', '𢂐', '𢂑', '𢂒', '𢂔',
+    '𢂕', '𢂗', '𢂘', '𢂝', '𢂤', '𢂨', '𢂱', '𢂶', '𢂹', '𢂺', '𢂼', '𢃀', '𢃍', '𢃎', '𢃏', '𢃐',
+    '𢃕', '𢃗', '𢃘', '𢃜', '𢃢', '𢃥', '𢃦', '𢃩', '𢃬', '𢃭', '𢃮', '𢃯', '𢃰', '𢃱', '𢃲', '𢃳',
+    '𢃴', '𢃸', '𢃺', '𢄀', '𢄄', '𢄅', '𢄇', '𢄊', '𢄋', '𢄌', '𢄍', '𢄎', '𢄏', '𢄐', '𢄓', '𢄔',
+    '𢄗', '𢄜', '𢄟', '𢄠', '𢄡', '𢄢', '𢄣', '𢄤', '𢄦', '𢄧', '𢄱', '𢄳', '𢄵', '𢄶', '𢄷', '𢄸',
+    '𢄹', '𢄺', '𢄼', '𢄽', '𢄿', '𢅀', '𢅁', '𢅈', '𢅉', '𢅊', '𢅋', '𢅎', '𢅏', '𢅑', '𢅒', '𢅕',
+    '𢅖', '𢅗', '𢅚', '𢅜', '𢅝', '𢅞', '𢅟', '𢅠', '𢅡', '𢅤', '𢅥', '𢅩', '𢅪', '𢅫', '𢅭', '𢅮',
+    '𢅯', '𢅰', '𢅹', '𢅺', '𢅼', '𢅾', '𢆀', '𢆂', '𢆉', '𢆜', '𢆞', '𢆟', '𢆣', '𢆦', '𢆩', '𢆯',
+    '𢆰', '𢆴', '𢆶', '𢆷', '𢆸', '𢆺', '𢆻', '𢆽', '𢇇', '𢇈', '𢇕', '𢇗', '𢇘', '𢇙', '𢇚', '𢇛',
+    '𢇞', '𢇟', '𢇤', '𢇥', '𢇦', '𢇧', '𢇨', '𢇪', '𢇫', '𢇰', '𢇲', '𢇳', '𢇴', '𢇵', '𢇶', '𢇷',
+    '𢇸', '𢈂', '𢈄', '𢈆', '𢈇', '𢈈', '𢈉', '𢈋', '𢈓', '𢈕', '𢈙', '𢈚', '𢈛', '𢈜', '𢈝', '𢈠',
+    '𢈡', '𢈢', '𢈤', '𢈯', '𢈲', '𢈴', '𢈵', '𢈶', '𢈸', '𢈹', '𢈻', '𢈼', '𢈽', '𢈾', '𢉁', '𢉂',
+    '𢉃', '𢉅', '𢉆', '𢉑', '𢉓', '𢉕', '𢉗', '𢉘', '𢉚', '𢉜', '𢉝', '𢉞', '𢉢', '𢉤', '𢉥', '𢉦',
+    '𢉧', '𢉨', '𢉩', '𢉭', '𢉵', '𢉺', '𢉿', '𢊀', '𢊁', '𢊄', '𢊅', '𢊇', '𢊍', '𢊏', '𢊒', '𢊓',
+    '𢊕', '𢊖', '𢊗', '𢊘', '𢊙', '𢊚', '𢊛', '𢊜', '𢊧', '𢊩', '𢊮', '𢊯', '𢊱', '𢊲', '𢊻', '𢋂',
+    '𢋃', '𢋄', '𢋅', '𢋆', '𢋇', '𢋈', '𢋒', '𢋔', '𢋚', '𢋝', '𢋡', '𢋣', '𢋮', '𢋹', '𢋻', '𢋼',
+    '𢋿', '𢌀', '𢌄', '𢌈', '𢌍', '𢌔', '𢌕', '𢌚', '𢌦', '𢌨', '𢌲', '𢌳', '𢌷', '𢌹', '𢌻', '𢍁',
+    '𢍆', '𢍇', '𢍈', '𢍎', '𢍏', '𢍑', '𢍓', '𢍕', '𢍚', '𢍧', '𢍫', '𢍭', '𢍰', '𢍱', '𢍼', '𢍿',
+    '𢎀', '𢎂', '𢎃', '𢎄', '𢎆', '𢎈', '𢎉', '𢎋', '𢎌', '𢎎', '𢎔', '𢎕', '𢎖', '𢎘', '𢎙', '𢎠',
+    '𢎡', '𢎥', '𢎪', '𢎭', '𢎴', '𢎵', '𢎹', '𢎻', '𢏃', '𢏄', '𢏅', '𢏆', '𢏈', '𢏍', '𢏎', '𢏒',
+    '𢏕', '𢏗', '𢏙', '𢏜', '𢏝', '𢏞', '𢏤', '𢏦', '𢏧', '𢏭', '𢏮', '𢏯', '𢏰', '𢏳', '𢏷', '𢐂',
+    '𢐃', '𢐄', '𢐆', '𢐊', '𢐎', '𢐐', '𢐒', '𢐔', '𢐖', '𢐚', '𢐞', '𢐟', '𢐦', '𢐩', '𢐫', '𢐲',
+    '𢐳', '𢐾', '𢐿', '𢑅', '𢑆', '𢑇', '𢑈', '𢑓', '𢑖', '𢑝', '𢑟', '𢑠', '𢑢', '𢑧', '𢑬', '𢑮',
+    '𢑹', '𢒆', '𢒉', '𢒍', '𢒐', '𢒒', '𢒔', '𢒝', '𢒞', '𢒩', '𢒯', '𢒰', '𢒱', '𢒲', '𢒷', '𢒹',
+    '𢒾', '𢓀', '𢓃', '𢓄', '𢓆', '𢓇', '𢓋', '𢓍', '𢓎', '𢓒', '𢓔', '𢓕', '𢓖', '𢓗', '𢓘', '𢓙',
+    '𢓜', '𢓝', '𢓞', '𢓟', '𢓡', '𢓢', '𢓣', '𢓧', '𢓬', '𢓮', '𢓯', '𢓰', '𢓱', '𢓲', '𢓵', '𢓿',
+    '𢔁', '𢔂', '𢔅', '𢔆', '𢔇', '𢔈', '𢔊', '𢔋', '𢔑', '𢔔', '𢔟', '𢔠', '𢔡', '𢔢', '𢔣', '𢔤',
+    '𢔥', '𢔧', '𢔩', '𢔪', '𢔬', '𢔱', '𢔲', '𢔳', '𢔴', '𢕅', '𢕋', '𢕎', '𢕑', '𢕓', '𢕔', '𢕕',
+    '𢕖', '𢕘', '𢕙', '𢕚', '𢕞', '𢕟', '𢕦', '𢕨', '𢕪', '𢕫', '𢕬', '𢕭', '𢕮', '𢕯', '𢕷', '𢕺',
+    '𢕻', '𢖄', '𢖅', '𢖆', '𢖇', '𢖈', '𢖊', '𢖋', '𢖎', '𢖏', '𢖑', '𢖗', '𢖛', '𢖝', '𢖟', '𢖡',
+    '𢖤', '𢖦', '𢖧', '𢖩', '𢖫', '𢖬', '𢖳', '𢖴', '𢖵', '𢖷', '𢖺', '𢖿', '𢗀', '𢗂', '𢗄', '𢗈',
+    '𢗉', '𢗊', '𢗎', '𢗫', '𢗭', '𢗯', '𢗰', '𢗲', '𢗳', '𢗴', '𢗵', '𢗹', '𢗺', '𢗿', '𢘀', '𢘃',
+    '𢘄', '𢘅', '𢘇', '𢘉', '𢘊', '𢘋', '𢘌', '𢘍', '𢘝', '𢘟', '𢘧', '𢘸', '𢘹', '𢘺', '𢘽', '𢘿',
+    '𢙁', '𢙂', '𢙅', '𢙇', '𢙐', '𢙓', '𢙮', '𢙱', '𢙲', '𢙳', '𢙺', '𢙼', '𢙾', '𢙿', '𢚀', '𢚁',
+    '𢚂', '𢚄', '𢚅', '𢚆', '𢚋', '𢚌', '𢚑', '𢚨', '𢚪', '𢚫', '𢚭', '𢚮', '𢚺', '𢚻', '𢛁', '𢛃',
+    '𢛄', '𢛆', '𢛉', '𢛍', '𢛎', '𢛒', '𢛓', '𢛔', '𢛕', '𢛖', '𢛘', '𢛙', '𢛚', '𢛛', '𢛜', '𢛞',
+    '𢛟', '𢛥', '𢛦', '𢛨', '𢛮', '𢛯', '𢜗', '𢜣', '𢜥', '𢜨', '𢜩', '𢜪', '𢜫', '𢜬', '𢜭', '𢜮',
+    '𢜯', '𢜰', '𢜱', '𢜲', '𢜳', '𢜴', '𢜶', '𢜸', '𢜻', '𢜼', '𢜽', '𢜿', '𢝀', '𢝁', '𢝂', '𢝅',
+    '𢝆', '𢝇', '𢝈', '𢝉', '𢝌', '𢝍', '𢝓', '𢝬', '𢝯', '𢝳', '𢝴', '𢝸', '𢝹', '𢝻', '𢞇', '𢞉',
+    '𢞋', '𢞏', '𢞐', '𢞒', '𢞕', '𢞖', '𢞗', '𢞚', '𢞜', '𢞞', '𢞟', '𢞠', '𢞡', '𢞣', '𢞦', '𢞬',
+    '𢞭', '𢟅', '𢟊', '𢟋', '𢟢', '𢟣', '𢟤', '𢟧', '𢟨', '𢟩', '𢟪', '𢟭', '𢟯', '𢟰', '𢟱', '𢟳',
+    '𢟴', '𢟵', '𢟾', '𢟿', '𢠛', '𢠝', '𢠡', '𢠫', '𢠬', '𢠭', '𢠮', '𢠲', '𢠳', '𢠵', '𢠷', '𢠹',
+    '𢠺', '𢠼', '𢠽', '𢠿', '𢡀', '𢡁', '𢡂', '𢡃', '𢡅', '𢡇', '𢡈', '𢡉', '𢡍', '𢡎', '𢡏', '𢡑',
+    '𢡒', '𢡰', '𢡴', '𢡵', '𢡹', '𢢂', '𢢌', '𢢍', '𢢒', '𢢓', '𢢖', '𢢗', '𢢚', '𢢜', '𢢞', '𢢹',
+    '𢣉', '𢣊', '𢣎', '𢣏', '𢣐', '𢣓', '𢣔', '𢣕', '𢣗', '𢣘', '𢣙', '𢣚', '𢣞', '𢣠', '𢣳', '𢣵',
+    '𢣻', '𢣼', '𢣿', '𢤁', '𢤂', '𢤄', '𢤆', '𢤋', '𢤍', '𢤎', '𢤐', '𢤘', '𢤚', '𢤤', '𢤧', '𢤩',
+    '𢤫', '𢤯', '𢤰', '𢤱', '𢤲', '𢥂', '𢥃', '𢥋', '𢥌', '𢥏', '𢥐', '𢥘', '𢥚', '𢥞', '𢥳', '𢥽',
+    '𢦅', '𢦈', '𢦊', '𢦌', '𢦍', '𢦎', '𢦏', '𢦑', '𢦒', '𢦕', '𢦚', '𢦟', '𢦰', '𢦱', '𢦲', '𢦴',
+    '𢦸', '𢦺', '𢦼', '𢦽', '𢦿', '𢧀', '𢧅', '𢧇', '𢧈', '𢧏', '𢧑', '𢧖', '𢧗', '𢧝', '𢧞', '𢧠',
+    '𢧤', '𢧥', '𢧦', '𢧧', '𢧮', '𢧰', '𢧱', '𢧳', '𢧴', '𢧻', '𢧽', '𢨁', '𢨂', '𢨏', '𢨐', '𢨒',
+    '𢨔', '𢨖', '𢨗', '𢨜', '𢨝', '𢨟', '𢨡', '𢨥', '𢨦', '𢨨', '𢨫', '𢨮', '𢨯', '𢨰', '𢨱', '𢨳',
+    '𢨺', '𢨿', '𢩀', '𢩁', '𢩄', '𢩈', '𢩏', '𢩐', '𢩑', '𢩒', '𢩓', '𢩕', '𢩖', '𢩗', '𢩘', '𢩞',
+    '𢩟', '𢩠', '𢩡', '𢩢', '𢩦', '𢩨', '𢩮', '𢩯', '𢩰', '𢩲', '𢩷', '𢩸', '𢩹', '𢩻', '𢩼', '𢪃',
+    '𢪇', '𢪈', '𢪋', '𢪍', '𢪎', '𢪏', '𢪥', '𢪦', '𢪧', '𢪪', '𢪬', '𢪶', '𢪷', '𢪼', '𢪾', '𢫐',
+    '𢫓', '𢫔', '𢫢', '𢫧', '𢫨', '𢫫', '𢫬', '𢫭', '𢫯', '𢫰', '𢫲', '𢫴', '𢫵', '𢫷', '𢫸', '𢫻',
+    '𢫿', '𢬀', '𢬫', '𢬬', '𢬱', '𢬲', '𢬳', '𢬴', '𢬵', '𢬶', '𢬷', '𢬼', '𢬾', '𢬿', '𢭁', '𢭂',
+    '𢭃', '𢭄', '𢭅', '𢭆', '𢭈', '𢭎', '𢭏', '𢭥', '𢭦', '𢭧', '𢮁', '𢮂', '𢮃', '𢮄', '𢮇', '𢮉',
+    '𢮊', '𢮌', '𢮍', '𢮏', '𢮐', '𢮚', '𢮛', '𢮞', '𢯅', '𢯉', '𢯋', '𢯌', '𢯩', '𢯫', '𢯭', '𢯮',
+    '𢯰', '𢯲', '𢯳', '𢯴', '𢯵', '𢯶', '𢯷', '𢯹', '𢯺', '𢯼', '𢯽', '𢯾', '𢰆', '𢰇', '𢰊', '𢰌',
+    '𢰍', '𢰒', '𢰘', '𢰙', '𢰜', '𢰿', '𢱁', '𢱄', '𢱆', '𢱈', '𢱉', '𢱌', '𢱟', '𢱡', '𢱢', '𢱣',
+    '𢱤', '𢱦', '𢱧', '𢱨', '𢱮', '𢱴', '𢱶', '𢱷', '𢱺', '𢱽', '𢱾', '𢲔', '𢲰', '𢲴', '𢲵', '𢲶',
+    '𢲷', '𢲸', '𢲼', '𢲽', '𢲾', '𢲿', '𢳀', '𢳁', '𢳂', '𢳃', '𢳄', '𢳆', '𢳇', '𢳈', '𢳋', '𢳑',
+    '𢳓', '𢳙', '𢳚', '𢳟', '𢳽', '𢳾', '𢴂', '𢴠', '𢴢', '𢴦', '𢴧', '𢴨', '𢴩', '𢴪', '𢴬', '𢴭',
+    '𢴮', '𢴰', '𢴱', '𢴲', '𢴳', '𢴼', '𢴾', '𢵇', '𢵈', '𢵥', '𢶀', '𢶃', '𢶅', '𢶇', '𢶉', '𢶋',
+    '𢶌', '𢶍', '𢶑', '𢶒', '𢶓', '𢶡', '𢶣', '𢶳', '𢶴', '𢶶', '𢷍', '𢷑', '𢷔', '𢷕', '𢷖', '𢷗',
+    '𢷙', '𢷢', '𢷶', '𢷸', '𢷹', '𢷺', '𢷻', '𢷾', '𢷿', '𢸀', '𢸁', '𢸌', '𢸗', '𢸘', '𢸙', '𢸣',
+    '𢸥', '𢸦', '𢸨', '𢸫', '𢸭', '𢸳', '𢸴', '𢹃', '𢹍', '𢹏', '𢹑', '𢹒', '𢹓', '𢹔', '𢹖', '𢹘',
+    '𢹙', '𢹚', '𢹝', '𢹮', '𢹲', '𢹳', '𢹼', '𢺄', '𢺅', '𢺆', '𢺇', '𢺈', '𢺉', '𢺑', '𢺞', '𢺟',
+    '𢺡', '𢺫', '𢺯', '𢺰', '𢺴', '𢺵', '𢺷', '𢺼', '𢺽', '𢺿', '𢻀', '𢻂', '𢻅', '𢻇', '𢻉', '𢻋',
+    '𢻓', '𢻔', '𢻗', '𢻘', '𢻙', '𢻚', '𢻜', '𢻟', '𢻠', '𢻢', '𢻤', '𢻨', '𢻪', '𢻫', '𢻮', '𢻵',
+    '𢻶', '𢻷', '𢻸', '𢻹', '𢻼', '𢻿', '𢼀', '𢼁', '𢼃', '𢼉', '𢼊', '𢼋', '𢼌', '𢼐', '𢼑', '𢼖',
+    '𢼙', '𢼚', '𢼛', '𢼟', '𢼠', '𢼣', '𢼤', '𢼦', '𢼩', '𢼫', '𢼰', '𢼱', '𢼲', '𢼳', '𢼵', '𢼷',
+    '𢼸', '𢼹', '𢼺', '𢼼', '𢽂', '𢽃', '𢽄', '𢽇', '𢽕', '𢽖', '𢽗', '𢽚', '𢽜', '𢽝', '𢽢', '𢽦',
+    '𢽧', '𢽨', '𢽩', '𢽭', '𢽸', '𢽹', '𢽾', '𢾀', '𢾁', '𢾃', '𢾄', '𢾅', '𢾆', '𢾇', '𢾊', '𢾎',
+    '𢾐', '𢾑', '𢾒', '𢾔', '𢾦', '𢾧', '𢾩', '𢾪', '𢾫', '𢾬', '𢾱', '𢾲', '𢾳', '𢾺', '𢾿', '𢿂',
+    '𢿈', '𢿌', '𢿎', '𢿏', '𢿚', '𢿜', '𢿞', '𢿡', '𢿢', '𢿣', '𢿤', '𢿦', '𢿧', '𢿭', '𢿸', '𣀀',
+    '𣀁', '𣀂', '𣀃', '𣀄', '𣀍', '𣀏', '𣀐', '𣀒', '𣀓', '𣀔', '𣀜', '𣀝', '𣀞', '𣀣', '𣀥', '𣀧',
+    '𣀫', '𣀯', '𣀳', '𣀶', '𣀷', '𣀻', '𣀾', '𣁉', '𣁍', '𣁒', '𣁖', '𣁗', '𣁜', '𣁟', '𣁢', '𣁦',
+    '𣁨', '𣁭', '𣁯', '𣁰', '𣁳', '𣁴', '𣁵', '𣁷', '𣁹', '𣁻', '𣂁', '𣂄', '𣂆', '𣂇', '𣂉', '𣂊',
+    '𣂒', '𣂖', '𣂗', '𣂜', '𣂝', '𣂞', '𣂤', '𣂥', '𣂬', '𣂮', '𣂳', '𣂴', '𣂵', '𣂻', '𣂽', '𣃄',
+    '𣃅', '𣃈', '𣃉', '𣃍', '𣃑', '𣃗', '𣃘', '𣃝', '𣃧', '𣃳', '𣃵', '𣃽', '𣃾', '𣄅', '𣄉', '𣄊',
+    '𣄍', '𣄎', '𣄏', '𣄑', '𣄓', '𣄙', '𣄜', '𣄝', '𣄟', '𣄠', '𣄧', '𣄮', '𣄯', '𣄰', '𣄱', '𣄲',
+    '𣄴', '𣄸', '𣄺', '𣄻', '𣄿', '𣅃', '𣅄', '𣅉', '𣅍', '𣅚', '𣅝', '𣅟', '𣅡', '𣅤', '𣅥', '𣅰',
+    '𣅷', '𣅺', '𣅻', '𣅾', '𣆈', '𣆍', '𣆐', '𣆒', '𣆔', '𣆕', '𣆖', '𣆗', '𣆘', '𣆙', '𣆚', '𣆛',
+    '𣆟', '𣆧', '𣆲', '𣆳', '𣆴', '𣆵', '𣆽', '𣇇', '𣇖', '𣇗', '𣇙', '𣇢', '𣇦', '𣇧', '𣇨', '𣇫',
+    '𣇬', '𣇰', '𣇲', '𣇳', '𣈅', '𣈊', '𣈍', '𣈎', '𣈠', '𣈡', '𣈥', '𣈶', '𣉄', '𣉅', '𣉆', '𣉒',
+    '𣉓', '𣉔', '𣉕', '𣉖', '𣉗', '𣉘', '𣉚', '𣉛', '𣉜', '𣉡', '𣉩', '𣉪', '𣉮', '𣉾', '𣉿', '𣊁',
+    '𣊃', '𣊔', '𣊖', '𣊝', '𣊞', '𣊟', '𣊧', '𣊶', '𣊷', '𣊺', '𣋄', '𣋉', '𣋊', '𣋋', '𣋍', '𣋏',
+    '𣋘', '𣋞', '𣋟', '𣋢', '𣋣', '𣋲', '𣋳', '𣋵', '𣋶', '𣋹', '𣋿', '𣌅', '𣌍', '𣌏', '𣌓', '𣌗',
+    '𣌚', '𣌜', '𣌞', '𣌟', '𣌠', '𣌧', '𣌨', '𣌬', '𣌭', '𣌾', '𣍆', '𣍇', '𣍏', '𣍖', '𣍛', '𣍟',
+    '𣍦', '𣍧', '𣍯', '𣍰', '𣍴', '𣍸', '𣍺', '𣎄', '𣎅', '𣎆', '𣎑', '𣎓', '𣎔', '𣎚', '𣎛', '𣎩',
+    '𣎮', '𣎱', '𣎲', '𣎳', '𣎴', '𣎵', '𣎸', '𣏀', '𣏎', '𣏑', '𣏔', '𣏖', '𣏗', '𣏚', '𣏞', '𣏟',
+    '𣏠', '𣏡', '𣏶', '𣏷', '𣏺', '𣐂', '𣐃', '𣐊', '𣐋', '𣐎', '𣐏', '𣐑', '𣐓', '𣐤', '𣐫', '𣐬',
+    '𣐮', '𣐯', '𣐵', '𣐺', '𣐿', '𣑁', '𣑂', '𣑃', '𣑦', '𣑧', '𣑸', '𣑹', '𣑿', '𣒂', '𣒃', '𣒄',
+    '𣒅', '𣒆', '𣒇', '𣒷', '𣒸', '𣒹', '𣒻', '𣒼', '𣓀', '𣓃', '𣓅', '𣓆', '𣓈', '𣓉', '𣓊', '𣓋',
+    '𣓌', '𣓏', '𣓐', '𣓒', '𣓔', '𣓕', '𣓗', '𣓛', '𣓝', '𣔐', '𣔓', '𣔗', '𣔘', '𣔙', '𣔚', '𣔛',
+    '𣔫', '𣔬', '𣔮', '𣔯', '𣔱', '𣔲', '𣔵', '𣔶', '𣔷', '𣔸', '𣔹', '𣔺', '𣔻', '𣔼', '𣔽', '𣕁',
+    '𣕃', '𣕄', '𣕅', '𣕇', '𣕉', '𣕌', '𣕍', '𣕎', '𣕏', '𣕾', '𣖄', '𣖅', '𣖆', '𣖉', '𣖊', '𣖌',
+    '𣖐', '𣖣', '𣖤', '𣖨', '𣖪', '𣖫', '𣖬', '𣖭', '𣖮', '𣖯', '𣖰', '𣖱', '𣖳', '𣖵', '𣖷', '𣖸',
+    '𣖹', '𣖻', '𣖼', '𣖾', '𣖿', '𣗁', '𣗋', '𣗐', '𣗲', '𣗵', '𣗶', '𣗸', '𣗹', '𣗺', '𣗻', '𣘖',
+    '𣘗', '𣘘', '𣘙', '𣘚', '𣘛', '𣘝', '𣘞', '𣘟', '𣘠', '𣘣', '𣘤', '𣘥', '𣘦', '𣘧', '𣘨', '𣘪',
+    '𣘫', '𣘬', '𣘱', '𣘲', '𣘴', '𣘷', '𣘻', '𣙗', '𣙘', '𣙛', '𣙟', '𣙢', '𣙰', '𣙱', '𣙴', '𣙵',
+    '𣙶', '𣙷', '𣙻', '𣙼', '𣙿', '𣚀', '𣚁', '𣚃', '𣚄', '𣚆', '𣚇', '𣚋', '𣚌', '𣚎', '𣚏', '𣚐',
+    '𣚒', '𣚓', '𣚔', '𣚕', '𣚖', '𣚘', '𣚙', '𣚜', '𣚝', '𣚟', '𣚠', '𣚡', '𣚢', '𣚦', '𣚧', '𣚩',
+    '𣚪', '𣛓', '𣛔', '𣛕', '𣛗', '𣛚', '𣛫', '𣛱', '𣛴', '𣛵', '𣛶', '𣛷', '𣛸', '𣛹', '𣛺', '𣛻',
+    '𣛽', '𣛾', '𣜁', '𣜂', '𣜄', '𣜇', '𣜢', '𣜣', '𣜧', '𣜨', '𣜬', '𣜭', '𣜹', '𣜺', '𣜽', '𣝁',
+    '𣝂', '𣝅', '𣝇', '𣝈', '𣝉', '𣝋', '𣝌', '𣝍', '𣝎', '𣝏', '𣝐', '𣝑', '𣝒', '𣝕', '𣝗', '𣝚',
+    '𣝜', '𣝝', '𣝞', '𣝸', '𣝽', '𣞇', '𣞐', '𣞑', '𣞒', '𣞓', '𣞔', '𣞗', '𣞘', '𣞙', '𣞜', '𣞝',
+    '𣞟', '𣞰', '𣞴', '𣟀', '𣟁', '𣟄', '𣟆', '𣟇', '𣟈', '𣟉', '𣟊', '𣟋', '𣟌', '𣟏', '𣟐', '𣟑',
+    '𣟒', '𣟤', '𣟦', '𣟰', '𣟲', '𣟳', '𣟴', '𣟵', '𣟸', '𣟹', '𣟺', '𣟼', '𣟽', '𣠎', '𣠏', '𣠚',
+    '𣠜', '𣠞', '𣠠', '𣠭', '𣠷', '𣠸', '𣠹', '𣠺', '𣠼', '𣡉', '𣡊', '𣡋', '𣡎', '𣡕', '𣡗', '𣡞',
+    '𣡟', '𣡠', '𣡧', '𣡩', '𣡰', '𣡶', '𣡺', '𣡽', '𣡾', '𣢁', '𣢂', '𣢄', '𣢅', '𣢇', '𣢉', '𣢊',
+    '𣢋', '𣢌', '𣢍', '𣢎', '𣢏', '𣢐', '𣢑', '𣢒', '𣢓', '𣢕', '𣢖', '𣢗', '𣢚', '𣢛', '𣢜', '𣢝',
+    '𣢞', '𣢟', '𣢠', '𣢡', '𣢤', '𣢥', '𣢦', '𣢨', '𣢪', '𣢫', '𣢬', '𣢭', '𣢰', '𣢲', '𣢳', '𣢴',
+    '𣢶', '𣢷', '𣢺', '𣢻', '𣣈', '𣣉', '𣣊', '𣣋', '𣣌', '𣣎', '𣣏', '𣣑', '𣣒', '𣣚', '𣣜', '𣣝',
+    '𣣞', '𣣟', '𣣠', '𣣡', '𣣣', '𣣩', '𣣭', '𣣮', '𣣲', '𣣳', '𣣴', '𣣶', '𣣷', '𣣸', '𣣹', '𣣺',
+    '𣣿', '𣤁', '𣤃', '𣤇', '𣤈', '𣤊', '𣤋', '𣤌', '𣤎', '𣤖', '𣤘', '𣤡', '𣤢', '𣤨', '𣤩', '𣤪',
+    '𣤯', '𣤰', '𣤱', '𣤲', '𣤳', '𣤴', '𣤵', '𣤶', '𣤷', '𣤺', '𣥂', '𣥃', '𣥇', '𣥊', '𣥋', '𣥐',
+    '𣥞', '𣥡', '𣥣', '𣥤', '𣥥', '𣥨', '𣥮', '𣥯', '𣥰', '𣥳', '𣥷', '𣥹', '𣥺', '𣥻', '𣥼', '𣥾',
+    '𣦇', '𣦉', '𣦌', '𣦏', '𣦐', '𣦖', '𣦜', '𣦠', '𣦡', '𣦢', '𣦦', '𣦩', '𣦪', '𣦫', '𣦬', '𣦭',
+    '𣦯', '𣦵', '𣦶', '𣦼', '𣧂', '𣧃', '𣧄', '𣧈', '𣧊', '𣧌', '𣧍', '𣧎', '𣧏', '𣧒', '𣧖', '𣧗',
+    '𣧙', '𣧚', '𣧛', '𣧜', '𣧝', '𣧡', '𣧣', '𣧤', '𣧥', '𣧦', '𣧧', '𣧬', '𣧲', '𣧳', '𣧵', '𣧷',
+    '𣧹', '𣧺', '𣧼', '𣧽', '𣧾', '𣧿', '𣨀', '𣨂', '𣨄', '𣨅', '𣨇', '𣨉', '𣨊', '𣨋', '𣨍', '𣨎',
+    '𣨓', '𣨔', '𣨗', '𣨘', '𣨙', '𣨛', '𣨜', '𣨝', '𣨞', '𣨟', '𣨠', '𣨡', '𣨢', '𣨣', '𣨤', '𣨥',
+    '𣨪', '𣨫', '𣨲', '𣨳', '𣨶', '𣨺', '𣨻', '𣨾', '𣨿', '𣩀', '𣩄', '𣩅', '𣩈', '𣩏', '𣩑', '𣩒',
+    '𣩓', '𣩔', '𣩙', '𣩚', '𣩜', '𣩟', '𣩠', '𣩡', '𣩢', '𣩧', '𣩩', '𣩪', '𣩫', '𣩭', '𣩯', '𣩰',
+    '𣩱', '𣩴', '𣩷', '𣩹', '𣩺', '𣩽', '𣪀', '𣪁', '𣪂', '𣪄', '𣪇', '𣪉', '𣪌', '𣪐', '𣪕', '𣪠',
+    '𣪨', '𣪪', '𣪭', '𣪮', '𣪯', '𣪶', '𣪸', '𣪹', '𣫀', '𣫈', '𣫉', '𣫌', '𣫎', '𣫐', '𣫒', '𣫔',
+    '𣫙', '𣫜', '𣫝', '𣫣', '𣫥', '𣫧', '𣫨', '𣫪', '𣫬', '𣫱', '𣫳', '𣫴', '𣫹', '𣫻', '𣬂', '𣬆',
+    '𣬉', '𣬋', '𣬍', '𣬎', '𣬏', '𣬐', '𣬕', '𣬘', '𣬙', '𣬚', '𣬛', '𣬠', '𣬩', '𣬪', '𣬫', '𣬬',
+    '𣬮', '𣬴', '𣬵', '𣬶', '𣬷', '𣬸', '𣬹', '𣬺', '𣬻', '𣬼', '𣬽', '𣬿', '𣭀', '𣭇', '𣭖', '𣭗',
+    '𣭘', '𣭙', '𣭝', '𣭠', '𣭡', '𣭤', '𣭮', '𣭲', '𣭳', '𣭷', '𣭹', '𣭺', '𣭻', '𣮃', '𣮄', '𣮆',
+    '𣮈', '𣮉', '𣮊', '𣮌', '𣮍', '𣮎', '𣮏', '𣮐', '𣮠', '𣮡', '𣮢', '𣮦', '𣮧', '𣮪', '𣮫', '𣮬',
+    '𣮰', '𣮾', '𣮿', '𣯀', '𣯃', '𣯅', '𣯆', '𣯋', '𣯌', '𣯍', '𣯎', '𣯏', '𣯐', '𣯚', '𣯜', '𣯤',
+    '𣯧', '𣯨', '𣯩', '𣯪', '𣯬', '𣯭', '𣯯', '𣯱', '𣯴', '𣯵', '𣯸', '𣯹', '𣯻', '𣯼', '𣰃', '𣰇',
+    '𣰈', '𣰋', '𣰌', '𣰕', '𣰘', '𣰚', '𣰛', '𣰜', '𣰞', '𣰠', '𣰡', '𣰥', '𣰦', '𣰨', '𣰩', '𣰬',
+    '𣰶', '𣰷', '𣰺', '𣰻', '𣰼', '𣰿', '𣱀', '𣱂', '𣱈', '𣱉', '𣱍', '𣱐', '𣱓', '𣱗', '𣱜', '𣱦',
+    '𣱧', '𣱫', '𣱱', '𣱳', '𣱶', '𣱺', '𣱻', '𣱼', '𣱽', '𣲀', '𣲂', '𣲄', '𣲊', '𣲋', '𣲑', '𣲒',
+    '𣲓', '𣲖', '𣲗', '𣲘', '𣲜', '𣲡', '𣲢', '𣲥', '𣲱', '𣲲', '𣲳', '𣲵', '𣲶', '𣲷', '𣲽', '𣲾',
+    '𣳀', '𣳃', '𣳜', '𣳟', '𣳠', '𣳤', '𣳦', '𣳩', '𣳬', '𣳭', '𣳰', '𣳲', '𣴓', '𣴖', '𣴞', '𣴟',
+    '𣴢', '𣴣', '𣴥', '𣴨', '𣴪', '𣴼', '𣴽', '𣵞', '𣵟', '𣵢', '𣵱', '𣵷', '𣵸', '𣵺', '𣵻', '𣵼',
+    '𣶆', '𣶈', '𣶊', '𣶋', '𣶌', '𣶍', '𣶏', '𣶐', '𣶘', '𣶝', '𣶠', '𣶡', '𣶣', '𣶤', '𣶦', '𣶩',
+    '𣶫', '𣷠', '𣷡', '𣷥', '𣷩', '𣷪', '𣷫', '𣷶', '𣷾', '𣷿', '𣸀', '𣸃', '𣸄', '𣸆', '𣸇', '𣸉',
+    '𣸊', '𣸋', '𣸎', '𣸏', '𣸐', '𣸒', '𣸘', '𣸙', '𣸚', '𣸛', '𣸝', '𣸠', '𣸣', '𣹇', '𣹋', '𣹒',
+    '𣹚', '𣹜', '𣹝', '𣹞', '𣹟', '𣹠', '𣹡', '𣹥', '𣹧', '𣹩', '𣹪', '𣹬', '𣹮', '𣹯', '𣹱', '𣹲',
+    '𣹵', '𣹶', '𣺀', '𣺬', '𣺭', '𣺮', '𣺰', '𣺳', '𣺼', '𣺽', '𣻍', '𣻎', '𣻐', '𣻑', '𣻒', '𣻕',
+    '𣻖', '𣻘', '𣻚', '𣻛', '𣻜', '𣻟', '𣻠', '𣻡', '𣻢', '𣻩', '𣻬', '𣻮', '𣻱', '𣼚', '𣼞', '𣼟',
+    '𣼠', '𣼦', '𣼧', '𣽅', '𣽆', '𣽈', '𣽉', '𣽌', '𣽍', '𣽎', '𣽒', '𣽕', '𣽖', '𣽚', '𣽛', '𣽝',
+    '𣽟', '𣽡', '𣽤', '𣽥', '𣽦', '𣽨', '𣽫', '𣽭', '𣽮', '𣽯', '𣽰', '𣽳', '𣽴', '𣽷', '𣾤', '𣾦',
+    '𣿅', '𣿆', '𣿇', '𣿈', '𣿉', '𣿊', '𣿌', '𣿎', '𣿐', '𣿒', '𣿓', '𣿝', '𣿞', '𣿟', '𣿣', '𣿤',
+    '𤀃', '𤀉', '𤀊', '𤀝', '𤀣', '𤀤', '𤀦', '𤀨', '𤀩', '𤀪', '𤀫', '𤀰', '𤀲', '𤀷', '𤁡', '𤁢',
+    '𤁣', '𤁥', '𤁦', '𤁧', '𤁪', '𤁫', '𤁮', '𤁯', '𤁰', '𤁱', '𤁳', '𤁵', '𤁹', '𤁼', '𤁽', '𤁿',
+    '𤂀', '𤂁', '𤂃', '𤂆', '𤂠', '𤂤', '𤂲', '𤂶', '𤂷', '𤂹', '𤂺', '𤂼', '𤂿', '𤃀', '𤃨', '𤃩',
+    '𤃪', '𤃫', '𤃭', '𤃮', '𤃲', '𤃵', '𤃶', '𤃷', '𤃺', '𤃻', '𤃼', '𤃽', '𤄎', '𤄏', '𤄑', '𤄒',
+    '𤄓', '𤄔', '𤄖', '𤄘', '𤄛', '𤄜', '𤄥', '𤄧', '𤄫', '𤄬', '𤄶', '𤄷', '𤄺', '𤄼', '𤄽', '𤅊',
+    '𤅋', '𤅎', '𤅐', '𤅑', '𤅒', '𤅣', '𤅱', '𤅲', '𤅸', '𤅹', '𤆀', '𤆁', '𤆂', '𤆄', '𤆍', '𤆏',
+    '𤆑', '𤆒', '𤆙', '𤆜', '𤆝', '𤆞', '𤆟', '𤆡', '𤆮', '𤆰', '𤆳', '𤆴', '𤆵', '𤆼', '𤆾', '𤇃',
+    '𤇄', '𤇜', '𤇞', '𤇠', '𤇯', '𤇰', '𤇳', '𤇴', '𤇾', '𤈔', '𤈙', '𤈥', '𤈦', '𤈧', '𤈩', '𤈶',
+    '𤈷', '𤉌', '𤉍', '𤉎', '𤉏', '𤉐', '𤉤', '𤉥', '𤉦', '𤉧', '𤉨', '𤉪', '𤉫', '𤊲', '𤊴', '𤊵',
+    '𤊶', '𤊻', '𤊼', '𤊽', '𤊾', '𤊿', '𤋂', '𤋏', '𤋨', '𤋫', '𤋭', '𤋰', '𤌂', '𤌃', '𤌇', '𤌊',
+    '𤌋', '𤌌', '𤌍', '𤌎', '𤌏', '𤌐', '𤌔', '𤌷', '𤌹', '𤌾', '𤌿', '𤍀', '𤍁', '𤍐', '𤍒', '𤍓',
+    '𤍕', '𤍖', '𤍜', '𤍠', '𤍤', '𤍽', '𤎄', '𤎋', '𤎗', '𤎘', '𤎣', '𤎤', '𤎥', '𤎧', '𤎭', '𤎯',
+    '𤎱', '𤎲', '𤏗', '𤏘', '𤏜', '𤏱', '𤏲', '𤏶', '𤏸', '𤏽', '𤏿', '𤐀', '𤐔', '𤐙', '𤐣', '𤐩',
+    '𤐫', '𤐰', '𤐱', '𤐲', '𤑃', '𤑄', '𤑓', '𤑕', '𤑗', '𤑣', '𤑦', '𤑪', '𤑳', '𤑷', '𤑸', '𤑹',
+    '𤑿', '𤒔', '𤒕', '𤒝', '𤒦', '𤒻', '𤓑', '𤓔', '𤓕', '𤓚', '𤓝', '𤓤', '𤓦', '𤓯', '𤓵', '𤓷',
+    '𤓾', '𤓿', '𤔀', '𤔉', '𤔋', '𤔔', '𤔖', '𤔙', '𤔚', '𤔝', '𤔟', '𤔣', '𤔨', '𤔫', '𤔶', '𤕀',
+    '𤕄', '𤕇', '𤕊', '𤕒', '𤕘', '𤕚', '𤕛', '𤕝', '𤕞', '𤕟', '𤕢', '𤕦', '𤕭', '𤕯', '𤕷', '𤕽',
+    '𤕾', '𤖀', '𤖆', '𤖇', '𤖒', '𤖓', '𤖖', '𤖗', '𤖘', '𤖛', '𤖝', '𤖞', '𤖢', '𤖦', '𤖪', '𤖬',
+    '𤖭', '𤖮', '𤖰', '𤖱', '𤖳', '𤖵', '𤖷', '𤖺', '𤖻', '𤖼', '𤖽', '𤖿', '𤗀', '𤗁', '𤗃', '𤗇',
+    '𤗈', '𤗍', '𤗎', '𤗏', '𤗓', '𤗘', '𤗙', '𤗚', '𤗛', '𤗜', '𤗞', '𤗢', '𤗨', '𤗪', '𤗫', '𤗬',
+    '𤗭', '𤗯', '𤗳', '𤗴', '𤗵', '𤗷', '𤗸', '𤗺', '𤗻', '𤗾', '𤗿', '𤘀', '𤘃', '𤘅', '𤘆', '𤘇',
+    '𤘊', '𤘌', '𤘔', '𤘖', '𤘘', '𤘛', '𤘜', '𤘝', '𤘟', '𤘠', '𤘡', '𤘢', '𤘣', '𤘤', '𤘦', '𤘧',
+    '𤘲', '𤘵', '𤘶', '𤘷', '𤘸', '𤘹', '𤘺', '𤘻', '𤘽', '𤘾', '𤙅', '𤙇', '𤙋', '𤙌', '𤙎', '𤙏',
+    '𤙒', '𤙔', '𤙕', '𤙖', '𤙘', '𤙛', '𤙝', '𤙞', '𤙟', '𤙡', '𤙣', '𤙤', '𤙩', '𤙭', '𤙰', '𤙱',
+    '𤙴', '𤙵', '𤙼', '𤙽', '𤙾', '𤚀', '𤚅', '𤚉', '𤚊', '𤚍', '𤚎', '𤚏', '𤚓', '𤚔', '𤚚', '𤚜',
+    '𤚝', '𤚟', '𤚡', '𤚢', '𤚣', '𤚤', '𤚨', '𤚩', '𤚪', '𤚫', '𤚬', '𤚭', '𤚯', '𤚰', '𤚱', '𤚵',
+    '𤚼', '𤚽', '𤛁', '𤛄', '𤛊', '𤛍', '𤛎', '𤛏', '𤛐', '𤛑', '𤛖', '𤛗', '𤛘', '𤛙', '𤛚', '𤛛',
+    '𤛜', '𤛝', '𤛢', '𤛣', '𤛥', '𤛦', '𤛧', '𤛪', '𤛬', '𤛭', '𤛮', '𤛱', '𤛲', '𤛷', '𤛻', '𤜁',
+    '𤜂', '𤜄', '𤜇', '𤜉', '𤜊', '𤜋', '𤜍', '𤜓', '𤜔', '𤜕', '𤜖', '𤜘', '𤜙', '𤜜', '𤜝', '𤜡',
+    '𤜢', '𤜣', '𤜤', '𤜧', '𤜫', '𤜯', '𤜰', '𤜱', '𤜲', '𤜵', '𤜷', '𤜹', '𤜻', '𤜼', '𤜾', '𤝂',
+    '𤝄', '𤝅', '𤝇', '𤝈', '𤝉', '𤝍', '𤝎', '𤝐', '𤝒', '𤝓', '𤝔', '𤝕', '𤝖', '𤝘', '𤝚', '𤝛',
+    '𤝞', '𤝟', '𤝧', '𤝬', '𤝭', '𤝮', '𤝯', '𤝰', '𤝱', '𤝳', '𤝷', '𤝸', '𤝹', '𤝻', '𤝽', '𤝿',
+    '𤞂', '𤞇', '𤞈', '𤞉', '𤞊', '𤞋', '𤞌', '𤞍', '𤞗', '𤞚', '𤞛', '𤞞', '𤞟', '𤞠', '𤞡', '𤞢',
+    '𤞤', '𤞥', '𤞮', '𤞰', '𤞲', '𤞳', '𤞴', '𤞶', '𤞿', '𤟀', '𤟃', '𤟅', '𤟆', '𤟇', '𤟉', '𤟍',
+    '𤟎', '𤟑', '𤟓', '𤟖', '𤟘', '𤟚', '𤟝', '𤟞', '𤟟', '𤟠', '𤟢', '𤟣', '𤟤', '𤟥', '𤟦', '𤟧',
+    '𤟪', '𤟷', '𤟸', '𤟼', '𤟾', '𤟿', '𤠀', '𤠇', '𤠋', '𤠌', '𤠍', '𤠎', '𤠐', '𤠓', '𤠖', '𤠘',
+    '𤠙', '𤠚', '𤠛', '𤠝', '𤠟', '𤠠', '𤠪', '𤠫', '𤠭', '𤠯', '𤠶', '𤠺', '𤠼', '𤠽', '𤠿', '𤡂',
+    '𤡃', '𤡆', '𤡇', '𤡏', '𤡐', '𤡓', '𤡔', '𤡕', '𤡖', '𤡝', '𤡟', '𤡡', '𤡢', '𤡣', '𤡤', '𤡥',
+    '𤡧', '𤡨', '𤡪', '𤡬', '𤡭', '𤡮', '𤡯', '𤡰', '𤡱', '𤡲', '𤡳', '𤡶', '𤡾', '𤡿', '𤢀', '𤢁',
+    '𤢂', '𤢄', '𤢊', '𤢋', '𤢎', '𤢏', '𤢐', '𤢒', '𤢓', '𤢕', '𤢖', '𤢗', '𤢘', '𤢟', '𤢣', '𤢦',
+    '𤢨', '𤢪', '𤢭', '𤢮', '𤢴', '𤢵', '𤢶', '𤢹', '𤢺', '𤢼', '𤣃', '𤣅', '𤣆', '𤣌', '𤣎', '𤣑',
+    '𤣘', '𤣙', '𤣞', '𤣟', '𤣠', '𤣨', '𤣬', '𤣮', '𤣯', '𤣰', '𤣲', '𤣵', '𤣶', '𤣸', '𤣹', '𤣻',
+    '𤣼', '𤤊', '𤤋', '𤤐', '𤤑', '𤤒', '𤤦', '𤤧', '𤤩', '𤤫', '𤤬', '𤤮', '𤤱', '𤤲', '𤤶', '𤤷',
+    '𤤸', '𤤺', '𤥒', '𤥓', '𤥔', '𤥗', '𤥙', '𤥭', '𤥮', '𤥰', '𤥷', '𤥽', '𤥿', '𤦀', '𤦃', '𤦄',
+    '𤦤', '𤦦', '𤦪', '𤦮', '𤧕', '𤧘', '𤧙', '𤧚', '𤧛', '𤧜', '𤧠', '𤧣', '𤧩', '𤧫', '𤧭', '𤨍',
+    '𤨎', '𤨐', '𤨑', '𤨖', '𤨗', '𤨙', '𤨶', '𤨻', '𤩂', '𤩄', '𤩨', '𤩩', '𤩪', '𤩭', '𤩮', '𤩱',
+    '𤩲', '𤩽', '𤪄', '𤪆', '𤪋', '𤪌', '𤪍', '𤪎', '𤪡', '𤪪', '𤪮', '𤫉', '𤫕', '𤫝', '𤫞', '𤫣',
+    '𤫧', '𤫨', '𤫩', '𤫫', '𤫬', '𤫱', '𤫲', '𤫵', '𤫶', '𤫷', '𤫹', '𤫺', '𤫻', '𤬁', '𤬂', '𤬃',
+    '𤬌', '𤬏', '𤬐', '𤬓', '𤬔', '𤬕', '𤬖', '𤬘', '𤬛', '𤬝', '𤬠', '𤬥', '𤬦', '𤬧', '𤬨', '𤬩',
+    '𤬪', '𤬫', '𤬭', '𤬯', '𤬱', '𤬳', '𤬵', '𤬷', '𤬾', '𤬿', '𤭂', '𤭈', '𤭉', '𤭌', '𤭍', '𤭑',
+    '𤭒', '𤭔', '𤭙', '𤭛', '𤭜', '𤭞', '𤭟', '𤭠', '𤭢', '𤭧', '𤭩', '𤭫', '𤭬', '𤭱', '𤭴', '𤭻',
+    '𤭼', '𤭽', '𤮆', '𤮊', '𤮋', '𤮌', '𤮍', '𤮎', '𤮏', '𤮐', '𤮕', '𤮘', '𤮙', '𤮚', '𤮜', '𤮧',
+    '𤮩', '𤮪', '𤮭', '𤮯', '𤮰', '𤮱', '𤮳', '𤮷', '𤮸', '𤮼', '𤮽', '𤯄', '𤯇', '𤯌', '𤯐', '𤯖',
+    '𤯚', '𤯜', '𤯡', '𤯥', '𤯷', '𤯸', '𤯻', '𤰂', '𤰅', '𤰈', '𤰌', '𤰑', '𤰙', '𤰚', '𤰝', '𤰟',
+    '𤰠', '𤰡', '𤰦', '𤰪', '𤰬', '𤰳', '𤱃', '𤱅', '𤱆', '𤱇', '𤱋', '𤱌', '𤱍', '𤱎', '𤱐', '𤱛',
+    '𤱜', '𤱝', '𤱟', '𤱠', '𤱡', '𤱣', '𤱧', '𤱨', '𤱩', '𤱴', '𤱵', '𤱷', '𤱸', '𤱹', '𤱺', '𤱽',
+    '𤱾', '𤲉', '𤲑', '𤲓', '𤲕', '𤲖', '𤲗', '𤲚', '𤲨', '𤲩', '𤲬', '𤲭', '𤲰', '𤲶', '𤲸', '𤲺',
+    '𤳂', '𤳃', '𤳅', '𤳈', '𤳉', '𤳊', '𤳎', '𤳓', '𤳕', '𤳖', '𤳘', '𤳝', '𤳠', '𤳤', '𤳥', '𤳦',
+    '𤳪', '𤳭', '𤳯', '𤳳', '𤳴', '𤳵', '𤴀', '𤴍', '𤴓', '𤴘', '𤴙', '𤴛', '𤴟', '𤴢', '𤴣', '𤴥',
+    '𤴦', '𤴧', '𤴨', '𤴪', '𤴯', '𤴱', '𤴻', '𤴼', '𤴽', '𤴾', '𤴿', '𤵀', '𤵂', '𤵊', '𤵋', '𤵎',
+    '𤵐', '𤵒', '𤵘', '𤵛', '𤵝', '𤵞', '𤵟', '𤵠', '𤵥', '𤵦', '𤵬', '𤵭', '𤵮', '𤵯', '𤵸', '𤵹',
+    '𤵻', '𤵼', '𤵽', '𤵾', '𤶀', '𤶁', '𤶈', '𤶋', '𤶓', '𤶕', '𤶖', '𤶘', '𤶚', '𤶛', '𤶜', '𤶠',
+    '𤶤', '𤶧', '𤶮', '𤶰', '𤶱', '𤶲', '𤶳', '𤶴', '𤷀', '𤷁', '𤷂', '𤷃', '𤷄', '𤷅', '𤷆', '𤷇',
+    '𤷈', '𤷉', '𤷌', '𤷍', '𤷎', '𤷏', '𤷑', '𤷒', '𤷓', '𤷔', '𤷕', '𤷖', '𤷗', '𤷘', '𤷙', '𤷟',
+    '𤷡', '𤷢', '𤷤', '𤷦', '𤷹', '𤷻', '𤷼', '𤷽', '𤷾', '𤷿', '𤸀', '𤸁', '𤸅', '𤸆', '𤸈', '𤸉',
+    '𤸊', '𤸎', '𤸏', '𤸑', '𤸒', '𤸓', '𤸕', '𤸖', '𤸗', '𤸡', '𤸤', '𤸦', '𤸪', '𤸫', '𤸬', '𤸮',
+    '𤸯', '𤸱', '𤸲', '𤸳', '𤸴', '𤸵', '𤸷', '𤸸', '𤸹', '𤸻', '𤸼', '𤹇', '𤹈', '𤹊', '𤹋', '𤹌',
+    '𤹎', '𤹛', '𤹜', '𤹝', '𤹞', '𤹡', '𤹢', '𤹣', '𤹤', '𤹥', '𤹦', '𤹧', '𤹨', '𤹩', '𤹪', '𤹲',
+    '𤹴', '𤹵', '𤹷', '𤹸', '𤺀', '𤺃', '𤺄', '𤺅', '𤺇', '𤺉', '𤺊', '𤺌', '𤺎', '𤺏', '𤺐', '𤺓',
+    '𤺔', '𤺕', '𤺖', '𤺗', '𤺪', '𤺫', '𤺷', '𤺺', '𤺻', '𤺼', '𤺾', '𤻂', '𤻃', '𤻄', '𤻅', '𤻆',
+    '𤻇', '𤻌', '𤻖', '𤻘', '𤻙', '𤻜', '𤻝', '𤻢', '𤻤', '𤻦', '𤻱', '𤻶', '𤻷', '𤻿', '𤼀', '𤼁',
+    '𤼂', '𤼃', '𤼅', '𤼋', '𤼌', '𤼍', '𤼏', '𤼐', '𤼒', '𤼗', '𤼘', '𤼙', '𤼚', '𤼜', '𤼢', '𤼮',
+    '𤼳', '𤼶', '𤼺', '𤽁', '𤽃', '𤽅', '𤽉', '𤽊', '𤽌', '𤽓', '𤽕', '𤽣', '𤽥', '𤽦', '𤽯', '𤽱',
+    '𤽲', '𤽳', '𤽹', '𤽺', '𤽼', '𤽿', '𤾀', '𤾄', '𤾅', '𤾈', '𤾉', '𤾊', '𤾑', '𤾒', '𤾛', '𤾝',
+    '𤾠', '𤾢', '𤾣', '𤾥', '𤾦', '𤾫', '𤾬', '𤾭', '𤾲', '𤾵', '𤾺', '𤾿', '𤿀', '𤿄', '𤿅', '𤿆',
+    '𤿇', '𤿈', '𤿋', '𤿎', '𤿏', '𤿐', '𤿑', '𤿒', '𤿓', '𤿕', '𤿖', '𤿗', '𤿘', '𤿙', '𤿚', '𤿟',
+    '𤿠', '𤿡', '𤿣', '𤿧', '𤿨', '𤿩', '𤿫', '𤿭', '𤿳', '𤿴', '𤿵', '𤿶', '𤿷', '𤿼', '𤿽', '𤿾',
+    '𥀁', '𥀂', '𥀃', '𥀈', '𥀉', '𥀎', '𥀏', '𥀐', '𥀓', '𥀔', '𥀖', '𥀘', '𥀙', '𥀛', '𥀝', '𥀠',
+    '𥀣', '𥀥', '𥀫', '𥀬', '𥀭', '𥀮', '𥀰', '𥀱', '𥀲', '𥀵', '𥀹', '𥀺', '𥀻', '𥀾', '𥁁', '𥁂',
+    '𥁃', '𥁆', '𥁇', '𥁎', '𥁐', '𥁑', '𥁒', '𥁕', '𥁟', '𥁠', '𥁡', '𥁢', '𥁮', '𥁯', '𥁲', '𥁵',
+    '𥁸', '𥁹', '𥂁', '𥂂', '𥂃', '𥂋', '𥂒', '𥂓', '𥂙', '𥂢', '𥂣', '𥂤', '𥂥', '𥂦', '𥂧', '𥂨',
+    '𥂰', '𥂵', '𥂸', '𥃀', '𥃌', '𥃔', '𥃕', '𥃟', '𥃠', '𥃣', '𥃤', '𥃧', '𥃨', '𥃩', '𥃪', '𥃫',
+    '𥃮', '𥃰', '𥃴', '𥃵', '𥃸', '𥃹', '𥃺', '𥃽', '𥃾', '𥃿', '𥄁', '𥄇', '𥄈', '𥄉', '𥄋', '𥄍',
+    '𥄎', '𥄑', '𥄒', '𥄓', '𥄔', '𥄕', '𥄖', '𥄗', '𥄘', '𥄛', '𥄜', '𥄝', '𥄦', '𥄨', '𥄱', '𥄲',
+    '𥄴', '𥄵', '𥄶', '𥄷', '𥄸', '𥄹', '𥄻', '𥄼', '𥄽', '𥄿', '𥅁', '𥅄', '𥅑', '𥅒', '𥅓', '𥅔',
+    '𥅗', '𥅘', '𥅞', '𥅟', '𥅠', '𥅡', '𥅦', '𥅧', '𥅨', '𥅪', '𥅬', '𥅴', '𥅵', '𥅷', '𥅸', '𥅺',
+    '𥅻', '𥆅', '𥆆', '𥆉', '𥆌', '𥆑', '𥆔', '𥆖', '𥆘', '𥆙', '𥆚', '𥆛', '𥆜', '𥆝', '𥆞', '𥆟',
+    '𥆡', '𥆣', '𥆥', '𥆦', '𥆧', '𥆯', '𥆲', '𥆶', '𥆷', '𥆸', '𥇄', '𥇆', '𥇇', '𥇉', '𥇌', '𥇍',
+    '𥇎', '𥇏', '𥇑', '𥇒', '𥇓', '𥇔', '𥇕', '𥇖', '𥇗', '𥇘', '𥇙', '𥇚', '𥇛', '𥇜', '𥇞', '𥇟',
+    '𥇠', '𥇢', '𥇭', '𥇯', '𥇰', '𥇱', '𥇲', '𥇳', '𥇿', '𥈂', '𥈄', '𥈆', '𥈇', '𥈈', '𥈉', '𥈊',
+    '𥈋', '𥈏', '𥈑', '𥈒', '𥈓', '𥈕', '𥈖', '𥈗', '𥈘', '𥈙', '𥈚', '𥈛', '𥈜', '𥈟', '𥈬', '𥈮',
+    '𥈷', '𥈸', '𥈻', '𥈼', '𥈽', '𥈾', '𥈿', '𥉀', '𥉁', '𥉃', '𥉄', '𥉅', '𥉆', '𥉇', '𥉈', '𥉊',
+    '𥉋', '𥉌', '𥉐', '𥉑', '𥉒', '𥉓', '𥉔', '𥉕', '𥉖', '𥉘', '𥉙', '𥉜', '𥉝', '𥉟', '𥉰', '𥉴',
+    '𥉵', '𥉶', '𥉷', '𥉸', '𥉺', '𥉻', '𥉼', '𥉽', '𥉾', '𥉿', '𥊀', '𥊇', '𥊈', '𥊉', '𥊊', '𥊑',
+    '𥊒', '𥊓', '𥊔', '𥊧', '𥊪', '𥊫', '𥊬', '𥊭', '𥊮', '𥊯', '𥊴', '𥊶', '𥊸', '𥊼', '𥊽', '𥊾',
+    '𥊿', '𥋁', '𥋌', '𥋙', '𥋚', '𥋛', '𥋜', '𥋝', '𥋟', '𥋡', '𥋣', '𥋤', '𥋥', '𥋮', '𥋿', '𥌀',
+    '𥌁', '𥌂', '𥌃', '𥌄', '𥌅', '𥌆', '𥌈', '𥌊', '𥌍', '𥌏', '𥌐', '𥌘', '𥌚', '𥌛', '𥌞', '𥌟',
+    '𥌤', '𥌨', '𥌩', '𥌪', '𥌭', '𥌮', '𥌯', '𥌰', '𥌱', '𥌺', '𥌻', '𥌼', '𥌽', '𥌾', '𥌿', '𥍀',
+    '𥍁', '𥍅', '𥍆', '𥍉', '𥍋', '𥍓', '𥍔', '𥍚', '𥍝', '𥍞', '𥍟', '𥍣', '𥍨', '𥍪', '𥍫', '𥍬',
+    '𥍭', '𥍮', '𥍯', '𥍱', '𥍲', '𥍳', '𥍴', '𥍷', '𥍸', '𥍹', '𥍺', '𥍻', '𥍼', '𥎀', '𥎂', '𥎃',
+    '𥎅', '𥎆', '𥎈', '𥎊', '𥎋', '𥎍', '𥎎', '𥎐', '𥎑', '𥎒', '𥎓', '𥎔', '𥎕', '𥎖', '𥎗', '𥎘',
+    '𥎛', '𥎜', '𥎟', '𥎡', '𥎢', '𥎣', '𥎤', '𥎮', '𥎰', '𥎱', '𥎸', '𥎹', '𥎺', '𥏄', '𥏅', '𥏇',
+    '𥏈', '𥏊', '𥏎', '𥏒', '𥏓', '𥏕', '𥏖', '𥏘', '𥏙', '𥏜', '𥏝', '𥏠', '𥏤', '𥏥', '𥏨', '𥏪',
+    '𥏫', '𥏮', '𥏯', '𥏰', '𥏷', '𥏹', '𥐀', '𥐁', '𥐂', '𥐊', '𥐑', '𥐓', '𥐕', '𥐘', '𥐙', '𥐚',
+    '𥐞', '𥐟', '𥐩', '𥐬', '𥐹', '𥐻', '𥐽', '𥐿', '𥑅', '𥑇', '𥑈', '𥑋', '𥑎', '𥑏', '𥑐', '𥑑',
+    '𥑒', '𥑓', '𥑔', '𥑡', '𥑢', '𥑣', '𥑥', '𥑪', '𥑫', '𥑯', '𥑰', '𥑱', '𥑳', '𥑴', '𥑵', '𥑶',
+    '𥑸', '𥑹', '𥑺', '𥑻', '𥑼', '𥑽', '𥑾', '𥑿', '𥒀', '𥒁', '𥒓', '𥒗', '𥒘', '𥒛', '𥒞', '𥒠',
+    '𥒡', '𥒢', '𥒧', '𥒨', '𥒫', '𥒬', '𥒭', '𥒮', '𥒰', '𥒱', '𥒲', '𥒳', '𥒵', '𥒶', '𥒾', '𥓃',
+    '𥓆', '𥓇', '𥓈', '𥓌', '𥓍', '𥓐', '𥓑', '𥓒', '𥓖', '𥓬', '𥓰', '𥓻', '𥓾', '𥓿', '𥔀', '𥔁',
+    '𥔂', '𥔄', '𥔇', '𥔊', '𥔑', '𥔡', '𥔢', '𥔣', '𥔩', '𥔪', '𥔫', '𥔭', '𥔮', '𥔯', '𥔱', '𥔲',
+    '𥔻', '𥔽', '𥕀', '𥕁', '𥕂', '𥕇', '𥕉', '𥕍', '𥕎', '𥕏', '𥕐', '𥕑', '𥕓', '𥕖', '𥕗', '𥕘',
+    '𥕙', '𥕢', '𥕣', '𥕦', '𥕰', '𥕱', '𥕲', '𥕵', '𥕶', '𥕸', '𥕹', '𥕻', '𥕼', '𥕽', '𥕾', '𥕿',
+    '𥖍', '𥖗', '𥖘', '𥖙', '𥖜', '𥖝', '𥖞', '𥖠', '𥖨', '𥖪', '𥖫', '𥖬', '𥖵', '𥖶', '𥖻', '𥖼',
+    '𥖾', '𥖿', '𥗀', '𥗁', '𥗊', '𥗒', '𥗓', '𥗙', '𥗚', '𥗝', '𥗟', '𥗦', '𥗧', '𥗪', '𥗫', '𥗬',
+    '𥗭', '𥗮', '𥗵', '𥗶', '𥗺', '𥗽', '𥗿', '𥘁', '𥘄', '𥘊', '𥘋', '𥘌', '𥘏', '𥘒', '𥘠', '𥘡',
+    '𥘤', '𥘥', '𥘦', '𥘫', '𥘬', '𥘮', '𥙀', '𥙁', '𥙆', '𥙇', '𥙉', '𥙋', '𥙎', '𥙡', '𥙣', '𥙦',
+    '𥙨', '𥙬', '𥙰', '𥙾', '𥙿', '𥚂', '𥚉', '𥚊', '𥚚', '𥚛', '𥚠', '𥚦', '𥚩', '𥚾', '𥚿', '𥛂',
+    '𥛅', '𥛑', '𥛘', '𥛚', '𥛜', '𥛝', '𥛞', '𥛟', '𥛥', '𥛧', '𥛨', '𥛩', '𥛪', '𥛫', '𥛯', '𥛰',
+    '𥛱', '𥜃', '𥜄', '𥜌', '𥜏', '𥜐', '𥜒', '𥜓', '𥜖', '𥜙', '𥜚', '𥜥', '𥜦', '𥜬', '𥜭', '𥜮',
+    '𥜰', '𥜲', '𥜴', '𥜵', '𥜶', '𥝀', '𥝂', '𥝄', '𥝊', '𥝋', '𥝌', '𥝍', '𥝑', '𥝒', '𥝔', '𥝕',
+    '𥝖', '𥝘', '𥝟', '𥝠', '𥝢', '𥝥', '𥝦', '𥝧', '𥝨', '𥝮', '𥝸', '𥝹', '𥝾', '𥝿', '𥞁', '𥞃',
+    '𥞄', '𥞅', '𥞊', '𥞍', '𥞎', '𥞏', '𥞒', '𥞘', '𥞚', '𥞛', '𥞜', '𥞧', '𥞩', '𥞪', '𥞯', '𥞲',
+    '𥞴', '𥞵', '𥞺', '𥞼', '𥟃', '𥟅', '𥟍', '𥟎', '𥟒', '𥟓', '𥟔', '𥟕', '𥟗', '𥟘', '𥟚', '𥟪',
+    '𥟶', '𥟽', '𥟾', '𥟿', '𥠀', '𥠁', '𥠃', '𥠄', '𥠅', '𥠈', '𥠉', '𥠊', '𥠋', '𥠍', '𥠛', '𥠜',
+    '𥠡', '𥠩', '𥠱', '𥠲', '𥠳', '𥠴', '𥠶', '𥠷', '𥠹', '𥠺', '𥠽', '𥡀', '𥡁', '𥡒', '𥡜', '𥡝',
+    '𥡟', '𥡠', '𥡢', '𥡣', '𥡤', '𥡦', '𥡧', '𥡪', '𥡬', '𥡭', '𥡮', '𥡴', '𥡽', '𥢇', '𥢊', '𥢌',
+    '𥢍', '𥢎', '𥢏', '𥢐', '𥢑', '𥢒', '𥢔', '𥢟', '𥢢', '𥢦', '𥢧', '𥢮', '𥢲', '𥢶', '𥢷', '𥢸',
+    '𥢹', '𥢻', '𥢽', '𥣆', '𥣈', '𥣋', '𥣌', '𥣗', '𥣘', '𥣙', '𥣛', '𥣝', '𥣟', '𥣤', '𥣥', '𥣩',
+    '𥣫', '𥣬', '𥣮', '𥣯', '𥣰', '𥣸', '𥣹', '𥣺', '𥤂', '𥤊', '𥤋', '𥤐', '𥤗', '𥤘', '𥤜', '𥤞',
+    '𥤟', '𥤣', '𥤤', '𥤥', '𥤦', '𥤨', '𥤩', '𥤱', '𥤳', '𥤴', '𥤵', '𥤷', '𥤸', '𥤹', '𥤺', '𥤻',
+    '𥤼', '𥥅', '𥥈', '𥥊', '𥥋', '𥥌', '𥥍', '𥥏', '𥥛', '𥥝', '𥥟', '𥥠', '𥥡', '𥥥', '𥥧', '𥥩',
+    '𥥱', '𥥳', '𥥴', '𥥵', '𥥶', '𥥷', '𥥸', '𥥹', '𥥽', '𥥾', '𥥿', '𥦀', '𥦁', '𥦃', '𥦅', '𥦊',
+    '𥦌', '𥦍', '𥦔', '𥦖', '𥦘', '𥦜', '𥦝', '𥦞', '𥦟', '𥦢', '𥦣', '𥦥', '𥦨', '𥦶', '𥦷', '𥧂',
+    '𥧆', '𥧇', '𥧋', '𥧑', '𥧒', '𥧓', '𥧕', '𥧙', '𥧚', '𥧡', '𥧣', '𥧤', '𥧧', '𥧫', '𥧬', '𥧭',
+    '𥧮', '𥧰', '𥧱', '𥧲', '𥧴', '𥧷', '𥧻', '𥨌', '𥨍', '𥨎', '𥨐', '𥨒', '𥨕', '𥨜', '𥨝', '𥨪',
+    '𥨳', '𥨻', '𥨿', '𥩀', '𥩌', '𥩒', '𥩔', '𥩖', '𥩗', '𥩙', '𥩝', '𥩡', '𥩢', '𥩣', '𥩤', '𥩱',
+    '𥩲', '𥪀', '𥪁', '𥪂', '𥪊', '𥪋', '𥪌', '𥪍', '𥪏', '𥪘', '𥪚', '𥪢', '𥪦', '𥪧', '𥪫', '𥪯',
+    '𥪱', '𥪴', '𥪵', '𥪻', '𥪼', '𥫂', '𥫃', '𥫅', '𥫋', '𥫎', '𥫖', '𥫙', '𥫛', '𥫜', '𥫝', '𥫞',
+    '𥫟', '𥫢', '𥫬', '𥫯', '𥫰', '𥫱', '𥫳', '𥫴', '𥫵', '𥫶', '𥫷', '𥫸', '𥫹', '𥫼', '𥫽', '𥬀',
+    '𥬆', '𥬈', '𥬉', '𥬍', '𥬎', '𥬐', '𥬒', '𥬓', '𥬔', '𥬕', '𥬙', '𥬜', '𥬝', '𥬞', '𥬠', '𥬪',
+    '𥬫', '𥬬', '𥬭', '𥬮', '𥬯', '𥬰', '𥬱', '𥬲', '𥬳', '𥬴', '𥬼', '𥭋', '𥭌', '𥭐', '𥭑', '𥭒',
+    '𥭓', '𥭔', '𥭖', '𥭘', '𥭙', '𥭚', '𥭜', '𥭝', '𥭞', '𥭠', '𥭡', '𥭢', '𥭥', '𥭫', '𥭬', '𥭭',
+    '𥭮', '𥮍', '𥮎', '𥮏', '𥮐', '𥮒', '𥮕', '𥮖', '𥮗', '𥮘', '𥮜', '𥮝', '𥮣', '𥮤', '𥮥', '𥮧',
+    '𥮨', '𥮪', '𥮬', '𥮯', '𥮾', '𥯃', '𥯑', '𥯔', '𥯕', '𥯖', '𥯘', '𥯙', '𥯚', '𥯛', '𥯜', '𥯝',
+    '𥯞', '𥯟', '𥯡', '𥯢', '𥯤', '𥯥', '𥯦', '𥯨', '𥯩', '𥯪', '𥯬', '𥯮', '𥯳', '𥯶', '𥯸', '𥯾',
+    '𥰛', '𥰜', '𥰝', '𥰞', '𥰟', '𥰠', '𥰡', '𥰢', '𥰣', '𥰥', '𥰦', '𥰧', '𥰨', '𥰪', '𥰭', '𥰮',
+    '𥰰', '𥰱', '𥰲', '𥰳', '𥰶', '𥰻', '𥰼', '𥰾', '𥱀', '𥱁', '𥱂', '𥱃', '𥱧', '𥱨', '𥱵', '𥱶',
+    '𥱷', '𥱸', '𥱹', '𥱺', '𥱻', '𥱼', '𥱽', '𥲀', '𥲁', '𥲂', '𥲃', '𥲄', '𥲅', '𥲆', '𥲇', '𥲈',
+    '𥲉', '𥲊', '𥲋', '𥲍', '𥲎', '𥲏', '𥲐', '𥲑', '𥲕', '𥲗', '𥲚', '𥲛', '𥲜', '𥲝', '𥲟', '𥲠',
+    '𥲡', '𥲢', '𥲣', '𥲥', '𥲦', '𥲧', '𥲪', '𥳆', '𥳇', '𥳈', '𥳉', '𥳋', '𥳌', '𥳍', '𥳎', '𥳏',
+    '𥳐', '𥳒', '𥳓', '𥳔', '𥳕', '𥳖', '𥳗', '𥳘', '𥳚', '𥳜', '𥳝', '𥳞', '𥳟', '𥳠', '𥳡', '𥳢',
+    '𥳣', '𥳥', '𥳪', '𥳫', '𥳬', '𥳯', '𥳰', '𥳱', '𥳲', '𥳳', '𥳴', '𥳵', '𥳶', '𥳷', '𥳸', '𥳹',
+    '𥳺', '𥴐', '𥴑', '𥴒', '𥴕', '𥴖', '𥴡', '𥴤', '𥴦', '𥴧', '𥴨', '𥴩', '𥴪', '𥴫', '𥴬', '𥴭',
+    '𥴮', '𥴯', '𥴱', '𥴴', '𥴵', '𥴺', '𥴻', '𥴿', '𥵏', '𥵒', '𥵜', '𥵝', '𥵞', '𥵟', '𥵠', '𥵣',
+    '𥵤', '𥵦', '𥵧', '𥵨', '𥵪', '𥵫', '𥵬', '𥵯', '𥵶', '𥶅', '𥶆', '𥶇', '𥶈', '𥶌', '𥶍', '𥶏',
+    '𥶐', '𥶑', '𥶒', '𥶓', '𥶔', '𥶕', '𥶗', '𥶛', '𥶜', '𥶢', '𥶵', '𥶶', '𥶷', '𥶹', '𥶺', '𥶻',
+    '𥶽', '𥶿', '𥷀', '𥷁', '𥷃', '𥷄', '𥷅', '𥷆', '𥷇', '𥷈', '𥷑', '𥷔', '𥷕', '𥷖', '𥷗', '𥷘',
+    '𥷙', '𥷚', '𥷜', '𥷞', '𥷨', '𥷩', '𥷪', '𥷫', '𥷬', '𥷮', '𥷱', '𥷹', '𥷼', '𥸃', '𥸈', '𥸉',
+    '𥸊', '𥸗', '𥸘', '𥸚', '𥸡', '𥸢', '𥸣', '𥸤', '𥸥', '𥸧', '𥸨', '𥸪', '𥸬', '𥸲', '𥸴', '𥸵',
+    '𥸸', '𥹁', '𥹂', '𥹄', '𥹆', '𥹇', '𥹉', '𥹊', '𥹋', '𥹌', '𥹍', '𥹓', '𥹔', '𥹖', '𥹚', '𥹛',
+    '𥹜', '𥹠', '𥹩', '𥹫', '𥹬', '𥹱', '𥹲', '𥹳', '𥹴', '𥹵', '𥹶', '𥹷', '𥹸', '𥹺', '𥹻', '𥺅',
+    '𥺉', '𥺊', '𥺙', '𥺚', '𥺜', '𥺝', '𥺞', '𥺣', '𥺴', '𥺵', '𥺷', '𥻄', '𥻇', '𥻉', '𥻋', '𥻍',
+    '𥻑', '𥻒', '𥻗', '𥻝', '𥻞', '𥻤', '𥻥', '𥻦', '𥻧', '𥻨', '𥻩', '𥻫', '𥻬', '𥻮', '𥻴', '𥻾',
+    '𥻿', '𥼀', '𥼂', '𥼃', '𥼅', '𥼘', '𥼚', '𥼛', '𥼜', '𥼞', '𥼟', '𥼩', '𥼪', '𥼫', '𥼬', '𥼭',
+    '𥼮', '𥼶', '𥼺', '𥼻', '𥼼', '𥽁', '𥽈', '𥽏', '𥽐', '𥽒', '𥽗', '𥽘', '𥽟', '𥽥', '𥽧', '𥽬',
+    '𥽰', '𥽳', '𥽶', '𥽸', '𥽿', '𥾂', '𥾅', '𥾇', '𥾊', '𥾋', '𥾌', '𥾍', '𥾐', '𥾓', '𥾕', '𥾚',
+    '𥾛', '𥾝', '𥾡', '𥾣', '𥾤', '𥾧', '𥾨', '𥾬', '𥾮', '𥾯', '𥾹', '𥾼', '𥿃', '𥿄', '𥿅', '𥿆',
+    '𥿇', '𥿉', '𥿊', '𥿋', '𥿍', '𥿎', '𥿑', '𥿚', '𥿜', '𥿝', '𥿞', '𥿦', '𥿧', '𥿨', '𥿩', '𥿪',
+    '𥿫', '𥿭', '𥿮', '𥿯', '𥿰', '𥿴', '𥿵', '𥿷', '𥿹', '𥿺', '𦀇', '𦀓', '𦀔', '𦀕', '𦀖', '𦀘',
+    '𦀛', '𦀜', '𦀝', '𦀠', '𦀡', '𦀣', '𦀨', '𦀸', '𦁄', '𦁆', '𦁇', '𦁈', '𦁉', '𦁊', '𦁍', '𦁎',
+    '𦁏', '𦁐', '𦁕', '𦁖', '𦁗', '𦁙', '𦁛', '𦁜', '𦁟', '𦁲', '𦁳', '𦁶', '𦁷', '𦂀', '𦂄', '𦂅',
+    '𦂆', '𦂇', '𦂈', '𦂉', '𦂊', '𦂋', '𦂌', '𦂍', '𦂑', '𦂒', '𦂔', '𦂕', '𦂖', '𦂗', '𦂠', '𦂡',
+    '𦃄', '𦃇', '𦃊', '𦃋', '𦃒', '𦃓', '𦃔', '𦃕', '𦃖', '𦃗', '𦃘', '𦃙', '𦃝', '𦃡', '𦃢', '𦃤',
+    '𦃽', '𦄂', '𦄊', '𦄋', '𦄌', '𦄍', '𦄏', '𦄐', '𦄑', '𦄓', '𦄔', '𦄜', '𦄯', '𦄰', '𦄼', '𦄽',
+    '𦅀', '𦅃', '𦅄', '𦅆', '𦅇', '𦅈', '𦅊', '𦅋', '𦅏', '𦅑', '𦅔', '𦅵', '𦅶', '𦅷', '𦅸', '𦅺',
+    '𦅼', '𦅽', '𦅿', '𦆀', '𦆁', '𦆄', '𦆆', '𦆈', '𦆋', '𦆌', '𦆍', '𦆘', '𦆞', '𦆟', '𦆡', '𦆤',
+    '𦆦', '𦆸', '𦆻', '𦆾', '𦇀', '𦇁', '𦇄', '𦇅', '𦇎', '𦇔', '𦇖', '𦇘', '𦇙', '𦇛', '𦇧', '𦇪',
+    '𦇬', '𦇭', '𦇱', '𦇲', '𦇵', '𦇸', '𦈃', '𦈅', '𦈈', '𦈉', '𦈋', '𦈌', '𦈎', '𦈏', '𦈐', '𦈑',
+    '𦈒', '𦈓', '𦈔', '𦈕', '𦈖', '𦈗', '𦈘', '𦈙', '𦈚', '𦈛', '𦈜', '𦈝', '𦈞', '𦈟', '𦈠', '𦈡',
+    '𦈣', '𦈤', '𦈦', '𦈧', '𦈨', '𦈩', '𦈬', '𦈰', '𦈲', '𦈵', '𦈶', '𦈷', '𦈸', '𦈺', '𦈻', '𦉂',
+    '𦉆', '𦉈', '𦉉', '𦉎', '𦉐', '𦉙', '𦉝', '𦉟', '𦉢', '𦉧', '𦉬', '𦉹', '𦊁', '𦊂', '𦊃', '𦊊',
+    '𦊋', '𦊌', '𦊎', '𦊐', '𦊑', '𦊓', '𦊗', '𦊘', '𦊦', '𦊧', '𦊪', '𦊫', '𦊬', '𦊱', '𦊹', '𦊻',
+    '𦊼', '𦊽', '𦊾', '𦊿', '𦋅', '𦋆', '𦋈', '𦋉', '𦋋', '𦋓', '𦋔', '𦋞', '𦋡', '𦋢', '𦋣', '𦋩',
+    '𦋪', '𦋯', '𦋰', '𦋳', '𦋿', '𦌀', '𦌁', '𦌆', '𦌉', '𦌊', '𦌋', '𦌒', '𦌔', '𦌕', '𦌗', '𦌚',
+    '𦌟', '𦌡', '𦌢', '𦌦', '𦌩', '𦌪', '𦌬', '𦌰', '𦌷', '𦌸', '𦌺', '𦌿', '𦍀', '𦍁', '𦍄', '𦍅',
+    '𦍌', '𦍏', '𦍒', '𦍓', '𦍕', '𦍦', '𦍧', '𦍩', '𦍪', '𦍭', '𦍱', '𦍲', '𦍴', '𦍵', '𦍷', '𦍸',
+    '𦍹', '𦍺', '𦍼', '𦎄', '𦎇', '𦎈', '𦎊', '𦎌', '𦎎', '𦎏', '𦎐', '𦎒', '𦎓', '𦎘', '𦎚', '𦎜',
+    '𦎝', '𦎞', '𦎢', '𦎣', '𦎦', '𦎧', '𦎨', '𦎭', '𦎮', '𦎯', '𦎰', '𦎱', '𦎵', '𦎷', '𦎸', '𦎹',
+    '𦎼', '𦏀', '𦏂', '𦏅', '𦏆', '𦏇', '𦏔', '𦏕', '𦏗', '𦏛', '𦏜', '𦏢', '𦏤', '𦏥', '𦏦', '𦏨',
+    '𦏮', '𦏱', '𦏳', '𦏷', '𦏸', '𦏹', '𦏺', '𦏻', '𦏿', '𦐁', '𦐄', '𦐅', '𦐆', '𦐇', '𦐈', '𦐉',
+    '𦐌', '𦐍', '𦐖', '𦐛', '𦐠', '𦐡', '𦐣', '𦐤', '𦐦', '𦐨', '𦐰', '𦐳', '𦐸', '𦐹', '𦐺', '𦐽',
+    '𦐾', '𦐿', '𦑀', '𦑇', '𦑈', '𦑋', '𦑌', '𦑍', '𦑎', '𦑏', '𦑑', '𦑘', '𦑙', '𦑚', '𦑛', '𦑜',
+    '𦑝', '𦑞', '𦑟', '𦑠', '𦑡', '𦑣', '𦑯', '𦑱', '𦑲', '𦑵', '𦑶', '𦑸', '𦑹', '𦑺', '𦑻', '𦑼',
+    '𦑾', '𦒁', '𦒃', '𦒅', '𦒆', '𦒈', '𦒍', '𦒎', '𦒏', '𦒐', '𦒑', '𦒜', '𦒝', '𦒟', '𦒦', '𦒧',
+    '𦒰', '𦒴', '𦒶', '𦒺', '𦒻', '𦓈', '𦓋', '𦓍', '𦓎', '𦓐', '𦓓', '𦓔', '𦓕', '𦓖', '𦓝', '𦓢',
+    '𦓤', '𦓥', '𦓧', '𦓬', '𦓯', '𦓰', '𦓱', '𦓳', '𦓴', '𦓹', '𦓺', '𦓻', '𦓼', '𦓽', '𦓾', '𦔂',
+    '𦔃', '𦔄', '𦔅', '𦔆', '𦔈', '𦔉', '𦔋', '𦔌', '𦔍', '𦔎', '𦔓', '𦔔', '𦔖', '𦔗', '𦔛', '𦔜',
+    '𦔠', '𦔥', '𦔦', '𦔩', '𦔫', '𦔬', '𦔭', '𦔮', '𦔯', '𦔰', '𦔷', '𦔸', '𦔹', '𦔼', '𦔿', '𦕆',
+    '𦕉', '𦕏', '𦕒', '𦕓', '𦕙', '𦕠', '𦕡', '𦕢', '𦕰', '𦕵', '𦕷', '𦕸', '𦕺', '𦕽', '𦖀', '𦖈',
+    '𦖉', '𦖊', '𦖋', '𦖍', '𦖎', '𦖐', '𦖝', '𦖞', '𦖢', '𦖤', '𦖦', '𦖧', '𦖩', '𦖬', '𦖸', '𦖺',
+    '𦖼', '𦖽', '𦖾', '𦖿', '𦗀', '𦗁', '𦗋', '𦗍', '𦗐', '𦗑', '𦗒', '𦗓', '𦗔', '𦗕', '𦗖', '𦗗',
+    '𦗛', '𦗜', '𦗟', '𦗡', '𦗢', '𦗣', '𦗥', '𦗧', '𦗨', '𦗮', '𦗳', '𦗴', '𦗵', '𦗻', '𦗼', '𦘊',
+    '𦘌', '𦘍', '𦘒', '𦘔', '𦘩', '𦘪', '𦘲', '𦘳', '𦘴', '𦘵', '𦘶', '𦘸', '𦘹', '𦘼', '𦘿', '𦙀',
+    '𦙂', '𦙄', '𦙆', '𦙇', '𦙈', '𦙉', '𦙊', '𦙋', '𦙙', '𦙜', '𦙝', '𦙧', '𦙨', '𦙫', '𦙮', '𦙯',
+    '𦙱', '𦙴', '𦙵', '𦙶', '𦙸', '𦙹', '𦙺', '𦙻', '𦚈', '𦚊', '𦚏', '𦚐', '𦚓', '𦚝', '𦚞', '𦚟',
+    '𦚡', '𦚢', '𦚣', '𦚥', '𦚦', '𦚧', '𦚨', '𦚩', '𦚫', '𦚸', '𦛅', '𦛏', '𦛐', '𦛓', '𦛔', '𦛕',
+    '𦛖', '𦛗', '𦛘', '𦛙', '𦛚', '𦛜', '𦛝', '𦛞', '𦛟', '𦛠', '𦛢', '𦛣', '𦛤', '𦛧', '𦛨', '𦛯',
+    '𦛰', '𦛱', '𦛲', '𦜁', '𦜃', '𦜄', '𦜆', '𦜇', '𦜊', '𦜋', '𦜍', '𦜎', '𦜏', '𦜐', '𦜒', '𦜓',
+    '𦜖', '𦜛', '𦜜', '𦜭', '𦜯', '𦜰', '𦜱', '𦜲', '𦜳', '𦜴', '𦜷', '𦜸', '𦝏', '𦝒', '𦝔', '𦝖',
+    '𦝚', '𦝛', '𦝝', '𦝞', '𦝟', '𦝢', '𦝣', '𦝤', '𦝥', '𦝦', '𦝧', '𦝨', '𦝪', '𦝬', '𦝮', '𦝰',
+    '𦝳', '𦝴', '𦝷', '𦝼', '𦞁', '𦞂', '𦞆', '𦞇', '𦞈', '𦞌', '𦞕', '𦞖', '𦞗', '𦞙', '𦞚', '𦞝',
+    '𦞞', '𦞟', '𦞡', '𦞢', '𦞣', '𦞤', '𦞥', '𦞦', '𦞨', '𦞫', '𦞬', '𦞭', '𦞱', '𦞲', '𦞽', '𦟂',
+    '𦟃', '𦟄', '𦟓', '𦟔', '𦟕', '𦟘', '𦟙', '𦟛', '𦟜', '𦟞', '𦟟', '𦟠', '𦟣', '𦟤', '𦟧', '𦟮',
+    '𦟰', '𦟱', '𦟲', '𦟳', '𦠁', '𦠄', '𦠅', '𦠆', '𦠇', '𦠉', '𦠎', '𦠒', '𦠓', '𦠖', '𦠛', '𦠜',
+    '𦠞', '𦠟', '𦠠', '𦠢', '𦠣', '𦠦', '𦠪', '𦠷', '𦠸', '𦠹', '𦠺', '𦠻', '𦠾', '𦠿', '𦡂', '𦡃',
+    '𦡄', '𦡅', '𦡆', '𦡉', '𦡕', '𦡖', '𦡙', '𦡨', '𦡪', '𦡫', '𦡭', '𦡱', '𦡲', '𦡴', '𦡵', '𦡷',
+    '𦡹', '𦡺', '𦡻', '𦡼', '𦡽', '𦢆', '𦢊', '𦢎', '𦢏', '𦢐', '𦢙', '𦢣', '𦢧', '𦢩', '𦢯', '𦢱',
+    '𦢺', '𦢿', '𦣀', '𦣂', '𦣄', '𦣇', '𦣋', '𦣍', '𦣏', '𦣒', '𦣖', '𦣘', '𦣛', '𦣜', '𦣢', '𦣥',
+    '𦣦', '𦣧', '𦣨', '𦣩', '𦣯', '𦣴', '𦣸', '𦣹', '𦣺', '𦣻', '𦣾', '𦤂', '𦤇', '𦤈', '𦤊', '𦤋',
+    '𦤎', '𦤕', '𦤘', '𦤙', '𦤚', '𦤟', '𦤢', '𦤣', '𦤦', '𦤧', '𦤨', '𦤪', '𦤫', '𦤬', '𦤸', '𦤻',
+    '𦤽', '𦥁', '𦥂', '𦥈', '𦥉', '𦥊', '𦥍', '𦥎', '𦥏', '𦥐', '𦥑', '𦥖', '𦥚', '𦥝', '𦥣', '𦥤',
+    '𦥦', '𦥬', '𦥭', '𦥯', '𦥰', '𦥱', '𦥲', '𦥻', '𦦃', '𦦄', '𦦅', '𦦌', '𦦑', '𦦕', '𦦗', '𦦘',
+    '𦦛', '𦦜', '𦦢', '𦦣', '𦦧', '𦦩', '𦦫', '𦦯', '𦦱', '𦦲', '𦦹', '𦦺', '𦦽', '𦦾', '𦦿', '𦧁',
+    '𦧅', '𦧈', '𦧉', '𦧋', '𦧍', '𦧏', '𦧐', '𦧒', '𦧔', '𦧖', '𦧘', '𦧙', '𦧚', '𦧛', '𦧝', '𦧞',
+    '𦧟', '𦧠', '𦧡', '𦧢', '𦧤', '𦧥', '𦧬', '𦧮', '𦧯', '𦧱', '𦧴', '𦧵', '𦧸', '𦧹', '𦧼', '𦨆',
+    '𦨇', '𦨈', '𦨉', '𦨋', '𦨍', '𦨎', '𦨖', '𦨙', '𦨚', '𦨜', '𦨡', '𦨢', '𦨣', '𦨤', '𦨦', '𦨩',
+    '𦨬', '𦨭', '𦨯', '𦨰', '𦨲', '𦨳', '𦨴', '𦨵', '𦨸', '𦨾', '𦩄', '𦩈', '𦩋', '𦩌', '𦩍', '𦩏',
+    '𦩕', '𦩖', '𦩘', '𦩜', '𦩝', '𦩞', '𦩠', '𦩡', '𦩢', '𦩣', '𦩤', '𦩧', '𦩩', '𦩫', '𦩬', '𦩭',
+    '𦩯', '𦩱', '𦩲', '𦩴', '𦩵', '𦩷', '𦩸', '𦩼', '𦩿', '𦪀', '𦪇', '𦪈', '𦪊', '𦪋', '𦪍', '𦪑',
+    '𦪒', '𦪔', '𦪕', '𦪖', '𦪗', '𦪘', '𦪙', '𦪚', '𦪛', '𦪜', '𦪝', '𦪠', '𦪡', '𦪣', '𦪧', '𦪪',
+    '𦪫', '𦪬', '𦪭', '𦪱', '𦪶', '𦪹', '𦪽', '𦪾', '𦪿', '𦫀', '𦫃', '𦫈', '𦫉', '𦫊', '𦫎', '𦫔',
+    '𦫕', '𦫖', '𦫙', '𦫡', '𦫤', '𦫪', '𦫫', '𦫭', '𦫮', '𦫯', '𦫰', '𦫳', '𦫶', '𦫻', '𦬁', '𦬂',
+    '𦬃', '𦬄', '𦬅', '𦬔', '𦬕', '𦬖', '𦬗', '𦬘', '𦬙', '𦬚', '𦬛', '𦬞', '𦬣', '𦬸', '𦬹', '𦬺',
+    '𦬻', '𦬾', '𦭁', '𦭈', '𦭉', '𦭜', '𦭟', '𦭡', '𦭥', '𦭭', '𦭮', '𦭯', '𦭰', '𦭲', '𦭳', '𦭴',
+    '𦭵', '𦭶', '𦭷', '𦭸', '𦭹', '𦭺', '𦭻', '𦭼', '𦮁', '𦮃', '𦮅', '𦮐', '𦮑', '𦮯', '𦮶', '𦮷',
+    '𦮸', '𦮹', '𦮺', '𦮻', '𦮼', '𦮾', '𦯀', '𦯅', '𦯈', '𦯉', '𦯌', '𦯍', '𦯏', '𦯐', '𦯑', '𦯒',
+    '𦯕', '𦯖', '𦯛', '𦯣', '𦯤', '𦯪', '𦯫', '𦯬', '𦯯', '𦯰', '𦰏', '𦰖', '𦰘', '𦰙', '𦰛', '𦰡',
+    '𦰥', '𦰪', '𦰫', '𦰬', '𦰭', '𦰯', '𦰰', '𦰱', '𦰲', '𦰳', '𦰴', '𦰸', '𦰹', '𦰺', '𦰽', '𦰾',
+    '𦱀', '𦱁', '𦱂', '𦱃', '𦱅', '𦱆', '𦱇', '𦱊', '𦱋', '𦱌', '𦱒', '𦱓', '𦱔', '𦱖', '𦱜', '𦱠',
+    '𦱣', '𦱰', '𦱱', '𦱲', '𦱵', '𦱷', '𦲫', '𦲯', '𦲰', '𦲱', '𦳁', '𦳃', '𦳄', '𦳅', '𦳇', '𦳈',
+    '𦳊', '𦳋', '𦳌', '𦳏', '𦳐', '𦳑', '𦳓', '𦳔', '𦳗', '𦳘', '𦳚', '𦳛', '𦳜', '𦳝', '𦳞', '𦳟',
+    '𦳡', '𦳢', '𦳣', '𦳥', '𦳦', '𦳧', '𦳩', '𦳫', '𦳬', '𦳭', '𦳮', '𦳲', '𦳳', '𦳶', '𦳷', '𦳹',
+    '𦳺', '𦳽', '𦳾', '𦳿', '𦴀', '𦴉', '𦴊', '𦴌', '𦴍', '𦴎', '𦴑', '𦴔', '𦴚', '𦵐', '𦵟', '𦵡',
+    '𦵣', '𦵦', '𦵧', '𦵩', '𦵪', '𦵫', '𦵬', '𦵭', '𦵯', '𦵱', '𦵴', '𦵵', '𦵷', '𦵸', '𦵼', '𦵽',
+    '𦵾', '𦵿', '𦶀', '𦶂', '𦶅', '𦶇', '𦶈', '𦶊', '𦶋', '𦶌', '𦶍', '𦶎', '𦶏', '𦶐', '𦶑', '𦶓',
+    '𦶕', '𦶙', '𦶚', '𦶲', '𦷙', '𦷝', '𦷦', '𦷲', '𦷳', '𦷴', '𦷵', '𦷷', '𦷸', '𦷹', '𦷻', '𦷽',
+    '𦷿', '𦸁', '𦸂', '𦸆', '𦸈', '𦸉', '𦸊', '𦸏', '𦸐', '𦸓', '𦸔', '𦸗', '𦸘', '𦸙', '𦸚', '𦸛',
+    '𦸡', '𦸢', '𦸣', '𦸪', '𦸮', '𦸯', '𦸰', '𦸱', '𦸶', '𦸷', '𦸺', '𦹫', '𦹽', '𦺀', '𦺆', '𦺇',
+    '𦺉', '𦺊', '𦺋', '𦺌', '𦺍', '𦺎', '𦺐', '𦺑', '𦺒', '𦺓', '𦺔', '𦺖', '𦺗', '𦺘', '𦺙', '𦺛',
+    '𦺜', '𦺞', '𦺟', '𦺠', '𦺡', '𦺢', '𦺣', '𦺥', '𦺦', '𦺧', '𦺨', '𦺩', '𦺪', '𦺫', '𦺬', '𦺭',
+    '𦺰', '𦺱', '𦺲', '𦺴', '𦺵', '𦺶', '𦺷', '𦺸', '𦺹', '𦺻', '𦺽', '𦺾', '𦻀', '𦻁', '𦻃', '𦻅',
+    '𦼆', '𦼇', '𦼈', '𦼉', '𦼊', '𦼋', '𦼎', '𦼏', '𦼓', '𦼗', '𦼡', '𦼢', '𦼧', '𦼪', '𦼫', '𦼭',
+    '𦼮', '𦼰', '𦼱', '𦼲', '𦼳', '𦼴', '𦼵', '𦼷', '𦼹', '𦼻', '𦼿', '𦽀', '𦽁', '𦽂', '𦽃', '𦽄',
+    '𦽅', '𦽇', '𦽊', '𦽋', '𦽎', '𦽏', '𦽐', '𦽒', '𦽔', '𦽕', '𦽖', '𦽜', '𦽞', '𦽟', '𦽤', '𦽫',
+    '𦽬', '𦽭', '𦽮', '𦾏', '𦾑', '𦾕', '𦾘', '𦾙', '𦾚', '𦾛', '𦾝', '𦾞', '𦾩', '𦾫', '𦾬', '𦾭',
+    '𦾮', '𦾱', '𦾲', '𦾳', '𦾵', '𦾶', '𦾷', '𦾸', '𦾹', '𦾺', '𦾻', '𦾽', '𦾾', '𦾿', '𦿀', '𦿁',
+    '𦿂', '𦿃', '𦿅', '𦿆', '𦿇', '𦿊', '𦿋', '𦿌', '𦿍', '𦿏', '𦿐', '𦿓', '𦿖', '𧀄', '𧀅', '𧀆',
+    '𧀇', '𧀊', '𧀌', '𧀔', '𧀕', '𧀗', '𧀘', '𧀙', '𧀚', '𧀠', '𧀡', '𧀢', '𧀣', '𧀤', '𧀥', '𧀦',
+    '𧀧', '𧀨', '𧀩', '𧀬', '𧀭', '𧀮', '𧀯', '𧀰', '𧀱', '𧀲', '𧀴', '𧀵', '𧀶', '𧀹', '𧀺', '𧀻',
+    '𧀿', '𧁃', '𧁈', '𧁉', '𧁊', '𧁋', '𧁾', '𧁿', '𧂁', '𧂂', '𧂃', '𧂄', '𧂅', '𧂆', '𧂇', '𧂈',
+    '𧂉', '𧂋', '𧂍', '𧂏', '𧂐', '𧂒', '𧂔', '𧂙', '𧂛', '𧂜', '𧂝', '𧂞', '𧂠', '𧂡', '𧂢', '𧂦',
+    '𧂨', '𧂩', '𧃏', '𧃐', '𧃑', '𧃒', '𧃔', '𧃘', '𧃙', '𧃚', '𧃛', '𧃝', '𧃞', '𧃟', '𧃠', '𧃨',
+    '𧃩', '𧃪', '𧃮', '𧃱', '𧄍', '𧄎', '𧄏', '𧄐', '𧄑', '𧄒', '𧄓', '𧄔', '𧄚', '𧄛', '𧄜', '𧄠',
+    '𧄤', '𧄸', '𧄹', '𧄺', '𧄻', '𧄼', '𧄽', '𧄿', '𧅀', '𧅃', '𧅆', '𧅈', '𧅋', '𧅖', '𧅗', '𧅘',
+    '𧅚', '𧅮', '𧅯', '𧅰', '𧅲', '𧅺', '𧆂', '𧆉', '𧆊', '𧆏', '𧆐', '𧆑', '𧆓', '𧆕', '𧆘', '𧆜',
+    '𧆡', '𧆢', '𧆣', '𧆥', '𧆦', '𧆮', '𧆯', '𧆰', '𧆷', '𧆸', '𧆹', '𧆺', '𧆻', '𧆼', '𧇄', '𧇈',
+    '𧇌', '𧇍', '𧇎', '𧇓', '𧇙', '𧇚', '𧇛', '𧇜', '𧇝', '𧇞', '𧇟', '𧇠', '𧇡', '𧇥', '𧇦', '𧇮',
+    '𧇯', '𧇰', '𧇱', '𧇶', '𧇷', '𧇸', '𧇹', '𧇼', '𧇿', '𧈁', '𧈄', '𧈅', '𧈈', '𧈌', '𧈍', '𧈑',
+    '𧈔', '𧈖', '𧈗', '𧈙', '𧈚', '𧈜', '𧈝', '𧈞', '𧈦', '𧈧', '𧈪', '𧈭', '𧈯', '𧈹', '𧈻', '𧈼',
+    '𧈽', '𧈾', '𧈿', '𧉀', '𧉁', '𧉂', '𧉃', '𧉅', '𧉆', '𧉇', '𧉈', '𧉊', '𧉍', '𧉎', '𧉑', '𧉓',
+    '𧉗', '𧉛', '𧉞', '𧉟', '𧉡', '𧉢', '𧉣', '𧉤', '𧉥', '𧉧', '𧉩', '𧉪', '𧉫', '𧉭', '𧉮', '𧉲',
+    '𧊄', '𧊅', '𧊇', '𧊏', '𧊒', '𧊔', '𧊕', '𧊖', '𧊘', '𧊙', '𧊚', '𧊛', '𧊜', '𧊞', '𧊟', '𧊠',
+    '𧊣', '𧊤', '𧊥', '𧊦', '𧊧', '𧊬', '𧊭', '𧊯', '𧊱', '𧊽', '𧊾', '𧋉', '𧋊', '𧋌', '𧋍', '𧋎',
+    '𧋏', '𧋐', '𧋑', '𧋒', '𧋓', '𧋔', '𧋕', '𧋖', '𧋗', '𧋘', '𧋚', '𧋠', '𧋡', '𧋱', '𧋲', '𧌁',
+    '𧌃', '𧌄', '𧌅', '𧌆', '𧌇', '𧌈', '𧌉', '𧌊', '𧌋', '𧌌', '𧌍', '𧌎', '𧌏', '𧌐', '𧌑', '𧌓',
+    '𧌔', '𧌖', '𧌗', '𧌘', '𧌙', '𧌚', '𧌝', '𧌞', '𧌠', '𧌢', '𧌣', '𧌧', '𧌻', '𧌽', '𧌾', '𧌿',
+    '𧍀', '𧍁', '𧍂', '𧍃', '𧍒', '𧍓', '𧍔', '𧍕', '𧍖', '𧍘', '𧍙', '𧍜', '𧍝', '𧍟', '𧍠', '𧍢',
+    '𧍥', '𧍨', '𧍩', '𧍪', '𧍫', '𧍬', '𧍭', '𧍮', '𧍯', '𧍰', '𧍱', '𧍲', '𧍴', '𧍵', '𧍶', '𧍿',
+    '𧎃', '𧎄', '𧎕', '𧎖', '𧎗', '𧎘', '𧎙', '𧎡', '𧎣', '𧎤', '𧎥', '𧎨', '𧎪', '𧎫', '𧎭', '𧎮',
+    '𧎯', '𧎰', '𧎱', '𧎲', '𧎳', '𧎴', '𧎵', '𧎷', '𧎸', '𧎹', '𧎺', '𧎻', '𧎾', '𧎿', '𧏂', '𧏃',
+    '𧏆', '𧏋', '𧏑', '𧏓', '𧏥', '𧏧', '𧏫', '𧏸', '𧏹', '𧏺', '𧏻', '𧏽', '𧏾', '𧏿', '𧐁', '𧐃',
+    '𧐄', '𧐅', '𧐈', '𧐉', '𧐊', '𧐋', '𧐌', '𧐍', '𧐎', '𧐐', '𧐒', '𧐓', '𧐔', '𧐕', '𧐖', '𧐗',
+    '𧐙', '𧐟', '𧐬', '𧐯', '𧐰', '𧐱', '𧐲', '𧐳', '𧐴', '𧑀', '𧑆', '𧑇', '𧑈', '𧑊', '𧑋', '𧑌',
+    '𧑍', '𧑎', '𧑐', '𧑑', '𧑒', '𧑓', '𧑔', '𧑕', '𧑖', '𧑗', '𧑘', '𧑙', '𧑚', '𧑜', '𧑝', '𧑠',
+    '𧑡', '𧑤', '𧑦', '𧑧', '𧑨', '𧑩', '𧑫', '𧒀', '𧒂', '𧒈', '𧒎', '𧒐', '𧒑', '𧒓', '𧒕', '𧒖',
+    '𧒗', '𧒙', '𧒚', '𧒜', '𧒝', '𧒨', '𧒭', '𧒽', '𧒿', '𧓀', '𧓁', '𧓂', '𧓄', '𧓋', '𧓎', '𧓏',
+    '𧓐', '𧓑', '𧓓', '𧓔', '𧓕', '𧓖', '𧓗', '𧓘', '𧓨', '𧓩', '𧓪', '𧓲', '𧓴', '𧓸', '𧓺', '𧓻',
+    '𧓽', '𧓿', '𧔂', '𧔅', '𧔖', '𧔗', '𧔝', '𧔞', '𧔡', '𧔣', '𧔤', '𧔥', '𧔦', '𧔧', '𧔨', '𧔩',
+    '𧔪', '𧔬', '𧔭', '𧔮', '𧔳', '𧔴', '𧔷', '𧔼', '𧕃', '𧕄', '𧕅', '𧕇', '𧕉', '𧕋', '𧕌', '𧕍',
+    '𧕎', '𧕒', '𧕓', '𧕙', '𧕝', '𧕞', '𧕟', '𧕡', '𧕤', '𧕥', '𧕦', '𧕧', '𧕨', '𧕮', '𧕯', '𧕱',
+    '𧕲', '𧕴', '𧕵', '𧕸', '𧕺', '𧕼', '𧕿', '𧖁', '𧖆', '𧖇', '𧖉', '𧖑', '𧖒', '𧖔', '𧖘', '𧖙',
+    '𧖚', '𧖜', '𧖠', '𧖢', '𧖨', '𧖪', '𧖬', '𧖮', '𧖵', '𧖷', '𧖻', '𧖼', '𧖿', '𧗁', '𧗆', '𧗈',
+    '𧗋', '𧗌', '𧗎', '𧗏', '𧗒', '𧗖', '𧗦', '𧗩', '𧗪', '𧗫', '𧗱', '𧗲', '𧗴', '𧗶', '𧗸', '𧗹',
+    '𧗼', '𧗿', '𧘂', '𧘃', '𧘈', '𧘍', '𧘏', '𧘑', '𧘗', '𧘜', '𧘞', '𧘟', '𧘢', '𧘥', '𧘧', '𧘨',
+    '𧘩', '𧘫', '𧘮', '𧘽', '𧘿', '𧙀', '𧙁', '𧙃', '𧙄', '𧙅', '𧙆', '𧙇', '𧙈', '𧙉', '𧙌', '𧙏',
+    '𧙛', '𧙝', '𧙞', '𧙡', '𧙣', '𧙤', '𧙥', '𧙧', '𧙭', '𧙮', '𧙶', '𧙸', '𧙺', '𧙾', '𧚀', '𧚁',
+    '𧚂', '𧚃', '𧚄', '𧚅', '𧚆', '𧚇', '𧚉', '𧚋', '𧚏', '𧚐', '𧚡', '𧚥', '𧚨', '𧚩', '𧚪', '𧚫',
+    '𧚬', '𧚭', '𧚯', '𧚷', '𧛄', '𧛏', '𧛒', '𧛓', '𧛔', '𧛗', '𧛚', '𧛞', '𧛟', '𧛡', '𧛢', '𧛧',
+    '𧛸', '𧛹', '𧛺', '𧛻', '𧛼', '𧛾', '𧜁', '𧜂', '𧜃', '𧜅', '𧜉', '𧜊', '𧜔', '𧜚', '𧜞', '𧜠',
+    '𧜡', '𧜣', '𧜤', '𧜦', '𧜧', '𧜱', '𧜲', '𧜵', '𧜽', '𧝂', '𧝃', '𧝆', '𧝇', '𧝉', '𧝊', '𧝋',
+    '𧝎', '𧝐', '𧝑', '𧝒', '𧝔', '𧝕', '𧝗', '𧝘', '𧝜', '𧝤', '𧝪', '𧝫', '𧝬', '𧝮', '𧝲', '𧝳',
+    '𧝷', '𧝸', '𧞀', '𧞍', '𧞏', '𧞐', '𧞑', '𧞒', '𧞕', '𧞝', '𧞞', '𧞣', '𧞩', '𧞪', '𧞫', '𧞬',
+    '𧞭', '𧞰', '𧞲', '𧞸', '𧞹', '𧞺', '𧟃', '𧟄', '𧟌', '𧟑', '𧟘', '𧟙', '𧟜', '𧟠', '𧟣', '𧟨',
+    '𧟬', '𧟱', '𧟼', '𧠂', '𧠆', '𧠈', '𧠊', '𧠋', '𧠎', '𧠒', '𧠓', '𧠚', '𧠛', '𧠜', '𧠝', '𧠞',
+    '𧠟', '𧠡', '𧠢', '𧠣', '𧠥', '𧠦', '𧠩', '𧠪', '𧠫', '𧠬', '𧠴', '𧠶', '𧠼', '𧠽', '𧠾', '𧡇',
+    '𧡈', '𧡉', '𧡋', '𧡍', '𧡏', '𧡑', '𧡘', '𧡙', '𧡚', '𧡜', '𧡡', '𧡢', '𧡣', '𧡤', '𧡦', '𧡨',
+    '𧡩', '𧡪', '𧡫', '𧡬', '𧡮', '𧡯', '𧡴', '𧡵', '𧡶', '𧡸', '𧡹', '𧡺', '𧡼', '𧢂', '𧢃', '𧢆',
+    '𧢋', '𧢌', '𧢐', '𧢒', '𧢖', '𧢛', '𧢜', '𧢝', '𧢞', '𧢢', '𧢦', '𧢧', '𧢩', '𧢬', '𧢭', '𧢰',
+    '𧢵', '𧢶', '𧢹', '𧢼', '𧢽', '𧢾', '𧣁', '𧣃', '𧣋', '𧣌', '𧣑', '𧣒', '𧣕', '𧣖', '𧣚', '𧣛',
+    '𧣟', '𧣡', '𧣢', '𧣣', '𧣤', '𧣦', '𧣩', '𧣪', '𧣬', '𧣭', '𧣵', '𧣷', '𧣸', '𧣹', '𧣺', '𧣻',
+    '𧣼', '𧣾', '𧤃', '𧤍', '𧤎', '𧤏', '𧤐', '𧤑', '𧤒', '𧤓', '𧤕', '𧤖', '𧤗', '𧤙', '𧤛', '𧤜',
+    '𧤟', '𧤠', '𧤡', '𧤣', '𧤨', '𧤮', '𧤯', '𧤲', '𧤴', '𧤵', '𧤼', '𧤽', '𧥄', '𧥆', '𧥈', '𧥊',
+    '𧥋', '𧥌', '𧥍', '𧥎', '𧥑', '𧥓', '𧥕', '𧥖', '𧥚', '𧥛', '𧥜', '𧥞', '𧥟', '𧥣', '𧥤', '𧥮',
+    '𧥯', '𧥱', '𧥴', '𧥵', '𧥷', '𧥸', '𧥹', '𧥺', '𧥼', '𧥿', '𧦁', '𧦎', '𧦑', '𧦚', '𧦝', '𧦞',
+    '𧦟', '𧦤', '𧦧', '𧦭', '𧦮', '𧦸', '𧦹', '𧧃', '𧧄', '𧧅', '𧧆', '𧧇', '𧧉', '𧧊', '𧧋', '𧧏',
+    '𧧒', '𧧓', '𧧔', '𧧕', '𧧜', '𧧝', '𧧟', '𧧠', '𧧩', '𧧵', '𧧶', '𧧸', '𧧹', '𧧺', '𧧻', '𧧾',
+    '𧨀', '𧨂', '𧨄', '𧨆', '𧨈', '𧨊', '𧨋', '𧨗', '𧨟', '𧨡', '𧨧', '𧨩', '𧨰', '𧨱', '𧨲', '𧨳',
+    '𧨴', '𧨵', '𧨷', '𧨸', '𧨹', '𧨾', '𧨿', '𧩃', '𧩅', '𧩒', '𧩓', '𧩚', '𧩝', '𧩣', '𧩦', '𧩧',
+    '𧩨', '𧩪', '𧩫', '𧩬', '𧩮', '𧩰', '𧩱', '𧩲', '𧩴', '𧩶', '𧩸', '𧩹', '𧩼', '𧪅', '𧪇', '𧪓',
+    '𧪕', '𧪘', '𧪚', '𧪝', '𧪞', '𧪠', '𧪡', '𧪢', '𧪣', '𧪦', '𧪨', '𧪫', '𧪮', '𧪯', '𧪹', '𧫊',
+    '𧫑', '𧫒', '𧫓', '𧫕', '𧫗', '𧫛', '𧫜', '𧫝', '𧫞', '𧫠', '𧫡', '𧫢', '𧫤', '𧫥', '𧫦', '𧫧',
+    '𧫬', '𧫹', '𧫾', '𧫿', '𧬁', '𧬂', '𧬅', '𧬆', '𧬇', '𧬈', '𧬊', '𧬋', '𧬌', '𧬏', '𧬕', '𧬞',
+    '𧬤', '𧬦', '𧬧', '𧬨', '𧬩', '𧬪', '𧬫', '𧬬', '𧬰', '𧬱', '𧭃', '𧭇', '𧭈', '𧭊', '𧭌', '𧭍',
+    '𧭎', '𧭏', '𧭓', '𧭔', '𧭕', '𧭚', '𧭜', '𧭝', '𧭞', '𧭟', '𧭠', '𧭣', '𧭤', '𧭥', '𧭦', '𧭪',
+    '𧭳', '𧭵', '𧭸', '𧭹', '𧭻', '𧭼', '𧭽', '𧭾', '𧮆', '𧮈', '𧮍', '𧮑', '𧮒', '𧮓', '𧮗', '𧮙',
+    '𧮛', '𧮝', '𧮞', '𧮠', '𧮡', '𧮤', '𧮪', '𧮫', '𧮬', '𧮭', '𧮮', '𧮰', '𧮱', '𧮳', '𧮴', '𧮵',
+    '𧮶', '𧮺', '𧮻', '𧮼', '𧮽', '𧮿', '𧯀', '𧯃', '𧯆', '𧯈', '𧯉', '𧯊', '𧯋', '𧯌', '𧯍', '𧯏',
+    '𧯒', '𧯖', '𧯗', '𧯘', '𧯞', '𧯠', '𧯡', '𧯤', '𧯥', '𧯦', '𧯨', '𧯩', '𧯫', '𧯯', '𧯰', '𧯱',
+    '𧯲', '𧯺', '𧯼', '𧯾', '𧰃', '𧰄', '𧰇', '𧰈', '𧰊', '𧰒', '𧰘', '𧰙', '𧰠', '𧰡', '𧰣', '𧰨',
+    '𧰩', '𧰪', '𧰫', '𧰭', '𧰯', '𧰰', '𧰱', '𧰲', '𧰴', '𧰵', '𧰷', '𧰹', '𧰻', '𧰿', '𧱀', '𧱄',
+    '𧱅', '𧱆', '𧱊', '𧱏', '𧱐', '𧱒', '𧱓', '𧱘', '𧱜', '𧱝', '𧱡', '𧱢', '𧱩', '𧱪', '𧱫', '𧱬',
+    '𧱰', '𧱲', '𧱳', '𧱴', '𧱵', '𧱹', '𧱻', '𧱼', '𧱾', '𧲀', '𧲂', '𧲃', '𧲄', '𧲅', '𧲇', '𧲈',
+    '𧲋', '𧲌', '𧲍', '𧲗', '𧲘', '𧲙', '𧲜', '𧲝', '𧲡', '𧲢', '𧲤', '𧲥', '𧲦', '𧲧', '𧲨', '𧲭',
+    '𧲮', '𧲯', '𧲰', '𧲱', '𧲴', '𧲵', '𧲺', '𧲻', '𧲾', '𧲿', '𧳀', '𧳁', '𧳂', '𧳄', '𧳅', '𧳆',
+    '𧳉', '𧳌', '𧳍', '𧳎', '𧳏', '𧳐', '𧳕', '𧳙', '𧳚', '𧳛', '𧳜', '𧳝', '𧳞', '𧳟', '𧳠', '𧳣',
+    '𧳦', '𧳧', '𧳨', '𧳩', '𧳪', '𧳫', '𧳬', '𧳭', '𧳮', '𧳶', '𧳸', '𧳹', '𧳻', '𧳼', '𧳿', '𧴁',
+    '𧴂', '𧴃', '𧴄', '𧴅', '𧴇', '𧴉', '𧴊', '𧴌', '𧴎', '𧴓', '𧴕', '𧴖', '𧴗', '𧴙', '𧴚', '𧴠',
+    '𧴢', '𧴣', '𧴤', '𧴥', '𧴪', '𧴬', '𧴭', '𧴲', '𧴸', '𧴺', '𧵃', '𧵈', '𧵊', '𧵋', '𧵌', '𧵎',
+    '𧵛', '𧵞', '𧵠', '𧵡', '𧵢', '𧵣', '𧵤', '𧵧', '𧵨', '𧵬', '𧵭', '𧵱', '𧵳', '𧵻', '𧵼', '𧶄',
+    '𧶆', '𧶇', '𧶊', '𧶍', '𧶔', '𧶕', '𧶖', '𧶙', '𧶜', '𧶠', '𧶡', '𧶲', '𧶵', '𧶶', '𧶷', '𧶸',
+    '𧶺', '𧷅', '𧷋', '𧷎', '𧷐', '𧷑', '𧷒', '𧷛', '𧷟', '𧷡', '𧷥', '𧷦', '𧷧', '𧷱', '𧷳', '𧷶',
+    '𧷾', '𧷿', '𧸂', '𧸅', '𧸈', '𧸕', '𧸖', '𧸗', '𧸘', '𧸙', '𧸦', '𧸧', '𧸨', '𧸩', '𧸪', '𧸫',
+    '𧸲', '𧸽', '𧸾', '𧸿', '𧹈', '𧹊', '𧹌', '𧹐', '𧹑', '𧹓', '𧹕', '𧹖', '𧹗', '𧹛', '𧹞', '𧹣',
+    '𧹨', '𧹩', '𧹬', '𧹭', '𧹲', '𧹳', '𧹴', '𧹶', '𧹺', '𧹽', '𧹾', '𧺅', '𧺎', '𧺏', '𧺐', '𧺒',
+    '𧺜', '𧺝', '𧺟', '𧺠', '𧺡', '𧺢', '𧺣', '𧺤', '𧺧', '𧺨', '𧺰', '𧺲', '𧺴', '𧺶', '𧺷', '𧺸',
+    '𧺹', '𧺺', '𧺼', '𧺽', '𧺾', '𧺿', '𧻁', '𧻃', '𧻍', '𧻒', '𧻓', '𧻔', '𧻕', '𧻖', '𧻗', '𧻘',
+    '𧻙', '𧻚', '𧻜', '𧻝', '𧻧', '𧻰', '𧻱', '𧻲', '𧻳', '𧻴', '𧻵', '𧻶', '𧻷', '𧻸', '𧻹', '𧻺',
+    '𧻼', '𧻿', '𧼊', '𧼎', '𧼏', '𧼐', '𧼑', '𧼒', '𧼓', '𧼔', '𧼕', '𧼖', '𧼗', '𧼘', '𧼙', '𧼜',
+    '𧼟', '𧼠', '𧼤', '𧼨', '𧼩', '𧼪', '𧼫', '𧼬', '𧼭', '𧼮', '𧼯', '𧼰', '𧼱', '𧼳', '𧼴', '𧼵',
+    '𧼶', '𧽄', '𧽅', '𧽆', '𧽉', '𧽊', '𧽋', '𧽌', '𧽍', '𧽎', '𧽏', '𧽐', '𧽑', '𧽒', '𧽓', '𧽔',
+    '𧽖', '𧽞', '𧽟', '𧽠', '𧽢', '𧽣', '𧽤', '𧽥', '𧽦', '𧽧', '𧽨', '𧽩', '𧽫', '𧽲', '𧽶', '𧽷',
+    '𧽸', '𧽻', '𧽼', '𧽽', '𧽾', '𧽿', '𧾁', '𧾂', '𧾆', '𧾇', '𧾊', '𧾍', '𧾎', '𧾏', '𧾐', '𧾑',
+    '𧾔', '𧾚', '𧾛', '𧾝', '𧾠', '𧾡', '𧾢', '𧾣', '𧾤', '𧾥', '𧾧', '𧾨', '𧾩', '𧾮', '𧾯', '𧾰',
+    '𧾱', '𧾲', '𧾳', '𧾵', '𧾶', '𧾻', '𧾽', '𧾾', '𧿅', '𧿆', '𧿈', '𧿉', '𧿋', '𧿌', '𧿏', '𧿒',
+    '𧿓', '𧿔', '𧿕', '𧿖', '𧿗', '𧿘', '𧿙', '𧿚', '𧿝', '𧿞', '𧿠', '𧿥', '𧿧', '𧿨', '𧿩', '𧿫',
+    '𧿲', '𧿳', '𧿴', '𧿵', '𧿶', '𧿷', '𧿹', '𧿺', '𧿻', '𧿼', '𧿽', '𨀀', '𨀄', '𨀅', '𨀔', '𨀕',
+    '𨀖', '𨀘', '𨀙', '𨀛', '𨀜', '𨀝', '𨀟', '𨀠', '𨀤', '𨀥', '𨀧', '𨀨', '𨀫', '𨀬', '𨀮', '𨀯',
+    '𨀳', '𨀴', '𨀶', '𨀸', '𨁀', '𨁁', '𨁂', '𨁃', '𨁄', '𨁅', '𨁆', '𨁇', '𨁈', '𨁉', '𨁊', '𨁋',
+    '𨁌', '𨁍', '𨁎', '𨁏', '𨁐', '𨁑', '𨁒', '𨁗', '𨁟', '𨁠', '𨁡', '𨁴', '𨁶', '𨁷', '𨁸', '𨁹',
+    '𨁺', '𨁽', '𨁿', '𨂀', '𨂁', '𨂂', '𨂃', '𨂅', '𨂇', '𨂐', '𨂒', '𨂜', '𨂝', '𨂞', '𨂟', '𨂠',
+    '𨂡', '𨂢', '𨂣', '𨂦', '𨂩', '𨂪', '𨂫', '𨂭', '𨂯', '𨂰', '𨂱', '𨂲', '𨂴', '𨂵', '𨂺', '𨂿',
+    '𨃃', '𨃄', '𨃅', '𨃇', '𨃓', '𨃕', '𨃖', '𨃗', '𨃘', '𨃙', '𨃚', '𨃛', '𨃞', '𨃟', '𨃣', '𨃤',
+    '𨃭', '𨃯', '𨃰', '𨃲', '𨃳', '𨄄', '𨄅', '𨄇', '𨄈', '𨄉', '𨄊', '𨄋', '𨄌', '𨄍', '𨄐', '𨄓',
+    '𨄔', '𨄕', '𨄗', '𨄚', '𨄛', '𨄮', '𨄯', '𨄰', '𨄱', '𨅅', '𨅊', '𨅋', '𨅍', '𨅎', '𨅏', '𨅑',
+    '𨅒', '𨅓', '𨅔', '𨅗', '𨅘', '𨅝', '𨅣', '𨅤', '𨅪', '𨅬', '𨆁', '𨆂', '𨆃', '𨆄', '𨆅', '𨆇',
+    '𨆈', '𨆉', '𨆊', '𨆌', '𨆍', '𨆎', '𨆏', '𨆙', '𨆧', '𨆨', '𨆪', '𨆬', '𨆰', '𨆱', '𨆲', '𨆵',
+    '𨆽', '𨆾', '𨇁', '𨇃', '𨇄', '𨇅', '𨇆', '𨇍', '𨇎', '𨇕', '𨇖', '𨇗', '𨇘', '𨇙', '𨇝', '𨇤',
+    '𨇦', '𨇨', '𨇩', '𨇯', '𨇻', '𨇼', '𨇽', '𨈀', '𨈈', '𨈊', '𨈋', '𨈌', '𨈎', '𨈓', '𨈕', '𨈖',
+    '𨈗', '𨈘', '𨈙', '𨈚', '𨈥', '𨈧', '𨈩', '𨈫', '𨈮', '𨈶', '𨈷', '𨈹', '𨈺', '𨉁', '𨉃', '𨉅',
+    '𨉋', '𨉖', '𨉗', '𨉚', '𨉜', '𨉝', '𨉡', '𨉢', '𨉣', '𨉤', '𨉥', '𨉩', '𨉪', '𨉫', '𨉬', '𨉭',
+    '𨉱', '𨉴', '𨉷', '𨉸', '𨉹', '𨉽', '𨉾', '𨊅', '𨊈', '𨊉', '𨊔', '𨊘', '𨊚', '𨊛', '𨊝', '𨊞',
+    '𨊟', '𨊡', '𨊢', '𨊧', '𨊰', '𨊱', '𨊳', '𨊸', '𨊹', '𨊺', '𨊻', '𨊼', '𨊿', '𨋀', '𨋈', '𨋉',
+    '𨋐', '𨋑', '𨋒', '𨋔', '𨋕', '𨋖', '𨋗', '𨋘', '𨋙', '𨋚', '𨋛', '𨋝', '𨋞', '𨋟', '𨋠', '𨋡',
+    '𨋥', '𨋦', '𨋧', '𨋨', '𨋩', '𨋫', '𨋬', '𨋮', '𨋯', '𨋰', '𨋲', '𨋷', '𨋺', '𨋽', '𨋾', '𨌁',
+    '𨌄', '𨌅', '𨌆', '𨌈', '𨌌', '𨌍', '𨌎', '𨌑', '𨌔', '𨌘', '𨌝', '𨌠', '𨌢', '𨌣', '𨌤', '𨌥',
+    '𨌧', '𨌨', '𨌩', '𨌫', '𨌬', '𨌭', '𨌮', '𨌰', '𨌳', '𨌴', '𨌶', '𨍅', '𨍆', '𨍇', '𨍈', '𨍉',
+    '𨍊', '𨍎', '𨍏', '𨍐', '𨍑', '𨍒', '𨍛', '𨍜', '𨍞', '𨍢', '𨍧', '𨍩', '𨍫', '𨍭', '𨍮', '𨍯',
+    '𨍰', '𨍲', '𨍳', '𨍴', '𨍶', '𨍷', '𨍸', '𨍹', '𨍺', '𨎉', '𨎋', '𨎌', '𨎍', '𨎔', '𨎖', '𨎗',
+    '𨎛', '𨎝', '𨎡', '𨎢', '𨎤', '𨎦', '𨎧', '𨎩', '𨎪', '𨎫', '𨎬', '𨎭', '𨎮', '𨎳', '𨎴', '𨎶',
+    '𨎷', '𨎸', '𨎹', '𨎾', '𨏂', '𨏆', '𨏈', '𨏉', '𨏊', '𨏒', '𨏔', '𨏚', '𨏛', '𨏞', '𨏠', '𨏤',
+    '𨏥', '𨏨', '𨏩', '𨏪', '𨏫', '𨏬', '𨏳', '𨏴', '𨏵', '𨏶', '𨏹', '𨏺', '𨏿', '𨐁', '𨐃', '𨐅',
+    '𨐆', '𨐇', '𨐈', '𨐉', '𨐊', '𨐋', '𨐍', '𨐐', '𨐑', '𨐔', '𨐕', '𨐖', '𨐛', '𨐡', '𨐣', '𨐥',
+    '𨐦', '𨐨', '𨐩', '𨐰', '𨐱', '𨐳', '𨐴', '𨐶', '𨐾', '𨑊', '𨑌', '𨑎', '𨑑', '𨑒', '𨑜', '𨑝',
+    '𨑠', '𨑣', '𨑤', '𨑥', '𨑨', '𨑩', '𨑪', '𨑫', '𨑹', '𨑼', '𨑽', '𨑿', '𨒀', '𨒃', '𨒄', '𨒅',
+    '𨒆', '𨒇', '𨒉', '𨒊', '𨒋', '𨒍', '𨒑', '𨒙', '𨒜', '𨒞', '𨒢', '𨒤', '𨒦', '𨒧', '𨒨', '𨒩',
+    '𨒫', '𨒬', '𨒮', '𨒱', '𨒲', '𨒴', '𨒼', '𨒽', '𨓁', '𨓇', '𨓈', '𨓊', '𨓍', '𨓚', '𨓞', '𨓦',
+    '𨓨', '𨓩', '𨓬', '𨓭', '𨓮', '𨓯', '𨓰', '𨓲', '𨓳', '𨓴', '𨓷', '𨓿', '𨔗', '𨔙', '𨔛', '𨔡',
+    '𨔢', '𨔣', '𨔤', '𨔥', '𨔦', '𨔯', '𨕕', '𨕗', '𨕚', '𨕜', '𨕠', '𨕢', '𨕦', '𨕧', '𨕹', '𨕺',
+    '𨕻', '𨕽', '𨕿', '𨖃', '𨖊', '𨖋', '𨖌', '𨖎', '𨖏', '𨖚', '𨖛', '𨖠', '𨖢', '𨖬', '𨖭', '𨖮',
+    '𨖰', '𨖱', '𨖳', '𨖴', '𨖵', '𨖶', '𨖷', '𨖹', '𨖼', '𨖾', '𨖿', '𨗓', '𨗝', '𨗞', '𨗡', '𨗥',
+    '𨗦', '𨗨', '𨗰', '𨗼', '𨗾', '𨘁', '𨘂', '𨘃', '𨘄', '𨘇', '𨘉', '𨘌', '𨘔', '𨘙', '𨘞', '𨘬',
+    '𨘮', '𨘲', '𨘴', '𨘵', '𨘸', '𨘼', '𨘾', '𨙀', '𨙂', '𨙄', '𨙎', '𨙏', '𨙓', '𨙔', '𨙡', '𨙩',
+    '𨙫', '𨙬', '𨙭', '𨙮', '𨙯', '𨙲', '𨙳', '𨙴', '𨙵', '𨙶', '𨙷', '𨙸', '𨙹', '𨙺', '𨙻', '𨙽',
+    '𨚍', '𨚓', '𨚔', '𨚕', '𨚖', '𨚗', '𨚘', '𨚙', '𨚡', '𨚣', '𨚧', '𨚮', '𨚯', '𨚰', '𨚱', '𨚲',
+    '𨚳', '𨚴', '𨚵', '𨚶', '𨚾', '𨛈', '𨛉', '𨛊', '𨛋', '𨛌', '𨛍', '𨛎', '𨛏', '𨛐', '𨛑', '𨛒',
+    '𨛓', '𨛔', '𨛕', '𨛖', '𨛜', '𨛡', '𨛣', '𨛤', '𨛥', '𨛫', '𨛬', '𨛭', '𨛮', '𨛯', '𨛰', '𨛱',
+    '𨛳', '𨛵', '𨛶', '𨜌', '𨜍', '𨜎', '𨜏', '𨜐', '𨜑', '𨜒', '𨜓', '𨜔', '𨜖', '𨜗', '𨜘', '𨜜',
+    '𨜩', '𨜰', '𨜱', '𨜳', '𨜴', '𨜵', '𨜶', '𨜷', '𨜺', '𨜻', '𨜼', '𨜽', '𨜾', '𨜿', '𨝀', '𨝆',
+    '𨝈', '𨝋', '𨝌', '𨝍', '𨝎', '𨝏', '𨝐', '𨝑', '𨝓', '𨝖', '𨝘', '𨝛', '𨝜', '𨝞', '𨝟', '𨝢',
+    '𨝦', '𨝨', '𨝩', '𨝫', '𨝭', '𨝮', '𨝯', '𨝰', '𨝱', '𨝲', '𨝳', '𨝴', '𨝵', '𨝸', '𨞌', '𨞎',
+    '𨞐', '𨞑', '𨞒', '𨞓', '𨞕', '𨞗', '𨞘', '𨞙', '𨞛', '𨞢', '𨞣', '𨞨', '𨞩', '𨞪', '𨞫', '𨞲',
+    '𨞳', '𨞶', '𨞷', '𨞺', '𨞼', '𨞽', '𨞾', '𨞿', '𨟂', '𨟃', '𨟄', '𨟊', '𨟏', '𨟑', '𨟖', '𨟗',
+    '𨟙', '𨟚', '𨟠', '𨟥', '𨟲', '𨟴', '𨟵', '𨟶', '𨟸', '𨟹', '𨟺', '𨟼', '𨟽', '𨟾', '𨠋', '𨠌',
+    '𨠎', '𨠏', '𨠐', '𨠑', '𨠒', '𨠓', '𨠔', '𨠖', '𨠟', '𨠡', '𨠤', '𨠥', '𨠦', '𨠬', '𨠭', '𨠶',
+    '𨠷', '𨠸', '𨠹', '𨠿', '𨡁', '𨡂', '𨡃', '𨡄', '𨡌', '𨡍', '𨡎', '𨡏', '𨡐', '𨡑', '𨡒', '𨡗',
+    '𨡞', '𨡡', '𨡢', '𨡣', '𨡤', '𨡩', '𨡫', '𨡬', '𨡭', '𨡮', '𨡱', '𨡲', '𨡴', '𨡸', '𨡻', '𨢂',
+    '𨢅', '𨢆', '𨢇', '𨢈', '𨢉', '𨢊', '𨢋', '𨢌', '𨢎', '𨢐', '𨢑', '𨢜', '𨢠', '𨢢', '𨢣', '𨢤',
+    '𨢥', '𨢦', '𨢩', '𨢪', '𨢬', '𨢮', '𨢯', '𨢵', '𨢸', '𨢹', '𨢿', '𨣀', '𨣁', '𨣂', '𨣃', '𨣄',
+    '𨣅', '𨣇', '𨣈', '𨣍', '𨣔', '𨣕', '𨣗', '𨣘', '𨣙', '𨣚', '𨣛', '𨣝', '𨣠', '𨣢', '𨣦', '𨣧',
+    '𨣨', '𨣬', '𨣯', '𨣱', '𨣵', '𨣸', '𨣻', '𨣾', '𨤂', '𨤃', '𨤄', '𨤇', '𨤎', '𨤑', '𨤕', '𨤘',
+    '𨤚', '𨤡', '𨤢', '𨤤', '𨤩', '𨤫', '𨤱', '𨤴', '𨤷', '𨤹', '𨤽', '𨥂', '𨥇', '𨥍', '𨥑', '𨥙',
+    '𨥛', '𨥞', '𨥣', '𨥦', '𨥧', '𨥨', '𨥪', '𨥴', '𨥶', '𨥸', '𨥺', '𨦂', '𨦃', '𨦄', '𨦆', '𨦖',
+    '𨦗', '𨦛', '𨦜', '𨦞', '𨦡', '𨦭', '𨦮', '𨦯', '𨦰', '𨦱', '𨦳', '𨧀', '𨧐', '𨧑', '𨧕', '𨧖',
+    '𨧗', '𨧙', '𨧥', '𨧦', '𨧧', '𨧨', '𨧪', '𨧫', '𨧭', '𨧰', '𨧱', '𨧳', '𨧵', '𨨏', '𨨗', '𨨘',
+    '𨨙', '𨨚', '𨨛', '𨨜', '𨨠', '𨨡', '𨨬', '𨨭', '𨨯', '𨨰', '𨨱', '𨨲', '𨨳', '𨨴', '𨨶', '𨨷',
+    '𨨸', '𨨹', '𨨺', '𨩥', '𨩦', '𨩧', '𨩨', '𨩩', '𨩪', '𨩫', '𨩬', '𨩯', '𨩰', '𨩲', '𨩴', '𨩵',
+    '𨩶', '𨩸', '𨩻', '𨩽', '𨪄', '𨪅', '𨪈', '𨪉', '𨪊', '𨪌', '𨪍', '𨪎', '𨪏', '𨪐', '𨪑', '𨪒',
+    '𨪕', '𨪗', '𨪳', '𨪶', '𨪷', '𨪹', '𨪺', '𨪾', '𨪿', '𨫀', '𨫁', '𨫒', '𨫔', '𨬁', '𨬅', '𨬍',
+    '𨬐', '𨬑', '𨬒', '𨬔', '𨬕', '𨬖', '𨬗', '𨬚', '𨭁', '𨭂', '𨭃', '𨭅', '𨭆', '𨭉', '𨭎', '𨭓',
+    '𨭖', '𨭗', '𨭚', '𨭛', '𨭺', '𨮂', '𨮒', '𨮔', '𨮪', '𨮬', '𨮭', '𨮴', '𨮸', '𨮹', '𨮺', '𨮻',
+    '𨮿', '𨯓', '𨯞', '𨯲', '𨯽', '𨰂', '𨰆', '𨰏', '𨰑', '𨰓', '𨰛', '𨰜', '𨰞', '𨰠', '𨰦', '𨰭',
+    '𨰵', '𨰸', '𨰻', '𨰾', '𨰿', '𨱀', '𨱁', '𨱂', '𨱃', '𨱄', '𨱅', '𨱇', '𨱈', '𨱉', '𨱊', '𨱋',
+    '𨱌', '𨱍', '𨱎', '𨱏', '𨱐', '𨱑', '𨱒', '𨱓', '𨱔', '𨱕', '𨱖', '𨱙', '𨱚', '𨱛', '𨱜', '𨱝',
+    '𨱡', '𨱣', '𨱦', '𨱧', '𨱫', '𨱭', '𨱮', '𨱯', '𨱱', '𨱵', '𨱸', '𨱺', '𨱻', '𨱼', '𨱿', '𨲀',
+    '𨲁', '𨲄', '𨲆', '𨲈', '𨲉', '𨲊', '𨲋', '𨲎', '𨲐', '𨲑', '𨲒', '𨲓', '𨲗', '𨲛', '𨲜', '𨲞',
+    '𨲟', '𨲧', '𨲪', '𨲫', '𨲬', '𨲭', '𨲮', '𨲯', '𨲰', '𨲱', '𨲳', '𨲵', '𨲾', '𨲿', '𨳀', '𨳁',
+    '𨳂', '𨳆', '𨳉', '𨳊', '𨳋', '𨳌', '𨳑', '𨳔', '𨳕', '𨳗', '𨳘', '𨳙', '𨳚', '𨳛', '𨳝', '𨳞',
+    '𨳠', '𨳡', '𨳣', '𨳲', '𨳷', '𨳺', '𨳻', '𨳼', '𨳿', '𨴀', '𨴆', '𨴊', '𨴌', '𨴍', '𨴎', '𨴏',
+    '𨴐', '𨴑', '𨴒', '𨴓', '𨴗', '𨴘', '𨴚', '𨴛', '𨴜', '𨴞', '𨴡', '𨴢', '𨴣', '𨴩', '𨴪', '𨴬',
+    '𨴭', '𨴯', '𨴰', '𨴹', '𨴺', '𨴻', '𨴼', '𨴾', '𨵂', '𨵃', '𨵄', '𨵆', '𨵈', '𨵉', '𨵊', '𨵋',
+    '𨵌', '𨵍', '𨵎', '𨵐', '𨵗', '𨵘', '𨵚', '𨵛', '𨵝', '𨵞', '𨵟', '𨵠', '𨵡', '𨵥', '𨵦', '𨵧',
+    '𨵩', '𨵪', '𨵫', '𨵬', '𨵭', '𨵮', '𨵰', '𨵴', '𨵶', '𨵸', '𨵼', '𨶀', '𨶁', '𨶂', '𨶅', '𨶆',
+    '𨶇', '𨶈', '𨶊', '𨶐', '𨶗', '𨶙', '𨶜', '𨶝', '𨶟', '𨶠', '𨶢', '𨶨', '𨶪', '𨶬', '𨶭', '𨶮',
+    '𨶯', '𨶰', '𨶱', '𨶲', '𨶺', '𨶼', '𨶽', '𨶿', '𨷀', '𨷁', '𨷃', '𨷄', '𨷆', '𨷇', '𨷎', '𨷐',
+    '𨷒', '𨷓', '𨷔', '𨷘', '𨷙', '𨷥', '𨷦', '𨷩', '𨷬', '𨷭', '𨷮', '𨷱', '𨷲', '𨷻', '𨷽', '𨷾',
+    '𨷿', '𨸀', '𨸁', '𨸂', '𨸃', '𨸄', '𨸅', '𨸆', '𨸇', '𨸉', '𨸋', '𨸌', '𨸎', '𨸐', '𨸑', '𨸒',
+    '𨸓', '𨸔', '𨸗', '𨸘', '𨸙', '𨸚', '𨸛', '𨸜', '𨸝', '𨸟', '𨸩', '𨸬', '𨸭', '𨸮', '𨸯', '𨸰',
+    '𨸱', '𨸷', '𨸺', '𨸻', '𨸼', '𨹀', '𨹁', '𨹃', '𨹅', '𨹆', '𨹈', '𨹊', '𨹋', '𨹌', '𨹔', '𨹗',
+    '𨹘', '𨹙', '𨹚', '𨹜', '𨹝', '𨹡', '𨹵', '𨹶', '𨹸', '𨹹', '𨹻', '𨺀', '𨺅', '𨺖', '𨺘', '𨺙',
+    '𨺚', '𨺝', '𨺟', '𨺠', '𨺡', '𨺢', '𨺣', '𨺤', '𨺥', '𨺦', '𨺧', '𨺨', '𨺩', '𨺫', '𨺭', '𨺮',
+    '𨺵', '𨺸', '𨺹', '𨺿', '𨻀', '𨻁', '𨻂', '𨻃', '𨻄', '𨻆', '𨻈', '𨻊', '𨻌', '𨻍', '𨻏', '𨻒',
+    '𨻕', '𨻖', '𨻗', '𨻣', '𨻥', '𨻧', '𨻨', '𨻱', '𨻲', '𨻳', '𨻵', '𨻷', '𨻺', '𨻻', '𨻼', '𨼈',
+    '𨼉', '𨼊', '𨼋', '𨼌', '𨼍', '𨼎', '𨼐', '𨼑', '𨼒', '𨼓', '𨼔', '𨼣', '𨼤', '𨼥', '𨼪', '𨼫',
+    '𨼬', '𨼮', '𨼯', '𨼱', '𨼽', '𨼿', '𨽀', '𨽁', '𨽂', '𨽄', '𨽉', '𨽊', '𨽍', '𨽏', '𨽐', '𨽑',
+    '𨽖', '𨽗', '𨽟', '𨽦', '𨽧', '𨽨', '𨽯', '𨽲', '𨽷', '𨽹', '𨽻', '𨽼', '𨽿', '𨾂', '𨾅', '𨾉',
+    '𨾊', '𨾌', '𨾎', '𨾒', '𨾓', '𨾔', '𨾗', '𨾚', '𨾛', '𨾞', '𨾡', '𨾤', '𨾬', '𨾰', '𨾴', '𨾻',
+    '𨾾', '𨿁', '𨿇', '𨿏', '𨿐', '𨿛', '𨿠', '𨿢', '𨿭', '𨿿', '𩀋', '𩀖', '𩀜', '𩀞', '𩀠', '𩀣',
+    '𩀧', '𩀪', '𩀫', '𩀲', '𩀶', '𩀼', '𩀿', '𩁌', '𩁞', '𩁟', '𩁯', '𩁴', '𩁷', '𩁺', '𩂂', '𩂃',
+    '𩂄', '𩂆', '𩂈', '𩂉', '𩂍', '𩂎', '𩂒', '𩂓', '𩂔', '𩂕', '𩂖', '𩂗', '𩂙', '𩂝', '𩂞', '𩂢',
+    '𩂣', '𩂤', '𩂥', '𩂦', '𩂧', '𩂨', '𩂪', '𩂫', '𩂴', '𩂵', '𩂹', '𩂻', '𩂼', '𩂽', '𩂾', '𩂿',
+    '𩃀', '𩃁', '𩃃', '𩃋', '𩃍', '𩃎', '𩃐', '𩃑', '𩃒', '𩃕', '𩃖', '𩃘', '𩃙', '𩃚', '𩃜', '𩃞',
+    '𩃠', '𩃡', '𩃬', '𩃭', '𩃯', '𩃰', '𩃱', '𩃴', '𩃵', '𩃶', '𩃷', '𩃹', '𩃺', '𩃾', '𩄅', '𩄊',
+    '𩄋', '𩄕', '𩄖', '𩄗', '𩄘', '𩄙', '𩄚', '𩄛', '𩄜', '𩄝', '𩄞', '𩄠', '𩄡', '𩄢', '𩄦', '𩄪',
+    '𩄬', '𩄮', '𩄯', '𩄷', '𩄸', '𩄺', '𩄻', '𩄼', '𩄽', '𩄾', '𩄿', '𩅀', '𩅁', '𩅃', '𩅄', '𩅅',
+    '𩅆', '𩅇', '𩅌', '𩅍', '𩅖', '𩅝', '𩅞', '𩅠', '𩅡', '𩅢', '𩅥', '𩅦', '𩅧', '𩅨', '𩅩', '𩅪',
+    '𩅮', '𩅰', '𩅲', '𩅴', '𩅼', '𩅽', '𩅾', '𩅿', '𩆀', '𩆁', '𩆂', '𩆄', '𩆅', '𩆆', '𩆎', '𩆑',
+    '𩆒', '𩆓', '𩆔', '𩆘', '𩆚', '𩆝', '𩆟', '𩆣', '𩆤', '𩆦', '𩆫', '𩆮', '𩆯', '𩆱', '𩆲', '𩆵',
+    '𩆶', '𩆷', '𩆸', '𩆻', '𩆼', '𩆽', '𩆿', '𩇄', '𩇇', '𩇎', '𩇏', '𩇐', '𩇔', '𩇕', '𩇖', '𩇜',
+    '𩇝', '𩇟', '𩇠', '𩇣', '𩇩', '𩇫', '𩇮', '𩇯', '𩇴', '𩇵', '𩇺', '𩇻', '𩇽', '𩈁', '𩈆', '𩈇',
+    '𩈈', '𩈉', '𩈊', '𩈋', '𩈍', '𩈎', '𩈏', '𩈐', '𩈑', '𩈔', '𩈕', '𩈖', '𩈗', '𩈚', '𩈜', '𩈞',
+    '𩈡', '𩈢', '𩈣', '𩈤', '𩈥', '𩈦', '𩈬', '𩈭', '𩈮', '𩈯', '𩈱', '𩈲', '𩈴', '𩈵', '𩈶', '𩈸',
+    '𩈹', '𩈺', '𩈻', '𩈼', '𩉀', '𩉁', '𩉂', '𩉄', '𩉆', '𩉋', '𩉍', '𩉐', '𩉔', '𩉕', '𩉗', '𩉙',
+    '𩉜', '𩉝', '𩉡', '𩉢', '𩉥', '𩉧', '𩉫', '𩉬', '𩉯', '𩉰', '𩉱', '𩉴', '𩉵', '𩉸', '𩉹', '𩉺',
+    '𩉼', '𩉽', '𩉾', '𩉿', '𩊀', '𩊁', '𩊂', '𩊃', '𩊄', '𩊅', '𩊇', '𩊌', '𩊍', '𩊏', '𩊑', '𩊓',
+    '𩊔', '𩊖', '𩊚', '𩊛', '𩊜', '𩊝', '𩊡', '𩊢', '𩊣', '𩊤', '𩊨', '𩊩', '𩊪', '𩊫', '𩊬', '𩊭',
+    '𩊮', '𩊯', '𩊰', '𩊴', '𩊵', '𩊶', '𩊺', '𩊻', '𩊿', '𩋁', '𩋂', '𩋃', '𩋄', '𩋅', '𩋆', '𩋈',
+    '𩋉', '𩋊', '𩋋', '𩋌', '𩋍', '𩋏', '𩋒', '𩋗', '𩋘', '𩋙', '𩋜', '𩋝', '𩋞', '𩋟', '𩋠', '𩋡',
+    '𩋢', '𩋣', '𩋤', '𩋧', '𩋨', '𩋩', '𩋪', '𩋫', '𩋬', '𩋮', '𩋯', '𩋰', '𩋱', '𩋵', '𩋷', '𩋹',
+    '𩋺', '𩋻', '𩋽', '𩋾', '𩋿', '𩌃', '𩌄', '𩌆', '𩌇', '𩌈', '𩌉', '𩌊', '𩌌', '𩌍', '𩌏', '𩌐',
+    '𩌑', '𩌘', '𩌝', '𩌠', '𩌡', '𩌢', '𩌧', '𩌨', '𩌩', '𩌪', '𩌫', '𩌬', '𩌭', '𩌮', '𩌯', '𩌰',
+    '𩌲', '𩌾', '𩍂', '𩍃', '𩍅', '𩍆', '𩍉', '𩍋', '𩍌', '𩍎', '𩍏', '𩍐', '𩍓', '𩍔', '𩍖', '𩍗',
+    '𩍙', '𩍚', '𩍜', '𩍝', '𩍡', '𩍥', '𩍦', '𩍧', '𩍨', '𩍪', '𩍲', '𩍳', '𩍵', '𩍷', '𩍸', '𩍻',
+    '𩍼', '𩍿', '𩎂', '𩎃', '𩎉', '𩎊', '𩎎', '𩎑', '𩎒', '𩎔', '𩎕', '𩎖', '𩎗', '𩎘', '𩎙', '𩎚',
+    '𩎛', '𩎜', '𩎟', '𩎢', '𩎤', '𩎥', '𩎫', '𩎭', '𩎰', '𩎲', '𩎳', '𩎵', '𩎷', '𩎹', '𩎻', '𩎼',
+    '𩎽', '𩎾', '𩏂', '𩏅', '𩏆', '𩏇', '𩏈', '𩏉', '𩏏', '𩏐', '𩏒', '𩏓', '𩏖', '𩏗', '𩏘', '𩏚',
+    '𩏝', '𩏞', '𩏟', '𩏠', '𩏡', '𩏣', '𩏤', '𩏥', '𩏩', '𩏪', '𩏫', '𩏭', '𩏮', '𩏯', '𩏲', '𩏴',
+    '𩏵', '𩏶', '𩏷', '𩏹', '𩏺', '𩏼', '𩏽', '𩏾', '𩏿', '𩐀', '𩐁', '𩐅', '𩐆', '𩐉', '𩐌', '𩐍',
+    '𩐘', '𩐙', '𩐚', '𩐛', '𩐜', '𩐞', '𩐠', '𩐡', '𩐣', '𩐤', '𩐥', '𩐦', '𩐨', '𩐫', '𩐬', '𩐭',
+    '𩐮', '𩐯', '𩐰', '𩐱', '𩐴', '𩐵', '𩐶', '𩐷', '𩐻', '𩐼', '𩐾', '𩑀', '𩑁', '𩑂', '𩑃', '𩑇',
+    '𩑈', '𩑉', '𩑊', '𩑍', '𩑐', '𩑒', '𩑓', '𩑔', '𩑕', '𩑖', '𩑗', '𩑘', '𩑙', '𩑚', '𩑝', '𩑟',
+    '𩑡', '𩑢', '𩑣', '𩑤', '𩑥', '𩑦', '𩑬', '𩑭', '𩑮', '𩑯', '𩑰', '𩑳', '𩑴', '𩑵', '𩑶', '𩑷',
+    '𩑸', '𩑹', '𩑻', '𩑼', '𩑾', '𩒀', '𩒂', '𩒃', '𩒄', '𩒇', '𩒈', '𩒊', '𩒏', '𩒐', '𩒓', '𩒔',
+    '𩒕', '𩒖', '𩒘', '𩒙', '𩒚', '𩒛', '𩒝', '𩒞', '𩒢', '𩒣', '𩒦', '𩒧', '𩒨', '𩒪', '𩒮', '𩒰',
+    '𩒳', '𩒴', '𩒵', '𩒷', '𩒺', '𩒼', '𩒽', '𩒾', '𩒿', '𩓂', '𩓅', '𩓐', '𩓒', '𩓓', '𩓖', '𩓗',
+    '𩓝', '𩓞', '𩓟', '𩓠', '𩓢', '𩓣', '𩓤', '𩓥', '𩓦', '𩓧', '𩓨', '𩓪', '𩓫', '𩓬', '𩓮', '𩓺',
+    '𩓻', '𩓼', '𩓽', '𩔀', '𩔁', '𩔂', '𩔃', '𩔄', '𩔆', '𩔇', '𩔈', '𩔉', '𩔊', '𩔋', '𩔔', '𩔕',
+    '𩔗', '𩔘', '𩔙', '𩔚', '𩔝', '𩔟', '𩔠', '𩔡', '𩔢', '𩔣', '𩔥', '𩔦', '𩔮', '𩔱', '𩔳', '𩔴',
+    '𩔵', '𩔶', '𩔷', '𩔸', '𩔹', '𩔻', '𩔼', '𩕀', '𩕆', '𩕉', '𩕊', '𩕌', '𩕍', '𩕏', '𩕐', '𩕕',
+    '𩕖', '𩕜', '𩕟', '𩕠', '𩕡', '𩕤', '𩕪', '𩕬', '𩕭', '𩕯', '𩕱', '𩕲', '𩕳', '𩕵', '𩕹', '𩕾',
+    '𩖀', '𩖁', '𩖄', '𩖇', '𩖉', '𩖊', '𩖌', '𩖍', '𩖒', '𩖔', '𩖕', '𩖖', '𩖗', '𩖛', '𩖝', '𩖠',
+    '𩖣', '𩖤', '𩖥', '𩖦', '𩖨', '𩖩', '𩖬', '𩖭', '𩖯', '𩖰', '𩖱', '𩖴', '𩖵', '𩖶', '𩖷', '𩖸',
+    '𩖹', '𩖺', '𩖼', '𩖽', '𩖾', '𩗀', '𩗄', '𩗅', '𩗉', '𩗊', '𩗎', '𩗏', '𩗐', '𩗑', '𩗒', '𩗓',
+    '𩗔', '𩗕', '𩗘', '𩗙', '𩗚', '𩗜', '𩗝', '𩗞', '𩗢', '𩗣', '𩗤', '𩗥', '𩗧', '𩗩', '𩗪', '𩗫',
+    '𩗭', '𩗰', '𩗱', '𩗲', '𩗴', '𩗵', '𩗶', '𩗷', '𩗼', '𩗽', '𩗾', '𩘀', '𩘁', '𩘅', '𩘇', '𩘈',
+    '𩘊', '𩘋', '𩘍', '𩘎', '𩘏', '𩘑', '𩘒', '𩘓', '𩘘', '𩘜', '𩘝', '𩘟', '𩘠', '𩘡', '𩘤', '𩘧',
+    '𩘭', '𩘮', '𩘯', '𩘰', '𩘱', '𩘳', '𩘵', '𩘹', '𩘺', '𩘻', '𩘼', '𩘽', '𩘿', '𩙄', '𩙆', '𩙇',
+    '𩙈', '𩙏', '𩙐', '𩙑', '𩙒', '𩙖', '𩙘', '𩙙', '𩙚', '𩙛', '𩙜', '𩙝', '𩙠', '𩙡', '𩙢', '𩙣',
+    '𩙥', '𩙦', '𩙧', '𩙨', '𩙩', '𩙪', '𩙫', '𩙬', '𩙭', '𩙮', '𩙯', '𩙰', '𩙲', '𩙷', '𩙸', '𩙽',
+    '𩙾', '𩚂', '𩚄', '𩚇', '𩚈', '𩚉', '𩚕', '𩚖', '𩚗', '𩚛', '𩚣', '𩚥', '𩚪', '𩚫', '𩚬', '𩚭',
+    '𩚮', '𩚯', '𩚱', '𩚲', '𩚵', '𩚹', '𩚻', '𩚾', '𩛅', '𩛆', '𩛇', '𩛋', '𩛌', '𩛎', '𩛏', '𩛑',
+    '𩛘', '𩛝', '𩛞', '𩛟', '𩛠', '𩛡', '𩛢', '𩛥', '𩛦', '𩛧', '𩛨', '𩛩', '𩛪', '𩛭', '𩛮', '𩛲',
+    '𩛳', '𩛵', '𩛶', '𩛹', '𩛺', '𩛻', '𩛼', '𩛽', '𩜀', '𩜁', '𩜃', '𩜆', '𩜇', '𩜊', '𩜌', '𩜍',
+    '𩜒', '𩜖', '𩜟', '𩜣', '𩜥', '𩜦', '𩜧', '𩜬', '𩜭', '𩜰', '𩜱', '𩜲', '𩜳', '𩜵', '𩜶', '𩜷',
+    '𩜸', '𩜺', '𩜽', '𩝈', '𩝊', '𩝌', '𩝐', '𩝔', '𩝕', '𩝖', '𩝗', '𩝚', '𩝛', '𩝝', '𩝞', '𩝟',
+    '𩝠', '𩝣', '𩝥', '𩝨', '𩝷', '𩝸', '𩝽', '𩝾', '𩝿', '𩞀', '𩞁', '𩞃', '𩞄', '𩞅', '𩞆', '𩞇',
+    '𩞈', '𩞉', '𩞊', '𩞋', '𩞙', '𩞞', '𩞤', '𩞥', '𩞦', '𩞧', '𩞨', '𩞩', '𩞬', '𩞯', '𩞶', '𩞺',
+    '𩞻', '𩞾', '𩞿', '𩟀', '𩟁', '𩟂', '𩟃', '𩟅', '𩟇', '𩟈', '𩟉', '𩟊', '𩟋', '𩟍', '𩟐', '𩟑',
+    '𩟒', '𩟓', '𩟔', '𩟕', '𩟗', '𩟞', '𩟟', '𩟠', '𩟥', '𩟦', '𩟧', '𩟨', '𩟫', '𩟭', '𩟮', '𩟰',
+    '𩟳', '𩟶', '𩟷', '𩟸', '𩟺', '𩟿', '𩠀', '𩠁', '𩠂', '𩠃', '𩠅', '𩠆', '𩠇', '𩠈', '𩠉', '𩠊',
+    '𩠋', '𩠌', '𩠎', '𩠏', '𩠑', '𩠚', '𩠟', '𩠦', '𩠨', '𩠮', '𩠯', '𩠶', '𩠷', '𩠹', '𩠻', '𩠽',
+    '𩠾', '𩠿', '𩡃', '𩡄', '𩡌', '𩡎', '𩡓', '𩡔', '𩡕', '𩡝', '𩡟', '𩡠', '𩡣', '𩡦', '𩡧', '𩡨',
+    '𩡩', '𩡫', '𩡯', '𩡷', '𩡺', '𩡾', '𩢄', '𩢅', '𩢈', '𩢊', '𩢌', '𩢍', '𩢎', '𩢐', '𩢑', '𩢒',
+    '𩢔', '𩢕', '𩢖', '𩢘', '𩢛', '𩢞', '𩢡', '𩢨', '𩢪', '𩢫', '𩢮', '𩢯', '𩢰', '𩢱', '𩢳', '𩢴',
+    '𩢵', '𩢶', '𩢷', '𩢸', '𩢹', '𩢻', '𩢼', '𩢽', '𩢾', '𩢿', '𩣊', '𩣖', '𩣘', '𩣚', '𩣜', '𩣝',
+    '𩣞', '𩣡', '𩣣', '𩣩', '𩣫', '𩣮', '𩣯', '𩣱', '𩣲', '𩣳', '𩣴', '𩣵', '𩣶', '𩣷', '𩣸', '𩣹',
+    '𩣺', '𩣻', '𩣼', '𩤁', '𩤈', '𩤉', '𩤊', '𩤋', '𩤏', '𩤒', '𩤖', '𩤗', '𩤘', '𩤙', '𩤚', '𩤛',
+    '𩤜', '𩤞', '𩤟', '𩤠', '𩤡', '𩤣', '𩤤', '𩤥', '𩤦', '𩤩', '𩤲', '𩤸', '𩤹', '𩤺', '𩤽', '𩤿',
+    '𩥁', '𩥂', '𩥄', '𩥅', '𩥆', '𩥇', '𩥈', '𩥉', '𩥊', '𩥋', '𩥌', '𩥐', '𩥑', '𩥔', '𩥘', '𩥠',
+    '𩥣', '𩥫', '𩥬', '𩥭', '𩥮', '𩥯', '𩥲', '𩥴', '𩥽', '𩥿', '𩦂', '𩦇', '𩦉', '𩦊', '𩦋', '𩦌',
+    '𩦎', '𩦐', '𩦘', '𩦠', '𩦡', '𩦢', '𩦤', '𩦱', '𩦲', '𩦹', '𩦺', '𩧃', '𩧄', '𩧅', '𩧆', '𩧇',
+    '𩧈', '𩧉', '𩧊', '𩧋', '𩧌', '𩧏', '𩧐', '𩧒', '𩧓', '𩧘', '𩧜', '𩧡', '𩧦', '𩧨', '𩧩', '𩧪',
+    '𩧫', '𩧬', '𩧭', '𩧮', '𩧯', '𩧰', '𩧱', '𩧲', '𩧳', '𩧴', '𩧵', '𩧺', '𩧼', '𩧿', '𩨀', '𩨁',
+    '𩨃', '𩨄', '𩨅', '𩨆', '𩨇', '𩨈', '𩨉', '𩨊', '𩨋', '𩨌', '𩨍', '𩨏', '𩨐', '𩨑', '𩨒', '𩨓',
+    '𩨔', '𩨗', '𩨘', '𩨙', '𩨚', '𩨜', '𩨝', '𩨞', '𩨟', '𩨠', '𩨡', '𩨨', '𩨩', '𩨫', '𩨬', '𩨭',
+    '𩨮', '𩨯', '𩨲', '𩨳', '𩨴', '𩨷', '𩨸', '𩨹', '𩨻', '𩨽', '𩨾', '𩩀', '𩩄', '𩩅', '𩩋', '𩩌',
+    '𩩍', '𩩑', '𩩒', '𩩔', '𩩗', '𩩘', '𩩛', '𩩝', '𩩞', '𩩟', '𩩠', '𩩡', '𩩢', '𩩤', '𩩥', '𩩧',
+    '𩩮', '𩩯', '𩩰', '𩩱', '𩩲', '𩩳', '𩩴', '𩩶', '𩩺', '𩩻', '𩩼', '𩩾', '𩪀', '𩪁', '𩪂', '𩪃',
+    '𩪈', '𩪉', '𩪊', '𩪌', '𩪍', '𩪎', '𩪐', '𩪖', '𩪗', '𩪘', '𩪛', '𩪟', '𩪢', '𩪣', '𩪤', '𩪥',
+    '𩪧', '𩪭', '𩪮', '𩪱', '𩪴', '𩪸', '𩪺', '𩪾', '𩫀', '𩫁', '𩫂', '𩫇', '𩫊', '𩫍', '𩫏', '𩫓',
+    '𩫔', '𩫕', '𩫠', '𩫥', '𩫦', '𩫫', '𩫲', '𩫳', '𩫴', '𩫹', '𩫺', '𩬀', '𩬍', '𩬏', '𩬑', '𩬔',
+    '𩬖', '𩬗', '𩬙', '𩬚', '𩬛', '𩬜', '𩬝', '𩬞', '𩬟', '𩬠', '𩬤', '𩬫', '𩬭', '𩬮', '𩬰', '𩬱',
+    '𩬲', '𩬳', '𩬵', '𩬷', '𩬸', '𩬹', '𩬺', '𩬻', '𩬼', '𩬽', '𩭇', '𩭊', '𩭋', '𩭌', '𩭏', '𩭐',
+    '𩭑', '𩭒', '𩭓', '𩭘', '𩭝', '𩭟', '𩭡', '𩭢', '𩭣', '𩭤', '𩭥', '𩭦', '𩭧', '𩭩', '𩭫', '𩭯',
+    '𩭲', '𩭳', '𩭷', '𩭺', '𩭼', '𩭽', '𩭾', '𩭿', '𩮀', '𩮁', '𩮂', '𩮃', '𩮄', '𩮅', '𩮉', '𩮎',
+    '𩮏', '𩮐', '𩮑', '𩮖', '𩮗', '𩮘', '𩮚', '𩮛', '𩮜', '𩮝', '𩮟', '𩮠', '𩮫', '𩮬', '𩮯', '𩮱',
+    '𩮳', '𩮴', '𩮵', '𩮶', '𩮷', '𩮸', '𩮹', '𩯃', '𩯄', '𩯆', '𩯇', '𩯈', '𩯉', '𩯊', '𩯋', '𩯌',
+    '𩯍', '𩯎', '𩯏', '𩯑', '𩯒', '𩯘', '𩯛', '𩯜', '𩯞', '𩯨', '𩯰', '𩯱', '𩯲', '𩯳', '𩯺', '𩯽',
+    '𩰀', '𩰃', '𩰍', '𩰎', '𩰓', '𩰗', '𩰙', '𩰞', '𩰟', '𩰠', '𩰢', '𩰤', '𩰨', '𩰪', '𩰬', '𩰭',
+    '𩰯', '𩰲', '𩰳', '𩰴', '𩰵', '𩰶', '𩰹', '𩰻', '𩰽', '𩰾', '𩱀', '𩱁', '𩱃', '𩱄', '𩱆', '𩱇',
+    '𩱈', '𩱊', '𩱋', '𩱌', '𩱍', '𩱎', '𩱏', '𩱓', '𩱘', '𩱙', '𩱚', '𩱝', '𩱞', '𩱦', '𩱧', '𩱨',
+    '𩱪', '𩱬', '𩱱', '𩱲', '𩱳', '𩱴', '𩱷', '𩱻', '𩱼', '𩱾', '𩲁', '𩲂', '𩲃', '𩲄', '𩲅', '𩲈',
+    '𩲊', '𩲋', '𩲌', '𩲍', '𩲎', '𩲏', '𩲒', '𩲠', '𩲡', '𩲢', '𩲣', '𩲤', '𩲦', '𩲧', '𩲨', '𩲩',
+    '𩲪', '𩲬', '𩲱', '𩲲', '𩲴', '𩲵', '𩲷', '𩲹', '𩲻', '𩲾', '𩳅', '𩳆', '𩳇', '𩳈', '𩳌', '𩳍',
+    '𩳎', '𩳏', '𩳐', '𩳒', '𩳓', '𩳔', '𩳕', '𩳝', '𩳡', '𩳢', '𩳣', '𩳤', '𩳥', '𩳧', '𩳨', '𩳯',
+    '𩳲', '𩳵', '𩳶', '𩳸', '𩴀', '𩴁', '𩴂', '𩴃', '𩴇', '𩴈', '𩴉', '𩴍', '𩴐', '𩴑', '𩴒', '𩴓',
+    '𩴘', '𩴙', '𩴜', '𩴝', '𩴞', '𩴟', '𩴠', '𩴡', '𩴣', '𩴤', '𩴧', '𩴨', '𩴩', '𩴪', '𩴮', '𩴱',
+    '𩴲', '𩴳', '𩴵', '𩴹', '𩴺', '𩴻', '𩵀', '𩵄', '𩵅', '𩵇', '𩵉', '𩵍', '𩵎', '𩵏', '𩵓', '𩵕',
+    '𩵖', '𩵗', '𩵚', '𩵛', '𩵠', '𩵢', '𩵣', '𩵥', '𩵦', '𩵩', '𩵬', '𩵭', '𩵮', '𩵰', '𩵱', '𩵹',
+    '𩶁', '𩶂', '𩶄', '𩶅', '𩶇', '𩶈', '𩶉', '𩶎', '𩶖', '𩶘', '𩶛', '𩶞', '𩶡', '𩶢', '𩶣', '𩶤',
+    '𩶥', '𩶦', '𩶧', '𩶩', '𩶫', '𩶭', '𩶯', '𩶱', '𩷁', '𩷂', '𩷄', '𩷋', '𩷌', '𩷍', '𩷎', '𩷏',
+    '𩷐', '𩷓', '𩷕', '𩷖', '𩷗', '𩷘', '𩷚', '𩷧', '𩷭', '𩷯', '𩷰', '𩷵', '𩷶', '𩷸', '𩷹', '𩷻',
+    '𩷽', '𩷾', '𩸀', '𩸁', '𩸂', '𩸃', '𩸄', '𩸇', '𩸋', '𩸎', '𩸐', '𩸖', '𩸞', '𩸟', '𩸥', '𩸦',
+    '𩸧', '𩸨', '𩸹', '𩸾', '𩸿', '𩹀', '𩹂', '𩹄', '𩹅', '𩹇', '𩹈', '𩹉', '𩹊', '𩹍', '𩹎', '𩹏',
+    '𩹑', '𩹞', '𩹟', '𩹢', '𩹥', '𩹱', '𩹳', '𩹴', '𩹵', '𩹶', '𩹷', '𩹸', '𩹺', '𩹼', '𩹾', '𩹿',
+    '𩺄', '𩺗', '𩺛', '𩺝', '𩺞', '𩺫', '𩺯', '𩺰', '𩺱', '𩺲', '𩺵', '𩺼', '𩻋', '𩻌', '𩻎', '𩻖',
+    '𩻗', '𩻘', '𩻚', '𩻛', '𩻜', '𩻝', '𩻟', '𩻡', '𩻢', '𩻤', '𩻧', '𩻱', '𩻵', '𩻷', '𩻼', '𩼂',
+    '𩼄', '𩼅', '𩼈', '𩼉', '𩼊', '𩼋', '𩼌', '𩼒', '𩼙', '𩼚', '𩼦', '𩼨', '𩼫', '𩼭', '𩼴', '𩼼',
+    '𩽀', '𩽁', '𩽆', '𩽇', '𩽍', '𩽎', '𩽏', '𩽛', '𩽜', '𩽝', '𩽞', '𩽡', '𩽧', '𩽨', '𩽩', '𩽰',
+    '𩽳', '𩽴', '𩽵', '𩽷', '𩽹', '𩽺', '𩽻', '𩽼', '𩽽', '𩽾', '𩾁', '𩾂', '𩾃', '𩾄', '𩾅', '𩾆',
+    '𩾇', '𩾈', '𩾊', '𩾋', '𩾌', '𩾒', '𩾓', '𩾔', '𩾕', '𩾗', '𩾘', '𩾚', '𩾝', '𩾞', '𩾠', '𩾡',
+    '𩾢', '𩾧', '𩾬', '𩾳', '𩾶', '𩾷', '𩾸', '𩾺', '𩾻', '𩾼', '𩾽', '𩾾', '𩾿', '𩿀', '𩿂', '𩿃',
+    '𩿄', '𩿈', '𩿉', '𩿊', '𩿑', '𩿓', '𩿔', '𩿕', '𩿛', '𩿝', '𩿟', '𩿠', '𩿡', '𩿢', '𩿣', '𩿤',
+    '𩿥', '𩿧', '𩿩', '𩿪', '𩿬', '𩿷', '𩿺', '𩿽', '𩿿', '𪀁', '𪀈', '𪀉', '𪀊', '𪀏', '𪀒', '𪀓',
+    '𪀔', '𪀕', '𪀗', '𪀘', '𪀚', '𪀛', '𪀝', '𪀞', '𪀟', '𪀠', '𪀣', '𪀥', '𪀦', '𪀧', '𪀩', '𪀪',
+    '𪀬', '𪀭', '𪀺', '𪀽', '𪀾', '𪀿', '𪁀', '𪁈', '𪁉', '𪁊', '𪁋', '𪁍', '𪁎', '𪁏', '𪁐', '𪁑',
+    '𪁓', '𪁔', '𪁕', '𪁖', '𪁘', '𪁚', '𪁛', '𪁜', '𪁞', '𪁟', '𪁠', '𪁡', '𪁥', '𪁧', '𪁩', '𪁪',
+    '𪁮', '𪁱', '𪁲', '𪁸', '𪁹', '𪁺', '𪁼', '𪁽', '𪁾', '𪂀', '𪂄', '𪂅', '𪂆', '𪂇', '𪂈', '𪂉',
+    '𪂋', '𪂌', '𪂍', '𪂏', '𪂒', '𪂓', '𪂕', '𪂛', '𪂜', '𪂟', '𪂠', '𪂤', '𪂦', '𪂧', '𪂴', '𪂵',
+    '𪂶', '𪂹', '𪂺', '𪂼', '𪂽', '𪂾', '𪃂', '𪃄', '𪃅', '𪃆', '𪃈', '𪃋', '𪃍', '𪃎', '𪃏', '𪃐',
+    '𪃑', '𪃒', '𪃓', '𪃛', '𪃦', '𪃨', '𪃭', '𪃶', '𪃺', '𪃼', '𪃾', '𪃿', '𪄀', '𪄁', '𪄂', '𪄅',
+    '𪄆', '𪄈', '𪄉', '𪄊', '𪄌', '𪄎', '𪄏', '𪄑', '𪄕', '𪄖', '𪄗', '𪄛', '𪄝', '𪄭', '𪄮', '𪄯',
+    '𪄱', '𪄲', '𪄳', '𪄴', '𪄵', '𪄶', '𪄷', '𪄸', '𪄹', '𪄺', '𪄻', '𪅂', '𪅃', '𪅄', '𪅅', '𪅆',
+    '𪅇', '𪅈', '𪅉', '𪅋', '𪅍', '𪅖', '𪅙', '𪅝', '𪅟', '𪅮', '𪅰', '𪅲', '𪅴', '𪅵', '𪅸', '𪅹',
+    '𪅺', '𪅻', '𪅼', '𪅾', '𪆀', '𪆁', '𪆂', '𪆋', '𪆗', '𪆛', '𪆠', '𪆯', '𪆰', '𪆱', '𪆲', '𪆳',
+    '𪆴', '𪆵', '𪆶', '𪆷', '𪆹', '𪆻', '𪆿', '𪇁', '𪇂', '𪇄', '𪇆', '𪇇', '𪇈', '𪇊', '𪇑', '𪇒',
+    '𪇓', '𪇕', '𪇖', '𪇗', '𪇘', '𪇛', '𪇜', '𪇝', '𪇞', '𪇭', '𪇯', '𪇰', '𪇱', '𪇲', '𪇳', '𪇴',
+    '𪇵', '𪇷', '𪇹', '𪈀', '𪈃', '𪈆', '𪈇', '𪈈', '𪈊', '𪈋', '𪈑', '𪈘', '𪈜', '𪈟', '𪈥', '𪈨',
+    '𪈩', '𪈰', '𪈴', '𪈸', '𪈻', '𪈼', '𪈿', '𪉂', '𪉃', '𪉄', '𪉅', '𪉆', '𪉈', '𪉉', '𪉊', '𪉋',
+    '𪉌', '𪉍', '𪉎', '𪉏', '𪉐', '𪉑', '𪉒', '𪉔', '𪉕', '𪉖', '𪉗', '𪉘', '𪉚', '𪉜', '𪉢', '𪉣',
+    '𪉦', '𪉧', '𪉨', '𪉪', '𪉭', '𪉮', '𪉯', '𪉱', '𪉸', '𪉻', '𪉿', '𪊂', '𪊄', '𪊆', '𪊇', '𪊉',
+    '𪊍', '𪊐', '𪊕', '𪊗', '𪊜', '𪊢', '𪊥', '𪊧', '𪊪', '𪊳', '𪊵', '𪊶', '𪊷', '𪊹', '𪊻', '𪋁',
+    '𪋄', '𪋅', '𪋆', '𪋇', '𪋉', '𪋊', '𪋋', '𪋌', '𪋍', '𪋏', '𪋐', '𪋑', '𪋖', '𪋗', '𪋜', '𪋝',
+    '𪋡', '𪋢', '𪋫', '𪋬', '𪋮', '𪋰', '𪋳', '𪋺', '𪋾', '𪌀', '𪌂', '𪌄', '𪌅', '𪌆', '𪌈', '𪌉',
+    '𪌊', '𪌋', '𪌍', '𪌏', '𪌓', '𪌔', '𪌘', '𪌞', '𪌟', '𪌢', '𪌣', '𪌤', '𪌧', '𪌩', '𪌪', '𪌫',
+    '𪌬', '𪌮', '𪌰', '𪌱', '𪌳', '𪌵', '𪌶', '𪌷', '𪌹', '𪌺', '𪌼', '𪌽', '𪌿', '𪍁', '𪍂', '𪍄',
+    '𪍅', '𪍇', '𪍈', '𪍌', '𪍎', '𪍏', '𪍑', '𪍔', '𪍗', '𪍛', '𪍝', '𪍟', '𪍠', '𪍡', '𪍣', '𪍤',
+    '𪍦', '𪍧', '𪍨', '𪍩', '𪍪', '𪍲', '𪍴', '𪍵', '𪍶', '𪍸', '𪍹', '𪍺', '𪍻', '𪍿', '𪎃', '𪎅',
+    '𪎆', '𪎇', '𪎈', '𪎉', '𪎊', '𪎋', '𪎒', '𪎔', '𪎗', '𪎛', '𪎝', '𪎞', '𪎠', '𪎣', '𪎤', '𪎦',
+    '𪎨', '𪎫', '𪎭', '𪎲', '𪎴', '𪎵', '𪎶', '𪎺', '𪎻', '𪎽', '𪎾', '𪏀', '𪏁', '𪏂', '𪏅', '𪏆',
+    '𪏇', '𪏈', '𪏉', '𪏊', '𪏋', '𪏍', '𪏏', '𪏐', '𪏒', '𪏓', '𪏔', '𪏖', '𪏗', '𪏙', '𪏚', '𪏟',
+    '𪏠', '𪏢', '𪏤', '𪏦', '𪏨', '𪏩', '𪏪', '𪏫', '𪏮', '𪏲', '𪏳', '𪏴', '𪏵', '𪏶', '𪏸', '𪏺',
+    '𪏻', '𪏼', '𪏿', '𪐀', '𪐂', '𪐃', '𪐄', '𪐅', '𪐆', '𪐇', '𪐈', '𪐋', '𪐌', '𪐍', '𪐎', '𪐏',
+    '𪐓', '𪐔', '𪐖', '𪐘', '𪐝', '𪐞', '𪐣', '𪐥', '𪐦', '𪐧', '𪐬', '𪐮', '𪐯', '𪐰', '𪐲', '𪐺',
+    '𪐻', '𪐿', '𪑀', '𪑂', '𪑃', '𪑄', '𪑆', '𪑈', '𪑉', '𪑊', '𪑋', '𪑌', '𪑏', '𪑐', '𪑒', '𪑕',
+    '𪑖', '𪑗', '𪑙', '𪑚', '𪑜', '𪑝', '𪑟', '𪑦', '𪑧', '𪑨', '𪑩', '𪑪', '𪑬', '𪑭', '𪑮', '𪑯',
+    '𪑰', '𪑱', '𪑳', '𪑶', '𪑷', '𪑸', '𪑹', '𪑻', '𪑽', '𪑾', '𪑿', '𪒀', '𪒂', '𪒄', '𪒆', '𪒇',
+    '𪒊', '𪒋', '𪒍', '𪒏', '𪒑', '𪒒', '𪒔', '𪒕', '𪒘', '𪒙', '𪒛', '𪒜', '𪒝', '𪒟', '𪒠', '𪒡',
+    '𪒢', '𪒥', '𪒩', '𪒪', '𪒫', '𪒬', '𪒭', '𪒯', '𪒲', '𪒴', '𪒹', '𪒼', '𪒾', '𪒿', '𪓀', '𪓃',
+    '𪓄', '𪓅', '𪓊', '𪓌', '𪓍', '𪓐', '𪓘', '𪓚', '𪓛', '𪓜', '𪓡', '𪓬', '𪓮', '𪓰', '𪓷', '𪓹',
+    '𪓻', '𪓼', '𪓿', '𪔂', '𪔃', '𪔅', '𪔆', '𪔊', '𪔋', '𪔍', '𪔏', '𪔑', '𪔔', '𪔕', '𪔗', '𪔘',
+    '𪔙', '𪔟', '𪔣', '𪔦', '𪔩', '𪔪', '𪔬', '𪔰', '𪔳', '𪔶', '𪔷', '𪔺', '𪔽', '𪔾', '𪔿', '𪕀',
+    '𪕁', '𪕃', '𪕇', '𪕈', '𪕉', '𪕊', '𪕌', '𪕍', '𪕎', '𪕒', '𪕓', '𪕔', '𪕝', '𪕞', '𪕦', '𪕨',
+    '𪕩', '𪕫', '𪕭', '𪕮', '𪕯', '𪕱', '𪕳', '𪕴', '𪕶', '𪕷', '𪕹', '𪖀', '𪖁', '𪖂', '𪖄', '𪖇',
+    '𪖈', '𪖉', '𪖋', '𪖌', '𪖍', '𪖎', '𪖐', '𪖕', '𪖙', '𪖚', '𪖛', '𪖜', '𪖞', '𪖢', '𪖥', '𪖦',
+    '𪖩', '𪖯', '𪖲', '𪖳', '𪖶', '𪖷', '𪖼', '𪗁', '𪗂', '𪗅', '𪗆', '𪗉', '𪗋', '𪗍', '𪗏', '𪗐',
+    '𪗒', '𪗓', '𪗔', '𪗖', '𪗙', '𪗜', '𪗝', '𪗤', '𪗦', '𪗧', '𪗨', '𪗭', '𪗴', '𪗵', '𪗷', '𪗸',
+    '𪗹', '𪗻', '𪗽', '𪗾', '𪘀', '𪘁', '𪘃', '𪘆', '𪘇', '𪘉', '𪘊', '𪘎', '𪘐', '𪘑', '𪘒', '𪘓',
+    '𪘕', '𪘗', '𪘘', '𪘛', '𪘝', '𪘥', '𪘦', '𪘧', '𪘨', '𪘩', '𪘪', '𪘬', '𪘲', '𪘳', '𪘹', '𪘺',
+    '𪘾', '𪙃', '𪙈', '𪙊', '𪙌', '𪙍', '𪙎', '𪙏', '𪙛', '𪙞', '𪙟', '𪙡', '𪙤', '𪙥', '𪙧', '𪙨',
+    '𪙫', '𪙭', '𪙯', '𪙳', '𪙴', '𪙹', '𪙺', '𪙽', '𪙾', '𪚁', '𪚃', '𪚅', '𪚇', '𪚎', '𪚏', '𪚑',
+    '𪚓', '𪚘', '𪚝', '𪚠', '𪚢', '𪚥', '𪚬', '𪚭', '𪚮', '𪚯', '𪚰', '𪚷', '𪚹', '𪚺', '𪚻', '𪛀',
+    '𪛂', '𪛃', '𪛈', '𪛊', '𪛎', '𪛒', '𪞝', '𪟝', '𪡈', '𪡏', '𪢮', '𪣻', '𪤗', '𪨊', '𪨗', '𪨰',
+    '𪨶', '𪩘', '𪪝', '𪺹', '𪻐', '𪾢', '𫁡', '𫂈', '𫂙', '𫃜', '𫄧', '𫄨', '𫄷', '𫄸', '𫇭', '𫈰',
+    '𫋐', '𫌀', '𫌨', '𫍙', '𫍟', '𫍢', '𫍣', '𫍯', '𫍰', '𫍲', '𫍽', '𫏋', '𫐄', '𫐆', '𫐉', '𫐐',
+    '𫐓', '𫑡', '𫒶', '𫓧', '𫓩', '𫓯', '𫓶', '𫓹', '𫔍', '𫔎', '𫔶', '𫖮', '𫖯', '𫖳', '𫗠', '𫗦',
+    '𫗧', '𫗮', '𫗴', '𫘜', '𫘝', '𫘣', '𫘤', '𫘦', '𫘧', '𫘨', '𫘪', '𫘬', '𫚈', '𫚉', '𫚒', '𫚔',
+    '𫚕', '𫚖', '𫚙', '𫚭', '𫛛', '𫛞', '𫛢', '𫛭', '𫛶', '𫛸', '𫜷', '𫜸', '𫞩', '𫟅', '𫟦', '𫟷',
+    '𫟹', '𫟼', '𫠆', '𫠊', '𫠜', '𫠥', '𫡑', '𫢸', '𫫇', '𫭟', '𫭢', '𫭼', '𫮃', '𫮬', '𫰛', '𫵷',
+    '𫶇', '𫷷', '𫸩', '𫼛', '𫽮', '𬀩', '𬀪', '𬀷', '𬂩', '𬃊', '𬆛', '𬇕', '𬇙', '𬇹', '𬉼', '𬊈',
+    '𬊤', '𬌗', '𬍛', '𬍡', '𬍤', '𬐚', '𬑔', '𬑡', '𬒈', '𬒔', '𬒗', '𬒘', '𬓼', '𬕂', '𬘓', '𬘘',
+    '𬘡', '𬘩', '𬘫', '𬘬', '𬘭', '𬘯', '𬙂', '𬙊', '𬙋', '𬛸', '𬜬', '𬜯', '𬞟', '𬟁', '𬟽', '𬣙',
+    '𬣞', '𬣡', '𬣳', '𬤇', '𬤊', '𬤝', '𬤥', '𬨂', '𬨎', '𬩽', '𬪩', '𬬩', '𬬭', '𬬮', '𬬱', '𬬸',
+    '𬬹', '𬬻', '𬬿', '𬭁', '𬭊', '𬭎', '𬭚', '𬭛', '𬭤', '𬭩', '𬭬', '𬭯', '𬭳', '𬭶', '𬭸', '𬭼',
+    '𬮱', '𬮿', '𬯀', '𬯎', '𬰃', '𬱖', '𬱟', '𬲜', '𬳲', '𬳵', '𬳶', '𬳽', '𬳿', '𬴂', '𬴃', '𬴊',
+    '𬶋', '𬶍', '𬶏', '𬶐', '𬶟', '𬶠', '𬶨', '𬶭', '𬶮', '𬷕', '𬸘', '𬸚', '𬸣', '𬸦', '𬸪', '𬹼',
+    '𬺈', '𬺓', '𭀖', '灰', '𰻝', '𰻞',
+];
+
+pub(super) static LIST_IDS: [u16; 41494] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+    32, 33, 4, 34, 35, 36, 37, 26, 38, 39, 40, 41, 42, 43, 44, 45,
+    46, 47, 48, 37, 49, 50, 51, 52, 53, 54, 26, 55, 56, 57, 58, 59,
+    60, 44, 61, 62, 63, 64, 65, 66, 67, 61, 68, 69, 70, 71, 67, 72,
+    73, 57, 74, 75, 76, 77, 78, 4, 64, 79, 80, 81, 38, 82, 83, 84,
+    85, 86, 87, 88, 89, 90, 91, 92, 93, 57, 94, 68, 95, 96, 97, 98,
+    30, 99, 55, 100, 56, 101, 102, 103, 104, 105, 106, 107, 105, 108, 109, 110,
+    111, 112, 113, 114, 115, 116, 117, 118, 36, 119, 120, 121, 122, 123, 124, 125,
+    96, 126, 127, 37, 128, 129, 12, 130, 131, 132, 133, 134, 135, 130, 136, 137,
+    138, 139, 140, 141, 142, 143, 111, 144, 145, 146, 147, 148, 149, 150, 26, 151,
+    152, 153, 78, 154, 155, 70, 156, 157, 158, 37, 159, 160, 161, 162, 163, 164,
+    165, 96, 166, 78, 167, 168, 169, 170, 171, 172, 127, 173, 174, 175, 176, 177,
+    158, 82, 18, 178, 179, 180, 181, 149, 182, 98, 48, 183, 184, 57, 185, 26,
+    186, 187, 188, 189, 190, 110, 191, 192, 193, 194, 195, 196, 197, 198, 199, 200,
+    201, 202, 203, 204, 205, 206, 203, 207, 208, 207, 209, 210, 37, 129, 68, 211,
+    11, 212, 213, 214, 215, 216, 217, 218, 219, 45, 144, 197, 220, 221, 222, 223,
+    224, 225, 226, 227, 228, 97, 229, 230, 231, 232, 55, 233, 234, 235, 236, 190,
+    237, 26, 238, 11, 239, 240, 241, 242, 243, 96, 244, 245, 246, 247, 242, 248,
+    249, 250, 251, 9, 252, 253, 254, 255, 68, 256, 257, 123, 258, 259, 260, 261,
+    262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 149, 272, 273, 149, 274, 275,
+    276, 277, 263, 278, 279, 280, 281, 282, 283, 250, 284, 206, 285, 286, 287, 288,
+    289, 290, 291, 55, 159, 292, 293, 294, 295, 296, 297, 298, 299, 197, 150, 300,
+    197, 301, 169, 118, 302, 303, 11, 304, 305, 306, 252, 307, 308, 309, 34, 26,
+    139, 310, 311, 312, 313, 314, 283, 315, 311, 316, 169, 317, 318, 55, 53, 305,
+    319, 303, 320, 303, 317, 321, 322, 242, 118, 126, 323, 324, 325, 326, 97, 205,
+    11, 242, 126, 327, 328, 329, 330, 249, 331, 332, 34, 333, 127, 206, 334, 139,
+    233, 242, 335, 336, 337, 338, 126, 339, 171, 340, 261, 341, 342, 209, 343, 270,
+    57, 344, 345, 346, 175, 209, 347, 348, 349, 350, 351, 7, 242, 141, 352, 353,
+    108, 354, 340, 355, 7, 56, 356, 26, 169, 248, 357, 26, 96, 327, 358, 359,
+    360, 90, 71, 361, 362, 363, 364, 365, 366, 65, 197, 367, 368, 369, 370, 343,
+    353, 104, 371, 143, 144, 372, 373, 374, 375, 376, 377, 378, 379, 380, 381, 382,
+    383, 65, 384, 285, 385, 386, 234, 387, 388, 389, 26, 390, 6, 218, 385, 76,
+    327, 83, 391, 97, 392, 393, 1, 394, 333, 395, 396, 397, 398, 399, 400, 401,
+    13, 402, 207, 403, 404, 127, 405, 75, 406, 270, 407, 408, 219, 409, 410, 411,
+    197, 412, 413, 414, 415, 416, 417, 418, 18, 97, 181, 50, 419, 111, 86, 420,
+    421, 422, 423, 424, 197, 123, 197, 425, 426, 427, 428, 26, 41, 120, 429, 400,
+    430, 431, 432, 433, 434, 311, 283, 30, 435, 241, 11, 436, 437, 438, 17, 439,
+    26, 440, 71, 441, 419, 430, 378, 26, 442, 443, 444, 445, 446, 123, 37, 447,
+    330, 448, 449, 450, 451, 452, 453, 454, 392, 455, 456, 330, 45, 69, 457, 197,
+    455, 283, 324, 458, 459, 95, 460, 461, 242, 239, 160, 435, 462, 463, 464, 11,
+    327, 465, 466, 467, 468, 469, 468, 470, 42, 471, 472, 473, 474, 475, 46, 313,
+    476, 203, 477, 478, 6, 479, 480, 248, 303, 37, 157, 481, 482, 483, 484, 57,
+    26, 469, 485, 68, 486, 487, 488, 370, 489, 490, 491, 91, 459, 492, 493, 494,
+    206, 495, 496, 443, 497, 498, 499, 500, 501, 502, 192, 503, 56, 432, 134, 387,
+    243, 504, 505, 11, 506, 399, 507, 508, 509, 243, 510, 511, 6, 96, 512, 219,
+    513, 514, 515, 387, 516, 517, 518, 297, 439, 519, 520, 387, 61, 463, 53, 521,
+    316, 522, 20, 127, 44, 451, 523, 524, 525, 526, 527, 107, 528, 529, 327, 530,
+    531, 532, 78, 443, 533, 534, 81, 535, 536, 537, 538, 219, 297, 378, 539, 476,
+    479, 540, 255, 541, 290, 542, 253, 255, 543, 544, 545, 271, 253, 160, 546, 547,
+    548, 549, 550, 253, 123, 399, 149, 551, 552, 553, 101, 409, 552, 554, 555, 477,
+    556, 55, 175, 550, 557, 558, 297, 559, 560, 561, 562, 17, 563, 564, 561, 167,
+    565, 566, 473, 567, 568, 320, 149, 569, 169, 570, 537, 571, 484, 572, 363, 573,
+    243, 202, 330, 57, 574, 539, 575, 576, 577, 111, 578, 95, 458, 55, 579, 30,
+    485, 580, 316, 439, 581, 202, 582, 583, 584, 585, 586, 263, 587, 46, 588, 81,
+    568, 589, 590, 126, 591, 55, 585, 241, 404, 592, 593, 311, 130, 594, 105, 595,
+    596, 597, 598, 21, 599, 600, 244, 601, 602, 603, 476, 604, 605, 606, 607, 608,
+    207, 279, 370, 609, 610, 608, 611, 470, 612, 26, 56, 169, 613, 57, 614, 615,
+    198, 201, 260, 198, 616, 617, 509, 288, 618, 26, 72, 619, 78, 620, 621, 622,
+    623, 411, 50, 365, 624, 625, 626, 322, 627, 628, 629, 630, 625, 477, 631, 632,
+    367, 443, 205, 182, 633, 634, 635, 26, 636, 122, 637, 638, 36, 639, 64, 640,
+    245, 351, 641, 126, 642, 578, 643, 126, 126, 311, 644, 645, 397, 639, 115, 181,
+    149, 57, 239, 160, 546, 26, 236, 646, 90, 438, 638, 495, 313, 647, 410, 648,
+    649, 650, 651, 12, 652, 653, 65, 654, 244, 646, 655, 283, 209, 609, 656, 657,
+    658, 353, 96, 96, 271, 659, 86, 660, 661, 662, 26, 663, 664, 665, 666, 467,
+    169, 667, 668, 669, 220, 33, 522, 670, 159, 671, 138, 672, 673, 568, 636, 129,
+    674, 675, 343, 676, 42, 35, 677, 678, 556, 679, 680, 681, 682, 683, 580, 390,
+    684, 55, 282, 685, 203, 686, 687, 61, 688, 122, 34, 689, 150, 690, 691, 139,
+    692, 693, 65, 694, 695, 8, 696, 697, 698, 687, 699, 700, 260, 251, 701, 702,
+    47, 98, 703, 405, 332, 56, 704, 261, 686, 705, 569, 580, 443, 451, 706, 707,
+    708, 485, 107, 7, 709, 78, 710, 61, 390, 150, 711, 712, 270, 559, 713, 66,
+    714, 5, 715, 716, 717, 557, 718, 719, 720, 252, 526, 721, 216, 722, 723, 576,
+    330, 724, 725, 726, 727, 728, 729, 730, 123, 26, 537, 300, 731, 149, 118, 732,
+    576, 733, 111, 734, 735, 370, 700, 736, 400, 737, 738, 739, 740, 8, 454, 190,
+    127, 708, 26, 741, 65, 742, 53, 5, 96, 505, 100, 260, 743, 118, 744, 745,
+    263, 746, 747, 358, 387, 536, 748, 167, 749, 580, 6, 750, 169, 751, 752, 753,
+    754, 755, 756, 757, 5, 758, 759, 186, 105, 760, 761, 762, 763, 502, 580, 764,
+    765, 766, 149, 767, 768, 769, 770, 682, 771, 772, 773, 746, 774, 775, 776, 219,
+    668, 777, 778, 685, 38, 779, 780, 55, 781, 782, 783, 784, 785, 786, 787, 403,
+    699, 86, 788, 789, 790, 779, 791, 792, 256, 793, 794, 795, 15, 796, 387, 797,
+    798, 799, 469, 338, 23, 331, 350, 353, 800, 297, 64, 801, 283, 127, 489, 802,
+    803, 804, 743, 805, 37, 806, 436, 60, 807, 61, 808, 763, 675, 809, 127, 810,
+    420, 200, 811, 118, 812, 67, 813, 814, 655, 168, 564, 815, 69, 816, 817, 818,
+    70, 819, 820, 316, 316, 195, 286, 821, 822, 823, 824, 825, 826, 827, 828, 813,
+    252, 829, 205, 830, 831, 832, 167, 833, 834, 363, 835, 420, 574, 620, 836, 837,
+    100, 838, 204, 839, 290, 61, 840, 7, 290, 743, 796, 841, 556, 842, 763, 843,
+    149, 242, 286, 47, 47, 844, 845, 846, 847, 276, 390, 848, 276, 216, 378, 849,
+    850, 619, 851, 216, 852, 853, 854, 855, 856, 583, 123, 252, 532, 857, 858, 859,
+    123, 860, 861, 528, 858, 19, 862, 435, 105, 863, 864, 865, 866, 126, 300, 609,
+    197, 646, 37, 83, 644, 867, 578, 260, 625, 210, 199, 868, 277, 524, 869, 509,
+    639, 528, 870, 871, 628, 872, 873, 42, 218, 713, 625, 874, 875, 876, 477, 477,
+    877, 685, 77, 878, 879, 436, 378, 779, 688, 880, 147, 303, 881, 882, 555, 883,
+    362, 432, 775, 105, 884, 885, 181, 230, 886, 887, 400, 888, 430, 889, 47, 123,
+    390, 90, 360, 279, 526, 210, 890, 891, 892, 282, 638, 290, 775, 55, 893, 541,
+    894, 574, 536, 619, 37, 243, 98, 197, 815, 895, 896, 671, 897, 260, 539, 569,
+    898, 455, 290, 61, 47, 735, 316, 571, 899, 682, 390, 294, 900, 4, 901, 389,
+    327, 902, 903, 904, 343, 905, 906, 841, 18, 686, 556, 537, 907, 908, 762, 243,
+    909, 236, 495, 578, 320, 910, 628, 408, 911, 313, 912, 682, 685, 775, 913, 662,
+    95, 7, 682, 204, 914, 74, 915, 916, 917, 918, 216, 169, 744, 919, 311, 920,
+    71, 86, 850, 556, 921, 255, 389, 332, 922, 923, 285, 242, 2, 924, 925, 91,
+    926, 927, 928, 929, 878, 930, 148, 199, 205, 482, 149, 55, 931, 932, 216, 127,
+    933, 934, 282, 935, 936, 937, 938, 56, 197, 38, 939, 940, 311, 127, 941, 131,
+    45, 942, 115, 660, 943, 400, 944, 198, 616, 141, 126, 945, 547, 20, 569, 845,
+    946, 947, 126, 37, 118, 948, 949, 950, 637, 447, 104, 32, 951, 490, 197, 687,
+    311, 952, 628, 953, 277, 490, 123, 639, 123, 954, 955, 115, 55, 956, 253, 294,
+    260, 239, 861, 182, 957, 958, 628, 959, 815, 960, 353, 85, 497, 572, 655, 812,
+    544, 607, 961, 111, 100, 385, 962, 655, 963, 181, 861, 311, 964, 195, 370, 484,
+    556, 965, 966, 96, 420, 253, 6, 48, 111, 455, 202, 967, 968, 969, 96, 786,
+    970, 971, 972, 881, 806, 973, 806, 974, 636, 241, 709, 975, 976, 977, 978, 979,
+    980, 235, 529, 981, 768, 861, 982, 983, 984, 985, 735, 571, 197, 322, 986, 487,
+    5, 987, 26, 197, 988, 815, 252, 273, 195, 989, 470, 598, 608, 805, 490, 990,
+    533, 991, 702, 992, 993, 994, 995, 996, 997, 623, 998, 197, 999, 569, 1000, 435,
+    1001, 700, 1002, 243, 873, 175, 1003, 1004, 61, 26, 1005, 768, 1006, 1007, 126, 55,
+    578, 134, 806, 1008, 1009, 1010, 1011, 1012, 530, 473, 1013, 56, 260, 1014, 195, 644,
+    386, 1015, 845, 559, 959, 1016, 1017, 1018, 40, 234, 204, 239, 1019, 198, 1020, 1021,
+    1022, 627, 482, 924, 495, 1023, 71, 64, 1024, 313, 156, 85, 1025, 880, 1026, 478,
+    682, 47, 1027, 1028, 180, 288, 197, 185, 169, 576, 1029, 1030, 57, 112, 1031, 1032,
+    1033, 490, 311, 841, 860, 343, 668, 1034, 1, 1035, 333, 495, 110, 993, 26, 495,
+    539, 53, 405, 1036, 679, 945, 11, 1037, 990, 86, 306, 50, 96, 1038, 168, 331,
+    819, 1039, 1040, 311, 514, 436, 56, 1041, 1042, 1043, 1044, 97, 271, 1045, 485, 1046,
+    96, 1047, 26, 331, 40, 358, 815, 1048, 609, 387, 37, 924, 112, 1049, 37, 26,
+    821, 1050, 169, 134, 273, 105, 1051, 107, 283, 495, 111, 367, 934, 197, 115, 17,
+    242, 1052, 96, 477, 96, 845, 1053, 81, 348, 230, 436, 1054, 566, 375, 37, 96,
+    290, 872, 529, 430, 142, 1055, 1056, 373, 808, 436, 241, 700, 655, 1057, 556, 896,
+    1058, 1059, 799, 975, 1060, 1061, 279, 26, 8, 276, 1062, 82, 528, 978, 126, 1063,
+    1064, 1065, 26, 1066, 576, 801, 161, 468, 724, 11, 1067, 4, 356, 1068, 1069, 1070,
+    495, 192, 1071, 1072, 990, 366, 1073, 159, 1025, 1074, 303, 966, 1075, 184, 801, 197,
+    443, 775, 615, 773, 435, 1076, 1068, 181, 168, 430, 1077, 488, 1078, 390, 1079, 366,
+    26, 1080, 1081, 1082, 138, 815, 447, 472, 1005, 925, 1083, 255, 523, 815, 322, 584,
+    1084, 1085, 2, 1086, 764, 1087, 290, 38, 430, 1088, 830, 1082, 343, 1089, 149, 708,
+    1090, 1020, 262, 896, 358, 830, 390, 631, 735, 219, 72, 122, 90, 701, 157, 122,
+    1091, 349, 1092, 11, 490, 840, 1005, 607, 1093, 1094, 735, 263, 1005, 181, 1095, 47,
+    1096, 1097, 735, 556, 1018, 90, 317, 1098, 56, 1099, 1100, 1101, 96, 1102, 586, 118,
+    65, 242, 546, 1026, 1103, 1104, 436, 556, 320, 568, 1105, 1106, 1107, 1108, 1109, 1110,
+    628, 845, 1111, 43, 181, 252, 1112, 360, 1113, 1114, 1115, 432, 468, 743, 181, 1116,
+    1090, 1117, 1118, 1118, 1119, 260, 185, 697, 110, 1120, 1121, 1019, 236, 181, 584, 1001,
+    290, 1122, 754, 828, 56, 631, 502, 1123, 111, 1124, 455, 990, 1125, 1126, 82, 497,
+    154, 236, 1127, 477, 1128, 1129, 210, 883, 748, 324, 801, 1130, 248, 110, 1131, 1132,
+    831, 1040, 1133, 1134, 150, 42, 143, 129, 6, 212, 26, 1005, 195, 1135, 1097, 1136,
+    627, 1137, 212, 532, 1136, 86, 1138, 332, 727, 1139, 1140, 1141, 41, 1142, 1143, 738,
+    282, 81, 967, 200, 820, 940, 619, 964, 313, 559, 1144, 1145, 1146, 271, 1147, 1148,
+    317, 636, 548, 1149, 42, 1084, 64, 390, 74, 129, 55, 1150, 197, 1018, 365, 443,
+    81, 1151, 85, 219, 685, 463, 390, 906, 1152, 1153, 1116, 1154, 11, 1061, 495, 105,
+    6, 390, 1061, 320, 339, 61, 333, 476, 1155, 919, 1097, 55, 260, 56, 68, 1156,
+    270, 556, 20, 181, 1155, 1157, 1063, 919, 1158, 1159, 107, 126, 554, 80, 410, 919,
+    467, 756, 408, 548, 583, 169, 1160, 826, 55, 1161, 17, 1162, 1163, 38, 58, 115,
+    831, 1164, 57, 366, 290, 1165, 431, 435, 79, 443, 890, 1166, 55, 1137, 770, 35,
+    762, 12, 1167, 220, 1168, 290, 1118, 43, 6, 290, 150, 1169, 685, 568, 255, 1170,
+    127, 1171, 124, 488, 1172, 36, 131, 1173, 682, 274, 89, 640, 390, 1174, 313, 1175,
+    71, 301, 1176, 1177, 123, 1178, 608, 55, 83, 22, 81, 1166, 277, 26, 619, 96,
+    58, 1083, 1179, 1180, 1020, 97, 125, 550, 68, 78, 1181, 460, 55, 14, 682, 323,
+    289, 1182, 580, 332, 1183, 241, 970, 1184, 410, 1185, 17, 1186, 148, 167, 430, 1187,
+    1188, 709, 354, 1189, 230, 1190, 169, 845, 790, 21, 1191, 430, 806, 1118, 1192, 463,
+    1026, 438, 343, 260, 1193, 1194, 186, 456, 1195, 1196, 55, 1197, 1198, 589, 408, 385,
+    1199, 1200, 1201, 1202, 805, 492, 150, 98, 97, 333, 1203, 195, 206, 1204, 1046, 171,
+    1205, 1206, 1207, 1208, 274, 146, 35, 527, 1209, 277, 181, 300, 56, 104, 197, 373,
+    1210, 69, 1211, 998, 1212, 1213, 623, 1214, 206, 775, 306, 1215, 998, 742, 1024, 1018,
+    46, 1216, 852, 192, 1217, 199, 366, 303, 1218, 630, 801, 819, 134, 55, 1219, 105,
+    1220, 1221, 192, 1222, 268, 216, 661, 8, 55, 79, 615, 126, 1116, 1223, 820, 442,
+    1224, 966, 970, 1225, 76, 338, 1226, 1227, 86, 1062, 138, 303, 197, 42, 1228, 220,
+    129, 432, 630, 363, 1229, 815, 104, 56, 491, 841, 303, 65, 1230, 467, 1231, 965,
+    575, 286, 210, 584, 115, 900, 1232, 1233, 658, 921, 1234, 104, 883, 586, 316, 1235,
+    711, 1236, 105, 943, 104, 901, 1237, 134, 677, 1238, 101, 1239, 585, 350, 104, 30,
+    438, 206, 1240, 711, 1241, 127, 78, 1242, 1243, 1244, 1245, 303, 1246, 640, 1200, 619,
+    1087, 124, 1247, 252, 436, 658, 658, 575, 1248, 580, 1249, 1250, 477, 1251, 536, 1252,
+    1253, 1254, 552, 1255, 1256, 477, 218, 1257, 851, 810, 1258, 274, 625, 443, 1040, 950,
+    1259, 576, 881, 54, 118, 390, 439, 1260, 1261, 1087, 743, 1262, 637, 1263, 454, 541,
+    1264, 1265, 1266, 1267, 1268, 126, 1269, 1270, 1005, 682, 1271, 1005, 197, 365, 239, 571,
+    118, 1018, 11, 1152, 568, 282, 580, 679, 290, 1272, 143, 31, 400, 130, 124, 61,
+    1059, 276, 1273, 67, 390, 907, 127, 1274, 382, 212, 850, 256, 139, 343, 552, 1275,
+    19, 1276, 1277, 1278, 685, 1279, 70, 765, 1155, 644, 1280, 1281, 303, 128, 127, 1208,
+    231, 870, 1282, 608, 242, 93, 376, 86, 490, 1035, 812, 147, 91, 888, 552, 1283,
+    390, 1284, 72, 1285, 349, 1286, 216, 287, 1287, 1288, 21, 1289, 786, 123, 1290, 443,
+    405, 367, 957, 945, 1291, 1204, 830, 37, 600, 1292, 370, 521, 1020, 222, 1293, 1294,
+    442, 55, 1295, 619, 1296, 1297, 746, 95, 1298, 801, 1299, 1002, 1300, 1078, 1301, 583,
+    204, 118, 26, 1083, 88, 773, 639, 1302, 530, 1303, 1163, 1304, 1305, 1306, 77, 390,
+    1307, 1308, 242, 527, 1283, 1229, 1309, 483, 444, 559, 1310, 1311, 450, 646, 566, 253,
+    1312, 260, 472, 906, 390, 26, 1313, 77, 6, 1314, 204, 168, 1315, 1316, 644, 1018,
+    1317, 1059, 416, 1314, 1318, 1319, 261, 261, 1320, 26, 682, 533, 216, 1321, 55, 1322,
+    768, 37, 686, 1323, 625, 460, 1324, 1325, 925, 61, 168, 1326, 476, 1283, 970, 509,
+    1327, 107, 704, 719, 1328, 1329, 1330, 1331, 227, 742, 244, 44, 473, 527, 658, 1332,
+    482, 1333, 1334, 630, 206, 713, 1335, 71, 1336, 719, 253, 1337, 870, 74, 1338, 1339,
+    1340, 869, 1341, 111, 1342, 1343, 1344, 1345, 1346, 674, 443, 1347, 439, 970, 283, 1078,
+    1348, 1, 370, 1018, 1349, 231, 530, 473, 1350, 679, 455, 245, 477, 1351, 67, 1352,
+    1306, 129, 1353, 477, 1354, 210, 623, 455, 230, 975, 478, 483, 1355, 1311, 824, 826,
+    815, 74, 498, 331, 487, 38, 530, 1115, 531, 903, 1356, 22, 367, 239, 1357, 26,
+    313, 81, 286, 236, 233, 363, 144, 684, 367, 1358, 195, 539, 55, 1359, 46, 709,
+    1360, 824, 1134, 200, 5, 198, 1361, 1362, 655, 42, 42, 1363, 242, 644, 271, 1364,
+    1365, 1366, 1367, 967, 400, 1368, 780, 584, 313, 903, 808, 1369, 6, 1370, 1371, 1372,
+    68, 411, 243, 26, 1373, 303, 1374, 262, 1061, 211, 255, 580, 20, 72, 1375, 985,
+    260, 86, 320, 1376, 252, 1377, 851, 106, 1378, 1379, 1380, 1117, 870, 139, 35, 90,
+    175, 522, 709, 1246, 1381, 1382, 1383, 1384, 608, 743, 756, 353, 243, 65, 808, 85,
+    1385, 316, 249, 1386, 883, 1286, 86, 954, 940, 313, 134, 277, 409, 1134, 85, 838,
+    756, 1387, 436, 430, 260, 366, 637, 1388, 283, 1313, 782, 1389, 220, 909, 107, 168,
+    71, 96, 276, 1097, 586, 134, 1390, 1261, 260, 405, 301, 419, 363, 1391, 283, 202,
+    222, 118, 659, 1020, 80, 96, 409, 763, 286, 1392, 26, 40, 111, 828, 682, 237,
+    619, 1393, 1394, 546, 1395, 1396, 1397, 215, 303, 495, 260, 245, 253, 618, 1398, 126,
+    277, 277, 1399, 668, 38, 483, 1400, 333, 1153, 782, 708, 111, 1401, 60, 307, 110,
+    801, 1402, 527, 473, 697, 83, 835, 96, 1403, 1404, 65, 108, 527, 1405, 159, 252,
+    126, 58, 586, 55, 1057, 1406, 1407, 1126, 1408, 207, 1018, 1409, 1410, 954, 219, 115,
+    200, 138, 322, 1411, 438, 290, 144, 1201, 67, 105, 112, 1412, 966, 903, 552, 637,
+    548, 1413, 455, 682, 35, 126, 584, 580, 386, 397, 26, 485, 1414, 112, 56, 350,
+    177, 1415, 455, 111, 586, 239, 226, 240, 1416, 903, 900, 580, 815, 77, 332, 704,
+    799, 690, 1417, 1367, 1418, 908, 241, 378, 678, 1419, 52, 1420, 209, 1117, 347, 1421,
+    1422, 1201, 71, 851, 61, 1423, 67, 1225, 658, 497, 70, 436, 436, 744, 123, 586,
+    1394, 546, 127, 226, 1424, 1221, 635, 67, 242, 736, 861, 1425, 744, 1426, 260, 1101,
+    1427, 5, 1347, 8, 1428, 1271, 218, 420, 154, 1025, 215, 1429, 242, 134, 1430, 134,
+    1431, 1432, 639, 122, 397, 26, 644, 1433, 1434, 378, 53, 107, 390, 97, 1098, 260,
+    42, 86, 1061, 1435, 1025, 499, 333, 113, 1436, 317, 317, 1437, 200, 128, 1438, 378,
+    945, 546, 1439, 1440, 790, 72, 86, 580, 1441, 111, 123, 1097, 82, 387, 55, 111,
+    1442, 179, 1443, 1118, 403, 72, 185, 1444, 456, 1445, 243, 903, 395, 243, 290, 210,
+    945, 1446, 55, 218, 527, 531, 277, 339, 76, 1447, 416, 618, 1448, 26, 11, 1449,
+    1100, 171, 808, 255, 1082, 123, 439, 86, 204, 1355, 1450, 491, 37, 610, 934, 1451,
+    207, 240, 1452, 148, 77, 236, 332, 218, 1453, 1454, 970, 227, 1455, 1078, 1456, 294,
+    1457, 1119, 1458, 628, 1443, 1459, 126, 75, 42, 21, 1460, 283, 35, 123, 1460, 812,
+    219, 1155, 1004, 409, 1461, 1462, 658, 444, 894, 1463, 1430, 370, 37, 1464, 1465, 724,
+    709, 556, 640, 1466, 1467, 1468, 584, 1469, 935, 819, 1470, 1471, 268, 1222, 1472, 1005,
+    1473, 1474, 110, 583, 556, 236, 1475, 1476, 1477, 197, 75, 990, 908, 167, 283, 567,
+    332, 1478, 1274, 1479, 1480, 126, 64, 390, 1481, 1482, 1053, 1483, 1484, 77, 56, 1485,
+    123, 1486, 1487, 197, 1433, 1488, 462, 1489, 1490, 451, 261, 171, 147, 1491, 1, 628,
+    300, 1183, 1492, 169, 197, 1493, 556, 263, 400, 1225, 1494, 1495, 1496, 1497, 695, 253,
+    1498, 191, 1499, 324, 1117, 1500, 1501, 1502, 220, 451, 444, 1503, 727, 1504, 990, 836,
+    628, 390, 851, 1505, 1506, 566, 1507, 1508, 1509, 1510, 65, 108, 653, 1511, 1512, 21,
+    1513, 363, 1514, 363, 179, 283, 290, 801, 1515, 1516, 1357, 1517, 727, 1518, 19, 85,
+    90, 828, 1162, 1519, 1184, 1520, 752, 322, 1521, 1522, 711, 527, 472, 1494, 815, 1523,
+    603, 378, 1159, 149, 172, 1524, 640, 640, 333, 460, 72, 262, 253, 438, 954, 353,
+    1525, 97, 1448, 86, 37, 333, 167, 637, 1526, 343, 1527, 98, 343, 828, 4, 197,
+    179, 1528, 1529, 541, 1530, 1531, 1532, 545, 1256, 573, 1533, 18, 658, 865, 210, 1534,
+    389, 1535, 566, 1536, 5, 921, 76, 1537, 108, 220, 1538, 1539, 216, 495, 20, 1005,
+    1540, 1541, 665, 270, 925, 327, 764, 531, 40, 497, 77, 320, 112, 405, 356, 303,
+    26, 248, 253, 628, 1542, 26, 1543, 1544, 845, 283, 317, 386, 204, 468, 1061, 771,
+    8, 1545, 38, 451, 1546, 1547, 1548, 320, 845, 459, 55, 1549, 86, 845, 790, 103,
+    779, 1155, 1098, 620, 599, 253, 74, 665, 105, 1001, 773, 1550, 644, 1551, 148, 204,
+    1552, 124, 744, 26, 1346, 1553, 56, 414, 64, 55, 1554, 1555, 1556, 673, 111, 1557,
+    119, 1558, 1559, 414, 37, 1560, 744, 1561, 181, 44, 638, 85, 260, 535, 34, 93,
+    468, 840, 125, 1562, 180, 6, 1563, 390, 537, 111, 1564, 111, 1565, 26, 661, 81,
+    587, 343, 405, 1566, 1026, 1567, 1568, 205, 562, 107, 1569, 1570, 1571, 206, 1572, 1573,
+    952, 716, 609, 425, 411, 61, 96, 271, 1020, 71, 283, 26, 378, 1163, 68, 382,
+    1574, 311, 279, 126, 1575, 127, 1576, 199, 120, 1440, 349, 828, 1577, 1091, 216, 400,
+    833, 801, 1393, 1527, 780, 271, 345, 1578, 1579, 1176, 586, 1580, 15, 546, 74, 7,
+    200, 546, 888, 1581, 301, 55, 1026, 1431, 1582, 64, 26, 470, 1583, 1026, 260, 1584,
+    580, 1585, 26, 1585, 26, 743, 323, 1586, 248, 436, 197, 808, 1587, 1555, 323, 303,
+    919, 189, 1588, 870, 248, 154, 887, 541, 557, 1060, 54, 1589, 460, 111, 1590, 808,
+    1591, 126, 1592, 907, 1593, 307, 1594, 1072, 1541, 1595, 1596, 67, 598, 502, 197, 1163,
+    1597, 545, 97, 556, 111, 111, 120, 1598, 1599, 548, 226, 726, 1600, 1579, 363, 1329,
+    429, 908, 349, 1601, 697, 1602, 1430, 26, 117, 451, 1603, 460, 640, 400, 263, 255,
+    290, 990, 14, 702, 702, 26, 1040, 1604, 1589, 64, 480, 353, 487, 390, 569, 1274,
+    728, 1565, 815, 934, 1605, 237, 1137, 1128, 1155, 26, 17, 620, 1606, 127, 1607, 708,
+    775, 1608, 343, 263, 1005, 42, 456, 775, 1460, 55, 702, 1271, 744, 576, 1609, 326,
+    11, 1060, 1610, 1611, 1230, 1612, 677, 1613, 708, 1431, 26, 143, 378, 583, 161, 1614,
+    430, 1615, 1616, 841, 1617, 679, 1338, 313, 283, 1618, 965, 580, 756, 123, 1619, 177,
+    26, 1620, 548, 580, 460, 532, 1621, 556, 52, 702, 1159, 515, 1622, 1576, 1623, 282,
+    241, 96, 1576, 802, 290, 203, 450, 11, 1624, 1394, 631, 294, 1625, 351, 95, 934,
+    724, 96, 156, 659, 556, 1626, 1627, 83, 270, 260, 363, 1628, 363, 370, 51, 934,
+    295, 111, 1001, 1629, 286, 197, 687, 687, 619, 443, 1630, 7, 1430, 708, 896, 97,
+    123, 1631, 627, 274, 639, 658, 168, 399, 658, 237, 169, 454, 1632, 1633, 544, 55,
+    260, 762, 530, 1634, 775, 277, 1635, 529, 1636, 1636, 1637, 834, 98, 244, 1638, 283,
+    14, 828, 1639, 1640, 1641, 157, 826, 906, 1642, 1643, 18, 646, 283, 1443, 860, 900,
+    1644, 96, 1645, 67, 1646, 1060, 327, 1647, 1648, 1649, 125, 347, 1105, 1650, 323, 35,
+    1650, 110, 1651, 1652, 56, 1653, 828, 531, 1654, 139, 436, 495, 1655, 580, 149, 198,
+    1647, 11, 70, 61, 642, 521, 64, 199, 411, 144, 554, 1656, 295, 1657, 944, 546,
+    18, 1658, 38, 1659, 1660, 124, 473, 316, 719, 244, 108, 424, 1661, 251, 366, 460,
+    98, 1662, 46, 129, 830, 1663, 37, 263, 41, 65, 1273, 351, 1664, 1665, 1666, 1667,
+    967, 1668, 61, 470, 356, 1669, 1670, 502, 21, 330, 1229, 1063, 1671, 429, 1672, 328,
+    1673, 86, 365, 708, 1674, 56, 230, 98, 580, 775, 662, 41, 735, 219, 1675, 763,
+    1676, 1677, 332, 421, 682, 1678, 322, 535, 1204, 1679, 430, 1680, 322, 1681, 390, 773,
+    1682, 297, 430, 628, 215, 502, 149, 978, 1441, 556, 1683, 1684, 559, 1685, 679, 42,
+    682, 628, 211, 26, 1686, 532, 504, 1687, 202, 327, 1323, 615, 279, 279, 239, 1688,
+    574, 1134, 552, 311, 115, 1162, 1119, 42, 469, 945, 26, 283, 7, 815, 1689, 333,
+    1122, 1690, 56, 460, 934, 509, 1691, 206, 343, 851, 879, 735, 148, 283, 789, 6,
+    870, 47, 851, 1692, 642, 781, 142, 960, 268, 245, 36, 120, 243, 1693, 1162, 1694,
+    169, 1581, 1695, 697, 343, 580, 378, 416, 845, 880, 1119, 1696, 1697, 129, 216, 879,
+    185, 1698, 1699, 824, 254, 1700, 554, 1701, 1119, 277, 290, 1702, 1703, 1704, 556, 343,
+    527, 1705, 358, 1088, 870, 1706, 286, 1707, 1708, 108, 1709, 1710, 1711, 126, 1712, 462,
+    1713, 239, 439, 169, 1714, 55, 55, 455, 101, 1715, 1716, 665, 1717, 110, 1718, 150,
+    253, 1719, 808, 254, 207, 295, 455, 1720, 230, 171, 769, 363, 173, 752, 1721, 1722,
+    1723, 1724, 1725, 1726, 1727, 1728, 168, 115, 219, 1729, 1730, 1731, 682, 1732, 1733, 4,
+    1734, 123, 1735, 207, 103, 261, 842, 726, 556, 919, 210, 828, 1736, 1737, 575, 1738,
+    881, 1739, 277, 1740, 107, 1707, 1741, 819, 713, 1742, 67, 1743, 1607, 295, 47, 1744,
+    1745, 459, 186, 1746, 1371, 1274, 207, 360, 851, 873, 194, 1747, 704, 1527, 455, 101,
+    1431, 260, 1494, 19, 343, 1229, 1111, 433, 1003, 38, 430, 1748, 375, 1749, 1750, 348,
+    1751, 55, 1752, 313, 455, 1753, 1754, 1755, 253, 245, 378, 578, 245, 1118, 552, 752,
+    1756, 77, 1757, 1414, 1758, 752, 623, 105, 609, 123, 1759, 332, 819, 7, 495, 1760,
+    746, 313, 1761, 1762, 665, 1763, 646, 1309, 279, 990, 1764, 1765, 1766, 1767, 81, 679,
+    107, 181, 864, 283, 708, 552, 260, 1768, 1769, 260, 658, 455, 26, 1770, 230, 1021,
+    1771, 1084, 97, 56, 290, 32, 1772, 1773, 1025, 390, 216, 11, 1774, 320, 573, 1775,
+    122, 1323, 430, 97, 944, 1776, 1335, 67, 430, 303, 1777, 618, 1778, 580, 290, 1063,
+    1779, 40, 157, 189, 1001, 455, 387, 419, 509, 1780, 1686, 230, 128, 549, 775, 432,
+    1781, 57, 366, 919, 230, 12, 219, 1718, 1782, 1433, 850, 1783, 970, 599, 365, 1784,
+    870, 24, 24, 71, 1785, 559, 1786, 1184, 919, 149, 1787, 1788, 6, 169, 1782, 556,
+    583, 40, 26, 468, 468, 416, 111, 1789, 66, 416, 1790, 316, 11, 220, 11, 75,
+    1791, 746, 1792, 233, 1324, 693, 1122, 1793, 436, 498, 520, 1153, 1794, 467, 919, 364,
+    436, 313, 1795, 1046, 546, 1796, 1645, 497, 1797, 1798, 363, 655, 1799, 362, 1294, 1800,
+    55, 1199, 219, 1801, 51, 824, 230, 198, 76, 70, 371, 777, 455, 61, 819, 625,
+    270, 365, 1019, 110, 1229, 12, 801, 110, 78, 252, 255, 1802, 1803, 218, 1804, 1602,
+    1805, 1159, 619, 1640, 883, 150, 727, 255, 459, 455, 295, 954, 497, 646, 534, 589,
+    403, 608, 283, 468, 57, 908, 1105, 1699, 569, 37, 1806, 752, 952, 295, 107, 1807,
+    655, 561, 841, 252, 252, 1808, 1809, 1062, 443, 615, 1810, 659, 775, 1811, 290, 608,
+    1431, 1812, 363, 474, 1394, 273, 1533, 55, 993, 546, 1813, 304, 474, 646, 31, 646,
+    556, 56, 1814, 95, 561, 171, 21, 1815, 96, 1325, 531, 1816, 1817, 1818, 403, 1819,
+    11, 619, 1820, 3, 608, 1097, 1821, 567, 1822, 513, 615, 1728, 343, 1823, 311, 205,
+    380, 53, 1323, 599, 195, 969, 268, 283, 1824, 1825, 549, 26, 833, 608, 1823, 1059,
+    648, 463, 1826, 11, 557, 44, 1347, 752, 1827, 6, 245, 1828, 1199, 276, 1829, 1830,
+    65, 945, 147, 704, 1448, 1831, 530, 655, 126, 1832, 260, 327, 1833, 71, 1834, 1835,
+    619, 1836, 17, 1837, 85, 61, 659, 202, 252, 98, 574, 544, 1838, 1839, 719, 64,
+    1840, 845, 253, 345, 1183, 599, 1841, 277, 1057, 1842, 1126, 21, 1, 735, 356, 1843,
+    544, 589, 1585, 1062, 627, 245, 139, 130, 244, 580, 82, 1062, 56, 378, 619, 520,
+    779, 1844, 172, 1845, 1846, 1847, 1513, 255, 288, 263, 1126, 1848, 1482, 285, 260, 454,
+    1610, 443, 234, 1849, 115, 416, 343, 1850, 1851, 539, 288, 824, 619, 5, 43, 539,
+    1093, 200, 716, 828, 1581, 181, 886, 1018, 110, 1852, 1853, 439, 1854, 61, 226, 1855,
+    1856, 491, 472, 1271, 1857, 245, 390, 253, 1858, 1859, 1078, 640, 1860, 1861, 1862, 35,
+    402, 1863, 1864, 1865, 1866, 919, 7, 1867, 545, 96, 1868, 1650, 1467, 103, 1869, 123,
+    1870, 945, 1871, 513, 1602, 277, 399, 727, 338, 1743, 1872, 940, 1078, 724, 498, 1274,
+    1090, 1467, 350, 263, 1292, 527, 1001, 1426, 290, 954, 682, 370, 708, 1873, 53, 1830,
+    1874, 126, 1118, 42, 1875, 1876, 332, 416, 323, 1877, 1878, 1879, 1880, 1881, 200, 1581,
+    586, 96, 245, 351, 1062, 248, 1882, 290, 148, 1883, 599, 405, 1884, 598, 824, 1885,
+    1228, 509, 1886, 1887, 1888, 263, 1889, 31, 327, 19, 274, 1890, 126, 270, 1891, 1892,
+    858, 530, 1026, 1893, 105, 547, 1894, 1645, 226, 31, 569, 157, 1895, 1404, 1097, 1896,
+    47, 1897, 323, 1898, 1761, 764, 282, 1899, 226, 1900, 719, 807, 1901, 81, 167, 483,
+    1902, 1903, 330, 782, 1565, 559, 1904, 61, 365, 237, 1655, 1905, 1001, 815, 91, 586,
+    149, 1906, 245, 245, 580, 531, 959, 1907, 1906, 925, 6, 531, 83, 11, 1908, 55,
+    686, 539, 118, 978, 1388, 1909, 779, 1910, 1911, 1326, 1912, 1913, 533, 390, 1914, 184,
+    536, 234, 67, 126, 184, 1660, 1392, 42, 1915, 1916, 26, 1917, 557, 473, 290, 244,
+    627, 6, 1918, 1479, 675, 603, 1398, 1005, 1919, 1920, 1921, 283, 820, 655, 686, 1922,
+    244, 1923, 642, 283, 26, 1924, 110, 277, 227, 1925, 655, 1926, 627, 339, 277, 277,
+    252, 820, 1927, 197, 1920, 1273, 1928, 42, 627, 627, 1929, 600, 288, 175, 26, 252,
+    168, 1920, 478, 139, 754, 531, 504, 485, 1189, 1300, 1122, 77, 77, 471, 378, 290,
+    1093, 1371, 202, 708, 615, 586, 253, 482, 343, 921, 1930, 1931, 253, 852, 1118, 26,
+    290, 431, 173, 1040, 580, 351, 578, 6, 55, 95, 65, 1097, 1932, 1933, 1934, 1611,
+    74, 1329, 1935, 240, 580, 1936, 343, 1937, 60, 268, 1938, 602, 1317, 993, 216, 55,
+    1939, 1004, 1940, 530, 1941, 1005, 436, 1610, 330, 628, 1431, 527, 586, 1942, 568, 1001,
+    575, 1943, 56, 78, 157, 1087, 1944, 168, 242, 58, 1945, 764, 242, 26, 276, 493,
+    1847, 1946, 1947, 1948, 260, 1180, 1949, 464, 1541, 184, 800, 271, 116, 623, 126, 210,
+    416, 477, 1105, 1950, 1926, 433, 1951, 33, 655, 1952, 1953, 175, 1954, 69, 1955, 1956,
+    1957, 327, 270, 1018, 456, 414, 46, 1958, 1959, 652, 726, 242, 1960, 652, 620, 1018,
+    1728, 1126, 1961, 1962, 1963, 745, 828, 1964, 1965, 1966, 1183, 253, 1184, 1967, 290, 108,
+    1968, 620, 1576, 56, 53, 363, 91, 1296, 578, 1101, 1115, 1969, 202, 1460, 1970, 55,
+    1627, 161, 60, 1971, 78, 124, 112, 395, 1972, 1645, 779, 909, 1973, 909, 1974, 332,
+    1338, 26, 23, 1975, 1323, 658, 970, 907, 128, 271, 46, 390, 1976, 181, 1977, 1118,
+    88, 491, 925, 283, 46, 1005, 1978, 646, 253, 646, 485, 685, 6, 1979, 1979, 860,
+    204, 966, 252, 1980, 1981, 1982, 14, 1390, 111, 43, 123, 1983, 1984, 1985, 14, 1986,
+    548, 332, 903, 529, 619, 1987, 1155, 1155, 709, 1979, 1163, 1988, 1028, 1989, 557, 660,
+    1979, 940, 623, 390, 1990, 1091, 464, 537, 439, 1329, 559, 360, 283, 708, 1040, 1991,
+    552, 974, 635, 81, 1992, 520, 952, 1527, 1165, 297, 182, 6, 96, 1993, 960, 1933,
+    8, 311, 1467, 127, 1552, 363, 252, 55, 609, 1705, 1, 1527, 390, 20, 114, 245,
+    65, 253, 557, 169, 399, 32, 1994, 677, 115, 195, 46, 131, 1180, 1995, 997, 1996,
+    401, 139, 1997, 290, 1998, 460, 139, 1999, 1220, 4, 339, 2000, 173, 2001, 128, 473,
+    2002, 620, 925, 557, 780, 2003, 378, 242, 1163, 1163, 489, 2004, 735, 919, 2005, 52,
+    483, 2006, 2007, 253, 557, 177, 826, 282, 527, 347, 639, 345, 149, 169, 1005, 2008,
+    339, 21, 115, 11, 951, 78, 2009, 301, 728, 113, 735, 1483, 896, 156, 1026, 568,
+    2010, 549, 521, 711, 1090, 2011, 260, 33, 2012, 6, 2012, 363, 599, 366, 735, 339,
+    47, 260, 1430, 1025, 682, 1882, 332, 189, 819, 550, 234, 250, 1823, 1126, 615, 477,
+    55, 546, 779, 997, 382, 248, 209, 1176, 123, 260, 26, 102, 646, 779, 2013, 1670,
+    1005, 537, 2014, 791, 1309, 2015, 204, 2016, 333, 111, 360, 131, 1184, 363, 2017, 2018,
+    462, 2019, 1843, 561, 363, 619, 587, 1580, 1684, 243, 1018, 1684, 780, 727, 576, 576,
+    111, 256, 316, 2020, 2021, 2022, 2023, 2024, 954, 2025, 2026, 638, 1294, 2027, 168, 909,
+    442, 2028, 580, 2029, 2030, 476, 111, 2031, 2032, 86, 1274, 820, 1674, 2033, 1851, 1279,
+    708, 2034, 2035, 2036, 1847, 845, 2037, 2038, 2033, 2039, 2040, 2041, 86, 86, 2042, 255,
+    1061, 216, 285, 35, 290, 14, 1060, 197, 318, 640, 1222, 2043, 2044, 294, 253, 56,
+    351, 2045, 815, 2046, 55, 316, 2047, 1155, 104, 130, 253, 619, 168, 2048, 2049, 2050,
+    537, 2051, 271, 477, 660, 56, 665, 2052, 744, 218, 1025, 568, 125, 455, 351, 2053,
+    2054, 2055, 2056, 2057, 340, 317, 2058, 354, 1374, 2059, 340, 2060, 2061, 2062, 467, 935,
+    1883, 1883, 1472, 2063, 807, 110, 110, 1, 2064, 252, 301, 395, 81, 2065, 2066, 1,
+    103, 2066, 925, 103, 303, 2067, 2068, 812, 1115, 2069, 1035, 832, 2070, 387, 46, 30,
+    2071, 2072, 2073, 157, 74, 161, 1116, 493, 566, 2074, 2075, 2076, 2077, 1283, 2078, 378,
+    2079, 2080, 2081, 4, 614, 614, 2082, 2083, 26, 405, 2084, 2085, 2086, 779, 233, 2087,
+    2088, 2089, 1482, 491, 1599, 1208, 530, 1170, 2090, 5, 2091, 2092, 2093, 2094, 2095, 167,
+    16, 935, 614, 340, 200, 43, 524, 212, 400, 2096, 382, 316, 1059, 757, 779, 2097,
+    2098, 970, 2099, 966, 218, 226, 1019, 2100, 138, 870, 390, 2101, 2102, 2103, 2104, 974,
+    138, 2098, 2105, 26, 556, 2106, 2107, 2108, 903, 110, 2109, 75, 171, 2110, 2111, 390,
+    419, 200, 253, 4, 2112, 236, 107, 2113, 2114, 1323, 2115, 1323, 2116, 2117, 1870, 1580,
+    2118, 2119, 2120, 1025, 2121, 26, 727, 2122, 534, 2123, 307, 1882, 1137, 2124, 307, 1882,
+    252, 2125, 290, 129, 96, 2126, 443, 270, 2127, 1434, 1434, 169, 169, 2128, 26, 2129,
+    1434, 2130, 2131, 1032, 2132, 2133, 2134, 1116, 2135, 756, 2136, 1559, 906, 2137, 2138, 782,
+    262, 1559, 2139, 2140, 110, 2141, 354, 343, 1395, 1018, 2142, 245, 568, 584, 286, 2143,
+    2144, 290, 20, 2145, 2146, 2147, 726, 2148, 726, 6, 438, 2149, 584, 1755, 2150, 2151,
+    2152, 1028, 2153, 575, 4, 2154, 2155, 2156, 30, 2139, 2157, 2128, 2158, 147, 2159, 1134,
+    77, 668, 2160, 93, 1801, 21, 26, 2161, 2162, 405, 2163, 206, 4, 2164, 565, 233,
+    2165, 2166, 807, 222, 204, 79, 2167, 2168, 2169, 2170, 57, 490, 419, 782, 243, 2171,
+    2172, 1315, 2173, 2033, 1329, 262, 2174, 642, 18, 243, 42, 2175, 311, 2176, 2177, 2178,
+    833, 2179, 96, 2180, 11, 260, 35, 45, 2181, 2182, 172, 2183, 2184, 807, 26, 236,
+    2185, 903, 2186, 2187, 1610, 2188, 42, 333, 2189, 583, 2190, 2191, 2192, 147, 42, 500,
+    129, 2193, 2194, 2195, 2196, 126, 2197, 142, 2198, 2199, 2200, 2201, 2202, 661, 568, 833,
+    1018, 239, 2203, 770, 286, 2204, 2205, 2206, 668, 2207, 2208, 1688, 535, 2209, 536, 2210,
+    1280, 555, 382, 2211, 117, 169, 2212, 1308, 2213, 101, 2214, 2215, 620, 26, 212, 12,
+    45, 1670, 2216, 462, 2217, 2218, 6, 322, 2219, 2220, 683, 773, 110, 485, 2221, 11,
+    129, 2222, 123, 2223, 240, 2224, 2225, 665, 967, 2226, 2227, 1448, 1828, 23, 2228, 323,
+    236, 20, 408, 2229, 2230, 249, 2231, 491, 175, 59, 661, 1084, 497, 4, 1603, 122,
+    2232, 2233, 34, 1883, 2234, 408, 332, 2235, 1325, 975, 2236, 290, 125, 234, 2237, 998,
+    57, 65, 2238, 1163, 219, 2239, 688, 587, 2240, 2241, 26, 226, 2242, 1555, 2243, 55,
+    358, 719, 2244, 2173, 858, 2245, 2246, 378, 197, 1107, 2247, 2089, 339, 2248, 2249, 323,
+    2250, 990, 57, 640, 2251, 8, 283, 218, 2252, 55, 704, 81, 168, 990, 226, 118,
+    1986, 159, 2253, 655, 789, 2254, 828, 2255, 980, 2256, 181, 580, 662, 2257, 2258, 2259,
+    845, 117, 473, 67, 2260, 2252, 460, 2261, 2262, 1441, 2263, 1640, 2264, 261, 43, 43,
+    2265, 2266, 2267, 2268, 2269, 124, 2270, 2271, 879, 2272, 2273, 2274, 1180, 2275, 46, 1830,
+    2276, 2277, 32, 462, 2278, 639, 353, 316, 2279, 2280, 262, 31, 2281, 100, 652, 1936,
+    719, 2282, 768, 2283, 4, 316, 2284, 1433, 168, 836, 2285, 462, 978, 1845, 661, 599,
+    120, 743, 218, 2286, 2287, 268, 2288, 2289, 243, 57, 2290, 2291, 244, 209, 146, 735,
+    30, 2292, 575, 149, 2293, 799, 274, 2216, 640, 34, 2294, 1137, 181, 2295, 527, 477,
+    548, 2296, 697, 97, 655, 2297, 300, 2298, 343, 2299, 283, 1968, 467, 169, 202, 96,
+    241, 2300, 551, 2231, 2301, 89, 2302, 2302, 181, 2303, 606, 127, 2304, 1006, 726, 2305,
+    409, 2306, 37, 973, 2307, 2308, 1021, 55, 2309, 399, 2310, 2311, 2312, 2313, 71, 327,
+    127, 441, 646, 2314, 2315, 2316, 171, 782, 181, 159, 2317, 67, 2318, 414, 179, 233,
+    117, 2319, 1636, 2030, 79, 104, 544, 2320, 48, 2321, 366, 450, 2322, 2323, 631, 2324,
+    2325, 2326, 2171, 57, 836, 2327, 2033, 2328, 638, 78, 2329, 64, 2330, 2331, 2332, 640,
+    2333, 2334, 181, 2335, 2336, 2337, 2338, 1342, 286, 1018, 2339, 801, 36, 2340, 549, 646,
+    2341, 122, 2342, 2343, 2344, 197, 1669, 882, 2220, 2345, 569, 2346, 2347, 243, 400, 504,
+    61, 2348, 2349, 2350, 2351, 473, 2352, 1006, 619, 2353, 149, 2354, 2355, 2356, 2357, 290,
+    790, 1018, 37, 619, 1128, 526, 252, 815, 2358, 367, 819, 30, 2359, 206, 2360, 6,
+    68, 1084, 2361, 2362, 2363, 328, 2364, 175, 168, 171, 2365, 2366, 2329, 2030, 410, 2367,
+    2368, 23, 2369, 2370, 59, 53, 2371, 2336, 954, 363, 4, 478, 38, 833, 726, 961,
+    2372, 408, 2373, 362, 2374, 1087, 1039, 283, 236, 273, 574, 1414, 2375, 55, 1527, 243,
+    2376, 2377, 2378, 2379, 2380, 2381, 2077, 58, 1039, 48, 2382, 218, 2383, 368, 2384, 2385,
+    2386, 2387, 557, 2388, 679, 72, 13, 1018, 2389, 539, 203, 539, 2390, 2391, 726, 240,
+    2392, 2393, 2394, 2395, 236, 218, 218, 212, 1782, 286, 811, 671, 482, 45, 811, 1882,
+    1199, 695, 748, 2128, 28, 101, 2396, 2397, 2398, 128, 2399, 197, 2400, 53, 2401, 900,
+    1640, 2402, 1559, 2403, 316, 2404, 2405, 671, 179, 744, 251, 56, 26, 56, 727, 2406,
+    105, 76, 2407, 557, 757, 762, 2408, 813, 2406, 2409, 2030, 106, 2410, 105, 35, 2411,
+    287, 44, 467, 436, 1021, 2412, 208, 2216, 391, 1084, 6, 546, 110, 2413, 363, 2414,
+    316, 589, 2415, 113, 157, 343, 474, 436, 1559, 395, 66, 762, 2416, 2417, 200, 2418,
+    556, 200, 1408, 344, 2419, 2420, 378, 526, 36, 2421, 682, 130, 744, 2422, 1602, 1346,
+    919, 569, 790, 2423, 652, 638, 2424, 1414, 143, 2425, 36, 1180, 168, 773, 2426, 1003,
+    55, 2427, 555, 2423, 450, 81, 653, 815, 15, 15, 478, 197, 1001, 2428, 586, 586,
+    586, 605, 509, 1586, 903, 30, 438, 198, 378, 30, 1061, 30, 1256, 527, 1256, 138,
+    1747, 1061, 2429, 13, 175, 554, 2430, 1686, 400, 1519, 255, 255, 2431, 2432, 143, 2433,
+    584, 584, 1311, 2434, 2435, 26, 400, 321, 954, 2436, 207, 486, 400, 222, 12, 2437,
+    493, 556, 111, 455, 2438, 14, 271, 762, 2439, 378, 1686, 881, 143, 965, 526, 260,
+    1134, 2420, 1371, 127, 127, 2440, 55, 965, 2441, 2442, 328, 764, 1136, 1347, 1455, 800,
+    2443, 1159, 75, 283, 2444, 2445, 2446, 2447, 2448, 127, 851, 620, 851, 1256, 1159, 56,
+    294, 328, 2449, 100, 271, 589, 1984, 2450, 2448, 2451, 2452, 1019, 1455, 106, 655, 1984,
+    111, 2453, 828, 2454, 2442, 253, 1455, 768, 762, 326, 504, 400, 2455, 2456, 2457, 2458,
+    655, 56, 2459, 697, 316, 2460, 1692, 168, 2461, 2462, 1588, 106, 1186, 1692, 655, 673,
+    2463, 2464, 2465, 2466, 727, 2467, 2468, 111, 2469, 965, 2470, 1263, 2471, 764, 2472, 556,
+    2473, 2474, 74, 443, 316, 995, 14, 851, 2471, 851, 655, 655, 136, 2475, 56, 655,
+    26, 655, 283, 365, 2476, 2477, 111, 1431, 55, 1323, 1307, 868, 128, 382, 21, 1610,
+    682, 815, 786, 2478, 198, 36, 1211, 2176, 127, 239, 1479, 26, 746, 1005, 55, 2479,
+    297, 297, 639, 2480, 536, 1847, 159, 908, 1541, 127, 682, 2481, 110, 203, 2479, 2482,
+    290, 526, 78, 623, 358, 358, 467, 203, 1607, 1593, 960, 123, 333, 2483, 2484, 2485,
+    2486, 467, 36, 2487, 11, 321, 2488, 26, 1607, 2489, 1692, 2490, 2491, 123, 894, 110,
+    400, 2492, 2493, 2494, 1307, 549, 26, 556, 2495, 1324, 1352, 316, 566, 7, 1610, 1607,
+    1607, 180, 55, 98, 2379, 1307, 2206, 2496, 419, 1115, 362, 2497, 2498, 2499, 1460, 13,
+    935, 935, 2500, 451, 26, 13, 403, 509, 2501, 263, 85, 149, 402, 378, 128, 442,
+    473, 128, 2502, 2503, 2504, 41, 2505, 2506, 473, 6, 505, 1699, 2507, 1699, 39, 779,
+    323, 320, 586, 185, 150, 2508, 1541, 2509, 779, 57, 185, 1953, 61, 566, 2510, 443,
+    443, 2511, 1001, 473, 556, 65, 1645, 1837, 352, 2512, 1304, 737, 2513, 2514, 1059, 642,
+    286, 1831, 83, 529, 997, 4, 61, 2180, 110, 65, 1105, 2515, 7, 1105, 2516, 2517,
+    2518, 7, 2519, 1610, 2520, 566, 2521, 290, 1777, 2522, 2523, 2524, 2525, 2526, 2527, 578,
+    925, 2528, 197, 2529, 575, 96, 127, 127, 708, 2530, 38, 845, 757, 2531, 209, 1479,
+    169, 195, 2532, 322, 2533, 96, 2534, 497, 2535, 21, 169, 149, 1845, 197, 1374, 2536,
+    2537, 2538, 116, 55, 2539, 2540, 55, 1228, 481, 735, 1229, 2541, 2542, 713, 1315, 150,
+    2543, 2544, 2545, 2546, 244, 2547, 1229, 879, 116, 1611, 2548, 828, 557, 813, 557, 16,
+    218, 55, 556, 2549, 2313, 207, 473, 497, 561, 185, 2550, 81, 55, 882, 2551, 2552,
+    303, 218, 557, 2553, 2554, 2555, 2556, 881, 2557, 397, 366, 2558, 619, 2559, 1374, 2560,
+    2561, 1390, 1390, 206, 20, 129, 2562, 169, 925, 215, 1190, 2563, 765, 173, 2564, 1496,
+    2565, 43, 2566, 2567, 2568, 495, 11, 2569, 2420, 409, 169, 708, 2570, 47, 644, 301,
+    2480, 2571, 2572, 2573, 106, 2574, 113, 2575, 571, 1096, 2576, 648, 2577, 2578, 2579, 212,
+    129, 219, 2580, 2581, 2582, 2583, 2584, 322, 2130, 143, 2585, 472, 2586, 2587, 2588, 692,
+    2589, 2263, 2590, 347, 2591, 2592, 169, 93, 2593, 2594, 2595, 879, 55, 2596, 549, 2597,
+    2598, 2599, 408, 2600, 2601, 89, 1821, 2602, 391, 1883, 237, 2603, 2604, 828, 2605, 522,
+    522, 2606, 2607, 2608, 2609, 2610, 2611, 2612, 2613, 2614, 2615, 2616, 77, 245, 2617, 2618,
+    1544, 2619, 2620, 808, 2621, 197, 2622, 2623, 486, 2624, 2625, 2626, 2482, 2627, 68, 408,
+    2628, 263, 2629, 2630, 2631, 2632, 1097, 2633, 204, 571, 1355, 2634, 997, 2635, 905, 4,
+    26, 2636, 2637, 55, 2638, 2639, 306, 2640, 2641, 1588, 149, 1430, 1095, 2642, 2643, 2644,
+    908, 2645, 1026, 273, 850, 1448, 110, 35, 2646, 2647, 26, 2648, 1061, 2649, 2650, 2651,
+    2652, 2653, 2654, 2655, 2656, 2657, 49, 2658, 45, 2659, 1528, 2660, 2661, 2662, 2663, 505,
+    1670, 342, 235, 402, 1473, 378, 2664, 216, 2665, 2666, 2667, 2668, 358, 1701, 1225, 35,
+    770, 2669, 2670, 2671, 2672, 333, 370, 395, 478, 2229, 81, 2673, 118, 658, 2674, 149,
+    2675, 1306, 2676, 2677, 2678, 970, 2679, 2680, 198, 2681, 2682, 2683, 2684, 2685, 2686, 159,
+    2687, 2688, 591, 2689, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 780, 405, 2697, 2698, 385,
+    568, 2699, 2700, 2701, 2702, 2703, 2704, 129, 2705, 2706, 307, 2697, 2707, 2708, 2709, 2710,
+    234, 126, 2711, 1314, 2712, 2713, 175, 270, 671, 56, 1084, 2714, 2715, 327, 2716, 50,
+    235, 476, 673, 2717, 2718, 2719, 2720, 2721, 529, 216, 276, 2722, 619, 297, 2723, 628,
+    300, 2724, 1116, 2587, 2725, 1830, 378, 2726, 693, 2727, 2728, 2729, 2730, 129, 735, 1510,
+    104, 2638, 255, 2731, 2732, 26, 2733, 2734, 554, 2735, 460, 55, 2736, 71, 2737, 219,
+    2172, 2738, 2739, 1228, 811, 77, 1126, 2740, 2741, 1005, 2742, 524, 881, 1669, 2743, 870,
+    2739, 210, 2744, 1019, 2745, 1555, 2746, 2747, 2166, 2748, 658, 2749, 1569, 213, 2750, 2751,
+    55, 2018, 1182, 2752, 2753, 2754, 498, 261, 1631, 985, 197, 2755, 779, 1003, 2756, 2757,
+    2758, 2759, 1140, 2033, 2760, 2761, 2762, 2763, 2764, 2765, 2766, 4, 2767, 2768, 2769, 104,
+    77, 77, 1660, 288, 288, 2770, 2771, 2772, 490, 2773, 216, 2774, 77, 628, 2775, 2743,
+    311, 131, 2776, 2777, 271, 2778, 2529, 1564, 2102, 80, 1832, 242, 306, 2779, 303, 147,
+    2780, 2781, 2782, 475, 2783, 159, 332, 71, 2784, 244, 2785, 1115, 288, 2786, 2787, 708,
+    2788, 646, 580, 628, 2789, 2790, 282, 2791, 2792, 1448, 59, 250, 2793, 580, 2794, 2795,
+    347, 2796, 467, 277, 824, 2797, 2798, 210, 2799, 2800, 2801, 400, 2802, 2803, 1515, 2804,
+    44, 527, 2805, 2806, 2807, 1200, 580, 2068, 2808, 2341, 2809, 2810, 2811, 2812, 111, 2715,
+    2813, 209, 2814, 801, 2815, 350, 345, 167, 2010, 888, 2816, 587, 2817, 200, 244, 67,
+    2818, 2819, 292, 2820, 80, 17, 37, 2821, 7, 2822, 2823, 2824, 2294, 684, 2825, 2826,
+    1761, 2827, 748, 2828, 2829, 400, 2830, 2085, 2831, 110, 263, 970, 2832, 2833, 2834, 1443,
+    236, 967, 574, 2835, 2836, 870, 205, 242, 1222, 462, 2360, 2730, 1655, 2837, 2838, 1046,
+    2839, 126, 561, 561, 443, 2840, 2841, 1005, 2842, 409, 880, 2843, 2844, 571, 679, 2845,
+    2846, 429, 2847, 444, 382, 1003, 159, 2848, 2849, 2850, 2851, 2852, 2853, 61, 306, 2854,
+    1018, 271, 726, 2855, 796, 2856, 2857, 69, 277, 2858, 513, 2859, 136, 344, 172, 599,
+    834, 571, 436, 159, 2712, 2097, 1026, 2860, 403, 2861, 2862, 400, 161, 2863, 1576, 2864,
+    234, 81, 574, 2865, 2866, 2867, 197, 2868, 2869, 2870, 2871, 642, 2872, 1573, 2873, 967,
+    502, 2874, 600, 2875, 288, 580, 2876, 2877, 2878, 2879, 197, 658, 574, 443, 709, 156,
+    502, 248, 1933, 2880, 205, 241, 242, 2881, 81, 218, 51, 2882, 2883, 2884, 2885, 815,
+    2886, 2887, 96, 658, 149, 2739, 2888, 110, 2889, 686, 2838, 2890, 2891, 2892, 57, 2893,
+    1347, 2894, 2895, 1292, 1166, 662, 834, 807, 1645, 773, 2864, 478, 1126, 2896, 1423, 2897,
+    23, 2898, 2899, 252, 342, 2900, 2901, 2902, 815, 574, 294, 726, 1607, 2903, 2904, 2905,
+    2906, 61, 2907, 273, 2908, 248, 2909, 2910, 1355, 628, 111, 14, 2911, 2096, 2912, 1300,
+    735, 55, 578, 370, 1300, 288, 781, 964, 1645, 2913, 739, 1300, 65, 270, 587, 2914,
+    2915, 599, 2916, 1098, 2917, 86, 234, 2918, 386, 2919, 118, 1832, 2920, 2912, 2921, 111,
+    727, 1609, 55, 26, 2779, 2922, 2923, 635, 1770, 2924, 744, 2925, 161, 53, 242, 2926,
+    420, 2927, 2928, 2929, 773, 219, 236, 678, 2930, 2929, 18, 773, 2931, 2932, 835, 2933,
+    2507, 589, 122, 146, 419, 451, 277, 2929, 1124, 1163, 231, 889, 2934, 2839, 919, 659,
+    262, 231, 542, 1537, 260, 790, 231, 2935, 231, 919, 129, 231, 248, 2936, 1127, 57,
+    255, 557, 262, 2937, 57, 1845, 231, 2938, 659, 557, 109, 276, 1127, 557, 557, 1323,
+    919, 919, 919, 2939, 70, 61, 26, 2940, 470, 470, 2941, 1323, 2942, 1325, 2943, 85,
+    123, 175, 1035, 2030, 2944, 2945, 2946, 1588, 974, 1645, 6, 2947, 2948, 286, 2949, 74,
+    1519, 130, 340, 965, 1408, 2950, 2951, 242, 1005, 2952, 199, 198, 1999, 1398, 1607, 26,
+    492, 2953, 2954, 2955, 2956, 2957, 2958, 675, 331, 2959, 2960, 34, 2961, 126, 2962, 2963,
+    1882, 56, 2964, 850, 1882, 744, 1020, 55, 1078, 21, 1090, 2965, 304, 2966, 71, 598,
+    2967, 880, 845, 2968, 138, 1061, 2969, 2970, 532, 90, 2971, 2972, 179, 2138, 2973, 123,
+    1, 2974, 2975, 469, 531, 173, 2976, 98, 260, 42, 1701, 779, 283, 2269, 2977, 348,
+    348, 578, 330, 20, 402, 2978, 12, 2979, 2980, 896, 180, 139, 322, 366, 1239, 773,
+    879, 1273, 72, 378, 2220, 67, 1746, 1746, 185, 625, 536, 2981, 233, 469, 557, 47,
+    7, 2982, 2983, 471, 2984, 117, 488, 245, 1035, 591, 558, 2205, 1519, 1256, 677, 41,
+    2985, 349, 619, 2986, 338, 2987, 2988, 26, 609, 2989, 11, 499, 205, 1201, 2990, 2991,
+    2992, 2993, 358, 68, 2994, 640, 2995, 1440, 1095, 530, 2996, 1291, 628, 682, 682, 2997,
+    530, 1043, 333, 1059, 1607, 306, 2972, 344, 2998, 147, 580, 333, 2999, 3000, 3001, 3002,
+    3003, 3004, 316, 3005, 3006, 1331, 3007, 395, 20, 1509, 448, 3008, 3009, 3010, 3011, 462,
+    270, 3012, 462, 26, 3013, 400, 3014, 253, 3015, 316, 3016, 276, 203, 71, 598, 576,
+    744, 3017, 3018, 762, 580, 3019, 3020, 790, 2391, 3021, 3022, 1315, 954, 598, 533, 45,
+    3023, 41, 554, 530, 773, 60, 126, 435, 3024, 47, 1832, 879, 3025, 339, 390, 3026,
+    3027, 888, 3028, 3029, 1979, 3030, 353, 390, 527, 149, 67, 735, 3031, 3032, 1153, 3033,
+    3034, 1118, 3035, 724, 1028, 46, 756, 3036, 762, 1705, 3037, 1061, 366, 3038, 585, 1388,
+    873, 326, 826, 3039, 169, 175, 801, 3040, 530, 358, 1256, 37, 37, 1059, 436, 3041,
+    324, 530, 3042, 71, 3043, 2414, 55, 3044, 907, 3045, 3046, 1273, 752, 3047, 608, 1607,
+    263, 115, 206, 128, 303, 2216, 3048, 557, 890, 96, 1083, 3049, 141, 3050, 1309, 1093,
+    230, 123, 182, 1394, 3051, 1153, 1005, 3052, 1565, 957, 869, 351, 279, 1087, 638, 813,
+    3053, 1503, 354, 1230, 17, 488, 3054, 3055, 65, 306, 422, 3056, 640, 2033, 438, 450,
+    424, 1149, 1450, 743, 832, 1122, 708, 2965, 3057, 3058, 709, 3059, 646, 3060, 85, 3061,
+    3062, 3063, 3064, 3065, 1292, 1292, 3066, 640, 1692, 3067, 1159, 1020, 3068, 3069, 3070, 527,
+    1020, 558, 252, 42, 655, 330, 832, 400, 3071, 2758, 3072, 131, 126, 488, 1128, 344,
+    3073, 3074, 3075, 420, 316, 3076, 1519, 1516, 954, 3077, 3078, 1770, 3079, 206, 481, 564,
+    574, 3080, 815, 3081, 3082, 578, 303, 1020, 243, 3083, 348, 348, 644, 55, 157, 1516,
+    365, 1607, 303, 82, 1078, 326, 110, 1434, 3084, 3085, 3086, 3087, 405, 1610, 3088, 42,
+    3085, 277, 277, 842, 3089, 277, 11, 842, 251, 541, 3090, 251, 405, 3091, 3092, 3093,
+    1699, 3094, 181, 1562, 495, 1830, 542, 260, 343, 1984, 3095, 3096, 96, 521, 340, 345,
+    315, 3097, 3098, 3099, 37, 342, 342, 252, 960, 3097, 469, 469, 658, 3100, 459, 248,
+    3101, 3054, 3102, 1032, 900, 3103, 3104, 1411, 3105, 3106, 3107, 373, 3108, 3109, 66, 3110,
+    3111, 583, 6, 3112, 3113, 653, 144, 2373, 824, 3114, 1116, 3115, 443, 210, 3116, 1783,
+    318, 639, 3117, 924, 605, 3118, 3119, 1098, 109, 3120, 3121, 210, 3122, 371, 3123, 3124,
+    26, 637, 3125, 3126, 616, 197, 695, 3127, 143, 642, 3128, 3129, 3130, 3131, 324, 3132,
+    3133, 21, 330, 637, 443, 3134, 369, 637, 110, 1006, 118, 126, 470, 3135, 3136, 3137,
+    3138, 2284, 286, 3139, 3140, 614, 3141, 665, 3142, 1018, 220, 387, 3143, 23, 3144, 3145,
+    245, 3146, 343, 3147, 566, 3148, 3149, 638, 303, 3150, 2767, 1134, 1564, 114, 2980, 3151,
+    1028, 3152, 3153, 419, 3154, 3155, 967, 3156, 3146, 3157, 735, 3158, 662, 1090, 583, 390,
+    1545, 30, 3159, 3160, 4, 580, 974, 114, 3161, 3162, 3163, 74, 1702, 442, 3164, 686,
+    860, 3165, 3166, 3167, 909, 1005, 3168, 126, 45, 150, 1416, 216, 2297, 233, 903, 552,
+    868, 534, 3104, 260, 463, 463, 212, 76, 3169, 88, 108, 3170, 1510, 236, 3171, 3172,
+    3173, 316, 965, 3174, 584, 3175, 36, 1128, 43, 169, 1239, 549, 3176, 1324, 3177, 469,
+    3178, 744, 744, 6, 3179, 462, 3180, 3181, 619, 184, 2220, 3182, 3183, 735, 555, 682,
+    668, 3019, 129, 303, 665, 3184, 773, 3185, 3186, 101, 3187, 652, 209, 245, 3188, 559,
+    1323, 3189, 1025, 470, 3190, 3191, 3192, 1199, 1170, 96, 476, 204, 3193, 3194, 243, 203,
+    3195, 3196, 3197, 3198, 12, 125, 390, 3199, 3200, 3201, 1324, 45, 283, 255, 640, 3146,
+    1163, 964, 701, 619, 3202, 3203, 303, 4, 1086, 303, 390, 236, 390, 1847, 55, 311,
+    509, 3204, 3205, 569, 311, 74, 2282, 1329, 123, 3206, 3167, 3167, 3207, 91, 301, 3208,
+    332, 796, 1433, 260, 460, 572, 234, 957, 77, 502, 3209, 745, 3210, 1020, 886, 886,
+    2277, 900, 1616, 3211, 638, 1451, 1882, 3212, 488, 124, 200, 3213, 2218, 126, 773, 3214,
+    3215, 343, 638, 262, 3216, 3217, 3218, 248, 3219, 509, 55, 1098, 311, 1043, 144, 599,
+    365, 385, 1137, 3220, 477, 3221, 899, 3222, 1522, 2302, 11, 2010, 1569, 3223, 1183, 1346,
+    566, 3224, 1204, 502, 575, 743, 1705, 54, 343, 3225, 551, 2677, 1028, 3226, 527, 3227,
+    3228, 599, 826, 888, 209, 909, 2308, 903, 1, 3229, 96, 3230, 435, 105, 76, 81,
+    146, 708, 1847, 295, 277, 3231, 127, 339, 709, 3232, 3233, 3234, 67, 3235, 167, 557,
+    3236, 313, 748, 967, 14, 1346, 1296, 33, 3147, 469, 3237, 3238, 3239, 425, 1275, 557,
+    3240, 313, 3241, 3242, 169, 37, 95, 311, 883, 3243, 623, 41, 1062, 206, 819, 964,
+    26, 3244, 3245, 82, 1040, 3246, 405, 2330, 487, 17, 576, 111, 1126, 3247, 697, 2336,
+    3052, 726, 3248, 3249, 447, 3250, 1300, 1106, 200, 200, 330, 447, 832, 2107, 3251, 1510,
+    4, 3252, 1545, 439, 54, 311, 311, 1005, 3253, 443, 144, 974, 156, 462, 709, 405,
+    144, 197, 1576, 3254, 3255, 735, 365, 1025, 1346, 3209, 2495, 3256, 26, 61, 571, 343,
+    110, 126, 3257, 107, 832, 861, 3258, 662, 95, 256, 3259, 1390, 206, 3198, 478, 3147,
+    3260, 8, 815, 139, 390, 964, 313, 3261, 3262, 3263, 90, 1251, 954, 95, 455, 801,
+    91, 1843, 3264, 385, 3265, 576, 1001, 766, 589, 420, 735, 215, 260, 841, 3266, 420,
+    3267, 599, 215, 2381, 1518, 485, 1784, 3268, 2914, 3269, 420, 3270, 127, 556, 556, 681,
+    399, 3271, 768, 3272, 3273, 378, 3274, 970, 159, 18, 459, 236, 2935, 2206, 56, 1510,
+    3137, 966, 129, 3275, 464, 470, 3276, 3277, 2587, 301, 286, 957, 3278, 1035, 970, 3279,
+    204, 970, 111, 3280, 3281, 23, 456, 242, 242, 599, 470, 91, 3282, 1021, 3283, 185,
+    3284, 38, 57, 3285, 432, 3286, 944, 3287, 3288, 3289, 245, 6, 328, 597, 3290, 161,
+    1519, 245, 353, 1640, 35, 475, 3291, 6, 339, 1059, 1059, 3292, 385, 3293, 826, 110,
+    129, 210, 6, 1111, 212, 3294, 128, 530, 852, 128, 234, 1004, 1306, 3295, 3296, 735,
+    234, 3297, 385, 230, 3298, 695, 436, 113, 3299, 544, 3300, 3301, 1039, 56, 589, 56,
+    248, 436, 113, 1845, 896, 249, 655, 343, 478, 2290, 615, 909, 492, 255, 580, 1059,
+    478, 815, 478, 3302, 57, 339, 3299, 478, 492, 1005, 3303, 3304, 106, 492, 779, 21,
+    356, 1591, 3305, 3306, 3307, 3308, 208, 3299, 61, 356, 68, 3309, 26, 6, 339, 3310,
+    3311, 339, 30, 3312, 539, 1820, 3313, 564, 3314, 539, 251, 2580, 3315, 1472, 3316, 3317,
+    2448, 3318, 3319, 3320, 3321, 3090, 3313, 3322, 539, 3323, 1533, 1099, 3324, 397, 397, 397,
+    3325, 744, 3326, 230, 2057, 3327, 1300, 3328, 726, 3329, 775, 775, 3330, 3331, 3332, 97,
+    3333, 618, 67, 3334, 3332, 3332, 3332, 3335, 67, 1159, 3336, 2414, 473, 138, 104, 138,
+    3337, 138, 138, 1430, 3338, 3339, 1948, 1616, 2336, 3340, 3341, 332, 819, 1261, 65, 602,
+    3342, 46, 752, 3343, 294, 46, 1588, 1313, 3344, 3345, 3346, 400, 96, 19, 96, 532,
+    439, 197, 243, 421, 1416, 188, 1061, 294, 828, 3347, 3348, 919, 408, 408, 1770, 1261,
+    408, 316, 96, 316, 2880, 356, 556, 3347, 65, 3349, 3350, 3351, 965, 1847, 1018, 55,
+    3352, 61, 61, 3353, 3354, 77, 584, 21, 2989, 45, 57, 1686, 107, 3355, 584, 455,
+    1398, 477, 921, 3356, 21, 127, 3357, 169, 286, 3358, 493, 253, 520, 743, 253, 665,
+    46, 320, 3359, 619, 330, 53, 564, 1116, 34, 34, 179, 316, 762, 528, 1143, 966,
+    304, 55, 545, 3360, 303, 378, 292, 3361, 3362, 1118, 131, 330, 20, 1408, 455, 320,
+    200, 416, 552, 533, 545, 260, 351, 1061, 395, 64, 800, 292, 757, 568, 966, 26,
+    495, 216, 3363, 134, 149, 3364, 241, 47, 123, 1336, 240, 1239, 109, 3365, 6, 534,
+    1059, 432, 1569, 615, 3366, 436, 3367, 56, 568, 1569, 129, 3368, 323, 1199, 67, 3369,
+    903, 470, 1025, 1163, 1163, 580, 272, 3370, 367, 542, 30, 30, 521, 1199, 97, 619,
+    313, 564, 45, 530, 919, 1201, 68, 2482, 323, 248, 3371, 884, 1230, 297, 704, 775,
+    195, 1331, 154, 154, 86, 157, 144, 509, 704, 3372, 552, 598, 598, 3373, 790, 450,
+    1315, 1315, 3359, 262, 262, 3374, 3375, 3376, 903, 306, 248, 2282, 255, 903, 243, 3377,
+    3378, 697, 652, 127, 172, 529, 3379, 395, 439, 790, 248, 970, 1032, 527, 390, 3380,
+    3381, 30, 219, 536, 244, 202, 283, 3382, 1004, 3383, 149, 42, 3384, 180, 1304, 1346,
+    255, 400, 3385, 210, 1137, 3386, 1153, 3387, 3388, 390, 353, 53, 3389, 3390, 303, 243,
+    353, 625, 107, 313, 1117, 1204, 390, 253, 869, 919, 1273, 197, 324, 2139, 3391, 3392,
+    127, 3393, 14, 21, 652, 3394, 970, 1569, 172, 2313, 3395, 754, 3396, 242, 1184, 3397,
+    1059, 1496, 763, 353, 1699, 3398, 487, 727, 3399, 3400, 872, 554, 869, 354, 439, 3401,
+    3402, 444, 320, 1046, 3403, 2864, 564, 564, 167, 580, 3404, 370, 549, 3405, 504, 1204,
+    1025, 697, 297, 548, 3406, 356, 356, 1291, 1747, 1159, 3090, 3369, 2002, 67, 1025, 67,
+    556, 3407, 26, 966, 521, 252, 252, 3408, 242, 3409, 169, 3410, 528, 197, 640, 330,
+    2864, 209, 3411, 313, 564, 3412, 127, 3413, 455, 5, 3414, 127, 683, 3415, 370, 370,
+    2313, 3416, 197, 332, 365, 627, 3417, 303, 209, 521, 101, 1082, 577, 470, 2313, 2313,
+    242, 303, 303, 218, 3418, 218, 3419, 3420, 2466, 1448, 585, 3421, 3422, 3423, 3424, 682,
+    128, 500, 401, 3425, 685, 316, 1588, 3426, 3426, 3427, 219, 219, 3428, 2146, 3429, 1116,
+    845, 1702, 3430, 6, 497, 642, 639, 1116, 3431, 756, 3432, 505, 3433, 3434, 333, 648,
+    1777, 3435, 242, 1430, 671, 3436, 198, 197, 200, 288, 659, 354, 3437, 20, 3438, 3439,
+    3440, 3441, 3442, 3443, 283, 887, 290, 283, 640, 1005, 26, 26, 1061, 130, 3444, 23,
+    1777, 20, 3445, 1189, 367, 249, 1430, 852, 167, 338, 20, 974, 2370, 1061, 354, 3446,
+    326, 20, 659, 726, 985, 3447, 271, 231, 105, 1246, 282, 608, 709, 436, 659, 575,
+    3448, 3449, 338, 1061, 47, 128, 1296, 695, 883, 436, 3239, 1527, 502, 1134, 3450, 279,
+    3451, 1087, 231, 3248, 438, 338, 354, 328, 2442, 378, 283, 3452, 2495, 1632, 126, 126,
+    2135, 436, 1279, 3453, 3070, 3454, 338, 2370, 1271, 207, 127, 775, 53, 3455, 3456, 3457,
+    611, 3458, 3458, 3459, 3460, 3461, 210, 3462, 273, 3463, 3464, 1645, 116, 1032, 3465, 3466,
+    1300, 1414, 3467, 205, 2933, 600, 3468, 3469, 3470, 46, 11, 578, 4, 3146, 587, 1251,
+    3471, 402, 488, 1035, 3472, 1136, 620, 226, 568, 713, 828, 535, 202, 283, 902, 283,
+    990, 3473, 34, 234, 919, 185, 587, 1376, 1137, 3474, 3370, 2290, 3475, 704, 3476, 3477,
+    3228, 3478, 634, 3479, 1545, 652, 430, 840, 580, 738, 3472, 1602, 3480, 16, 828, 473,
+    149, 3481, 1083, 2549, 443, 623, 1602, 283, 333, 3482, 473, 473, 3483, 561, 1728, 559,
+    609, 3329, 20, 123, 26, 207, 365, 919, 81, 3484, 3472, 882, 2240, 828, 3485, 587,
+    175, 126, 3486, 96, 3487, 15, 356, 3488, 56, 627, 1018, 116, 17, 111, 116, 3489,
+    241, 303, 1137, 1126, 3490, 655, 2929, 2284, 2929, 685, 1831, 371, 2524, 3491, 658, 3492,
+    1006, 3493, 3494, 26, 3046, 126, 26, 405, 75, 1374, 3495, 75, 110, 110, 128, 3496,
+    5, 200, 378, 245, 1588, 630, 351, 1699, 1078, 385, 3497, 2330, 3498, 883, 226, 850,
+    546, 311, 277, 1906, 2176, 638, 19, 6, 485, 3499, 326, 1386, 1496, 3500, 638, 143,
+    3501, 1699, 3502, 703, 2870, 3502, 126, 126, 1229, 288, 168, 3503, 673, 3504, 126, 1263,
+    168, 645, 234, 2870, 306, 3505, 245, 3506, 306, 326, 556, 56, 56, 974, 3507, 123,
+    123, 3508, 3509, 283, 61, 61, 6, 6, 6, 6, 3510, 3510, 3511, 12, 1460, 568,
+    735, 3512, 580, 967, 1433, 1087, 143, 1157, 3513, 358, 3514, 2330, 627, 967, 3515, 3516,
+    3517, 169, 3518, 1134, 26, 2128, 98, 583, 147, 1575, 378, 3519, 903, 1772, 112, 638,
+    3520, 197, 642, 3521, 536, 3522, 180, 879, 3523, 3524, 283, 1444, 3525, 919, 301, 3526,
+    2218, 301, 3527, 2253, 3528, 395, 56, 726, 283, 3529, 1448, 2218, 3530, 96, 46, 655,
+    3531, 2569, 3532, 527, 343, 241, 243, 3243, 67, 209, 3533, 903, 894, 3534, 687, 903,
+    3535, 1371, 687, 3536, 98, 841, 61, 3537, 502, 1153, 3538, 3539, 362, 3540, 662, 3541,
+    126, 26, 190, 1434, 2433, 3542, 3543, 56, 3544, 1434, 3545, 3546, 3547, 3548, 3549, 1464,
+    3550, 2436, 283, 3145, 476, 3551, 2495, 599, 261, 797, 486, 1134, 679, 21, 169, 3552,
+    65, 199, 3553, 3554, 451, 3555, 175, 3556, 495, 3557, 3558, 490, 3559, 66, 3560, 3561,
+    1831, 60, 13, 1152, 3562, 1018, 662, 3563, 779, 3564, 1006, 3565, 66, 763, 4, 1421,
+    2750, 1455, 316, 26, 3566, 1906, 1887, 2657, 3567, 3568, 3569, 3570, 868, 2309, 3571, 3572,
+    3573, 333, 3574, 3575, 3576, 3577, 3578, 3579, 3580, 3581, 640, 3582, 6, 3583, 97, 204,
+    169, 403, 124, 3584, 532, 765, 3585, 65, 126, 3586, 150, 945, 451, 791, 695, 3587,
+    3588, 3589, 430, 197, 331, 358, 631, 539, 3590, 198, 26, 110, 3591, 3592, 3593, 65,
+    780, 3594, 1541, 908, 198, 7, 435, 3595, 3596, 47, 327, 681, 3597, 184, 3598, 3599,
+    430, 81, 3600, 1, 820, 61, 2929, 78, 3601, 6, 13, 765, 237, 502, 768, 11,
+    322, 2057, 1060, 3602, 3115, 203, 3603, 752, 128, 3604, 197, 130, 455, 403, 2758, 687,
+    61, 3605, 3606, 3607, 697, 1256, 813, 41, 399, 476, 3608, 358, 3609, 3610, 842, 3611,
+    455, 3556, 3612, 3613, 197, 628, 237, 294, 64, 3614, 150, 294, 333, 26, 954, 830,
+    197, 3615, 3616, 352, 3617, 763, 3618, 3475, 3619, 21, 21, 273, 111, 1241, 210, 451,
+    26, 455, 55, 678, 41, 149, 195, 1040, 286, 21, 526, 301, 716, 289, 687, 490,
+    2750, 3620, 55, 56, 934, 934, 124, 1347, 3168, 2278, 261, 2224, 197, 2216, 812, 576,
+    960, 3621, 1292, 1628, 660, 3622, 460, 3623, 756, 56, 3624, 384, 118, 216, 3211, 1405,
+    294, 294, 242, 112, 3625, 779, 3626, 197, 3627, 662, 1569, 61, 3628, 3629, 631, 744,
+    358, 488, 316, 742, 530, 687, 181, 150, 702, 288, 934, 3630, 41, 399, 307, 3631,
+    3632, 527, 268, 38, 3633, 349, 813, 737, 3634, 353, 3635, 3636, 3637, 181, 826, 209,
+    263, 286, 243, 580, 3638, 126, 826, 210, 3639, 126, 2362, 467, 358, 3640, 3641, 773,
+    149, 3642, 105, 3643, 203, 390, 206, 150, 218, 9, 2143, 3644, 631, 3645, 3021, 1006,
+    599, 1576, 1576, 195, 3646, 419, 37, 3647, 253, 1556, 763, 810, 169, 104, 316, 3648,
+    576, 7, 1256, 1757, 358, 1532, 642, 975, 290, 3649, 686, 3650, 719, 358, 179, 55,
+    3651, 883, 773, 139, 3652, 3653, 66, 3654, 399, 3655, 555, 3656, 1229, 3657, 3658, 438,
+    438, 231, 78, 742, 702, 702, 450, 526, 3659, 2330, 3607, 2326, 1565, 3660, 934, 195,
+    3661, 473, 61, 1256, 443, 3662, 763, 3663, 609, 3664, 2033, 708, 835, 108, 3665, 3666,
+    17, 3667, 487, 3668, 361, 606, 639, 569, 569, 580, 1061, 356, 3669, 273, 679, 283,
+    3607, 120, 658, 320, 1309, 181, 177, 38, 1025, 1450, 78, 443, 1061, 1576, 61, 491,
+    3670, 3671, 609, 3672, 3255, 3673, 1020, 1159, 539, 3674, 37, 556, 813, 3675, 2495, 1006,
+    297, 3250, 3676, 693, 658, 3677, 526, 328, 3678, 2240, 742, 556, 295, 1838, 880, 1978,
+    807, 26, 3093, 1018, 3679, 288, 880, 2112, 652, 3680, 3681, 708, 3682, 256, 205, 205,
+    316, 3683, 2758, 96, 1251, 3684, 105, 26, 15, 80, 68, 458, 700, 420, 206, 3487,
+    3685, 1728, 340, 78, 57, 609, 3686, 3687, 3688, 3454, 539, 3689, 1856, 3690, 700, 8,
+    283, 9, 9, 697, 179, 290, 283, 1408, 2373, 3691, 204, 1392, 1271, 530, 3692, 360,
+    1247, 3693, 458, 1040, 3694, 360, 56, 316, 3695, 1229, 2362, 435, 1402, 1410, 79, 556,
+    3696, 3697, 3698, 673, 455, 21, 744, 3699, 430, 3700, 3701, 530, 745, 46, 673, 3702,
+    3703, 1972, 2641, 548, 36, 3704, 3705, 47, 3706, 199, 387, 3707, 3708, 169, 345, 935,
+    1540, 548, 3709, 673, 168, 127, 580, 168, 3710, 123, 3711, 548, 65, 3712, 3713, 20,
+    239, 200, 200, 200, 149, 3714, 294, 757, 200, 55, 3715, 870, 3716, 488, 2406, 2983,
+    6, 202, 3717, 200, 886, 218, 471, 3718, 3719, 3720, 219, 3721, 3722, 3723, 3724, 3725,
+    1755, 748, 2030, 1670, 354, 3726, 3727, 3728, 3729, 2143, 45, 3730, 3731, 3732, 113, 21,
+    829, 286, 462, 584, 1728, 1404, 3733, 536, 3734, 3735, 799, 3736, 3737, 3561, 3738, 199,
+    390, 3739, 330, 3740, 3741, 1334, 3742, 149, 829, 3743, 3744, 3745, 218, 3562, 495, 3746,
+    509, 486, 277, 455, 556, 3747, 522, 3748, 1757, 824, 493, 3749, 26, 43, 3750, 785,
+    3751, 524, 3752, 3753, 785, 226, 959, 173, 3754, 271, 835, 1263, 3755, 3756, 3757, 200,
+    362, 791, 3758, 3759, 125, 3760, 807, 3761, 3762, 19, 300, 88, 3763, 3764, 3765, 3766,
+    3767, 78, 3768, 382, 3769, 909, 3770, 3771, 942, 320, 526, 171, 3772, 3773, 3774, 161,
+    3775, 3776, 534, 3777, 3778, 3779, 959, 3780, 3781, 3782, 1655, 3783, 743, 3784, 3785, 3786,
+    1670, 3787, 3788, 3789, 31, 3790, 3791, 3792, 573, 850, 833, 316, 1728, 348, 168, 3793,
+    17, 53, 3794, 504, 3795, 286, 948, 3796, 110, 3797, 1757, 3798, 3799, 685, 101, 3800,
+    3801, 3802, 3803, 3804, 7, 3805, 502, 3806, 3807, 3808, 1655, 137, 351, 2529, 298, 3809,
+    1746, 270, 3810, 3811, 533, 3812, 115, 910, 3813, 3814, 36, 3815, 3816, 682, 300, 408,
+    3817, 1847, 556, 3818, 1283, 283, 470, 1323, 3819, 86, 3820, 521, 3821, 841, 3572, 387,
+    502, 311, 57, 3822, 1670, 48, 3823, 290, 1201, 3824, 3825, 530, 3826, 3827, 786, 1346,
+    3812, 509, 1309, 168, 26, 3828, 2996, 3829, 460, 3820, 2294, 724, 3830, 2240, 68, 3831,
+    854, 3832, 3833, 3834, 31, 3835, 3836, 3837, 3838, 3839, 3840, 3841, 1032, 3842, 3843, 3844,
+    350, 3845, 3846, 3847, 252, 1562, 408, 1602, 3848, 3849, 191, 338, 168, 210, 564, 243,
+    3850, 205, 3851, 1921, 2438, 552, 1404, 3852, 3853, 1986, 3854, 3855, 3856, 3857, 455, 631,
+    3858, 3859, 3860, 554, 675, 808, 3861, 3862, 3863, 3864, 569, 3865, 530, 2313, 3733, 3755,
+    1414, 3866, 3867, 251, 3868, 3869, 1773, 2135, 719, 3870, 3871, 3872, 253, 3873, 43, 3874,
+    260, 3875, 3876, 1013, 903, 103, 2529, 3877, 3878, 3879, 3880, 3881, 1433, 3882, 98, 1559,
+    3883, 294, 3884, 516, 1304, 3885, 3886, 509, 2, 743, 1114, 137, 3887, 888, 182, 3888,
+    3889, 127, 3890, 3891, 3892, 3893, 715, 934, 3894, 3895, 9, 168, 3896, 3897, 3898, 3899,
+    303, 345, 1402, 3900, 1183, 3901, 209, 3902, 3121, 826, 3903, 3904, 3840, 3905, 110, 3906,
+    3907, 3908, 241, 3909, 3910, 536, 210, 218, 3911, 3912, 3913, 1323, 3914, 3915, 3916, 169,
+    3917, 2451, 1155, 230, 3918, 1115, 1115, 919, 3919, 3920, 878, 3921, 3922, 3923, 3924, 3925,
+    3926, 1813, 2294, 300, 289, 67, 209, 3927, 420, 3928, 522, 636, 3929, 3930, 631, 878,
+    841, 287, 522, 313, 3931, 3932, 3933, 439, 191, 3273, 290, 171, 3934, 3935, 3936, 3937,
+    3938, 67, 564, 845, 3939, 3940, 3941, 3942, 3943, 3944, 3945, 943, 1761, 815, 1271, 3946,
+    3947, 9, 965, 951, 3948, 71, 3949, 3950, 1879, 55, 883, 3951, 3952, 3953, 3954, 286,
+    2767, 3955, 3956, 249, 149, 7, 9, 101, 625, 1306, 263, 4, 1920, 1229, 3957, 1229,
+    43, 1624, 67, 3958, 1602, 331, 3959, 3960, 414, 3961, 679, 1311, 3888, 2064, 3962, 1023,
+    3963, 3964, 3965, 1562, 3966, 120, 967, 934, 123, 134, 3930, 631, 3967, 3968, 3969, 2328,
+    1717, 144, 3970, 3971, 3972, 3973, 974, 627, 462, 3974, 283, 3975, 3660, 3976, 3977, 3978,
+    3979, 3980, 2471, 3981, 3982, 3983, 3984, 3985, 3986, 1699, 67, 469, 3987, 618, 400, 599,
+    3988, 3989, 3990, 3849, 1292, 619, 3991, 851, 3992, 26, 3993, 120, 120, 3994, 3995, 1085,
+    3996, 3997, 688, 1225, 1573, 1633, 15, 3998, 3999, 3085, 4000, 387, 1456, 4001, 4002, 98,
+    4003, 799, 2880, 4004, 4005, 675, 4006, 367, 4007, 4008, 74, 4009, 4010, 4011, 522, 1292,
+    1633, 537, 7, 2881, 841, 4012, 86, 64, 4013, 3819, 4014, 1294, 17, 4015, 1079, 182,
+    646, 74, 4016, 1933, 386, 4017, 3821, 80, 1456, 960, 4018, 4019, 888, 205, 771, 2365,
+    7, 4020, 4021, 4022, 330, 316, 252, 149, 4023, 4024, 546, 387, 90, 74, 4025, 489,
+    420, 1295, 1283, 1813, 4026, 252, 252, 833, 4027, 127, 3958, 3794, 3929, 3890, 4028, 4029,
+    1300, 655, 7, 961, 331, 1624, 4024, 2906, 4030, 62, 4031, 22, 179, 40, 4032, 4033,
+    1039, 1633, 775, 4034, 4035, 55, 4036, 656, 801, 4037, 348, 286, 286, 4038, 286, 53,
+    4039, 599, 1153, 4040, 4041, 324, 4012, 7, 4042, 4043, 68, 1410, 967, 4044, 4045, 4046,
+    1804, 4047, 2313, 1847, 4048, 841, 556, 420, 166, 1079, 4049, 851, 3468, 4050, 241, 1933,
+    1933, 2563, 3803, 273, 3543, 202, 128, 3549, 4051, 4052, 4053, 416, 2313, 113, 3639, 4054,
+    790, 216, 813, 159, 485, 4055, 263, 4056, 11, 571, 526, 230, 4057, 45, 653, 1846,
+    4058, 3402, 573, 11, 3813, 653, 861, 242, 126, 4059, 1098, 4060, 26, 1294, 4061, 528,
+    4062, 4063, 1661, 860, 4064, 342, 4065, 638, 536, 323, 526, 4066, 4067, 1279, 1003, 1757,
+    1126, 115, 204, 4066, 356, 1837, 4068, 202, 841, 646, 841, 4069, 4070, 4071, 126, 4072,
+    4064, 159, 1460, 966, 253, 253, 1562, 544, 556, 1562, 623, 716, 4073, 4073, 53, 57,
+    53, 243, 4074, 997, 3329, 1522, 277, 4075, 1522, 57, 249, 571, 4076, 4077, 411, 756,
+    4078, 4079, 226, 1046, 439, 239, 4080, 1750, 873, 4081, 4082, 662, 74, 74, 4083, 157,
+    74, 171, 873, 4084, 4085, 4086, 677, 4087, 4088, 668, 4089, 909, 4090, 253, 660, 4091,
+    408, 668, 4092, 14, 204, 32, 4093, 1882, 1882, 833, 4094, 72, 202, 14, 1386, 655,
+    390, 202, 253, 283, 2495, 4095, 2495, 660, 175, 107, 390, 4096, 56, 3039, 56, 118,
+    1537, 288, 473, 198, 1589, 7, 902, 4097, 11, 4098, 4099, 4100, 64, 4101, 640, 709,
+    727, 1059, 1408, 536, 1059, 1797, 552, 552, 4102, 1705, 3552, 419, 181, 4103, 1032, 1398,
+    127, 4104, 997, 200, 32, 327, 4105, 4106, 826, 4107, 4108, 671, 492, 779, 26, 4109,
+    4110, 303, 1032, 32, 4111, 975, 316, 536, 1039, 4112, 349, 4113, 945, 4114, 19, 260,
+    4115, 327, 4116, 1119, 1005, 495, 4117, 1451, 4118, 1441, 4119, 4120, 580, 828, 4121, 757,
+    4122, 882, 890, 395, 1513, 791, 921, 35, 370, 775, 1408, 1111, 4123, 1059, 4124, 695,
+    2693, 345, 4125, 841, 815, 283, 815, 4126, 568, 901, 735, 1239, 307, 2022, 1533, 252,
+    4127, 502, 255, 64, 68, 460, 619, 598, 35, 197, 4128, 1692, 3370, 1373, 628, 21,
+    460, 61, 327, 230, 460, 261, 74, 484, 887, 1127, 4129, 197, 375, 833, 197, 960,
+    4130, 1882, 185, 1757, 26, 3890, 4131, 460, 157, 1200, 1329, 3519, 1533, 160, 886, 826,
+    4132, 6, 4133, 4127, 502, 44, 4134, 345, 2285, 51, 4135, 243, 1162, 960, 105, 125,
+    4136, 4137, 533, 536, 1705, 67, 870, 1127, 483, 841, 1256, 4138, 324, 2750, 658, 327,
+    735, 55, 4139, 4140, 56, 700, 160, 96, 327, 438, 1005, 451, 576, 2330, 61, 4141,
+    64, 1040, 950, 356, 1018, 880, 328, 3989, 157, 905, 3672, 26, 56, 1221, 20, 252,
+    252, 55, 1020, 568, 1533, 828, 385, 72, 327, 26, 4142, 349, 45, 841, 362, 638,
+    735, 206, 252, 23, 1162, 363, 1607, 192, 4143, 55, 230, 1408, 47, 472, 735, 118,
+    578, 197, 313, 370, 1079, 100, 470, 2022, 79, 218, 1431, 3510, 3510, 4144, 252, 849,
+    252, 779, 4145, 43, 444, 444, 997, 279, 4146, 4147, 294, 4148, 4149, 11, 4150, 5,
+    150, 671, 131, 4151, 4152, 4153, 1309, 4154, 4155, 260, 4156, 4157, 294, 1060, 4158, 943,
+    115, 141, 141, 4159, 483, 4160, 1797, 905, 4161, 4162, 4050, 599, 353, 1797, 4163, 141,
+    297, 4164, 4165, 438, 1540, 708, 4166, 4167, 4168, 4169, 4170, 438, 4171, 1434, 1116, 4172,
+    270, 270, 4173, 55, 4174, 4175, 1115, 583, 4176, 4177, 620, 1019, 748, 4178, 101, 286,
+    4179, 4180, 1588, 4181, 4182, 241, 586, 4183, 463, 1199, 124, 102, 4184, 4185, 4186, 2082,
+    4187, 354, 476, 78, 26, 4188, 128, 4189, 4190, 77, 430, 4191, 545, 4192, 91, 1105,
+    704, 614, 476, 536, 69, 740, 4193, 4194, 4195, 4196, 289, 127, 395, 373, 477, 4197,
+    2320, 1705, 4198, 4199, 144, 662, 4200, 4201, 1883, 652, 1398, 652, 169, 4202, 815, 469,
+    400, 482, 4203, 3589, 4204, 1167, 4205, 1090, 26, 4206, 125, 4207, 200, 4208, 4209, 392,
+    4210, 4211, 157, 4212, 4213, 1153, 72, 4214, 4215, 1778, 399, 4216, 997, 43, 1589, 640,
+    55, 578, 168, 530, 652, 1046, 30, 660, 234, 4217, 996, 1061, 4218, 1770, 2575, 3783,
+    234, 3239, 4219, 4220, 4221, 1005, 4222, 252, 252, 110, 242, 583, 4223, 4224, 260, 791,
+    4225, 201, 216, 4226, 586, 1134, 20, 139, 4227, 4228, 4229, 4230, 4231, 569, 76, 1183,
+    105, 4232, 652, 1274, 4233, 4234, 45, 4235, 2755, 4236, 4237, 4238, 358, 4239, 298, 4240,
+    4241, 288, 1510, 4242, 473, 225, 378, 168, 4243, 4244, 1117, 924, 4245, 769, 766, 4246,
+    4247, 236, 4248, 4249, 4250, 3783, 300, 477, 4251, 473, 110, 845, 766, 1153, 55, 313,
+    4252, 1589, 1087, 548, 283, 370, 36, 578, 997, 4253, 53, 358, 430, 803, 4254, 658,
+    249, 1786, 683, 6, 535, 115, 55, 4255, 4256, 321, 4257, 663, 4258, 1624, 663, 117,
+    970, 8, 26, 620, 4259, 746, 4260, 484, 240, 397, 4261, 233, 685, 3803, 780, 660,
+    682, 773, 4262, 4263, 4264, 4265, 110, 4266, 4267, 470, 378, 127, 4268, 851, 4269, 2389,
+    4270, 4271, 23, 533, 4272, 4273, 718, 31, 276, 283, 38, 4274, 4275, 4275, 615, 4276,
+    473, 531, 1159, 2965, 4277, 1039, 599, 127, 766, 548, 1035, 1130, 249, 1519, 569, 491,
+    144, 4278, 637, 3146, 241, 414, 1019, 4279, 289, 1419, 1728, 638, 4280, 675, 378, 1326,
+    854, 556, 197, 623, 766, 4281, 569, 486, 391, 2143, 4282, 1122, 219, 4283, 925, 1153,
+    338, 50, 403, 3146, 640, 4284, 4285, 2739, 4286, 4287, 126, 1126, 255, 463, 845, 584,
+    181, 1201, 4288, 210, 460, 48, 382, 4289, 56, 234, 408, 4290, 4291, 4292, 1090, 652,
+    459, 4293, 111, 4294, 411, 4295, 627, 4296, 509, 4297, 96, 3832, 74, 43, 636, 220,
+    243, 638, 111, 4298, 340, 2138, 1660, 283, 704, 443, 168, 4299, 260, 111, 658, 791,
+    262, 1383, 954, 91, 253, 4300, 1964, 4301, 365, 4302, 4303, 4304, 907, 4305, 1451, 353,
+    4306, 1589, 169, 683, 1309, 580, 580, 790, 68, 36, 71, 762, 112, 4307, 1984, 586,
+    120, 4308, 77, 589, 1304, 580, 4309, 26, 4310, 4311, 3859, 4312, 1786, 31, 4313, 4314,
+    704, 4315, 1156, 4316, 2413, 671, 4317, 4318, 960, 4319, 460, 1628, 123, 574, 4320, 4321,
+    4322, 1283, 332, 1170, 4323, 1122, 4324, 462, 4325, 4326, 509, 4327, 1025, 74, 970, 544,
+    1309, 475, 2320, 1329, 4328, 1043, 168, 974, 65, 1001, 954, 1347, 851, 1098, 58, 845,
+    4329, 1528, 226, 1136, 3894, 646, 6, 1545, 4310, 4330, 2285, 343, 4331, 209, 873, 4332,
+    353, 4333, 6, 4334, 167, 4335, 218, 950, 4336, 1705, 57, 216, 4337, 575, 431, 602,
+    347, 4338, 709, 1496, 536, 1155, 303, 616, 502, 345, 46, 345, 81, 4339, 4340, 1545,
+    4341, 4342, 438, 105, 2320, 4343, 277, 435, 4344, 1137, 125, 390, 4345, 4346, 4347, 4348,
+    945, 179, 249, 431, 4349, 169, 4350, 252, 4351, 4352, 390, 4353, 209, 1153, 4354, 4355,
+    127, 4356, 1, 801, 4357, 527, 559, 190, 101, 16, 2698, 212, 4358, 1020, 420, 4359,
+    390, 781, 1577, 1283, 1971, 4360, 602, 96, 1522, 26, 4361, 4362, 9, 436, 479, 313,
+    104, 4363, 1283, 953, 477, 249, 4364, 4365, 557, 463, 483, 37, 3239, 67, 127, 1221,
+    4366, 716, 1306, 743, 2311, 191, 557, 96, 313, 1059, 845, 450, 890, 1137, 14, 313,
+    71, 195, 1562, 81, 1692, 86, 1117, 197, 104, 569, 4367, 4368, 3273, 1296, 883, 263,
+    1705, 4369, 1338, 169, 943, 4370, 4371, 4372, 625, 4373, 458, 1153, 11, 3696, 674, 31,
+    1118, 491, 820, 2313, 1522, 4374, 484, 564, 370, 4298, 746, 4275, 4375, 4376, 725, 61,
+    17, 954, 1628, 935, 935, 4377, 144, 4378, 4379, 3403, 1309, 4380, 277, 4381, 200, 210,
+    851, 4382, 26, 674, 487, 974, 974, 956, 4383, 3483, 4384, 4385, 4386, 141, 230, 1778,
+    1310, 990, 960, 1565, 4387, 2839, 934, 271, 37, 4388, 182, 1122, 436, 1941, 4389, 37,
+    1117, 1686, 4390, 4391, 4392, 4393, 1274, 300, 4394, 2330, 4395, 4396, 4397, 4398, 545, 1309,
+    42, 1019, 197, 101, 4399, 744, 451, 400, 697, 832, 1849, 599, 4400, 241, 3166, 491,
+    1611, 301, 4401, 430, 144, 1576, 249, 3090, 455, 646, 197, 1705, 488, 4402, 1294, 1300,
+    2240, 1279, 4403, 237, 14, 4404, 619, 567, 586, 4405, 4406, 1688, 419, 711, 4407, 120,
+    4390, 363, 332, 4408, 4409, 556, 556, 4410, 61, 400, 4411, 549, 4412, 4413, 4414, 746,
+    4415, 4416, 4417, 38, 4418, 184, 3561, 1510, 766, 2833, 674, 207, 1770, 1692, 463, 3084,
+    169, 811, 1882, 182, 4419, 4360, 557, 86, 4420, 1128, 4421, 15, 1084, 5, 167, 61,
+    965, 484, 1040, 120, 1180, 332, 484, 26, 205, 1127, 4422, 4423, 30, 830, 2363, 56,
+    107, 4424, 4425, 530, 2320, 3750, 4426, 169, 4427, 1522, 4428, 4429, 168, 832, 1347, 202,
+    572, 652, 3131, 15, 55, 4430, 363, 248, 4431, 90, 91, 253, 835, 4298, 4432, 56,
+    286, 4433, 478, 405, 373, 4374, 609, 4434, 840, 218, 253, 436, 4435, 851, 1705, 4436,
+    345, 796, 1747, 207, 263, 91, 273, 283, 4437, 286, 366, 1504, 83, 182, 55, 577,
+    4438, 4439, 61, 1394, 1577, 283, 674, 1001, 557, 4440, 828, 4441, 409, 443, 1699, 207,
+    1414, 746, 3488, 2914, 55, 746, 4442, 4443, 149, 635, 4444, 242, 580, 370, 1552, 4445,
+    775, 974, 1283, 4446, 260, 599, 4447, 5, 4448, 801, 4449, 1084, 290, 1839, 4450, 1283,
+    215, 1229, 4451, 301, 4452, 239, 1068, 473, 580, 4043, 4453, 4454, 470, 4048, 556, 303,
+    420, 53, 1431, 4455, 1199, 1078, 2096, 580, 260, 4456, 954, 4457, 1129, 662, 390, 4458,
+    4459, 1394, 709, 1906, 4460, 658, 4461, 4462, 556, 4463, 11, 4464, 580, 1576, 623, 952,
+    4465, 197, 4466, 1895, 569, 4467, 4468, 4469, 4470, 4471, 952, 4472, 4473, 4474, 764, 773,
+    662, 4475, 390, 954, 234, 252, 673, 4476, 429, 4477, 4478, 779, 4479, 1129, 709, 4480,
+    4481, 159, 4467, 4482, 646, 4483, 171, 26, 149, 390, 386, 1129, 198, 4053, 2191, 333,
+    4, 253, 333, 333, 4484, 316, 743, 4485, 80, 322, 4486, 2414, 4487, 107, 55, 271,
+    390, 55, 4488, 4489, 149, 4490, 744, 628, 4491, 1005, 347, 4491, 1772, 88, 261, 997,
+    20, 2033, 11, 642, 43, 702, 556, 4492, 130, 219, 37, 4493, 824, 435, 26, 4494,
+    4495, 4496, 702, 4497, 566, 169, 47, 249, 824, 890, 1560, 3958, 294, 815, 2033, 248,
+    143, 473, 4498, 1103, 26, 566, 114, 1128, 435, 3958, 1001, 744, 744, 43, 1394, 873,
+    42, 4499, 4500, 26, 4501, 367, 3088, 4502, 642, 488, 830, 4503, 4504, 1279, 400, 26,
+    4505, 830, 873, 405, 234, 4506, 934, 4507, 1346, 1346, 1513, 3225, 4508, 580, 4509, 126,
+    126, 131, 131, 126, 365, 4510, 574, 1433, 131, 3818, 382, 660, 834, 4511, 2755, 4512,
+    397, 313, 775, 332, 1988, 574, 219, 4513, 1019, 880, 547, 332, 342, 4514, 602, 1374,
+    1374, 105, 4515, 4516, 43, 2755, 4517, 655, 86, 1374, 4518, 1988, 4519, 568, 4520, 882,
+    1127, 4521, 660, 1024, 660, 363, 182, 239, 47, 4522, 4523, 552, 556, 1376, 4524, 3989,
+    2284, 658, 2433, 1018, 2466, 671, 4525, 28, 4526, 378, 45, 395, 1845, 4527, 4528, 197,
+    684, 179, 432, 1323, 203, 1845, 1323, 395, 288, 180, 960, 179, 4529, 369, 4530, 4531,
+    4532, 1559, 358, 1519, 4530, 190, 4533, 4534, 1077, 4535, 4536, 4537, 219, 4538, 1032, 467,
+    4012, 61, 143, 64, 665, 4539, 4540, 4541, 4542, 4543, 197, 1670, 4544, 4545, 658, 4546,
+    4547, 4548, 286, 4549, 1588, 642, 236, 2099, 685, 1128, 4550, 1588, 4551, 614, 4552, 4553,
+    198, 4554, 436, 4555, 4556, 328, 328, 4557, 391, 245, 66, 4558, 3477, 495, 495, 4559,
+    4560, 1134, 4561, 13, 4562, 4563, 4564, 4565, 4566, 11, 11, 522, 4567, 575, 419, 557,
+    4568, 4569, 4570, 230, 288, 97, 4571, 4572, 118, 4573, 438, 4574, 1153, 4575, 467, 4576,
+    679, 4577, 583, 4578, 198, 668, 2420, 4579, 505, 603, 14, 4580, 271, 30, 4581, 55,
+    262, 2139, 30, 659, 200, 1005, 4582, 430, 4583, 1581, 4584, 4585, 1118, 216, 4586, 485,
+    4587, 3504, 4588, 4448, 752, 4589, 211, 4590, 4591, 4592, 81, 1408, 4593, 4594, 4595, 4596,
+    2199, 382, 1134, 101, 4597, 4598, 4599, 4600, 271, 4601, 190, 4602, 1510, 245, 3549, 1280,
+    398, 236, 779, 4603, 4604, 4605, 4606, 37, 4607, 120, 1575, 4608, 4609, 4610, 236, 4611,
+    332, 4612, 4613, 997, 40, 4614, 1232, 378, 2420, 4615, 66, 88, 387, 358, 934, 4616,
+    966, 4617, 578, 288, 4618, 96, 304, 271, 1882, 248, 4619, 1099, 252, 502, 4620, 2030,
+    530, 4621, 659, 879, 4622, 4623, 682, 236, 56, 240, 12, 565, 4624, 4625, 198, 609,
+    1097, 4626, 1957, 746, 4627, 134, 134, 4628, 3365, 294, 908, 4629, 405, 485, 101, 756,
+    416, 243, 389, 4595, 245, 4630, 4631, 1541, 4632, 4633, 316, 397, 1448, 4634, 4603, 1617,
+    13, 283, 4635, 615, 483, 4636, 4637, 4638, 4639, 4640, 320, 14, 26, 382, 638, 4641,
+    4642, 1025, 249, 1430, 74, 813, 233, 2170, 1798, 14, 727, 60, 4643, 1084, 241, 815,
+    682, 219, 243, 1274, 4644, 4645, 4646, 476, 74, 4647, 197, 290, 411, 210, 245, 2026,
+    1127, 4648, 420, 4649, 4650, 317, 4651, 1136, 378, 1097, 4652, 468, 4653, 26, 580, 568,
+    297, 3049, 4654, 2994, 679, 2410, 1346, 4655, 120, 668, 4656, 975, 4657, 889, 2089, 242,
+    64, 4658, 234, 4659, 4660, 554, 4661, 159, 4662, 1325, 1163, 4663, 4664, 4665, 4666, 294,
+    55, 1189, 236, 40, 1189, 883, 1001, 2833, 704, 443, 659, 4667, 419, 4668, 1126, 2727,
+    567, 655, 4669, 80, 4670, 934, 124, 4671, 4672, 1315, 386, 354, 4673, 4674, 4675, 2613,
+    4676, 395, 216, 4677, 509, 1229, 2424, 4108, 86, 4678, 488, 4679, 687, 639, 970, 197,
+    389, 253, 4680, 4681, 4682, 4683, 4684, 4685, 2469, 327, 1329, 4686, 4687, 1316, 462, 123,
+    4688, 332, 1660, 4689, 4690, 1274, 4691, 4692, 652, 261, 4693, 4694, 384, 532, 498, 123,
+    4366, 436, 4695, 260, 4696, 4697, 2260, 239, 458, 580, 4698, 45, 4699, 4700, 779, 589,
+    704, 4701, 1845, 4702, 4703, 4704, 740, 198, 5, 290, 2283, 589, 4705, 2291, 886, 4706,
+    589, 528, 56, 1229, 589, 80, 123, 768, 1001, 405, 4707, 4708, 1072, 197, 390, 589,
+    385, 975, 1183, 210, 161, 168, 4709, 4710, 4711, 4712, 3540, 114, 4713, 300, 290, 4714,
+    4667, 4715, 209, 4359, 23, 4716, 813, 708, 216, 4717, 218, 4718, 945, 485, 4719, 482,
+    599, 218, 4089, 4720, 740, 274, 1346, 2704, 4721, 924, 185, 78, 149, 4722, 1153, 4723,
+    658, 658, 1153, 752, 2426, 659, 1390, 4724, 467, 4725, 1005, 4726, 169, 4727, 4728, 168,
+    277, 605, 16, 26, 609, 4729, 1059, 4730, 4731, 4732, 429, 390, 126, 4733, 1430, 554,
+    997, 358, 332, 4734, 4735, 4736, 841, 219, 929, 4737, 370, 118, 557, 1402, 4738, 925,
+    101, 4739, 1200, 365, 311, 468, 242, 970, 326, 1430, 421, 801, 1155, 1626, 724, 4707,
+    11, 559, 659, 4740, 504, 801, 304, 815, 4741, 71, 557, 870, 557, 4742, 477, 363,
+    4743, 530, 4744, 66, 4745, 1847, 4746, 4747, 715, 4748, 4749, 1059, 26, 4750, 24, 4751,
+    4752, 55, 2704, 21, 4753, 890, 1046, 271, 1430, 4631, 1003, 4754, 4755, 4756, 4757, 773,
+    313, 1637, 37, 4758, 4759, 953, 1430, 4634, 2697, 2420, 3588, 207, 4760, 2834, 2139, 1271,
+    263, 4761, 4762, 1221, 4763, 4764, 1126, 283, 4765, 226, 127, 4766, 673, 463, 883, 1527,
+    1830, 126, 571, 61, 812, 248, 674, 370, 283, 735, 1229, 421, 801, 468, 180, 1002,
+    470, 4767, 544, 331, 580, 4176, 200, 126, 1087, 4768, 1699, 113, 4769, 2033, 147, 436,
+    561, 182, 4643, 4770, 273, 727, 220, 17, 812, 4771, 4772, 390, 4773, 56, 1315, 3424,
+    4573, 1770, 56, 123, 4774, 370, 497, 231, 4775, 4776, 462, 935, 832, 111, 1304, 4777,
+    571, 451, 468, 4778, 4581, 1527, 344, 167, 436, 71, 1005, 4779, 4780, 4781, 420, 1847,
+    4782, 138, 4783, 1040, 405, 279, 4784, 541, 487, 4785, 403, 430, 4786, 2330, 4787, 11,
+    210, 4788, 4789, 697, 4790, 4791, 4792, 244, 820, 4642, 301, 627, 245, 4176, 430, 934,
+    801, 234, 4793, 598, 11, 435, 283, 659, 4794, 4795, 4796, 4797, 4798, 61, 127, 4,
+    4799, 169, 4800, 4801, 4802, 743, 743, 4803, 123, 65, 4804, 1801, 4805, 526, 567, 37,
+    4806, 249, 4807, 26, 4808, 326, 4809, 4810, 3424, 4811, 4812, 2285, 1479, 4813, 746, 4814,
+    4815, 126, 965, 365, 365, 1162, 4816, 4817, 157, 659, 80, 80, 530, 122, 4818, 144,
+    4819, 3250, 98, 2143, 4820, 1085, 81, 3322, 4821, 255, 419, 1083, 1085, 378, 327, 216,
+    311, 655, 965, 65, 4822, 182, 53, 661, 390, 15, 4708, 4823, 1166, 4824, 4825, 96,
+    580, 1199, 4826, 4827, 260, 4828, 2405, 2890, 4829, 410, 435, 488, 252, 3684, 4830, 249,
+    4831, 279, 222, 4832, 26, 107, 819, 316, 86, 205, 4833, 74, 4834, 1084, 3024, 815,
+    4835, 80, 4836, 1830, 4837, 2320, 86, 652, 4838, 80, 161, 1552, 544, 443, 4839, 4840,
+    430, 436, 4841, 801, 801, 363, 4842, 253, 4843, 4844, 574, 4845, 4846, 575, 4847, 5,
+    5, 539, 139, 4848, 1414, 4849, 4850, 4851, 85, 4852, 659, 544, 104, 743, 801, 544,
+    1728, 828, 2139, 4853, 4854, 243, 4855, 160, 180, 55, 273, 4856, 182, 236, 283, 4857,
+    4858, 1044, 502, 96, 1296, 4859, 1087, 365, 4860, 4861, 744, 4862, 80, 530, 104, 544,
+    118, 619, 578, 522, 64, 801, 313, 55, 638, 234, 801, 1926, 4863, 96, 4864, 531,
+    746, 4617, 1552, 539, 586, 277, 1552, 43, 4865, 801, 4866, 56, 435, 4260, 4867, 1006,
+    157, 26, 744, 4868, 365, 20, 4869, 168, 53, 586, 749, 589, 4870, 30, 4871, 366,
+    53, 301, 239, 17, 743, 1280, 4872, 556, 735, 327, 801, 4873, 4874, 4875, 735, 111,
+    485, 646, 4876, 1782, 841, 727, 801, 327, 1078, 1431, 4877, 53, 1079, 326, 470, 4878,
+    775, 735, 1180, 735, 580, 4879, 4880, 1271, 2220, 4881, 502, 234, 234, 502, 1355, 260,
+    2739, 1832, 614, 4882, 96, 78, 74, 2704, 2704, 410, 536, 658, 1134, 4883, 1152, 4884,
+    1460, 1933, 26, 578, 4885, 807, 2486, 2420, 4886, 4887, 4888, 438, 1082, 618, 4889, 4890,
+    2933, 2220, 662, 283, 2220, 2220, 243, 2018, 4891, 442, 323, 903, 1431, 528, 4892, 378,
+    173, 96, 96, 2573, 4893, 945, 316, 429, 4894, 122, 4895, 90, 90, 791, 179, 2839,
+    375, 42, 4896, 1098, 2074, 1059, 435, 78, 695, 1448, 779, 943, 1002, 116, 4897, 11,
+    184, 1328, 682, 210, 4898, 4899, 990, 775, 248, 4900, 1448, 67, 110, 209, 1352, 1271,
+    2704, 2486, 1355, 4901, 323, 4902, 4903, 903, 416, 929, 4904, 2220, 98, 502, 3803, 316,
+    586, 1386, 252, 61, 1717, 815, 4905, 682, 197, 4206, 122, 4906, 1127, 4907, 74, 1325,
+    493, 3049, 403, 141, 4908, 11, 30, 78, 313, 277, 197, 43, 3079, 4909, 4910, 4911,
+    234, 197, 4912, 64, 3085, 4913, 640, 96, 1870, 21, 697, 408, 255, 735, 210, 700,
+    332, 4914, 181, 3070, 237, 598, 719, 4915, 197, 490, 4096, 1355, 4916, 226, 4917, 4918,
+    451, 605, 1061, 38, 2291, 315, 38, 18, 4919, 735, 4920, 26, 556, 580, 3696, 1026,
+    131, 4921, 3696, 997, 4922, 1386, 4923, 1633, 1136, 4924, 230, 896, 1902, 2414, 873, 4925,
+    4926, 605, 4723, 826, 349, 527, 841, 4927, 126, 599, 161, 4331, 1684, 4928, 197, 4137,
+    891, 365, 697, 122, 122, 580, 909, 4929, 708, 1937, 815, 38, 4930, 1153, 210, 4931,
+    72, 4932, 586, 219, 107, 4898, 682, 161, 888, 2739, 764, 2206, 277, 4933, 1402, 110,
+    4934, 4935, 469, 701, 71, 1082, 965, 4936, 290, 4937, 7, 65, 21, 197, 399, 216,
+    4938, 197, 419, 1841, 456, 375, 38, 192, 3077, 436, 4939, 4940, 4, 313, 128, 735,
+    2018, 4941, 4942, 126, 1087, 1764, 4943, 744, 4944, 26, 559, 4945, 4946, 26, 4947, 556,
+    353, 4948, 200, 6, 283, 4949, 708, 766, 4950, 4581, 4905, 122, 279, 598, 2033, 1103,
+    1450, 744, 197, 197, 197, 26, 159, 78, 4951, 4952, 252, 4953, 1026, 735, 241, 4954,
+    68, 156, 4955, 45, 4956, 4883, 3996, 4957, 580, 157, 568, 4958, 1090, 4959, 424, 4960,
+    1002, 1346, 4961, 26, 122, 700, 4962, 841, 4963, 4964, 26, 966, 53, 4965, 4966, 410,
+    107, 197, 195, 631, 443, 830, 4967, 96, 260, 209, 26, 7, 72, 61, 442, 1084,
+    53, 4968, 4969, 896, 1607, 815, 8, 4914, 4970, 896, 1002, 1087, 313, 683, 1005, 4971,
+    1496, 180, 1297, 1899, 1607, 4972, 4973, 356, 55, 578, 556, 472, 4974, 197, 96, 370,
+    252, 1390, 1516, 455, 1002, 301, 1753, 4975, 934, 4976, 98, 546, 79, 1002, 1431, 420,
+    260, 1103, 580, 4977, 211, 4799, 3572, 402, 4978, 557, 206, 2074, 2400, 556, 556, 4979,
+    1813, 1078, 1875, 1813, 4767, 1555, 4980, 4981, 4982, 600, 1117, 932, 47, 1046, 17, 832,
+    4983, 1398, 2420, 3424, 744, 2253, 1001, 1311, 390, 993, 4984, 47, 4985, 290, 1311, 925,
+    925, 1001, 4986, 4978, 1534, 1534, 4987, 4988, 4989, 2141, 534, 4990, 584, 4991, 1316, 482,
+    438, 762, 21, 735, 4992, 181, 236, 655, 104, 4993, 673, 997, 534, 4994, 286, 1307,
+    101, 768, 699, 197, 476, 786, 3987, 2240, 790, 197, 111, 111, 235, 4995, 762, 4996,
+    4997, 101, 468, 1001, 316, 382, 4998, 30, 146, 1117, 332, 1100, 207, 65, 181, 134,
+    46, 728, 1374, 708, 4519, 1292, 568, 491, 1699, 197, 55, 1001, 682, 2253, 4999, 290,
+    5000, 942, 659, 5001, 942, 1328, 1328, 173, 220, 219, 387, 59, 74, 5002, 5003, 3085,
+    3465, 2311, 4448, 5004, 5005, 2624, 1323, 248, 5006, 3085, 824, 5007, 5008, 5009, 1203, 1028,
+    438, 181, 807, 332, 5010, 5011, 402, 323, 2968, 277, 260, 828, 5012, 833, 477, 129,
+    1143, 531, 509, 288, 290, 5013, 775, 478, 5014, 5015, 3599, 5016, 72, 5017, 642, 965,
+    5018, 313, 251, 1352, 780, 1001, 323, 1430, 175, 903, 580, 3273, 390, 126, 5019, 5020,
+    639, 111, 323, 248, 5021, 5022, 181, 283, 303, 1019, 55, 64, 775, 1882, 2253, 886,
+    234, 178, 253, 532, 1087, 609, 704, 5023, 744, 1046, 598, 735, 5024, 1441, 546, 1329,
+    5025, 283, 903, 5026, 2483, 1707, 295, 3131, 682, 1098, 58, 277, 353, 851, 1923, 30,
+    4677, 557, 349, 746, 5027, 708, 5028, 619, 1684, 5029, 521, 5030, 382, 282, 5031, 5032,
+    97, 1153, 625, 67, 3273, 5033, 483, 537, 557, 1602, 2311, 557, 5034, 580, 1430, 574,
+    1046, 26, 249, 2139, 5035, 279, 638, 637, 5036, 2330, 365, 561, 561, 574, 450, 5037,
+    556, 126, 126, 527, 85, 4683, 5038, 5039, 5040, 5041, 943, 234, 251, 1292, 571, 5042,
+    639, 1001, 61, 5043, 775, 5044, 1149, 5045, 1607, 478, 4935, 118, 5046, 5047, 682, 5048,
+    5049, 251, 578, 1149, 5050, 546, 1396, 5051, 5052, 5053, 775, 253, 556, 4093, 5054, 5055,
+    5056, 578, 580, 37, 5057, 219, 3325, 648, 190, 1116, 400, 245, 640, 4540, 138, 614,
+    390, 77, 390, 5058, 24, 245, 4, 204, 5059, 46, 4986, 5060, 495, 907, 455, 556,
+    5061, 556, 493, 5062, 1153, 222, 964, 243, 615, 619, 1046, 260, 20, 26, 5063, 1061,
+    5064, 378, 5065, 1770, 504, 5066, 1143, 1522, 1108, 416, 139, 1117, 76, 997, 45, 5067,
+    5068, 382, 552, 965, 766, 126, 249, 249, 556, 428, 370, 756, 571, 655, 55, 2220,
+    1018, 1448, 685, 697, 292, 536, 44, 5069, 37, 746, 205, 2981, 241, 339, 397, 549,
+    67, 323, 5070, 974, 679, 11, 4073, 668, 780, 1166, 599, 3920, 1460, 1163, 5071, 3223,
+    3552, 1097, 530, 68, 1153, 181, 1325, 619, 171, 255, 5072, 303, 219, 945, 5073, 1199,
+    292, 4206, 14, 1315, 197, 260, 111, 815, 861, 870, 870, 30, 493, 488, 5074, 439,
+    5075, 552, 580, 509, 1494, 704, 552, 5076, 5077, 5078, 171, 5079, 5080, 120, 1599, 253,
+    149, 598, 1329, 253, 52, 460, 123, 301, 5081, 218, 143, 181, 157, 205, 131, 4799,
+    195, 74, 205, 428, 756, 38, 1246, 46, 5082, 57, 105, 1153, 1705, 826, 244, 349,
+    20, 1183, 552, 744, 243, 148, 210, 7, 2466, 168, 1832, 5083, 435, 101, 323, 873,
+    2986, 1315, 41, 277, 599, 390, 527, 644, 80, 14, 1430, 313, 870, 67, 890, 4,
+    249, 815, 1258, 24, 883, 14, 71, 55, 623, 974, 5084, 5085, 5086, 556, 211, 67,
+    206, 544, 5087, 726, 598, 1472, 451, 2336, 405, 1057, 451, 253, 111, 328, 5088, 219,
+    1040, 561, 4071, 1404, 2330, 248, 252, 599, 5089, 123, 1097, 156, 292, 1450, 241, 239,
+    1519, 157, 356, 5090, 37, 527, 974, 85, 328, 586, 4842, 14, 400, 61, 328, 206,
+    126, 410, 239, 1589, 1166, 841, 355, 880, 2170, 5091, 80, 107, 752, 2320, 390, 815,
+    5092, 5093, 957, 2767, 2864, 53, 1770, 5094, 1040, 148, 575, 935, 82, 1001, 55, 283,
+    1183, 5095, 160, 5096, 294, 974, 1063, 1155, 370, 578, 55, 160, 1002, 599, 5097, 16,
+    5098, 934, 1347, 160, 5099, 764, 290, 47, 5100, 5101, 5102, 1176, 868, 1396, 55, 5103,
+    5104, 5105, 1398, 5106, 32, 5107, 5108, 1394, 5109, 277, 260, 6, 1061, 139, 1624, 5110,
+    726, 967, 1519, 2483, 5111, 2965, 1061, 4935, 35, 5112, 139, 599, 658, 311, 869, 640,
+    4478, 363, 5113, 1093, 237, 1627, 4, 819, 5114, 5108, 599, 218, 5115, 20, 5116, 752,
+    752, 255, 726, 997, 960, 45, 727, 727, 1326, 997, 635, 45, 523, 5117, 123, 5118,
+    5119, 5120, 605, 3305, 752, 5121, 1522, 45, 5122, 488, 378, 125, 5123, 2089, 5124, 144,
+    1325, 5125, 5126, 5127, 126, 126, 1083, 241, 14, 2750, 534, 5128, 220, 378, 1136, 752,
+    46, 46, 1328, 5129, 5130, 752, 534, 342, 2420, 1128, 1847, 5131, 125, 5132, 616, 19,
+    3588, 5133, 534, 5134, 752, 1239, 126, 442, 5135, 70, 253, 70, 5136, 405, 5137, 144,
+    5138, 390, 534, 68, 26, 14, 3131, 47, 8, 144, 5139, 2965, 2060, 5140, 4642, 530,
+    2750, 5141, 82, 400, 2294, 14, 47, 1674, 5142, 1128, 4642, 8, 819, 47, 47, 5143,
+    2277, 288, 43, 43, 1503, 5144, 227, 2284, 5145, 583, 888, 356, 5146, 2945, 473, 887,
+    340, 646, 709, 5147, 55, 536, 781, 4448, 1116, 46, 1232, 253, 5148, 490, 126, 26,
+    55, 1441, 1311, 30, 42, 318, 131, 138, 5149, 5150, 308, 845, 5151, 5152, 5153, 198,
+    1527, 5154, 169, 828, 4594, 965, 382, 1040, 1306, 117, 5155, 4053, 17, 638, 101, 5156,
+    5157, 6, 888, 5158, 2929, 5159, 5160, 78, 283, 5161, 1228, 909, 411, 638, 234, 3607,
+    554, 476, 1645, 234, 1687, 5162, 55, 283, 1184, 653, 5163, 1019, 297, 251, 5164, 311,
+    26, 5165, 354, 1467, 1020, 5166, 728, 4683, 967, 56, 5167, 5168, 78, 126, 126, 552,
+    790, 1273, 5169, 243, 498, 490, 1228, 1431, 295, 5114, 975, 2414, 5170, 580, 282, 30,
+    1155, 3381, 230, 919, 1846, 306, 1460, 210, 587, 5171, 5172, 26, 188, 1024, 556, 78,
+    197, 1640, 26, 5173, 169, 1311, 4073, 5174, 14, 5175, 251, 5147, 5176, 5177, 5178, 331,
+    354, 1087, 975, 295, 100, 26, 1441, 5179, 354, 5180, 5181, 80, 5182, 93, 188, 5183,
+    1005, 205, 5, 627, 527, 378, 5184, 370, 491, 14, 1669, 311, 828, 5185, 609, 896,
+    5186, 4073, 311, 1640, 5187, 5188, 580, 659, 26, 17, 1645, 366, 2552, 1162, 288, 5189,
+    488, 15, 1552, 5190, 56, 967, 179, 186, 888, 903, 4853, 55, 118, 1102, 400, 2313,
+    186, 627, 5, 239, 17, 331, 2313, 100, 470, 470, 504, 504, 185, 173, 3504, 5191,
+    5192, 5193, 5194, 5195, 5196, 2739, 2739, 105, 5197, 1959, 888, 5198, 974, 2191, 260, 5199,
+    1005, 169, 841, 2469, 674, 1003, 1163, 5200, 64, 126, 5201, 8, 954, 197, 1003, 5202,
+    327, 1221, 327, 271, 1057, 327, 1533, 252, 5203, 327, 841, 206, 349, 1221, 5204, 1067,
+    216, 571, 131, 1294, 318, 35, 391, 219, 4184, 195, 300, 104, 391, 391, 2359, 5205,
+    104, 5206, 1001, 5207, 77, 801, 390, 289, 1702, 5208, 5106, 216, 801, 216, 26, 504,
+    460, 5209, 1167, 439, 303, 5210, 5211, 3728, 800, 220, 5212, 1347, 1296, 226, 219, 3386,
+    1347, 123, 439, 5213, 111, 2336, 11, 5214, 5215, 934, 432, 5216, 44, 5217, 1519, 432,
+    5218, 55, 438, 5219, 1180, 709, 476, 5220, 462, 658, 1650, 5221, 5222, 5223, 662, 5224,
+    5225, 30, 5226, 552, 483, 5227, 110, 5228, 5229, 22, 32, 5230, 5231, 1153, 5232, 5233,
+    619, 1263, 110, 5234, 903, 5235, 45, 118, 2120, 19, 1408, 5236, 975, 5237, 274, 909,
+    4860, 42, 249, 249, 5238, 110, 589, 5239, 576, 768, 768, 1082, 1091, 5240, 4112, 5241,
+    370, 5242, 568, 5243, 5244, 640, 227, 483, 5245, 967, 1408, 639, 908, 115, 5246, 536,
+    5247, 5248, 1028, 1005, 5249, 903, 1153, 5250, 5251, 64, 892, 5252, 3475, 5253, 639, 1163,
+    421, 619, 197, 889, 1552, 168, 375, 261, 5254, 5255, 5256, 110, 38, 682, 1315, 5257,
+    903, 111, 1552, 5258, 639, 1189, 5259, 513, 126, 819, 438, 1451, 576, 123, 5260, 5261,
+    1433, 887, 390, 1451, 69, 983, 5262, 502, 355, 2704, 268, 744, 909, 1246, 282, 826,
+    2, 5263, 5264, 644, 5265, 11, 233, 575, 54, 1883, 1576, 5266, 324, 5267, 1519, 5268,
+    1117, 409, 973, 5269, 1005, 5270, 108, 1006, 271, 354, 26, 5271, 1263, 1005, 711, 451,
+    5272, 967, 5273, 5274, 5275, 974, 5276, 1105, 5277, 5278, 197, 491, 85, 161, 1747, 385,
+    711, 5279, 98, 5280, 1230, 252, 3587, 568, 5281, 5282, 5283, 5284, 252, 206, 61, 660,
+    168, 104, 72, 5285, 1153, 1883, 1024, 5286, 1607, 192, 5287, 5288, 91, 964, 91, 366,
+    5289, 556, 5290, 91, 118, 578, 363, 370, 5291, 5292, 5293, 171, 79, 1230, 161, 482,
+    5294, 756, 5295, 943, 5296, 556, 212, 5297, 385, 1690, 282, 385, 627, 1283, 1448, 5298,
+    1184, 5299, 1513, 5300, 1450, 3510, 1078, 5301, 475, 5302, 400, 463, 5303, 21, 5304, 5305,
+    5306, 1851, 586, 1408, 1519, 24, 1019, 566, 556, 55, 204, 552, 5307, 5308, 634, 198,
+    5309, 2216, 46, 5310, 149, 1323, 807, 628, 5311, 107, 1472, 2172, 1801, 770, 735, 756,
+    30, 5312, 1005, 5313, 5314, 580, 5315, 1118, 1118, 2541, 836, 249, 149, 5316, 534, 4245,
+    5317, 4935, 5318, 2089, 5319, 260, 5320, 190, 416, 5321, 416, 45, 1581, 206, 55, 370,
+    568, 523, 55, 1408, 2320, 786, 101, 746, 5322, 5323, 149, 521, 130, 123, 5324, 206,
+    5325, 5326, 5327, 1033, 5328, 5329, 197, 780, 5330, 943, 323, 1279, 5331, 1515, 491, 26,
+    5332, 5333, 5334, 360, 623, 1355, 390, 234, 323, 5335, 5336, 5337, 2172, 98, 5338, 5339,
+    5340, 476, 195, 735, 1019, 842, 580, 78, 1043, 182, 5341, 168, 1585, 652, 74, 5342,
+    703, 4702, 5343, 5344, 530, 253, 5345, 1414, 157, 391, 504, 475, 5346, 143, 4336, 896,
+    5347, 206, 107, 1842, 1388, 289, 248, 5348, 4, 5349, 5350, 460, 1098, 5351, 181, 658,
+    3054, 148, 303, 5352, 475, 5353, 1118, 5354, 599, 737, 203, 126, 5355, 943, 5356, 873,
+    323, 1519, 5357, 41, 3894, 5358, 640, 429, 5359, 752, 658, 1801, 30, 945, 195, 3043,
+    24, 128, 1633, 5360, 1163, 139, 5361, 5362, 71, 5363, 5364, 807, 5365, 1761, 366, 5086,
+    752, 5366, 4753, 4939, 1296, 5367, 824, 5368, 628, 5369, 5370, 1472, 67, 812, 5371, 365,
+    5372, 3974, 403, 2399, 182, 1230, 2328, 5373, 5374, 4388, 1087, 658, 5375, 5376, 450, 353,
+    5377, 386, 262, 400, 646, 297, 239, 1450, 3068, 655, 65, 5378, 475, 5379, 5380, 5381,
+    5382, 5383, 147, 55, 655, 1025, 197, 2135, 491, 1292, 5384, 580, 2965, 5385, 5386, 271,
+    5387, 127, 2320, 252, 1054, 1519, 202, 1128, 807, 807, 580, 610, 5388, 5389, 1117, 744,
+    580, 148, 363, 318, 139, 290, 179, 69, 5390, 5391, 5392, 5387, 366, 283, 55, 5393,
+    586, 195, 318, 599, 55, 370, 370, 1005, 290, 215, 934, 53, 5390, 2914, 5394, 110,
+    1199, 906, 1229, 455, 236, 5395, 953, 69, 96, 347, 1018, 5396, 5397, 198, 5398, 539,
+    5399, 584, 405, 1059, 129, 283, 545, 5316, 343, 4599, 5400, 845, 5401, 909, 34, 320,
+    200, 1498, 5402, 107, 5403, 59, 5404, 408, 57, 535, 1097, 902, 2848, 42, 185, 323,
+    845, 5405, 2482, 249, 2482, 5406, 815, 975, 1239, 842, 640, 564, 118, 1660, 253, 790,
+    934, 484, 260, 123, 791, 4842, 564, 462, 123, 5407, 126, 2102, 502, 925, 65, 773,
+    970, 118, 2301, 565, 686, 1097, 775, 5408, 845, 405, 1153, 81, 640, 181, 74, 249,
+    5409, 56, 2482, 71, 81, 69, 86, 343, 826, 253, 580, 3672, 5410, 236, 5411, 288,
+    851, 107, 1199, 1084, 5412, 564, 55, 1396, 455, 244, 160, 40, 1183, 57, 5413, 1310,
+    96, 205, 216, 554, 292, 81, 1434, 554, 5414, 5415, 1294, 5416, 1018, 791, 1314, 1,
+    1, 5417, 1006, 5418, 419, 5419, 740, 845, 1882, 583, 845, 580, 5420, 587, 868, 807,
+    5421, 55, 97, 5422, 807, 290, 260, 1005, 5423, 1314, 205, 88, 1119, 283, 845, 957,
+    316, 463, 1308, 5424, 5425, 568, 3366, 5426, 5427, 773, 970, 283, 5428, 663, 114, 5429,
+    746, 61, 1084, 5430, 197, 373, 623, 204, 5431, 1189, 408, 842, 1294, 1882, 244, 530,
+    5432, 4958, 5433, 1323, 5434, 123, 790, 1119, 663, 5435, 907, 573, 2060, 1690, 283, 842,
+    5436, 1309, 5437, 5438, 5439, 5440, 580, 635, 70, 16, 405, 5441, 737, 56, 378, 5442,
+    9, 888, 5417, 5443, 2297, 5425, 1347, 486, 5444, 5445, 580, 5446, 11, 5447, 283, 1347,
+    3239, 1661, 5448, 373, 104, 313, 107, 5449, 56, 487, 438, 5450, 5451, 5452, 56, 2468,
+    635, 400, 627, 486, 1, 80, 896, 26, 527, 150, 5453, 107, 5454, 85, 1025, 5455,
+    5456, 4216, 408, 107, 1084, 80, 61, 1396, 9, 5457, 610, 5458, 630, 486, 5459, 4848,
+    1408, 408, 5460, 80, 5461, 5462, 111, 5463, 5464, 5465, 1751, 38, 197, 5466, 5467, 5468,
+    45, 328, 5469, 5470, 5471, 554, 297, 150, 1435, 477, 737, 1062, 3383, 791, 1847, 4601,
+    5472, 367, 93, 1588, 1322, 67, 298, 1311, 5469, 5473, 5474, 5475, 122, 234, 530, 113,
+    1103, 4677, 288, 276, 1117, 74, 709, 635, 1640, 800, 411, 5476, 5477, 4677, 1847, 5478,
+    5479, 316, 38, 5480, 67, 1111, 3424, 738, 752, 93, 5481, 618, 197, 21, 5482, 1311,
+    5483, 952, 5484, 370, 4978, 490, 356, 2739, 5485, 367, 38, 5486, 2739, 348, 150, 5487,
+    171, 1059, 343, 286, 171, 245, 253, 574, 997, 671, 430, 3472, 5488, 5489, 42, 260,
+    5490, 117, 638, 638, 3501, 1624, 236, 68, 245, 5491, 763, 5492, 93, 26, 430, 638,
+    2047, 127, 2089, 551, 111, 1766, 1261, 156, 4184, 5493, 638, 2959, 638, 1431, 5494, 5495,
+    1309, 390, 351, 5496, 476, 1431, 493, 1246, 400, 841, 1116, 5401, 169, 492, 72, 191,
+    1315, 5497, 557, 5498, 5499, 159, 5500, 5501, 583, 168, 202, 395, 965, 997, 5502, 1126,
+    1431, 808, 967, 1510, 55, 150, 526, 2206, 5503, 236, 378, 5504, 675, 233, 442, 463,
+    640, 260, 5505, 3137, 5316, 1143, 586, 382, 1294, 220, 212, 757, 2580, 5506, 744, 38,
+    4165, 526, 4935, 134, 851, 320, 351, 773, 192, 775, 583, 38, 1728, 1540, 5507, 5508,
+    101, 5509, 23, 233, 1541, 5510, 5511, 400, 2710, 677, 813, 1028, 113, 704, 126, 821,
+    1166, 903, 813, 204, 5512, 919, 4799, 111, 5073, 1283, 1467, 2028, 255, 5513, 323, 5514,
+    530, 5401, 110, 42, 1119, 1533, 1386, 1137, 5515, 303, 5516, 175, 138, 8, 1541, 3696,
+    419, 5517, 286, 1533, 168, 5518, 704, 1156, 5519, 126, 126, 1827, 5520, 5521, 498, 744,
+    5522, 1510, 5523, 903, 5524, 5525, 887, 123, 290, 400, 3406, 5401, 5526, 378, 993, 1510,
+    1331, 743, 286, 68, 4325, 1467, 5527, 813, 529, 501, 271, 150, 2755, 58, 566, 234,
+    5528, 655, 826, 4935, 191, 16, 775, 1061, 5529, 5530, 277, 5531, 5532, 5533, 1705, 180,
+    1588, 256, 5534, 169, 5535, 527, 349, 909, 220, 5536, 1832, 146, 30, 5510, 5537, 150,
+    282, 1, 740, 954, 1510, 1576, 2204, 869, 5538, 216, 71, 455, 942, 674, 716, 1496,
+    903, 603, 242, 954, 1533, 1103, 5539, 5540, 1246, 55, 3476, 5541, 207, 5542, 351, 42,
+    5543, 370, 53, 744, 333, 111, 3509, 126, 5544, 451, 697, 1309, 5545, 5546, 131, 5547,
+    811, 580, 1271, 4778, 5548, 821, 5549, 6, 200, 727, 113, 5550, 1061, 5551, 400, 185,
+    37, 5552, 5553, 123, 1633, 414, 1103, 143, 414, 190, 873, 42, 234, 290, 436, 5554,
+    5555, 356, 566, 488, 226, 168, 526, 1576, 20, 1025, 156, 527, 5556, 297, 5557, 5558,
+    123, 110, 2387, 5559, 2253, 253, 2253, 5560, 316, 123, 123, 303, 5561, 1166, 2360, 5562,
+    603, 286, 443, 5563, 35, 1552, 2204, 53, 1576, 390, 455, 574, 5564, 90, 294, 5565,
+    5566, 5567, 6, 253, 1527, 1397, 35, 5568, 5569, 35, 5570, 74, 1527, 123, 578, 5571,
+    2755, 801, 580, 1552, 4165, 150, 443, 53, 286, 455, 1134, 5572, 443, 4935, 873, 1101,
+    111, 81, 58, 801, 455, 74, 580, 485, 5573, 586, 45, 628, 45, 5574, 216, 40,
+    1018, 463, 532, 5575, 354, 286, 1562, 5576, 1398, 21, 5577, 5578, 1183, 721, 126, 490,
+    5579, 628, 485, 5580, 200, 1116, 55, 138, 316, 416, 1005, 5581, 5582, 35, 967, 37,
+    5246, 55, 197, 37, 245, 568, 5583, 1399, 455, 5584, 157, 3146, 1624, 3849, 1006, 240,
+    320, 216, 1122, 619, 4206, 1122, 410, 1882, 1199, 455, 123, 332, 253, 5585, 573, 2330,
+    3406, 1441, 5586, 5587, 1043, 5588, 782, 71, 5589, 1183, 1651, 282, 44, 1441, 5590, 655,
+    2387, 139, 111, 96, 204, 9, 181, 104, 4176, 674, 71, 3943, 382, 1456, 3146, 71,
+    5591, 5592, 1006, 607, 487, 1699, 1300, 782, 782, 9, 197, 1122, 5593, 175, 3281, 615,
+    1162, 1441, 619, 9, 835, 242, 55, 34, 1126, 242, 5594, 1002, 5595, 81, 5596, 5597,
+    128, 5598, 5599, 129, 1798, 665, 35, 241, 5600, 5601, 5602, 5603, 493, 584, 5604, 5605,
+    219, 808, 970, 860, 1152, 5606, 5607, 43, 5608, 5609, 115, 5610, 245, 198, 169, 671,
+    419, 584, 222, 736, 37, 5611, 870, 490, 614, 5612, 5613, 497, 5614, 283, 569, 463,
+    5615, 245, 5612, 5616, 65, 378, 4595, 45, 5617, 5618, 4144, 260, 42, 5619, 1180, 179,
+    5316, 1118, 5620, 20, 171, 1430, 1134, 5621, 5622, 2406, 868, 239, 1005, 430, 484, 1408,
+    1882, 584, 677, 4595, 5623, 746, 8, 5624, 2210, 556, 1408, 277, 620, 5625, 1136, 883,
+    5626, 587, 5627, 101, 5628, 5629, 1231, 5630, 619, 378, 5631, 5632, 313, 5633, 773, 366,
+    96, 639, 5634, 2217, 47, 854, 81, 1699, 535, 61, 556, 462, 168, 5635, 5636, 5637,
+    115, 408, 530, 219, 43, 923, 854, 5638, 5639, 5640, 5641, 4176, 5642, 5643, 7, 245,
+    65, 378, 116, 5644, 539, 842, 204, 5645, 200, 845, 5646, 122, 724, 56, 11, 663,
+    5647, 5648, 270, 683, 180, 1122, 5649, 1328, 1479, 253, 5650, 5651, 253, 460, 5652, 619,
+    251, 5653, 5654, 1660, 460, 762, 112, 172, 1305, 1433, 1628, 2260, 5655, 766, 5656, 548,
+    126, 5657, 260, 91, 569, 150, 5658, 353, 5659, 1156, 197, 970, 124, 103, 497, 886,
+    1326, 552, 580, 1968, 586, 5660, 11, 599, 2057, 5559, 11, 16, 744, 203, 619, 5661,
+    91, 5662, 873, 5663, 640, 5664, 5665, 4194, 5666, 339, 81, 1, 5667, 656, 5668, 451,
+    467, 708, 343, 243, 5669, 603, 740, 7, 435, 5670, 5671, 5672, 773, 244, 764, 283,
+    5673, 120, 365, 20, 323, 557, 631, 709, 1978, 209, 1136, 826, 801, 815, 26, 2965,
+    576, 5674, 5675, 1296, 5676, 744, 5677, 101, 1565, 399, 323, 5678, 197, 5679, 883, 343,
+    419, 5680, 373, 1320, 277, 5681, 1527, 5682, 37, 19, 1441, 883, 695, 1964, 126, 5683,
+    295, 111, 5684, 5685, 1770, 5686, 82, 5687, 5688, 279, 462, 408, 5689, 5690, 443, 546,
+    945, 5691, 400, 5692, 5693, 5694, 180, 5695, 352, 5696, 5697, 5698, 422, 60, 1018, 56,
+    5699, 292, 1026, 945, 107, 5700, 3058, 500, 1690, 646, 782, 157, 5701, 1880, 5702, 386,
+    3090, 655, 5703, 5704, 4414, 292, 5705, 144, 1101, 197, 422, 3757, 558, 5706, 5707, 5597,
+    80, 168, 1128, 615, 5708, 451, 96, 5709, 126, 5710, 26, 2025, 107, 3012, 5711, 5712,
+    56, 544, 754, 53, 5713, 1607, 1101, 253, 1309, 5714, 1005, 40, 7, 1101, 1408, 273,
+    11, 5715, 1018, 365, 841, 578, 365, 599, 5716, 5717, 4039, 1611, 1101, 134, 5718, 5719,
+    420, 82, 435, 81, 1115, 498, 5720, 35, 5721, 5003, 5722, 169, 493, 1408, 1798, 584,
+    243, 419, 245, 468, 807, 1019, 762, 808, 584, 1441, 5655, 671, 198, 5605, 32, 42,
+    115, 1152, 43, 619, 1180, 96, 378, 435, 1555, 45, 65, 845, 1134, 35, 868, 378,
+    171, 1479, 26, 5645, 20, 923, 313, 5535, 587, 5703, 47, 677, 61, 5723, 945, 1699,
+    5629, 556, 841, 854, 1201, 234, 639, 292, 65, 1063, 883, 56, 5642, 2980, 11, 260,
+    599, 11, 77, 886, 5657, 2057, 812, 1978, 659, 91, 251, 5724, 8, 1660, 766, 1328,
+    5725, 548, 460, 5660, 2965, 970, 203, 16, 744, 467, 420, 244, 740, 4162, 4127, 61,
+    81, 270, 873, 5726, 619, 603, 2965, 656, 640, 408, 4935, 552, 557, 815, 343, 748,
+    19, 5683, 450, 373, 365, 111, 26, 744, 544, 5727, 279, 82, 599, 5684, 5728, 715,
+    7, 356, 646, 5729, 1128, 754, 5730, 615, 5731, 1101, 5732, 96, 762, 5732, 5733, 5732,
+    5559, 504, 1061, 549, 5734, 762, 599, 599, 1414, 340, 934, 3090, 1020, 120, 658, 5108,
+    599, 82, 1020, 578, 934, 112, 112, 762, 5735, 5736, 58, 5737, 378, 45, 233, 1510,
+    5738, 5739, 482, 104, 552, 762, 5740, 2529, 5741, 639, 378, 975, 218, 72, 484, 5742,
+    74, 580, 283, 1182, 233, 5743, 1162, 81, 131, 69, 766, 5744, 233, 111, 3424, 708,
+    126, 56, 1450, 679, 766, 400, 639, 436, 72, 5745, 131, 400, 400, 470, 536, 5746,
+    1046, 442, 1346, 5747, 925, 925, 1090, 1116, 674, 820, 104, 5748, 932, 5749, 260, 5750,
+    42, 147, 990, 422, 6, 5751, 313, 852, 852, 5752, 615, 5753, 619, 5754, 5755, 5756,
+    5757, 390, 1136, 127, 136, 557, 197, 586, 965, 1090, 965, 861, 5758, 5759, 496, 2018,
+    360, 5760, 119, 26, 679, 2834, 671, 245, 78, 78, 490, 378, 323, 616, 26, 201,
+    26, 5761, 260, 2399, 283, 239, 167, 7, 535, 197, 197, 5762, 5763, 61, 502, 5764,
+    978, 245, 1128, 5765, 490, 716, 5766, 978, 78, 42, 168, 826, 78, 146, 353, 5767,
+    502, 282, 5768, 5769, 64, 561, 4095, 26, 443, 5770, 561, 157, 2642, 5763, 561, 2495,
+    26, 61, 826, 1347, 192, 780, 780, 3803, 105, 2102, 5771, 1143, 1143, 1143, 47, 47,
+    5772, 5773, 5774, 5775, 5776, 5777, 366, 116, 463, 1136, 2147, 5778, 419, 5779, 807, 5780,
+    236, 5781, 382, 316, 1821, 207, 2833, 5782, 5567, 5783, 89, 559, 1338, 637, 1529, 5784,
+    5785, 400, 2833, 118, 273, 1005, 458, 5786, 26, 648, 5787, 5788, 763, 205, 5789, 322,
+    566, 566, 245, 1201, 462, 2420, 242, 566, 19, 98, 260, 903, 925, 5790, 5791, 370,
+    462, 478, 902, 5792, 1323, 5793, 5794, 11, 443, 327, 1692, 682, 5795, 1882, 316, 583,
+    850, 231, 5605, 11, 2089, 451, 475, 532, 1137, 1283, 451, 800, 443, 1576, 451, 443,
+    324, 1576, 443, 443, 451, 5796, 997, 763, 116, 1576, 5797, 4996, 566, 478, 380, 5798,
+    116, 5799, 370, 580, 580, 72, 236, 37, 5800, 37, 5801, 72, 72, 5802, 26, 5803,
+    4436, 219, 2758, 1526, 5804, 5805, 210, 585, 322, 584, 5806, 2099, 887, 686, 5807, 762,
+    5808, 138, 2294, 575, 726, 104, 5809, 5810, 204, 5811, 1090, 2657, 5812, 2049, 667, 5813,
+    97, 5814, 5815, 2758, 1026, 128, 5816, 486, 67, 253, 5817, 5818, 5819, 197, 5820, 2758,
+    328, 66, 975, 2414, 354, 7, 5821, 708, 35, 47, 5822, 5823, 173, 290, 239, 752,
+    5824, 5825, 2935, 5826, 716, 845, 576, 4603, 5827, 5828, 5829, 5830, 311, 34, 2657, 1522,
+    5831, 5832, 5833, 534, 320, 277, 1117, 322, 609, 5834, 88, 370, 36, 2205, 578, 638,
+    5835, 697, 1482, 5836, 6, 2220, 5837, 5838, 36, 1834, 5839, 13, 13, 240, 5840, 184,
+    431, 5841, 768, 5842, 5843, 1111, 5844, 490, 1153, 5845, 490, 7, 5846, 5846, 4355, 7,
+    5847, 175, 83, 5848, 253, 41, 485, 1084, 5849, 5850, 5851, 486, 5201, 990, 5852, 5853,
+    925, 2727, 5854, 5855, 5856, 1325, 1153, 468, 975, 5857, 5858, 5859, 5860, 5861, 5862, 1670,
+    959, 530, 5863, 5864, 411, 5865, 514, 41, 1645, 104, 58, 55, 861, 5866, 5585, 888,
+    5867, 5868, 5869, 5870, 262, 431, 5871, 5872, 5873, 288, 261, 1165, 1882, 456, 5874, 252,
+    5875, 5876, 975, 5877, 226, 226, 509, 4692, 5878, 1105, 36, 131, 231, 353, 475, 575,
+    1153, 5879, 5880, 78, 2426, 58, 89, 640, 432, 349, 5881, 5882, 1836, 1402, 399, 2414,
+    1183, 149, 834, 5883, 347, 5884, 243, 5852, 390, 382, 873, 126, 726, 343, 619, 576,
+    467, 1443, 1527, 998, 5885, 5886, 408, 1443, 251, 71, 37, 2965, 263, 26, 5887, 356,
+    169, 131, 7, 5888, 408, 3958, 1394, 726, 5889, 1074, 1482, 857, 5890, 637, 5891, 5892,
+    1300, 197, 5893, 180, 5894, 51, 1577, 283, 1849, 4184, 5895, 568, 5896, 576, 5897, 5898,
+    490, 5899, 234, 5900, 5901, 462, 1025, 646, 5902, 490, 567, 16, 5903, 1006, 599, 5904,
+    3750, 222, 175, 1084, 831, 443, 5905, 17, 5906, 171, 3259, 1095, 5907, 861, 3937, 831,
+    104, 253, 490, 3958, 1607, 5908, 4202, 3046, 619, 1087, 124, 3299, 1297, 697, 5909, 118,
+    505, 100, 239, 3046, 470, 5910, 5911, 230, 5912, 575, 5913, 5914, 4683, 5915, 768, 841,
+    242, 5916, 56, 674, 1560, 5917, 242, 298, 5918, 263, 655, 5919, 5920, 990, 90, 249,
+    473, 619, 5921, 2294, 477, 390, 5922, 65, 5923, 473, 390, 5924, 5925, 1283, 473, 18,
+    5926, 5927, 1229, 614, 110, 331, 5928, 110, 5929, 429, 5930, 5930, 1467, 5931, 2, 2797,
+    711, 323, 4, 1448, 2433, 5932, 965, 202, 586, 1564, 66, 586, 1398, 5933, 677, 32,
+    5934, 583, 578, 1134, 655, 2139, 260, 5935, 271, 1159, 290, 311, 2730, 950, 323, 578,
+    5936, 5937, 197, 433, 378, 2739, 724, 111, 4958, 390, 623, 1325, 580, 243, 290, 2483,
+    5938, 509, 527, 471, 5939, 495, 5689, 47, 1347, 907, 2294, 26, 1602, 2139, 444, 559,
+    20, 1849, 5940, 5941, 156, 1166, 832, 182, 202, 169, 655, 5942, 363, 253, 182, 578,
+    365, 215, 5943, 5944, 744, 744, 5945, 735, 5946, 2089, 735, 735, 1532, 1532, 26, 5947,
+    5948, 5949, 5950, 5951, 545, 1025, 5535, 1309, 493, 26, 5952, 91, 485, 1362, 3987, 5953,
+    580, 5954, 5955, 114, 5956, 77, 5957, 5958, 5959, 1830, 283, 16, 5960, 378, 5961, 243,
+    1097, 845, 658, 5962, 1460, 954, 1434, 5963, 1263, 5964, 5965, 5966, 400, 5967, 5968, 5969,
+    671, 5970, 5971, 5972, 56, 1043, 1043, 1316, 5973, 556, 1143, 198, 5974, 205, 33, 1337,
+    105, 1315, 4588, 5975, 677, 451, 248, 925, 495, 26, 5976, 243, 55, 1645, 149, 619,
+    726, 2139, 42, 635, 1989, 5977, 76, 5978, 5979, 5980, 1126, 54, 840, 5981, 523, 5982,
+    438, 181, 5983, 5984, 552, 202, 202, 5985, 5986, 5987, 5988, 5989, 833, 5990, 791, 5991,
+    5992, 477, 1018, 616, 245, 5993, 5313, 395, 5994, 47, 5995, 138, 779, 4603, 1153, 3739,
+    5996, 5997, 5998, 708, 5999, 4069, 105, 220, 6000, 482, 6001, 6002, 6003, 1005, 970, 6004,
+    351, 56, 1882, 370, 451, 95, 557, 966, 801, 38, 6005, 483, 55, 313, 609, 6006,
+    6007, 6008, 230, 6009, 6010, 768, 682, 1097, 6011, 3233, 620, 6012, 139, 469, 2220, 6013,
+    6014, 6015, 6016, 378, 390, 746, 2405, 1128, 2929, 773, 625, 6017, 6018, 23, 679, 6019,
+    568, 1028, 286, 1431, 241, 615, 204, 6020, 1239, 6021, 1882, 124, 2797, 6022, 1882, 240,
+    533, 491, 351, 663, 655, 6023, 6024, 4603, 55, 756, 6025, 430, 3146, 442, 199, 1130,
+    126, 813, 491, 61, 3745, 1519, 768, 313, 6026, 6027, 134, 801, 4960, 815, 3273, 5172,
+    2097, 245, 35, 192, 114, 1923, 111, 411, 204, 663, 248, 6028, 1843, 6029, 6030, 1126,
+    6031, 6032, 1830, 530, 6033, 1097, 1371, 197, 1201, 55, 5864, 42, 1005, 6034, 3146, 6035,
+    1670, 219, 6036, 414, 230, 6037, 1283, 1153, 6038, 124, 6039, 2172, 6040, 391, 303, 1980,
+    6041, 1984, 6042, 64, 6043, 1984, 199, 2128, 6044, 6045, 6046, 6047, 6048, 6049, 1163, 6050,
+    6051, 127, 125, 438, 2216, 704, 443, 6052, 4677, 2391, 1018, 118, 97, 801, 599, 119,
+    468, 563, 541, 620, 6053, 1882, 640, 239, 395, 6054, 6055, 1510, 201, 6056, 332, 708,
+    6057, 6058, 598, 6059, 85, 6060, 373, 1628, 378, 262, 1329, 8, 652, 499, 548, 1404,
+    1964, 173, 111, 6061, 504, 64, 362, 522, 1095, 4207, 205, 850, 6062, 6063, 1519, 497,
+    6064, 6065, 1136, 6066, 1510, 260, 6067, 6068, 6069, 112, 52, 887, 6070, 6071, 744, 15,
+    880, 6072, 6073, 2266, 1599, 4300, 216, 490, 1660, 1705, 6074, 726, 615, 6075, 704, 569,
+    6076, 1061, 6077, 288, 978, 6078, 6079, 6, 545, 253, 460, 813, 924, 19, 2755, 1115,
+    6080, 58, 126, 26, 22, 6081, 959, 475, 801, 801, 801, 234, 83, 6082, 1117, 549,
+    1105, 6083, 6084, 6085, 435, 826, 826, 6086, 813, 4677, 268, 6087, 390, 6088, 6089, 432,
+    149, 6090, 801, 6091, 1728, 1128, 467, 6092, 34, 6093, 6094, 4930, 1770, 6095, 432, 239,
+    744, 378, 180, 1882, 6096, 4281, 245, 245, 282, 735, 554, 6097, 970, 16, 6098, 6099,
+    3872, 4428, 485, 527, 6100, 6101, 935, 6102, 35, 5884, 243, 290, 209, 1564, 56, 277,
+    3046, 2994, 873, 192, 6103, 2934, 101, 209, 6104, 345, 6105, 6026, 1770, 1430, 658, 53,
+    353, 4211, 589, 1153, 369, 430, 640, 1832, 1640, 76, 308, 727, 1256, 1576, 1043, 637,
+    559, 6106, 1072, 6107, 6108, 773, 1430, 468, 6052, 369, 249, 1440, 6109, 6110, 55, 6111,
+    5401, 6112, 55, 1283, 197, 6113, 207, 6114, 919, 14, 5942, 1098, 954, 6115, 416, 1184,
+    589, 207, 580, 6116, 1296, 85, 6117, 808, 943, 1215, 1090, 419, 903, 744, 169, 1496,
+    6118, 3115, 546, 6119, 3273, 6120, 6121, 1938, 11, 14, 167, 4504, 623, 313, 324, 5212,
+    1984, 1430, 71, 100, 748, 6122, 826, 181, 6123, 851, 126, 631, 812, 34, 545, 813,
+    668, 6124, 288, 56, 111, 45, 141, 580, 260, 801, 1005, 6125, 6126, 757, 2839, 6127,
+    5896, 432, 725, 6128, 1770, 1061, 6129, 497, 6130, 127, 659, 516, 444, 580, 26, 6131,
+    6132, 126, 182, 6133, 333, 2330, 82, 6134, 279, 303, 260, 3039, 6135, 812, 5736, 1126,
+    37, 6136, 1229, 2033, 1126, 1271, 1607, 6137, 6138, 6139, 6140, 1274, 6141, 945, 1912, 277,
+    561, 485, 6142, 6143, 1134, 6144, 6145, 4381, 436, 451, 95, 61, 6146, 248, 6147, 6148,
+    6149, 5172, 231, 230, 200, 1019, 113, 954, 728, 2508, 271, 832, 811, 861, 237, 113,
+    1513, 6150, 111, 243, 169, 6151, 1692, 6152, 363, 89, 727, 1098, 6153, 6154, 6155, 4414,
+    366, 390, 3252, 207, 537, 744, 2097, 419, 2206, 97, 239, 123, 6156, 61, 149, 244,
+    716, 6157, 6158, 6159, 23, 6160, 214, 711, 1326, 1228, 709, 343, 556, 6161, 6162, 256,
+    81, 234, 65, 370, 4359, 1386, 253, 744, 399, 3273, 260, 580, 323, 6163, 6164, 6165,
+    236, 1084, 366, 826, 399, 4964, 6166, 327, 6167, 2898, 206, 209, 61, 61, 56, 6168,
+    6169, 6170, 1271, 26, 1388, 1128, 410, 45, 3486, 443, 1117, 557, 442, 2905, 136, 308,
+    6171, 660, 3273, 6172, 586, 872, 96, 104, 96, 6173, 655, 6174, 1355, 83, 662, 1607,
+    192, 573, 409, 1162, 1607, 539, 964, 6175, 478, 6176, 6177, 23, 1176, 90, 6178, 1589,
+    230, 249, 397, 833, 801, 373, 6179, 6180, 253, 233, 168, 6181, 345, 5567, 737, 6182,
+    436, 6124, 815, 6183, 6184, 38, 150, 775, 356, 89, 6185, 37, 1577, 26, 11, 474,
+    111, 26, 6186, 366, 571, 1126, 198, 289, 1527, 6187, 6188, 210, 5460, 1880, 6189, 1020,
+    516, 38, 491, 708, 1392, 5705, 1394, 373, 5673, 339, 55, 6190, 6191, 1513, 237, 1589,
+    826, 522, 1552, 6192, 6193, 6194, 4414, 4414, 6195, 184, 6196, 635, 630, 5127, 399, 6197,
+    57, 1607, 56, 2972, 826, 1300, 1, 635, 2972, 1309, 6198, 4424, 6199, 26, 775, 390,
+    332, 861, 6200, 5, 832, 599, 6201, 860, 1043, 455, 260, 3540, 67, 586, 1153, 6202,
+    6203, 53, 56, 1519, 279, 40, 82, 502, 6204, 845, 708, 345, 548, 458, 111, 56,
+    546, 366, 3076, 58, 400, 345, 123, 744, 83, 1527, 82, 1328, 234, 26, 470, 1404,
+    177, 779, 6205, 182, 1585, 6206, 6207, 234, 743, 6208, 6209, 709, 1184, 378, 709, 709,
+    182, 1599, 390, 6210, 6211, 316, 231, 362, 303, 548, 548, 800, 544, 6212, 430, 6213,
+    219, 6214, 2428, 219, 6215, 1430, 973, 556, 628, 6216, 498, 6217, 463, 6218, 6219, 363,
+    1827, 1830, 6220, 1609, 1059, 202, 6221, 307, 6222, 149, 1116, 322, 286, 1460, 1460, 644,
+    6223, 131, 455, 455, 391, 253, 568, 5, 5395, 6224, 1124, 6225, 6226, 6227, 253, 1028,
+    1315, 6228, 438, 2128, 1090, 1090, 677, 6229, 6230, 4979, 1026, 46, 378, 967, 524, 6231,
+    775, 532, 20, 1, 6232, 1306, 1061, 6233, 6234, 6235, 6236, 316, 55, 343, 6237, 993,
+    6238, 131, 6239, 311, 42, 143, 1371, 791, 4590, 660, 6240, 6241, 1811, 260, 104, 288,
+    104, 801, 55, 120, 320, 2223, 6005, 620, 2929, 6242, 6243, 343, 6244, 6245, 682, 746,
+    405, 311, 6246, 1025, 55, 6247, 1061, 6248, 6249, 142, 6, 112, 1005, 6250, 6251, 185,
+    38, 283, 108, 780, 628, 199, 521, 81, 253, 349, 46, 219, 6046, 358, 199, 701,
+    2172, 181, 6252, 64, 1162, 1040, 30, 975, 3655, 6253, 1838, 6254, 1097, 378, 111, 6255,
+    126, 6256, 6257, 925, 127, 288, 6258, 6259, 488, 6260, 2929, 4677, 845, 652, 6261, 509,
+    436, 253, 253, 580, 68, 6262, 1247, 1046, 3996, 197, 6263, 55, 47, 6264, 1660, 598,
+    255, 64, 6265, 907, 6266, 6267, 553, 6268, 6269, 6270, 1495, 3000, 754, 6271, 532, 6272,
+    6273, 103, 5024, 6274, 395, 149, 1398, 6275, 112, 702, 179, 801, 306, 365, 475, 1155,
+    1117, 1099, 6276, 1137, 105, 6277, 91, 390, 888, 6278, 826, 527, 218, 6279, 6280, 209,
+    343, 6281, 909, 708, 378, 4968, 7, 97, 6282, 6283, 6284, 599, 1430, 6285, 136, 746,
+    353, 3534, 343, 557, 345, 363, 1155, 4508, 277, 1, 3632, 6286, 6287, 6288, 6289, 1402,
+    559, 1705, 313, 801, 1128, 6290, 623, 6291, 81, 6292, 620, 4753, 557, 940, 443, 1602,
+    6293, 313, 313, 169, 1588, 292, 64, 205, 6294, 6295, 537, 71, 202, 114, 6296, 6297,
+    277, 6298, 6299, 483, 26, 1460, 801, 6300, 1134, 2139, 715, 253, 421, 6301, 2033, 6302,
+    444, 967, 6303, 561, 123, 708, 298, 71, 230, 4095, 3540, 131, 390, 6304, 58, 559,
+    492, 1134, 6305, 1128, 1777, 1460, 234, 1105, 628, 1274, 6306, 728, 6307, 6308, 6309, 197,
+    451, 111, 421, 234, 6310, 2330, 6311, 549, 1005, 1611, 81, 1, 699, 462, 1309, 1309,
+    6312, 239, 6313, 356, 6314, 185, 1770, 6315, 1472, 527, 6316, 6317, 556, 556, 61, 6318,
+    6319, 1025, 646, 6320, 234, 4506, 6321, 241, 81, 207, 120, 1166, 1199, 96, 6322, 6323,
+    328, 442, 365, 6324, 6325, 307, 1229, 6326, 205, 801, 1609, 55, 3706, 826, 443, 1431,
+    271, 805, 541, 96, 253, 313, 168, 2483, 574, 23, 6327, 74, 127, 964, 1222, 1271,
+    586, 366, 127, 1155, 6328, 6329, 268, 55, 1, 242, 578, 114, 234, 1075, 370, 111,
+    370, 6330, 2285, 131, 6331, 6332, 1324, 599, 1162, 197, 702, 239, 6333, 114, 702, 108,
+    6334, 127, 6335, 31, 6336, 585, 1060, 6337, 1060, 18, 6338, 1610, 75, 1612, 1271, 65,
+    6339, 1993, 6340, 686, 239, 260, 945, 430, 311, 6341, 6342, 888, 6343, 6344, 277, 708,
+    1347, 6345, 708, 1347, 597, 184, 239, 352, 405, 350, 1294, 390, 572, 6346, 6, 965,
+    781, 204, 812, 6347, 6348, 127, 808, 1134, 222, 26, 1028, 2070, 46, 6349, 7, 1026,
+    845, 584, 1204, 6350, 391, 557, 6351, 1609, 33, 95, 502, 1026, 382, 6352, 3362, 20,
+    6353, 2440, 192, 34, 126, 1479, 6354, 6355, 6356, 966, 292, 19, 6357, 6358, 3442, 147,
+    6359, 343, 812, 283, 283, 1026, 6360, 26, 6361, 1670, 6362, 2497, 945, 6363, 320, 6364,
+    580, 167, 546, 290, 504, 378, 6365, 6366, 587, 584, 1699, 6367, 4260, 6368, 127, 240,
+    6369, 23, 746, 6370, 773, 102, 6371, 2205, 2713, 3146, 1166, 709, 598, 1114, 95, 430,
+    6372, 842, 6373, 1199, 1324, 45, 6374, 6375, 26, 580, 19, 14, 219, 852, 56, 26,
+    350, 3146, 1189, 1019, 852, 1199, 6376, 861, 587, 168, 5606, 6377, 3476, 598, 1660, 686,
+    260, 322, 1329, 6378, 342, 572, 103, 6379, 6380, 547, 886, 6381, 100, 1628, 6382, 1001,
+    1950, 283, 6383, 6384, 253, 1628, 2529, 1114, 569, 294, 3635, 1701, 6385, 96, 6386, 47,
+    598, 6387, 6388, 292, 896, 2308, 2206, 339, 343, 6389, 6390, 218, 6391, 181, 6392, 408,
+    402, 566, 6393, 953, 603, 1225, 458, 313, 686, 6394, 924, 122, 6395, 1286, 6396, 322,
+    1761, 95, 1251, 46, 286, 458, 587, 443, 53, 111, 6397, 1430, 408, 6398, 1875, 96,
+    1018, 708, 572, 444, 4436, 422, 6399, 6400, 16, 126, 3739, 168, 6401, 168, 6402, 169,
+    566, 1581, 6403, 1947, 549, 392, 1371, 1130, 421, 53, 33, 6404, 4278, 1526, 107, 1084,
+    6405, 6406, 126, 756, 1166, 6407, 6408, 126, 53, 378, 23, 198, 539, 1162, 1443, 110,
+    6409, 7, 290, 781, 1552, 1944, 167, 6410, 53, 6411, 20, 1283, 6412, 1430, 168, 2420,
+    26, 53, 1323, 197, 197, 6413, 6414, 6415, 343, 6416, 343, 1078, 216, 400, 400, 6417,
+    1640, 495, 735, 6418, 556, 6419, 105, 436, 436, 6420, 110, 236, 6421, 58, 556, 436,
+    5246, 443, 192, 283, 391, 167, 375, 209, 65, 261, 390, 420, 149, 1246, 2125, 1529,
+    56, 483, 801, 469, 1538, 5489, 815, 1640, 156, 6422, 6423, 881, 744, 659, 1753, 1538,
+    58, 4877, 385, 6424, 2413, 6417, 2413, 735, 974, 436, 110, 636, 420, 1753, 56, 167,
+    1126, 261, 390, 469, 815, 2557, 6425, 219, 756, 6426, 6427, 283, 2147, 169, 1510, 288,
+    6428, 2541, 2033, 6429, 101, 263, 110, 6430, 185, 128, 171, 6431, 719, 219, 349, 37,
+    532, 6432, 123, 283, 6433, 126, 349, 6434, 2033, 128, 283, 6435, 171, 197, 6, 6436,
+    556, 197, 735, 6437, 6438, 303, 475, 343, 6439, 219, 571, 6440, 56, 220, 642, 93,
+    1085, 6441, 2024, 5834, 4443, 6442, 584, 642, 248, 646, 658, 1670, 56, 642, 248, 1163,
+    6443, 1323, 347, 944, 385, 248, 6444, 556, 6445, 227, 230, 97, 198, 13, 32, 18,
+    6446, 1229, 303, 6447, 6448, 6449, 26, 26, 37, 6450, 1222, 45, 216, 11, 19, 42,
+    608, 469, 6451, 463, 6452, 104, 343, 168, 47, 260, 6453, 820, 55, 6454, 2420, 35,
+    1180, 26, 316, 192, 1306, 6455, 6456, 2047, 6457, 1061, 126, 4068, 6458, 6459, 442, 1555,
+    883, 161, 139, 628, 358, 44, 241, 26, 695, 5427, 110, 6460, 159, 1430, 3521, 6461,
+    6462, 185, 101, 61, 127, 144, 1239, 6463, 209, 45, 6464, 1352, 546, 6465, 1528, 6466,
+    502, 303, 13, 2529, 6467, 117, 3260, 6468, 366, 746, 1541, 6469, 1588, 580, 1527, 56,
+    283, 584, 295, 6470, 6471, 1203, 6472, 110, 1325, 288, 181, 365, 129, 786, 367, 1204,
+    6473, 432, 1846, 159, 530, 46, 619, 1588, 21, 2482, 944, 3370, 61, 638, 6474, 115,
+    6475, 1001, 1043, 2750, 6476, 127, 203, 6477, 301, 389, 107, 112, 311, 716, 6478, 86,
+    26, 6479, 248, 6480, 6481, 74, 727, 1494, 597, 2980, 569, 1020, 2965, 243, 509, 6482,
+    256, 4053, 6483, 6484, 286, 74, 2124, 655, 6485, 6486, 6487, 6488, 572, 144, 431, 390,
+    6489, 709, 6490, 6491, 826, 110, 719, 1237, 149, 1028, 3223, 7, 378, 1127, 1137, 6492,
+    77, 6493, 970, 1472, 6494, 61, 773, 6495, 6496, 6497, 230, 6498, 1075, 536, 735, 527,
+    826, 263, 9, 6499, 908, 6500, 708, 349, 1527, 6501, 646, 168, 6502, 6503, 695, 118,
+    673, 6504, 546, 6505, 436, 6506, 1046, 6507, 531, 6508, 37, 907, 351, 6509, 6510, 637,
+    6511, 96, 6512, 883, 67, 67, 2881, 6513, 6514, 6515, 1955, 111, 1300, 1300, 2033, 6516,
+    6517, 168, 271, 6518, 443, 606, 6519, 6520, 355, 6521, 6522, 3402, 561, 497, 628, 5780,
+    6523, 1699, 1342, 3424, 6524, 6525, 6526, 709, 1450, 80, 197, 300, 539, 608, 6527, 53,
+    6528, 1251, 556, 400, 1573, 6529, 3281, 61, 6530, 4096, 6531, 993, 6532, 5763, 1020, 6533,
+    1127, 1978, 826, 2739, 1020, 1782, 107, 775, 400, 571, 328, 6534, 6535, 405, 1513, 660,
+    819, 830, 6536, 26, 26, 646, 3694, 1338, 754, 539, 86, 200, 5217, 574, 6537, 599,
+    655, 580, 168, 61, 4858, 628, 945, 160, 366, 385, 708, 727, 55, 6538, 495, 628,
+    735, 149, 8, 708, 8, 192, 365, 3694, 5, 53, 6539, 7, 242, 2919, 160, 26,
+    3821, 6540, 735, 1001, 303, 56, 475, 343, 584, 400, 127, 1085, 2024, 3694, 646, 658,
+    1670, 642, 26, 642, 56, 584, 637, 61, 1394, 316, 1323, 227, 6449, 1163, 6487, 13,
+    944, 6498, 1229, 32, 556, 608, 104, 1222, 1061, 1555, 6532, 4068, 1306, 37, 19, 850,
+    1448, 139, 320, 72, 126, 26, 6, 1541, 366, 110, 2529, 1430, 570, 502, 530, 746,
+    45, 144, 288, 469, 101, 185, 241, 26, 4053, 1239, 535, 665, 719, 44, 1448, 46,
+    1588, 1846, 367, 21, 2482, 129, 61, 1203, 6541, 944, 6542, 840, 746, 1156, 9, 4858,
+    74, 716, 203, 243, 390, 6476, 385, 6543, 727, 1200, 597, 107, 1020, 385, 26, 908,
+    230, 47, 695, 655, 7, 531, 252, 708, 149, 580, 826, 365, 970, 432, 735, 640,
+    6544, 6545, 709, 1300, 1782, 37, 96, 67, 907, 110, 286, 436, 497, 1342, 628, 168,
+    6517, 1020, 6533, 2867, 53, 1127, 556, 735, 754, 660, 781, 6546, 286, 245, 973, 169,
+    245, 693, 6547, 6548, 197, 2475, 356, 6549, 1001, 1944, 411, 1128, 3355, 6550, 6551, 156,
+    326, 6552, 430, 619, 30, 283, 283, 735, 735, 212, 171, 502, 831, 26, 6553, 26,
+    744, 1116, 879, 149, 207, 549, 210, 6554, 6555, 1239, 316, 6556, 197, 6557, 574, 6558,
+    746, 382, 1090, 197, 6559, 890, 615, 1157, 1126, 353, 1090, 26, 6560, 362, 59, 533,
+    131, 808, 807, 1143, 6561, 129, 143, 1005, 236, 990, 6562, 6563, 5016, 6564, 6565, 533,
+    2411, 6566, 532, 583, 57, 382, 6567, 6568, 131, 197, 26, 6569, 1005, 1686, 1020, 1129,
+    556, 181, 2301, 557, 343, 102, 119, 699, 6570, 677, 493, 964, 118, 220, 331, 6571,
+    5548, 283, 75, 42, 110, 126, 970, 75, 851, 6419, 6572, 2097, 6573, 1692, 1408, 6574,
+    6080, 6, 351, 105, 896, 6575, 123, 237, 61, 1239, 431, 3187, 6576, 11, 3706, 841,
+    1239, 932, 655, 599, 642, 115, 6577, 544, 544, 219, 3131, 4540, 932, 1448, 1552, 160,
+    620, 1494, 4126, 261, 547, 1136, 3677, 1610, 655, 107, 343, 429, 301, 301, 283, 400,
+    354, 1246, 815, 13, 268, 824, 339, 2704, 1552, 605, 1335, 400, 1692, 2080, 6578, 343,
+    469, 2360, 271, 356, 26, 573, 3054, 1105, 792, 2965, 1087, 6579, 1627, 288, 160, 735,
+    85, 6580, 1105, 801, 815, 1180, 311, 932, 126, 1001, 6581, 2052, 4126, 945, 50, 6582,
+    932, 181, 249, 343, 557, 119, 102, 271, 311, 573, 354, 118, 283, 220, 331, 964,
+    737, 469, 42, 934, 75, 655, 6583, 110, 6080, 851, 1408, 20, 105, 828, 896, 6,
+    3706, 283, 6584, 61, 970, 237, 123, 932, 970, 1239, 815, 219, 115, 1552, 3131, 343,
+    1246, 400, 957, 4126, 620, 126, 1448, 1136, 547, 222, 1552, 605, 2965, 343, 6578, 2360,
+    271, 735, 160, 369, 1627, 646, 801, 1180, 78, 197, 6585, 1402, 6586, 65, 120, 6587,
+    120, 2102, 323, 71, 6588, 6588, 55, 1751, 343, 72, 6589, 77, 646, 38, 6590, 775,
+    970, 6591, 492, 1706, 2298, 6592, 781, 6593, 6594, 6595, 147, 65, 660, 6596, 6597, 320,
+    570, 6598, 207, 6599, 6600, 6601, 6602, 270, 682, 1294, 414, 295, 167, 6603, 37, 5,
+    6604, 655, 6605, 6606, 6607, 6608, 123, 6609, 1519, 1740, 970, 244, 6610, 78, 6611, 6612,
+    1279, 571, 2739, 6613, 397, 1039, 6614, 6615, 1564, 6616, 6617, 1117, 6618, 6591, 204, 3054,
+    6619, 6620, 198, 6621, 455, 5779, 6622, 455, 2641, 6623, 90, 26, 6624, 260, 909, 6625,
+    6626, 276, 1118, 382, 6627, 6628, 3368, 42, 6629, 6630, 462, 5614, 6631, 6632, 965, 6633,
+    532, 316, 5393, 4264, 6, 400, 6634, 6635, 6636, 1159, 1075, 6637, 6638, 6639, 851, 6640,
+    6641, 535, 6642, 6643, 6644, 283, 6645, 6646, 1433, 655, 6647, 1279, 126, 1018, 1159, 400,
+    6648, 56, 6649, 6650, 6651, 5853, 242, 5333, 333, 6652, 6653, 534, 43, 6654, 358, 841,
+    8, 1279, 908, 6655, 655, 2403, 6656, 6657, 6658, 127, 6659, 242, 509, 242, 262, 123,
+    6660, 458, 316, 351, 1855, 3000, 6661, 6662, 2517, 150, 6663, 6664, 353, 6665, 353, 6666,
+    283, 903, 1098, 462, 6667, 6668, 1801, 6669, 358, 6670, 6671, 1091, 3054, 6672, 655, 6673,
+    6674, 1283, 5130, 529, 47, 431, 4930, 6675, 1989, 6676, 998, 168, 564, 5086, 1786, 86,
+    2641, 6677, 2313, 244, 169, 242, 6678, 6679, 6680, 126, 679, 123, 356, 295, 6681, 6682,
+    37, 1770, 1902, 400, 3055, 2641, 6683, 6684, 353, 443, 1093, 607, 6685, 1371, 630, 332,
+    6686, 6687, 1018, 586, 207, 6688, 6689, 504, 6690, 295, 6691, 556, 5142, 6692, 3057, 6693,
+    1127, 6694, 1292, 4012, 934, 2739, 442, 126, 126, 6695, 316, 6696, 367, 6618, 8, 400,
+    4, 6697, 1633, 237, 682, 462, 6698, 3890, 1839, 207, 873, 708, 4165, 237, 1018, 708,
+    1768, 53, 96, 1396, 6699, 242, 86, 239, 169, 4012, 6700, 1770, 345, 6701, 237, 6702,
+    128, 566, 671, 320, 1426, 270, 270, 128, 623, 663, 100, 1513, 400, 332, 6703, 1331,
+    2833, 1304, 1346, 487, 320, 606, 2833, 6704, 462, 735, 1426, 1347, 599, 580, 2251, 6705,
+    185, 391, 708, 455, 6706, 20, 6707, 220, 584, 965, 1203, 43, 831, 6708, 20, 149,
+    808, 253, 482, 148, 1203, 743, 1766, 1355, 779, 6709, 1408, 6710, 1575, 20, 225, 19,
+    6711, 1541, 179, 403, 181, 1510, 1510, 402, 42, 6712, 149, 173, 6713, 198, 6714, 1117,
+    6715, 6716, 110, 1061, 240, 685, 332, 6717, 2220, 6718, 6719, 6720, 1448, 6721, 283, 3131,
+    1200, 580, 1386, 97, 6722, 6723, 628, 460, 226, 4706, 1448, 6724, 6725, 628, 548, 1200,
+    3187, 502, 112, 386, 6726, 2216, 202, 1309, 954, 812, 6727, 4603, 1467, 181, 262, 2253,
+    1122, 6728, 4930, 169, 536, 6729, 950, 2426, 6730, 6731, 97, 1355, 6732, 6733, 970, 378,
+    6734, 675, 6735, 5413, 890, 6736, 4504, 249, 6737, 557, 123, 6738, 3424, 6739, 708, 60,
+    1849, 628, 571, 548, 6740, 6741, 1090, 2495, 4683, 263, 80, 2216, 5091, 202, 169, 2965,
+    240, 580, 655, 1355, 82, 668, 55, 55, 578, 237, 2251, 6705, 185, 826, 20, 584,
+    6739, 149, 262, 148, 1355, 1510, 5369, 578, 6742, 198, 26, 779, 19, 55, 67, 1845,
+    110, 6743, 283, 571, 1448, 101, 123, 571, 628, 226, 1200, 1633, 181, 502, 812, 112,
+    1122, 386, 970, 2426, 378, 169, 890, 43, 668, 557, 323, 6744, 123, 628, 157, 662,
+    1510, 139, 139, 6745, 6746, 495, 1155, 1155, 139, 6747, 868, 495, 6748, 495, 308, 495,
+    4073, 139, 495, 6749, 230, 2099, 1084, 1084, 6750, 386, 386, 405, 906, 6751, 4935, 1059,
+    498, 356, 6752, 6753, 138, 286, 498, 498, 658, 642, 6754, 6755, 1610, 569, 505, 6756,
+    2391, 597, 6757, 442, 6758, 815, 6759, 1323, 1755, 3047, 442, 1163, 6760, 6761, 442, 815,
+    1650, 659, 443, 351, 98, 6762, 545, 4768, 6754, 122, 6763, 230, 20, 397, 1126, 6764,
+    6765, 6766, 6767, 1660, 430, 2968, 6768, 638, 2929, 36, 129, 6769, 1093, 2980, 2284, 6,
+    127, 6770, 682, 642, 701, 944, 6771, 1660, 5937, 879, 576, 1801, 122, 186, 642, 3370,
+    2168, 234, 219, 6772, 6773, 219, 640, 640, 919, 638, 294, 6774, 2146, 6775, 6776, 6777,
+    3047, 110, 6778, 37, 6779, 6780, 6781, 6782, 1495, 2929, 1199, 104, 2218, 2283, 1984, 6783,
+    6784, 210, 6785, 6786, 97, 1448, 815, 580, 6787, 3120, 209, 294, 26, 442, 1650, 58,
+    602, 9, 6788, 3391, 1063, 6789, 6790, 6791, 950, 351, 244, 6792, 1059, 249, 97, 399,
+    149, 495, 6793, 149, 323, 527, 6794, 2266, 4575, 6795, 125, 6796, 469, 67, 1560, 5361,
+    642, 86, 6797, 6798, 6799, 37, 6655, 1361, 24, 67, 934, 2330, 561, 6800, 2039, 78,
+    37, 607, 1106, 1801, 6797, 559, 4485, 5086, 4683, 3090, 6801, 286, 6802, 580, 6803, 149,
+    356, 3425, 110, 126, 347, 1610, 96, 107, 6804, 6805, 1527, 397, 6806, 4935, 4935, 6807,
+    6808, 557, 67, 6809, 1199, 413, 1137, 6810, 77, 2890, 965, 255, 390, 476, 6811, 38,
+    197, 1408, 204, 6812, 544, 2506, 6813, 6814, 662, 385, 338, 557, 4184, 3101, 6815, 338,
+    1588, 316, 97, 2613, 90, 1, 6816, 807, 791, 1479, 181, 770, 850, 1156, 6817, 157,
+    1541, 974, 746, 1430, 276, 580, 6818, 6297, 6819, 4341, 169, 6820, 879, 12, 1025, 167,
+    974, 6821, 5073, 199, 175, 608, 623, 399, 303, 530, 411, 6822, 408, 226, 1544, 378,
+    2482, 6823, 623, 199, 1201, 68, 6824, 290, 65, 181, 55, 419, 5111, 6825, 569, 131,
+    1845, 6826, 1448, 1020, 6827, 1061, 6828, 532, 6829, 6830, 333, 16, 566, 332, 17, 1279,
+    405, 6831, 1783, 1153, 1496, 181, 149, 43, 639, 57, 399, 282, 345, 6832, 16, 1602,
+    71, 483, 197, 2099, 171, 970, 6833, 252, 1588, 16, 419, 6834, 17, 583, 2411, 3424,
+    6835, 472, 248, 1472, 200, 1279, 697, 3501, 6836, 1279, 44, 1747, 126, 241, 126, 4416,
+    659, 608, 105, 646, 4683, 6837, 6838, 363, 252, 6839, 175, 30, 363, 6833, 6840, 861,
+    160, 365, 273, 2060, 2809, 365, 6841, 260, 6842, 197, 30, 6843, 6844, 925, 2131, 219,
+    74, 668, 35, 6845, 6846, 390, 614, 6847, 484, 482, 6848, 11, 411, 249, 671, 557,
+    6849, 399, 66, 900, 3368, 6850, 6851, 1619, 1510, 635, 6852, 8, 6853, 555, 6854, 386,
+    8, 129, 6855, 198, 1018, 1699, 530, 609, 919, 571, 1153, 587, 1687, 40, 85, 6856,
+    3049, 735, 5542, 6857, 6858, 123, 420, 6859, 1660, 2657, 439, 468, 1405, 484, 2965, 6851,
+    598, 6860, 6861, 513, 277, 44, 6862, 880, 6863, 468, 399, 416, 1117, 1602, 546, 6020,
+    1883, 6864, 399, 523, 1167, 1306, 3049, 71, 1699, 1067, 6865, 6866, 111, 607, 297, 1828,
+    1699, 6740, 6867, 197, 1020, 6868, 1084, 3012, 1199, 316, 6869, 6870, 1584, 23, 1607, 6871,
+    735, 260, 546, 546, 6857, 18, 571, 6872, 546, 735, 6873, 3301, 110, 129, 110, 1269,
+    6874, 6875, 6876, 2124, 6877, 736, 756, 6878, 202, 5279, 2433, 1702, 6879, 6880, 219, 1116,
+    226, 249, 462, 1116, 2098, 226, 2284, 93, 6881, 6882, 113, 6883, 463, 6884, 6885, 6886,
+    6887, 5146, 253, 476, 6888, 640, 236, 65, 26, 5952, 6889, 2942, 197, 5574, 286, 219,
+    655, 6890, 6891, 6892, 6893, 1316, 6894, 12, 524, 455, 6895, 6896, 6897, 6898, 6899, 1163,
+    556, 1801, 603, 5, 6900, 1398, 6901, 1434, 6902, 6903, 671, 6904, 202, 205, 6905, 306,
+    245, 248, 391, 93, 26, 1134, 1770, 935, 1537, 3101, 66, 2507, 557, 578, 149, 205,
+    653, 970, 3765, 919, 110, 1721, 6906, 1117, 260, 791, 6907, 6908, 290, 3741, 580, 236,
+    1119, 3370, 6909, 6910, 6911, 6912, 212, 6913, 6914, 316, 6915, 6916, 6917, 945, 1702, 6918,
+    216, 6919, 997, 6920, 6921, 290, 42, 78, 505, 6922, 568, 6923, 378, 1435, 6924, 4554,
+    378, 55, 455, 807, 88, 868, 504, 127, 6925, 6926, 903, 534, 6927, 6928, 640, 382,
+    438, 880, 6929, 202, 81, 1408, 2205, 690, 655, 6930, 12, 245, 841, 322, 6931, 6932,
+    6933, 110, 2223, 6934, 248, 391, 1448, 774, 6935, 568, 1005, 40, 400, 6936, 6937, 6938,
+    3847, 283, 6939, 6940, 6941, 6942, 6943, 101, 807, 6944, 746, 6945, 483, 591, 6946, 6947,
+    311, 990, 391, 2294, 780, 169, 1645, 23, 485, 405, 773, 2220, 1182, 2066, 925, 80,
+    1100, 743, 470, 81, 2697, 93, 64, 644, 3593, 786, 219, 234, 6948, 6388, 3046, 6949,
+    1984, 764, 6950, 6951, 6952, 6953, 6954, 623, 1105, 6955, 5512, 181, 21, 37, 580, 6956,
+    6957, 290, 64, 199, 245, 6958, 30, 636, 460, 283, 6959, 6960, 6961, 6962, 545, 1408,
+    4080, 70, 6963, 6964, 975, 255, 70, 1813, 6965, 1627, 6966, 619, 6967, 476, 6968, 111,
+    2420, 6969, 530, 2482, 1199, 699, 1559, 42, 115, 554, 766, 6970, 1986, 882, 6971, 4707,
+    3866, 93, 1660, 726, 6972, 6973, 260, 807, 123, 111, 1046, 6974, 1095, 552, 6975, 6976,
+    533, 6977, 6978, 481, 285, 4690, 6979, 1331, 86, 6980, 1001, 6981, 499, 970, 903, 4997,
+    242, 1441, 6982, 6983, 475, 6984, 2452, 1305, 400, 580, 497, 1467, 482, 1329, 6985, 6986,
+    435, 6987, 790, 6988, 430, 249, 6056, 2483, 123, 1043, 572, 3328, 704, 2758, 1316, 6989,
+    924, 6990, 160, 1599, 687, 1018, 146, 1821, 1200, 1280, 1404, 6991, 599, 6992, 6993, 6994,
+    775, 114, 919, 659, 353, 343, 1183, 169, 149, 391, 6995, 244, 993, 200, 536, 873,
+    323, 390, 786, 997, 527, 243, 343, 1702, 2294, 150, 6996, 1355, 345, 6997, 908, 1279,
+    1279, 282, 2302, 451, 615, 2581, 552, 655, 551, 655, 6998, 345, 277, 826, 6999, 127,
+    6104, 4935, 1134, 970, 990, 1813, 1346, 1072, 2697, 46, 5570, 1153, 7000, 4575, 7001, 323,
+    443, 937, 2486, 14, 7002, 7003, 7004, 940, 313, 71, 870, 7005, 1970, 943, 7006, 290,
+    1296, 7007, 6295, 1761, 762, 970, 1588, 7008, 1221, 545, 5361, 1256, 191, 7009, 1602, 1105,
+    7010, 115, 7011, 7012, 26, 557, 79, 242, 167, 382, 673, 24, 1324, 944, 1555, 870,
+    340, 30, 890, 1655, 182, 870, 7013, 7014, 835, 1194, 934, 7015, 2337, 7016, 330, 421,
+    1005, 58, 126, 708, 7017, 7018, 7019, 1968, 6, 7020, 7021, 786, 1046, 450, 569, 726,
+    7022, 279, 17, 727, 30, 638, 1087, 430, 5183, 292, 451, 370, 160, 1711, 444, 111,
+    340, 197, 487, 1565, 1093, 7023, 5570, 120, 123, 537, 169, 85, 7024, 3756, 304, 157,
+    80, 292, 7025, 120, 1953, 81, 14, 7026, 527, 1806, 107, 586, 491, 109, 536, 1704,
+    549, 3375, 1025, 3090, 356, 150, 297, 7027, 392, 2387, 2060, 168, 1134, 3068, 1035, 627,
+    7028, 556, 1338, 7029, 1127, 1811, 586, 120, 872, 646, 371, 7030, 828, 107, 182, 1324,
+    61, 580, 443, 74, 7031, 7032, 7033, 82, 7034, 7035, 4830, 252, 653, 7036, 7037, 3425,
+    7038, 442, 181, 26, 206, 353, 642, 93, 42, 184, 2965, 400, 7039, 216, 118, 1845,
+    544, 599, 1576, 1608, 7040, 655, 655, 754, 7041, 283, 838, 111, 1504, 400, 1804, 5392,
+    4126, 1309, 1155, 1001, 7042, 386, 180, 1087, 362, 182, 7043, 3299, 370, 149, 578, 7044,
+    655, 457, 290, 611, 7045, 365, 7046, 655, 7047, 934, 1068, 242, 366, 7048, 239, 2420,
+    58, 7049, 470, 7050, 7051, 556, 79, 1431, 53, 756, 3328, 202, 249, 7052, 1702, 304,
+    5279, 2942, 286, 4540, 7053, 83, 586, 93, 1404, 5574, 536, 7054, 12, 935, 333, 66,
+    316, 1801, 1906, 1134, 808, 181, 3866, 1398, 743, 7045, 1204, 391, 1588, 603, 2507, 1316,
+    3101, 7055, 1152, 7056, 580, 743, 903, 743, 104, 504, 1117, 2580, 3370, 290, 455, 7049,
+    438, 880, 1522, 2186, 97, 1811, 290, 260, 943, 4554, 757, 362, 110, 945, 2141, 126,
+    532, 3741, 653, 12, 1100, 780, 397, 476, 1035, 925, 530, 199, 1813, 521, 283, 7057,
+    568, 408, 93, 773, 1256, 993, 746, 4626, 6957, 2066, 1018, 537, 101, 1019, 7058, 7059,
+    1827, 483, 3572, 80, 841, 405, 727, 774, 136, 1182, 248, 23, 42, 297, 6968, 7060,
+    704, 699, 435, 786, 234, 870, 1199, 1627, 207, 306, 2482, 1163, 292, 1984, 70, 30,
+    662, 766, 371, 7061, 644, 189, 623, 492, 6966, 7062, 1046, 2102, 9, 1984, 482, 4997,
+    253, 687, 203, 598, 1329, 197, 790, 58, 499, 285, 497, 283, 1018, 1095, 1821, 547,
+    7063, 475, 655, 1986, 2483, 970, 150, 599, 1256, 1046, 81, 149, 2294, 1279, 1134, 873,
+    1602, 527, 615, 2697, 114, 1346, 188, 970, 828, 1153, 1005, 115, 290, 263, 242, 79,
+    1324, 242, 1655, 5361, 4138, 907, 26, 382, 544, 313, 1087, 7022, 279, 58, 1093, 17,
+    638, 7064, 529, 945, 14, 7065, 556, 618, 85, 182, 7066, 53, 1127, 4012, 7067, 1747,
+    118, 82, 615, 74, 443, 26, 7041, 1087, 1155, 365, 16, 7068, 726, 614, 33, 47,
+    320, 3678, 546, 7069, 1404, 69, 7070, 375, 7071, 1404, 303, 126, 7072, 126, 965, 7073,
+    2507, 172, 245, 567, 858, 311, 7074, 4927, 4134, 7075, 4531, 411, 7076, 7077, 660, 7078,
+    7079, 260, 7080, 126, 567, 7081, 1640, 263, 7082, 233, 171, 7083, 974, 526, 7084, 842,
+    7085, 1577, 1980, 978, 332, 455, 455, 727, 881, 237, 7086, 7087, 842, 697, 1460, 4592,
+    7088, 1451, 580, 1460, 1085, 2206, 7089, 881, 477, 1460, 7090, 7091, 659, 773, 1728, 7092,
+    1357, 7093, 101, 30, 752, 242, 86, 1256, 216, 7094, 7095, 1640, 411, 77, 800, 7096,
+    7097, 1176, 7098, 7099, 61, 727, 7100, 5555, 615, 86, 1460, 953, 1404, 7070, 375, 303,
+    64, 126, 2767, 2328, 567, 659, 311, 245, 5210, 526, 634, 3620, 993, 7077, 974, 1460,
+    86, 526, 1577, 1256, 233, 263, 216, 842, 1115, 455, 5073, 7093, 580, 697, 1329, 65,
+    1460, 1451, 303, 7101, 727, 53, 881, 61, 1728, 195, 216, 752, 4575, 7102, 7103, 615,
+    343, 343, 190, 539, 18, 286, 7104, 7105, 7106, 773, 536, 524, 7107, 997, 1398, 547,
+    7108, 7109, 7110, 198, 131, 328, 667, 536, 773, 115, 888, 120, 7107, 320, 850, 7111,
+    34, 7112, 3362, 7113, 6850, 7114, 7115, 791, 7116, 56, 7117, 348, 230, 12, 1159, 188,
+    1005, 7118, 43, 7119, 7120, 240, 185, 498, 1239, 375, 68, 367, 7121, 468, 7122, 126,
+    323, 375, 997, 7123, 7124, 524, 686, 115, 7125, 619, 564, 242, 824, 775, 547, 7126,
+    7127, 3259, 539, 2224, 7128, 509, 499, 7129, 5817, 260, 4632, 619, 7117, 997, 775, 773,
+    7130, 536, 7131, 323, 154, 2809, 773, 7132, 7133, 390, 7134, 209, 209, 242, 7135, 7136,
+    1182, 527, 888, 1063, 5, 7137, 218, 7138, 7139, 2386, 21, 7140, 7141, 65, 71, 56,
+    7142, 564, 561, 65, 5, 83, 62, 157, 630, 1747, 7143, 7144, 1063, 3684, 7145, 1090,
+    833, 240, 400, 564, 7146, 3489, 283, 502, 348, 197, 7147, 55, 55, 7148, 7149, 7150,
+    191, 6146, 6497, 26, 7151, 735, 205, 7152, 1841, 7153, 169, 7154, 615, 7155, 469, 7156,
+    139, 17, 509, 207, 779, 1581, 134, 390, 8, 143, 1830, 64, 575, 215, 7157, 7158,
+    1581, 17, 400, 197, 8, 1083, 7159, 7160, 966, 1581, 169, 169, 1846, 7161, 1849, 2100,
+    5732, 7162, 438, 1460, 671, 7163, 419, 55, 78, 88, 5978, 4423, 7164, 1062, 7165, 488,
+    1519, 7166, 21, 93, 7167, 56, 438, 230, 1135, 7168, 1137, 7169, 668, 1153, 260, 569,
+    1448, 7170, 978, 886, 245, 660, 773, 532, 42, 831, 157, 260, 36, 5993, 21, 260,
+    215, 260, 323, 245, 773, 1610, 1610, 824, 1083, 459, 544, 7165, 708, 1728, 248, 167,
+    26, 1513, 288, 7171, 7172, 580, 7173, 370, 20, 169, 1482, 536, 7174, 807, 659, 30,
+    65, 56, 7175, 7176, 363, 82, 55, 7177, 1513, 828, 20, 7178, 260, 7179, 30, 55,
+    339, 896, 896, 896, 117, 1845, 6272, 2275, 900, 249, 638, 120, 7180, 638, 7181, 488,
+    638, 900, 7182, 886, 1100, 7183, 1651, 1651, 362, 252, 7184, 61, 7185, 7186, 648, 625,
+    7187, 584, 1126, 114, 21, 584, 205, 815, 308, 1152, 7188, 5, 7189, 808, 3438, 1555,
+    442, 868, 26, 192, 1660, 7190, 888, 245, 402, 7191, 791, 773, 7192, 1660, 1099, 7193,
+    432, 432, 948, 685, 3368, 442, 491, 116, 3616, 7194, 1830, 545, 7195, 7196, 1109, 52,
+    86, 7197, 7198, 1728, 7199, 7200, 7201, 1183, 338, 7202, 1, 1, 7203, 367, 7204, 7205,
+    5210, 4935, 147, 744, 890, 883, 603, 86, 7206, 7, 1296, 263, 7207, 1728, 136, 559,
+    4278, 7208, 308, 400, 744, 1128, 360, 4575, 200, 775, 286, 1001, 1443, 744, 53, 6795,
+    584, 378, 909, 7209, 263, 243, 7195, 255, 2750, 1728, 7210, 399, 1229, 243, 263, 7211,
+    7212, 603, 5673, 674, 126, 7213, 7214, 1001, 1443, 1001, 659, 584, 378, 255, 243, 399,
+    883, 614, 614, 1018, 96, 1018, 400, 773, 1581, 399, 1108, 190, 1309, 7215, 599, 399,
+    1309, 432, 773, 307, 200, 2581, 872, 7216, 345, 549, 711, 7217, 709, 6, 709, 5307,
+    7218, 1902, 7219, 7220, 580, 7221, 7222, 7223, 1126, 7224, 2420, 7225, 3412, 98, 328, 40,
+    7226, 1279, 2692, 1163, 243, 7227, 1728, 385, 6, 6, 7228, 7229, 7230, 7231, 40, 7232,
+    199, 3110, 61, 345, 199, 7233, 1325, 120, 7234, 369, 277, 64, 7235, 630, 630, 7236,
+    1552, 630, 970, 970, 499, 7237, 1631, 7238, 64, 286, 7239, 2260, 7240, 1204, 6, 834,
+    1989, 1163, 149, 303, 7241, 7242, 2787, 1472, 7243, 775, 18, 202, 7244, 1761, 7245, 2313,
+    637, 7246, 40, 297, 1067, 7247, 108, 295, 7248, 327, 491, 790, 642, 7249, 61, 7250,
+    23, 363, 544, 1829, 964, 578, 7251, 242, 101, 252, 872, 840, 693, 549, 711, 709,
+    709, 493, 790, 7223, 253, 4073, 944, 677, 580, 578, 3412, 7252, 7235, 7253, 199, 1325,
+    5427, 627, 122, 7254, 6, 964, 61, 630, 64, 627, 627, 1117, 244, 480, 149, 1472,
+    303, 1163, 242, 7255, 2313, 1761, 327, 40, 7256, 23, 964, 101, 7257, 7258, 764, 378,
+    973, 439, 7259, 7260, 7261, 90, 682, 7262, 945, 6046, 316, 1087, 81, 243, 536, 1556,
+    1602, 1256, 1525, 2495, 14, 167, 7263, 3658, 4095, 14, 1087, 1087, 1087, 356, 1087, 80,
+    30, 990, 30, 536, 439, 1087, 83, 316, 81, 1602, 67, 14, 4095, 1087, 1087, 886,
+    2495, 886, 886, 7264, 1059, 1390, 400, 475, 236, 1670, 7265, 3273, 307, 7266, 584, 580,
+    7267, 7268, 5, 220, 220, 7269, 3489, 7270, 7271, 6092, 126, 3225, 883, 339, 139, 2041,
+    236, 339, 7272, 1159, 684, 584, 7273, 7274, 7275, 791, 67, 568, 139, 307, 7276, 639,
+    397, 735, 7277, 197, 7278, 504, 1165, 149, 7279, 68, 411, 37, 7280, 7281, 67, 7282,
+    1628, 110, 655, 2965, 791, 7283, 333, 252, 1405, 886, 2330, 7284, 1467, 149, 7285, 7286,
+    277, 527, 2041, 61, 7287, 282, 7288, 7289, 671, 708, 104, 2294, 944, 71, 290, 674,
+    65, 1576, 7290, 1602, 7291, 252, 890, 1300, 71, 108, 126, 580, 990, 497, 782, 7292,
+    7293, 646, 78, 288, 7294, 2060, 1130, 120, 17, 883, 708, 307, 660, 671, 684, 363,
+    735, 1300, 365, 307, 58, 160, 7295, 1059, 475, 400, 1670, 7289, 831, 65, 584, 580,
+    78, 220, 3489, 655, 110, 339, 236, 1159, 6, 397, 1130, 307, 216, 7296, 841, 197,
+    791, 504, 411, 149, 390, 1165, 68, 1628, 60, 619, 1467, 7297, 1576, 104, 1602, 365,
+    252, 1300, 290, 5361, 990, 497, 108, 782, 1832, 7295, 471, 7298, 1910, 16, 1090, 290,
+    833, 126, 5033, 919, 693, 886, 744, 432, 206, 7299, 1018, 7300, 662, 1090, 610, 662,
+    24, 580, 2416, 7301, 1126, 7302, 7303, 351, 241, 42, 5809, 668, 7304, 1537, 83, 824,
+    1460, 462, 7305, 1577, 97, 290, 339, 1132, 1118, 26, 320, 1460, 320, 2972, 2580, 1702,
+    589, 7306, 35, 316, 42, 3137, 6383, 807, 7307, 7308, 260, 19, 7309, 343, 179, 212,
+    126, 1118, 1118, 236, 14, 69, 431, 1660, 283, 313, 1527, 36, 7310, 109, 45, 2972,
+    397, 684, 290, 746, 773, 7311, 1448, 288, 684, 14, 332, 763, 1204, 476, 6255, 64,
+    919, 826, 701, 68, 7312, 3475, 349, 7313, 123, 285, 7314, 1229, 431, 598, 1660, 704,
+    353, 203, 3447, 253, 735, 886, 715, 735, 263, 477, 21, 1112, 451, 1112, 743, 886,
+    527, 743, 1821, 390, 244, 101, 323, 353, 7315, 1183, 81, 764, 1118, 7316, 1602, 7317,
+    530, 283, 14, 7318, 1527, 167, 1532, 1001, 735, 557, 7319, 7320, 646, 253, 4768, 215,
+    123, 167, 58, 2330, 7321, 7322, 1390, 7323, 451, 320, 126, 283, 580, 709, 537, 504,
+    37, 234, 157, 548, 1292, 14, 1118, 1261, 488, 7324, 1811, 735, 58, 6805, 1882, 26,
+    252, 1670, 610, 35, 735, 7325, 1577, 1527, 16, 56, 215, 332, 167, 1129, 6808, 6514,
+    24, 580, 6850, 2226, 351, 320, 1537, 290, 1577, 7326, 212, 236, 343, 509, 1156, 42,
+    1118, 3137, 3239, 26, 7327, 234, 69, 773, 1025, 537, 134, 684, 431, 1087, 111, 3475,
+    735, 349, 1204, 68, 253, 253, 203, 285, 353, 37, 1390, 1112, 283, 345, 715, 21,
+    330, 14, 286, 646, 7323, 58, 451, 727, 35, 56, 215, 16, 7328, 243, 243, 7329,
+    390, 1180, 26, 7330, 7331, 7332, 362, 181, 7333, 1426, 850, 276, 7334, 7335, 282, 3,
+    263, 998, 1201, 431, 126, 7336, 7337, 390, 1926, 559, 5033, 234, 7338, 7339, 7340, 3299,
+    3958, 1300, 356, 559, 234, 1001, 7341, 1926, 7342, 3958, 3299, 578, 5888, 674, 367, 1948,
+    401, 297, 1024, 7343, 598, 598, 1126, 32, 990, 1026, 482, 288, 598, 3958, 7344, 545,
+    807, 970, 7344, 1026, 294, 362, 126, 7345, 5316, 240, 7346, 320, 128, 990, 7347, 7348,
+    1309, 3750, 7349, 414, 294, 55, 3958, 353, 5009, 1309, 7350, 903, 101, 353, 711, 168,
+    7351, 277, 1155, 1115, 253, 443, 19, 3958, 1309, 69, 7352, 108, 108, 1530, 709, 682,
+    286, 286, 7353, 615, 7354, 478, 3958, 682, 7355, 411, 411, 7077, 7083, 7356, 411, 317,
+    411, 411, 1115, 2750, 580, 580, 7357, 735, 7358, 7359, 974, 7360, 1083, 7361, 2033, 7362,
+    185, 909, 3745, 253, 3335, 7363, 60, 173, 7364, 909, 709, 218, 234, 103, 580, 7365,
+    569, 112, 103, 7366, 138, 967, 4095, 126, 1300, 387, 709, 7367, 218, 660, 390, 2433,
+    1434, 4443, 1116, 5720, 1670, 7368, 387, 7369, 7370, 7371, 7372, 677, 831, 1005, 46, 385,
+    1398, 7373, 3378, 7374, 1460, 7375, 578, 7376, 667, 3070, 808, 97, 1112, 1300, 2592, 7377,
+    7378, 7379, 3741, 7380, 1118, 7381, 219, 1061, 378, 126, 7382, 708, 7383, 143, 7384, 7385,
+    812, 7386, 1314, 7387, 90, 7388, 343, 7389, 316, 1510, 1059, 395, 7390, 86, 4350, 43,
+    879, 7391, 240, 7392, 659, 72, 746, 609, 682, 871, 568, 7393, 7394, 7395, 243, 1025,
+    7396, 7397, 7398, 263, 2929, 780, 378, 1100, 990, 653, 391, 244, 467, 1386, 1091, 414,
+    1204, 390, 1165, 628, 812, 1201, 635, 1097, 219, 7399, 7400, 210, 7401, 1199, 1019, 1019,
+    1100, 363, 530, 111, 6588, 197, 358, 45, 970, 253, 7402, 307, 1165, 468, 56, 143,
+    150, 790, 887, 395, 704, 7403, 532, 26, 598, 123, 7404, 1329, 7405, 262, 260, 1156,
+    111, 2483, 353, 283, 1314, 1599, 390, 850, 1430, 45, 210, 244, 282, 349, 746, 1155,
+    353, 7406, 4935, 4935, 210, 101, 7407, 209, 209, 390, 1705, 1183, 7408, 527, 435, 218,
+    1, 1, 168, 602, 149, 536, 343, 7409, 7410, 973, 7411, 277, 110, 1496, 826, 890,
+    2314, 327, 1588, 3715, 715, 14, 24, 1059, 1430, 7412, 970, 1527, 7413, 67, 7414, 480,
+    743, 253, 890, 1496, 1971, 443, 561, 190, 502, 526, 56, 545, 320, 744, 7415, 108,
+    167, 219, 789, 56, 56, 1431, 1128, 7416, 7417, 3479, 2330, 487, 1849, 177, 580, 320,
+    549, 504, 841, 241, 37, 527, 7418, 7419, 646, 2495, 2002, 157, 241, 54, 3672, 1450,
+    16, 1006, 1640, 879, 175, 3706, 715, 3407, 1294, 851, 7420, 1199, 726, 82, 1162, 206,
+    23, 56, 7369, 200, 1162, 55, 961, 7421, 1271, 249, 307, 149, 578, 934, 7422, 1395,
+    390, 2433, 387, 97, 831, 182, 667, 1116, 216, 7423, 1061, 1314, 578, 97, 1091, 343,
+    7384, 362, 879, 131, 90, 7361, 127, 1100, 243, 240, 568, 3706, 879, 175, 56, 1025,
+    1395, 1091, 307, 241, 1201, 111, 443, 744, 1199, 1059, 545, 812, 1019, 210, 391, 56,
+    358, 3003, 260, 253, 1156, 886, 598, 1329, 790, 532, 1314, 143, 1882, 45, 1430, 970,
+    1006, 47, 602, 726, 244, 890, 209, 834, 149, 1, 343, 527, 101, 1128, 4935, 715,
+    561, 253, 1149, 1640, 67, 713, 744, 190, 789, 1849, 177, 108, 526, 17, 708, 167,
+    851, 646, 157, 3090, 200, 1294, 1199, 660, 1467, 7424, 202, 378, 55, 7425, 6740, 735,
+    226, 7426, 400, 605, 748, 7427, 1430, 605, 483, 339, 589, 7428, 200, 205, 7429, 7222,
+    1460, 7430, 1430, 580, 5732, 7431, 7432, 556, 1645, 1129, 115, 339, 735, 7433, 608, 7434,
+    605, 1460, 1394, 20, 673, 23, 260, 7435, 378, 1118, 7436, 55, 737, 283, 673, 589,
+    139, 7437, 234, 967, 288, 509, 7438, 1510, 1134, 580, 88, 580, 1035, 7439, 580, 752,
+    599, 1273, 1588, 240, 764, 206, 845, 7440, 184, 234, 199, 682, 746, 5747, 6023, 6165,
+    134, 23, 908, 673, 1434, 7441, 990, 7442, 967, 871, 184, 1314, 5307, 470, 199, 56,
+    919, 7443, 1545, 7444, 1097, 1324, 580, 290, 68, 68, 602, 197, 68, 332, 554, 1882,
+    6949, 1163, 1163, 1203, 7445, 4, 45, 7446, 4445, 2420, 123, 131, 43, 378, 7447, 74,
+    7448, 205, 286, 289, 143, 123, 195, 744, 332, 2391, 1035, 589, 253, 111, 252, 285,
+    1331, 1159, 598, 997, 253, 1882, 26, 26, 7449, 970, 704, 395, 569, 6130, 1136, 509,
+    7450, 26, 3090, 400, 430, 599, 78, 54, 1183, 432, 1, 3534, 277, 244, 149, 7451,
+    482, 5130, 1705, 919, 218, 2196, 557, 7452, 598, 1153, 277, 599, 7453, 7454, 332, 395,
+    7455, 32, 7456, 599, 557, 1018, 940, 1430, 896, 207, 71, 323, 1496, 14, 169, 7457,
+    611, 7458, 64, 139, 139, 26, 7459, 735, 400, 55, 752, 113, 189, 7460, 26, 919,
+    24, 234, 674, 752, 230, 169, 835, 1274, 3402, 7461, 352, 1394, 78, 7462, 1083, 17,
+    7463, 126, 7464, 74, 390, 1097, 556, 248, 7465, 81, 1025, 26, 537, 126, 599, 37,
+    527, 586, 1025, 356, 735, 674, 473, 311, 311, 919, 2097, 3090, 7466, 599, 123, 835,
+    311, 966, 26, 819, 7467, 58, 4753, 6, 400, 271, 390, 660, 252, 536, 5442, 478,
+    200, 546, 599, 5127, 1126, 455, 580, 366, 350, 578, 896, 370, 215, 455, 599, 7468,
+    239, 111, 470, 7469, 1115, 400, 589, 483, 1430, 1394, 1035, 2139, 339, 115, 1510, 395,
+    578, 1035, 234, 88, 260, 967, 239, 589, 966, 1118, 81, 283, 240, 764, 990, 184,
+    1448, 673, 470, 245, 1097, 290, 111, 1324, 5675, 1163, 580, 311, 244, 4, 195, 54,
+    432, 598, 289, 1309, 286, 468, 1136, 589, 37, 277, 216, 149, 5675, 1, 139, 1153,
+    21, 26, 192, 940, 14, 169, 26, 744, 896, 405, 599, 1274, 1083, 356, 1025, 473,
+    580, 123, 615, 660, 599, 200, 363, 934, 215, 7470, 756, 260, 168, 6861, 1184, 168,
+    168, 1033, 1184, 7471, 273, 5581, 387, 7472, 5581, 402, 7473, 7474, 161, 744, 546, 546,
+    57, 14, 230, 391, 157, 532, 253, 123, 473, 7474, 1882, 7475, 16, 7476, 382, 546,
+    55, 1229, 2330, 157, 1882, 253, 260, 303, 5581, 1610, 1610, 216, 1082, 204, 1651, 1651,
+    204, 318, 881, 320, 908, 204, 7477, 704, 320, 1651, 7478, 30, 204, 320, 1651, 7479,
+    4853, 7480, 502, 546, 1156, 7481, 1090, 527, 527, 756, 2220, 900, 860, 245, 144, 1408,
+    245, 1162, 111, 1314, 1094, 4815, 4815, 26, 743, 222, 65, 905, 1005, 1005, 7482, 20,
+    171, 7483, 7484, 405, 323, 218, 320, 1346, 218, 960, 7485, 7486, 7487, 1001, 742, 697,
+    7488, 218, 7489, 7392, 7490, 7491, 742, 7492, 3226, 162, 218, 1001, 578, 7493, 721, 378,
+    226, 7494, 7495, 557, 295, 881, 2863, 1847, 746, 845, 3454, 561, 177, 1118, 126, 557,
+    3424, 1118, 7496, 436, 924, 872, 970, 104, 104, 7497, 1090, 1660, 589, 131, 1329, 674,
+    7498, 589, 136, 7499, 1162, 1162, 1090, 828, 5605, 3060, 143, 1118, 1134, 239, 997, 1059,
+    129, 1059, 1137, 1097, 332, 1882, 60, 7500, 218, 554, 81, 197, 619, 218, 82, 1843,
+    192, 219, 693, 7501, 21, 7502, 96, 7503, 300, 292, 5108, 300, 3812, 368, 7504, 1562,
+    56, 5094, 400, 400, 3447, 400, 322, 781, 781, 216, 4986, 7505, 96, 2206, 271, 7506,
+    7507, 967, 218, 7508, 545, 260, 260, 7509, 101, 96, 7510, 242, 473, 477, 386, 824,
+    3371, 2320, 6384, 532, 7511, 7512, 2047, 824, 218, 7513, 149, 575, 26, 7514, 1156, 2313,
+    2320, 815, 7515, 322, 781, 216, 248, 1283, 260, 2206, 545, 970, 2982, 57, 386, 2047,
+    575, 7516, 713, 7517, 7518, 218, 370, 7519, 128, 7520, 442, 260, 442, 370, 128, 321,
+    7521, 1, 177, 7522, 455, 4885, 216, 556, 7, 580, 375, 3696, 4745, 1610, 673, 566,
+    330, 752, 833, 252, 7523, 812, 63, 63, 5535, 250, 317, 317, 35, 35, 471, 762,
+    771, 763, 763, 30, 119, 762, 4498, 86, 744, 3115, 1533, 1941, 5450, 1431, 1432, 112,
+    5747, 2704, 1458, 1458, 7524, 38, 1626, 1626, 1095, 662, 735, 960, 960, 646, 6891, 304,
+    646, 31, 646, 556, 1823, 608, 1823, 608, 74, 801, 390, 609, 1705, 1, 390, 1527,
+    1430, 1025, 682, 1882, 332, 189, 819, 218, 470, 1199, 1222, 569, 7525, 684, 1, 1456,
+    110, 81, 556, 580, 1331, 970, 12, 1437, 400, 252, 391, 7526, 123, 411, 1686, 7527,
+    150, 3325, 253, 727, 1129, 26, 500, 7528, 1156, 463, 505, 756, 1072, 1273, 301, 975,
+    527, 5, 812, 1115, 45, 473, 252, 36, 7529, 127, 1313, 556, 7530, 260, 116, 878,
+    7531, 6, 659, 127, 6, 7532, 253, 167, 2206, 96, 354, 17, 11, 7532, 566, 243,
+    7533, 220, 1005, 197, 218, 532, 288, 222, 883, 128, 433, 171, 881, 1005, 1430, 1294,
+    997, 1670, 2563, 7534, 399, 106, 234, 297, 288, 414, 541, 6, 699, 126, 86, 134,
+    1770, 4108, 442, 316, 1001, 448, 909, 1026, 1003, 7535, 655, 658, 526, 35, 283, 2414,
+    41, 117, 1832, 430, 7536, 556, 754, 106, 554, 51, 283, 345, 781, 435, 1035, 1031,
+    740, 142, 57, 236, 7537, 283, 5976, 343, 332, 181, 126, 870, 754, 555, 727, 715,
+    400, 119, 38, 7538, 409, 409, 67, 1243, 1777, 1106, 2077, 935, 1830, 660, 3085, 343,
+    400, 811, 61, 168, 218, 283, 1346, 192, 147, 6, 1371, 239, 26, 820, 993, 978,
+    704, 556, 569, 390, 1004, 83, 80, 1801, 3225, 1117, 3510, 168, 67, 1018, 159, 1279,
+    580, 239, 7539, 134, 3465, 120, 2328, 6, 1757, 1441, 539, 1435, 716, 6, 363, 6279,
+    127, 430, 356, 7540, 2246, 640, 181, 2767, 363, 727, 564, 964, 168, 237, 7541, 569,
+    1355, 169, 96, 903, 727, 67, 727, 2313, 774, 7542, 7542, 836, 6279, 727, 234, 295,
+    662, 328, 286, 1845, 104, 21, 1650, 791, 493, 7543, 435, 62, 220, 1126, 7544, 26,
+    311, 53, 343, 4068, 103, 883, 169, 46, 993, 1430, 253, 737, 420, 15, 283, 7545,
+    1692, 1692, 205, 1087, 197, 639, 7546, 197, 492, 684, 262, 455, 443, 4073, 184, 569,
+    286, 608, 757, 301, 808, 1325, 353, 2972, 211, 1633, 530, 130, 580, 841, 72, 1126,
+    1115, 1926, 347, 3332, 356, 568, 459, 925, 81, 188, 773, 3292, 1294, 1115, 522, 122,
+    1819, 896, 883, 219, 7, 638, 1633, 638, 169, 261, 490, 47, 840, 1244, 1061, 47,
+    188, 861, 255, 1482, 71, 6, 1040, 1495, 14, 1565, 975, 333, 1602, 205, 385, 3812,
+    1325, 1128, 197, 283, 1552, 55, 55, 216, 571, 303, 43, 212, 19, 273, 1495, 1097,
+    726, 301, 316, 43, 473, 659, 118, 888, 1589, 89, 106, 574, 1199, 283, 619, 3370,
+    2750, 369, 216, 883, 1087, 143, 75, 1115, 640, 26, 598, 628, 1728, 1448, 316, 646,
+    978, 143, 4073, 400, 1134, 6, 1263, 1588, 673, 1116, 603, 311, 764, 766, 322, 1208,
+    2466, 111, 295, 213, 583, 791, 55, 614, 7547, 270, 7548, 109, 7549, 7550, 4264, 249,
+    1090, 6, 473, 11, 841, 180, 614, 1883, 619, 175, 539, 7233, 441, 522, 333, 130,
+    807, 1035, 172, 1628, 764, 332, 3872, 6591, 55, 1043, 1025, 130, 7551, 3872, 7552, 2294,
+    627, 697, 679, 322, 326, 1602, 2216, 557, 8, 870, 554, 628, 7553, 1588, 1225, 55,
+    7554, 313, 119, 195, 111, 883, 55, 546, 7555, 355, 7556, 2328, 870, 841, 815, 233,
+    1562, 815, 490, 647, 1573, 7557, 3989, 7558, 197, 192, 7559, 679, 490, 764, 400, 80,
+    660, 2645, 80, 252, 509, 554, 4968, 7560, 615, 775, 286, 72, 702, 1728, 55, 1183,
+    1001, 682, 599, 55, 1001, 260, 326, 47, 1115, 55, 276, 786, 19, 896, 7561, 807,
+    969, 74, 7562, 26, 203, 26, 1005, 702, 1201, 203, 110, 2425, 3128, 873, 1324, 41,
+    463, 1441, 71, 323, 64, 70, 743, 1005, 1394, 574, 993, 639, 301, 7563, 993, 925,
+    488, 556, 181, 477, 3989, 815, 7564, 80, 179, 815, 203, 636, 1633, 1105, 180, 419,
+    347, 2206, 391, 1040, 1448, 1576, 605, 239, 1479, 191, 513, 771, 318, 362, 343, 473,
+    1026, 509, 38, 1448, 144, 339, 7565, 6, 3408, 105, 2286, 139, 255, 7566, 65, 101,
+    545, 93, 255, 344, 1416, 659, 2139, 93, 149, 640, 2511, 101, 149, 4478, 1040, 4,
+    26, 908, 779, 6217, 1059, 83, 126, 255, 638, 65, 522, 1184, 620, 4073, 1273, 7567,
+    845, 2098, 779, 169, 1208, 1482, 746, 583, 390, 77, 216, 2320, 1479, 78, 290, 7568,
+    97, 2284, 4598, 7569, 1398, 709, 4448, 78, 184, 3097, 96, 556, 1830, 1845, 1832, 56,
+    126, 197, 169, 68, 356, 273, 332, 455, 907, 131, 1032, 26, 850, 150, 870, 620,
+    42, 7570, 1025, 1059, 6, 323, 557, 231, 203, 490, 26, 55, 1098, 155, 126, 737,
+    1153, 55, 409, 14, 851, 203, 6, 1770, 7571, 203, 1229, 745, 819, 455, 245, 55,
+    343, 556, 1018, 2313, 55, 554, 744, 1624, 640, 2135, 580, 539, 1390, 919, 331, 169,
+    2116, 646, 7572, 934, 583, 7573, 19, 2148, 1005, 378, 883, 907, 789, 197, 127, 815,
+    286, 236, 328, 322, 328, 107, 300, 111, 31, 495, 831, 126, 828, 687, 1431, 509,
+    202, 7574, 78, 7575, 198, 906, 273, 290, 387, 2698, 599, 88, 7576, 50, 7577, 2466,
+    139, 4, 584, 20, 169, 202, 1026, 118, 764, 628, 819, 505, 868, 127, 7578, 619,
+    502, 300, 7579, 673, 819, 1112, 1059, 103, 455, 7580, 495, 906, 906, 405, 845, 756,
+    940, 1906, 1, 2075, 993, 2580, 533, 216, 1686, 303, 110, 7581, 149, 1059, 1670, 20,
+    7582, 304, 1021, 509, 504, 57, 811, 185, 251, 414, 576, 7583, 50, 791, 3847, 252,
+    76, 260, 7584, 366, 7585, 1028, 387, 149, 34, 808, 824, 96, 2864, 430, 2066, 7586,
+    663, 338, 255, 245, 405, 405, 1117, 26, 2929, 903, 638, 263, 496, 4633, 47, 56,
+    26, 6, 378, 943, 943, 358, 2758, 537, 1085, 216, 1222, 754, 130, 236, 338, 1882,
+    203, 2697, 559, 554, 600, 944, 530, 209, 2176, 614, 544, 159, 997, 2624, 42, 1640,
+    1661, 7587, 197, 1229, 89, 277, 86, 389, 2739, 3281, 658, 1847, 1670, 1347, 808, 1562,
+    1323, 4, 7588, 7589, 1222, 7590, 131, 80, 721, 764, 2580, 945, 7591, 762, 1116, 353,
+    459, 118, 2313, 197, 2120, 368, 143, 134, 203, 26, 556, 216, 169, 896, 7592, 7593,
+    7482, 20, 7594, 203, 1431, 1430, 7595, 382, 431, 879, 7596, 86, 7597, 4677, 7598, 304,
+    1562, 662, 495, 11, 1510, 46, 311, 7599, 290, 4095, 7600, 1072, 554, 599, 307, 9,
+    673, 290, 96, 7601, 390, 576, 642, 1443, 1167, 64, 1355, 566, 48, 1532, 169, 7602,
+    2317, 234, 7603, 192, 263, 71, 362, 727, 11, 464, 8, 168, 1070, 708, 1225, 807,
+    210, 197, 7604, 7605, 7606, 2587, 1440, 701, 4073, 673, 587, 382, 504, 210, 529, 134,
+    111, 216, 1300, 7607, 45, 1754, 78, 260, 100, 218, 72, 7603, 104, 492, 1020, 1006,
+    919, 260, 141, 53, 160, 21, 111, 7062, 70, 198, 8, 7608, 744, 262, 6, 2033,
+    400, 26, 678, 61, 300, 317, 5, 126, 432, 7075, 532, 147, 168, 1296, 580, 7609,
+    505, 625, 628, 80, 7610, 1510, 628, 111, 811, 8, 2864, 416, 3131, 370, 430, 815,
+    260, 487, 1155, 709, 815, 2466, 455, 2097, 96, 1115, 56, 455, 744, 7611, 83, 61,
+    367, 80, 2864, 182, 144, 1686, 375, 575, 169, 74, 3677, 405, 231, 1953, 1448, 7612,
+    504, 1059, 51, 276, 283, 7, 7613, 149, 180, 64, 7614, 134, 386, 220, 462, 51,
+    486, 1847, 206, 390, 1043, 682, 1882, 1581, 932, 539, 56, 4677, 169, 197, 548, 835,
+    390, 682, 283, 1430, 780, 7615, 243, 402, 351, 627, 411, 411, 362, 150, 430, 462,
+    682, 1309, 1106, 7616, 96, 169, 1552, 801, 4146, 7617, 4750, 370, 65, 237, 974, 349,
+    111, 2298, 840, 744, 1347, 168, 1414, 96, 51, 7618, 7619, 3131, 546, 615, 295, 23,
+    7620, 118, 405, 147, 2098, 26, 3281, 713, 1020, 7621, 932, 301, 1562, 1770, 541, 1155,
+    399, 149, 47, 1640, 210, 110, 168, 660, 169, 210, 1105, 100, 411, 435, 7622, 1402,
+    473, 455, 7623, 1311, 742, 1199, 1801, 1402, 1402, 7624, 455, 97, 773, 7625, 7626, 249,
+    546, 47, 249, 433, 255, 944, 216, 400, 628, 791, 659, 2302, 919, 762, 559, 101,
+    719, 1766, 195, 245, 1519, 216, 66, 1208, 580, 1323, 326, 542, 556, 1394, 109, 462,
+    260, 7627, 18, 442, 589, 686, 1005, 97, 4, 2330, 826, 62, 812, 580, 323, 737,
+    97, 773, 7628, 97, 82, 7629, 249, 7630, 2859, 4099, 195, 332, 1705, 1225, 831, 2697,
+    463, 7631, 26, 362, 351, 1537, 7632, 127, 110, 1768, 26, 1006, 7633, 1005, 430, 561,
+    1645, 7634, 304, 199, 423, 1, 925, 1020, 1021, 436, 26, 1021, 682, 38, 2929, 56,
+    2482, 97, 2294, 687, 773, 580, 181, 290, 1279, 7041, 662, 351, 2739, 345, 828, 7635,
+    765, 342, 974, 462, 727, 41, 277, 1660, 26, 242, 836, 615, 114, 658, 813, 499,
+    1225, 283, 1979, 5108, 114, 351, 533, 1728, 575, 599, 6419, 7636, 7637, 37, 576, 1431,
+    365, 1093, 532, 462, 61, 340, 283, 197, 637, 1273, 204, 1025, 3424, 573, 682, 561,
+    607, 171, 1545, 7638, 487, 248, 619, 565, 177, 800, 150, 83, 357, 6876, 2135, 1122,
+    7562, 297, 1607, 283, 276, 107, 4677, 276, 168, 637, 2965, 1565, 390, 83, 4964, 420,
+    580, 754, 316, 682, 957, 619, 935, 90, 261, 459, 1126, 91, 7639, 1159, 682, 1552,
+    7640, 420, 1025, 118, 306, 548, 485, 800, 1159, 248, 40, 119, 1325, 67, 112, 2277,
+    990, 430, 708, 580, 660, 7590, 1761, 8, 3, 7641, 684, 7642, 1718, 8, 260, 353,
+    598, 1134, 1702, 7643, 1143, 419, 566, 7644, 791, 1026, 636, 1021, 773, 636, 283, 765,
+    48, 45, 37, 745, 322, 1271, 462, 569, 603, 869, 970, 1782, 775, 7210, 2469, 197,
+    7645, 851, 468, 46, 7646, 197, 276, 1510, 7647, 220, 96, 7648, 95, 65, 5581, 812,
+    197, 3793, 4991, 628, 639, 177, 177, 101, 65, 7649, 101, 198, 900, 371, 7650, 970,
+    127, 1371, 411, 1611, 218, 126, 771, 735, 659, 1129, 327, 128, 363, 366, 640, 791,
+    7651, 1847, 556, 78, 1116, 614, 640, 354, 2120, 1059, 327, 252, 126, 1645, 3289, 147,
+    410, 964, 455, 5976, 807, 1545, 96, 252, 220, 764, 200, 2099, 7652, 5732, 585, 23,
+    757, 1273, 61, 65, 990, 76, 405, 628, 56, 2482, 129, 1933, 171, 5581, 628, 95,
+    150, 625, 95, 1830, 625, 530, 67, 114, 3332, 1831, 546, 2757, 65, 347, 636, 96,
+    1271, 7653, 295, 274, 2420, 200, 203, 619, 282, 38, 353, 378, 924, 576, 5013, 447,
+    673, 282, 2697, 1430, 990, 451, 1025, 993, 234, 435, 2047, 375, 96, 119, 1271, 59,
+    3115, 411, 113, 545, 1430, 4275, 1640, 327, 283, 820, 2839, 126, 5375, 378, 832, 283,
+    1602, 95, 639, 820, 585, 172, 1300, 3424, 7654, 1386, 350, 1450, 3996, 484, 3510, 7655,
+    7656, 239, 740, 1832, 1519, 589, 1283, 830, 658, 7657, 279, 1856, 4095, 815, 347, 78,
+    576, 1602, 430, 4095, 64, 347, 7542, 110, 589, 1964, 127, 96, 303, 234, 96, 55,
+    220, 42, 808, 1766, 800, 58, 3872, 326, 1162, 7658, 26, 3142, 571, 61, 159, 7659,
+    7660, 7661, 7661, 1426, 7662, 1528, 111, 1028, 11, 38, 378, 3958, 56, 253, 65, 1747,
+    240, 957, 568, 7663, 131, 222, 169, 159, 301, 544, 1021, 7664, 1651, 43, 7665, 339,
+    1443, 318, 3543, 779, 184, 1431, 1208, 7666, 20, 544, 7667, 625, 7668, 332, 7669, 721,
+    2739, 30, 509, 580, 1451, 127, 7670, 924, 2757, 274, 580, 615, 1201, 460, 1118, 1279,
+    609, 7671, 870, 127, 197, 324, 476, 536, 67, 476, 1394, 7164, 188, 149, 463, 149,
+    533, 118, 1261, 4068, 56, 1119, 253, 903, 400, 7672, 1095, 478, 80, 896, 1021, 492,
+    509, 55, 1059, 576, 311, 204, 7673, 4068, 851, 56, 1247, 204, 2360, 580, 571, 459,
+    7674, 1046, 7675, 6, 370, 693, 576, 2833, 7676, 15, 580, 48, 21, 737, 737, 7677,
+    744, 737, 687, 1472, 1021, 1836, 382, 830, 660, 573, 682, 805, 168, 471, 1100, 1640,
+    2098, 2757, 882, 1122, 924, 2099, 283, 444, 55, 53, 636, 3332, 55, 21, 402, 129,
+    138, 432, 990, 7678, 4414, 1398, 97, 1308, 502, 34, 234, 91, 3335, 1650, 3281, 2147,
+    7679, 7680, 3335, 902, 1655, 277, 242, 2929, 869, 244, 367, 491, 2414, 147, 157, 101,
+    1472, 7681, 7, 7682, 1166, 513, 1633, 7683, 311, 462, 206, 2298, 85, 212, 320, 1162,
+    2313, 1533, 4530, 615, 6, 1324, 7684, 72, 11, 50, 42, 870, 47, 239, 7685, 779,
+    509, 840, 1559, 294, 556, 219, 1699, 399, 909, 807, 239, 436, 294, 2486, 583, 1538,
+    902, 171, 332, 197, 237, 322, 400, 578, 55, 556, 746, 123, 242, 101, 1323, 149,
+    7686, 476, 21, 2294, 1204, 7687, 245, 566, 1336, 1032, 200, 1167, 46, 343, 523, 30,
+    438, 80, 301, 487, 192, 206, 2206, 2580, 212, 220, 332, 131, 708, 276, 380, 138,
+    1408, 107, 7688, 382, 1323, 1230, 242, 12, 65, 237, 270, 727, 110, 539, 1128, 57,
+    123, 1336, 104, 243, 2172, 7689, 1371, 64, 630, 808, 77, 1580, 589, 2128, 1097, 2482,
+    7690, 5144, 234, 185, 1323, 107, 763, 74, 7691, 7692, 1032, 55, 42, 127, 294, 7,
+    38, 1323, 509, 248, 462, 2216, 970, 1117, 242, 832, 460, 271, 509, 768, 1323, 157,
+    253, 2929, 658, 536, 107, 77, 974, 522, 1416, 34, 1032, 658, 169, 1118, 47, 61,
+    482, 44, 282, 218, 535, 451, 277, 7693, 791, 270, 161, 47, 273, 77, 1059, 1607,
+    273, 321, 401, 7694, 5106, 101, 801, 1019, 883, 1085, 1645, 67, 919, 59, 340, 253,
+    38, 815, 249, 746, 197, 940, 2414, 107, 1117, 1728, 1754, 3424, 126, 1005, 42, 7695,
+    57, 6, 728, 658, 355, 279, 7696, 157, 529, 366, 200, 2328, 658, 82, 967, 796,
+    47, 366, 202, 488, 1292, 674, 779, 234, 3328, 403, 385, 659, 539, 3424, 5, 175,
+    276, 484, 104, 399, 283, 56, 120, 96, 2864, 7164, 2898, 2580, 1126, 252, 521, 127,
+    907, 420, 1068, 126, 439, 658, 521, 180, 1408, 1300, 4388, 402, 55, 1261, 1519, 366,
+    149, 1093, 7697, 1040, 242, 684, 775, 168, 546, 242, 1068, 763, 1450, 26, 154, 1068,
+    366, 48, 55, 111, 58, 79, 7698, 242, 400, 366, 368, 157, 260, 311, 580, 2704,
+    1328, 682, 580, 585, 41, 642, 332, 118, 26, 197, 80, 841, 17, 1430, 1882, 1105,
+    344, 1115, 685, 7699, 397, 64, 378, 378, 74, 4436, 338, 253, 7700, 1313, 668, 7701,
+    1374, 726, 3510, 128, 1588, 671, 727, 28, 556, 211, 743, 33, 112, 1134, 585, 333,
+    161, 126, 1906, 903, 378, 7702, 1119, 945, 343, 477, 504, 181, 7, 110, 6, 245,
+    490, 26, 1472, 78, 7703, 248, 686, 2972, 105, 954, 26, 1097, 289, 118, 301, 1331,
+    86, 64, 954, 462, 80, 286, 1628, 7704, 744, 1134, 467, 185, 110, 908, 149, 7705,
+    1155, 35, 169, 1589, 300, 26, 1143, 974, 599, 5711, 5427, 907, 1005, 363, 21, 20,
+    122, 64, 2839, 1263, 111, 283, 61, 1039, 1313, 295, 283, 591, 549, 144, 356, 490,
+    1279, 841, 709, 75, 1670, 1020, 283, 41, 105, 640, 1261, 841, 443, 1019, 288, 107,
+    443, 1031, 562, 620, 488, 180, 833, 218, 53, 935, 207, 126, 529, 61, 376, 311,
+    1006, 896, 192, 439, 7542, 100, 557, 1053, 663, 263, 168, 1061, 645, 655, 117, 7706,
+    200, 1313, 7707, 7708, 172, 230, 7709, 347, 1640, 735, 322, 978, 218, 26, 26, 7710,
+    64, 585, 1189, 107, 1434, 1020, 198, 220, 7711, 1020, 546, 131, 333, 808, 752, 173,
+    26, 218, 902, 67, 385, 7712, 568, 186, 129, 573, 323, 408, 889, 932, 219, 7713,
+    1611, 869, 323, 45, 85, 1882, 1046, 7714, 242, 7715, 127, 107, 548, 7716, 172, 1640,
+    1921, 815, 640, 566, 2284, 496, 169, 303, 1338, 7717, 708, 146, 277, 3239, 252, 7718,
+    7719, 1155, 773, 630, 41, 1555, 69, 7720, 283, 502, 2965, 61, 207, 98, 990, 53,
+    451, 975, 1005, 405, 67, 1770, 2864, 117, 390, 123, 630, 243, 1090, 385, 356, 1162,
+    222, 1095, 390, 18, 67, 635, 1308, 286, 728, 1256, 182, 273, 619, 1097, 5, 197,
+    1562, 96, 239, 111, 286, 260, 470, 636, 608, 303, 609, 345, 320, 378, 580, 7721,
+    5309, 56, 7722, 2482, 639, 253, 3543, 1307, 708, 283, 168, 236, 7723, 286, 55, 932,
+    26, 102, 26, 673, 47, 845, 26, 2704, 20, 37, 127, 781, 239, 64, 311, 3211,
+    127, 639, 288, 756, 1559, 277, 556, 390, 1199, 832, 4530, 276, 19, 378, 45, 499,
+    568, 26, 536, 1118, 1448, 169, 642, 385, 826, 14, 589, 7724, 608, 3759, 556, 283,
+    146, 686, 744, 713, 1832, 311, 172, 451, 1005, 231, 530, 1279, 126, 422, 1448, 586,
+    177, 290, 7725, 872, 101, 473, 67, 323, 1589, 566, 4, 1118, 779, 197, 704, 886,
+    277, 1018, 375, 828, 1984, 378, 171, 2066, 1002, 1770, 1087, 580, 107, 1770, 785, 375,
+    571, 26, 493, 387, 493, 7726, 1167, 900, 351, 1026, 83, 248, 807, 2191, 7727, 5,
+    263, 902, 903, 35, 718, 3, 652, 640, 7, 234, 7728, 1545, 4281, 7729, 292, 97,
+    260, 735, 395, 658, 1660, 255, 351, 652, 1328, 7730, 7731, 658, 371, 390, 434, 29,
+    580, 117, 7732, 746, 580, 2315, 1083, 715, 580, 1777, 686, 2330, 1777, 2320, 3501, 7733,
+    1018, 450, 2483, 1126, 283, 330, 990, 431, 571, 952, 83, 619, 1306, 488, 6, 61,
+    646, 154, 6, 96, 283, 5246, 2089, 311, 1018, 37, 577, 763, 4815, 619, 97, 580,
+    90, 556, 368, 2313, 26, 126, 709, 26, 748, 128, 26, 283, 662, 56, 340, 1702,
+    227, 7734, 26, 226, 1229, 557, 1755, 204, 21, 197, 901, 56, 2750, 1005, 668, 7735,
+    219, 7736, 3578, 323, 45, 699, 245, 7561, 833, 491, 148, 1699, 2294, 7737, 26, 414,
+    21, 826, 167, 202, 521, 708, 1230, 50, 408, 7738, 283, 124, 1201, 815, 619, 56,
+    1984, 297, 721, 316, 3472, 323, 37, 283, 200, 113, 870, 576, 7499, 42, 7739, 219,
+    1323, 311, 28, 198, 1371, 3292, 53, 395, 542, 549, 389, 460, 748, 1797, 576, 137,
+    1323, 81, 5, 580, 111, 118, 907, 197, 1115, 7740, 286, 1638, 489, 653, 387, 4,
+    7741, 2809, 658, 1655, 78, 282, 1024, 521, 3475, 3475, 3120, 3239, 919, 1355, 1001, 323,
+    1028, 118, 154, 442, 105, 192, 1324, 110, 248, 104, 21, 1031, 294, 1355, 7742, 6,
+    246, 38, 684, 658, 118, 294, 7743, 1201, 96, 7744, 2482, 953, 307, 43, 378, 1472,
+    1083, 97, 3475, 1273, 111, 820, 111, 182, 534, 107, 7745, 113, 7746, 206, 1527, 180,
+    630, 602, 61, 615, 1728, 662, 1024, 430, 195, 1116, 701, 343, 177, 79, 549, 3996,
+    290, 454, 1519, 851, 4815, 197, 1519, 26, 602, 1510, 490, 80, 263, 580, 2100, 55,
+    283, 72, 400, 148, 774, 127, 2750, 628, 7747, 17, 658, 74, 2486, 252, 7748, 4068,
+    405, 1883, 1674, 206, 610, 682, 91, 7749, 1856, 708, 51, 833, 290, 1083, 644, 207,
+    1577, 625, 171, 1024, 111, 652, 7750, 65, 697, 7751, 14, 363, 548, 3085, 740, 55,
+    1283, 96, 96, 348, 370, 1527, 42, 365, 775, 801, 668, 7, 571, 679, 1222, 246,
+    7752, 108, 756, 239, 524, 219, 2704, 997, 2704, 7753, 144, 321, 455, 576, 81, 745,
+    702, 744, 1271, 7754, 1021, 138, 832, 957, 74, 1430, 294, 993, 660, 1006, 1271, 1032,
+    283, 286, 64, 263, 702, 231, 1025, 17, 561, 993, 65, 709, 4, 556, 3477, 78,
+    460, 1271, 3706, 127, 1059, 441, 149, 200, 200, 55, 171, 405, 757, 709, 1134, 26,
+    356, 744, 168, 332, 42, 4, 203, 528, 55, 583, 263, 709, 1019, 260, 1117, 290,
+    4935, 7070, 253, 646, 400, 7755, 26, 556, 2135, 18, 1670, 684, 340, 1118, 6, 295,
+    1128, 125, 7756, 7757, 308, 7758, 925, 2176, 252, 609, 681, 234, 16, 521, 354, 127,
+    2176, 7759, 199, 887, 7760, 123, 7761, 2309, 290, 628, 492, 2298, 529, 745, 7762, 612,
+    1018, 245, 7763, 497, 127, 896, 554, 7764, 7765, 815, 899, 1801, 197, 96, 65, 297,
+    7766, 56, 2294, 942, 762, 535, 564, 495, 234, 662, 57, 311, 111, 754, 1346, 1279,
+    1323, 7767, 7768, 907, 903, 1032, 7769, 1024, 7770, 7, 226, 836, 1032, 7771, 1747, 580,
+    180, 1105, 966, 841, 7772, 283, 4331, 263, 1283, 218, 1984, 105, 378, 2697, 826, 762,
+    432, 169, 131, 198, 48, 2420, 6, 127, 970, 3239, 7773, 3239, 7774, 870, 937, 169,
+    763, 189, 807, 796, 436, 252, 522, 815, 2880, 589, 3080, 7775, 544, 708, 2739, 150,
+    1602, 182, 47, 7776, 126, 1431, 3264, 828, 3989, 609, 7015, 2326, 7777, 529, 583, 1440,
+    294, 1111, 744, 854, 342, 36, 737, 283, 1090, 3957, 283, 7778, 126, 952, 868, 2880,
+    320, 569, 82, 7779, 136, 1602, 181, 820, 7780, 2739, 7781, 586, 142, 38, 416, 1811,
+    1019, 7782, 95, 765, 1057, 7783, 47, 610, 139, 907, 609, 775, 202, 740, 5299, 35,
+    1331, 2330, 14, 198, 727, 1001, 557, 7784, 127, 55, 685, 338, 231, 356, 385, 95,
+    1103, 243, 1670, 635, 370, 234, 1993, 840, 197, 390, 4053, 96, 7054, 1006, 231, 328,
+    1002, 1018, 260, 366, 68, 159, 1581, 1640, 150, 134, 192, 470, 86, 134, 7705, 360,
+    74, 1111, 326, 2425, 580, 4524, 253, 56, 7785, 1430, 226, 974, 1098, 2898, 3543, 253,
+    530, 61, 7786, 1059, 283, 253, 684, 841, 55, 472, 2472, 83, 569, 1430, 7787, 7788,
+    4935, 1116, 391, 807, 222, 71, 1902, 276, 2480, 1430, 7789, 400, 362, 528, 1541, 526,
+    356, 149, 7790, 112, 653, 130, 537, 901, 403, 571, 320, 768, 35, 1541, 1019, 56,
+    7791, 4011, 966, 1386, 623, 198, 1325, 2120, 536, 815, 198, 1485, 1581, 365, 105, 1331,
+    1448, 779, 403, 473, 386, 526, 159, 1246, 659, 702, 390, 114, 371, 819, 530, 268,
+    865, 218, 175, 3510, 253, 903, 203, 253, 198, 123, 807, 9, 7792, 716, 1460, 363,
+    375, 3096, 1159, 7793, 273, 279, 472, 7, 2098, 1279, 1747, 530, 530, 386, 813, 82,
+    439, 1199, 443, 852, 230, 530, 1510, 1441, 7794, 7795, 40, 943, 180, 378, 55, 858,
+    1374, 83, 242, 1768, 7796, 3347, 378, 126, 1347, 1430, 1180, 429, 279, 111, 126, 1296,
+    273, 1115, 306, 356, 575, 130, 524, 682, 841, 1121, 7797, 306, 1482, 1279, 1126, 399,
+    190, 81, 662, 662, 549, 100, 172, 7797, 159, 7798, 1519, 1137, 1832, 7013, 575, 662,
+    148, 74, 1519, 7799, 74, 301, 7800, 179, 218, 7801, 7169, 404, 735, 32, 218, 390,
+    244, 343, 616, 218, 502, 695, 851, 735, 277, 1087, 7802, 768, 56, 5307, 56, 1902,
+    1200, 118, 659, 31, 1325, 1004, 129, 584, 7803, 7804, 554, 566, 556, 709, 147, 549,
+    4068, 925, 7805, 197, 896, 872, 578, 1444, 35, 619, 585, 2294, 212, 1180, 7806, 7807,
+    7808, 327, 1204, 1201, 965, 378, 1032, 288, 1098, 45, 1555, 177, 499, 1274, 20, 745,
+    38, 157, 1451, 400, 444, 438, 47, 708, 495, 1426, 919, 1979, 351, 2426, 1426, 118,
+    569, 715, 7809, 186, 1167, 924, 536, 43, 1019, 1325, 1323, 695, 544, 1421, 444, 561,
+    105, 363, 900, 7809, 11, 1230, 7810, 177, 301, 311, 905, 580, 288, 51, 1702, 5930,
+    61, 206, 1856, 1882, 53, 682, 7811, 290, 38, 126, 17, 55, 242, 687, 1129, 455,
+    1705, 55, 2330, 260, 468, 813, 241, 1283, 7812, 2839, 478, 316, 665, 607, 580, 7813,
+    1408, 716, 399, 754, 101, 416, 2245, 1979, 7814, 134, 3299, 186, 1831, 277, 7815, 252,
+    197, 455, 79, 4987, 7816, 7817, 472, 358, 1035, 841, 7818, 115, 430, 1059, 97, 1072,
+    389, 169, 569, 216, 681, 252, 78, 7819, 455, 770, 1633, 139, 6, 7820, 88, 111,
+    3686, 154, 6, 64, 405, 774, 200, 7637, 38, 1159, 568, 1018, 378, 488, 167, 805,
+    115, 367, 554, 64, 1408, 414, 251, 545, 1275, 28, 754, 248, 103, 7821, 768, 131,
+    7822, 815, 97, 646, 436, 1421, 779, 129, 2483, 198, 583, 45, 658, 1018, 1296, 1754,
+    7070, 131, 2704, 161, 1525, 122, 365, 4387, 389, 5, 282, 105, 7823, 138, 56, 6,
+    580, 2972, 318, 234, 1143, 603, 191, 775, 1766, 7824, 1279, 244, 419, 965, 2438, 198,
+    7825, 332, 704, 463, 239, 1501, 556, 462, 1167, 522, 131, 1611, 743, 1184, 169, 244,
+    23, 3049, 241, 181, 462, 7826, 1782, 906, 138, 7827, 86, 2755, 820, 276, 283, 744,
+    576, 7828, 907, 1777, 811, 286, 255, 3872, 1294, 468, 4430, 126, 405, 204, 7829, 297,
+    574, 111, 699, 45, 248, 744, 7830, 242, 2426, 6, 71, 639, 78, 1143, 127, 628,
+    277, 476, 1156, 7831, 828, 970, 970, 127, 81, 1705, 318, 1813, 7832, 82, 709, 23,
+    4799, 639, 65, 7185, 432, 26, 5210, 652, 745, 80, 198, 602, 1832, 1699, 327, 78,
+    1801, 290, 169, 7603, 134, 4414, 277, 7833, 317, 195, 1019, 439, 271, 7834, 569, 47,
+    1306, 254, 2298, 83, 58, 169, 100, 205, 38, 639, 206, 168, 294, 1460, 1279, 1985,
+    60, 206, 943, 443, 539, 86, 497, 126, 218, 2482, 1176, 2010, 1229, 744, 277, 682,
+    495, 37, 1025, 2965, 693, 1801, 474, 363, 204, 123, 429, 14, 1018, 1761, 2426, 3146,
+    1494, 435, 111, 3894, 1545, 2755, 356, 159, 7835, 3076, 95, 286, 55, 959, 545, 14,
+    1097, 627, 993, 390, 775, 1040, 7070, 197, 1153, 4310, 435, 7836, 2833, 234, 1156, 14,
+    72, 7837, 366, 5299, 168, 7070, 1119, 150, 780, 580, 26, 833, 520, 735, 148, 303,
+    47, 91, 82, 326, 2100, 303, 366, 1019, 779, 197, 197, 3691, 693, 7838, 1847, 11,
+    807, 331, 197, 197, 544, 7839, 197, 390, 65, 620, 954, 973, 7840, 149, 3691, 124,
+    532, 7841, 126, 997, 660, 488, 57, 89, 185, 1044, 286, 6, 529, 286, 475, 786,
+    171, 405, 693, 952, 488, 65, 970, 260, 7842, 580, 1451, 4490, 2216, 533, 925, 169,
+    719, 3872, 282, 282, 488, 805, 1229, 978, 7, 7843, 2120, 96, 967, 925, 1222, 978,
+    90, 1431, 1513, 195, 271, 201, 188, 7844, 925, 294, 1430, 7845, 65, 118, 78, 26,
+    957, 455, 365, 149, 197, 197, 627, 7846, 505, 505, 86, 1105, 18, 1797, 226, 7374,
+    168, 303, 126, 2758, 934, 970, 1902, 887, 283, 919, 86, 171, 120, 3475, 42, 2120,
+    126, 1522, 26, 455, 762, 138, 1279, 207, 207, 126, 851, 104, 791, 609, 2965, 104,
+    55, 7847, 1636, 702, 294, 114, 26, 47, 1152, 531, 227, 851, 3803, 7848, 993, 97,
+    573, 1059, 488, 1564, 219, 531, 1005, 1117, 925, 841, 290, 4176, 485, 134, 7849, 1159,
+    75, 965, 1576, 7077, 467, 55, 2098, 47, 130, 40, 105, 2727, 98, 283, 104, 5086,
+    21, 374, 197, 347, 1923, 529, 69, 57, 2469, 26, 2740, 455, 7850, 1699, 11, 172,
+    100, 285, 114, 549, 61, 104, 3803, 349, 60, 4935, 7851, 3803, 7852, 123, 484, 607,
+    841, 934, 697, 240, 960, 1747, 236, 107, 618, 646, 126, 708, 252, 836, 1813, 93,
+    7853, 1699, 635, 3076, 580, 1516, 2313, 1768, 4073, 205, 382, 131, 7854, 8, 185, 7855,
+    531, 488, 495, 1004, 568, 375, 790, 195, 104, 277, 771, 469, 37, 8, 786, 513,
+    26, 1347, 1046, 370, 111, 55, 1845, 209, 908, 658, 637, 7, 20, 559, 934, 547,
+    131, 7856, 289, 556, 639, 110, 474, 1326, 638, 416, 7857, 400, 671, 181, 7858, 2148,
+    807, 640, 4510, 5613, 5613, 545, 260, 997, 19, 807, 21, 1032, 362, 408, 2898, 524,
+    378, 532, 263, 23, 775, 126, 482, 1021, 7859, 290, 2898, 41, 303, 402, 1830, 2755,
+    320, 111, 687, 127, 127, 812, 744, 126, 858, 338, 468, 924, 923, 313, 382, 1602,
+    687, 1018, 660, 105, 970, 56, 253, 748, 414, 1021, 21, 1743, 313, 86, 1602, 111,
+    7860, 353, 1404, 1770, 541, 242, 1830, 547, 126, 640, 239, 491, 671, 37, 709, 1021,
+    400, 239, 7861, 1024, 889, 490, 252, 117, 127, 239, 239, 363, 7862, 544, 3424, 1001,
+    7863, 1018, 1660, 239, 242, 43, 182, 889, 552, 526, 288, 7864, 7865, 56, 773, 671,
+    1028, 104, 625, 14, 350, 1564, 236, 2433, 19, 965, 7866, 614, 203, 351, 7867, 7868,
+    1019, 7869, 659, 4, 801, 7870, 147, 566, 554, 216, 2580, 198, 1152, 576, 1021, 765,
+    462, 169, 220, 127, 7871, 548, 7872, 970, 167, 894, 4997, 168, 655, 1581, 675, 7873,
+    2739, 3085, 1203, 1843, 7874, 105, 429, 246, 100, 331, 7164, 7875, 1431, 3630, 3630, 1180,
+    7768, 7876, 1005, 1274, 486, 3085, 7877, 318, 37, 316, 569, 410, 835, 1019, 1118, 144,
+    26, 552, 1134, 943, 26, 112, 561, 7878, 185, 1545, 830, 11, 1039, 463, 495, 442,
+    773, 1328, 3076, 1655, 1581, 244, 6, 331, 142, 943, 12, 925, 1090, 203, 378, 526,
+    819, 169, 7879, 2414, 1180, 589, 351, 954, 1413, 7, 482, 277, 819, 642, 323, 244,
+    808, 7603, 4, 585, 531, 374, 367, 1025, 1782, 573, 7880, 113, 509, 7881, 1882, 1300,
+    496, 943, 430, 3146, 378, 932, 7, 1440, 2839, 628, 410, 455, 35, 331, 303, 123,
+    218, 271, 1777, 306, 1431, 7882, 23, 2216, 56, 7883, 96, 1421, 744, 462, 993, 901,
+    952, 290, 80, 533, 655, 1527, 652, 459, 248, 331, 306, 3080, 708, 236, 658, 2135,
+    256, 378, 385, 37, 26, 435, 216, 249, 271, 1057, 1057, 605, 1199, 113, 159, 925,
+    574, 64, 2758, 580, 656, 7884, 1155, 411, 655, 304, 737, 531, 495, 708, 288, 127,
+    573, 1633, 619, 80, 537, 1406, 1421, 682, 1126, 1964, 993, 1577, 118, 55, 627, 243,
+    126, 231, 819, 1087, 218, 1832, 245, 237, 149, 5, 1002, 192, 945, 55, 1002, 260,
+    197, 1355, 841, 74, 462, 290, 7499, 1182, 241, 366, 932, 830, 65, 245, 220, 168,
+    301, 1581, 7885, 273, 539, 22, 86, 2420, 2495, 197, 7886, 58, 4935, 815, 55, 735,
+    411, 279, 128, 1516, 410, 1404, 104, 1836, 1033, 126, 1087, 530, 1576, 7887, 78, 575,
+    2426, 283, 4530, 2529, 1933, 11, 81, 4, 204, 110, 61, 585, 1564, 161, 6, 55,
+    375, 552, 673, 779, 7888, 233, 3587, 2677, 801, 351, 6, 1126, 7889, 216, 1091, 419,
+    1018, 311, 2833, 1479, 110, 74, 177, 614, 4677, 841, 378, 16, 2486, 41, 118, 169,
+    1155, 7890, 375, 7891, 1271, 98, 1300, 559, 1159, 41, 400, 42, 37, 1159, 122, 1004,
+    830, 627, 277, 2357, 324, 64, 125, 65, 1180, 896, 400, 307, 1019, 701, 1702, 430,
+    925, 744, 2739, 4905, 2330, 1496, 697, 490, 169, 2033, 149, 297, 7892, 42, 7893, 1450,
+    1095, 779, 385, 118, 1576, 171, 35, 1899, 74, 349, 1271, 779, 1020, 126, 872, 2486,
+    1087, 118, 682, 1103, 619, 4905, 455, 1607, 7894, 1019, 110, 96, 234, 1813, 420, 26,
+    861, 290, 1456, 192, 7895, 86, 56, 197, 283, 197, 455, 775, 7896, 7897, 1555, 1655,
+    1347, 682, 1655, 402, 316, 2098, 100, 5773, 2057, 100, 671, 362, 55, 4068, 1166, 29,
+    2135, 409, 1971, 2730, 7898, 502, 98, 7899, 42, 43, 389, 478, 1128, 7900, 93, 832,
+    7901, 30, 548, 1117, 47, 271, 2220, 80, 1216, 637, 303, 283, 55, 260, 6, 2047,
+    1296, 603, 1522, 216, 3894, 316, 98, 682, 110, 416, 549, 819, 100, 295, 57, 1109,
+    96, 460, 218, 547, 530, 244, 7902, 126, 435, 1522, 1137, 189, 47, 430, 111, 1577,
+    7903, 450, 504, 630, 85, 237, 1216, 290, 360, 1166, 312, 1347, 55, 1315, 1315, 660,
+    6, 569, 200, 116, 2480, 468, 97, 1006, 48, 2, 815, 131, 230, 819, 46, 185,
+    3085, 277, 1883, 430, 883, 131, 1021, 1021, 2624, 403, 573, 323, 492, 833, 883, 881,
+    7, 72, 1043, 662, 2563, 919, 1122, 126, 1686, 349, 662, 204, 46, 226, 699, 1229,
+    1906, 4885, 1026, 2624, 172, 1433, 1988, 709, 47, 727, 390, 1028, 111, 2563, 1001, 7904,
+    527, 1660, 114, 244, 997, 1153, 249, 205, 819, 71, 2139, 67, 292, 338, 104, 333,
+    469, 290, 2767, 56, 201, 450, 526, 7905, 1394, 17, 482, 203, 4991, 872, 1129, 270,
+    1128, 37, 1261, 86, 527, 556, 241, 4068, 436, 852, 297, 283, 3308, 80, 932, 1182,
+    1943, 7906, 548, 599, 673, 61, 101, 682, 332, 1078, 82, 108, 260, 55, 387, 2929,
+    18, 7907, 628, 504, 2294, 2294, 1882, 1116, 1732, 735, 200, 390, 3476, 950, 387, 438,
+    482, 1134, 252, 811, 344, 1537, 773, 327, 808, 2041, 7908, 534, 1004, 277, 1494, 1118,
+    171, 5316, 362, 850, 1433, 123, 2580, 442, 252, 202, 535, 602, 746, 6, 180, 1541,
+    283, 7909, 147, 430, 682, 1589, 283, 521, 59, 234, 3046, 580, 411, 665, 7, 536,
+    775, 339, 7910, 219, 277, 2030, 556, 1619, 533, 2739, 978, 619, 322, 218, 533, 628,
+    556, 55, 190, 1433, 123, 382, 340, 159, 697, 709, 1801, 801, 7911, 244, 496, 1770,
+    919, 250, 1494, 1355, 244, 826, 1581, 263, 559, 59, 1296, 169, 86, 197, 234, 715,
+    382, 37, 585, 5086, 86, 1023, 378, 55, 1229, 71, 2313, 126, 469, 295, 286, 1504,
+    37, 7912, 2898, 78, 444, 575, 234, 7913, 1604, 126, 210, 197, 967, 709, 7914, 5646,
+    96, 993, 7915, 65, 2324, 1026, 7916, 4073, 851, 371, 1494, 11, 149, 55, 197, 210,
+    37, 882, 123, 303, 1166, 222, 88, 1435, 3425, 653, 7917, 704, 37, 271, 126, 609,
+    2898, 682, 574, 536, 1270, 7918, 38, 366, 7, 110, 182, 195, 443, 159, 599, 7,
+    260, 273, 1782, 420, 234, 26, 1588, 26, 1670, 350, 18, 81, 756, 1116, 1280, 1005,
+    1496, 2120, 56, 37, 38, 1116, 752, 97, 1118, 3097, 129, 395, 1770, 681, 38, 1273,
+    1159, 26, 197, 1204, 37, 14, 493, 2172, 746, 105, 101, 498, 26, 546, 704, 283,
+    532, 4073, 395, 283, 26, 260, 390, 451, 640, 283, 148, 655, 1105, 7919, 713, 123,
+    239, 7920, 442, 200, 100, 190, 685, 2573, 297, 1832, 1589, 327, 549, 327, 55, 7921,
+    263, 615, 149, 323, 744, 253, 323, 925, 903, 7922, 360, 96, 521, 56, 752, 218,
+    7923, 392, 260, 4073, 52, 603, 260, 7924, 130, 571, 3115, 67, 513, 7925, 1246, 2657,
+    557, 559, 12, 725, 67, 197, 67, 197, 578, 735, 101, 1396, 1443, 529, 220, 26,
+    7926, 107, 807, 7927, 11, 685, 640, 808, 3630, 1847, 242, 93, 585, 244, 220, 1097,
+    921, 1061, 6217, 762, 111, 1292, 343, 808, 7928, 888, 1414, 599, 535, 277, 37, 673,
+    149, 11, 197, 487, 231, 888, 950, 82, 184, 3090, 3251, 156, 197, 82, 646, 578,
+    539, 68, 360, 7, 1847, 628, 7929, 1083, 82, 20, 138, 110, 218, 138, 303, 1830,
+    1134, 110, 1692, 727, 527, 609, 2483, 1396, 535, 7930, 950, 85, 7931, 7932, 1328, 1718,
+    78, 476, 1306, 1006, 2147, 328, 682, 808, 808, 568, 1026, 1555, 7933, 925, 43, 682,
+    2563, 1674, 373, 1108, 1118, 125, 1545, 128, 93, 2483, 338, 7, 236, 1325, 851, 378,
+    851, 851, 161, 704, 5434, 261, 76, 395, 639, 303, 3080, 222, 105, 7934, 7935, 197,
+    728, 569, 665, 2033, 64, 1061, 400, 55, 580, 7936, 7499, 8, 8, 569, 197, 181,
+    252, 3465, 42, 7937, 82, 2294, 7938, 47, 1228, 242, 7939, 283, 283, 283, 1645, 1115,
+    1115, 26, 7940, 1115, 210, 114, 1660, 7941, 205, 18, 636, 56, 522, 114, 845, 89,
+    21, 1460, 126, 289, 534, 815, 1660, 356, 7942, 993, 322, 1035, 345, 609, 7943, 130,
+    969, 7944, 684, 2294, 841, 780, 197, 290, 198, 787, 378, 779, 242, 26, 3085, 625,
+    1687, 399, 114, 197, 4540, 12, 841, 45, 3332, 289, 4692, 655, 101, 7945, 395, 11,
+    7946, 169, 19, 253, 1273, 248, 473, 7947, 18, 262, 1433, 2573, 177, 1347, 687, 7200,
+    7948, 316, 389, 1882, 3097, 41, 16, 195, 150, 554, 44, 61, 552, 243, 97, 630,
+    20, 7949, 7946, 343, 580, 198, 693, 2697, 343, 88, 1059, 365, 78, 399, 1023, 37,
+    1761, 7950, 1757, 1003, 870, 333, 852, 26, 218, 808, 4, 55, 55, 197, 556, 1430,
+    1228, 230, 801, 126, 98, 300, 1545, 200, 1527, 51, 583, 478, 435, 18, 57, 181,
+    1300, 1273, 564, 253, 1023, 7951, 7952, 2965, 495, 243, 4207, 156, 805, 22, 242, 1371,
+    1229, 828, 526, 6324, 646, 870, 169, 7953, 639, 123, 330, 26, 1162, 107, 708, 493,
+    2320, 575, 126, 5, 1308, 7954, 478, 206, 55, 1562, 123, 495, 1296, 851, 635, 363,
+    775, 543, 658, 360, 26, 677, 861, 4872, 7955, 556, 82, 470, 55, 131, 656, 974,
+    332, 156, 828, 845, 909, 210, 1564, 583, 304, 240, 210, 2750, 134, 5732, 8, 529,
+    125, 1533, 573, 123, 134, 1831, 271, 42, 277, 502, 79, 8, 527, 811, 3472, 290,
+    640, 1540, 1933, 652, 8, 192, 2483, 370, 443, 1371, 408, 80, 1119, 3272, 260, 1757,
+    1645, 1062, 195, 807, 899, 807, 501, 181, 3504, 526, 1005, 1443, 1702, 7956, 5581, 241,
+    169, 7957, 530, 64, 159, 195, 386, 226, 492, 123, 195, 1098, 286, 882, 86, 289,
+    114, 7958, 879, 1091, 1091, 195, 728, 255, 14, 123, 970, 1645, 35, 607, 1152, 61,
+    7959, 1155, 7960, 7801, 76, 41, 1155, 3465, 1001, 578, 168, 96, 658, 549, 1628, 127,
+    7576, 973, 800, 523, 3049, 436, 192, 890, 55, 7961, 1588, 491, 93, 7962, 414, 679,
+    109, 1229, 303, 2483, 316, 919, 1084, 1300, 1006, 561, 306, 277, 702, 1292, 3049, 841,
+    1510, 756, 536, 665, 502, 239, 1117, 1845, 26, 1256, 841, 7963, 350, 7964, 1025, 845,
+    2767, 544, 4068, 220, 6, 4540, 192, 88, 114, 218, 363, 7965, 438, 1025, 1060, 231,
+    531, 378, 308, 204, 7966, 1005, 197, 7967, 375, 197, 658, 1651, 222, 1883, 828, 7968,
+    7969, 5038, 81, 1283, 757, 362, 6, 764, 576, 7970, 34, 2176, 488, 220, 26, 110,
+    5581, 7971, 110, 841, 879, 240, 40, 531, 1979, 2563, 1324, 127, 659, 471, 638, 1444,
+    774, 1128, 7972, 580, 556, 7763, 234, 811, 231, 476, 3332, 11, 1797, 414, 639, 455,
+    255, 45, 7, 14, 7973, 126, 7077, 1105, 473, 6111, 576, 546, 414, 422, 7974, 74,
+    546, 11, 141, 127, 475, 1868, 283, 886, 199, 68, 118, 569, 7975, 7976, 488, 841,
+    1035, 439, 845, 1610, 779, 96, 1059, 974, 11, 169, 1455, 105, 3080, 709, 210, 978,
+    1283, 1408, 282, 1640, 764, 546, 47, 126, 103, 1155, 375, 123, 65, 409, 1394, 1388,
+    276, 974, 197, 7977, 80, 7850, 1085, 934, 316, 924, 537, 263, 55, 469, 294, 69,
+    1527, 442, 569, 7978, 841, 1271, 1201, 3100, 708, 244, 253, 230, 811, 1296, 7974, 436,
+    728, 123, 569, 786, 47, 658, 1025, 487, 1279, 436, 7979, 744, 111, 203, 11, 1342,
+    605, 360, 830, 113, 243, 934, 169, 3847, 118, 7, 107, 148, 699, 608, 598, 307,
+    91, 65, 2148, 149, 1271, 161, 1156, 2483, 197, 71, 3239, 726, 169, 74, 896, 625,
+    658, 91, 19, 276, 252, 1448, 744, 2420, 502, 483, 1083, 1189, 7980, 111, 943, 6,
+    55, 7981, 699, 990, 945, 55, 363, 659, 363, 192, 53, 260, 599, 599, 55, 168,
+    3233, 1640, 96, 1229, 7982, 7983, 82, 495, 1162, 1060, 4630, 327, 185, 836, 623, 4012,
+    283, 7984, 1204, 271, 808, 1152, 26, 451, 1430, 168, 353, 218, 599, 148, 313, 65,
+    1640, 2486, 21, 205, 451, 271, 96, 580, 160, 1311, 1199, 1199, 11, 546, 11, 148,
+    851, 1021, 482, 205, 1103, 1103, 1103, 1588, 1280, 173, 130, 283, 5246, 283, 462, 210,
+    8, 283, 627, 21, 289, 245, 385, 556, 1576, 202, 1323, 602, 3, 286, 1702, 1256,
+    2033, 533, 628, 283, 283, 841, 81, 85, 89, 74, 599, 1129, 1323, 1059, 7985, 1127,
+    476, 7054, 419, 104, 222, 521, 628, 277, 786, 47, 116, 765, 7986, 212, 1522, 330,
+    7987, 2698, 199, 1274, 333, 745, 7041, 521, 7988, 1001, 765, 38, 313, 202, 1273, 366,
+    1448, 433, 1416, 1018, 488, 9, 149, 17, 21, 786, 283, 198, 241, 608, 536, 118,
+    169, 7989, 1323, 123, 204, 782, 171, 243, 226, 786, 236, 2507, 26, 144, 57, 55,
+    15, 1246, 149, 422, 1001, 127, 386, 7283, 373, 1347, 1355, 353, 658, 1118, 1355, 1645,
+    1979, 242, 1331, 198, 1533, 1229, 390, 1128, 77, 3054, 1761, 870, 743, 61, 646, 149,
+    1, 203, 940, 970, 169, 7990, 1984, 869, 487, 1728, 1126, 7991, 1005, 1628, 1355, 7992,
+    4815, 444, 628, 812, 709, 703, 556, 1294, 81, 107, 195, 6162, 303, 3894, 1533, 22,
+    1155, 1093, 19, 169, 497, 443, 2758, 7993, 2739, 190, 569, 117, 5, 610, 409, 180,
+    1126, 1001, 472, 74, 882, 781, 86, 195, 1347, 1516, 416, 1134, 805, 1128, 239, 366,
+    2039, 6111, 549, 40, 1002, 53, 6186, 6186, 580, 571, 205, 400, 1294, 26, 26, 845,
+    572, 997, 7994, 1222, 378, 509, 500, 6, 7995, 6, 7996, 7835, 1005, 660, 241, 23,
+    118, 1386, 251, 129, 580, 68, 970, 123, 322, 598, 7997, 282, 44, 353, 51, 746,
+    1083, 2309, 126, 78, 1555, 724, 123, 1127, 1832, 628, 1430, 57, 123, 1122, 556, 618,
+    172, 26, 1640, 33, 851, 526, 218, 53, 290, 160, 925, 26, 7998, 7998, 1628, 68,
+    1430, 7692, 286, 195, 345, 142, 118, 1105, 828, 828, 400, 7999, 845, 2529, 127, 476,
+    8000, 925, 114, 8001, 55, 127, 1152, 1078, 390, 845, 216, 1117, 1733, 382, 1494, 8002,
+    216, 1435, 909, 216, 463, 1431, 1118, 3847, 23, 1159, 1699, 184, 8003, 8004, 529, 177,
+    587, 199, 31, 990, 216, 1279, 716, 997, 2965, 952, 1032, 1018, 126, 26, 2750, 105,
+    460, 1588, 276, 745, 349, 1117, 1115, 551, 210, 1987, 813, 1183, 169, 252, 1882, 820,
+    631, 742, 81, 55, 104, 2750, 716, 14, 127, 369, 283, 8, 177, 400, 8005, 611,
+    1311, 1565, 180, 68, 571, 3917, 300, 26, 451, 1165, 382, 56, 206, 168, 675, 586,
+    8006, 3847, 488, 674, 4138, 1669, 1140, 200, 630, 126, 8007, 537, 530, 175, 1166, 263,
+    7, 127, 1390, 529, 85, 1162, 350, 478, 218, 8008, 363, 737, 549, 123, 111, 56,
+    1271, 40, 283, 273, 737, 438, 8009, 8009, 95, 101, 628, 8010, 1782, 556, 260, 260,
+    303, 477, 115, 569, 206, 3137, 541, 8011, 8012, 218, 1651, 248, 493, 5469, 1847, 131,
+    107, 681, 8013, 555, 260, 2199, 8014, 34, 8015, 523, 1443, 185, 1085, 110, 4068, 8016,
+    530, 786, 26, 820, 1137, 411, 625, 1083, 219, 186, 45, 8017, 3607, 754, 1560, 486,
+    50, 448, 2216, 477, 378, 1093, 420, 130, 488, 571, 974, 4068, 203, 619, 5108, 685,
+    89, 8018, 276, 8019, 469, 24, 1083, 708, 486, 119, 919, 478, 436, 623, 754, 108,
+    628, 144, 17, 8020, 909, 378, 239, 1083, 343, 288, 685, 8021, 489, 554, 251, 38,
+    313, 55, 400, 2755, 568, 1020, 260, 26, 148, 8022, 2039, 455, 195, 42, 684, 233,
+    684, 3370, 2089, 682, 8023, 378, 261, 575, 509, 249, 378, 370, 65, 752, 56, 8024,
+    295, 221, 150, 370, 387, 568, 6, 1329, 128, 36, 16, 1325, 1472, 202, 26, 463,
+    77, 7041, 1801, 679, 123, 1801, 1316, 110, 294, 400, 1, 4530, 230, 1221, 2204, 419,
+    370, 421, 603, 1018, 1005, 385, 304, 192, 320, 76, 316, 5, 1220, 242, 942, 313,
+    2797, 242, 683, 1128, 3803, 2409, 774, 8025, 463, 536, 486, 56, 56, 180, 219, 1801,
+    1062, 636, 290, 967, 8026, 7576, 56, 3472, 686, 1097, 283, 2089, 679, 546, 4692, 5086,
+    363, 248, 541, 1098, 143, 8027, 1718, 462, 332, 1831, 546, 104, 3750, 242, 74, 252,
+    6515, 8028, 65, 504, 8029, 218, 497, 1283, 1519, 114, 1813, 638, 203, 134, 243, 554,
+    97, 1072, 131, 475, 243, 98, 8030, 81, 74, 5181, 148, 390, 149, 5013, 42, 323,
+    378, 1660, 197, 8031, 557, 1577, 813, 646, 14, 65, 400, 202, 1020, 277, 8032, 2730,
+    7907, 715, 1085, 619, 323, 438, 870, 836, 204, 80, 3137, 26, 205, 1414, 8033, 1565,
+    222, 1394, 1005, 286, 8034, 8035, 639, 3424, 682, 1559, 113, 288, 8, 2839, 288, 421,
+    200, 356, 311, 444, 123, 4540, 1097, 108, 463, 114, 749, 343, 316, 35, 93, 1797,
+    1311, 286, 701, 443, 1087, 111, 111, 126, 343, 490, 513, 8036, 370, 241, 38, 400,
+    754, 168, 1386, 1159, 43, 333, 709, 256, 1026, 536, 4414, 237, 168, 640, 1090, 488,
+    484, 4413, 5401, 261, 533, 813, 475, 45, 114, 244, 841, 484, 2135, 168, 288, 222,
+    763, 439, 1137, 283, 97, 2253, 1199, 743, 8037, 1283, 206, 263, 316, 8038, 126, 130,
+    290, 61, 655, 603, 5401, 139, 2018, 303, 488, 1271, 416, 3412, 127, 42, 104, 551,
+    72, 865, 492, 546, 1061, 301, 942, 83, 261, 14, 1577, 182, 1156, 180, 656, 545,
+    630, 422, 237, 289, 402, 660, 55, 244, 277, 682, 830, 320, 186, 638, 47, 1063,
+    708, 303, 697, 868, 637, 833, 55, 277, 658, 1134, 126, 390, 47, 237, 55, 74,
+    56, 509, 30, 580, 682, 1581, 743, 888, 1640, 8039, 343, 80, 295, 830, 1519, 370,
+    26, 7620, 455, 1126, 1294, 2387, 646, 580, 501, 475, 8040, 580, 45, 128, 1271, 831,
+    682, 8041, 2657, 546, 483, 220, 808, 236, 6, 382, 42, 4073, 580, 2580, 1129, 410,
+    1025, 1020, 283, 485, 3803, 347, 539, 8042, 333, 171, 401, 14, 290, 487, 1006, 1347,
+    411, 263, 260, 167, 576, 1448, 5584, 942, 8043, 400, 320, 371, 619, 216, 157, 970,
+    8044, 8045, 625, 1028, 6740, 942, 65, 83, 8046, 80, 1651, 586, 845, 490, 340, 9,
+    111, 529, 450, 271, 111, 253, 31, 490, 1933, 586, 1020, 970, 1555, 1448, 313, 157,
+    1020, 110, 1057, 970, 204, 159, 8047, 619, 655, 1006, 55, 4860, 273, 118, 320, 1584,
+    546, 658, 216, 435, 34, 260, 1431, 95, 387, 906, 127, 1294, 26, 423, 21, 8048,
+    295, 8049, 8050, 845, 389, 378, 277, 147, 556, 93, 471, 112, 808, 147, 110, 139,
+    43, 6359, 98, 1148, 1510, 5527, 8051, 150, 8052, 20, 3131, 1448, 307, 483, 768, 585,
+    8053, 65, 3366, 1072, 270, 620, 908, 2147, 26, 603, 1882, 8054, 2089, 252, 127, 7743,
+    1019, 3085, 122, 14, 57, 509, 9, 105, 1494, 8055, 1831, 1109, 888, 144, 662, 1119,
+    735, 556, 779, 35, 1229, 218, 7740, 47, 8056, 168, 56, 386, 245, 442, 371, 8057,
+    61, 168, 35, 1091, 343, 78, 52, 9, 56, 743, 1105, 8058, 126, 943, 1882, 252,
+    716, 111, 55, 131, 107, 14, 216, 8059, 880, 943, 283, 290, 65, 8060, 8061, 438,
+    17, 20, 77, 1313, 242, 1565, 1386, 8062, 107, 811, 47, 128, 1766, 231, 709, 239,
+    241, 6636, 628, 488, 4275, 172, 870, 754, 709, 241, 1005, 107, 1297, 2075, 35, 55,
+    566, 332, 399, 727, 58, 80, 443, 8063, 1552, 1565, 150, 811, 21, 363, 56, 967,
+    833, 67, 1155, 180, 107, 204, 366, 243, 451, 55, 964, 391, 1283, 1155, 56, 1271,
+    192, 4935, 301, 8064, 81, 216, 368, 47, 98, 399, 4176, 43, 727, 552, 443, 773,
+    349, 209, 104, 1580, 953, 716, 1225, 242, 295, 500, 127, 945, 290, 756, 5, 709,
+    390, 4068, 658, 289, 12, 685, 2864, 888, 8065, 12, 289, 43, 580, 887, 439, 1134,
+    625, 499, 1083, 1830, 161, 495, 18, 1323, 260, 1323, 116, 1126, 131, 200, 520, 900,
+    1437, 1095, 1437, 316, 766, 3412, 766, 200, 378, 277, 149, 128, 1510, 2529, 70, 586,
+    180, 363, 378, 14, 7, 1510, 619, 290, 56, 109, 123, 943, 1437, 580, 64, 455,
+    288, 390, 744, 762, 444, 975, 8066, 1025, 37, 37, 1028, 618, 186, 123, 56, 303,
+    123, 526, 244, 210, 26, 880, 2240, 400, 1001, 598, 391, 1430, 899, 416, 43, 1307,
+    584, 1090, 86, 831, 536, 342, 2298, 104, 1090, 1183, 674, 8067, 535, 2624, 883, 646,
+    536, 768, 557, 2246, 4540, 8068, 4073, 421, 226, 6186, 1199, 127, 273, 580, 78, 4540,
+    26, 965, 169, 697, 21, 8069, 476, 378, 382, 469, 1940, 199, 311, 815, 768, 559,
+    469, 1434, 965, 556, 568, 925, 744, 1001, 277, 715, 580, 1610, 845, 697, 674, 3076,
+    101, 8070, 2864, 234, 8071, 218, 4718, 5413, 78, 351, 677, 80, 1564, 86, 671, 967,
+    245, 531, 198, 8072, 197, 378, 43, 684, 416, 2191, 1609, 1355, 7792, 45, 234, 8073,
+    2191, 1137, 416, 86, 2294, 529, 118, 11, 735, 1609, 1118, 311, 826, 282, 656, 263,
+    3292, 126, 1355, 1355, 8074, 625, 1091, 8075, 86, 416, 86, 97, 378, 2298, 442, 1149,
+    14, 2298, 1355, 64, 201, 1430, 568, 61, 216, 3989, 580, 1018, 317, 416, 1155, 118,
+    66, 780, 430, 1347, 1098, 4068, 1797, 799, 924, 556, 8076, 8077, 8078, 1060, 1766, 9,
+    682, 366, 1116, 120, 974, 101, 1827, 685, 3192, 704, 903, 26, 812, 209, 8079, 1059,
+    599, 274, 919, 126, 271, 1028, 1021, 8080, 343, 813, 111, 1342, 443, 1087, 600, 26,
+    1072, 3012, 175, 5460, 8081, 239, 1005, 628, 978, 8082, 347, 128, 924, 96, 2, 1813,
+    1019, 1024, 2313, 11, 239, 1355, 1692, 7763, 8083, 356, 1085, 1199, 8084, 975, 204, 218,
+    112, 569, 270, 144, 286, 96, 620, 8085, 75, 349, 701, 735, 682, 546, 1441, 970,
+    277, 801, 443, 1225, 752, 2, 313, 206, 206, 628, 306, 123, 1702, 546, 356, 628,
+    2047, 451, 8086, 1020, 439, 277, 3989, 1225, 313, 41, 2096, 1166, 841, 316, 397, 55,
+    306, 8087, 242, 756, 1645, 78, 1645, 26, 513, 770, 1607, 658, 8088, 709, 1222, 2420,
+    2657, 13, 322, 331, 1620, 1101, 658, 513, 4530, 2100, 197, 1082, 26, 903, 332, 20,
+    1374, 42, 1105, 104, 1374, 868, 7308, 1610, 8089, 658, 3146, 1118, 1061, 403, 8090, 6,
+    8091, 3510, 948, 8092, 903, 268, 110, 770, 7, 1979, 149, 8093, 808, 509, 945, 320,
+    98, 408, 216, 1692, 899, 8094, 625, 697, 1201, 242, 231, 303, 1467, 283, 8095, 1246,
+    658, 320, 556, 30, 11, 701, 255, 276, 45, 283, 221, 903, 55, 460, 1755, 18,
+    1323, 509, 975, 541, 246, 74, 197, 609, 1882, 831, 1979, 56, 8096, 6080, 845, 169,
+    801, 708, 210, 1137, 365, 345, 1946, 868, 8097, 1545, 1402, 888, 697, 919, 486, 451,
+    11, 609, 1093, 8098, 2704, 357, 242, 1283, 282, 1979, 282, 321, 128, 830, 96, 65,
+    255, 546, 324, 719, 715, 8099, 628, 8100, 128, 2360, 8101, 1847, 7562, 967, 8102, 546,
+    932, 8103, 831, 283, 486, 248, 246, 679, 271, 234, 1300, 295, 737, 990, 6, 695,
+    300, 414, 60, 316, 295, 400, 241, 5556, 1261, 26, 1087, 556, 55, 318, 505, 252,
+    126, 1274, 1274, 473, 628, 430, 197, 44, 2284, 311, 812, 708, 169, 83, 256, 8104,
+    114, 903, 276, 483, 1062, 61, 353, 858, 7499, 6, 580, 8105, 4413, 8106, 719, 539,
+    658, 627, 117, 478, 527, 627, 8107, 688, 366, 1573, 8108, 168, 363, 8109, 8110, 111,
+    401, 8111, 8112, 1482, 82, 58, 470, 1136, 470, 239, 58, 2096, 58, 455, 1189, 436,
+    2128, 813, 744, 112, 382, 615, 435, 768, 1220, 8113, 460, 43, 974, 197, 23, 192,
+    674, 455, 17, 1443, 290, 18, 819, 290, 8114, 836, 575, 252, 8115, 684, 78, 283,
+    532, 1097, 1513, 1513, 57, 78, 638, 283, 283, 283, 8116, 7841, 2089, 477, 97, 2089,
+    1005, 34, 416, 966, 1203, 26, 416, 2965, 532, 3943, 2426, 477, 1090, 323, 1128, 2294,
+    159, 2294, 530, 490, 6250, 580, 390, 2767, 2294, 1846, 1119, 564, 8117, 564, 599, 7711,
+    5108, 815, 658, 522, 1728, 331, 1018, 900, 1728, 2, 277, 746, 660, 86, 900, 86,
+    86, 537, 8118, 2041, 2041, 86, 3076, 199, 658, 86, 331, 144, 1832, 1043, 53, 3090,
+    26, 378, 21, 378, 648, 86, 2147, 1537, 1328, 673, 378, 640, 143, 358, 3239, 370,
+    358, 131, 1308, 38, 586, 1097, 568, 677, 331, 2727, 902, 1448, 573, 96, 2432, 8119,
+    779, 289, 475, 48, 708, 390, 349, 378, 619, 658, 554, 169, 51, 8120, 209, 197,
+    2253, 1978, 925, 1003, 655, 603, 1496, 119, 978, 71, 123, 561, 658, 990, 3686, 233,
+    708, 1801, 356, 2495, 8121, 556, 86, 3847, 1130, 1390, 1527, 144, 709, 660, 1294, 1309,
+    1390, 805, 442, 56, 1199, 1296, 4165, 55, 167, 1527, 260, 1199, 1026, 260, 812, 304,
+    1005, 7825, 1078, 253, 303, 1443, 1930, 1528, 526, 642, 363, 3783, 1025, 3543, 102, 21,
+    628, 663, 1263, 211, 1134, 219, 306, 1994, 1933, 200, 467, 900, 112, 1431, 3578, 8122,
+    1655, 1324, 1633, 200, 1019, 845, 953, 81, 26, 38, 283, 7654, 23, 253, 57, 1448,
+    536, 775, 908, 8, 502, 1115, 473, 4492, 571, 8123, 1005, 197, 85, 56, 486, 181,
+    202, 378, 81, 1324, 3745, 576, 675, 11, 492, 290, 2128, 1274, 575, 1108, 2739, 179,
+    944, 242, 126, 1772, 1046, 159, 845, 142, 283, 403, 93, 575, 198, 126, 1090, 3199,
+    338, 219, 833, 290, 1801, 212, 709, 726, 709, 1813, 546, 662, 74, 343, 1645, 531,
+    580, 311, 580, 390, 509, 953, 1331, 903, 363, 762, 438, 1770, 126, 343, 159, 1115,
+    1143, 351, 1115, 1115, 1019, 886, 378, 1105, 709, 504, 5418, 7, 431, 57, 752, 5817,
+    212, 771, 400, 300, 8124, 1437, 378, 114, 274, 625, 351, 185, 552, 8125, 573, 1046,
+    74, 708, 241, 740, 2704, 97, 129, 965, 216, 408, 462, 638, 249, 363, 97, 575,
+    173, 639, 23, 2426, 845, 277, 88, 68, 142, 113, 743, 363, 545, 242, 351, 8126,
+    719, 1126, 623, 8127, 813, 870, 8128, 1830, 323, 96, 127, 97, 469, 1201, 68, 695,
+    169, 1909, 1588, 26, 2284, 8129, 125, 1061, 646, 143, 169, 1043, 539, 681, 86, 1085,
+    43, 184, 721, 113, 1314, 207, 2018, 65, 277, 944, 575, 684, 23, 363, 782, 1097,
+    97, 331, 45, 77, 231, 130, 1018, 1830, 123, 569, 143, 253, 199, 97, 167, 3424,
+    436, 188, 583, 547, 249, 45, 727, 343, 320, 81, 1611, 72, 131, 2426, 674, 1001,
+    204, 1640, 425, 409, 168, 785, 702, 1093, 7972, 1702, 389, 332, 43, 168, 111, 4540,
+    3250, 896, 277, 1510, 2135, 127, 549, 513, 255, 199, 549, 169, 1162, 1440, 400, 965,
+    8130, 970, 1836, 169, 171, 56, 45, 5142, 356, 782, 1182, 148, 1568, 288, 615, 83,
+    1326, 1588, 316, 615, 1388, 123, 331, 1450, 743, 197, 2191, 142, 83, 105, 239, 290,
+    5560, 8131, 639, 1166, 726, 536, 216, 400, 791, 1153, 1292, 33, 1882, 123, 1651, 488,
+    896, 744, 537, 603, 123, 378, 830, 3706, 815, 81, 542, 288, 1105, 737, 199, 222,
+    1115, 311, 290, 323, 1087, 416, 425, 181, 978, 243, 2139, 123, 288, 104, 505, 923,
+    8132, 1906, 56, 8133, 38, 655, 123, 551, 1687, 67, 5, 86, 67, 1882, 207, 378,
+    557, 865, 117, 1519, 110, 578, 150, 58, 416, 1531, 127, 400, 123, 2750, 8134, 363,
+    168, 1433, 37, 896, 83, 970, 786, 1201, 81, 244, 548, 96, 5868, 322, 273, 182,
+    459, 682, 236, 197, 586, 204, 385, 244, 1609, 455, 204, 8135, 640, 1274, 7, 566,
+    462, 11, 242, 220, 363, 526, 559, 8136, 548, 655, 64, 288, 4310, 655, 8137, 841,
+    796, 1061, 8138, 356, 768, 74, 200, 65, 363, 316, 1271, 311, 1576, 363, 744, 496,
+    640, 715, 171, 462, 743, 408, 74, 34, 255, 1926, 469, 1883, 56, 26, 390, 496,
+    833, 1496, 157, 478, 491, 67, 343, 215, 1576, 239, 256, 1162, 111, 332, 4414, 993,
+    234, 8139, 1059, 2313, 55, 8120, 8140, 58, 702, 1899, 168, 93, 801, 26, 1782, 496,
+    455, 1199, 111, 200, 129, 368, 781, 30, 177, 421, 1180, 8141, 5581, 925, 129, 709,
+    11, 1599, 578, 323, 26, 1599, 200, 463, 128, 516, 1588, 260, 1510, 8142, 578, 8128,
+    568, 973, 216, 455, 125, 290, 779, 658, 957, 1046, 1448, 192, 1510, 4073, 2216, 216,
+    56, 277, 303, 1705, 872, 1, 282, 327, 8128, 311, 340, 65, 988, 263, 65, 263,
+    408, 263, 203, 251, 42, 1527, 1323, 576, 134, 8143, 727, 21, 498, 1589, 26, 8144,
+    245, 101, 112, 322, 65, 261, 824, 405, 169, 502, 8145, 226, 169, 1040, 66, 114,
+    557, 640, 161, 2935, 1021, 966, 580, 220, 690, 8146, 333, 382, 993, 2176, 8147, 55,
+    185, 3783, 8148, 1239, 620, 218, 652, 110, 587, 198, 568, 239, 149, 12, 23, 8149,
+    26, 26, 11, 5732, 8150, 216, 773, 1085, 270, 12, 586, 569, 8151, 114, 8152, 111,
+    26, 167, 1201, 8153, 1100, 1085, 889, 242, 351, 244, 568, 7422, 808, 660, 690, 545,
+    505, 8154, 251, 1331, 1309, 343, 123, 96, 96, 990, 123, 261, 86, 620, 320, 343,
+    845, 8155, 6588, 828, 552, 349, 568, 253, 4095, 107, 397, 1599, 652, 177, 648, 1398,
+    8156, 96, 389, 716, 8157, 1777, 347, 556, 8158, 273, 220, 345, 640, 482, 127, 8159,
+    209, 4275, 127, 390, 708, 149, 101, 122, 724, 370, 47, 431, 435, 277, 180, 488,
+    490, 8160, 2128, 1324, 1117, 303, 841, 128, 313, 3273, 646, 351, 253, 870, 252, 1589,
+    6111, 439, 1116, 529, 870, 628, 65, 2320, 841, 484, 673, 4054, 70, 169, 7, 7,
+    524, 1, 1061, 14, 127, 61, 978, 462, 206, 8161, 126, 2285, 576, 1431, 37, 7,
+    2010, 320, 529, 845, 2330, 70, 243, 679, 436, 400, 37, 344, 8162, 835, 8163, 1040,
+    21, 482, 245, 70, 1001, 301, 365, 123, 37, 70, 1134, 111, 828, 328, 1576, 26,
+    537, 490, 580, 52, 905, 1162, 20, 1588, 620, 661, 1519, 529, 255, 131, 4540, 114,
+    4799, 746, 7, 628, 150, 826, 1024, 126, 343, 55, 1163, 2285, 1162, 80, 569, 1031,
+    80, 343, 482, 82, 660, 1609, 708, 82, 3706, 599, 206, 805, 126, 365, 946, 301,
+    682, 253, 56, 1882, 395, 4692, 6, 835, 2483, 702, 1315, 179, 1137, 462, 96, 180,
+    8164, 482, 323, 5181, 635, 531, 55, 557, 439, 86, 1040, 708, 252, 713, 482, 244,
+    964, 114, 219, 202, 1118, 1609, 815, 1163, 365, 599, 260, 775, 569, 455, 70, 801,
+    239, 716, 970, 1845, 478, 708, 215, 343, 1005, 1005, 1118, 1609, 3046, 111, 8165, 323,
+    1095, 125, 436, 527, 215, 44, 716, 8166, 86, 358, 439, 832, 2096, 237, 470, 775,
+    378, 260, 715, 61, 1137, 960, 585, 533, 421, 8167, 231, 89, 549, 756, 903, 3137,
+    858, 200, 529, 830, 400, 252, 12, 1155, 8168, 556, 8169, 903, 358, 673, 655, 8170,
+    502, 1777, 679, 677, 472, 1128, 128, 8171, 77, 286, 524, 4797, 200, 1152, 658, 143,
+    143, 55, 13, 1655, 903, 1155, 8172, 5307, 504, 304, 709, 8173, 139, 55, 402, 8174,
+    5930, 8175, 658, 26, 244, 270, 8176, 655, 548, 686, 390, 1136, 879, 77, 438, 210,
+    50, 65, 1163, 141, 828, 8177, 4184, 1309, 1984, 940, 373, 490, 8178, 111, 150, 8179,
+    1331, 52, 251, 209, 965, 970, 294, 286, 1001, 919, 209, 277, 349, 965, 198, 322,
+    35, 940, 351, 870, 96, 203, 8180, 1430, 251, 46, 373, 408, 96, 198, 1342, 1777,
+    203, 1313, 405, 37, 1311, 490, 2755, 96, 1040, 896, 556, 294, 828, 198, 110, 630,
+    8181, 294, 548, 184, 239, 659, 1292, 362, 356, 81, 572, 96, 1473, 8182, 316, 216,
+    1576, 17, 430, 242, 390, 74, 363, 277, 682, 888, 1841, 218, 127, 8183, 430, 127,
+    82, 529, 110, 8184, 513, 37, 7, 1396, 134, 286, 368, 260, 56, 1528, 104, 1040,
+    709, 290, 209, 276, 460, 665, 105, 203, 620, 619, 1005, 1451, 360, 1430, 19, 149,
+    546, 1430, 320, 43, 2298, 218, 779, 569, 8185, 585, 198, 925, 2482, 477, 1909, 6,
+    29, 56, 8186, 36, 107, 473, 8187, 443, 186, 472, 399, 186, 8188, 146, 345, 244,
+    656, 5114, 2510, 1494, 4126, 56, 435, 321, 1692, 811, 97, 253, 1533, 26, 559, 1311,
+    2833, 674, 1450, 1569, 744, 599, 586, 55, 286, 192, 8189, 659, 195, 1533, 195, 779,
+    342, 171, 8190, 74, 1163, 56, 1020, 1564, 46, 367, 743, 316, 219, 1118, 9, 236,
+    6, 104, 719, 4799, 970, 1025, 1770, 3324, 6, 283, 4750, 1448, 556, 1020, 8191, 316,
+    277, 283, 602, 8192, 826, 169, 106, 332, 575, 1118, 219, 209, 551, 251, 19, 8193,
+    65, 628, 283, 1655, 744, 67, 8194, 850, 118, 1882, 556, 8195, 169, 37, 744, 598,
+    575, 1408, 1087, 556, 126, 365, 970, 8196, 2138, 754, 218, 429, 1005, 113, 8197, 8198,
+    200, 378, 820, 1031, 1434, 609, 30, 8199, 419, 642, 65, 973, 677, 8198, 8200, 5930,
+    2495, 382, 8201, 8202, 1655, 248, 609, 56, 1797, 8203, 1273, 1159, 1118, 1847, 55, 8204,
+    620, 11, 1448, 768, 112, 1228, 56, 1082, 169, 375, 919, 1371, 65, 807, 1306, 61,
+    8205, 896, 3510, 8206, 8207, 1306, 537, 365, 149, 230, 107, 261, 283, 294, 1899, 74,
+    768, 1117, 80, 752, 123, 8208, 1091, 679, 735, 534, 779, 967, 37, 41, 169, 653,
+    879, 6515, 8209, 248, 6505, 495, 1093, 1155, 6450, 130, 432, 8210, 8211, 2309, 56, 89,
+    973, 8212, 1003, 1692, 5427, 169, 967, 65, 903, 953, 69, 807, 8213, 754, 340, 580,
+    127, 340, 182, 150, 665, 820, 56, 2329, 188, 400, 283, 1797, 126, 432, 405, 4272,
+    111, 1018, 473, 880, 327, 896, 300, 439, 26, 65, 8214, 428, 303, 534, 1510, 419,
+    1028, 727, 600, 61, 1581, 355, 168, 1315, 2913, 896, 288, 91, 3305, 363, 682, 35,
+    1933, 2935, 801, 1527, 231, 832, 180, 83, 682, 351, 474, 231, 8215, 134, 1756, 149,
+    216, 909, 96, 964, 693, 781, 646, 61, 599, 168, 432, 86, 405, 630, 14, 1119,
+    111, 964, 531, 447, 411, 420, 660, 556, 8216, 169, 286, 693, 1090, 693, 245, 216,
+    282, 548, 8217, 66, 954, 1229, 599, 1204, 118, 65, 896, 65, 973, 2898, 2833, 55,
+    120, 68, 197, 317, 8218, 811, 8219, 811, 2030, 639, 869, 171, 903, 253, 1230, 1972,
+    704, 1239, 251, 395, 559, 1684, 390, 21, 752, 231, 1020, 253, 682, 55, 1607, 1979,
+    116, 64, 171, 831, 1841, 97, 1005, 322, 1599, 8220, 534, 808, 260, 206, 1018, 2216,
+    236, 1374, 26, 26, 5806, 8221, 411, 1610, 262, 8222, 1046, 260, 431, 2426, 1159, 57,
+    31, 65, 3076, 483, 71, 1933, 436, 108, 1208, 286, 157, 526, 243, 1261, 200, 1063,
+    316, 978, 363, 659, 197, 2573, 126, 708, 55, 628, 480, 277, 8223, 1116, 744, 500,
+    439, 504, 8224, 88, 395, 239, 131, 1435, 965, 469, 8225, 6, 378, 18, 8226, 5157,
+    648, 554, 234, 1097, 547, 8227, 704, 236, 1057, 978, 887, 72, 659, 704, 8228, 256,
+    41, 805, 62, 6390, 708, 8229, 1153, 557, 1028, 1602, 1940, 1479, 211, 131, 2839, 967,
+    1309, 365, 17, 1565, 4, 131, 210, 378, 789, 521, 789, 708, 17, 41, 765, 55,
+    18, 303, 416, 547, 870, 584, 375, 870, 566, 700, 471, 469, 8230, 110, 179, 104,
+    1117, 509, 1072, 813, 2206, 8231, 8232, 15, 206, 8233, 376, 234, 142, 1308, 576, 608,
+    237, 993, 419, 11, 530, 745, 197, 181, 8234, 580, 436, 8235, 665, 220, 271, 29,
+    1137, 6, 347, 276, 1090, 7, 29, 1576, 544, 7556, 26, 436, 96, 974, 58, 646,
+    332, 513, 1018, 198, 3958, 198, 8236, 966, 8237, 107, 1002, 316, 91, 642, 548, 355,
+    283, 708, 8238, 1609, 906, 455, 970, 134, 851, 530, 509, 261, 1105, 845, 8239, 693,
+    197, 157, 697, 11, 200, 1180, 8240, 65, 490, 65, 277, 697, 26, 351, 556, 529,
+    571, 26, 880, 78, 173, 8241, 353, 8242, 78, 1770, 576, 5581, 4, 171, 635, 480,
+    1283, 173, 2191, 640, 8243, 8081, 3943, 8244, 1435, 619, 1093, 1541, 658, 1448, 332, 150,
+    8245, 557, 8246, 1611, 150, 8247, 219, 8248, 343, 1440, 978, 197, 3370, 110, 358, 8249,
+    242, 2624, 436, 149, 311, 8250, 11, 492, 395, 1388, 658, 53, 378, 77, 3292, 295,
+    1005, 289, 1347, 8251, 8252, 78, 580, 450, 8253, 294, 5882, 993, 8254, 98, 8255, 282,
+    993, 127, 993, 660, 218, 464, 4, 537, 8256, 67, 1602, 286, 169, 4068, 658, 391,
+    464, 218, 127, 450, 835, 2330, 4095, 123, 845, 171, 436, 1046, 435, 55, 1163, 37,
+    6591, 332, 1020, 356, 8257, 36, 1581, 462, 945, 260, 156, 8258, 826, 492, 571, 819,
+    317, 390, 231, 241, 241, 365, 1099, 332, 218, 1001, 1085, 1838, 1617, 260, 127, 26,
+    239, 138, 30, 556, 320, 473, 56, 387, 167, 1482, 1408, 8259, 587, 1306, 1078, 3054,
+    200, 2309, 1163, 13, 6618, 997, 493, 671, 197, 970, 8260, 1371, 2755, 1398, 263, 1117,
+    8261, 5316, 1005, 199, 1118, 580, 534, 556, 332, 764, 2580, 8262, 770, 218, 1883, 1541,
+    684, 535, 197, 3272, 2839, 1496, 653, 98, 40, 970, 1757, 500, 487, 2030, 6257, 38,
+    233, 241, 56, 2294, 6649, 945, 7, 189, 64, 1018, 965, 787, 8263, 842, 520, 811,
+    48, 735, 8264, 1933, 658, 455, 204, 1325, 745, 997, 1545, 880, 2709, 283, 8265, 218,
+    509, 687, 8266, 1555, 149, 8267, 1309, 177, 850, 704, 252, 574, 1296, 429, 487, 8268,
+    194, 169, 21, 4540, 416, 218, 2755, 1001, 431, 78, 719, 2089, 451, 1091, 326, 7773,
+    149, 708, 137, 1128, 625, 171, 3, 1527, 8269, 55, 86, 83, 1296, 1296, 1024, 8270,
+    529, 283, 218, 127, 1053, 470, 320, 8120, 1122, 727, 150, 188, 6489, 450, 387, 3424,
+    1836, 529, 487, 1046, 111, 1023, 580, 2330, 366, 796, 1403, 159, 880, 490, 53, 709,
+    8271, 8272, 702, 583, 3894, 530, 491, 400, 1562, 53, 8273, 83, 756, 42, 1159, 665,
+    639, 71, 1093, 586, 682, 3706, 107, 80, 283, 630, 1845, 386, 8274, 117, 486, 2580,
+    1300, 1295, 1408, 6700, 62, 362, 1552, 1633, 111, 841, 578, 55, 370, 851, 727, 1018,
+    360, 96, 548, 215, 485, 470, 134, 2313, 47, 326, 455, 470, 470, 1388, 7773, 8275,
+    447, 865, 8276, 131, 482, 5, 290, 42, 679, 534, 1118, 854, 1813, 1221, 584, 252,
+    1670, 1768, 580, 7062, 1448, 326, 270, 1028, 7058, 527, 8277, 1705, 150, 8278, 1137, 1153,
+    1717, 313, 313, 253, 231, 549, 752, 234, 660, 490, 53, 8279, 366, 55, 636, 242,
+    470, 116, 8280, 128, 658, 390, 662, 455, 1116, 20, 400, 945, 556, 1152, 114, 169,
+    1564, 685, 675, 8281, 8282, 766, 532, 1306, 609, 8283, 318, 128, 333, 216, 1021, 851,
+    126, 197, 332, 60, 7561, 902, 8284, 8285, 26, 620, 117, 128, 233, 536, 44, 1355,
+    3046, 59, 245, 752, 845, 349, 1444, 115, 8286, 68, 8287, 123, 903, 1021, 8288, 378,
+    149, 883, 71, 1324, 2147, 86, 850, 353, 786, 516, 786, 1021, 369, 216, 631, 1764,
+    1, 438, 653, 44, 786, 8289, 1001, 2216, 599, 970, 527, 1309, 55, 5033, 8290, 332,
+    1117, 8291, 67, 71, 38, 1021, 766, 61, 400, 283, 4390, 198, 8292, 820, 8293, 1355,
+    1122, 444, 2284, 631, 1747, 1025, 1309, 2220, 240, 655, 571, 48, 1589, 1309, 1166, 239,
+    443, 438, 420, 1090, 8294, 1541, 5, 1836, 655, 8295, 4392, 263, 8296, 964, 370, 19,
+    619, 237, 443, 965, 290, 55, 7, 263, 526, 443, 556, 1448, 1117, 47, 628, 43,
+    400, 370, 2220, 1589, 619, 286, 45, 5, 46, 45, 8297, 7620, 65, 587, 239, 263,
+    868, 126, 286, 544, 868, 34, 819, 118, 8298, 1084, 230, 403, 226, 919, 1645, 416,
+    322, 531, 658, 21, 283, 640, 451, 97, 451, 640, 74, 6588, 301, 2420, 218, 658,
+    1021, 199, 8299, 219, 455, 110, 574, 8300, 1371, 1230, 386, 2191, 5, 110, 684, 148,
+    8301, 967, 2298, 128, 8302, 387, 878, 8303, 52, 8304, 1728, 47, 1137, 1189, 20, 1199,
+    358, 1025, 6692, 8305, 248, 589, 127, 286, 347, 1323, 1845, 547, 382, 254, 189, 8306,
+    727, 4219, 78, 8307, 430, 86, 845, 557, 779, 682, 271, 171, 1725, 172, 615, 433,
+    997, 127, 112, 779, 8308, 8309, 820, 322, 473, 356, 498, 8310, 1203, 1560, 1315, 74,
+    150, 619, 589, 4, 841, 549, 978, 283, 774, 4935, 209, 1347, 8311, 630, 2147, 61,
+    754, 243, 97, 6303, 2120, 97, 473, 630, 1039, 61, 978, 118, 67, 619, 619, 640,
+    473, 61, 1100, 97, 55, 950, 351, 118, 97, 455, 86, 160, 242, 42, 775, 1059,
+    2480, 77, 2942, 586, 4184, 8312, 2294, 8313, 549, 668, 328, 8314, 865, 1152, 808, 205,
+    7034, 8315, 2206, 495, 970, 808, 708, 574, 497, 608, 380, 327, 568, 1589, 1692, 3272,
+    585, 23, 2030, 1314, 1018, 101, 56, 248, 1199, 476, 1479, 64, 1984, 68, 56, 333,
+    50, 5732, 97, 175, 549, 419, 205, 2929, 85, 111, 547, 8316, 509, 6, 903, 154,
+    8317, 1599, 8318, 210, 367, 845, 801, 1770, 1279, 56, 903, 527, 390, 1156, 1153, 274,
+    101, 1128, 216, 568, 216, 890, 26, 713, 940, 743, 55, 6, 1455, 11, 243, 673,
+    57, 836, 138, 286, 487, 111, 45, 934, 1176, 111, 1599, 919, 711, 200, 111, 188,
+    1519, 34, 965, 8319, 724, 8320, 568, 841, 491, 5264, 970, 527, 965, 1020, 1670, 2573,
+    530, 5108, 1119, 580, 8321, 852, 1770, 239, 263, 569, 709, 935, 195, 7597, 363, 45,
+    239, 1279, 702, 55, 1105, 82, 349, 623, 110, 903, 586, 283, 248, 55, 1300, 243,
+    599, 1396, 8322, 100, 20, 609, 583, 673, 2767, 218, 467, 550, 219, 845, 1510, 568,
+    260, 244, 139, 8323, 220, 304, 126, 362, 403, 1687, 8324, 659, 389, 327, 218, 6,
+    607, 627, 1402, 505, 261, 197, 571, 303, 7542, 880, 735, 261, 283, 1432, 1660, 1306,
+    467, 4, 5, 735, 780, 304, 719, 3049, 1988, 451, 8325, 942, 925, 74, 1602, 609,
+    484, 4275, 1083, 64, 708, 363, 277, 55, 8326, 338, 168, 195, 363, 1988, 901, 200,
+    546, 8327, 2033, 2147, 8328, 845, 1831, 56, 1117, 903, 566, 472, 439, 685, 1669, 1043,
+    1823, 168, 1576, 3131, 3054, 880, 277, 363, 318, 439, 726, 8329, 26, 107, 11, 56,
+    1002, 6, 436, 1271, 577, 420, 4702, 546, 358, 8330, 168, 8331, 303, 639, 149, 1006,
+    1006, 688, 454, 3818, 679, 111, 462, 96, 8, 169, 807, 127, 8332, 13, 5392, 328,
+    200, 743, 520, 253, 8333, 482, 524, 2480, 20, 521, 245, 376, 1746, 286, 248, 188,
+    502, 343, 482, 1448, 8334, 297, 169, 26, 14, 451, 1402, 114, 905, 549, 495, 600,
+    21, 509, 805, 807, 74, 8335, 1024, 768, 608, 529, 320, 78, 283, 504, 1307, 286,
+    1035, 2147, 216, 2099, 509, 21, 78, 5392, 8336, 8337, 1728, 351, 529, 1025, 390, 919,
+    363, 1225, 8338, 30, 1143, 395, 7041, 105, 727, 4935, 580, 493, 529, 970, 2466, 460,
+    1847, 8339, 460, 340, 51, 655, 243, 244, 715, 253, 1019, 580, 169, 8340, 727, 835,
+    14, 2965, 8337, 735, 104, 55, 2294, 640, 14, 439, 304, 188, 8341, 443, 100, 8342,
+    556, 55, 53, 8337, 1510, 365, 709, 198, 531, 504, 120, 42, 4815, 4073, 1770, 47,
+    439, 231, 1087, 8343, 756, 3543, 459, 580, 1770, 4095, 81, 1747, 3713, 1126, 400, 365,
+    74, 1964, 1699, 1580, 111, 954, 3713, 86, 93, 168, 198, 556, 1300, 58, 339, 1101,
+    1106, 390, 339, 24, 65, 200, 26, 1163, 1510, 919, 249, 219, 37, 1200, 320, 260,
+    934, 623, 2302, 1225, 188, 527, 251, 1025, 3090, 3543, 659, 598, 873, 652, 253, 179,
+    110, 3543, 1347, 8344, 743, 1386, 726, 8345, 1300, 41, 451, 242, 1702, 520, 652, 242,
+    620, 68, 1386, 1431, 8346, 533, 602, 294, 1645, 323, 219, 274, 136, 1960, 146, 294,
+    313, 451, 400, 724, 21, 571, 297, 1450, 1309, 742, 1084, 727, 1960, 851, 514, 679,
+    360, 368, 973, 1115, 387, 115, 1325, 1404, 455, 1134, 831, 644, 8347, 197, 8348, 1152,
+    112, 6054, 671, 8349, 6, 47, 400, 1294, 8350, 2972, 371, 8351, 125, 908, 11, 763,
+    975, 1541, 195, 659, 47, 125, 1496, 128, 8352, 1633, 1906, 216, 735, 919, 350, 277,
+    358, 212, 171, 234, 1404, 1199, 244, 744, 198, 8353, 1467, 658, 886, 57, 628, 243,
+    5307, 636, 8354, 104, 195, 61, 7, 599, 86, 250, 378, 46, 819, 274, 390, 433,
+    819, 7, 1585, 619, 655, 11, 126, 125, 1122, 431, 638, 1149, 735, 206, 234, 1046,
+    4, 71, 68, 1728, 1440, 7602, 8355, 957, 841, 242, 390, 813, 8356, 144, 1460, 2285,
+    1163, 2220, 1043, 1025, 40, 2033, 523, 156, 1640, 1534, 107, 549, 83, 1329, 567, 369,
+    671, 655, 11, 65, 957, 7, 55, 1580, 485, 365, 1821, 1766, 455, 53, 303, 1519,
+    549, 455, 1325, 172, 858, 619, 47, 819, 431, 1149, 1025, 2285, 455, 906, 401, 253,
+    143, 8357, 64, 557, 97, 169, 935, 806, 110, 320, 2767, 115, 304, 8358, 1510, 332,
+    8359, 149, 1035, 237, 126, 463, 245, 1746, 539, 945, 8360, 375, 67, 76, 1118, 1559,
+    11, 905, 530, 411, 2026, 98, 1095, 400, 72, 8361, 261, 403, 343, 1545, 311, 576,
+    370, 74, 903, 2616, 353, 30, 873, 819, 218, 409, 219, 149, 743, 754, 1295, 642,
+    2965, 757, 841, 439, 946, 197, 735, 828, 242, 283, 870, 26, 366, 11, 26, 209,
+    400, 1494, 47, 557, 167, 14, 870, 172, 340, 424, 8362, 487, 1845, 559, 602, 548,
+    1103, 1097, 709, 1494, 574, 556, 781, 625, 727, 462, 241, 263, 230, 8363, 2320, 239,
+    96, 548, 2758, 556, 239, 363, 252, 8364, 85, 110, 1162, 365, 1001, 306, 8365, 697,
+    95, 8366, 630, 576, 1129, 743, 340, 260, 443, 8367, 55, 236, 20, 708, 620, 614,
+    245, 390, 345, 677, 2945, 8368, 8369, 8370, 8371, 614, 8372, 47, 1926, 205, 974, 285,
+    5246, 455, 2864, 1097, 1057, 8373, 1189, 395, 659, 3292, 7973, 169, 143, 2139, 8374, 659,
+    702, 728, 1421, 782, 8375, 782, 756, 659, 1964, 111, 455, 369, 120, 965, 779, 2022,
+    831, 8376, 522, 8377, 2466, 343, 2080, 395, 378, 378, 271, 5930, 260, 8378, 318, 8379,
+    134, 144, 609, 1093, 498, 1229, 96, 2320, 1229, 488, 26, 98, 1201, 370, 1061, 824,
+    735, 1005, 1830, 638, 944, 713, 1315, 80, 270, 600, 96, 835, 128, 945, 201, 260,
+    20, 993, 773, 652, 57, 1118, 1118, 1078, 76, 290, 20, 8380, 282, 830, 578, 2573,
+    23, 288, 363, 340, 324, 255, 970, 115, 80, 1184, 55, 2313, 443, 469, 1309, 599,
+    879, 539, 21, 894, 896, 370, 1005, 716, 408, 271, 290, 8381, 541, 8382, 123, 1482,
+    539, 333, 1494, 279, 197, 1182, 6688, 899, 2755, 216, 539, 8383, 1134, 8384, 55, 215,
+    520, 81, 539, 1451, 5210, 1084, 288, 343, 118, 8385, 139, 358, 83, 1137, 1083, 1687,
+    260, 1342, 488, 959, 260, 55, 496, 682, 375, 828, 375, 260, 8386, 544, 111, 8387,
+    1396, 744, 74, 260, 260, 459, 215, 260, 719, 260, 744, 239, 1084, 638, 1494, 8388,
+    1414, 1414, 3352, 80, 181, 886, 828, 4692, 1316, 5013, 1581, 61, 4692, 539, 1564, 1152,
+    221, 288, 5114, 206, 261, 1082, 8389, 909, 1402, 3281, 8390, 8391, 1610, 2089, 1273, 1347,
+    124, 8167, 693, 171, 5773, 421, 1105, 26, 93, 697, 575, 5401, 1182, 53, 1402, 3943,
+    1651, 404, 702, 742, 1002, 261, 252, 1633, 5773, 139, 168, 1180, 655, 231, 660, 100,
+    8392, 851, 199, 387, 945, 30, 126, 5968, 557, 1167, 147, 149, 1090, 316, 833, 1118,
+    45, 378, 323, 239, 416, 460, 260, 69, 35, 362, 580, 52, 1610, 382, 179, 8393,
+    571, 791, 134, 185, 1159, 283, 115, 149, 746, 173, 115, 8394, 411, 1633, 333, 539,
+    7372, 80, 126, 283, 628, 333, 556, 642, 65, 74, 573, 8395, 1883, 86, 286, 7077,
+    580, 149, 744, 26, 234, 242, 1559, 1628, 7, 93, 509, 870, 47, 565, 467, 110,
+    8396, 244, 580, 8397, 378, 283, 833, 945, 536, 8398, 1441, 35, 826, 746, 7200, 51,
+    2482, 1728, 1163, 8399, 1153, 8400, 8401, 870, 86, 870, 86, 531, 685, 1522, 8402, 86,
+    1650, 86, 499, 13, 8403, 414, 1005, 154, 1830, 271, 123, 2330, 134, 11, 744, 965,
+    11, 637, 362, 454, 568, 65, 313, 1978, 35, 744, 343, 1747, 17, 7205, 26, 338,
+    80, 107, 653, 96, 615, 2099, 833, 35, 851, 134, 8404, 11, 845, 556, 509, 557,
+    578, 8405, 313, 96, 1770, 58, 263, 1768, 8406, 1025, 7620, 6227, 542, 402, 455, 98,
+    378, 2657, 8407, 883, 8408, 65, 1159, 26, 107, 323, 1324, 659, 26, 580, 573, 1118,
+    86, 402, 8409, 399, 399, 873, 148, 659, 659, 8410, 442, 323, 719, 1095, 1830, 107,
+    559, 573, 580, 608, 851, 800, 674, 566, 775, 8411, 80, 1117, 350, 290, 107, 580,
+    8412, 1115, 8413, 639, 556, 808, 8411, 883, 243, 323, 96, 83, 400, 96, 539, 463,
+    1650, 522, 378, 1309, 402, 609, 1355, 529, 128, 36, 1222, 575, 1482, 37, 2216, 242,
+    574, 605, 149, 252, 1137, 36, 628, 4275, 1005, 37, 190, 1127, 1163, 74, 252, 16,
+    688, 663, 260, 330, 7054, 653, 38, 8414, 709, 615, 347, 115, 1325, 8415, 921, 8416,
+    8417, 8418, 129, 33, 1404, 1005, 8419, 1845, 1155, 524, 880, 754, 192, 708, 3951, 1005,
+    1222, 945, 8420, 304, 147, 19, 1430, 2216, 813, 709, 19, 161, 61, 322, 901, 496,
+    8421, 735, 3292, 8422, 2220, 569, 1890, 1325, 880, 754, 8423, 709, 77, 115, 219, 5307,
+    61, 1085, 840, 8424, 343, 1355, 197, 1097, 476, 189, 1355, 290, 492, 1890, 378, 1902,
+    1371, 638, 2216, 974, 373, 709, 533, 455, 21, 6, 1882, 123, 101, 630, 56, 122,
+    556, 3989, 598, 3097, 61, 1801, 1650, 127, 851, 373, 416, 8425, 1832, 1718, 480, 219,
+    40, 921, 8426, 2834, 522, 492, 1271, 811, 546, 660, 840, 6, 4073, 1324, 271, 11,
+    53, 728, 728, 1394, 289, 785, 11, 330, 901, 901, 439, 8427, 8428, 8429, 618, 3097,
+    826, 345, 149, 64, 80, 288, 826, 149, 935, 2433, 2483, 405, 4413, 964, 2139, 686,
+    149, 8430, 5, 1279, 1355, 260, 636, 627, 1640, 95, 709, 1020, 815, 1309, 356, 181,
+    8431, 831, 1906, 138, 779, 112, 378, 668, 521, 3313, 531, 766, 260, 531, 320, 574,
+    6, 64, 378, 173, 6, 290, 1355, 55, 83, 197, 110, 4095, 144, 6, 290, 290,
+    1165, 219, 243, 98, 97, 708, 830, 83, 1085, 1602, 64, 402, 667, 14, 35, 131,
+    55, 499, 197, 903, 1093, 903, 107, 218, 1845, 21, 103, 72, 1122, 888, 1355, 273,
+    1155, 879, 686, 245, 252, 8432, 8433, 97, 101, 71, 870, 55, 1602, 55, 580, 26,
+    990, 561, 835, 37, 1777, 580, 30, 37, 630, 580, 903, 903, 1660, 14, 530, 1063,
+    715, 104, 30, 682, 3658, 55, 370, 1686, 234, 1355, 96, 1229, 370, 8434, 8435, 30,
+    173, 290, 1660, 37, 72, 1087, 1602, 630, 870, 234, 184, 715, 886, 1417, 541, 8436,
+    845, 26, 580, 6, 3510, 351, 8437, 1417, 1021, 808, 752, 1116, 397, 903, 149, 785,
+    8438, 532, 122, 199, 138, 260, 484, 181, 43, 202, 7825, 41, 110, 421, 110, 244,
+    128, 40, 8439, 8440, 1070, 8441, 990, 2030, 29, 744, 105, 199, 580, 580, 6, 4991,
+    8442, 2965, 244, 65, 332, 8443, 123, 1660, 8444, 260, 316, 400, 3444, 970, 3510, 395,
+    1079, 154, 1167, 1136, 504, 475, 243, 101, 1117, 819, 8445, 378, 358, 835, 860, 8446,
+    347, 2285, 735, 311, 244, 107, 139, 44, 21, 1390, 580, 727, 323, 8447, 2139, 2294,
+    3943, 1225, 37, 1043, 1588, 589, 1699, 307, 1562, 8448, 2477, 8449, 444, 1830, 600, 546,
+    1431, 154, 56, 154, 443, 684, 1292, 307, 120, 4126, 55, 527, 1747, 1122, 505, 118,
+    157, 8450, 64, 2890, 8451, 1031, 260, 861, 330, 1166, 26, 1084, 646, 18, 442, 580,
+    1390, 575, 625, 290, 168, 363, 243, 1300, 8452, 127, 943, 118, 386, 370, 3076, 1670,
+    390, 8453, 17, 118, 420, 808, 1116, 138, 202, 199, 442, 475, 642, 663, 1095, 835,
+    44, 944, 444, 120, 872, 464, 4, 8454, 1661, 345, 659, 471, 378, 835, 8455, 1020,
+    969, 3989, 390, 8456, 16, 990, 324, 684, 1309, 1020, 1371, 16, 202, 1176, 615, 534,
+    1116, 220, 648, 8457, 46, 8458, 1105, 673, 671, 1118, 1460, 764, 342, 628, 2191, 477,
+    868, 333, 1005, 2580, 263, 14, 76, 138, 277, 908, 990, 585, 378, 2929, 239, 1099,
+    1670, 390, 1005, 1448, 473, 957, 1541, 38, 682, 343, 11, 237, 242, 8459, 343, 333,
+    8460, 290, 6471, 628, 55, 2391, 442, 123, 697, 395, 150, 1855, 555, 8461, 343, 320,
+    4997, 33, 1046, 542, 195, 8462, 8289, 889, 490, 6, 8463, 1441, 41, 530, 551, 480,
+    2102, 429, 536, 7, 826, 873, 5773, 311, 311, 1163, 201, 708, 273, 390, 189, 756,
+    71, 253, 2313, 883, 180, 548, 890, 56, 8464, 8465, 130, 1430, 1149, 1005, 97, 993,
+    67, 679, 111, 390, 727, 405, 78, 111, 919, 529, 311, 65, 1371, 8466, 253, 8467,
+    7182, 8468, 1129, 907, 390, 390, 169, 771, 353, 619, 363, 55, 283, 586, 961, 1964,
+    1001, 2220, 4068, 111, 658, 556, 1670, 316, 234, 239, 1766, 556, 46, 35, 619, 370,
+    179, 76, 26, 682, 504, 60, 56, 36, 1448, 109, 127, 332, 4997, 602, 263, 268,
+    743, 1602, 708, 530, 559, 390, 201, 743, 2313, 1149, 548, 586, 682, 116, 400, 286,
+    8469, 390, 8470, 390, 1847, 1078, 253, 7620, 1279, 1323, 8471, 2298, 220, 842, 812, 6111,
+    149, 38, 69, 8472, 477, 6111, 1686, 1522, 161, 1273, 7840, 41, 303, 568, 7121, 812,
+    2089, 57, 896, 74, 3131, 57, 56, 1046, 1189, 386, 529, 1388, 532, 1847, 993, 288,
+    114, 495, 8473, 130, 216, 774, 303, 735, 517, 1527, 477, 1948, 285, 851, 1003, 684,
+    870, 11, 1087, 605, 8474, 541, 231, 126, 556, 600, 85, 26, 286, 26, 149, 260,
+    126, 118, 8475, 1607, 735, 55, 1020, 470, 2486, 105, 234, 1513, 1149, 909, 8476, 373,
+    521, 574, 6111, 444, 1024, 131, 805, 234, 332, 530, 41, 28, 534, 1386, 8477, 260,
+    19, 477, 2307, 8478, 38, 332, 8479, 8480, 1306, 2935, 679, 1435, 1430, 523, 38, 1347,
+    294, 8481, 609, 41, 504, 1541, 198, 270, 451, 339, 111, 332, 8482, 682, 745, 212,
+    95, 476, 1115, 990, 745, 1347, 197, 533, 442, 8483, 64, 499, 8484, 36, 2135, 1035,
+    1126, 1821, 552, 343, 339, 203, 482, 4905, 8485, 130, 973, 1602, 990, 808, 1960, 300,
+    365, 1229, 745, 1003, 8486, 2898, 715, 870, 294, 1323, 117, 313, 1019, 324, 561, 3146,
+    8487, 4946, 405, 1602, 811, 8488, 58, 8489, 3847, 7077, 156, 462, 5086, 356, 387, 504,
+    301, 530, 350, 1374, 160, 571, 192, 182, 410, 833, 4443, 85, 3085, 8490, 55, 1155,
+    679, 548, 495, 940, 1085, 610, 80, 833, 671, 44, 212, 316, 556, 580, 8491, 306,
+    277, 8492, 7, 240, 557, 8493, 638, 203, 353, 828, 403, 1136, 744, 532, 311, 55,
+    1082, 8494, 8495, 580, 277, 828, 561, 397, 203, 587, 290, 252, 571, 1082, 1136, 748,
+    455, 157, 580, 455, 1562, 234, 1271, 185, 1115, 2755, 167, 243, 74, 708, 345, 8496,
+    327, 969, 1316, 1534, 129, 144, 1440, 1431, 185, 8497, 1522, 545, 180, 681, 2864, 260,
+    253, 1431, 104, 8128, 88, 635, 345, 1329, 67, 580, 43, 1552, 26, 811, 1097, 627,
+    378, 1832, 226, 37, 1199, 192, 8498, 851, 180, 735, 253, 8499, 1431, 185, 779, 1882,
+    322, 332, 993, 54, 746, 138, 13, 169, 2022, 909, 399, 251, 180, 129, 1699, 48,
+    316, 129, 26, 1527, 659, 799, 237, 790, 55, 618, 1025, 536, 1087, 253, 26, 544,
+    363, 665, 138, 239, 1126, 82, 260, 1129, 239, 58, 345, 219, 738, 144, 40, 8500,
+    1533, 236, 114, 177, 8501, 8502, 2657, 828, 438, 204, 277, 2128, 8503, 8504, 4, 343,
+    791, 42, 1431, 967, 385, 779, 3370, 76, 438, 55, 382, 8505, 363, 908, 1448, 775,
+    8506, 765, 473, 438, 8507, 1097, 23, 1306, 404, 7, 1699, 1199, 43, 26, 1126, 960,
+    332, 283, 623, 333, 1203, 26, 290, 78, 1699, 575, 642, 905, 476, 667, 74, 130,
+    1149, 253, 3894, 177, 8508, 2391, 144, 149, 8509, 475, 23, 149, 8510, 236, 801, 532,
+    532, 6, 546, 1813, 304, 2426, 708, 684, 599, 1137, 283, 886, 97, 345, 533, 1078,
+    64, 125, 924, 1882, 209, 171, 870, 883, 253, 71, 243, 1294, 263, 64, 808, 263,
+    903, 4745, 81, 576, 1761, 7, 529, 8511, 576, 569, 45, 3370, 598, 111, 764, 218,
+    333, 655, 1097, 8512, 157, 1832, 502, 568, 1091, 4815, 1628, 328, 47, 801, 283, 243,
+    56, 1021, 8513, 8514, 58, 252, 209, 832, 263, 56, 194, 6, 1091, 682, 252, 660,
+    8, 1087, 11, 273, 96, 659, 55, 290, 168, 365, 598, 960, 215, 197, 239, 58,
+    1782, 1314, 1199, 1078, 149, 204, 343, 8059, 1306, 432, 219, 8, 467, 642, 2391, 532,
+    8515, 43, 709, 1028, 487, 234, 234, 620, 78, 8516, 26, 648, 8517, 493, 202, 362,
+    8518, 642, 535, 1843, 127, 673, 8519, 115, 277, 197, 662, 3675, 343, 1028, 105, 662,
+    1046, 8520, 4073, 1129, 1025, 362, 1035, 67, 65, 316, 881, 455, 90, 860, 1005, 993,
+    239, 204, 8521, 78, 97, 244, 1847, 1118, 207, 673, 589, 8522, 239, 8523, 47, 6,
+    1430, 26, 185, 1699, 652, 38, 8524, 585, 2929, 241, 332, 8411, 78, 780, 8525, 990,
+    502, 568, 343, 8526, 127, 485, 580, 8527, 1025, 7462, 530, 127, 234, 230, 111, 455,
+    283, 297, 575, 239, 3332, 405, 26, 623, 1580, 8528, 556, 735, 316, 8529, 1318, 541,
+    292, 600, 1686, 1046, 886, 8530, 91, 37, 8531, 226, 708, 845, 552, 1329, 303, 580,
+    343, 86, 387, 828, 277, 509, 57, 253, 1153, 177, 1628, 1528, 8532, 460, 1882, 580,
+    311, 1705, 169, 16, 5106, 343, 14, 834, 308, 194, 127, 8533, 390, 390, 1153, 740,
+    105, 653, 343, 655, 54, 1899, 203, 282, 469, 197, 313, 673, 1296, 557, 340, 1019,
+    3741, 960, 17, 239, 119, 263, 1018, 37, 4073, 253, 879, 197, 1588, 569, 3575, 974,
+    1126, 2033, 1610, 526, 56, 167, 1018, 169, 726, 113, 8534, 2330, 5690, 37, 70, 111,
+    459, 679, 900, 260, 78, 8534, 78, 95, 480, 436, 43, 65, 149, 970, 127, 400,
+    8434, 1692, 55, 569, 1448, 81, 239, 7, 81, 709, 343, 1084, 1323, 14, 8535, 974,
+    345, 78, 410, 207, 306, 222, 655, 1166, 879, 8536, 8537, 286, 206, 819, 642, 1882,
+    459, 544, 53, 104, 5566, 17, 8538, 390, 35, 1964, 14, 350, 134, 127, 249, 1271,
+    3465, 199, 1155, 251, 306, 459, 743, 1552, 216, 835, 502, 1355, 408, 199, 974, 405,
+    1129, 58, 556, 934, 101, 95, 108, 399, 1460, 78, 78, 845, 139, 3085, 537, 127,
+    239, 554, 552, 1153, 390, 561, 4073, 807, 249, 182, 78, 1580, 888, 660, 756, 182,
+    8539, 429, 1329, 620, 250, 2426, 1230, 495, 890, 286, 1180, 61, 5560, 56, 8540, 458,
+    236, 204, 131, 1295, 675, 8541, 615, 974, 89, 402, 627, 1325, 159, 42, 390, 655,
+    2047, 460, 598, 285, 580, 1628, 1061, 2864, 529, 746, 8542, 746, 4095, 546, 126, 37,
+    85, 546, 252, 57, 580, 161, 260, 1338, 260, 472, 1670, 583, 808, 239, 131, 524,
+    242, 831, 400, 260, 587, 37, 860, 456, 271, 854, 263, 1273, 127, 752, 5246, 351,
+    8543, 7372, 290, 111, 134, 356, 430, 1540, 967, 1271, 1660, 60, 612, 68, 200, 123,
+    252, 8544, 2147, 1495, 1117, 343, 1082, 870, 1, 5360, 399, 870, 276, 290, 869, 1005,
+    861, 945, 870, 108, 126, 294, 443, 1020, 646, 239, 1001, 5091, 1024, 1408, 242, 242,
+    58, 1119, 26, 619, 1082, 2041, 943, 485, 546, 460, 675, 1046, 1856, 14, 575, 1346,
+    1580, 534, 1153, 34, 831, 2507, 831, 98, 608, 679, 900, 283, 365, 365, 1845, 905,
+    830, 195, 660, 8545, 365, 527, 502, 351, 527, 184, 824, 1684, 4935, 527, 824, 1005,
+    128, 128, 851, 365, 195, 644, 1408, 894, 2099, 1152, 200, 497, 8546, 362, 8547, 126,
+    277, 111, 746, 2100, 1328, 7711, 583, 111, 1371, 1314, 256, 443, 576, 443, 728, 8548,
+    382, 6, 348, 8549, 8550, 114, 202, 66, 969, 430, 493, 37, 477, 75, 115, 411,
+    744, 1482, 502, 665, 965, 578, 580, 735, 460, 367, 7233, 580, 919, 708, 787, 8059,
+    4997, 150, 5508, 8551, 198, 390, 620, 252, 47, 665, 488, 108, 1747, 209, 1633, 40,
+    1559, 8552, 19, 1183, 3097, 8553, 46, 282, 836, 2099, 970, 1296, 1005, 436, 569, 1005,
+    845, 1286, 1271, 123, 569, 679, 111, 26, 1747, 5086, 539, 69, 218, 1627, 8554, 8555,
+    1933, 455, 118, 1610, 168, 1084, 205, 205, 252, 90, 744, 625, 288, 1527, 55, 8556,
+    365, 932, 580, 484, 4935, 2320, 1026, 1026, 88, 501, 295, 546, 528, 295, 167, 728,
+    1430, 2313, 1430, 872, 2972, 557, 138, 61, 400, 1309, 156, 1093, 8557, 8558, 589, 674,
+    589, 382, 1331, 36, 311, 658, 4275, 609, 370, 7499, 370, 584, 609, 1061, 1933, 557,
+    8559, 1316, 969, 546, 277, 970, 260, 2972, 1021, 1061, 2220, 397, 295, 68, 4176, 240,
+    294, 536, 206, 277, 8560, 277, 81, 1199, 26, 104, 71, 195, 353, 111, 571, 586,
+    85, 81, 127, 578, 55, 365, 8561, 502, 8434, 2313, 1725, 556, 819, 800, 1770, 189,
+    11, 737, 1222, 443, 37, 618, 756, 55, 365, 253, 253, 970, 970, 253, 253, 970,
+    1562, 1562, 6358, 509, 218, 677, 808, 218, 548, 212, 462, 300, 1021, 300, 26, 483,
+    1315, 283, 8562, 323, 431, 8563, 202, 990, 1562, 270, 149, 8564, 149, 1687, 432, 1184,
+    1118, 1118, 323, 386, 1687, 56, 754, 529, 1562, 8565, 548, 8566, 8567, 3510, 216, 130,
+    2294, 8058, 277, 735, 150, 290, 1046, 8568, 576, 727, 492, 607, 5, 96, 253, 4707,
+    709, 1627, 149, 6093, 202, 462, 55, 55, 248, 443, 365, 556, 1581, 1562, 431, 370,
+    370, 370, 370, 370, 541, 628, 1180, 603, 1026, 295, 1025, 501, 42, 1, 88, 159,
+    2929, 320, 260, 248, 819, 443, 653, 2980, 1220, 660, 470, 559, 618, 517, 2880, 320,
+    3131, 218, 17, 2176, 451, 619, 55, 828, 37, 113, 303, 967, 26, 1607, 243, 130,
+    685, 400, 58, 26, 6, 521, 568, 311, 1770, 1533, 826, 8569, 455, 175, 260, 532,
+    8570, 363, 255, 204, 451, 400, 826, 400, 586, 556, 242, 202, 226, 369, 2330, 333,
+    37, 527, 660, 1460, 556, 64, 1003, 1660, 123, 244, 557, 167, 11, 245, 378, 2929,
+    1430, 2191, 1933, 682, 845, 556, 478, 1203, 78, 244, 251, 144, 1404, 1122, 107, 55,
+    245, 442, 1902, 1040, 532, 188, 773, 288, 4213, 8571, 5434, 297, 646, 1699, 12, 55,
+    47, 662, 1263, 93, 1782, 243, 619, 2033, 382, 283, 41, 1105, 8572, 231, 4478, 241,
+    727, 216, 55, 1519, 241, 3049, 1028, 1883, 195, 1201, 53, 18, 630, 8573, 241, 222,
+    773, 116, 615, 954, 8574, 2423, 218, 1005, 16, 3840, 279, 103, 964, 26, 395, 709,
+    161, 655, 3521, 773, 110, 61, 1832, 253, 97, 241, 1084, 26, 262, 882, 756, 430,
+    1971, 578, 1702, 534, 114, 245, 468, 504, 282, 940, 8575, 1806, 1770, 4815, 157, 107,
+    773, 8576, 400, 630, 438, 1126, 243, 120, 727, 807, 2972, 45, 919, 886, 1821, 157,
+    509, 1118, 659, 72, 1155, 435, 56, 56, 1770, 8577, 218, 455, 1018, 74, 586, 96,
+    202, 2320, 111, 502, 8578, 8578,
+];
+
+pub(super) static LISTS: [u16; 8580] = [
+    0, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 16, 17, 18,
+    19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 30, 31, 33, 34, 35, 36,
+    37, 38, 39, 40, 41, 42, 43, 44, 46, 47, 48, 49, 50, 51, 52, 53,
+    54, 55, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70,
+    72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 83, 84, 85, 86, 87, 88,
+    89, 90, 91, 92, 93, 95, 96, 97, 100, 101, 102, 103, 104, 106, 107, 109,
+    110, 111, 112, 113, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126,
+    127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 139, 140, 141, 142, 143, 144,
+    145, 146, 147, 148, 149, 151, 153, 154, 156, 157, 158, 159, 160, 162, 163, 164,
+    165, 166, 168, 169, 170, 171, 172, 173, 175, 178, 180, 181, 183, 184, 185, 187,
+    188, 189, 190, 192, 194, 196, 198, 200, 201, 202, 203, 206, 207, 208, 209, 211,
+    212, 214, 215, 217, 218, 219, 220, 221, 223, 224, 225, 226, 228, 229, 230, 231,
+    232, 233, 235, 236, 237, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249,
+    250, 252, 253, 254, 255, 256, 257, 259, 260, 261, 263, 264, 265, 266, 267, 268,
+    270, 272, 274, 275, 276, 278, 280, 281, 282, 284, 285, 286, 287, 288, 289, 291,
+    292, 293, 294, 295, 296, 297, 298, 299, 301, 302, 303, 304, 305, 306, 307, 308,
+    309, 310, 312, 314, 317, 318, 319, 320, 321, 324, 326, 328, 330, 331, 333, 334,
+    335, 337, 338, 339, 341, 342, 343, 345, 346, 348, 350, 351, 352, 353, 354, 355,
+    357, 358, 359, 360, 363, 364, 367, 368, 369, 371, 372, 374, 376, 377, 378, 380,
+    381, 382, 383, 384, 385, 386, 389, 390, 391, 392, 393, 395, 397, 398, 399, 400,
+    402, 403, 404, 405, 406, 407, 409, 410, 411, 412, 414, 415, 416, 417, 418, 420,
+    422, 424, 426, 427, 428, 429, 431, 432, 433, 434, 435, 437, 438, 439, 440, 441,
+    442, 444, 445, 446, 447, 448, 450, 451, 453, 454, 456, 457, 458, 460, 461, 462,
+    463, 464, 465, 466, 467, 469, 470, 471, 472, 473, 475, 476, 478, 479, 482, 483,
+    485, 487, 488, 489, 490, 492, 493, 494, 495, 496, 498, 501, 502, 504, 505, 507,
+    508, 509, 510, 511, 512, 513, 514, 516, 519, 520, 521, 522, 523, 525, 527, 528,
+    531, 532, 534, 536, 537, 538, 539, 540, 541, 543, 544, 547, 549, 550, 551, 552,
+    553, 554, 555, 557, 558, 559, 561, 562, 563, 566, 568, 569, 570, 571, 573, 576,
+    577, 579, 581, 582, 583, 585, 588, 589, 590, 591, 593, 594, 595, 596, 598, 599,
+    600, 601, 603, 605, 606, 607, 608, 609, 610, 611, 612, 613, 614, 615, 616, 617,
+    619, 620, 622, 623, 624, 625, 626, 627, 628, 629, 630, 631, 632, 633, 634, 636,
+    637, 638, 639, 640, 641, 642, 643, 644, 646, 647, 648, 650, 652, 654, 655, 657,
+    659, 661, 662, 663, 665, 666, 667, 669, 671, 672, 673, 674, 675, 676, 678, 679,
+    680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 690, 692, 693, 695, 696, 697,
+    699, 700, 701, 702, 703, 704, 705, 706, 707, 708, 710, 711, 712, 713, 714, 715,
+    716, 718, 719, 721, 723, 724, 726, 727, 728, 729, 730, 732, 733, 734, 735, 736,
+    737, 738, 739, 740, 743, 744, 746, 748, 749, 750, 751, 752, 753, 755, 756, 758,
+    759, 761, 764, 766, 770, 772, 773, 774, 775, 776, 778, 779, 780, 782, 783, 785,
+    786, 787, 788, 789, 791, 792, 795, 796, 797, 798, 801, 802, 803, 804, 806, 809,
+    810, 812, 813, 815, 816, 817, 820, 821, 822, 824, 826, 828, 829, 830, 831, 832,
+    833, 834, 836, 837, 839, 840, 841, 842, 843, 844, 846, 849, 851, 852, 853, 855,
+    856, 857, 859, 860, 861, 862, 863, 864, 865, 867, 868, 870, 871, 872, 875, 877,
+    878, 880, 881, 882, 883, 885, 886, 887, 888, 891, 892, 893, 895, 896, 897, 898,
+    899, 900, 902, 903, 906, 908, 909, 911, 912, 914, 915, 917, 918, 919, 920, 921,
+    923, 924, 926, 928, 930, 931, 932, 934, 935, 937, 938, 940, 941, 942, 944, 946,
+    947, 949, 950, 952, 954, 955, 957, 958, 959, 960, 963, 965, 967, 969, 971, 973,
+    974, 976, 977, 979, 981, 982, 984, 985, 986, 987, 988, 989, 991, 992, 993, 995,
+    997, 998, 1000, 1001, 1003, 1004, 1005, 1008, 1010, 1012, 1015, 1016, 1017, 1018, 1019, 1020,
+    1023, 1024, 1026, 1027, 1028, 1030, 1031, 1032, 1033, 1035, 1037, 1040, 1041, 1042, 1043, 1044,
+    1046, 1048, 1049, 1050, 1051, 1054, 1055, 1056, 1057, 1059, 1061, 1064, 1066, 1067, 1069, 1072,
+    1073, 1074, 1075, 1077, 1079, 1081, 1082, 1083, 1084, 1085, 1087, 1089, 1090, 1091, 1092, 1094,
+    1095, 1097, 1099, 1103, 1104, 1105, 1106, 1108, 1111, 1112, 1116, 1117, 1119, 1120, 1121, 1122,
+    1123, 1124, 1125, 1126, 1127, 1128, 1131, 1133, 1135, 1136, 1137, 1138, 1140, 1143, 1144, 1147,
+    1149, 1153, 1155, 1156, 1157, 1158, 1160, 1161, 1163, 1165, 1166, 1167, 1169, 1170, 1171, 1173,
+    1176, 1178, 1179, 1181, 1183, 1184, 1185, 1186, 1188, 1189, 1190, 1192, 1194, 1196, 1198, 1199,
+    1200, 1201, 1202, 1203, 1204, 1206, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1216, 1218, 1219,
+    1221, 1222, 1224, 1226, 1227, 1228, 1229, 1230, 1231, 1234, 1235, 1236, 1237, 1238, 1239, 1241,
+    1243, 1245, 1247, 1249, 1252, 1254, 1256, 1258, 1259, 1262, 1263, 1265, 1266, 1267, 1268, 1271,
+    1273, 1275, 1277, 1279, 1281, 1282, 1284, 1285, 1286, 1288, 1290, 1292, 1295, 1296, 1298, 1299,
+    1300, 1301, 1302, 1304, 1306, 1307, 1309, 1310, 1312, 1313, 1314, 1315, 1317, 1319, 1320, 1323,
+    1324, 1325, 1327, 1329, 1331, 1332, 1333, 1334, 1335, 1337, 1338, 1339, 1341, 1343, 1344, 1345,
+    1346, 1350, 1353, 1354, 1356, 1358, 1360, 1363, 1365, 1368, 1370, 1372, 1374, 1376, 1378, 1379,
+    1381, 1383, 1384, 1386, 1388, 1390, 1391, 1392, 1394, 1396, 1397, 1398, 1399, 1400, 1401, 1402,
+    1404, 1406, 1408, 1411, 1413, 1415, 1417, 1419, 1420, 1422, 1424, 1425, 1426, 1427, 1428, 1430,
+    1431, 1432, 1433, 1434, 1436, 1437, 1439, 1441, 1442, 1443, 1445, 1447, 1448, 1450, 1452, 1454,
+    1455, 1456, 1458, 1462, 1463, 1465, 1467, 1468, 1470, 1472, 1474, 1478, 1480, 1482, 1483, 1485,
+    1487, 1489, 1490, 1493, 1494, 1495, 1496, 1497, 1498, 1500, 1502, 1505, 1506, 1507, 1509, 1510,
+    1512, 1513, 1515, 1517, 1519, 1522, 1524, 1525, 1526, 1528, 1530, 1531, 1532, 1533, 1534, 1536,
+    1537, 1538, 1540, 1541, 1542, 1544, 1545, 1547, 1548, 1550, 1551, 1552, 1554, 1555, 1556, 1558,
+    1559, 1561, 1562, 1563, 1565, 1566, 1567, 1569, 1570, 1571, 1573, 1574, 1575, 1576, 1577, 1578,
+    1579, 1582, 1584, 1585, 1587, 1589, 1591, 1592, 1593, 1594, 1595, 1596, 1599, 1601, 1603, 1604,
+    1606, 1607, 1608, 1610, 1612, 1614, 1616, 1618, 1619, 1621, 1625, 1629, 1631, 1633, 1634, 1636,
+    1638, 1639, 1640, 1641, 1642, 1643, 1645, 1647, 1648, 1649, 1651, 1652, 1653, 1655, 1656, 1657,
+    1658, 1660, 1662, 1664, 1666, 1668, 1670, 1672, 1674, 1675, 1677, 1679, 1681, 1682, 1684, 1685,
+    1686, 1687, 1688, 1690, 1693, 1695, 1696, 1698, 1700, 1702, 1705, 1707, 1709, 1711, 1714, 1716,
+    1717, 1718, 1719, 1721, 1722, 1723, 1725, 1727, 1730, 1731, 1733, 1736, 1738, 1740, 1742, 1744,
+    1746, 1748, 1751, 1753, 1754, 1755, 1756, 1757, 1759, 1761, 1762, 1764, 1766, 1767, 1768, 1769,
+    1771, 1773, 1775, 1778, 1781, 1783, 1785, 1787, 1788, 1791, 1793, 1798, 1800, 1801, 1803, 1804,
+    1806, 1808, 1810, 1813, 1815, 1817, 1820, 1822, 1823, 1824, 1826, 1828, 1831, 1833, 1834, 1836,
+    1837, 1839, 1841, 1843, 1845, 1847, 1849, 1851, 1852, 1854, 1855, 1856, 1858, 1860, 1862, 1864,
+    1865, 1866, 1868, 1870, 1871, 1873, 1875, 1877, 1879, 1881, 1883, 1885, 1887, 1888, 1891, 1892,
+    1893, 1894, 1896, 1899, 1901, 1902, 1905, 1907, 1909, 1911, 1913, 1914, 1915, 1916, 1917, 1919,
+    1920, 1922, 1923, 1924, 1925, 1926, 1928, 1930, 1933, 1935, 1937, 1939, 1940, 1941, 1942, 1943,
+    1948, 1949, 1950, 1953, 1954, 1956, 1958, 1960, 1961, 1962, 1964, 1965, 1967, 1969, 1971, 1973,
+    1975, 1977, 1979, 1980, 1981, 1983, 1985, 1987, 1989, 1991, 1993, 1995, 1996, 1998, 2000, 2002,
+    2004, 2006, 2008, 2010, 2012, 2014, 2016, 2018, 2020, 2023, 2025, 2027, 2028, 2030, 2032, 2033,
+    2035, 2037, 2039, 2041, 2043, 2047, 2049, 2051, 2053, 2055, 2057, 2058, 2060, 2061, 2063, 2064,
+    2066, 2068, 2070, 2071, 2073, 2074, 2076, 2077, 2079, 2081, 2083, 2084, 2086, 2087, 2089, 2090,
+    2092, 2093, 2095, 2097, 2099, 2101, 2103, 2104, 2106, 2107, 2108, 2110, 2112, 2114, 2115, 2117,
+    2119, 2121, 2123, 2124, 2126, 2128, 2131, 2132, 2133, 2135, 2136, 2137, 2138, 2140, 2141, 2143,
+    2145, 2146, 2147, 2149, 2150, 2151, 2154, 2156, 2158, 2159, 2161, 2162, 2163, 2165, 2167, 2169,
+    2170, 2171, 2173, 2175, 2177, 2178, 2180, 2182, 2184, 2186, 2188, 2190, 2191, 2195, 2197, 2200,
+    2202, 2203, 2205, 2207, 2209, 2212, 2214, 2216, 2217, 2220, 2222, 2223, 2225, 2227, 2229, 2231,
+    2233, 2235, 2237, 2240, 2242, 2244, 2246, 2247, 2248, 2249, 2251, 2254, 2256, 2258, 2260, 2263,
+    2265, 2267, 2269, 2272, 2274, 2277, 2279, 2280, 2282, 2284, 2285, 2287, 2289, 2290, 2293, 2295,
+    2296, 2299, 2301, 2302, 2304, 2307, 2309, 2310, 2311, 2312, 2313, 2314, 2316, 2317, 2318, 2319,
+    2321, 2323, 2324, 2326, 2328, 2329, 2330, 2333, 2335, 2337, 2338, 2340, 2342, 2344, 2346, 2348,
+    2350, 2351, 2353, 2355, 2356, 2358, 2360, 2362, 2363, 2364, 2367, 2368, 2371, 2372, 2373, 2375,
+    2378, 2380, 2382, 2384, 2386, 2388, 2389, 2391, 2393, 2394, 2395, 2397, 2399, 2400, 2401, 2403,
+    2405, 2406, 2407, 2409, 2411, 2412, 2413, 2415, 2417, 2419, 2421, 2423, 2425, 2427, 2429, 2431,
+    2432, 2434, 2436, 2437, 2439, 2441, 2444, 2446, 2447, 2449, 2450, 2451, 2452, 2453, 2455, 2457,
+    2459, 2461, 2463, 2465, 2467, 2469, 2471, 2473, 2475, 2476, 2478, 2480, 2481, 2482, 2484, 2486,
+    2488, 2490, 2491, 2493, 2495, 2496, 2498, 2500, 2503, 2504, 2506, 2508, 2510, 2513, 2514, 2516,
+    2518, 2520, 2523, 2524, 2525, 2527, 2529, 2532, 2533, 2535, 2538, 2541, 2543, 2544, 2546, 2548,
+    2551, 2553, 2556, 2558, 2560, 2562, 2564, 2565, 2567, 2569, 2571, 2572, 2574, 2576, 2578, 2580,
+    2582, 2584, 2586, 2588, 2590, 2591, 2593, 2594, 2595, 2597, 2599, 2601, 2603, 2604, 2606, 2608,
+    2610, 2613, 2616, 2618, 2619, 2621, 2623, 2624, 2626, 2628, 2629, 2631, 2632, 2634, 2636, 2639,
+    2641, 2643, 2645, 2647, 2650, 2652, 2653, 2654, 2657, 2659, 2661, 2663, 2665, 2667, 2668, 2670,
+    2672, 2673, 2675, 2677, 2679, 2681, 2683, 2685, 2688, 2691, 2694, 2696, 2699, 2701, 2703, 2705,
+    2707, 2709, 2711, 2713, 2714, 2716, 2718, 2720, 2722, 2724, 2726, 2727, 2728, 2730, 2731, 2733,
+    2735, 2737, 2738, 2740, 2742, 2744, 2747, 2748, 2750, 2751, 2754, 2755, 2757, 2758, 2760, 2762,
+    2764, 2767, 2768, 2770, 2772, 2774, 2777, 2778, 2780, 2782, 2785, 2787, 2790, 2793, 2795, 2797,
+    2799, 2802, 2804, 2807, 2809, 2811, 2812, 2814, 2816, 2818, 2819, 2822, 2824, 2825, 2827, 2828,
+    2831, 2833, 2834, 2836, 2837, 2839, 2840, 2842, 2844, 2846, 2848, 2850, 2852, 2854, 2855, 2857,
+    2858, 2860, 2863, 2865, 2866, 2868, 2870, 2871, 2872, 2873, 2875, 2877, 2879, 2880, 2882, 2884,
+    2886, 2888, 2889, 2891, 2892, 2894, 2895, 2897, 2898, 2900, 2901, 2903, 2905, 2907, 2909, 2911,
+    2913, 2914, 2917, 2920, 2922, 2924, 2926, 2928, 2930, 2932, 2934, 2936, 2938, 2940, 2942, 2944,
+    2947, 2949, 2951, 2953, 2954, 2956, 2958, 2961, 2963, 2965, 2968, 2969, 2970, 2972, 2975, 2977,
+    2979, 2982, 2984, 2985, 2987, 2988, 2991, 2993, 2995, 2999, 3001, 3004, 3005, 3007, 3011, 3012,
+    3014, 3016, 3018, 3019, 3021, 3024, 3025, 3027, 3029, 3031, 3033, 3035, 3038, 3040, 3042, 3044,
+    3046, 3047, 3048, 3050, 3052, 3054, 3056, 3057, 3060, 3062, 3064, 3065, 3067, 3069, 3070, 3072,
+    3074, 3076, 3078, 3080, 3082, 3083, 3085, 3087, 3089, 3091, 3093, 3095, 3097, 3098, 3100, 3102,
+    3104, 3106, 3108, 3110, 3112, 3114, 3116, 3119, 3121, 3123, 3126, 3128, 3131, 3132, 3135, 3137,
+    3139, 3141, 3143, 3145, 3146, 3148, 3150, 3153, 3155, 3158, 3160, 3161, 3162, 3164, 3166, 3168,
+    3171, 3172, 3174, 3176, 3178, 3179, 3181, 3183, 3186, 3189, 3191, 3193, 3195, 3197, 3199, 3203,
+    3205, 3207, 3210, 3212, 3214, 3216, 3218, 3220, 3222, 3224, 3227, 3229, 3231, 3233, 3235, 3237,
+    3239, 3242, 3244, 3245, 3247, 3249, 3251, 3252, 3254, 3255, 3256, 3258, 3260, 3262, 3264, 3265,
+    3267, 3269, 3270, 3273, 3275, 3277, 3280, 3282, 3283, 3285, 3286, 3288, 3290, 3292, 3294, 3297,
+    3298, 3300, 3302, 3304, 3307, 3309, 3312, 3314, 3317, 3319, 3320, 3322, 3324, 3326, 3331, 3334,
+    3337, 3339, 3343, 3344, 3347, 3349, 3352, 3354, 3356, 3359, 3361, 3363, 3365, 3367, 3369, 3371,
+    3373, 3375, 3377, 3379, 3383, 3386, 3388, 3390, 3392, 3395, 3396, 3399, 3400, 3402, 3404, 3406,
+    3408, 3409, 3410, 3411, 3412, 3413, 3414, 3415, 3417, 3419, 3421, 3424, 3425, 3428, 3430, 3434,
+    3436, 3438, 3441, 3443, 3446, 3448, 3451, 3453, 3456, 3457, 3459, 3462, 3463, 3465, 3467, 3471,
+    3473, 3474, 3476, 3478, 3480, 3484, 3486, 3489, 3490, 3492, 3494, 3495, 3496, 3499, 3501, 3505,
+    3507, 3510, 3514, 3516, 3517, 3518, 3520, 3522, 3524, 3526, 3529, 3531, 3534, 3536, 3539, 3541,
+    3543, 3546, 3548, 3551, 3553, 3557, 3559, 3561, 3563, 3565, 3567, 3569, 3571, 3572, 3574, 3576,
+    3577, 3578, 3582, 3584, 3587, 3589, 3591, 3593, 3595, 3598, 3602, 3604, 3606, 3609, 3611, 3614,
+    3615, 3617, 3619, 3623, 3626, 3628, 3632, 3636, 3638, 3642, 3645, 3648, 3650, 3651, 3652, 3653,
+    3655, 3657, 3659, 3661, 3663, 3665, 3667, 3674, 3677, 3678, 3680, 3682, 3686, 3687, 3689, 3691,
+    3693, 3695, 3699, 3701, 3703, 3705, 3706, 3708, 3711, 3713, 3716, 3718, 3720, 3722, 3725, 3727,
+    3729, 3731, 3734, 3736, 3739, 3741, 3742, 3743, 3745, 3747, 3749, 3751, 3753, 3755, 3756, 3759,
+    3761, 3763, 3767, 3769, 3772, 3774, 3777, 3779, 3781, 3783, 3786, 3788, 3791, 3793, 3795, 3798,
+    3800, 3803, 3806, 3809, 3811, 3813, 3815, 3817, 3819, 3822, 3825, 3827, 3829, 3830, 3831, 3833,
+    3836, 3842, 3845, 3847, 3849, 3851, 3853, 3854, 3857, 3860, 3862, 3863, 3865, 3868, 3870, 3871,
+    3874, 3878, 3881, 3883, 3885, 3887, 3888, 3890, 3892, 3896, 3897, 3899, 3901, 3904, 3906, 3908,
+    3910, 3911, 3916, 3919, 3921, 3923, 3925, 3927, 3929, 3930, 3931, 3932, 3934, 3937, 3939, 3942,
+    3944, 3946, 3948, 3950, 3953, 3955, 3957, 3959, 3961, 3964, 3967, 3971, 3973, 3975, 3977, 3982,
+    3985, 3987, 3989, 3992, 3994, 3997, 3999, 4002, 4004, 4005, 4010, 4012, 4014, 4016, 4019, 4021,
+    4023, 4025, 4027, 4029, 4033, 4036, 4037, 4040, 4043, 4046, 4048, 4050, 4052, 4054, 4056, 4059,
+    4062, 4065, 4067, 4069, 4070, 4072, 4074, 4077, 4078, 4081, 4083, 4085, 4088, 4090, 4095, 4097,
+    4099, 4100, 4104, 4106, 4109, 4111, 4113, 4115, 4118, 4121, 4123, 4125, 4127, 4129, 4131, 4132,
+    4135, 4137, 4139, 4141, 4144, 4145, 4147, 4149, 4150, 4152, 4154, 4155, 4157, 4159, 4161, 4163,
+    4165, 4167, 4168, 4171, 4174, 4175, 4180, 4182, 4184, 4186, 4188, 4190, 4192, 4194, 4196, 4198,
+    4201, 4203, 4205, 4209, 4211, 4213, 4215, 4217, 4219, 4221, 4223, 4225, 4228, 4230, 4232, 4235,
+    4237, 4239, 4242, 4243, 4246, 4248, 4249, 4254, 4256, 4258, 4261, 4263, 4266, 4268, 4270, 4272,
+    4274, 4275, 4277, 4278, 4279, 4281, 4284, 4285, 4287, 4292, 4294, 4297, 4299, 4301, 4304, 4307,
+    4308, 4312, 4314, 4316, 4319, 4322, 4324, 4326, 4329, 4331, 4333, 4335, 4336, 4338, 4341, 4343,
+    4344, 4347, 4349, 4354, 4357, 4362, 4365, 4367, 4370, 4372, 4374, 4377, 4379, 4381, 4383, 4386,
+    4388, 4390, 4391, 4393, 4395, 4397, 4403, 4405, 4408, 4412, 4414, 4416, 4418, 4420, 4422, 4424,
+    4426, 4428, 4430, 4432, 4434, 4436, 4438, 4443, 4445, 4447, 4449, 4451, 4453, 4455, 4457, 4459,
+    4461, 4464, 4470, 4474, 4475, 4477, 4479, 4483, 4485, 4487, 4489, 4492, 4495, 4499, 4500, 4503,
+    4505, 4508, 4511, 4515, 4518, 4519, 4521, 4524, 4527, 4530, 4532, 4534, 4535, 4538, 4540, 4542,
+    4544, 4546, 4549, 4551, 4553, 4555, 4557, 4560, 4562, 4564, 4567, 4569, 4571, 4574, 4577, 4580,
+    4582, 4584, 4587, 4590, 4592, 4594, 4596, 4599, 4602, 4603, 4606, 4608, 4610, 4614, 4617, 4619,
+    4621, 4622, 4625, 4628, 4631, 4634, 4636, 4639, 4641, 4644, 4647, 4649, 4654, 4656, 4659, 4661,
+    4664, 4665, 4667, 4668, 4670, 4674, 4676, 4678, 4680, 4682, 4686, 4689, 4692, 4694, 4700, 4708,
+    4711, 4714, 4715, 4717, 4722, 4726, 4728, 4730, 4733, 4735, 4739, 4745, 4747, 4749, 4752, 4754,
+    4757, 4758, 4760, 4762, 4764, 4768, 4770, 4775, 4777, 4781, 4785, 4788, 4792, 4797, 4799, 4803,
+    4807, 4809, 4814, 4816, 4819, 4821, 4823, 4827, 4833, 4837, 4838, 4839, 4842, 4844, 4850, 4855,
+    4862, 4863, 4865, 4867, 4869, 4871, 4873, 4875, 4877, 4879, 4880, 4881, 4883, 4886, 4889, 4892,
+    4897, 4903, 4911, 4914, 4919, 4921, 4923, 4926, 4927, 4930, 4932, 4933, 4935, 4938, 4941, 4943,
+    4946, 4948, 4950, 4952, 4953, 4955, 4957, 4959, 4961, 4962, 4964, 4966, 4968, 4973, 4975, 4976,
+    4978, 4980, 4982, 4984, 4985, 4990, 4991, 4992, 4994, 4997, 5001, 5004, 5010, 5013, 5015, 5020,
+    5021, 5026, 5031, 5033, 5035, 5037, 5040, 5043, 5048, 5050, 5052, 5054, 5057, 5059, 5061, 5063,
+    5064, 5066, 5068, 5071, 5073, 5075, 5079, 5081, 5084, 5087, 5089, 5092, 5097, 5102, 5103, 5105,
+    5109, 5112, 5116, 5118, 5120, 5122, 5124, 5126, 5128, 5130, 5132, 5140, 5142, 5145, 5148, 5149,
+    5151, 5153, 5155, 5159, 5163, 5166, 5169, 5172, 5174, 5178, 5182, 5185, 5187, 5190, 5192, 5195,
+    5200, 5203, 5204, 5206, 5208, 5210, 5212, 5215, 5216, 5219, 5223, 5226, 5229, 5232, 5235, 5238,
+    5241, 5243, 5246, 5249, 5251, 5256, 5258, 5264, 5266, 5270, 5272, 5274, 5275, 5277, 5281, 5283,
+    5285, 5286, 5288, 5290, 5292, 5295, 5300, 5302, 5305, 5308, 5310, 5312, 5315, 5317, 5319, 5322,
+    5325, 5326, 5327, 5329, 5331, 5335, 5339, 5341, 5343, 5346, 5349, 5351, 5353, 5357, 5360, 5362,
+    5364, 5366, 5370, 5371, 5373, 5376, 5378, 5381, 5382, 5384, 5386, 5388, 5391, 5393, 5395, 5398,
+    5399, 5401, 5403, 5405, 5407, 5409, 5411, 5414, 5416, 5418, 5421, 5423, 5426, 5427, 5431, 5433,
+    5434, 5436, 5437, 5440, 5442, 5444, 5446, 5448, 5449, 5451, 5456, 5458, 5460, 5462, 5466, 5467,
+    5469, 5472, 5474, 5477, 5479, 5481, 5485, 5487, 5489, 5492, 5494, 5496, 5498, 5500, 5502, 5504,
+    5506, 5509, 5511, 5513, 5515, 5517, 5518, 5521, 5523, 5525, 5527, 5529, 5531, 5532, 5535, 5538,
+    5541, 5543, 5545, 5546, 5549, 5551, 5553, 5555, 5557, 5559, 5561, 5563, 5568, 5570, 5572, 5574,
+    5576, 5579, 5581, 5583, 5585, 5587, 5589, 5592, 5596, 5598, 5600, 5604, 5606, 5608, 5610, 5612,
+    5615, 5617, 5619, 5621, 5623, 5625, 5628, 5631, 5633, 5636, 5638, 5641, 5643, 5647, 5648, 5649,
+    5651, 5653, 5656, 5661, 5664, 5666, 5669, 5671, 5673, 5675, 5679, 5682, 5684, 5685, 5688, 5690,
+    5692, 5694, 5697, 5699, 5701, 5703, 5706, 5707, 5709, 5713, 5714, 5717, 5719, 5721, 5723, 5724,
+    5726, 5728, 5730, 5733, 5735, 5737, 5739, 5741, 5743, 5745, 5747, 5749, 5752, 5754, 5756, 5758,
+    5761, 5763, 5765, 5768, 5772, 5773, 5775, 5777, 5779, 5780, 5783, 5785, 5788, 5789, 5790, 5792,
+    5794, 5796, 5798, 5799, 5801, 5803, 5805, 5807, 5809, 5811, 5812, 5816, 5818, 5820, 5821, 5824,
+    5826, 5828, 5831, 5833, 5835, 5837, 5839, 5841, 5843, 5846, 5849, 5854, 5855, 5857, 5861, 5865,
+    5867, 5869, 5871, 5875, 5879, 5882, 5884, 5886, 5888, 5890, 5895, 5897, 5898, 5901, 5904, 5906,
+    5908, 5911, 5912, 5915, 5917, 5920, 5921, 5923, 5925, 5927, 5929, 5930, 5931, 5933, 5935, 5937,
+    5939, 5941, 5943, 5945, 5948, 5950, 5952, 5954, 5956, 5958, 5960, 5962, 5964, 5966, 5969, 5971,
+    5973, 5975, 5978, 5982, 5984, 5987, 5990, 5992, 5996, 5998, 6001, 6003, 6005, 6007, 6009, 6013,
+    6015, 6017, 6020, 6022, 6024, 6027, 6029, 6031, 6033, 6035, 6038, 6041, 6044, 6046, 6048, 6049,
+    6051, 6053, 6055, 6058, 6061, 6064, 6066, 6069, 6073, 6076, 6078, 6081, 6083, 6085, 6087, 6089,
+    6091, 6093, 6095, 6097, 6100, 6102, 6105, 6107, 6109, 6112, 6113, 6115, 6117, 6120, 6124, 6127,
+    6130, 6132, 6134, 6136, 6139, 6141, 6143, 6145, 6146, 6148, 6151, 6154, 6156, 6159, 6162, 6165,
+    6167, 6169, 6171, 6173, 6174, 6176, 6180, 6183, 6185, 6188, 6190, 6194, 6196, 6198, 6200, 6203,
+    6205, 6207, 6209, 6211, 6213, 6216, 6219, 6221, 6223, 6224, 6225, 6227, 6229, 6231, 6232, 6236,
+    6239, 6242, 6243, 6246, 6249, 6252, 6254, 6256, 6258, 6259, 6261, 6263, 6267, 6268, 6270, 6272,
+    6274, 6276, 6281, 6283, 6284, 6288, 6290, 6292, 6294, 6297, 6299, 6302, 6304, 6306, 6308, 6310,
+    6312, 6314, 6316, 6318, 6320, 6324, 6327, 6330, 6335, 6338, 6341, 6343, 6345, 6347, 6348, 6350,
+    6352, 6353, 6355, 6356, 6358, 6359, 6361, 6364, 6365, 6367, 6369, 6371, 6373, 6375, 6377, 6379,
+    6383, 6385, 6387, 6391, 6393, 6395, 6397, 6399, 6402, 6404, 6406, 6408, 6410, 6412, 6414, 6416,
+    6418, 6422, 6424, 6426, 6428, 6430, 6432, 6434, 6436, 6437, 6439, 6440, 6442, 6445, 6447, 6449,
+    6451, 6453, 6455, 6457, 6459, 6462, 6464, 6466, 6468, 6470, 6472, 6474, 6477, 6479, 6481, 6483,
+    6485, 6487, 6489, 6491, 6493, 6495, 6497, 6499, 6501, 6503, 6505, 6507, 6509, 6511, 6513, 6515,
+    6517, 6519, 6521, 6523, 6525, 6526, 6528, 6529, 6532, 6535, 6538, 6540, 6542, 6544, 6546, 6549,
+    6551, 6552, 6554, 6555, 6562, 6564, 6566, 6568, 6570, 6572, 6574, 6576, 6579, 6581, 6583, 6585,
+    6587, 6589, 6592, 6594, 6596, 6598, 6600, 6603, 6605, 6607, 6609, 6612, 6614, 6616, 6618, 6620,
+    6623, 6627, 6629, 6631, 6633, 6637, 6639, 6641, 6645, 6648, 6649, 6651, 6654, 6656, 6658, 6661,
+    6663, 6664, 6667, 6669, 6670, 6672, 6674, 6677, 6679, 6683, 6686, 6689, 6691, 6693, 6697, 6699,
+    6701, 6703, 6705, 6707, 6709, 6711, 6713, 6716, 6718, 6720, 6723, 6725, 6727, 6729, 6731, 6734,
+    6736, 6737, 6741, 6744, 6746, 6748, 6750, 6751, 6753, 6755, 6759, 6762, 6764, 6766, 6768, 6771,
+    6773, 6775, 6776, 6778, 6780, 6782, 6784, 6785, 6788, 6790, 6795, 6797, 6799, 6802, 6804, 6806,
+    6809, 6812, 6816, 6818, 6820, 6822, 6823, 6825, 6826, 6828, 6831, 6833, 6835, 6838, 6840, 6842,
+    6844, 6846, 6848, 6850, 6852, 6854, 6856, 6858, 6860, 6863, 6865, 6867, 6870, 6872, 6874, 6876,
+    6878, 6879, 6881, 6883, 6885, 6887, 6889, 6891, 6893, 6895, 6899, 6901, 6904, 6906, 6908, 6910,
+    6913, 6915, 6918, 6920, 6922, 6924, 6926, 6927, 6930, 6932, 6934, 6936, 6938, 6940, 6942, 6945,
+    6947, 6949, 6951, 6952, 6954, 6956, 6960, 6961, 6962, 6964, 6966, 6969, 6972, 6974, 6976, 6978,
+    6980, 6982, 6984, 6986, 6988, 6990, 6993, 6995, 6998, 7000, 7002, 7005, 7007, 7012, 7014, 7016,
+    7019, 7021, 7023, 7025, 7027, 7029, 7032, 7035, 7037, 7040, 7044, 7047, 7050, 7053, 7056, 7059,
+    7061, 7063, 7066, 7068, 7070, 7072, 7076, 7079, 7081, 7082, 7084, 7086, 7088, 7090, 7092, 7094,
+    7097, 7099, 7101, 7103, 7106, 7108, 7110, 7113, 7116, 7118, 7121, 7124, 7126, 7128, 7130, 7132,
+    7136, 7138, 7140, 7142, 7145, 7147, 7150, 7151, 7154, 7157, 7160, 7162, 7164, 7165, 7167, 7168,
+    7170, 7171, 7173, 7175, 7177, 7179, 7181, 7183, 7185, 7187, 7189, 7190, 7192, 7194, 7197, 7199,
+    7201, 7207, 7208, 7210, 7212, 7216, 7218, 7219, 7221, 7225, 7228, 7230, 7234, 7236, 7238, 7242,
+    7244, 7246, 7248, 7251, 7254, 7258, 7260, 7264, 7268, 7270, 7273, 7275, 7277, 7279, 7281, 7284,
+    7286, 7289, 7291, 7295, 7298, 7300, 7304, 7305, 7307, 7309, 7313, 7316, 7318, 7320, 7321, 7323,
+    7325, 7328, 7330, 7332, 7335, 7338, 7340, 7342, 7345, 7347, 7350, 7352, 7355, 7357, 7361, 7365,
+    7369, 7373, 7376, 7381, 7384, 7386, 7388, 7390, 7391, 7393, 7396, 7401, 7403, 7407, 7411, 7416,
+    7419, 7421, 7422, 7425, 7427, 7429, 7431, 7432, 7434, 7436, 7438, 7440, 7441, 7444, 7447, 7449,
+    7452, 7455, 7457, 7460, 7465, 7466, 7468, 7471, 7474, 7480, 7482, 7483, 7485, 7487, 7489, 7491,
+    7493, 7495, 7498, 7501, 7504, 7506, 7510, 7512, 7515, 7517, 7519, 7522, 7525, 7527, 7529, 7532,
+    7537, 7538, 7540, 7543, 7545, 7547, 7549, 7552, 7553, 7556, 7557, 7561, 7563, 7566, 7569, 7571,
+    7574, 7576, 7579, 7582, 7584, 7587, 7590, 7594, 7598, 7600, 7602, 7604, 7607, 7609, 7613, 7615,
+    7617, 7618, 7621, 7623, 7625, 7627, 7631, 7633, 7636, 7638, 7640, 7642, 7646, 7649, 7652, 7655,
+    7657, 7659, 7660, 7662, 7664, 7665, 7668, 7669, 7671, 7673, 7675, 7678, 7683, 7687, 7689, 7691,
+    7693, 7698, 7700, 7702, 7704, 7706, 7708, 7710, 7712, 7715, 7717, 7719, 7721, 7727, 7729, 7731,
+    7734, 7736, 7738, 7741, 7744, 7748, 7750, 7753, 7754, 7756, 7758, 7760, 7762, 7765, 7768, 7771,
+    7775, 7778, 7780, 7782, 7785, 7788, 7790, 7794, 7795, 7798, 7800, 7802, 7804, 7806, 7809, 7811,
+    7813, 7815, 7818, 7821, 7826, 7828, 7829, 7830, 7833, 7835, 7840, 7842, 7845, 7847, 7849, 7851,
+    7853, 7855, 7857, 7860, 7862, 7865, 7867, 7869, 7871, 7873, 7875, 7877, 7880, 7883, 7885, 7887,
+    7890, 7895, 7897, 7901, 7903, 7907, 7908, 7911, 7916, 7920, 7922, 7924, 7927, 7929, 7931, 7933,
+    7935, 7938, 7942, 7949, 7951, 7954, 7959, 7961, 7963, 7968, 7971, 7973, 7975, 7976, 7979, 7981,
+    7984, 7987, 7990, 7993, 7996, 8004, 8006, 8008, 8009, 8011, 8013, 8018, 8021, 8023, 8026, 8029,
+    8032, 8034, 8036, 8038, 8041, 8043, 8045, 8047, 8049, 8053, 8055, 8059, 8061, 8065, 8067, 8069,
+    8071, 8073, 8076, 8078, 8080, 8083, 8085, 8087, 8089, 8091, 8093, 8095, 8098, 8100, 8103, 8105,
+    8115, 8117, 8120, 8123, 8125, 8126, 8128, 8131, 8133, 8135, 8136, 8139, 8143, 8145, 8147, 8150,
+    8152, 8154, 8156, 8158, 8160, 8162, 8168, 8170, 8173, 8176, 8178, 8182, 8185, 8187, 8189, 8193,
+    8194, 8196, 8198, 8200, 8201, 8203, 8205, 8207, 8209, 8212, 8214, 8216, 8218, 8220, 8222, 8224,
+    8226, 8228, 8230, 8232, 8234, 8237, 8239, 8241, 8243, 8245, 8248, 8251, 8254, 8256, 8258, 8259,
+    8261, 8264, 8266, 8268, 8271, 8273, 8276, 8278, 8280, 8282, 8284, 8286, 8288, 8290, 8293, 8296,
+    8298, 8300, 8303, 8304, 8306, 8308, 8311, 8314, 8316, 8319, 8321, 8323, 8327, 8329, 8333, 8336,
+    8338, 8342, 8345, 8347, 8349, 8351, 8353, 8355, 8357, 8359, 8361, 8364, 8366, 8371, 8374, 8376,
+    8378, 8379, 8383, 8385, 8387, 8390, 8392, 8394, 8396, 8397, 8399, 8404, 8406, 8408, 8411, 8416,
+    8419, 8420, 8423, 8425, 8428, 8430, 8432, 8434, 8436, 8438, 8440, 8442, 8446, 8448, 8451, 8453,
+    8455, 8459, 8461, 8463, 8465, 8467, 8469, 8472, 8474, 8475, 8477, 8480, 8482, 8486, 8488, 8490,
+    8493, 8496, 8498, 8501, 8504, 8507, 8510, 8512, 8514, 8516, 8518, 8521, 8523, 8526, 8528, 8531,
+    8534, 8537, 8540, 8542, 8547, 8550, 8552, 8556, 8558, 8560, 8562, 8564, 8566, 8569, 8571, 8574,
+    8576, 8579, 8581, 8585, 8587, 8589, 8591, 8593, 8597, 8598, 8601, 8605, 8607, 8610, 8613, 8615,
+    8618, 8620, 8622, 8625, 8626, 8629, 8631, 8633, 8636, 8638, 8640, 8642, 8644, 8647, 8650, 8652,
+    8654, 8657, 8659, 8661, 8665, 8668, 8671, 8674, 8676, 8678, 8680, 8682, 8685, 8687, 8691, 8693,
+    8695, 8698, 8703, 8705, 8708, 8711, 8714, 8715, 8717, 8722, 8724, 8727, 8729, 8731, 8734, 8736,
+    8738, 8741, 8743, 8746, 8748, 8750, 8752, 8754, 8758, 8759, 8760, 8762, 8764, 8766, 8768, 8770,
+    8772, 8774, 8776, 8779, 8781, 8783, 8785, 8787, 8789, 8791, 8793, 8795, 8797, 8799, 8801, 8803,
+    8805, 8808, 8810, 8813, 8815, 8818, 8820, 8823, 8825, 8826, 8830, 8833, 8835, 8840, 8842, 8844,
+    8846, 8849, 8852, 8854, 8857, 8860, 8863, 8865, 8867, 8869, 8872, 8876, 8878, 8880, 8882, 8885,
+    8888, 8892, 8894, 8899, 8901, 8903, 8906, 8908, 8910, 8912, 8914, 8916, 8918, 8920, 8922, 8924,
+    8930, 8932, 8934, 8936, 8938, 8943, 8945, 8948, 8950, 8953, 8956, 8959, 8961, 8963, 8964, 8965,
+    8969, 8971, 8974, 8976, 8977, 8980, 8982, 8984, 8986, 8988, 8990, 8993, 8995, 8997, 9000, 9002,
+    9005, 9007, 9009, 9012, 9014, 9016, 9020, 9022, 9024, 9027, 9029, 9032, 9034, 9036, 9037, 9040,
+    9042, 9044, 9046, 9048, 9050, 9052, 9054, 9056, 9058, 9059, 9062, 9065, 9069, 9072, 9074, 9076,
+    9078, 9080, 9082, 9089, 9091, 9093, 9096, 9098, 9101, 9104, 9106, 9108, 9110, 9112, 9114, 9116,
+    9118, 9119, 9121, 9124, 9126, 9128, 9130, 9132, 9134, 9137, 9140, 9141, 9143, 9145, 9148, 9151,
+    9154, 9157, 9159, 9161, 9164, 9167, 9172, 9175, 9177, 9179, 9182, 9184, 9188, 9190, 9194, 9196,
+    9198, 9200, 9202, 9205, 9207, 9209, 9212, 9216, 9218, 9220, 9221, 9224, 9227, 9229, 9231, 9232,
+    9234, 9236, 9238, 9239, 9240, 9243, 9245, 9247, 9250, 9253, 9256, 9259, 9263, 9264, 9266, 9268,
+    9270, 9272, 9274, 9276, 9278, 9281, 9285, 9288, 9289, 9291, 9293, 9295, 9298, 9301, 9303, 9305,
+    9308, 9312, 9314, 9317, 9319, 9321, 9324, 9327, 9329, 9332, 9334, 9337, 9341, 9343, 9345, 9347,
+    9349, 9352, 9354, 9357, 9359, 9362, 9364, 9366, 9371, 9373, 9376, 9378, 9381, 9383, 9385, 9387,
+    9391, 9394, 9398, 9400, 9402, 9404, 9407, 9409, 9411, 9413, 9415, 9417, 9419, 9421, 9424, 9427,
+    9429, 9432, 9435, 9438, 9443, 9445, 9447, 9450, 9452, 9455, 9457, 9459, 9461, 9463, 9466, 9468,
+    9471, 9477, 9480, 9482, 9484, 9487, 9489, 9491, 9493, 9496, 9498, 9500, 9502, 9504, 9506, 9508,
+    9512, 9514, 9516, 9518, 9520, 9523, 9526, 9528, 9530, 9535, 9538, 9540, 9542, 9544, 9546, 9548,
+    9550, 9552, 9555, 9557, 9559, 9561, 9563, 9566, 9569, 9571, 9574, 9577, 9579, 9581, 9583, 9585,
+    9587, 9589, 9591, 9593, 9596, 9598, 9599, 9601, 9603, 9605, 9607, 9611, 9613, 9616, 9618, 9620,
+    9622, 9626, 9628, 9630, 9632, 9633, 9635, 9638, 9640, 9643, 9646, 9649, 9652, 9655, 9659, 9661,
+    9666, 9668, 9670, 9672, 9674, 9676, 9678, 9680, 9682, 9684, 9686, 9689, 9692, 9697, 9699, 9701,
+    9705, 9707, 9710, 9712, 9714, 9716, 9718, 9721, 9723, 9728, 9737, 9739, 9741, 9743, 9746, 9749,
+    9751, 9756, 9760, 9762, 9764, 9766, 9768, 9774, 9776, 9778, 9780, 9783, 9786, 9789, 9791, 9792,
+    9797, 9799, 9801, 9803, 9806, 9808, 9810, 9813, 9816, 9818, 9821, 9823, 9826, 9829, 9832, 9834,
+    9836, 9838, 9842, 9844, 9846, 9848, 9852, 9855, 9857, 9859, 9861, 9863, 9865, 9867, 9869, 9871,
+    9874, 9877, 9880, 9883, 9887, 9890, 9894, 9896, 9899, 9901, 9902, 9905, 9908, 9911, 9913, 9916,
+    9917, 9922, 9924, 9927, 9929, 9931, 9934, 9939, 9941, 9943, 9945, 9949, 9951, 9953, 9955, 9957,
+    9958, 9961, 9963, 9965, 9968, 9970, 9972, 9973, 9975, 9977, 9981, 9983, 9985, 9988, 9991, 9993,
+    9996, 10000, 10003, 10005, 10007, 10009, 10012, 10014, 10017, 10020, 10023, 10025, 10027, 10030, 10035, 10037,
+    10041, 10043, 10047, 10049, 10052, 10055, 10056, 10059, 10062, 10064, 10067, 10069, 10072, 10074, 10076, 10079,
+    10081, 10083, 10084, 10087, 10090, 10092, 10095, 10098, 10100, 10102, 10107, 10111, 10113, 10116, 10118, 10121,
+    10123, 10125, 10127, 10129, 10131, 10133, 10134, 10137, 10139, 10141, 10144, 10146, 10147, 10150, 10152, 10155,
+    10157, 10159, 10161, 10163, 10165, 10168, 10170, 10172, 10175, 10178, 10179, 10181, 10183, 10185, 10187, 10189,
+    10193, 10195, 10197, 10199, 10202, 10204, 10206, 10208, 10212, 10214, 10217, 10219, 10220, 10221, 10223, 10228,
+    10231, 10234, 10236, 10238, 10240, 10242, 10245, 10246, 10247, 10249, 10251, 10255, 10257, 10260, 10261, 10263,
+    10265, 10267, 10270, 10272, 10274, 10277, 10279, 10281, 10283, 10286, 10289, 10291, 10294, 10298, 10301, 10303,
+    10305, 10307, 10309, 10310, 10312, 10314, 10316, 10318, 10320, 10322, 10324, 10327, 10329, 10332, 10335, 10337,
+    10339, 10340, 10342, 10344, 10346, 10348, 10351, 10353, 10356, 10358, 10360, 10362, 10363, 10365, 10368, 10371,
+    10372, 10375, 10377, 10380, 10382, 10384, 10385, 10388, 10390, 10392, 10394, 10398, 10400, 10402, 10405, 10407,
+    10409, 10411, 10413, 10416, 10418, 10420, 10421, 10424, 10428, 10430, 10433, 10435, 10437, 10439, 10442, 10446,
+    10449, 10451, 10456, 10458, 10461, 10464, 10467, 10471, 10473, 10475, 10477, 10479, 10481, 10483, 10486, 10488,
+    10490, 10492, 10494, 10497, 10499, 10502, 10504, 10506, 10508, 10511, 10513, 10516, 10518, 10520, 10522, 10524,
+    10526, 10529, 10532, 10534, 10536, 10538, 10540, 10544, 10546, 10548, 10551, 10554, 10556, 10558, 10560, 10562,
+    10564, 10566, 10568, 10570, 10572, 10574, 10576, 10578, 10580, 10582, 10586, 10588, 10591, 10593, 10596, 10597,
+    10599, 10601, 10603, 10605, 10607, 10609, 10611, 10613, 10615, 10617, 10619, 10622, 10625, 10627, 10631, 10633,
+    10635, 10637, 10639, 10640, 10642, 10643, 10644, 10646, 10648, 10651, 10653, 10655, 10657, 10659, 10660, 10663,
+    10665, 10667, 10669, 10671, 10676, 10681, 10684, 10686, 10688, 10690, 10693, 10695, 10697, 10700, 10702, 10704,
+    10707, 10709, 10717, 10719, 10722, 10724, 10726, 10728, 10731, 10733, 10735, 10737, 10739, 10741, 10744, 10748,
+    10750, 10752, 10754, 10756, 10759, 10761, 10763, 10766, 10768, 10770, 10772, 10775, 10777, 10779, 10782, 10785,
+    10787, 10790, 10792, 10794, 10796, 10798, 10801, 10803, 10805, 10807, 10810, 10812, 10814, 10816, 10817, 10818,
+    10820, 10823, 10827, 10829, 10831, 10833, 10835, 10838, 10840, 10842, 10844, 10847, 10849, 10852, 10856, 10858,
+    10861, 10863, 10865, 10868, 10870, 10873, 10875, 10878, 10880, 10882, 10884, 10886, 10888, 10890, 10893, 10896,
+    10898, 10901, 10903, 10906, 10908, 10910, 10913, 10915, 10917, 10919, 10921, 10924, 10927, 10929, 10931, 10933,
+    10935, 10937, 10939, 10942, 10945, 10948, 10950, 10953, 10955, 10958, 10960, 10964, 10968, 10970, 10973, 10974,
+    10976, 10978, 10982, 10985, 10988, 10991, 10993, 10996, 10999, 11001, 11003, 11005, 11008, 11012, 11014, 11016,
+    11019, 11021, 11023, 11026, 11028, 11032, 11034, 11037, 11039, 11042, 11045, 11048, 11050, 11053, 11055, 11057,
+    11059, 11061, 11064, 11067, 11070, 11072, 11075, 11077, 11081, 11084, 11086, 11088, 11090, 11092, 11094, 11097,
+    11100, 11102, 11104, 11106, 11107, 11110, 11112, 11114, 11118, 11120, 11122, 11125, 11126, 11129, 11131, 11134,
+    11137, 11141, 11144, 11146, 11148, 11150, 11153, 11155, 11158, 11161, 11163, 11165, 11167, 11170, 11173, 11178,
+    11180, 11183, 11185, 11187, 11188, 11190, 11192, 11195, 11197, 11199, 11201, 11204, 11206, 11208, 11210, 11212,
+    11214, 11216, 11218, 11220, 11223, 11225, 11228, 11231, 11233, 11235, 11237, 11240, 11242, 11245, 11247, 11249,
+    11251, 11253, 11255, 11257, 11260, 11263, 11265, 11268, 11272, 11274, 11276, 11278, 11281, 11283, 11285, 11287,
+    11289, 11291, 11293, 11297, 11299, 11302, 11304, 11306, 11310, 11312, 11316, 11318, 11321, 11323, 11325, 11326,
+    11328, 11330, 11332, 11336, 11338, 11342, 11344, 11346, 11348, 11350, 11351, 11353, 11355, 11357, 11359, 11361,
+    11363, 11365, 11367, 11370, 11373, 11376, 11378, 11380, 11382, 11384, 11387, 11389, 11391, 11394, 11396, 11398,
+    11401, 11403, 11406, 11408, 11410, 11412, 11415, 11418, 11420, 11422, 11426, 11427, 11429, 11432, 11434, 11436,
+    11439, 11442, 11444, 11446, 11448, 11452, 11454, 11456, 11459, 11463, 11464, 11467, 11470, 11473, 11475, 11477,
+    11479, 11482, 11484, 11486, 11488, 11490, 11493, 11495, 11497, 11499, 11501, 11504, 11507, 11509, 11511, 11514,
+    11516, 11520, 11522, 11524, 11526, 11527, 11530, 11532, 11534, 11537, 11539, 11541, 11543, 11545, 11547, 11549,
+    11551, 11553, 11555, 11557, 11559, 11561, 11563, 11565, 11567, 11570, 11572, 11575, 11577, 11580, 11583, 11586,
+    11588, 11590, 11593, 11594, 11597, 11599, 11602, 11604, 11607, 11609, 11613, 11615, 11618, 11620, 11622, 11626,
+    11631, 11633, 11635, 11637, 11639, 11642, 11644, 11646, 11648, 11650, 11652, 11655, 11657, 11659, 11662, 11665,
+    11667, 11669, 11671, 11673, 11676, 11678, 11680, 11682, 11684, 11686, 11689, 11692, 11694, 11696, 11698, 11701,
+    11703, 11706, 11708, 11710, 11712, 11714, 11716, 11718, 11719, 11721, 11723, 11725, 11727, 11729, 11731, 11733,
+    11735, 11738, 11740, 11741, 11743, 11745, 11747, 11748, 11749, 11751, 11753, 11755, 11756, 11757, 11758, 11760,
+    11763, 11765, 11767, 11770, 11772, 11774, 11776, 11779, 11781, 11784, 11786, 11788, 11790, 11792, 11794, 11796,
+    11798, 11802, 11805, 11807, 11810, 11812, 11814, 11815, 11818, 11824, 11830, 11832, 11835, 11837, 11838, 11840,
+    11843, 11848, 11850, 11852, 11854, 11857, 11859, 11861, 11864, 11866, 11868, 11873, 11876, 11878, 11880, 11883,
+    11886, 11889, 11892, 11896, 11898, 11900, 11904, 11906, 11909, 11911, 11913, 11915, 11917, 11922, 11924, 11926,
+    11929, 11932, 11935, 11937, 11939, 11942, 11944, 11947, 11949, 11951, 11953, 11956, 11959, 11961, 11963, 11967,
+    11969, 11973, 11975, 11977, 11980, 11982, 11985, 11987, 11990, 11992, 11994, 11996, 11998, 12002, 12005, 12008,
+    12011, 12013, 12015, 12017, 12019, 12021, 12023, 12026, 12028, 12030, 12032, 12034, 12037, 12042, 12044, 12047,
+    12050, 12052, 12061, 12064, 12065, 12067, 12069, 12071, 12073, 12075, 12077, 12081, 12083, 12086, 12090, 12093,
+    12094, 12096, 12098, 12100, 12103, 12105, 12108, 12112, 12114, 12116, 12118, 12120, 12122, 12125, 12127, 12129,
+    12131, 12134, 12136, 12138, 12140, 12141, 12143, 12144, 12146, 12148, 12150, 12152, 12154, 12156, 12158, 12161,
+    12163, 12169, 12172, 12174, 12176, 12178, 12180, 12182, 12185, 12187, 12189, 12192, 12193, 12195, 12198, 12200,
+    12202, 12204, 12207, 12208, 12210, 12212, 12214, 12216, 12218, 12220, 12222, 12224, 12227, 12229, 12230, 12232,
+    12234, 12236, 12238, 12241, 12243, 12245, 12247, 12249, 12251, 12253, 12255, 12257, 12260, 12262, 12264, 12267,
+    12269, 12271, 12273, 12275, 12277, 12279, 12283, 12285, 12287, 12289, 12291, 12293, 12296, 12298, 12300, 12302,
+    12304, 12306, 12308, 12311, 12313, 12316, 12320, 12322, 12325, 12328, 12330, 12332, 12334, 12336, 12339, 12343,
+    12346, 12348, 12351, 12353, 12356, 12360, 12363, 12366, 12370, 12373, 12376, 12378, 12380, 12382, 12385, 12388,
+    12390, 12392, 12397, 12399, 12402, 12404, 12407, 12409, 12410, 12412, 12414, 12416, 12418, 12420, 12422, 12424,
+    12427, 12429, 12432, 12435, 12437, 12439, 12443, 12445, 12447, 12449, 12451, 12453, 12455, 12456, 12460, 12463,
+    12466, 12470, 12473, 12475, 12478, 12480, 12482, 12484, 12489, 12492, 12494, 12496, 12498, 12500, 12505, 12508,
+    12511, 12513, 12515, 12517, 12519, 12521, 12525, 12529, 12531, 12533, 12536, 12538, 12541, 12543, 12545, 12548,
+    12550, 12552, 12554, 12556, 12558, 12563, 12565, 12567, 12569, 12572, 12574, 12577, 12579, 12581, 12583, 12585,
+    12587, 12589, 12593, 12596, 12599, 12602, 12605, 12607, 12610, 12612, 12614, 12615, 12617, 12619, 12621, 12625,
+    12627, 12629, 12631, 12633, 12636, 12638, 12641, 12643, 12646, 12648, 12650, 12653, 12655, 12657, 12659, 12661,
+    12664, 12666, 12670, 12674, 12676, 12678, 12682, 12685, 12687, 12690, 12692, 12694, 12697, 12699, 12702, 12704,
+    12706, 12708, 12710, 12712, 12714, 12716, 12718, 12720, 12721, 12722, 12726, 12728, 12730, 12732, 12734, 12736,
+    12739, 12741, 12743, 12745, 12749, 12752, 12754, 12756, 12760, 12761, 12763, 12770, 12772, 12774, 12776, 12779,
+    12784, 12786, 12788, 12791, 12795, 12797, 12799, 12801, 12803, 12805, 12807, 12810, 12812, 12814, 12816, 12818,
+    12821, 12823, 12827, 12830, 12832, 12834, 12837, 12839, 12841, 12845, 12847, 12849, 12851, 12854, 12856, 12858,
+    12862, 12864, 12866, 12869, 12871, 12874, 12876, 12879, 12881, 12883, 12886, 12889, 12891, 12893, 12895, 12897,
+    12899, 12902, 12904, 12906, 12909, 12911, 12914, 12916, 12920, 12922, 12924, 12926, 12928, 12931, 12933, 12935,
+    12938, 12940, 12943, 12947, 12950, 12953, 12955, 12958, 12961, 12963, 12965, 12969, 12972, 12974, 12977, 12979,
+    12981, 12982, 12983, 12985, 12987, 12990, 12992, 12994, 12997, 13000, 13002, 13006, 13008, 13010, 13011, 13013,
+    13015, 13017, 13023, 13025, 13027, 13029, 13031, 13033, 13036, 13038, 13040, 13044, 13045, 13047, 13049, 13052,
+    13053, 13055, 13057, 13059, 13062, 13064, 13066, 13068, 13070, 13072, 13074, 13076, 13078, 13080, 13083, 13085,
+    13087, 13090, 13094, 13096, 13098, 13102, 13104, 13106, 13110, 13112, 13115, 13117, 13120, 13123, 13126, 13130,
+    13133, 13136, 13138, 13140, 13143, 13146, 13148, 13151, 13154, 13158, 13160, 13163, 13165, 13167, 13169, 13172,
+    13174, 13176, 13179, 13181, 13183, 13185, 13187, 13189, 13193, 13195, 13197, 13199, 13204, 13208, 13210, 13212,
+    13214, 13217, 13219, 13223, 13225, 13228, 13233, 13236, 13239, 13241, 13243, 13244, 13247, 13249, 13252, 13254,
+    13257, 13259, 13261, 13263, 13266, 13268, 13270, 13272, 13275, 13277, 13280, 13282, 13285, 13287, 13289, 13291,
+    13295, 13298, 13301, 13303, 13305, 13307, 13309, 13311, 13313, 13315, 13317, 13321, 13323, 13325, 13328, 13330,
+    13334, 13336, 13338, 13341, 13343, 13345, 13347, 13349, 13351, 13353, 13356, 13359, 13361, 13363, 13365, 13368,
+    13370, 13372, 13376, 13378, 13380, 13382, 13384, 13386, 13388, 13391, 13395, 13397, 13399, 13401, 13403, 13405,
+    13407, 13409, 13411, 13414, 13416, 13418, 13422, 13426, 13428, 13430, 13432, 13434, 13436, 13438, 13440, 13442,
+    13444, 13446, 13449, 13451, 13453, 13455, 13457, 13460, 13462, 13464, 13466, 13469, 13472, 13475, 13477, 13479,
+    13482, 13484, 13486, 13487, 13489, 13491, 13494, 13496, 13498, 13501, 13503, 13505, 13508, 13510, 13511, 13513,
+    13515, 13517, 13519, 13521, 13523, 13525, 13527, 13528, 13530, 13532, 13534, 13536, 13538, 13540, 13542, 13544,
+    13547, 13549, 13552, 13556, 13558, 13560, 13562, 13565, 13567, 13569, 13574, 13576, 13579, 13581, 13583, 13585,
+    13587, 13589, 13591, 13593, 13598, 13600, 13603, 13605, 13607, 13610, 13613, 13615, 13617, 13620, 13624, 13626,
+    13628, 13630, 13633, 13635, 13637, 13640, 13643, 13644, 13646, 13648, 13650, 13652, 13654, 13658, 13660, 13664,
+    13668, 13670, 13672, 13674, 13676, 13679, 13681, 13683, 13685, 13687, 13690, 13692, 13694, 13696, 13698, 13701,
+    13703, 13705, 13708, 13710, 13712, 13714, 13716, 13717, 13719, 13722, 13724, 13726, 13728, 13730, 13733, 13735,
+    13737, 13739, 13741, 13743, 13745, 13748, 13751, 13753, 13755, 13759, 13761, 13763, 13765, 13767, 13770, 13772,
+    13775, 13776, 13778, 13781, 13782, 13784, 13787, 13789, 13791, 13793, 13797, 13800, 13803, 13805, 13807, 13810,
+    13812, 13815, 13818, 13821, 13823, 13825, 13827, 13830, 13832, 13835, 13838, 13843, 13845, 13848, 13850, 13853,
+    13855, 13857, 13859, 13861, 13863, 13868, 13870, 13873, 13878, 13881, 13883, 13885, 13887, 13889, 13891, 13893,
+    13895, 13897, 13899, 13902, 13904, 13908, 13910, 13912, 13914, 13920, 13921, 13922, 13926, 13928, 13930, 13932,
+    13934, 13937, 13939, 13942, 13947, 13949, 13951, 13954, 13956, 13958, 13960, 13962, 13964, 13966, 13968, 13971,
+    13973, 13975, 13977, 13979, 13981, 13983, 13986, 13988, 13992, 13995, 13998, 14001, 14003, 14005, 14007, 14010,
+    14012, 14014, 14016, 14018, 14019, 14021, 14022, 14026, 14030, 14032, 14034, 14037, 14039, 14041, 14043, 14045,
+    14047, 14050, 14052, 14054, 14056, 14059, 14060, 14062, 14064, 14067, 14072, 14074, 14076, 14078, 14081, 14082,
+    14084, 14086, 14088, 14091, 14093, 14095, 14097, 14099, 14101, 14103, 14106, 14108, 14110, 14113, 14115, 14118,
+    14122, 14124, 14126, 14128, 14131, 14135, 14137, 14140, 14142, 14144, 14146, 14148, 14150, 14152, 14154, 14157,
+    14165, 14168, 14170, 14172, 14174, 14176, 14178, 14181, 14183, 14185, 14187, 14189, 14191, 14192, 14194, 14196,
+    14198, 14203, 14205, 14207, 14211, 14213, 14215, 14217, 14219, 14221, 14223, 14226, 14228, 14231, 14234, 14239,
+    14243, 14246, 14251, 14255, 14257, 14260, 14263, 14265, 14267, 14272, 14274, 14275, 14279, 14282, 14285, 14290,
+    14292, 14296, 14298, 14301, 14304, 14307, 14309, 14314, 14316, 14321, 14324, 14328, 14331, 14333, 14335, 14339,
+    14341, 14344, 14346, 14348, 14350, 14353, 14355, 14358, 14360, 14362, 14364, 14367, 14369, 14371, 14377, 14381,
+    14383, 14386, 14388, 14393, 14396, 14399, 14401, 14403, 14408, 14410, 14412, 14414, 14417, 14420, 14423, 14425,
+    14430, 14434, 14437, 14440, 14443, 14445, 14448, 14450, 14452, 14454, 14457, 14461, 14463, 14466, 14468, 14470,
+    14472, 14474, 14481, 14484, 14487, 14488, 14494, 14495, 14497, 14499, 14501, 14504, 14506, 14508, 14510, 14512,
+    14514, 14516, 14519, 14521, 14526, 14528, 14532, 14534, 14536, 14540, 14543, 14545, 14548, 14550, 14553, 14556,
+    14558, 14560, 14565, 14567, 14569, 14571, 14574, 14577, 14579, 14581, 14583, 14585, 14589, 14591, 14593, 14595,
+    14597, 14599, 14601, 14603, 14606, 14607, 14612, 14614, 14616, 14618, 14622, 14624, 14626, 14630, 14634, 14636,
+    14638, 14641, 14643, 14646, 14648, 14651, 14653, 14655, 14657, 14660, 14662, 14664, 14666, 14668, 14670, 14673,
+    14675, 14676, 14678, 14681, 14683, 14685, 14689, 14693, 14696, 14698, 14700, 14702, 14705, 14708, 14711, 14715,
+    14717, 14719, 14722, 14724, 14726, 14728, 14731, 14733, 14735, 14738, 14742, 14744, 14746, 14748, 14751, 14754,
+    14756, 14760, 14761, 14765, 14768, 14771, 14773, 14775, 14777, 14779, 14781, 14783, 14785, 14788, 14790, 14800,
+    14805, 14807, 14809, 14811, 14813, 14815, 14818, 14820, 14822, 14824, 14826, 14828, 14830, 14832, 14834, 14836,
+    14838, 14841, 14843, 14846, 14848, 14850, 14853, 14855, 14857, 14859, 14861, 14863, 14865, 14868, 14870, 14872,
+    14875, 14877, 14878, 14880, 14882, 14885, 14887, 14890, 14893, 14895, 14897, 14899, 14901, 14903, 14905, 14908,
+    14910, 14912, 14914, 14916, 14919, 14922, 14926, 14929, 14932, 14935, 14937, 14939, 14942, 14944, 14950, 14952,
+    14955, 14957, 14960, 14962, 14964, 14967, 14969, 14971, 14973, 14976, 14979, 14982, 14984, 14987, 14990, 14993,
+    14995, 14997, 14999, 15001, 15003, 15005, 15007, 15009, 15011, 15013, 15016, 15018, 15021, 15024, 15026, 15028,
+    15030, 15032, 15034, 15036, 15039, 15041, 15046, 15049, 15051, 15057, 15059, 15061, 15063, 15065, 15068, 15070,
+    15073, 15075, 15078, 15080, 15083, 15086, 15088, 15092, 15094, 15096, 15098, 15100, 15102, 15104, 15106, 15109,
+    15111, 15113, 15115, 15120, 15123, 15125, 15127, 15129, 15131, 15134, 15136, 15138, 15140, 15142, 15144, 15147,
+    15149, 15152, 15155, 15158, 15161, 15165, 15167, 15169, 15171, 15173, 15176, 15178, 15182, 15184, 15187, 15190,
+    15192, 15194, 15198, 15201, 15203, 15205, 15208, 15211, 15214, 15216, 15218, 15220, 15222, 15225, 15226, 15228,
+    15231, 15235, 15237, 15239, 15241, 15243, 15245, 15247, 15250, 15252, 15254, 15256, 15259, 15261, 15264, 15267,
+    15269, 15272, 15275, 15277, 15280, 15282, 15284, 15286, 15288, 15290, 15293, 15295, 15298, 15300, 15302, 15304,
+    15305, 15308, 15311, 15313, 15316, 15318, 15320, 15322, 15325, 15327, 15329, 15331, 15333, 15335, 15338, 15340,
+    15342, 15345, 15346, 15349, 15350, 15352, 15354, 15356, 15358, 15360, 15362, 15366, 15368, 15370, 15372, 15373,
+    15375, 15377, 15379, 15380, 15382, 15384, 15386, 15387, 15389, 15391, 15394, 15396, 15398, 15401, 15403, 15404,
+    15406, 15408, 15410, 15414, 15416, 15419, 15420, 15422, 15424, 15426, 15431, 15434, 15436, 15437, 15440, 15442,
+    15444, 15447, 15450, 15452, 15457, 15460, 15462, 15465, 15467, 15470, 15473, 15476, 15479, 15481, 15483, 15485,
+    15487, 15489, 15491, 15494, 15496, 15499, 15501, 15503, 15505, 15507, 15513, 15516, 15520, 15523, 15525, 15528,
+    15530, 15532, 15534, 15536, 15538, 15541, 15544, 15546, 15548, 15551, 15553, 15555, 15557, 15560, 15562, 15564,
+    15567, 15571, 15575, 15577, 15580, 15583, 15585, 15587, 15589, 15591, 15594, 15596, 15600, 15603, 15606, 15608,
+    15611, 15613, 15616, 15618, 15622, 15625, 15627, 15630, 15635, 15638, 15641, 15643, 15646, 15647, 15649, 15651,
+    15655, 15660, 15662, 15665, 15666, 15668, 15670, 15672, 15674, 15677, 15680, 15682, 15684, 15687, 15689, 15691,
+    15694, 15696, 15698, 15700, 15704, 15706, 15708, 15711, 15714, 15717, 15719, 15721, 15723, 15725, 15727, 15731,
+    15734, 15735, 15738, 15739, 15742, 15744, 15746, 15750, 15752, 15755, 15757, 15758, 15760, 15762, 15764, 15766,
+    15768, 15771, 15773, 15775, 15777, 15780, 15782, 15784, 15786, 15788, 15792, 15796, 15799, 15802, 15804, 15806,
+    15808, 15811, 15812, 15814, 15816, 15818, 15820, 15822, 15825, 15829, 15832, 15834, 15836, 15838, 15841, 15843,
+    15845, 15847, 15851, 15854, 15856, 15858, 15860, 15862, 15863, 15865, 15868, 15870, 15872, 15874, 15876, 15878,
+    15880, 15883, 15885, 15887, 15889, 15891, 15893, 15895, 15897, 15899, 15901, 15903, 15905, 15907, 15908, 15910,
+    15912, 15914, 15916, 15918, 15920, 15922, 15924, 15926, 15928, 15930, 15932, 15934, 15937, 15939, 15941, 15944,
+    15946, 15948, 15950, 15952, 15954, 15956, 15959, 15962, 15964, 15966, 15968, 15970, 15973, 15975, 15979, 15981,
+    15983, 15985, 15988, 15990, 15992, 15994, 15996, 15998, 16002, 16005, 16007, 16009, 16011, 16015, 16017, 16018,
+    16020, 16023, 16025, 16027, 16029, 16032, 16034, 16036, 16039, 16041, 16043, 16046, 16048, 16050, 16052, 16054,
+    16057, 16059, 16062, 16064, 16066, 16068, 16071, 16074, 16077, 16079, 16081, 16083, 16085, 16087, 16090, 16092,
+    16094, 16096, 16098, 16101, 16104, 16107, 16109, 16112, 16114, 16116, 16118, 16120, 16123, 16125, 16127, 16129,
+    16131, 16133, 16135, 16137, 16139, 16141, 16143, 16145, 16148, 16150, 16152, 16155, 16157, 16160, 16162, 16163,
+    16165, 16167, 16169, 16171, 16173, 16175, 16180, 16183, 16185, 16187, 16190, 16192, 16194, 16196, 16198, 16200,
+    16202, 16205, 16207, 16210, 16212, 16216, 16218, 16220, 16223, 16225, 16228, 16230, 16232, 16235, 16238, 16240,
+    16242, 16246, 16249, 16251, 16254, 16256, 16258, 16261, 16263, 16265, 16267, 16269, 16271, 16273, 16276, 16278,
+    16280, 16283, 16285, 16287, 16290, 16294, 16297, 16299, 16301, 16303, 16305, 16307, 16309, 16311, 16313, 16315,
+    16317, 16319, 16321, 16323, 16325, 16327, 16329, 16331, 16333, 16335, 16337, 16339, 16342, 16345, 16347, 16349,
+    16351, 16354, 16356, 16358, 16362, 16364, 16366, 16367, 16369, 16373, 16374, 16376, 16378, 16381, 16383, 16385,
+    16388, 16391, 16395, 16397, 16399, 16401, 16403, 16407, 16410, 16412, 16414, 16416, 16417, 16419, 16421, 16423,
+    16425, 16431, 16434, 16436, 16438, 16440, 16442, 16446, 16449, 16451, 16453, 16455, 16457, 16459, 16461, 16463,
+    16465, 16467, 16470, 16473, 16475, 16479, 16481, 16483, 16485, 16487, 16489, 16491, 16493, 16494, 16496, 16497,
+    16499, 16501, 16503, 16505, 16507, 16509, 16511, 16512, 16514, 16516, 16518, 16520, 16522, 16524, 16526, 16528,
+    16530, 16532, 16534, 16537, 16539, 16541, 16544, 16546, 16548, 16550, 16552, 16553, 16555, 16557, 16559, 16561,
+    16563, 16565, 16567, 16569, 16572, 16574, 16576, 16578, 16580, 16581, 16583, 16585, 16588, 16590, 16592, 16594,
+    16598, 16600, 16603, 16607, 16611, 16613, 16614, 16615, 16617, 16619, 16620, 16623, 16625, 16627, 16629, 16631,
+    16633, 16634, 16636, 16637, 16638, 16639, 16641, 16643, 16646, 16648, 16651, 16653, 16657, 16659, 16661, 16663,
+    16665, 16667, 16668, 16669, 16670, 16671, 16673, 16675, 16677, 16679, 16681, 16683, 16685, 16687, 16691, 16693,
+    16695, 16697, 16699, 16701, 16703, 16705, 16706, 16708, 16710, 16712, 16714, 16716, 16717, 16719, 16721, 16723,
+    16725, 16727, 16729, 16731, 16733, 16735, 16736, 16738, 16740, 16742, 16744, 16746, 16748, 16750, 16752, 16754,
+    16756, 16758, 16760, 16762, 16764, 16766, 16768, 16770, 16772, 16774, 16776, 16779, 16781, 16782, 16784, 16786,
+    16788, 16790, 16792, 16794, 16797, 16799, 16801, 16803, 16805, 16807, 16809, 16811, 16813, 16815, 16817, 16819,
+    16821, 16823, 16825, 16827, 16829, 16831, 16833, 16835, 16837, 16839, 16840, 16842, 16844, 16846, 16848, 16850,
+    16851, 16853, 16855, 16857, 16859, 16860, 16862, 16863, 16865, 16867, 16869, 16871, 16873, 16875, 16877, 16879,
+    16881, 16883, 16885, 16887, 16889, 16891, 16893, 16895, 16897, 16899, 16901, 16902, 16904, 16906, 16909, 16910,
+    16912, 16914, 16916, 16918, 16920, 16923, 16925, 16927, 16929, 16931, 16933, 16935, 16937, 16940, 16942, 16944,
+    16946, 16948, 16951, 16953, 16954, 16957, 16961, 16963, 16965, 16966, 16968, 16970, 16972, 16973, 16974, 16976,
+    16979, 16980, 16982, 16985, 16987, 16989, 16991, 16993, 16995, 16997, 16999, 17001, 17003, 17005, 17007, 17010,
+    17012, 17013, 17015, 17017, 17019, 17021, 17023, 17025, 17027, 17029, 17031, 17033, 17035, 17037, 17039, 17041,
+    17043, 17045, 17046, 17048, 17050, 17052, 17054, 17056, 17058, 17061, 17063, 17065, 17067, 17068, 17070, 17072,
+    17074, 17076, 17077, 17079, 17081, 17083, 17085, 17087, 17089, 17091, 17093, 17095, 17096, 17098, 17100, 17102,
+    17104, 17105, 17107, 17109, 17111, 17114, 17116, 17119, 17121, 17123, 17125, 17126, 17128, 17130, 17132, 17134,
+    17137, 17139, 17141, 17143, 17145, 17147, 17149, 17151, 17153, 17155, 17157, 17158, 17162, 17164, 17167, 17170,
+    17172, 17174, 17176, 17178, 17180, 17182, 17184, 17185, 17187, 17189, 17191, 17193, 17196, 17199, 17201, 17203,
+    17205, 17207, 17209, 17210, 17212, 17214, 17216, 17218, 17220, 17221, 17223, 17225, 17227, 17229, 17231, 17233,
+    17235, 17236, 17237, 17239, 17241, 17243, 17245, 17247, 17249, 17251, 17253, 17256, 17258, 17261, 17263, 17265,
+    17268, 17270, 17272, 17274, 17275, 17277, 17280, 17282, 17284, 17286, 17288, 17290, 17292, 17294, 17296, 17298,
+    17300, 17302, 17304, 17306, 17308, 17310, 17312, 17314, 17316, 17318, 17320, 17322, 17325, 17327, 17329, 17333,
+    17336, 17338, 17341, 17343, 17345, 17347, 17349, 17351, 17353, 17355, 17358, 17360, 17363, 17365, 17367, 17369,
+    17371, 17372, 17374, 17376, 17378, 17379, 17380, 17382, 17385, 17387, 17389, 17391, 17393, 17396, 17398, 17401,
+    17403, 17405, 17407, 17408, 17410, 17412, 17414, 17415, 17417, 17419, 17421, 17423, 17425, 17427, 17428, 17430,
+    17432, 17434, 17436, 17438, 17440, 17442, 17444, 17446, 17448, 17450, 17451, 17453, 17455, 17457, 17459, 17461,
+    17463, 17465, 17467, 17469, 17473, 17475, 17477, 17478, 17480, 17482, 17484, 17486, 17488, 17490, 17492, 17494,
+    17496, 17499, 17502, 17504, 17506, 17508, 17510, 17512, 17514, 17517, 17519, 17521, 17522, 17524, 17526, 17528,
+    17530, 17532, 17534, 17536, 17538, 17540, 17543, 17545, 17548, 17551, 17553, 17554, 17555, 17557, 17558, 17560,
+    17562, 17564, 17566, 17568, 17570, 17572, 17575, 17577, 17579, 17581, 17583, 17585, 17587, 17589, 17591, 17592,
+    17594, 17596, 17598, 17600, 17601, 17603, 17605, 17607, 17609, 17611, 17613, 17615, 17617, 17619, 17620, 17622,
+    17623, 17625, 17627, 17629, 17631, 17633, 17635, 17638, 17640, 17642, 17644, 17646, 17648, 17651, 17653, 17655,
+    17657, 17660, 17662, 17665, 17667, 17670, 17672, 17674, 17676, 17678, 17680, 17682, 17684, 17686, 17688, 17690,
+    17692, 17693, 17695, 17697, 17699, 17701, 17702, 17704, 17706, 17708, 17710, 17712, 17714, 17716, 17718, 17720,
+    17722, 17724, 17726, 17728, 17731, 17733, 17735, 17737, 17739, 17741, 17743, 17745, 17747, 17749, 17751, 17753,
+    17755, 17757, 17759, 17761, 17763, 17765, 17767, 17769, 17771, 17773, 17775, 17777, 17779, 17783, 17785, 17787,
+    17788, 17790, 17792, 17795, 17797, 17800, 17803, 17804, 17806, 17808, 17811, 17813, 17815, 17816, 17819, 17821,
+    17823, 17825, 17827, 17829, 17831, 17833, 17835, 17837, 17839, 17841, 17843, 17845, 17847, 17849, 17851, 17853,
+    17855, 17856, 17858, 17860, 17862, 17865, 17867, 17869, 17871, 17873, 17875, 17877, 17879, 17882, 17884, 17886,
+    17888, 17890, 17892, 17894, 17896, 17898, 17902, 17905, 17907, 17909, 17911, 17913, 17915, 17917, 17920, 17922,
+    17924, 17926, 17928, 17930, 17931, 17933, 17935, 17937, 17939, 17941, 17943, 17945, 17947, 17949, 17953, 17955,
+    17957, 17959, 17961, 17964, 17966, 17968, 17970, 17972, 17974, 17976, 17978, 17980, 17982, 17984, 17986, 17988,
+    17990, 17992, 17994, 17996, 17997, 17999, 18001, 18003, 18005, 18007, 18009, 18011, 18013, 18015, 18017, 18019,
+    18021, 18023, 18025, 18027, 18029, 18031, 18033, 18035, 18037, 18039, 18041, 18043, 18046, 18048, 18050, 18052,
+    18054, 18055, 18057, 18061, 18063, 18065, 18067, 18069, 18071, 18073, 18075, 18077, 18080, 18082, 18084, 18086,
+    18088, 18090, 18092, 18095, 18097, 18099, 18101, 18103, 18105, 18107, 18109, 18111, 18113, 18115, 18118, 18120,
+    18122, 18124, 18125, 18127, 18129, 18131, 18133, 18135, 18137, 18139, 18140, 18143, 18145, 18147, 18149, 18152,
+    18154, 18156, 18159, 18161, 18163, 18165, 18167, 18169, 18171, 18173, 18175, 18178, 18180, 18182, 18184, 18186,
+    18188, 18189, 18191, 18193, 18195, 18197, 18199, 18201, 18204, 18206, 18208, 18210, 18212, 18214, 18216, 18218,
+    18220, 18222, 18224, 18226, 18228, 18231, 18233, 18235, 18237, 18239, 18241, 18243, 18245, 18247, 18249, 18251,
+    18254, 18256, 18258, 18262, 18264, 18266, 18268, 18270, 18273, 18274, 18276, 18278, 18279, 18281, 18283, 18285,
+    18287, 18289, 18291, 18293, 18295, 18297, 18300, 18302, 18304, 18306, 18309, 18311, 18312, 18314, 18316, 18318,
+    18320, 18322, 18324, 18325, 18327, 18329, 18331, 18333, 18336, 18338, 18340, 18342, 18345, 18347, 18349, 18351,
+    18353, 18356, 18358, 18360, 18362, 18364, 18366, 18368, 18370, 18372, 18374, 18375, 18377, 18379, 18381, 18383,
+    18385, 18387, 18389, 18391, 18393, 18395, 18397, 18399, 18401, 18403, 18405, 18407, 18409, 18411, 18413, 18415,
+    18417, 18420, 18421, 18423, 18425, 18427, 18430, 18433, 18436, 18438, 18440, 18443, 18445, 18447, 18449, 18451,
+    18453, 18455, 18457, 18459, 18461, 18463, 18465, 18467, 18469, 18471, 18473, 18475, 18477, 18479, 18481, 18483,
+    18485, 18487, 18489, 18491, 18492, 18494, 18496, 18498, 18500, 18502, 18504, 18506, 18508, 18510, 18513, 18515,
+    18517, 18519, 18521, 18523, 18525, 18527, 18529, 18531, 18533, 18535, 18537, 18538, 18541, 18543, 18545, 18546,
+    18548, 18551, 18553, 18555, 18557, 18559, 18561, 18563, 18565, 18567, 18569, 18572, 18574, 18575, 18577, 18579,
+    18581, 18583, 18585, 18587, 18589, 18591, 18593, 18596, 18600, 18602, 18604, 18606, 18608, 18610, 18612, 18614,
+    18616, 18618, 18620, 18621,
+];
+
+pub(super) static READINGS: [Syllable; 18621] = [
+    s(8, 1, 12, 2), s(0, 3, 9, 2), s(14, 1, 12, 1),
+    s(13, 1, 8, 1),
+    s(6, 1, 9, 4),
+    s(10, 2, 1, 4),
+    s(0, 2, 0, 3),
+    s(0, 1, 10, 3),
+    s(0, 1, 0, 2),
+    s(14, 1, 4, 2),
+    s(16, 0, 8, 2),
+    s(7, 2, 2, 4),
+    s(5, 0, 9, 1), s(13, 1, 8, 2),
+    s(14, 3, 0, 4),
+    s(14, 1, 12, 2),
+    s(14, 3, 12, 1),
+    s(8, 1, 8, 2),
+    s(8, 1, 10, 3),
+    s(14, 1, 11, 1),
+    s(0, 3, 12, 1),
+    s(14, 1, 10, 4),
+    s(15, 0, 10, 3),
+    s(5, 0, 5, 4),
+    s(0, 2, 0, 4),
+    s(2, 0, 9, 1),
+    s(18, 2, 0, 2),
+    s(3, 0, 1, 3),
+    s(13, 1, 9, 4), s(20, 0, 0, 4),
+    s(0, 1, 0, 4),
+    s(0, 1, 10, 2), s(15, 2, 12, 4),
+    s(7, 0, 6, 4),
+    s(16, 0, 12, 4),
+    s(4, 0, 12, 1),
+    s(15, 2, 2, 1),
+    s(4, 0, 11, 3),
+    s(0, 0, 7, 3),
+    s(19, 2, 2, 4),
+    s(15, 0, 8, 4),
+    s(5, 2, 12, 4),
+    s(21, 2, 0, 4),
+    s(13, 3, 12, 2),
+    s(10, 2, 11, 1), s(0, 2, 11, 1),
+    s(8, 0, 6, 4),
+    s(7, 0, 7, 3),
+    s(15, 2, 0, 4),
+    s(17, 2, 0, 1),
+    s(14, 3, 0, 3),
+    s(17, 0, 10, 1),
+    s(12, 1, 4, 4),
+    s(5, 1, 4, 2),
+    s(7, 2, 2, 2),
+    s(0, 1, 0, 4), s(16, 0, 0, 4),
+    s(8, 2, 12, 4),
+    s(0, 1, 12, 4),
+    s(1, 0, 12, 3),
+    s(8, 0, 9, 2),
+    s(3, 1, 7, 2),
+    s(8, 1, 0, 4),
+    s(12, 1, 0, 4),
+    s(0, 3, 0, 3),
+    s(8, 2, 2, 2),
+    s(16, 0, 5, 2),
+    s(11, 2, 10, 2),
+    s(11, 2, 6, 4),
+    s(18, 0, 7, 3),
+    s(15, 0, 8, 4), s(15, 2, 0, 1),
+    s(11, 0, 9, 4),
+    s(14, 1, 0, 4),
+    s(6, 0, 5, 4),
+    s(0, 1, 7, 2),
+    s(12, 3, 10, 4),
+    s(3, 0, 1, 4),
+    s(8, 3, 4, 4),
+    s(6, 0, 11, 2),
+    s(15, 0, 7, 4),
+    s(15, 0, 5, 1), s(15, 0, 1, 3),
+    s(15, 2, 2, 2),
+    s(0, 0, 13, 4),
+    s(18, 0, 9, 3),
+    s(13, 1, 0, 3),
+    s(16, 0, 0, 4),
+    s(6, 0, 11, 3),
+    s(21, 0, 3, 4),
+    s(21, 0, 0, 1),
+    s(8, 0, 6, 2),
+    s(21, 0, 1, 4),
+    s(10, 2, 6, 3), s(11, 2, 6, 4),
+    s(2, 2, 0, 2),
+    s(6, 0, 1, 4),
+    s(17, 2, 0, 2), s(5, 2, 0, 2), s(6, 2, 0, 4),
+    s(0, 1, 11, 1),
+    s(0, 0, 8, 3),
+    s(6, 0, 5, 2),
+    s(3, 1, 9, 2),
+    s(0, 1, 10, 4), s(0, 2, 10, 3),
+    s(5, 1, 7, 4),
+    s(3, 1, 4, 4), s(0, 2, 1, 4),
+    s(7, 1, 7, 3),
+    s(14, 1, 4, 4),
+    s(0, 1, 8, 2),
+    s(16, 0, 3, 4),
+    s(8, 0, 6, 3), s(8, 0, 6, 4),
+    s(8, 2, 2, 3),
+    s(13, 3, 9, 2),
+    s(20, 0, 5, 2),
+    s(8, 1, 11, 3),
+    s(9, 2, 0, 3),
+    s(3, 0, 7, 4),
+    s(9, 2, 1, 3),
+    s(21, 2, 6, 4),
+    s(3, 0, 9, 2),
+    s(13, 3, 9, 1),
+    s(17, 0, 0, 4),
+    s(8, 1, 0, 2),
+    s(0, 2, 11, 3),
+    s(10, 0, 8, 4),
+    s(5, 2, 0, 4),
+    s(15, 0, 10, 4),
+    s(6, 1, 12, 1),
+    s(1, 1, 12, 4),
+    s(11, 2, 2, 4),
+    s(9, 2, 12, 4),
+    s(16, 0, 12, 1),
+    s(13, 1, 10, 1), s(13, 1, 10, 4), s(13, 1, 10, 3),
+    s(12, 3, 12, 3),
+    s(8, 2, 0, 4),
+    s(14, 1, 12, 4),
+    s(7, 0, 9, 2),
+    s(1, 1, 0, 4),
+    s(12, 1, 4, 2),
+    s(9, 2, 12, 1),
+    s(0, 1, 8, 4),
+    s(13, 1, 1, 4),
+    s(2, 1, 0, 2),
+    s(5, 1, 9, 4), s(5, 1, 9, 3),
+    s(4, 2, 0, 3), s(9, 2, 1, 1),
+    s(8, 2, 2, 4),
+    s(13, 1, 1, 4), s(9, 0, 3, 1),
+    s(6, 0, 11, 1),
+    s(1, 0, 5, 1),
+    s(9, 0, 9, 1),
+    s(20, 0, 0, 2),
+    s(14, 3, 9, 1), s(12, 1, 4, 1),
+    s(8, 0, 11, 3),
+    s(17, 0, 3, 2),
+    s(5, 1, 7, 1),
+    s(11, 2, 1, 4),
+    s(6, 0, 8, 2), s(17, 2, 0, 1),
+    s(2, 1, 9, 1),
+    s(5, 1, 0, 1),
+    s(18, 2, 9, 3),
+    s(0, 0, 3, 4),
+    s(13, 1, 4, 4),
+    s(15, 2, 2, 1), s(5, 0, 8, 1),
+    s(18, 2, 6, 4), s(20, 2, 6, 4), s(12, 1, 0, 4),
+    s(12, 1, 9, 1), s(13, 1, 9, 2),
+    s(16, 2, 12, 2),
+    s(14, 1, 0, 1), s(16, 0, 0, 2),
+    s(5, 0, 12, 1),
+    s(8, 1, 10, 2),
+    s(12, 3, 4, 2), s(14, 3, 4, 1),
+    s(14, 1, 7, 4),
+    s(19, 0, 9, 4),
+    s(15, 2, 0, 3),
+    s(15, 0, 9, 3), s(5, 0, 9, 3),
+    s(12, 1, 9, 1), s(8, 0, 9, 2),
+    s(19, 0, 8, 4), s(20, 0, 8, 3),
+    s(16, 2, 1, 1), s(15, 0, 1, 2),
+    s(8, 1, 0, 4), s(8, 2, 2, 3),
+    s(14, 1, 0, 2),
+    s(12, 1, 9, 3),
+    s(12, 1, 0, 2),
+    s(4, 0, 6, 4), s(1, 0, 6, 4), s(4, 2, 0, 2),
+    s(16, 2, 0, 4),
+    s(1, 0, 12, 1),
+    s(1, 0, 1, 2),
+    s(8, 1, 11, 3), s(8, 1, 11, 2),
+    s(10, 2, 5, 4),
+    s(14, 1, 1, 1), s(11, 0, 3, 2),
+    s(1, 1, 4, 1),
+    s(1, 0, 5, 4), s(2, 1, 0, 2),
+    s(0, 1, 11, 3),
+    s(8, 3, 0, 4),
+    s(1, 0, 6, 4),
+    s(8, 2, 0, 3),
+    s(14, 3, 9, 2), s(21, 2, 9, 3),
+    s(11, 0, 12, 2),
+    s(9, 2, 6, 3),
+    s(14, 3, 9, 3),
+    s(9, 2, 12, 4), s(9, 0, 9, 3),
+    s(8, 0, 8, 4),
+    s(6, 1, 0, 1),
+    s(8, 0, 3, 4),
+    s(21, 2, 10, 3),
+    s(0, 1, 7, 4),
+    s(14, 1, 9, 1), s(12, 1, 4, 2),
+    s(19, 0, 8, 4),
+    s(13, 3, 4, 4),
+    s(0, 1, 10, 2), s(13, 1, 10, 2),
+    s(14, 1, 0, 1),
+    s(15, 0, 0, 3),
+    s(12, 1, 1, 2),
+    s(11, 2, 0, 4),
+    s(8, 0, 1, 1),
+    s(0, 1, 0, 3),
+    s(10, 0, 3, 4),
+    s(4, 2, 0, 1),
+    s(13, 1, 10, 2),
+    s(0, 0, 5, 4),
+    s(16, 2, 0, 2),
+    s(14, 1, 4, 3), s(14, 1, 4, 4),
+    s(0, 2, 6, 1),
+    s(11, 2, 9, 4),
+    s(15, 0, 7, 3),
+    s(17, 0, 0, 3),
+    s(17, 2, 1, 1),
+    s(10, 2, 6, 4), s(10, 2, 5, 4),
+    s(17, 2, 11, 1),
+    s(11, 0, 3, 2),
+    s(9, 0, 5, 4), s(11, 0, 5, 4),
+    s(0, 1, 9, 3),
+    s(13, 1, 8, 2),
+    s(4, 0, 9, 4),
+    s(2, 0, 11, 4),
+    s(5, 0, 9, 3),
+    s(4, 0, 11, 3), s(4, 0, 12, 1),
+    s(9, 2, 12, 1), s(21, 2, 12, 4),
+    s(0, 0, 7, 1), s(0, 0, 7, 4),
+    s(4, 2, 0, 3),
+    s(7, 0, 3, 4),
+    s(14, 3, 4, 4), s(3, 0, 1, 5),
+    s(11, 2, 1, 2), s(0, 1, 12, 2),
+    s(16, 0, 10, 2),
+    s(9, 2, 2, 2),
+    s(11, 2, 1, 4), s(2, 0, 1, 1),
+    s(4, 0, 1, 2),
+    s(14, 1, 7, 1),
+    s(2, 0, 8, 3),
+    s(21, 0, 0, 4),
+    s(8, 1, 10, 4),
+    s(11, 0, 8, 3), s(11, 0, 8, 4),
+    s(13, 3, 0, 2),
+    s(0, 0, 13, 2),
+    s(14, 3, 10, 2),
+    s(7, 1, 4, 4),
+    s(0, 2, 6, 3),
+    s(6, 1, 0, 2),
+    s(11, 2, 12, 2),
+    s(6, 2, 10, 3),
+    s(7, 1, 4, 4), s(14, 1, 10, 1),
+    s(0, 1, 10, 2),
+    s(15, 0, 10, 1),
+    s(0, 2, 5, 1),
+    s(17, 0, 8, 4),
+    s(0, 1, 4, 4),
+    s(13, 1, 0, 2),
+    s(6, 0, 8, 4),
+    s(11, 0, 9, 2),
+    s(5, 2, 12, 3),
+    s(11, 2, 10, 1), s(0, 2, 10, 3),
+    s(12, 3, 0, 1), s(21, 0, 8, 3),
+    s(11, 2, 2, 4), s(9, 2, 2, 2), s(14, 3, 0, 4),
+    s(8, 1, 12, 2),
+    s(6, 1, 9, 3),
+    s(8, 2, 10, 2),
+    s(9, 0, 3, 2),
+    s(0, 1, 9, 1), s(0, 1, 4, 4), s(0, 1, 10, 1),
+    s(17, 0, 0, 2), s(6, 1, 0, 2),
+    s(14, 3, 4, 2), s(7, 1, 1, 1),
+    s(2, 0, 10, 1), s(4, 0, 10, 4),
+    s(16, 2, 10, 3),
+    s(7, 1, 8, 2), s(18, 0, 8, 4),
+    s(5, 2, 2, 3),
+    s(19, 0, 3, 2),
+    s(14, 1, 4, 2), s(0, 1, 4, 2),
+    s(0, 1, 8, 1),
+    s(17, 0, 12, 3),
+    s(0, 2, 10, 3), s(11, 2, 10, 1),
+    s(10, 2, 0, 1),
+    s(11, 2, 0, 2),
+    s(14, 1, 1, 2), s(0, 1, 1, 5),
+    s(3, 0, 9, 4),
+    s(8, 3, 4, 4), s(0, 0, 3, 4),
+    s(12, 1, 0, 2), s(8, 0, 6, 2),
+    s(11, 0, 8, 2),
+    s(15, 0, 0, 4),
+    s(1, 0, 5, 5),
+    s(15, 2, 6, 1),
+    s(13, 1, 9, 1),
+    s(9, 0, 8, 4), s(9, 0, 8, 1),
+    s(5, 0, 9, 4),
+    s(1, 0, 6, 1),
+    s(1, 0, 2, 2),
+    s(16, 2, 0, 1), s(7, 0, 1, 4), s(15, 0, 8, 1),
+    s(14, 1, 8, 4),
+    s(11, 2, 12, 2), s(5, 2, 12, 4), s(11, 2, 12, 4),
+    s(6, 1, 0, 4),
+    s(20, 2, 0, 4),
+    s(10, 2, 2, 4), s(9, 2, 2, 1),
+    s(8, 0, 7, 2),
+    s(15, 0, 0, 4), s(5, 1, 4, 2),
+    s(14, 1, 4, 1), s(0, 0, 5, 3),
+    s(15, 0, 1, 1),
+    s(20, 2, 12, 2),
+    s(6, 0, 1, 3), s(5, 0, 1, 1),
+    s(0, 1, 9, 2),
+    s(2, 0, 2, 1),
+    s(21, 0, 5, 3),
+    s(9, 2, 2, 1),
+    s(14, 1, 11, 3),
+    s(14, 3, 4, 1),
+    s(11, 0, 3, 2), s(14, 1, 1, 4), s(14, 1, 1, 1),
+    s(8, 0, 12, 1),
+    s(14, 1, 9, 2),
+    s(6, 0, 5, 3),
+    s(18, 2, 12, 2),
+    s(0, 1, 0, 4), s(7, 1, 0, 3),
+    s(14, 1, 0, 1), s(0, 1, 0, 4),
+    s(12, 3, 0, 4),
+    s(11, 0, 9, 3),
+    s(2, 0, 7, 4),
+    s(11, 0, 9, 3), s(8, 0, 9, 2),
+    s(13, 3, 0, 1),
+    s(10, 0, 9, 1),
+    s(16, 0, 0, 3),
+    s(14, 1, 1, 2),
+    s(0, 2, 12, 3),
+    s(14, 3, 9, 2), s(0, 3, 9, 2),
+    s(0, 2, 9, 1),
+    s(11, 0, 7, 4),
+    s(12, 1, 12, 3),
+    s(0, 0, 7, 4), s(0, 2, 0, 4),
+    s(0, 0, 7, 4),
+    s(6, 0, 9, 1),
+    s(12, 3, 0, 2),
+    s(1, 2, 0, 4),
+    s(19, 0, 11, 4), s(19, 2, 2, 4),
+    s(8, 1, 8, 4), s(12, 1, 8, 4),
+    s(3, 0, 6, 2), s(3, 2, 0, 4),
+    s(5, 2, 6, 4), s(0, 2, 12, 4),
+    s(1, 0, 11, 1),
+    s(1, 0, 7, 3),
+    s(14, 1, 1, 4),
+    s(16, 0, 11, 2), s(15, 0, 11, 4),
+    s(5, 2, 2, 1),
+    s(4, 2, 0, 4),
+    s(0, 1, 4, 3),
+    s(10, 2, 6, 2),
+    s(0, 2, 6, 3), s(11, 0, 9, 2),
+    s(0, 1, 7, 1),
+    s(8, 2, 12, 3),
+    s(14, 1, 12, 1),
+    s(1, 2, 0, 3),
+    s(16, 0, 0, 2),
+    s(0, 1, 0, 1), s(0, 1, 0, 4),
+    s(19, 2, 12, 1),
+    s(15, 0, 11, 4),
+    s(9, 2, 10, 4),
+    s(8, 1, 7, 2),
+    s(5, 0, 1, 1), s(5, 0, 1, 5),
+    s(0, 3, 12, 3),
+    s(10, 0, 9, 3), s(11, 0, 9, 3),
+    s(16, 0, 9, 4),
+    s(15, 0, 0, 2), s(15, 0, 3, 2),
+    s(1, 0, 7, 4),
+    s(3, 0, 12, 2),
+    s(10, 2, 6, 2), s(9, 2, 6, 4),
+    s(16, 0, 9, 2),
+    s(8, 0, 6, 3),
+    s(13, 1, 7, 4),
+    s(7, 0, 11, 4),
+    s(0, 3, 10, 1),
+    s(8, 2, 12, 2),
+    s(10, 0, 5, 1),
+    s(10, 2, 6, 3), s(10, 2, 6, 4),
+    s(9, 0, 7, 3),
+    s(6, 0, 7, 4),
+    s(17, 0, 9, 3),
+    s(8, 0, 5, 3),
+    s(7, 1, 4, 4), s(14, 1, 12, 4),
+    s(4, 2, 0, 2),
+    s(9, 0, 7, 3), s(19, 0, 3, 2),
+    s(13, 1, 4, 2),
+    s(1, 0, 9, 4), s(11, 0, 3, 4), s(4, 2, 0, 2),
+    s(12, 1, 1, 1),
+    s(10, 2, 12, 1), s(10, 2, 11, 1),
+    s(0, 3, 0, 4), s(14, 3, 0, 4),
+    s(17, 0, 10, 3),
+    s(16, 2, 2, 4),
+    s(12, 1, 0, 3),
+    s(7, 2, 0, 2), s(0, 2, 0, 3),
+    s(14, 1, 7, 2),
+    s(0, 3, 0, 2),
+    s(12, 3, 10, 1),
+    s(14, 1, 10, 2),
+    s(2, 0, 6, 1), s(1, 1, 0, 3),
+    s(16, 0, 9, 1), s(5, 1, 9, 3), s(5, 1, 9, 4),
+    s(5, 2, 12, 1),
+    s(21, 0, 0, 4), s(0, 1, 0, 2),
+    s(0, 0, 13, 3),
+    s(3, 0, 7, 3), s(8, 1, 8, 3),
+    s(0, 3, 10, 4),
+    s(12, 1, 0, 1),
+    s(13, 1, 7, 3),
+    s(2, 0, 7, 2),
+    s(2, 0, 12, 1),
+    s(7, 2, 2, 3),
+    s(0, 1, 0, 1),
+    s(5, 2, 2, 4), s(5, 2, 2, 3),
+    s(13, 1, 4, 4), s(14, 1, 9, 3), s(14, 1, 1, 2),
+    s(8, 3, 0, 3),
+    s(21, 0, 8, 3),
+    s(20, 0, 9, 4),
+    s(5, 0, 8, 4),
+    s(4, 0, 12, 1), s(2, 0, 12, 2),
+    s(0, 1, 0, 4), s(0, 0, 3, 4),
+    s(21, 2, 2, 1),
+    s(13, 1, 4, 1), s(19, 2, 2, 1), s(21, 2, 2, 1),
+    s(2, 0, 2, 4),
+    s(14, 1, 10, 1), s(13, 1, 4, 4),
+    s(6, 2, 12, 3), s(0, 3, 12, 3),
+    s(0, 3, 10, 2),
+    s(8, 0, 9, 3),
+    s(3, 0, 9, 3),
+    s(13, 1, 11, 3),
+    s(15, 0, 8, 2),
+    s(0, 1, 9, 4), s(0, 1, 9, 1),
+    s(21, 0, 7, 3),
+    s(4, 0, 9, 4), s(3, 1, 9, 3), s(15, 2, 9, 4),
+    s(0, 2, 6, 3), s(0, 2, 6, 1),
+    s(4, 0, 1, 4),
+    s(6, 0, 9, 4),
+    s(17, 2, 0, 4),
+    s(2, 1, 9, 2),
+    s(0, 0, 9, 1),
+    s(10, 2, 1, 1),
+    s(16, 0, 1, 1), s(17, 0, 1, 4),
+    s(8, 1, 9, 4),
+    s(3, 1, 0, 4),
+    s(11, 2, 6, 4), s(0, 1, 4, 4),
+    s(3, 2, 0, 4),
+    s(15, 0, 9, 3),
+    s(7, 0, 8, 3), s(9, 0, 8, 4), s(10, 0, 8, 4),
+    s(14, 1, 0, 1), s(14, 1, 0, 4),
+    s(5, 0, 1, 2),
+    s(8, 1, 9, 2),
+    s(20, 0, 7, 2),
+    s(20, 0, 9, 1), s(21, 0, 10, 1),
+    s(12, 3, 0, 4), s(13, 3, 0, 4), s(16, 0, 1, 2),
+    s(7, 0, 10, 4),
+    s(0, 0, 9, 3), s(0, 1, 9, 3),
+    s(13, 1, 9, 4), s(20, 0, 9, 2),
+    s(20, 2, 6, 1),
+    s(20, 2, 12, 1),
+    s(18, 0, 9, 2), s(18, 0, 9, 3),
+    s(7, 1, 9, 3), s(6, 1, 9, 3), s(6, 0, 9, 2),
+    s(3, 0, 5, 2),
+    s(0, 3, 4, 4),
+    s(7, 0, 5, 2),
+    s(8, 0, 9, 4), s(8, 0, 9, 2),
+    s(11, 2, 5, 2),
+    s(3, 0, 12, 4),
+    s(0, 2, 9, 3),
+    s(3, 1, 0, 2), s(14, 1, 9, 3),
+    s(15, 0, 0, 2),
+    s(19, 0, 0, 3),
+    s(11, 0, 5, 2),
+    s(14, 3, 9, 1), s(13, 3, 12, 2),
+    s(15, 0, 0, 4), s(15, 0, 3, 4),
+    s(3, 1, 9, 3),
+    s(16, 2, 10, 2),
+    s(9, 0, 8, 4),
+    s(8, 2, 9, 2),
+    s(17, 0, 8, 3),
+    s(8, 1, 7, 3),
+    s(12, 1, 8, 4),
+    s(14, 1, 4, 3),
+    s(5, 1, 12, 4),
+    s(3, 0, 11, 2),
+    s(0, 1, 7, 3),
+    s(7, 1, 12, 2),
+    s(8, 0, 11, 2), s(8, 0, 11, 3),
+    s(0, 3, 12, 2),
+    s(0, 1, 1, 1), s(0, 1, 1, 4),
+    s(3, 0, 7, 2),
+    s(3, 1, 12, 2),
+    s(19, 2, 6, 4),
+    s(3, 1, 0, 3),
+    s(0, 2, 10, 3),
+    s(10, 0, 11, 1),
+    s(5, 1, 9, 4),
+    s(14, 1, 12, 3),
+    s(20, 2, 6, 4),
+    s(13, 1, 7, 2),
+    s(13, 1, 10, 3),
+    s(0, 2, 9, 2),
+    s(5, 0, 3, 2), s(0, 0, 5, 4),
+    s(1, 1, 9, 4),
+    s(7, 0, 8, 2),
+    s(12, 1, 10, 3),
+    s(0, 3, 0, 1),
+    s(16, 2, 6, 2),
+    s(19, 2, 2, 3),
+    s(1, 0, 2, 3),
+    s(11, 2, 6, 1),
+    s(6, 2, 6, 3), s(6, 2, 6, 4),
+    s(1, 0, 2, 1),
+    s(19, 0, 1, 1),
+    s(7, 1, 9, 3), s(12, 1, 0, 2),
+    s(1, 0, 1, 3), s(2, 0, 1, 1),
+    s(15, 0, 3, 2), s(12, 1, 4, 2),
+    s(12, 3, 0, 1),
+    s(14, 1, 4, 4), s(14, 1, 0, 4),
+    s(13, 1, 0, 4), s(12, 1, 0, 1),
+    s(20, 0, 0, 2), s(20, 0, 0, 4),
+    s(5, 2, 0, 1),
+    s(7, 1, 7, 4),
+    s(13, 1, 0, 4), s(15, 0, 1, 3),
+    s(6, 2, 6, 1),
+    s(21, 2, 12, 2),
+    s(5, 1, 9, 4), s(5, 1, 12, 3),
+    s(0, 1, 10, 2), s(20, 0, 10, 2),
+    s(20, 0, 10, 2),
+    s(7, 0, 7, 2),
+    s(13, 1, 10, 4),
+    s(0, 3, 12, 4),
+    s(5, 0, 8, 3),
+    s(16, 0, 0, 2), s(3, 1, 10, 2),
+    s(3, 1, 10, 3),
+    s(11, 2, 11, 2),
+    s(10, 0, 3, 3),
+    s(19, 2, 0, 2),
+    s(16, 0, 12, 2),
+    s(14, 3, 4, 4),
+    s(7, 1, 0, 2),
+    s(0, 0, 9, 4),
+    s(3, 2, 0, 3),
+    s(14, 1, 11, 2),
+    s(0, 1, 11, 2),
+    s(11, 2, 1, 2),
+    s(20, 2, 2, 4), s(20, 2, 2, 2),
+    s(5, 2, 6, 4),
+    s(6, 2, 2, 3), s(6, 2, 6, 3),
+    s(3, 0, 11, 3),
+    s(13, 3, 10, 1),
+    s(8, 2, 12, 4), s(8, 2, 12, 2),
+    s(1, 1, 10, 1),
+    s(6, 1, 7, 2),
+    s(3, 1, 0, 2),
+    s(2, 0, 6, 2),
+    s(15, 0, 9, 4),
+    s(14, 1, 11, 4),
+    s(9, 0, 11, 3),
+    s(5, 2, 9, 1),
+    s(3, 1, 10, 2),
+    s(0, 2, 6, 1), s(0, 2, 6, 3),
+    s(6, 2, 0, 1),
+    s(3, 1, 12, 3),
+    s(12, 3, 4, 2),
+    s(0, 3, 9, 2),
+    s(5, 0, 1, 5),
+    s(8, 0, 8, 2),
+    s(13, 1, 9, 4), s(15, 0, 9, 3),
+    s(0, 0, 7, 2),
+    s(1, 1, 7, 3), s(1, 1, 7, 1),
+    s(3, 0, 11, 3), s(3, 0, 11, 2),
+    s(5, 0, 7, 3),
+    s(4, 2, 0, 2), s(4, 2, 0, 4),
+    s(5, 0, 9, 1),
+    s(18, 2, 10, 4),
+    s(6, 2, 12, 2),
+    s(13, 1, 0, 1),
+    s(12, 1, 0, 2), s(12, 1, 4, 2),
+    s(12, 1, 7, 4),
+    s(1, 1, 7, 3),
+    s(1, 0, 5, 4),
+    s(11, 0, 7, 2),
+    s(0, 2, 2, 4),
+    s(7, 1, 0, 4),
+    s(20, 2, 9, 2),
+    s(8, 2, 0, 2),
+    s(8, 3, 0, 4), s(8, 0, 6, 2), s(8, 0, 6, 3),
+    s(0, 3, 0, 4),
+    s(2, 0, 12, 1), s(9, 0, 11, 1),
+    s(12, 1, 0, 4), s(13, 1, 0, 3),
+    s(1, 1, 0, 3),
+    s(18, 0, 10, 4),
+    s(11, 2, 11, 1),
+    s(4, 0, 9, 2),
+    s(10, 2, 0, 4),
+    s(17, 0, 1, 1), s(3, 1, 7, 2),
+    s(0, 3, 9, 1),
+    s(19, 0, 0, 1), s(20, 0, 0, 3),
+    s(10, 2, 1, 3),
+    s(15, 0, 3, 2), s(12, 1, 4, 1),
+    s(0, 2, 10, 4), s(3, 0, 10, 2), s(3, 1, 9, 3),
+    s(0, 1, 4, 2), s(0, 0, 9, 1),
+    s(17, 2, 0, 4), s(14, 3, 0, 1), s(6, 0, 8, 2), s(17, 2, 0, 1),
+    s(13, 1, 7, 1), s(12, 1, 7, 3),
+    s(15, 2, 10, 1),
+    s(10, 2, 10, 1),
+    s(0, 1, 12, 1),
+    s(16, 2, 11, 2),
+    s(8, 1, 9, 2), s(8, 1, 10, 2),
+    s(1, 1, 0, 1),
+    s(9, 0, 8, 1),
+    s(14, 1, 4, 4), s(14, 3, 4, 3),
+    s(4, 0, 12, 4),
+    s(8, 0, 8, 2), s(8, 3, 0, 3),
+    s(19, 0, 7, 1),
+    s(15, 0, 12, 4),
+    s(0, 1, 10, 4),
+    s(2, 1, 10, 1),
+    s(12, 1, 9, 1), s(13, 1, 9, 1),
+    s(7, 1, 4, 2),
+    s(15, 0, 5, 2), s(5, 2, 0, 4), s(5, 2, 2, 2),
+    s(12, 1, 8, 3),
+    s(11, 2, 9, 2),
+    s(1, 0, 10, 3),
+    s(15, 0, 1, 4), s(15, 0, 1, 3), s(16, 0, 1, 2),
+    s(8, 1, 7, 4),
+    s(14, 1, 9, 4),
+    s(20, 0, 0, 4),
+    s(16, 0, 0, 3), s(17, 0, 0, 3),
+    s(0, 1, 9, 3), s(6, 2, 6, 2), s(5, 2, 6, 1),
+    s(8, 0, 11, 2),
+    s(6, 2, 6, 2), s(5, 2, 6, 1),
+    s(16, 0, 1, 2),
+    s(0, 0, 5, 3), s(0, 1, 0, 3),
+    s(0, 1, 12, 3),
+    s(15, 0, 3, 2),
+    s(6, 0, 8, 2), s(0, 3, 0, 3), s(0, 3, 0, 2),
+    s(6, 2, 6, 2),
+    s(19, 2, 12, 3),
+    s(2, 0, 9, 1), s(1, 0, 9, 1),
+    s(0, 1, 9, 4), s(13, 1, 9, 1),
+    s(10, 0, 11, 1), s(10, 0, 11, 4),
+    s(21, 2, 0, 1),
+    s(16, 0, 9, 1),
+    s(12, 1, 11, 3),
+    s(12, 1, 12, 4),
+    s(12, 3, 9, 4),
+    s(5, 1, 0, 4),
+    s(5, 1, 7, 1), s(3, 1, 10, 2),
+    s(14, 3, 10, 4),
+    s(14, 1, 4, 2), s(17, 0, 3, 4),
+    s(18, 2, 6, 4),
+    s(1, 1, 4, 4),
+    s(17, 0, 9, 4),
+    s(20, 0, 12, 4),
+    s(5, 1, 12, 1),
+    s(5, 1, 0, 2), s(15, 0, 8, 4),
+    s(6, 2, 12, 3), s(6, 2, 12, 2), s(5, 2, 12, 4),
+    s(6, 0, 1, 4), s(11, 2, 6, 4),
+    s(21, 2, 12, 1),
+    s(5, 2, 2, 2),
+    s(6, 0, 7, 1), s(6, 2, 12, 2),
+    s(12, 1, 9, 4),
+    s(11, 2, 9, 3),
+    s(15, 2, 12, 3), s(5, 2, 12, 4),
+    s(13, 1, 0, 4),
+    s(0, 2, 6, 2),
+    s(15, 0, 9, 1),
+    s(7, 1, 12, 4),
+    s(14, 1, 10, 1),
+    s(18, 0, 10, 3),
+    s(17, 2, 0, 4), s(7, 2, 0, 4),
+    s(16, 0, 1, 4),
+    s(15, 2, 2, 2), s(5, 1, 7, 3),
+    s(4, 0, 11, 2),
+    s(2, 0, 6, 4),
+    s(0, 0, 5, 4), s(14, 1, 0, 4), s(12, 1, 0, 4),
+    s(13, 1, 1, 1), s(0, 1, 1, 4),
+    s(4, 0, 10, 1),
+    s(13, 1, 7, 1), s(13, 1, 7, 3),
+    s(9, 0, 3, 1),
+    s(9, 0, 7, 1),
+    s(1, 0, 10, 4),
+    s(16, 2, 0, 4), s(2, 0, 2, 4),
+    s(11, 0, 11, 2),
+    s(7, 1, 10, 2),
+    s(16, 2, 12, 1),
+    s(16, 0, 1, 4), s(5, 2, 2, 2), s(19, 0, 3, 2),
+    s(10, 2, 12, 3),
+    s(8, 1, 4, 4),
+    s(8, 1, 0, 4), s(8, 1, 4, 4),
+    s(11, 0, 5, 4),
+    s(9, 2, 12, 3),
+    s(0, 3, 9, 4),
+    s(5, 0, 3, 2),
+    s(9, 2, 11, 4),
+    s(4, 2, 0, 4), s(5, 0, 8, 4),
+    s(1, 0, 6, 3),
+    s(16, 0, 3, 4), s(17, 0, 3, 4), s(5, 1, 4, 2),
+    s(3, 0, 11, 2), s(3, 0, 11, 4),
+    s(11, 0, 9, 1),
+    s(13, 1, 8, 2), s(12, 1, 8, 4),
+    s(11, 2, 11, 3),
+    s(21, 0, 9, 4), s(6, 0, 9, 4),
+    s(0, 1, 9, 1),
+    s(15, 0, 0, 2), s(5, 0, 3, 2),
+    s(6, 0, 3, 4),
+    s(3, 0, 10, 4),
+    s(6, 2, 6, 4),
+    s(20, 0, 9, 2),
+    s(2, 0, 12, 2), s(2, 0, 12, 1),
+    s(8, 0, 5, 2),
+    s(20, 0, 5, 3), s(20, 0, 5, 1),
+    s(0, 3, 0, 2), s(0, 3, 0, 3),
+    s(12, 1, 0, 2), s(10, 0, 3, 4),
+    s(0, 2, 6, 4),
+    s(14, 3, 0, 1),
+    s(13, 1, 8, 2), s(12, 1, 8, 1),
+    s(17, 2, 10, 4),
+    s(17, 2, 6, 4), s(0, 2, 6, 3),
+    s(2, 0, 11, 2),
+    s(15, 0, 8, 4), s(16, 0, 7, 3),
+    s(21, 0, 7, 1),
+    s(4, 0, 6, 3),
+    s(16, 0, 10, 1), s(17, 0, 10, 4),
+    s(0, 1, 0, 2), s(6, 1, 0, 2),
+    s(11, 2, 10, 4),
+    s(21, 0, 3, 4), s(13, 1, 9, 1),
+    s(4, 0, 10, 3),
+    s(0, 1, 10, 4), s(0, 1, 9, 1),
+    s(20, 0, 3, 4), s(19, 0, 3, 2),
+    s(4, 0, 12, 2),
+    s(8, 1, 9, 2), s(8, 1, 9, 3),
+    s(16, 0, 11, 2),
+    s(16, 0, 9, 3),
+    s(3, 0, 1, 2),
+    s(5, 1, 4, 1), s(5, 1, 0, 4), s(16, 0, 5, 4),
+    s(11, 2, 0, 1), s(14, 1, 1, 1),
+    s(11, 2, 0, 1), s(14, 3, 0, 4),
+    s(21, 2, 10, 3), s(14, 3, 9, 4),
+    s(14, 1, 9, 4), s(11, 0, 9, 1),
+    s(14, 1, 9, 4), s(18, 0, 9, 3),
+    s(0, 1, 9, 4),
+    s(12, 1, 10, 1), s(12, 1, 10, 4),
+    s(1, 1, 9, 3),
+    s(0, 3, 0, 3), s(0, 3, 0, 2),
+    s(11, 2, 2, 4), s(14, 3, 4, 4),
+    s(3, 1, 7, 3),
+    s(14, 3, 9, 1), s(14, 1, 9, 3),
+    s(20, 0, 9, 3),
+    s(13, 1, 9, 2),
+    s(12, 1, 9, 1),
+    s(0, 2, 2, 3),
+    s(15, 2, 0, 1),
+    s(5, 1, 4, 2), s(0, 3, 12, 3),
+    s(18, 2, 0, 4),
+    s(17, 2, 11, 4),
+    s(13, 1, 9, 3), s(11, 2, 0, 4),
+    s(13, 3, 0, 4), s(11, 0, 3, 2),
+    s(6, 1, 9, 2),
+    s(17, 0, 8, 1), s(12, 1, 8, 1),
+    s(13, 1, 9, 3),
+    s(3, 2, 0, 4), s(5, 0, 7, 1),
+    s(12, 1, 10, 1),
+    s(3, 0, 7, 3),
+    s(9, 0, 5, 4), s(11, 0, 5, 4), s(0, 1, 4, 4),
+    s(2, 0, 2, 1), s(1, 0, 1, 2),
+    s(4, 0, 11, 3), s(1, 0, 12, 1),
+    s(0, 1, 1, 2), s(0, 1, 1, 4), s(13, 1, 1, 1),
+    s(9, 0, 11, 1),
+    s(21, 2, 12, 3),
+    s(9, 2, 1, 1),
+    s(9, 2, 5, 4),
+    s(8, 1, 8, 3),
+    s(19, 0, 0, 3), s(12, 1, 0, 3), s(15, 0, 0, 3),
+    s(19, 0, 0, 4),
+    s(1, 1, 0, 4), s(1, 1, 4, 2),
+    s(0, 2, 1, 3),
+    s(10, 2, 5, 3),
+    s(11, 0, 5, 4), s(0, 2, 6, 4),
+    s(0, 1, 10, 1),
+    s(16, 2, 12, 4),
+    s(14, 1, 9, 3),
+    s(14, 3, 9, 4), s(11, 2, 12, 1),
+    s(9, 2, 6, 3), s(0, 2, 6, 3),
+    s(0, 0, 13, 2), s(18, 2, 9, 2), s(18, 2, 6, 2),
+    s(11, 2, 0, 1),
+    s(8, 0, 7, 3),
+    s(16, 0, 10, 4),
+    s(21, 0, 9, 3),
+    s(15, 2, 2, 4), s(1, 0, 5, 1),
+    s(0, 2, 2, 3), s(0, 0, 3, 2),
+    s(2, 0, 8, 2),
+    s(10, 0, 12, 1),
+    s(6, 2, 10, 4),
+    s(15, 2, 2, 2), s(19, 2, 0, 2), s(5, 2, 0, 1),
+    s(1, 1, 7, 4),
+    s(9, 2, 0, 4),
+    s(1, 1, 12, 3),
+    s(15, 0, 0, 4), s(15, 0, 0, 2),
+    s(5, 2, 6, 3), s(16, 0, 12, 2),
+    s(15, 0, 8, 1), s(15, 0, 7, 4), s(6, 1, 7, 2),
+    s(7, 0, 6, 4), s(18, 2, 6, 4),
+    s(2, 0, 2, 2),
+    s(3, 1, 10, 2), s(0, 2, 10, 3),
+    s(0, 2, 6, 3), s(6, 2, 2, 3), s(5, 2, 2, 4),
+    s(16, 0, 3, 3),
+    s(10, 2, 6, 1),
+    s(0, 1, 12, 2),
+    s(14, 1, 4, 2), s(14, 1, 0, 4),
+    s(14, 3, 10, 2), s(21, 2, 10, 3),
+    s(17, 0, 9, 3), s(17, 0, 9, 4),
+    s(14, 1, 4, 1),
+    s(11, 0, 5, 1),
+    s(2, 1, 0, 1),
+    s(7, 0, 1, 4),
+    s(16, 0, 8, 1), s(8, 1, 8, 4),
+    s(11, 2, 11, 4), s(11, 2, 11, 3),
+    s(5, 0, 8, 1),
+    s(9, 2, 10, 3),
+    s(20, 0, 3, 4),
+    s(9, 2, 6, 3), s(12, 1, 0, 4),
+    s(12, 1, 10, 4),
+    s(11, 2, 6, 4), s(14, 3, 4, 3),
+    s(3, 1, 9, 3), s(3, 0, 10, 2),
+    s(20, 0, 9, 2), s(17, 0, 9, 3), s(19, 0, 9, 4), s(16, 0, 9, 4),
+    s(2, 1, 0, 4),
+    s(0, 1, 11, 4),
+    s(17, 0, 5, 1),
+    s(12, 1, 8, 4), s(19, 2, 0, 2),
+    s(11, 2, 2, 4), s(19, 2, 2, 2), s(11, 2, 1, 2),
+    s(0, 3, 10, 3),
+    s(5, 0, 1, 2), s(8, 0, 1, 1), s(14, 1, 0, 1), s(14, 1, 4, 2),
+    s(14, 3, 9, 1),
+    s(14, 1, 7, 1), s(21, 2, 0, 4),
+    s(4, 0, 6, 4),
+    s(5, 0, 10, 4),
+    s(11, 2, 6, 3),
+    s(6, 2, 10, 2),
+    s(13, 1, 11, 2),
+    s(7, 1, 0, 3),
+    s(21, 0, 5, 1),
+    s(6, 2, 9, 2),
+    s(15, 0, 5, 4),
+    s(0, 0, 7, 1), s(2, 1, 7, 3), s(2, 0, 8, 2),
+    s(3, 1, 4, 4), s(3, 1, 0, 4),
+    s(14, 1, 4, 2), s(14, 1, 0, 1),
+    s(13, 1, 12, 3),
+    s(12, 1, 7, 3),
+    s(10, 2, 10, 3),
+    s(12, 3, 0, 2), s(13, 3, 0, 2),
+    s(13, 1, 0, 1), s(9, 2, 6, 4), s(9, 2, 6, 3),
+    s(15, 0, 0, 1),
+    s(13, 1, 0, 2), s(16, 0, 0, 4), s(0, 0, 3, 4),
+    s(15, 2, 6, 4), s(13, 1, 0, 2),
+    s(13, 1, 10, 2), s(10, 0, 9, 1), s(13, 1, 9, 4), s(13, 1, 9, 2),
+    s(9, 0, 12, 4), s(9, 0, 12, 1),
+    s(5, 1, 0, 3),
+    s(9, 2, 6, 4),
+    s(13, 3, 10, 2),
+    s(11, 0, 9, 4), s(11, 0, 3, 3),
+    s(6, 2, 12, 3),
+    s(1, 0, 2, 2), s(1, 0, 6, 4),
+    s(17, 0, 9, 3), s(7, 0, 1, 4),
+    s(16, 2, 5, 2),
+    s(21, 0, 9, 4),
+    s(5, 1, 7, 4), s(16, 2, 2, 4),
+    s(6, 0, 8, 3),
+    s(8, 1, 9, 3),
+    s(16, 2, 5, 3), s(5, 2, 2, 3),
+    s(13, 1, 9, 1), s(13, 1, 9, 4), s(12, 1, 9, 1),
+    s(10, 0, 5, 4), s(10, 0, 3, 3),
+    s(17, 0, 7, 3),
+    s(14, 1, 7, 1), s(13, 1, 7, 1),
+    s(16, 2, 2, 1), s(15, 2, 2, 2),
+    s(1, 0, 9, 4),
+    s(8, 0, 8, 3),
+    s(21, 2, 2, 3),
+    s(8, 2, 2, 4), s(9, 0, 3, 2),
+    s(5, 1, 12, 3),
+    s(5, 2, 9, 4),
+    s(0, 1, 0, 3), s(0, 0, 3, 3),
+    s(0, 1, 8, 2), s(0, 1, 7, 3),
+    s(11, 2, 6, 1), s(9, 2, 10, 3),
+    s(15, 0, 0, 3), s(17, 0, 0, 2),
+    s(9, 0, 10, 4),
+    s(11, 0, 8, 4),
+    s(6, 0, 9, 3),
+    s(13, 3, 0, 4),
+    s(16, 0, 11, 3),
+    s(6, 0, 7, 1),
+    s(8, 0, 11, 4), s(8, 0, 11, 3),
+    s(7, 0, 9, 3), s(7, 0, 9, 4),
+    s(4, 0, 6, 1),
+    s(15, 0, 8, 3),
+    s(12, 1, 4, 1),
+    s(10, 2, 10, 4),
+    s(0, 2, 10, 1),
+    s(7, 2, 9, 3),
+    s(11, 2, 9, 4), s(11, 2, 9, 3),
+    s(12, 1, 10, 4), s(19, 0, 0, 1),
+    s(2, 1, 7, 4),
+    s(20, 0, 12, 2), s(21, 2, 12, 1),
+    s(11, 0, 3, 4),
+    s(17, 0, 5, 4), s(17, 0, 1, 4),
+    s(2, 2, 0, 4), s(1, 0, 2, 2),
+    s(7, 0, 9, 4),
+    s(6, 1, 9, 1),
+    s(11, 2, 12, 3),
+    s(6, 1, 7, 1),
+    s(15, 0, 12, 1),
+    s(11, 2, 11, 1), s(11, 0, 11, 1), s(11, 2, 11, 3),
+    s(6, 2, 10, 1),
+    s(18, 0, 12, 2),
+    s(1, 0, 11, 4),
+    s(3, 0, 8, 2),
+    s(3, 0, 6, 4),
+    s(9, 0, 3, 2), s(11, 0, 3, 2),
+    s(2, 0, 5, 4), s(1, 0, 1, 4),
+    s(13, 1, 8, 2), s(0, 0, 8, 4),
+    s(14, 1, 0, 4), s(14, 1, 10, 4),
+    s(20, 2, 2, 2), s(20, 2, 9, 2),
+    s(1, 0, 1, 1), s(1, 0, 6, 4), s(1, 1, 4, 1),
+    s(19, 2, 2, 4), s(19, 0, 9, 3),
+    s(15, 0, 3, 2), s(5, 1, 4, 2),
+    s(19, 2, 6, 1), s(19, 2, 6, 3),
+    s(6, 2, 0, 2),
+    s(0, 1, 9, 3), s(0, 1, 9, 4), s(0, 0, 9, 1),
+    s(0, 0, 11, 2),
+    s(0, 2, 6, 1), s(18, 2, 6, 2),
+    s(1, 0, 11, 3),
+    s(7, 0, 5, 4),
+    s(0, 1, 8, 3),
+    s(7, 0, 5, 4), s(7, 1, 0, 4), s(7, 0, 1, 4),
+    s(17, 0, 12, 3), s(21, 0, 0, 4),
+    s(16, 0, 1, 1), s(13, 1, 0, 4),
+    s(0, 1, 9, 1), s(0, 1, 10, 1),
+    s(16, 2, 12, 4), s(6, 2, 12, 2),
+    s(16, 0, 0, 2), s(0, 1, 0, 2),
+    s(19, 0, 11, 1),
+    s(7, 1, 9, 3), s(10, 0, 1, 1),
+    s(9, 2, 9, 4),
+    s(9, 0, 5, 4),
+    s(1, 0, 10, 4), s(4, 0, 9, 4),
+    s(21, 2, 2, 3), s(21, 0, 3, 4),
+    s(0, 2, 0, 1), s(0, 2, 10, 1),
+    s(11, 0, 3, 2), s(14, 1, 1, 2), s(13, 1, 1, 4),
+    s(0, 2, 12, 1),
+    s(11, 2, 10, 2), s(11, 2, 1, 2),
+    s(16, 0, 8, 1),
+    s(17, 2, 2, 4),
+    s(21, 2, 12, 4),
+    s(14, 3, 9, 4),
+    s(2, 1, 0, 2), s(1, 1, 0, 1),
+    s(16, 0, 9, 3), s(17, 0, 9, 4),
+    s(11, 0, 10, 2),
+    s(12, 3, 12, 3), s(0, 1, 12, 3),
+    s(16, 2, 9, 2),
+    s(6, 0, 1, 4), s(5, 0, 1, 2),
+    s(10, 2, 9, 3),
+    s(6, 0, 1, 1),
+    s(13, 1, 9, 4),
+    s(2, 0, 7, 4), s(2, 0, 7, 2),
+    s(20, 0, 7, 2), s(19, 0, 7, 1),
+    s(17, 2, 0, 2),
+    s(9, 2, 12, 4), s(5, 0, 9, 3), s(12, 3, 0, 4),
+    s(2, 0, 7, 1),
+    s(13, 1, 12, 2),
+    s(8, 1, 4, 4), s(8, 0, 1, 4),
+    s(3, 1, 9, 2), s(3, 1, 9, 4),
+    s(5, 1, 0, 2), s(15, 0, 3, 2),
+    s(2, 1, 10, 2),
+    s(17, 0, 9, 1),
+    s(14, 3, 4, 2),
+    s(16, 0, 0, 1),
+    s(12, 1, 0, 4), s(13, 1, 0, 4),
+    s(11, 0, 11, 1),
+    s(19, 0, 0, 1),
+    s(11, 0, 3, 1), s(13, 1, 4, 4),
+    s(14, 1, 0, 4), s(11, 2, 6, 4),
+    s(14, 1, 1, 1),
+    s(9, 2, 6, 1),
+    s(17, 0, 10, 4),
+    s(10, 0, 8, 4), s(6, 0, 8, 4), s(6, 0, 8, 3), s(11, 0, 8, 4),
+    s(14, 1, 1, 1), s(13, 1, 4, 4), s(11, 0, 3, 1),
+    s(17, 0, 1, 4),
+    s(0, 3, 0, 1), s(14, 3, 0, 4),
+    s(0, 1, 1, 4), s(0, 1, 1, 1),
+    s(0, 1, 8, 3), s(0, 0, 8, 3),
+    s(14, 1, 9, 1), s(14, 1, 9, 4), s(11, 0, 9, 3),
+    s(14, 1, 1, 4), s(14, 1, 1, 2),
+    s(0, 1, 0, 3), s(14, 1, 0, 1), s(11, 0, 8, 4),
+    s(17, 0, 1, 4), s(13, 1, 4, 4),
+    s(17, 0, 0, 4), s(10, 2, 9, 3),
+    s(11, 0, 6, 1), s(3, 0, 2, 4),
+    s(21, 0, 3, 4), s(14, 1, 0, 4),
+    s(0, 1, 4, 2), s(16, 0, 3, 4),
+    s(14, 1, 8, 1),
+    s(14, 1, 8, 3), s(9, 2, 1, 3),
+    s(16, 2, 9, 3), s(1, 2, 0, 4),
+    s(15, 0, 1, 2),
+    s(0, 1, 0, 4), s(8, 0, 1, 1),
+    s(2, 1, 0, 1), s(2, 1, 0, 3),
+    s(10, 2, 0, 1), s(9, 2, 0, 1),
+    s(17, 0, 12, 1),
+    s(6, 2, 6, 3),
+    s(8, 1, 12, 2), s(8, 0, 12, 4),
+    s(0, 2, 2, 4), s(0, 3, 9, 3),
+    s(5, 2, 0, 2),
+    s(8, 0, 9, 4),
+    s(0, 0, 5, 2),
+    s(19, 0, 5, 3),
+    s(3, 0, 2, 4),
+    s(4, 0, 10, 4),
+    s(2, 0, 12, 2), s(1, 0, 12, 1),
+    s(15, 0, 10, 3), s(13, 1, 10, 2),
+    s(9, 0, 5, 1), s(10, 0, 5, 1),
+    s(13, 3, 4, 4), s(11, 2, 0, 4), s(13, 1, 11, 3),
+    s(15, 0, 10, 1), s(16, 0, 10, 1),
+    s(10, 2, 12, 1), s(15, 2, 12, 1),
+    s(12, 3, 4, 2), s(10, 2, 0, 1),
+    s(17, 0, 7, 2), s(6, 0, 7, 2),
+    s(7, 0, 12, 4),
+    s(1, 0, 7, 4), s(13, 3, 0, 2),
+    s(18, 2, 12, 2), s(17, 2, 0, 4),
+    s(14, 1, 9, 1),
+    s(17, 0, 1, 1),
+    s(6, 0, 9, 2),
+    s(18, 2, 12, 3),
+    s(21, 2, 0, 1), s(19, 2, 0, 2),
+    s(17, 2, 5, 1),
+    s(21, 0, 7, 4),
+    s(12, 1, 7, 1),
+    s(18, 0, 9, 2),
+    s(5, 1, 4, 2), s(15, 0, 0, 4),
+    s(15, 2, 12, 4),
+    s(0, 1, 0, 4), s(16, 0, 1, 4),
+    s(7, 1, 9, 4), s(18, 0, 10, 3),
+    s(9, 2, 2, 4),
+    s(19, 0, 3, 4),
+    s(0, 1, 9, 2), s(0, 1, 9, 4),
+    s(0, 1, 8, 1), s(15, 2, 12, 1),
+    s(0, 1, 1, 1),
+    s(16, 0, 5, 4), s(16, 0, 1, 4),
+    s(0, 3, 0, 2), s(0, 1, 8, 2),
+    s(16, 0, 3, 4), s(18, 0, 3, 4),
+    s(19, 0, 9, 3),
+    s(14, 3, 9, 2),
+    s(7, 0, 8, 3), s(18, 2, 0, 3),
+    s(11, 2, 0, 1), s(3, 1, 10, 3), s(0, 2, 10, 3), s(6, 2, 6, 4),
+    s(11, 2, 1, 1),
+    s(0, 2, 11, 3), s(0, 2, 11, 1),
+    s(1, 1, 0, 4), s(0, 3, 0, 4),
+    s(13, 1, 11, 1),
+    s(4, 0, 9, 4), s(4, 0, 9, 1),
+    s(17, 0, 3, 4), s(3, 0, 11, 2),
+    s(3, 1, 9, 4), s(3, 1, 9, 3),
+    s(14, 1, 0, 1), s(12, 1, 0, 2), s(0, 1, 0, 4), s(21, 0, 3, 4),
+    s(15, 0, 1, 3), s(15, 0, 1, 2),
+    s(12, 3, 10, 4), s(0, 1, 1, 2),
+    s(7, 0, 12, 2),
+    s(13, 3, 4, 4), s(11, 2, 0, 2),
+    s(11, 0, 9, 4), s(0, 3, 0, 4),
+    s(6, 0, 9, 1), s(6, 0, 9, 4),
+    s(20, 2, 6, 3),
+    s(4, 0, 6, 4), s(2, 0, 5, 4), s(1, 1, 0, 4),
+    s(17, 0, 0, 2),
+    s(7, 3, 0, 4),
+    s(2, 1, 12, 2),
+    s(1, 0, 7, 2),
+    s(21, 2, 6, 2),
+    s(9, 0, 3, 2), s(0, 1, 0, 4),
+    s(2, 1, 0, 4), s(1, 0, 2, 2),
+    s(14, 1, 9, 2), s(0, 1, 9, 4), s(0, 1, 9, 2),
+    s(2, 1, 7, 3),
+    s(20, 0, 11, 2),
+    s(19, 0, 8, 4), s(12, 3, 0, 4),
+    s(19, 2, 2, 1),
+    s(11, 2, 9, 2), s(3, 0, 2, 4),
+    s(2, 0, 5, 4),
+    s(13, 1, 12, 4), s(12, 1, 12, 4),
+    s(9, 2, 2, 2), s(11, 2, 2, 4),
+    s(15, 2, 0, 1), s(16, 2, 0, 2),
+    s(17, 0, 9, 3), s(17, 0, 10, 3), s(6, 0, 9, 4),
+    s(4, 0, 9, 4), s(4, 0, 9, 2),
+    s(1, 0, 1, 4),
+    s(7, 0, 11, 3),
+    s(5, 0, 5, 4), s(11, 2, 2, 3),
+    s(16, 0, 9, 2), s(0, 1, 10, 2),
+    s(16, 0, 7, 3),
+    s(8, 1, 8, 4),
+    s(7, 2, 12, 2),
+    s(11, 2, 12, 4),
+    s(0, 0, 5, 1), s(14, 1, 0, 1),
+    s(1, 1, 7, 1),
+    s(16, 2, 6, 3),
+    s(11, 2, 6, 1), s(9, 2, 5, 4),
+    s(4, 0, 10, 2),
+    s(15, 0, 1, 3),
+    s(11, 2, 0, 4), s(14, 3, 4, 4),
+    s(1, 0, 12, 4),
+    s(16, 0, 0, 1), s(8, 1, 0, 2),
+    s(12, 3, 9, 3),
+    s(15, 0, 7, 4), s(17, 0, 7, 4),
+    s(0, 2, 0, 2),
+    s(5, 1, 9, 3),
+    s(12, 1, 4, 2), s(12, 1, 0, 2),
+    s(10, 0, 7, 4),
+    s(19, 2, 9, 3),
+    s(8, 0, 5, 4), s(8, 0, 1, 4),
+    s(20, 2, 9, 4),
+    s(0, 1, 10, 2), s(12, 1, 12, 1),
+    s(0, 2, 9, 4),
+    s(15, 0, 3, 1),
+    s(8, 1, 0, 3), s(8, 1, 0, 4),
+    s(17, 0, 7, 2),
+    s(10, 2, 12, 4),
+    s(14, 1, 9, 1), s(10, 0, 9, 3),
+    s(6, 1, 7, 3),
+    s(2, 1, 9, 4),
+    s(11, 2, 0, 4), s(12, 1, 1, 4),
+    s(10, 0, 10, 4),
+    s(12, 1, 8, 1),
+    s(1, 0, 1, 1),
+    s(10, 0, 3, 1),
+    s(6, 2, 2, 2),
+    s(19, 2, 2, 2),
+    s(11, 0, 8, 3), s(0, 0, 8, 3), s(10, 0, 8, 3),
+    s(8, 1, 4, 4), s(8, 2, 2, 1),
+    s(8, 1, 11, 2),
+    s(11, 0, 3, 2), s(12, 1, 9, 1),
+    s(0, 3, 9, 2), s(0, 2, 9, 2),
+    s(16, 0, 9, 3), s(17, 0, 12, 4),
+    s(5, 1, 0, 2),
+    s(2, 2, 0, 3),
+    s(12, 1, 11, 1),
+    s(11, 2, 9, 1),
+    s(18, 0, 7, 2),
+    s(0, 1, 10, 2), s(0, 1, 10, 3), s(0, 1, 1, 2),
+    s(12, 3, 4, 2), s(10, 2, 5, 4),
+    s(12, 1, 1, 1), s(9, 0, 1, 1),
+    s(15, 2, 12, 1),
+    s(14, 1, 7, 1), s(14, 1, 7, 4),
+    s(9, 0, 12, 1),
+    s(6, 1, 12, 2),
+    s(0, 2, 2, 1), s(0, 2, 6, 1),
+    s(2, 1, 7, 3), s(12, 1, 7, 4),
+    s(15, 2, 2, 2), s(15, 0, 7, 4),
+    s(11, 2, 0, 1), s(7, 0, 7, 2),
+    s(0, 1, 9, 2), s(9, 0, 9, 3),
+    s(9, 0, 8, 3),
+    s(8, 0, 7, 3), s(21, 0, 7, 1),
+    s(17, 0, 9, 1), s(17, 0, 9, 4), s(21, 0, 7, 1), s(17, 0, 9, 3),
+    s(14, 1, 7, 1), s(7, 0, 7, 3), s(13, 1, 7, 1), s(14, 1, 7, 4),
+    s(11, 0, 5, 4), s(11, 2, 6, 1),
+    s(4, 0, 9, 2), s(1, 1, 9, 4),
+    s(6, 0, 1, 3),
+    s(11, 2, 2, 4), s(11, 2, 2, 1),
+    s(12, 1, 8, 4), s(13, 1, 8, 2),
+    s(7, 1, 8, 3),
+    s(3, 0, 6, 2),
+    s(12, 1, 8, 2),
+    s(8, 0, 1, 4),
+    s(19, 0, 8, 1),
+    s(1, 1, 10, 1), s(1, 0, 9, 1),
+    s(14, 3, 4, 1), s(5, 1, 4, 2),
+    s(5, 2, 2, 4),
+    s(19, 0, 10, 1),
+    s(21, 2, 6, 4), s(14, 3, 9, 2),
+    s(17, 2, 0, 3),
+    s(0, 0, 3, 2),
+    s(9, 2, 0, 1), s(18, 2, 0, 3),
+    s(7, 0, 6, 3),
+    s(5, 0, 11, 1),
+    s(0, 0, 11, 4),
+    s(2, 0, 12, 4), s(1, 0, 12, 4),
+    s(6, 0, 8, 3), s(10, 0, 7, 3),
+    s(16, 0, 12, 2), s(17, 0, 12, 4),
+    s(11, 2, 9, 4), s(11, 2, 1, 4),
+    s(16, 0, 5, 1), s(13, 1, 0, 4),
+    s(18, 2, 9, 3), s(12, 3, 10, 4),
+    s(16, 2, 9, 2), s(15, 2, 9, 1),
+    s(10, 0, 11, 1), s(11, 2, 11, 1),
+    s(2, 1, 7, 2),
+    s(16, 2, 11, 3), s(17, 2, 11, 3),
+    s(6, 2, 12, 2), s(15, 2, 12, 4),
+    s(6, 0, 9, 2), s(14, 1, 10, 1),
+    s(9, 0, 9, 4),
+    s(9, 0, 8, 1), s(13, 3, 0, 2),
+    s(0, 0, 9, 3),
+    s(18, 0, 8, 2),
+    s(20, 2, 2, 2),
+    s(19, 0, 12, 3),
+    s(4, 2, 0, 4), s(2, 1, 0, 4),
+    s(12, 1, 7, 3), s(12, 1, 8, 1), s(7, 1, 8, 2),
+    s(12, 1, 7, 3), s(14, 1, 8, 3),
+    s(17, 2, 6, 4),
+    s(4, 0, 9, 3), s(4, 0, 9, 4),
+    s(13, 1, 4, 4), s(13, 3, 0, 3),
+    s(5, 2, 0, 2), s(16, 0, 8, 2),
+    s(12, 1, 0, 2), s(20, 0, 0, 4), s(21, 0, 3, 4),
+    s(14, 1, 8, 1), s(14, 1, 8, 4),
+    s(0, 1, 1, 1), s(14, 1, 1, 1),
+    s(12, 1, 4, 4), s(0, 1, 1, 2),
+    s(5, 0, 1, 2), s(11, 0, 3, 4), s(5, 0, 1, 5),
+    s(18, 2, 0, 2), s(18, 2, 0, 4),
+    s(8, 1, 0, 3),
+    s(8, 1, 11, 4),
+    s(9, 0, 12, 3),
+    s(14, 1, 10, 4), s(14, 1, 0, 4),
+    s(10, 2, 11, 2),
+    s(13, 1, 10, 1),
+    s(2, 0, 6, 4), s(2, 0, 6, 1),
+    s(10, 2, 2, 4), s(8, 2, 2, 3),
+    s(0, 1, 0, 1), s(13, 1, 0, 3), s(0, 0, 5, 3),
+    s(9, 2, 5, 1),
+    s(11, 2, 6, 1), s(6, 2, 6, 2),
+    s(16, 0, 5, 2), s(15, 0, 5, 4), s(16, 0, 0, 2),
+    s(15, 2, 0, 4), s(16, 2, 0, 2),
+    s(15, 2, 6, 4), s(6, 2, 6, 2),
+    s(14, 1, 9, 1), s(8, 1, 9, 2),
+    s(15, 0, 0, 4), s(5, 0, 5, 4),
+    s(14, 1, 0, 2), s(14, 1, 0, 4),
+    s(4, 0, 10, 4), s(4, 0, 10, 2),
+    s(5, 0, 7, 3), s(15, 0, 8, 4), s(16, 0, 8, 2),
+    s(8, 2, 0, 2), s(8, 2, 0, 4),
+    s(1, 1, 4, 3),
+    s(1, 0, 5, 2),
+    s(11, 2, 11, 4),
+    s(11, 0, 3, 1),
+    s(16, 0, 3, 4), s(8, 0, 3, 4),
+    s(0, 0, 7, 2), s(1, 1, 0, 4),
+    s(5, 0, 1, 1),
+    s(20, 2, 2, 2), s(20, 0, 7, 1),
+    s(0, 2, 9, 3), s(3, 0, 9, 2),
+    s(0, 1, 1, 3),
+    s(17, 0, 3, 4),
+    s(10, 0, 9, 4),
+    s(14, 3, 9, 4), s(14, 3, 10, 2),
+    s(12, 3, 4, 2), s(14, 3, 4, 4),
+    s(14, 1, 4, 2), s(12, 1, 0, 1),
+    s(3, 0, 6, 4), s(0, 2, 0, 4), s(3, 0, 1, 4),
+    s(16, 0, 9, 4), s(5, 1, 9, 1), s(6, 0, 9, 4),
+    s(1, 1, 0, 4), s(3, 0, 1, 4),
+    s(11, 2, 1, 4), s(9, 2, 1, 1),
+    s(12, 1, 1, 2), s(6, 2, 10, 3),
+    s(9, 0, 5, 1),
+    s(0, 1, 12, 3), s(0, 1, 1, 4), s(10, 0, 12, 1),
+    s(8, 1, 11, 4), s(8, 0, 11, 3),
+    s(12, 1, 4, 2), s(15, 0, 1, 3), s(17, 0, 3, 4), s(12, 1, 1, 2), s(0, 1, 1, 4),
+    s(16, 0, 8, 1), s(6, 0, 7, 1),
+    s(2, 1, 12, 4),
+    s(16, 0, 10, 1), s(18, 0, 10, 4),
+    s(5, 2, 0, 3),
+    s(3, 0, 12, 3), s(3, 0, 12, 4),
+    s(14, 1, 4, 4), s(15, 0, 3, 2),
+    s(13, 1, 1, 4), s(10, 0, 9, 1),
+    s(16, 0, 12, 2), s(6, 1, 12, 2), s(16, 0, 12, 1),
+    s(0, 1, 12, 1), s(0, 1, 12, 4),
+    s(12, 1, 0, 2), s(19, 0, 0, 2),
+    s(19, 2, 12, 1), s(19, 2, 12, 3), s(12, 1, 4, 4),
+    s(14, 3, 9, 1), s(11, 0, 9, 4),
+    s(8, 0, 8, 1),
+    s(10, 0, 5, 3),
+    s(21, 2, 10, 3), s(13, 3, 12, 2),
+    s(0, 1, 12, 2), s(0, 1, 12, 3),
+    s(16, 0, 3, 4), s(15, 0, 3, 2), s(11, 2, 6, 3),
+    s(11, 2, 0, 2), s(3, 0, 12, 2),
+    s(20, 0, 12, 2),
+    s(14, 1, 0, 1), s(14, 1, 4, 2),
+    s(10, 0, 8, 1),
+    s(15, 0, 9, 3), s(17, 0, 9, 3),
+    s(1, 1, 9, 3), s(11, 2, 9, 2),
+    s(12, 1, 0, 1), s(12, 1, 7, 3),
+    s(12, 3, 4, 2), s(0, 2, 0, 4),
+    s(7, 0, 11, 2), s(7, 2, 12, 3),
+    s(17, 0, 0, 4), s(0, 1, 0, 4),
+    s(17, 2, 2, 4), s(8, 1, 0, 4),
+    s(3, 1, 4, 4),
+    s(19, 2, 9, 3), s(20, 2, 9, 1),
+    s(5, 2, 6, 1),
+    s(15, 0, 3, 4),
+    s(15, 0, 10, 1), s(15, 0, 10, 3),
+    s(0, 1, 9, 4), s(13, 1, 12, 4),
+    s(13, 1, 7, 3), s(5, 1, 7, 4),
+    s(11, 0, 11, 1), s(11, 2, 12, 2),
+    s(13, 1, 7, 1),
+    s(4, 0, 1, 3),
+    s(20, 2, 2, 3), s(16, 0, 1, 3),
+    s(0, 2, 2, 3), s(10, 0, 3, 1),
+    s(12, 3, 0, 3),
+    s(15, 0, 10, 1), s(0, 1, 10, 1),
+    s(15, 0, 1, 4), s(15, 0, 1, 3),
+    s(1, 0, 9, 1), s(2, 0, 9, 2),
+    s(11, 2, 12, 2), s(13, 3, 12, 2),
+    s(8, 0, 7, 2), s(8, 2, 2, 4),
+    s(1, 0, 2, 1), s(15, 2, 2, 2),
+    s(20, 2, 0, 4), s(19, 2, 0, 2),
+    s(12, 1, 10, 1), s(13, 1, 10, 2),
+    s(5, 2, 10, 1),
+    s(0, 2, 6, 3), s(10, 2, 6, 3), s(8, 0, 6, 3),
+    s(9, 0, 9, 3),
+    s(20, 0, 1, 1),
+    s(2, 0, 9, 2),
+    s(8, 0, 1, 4), s(8, 1, 4, 4),
+    s(11, 2, 5, 2), s(9, 2, 6, 1), s(9, 2, 5, 4),
+    s(14, 1, 9, 4), s(12, 1, 10, 1),
+    s(3, 0, 2, 2),
+    s(9, 2, 6, 3), s(15, 0, 0, 1), s(4, 2, 0, 2),
+    s(11, 2, 2, 2), s(11, 2, 9, 4),
+    s(5, 0, 8, 4), s(14, 1, 11, 2),
+    s(0, 1, 9, 3), s(0, 1, 9, 4),
+    s(15, 2, 6, 4), s(16, 2, 2, 4),
+    s(15, 0, 1, 4),
+    s(13, 3, 9, 4),
+    s(11, 2, 2, 2),
+    s(2, 0, 12, 2),
+    s(8, 1, 0, 2), s(16, 0, 0, 1),
+    s(16, 2, 11, 1),
+    s(8, 0, 5, 4), s(8, 0, 9, 3),
+    s(5, 1, 7, 3),
+    s(7, 1, 9, 2),
+    s(0, 1, 1, 2),
+    s(4, 0, 11, 1),
+    s(1, 1, 0, 4), s(1, 0, 2, 2),
+    s(15, 0, 0, 4), s(6, 1, 0, 2),
+    s(6, 0, 7, 2), s(16, 0, 7, 2), s(6, 1, 7, 1),
+    s(18, 2, 0, 4), s(18, 2, 12, 3),
+    s(13, 3, 12, 2), s(12, 3, 12, 4),
+    s(9, 2, 6, 1), s(0, 2, 1, 1),
+    s(0, 1, 1, 4),
+    s(12, 3, 9, 1),
+    s(6, 1, 12, 3),
+    s(18, 2, 6, 2),
+    s(0, 0, 9, 1), s(0, 0, 9, 3), s(0, 1, 9, 1), s(0, 1, 9, 3), s(0, 1, 4, 4),
+    s(13, 3, 9, 3),
+    s(16, 0, 11, 1),
+    s(5, 2, 2, 4), s(16, 2, 6, 2), s(6, 2, 2, 3),
+    s(10, 2, 12, 1),
+    s(12, 1, 4, 2), s(9, 0, 3, 2),
+    s(0, 1, 9, 1), s(0, 1, 10, 4),
+    s(15, 0, 0, 3), s(13, 1, 0, 2),
+    s(20, 0, 11, 4),
+    s(0, 0, 10, 3),
+    s(16, 2, 0, 2), s(19, 0, 8, 1),
+    s(7, 0, 8, 4),
+    s(6, 2, 0, 2), s(16, 2, 0, 2),
+    s(17, 0, 10, 1), s(19, 2, 0, 2),
+    s(1, 1, 7, 1), s(3, 1, 7, 3),
+    s(3, 0, 9, 2), s(3, 0, 9, 4),
+    s(14, 1, 10, 1), s(9, 2, 0, 3),
+    s(20, 0, 10, 2), s(13, 1, 9, 2),
+    s(11, 2, 1, 2), s(11, 2, 11, 2),
+    s(3, 0, 6, 3),
+    s(5, 0, 7, 4),
+    s(20, 2, 6, 4), s(3, 0, 6, 4),
+    s(4, 0, 6, 4), s(4, 0, 10, 4),
+    s(0, 0, 3, 4), s(18, 2, 9, 3),
+    s(0, 1, 1, 1), s(19, 0, 1, 1),
+    s(6, 2, 12, 2), s(5, 2, 12, 4),
+    s(14, 3, 4, 4), s(15, 2, 0, 2),
+    s(10, 0, 3, 4), s(0, 0, 7, 1),
+    s(11, 2, 12, 1),
+    s(16, 2, 12, 1), s(6, 2, 12, 3),
+    s(8, 0, 9, 2), s(8, 0, 9, 4),
+    s(16, 2, 0, 4), s(13, 1, 0, 4),
+    s(1, 0, 1, 4), s(2, 1, 0, 1),
+    s(8, 2, 2, 4), s(7, 2, 2, 4),
+    s(13, 1, 9, 3), s(13, 1, 9, 4),
+    s(9, 0, 9, 1), s(9, 0, 9, 3),
+    s(10, 2, 0, 3), s(9, 2, 0, 4),
+    s(0, 0, 11, 3), s(0, 1, 12, 1),
+    s(15, 0, 0, 4), s(12, 1, 0, 1),
+    s(9, 2, 12, 4), s(14, 1, 11, 2),
+    s(0, 1, 0, 4), s(0, 1, 4, 4),
+    s(15, 2, 0, 1), s(17, 2, 0, 1), s(16, 2, 11, 3),
+    s(20, 0, 3, 4), s(12, 1, 1, 1),
+    s(12, 3, 4, 2), s(0, 2, 2, 4),
+    s(1, 1, 4, 2),
+    s(16, 0, 12, 2), s(6, 1, 12, 1),
+    s(15, 0, 3, 2), s(15, 0, 0, 4),
+    s(21, 0, 9, 1),
+    s(4, 0, 6, 2), s(1, 0, 1, 1),
+    s(3, 0, 11, 2), s(3, 0, 12, 2),
+    s(1, 0, 7, 2), s(4, 2, 0, 2),
+    s(7, 0, 9, 3), s(8, 0, 9, 3),
+    s(9, 0, 3, 2), s(13, 1, 1, 4),
+    s(17, 0, 0, 2), s(6, 1, 0, 2), s(12, 1, 0, 1), s(0, 1, 0, 2),
+    s(20, 0, 3, 4), s(15, 0, 1, 2),
+    s(11, 2, 9, 3), s(0, 3, 9, 4),
+    s(20, 2, 12, 1), s(21, 2, 12, 1),
+    s(21, 0, 5, 3), s(14, 1, 0, 3),
+    s(21, 0, 8, 1), s(11, 2, 5, 2),
+    s(17, 0, 7, 1),
+    s(17, 0, 7, 1), s(17, 2, 2, 4),
+    s(8, 0, 12, 2),
+    s(9, 2, 0, 1), s(11, 2, 0, 2),
+    s(20, 0, 9, 1),
+    s(0, 3, 0, 4), s(0, 0, 7, 3),
+    s(8, 1, 8, 2), s(8, 1, 8, 3),
+    s(14, 1, 7, 4), s(12, 1, 7, 3),
+    s(0, 0, 8, 1),
+    s(14, 1, 9, 1), s(14, 1, 9, 3),
+    s(18, 0, 11, 2),
+    s(15, 2, 9, 4), s(19, 2, 9, 3),
+    s(1, 0, 9, 3),
+    s(20, 0, 3, 4), s(21, 0, 3, 4),
+    s(12, 1, 8, 4), s(13, 1, 8, 3),
+    s(13, 3, 9, 3), s(13, 3, 10, 2),
+    s(7, 0, 9, 3),
+    s(15, 0, 3, 2), s(16, 0, 3, 4),
+    s(3, 0, 10, 2),
+    s(6, 0, 9, 2), s(5, 0, 9, 4),
+    s(16, 2, 1, 1),
+    s(5, 0, 7, 4), s(16, 0, 8, 2),
+    s(10, 2, 11, 4),
+    s(16, 0, 9, 4), s(16, 0, 9, 3),
+    s(3, 1, 0, 2), s(3, 0, 2, 2),
+    s(9, 2, 5, 4), s(12, 3, 4, 2),
+    s(18, 2, 0, 2), s(7, 0, 1, 3),
+    s(19, 2, 2, 2), s(15, 0, 1, 4),
+    s(13, 1, 12, 4),
+    s(4, 0, 9, 2), s(2, 0, 2, 2),
+    s(0, 0, 3, 1),
+    s(7, 1, 8, 4),
+    s(14, 3, 0, 3), s(14, 1, 4, 2),
+    s(6, 1, 12, 1), s(0, 1, 12, 2),
+    s(15, 0, 1, 3), s(7, 0, 1, 4),
+    s(0, 0, 8, 4),
+    s(14, 1, 7, 1), s(17, 2, 2, 4),
+    s(6, 0, 1, 1), s(6, 0, 1, 4),
+    s(1, 1, 4, 4), s(1, 1, 0, 4),
+    s(1, 0, 2, 2), s(1, 1, 0, 4),
+    s(6, 1, 0, 3),
+    s(17, 0, 0, 1), s(15, 0, 0, 3),
+    s(20, 0, 1, 1), s(20, 0, 5, 4),
+    s(13, 3, 9, 1), s(13, 3, 9, 2), s(9, 2, 9, 4),
+    s(17, 0, 0, 1),
+    s(15, 2, 0, 2),
+    s(16, 0, 8, 1), s(16, 0, 8, 2),
+    s(20, 0, 5, 3),
+    s(18, 0, 10, 2),
+    s(15, 0, 5, 3),
+    s(20, 2, 10, 4), s(14, 1, 9, 3),
+    s(3, 0, 8, 3),
+    s(5, 1, 0, 1), s(5, 1, 0, 3),
+    s(3, 0, 6, 2), s(3, 0, 8, 3),
+    s(8, 0, 11, 4),
+    s(19, 2, 12, 4),
+    s(2, 0, 2, 1), s(4, 0, 6, 4),
+    s(0, 2, 1, 4),
+    s(14, 3, 0, 2),
+    s(13, 3, 4, 4), s(12, 3, 4, 2), s(16, 2, 2, 4),
+    s(19, 0, 9, 1), s(20, 0, 9, 2),
+    s(14, 1, 7, 1), s(6, 1, 7, 2),
+    s(15, 0, 8, 1),
+    s(8, 2, 2, 4), s(2, 0, 2, 4),
+    s(19, 0, 12, 1),
+    s(11, 2, 10, 1),
+    s(0, 0, 13, 2), s(7, 2, 2, 4),
+    s(1, 0, 11, 4), s(2, 0, 8, 2),
+    s(0, 1, 4, 4), s(0, 0, 9, 4),
+    s(16, 2, 11, 4),
+    s(20, 0, 7, 1),
+    s(11, 0, 9, 4), s(11, 0, 9, 3),
+    s(1, 0, 1, 4), s(1, 0, 6, 1),
+    s(9, 0, 12, 1), s(17, 0, 12, 4),
+    s(0, 2, 10, 2),
+    s(3, 1, 9, 2), s(3, 1, 12, 2),
+    s(5, 1, 0, 3), s(15, 0, 0, 4),
+    s(8, 1, 9, 2), s(8, 2, 9, 2),
+    s(13, 1, 0, 4), s(0, 1, 0, 4),
+    s(0, 3, 9, 4), s(0, 3, 9, 1),
+    s(2, 1, 9, 4), s(2, 0, 9, 4),
+    s(9, 2, 9, 3),
+    s(7, 1, 8, 3), s(15, 0, 8, 3), s(18, 0, 8, 4), s(7, 3, 0, 4),
+    s(15, 0, 10, 4), s(0, 1, 10, 3),
+    s(6, 0, 9, 3), s(5, 0, 9, 4), s(15, 2, 9, 4),
+    s(16, 0, 7, 3), s(3, 1, 7, 3),
+    s(15, 2, 9, 1),
+    s(1, 1, 4, 2), s(1, 1, 0, 4),
+    s(13, 1, 0, 4), s(8, 0, 1, 1),
+    s(9, 2, 1, 1), s(6, 1, 9, 2),
+    s(7, 0, 1, 4), s(7, 0, 1, 2), s(16, 0, 0, 3),
+    s(11, 2, 6, 3), s(5, 2, 6, 1),
+    s(5, 1, 4, 2), s(6, 1, 0, 1),
+    s(17, 0, 7, 4),
+    s(3, 0, 12, 3), s(3, 0, 11, 3), s(3, 0, 11, 2),
+    s(14, 1, 9, 4), s(16, 0, 10, 1),
+    s(2, 0, 11, 1),
+    s(20, 0, 9, 2), s(15, 0, 9, 4),
+    s(1, 2, 0, 4), s(2, 0, 6, 2),
+    s(15, 2, 2, 2), s(5, 2, 0, 1),
+    s(12, 3, 10, 4), s(15, 2, 10, 1),
+    s(14, 1, 9, 4), s(11, 0, 9, 4),
+    s(0, 2, 10, 3), s(16, 2, 10, 2),
+    s(19, 0, 0, 4), s(7, 0, 7, 3),
+    s(10, 2, 10, 1), s(11, 2, 10, 2), s(11, 2, 10, 4),
+    s(16, 2, 10, 3), s(17, 2, 10, 3),
+    s(1, 1, 10, 3), s(1, 1, 9, 4),
+    s(15, 2, 0, 1), s(5, 2, 0, 3),
+    s(16, 0, 10, 1),
+    s(21, 2, 2, 4),
+    s(18, 2, 2, 4),
+    s(14, 1, 11, 3), s(9, 0, 8, 1),
+    s(15, 2, 0, 4), s(15, 0, 8, 4), s(16, 2, 0, 4),
+    s(16, 0, 5, 1), s(20, 2, 2, 2),
+    s(10, 0, 7, 4), s(11, 0, 3, 4),
+    s(9, 2, 0, 3), s(13, 3, 4, 4),
+    s(9, 0, 5, 1), s(9, 2, 6, 1), s(10, 0, 5, 3),
+    s(15, 0, 0, 4), s(5, 1, 0, 4),
+    s(8, 0, 6, 2), s(8, 0, 6, 3),
+    s(9, 2, 12, 1), s(11, 2, 11, 2),
+    s(19, 0, 9, 1), s(12, 1, 10, 3), s(13, 1, 9, 2),
+    s(11, 2, 2, 4), s(9, 2, 2, 2),
+    s(21, 0, 8, 1), s(21, 0, 7, 4), s(14, 1, 7, 4),
+    s(6, 0, 9, 4), s(6, 0, 9, 2),
+    s(9, 2, 0, 1),
+    s(0, 0, 7, 4), s(0, 0, 7, 3),
+    s(2, 1, 0, 4), s(2, 1, 0, 3),
+    s(0, 0, 5, 3),
+    s(2, 1, 0, 4), s(0, 1, 0, 4),
+    s(0, 2, 6, 2), s(0, 2, 6, 4),
+    s(18, 0, 11, 3),
+    s(0, 1, 9, 1), s(0, 1, 9, 4), s(0, 1, 12, 3),
+    s(13, 3, 9, 2), s(11, 2, 9, 1),
+    s(5, 0, 11, 4),
+    s(6, 0, 9, 1), s(18, 0, 9, 2), s(6, 1, 9, 4),
+    s(6, 0, 1, 4), s(6, 1, 4, 4),
+    s(12, 1, 1, 3),
+    s(0, 0, 3, 4), s(21, 0, 1, 4),
+    s(6, 0, 7, 1), s(0, 1, 7, 4), s(6, 1, 7, 1),
+    s(21, 0, 8, 1), s(21, 0, 7, 1),
+    s(20, 0, 7, 4),
+    s(6, 0, 12, 2),
+    s(3, 1, 12, 4),
+    s(2, 0, 11, 3),
+    s(21, 0, 12, 1),
+    s(3, 0, 12, 2), s(3, 0, 12, 4),
+    s(20, 0, 7, 3),
+    s(14, 1, 7, 3),
+    s(7, 1, 8, 2),
+    s(16, 0, 10, 2), s(0, 1, 10, 2),
+    s(4, 0, 12, 1), s(14, 1, 1, 2),
+    s(18, 0, 0, 4),
+    s(13, 3, 0, 1), s(13, 3, 0, 4),
+    s(17, 0, 0, 3), s(21, 0, 0, 4),
+    s(5, 0, 12, 3),
+    s(10, 2, 11, 1),
+    s(14, 1, 0, 4), s(11, 0, 3, 4), s(10, 0, 3, 4),
+    s(0, 1, 4, 2), s(0, 1, 4, 1),
+    s(0, 2, 0, 2), s(0, 3, 0, 2),
+    s(6, 2, 2, 3),
+    s(9, 0, 7, 1), s(12, 1, 8, 4),
+    s(10, 2, 1, 1), s(9, 2, 5, 1),
+    s(13, 3, 0, 1), s(20, 2, 0, 2),
+    s(15, 0, 8, 1), s(8, 1, 4, 4),
+    s(16, 0, 5, 2), s(19, 2, 6, 1),
+    s(11, 0, 10, 3), s(14, 1, 4, 2),
+    s(8, 0, 5, 4),
+    s(3, 0, 11, 2), s(11, 0, 3, 4),
+    s(19, 0, 0, 3), s(19, 0, 5, 3),
+    s(19, 2, 0, 3),
+    s(0, 1, 7, 2), s(0, 1, 7, 4),
+    s(13, 1, 0, 3), s(0, 0, 5, 2),
+    s(8, 1, 0, 4), s(21, 2, 9, 4),
+    s(1, 1, 12, 1),
+    s(16, 0, 8, 4),
+    s(14, 1, 4, 2), s(0, 1, 4, 2), s(6, 2, 0, 2),
+    s(15, 0, 5, 1),
+    s(17, 0, 7, 3), s(17, 0, 7, 1), s(17, 2, 2, 4),
+    s(2, 0, 1, 1),
+    s(17, 2, 11, 3),
+    s(5, 0, 12, 1), s(16, 0, 12, 2),
+    s(20, 0, 3, 4), s(20, 2, 6, 4), s(16, 2, 1, 4),
+    s(6, 1, 0, 2), s(6, 0, 5, 2),
+    s(0, 2, 6, 2), s(0, 2, 6, 3),
+    s(5, 2, 10, 1), s(5, 2, 6, 1),
+    s(21, 0, 3, 4), s(19, 0, 3, 2),
+    s(13, 1, 0, 4), s(0, 0, 3, 4),
+    s(19, 2, 10, 3),
+    s(16, 0, 1, 2), s(16, 2, 6, 4),
+    s(8, 1, 12, 2), s(8, 1, 12, 3),
+    s(10, 2, 6, 4),
+    s(8, 3, 0, 2),
+    s(13, 1, 9, 2), s(14, 1, 9, 2),
+    s(12, 3, 0, 2), s(13, 3, 0, 1),
+    s(6, 0, 8, 2),
+    s(19, 0, 1, 2),
+    s(0, 1, 10, 2), s(0, 1, 9, 2),
+    s(0, 3, 4, 4), s(8, 0, 1, 3),
+    s(7, 1, 11, 4),
+    s(7, 3, 4, 4),
+    s(16, 2, 0, 3), s(16, 2, 0, 4),
+    s(10, 0, 9, 3), s(11, 0, 9, 4),
+    s(0, 2, 0, 1),
+    s(19, 0, 7, 3),
+    s(13, 3, 0, 1), s(15, 2, 2, 1),
+    s(17, 0, 0, 2), s(15, 0, 0, 4),
+    s(19, 0, 0, 1), s(20, 0, 0, 1),
+    s(12, 3, 9, 4), s(12, 3, 9, 1),
+    s(10, 2, 6, 2), s(0, 2, 1, 1),
+    s(14, 3, 10, 2), s(19, 2, 12, 1),
+    s(15, 0, 1, 4), s(15, 0, 3, 2),
+    s(0, 1, 11, 2), s(3, 1, 0, 3),
+    s(12, 1, 0, 4), s(13, 1, 0, 1),
+    s(11, 2, 0, 3),
+    s(9, 2, 9, 3), s(9, 2, 9, 1),
+    s(8, 1, 0, 3), s(8, 2, 2, 2),
+    s(21, 0, 8, 1),
+    s(11, 0, 8, 2), s(11, 0, 8, 4),
+    s(0, 1, 9, 2), s(0, 1, 9, 1),
+    s(2, 2, 0, 2), s(2, 2, 0, 4), s(2, 2, 0, 3),
+    s(0, 1, 9, 3), s(5, 0, 9, 4),
+    s(14, 3, 10, 1),
+    s(7, 1, 12, 2), s(7, 1, 12, 3),
+    s(16, 0, 5, 4),
+    s(3, 0, 5, 4),
+    s(19, 2, 6, 1),
+    s(10, 0, 1, 1),
+    s(0, 3, 0, 4), s(21, 2, 0, 4),
+    s(19, 0, 0, 3), s(15, 0, 0, 4),
+    s(14, 1, 1, 2), s(12, 1, 1, 2),
+    s(7, 1, 0, 2), s(7, 1, 0, 3),
+    s(14, 3, 10, 2), s(14, 3, 9, 4),
+    s(21, 0, 11, 1), s(21, 0, 11, 4),
+    s(11, 0, 9, 1), s(11, 0, 9, 4),
+    s(19, 2, 6, 4), s(20, 2, 6, 4),
+    s(9, 2, 9, 3), s(9, 2, 9, 4),
+    s(0, 1, 7, 1), s(0, 1, 7, 4),
+    s(18, 2, 9, 2), s(7, 2, 9, 3),
+    s(1, 0, 5, 3),
+    s(16, 2, 0, 3), s(19, 2, 0, 2),
+    s(10, 2, 6, 4), s(11, 2, 6, 4),
+    s(19, 0, 12, 4),
+    s(9, 2, 2, 3),
+    s(1, 1, 7, 1), s(0, 0, 3, 4),
+    s(17, 0, 7, 4), s(12, 1, 7, 1),
+    s(8, 0, 5, 4), s(8, 0, 5, 2),
+    s(16, 2, 11, 2), s(15, 2, 11, 4),
+    s(7, 1, 9, 3),
+    s(14, 3, 9, 1), s(14, 1, 0, 1),
+    s(1, 1, 0, 1), s(1, 0, 6, 1),
+    s(5, 0, 5, 3),
+    s(11, 2, 10, 4), s(11, 2, 10, 2),
+    s(7, 2, 2, 4), s(18, 2, 2, 4),
+    s(12, 1, 7, 3), s(12, 1, 7, 4), s(13, 1, 7, 2),
+    s(9, 2, 9, 1),
+    s(14, 1, 0, 2), s(0, 0, 7, 2),
+    s(6, 0, 7, 3), s(14, 3, 9, 1),
+    s(2, 1, 0, 3), s(0, 0, 3, 2),
+    s(0, 1, 0, 1), s(0, 1, 0, 3), s(14, 1, 0, 4),
+    s(2, 1, 0, 3),
+    s(0, 1, 7, 2), s(0, 1, 8, 2),
+    s(18, 0, 10, 2), s(7, 1, 10, 2),
+    s(0, 1, 10, 4), s(14, 1, 0, 1),
+    s(4, 0, 9, 3), s(4, 0, 9, 4), s(1, 0, 9, 4),
+    s(0, 3, 9, 3),
+    s(3, 1, 9, 4),
+    s(14, 1, 0, 1), s(15, 0, 0, 1),
+    s(6, 0, 7, 2), s(2, 0, 7, 2),
+    s(7, 1, 0, 2), s(7, 1, 0, 3), s(7, 1, 0, 4),
+    s(7, 0, 1, 2),
+    s(2, 1, 12, 1), s(16, 0, 8, 1),
+    s(14, 1, 0, 1), s(14, 1, 0, 4), s(14, 1, 10, 1),
+    s(0, 2, 0, 4), s(13, 1, 1, 4), s(0, 0, 3, 4),
+    s(15, 0, 11, 1), s(15, 0, 12, 4),
+    s(6, 0, 7, 2),
+    s(12, 1, 0, 1), s(13, 1, 0, 3),
+    s(20, 0, 9, 4), s(14, 3, 9, 4),
+    s(15, 0, 1, 3), s(16, 0, 1, 1), s(21, 0, 1, 4),
+    s(0, 2, 6, 2), s(16, 2, 6, 1),
+    s(15, 0, 3, 2), s(7, 1, 4, 4), s(3, 0, 2, 4),
+    s(9, 2, 10, 3), s(9, 2, 10, 4),
+    s(16, 0, 7, 1), s(15, 0, 7, 1),
+    s(19, 0, 7, 1), s(19, 0, 7, 4),
+    s(8, 0, 7, 2), s(8, 0, 7, 4),
+    s(6, 2, 2, 1),
+    s(3, 0, 5, 4), s(11, 0, 5, 4),
+    s(14, 1, 10, 4), s(11, 0, 9, 4),
+    s(0, 0, 9, 2), s(0, 0, 3, 4),
+    s(6, 2, 9, 3),
+    s(12, 1, 11, 1), s(11, 2, 12, 2),
+    s(12, 3, 10, 4), s(18, 2, 6, 4),
+    s(8, 1, 7, 2), s(8, 0, 7, 2),
+    s(16, 2, 0, 4), s(17, 0, 0, 4),
+    s(3, 0, 6, 3), s(3, 0, 6, 2),
+    s(16, 0, 5, 3), s(20, 0, 3, 4),
+    s(9, 2, 0, 2), s(3, 0, 8, 2),
+    s(8, 1, 8, 2), s(8, 1, 7, 2),
+    s(12, 3, 10, 4), s(12, 3, 10, 1),
+    s(6, 2, 9, 1),
+    s(0, 2, 6, 2), s(5, 2, 2, 4),
+    s(16, 2, 0, 1),
+    s(21, 2, 9, 1),
+    s(8, 0, 7, 3), s(8, 1, 7, 2),
+    s(20, 0, 9, 2), s(11, 0, 5, 4),
+    s(15, 0, 0, 1), s(15, 0, 0, 4),
+    s(12, 1, 12, 4), s(13, 1, 12, 2),
+    s(17, 0, 12, 4),
+    s(18, 2, 0, 2), s(0, 3, 0, 2),
+    s(20, 0, 5, 1), s(16, 0, 5, 1),
+    s(13, 1, 0, 2), s(10, 2, 6, 2),
+    s(16, 0, 3, 3), s(16, 0, 3, 4), s(13, 1, 4, 4),
+    s(13, 3, 0, 2), s(4, 2, 0, 3), s(13, 3, 0, 3),
+    s(0, 0, 3, 4), s(14, 1, 1, 2),
+    s(12, 1, 11, 4),
+    s(20, 2, 10, 1), s(13, 1, 8, 3),
+    s(4, 2, 0, 4), s(4, 2, 0, 2),
+    s(15, 0, 7, 1),
+    s(12, 3, 4, 3), s(15, 2, 2, 4),
+    s(6, 0, 11, 1), s(6, 0, 11, 4),
+    s(16, 2, 10, 1),
+    s(16, 0, 0, 2), s(5, 1, 0, 4),
+    s(20, 0, 5, 1),
+    s(14, 1, 7, 1), s(16, 0, 7, 1),
+    s(20, 0, 9, 1), s(20, 0, 1, 4),
+    s(13, 1, 0, 4), s(19, 2, 2, 2), s(19, 0, 3, 4),
+    s(12, 1, 9, 4), s(19, 0, 9, 4),
+    s(12, 1, 0, 1), s(14, 1, 0, 1),
+    s(15, 2, 0, 2), s(17, 2, 0, 3),
+    s(1, 1, 9, 1), s(5, 1, 9, 2),
+    s(16, 0, 12, 1), s(15, 0, 12, 1), s(5, 1, 12, 1),
+    s(4, 2, 0, 4), s(1, 0, 2, 2),
+    s(6, 0, 11, 4),
+    s(11, 0, 11, 4),
+    s(1, 0, 2, 2), s(4, 0, 6, 4), s(1, 0, 6, 4),
+    s(15, 0, 0, 1), s(5, 1, 0, 4),
+    s(3, 1, 10, 2), s(3, 1, 10, 3),
+    s(8, 1, 4, 3), s(13, 3, 4, 4),
+    s(4, 0, 12, 3), s(4, 0, 9, 3),
+    s(16, 0, 12, 1), s(17, 0, 11, 4),
+    s(13, 1, 8, 4),
+    s(6, 1, 7, 2), s(19, 2, 2, 4),
+    s(4, 2, 0, 2), s(1, 0, 2, 2),
+    s(10, 2, 2, 4),
+    s(19, 2, 2, 4), s(20, 2, 2, 4),
+    s(16, 0, 0, 4), s(13, 1, 0, 4),
+    s(10, 2, 6, 2), s(9, 2, 6, 1),
+    s(1, 0, 6, 4), s(2, 0, 6, 4),
+    s(5, 2, 0, 4), s(15, 0, 1, 4),
+    s(15, 2, 2, 2), s(12, 3, 4, 3),
+    s(6, 0, 11, 1), s(16, 0, 11, 3), s(6, 0, 11, 4),
+    s(1, 1, 0, 4), s(1, 1, 0, 3), s(1, 0, 5, 1),
+    s(14, 1, 1, 1), s(13, 1, 1, 2), s(13, 1, 4, 2),
+    s(12, 1, 0, 4), s(10, 2, 6, 2),
+    s(10, 2, 6, 3), s(10, 2, 6, 2), s(0, 2, 6, 3),
+    s(13, 1, 8, 1), s(20, 2, 0, 4),
+    s(13, 1, 8, 4), s(14, 3, 12, 4),
+    s(0, 2, 1, 4), s(0, 2, 1, 3),
+    s(18, 2, 12, 2), s(18, 2, 12, 3),
+    s(16, 0, 0, 4), s(5, 0, 5, 4),
+    s(20, 2, 2, 2), s(16, 0, 1, 2),
+    s(5, 2, 2, 3), s(5, 2, 2, 4),
+    s(5, 0, 12, 4),
+    s(11, 0, 5, 2), s(10, 0, 5, 3),
+    s(0, 2, 5, 4), s(10, 2, 6, 4),
+    s(15, 0, 9, 3), s(15, 0, 9, 4),
+    s(12, 1, 8, 1), s(12, 1, 8, 4),
+    s(16, 2, 10, 1), s(14, 3, 10, 2),
+    s(12, 3, 4, 2), s(12, 1, 7, 4),
+    s(10, 0, 11, 3),
+    s(4, 0, 9, 3),
+    s(12, 3, 9, 4), s(14, 3, 9, 1),
+    s(15, 0, 12, 3),
+    s(9, 2, 12, 3), s(9, 2, 12, 4),
+    s(0, 3, 9, 1), s(0, 3, 10, 3),
+    s(11, 2, 10, 1), s(14, 3, 9, 1),
+    s(21, 0, 11, 3),
+    s(0, 2, 0, 1), s(0, 2, 0, 3),
+    s(10, 0, 12, 1), s(15, 0, 10, 3),
+    s(20, 2, 12, 1), s(19, 2, 12, 3),
+    s(10, 0, 12, 1), s(10, 0, 12, 3), s(9, 2, 0, 3),
+    s(15, 2, 9, 3),
+    s(16, 0, 9, 2), s(5, 0, 9, 1),
+    s(19, 2, 9, 1),
+    s(7, 1, 4, 4), s(0, 1, 4, 4), s(0, 1, 0, 3),
+    s(14, 1, 0, 3),
+    s(16, 0, 10, 2), s(11, 2, 6, 4),
+    s(20, 2, 0, 2),
+    s(5, 1, 0, 3), s(5, 1, 0, 4),
+    s(0, 3, 0, 4), s(8, 3, 0, 4),
+    s(9, 0, 12, 1), s(11, 0, 11, 2),
+    s(0, 1, 4, 4), s(14, 1, 4, 4), s(15, 2, 6, 4),
+    s(17, 2, 5, 4),
+    s(20, 2, 12, 1), s(19, 2, 12, 1),
+    s(0, 1, 9, 4), s(14, 1, 9, 4),
+    s(12, 3, 0, 3), s(13, 3, 0, 2),
+    s(9, 2, 6, 3), s(13, 1, 0, 1), s(0, 2, 6, 2),
+    s(5, 0, 11, 3),
+    s(0, 1, 9, 3), s(0, 1, 9, 1),
+    s(8, 1, 0, 2), s(8, 1, 0, 4),
+    s(2, 0, 6, 2), s(2, 0, 12, 3), s(1, 0, 6, 1),
+    s(13, 1, 0, 1), s(14, 1, 0, 1),
+    s(20, 0, 9, 2), s(20, 0, 9, 3), s(6, 1, 0, 4),
+    s(12, 1, 4, 2), s(6, 1, 0, 4), s(19, 0, 1, 2),
+    s(19, 0, 5, 3), s(9, 0, 3, 1),
+    s(20, 2, 0, 2), s(6, 1, 9, 3),
+    s(11, 2, 2, 2), s(6, 1, 9, 2),
+    s(18, 0, 9, 3), s(7, 0, 9, 3), s(7, 0, 9, 4),
+    s(15, 0, 0, 1), s(6, 1, 0, 3),
+    s(0, 1, 10, 1), s(0, 0, 9, 1), s(0, 1, 10, 4),
+    s(0, 3, 0, 4), s(9, 2, 2, 2),
+    s(12, 3, 0, 2), s(12, 3, 4, 2),
+    s(0, 2, 11, 4),
+    s(12, 1, 0, 4), s(12, 1, 0, 3),
+    s(14, 3, 4, 4), s(11, 2, 0, 4),
+    s(16, 0, 9, 3), s(13, 1, 9, 3),
+    s(5, 2, 10, 4),
+    s(4, 0, 6, 4), s(2, 1, 0, 1), s(4, 0, 6, 1),
+    s(0, 1, 4, 4), s(0, 0, 9, 1),
+    s(19, 2, 9, 4),
+    s(12, 1, 10, 1), s(0, 1, 10, 3),
+    s(2, 1, 4, 3),
+    s(12, 1, 4, 1), s(12, 1, 4, 2), s(13, 1, 0, 4),
+    s(8, 2, 12, 2), s(13, 1, 0, 1),
+    s(19, 0, 7, 2),
+    s(8, 3, 0, 4), s(8, 2, 0, 2),
+    s(6, 1, 4, 3),
+    s(1, 0, 6, 1), s(1, 0, 1, 4),
+    s(0, 1, 4, 2),
+    s(12, 1, 4, 1), s(19, 2, 2, 3),
+    s(9, 2, 9, 3), s(10, 0, 11, 4),
+    s(5, 0, 11, 4), s(13, 1, 7, 1),
+    s(15, 0, 9, 1), s(16, 0, 9, 1),
+    s(6, 0, 9, 3), s(5, 0, 9, 3),
+    s(14, 1, 4, 2), s(14, 1, 1, 2),
+    s(21, 0, 0, 4), s(17, 0, 0, 4),
+    s(11, 2, 2, 1),
+    s(0, 2, 10, 3), s(16, 2, 5, 4),
+    s(16, 2, 5, 4),
+    s(0, 2, 6, 3), s(10, 2, 1, 1),
+    s(0, 1, 9, 2), s(13, 1, 9, 4), s(16, 0, 9, 4),
+    s(12, 3, 4, 2), s(2, 1, 0, 4),
+    s(9, 0, 3, 4),
+    s(0, 1, 0, 1), s(0, 1, 0, 3),
+    s(14, 1, 9, 3), s(14, 1, 9, 4),
+    s(21, 2, 6, 1),
+    s(7, 1, 9, 4),
+    s(15, 2, 9, 4),
+    s(1, 0, 11, 4), s(2, 0, 12, 2),
+    s(16, 0, 0, 1), s(15, 0, 0, 4),
+    s(3, 0, 1, 4), s(4, 2, 0, 4),
+    s(17, 2, 9, 4),
+    s(13, 3, 0, 1), s(0, 0, 8, 1),
+    s(14, 1, 9, 4), s(14, 1, 9, 3),
+    s(16, 0, 9, 2), s(15, 0, 9, 4),
+    s(20, 0, 0, 2), s(15, 2, 6, 1),
+    s(14, 3, 12, 2),
+    s(13, 3, 4, 4), s(14, 1, 0, 1),
+    s(1, 1, 0, 2),
+    s(21, 0, 3, 4), s(14, 1, 0, 1),
+    s(13, 1, 12, 1),
+    s(0, 3, 0, 3), s(0, 3, 0, 4),
+    s(0, 2, 1, 1),
+    s(11, 2, 2, 4), s(17, 2, 11, 1),
+    s(14, 3, 4, 3),
+    s(7, 0, 8, 2), s(0, 2, 9, 4),
+    s(14, 1, 0, 4), s(14, 1, 0, 1),
+    s(3, 1, 9, 3), s(6, 1, 9, 3),
+    s(15, 0, 9, 3), s(7, 0, 9, 3),
+    s(15, 0, 9, 1), s(5, 1, 9, 1),
+    s(0, 2, 9, 3), s(0, 2, 2, 4),
+    s(3, 0, 2, 3),
+    s(11, 2, 0, 1), s(12, 1, 0, 2), s(9, 0, 3, 2),
+    s(5, 2, 2, 4), s(17, 0, 9, 1), s(2, 0, 9, 2),
+    s(0, 0, 11, 2), s(0, 1, 12, 4),
+    s(14, 3, 9, 4), s(14, 1, 9, 3),
+    s(5, 1, 0, 4), s(5, 1, 0, 1),
+    s(1, 1, 0, 4), s(2, 0, 6, 4),
+    s(6, 1, 4, 2), s(5, 1, 4, 1),
+    s(0, 1, 0, 2), s(6, 1, 0, 4),
+    s(12, 1, 1, 2), s(9, 0, 3, 2),
+    s(15, 0, 0, 4), s(5, 0, 1, 2),
+    s(5, 0, 9, 4), s(16, 0, 9, 1),
+    s(16, 0, 11, 4), s(15, 0, 11, 1),
+    s(1, 0, 12, 3), s(4, 0, 12, 3),
+    s(12, 1, 0, 2), s(13, 1, 0, 4),
+    s(17, 2, 0, 4), s(0, 3, 0, 2), s(17, 2, 0, 1),
+    s(0, 0, 3, 2), s(10, 2, 2, 4),
+    s(15, 0, 9, 4), s(17, 0, 9, 1),
+    s(7, 0, 1, 4), s(5, 0, 1, 1),
+    s(5, 1, 4, 1),
+    s(1, 2, 0, 3), s(4, 2, 0, 4),
+    s(0, 2, 6, 3), s(5, 1, 0, 1),
+    s(2, 0, 2, 4), s(4, 2, 0, 2), s(4, 2, 0, 4),
+    s(5, 0, 1, 1), s(6, 0, 1, 4),
+    s(4, 0, 9, 1), s(4, 0, 9, 2),
+    s(16, 0, 9, 1), s(16, 0, 9, 4), s(0, 1, 9, 2),
+    s(12, 1, 12, 1),
+    s(16, 0, 8, 3),
+    s(9, 0, 5, 3), s(11, 0, 5, 2),
+    s(15, 0, 10, 3), s(5, 0, 9, 3), s(5, 0, 9, 4),
+    s(11, 0, 8, 4), s(9, 0, 8, 4),
+    s(3, 1, 10, 2), s(3, 0, 10, 2),
+    s(1, 0, 5, 1), s(2, 1, 0, 1), s(2, 0, 6, 2),
+    s(1, 1, 9, 4), s(4, 0, 9, 4),
+    s(9, 0, 10, 3),
+    s(16, 2, 10, 2), s(15, 0, 10, 4),
+    s(16, 2, 1, 4),
+    s(16, 0, 10, 3), s(19, 0, 10, 4), s(20, 0, 10, 2),
+    s(0, 2, 6, 1), s(6, 2, 6, 2),
+    s(0, 1, 0, 1), s(13, 1, 0, 1),
+    s(13, 1, 0, 4), s(13, 1, 4, 4), s(0, 1, 1, 4), s(10, 2, 6, 2),
+    s(16, 0, 10, 3), s(17, 0, 10, 4),
+    s(11, 0, 3, 2), s(12, 1, 4, 2), s(10, 0, 3, 3),
+    s(0, 0, 7, 1),
+    s(13, 1, 7, 1), s(4, 0, 10, 2),
+    s(0, 1, 9, 2), s(13, 1, 9, 4), s(13, 1, 9, 1), s(12, 1, 9, 4),
+    s(10, 2, 6, 3),
+    s(9, 0, 3, 2), s(10, 0, 5, 4),
+    s(15, 0, 10, 3), s(13, 1, 10, 3),
+    s(11, 0, 9, 4), s(10, 0, 9, 3),
+    s(16, 0, 7, 1),
+    s(1, 1, 0, 1), s(1, 1, 0, 4),
+    s(8, 1, 0, 4), s(8, 1, 4, 4), s(14, 1, 4, 2),
+    s(2, 0, 8, 1),
+    s(9, 2, 2, 2), s(14, 3, 0, 4),
+    s(1, 2, 0, 4), s(4, 0, 8, 3),
+    s(0, 2, 6, 4), s(0, 3, 0, 4),
+    s(21, 0, 8, 1), s(14, 1, 7, 1),
+    s(14, 1, 11, 1), s(17, 0, 11, 3),
+    s(14, 1, 0, 4), s(13, 1, 0, 4), s(9, 0, 3, 1),
+    s(19, 0, 9, 4), s(15, 0, 9, 1),
+    s(5, 0, 8, 4), s(17, 0, 3, 4),
+    s(7, 1, 9, 2), s(6, 1, 9, 3),
+    s(9, 0, 5, 1), s(0, 0, 5, 4),
+    s(0, 0, 10, 4),
+    s(17, 0, 3, 3),
+    s(17, 2, 6, 4), s(5, 2, 6, 4),
+    s(0, 2, 6, 3), s(0, 2, 6, 4),
+    s(18, 0, 10, 3), s(7, 1, 4, 4),
+    s(0, 1, 4, 1), s(0, 1, 0, 4),
+    s(21, 2, 6, 3),
+    s(8, 1, 9, 2), s(13, 1, 9, 4), s(14, 1, 9, 4),
+    s(19, 0, 9, 3), s(12, 1, 9, 3),
+    s(12, 1, 10, 3), s(12, 1, 9, 4),
+    s(0, 0, 11, 3),
+    s(4, 0, 10, 1), s(4, 2, 0, 4),
+    s(8, 0, 6, 4), s(8, 2, 2, 4),
+    s(2, 2, 0, 1),
+    s(15, 2, 2, 1), s(16, 0, 7, 4),
+    s(0, 0, 9, 4), s(0, 1, 9, 4),
+    s(8, 0, 12, 4), s(8, 1, 12, 2),
+    s(15, 0, 1, 2), s(0, 1, 4, 4),
+    s(21, 0, 8, 1), s(21, 0, 8, 3),
+    s(11, 0, 9, 4), s(11, 0, 9, 2),
+    s(9, 2, 0, 2),
+    s(8, 0, 8, 2), s(8, 3, 0, 2),
+    s(5, 0, 12, 4), s(6, 0, 12, 1),
+    s(0, 2, 6, 4), s(9, 2, 6, 4),
+    s(8, 2, 12, 2), s(8, 2, 12, 4),
+    s(17, 0, 10, 1), s(12, 1, 0, 2),
+    s(13, 1, 1, 4), s(10, 0, 3, 1),
+    s(1, 0, 2, 2), s(4, 0, 6, 4),
+    s(10, 0, 7, 1),
+    s(9, 2, 1, 1), s(11, 2, 1, 2),
+    s(6, 1, 0, 4), s(14, 1, 0, 1),
+    s(5, 2, 6, 4), s(14, 1, 1, 2),
+    s(3, 0, 2, 2), s(3, 0, 2, 3),
+    s(10, 2, 6, 4), s(9, 2, 6, 4),
+    s(0, 0, 9, 4), s(13, 1, 0, 4),
+    s(13, 1, 12, 3), s(13, 1, 12, 4),
+    s(13, 1, 7, 1), s(11, 0, 3, 4),
+    s(10, 0, 7, 3), s(10, 0, 7, 4),
+    s(2, 1, 0, 1), s(2, 0, 6, 1), s(4, 2, 0, 4),
+    s(2, 0, 1, 1), s(1, 0, 1, 4),
+    s(3, 0, 9, 2), s(3, 1, 9, 2),
+    s(2, 0, 8, 2), s(2, 0, 8, 3), s(1, 0, 7, 3),
+    s(6, 1, 7, 2), s(5, 1, 7, 1),
+    s(19, 2, 0, 2), s(21, 2, 6, 4), s(19, 2, 6, 4),
+    s(20, 0, 5, 4),
+    s(2, 0, 12, 2), s(2, 0, 12, 4), s(4, 0, 11, 3),
+    s(21, 0, 5, 1), s(17, 0, 0, 4),
+    s(2, 0, 9, 2), s(1, 0, 9, 1),
+    s(19, 2, 12, 3), s(20, 2, 12, 1),
+    s(8, 1, 8, 2), s(12, 1, 7, 3),
+    s(8, 1, 0, 4), s(9, 0, 3, 2),
+    s(17, 0, 10, 2),
+    s(11, 2, 2, 4), s(0, 3, 0, 4),
+    s(16, 0, 3, 3), s(5, 2, 0, 1),
+    s(16, 0, 7, 1), s(16, 0, 7, 2), s(15, 0, 7, 4),
+    s(8, 1, 12, 2), s(8, 2, 12, 2),
+    s(13, 1, 10, 2), s(0, 1, 10, 2), s(17, 0, 10, 4),
+    s(1, 0, 1, 1), s(1, 0, 1, 4),
+    s(17, 0, 12, 2),
+    s(9, 0, 12, 4),
+    s(6, 1, 12, 2), s(6, 1, 12, 3),
+    s(6, 1, 7, 2), s(16, 0, 8, 2),
+    s(3, 0, 8, 2), s(3, 0, 6, 2),
+    s(11, 2, 2, 4), s(14, 3, 0, 4), s(0, 1, 0, 4),
+    s(20, 2, 2, 4),
+    s(2, 0, 5, 2), s(1, 0, 6, 1),
+    s(12, 3, 0, 4), s(12, 3, 0, 1),
+    s(15, 0, 0, 4), s(12, 1, 0, 4),
+    s(3, 2, 0, 2),
+    s(6, 1, 0, 2), s(5, 1, 0, 4),
+    s(21, 0, 8, 1), s(13, 1, 8, 1),
+    s(19, 0, 7, 3), s(21, 2, 2, 3), s(16, 0, 7, 2),
+    s(5, 2, 2, 4), s(0, 2, 6, 3), s(6, 2, 2, 4),
+    s(0, 1, 9, 3), s(0, 1, 9, 2),
+    s(9, 2, 12, 1), s(21, 2, 12, 1),
+    s(14, 3, 9, 2), s(0, 3, 9, 1),
+    s(17, 0, 0, 2), s(5, 1, 7, 3),
+    s(5, 1, 4, 2), s(0, 1, 0, 4),
+    s(5, 1, 7, 1), s(16, 0, 7, 1), s(6, 1, 7, 2), s(14, 1, 7, 1),
+    s(15, 0, 0, 3), s(15, 0, 0, 4),
+    s(5, 2, 12, 1), s(5, 0, 9, 4),
+    s(6, 2, 12, 2), s(6, 2, 12, 1), s(14, 1, 7, 1),
+    s(9, 2, 6, 4), s(12, 3, 4, 2),
+    s(12, 3, 0, 2), s(12, 1, 8, 4),
+    s(10, 0, 9, 4), s(11, 0, 3, 2),
+    s(2, 0, 8, 3), s(1, 2, 0, 4),
+    s(17, 2, 6, 4), s(15, 2, 0, 4),
+    s(0, 1, 9, 4), s(15, 2, 6, 1),
+    s(16, 2, 12, 2), s(16, 2, 12, 3),
+    s(14, 1, 0, 1), s(13, 1, 0, 1), s(12, 1, 0, 1),
+    s(0, 3, 0, 4), s(0, 3, 0, 2),
+    s(2, 2, 0, 2), s(2, 2, 0, 1),
+    s(11, 2, 9, 2), s(14, 3, 9, 2),
+    s(17, 2, 0, 2), s(0, 1, 0, 4),
+    s(6, 1, 4, 4), s(7, 1, 9, 2),
+    s(11, 2, 10, 2), s(11, 2, 9, 4),
+    s(9, 2, 2, 3), s(8, 2, 2, 3), s(11, 2, 10, 2),
+    s(21, 2, 2, 3), s(21, 2, 2, 4),
+    s(13, 1, 11, 4),
+    s(10, 2, 11, 4), s(11, 2, 11, 2),
+    s(13, 1, 9, 4), s(13, 1, 10, 1),
+    s(1, 0, 2, 2), s(1, 1, 0, 2),
+    s(17, 0, 5, 4),
+    s(15, 0, 1, 1), s(15, 0, 1, 3),
+    s(6, 0, 7, 3),
+    s(7, 2, 12, 3),
+    s(0, 1, 0, 4), s(0, 1, 1, 4),
+    s(5, 1, 0, 2), s(15, 2, 2, 2),
+    s(12, 1, 9, 3), s(14, 1, 9, 4),
+    s(14, 1, 4, 4), s(0, 2, 0, 1),
+    s(19, 0, 5, 4),
+    s(9, 2, 10, 4), s(11, 2, 10, 4),
+    s(14, 3, 10, 1), s(0, 3, 0, 4),
+    s(17, 0, 11, 1),
+    s(0, 1, 12, 4), s(19, 0, 12, 4), s(0, 3, 10, 4),
+    s(16, 2, 1, 1), s(15, 2, 2, 2),
+    s(13, 3, 0, 2), s(9, 0, 8, 1),
+    s(6, 1, 12, 3), s(5, 1, 12, 3), s(6, 1, 9, 3),
+    s(11, 2, 6, 4), s(9, 2, 0, 3),
+    s(20, 0, 1, 4),
+    s(4, 2, 0, 2), s(4, 2, 0, 3),
+    s(6, 1, 4, 4),
+    s(15, 2, 2, 2), s(12, 3, 4, 2),
+    s(6, 1, 0, 4), s(6, 1, 0, 3),
+    s(0, 2, 5, 4), s(11, 2, 6, 4),
+    s(1, 0, 1, 4), s(1, 0, 1, 1),
+    s(10, 0, 3, 4), s(13, 1, 1, 1), s(13, 1, 1, 3),
+    s(13, 3, 0, 3),
+    s(17, 0, 0, 2), s(19, 0, 3, 2),
+    s(12, 1, 9, 1), s(14, 1, 9, 2),
+    s(0, 0, 5, 2), s(9, 0, 5, 1),
+    s(15, 0, 1, 1), s(12, 3, 0, 3), s(16, 2, 0, 3),
+    s(0, 1, 9, 4), s(0, 1, 9, 2),
+    s(0, 1, 0, 1), s(0, 1, 0, 2), s(0, 1, 0, 4),
+    s(5, 1, 12, 1), s(15, 0, 12, 1),
+    s(10, 0, 7, 3), s(13, 1, 7, 3), s(0, 3, 0, 2),
+    s(13, 1, 0, 1), s(13, 1, 0, 2),
+    s(17, 0, 11, 4),
+    s(0, 2, 9, 4), s(3, 0, 2, 4),
+    s(17, 0, 11, 4), s(17, 0, 11, 3),
+    s(12, 1, 0, 1), s(13, 1, 0, 2),
+    s(1, 2, 0, 4), s(4, 0, 8, 3), s(4, 0, 8, 1), s(4, 2, 0, 1), s(1, 2, 0, 2),
+    s(0, 3, 0, 3), s(0, 3, 0, 4), s(0, 3, 0, 2),
+    s(13, 1, 4, 3), s(12, 3, 0, 1), s(20, 2, 0, 2),
+    s(1, 1, 12, 3), s(1, 1, 12, 4),
+    s(16, 0, 12, 2), s(17, 0, 12, 4), s(15, 0, 12, 1), s(15, 0, 12, 3),
+    s(5, 1, 8, 1),
+    s(1, 1, 12, 4), s(1, 0, 9, 4), s(1, 0, 11, 4),
+    s(21, 0, 11, 4), s(21, 0, 11, 1),
+    s(9, 0, 3, 4), s(9, 0, 3, 3), s(9, 0, 9, 4),
+    s(15, 2, 12, 1), s(15, 2, 12, 4),
+    s(9, 2, 9, 4), s(10, 2, 11, 4),
+    s(16, 2, 9, 4), s(9, 2, 9, 4), s(13, 3, 9, 4),
+    s(16, 0, 9, 3), s(16, 2, 9, 4),
+    s(0, 2, 6, 4), s(0, 2, 6, 2),
+    s(15, 2, 0, 3), s(15, 2, 0, 4),
+    s(12, 1, 12, 3), s(5, 0, 9, 3),
+    s(8, 1, 0, 4), s(8, 1, 0, 2),
+    s(2, 1, 4, 3), s(0, 1, 0, 4),
+    s(0, 1, 0, 2), s(12, 1, 0, 2),
+    s(0, 1, 0, 4), s(0, 0, 5, 4),
+    s(7, 0, 5, 3), s(0, 0, 5, 3),
+    s(6, 2, 2, 1), s(15, 0, 3, 2),
+    s(3, 0, 3, 5), s(0, 1, 7, 1), s(3, 0, 2, 2), s(3, 0, 1, 5),
+    s(15, 0, 0, 1), s(15, 2, 0, 1), s(15, 0, 0, 4),
+    s(0, 2, 0, 1), s(0, 2, 0, 4),
+    s(15, 0, 1, 4), s(19, 2, 2, 4),
+    s(8, 0, 3, 4), s(0, 3, 4, 4),
+    s(0, 1, 10, 2), s(2, 0, 9, 1), s(15, 2, 12, 4),
+    s(2, 1, 12, 1),
+    s(0, 1, 0, 3), s(0, 1, 0, 4), s(12, 3, 4, 2),
+    s(0, 1, 1, 5),
+    s(3, 1, 4, 1), s(7, 1, 4, 4),
+    s(12, 1, 8, 3), s(12, 1, 8, 1),
+    s(13, 1, 0, 3), s(13, 1, 0, 4),
+    s(0, 1, 4, 3), s(0, 1, 0, 2),
+    s(7, 0, 11, 2),
+    s(3, 0, 5, 3),
+    s(8, 2, 9, 4),
+    s(18, 2, 0, 3),
+    s(7, 0, 1, 3),
+    s(20, 2, 6, 5),
+    s(15, 0, 3, 3),
+    s(13, 1, 9, 2), s(9, 0, 9, 1),
+    s(15, 0, 0, 4), s(8, 2, 9, 4),
+    s(8, 1, 10, 3), s(8, 1, 10, 4),
+    s(8, 0, 3, 5), s(8, 1, 7, 3), s(8, 1, 7, 4),
+    s(3, 0, 1, 5),
+    s(0, 3, 0, 3), s(0, 3, 0, 2), s(15, 2, 0, 4),
+    s(17, 0, 0, 4), s(19, 0, 0, 4),
+    s(0, 3, 0, 2), s(0, 2, 6, 2), s(0, 3, 0, 1), s(14, 3, 0, 1),
+    s(10, 2, 6, 1), s(0, 3, 0, 2),
+    s(12, 1, 12, 3), s(12, 1, 12, 4),
+    s(9, 0, 10, 4), s(14, 3, 9, 1), s(9, 0, 12, 4),
+    s(9, 0, 10, 4), s(9, 0, 12, 4),
+    s(14, 1, 4, 1), s(21, 2, 2, 4), s(21, 2, 2, 1),
+    s(13, 1, 0, 2), s(15, 0, 5, 1),
+    s(0, 1, 1, 4), s(0, 1, 1, 1), s(0, 0, 3, 4),
+    s(0, 2, 11, 2), s(0, 2, 0, 2),
+    s(10, 0, 11, 4), s(9, 0, 11, 1), s(9, 0, 12, 1),
+    s(5, 0, 1, 4),
+    s(11, 0, 5, 4), s(12, 1, 4, 1),
+    s(11, 0, 12, 1), s(14, 1, 11, 3), s(2, 0, 12, 1),
+    s(0, 1, 4, 5),
+    s(8, 1, 11, 4), s(8, 1, 11, 2),
+    s(13, 1, 10, 1), s(13, 1, 12, 4),
+    s(5, 0, 9, 3), s(5, 0, 9, 4), s(16, 0, 9, 2), s(15, 0, 9, 1),
+    s(0, 2, 6, 3), s(3, 0, 10, 2),
+    s(0, 2, 11, 2),
+    s(17, 0, 10, 2), s(17, 0, 0, 2),
+    s(8, 0, 3, 4), s(8, 1, 0, 4),
+    s(5, 1, 12, 1), s(5, 1, 12, 3),
+    s(12, 1, 10, 3), s(4, 2, 0, 4), s(7, 2, 0, 2), s(12, 1, 10, 4),
+    s(2, 2, 0, 1), s(2, 2, 0, 2),
+    s(16, 0, 8, 2), s(13, 1, 8, 2), s(12, 3, 0, 1),
+    s(15, 0, 11, 3),
+    s(12, 1, 4, 4), s(9, 0, 3, 4),
+    s(20, 2, 12, 2), s(19, 2, 12, 4),
+    s(4, 0, 2, 2),
+    s(20, 0, 11, 1),
+    s(19, 0, 5, 3), s(19, 0, 0, 3), s(19, 0, 0, 1),
+    s(6, 0, 1, 1), s(6, 2, 2, 2),
+    s(6, 2, 2, 1), s(5, 2, 2, 2), s(16, 0, 1, 4), s(15, 0, 3, 2),
+    s(9, 0, 9, 3), s(11, 0, 9, 4),
+    s(9, 0, 3, 1), s(0, 1, 0, 4), s(0, 2, 0, 4),
+    s(8, 1, 12, 4), s(8, 1, 12, 2), s(8, 1, 12, 3), s(8, 1, 9, 2),
+    s(0, 1, 0, 3), s(21, 0, 0, 4),
+    s(16, 0, 7, 4),
+    s(21, 0, 1, 1),
+    s(3, 0, 10, 5), s(3, 0, 10, 2),
+    s(16, 0, 7, 4), s(3, 1, 7, 3),
+    s(0, 1, 11, 3), s(0, 0, 11, 2),
+    s(13, 1, 9, 2), s(12, 1, 12, 1),
+    s(2, 1, 0, 3), s(2, 1, 0, 2), s(1, 1, 0, 4),
+    s(12, 1, 9, 4), s(3, 0, 8, 2),
+    s(12, 1, 1, 4), s(12, 1, 4, 5), s(12, 1, 4, 4),
+    s(0, 1, 7, 3), s(4, 0, 2, 2),
+    s(18, 0, 10, 4), s(18, 0, 10, 2), s(8, 1, 10, 4),
+    s(4, 0, 10, 4), s(1, 1, 10, 1),
+    s(4, 0, 11, 3), s(2, 0, 11, 2),
+    s(0, 3, 0, 2), s(0, 3, 0, 3), s(14, 3, 0, 4),
+    s(14, 1, 10, 3), s(8, 1, 10, 3),
+    s(10, 0, 11, 4), s(9, 0, 11, 1), s(10, 0, 11, 3),
+    s(12, 1, 0, 2), s(4, 0, 9, 2),
+    s(12, 1, 0, 4), s(15, 0, 0, 4), s(13, 1, 0, 2), s(13, 1, 0, 4),
+    s(14, 1, 8, 1), s(14, 3, 0, 4),
+    s(12, 1, 10, 4), s(0, 1, 10, 2),
+    s(15, 2, 12, 4), s(0, 1, 10, 2),
+    s(0, 1, 8, 1), s(0, 1, 8, 2),
+    s(11, 2, 2, 3), s(11, 2, 2, 5),
+    s(11, 2, 6, 4), s(10, 2, 5, 4),
+    s(16, 2, 9, 2), s(15, 2, 9, 4),
+    s(16, 0, 3, 1),
+    s(13, 1, 9, 4), s(14, 1, 9, 4),
+    s(20, 0, 11, 1), s(16, 0, 10, 5),
+    s(19, 0, 3, 5),
+    s(7, 2, 0, 3),
+    s(1, 0, 2, 2), s(1, 0, 5, 3), s(3, 0, 2, 4), s(1, 0, 1, 4),
+    s(9, 2, 0, 1), s(9, 2, 0, 4),
+    s(7, 1, 0, 4), s(7, 1, 0, 2), s(7, 1, 0, 3),
+    s(1, 0, 9, 4), s(2, 0, 9, 4),
+    s(13, 3, 0, 1), s(19, 2, 0, 4),
+    s(20, 0, 0, 4), s(21, 0, 0, 4),
+    s(17, 0, 0, 4), s(21, 0, 0, 4),
+    s(9, 0, 1, 1), s(12, 1, 1, 1), s(13, 1, 4, 2),
+    s(0, 1, 0, 3), s(0, 0, 5, 3), s(21, 0, 0, 4), s(16, 0, 0, 4),
+    s(5, 1, 9, 4), s(6, 1, 9, 2),
+    s(11, 0, 9, 1), s(9, 0, 9, 4),
+    s(5, 0, 9, 4), s(6, 0, 9, 3), s(0, 1, 9, 4),
+    s(13, 3, 0, 1), s(13, 1, 1, 1),
+    s(15, 0, 7, 1), s(17, 0, 7, 2), s(17, 0, 7, 4),
+    s(20, 0, 0, 3),
+    s(0, 2, 6, 4), s(8, 1, 0, 4),
+    s(0, 1, 11, 3), s(0, 1, 11, 1),
+    s(6, 1, 0, 3), s(6, 1, 0, 1), s(1, 0, 10, 4), s(20, 2, 6, 4),
+    s(15, 0, 9, 4), s(16, 0, 9, 1), s(5, 1, 9, 1),
+    s(11, 0, 3, 2), s(11, 0, 3, 4),
+    s(6, 2, 2, 2), s(6, 2, 2, 1), s(6, 2, 2, 4), s(0, 1, 0, 2),
+    s(0, 3, 0, 2), s(6, 2, 0, 2), s(14, 3, 0, 2), s(0, 3, 0, 4),
+    s(0, 1, 0, 4), s(5, 1, 4, 2),
+    s(4, 2, 0, 2), s(4, 0, 2, 2), s(1, 0, 2, 2), s(1, 1, 0, 4),
+    s(19, 2, 2, 4), s(19, 2, 2, 1), s(19, 2, 2, 2),
+    s(9, 0, 8, 1), s(10, 0, 8, 4), s(12, 3, 0, 1),
+    s(0, 3, 12, 1), s(0, 3, 12, 4),
+    s(17, 0, 0, 5),
+    s(10, 0, 1, 3),
+    s(1, 0, 7, 1),
+    s(11, 2, 6, 2), s(11, 2, 5, 2),
+    s(11, 0, 3, 4), s(9, 0, 3, 2),
+    s(9, 0, 3, 2), s(0, 0, 3, 2),
+    s(1, 0, 5, 3), s(3, 0, 2, 4),
+    s(0, 0, 13, 4), s(7, 0, 5, 4),
+    s(11, 0, 10, 3), s(11, 0, 12, 2),
+    s(9, 2, 6, 3), s(9, 2, 6, 1),
+    s(6, 1, 7, 1), s(6, 1, 7, 2), s(6, 1, 7, 4), s(5, 1, 7, 3), s(0, 1, 7, 2), s(5, 0, 7, 4), s(15, 0, 7, 4),
+    s(12, 1, 7, 3), s(12, 1, 7, 1), s(14, 1, 7, 2),
+    s(10, 0, 9, 3),
+    s(9, 0, 5, 1), s(11, 0, 5, 4),
+    s(8, 0, 5, 2), s(8, 0, 5, 4),
+    s(10, 2, 1, 3), s(11, 2, 1, 2), s(0, 0, 3, 4), s(0, 2, 0, 2),
+    s(9, 2, 11, 1),
+    s(15, 2, 0, 1), s(15, 0, 8, 1),
+    s(0, 0, 9, 1), s(0, 0, 9, 3),
+    s(3, 0, 8, 2), s(3, 0, 7, 2),
+    s(8, 2, 10, 2), s(8, 2, 10, 4),
+    s(5, 2, 12, 4), s(6, 2, 12, 1), s(6, 2, 12, 2), s(6, 2, 12, 3),
+    s(14, 3, 10, 4), s(14, 3, 10, 2),
+    s(9, 2, 12, 1), s(9, 2, 12, 4),
+    s(20, 2, 10, 2), s(12, 1, 9, 4),
+    s(6, 0, 1, 5),
+    s(12, 1, 7, 3), s(0, 1, 7, 2),
+    s(20, 0, 3, 4), s(19, 0, 3, 4), s(15, 0, 5, 1),
+    s(16, 0, 12, 3), s(6, 1, 12, 3),
+    s(15, 0, 10, 4), s(15, 0, 10, 1), s(16, 0, 10, 1),
+    s(13, 1, 10, 1), s(13, 1, 10, 3),
+    s(17, 2, 0, 4), s(5, 0, 8, 1),
+    s(6, 2, 6, 4), s(6, 2, 2, 1),
+    s(1, 1, 9, 4), s(2, 1, 9, 2), s(1, 1, 9, 1),
+    s(20, 2, 0, 4), s(16, 2, 2, 4),
+    s(14, 3, 0, 2), s(17, 2, 0, 1),
+    s(0, 2, 0, 3), s(0, 2, 0, 2),
+    s(12, 3, 10, 4), s(17, 2, 10, 4), s(5, 2, 10, 1),
+    s(8, 1, 11, 2), s(8, 0, 11, 3),
+    s(13, 1, 7, 4), s(14, 1, 7, 4), s(14, 1, 7, 1),
+    s(12, 1, 12, 4), s(0, 1, 12, 2),
+    s(2, 0, 6, 3),
+    s(21, 2, 0, 2),
+    s(4, 2, 0, 3), s(3, 1, 9, 3),
+    s(0, 3, 0, 2), s(17, 2, 0, 4),
+    s(13, 1, 0, 2), s(21, 0, 0, 4),
+    s(14, 1, 10, 4), s(17, 0, 10, 1),
+    s(16, 0, 3, 1), s(12, 3, 0, 1),
+    s(8, 1, 1, 3), s(8, 1, 11, 3),
+    s(2, 0, 5, 2),
+    s(16, 2, 0, 4), s(17, 2, 0, 1), s(6, 1, 0, 4),
+    s(4, 0, 12, 4), s(1, 0, 12, 3),
+    s(0, 0, 9, 3), s(0, 1, 9, 4),
+    s(1, 1, 0, 3), s(1, 1, 0, 4), s(1, 0, 6, 1), s(2, 1, 0, 4),
+    s(11, 2, 0, 3), s(16, 0, 0, 2),
+    s(16, 0, 11, 1), s(16, 0, 12, 2), s(15, 0, 12, 4),
+    s(20, 2, 6, 4), s(19, 2, 0, 2),
+    s(8, 0, 5, 2), s(8, 0, 5, 4), s(8, 1, 4, 1),
+    s(20, 0, 11, 1), s(16, 2, 11, 4),
+    s(9, 0, 3, 4), s(9, 0, 3, 3),
+    s(1, 0, 6, 4), s(2, 0, 6, 2),
+    s(3, 0, 10, 5), s(3, 0, 10, 4), s(3, 0, 10, 2),
+    s(5, 0, 7, 4), s(5, 0, 7, 3),
+    s(6, 0, 9, 2), s(5, 0, 9, 4), s(6, 0, 9, 4),
+    s(12, 3, 4, 2), s(12, 3, 4, 4),
+    s(16, 2, 6, 2), s(15, 2, 6, 4),
+    s(2, 0, 12, 2), s(2, 0, 12, 3), s(2, 1, 12, 2),
+    s(6, 0, 11, 3), s(16, 0, 11, 2),
+    s(0, 1, 0, 3), s(12, 1, 0, 1), s(0, 1, 0, 1),
+    s(13, 1, 0, 1), s(13, 1, 0, 2), s(13, 1, 0, 4),
+    s(6, 1, 0, 4), s(5, 1, 7, 4), s(15, 0, 8, 1),
+    s(12, 1, 12, 4), s(8, 1, 11, 4),
+    s(16, 0, 11, 4), s(16, 0, 11, 1),
+    s(12, 1, 4, 2), s(13, 1, 4, 4),
+    s(10, 2, 12, 1), s(10, 2, 12, 3),
+    s(13, 1, 9, 4), s(13, 1, 12, 4),
+    s(7, 1, 0, 2), s(7, 1, 0, 4), s(7, 1, 4, 4),
+    s(0, 2, 2, 1), s(0, 2, 6, 1), s(0, 2, 2, 3),
+    s(5, 2, 12, 1), s(5, 2, 12, 4),
+    s(1, 0, 10, 4), s(1, 0, 10, 1),
+    s(7, 0, 5, 3),
+    s(0, 1, 4, 1),
+    s(18, 2, 9, 3), s(18, 2, 0, 2),
+    s(15, 2, 12, 4), s(16, 2, 12, 1), s(6, 2, 12, 2),
+    s(12, 1, 1, 3), s(12, 1, 1, 4), s(12, 1, 4, 5), s(14, 1, 1, 4), s(14, 1, 1, 2), s(9, 0, 3, 2),
+    s(12, 1, 0, 4), s(12, 1, 4, 2), s(13, 1, 0, 4),
+    s(1, 1, 12, 4), s(1, 1, 12, 3),
+    s(18, 2, 2, 4), s(18, 0, 3, 4),
+    s(6, 0, 11, 3), s(5, 0, 11, 4),
+    s(14, 1, 4, 2), s(12, 1, 4, 1),
+    s(16, 0, 1, 1),
+    s(14, 1, 4, 4), s(0, 1, 4, 4), s(15, 0, 1, 2),
+    s(16, 0, 8, 3), s(13, 1, 7, 4), s(19, 0, 8, 1),
+    s(14, 3, 0, 3), s(14, 3, 0, 1),
+    s(20, 0, 0, 1),
+    s(1, 1, 0, 1), s(4, 2, 0, 4),
+    s(20, 0, 5, 1), s(21, 0, 0, 1), s(21, 0, 0, 3),
+    s(15, 0, 10, 1), s(15, 0, 12, 1),
+    s(6, 0, 8, 1),
+    s(19, 0, 1, 2), s(19, 0, 9, 2), s(19, 0, 9, 5),
+    s(0, 2, 6, 3), s(0, 2, 6, 2), s(0, 0, 3, 2), s(9, 2, 6, 4),
+    s(9, 2, 6, 1), s(10, 2, 6, 3), s(10, 2, 5, 4),
+    s(15, 0, 0, 4), s(21, 0, 0, 1),
+    s(4, 2, 0, 4), s(4, 2, 0, 1),
+    s(0, 3, 9, 4), s(0, 3, 9, 2),
+    s(7, 2, 0, 4),
+    s(12, 1, 11, 3), s(9, 0, 8, 4),
+    s(3, 0, 1, 4), s(3, 0, 1, 3),
+    s(1, 0, 11, 4), s(2, 0, 11, 2), s(1, 0, 12, 1), s(2, 0, 12, 2),
+    s(5, 1, 9, 1),
+    s(13, 1, 9, 4), s(12, 1, 9, 1),
+    s(12, 3, 4, 2), s(13, 3, 4, 4),
+    s(20, 0, 11, 1), s(16, 0, 12, 2), s(16, 0, 10, 5),
+    s(0, 3, 12, 3), s(18, 2, 12, 2),
+    s(6, 0, 9, 4), s(6, 0, 1, 4),
+    s(12, 1, 1, 1), s(14, 1, 11, 4),
+    s(16, 2, 0, 3),
+    s(20, 0, 9, 1), s(21, 0, 9, 3), s(20, 0, 9, 4), s(20, 0, 1, 1), s(21, 0, 10, 1),
+    s(0, 3, 12, 1), s(16, 2, 12, 1), s(0, 3, 12, 4),
+    s(19, 0, 7, 1), s(20, 0, 7, 2),
+    s(1, 0, 12, 1), s(2, 0, 12, 2),
+    s(21, 2, 12, 3), s(17, 2, 11, 3),
+    s(0, 0, 7, 4), s(0, 0, 7, 2),
+    s(19, 2, 0, 2), s(13, 1, 0, 1),
+    s(16, 2, 11, 3),
+    s(17, 0, 1, 3),
+    s(15, 0, 11, 1),
+    s(13, 1, 12, 1), s(13, 1, 12, 3),
+    s(0, 1, 9, 4), s(0, 1, 9, 1), s(0, 1, 10, 4),
+    s(14, 1, 4, 4), s(21, 2, 0, 4),
+    s(8, 0, 8, 2), s(8, 1, 8, 3), s(8, 3, 0, 3),
+    s(2, 1, 7, 4), s(1, 1, 7, 1),
+    s(12, 1, 10, 3), s(12, 1, 10, 4),
+    s(8, 1, 9, 4), s(8, 1, 9, 2),
+    s(8, 2, 0, 4), s(8, 1, 7, 2),
+    s(6, 0, 9, 4), s(8, 0, 9, 4), s(6, 0, 9, 3),
+    s(15, 2, 9, 4), s(19, 2, 10, 1),
+    s(13, 1, 7, 2), s(12, 1, 7, 3),
+    s(6, 2, 6, 3), s(6, 2, 6, 2),
+    s(19, 2, 10, 3), s(20, 2, 9, 2),
+    s(2, 2, 0, 2), s(2, 2, 0, 1), s(1, 2, 0, 2),
+    s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 0, 7, 3),
+    s(16, 0, 12, 1), s(5, 0, 12, 4), s(5, 0, 12, 1), s(6, 0, 12, 2),
+    s(16, 0, 9, 2), s(15, 2, 9, 4),
+    s(16, 2, 9, 3), s(16, 2, 10, 3),
+    s(6, 1, 4, 3), s(12, 1, 9, 4),
+    s(5, 0, 9, 4), s(16, 0, 9, 2), s(16, 0, 9, 3), s(17, 0, 9, 4), s(5, 0, 1, 2),
+    s(12, 1, 7, 3), s(0, 1, 7, 2), s(12, 1, 7, 1),
+    s(21, 0, 12, 1), s(20, 0, 12, 2),
+    s(12, 3, 0, 2), s(0, 3, 0, 4),
+    s(12, 1, 7, 1), s(12, 1, 7, 4), s(12, 1, 7, 3),
+    s(12, 1, 9, 4), s(19, 0, 10, 4),
+    s(6, 2, 12, 2), s(15, 2, 11, 4), s(16, 2, 12, 4),
+    s(8, 1, 10, 4), s(8, 1, 10, 3),
+    s(12, 1, 1, 4), s(13, 1, 1, 3), s(12, 1, 4, 5),
+    s(15, 0, 8, 4), s(15, 0, 8, 1),
+    s(21, 0, 5, 4),
+    s(16, 0, 9, 2), s(17, 0, 9, 4), s(6, 0, 9, 3), s(5, 0, 9, 4), s(15, 0, 9, 3),
+    s(0, 1, 0, 4), s(0, 1, 0, 1),
+    s(5, 0, 11, 4), s(5, 0, 11, 1),
+    s(14, 3, 9, 1), s(14, 3, 9, 2),
+    s(5, 0, 9, 1), s(5, 0, 9, 4), s(17, 0, 9, 4),
+    s(12, 1, 7, 3), s(12, 1, 7, 1),
+    s(1, 1, 10, 1), s(1, 1, 10, 4),
+    s(0, 0, 9, 2), s(0, 0, 9, 4),
+    s(6, 0, 5, 2), s(6, 0, 5, 4),
+    s(16, 0, 8, 2), s(5, 0, 7, 4),
+    s(7, 1, 0, 3), s(0, 1, 0, 2), s(0, 1, 0, 4), s(0, 0, 5, 4),
+    s(8, 0, 6, 3), s(8, 0, 6, 2), s(8, 0, 6, 4),
+    s(10, 2, 11, 3),
+    s(0, 3, 0, 4), s(5, 1, 0, 2), s(5, 2, 0, 2),
+    s(16, 0, 10, 4), s(13, 1, 10, 4), s(13, 1, 10, 1),
+    s(8, 2, 12, 3), s(8, 2, 12, 4), s(8, 2, 12, 2),
+    s(16, 2, 0, 3), s(16, 2, 0, 2),
+    s(16, 0, 9, 2), s(16, 0, 9, 4),
+    s(18, 0, 11, 2), s(14, 1, 11, 1),
+    s(17, 2, 0, 1), s(6, 1, 7, 2),
+    s(11, 2, 6, 4), s(14, 1, 4, 2),
+    s(6, 0, 11, 3), s(6, 0, 11, 4), s(16, 0, 11, 3),
+    s(8, 0, 6, 2), s(8, 0, 6, 3), s(8, 2, 2, 3),
+    s(0, 0, 13, 2), s(0, 0, 13, 5), s(18, 0, 10, 2),
+    s(0, 2, 0, 4), s(0, 2, 0, 1),
+    s(0, 3, 10, 3), s(0, 3, 9, 2),
+    s(19, 0, 9, 1),
+    s(14, 3, 12, 1), s(10, 2, 11, 4),
+    s(9, 2, 11, 1), s(9, 2, 11, 4),
+    s(3, 1, 9, 3), s(0, 2, 10, 4), s(0, 2, 9, 3),
+    s(6, 2, 0, 4),
+    s(5, 2, 6, 4), s(18, 2, 6, 4), s(5, 2, 2, 2),
+    s(0, 0, 13, 2), s(7, 1, 0, 2),
+    s(12, 1, 10, 1), s(19, 0, 9, 4),
+    s(6, 2, 0, 4), s(6, 2, 0, 2), s(16, 0, 9, 1),
+    s(8, 1, 11, 3), s(8, 1, 11, 4),
+    s(0, 3, 0, 2), s(0, 3, 0, 4), s(17, 2, 0, 4), s(17, 2, 0, 1), s(15, 2, 0, 1),
+    s(1, 0, 1, 1), s(1, 0, 1, 2),
+    s(8, 1, 8, 4), s(8, 2, 0, 4),
+    s(11, 0, 9, 5),
+    s(9, 2, 12, 4), s(9, 2, 12, 1), s(9, 2, 12, 3), s(11, 2, 12, 2),
+    s(14, 1, 12, 1), s(14, 1, 12, 4),
+    s(13, 1, 0, 2), s(12, 1, 0, 1), s(12, 1, 0, 4),
+    s(5, 1, 9, 3), s(6, 1, 9, 3),
+    s(19, 0, 0, 1), s(20, 0, 0, 2),
+    s(12, 3, 12, 1), s(12, 3, 12, 3),
+    s(7, 0, 6, 4), s(7, 0, 1, 4), s(18, 2, 6, 4),
+    s(18, 0, 9, 3), s(7, 0, 9, 2), s(5, 0, 9, 1),
+    s(20, 0, 3, 4), s(15, 0, 1, 4),
+    s(12, 3, 12, 3), s(12, 3, 12, 1),
+    s(3, 0, 7, 4), s(3, 0, 2, 4),
+    s(0, 1, 10, 2), s(0, 1, 8, 2),
+    s(9, 2, 9, 1), s(9, 2, 9, 4),
+    s(15, 2, 12, 3),
+    s(3, 1, 12, 2), s(3, 1, 9, 2), s(3, 1, 9, 4),
+    s(4, 0, 12, 2), s(2, 1, 12, 2),
+    s(1, 1, 12, 1), s(7, 1, 12, 2),
+    s(16, 2, 12, 1), s(16, 2, 12, 4),
+    s(8, 0, 12, 3), s(8, 1, 12, 2), s(8, 1, 12, 3),
+    s(2, 0, 9, 4),
+    s(14, 1, 9, 3), s(17, 0, 12, 3),
+    s(12, 1, 12, 4), s(16, 0, 12, 1),
+    s(15, 2, 10, 3),
+    s(8, 1, 11, 2), s(8, 1, 11, 4),
+    s(8, 1, 12, 2), s(8, 1, 12, 4),
+    s(20, 0, 8, 4),
+    s(16, 2, 11, 4), s(20, 0, 11, 1),
+    s(12, 1, 0, 3), s(12, 1, 0, 1),
+    s(13, 1, 9, 3), s(10, 0, 9, 3),
+    s(0, 0, 7, 1), s(0, 2, 1, 1),
+    s(19, 0, 7, 2), s(19, 2, 2, 4),
+    s(5, 0, 7, 1), s(5, 1, 7, 1),
+    s(5, 0, 7, 1),
+    s(4, 0, 10, 1), s(4, 0, 10, 4), s(4, 0, 10, 2),
+    s(13, 1, 4, 4), s(13, 1, 4, 1), s(13, 1, 0, 4),
+    s(20, 2, 10, 3),
+    s(11, 2, 1, 4), s(11, 2, 1, 2), s(9, 2, 2, 4), s(9, 2, 2, 3), s(11, 2, 5, 5),
+    s(8, 1, 4, 4), s(8, 1, 0, 4),
+    s(16, 2, 11, 4), s(16, 2, 11, 1),
+    s(2, 0, 7, 2), s(1, 0, 7, 4),
+    s(1, 1, 4, 2), s(1, 1, 4, 4),
+    s(16, 0, 9, 3), s(16, 0, 9, 4),
+    s(10, 2, 0, 1), s(10, 0, 8, 1),
+    s(17, 2, 1, 1), s(17, 2, 1, 4),
+    s(13, 3, 9, 4), s(14, 3, 9, 4),
+    s(17, 0, 1, 1), s(16, 0, 1, 4),
+    s(20, 0, 0, 4), s(20, 0, 0, 1), s(13, 1, 0, 4),
+    s(10, 0, 3, 4), s(10, 0, 6, 1),
+    s(8, 0, 8, 2), s(5, 0, 8, 1),
+    s(14, 3, 4, 1), s(14, 1, 7, 1), s(13, 1, 7, 4), s(17, 0, 7, 4),
+    s(8, 0, 1, 2), s(8, 0, 1, 4),
+    s(13, 1, 9, 2), s(12, 1, 9, 3),
+    s(6, 1, 0, 1), s(6, 1, 0, 4),
+    s(2, 0, 8, 1), s(2, 0, 2, 3),
+    s(5, 2, 2, 1), s(16, 0, 0, 4),
+    s(13, 1, 12, 2), s(8, 3, 4, 4),
+    s(17, 0, 9, 4), s(0, 1, 9, 3),
+    s(5, 2, 0, 1), s(15, 2, 2, 2),
+    s(1, 0, 2, 1), s(1, 0, 7, 1), s(2, 2, 0, 1),
+    s(11, 2, 2, 1), s(11, 2, 2, 4),
+    s(5, 2, 2, 2), s(5, 2, 0, 4),
+    s(5, 2, 9, 1), s(6, 2, 9, 2), s(15, 0, 0, 4),
+    s(5, 0, 1, 2), s(15, 0, 1, 2),
+    s(10, 0, 5, 3), s(0, 0, 5, 1),
+    s(16, 2, 11, 4), s(16, 2, 11, 1), s(13, 1, 11, 1),
+    s(16, 2, 9, 1),
+    s(6, 2, 9, 2), s(15, 2, 9, 1), s(15, 2, 9, 4),
+    s(8, 2, 0, 4), s(12, 1, 8, 1),
+    s(2, 0, 12, 3),
+    s(2, 1, 7, 1), s(2, 1, 7, 4), s(2, 1, 7, 2), s(1, 1, 7, 3), s(1, 1, 7, 1),
+    s(12, 1, 7, 3), s(16, 0, 7, 1),
+    s(13, 1, 7, 1), s(13, 1, 7, 2),
+    s(11, 2, 1, 4), s(11, 2, 1, 2), s(11, 2, 5, 5),
+    s(15, 0, 1, 1), s(15, 0, 1, 2),
+    s(11, 2, 2, 1), s(11, 2, 2, 4), s(11, 2, 1, 2),
+    s(12, 1, 9, 3), s(19, 2, 9, 1),
+    s(3, 0, 2, 2), s(3, 1, 0, 2),
+    s(14, 1, 4, 2), s(8, 1, 4, 4),
+    s(12, 1, 10, 4), s(12, 1, 12, 4),
+    s(10, 0, 8, 3),
+    s(11, 0, 3, 2), s(10, 0, 5, 4),
+    s(9, 0, 7, 4),
+    s(3, 0, 12, 3),
+    s(16, 0, 0, 4), s(8, 0, 5, 4),
+    s(8, 0, 6, 1), s(8, 0, 3, 4), s(8, 0, 6, 5),
+    s(10, 0, 5, 4),
+    s(14, 3, 0, 4), s(3, 0, 7, 4),
+    s(0, 2, 0, 4), s(0, 2, 0, 3), s(0, 2, 0, 2), s(3, 0, 7, 2), s(3, 0, 7, 4),
+    s(0, 2, 12, 3), s(0, 1, 11, 3),
+    s(8, 0, 7, 2), s(8, 0, 7, 4), s(8, 1, 7, 2),
+    s(3, 2, 0, 4), s(1, 0, 2, 2),
+    s(13, 1, 10, 2), s(13, 1, 0, 2),
+    s(12, 1, 11, 4), s(13, 1, 11, 3), s(12, 1, 11, 3),
+    s(16, 0, 7, 1), s(12, 1, 7, 3), s(16, 0, 7, 2),
+    s(4, 0, 9, 1),
+    s(17, 0, 7, 2), s(17, 2, 2, 4), s(15, 2, 2, 2), s(5, 1, 0, 4),
+    s(9, 0, 8, 1), s(9, 0, 8, 4),
+    s(0, 2, 0, 4), s(3, 0, 2, 4),
+    s(0, 3, 10, 2), s(12, 3, 10, 1), s(0, 3, 10, 4),
+    s(1, 0, 7, 1), s(2, 0, 7, 2), s(4, 2, 0, 2),
+    s(6, 0, 7, 2), s(0, 1, 7, 2),
+    s(1, 1, 0, 3), s(2, 1, 10, 4),
+    s(11, 2, 1, 4), s(11, 2, 1, 1), s(11, 2, 2, 4),
+    s(1, 0, 6, 3), s(1, 0, 6, 4),
+    s(17, 0, 0, 5), s(16, 0, 0, 2),
+    s(4, 0, 11, 1), s(4, 0, 11, 4),
+    s(10, 0, 11, 4),
+    s(19, 0, 11, 1), s(20, 0, 11, 2),
+    s(4, 0, 6, 3), s(4, 0, 6, 1), s(4, 0, 10, 1),
+    s(9, 2, 6, 4), s(10, 2, 6, 4),
+    s(21, 2, 9, 3),
+    s(10, 0, 3, 1), s(0, 0, 3, 1), s(0, 0, 9, 3),
+    s(7, 1, 0, 4), s(6, 0, 3, 4),
+    s(13, 3, 0, 1), s(0, 0, 8, 1), s(9, 0, 8, 1), s(13, 1, 8, 1), s(10, 0, 8, 4),
+    s(11, 2, 1, 2), s(11, 2, 1, 4), s(11, 2, 1, 1),
+    s(1, 0, 6, 1), s(1, 1, 0, 3), s(1, 1, 0, 4), s(2, 1, 0, 2), s(1, 0, 9, 1),
+    s(19, 2, 0, 2), s(20, 2, 0, 4), s(20, 2, 6, 4),
+    s(15, 2, 2, 2), s(15, 2, 2, 1),
+    s(5, 0, 9, 1), s(16, 0, 9, 2), s(17, 0, 9, 4),
+    s(7, 0, 9, 2), s(7, 0, 1, 1),
+    s(12, 1, 0, 2), s(16, 0, 0, 4),
+    s(1, 0, 2, 5), s(1, 2, 0, 3), s(2, 2, 0, 1),
+    s(10, 2, 11, 4), s(9, 2, 9, 4),
+    s(1, 1, 9, 4), s(2, 0, 9, 2),
+    s(1, 2, 0, 3), s(12, 1, 0, 1),
+    s(15, 0, 9, 4), s(15, 0, 9, 1), s(6, 1, 4, 1),
+    s(10, 0, 1, 3), s(13, 1, 1, 3),
+    s(8, 2, 0, 3), s(14, 1, 0, 1),
+    s(9, 2, 1, 4),
+    s(0, 0, 11, 2), s(0, 1, 11, 3),
+    s(0, 1, 10, 4), s(0, 1, 0, 4),
+    s(8, 2, 9, 3), s(10, 2, 10, 1),
+    s(12, 3, 9, 3), s(12, 3, 9, 4), s(13, 3, 9, 2), s(13, 3, 9, 1), s(9, 2, 10, 3), s(12, 3, 10, 4),
+    s(14, 3, 0, 4), s(21, 2, 0, 1),
+    s(13, 3, 4, 4), s(12, 1, 7, 3), s(14, 1, 0, 4),
+    s(16, 0, 11, 3), s(11, 0, 9, 3), s(0, 1, 9, 2), s(0, 0, 9, 1),
+    s(0, 2, 6, 3), s(0, 1, 9, 2),
+    s(0, 0, 3, 4), s(0, 0, 3, 3),
+    s(15, 0, 3, 2), s(15, 0, 5, 2),
+    s(11, 0, 9, 3), s(0, 0, 9, 4),
+    s(5, 1, 0, 3), s(15, 0, 0, 3),
+    s(15, 0, 1, 3), s(15, 0, 5, 3),
+    s(0, 1, 1, 2), s(0, 0, 5, 2),
+    s(15, 0, 0, 4), s(17, 0, 0, 1),
+    s(20, 0, 3, 4), s(21, 0, 0, 5),
+    s(2, 0, 11, 2), s(3, 0, 11, 2),
+    s(8, 1, 0, 2), s(16, 0, 9, 2),
+    s(20, 2, 2, 4), s(12, 1, 0, 2),
+    s(17, 0, 1, 4), s(14, 1, 1, 4),
+    s(0, 1, 9, 4), s(0, 1, 1, 1), s(0, 1, 9, 3), s(0, 1, 9, 1), s(0, 1, 0, 4),
+    s(8, 0, 9, 2), s(13, 1, 9, 1),
+    s(8, 1, 0, 4), s(8, 0, 5, 4),
+    s(21, 0, 0, 1), s(3, 0, 8, 3),
+    s(9, 2, 12, 1), s(11, 2, 12, 2),
+    s(8, 1, 10, 2), s(3, 1, 10, 5),
+    s(18, 0, 8, 2), s(13, 1, 8, 2),
+    s(13, 3, 0, 4), s(13, 3, 0, 1),
+    s(5, 2, 0, 1), s(5, 2, 0, 3),
+    s(15, 2, 9, 1), s(11, 2, 6, 4),
+    s(20, 0, 9, 1), s(20, 0, 10, 1), s(17, 0, 10, 1),
+    s(20, 0, 9, 1), s(17, 0, 10, 1), s(21, 0, 9, 1), s(20, 0, 10, 1), s(20, 0, 9, 4), s(21, 0, 9, 3),
+    s(16, 0, 1, 1), s(16, 0, 1, 2), s(16, 0, 1, 3), s(16, 0, 1, 4),
+    s(17, 0, 8, 1),
+    s(4, 0, 1, 1), s(4, 0, 1, 4),
+    s(17, 0, 0, 4), s(8, 1, 0, 4),
+    s(15, 2, 2, 2), s(0, 1, 0, 3), s(8, 1, 0, 4), s(12, 3, 4, 2),
+    s(13, 3, 0, 3), s(13, 3, 0, 1),
+    s(17, 0, 8, 4), s(5, 0, 7, 4),
+    s(12, 1, 1, 3), s(14, 1, 1, 2),
+    s(21, 0, 8, 3), s(21, 0, 8, 1), s(14, 1, 7, 1),
+    s(9, 2, 0, 3), s(9, 2, 0, 4), s(10, 2, 0, 1),
+    s(12, 3, 0, 4), s(9, 0, 8, 1), s(9, 0, 8, 4), s(13, 3, 0, 2),
+    s(8, 1, 12, 4),
+    s(5, 0, 7, 1), s(5, 0, 7, 2), s(6, 0, 7, 1),
+    s(15, 0, 0, 3), s(15, 0, 0, 1),
+    s(1, 0, 1, 1), s(2, 0, 1, 1), s(1, 0, 1, 5),
+    s(10, 0, 3, 3), s(10, 0, 3, 4), s(9, 0, 3, 1),
+    s(6, 0, 5, 2), s(6, 0, 5, 1), s(0, 1, 0, 2), s(21, 0, 0, 4),
+    s(16, 0, 0, 4), s(11, 2, 1, 4), s(0, 0, 3, 2),
+    s(2, 0, 2, 3),
+    s(0, 1, 4, 4), s(14, 1, 4, 2),
+    s(11, 0, 7, 4), s(11, 0, 7, 2), s(14, 1, 7, 1),
+    s(21, 0, 0, 1), s(20, 0, 0, 2), s(21, 0, 0, 4),
+    s(6, 0, 9, 4), s(0, 1, 0, 3), s(0, 1, 8, 4),
+    s(12, 1, 0, 1), s(12, 1, 7, 4),
+    s(11, 2, 12, 1), s(11, 2, 12, 2),
+    s(3, 1, 4, 1),
+    s(14, 3, 0, 1), s(0, 3, 0, 1), s(0, 3, 0, 4),
+    s(16, 0, 0, 1), s(13, 1, 0, 1),
+    s(14, 3, 9, 1), s(21, 2, 12, 4),
+    s(19, 0, 0, 3), s(12, 1, 0, 2),
+    s(11, 0, 3, 2), s(9, 0, 3, 3),
+    s(20, 2, 10, 4), s(5, 0, 8, 4), s(0, 1, 12, 1),
+    s(6, 2, 12, 2), s(6, 2, 12, 4),
+    s(3, 1, 12, 2), s(3, 1, 12, 4),
+    s(6, 2, 0, 3), s(6, 2, 0, 4),
+    s(15, 0, 1, 1), s(15, 0, 1, 4),
+    s(14, 1, 1, 4), s(11, 0, 3, 4), s(11, 0, 1, 4),
+    s(0, 1, 4, 3), s(0, 1, 4, 1),
+    s(0, 1, 1, 1), s(0, 0, 1, 1),
+    s(3, 0, 1, 5), s(3, 0, 1, 2), s(3, 0, 1, 3),
+    s(0, 1, 0, 1), s(14, 1, 0, 1),
+    s(6, 2, 10, 1), s(6, 1, 9, 1),
+    s(0, 1, 10, 2), s(0, 1, 10, 3), s(12, 1, 10, 4),
+    s(1, 1, 0, 3), s(1, 1, 0, 4), s(2, 1, 0, 3),
+    s(12, 1, 4, 4), s(9, 0, 3, 4), s(14, 1, 4, 4),
+    s(1, 2, 0, 4), s(2, 0, 8, 1),
+    s(4, 0, 8, 3), s(2, 1, 0, 3),
+    s(1, 0, 1, 5), s(1, 0, 1, 1), s(2, 0, 1, 1),
+    s(5, 2, 10, 1), s(6, 2, 10, 2), s(6, 2, 10, 3),
+    s(4, 0, 10, 1), s(2, 0, 10, 4),
+    s(0, 0, 3, 2), s(11, 2, 1, 1),
+    s(11, 0, 9, 2), s(11, 0, 9, 4),
+    s(6, 1, 12, 1), s(0, 1, 10, 3), s(0, 1, 0, 2),
+    s(10, 0, 12, 1), s(11, 0, 11, 2), s(11, 0, 11, 4),
+    s(17, 2, 10, 3),
+    s(15, 0, 0, 1), s(19, 0, 0, 1), s(13, 1, 0, 4),
+    s(0, 1, 10, 3), s(17, 0, 10, 3),
+    s(0, 2, 0, 2), s(6, 2, 10, 1),
+    s(16, 0, 7, 3), s(16, 0, 7, 1), s(3, 1, 7, 3), s(16, 0, 7, 4),
+    s(14, 3, 4, 4), s(16, 2, 2, 4), s(12, 3, 4, 2),
+    s(16, 2, 6, 1), s(16, 2, 6, 4),
+    s(5, 0, 8, 1), s(18, 2, 0, 2),
+    s(11, 0, 8, 3),
+    s(11, 2, 12, 1), s(0, 0, 8, 1), s(11, 0, 8, 3),
+    s(0, 2, 0, 2), s(0, 3, 0, 2), s(0, 1, 1, 2),
+    s(0, 1, 1, 5), s(0, 1, 1, 1), s(14, 1, 1, 1),
+    s(0, 0, 3, 4), s(0, 0, 3, 5), s(0, 0, 5, 4),
+    s(3, 0, 6, 2), s(0, 2, 10, 3),
+    s(5, 0, 5, 1), s(1, 0, 7, 3), s(0, 0, 5, 2),
+    s(13, 1, 0, 3), s(3, 0, 10, 4),
+    s(16, 0, 12, 2), s(10, 2, 11, 2), s(16, 0, 12, 3),
+    s(9, 0, 7, 4), s(12, 3, 0, 1), s(9, 2, 0, 4),
+    s(16, 0, 0, 3), s(0, 1, 12, 1),
+    s(7, 0, 1, 4), s(7, 0, 3, 4), s(7, 0, 1, 5), s(7, 2, 2, 4), s(7, 0, 3, 5),
+    s(5, 0, 5, 1), s(6, 0, 5, 3),
+    s(0, 0, 8, 3), s(0, 0, 8, 1), s(0, 0, 8, 4),
+    s(1, 0, 6, 5), s(1, 0, 5, 4),
+    s(0, 3, 9, 2), s(0, 3, 10, 4), s(0, 3, 10, 2),
+    s(0, 2, 10, 5),
+    s(13, 1, 11, 1), s(13, 1, 11, 4),
+    s(2, 0, 10, 3),
+    s(0, 2, 10, 3), s(3, 1, 10, 3),
+    s(7, 0, 3, 5), s(7, 1, 0, 2), s(7, 1, 0, 3), s(7, 1, 0, 1),
+    s(8, 1, 12, 4), s(8, 1, 12, 2),
+    s(6, 1, 4, 4), s(16, 0, 3, 4),
+    s(14, 1, 0, 4), s(16, 0, 0, 4),
+    s(13, 1, 0, 4), s(15, 0, 0, 1),
+    s(19, 0, 0, 3), s(20, 0, 0, 1), s(12, 1, 0, 1), s(14, 1, 0, 4),
+    s(9, 2, 0, 1), s(9, 2, 1, 1), s(9, 2, 1, 3),
+    s(20, 0, 0, 1), s(20, 0, 0, 2), s(19, 0, 0, 1),
+    s(0, 2, 6, 4), s(3, 0, 6, 4),
+    s(14, 3, 0, 3), s(11, 0, 8, 3), s(11, 0, 8, 1), s(9, 0, 8, 4), s(9, 0, 8, 1), s(9, 2, 0, 1),
+    s(11, 0, 3, 1), s(11, 0, 1, 1), s(0, 0, 1, 1), s(0, 0, 1, 5), s(10, 0, 3, 1), s(11, 2, 2, 1), s(0, 0, 1, 2), s(0, 0, 1, 4),
+    s(7, 0, 7, 2), s(7, 0, 1, 2), s(7, 2, 0, 3),
+    s(9, 0, 1, 1), s(14, 1, 1, 1), s(12, 1, 1, 3),
+    s(2, 0, 6, 1),
+    s(14, 1, 7, 1), s(11, 0, 7, 2),
+    s(11, 2, 0, 1), s(14, 1, 7, 1), s(14, 3, 0, 1), s(11, 0, 3, 4), s(14, 1, 1, 4),
+    s(5, 0, 1, 2), s(0, 1, 1, 4), s(6, 0, 1, 3), s(5, 0, 9, 4),
+    s(13, 3, 0, 4), s(10, 0, 1, 1),
+    s(12, 3, 0, 3), s(19, 2, 6, 3),
+    s(9, 0, 9, 4), s(11, 0, 9, 2), s(14, 1, 9, 2),
+    s(0, 1, 11, 1), s(0, 1, 11, 3),
+    s(19, 0, 1, 3), s(19, 0, 3, 2), s(15, 0, 1, 1), s(15, 0, 1, 4),
+    s(11, 0, 3, 2), s(11, 0, 3, 4), s(11, 2, 0, 2), s(11, 2, 2, 2), s(11, 2, 2, 4), s(11, 2, 2, 5),
+    s(11, 0, 5, 1), s(6, 0, 5, 1),
+    s(12, 1, 8, 4), s(9, 0, 7, 1),
+    s(10, 0, 1, 1), s(10, 0, 1, 3), s(7, 2, 12, 4),
+    s(9, 2, 0, 1), s(9, 2, 0, 5),
+    s(10, 0, 1, 1), s(9, 0, 1, 1), s(12, 1, 1, 1),
+    s(19, 2, 2, 5),
+    s(14, 1, 9, 4), s(14, 1, 9, 2),
+    s(0, 0, 13, 4), s(0, 0, 13, 2),
+    s(9, 2, 11, 1), s(9, 2, 12, 1),
+    s(14, 1, 0, 4), s(14, 1, 0, 1), s(5, 1, 4, 2), s(15, 0, 0, 4),
+    s(0, 1, 0, 2), s(14, 1, 0, 1),
+    s(8, 1, 4, 3), s(8, 1, 4, 1), s(8, 1, 4, 4), s(8, 1, 4, 2), s(8, 1, 4, 5),
+    s(3, 1, 4, 1), s(3, 1, 4, 5),
+    s(3, 1, 0, 1), s(3, 1, 0, 3), s(3, 1, 4, 1), s(3, 0, 5, 3),
+    s(0, 1, 7, 3), s(12, 1, 7, 1), s(0, 1, 7, 1), s(12, 1, 7, 3),
+    s(12, 1, 0, 1), s(14, 1, 0, 1), s(13, 1, 1, 4),
+    s(15, 0, 8, 4), s(15, 2, 0, 4), s(15, 2, 0, 1), s(18, 2, 0, 2),
+    s(9, 0, 3, 1), s(10, 0, 1, 3), s(8, 0, 2, 5), s(8, 2, 2, 4), s(10, 0, 1, 1),
+    s(17, 2, 0, 4), s(14, 3, 10, 2),
+    s(19, 0, 9, 2), s(19, 0, 1, 2), s(19, 0, 1, 3), s(19, 0, 9, 5),
+    s(10, 0, 3, 2), s(11, 0, 5, 1), s(11, 0, 5, 2), s(9, 0, 5, 1),
+    s(11, 2, 6, 1), s(11, 0, 5, 2),
+    s(11, 2, 5, 4), s(17, 0, 0, 4), s(9, 2, 2, 1), s(9, 2, 1, 1), s(11, 2, 1, 4),
+    s(6, 0, 7, 2), s(6, 1, 7, 4),
+    s(14, 1, 9, 2), s(12, 1, 9, 3), s(12, 1, 9, 1),
+    s(0, 0, 3, 4), s(0, 0, 9, 4),
+    s(14, 3, 9, 3), s(14, 3, 9, 1),
+    s(14, 1, 8, 1), s(14, 3, 0, 3), s(14, 1, 7, 1), s(14, 3, 0, 4),
+    s(9, 2, 2, 1), s(0, 2, 5, 1), s(11, 0, 3, 2), s(0, 2, 2, 3), s(0, 2, 2, 1), s(9, 2, 1, 3),
+    s(0, 1, 9, 4), s(0, 1, 9, 1), s(0, 1, 4, 4), s(0, 3, 9, 1),
+    s(0, 0, 5, 1),
+    s(2, 1, 10, 3),
+    s(11, 2, 12, 3), s(11, 2, 12, 1), s(11, 2, 12, 4),
+    s(14, 3, 12, 1), s(11, 2, 12, 1),
+    s(5, 2, 2, 1), s(16, 0, 0, 3), s(15, 0, 1, 4), s(16, 0, 0, 4), s(5, 2, 2, 4), s(5, 1, 4, 3),
+    s(0, 2, 1, 5), s(0, 2, 1, 1), s(9, 2, 6, 1), s(11, 2, 1, 2), s(0, 2, 1, 2),
+    s(11, 0, 1, 1), s(11, 0, 1, 3), s(11, 0, 1, 4), s(11, 0, 3, 1), s(11, 0, 3, 2), s(6, 0, 1, 4), s(17, 0, 1, 4),
+    s(19, 0, 5, 1),
+    s(5, 1, 4, 4), s(5, 1, 0, 4),
+    s(2, 0, 5, 4), s(9, 2, 0, 1),
+    s(9, 0, 10, 2), s(11, 0, 10, 3),
+    s(10, 2, 11, 1), s(13, 1, 11, 1),
+    s(0, 1, 1, 3), s(0, 1, 1, 1),
+    s(5, 0, 1, 2), s(5, 0, 1, 1),
+    s(11, 2, 6, 4), s(0, 3, 4, 3),
+    s(11, 2, 1, 1), s(11, 2, 1, 2),
+    s(14, 1, 12, 5),
+    s(3, 0, 8, 1),
+    s(0, 1, 2, 1), s(0, 1, 2, 5),
+    s(0, 3, 9, 2), s(0, 3, 10, 2), s(0, 3, 10, 4),
+    s(0, 0, 2, 2), s(0, 0, 3, 2), s(0, 0, 2, 4),
+    s(16, 0, 0, 1), s(14, 1, 1, 4), s(11, 0, 3, 4),
+    s(17, 0, 7, 4), s(21, 0, 7, 1), s(14, 1, 7, 1), s(14, 1, 7, 4), s(21, 0, 7, 4),
+    s(8, 1, 0, 1), s(8, 1, 0, 5), s(8, 1, 0, 4), s(8, 1, 0, 3), s(3, 0, 5, 2), s(0, 1, 12, 1),
+    s(7, 0, 1, 3), s(7, 0, 1, 5), s(7, 0, 3, 2), s(7, 2, 2, 2), s(7, 0, 5, 3), s(7, 0, 1, 4), s(7, 1, 4, 4), s(7, 0, 6, 3),
+    s(14, 1, 7, 1), s(14, 1, 7, 4), s(14, 3, 4, 1),
+    s(1, 0, 2, 1), s(2, 0, 2, 4), s(1, 0, 6, 4), s(1, 0, 1, 1), s(1, 0, 2, 2),
+    s(8, 1, 11, 4), s(8, 0, 11, 2),
+    s(8, 1, 4, 4), s(8, 3, 0, 4),
+    s(1, 2, 0, 3), s(1, 2, 0, 1), s(4, 2, 0, 3),
+    s(11, 0, 12, 1),
+    s(9, 0, 12, 3), s(0, 1, 12, 3), s(0, 1, 12, 4),
+    s(17, 2, 2, 1), s(0, 3, 4, 4),
+    s(9, 0, 3, 3),
+    s(21, 2, 2, 1), s(17, 2, 1, 4),
+    s(16, 2, 10, 2), s(15, 0, 10, 1), s(15, 0, 10, 4),
+    s(0, 0, 5, 1), s(0, 0, 5, 4), s(0, 0, 5, 3),
+    s(12, 1, 1, 2), s(13, 1, 9, 3),
+    s(14, 1, 9, 2), s(0, 1, 9, 2), s(5, 0, 9, 4),
+    s(14, 1, 0, 1), s(14, 1, 4, 4),
+    s(19, 2, 2, 4), s(17, 0, 0, 4),
+    s(0, 2, 0, 2), s(0, 2, 0, 4),
+    s(19, 0, 7, 4),
+    s(13, 1, 10, 4), s(13, 1, 10, 1),
+    s(3, 0, 1, 4), s(3, 0, 5, 4),
+    s(9, 2, 12, 4), s(11, 2, 12, 3),
+    s(8, 1, 11, 3), s(0, 1, 12, 1),
+    s(8, 0, 11, 5),
+    s(12, 1, 0, 1), s(12, 1, 4, 2),
+    s(0, 2, 2, 1), s(0, 2, 6, 3),
+    s(4, 0, 12, 3), s(1, 0, 12, 3),
+    s(11, 2, 0, 3), s(14, 1, 7, 1), s(9, 2, 2, 2), s(14, 1, 1, 4), s(11, 0, 7, 2),
+    s(17, 0, 8, 4), s(17, 2, 0, 2),
+    s(16, 0, 11, 4),
+    s(0, 0, 13, 2), s(0, 2, 1, 1),
+    s(19, 0, 3, 2), s(12, 1, 4, 4),
+    s(0, 1, 2, 1), s(0, 3, 0, 4),
+    s(7, 1, 9, 4), s(5, 1, 9, 4),
+    s(6, 2, 2, 4),
+    s(15, 0, 7, 1), s(15, 0, 8, 1), s(5, 0, 7, 1), s(6, 1, 7, 2), s(5, 1, 7, 4),
+    s(7, 0, 8, 3),
+    s(10, 0, 10, 3),
+    s(15, 2, 2, 2), s(15, 0, 8, 4),
+    s(5, 1, 0, 4), s(17, 0, 0, 4), s(15, 0, 5, 1),
+    s(11, 0, 12, 1), s(11, 0, 12, 4), s(0, 0, 3, 4), s(19, 0, 1, 2),
+    s(8, 1, 10, 2), s(8, 0, 9, 2), s(8, 0, 10, 4),
+    s(0, 0, 1, 5), s(0, 0, 1, 1), s(0, 0, 1, 2), s(0, 0, 1, 3), s(0, 0, 1, 4), s(0, 0, 3, 4),
+    s(20, 0, 5, 3), s(20, 0, 5, 1), s(14, 1, 7, 1),
+    s(14, 1, 11, 1), s(13, 1, 11, 1),
+    s(6, 2, 10, 1), s(15, 2, 10, 1), s(14, 1, 11, 1), s(6, 2, 6, 1), s(5, 2, 6, 3),
+    s(0, 2, 10, 4),
+    s(20, 2, 6, 4), s(19, 2, 0, 2), s(19, 0, 1, 2), s(0, 0, 3, 4), s(16, 2, 5, 4),
+    s(17, 0, 1, 4), s(19, 0, 1, 1), s(12, 1, 4, 2), s(5, 1, 4, 2), s(6, 1, 0, 4),
+    s(0, 1, 4, 4), s(0, 2, 1, 1),
+    s(19, 0, 0, 3), s(20, 0, 0, 1),
+    s(1, 1, 0, 3), s(6, 2, 0, 2),
+    s(16, 2, 5, 4), s(16, 2, 2, 4), s(15, 2, 2, 2),
+    s(0, 1, 1, 3), s(0, 0, 3, 4), s(0, 1, 1, 1),
+    s(4, 0, 6, 1), s(2, 0, 6, 4), s(2, 0, 5, 2), s(2, 0, 6, 1), s(1, 0, 5, 4),
+    s(17, 0, 1, 2), s(17, 0, 1, 4),
+    s(8, 0, 1, 5), s(8, 0, 1, 1),
+    s(0, 1, 12, 1), s(13, 1, 12, 2),
+    s(8, 2, 2, 1), s(8, 2, 2, 2), s(8, 2, 2, 5),
+    s(13, 3, 9, 2), s(12, 3, 4, 2),
+    s(16, 0, 9, 3), s(6, 0, 9, 1),
+    s(1, 0, 2, 1), s(1, 0, 2, 5),
+    s(8, 0, 11, 1),
+    s(16, 0, 0, 4), s(5, 1, 0, 4),
+    s(0, 0, 9, 2), s(0, 0, 9, 1),
+    s(10, 0, 1, 1), s(10, 0, 3, 4), s(10, 0, 3, 5),
+    s(0, 3, 12, 2), s(0, 3, 0, 2),
+    s(7, 0, 9, 2), s(7, 0, 9, 3),
+    s(8, 0, 1, 3), s(8, 0, 1, 2), s(8, 0, 1, 1), s(8, 0, 1, 5),
+    s(12, 1, 4, 1), s(14, 1, 4, 4),
+    s(11, 0, 9, 3), s(10, 0, 9, 4), s(12, 1, 9, 1),
+    s(5, 1, 4, 2), s(15, 0, 1, 2), s(13, 1, 4, 4),
+    s(7, 2, 2, 4), s(18, 0, 3, 3),
+    s(0, 1, 10, 1), s(0, 1, 10, 3), s(0, 1, 10, 4),
+    s(19, 0, 1, 2), s(19, 0, 9, 3), s(19, 0, 9, 2), s(19, 0, 1, 4), s(19, 0, 9, 5),
+    s(0, 0, 2, 1), s(0, 2, 2, 1), s(0, 2, 0, 1), s(0, 0, 2, 5), s(0, 0, 2, 4),
+    s(16, 2, 9, 3),
+    s(11, 2, 6, 4), s(15, 0, 8, 4),
+    s(11, 2, 9, 4), s(0, 3, 9, 2), s(14, 3, 9, 3), s(11, 0, 3, 2),
+    s(14, 1, 0, 3), s(14, 1, 0, 1), s(16, 0, 0, 4),
+    s(11, 0, 3, 1), s(11, 0, 3, 4), s(0, 1, 4, 4), s(10, 0, 5, 4),
+    s(10, 2, 6, 4), s(11, 2, 5, 4),
+    s(15, 2, 12, 3), s(16, 2, 12, 3),
+    s(17, 0, 1, 4), s(16, 0, 3, 4),
+    s(5, 2, 2, 2), s(15, 0, 1, 4),
+    s(7, 1, 4, 4), s(0, 1, 0, 4),
+    s(14, 3, 9, 1), s(14, 3, 9, 3),
+    s(16, 0, 0, 1), s(10, 0, 5, 4),
+    s(0, 1, 9, 4), s(0, 1, 9, 3),
+    s(5, 0, 9, 1), s(5, 0, 9, 3), s(16, 0, 9, 2), s(17, 0, 9, 4), s(16, 0, 9, 3), s(5, 0, 9, 4), s(15, 0, 9, 4), s(6, 0, 9, 2),
+    s(2, 0, 10, 4), s(1, 0, 10, 1),
+    s(20, 0, 9, 1), s(21, 2, 10, 1), s(13, 1, 0, 1),
+    s(15, 0, 1, 1), s(16, 0, 1, 1), s(15, 0, 1, 5),
+    s(3, 1, 7, 1),
+    s(2, 0, 10, 1), s(2, 0, 10, 4),
+    s(8, 0, 8, 2), s(8, 0, 8, 5),
+    s(19, 0, 7, 4), s(13, 1, 7, 1),
+    s(11, 0, 3, 4), s(14, 1, 7, 1), s(14, 1, 7, 4), s(11, 2, 0, 4),
+    s(0, 0, 1, 2), s(17, 0, 1, 4), s(0, 0, 1, 5), s(14, 1, 1, 4),
+    s(13, 1, 11, 1), s(13, 1, 11, 4), s(16, 0, 12, 2),
+    s(11, 2, 12, 3), s(9, 2, 12, 3), s(9, 2, 12, 4),
+    s(0, 0, 5, 4), s(0, 1, 0, 4), s(0, 2, 2, 4),
+    s(21, 2, 2, 1), s(17, 2, 2, 4),
+    s(3, 0, 1, 5), s(3, 0, 1, 4), s(3, 0, 1, 2), s(3, 0, 1, 3),
+    s(10, 0, 3, 1), s(10, 0, 3, 4), s(11, 0, 3, 2), s(14, 1, 1, 2),
+    s(5, 0, 1, 1), s(6, 0, 1, 4), s(5, 0, 1, 5),
+    s(16, 0, 10, 1), s(6, 1, 9, 2),
+    s(21, 0, 8, 1), s(21, 2, 0, 4), s(21, 0, 8, 4),
+    s(0, 2, 1, 1), s(9, 2, 0, 1),
+    s(2, 0, 11, 3), s(1, 0, 12, 1), s(1, 0, 11, 4),
+    s(13, 1, 9, 3), s(14, 1, 9, 2), s(13, 1, 9, 4), s(13, 1, 9, 1), s(13, 1, 4, 4),
+    s(12, 1, 4, 1), s(12, 1, 4, 4), s(12, 3, 4, 1),
+    s(8, 0, 7, 4),
+    s(0, 2, 12, 1), s(0, 2, 12, 3),
+    s(21, 2, 2, 5), s(21, 2, 2, 1),
+    s(11, 0, 5, 1), s(11, 0, 6, 1),
+    s(5, 1, 4, 1), s(5, 1, 1, 3),
+    s(0, 0, 5, 1), s(0, 0, 5, 3), s(0, 0, 5, 4),
+    s(6, 2, 12, 1),
+    s(19, 2, 6, 1), s(21, 2, 6, 1), s(19, 2, 6, 3),
+    s(15, 0, 3, 1), s(15, 0, 3, 4), s(15, 2, 0, 4), s(15, 0, 3, 5),
+    s(21, 0, 8, 4), s(17, 2, 2, 4), s(17, 2, 0, 4),
+    s(5, 1, 0, 2), s(15, 0, 3, 2), s(5, 1, 0, 1),
+    s(13, 1, 0, 1), s(19, 2, 0, 2), s(19, 0, 1, 1),
+    s(12, 1, 7, 1), s(12, 1, 7, 4), s(5, 0, 7, 3),
+    s(10, 0, 5, 3), s(10, 0, 5, 4), s(9, 0, 3, 2),
+    s(17, 0, 9, 1), s(20, 0, 9, 4), s(17, 0, 10, 3),
+    s(2, 1, 7, 4), s(2, 1, 7, 1),
+    s(8, 0, 8, 2), s(8, 0, 8, 3), s(8, 0, 8, 5),
+    s(9, 0, 1, 1), s(9, 0, 1, 2), s(9, 0, 1, 3),
+    s(9, 2, 0, 3), s(12, 1, 1, 3),
+    s(14, 1, 7, 1), s(12, 1, 7, 1), s(8, 0, 7, 2), s(1, 0, 7, 4), s(3, 1, 8, 4),
+    s(11, 2, 0, 1), s(11, 2, 0, 4),
+    s(0, 0, 8, 3), s(0, 0, 8, 1), s(0, 0, 8, 4), s(14, 3, 0, 1), s(16, 2, 0, 1), s(0, 0, 8, 5),
+    s(14, 3, 0, 1), s(17, 0, 0, 1),
+    s(5, 0, 3, 1), s(5, 0, 6, 1), s(5, 0, 3, 2), s(5, 0, 5, 1),
+    s(3, 0, 1, 5), s(3, 0, 1, 2),
+    s(8, 0, 6, 5), s(8, 0, 3, 1),
+    s(9, 0, 1, 1),
+    s(16, 2, 5, 4), s(19, 2, 2, 1),
+    s(8, 0, 7, 2), s(16, 0, 7, 1), s(8, 0, 7, 4), s(14, 1, 7, 1),
+    s(14, 1, 7, 4), s(16, 0, 0, 4),
+    s(16, 0, 7, 2), s(15, 0, 7, 1),
+    s(19, 2, 6, 3),
+    s(4, 2, 0, 3), s(0, 2, 0, 3),
+    s(8, 1, 7, 2), s(8, 1, 7, 4),
+    s(13, 1, 7, 2), s(13, 1, 7, 4),
+    s(16, 2, 0, 4), s(14, 3, 0, 4), s(17, 0, 8, 4),
+    s(16, 0, 9, 3), s(6, 0, 9, 1), s(16, 0, 9, 1), s(6, 2, 2, 1), s(5, 0, 9, 3),
+    s(5, 0, 9, 4), s(6, 0, 9, 2),
+    s(11, 0, 6, 1), s(3, 0, 2, 4), s(3, 2, 0, 4),
+    s(0, 0, 3, 3), s(0, 2, 0, 4), s(0, 2, 2, 4),
+    s(4, 0, 9, 1), s(1, 0, 2, 5),
+    s(19, 0, 9, 3), s(20, 0, 9, 3),
+    s(20, 2, 0, 4), s(19, 0, 1, 1), s(11, 0, 3, 2),
+    s(6, 2, 10, 1), s(10, 2, 2, 4),
+    s(20, 0, 12, 1), s(16, 0, 12, 1),
+    s(12, 1, 7, 4), s(12, 1, 7, 1), s(12, 1, 8, 1),
+    s(0, 1, 4, 1), s(0, 1, 0, 4), s(17, 0, 1, 4),
+    s(12, 3, 4, 1),
+    s(8, 2, 0, 1),
+    s(5, 0, 1, 1), s(5, 0, 1, 2),
+    s(15, 0, 9, 1), s(5, 0, 9, 1),
+    s(0, 0, 2, 1), s(0, 3, 0, 3), s(0, 3, 0, 4), s(0, 0, 7, 4),
+    s(15, 0, 8, 4), s(15, 2, 2, 2), s(15, 2, 0, 2), s(5, 2, 0, 2),
+    s(7, 2, 12, 2), s(7, 0, 11, 2),
+    s(0, 3, 4, 3), s(11, 2, 6, 4),
+    s(0, 1, 0, 1), s(0, 0, 5, 3), s(0, 1, 0, 4),
+    s(12, 1, 7, 4), s(13, 1, 7, 4), s(16, 0, 0, 1),
+    s(0, 3, 12, 1), s(0, 3, 12, 3),
+    s(12, 3, 4, 2), s(14, 3, 4, 2),
+    s(10, 2, 5, 4), s(9, 2, 5, 4), s(10, 2, 2, 4), s(0, 2, 6, 4),
+    s(2, 0, 10, 1), s(2, 0, 10, 4), s(4, 0, 10, 4),
+    s(9, 0, 1, 2), s(9, 0, 3, 2),
+    s(14, 1, 10, 1), s(11, 0, 10, 1),
+    s(8, 0, 9, 4), s(11, 0, 9, 3),
+    s(11, 2, 2, 1), s(11, 2, 2, 4), s(0, 2, 2, 4), s(0, 0, 2, 3),
+    s(11, 0, 7, 1),
+    s(14, 1, 1, 4), s(11, 0, 3, 4),
+    s(2, 1, 0, 4), s(14, 1, 0, 4), s(14, 1, 8, 4),
+    s(16, 0, 8, 2), s(15, 0, 8, 1),
+    s(12, 1, 0, 4), s(12, 1, 4, 1), s(15, 0, 5, 1),
+    s(3, 0, 3, 1),
+    s(20, 0, 1, 1), s(16, 0, 1, 1),
+    s(6, 1, 0, 4), s(15, 0, 0, 4),
+    s(8, 2, 0, 3), s(8, 2, 0, 1),
+    s(1, 0, 2, 2), s(2, 0, 7, 4), s(1, 0, 7, 4),
+    s(7, 1, 4, 4), s(0, 1, 7, 3),
+    s(11, 2, 0, 4), s(0, 1, 2, 5),
+    s(3, 0, 3, 5), s(3, 0, 6, 4), s(3, 0, 1, 5),
+    s(11, 0, 5, 5),
+    s(14, 1, 7, 1), s(0, 0, 7, 2),
+    s(14, 1, 11, 4), s(14, 1, 11, 3),
+    s(0, 1, 9, 2), s(0, 1, 9, 3),
+    s(16, 0, 9, 2), s(16, 0, 9, 1),
+    s(18, 0, 11, 3), s(18, 0, 11, 1),
+    s(8, 0, 1, 2), s(8, 0, 1, 5),
+    s(12, 3, 4, 2), s(12, 1, 7, 2), s(12, 1, 7, 4),
+    s(11, 2, 9, 1), s(11, 2, 9, 4),
+    s(7, 1, 4, 4), s(15, 0, 3, 2),
+    s(20, 0, 1, 4), s(15, 0, 1, 1), s(19, 0, 1, 3),
+    s(7, 0, 11, 2), s(7, 0, 11, 1),
+    s(19, 0, 1, 2), s(19, 0, 9, 4), s(20, 0, 9, 1),
+    s(19, 0, 10, 5),
+    s(19, 0, 1, 2), s(7, 1, 4, 4), s(0, 1, 9, 4), s(0, 0, 3, 4),
+    s(7, 0, 11, 1), s(7, 0, 11, 5),
+    s(8, 0, 2, 5),
+    s(0, 2, 6, 2), s(9, 2, 2, 2),
+    s(11, 2, 6, 2),
+    s(12, 1, 9, 3), s(7, 0, 9, 1), s(0, 3, 4, 4),
+    s(7, 0, 9, 1), s(7, 1, 4, 4),
+    s(6, 2, 9, 2), s(13, 1, 8, 2),
+    s(5, 2, 10, 4), s(6, 2, 10, 2),
+    s(20, 2, 12, 1), s(16, 2, 11, 1),
+    s(6, 0, 5, 1),
+    s(11, 2, 10, 4), s(11, 2, 9, 4),
+    s(13, 3, 9, 1), s(12, 3, 9, 1), s(12, 3, 9, 4), s(13, 3, 9, 2), s(12, 3, 9, 3),
+    s(16, 2, 9, 2), s(16, 2, 6, 2),
+    s(6, 2, 9, 2), s(16, 2, 9, 2),
+    s(11, 2, 9, 2), s(0, 3, 9, 2),
+    s(6, 2, 0, 3), s(5, 2, 0, 4), s(16, 0, 1, 3), s(6, 2, 0, 2),
+    s(6, 2, 0, 3),
+    s(17, 0, 12, 4), s(10, 2, 0, 1),
+    s(0, 2, 6, 2), s(14, 3, 0, 1), s(0, 3, 0, 2),
+    s(9, 0, 3, 1), s(0, 1, 0, 4),
+    s(0, 3, 0, 4), s(6, 2, 2, 1), s(15, 2, 10, 1),
+    s(5, 1, 0, 4), s(5, 0, 3, 5),
+    s(13, 1, 9, 1), s(21, 2, 0, 2),
+    s(15, 0, 10, 4), s(13, 3, 9, 3), s(16, 0, 8, 2), s(11, 2, 5, 2),
+    s(16, 0, 11, 3), s(16, 0, 11, 2),
+    s(13, 1, 0, 2), s(0, 1, 10, 2),
+    s(12, 1, 0, 1), s(12, 1, 0, 2), s(12, 1, 4, 2),
+    s(3, 0, 6, 2), s(4, 0, 10, 2),
+    s(12, 3, 10, 1), s(0, 3, 10, 4),
+    s(18, 2, 12, 3), s(10, 0, 12, 1),
+    s(6, 2, 10, 2), s(5, 2, 10, 4),
+    s(4, 0, 11, 1), s(4, 0, 11, 2),
+    s(1, 0, 10, 4), s(4, 0, 10, 4),
+    s(10, 0, 9, 3), s(10, 0, 9, 4),
+    s(11, 2, 5, 4), s(2, 1, 0, 1), s(2, 0, 6, 2),
+    s(10, 0, 12, 1), s(10, 0, 11, 4),
+    s(12, 1, 12, 3), s(14, 1, 12, 2),
+    s(5, 1, 0, 4), s(8, 0, 9, 4),
+    s(10, 2, 5, 4), s(0, 3, 4, 2),
+    s(15, 2, 6, 4),
+    s(1, 0, 9, 4), s(2, 0, 9, 3), s(2, 0, 9, 4),
+    s(13, 3, 0, 1), s(12, 3, 0, 4),
+    s(6, 2, 2, 2), s(0, 1, 0, 2),
+    s(5, 1, 9, 4), s(15, 0, 10, 1),
+    s(9, 2, 1, 4), s(0, 2, 1, 1),
+    s(2, 1, 0, 1), s(11, 2, 5, 4),
+    s(12, 3, 12, 1),
+    s(0, 0, 7, 4), s(0, 0, 7, 1), s(0, 1, 8, 3),
+    s(3, 2, 0, 3), s(3, 2, 0, 4), s(3, 0, 6, 2),
+    s(10, 0, 3, 3), s(10, 0, 3, 1), s(12, 3, 12, 1),
+    s(16, 0, 0, 2), s(5, 1, 0, 3),
+    s(8, 0, 1, 1), s(8, 0, 1, 5),
+    s(3, 1, 10, 5),
+    s(5, 2, 12, 4), s(6, 2, 12, 2), s(6, 2, 12, 3),
+    s(12, 1, 0, 4), s(12, 1, 0, 1),
+    s(0, 1, 10, 2), s(10, 0, 10, 4),
+    s(10, 0, 10, 3), s(0, 1, 10, 2),
+    s(17, 0, 11, 3), s(12, 3, 12, 1),
+    s(0, 0, 3, 4), s(17, 0, 12, 4),
+    s(0, 0, 9, 3), s(0, 0, 9, 1),
+    s(0, 3, 9, 4), s(11, 2, 9, 2),
+    s(4, 2, 0, 1), s(4, 2, 0, 2), s(4, 0, 8, 2), s(2, 0, 6, 1), s(2, 0, 8, 2),
+    s(1, 0, 1, 4), s(1, 0, 6, 4),
+    s(11, 0, 9, 4), s(0, 0, 9, 4),
+    s(0, 0, 5, 1), s(15, 0, 0, 4),
+    s(1, 0, 12, 3), s(4, 0, 12, 1),
+    s(4, 0, 11, 2), s(4, 0, 11, 1), s(5, 1, 0, 4),
+    s(13, 3, 4, 4), s(12, 3, 4, 2),
+    s(12, 1, 1, 1), s(14, 1, 1, 2),
+    s(3, 0, 5, 2), s(3, 0, 9, 2),
+    s(17, 0, 9, 1), s(0, 1, 9, 2),
+    s(2, 2, 0, 3), s(1, 2, 0, 4),
+    s(7, 1, 9, 4), s(5, 1, 9, 4), s(7, 1, 4, 4),
+    s(0, 1, 1, 1), s(0, 0, 3, 4), s(0, 2, 0, 3), s(0, 1, 1, 4),
+    s(13, 3, 9, 2), s(12, 3, 9, 3),
+    s(21, 2, 6, 4), s(21, 2, 0, 4),
+    s(2, 1, 0, 2), s(2, 1, 0, 4), s(1, 1, 0, 4), s(1, 0, 6, 1),
+    s(13, 1, 12, 1), s(15, 0, 12, 1),
+    s(0, 2, 9, 3), s(0, 2, 9, 1),
+    s(8, 2, 10, 3), s(8, 2, 10, 4),
+    s(15, 0, 12, 1), s(16, 0, 12, 2),
+    s(16, 2, 12, 3), s(6, 0, 11, 3), s(17, 0, 11, 3),
+    s(20, 0, 5, 4), s(20, 0, 5, 3),
+    s(16, 2, 0, 4), s(6, 0, 8, 4),
+    s(1, 0, 12, 3), s(1, 0, 11, 4),
+    s(10, 0, 9, 3), s(14, 1, 9, 4),
+    s(0, 1, 0, 4), s(17, 0, 0, 4),
+    s(2, 0, 6, 2), s(2, 0, 8, 3), s(2, 1, 0, 1),
+    s(15, 2, 10, 3), s(5, 2, 6, 1), s(9, 2, 2, 2),
+    s(21, 0, 7, 4), s(21, 0, 7, 3),
+    s(7, 1, 0, 2), s(7, 1, 0, 4), s(1, 0, 9, 4),
+    s(5, 2, 6, 1), s(19, 2, 6, 1),
+    s(12, 1, 10, 3), s(13, 1, 10, 2), s(12, 1, 10, 4),
+    s(0, 0, 3, 4), s(0, 1, 1, 4),
+    s(2, 0, 12, 2), s(1, 0, 12, 4), s(2, 0, 12, 1), s(2, 1, 12, 1),
+    s(8, 0, 12, 4),
+    s(4, 0, 11, 5),
+    s(5, 2, 2, 4), s(11, 2, 6, 1),
+    s(9, 2, 2, 1), s(0, 2, 2, 1),
+    s(1, 0, 7, 3), s(1, 2, 0, 3), s(2, 2, 0, 4),
+    s(5, 1, 0, 1), s(6, 1, 0, 2), s(5, 1, 0, 3), s(17, 0, 0, 2), s(0, 2, 6, 2),
+    s(3, 0, 7, 2), s(3, 0, 8, 2), s(0, 2, 0, 3),
+    s(18, 2, 9, 2), s(7, 2, 2, 4),
+    s(0, 1, 4, 4), s(0, 0, 3, 4), s(0, 0, 5, 4),
+    s(10, 0, 9, 1), s(16, 0, 10, 3),
+    s(1, 0, 7, 4), s(4, 2, 0, 4),
+    s(20, 0, 0, 2), s(12, 1, 0, 2),
+    s(16, 0, 11, 3), s(16, 0, 11, 2), s(17, 0, 11, 1), s(5, 0, 11, 4),
+    s(5, 2, 0, 3), s(15, 0, 3, 3), s(5, 2, 0, 1),
+    s(0, 1, 10, 4), s(2, 0, 8, 3),
+    s(11, 0, 12, 4),
+    s(12, 1, 9, 3), s(10, 0, 9, 3), s(14, 1, 9, 2),
+    s(14, 1, 11, 4), s(12, 1, 11, 3),
+    s(12, 1, 0, 4), s(14, 1, 0, 4),
+    s(6, 0, 1, 1), s(5, 0, 1, 1),
+    s(6, 0, 1, 3), s(5, 0, 1, 1), s(5, 0, 1, 5),
+    s(6, 2, 0, 2), s(5, 2, 0, 4),
+    s(13, 3, 4, 4), s(13, 1, 7, 1),
+    s(15, 2, 12, 3), s(2, 0, 12, 2),
+    s(21, 0, 5, 1), s(21, 0, 5, 4), s(21, 0, 3, 4),
+    s(19, 0, 11, 4),
+    s(0, 2, 0, 4), s(0, 2, 0, 3),
+    s(6, 1, 9, 2), s(6, 1, 9, 3), s(16, 0, 10, 2), s(15, 0, 10, 4),
+    s(11, 0, 5, 3),
+    s(16, 0, 11, 2), s(17, 0, 11, 1), s(16, 0, 11, 3),
+    s(13, 1, 9, 4), s(12, 1, 9, 4),
+    s(15, 2, 9, 1), s(6, 2, 9, 2),
+    s(17, 2, 0, 4), s(0, 1, 4, 3),
+    s(16, 0, 10, 3),
+    s(15, 0, 0, 2), s(15, 2, 2, 2),
+    s(12, 1, 10, 4), s(13, 1, 10, 2),
+    s(13, 1, 7, 1), s(13, 1, 7, 4),
+    s(19, 0, 12, 1), s(19, 0, 12, 4), s(20, 0, 12, 2),
+    s(17, 0, 9, 4), s(16, 0, 9, 3),
+    s(1, 0, 1, 2), s(4, 0, 6, 4),
+    s(10, 2, 5, 4), s(6, 2, 6, 2),
+    s(5, 2, 12, 3), s(6, 2, 9, 3),
+    s(4, 0, 9, 2), s(4, 0, 9, 1),
+    s(13, 3, 4, 4), s(13, 1, 7, 2),
+    s(3, 0, 2, 4), s(3, 0, 6, 4),
+    s(19, 2, 10, 1), s(20, 2, 10, 1),
+    s(5, 2, 2, 4), s(11, 2, 6, 1), s(11, 2, 6, 4),
+    s(5, 0, 12, 4), s(5, 0, 12, 1),
+    s(3, 2, 0, 2), s(0, 2, 0, 2),
+    s(4, 0, 10, 2), s(4, 0, 10, 4),
+    s(13, 1, 7, 1), s(13, 1, 7, 4), s(0, 0, 7, 2),
+    s(0, 1, 0, 4), s(6, 2, 0, 2),
+    s(0, 3, 12, 1), s(0, 2, 12, 4),
+    s(14, 3, 4, 2), s(12, 3, 4, 2), s(1, 0, 2, 2),
+    s(6, 0, 9, 2), s(17, 0, 9, 4), s(5, 0, 9, 4), s(6, 0, 9, 3),
+    s(11, 2, 5, 4),
+    s(14, 3, 10, 1), s(14, 3, 10, 4),
+    s(14, 1, 9, 4), s(8, 0, 9, 4),
+    s(11, 0, 3, 4), s(11, 2, 2, 4),
+    s(18, 2, 9, 2),
+    s(8, 0, 6, 3), s(8, 0, 6, 2), s(8, 3, 0, 4),
+    s(10, 2, 11, 4), s(10, 2, 11, 3),
+    s(11, 2, 5, 4), s(11, 2, 6, 4), s(11, 2, 5, 2),
+    s(21, 0, 9, 5),
+    s(15, 2, 11, 4),
+    s(15, 2, 11, 4), s(15, 2, 11, 1),
+    s(17, 0, 12, 1), s(13, 1, 12, 4),
+    s(10, 0, 3, 2), s(13, 1, 7, 4),
+    s(0, 1, 0, 1), s(0, 1, 10, 1),
+    s(19, 2, 10, 1),
+    s(15, 0, 0, 3), s(15, 2, 12, 1),
+    s(9, 2, 0, 3), s(0, 1, 12, 2),
+    s(16, 2, 0, 4), s(16, 2, 0, 3),
+    s(4, 0, 12, 2), s(2, 0, 11, 2),
+    s(14, 1, 1, 4), s(12, 1, 1, 3),
+    s(14, 3, 12, 4), s(14, 3, 9, 4),
+    s(0, 2, 5, 4),
+    s(0, 3, 9, 4), s(0, 2, 9, 3), s(0, 2, 9, 1), s(0, 3, 9, 1),
+    s(3, 0, 7, 3), s(0, 2, 9, 1),
+    s(3, 0, 12, 4), s(3, 0, 12, 2),
+    s(11, 2, 2, 3),
+    s(5, 0, 1, 4), s(5, 0, 5, 4), s(6, 0, 5, 4),
+    s(6, 0, 5, 4), s(6, 0, 1, 1),
+    s(4, 2, 0, 1), s(4, 2, 0, 2),
+    s(0, 1, 7, 1), s(0, 2, 2, 4), s(0, 2, 5, 1),
+    s(0, 1, 11, 1), s(0, 1, 12, 1),
+    s(11, 0, 11, 1), s(1, 0, 10, 4),
+    s(17, 0, 0, 1), s(0, 1, 0, 4),
+    s(6, 0, 7, 1), s(1, 0, 10, 3),
+    s(6, 0, 8, 2), s(6, 0, 8, 5),
+    s(0, 1, 9, 3), s(6, 0, 7, 1),
+    s(10, 2, 1, 1), s(10, 2, 1, 4), s(10, 2, 1, 3),
+    s(12, 1, 1, 1), s(9, 0, 1, 1), s(12, 1, 1, 2),
+    s(12, 1, 1, 1), s(12, 1, 1, 2), s(14, 1, 4, 2), s(14, 1, 1, 2), s(9, 0, 1, 1),
+    s(0, 0, 10, 1),
+    s(5, 1, 0, 1), s(6, 1, 0, 4),
+    s(13, 1, 0, 2), s(12, 1, 0, 1), s(0, 0, 5, 3), s(0, 1, 0, 3),
+    s(14, 1, 4, 2), s(8, 1, 4, 4), s(14, 1, 0, 3), s(2, 1, 0, 2),
+    s(4, 0, 10, 4), s(10, 0, 11, 3),
+    s(10, 2, 6, 2), s(10, 2, 6, 3),
+    s(19, 0, 8, 4), s(20, 0, 8, 4),
+    s(13, 1, 0, 4), s(14, 1, 4, 4), s(13, 1, 4, 4), s(12, 1, 4, 2),
+    s(15, 0, 1, 1), s(17, 0, 3, 1), s(16, 0, 0, 3), s(15, 0, 1, 4),
+    s(1, 0, 10, 1), s(1, 0, 10, 4), s(4, 0, 10, 4),
+    s(6, 0, 7, 4), s(6, 0, 7, 3),
+    s(19, 0, 11, 4), s(15, 2, 11, 3),
+    s(4, 0, 6, 3), s(4, 0, 6, 1),
+    s(1, 0, 12, 1), s(10, 0, 12, 1),
+    s(5, 1, 9, 4), s(6, 1, 12, 2), s(5, 1, 12, 4), s(15, 0, 12, 4), s(19, 2, 10, 1),
+    s(0, 0, 7, 4), s(14, 1, 7, 4),
+    s(17, 0, 3, 1),
+    s(11, 0, 1, 3), s(2, 0, 2, 4), s(6, 0, 5, 3),
+    s(0, 0, 7, 4), s(0, 3, 0, 4), s(0, 1, 8, 1),
+    s(5, 2, 2, 2), s(5, 2, 6, 4),
+    s(5, 2, 2, 3), s(16, 0, 3, 3),
+    s(7, 3, 0, 3), s(7, 3, 0, 4), s(18, 2, 0, 3),
+    s(7, 2, 0, 2),
+    s(5, 1, 12, 3), s(5, 1, 12, 1), s(6, 1, 9, 3),
+    s(12, 1, 9, 1), s(9, 0, 9, 1),
+    s(6, 0, 1, 1), s(12, 1, 4, 3), s(16, 0, 0, 2),
+    s(7, 2, 9, 2),
+    s(11, 0, 7, 3), s(11, 0, 7, 4),
+    s(17, 2, 2, 4), s(0, 3, 4, 1),
+    s(4, 0, 6, 1), s(2, 0, 6, 4),
+    s(0, 2, 11, 4), s(0, 2, 11, 2),
+    s(15, 2, 11, 1),
+    s(3, 0, 1, 1),
+    s(18, 0, 10, 4), s(18, 0, 10, 2),
+    s(4, 2, 0, 1), s(0, 1, 8, 1),
+    s(11, 0, 5, 4), s(12, 1, 4, 4),
+    s(10, 0, 12, 1), s(11, 0, 11, 2),
+    s(0, 1, 7, 1), s(12, 1, 7, 3),
+    s(12, 1, 10, 4), s(14, 1, 9, 1),
+    s(3, 1, 7, 4), s(3, 1, 7, 3),
+    s(4, 0, 8, 3), s(2, 0, 6, 1), s(2, 1, 0, 1),
+    s(0, 3, 4, 4), s(12, 3, 4, 2),
+    s(7, 1, 8, 1), s(11, 0, 7, 4),
+    s(7, 0, 1, 4), s(7, 0, 9, 4),
+    s(0, 2, 9, 4), s(0, 3, 9, 2),
+    s(4, 0, 11, 2), s(4, 0, 11, 1),
+    s(1, 0, 1, 2), s(1, 0, 2, 1),
+    s(7, 1, 0, 1), s(7, 1, 0, 2),
+    s(15, 0, 8, 2), s(16, 0, 8, 1),
+    s(7, 1, 0, 3), s(7, 0, 5, 3),
+    s(14, 1, 9, 2), s(14, 3, 9, 2), s(14, 3, 0, 4),
+    s(15, 0, 0, 2), s(0, 1, 0, 4),
+    s(0, 0, 3, 1), s(0, 0, 3, 3),
+    s(13, 1, 0, 1), s(13, 1, 0, 4),
+    s(17, 0, 9, 1), s(14, 1, 9, 1), s(2, 0, 9, 1),
+    s(12, 1, 4, 3), s(12, 3, 0, 4), s(14, 3, 0, 4), s(19, 2, 0, 1),
+    s(14, 1, 12, 4), s(17, 0, 12, 1),
+    s(0, 2, 6, 3), s(0, 2, 6, 1), s(0, 2, 6, 4),
+    s(19, 0, 0, 1), s(20, 0, 0, 3), s(20, 0, 0, 1),
+    s(2, 1, 10, 1), s(2, 1, 10, 2),
+    s(0, 1, 7, 2), s(6, 1, 7, 4), s(6, 0, 7, 2), s(0, 1, 7, 4),
+    s(11, 2, 1, 2), s(11, 2, 2, 2),
+    s(12, 1, 7, 1), s(12, 1, 7, 3), s(14, 1, 7, 2),
+    s(8, 0, 7, 3), s(3, 2, 0, 3),
+    s(7, 1, 9, 4), s(7, 1, 9, 2),
+    s(12, 1, 0, 1), s(15, 0, 10, 3),
+    s(12, 1, 0, 1), s(0, 1, 0, 2),
+    s(12, 3, 10, 1), s(14, 3, 10, 1), s(14, 3, 9, 4), s(14, 1, 10, 1),
+    s(10, 2, 1, 1), s(11, 2, 0, 4),
+    s(17, 0, 10, 1), s(14, 1, 9, 1),
+    s(17, 0, 0, 2), s(6, 1, 0, 2), s(12, 1, 0, 4),
+    s(9, 2, 6, 3), s(0, 2, 1, 2),
+    s(19, 0, 0, 1), s(19, 0, 0, 4),
+    s(0, 2, 1, 2), s(0, 2, 1, 1), s(9, 2, 6, 4),
+    s(18, 0, 7, 2), s(18, 0, 7, 3),
+    s(2, 1, 12, 1), s(2, 1, 10, 4),
+    s(14, 1, 9, 4), s(5, 0, 9, 1),
+    s(17, 0, 7, 4), s(17, 0, 7, 1),
+    s(21, 2, 2, 1), s(21, 2, 2, 3), s(21, 2, 2, 4),
+    s(3, 0, 6, 2), s(3, 2, 0, 3), s(0, 2, 0, 3),
+    s(16, 2, 2, 4), s(20, 2, 0, 4), s(8, 0, 5, 4),
+    s(16, 2, 2, 4), s(20, 2, 0, 4),
+    s(6, 1, 12, 3), s(6, 1, 9, 3),
+    s(7, 1, 11, 2),
+    s(7, 0, 1, 4), s(7, 2, 2, 2),
+    s(2, 0, 8, 1), s(1, 1, 0, 3),
+    s(7, 0, 6, 3), s(21, 2, 6, 1),
+    s(3, 1, 9, 3), s(0, 2, 9, 3), s(0, 2, 10, 4),
+    s(0, 2, 0, 2), s(0, 2, 0, 4), s(0, 3, 0, 2),
+    s(13, 3, 0, 3), s(12, 3, 0, 1), s(17, 2, 0, 1),
+    s(15, 2, 6, 4), s(17, 2, 6, 4),
+    s(0, 0, 5, 3), s(0, 0, 5, 2), s(0, 0, 3, 4),
+    s(8, 0, 8, 2), s(8, 3, 0, 3), s(8, 3, 0, 2), s(8, 0, 6, 2),
+    s(2, 0, 8, 3), s(2, 0, 6, 2), s(1, 2, 0, 4),
+    s(4, 0, 9, 4), s(4, 2, 0, 4),
+    s(18, 2, 6, 2), s(0, 2, 2, 3), s(7, 0, 6, 3),
+    s(13, 3, 9, 2), s(12, 3, 9, 4),
+    s(13, 1, 9, 1), s(12, 1, 10, 3),
+    s(0, 2, 9, 1), s(9, 2, 9, 4),
+    s(16, 0, 8, 1), s(15, 0, 8, 1),
+    s(7, 0, 7, 4), s(16, 2, 2, 4),
+    s(0, 0, 9, 4), s(7, 3, 4, 4),
+    s(8, 0, 9, 2), s(8, 0, 9, 3),
+    s(10, 2, 10, 1), s(11, 2, 10, 4),
+    s(0, 1, 1, 4), s(0, 1, 1, 1), s(0, 1, 1, 3),
+    s(5, 0, 11, 4), s(0, 1, 11, 2),
+    s(0, 2, 0, 4), s(3, 0, 8, 2), s(3, 2, 0, 4),
+    s(16, 2, 2, 4), s(18, 2, 2, 4),
+    s(5, 1, 0, 4), s(6, 1, 0, 2),
+    s(18, 2, 9, 3), s(7, 0, 10, 4), s(7, 2, 10, 4),
+    s(12, 1, 4, 3),
+    s(3, 0, 6, 2), s(3, 0, 6, 4),
+    s(3, 1, 9, 2), s(3, 1, 9, 3),
+    s(0, 0, 9, 1), s(0, 1, 9, 3), s(0, 0, 3, 4),
+    s(17, 0, 0, 4), s(6, 1, 0, 2), s(15, 0, 0, 1), s(5, 0, 5, 4),
+    s(6, 2, 2, 3), s(5, 2, 2, 4), s(7, 2, 2, 3),
+    s(0, 0, 7, 3), s(0, 3, 10, 3), s(0, 2, 2, 4),
+    s(6, 0, 8, 1), s(0, 3, 0, 2),
+    s(9, 2, 6, 1), s(9, 2, 6, 4),
+    s(16, 2, 0, 2), s(19, 0, 8, 4),
+    s(2, 1, 0, 4), s(1, 1, 0, 1), s(2, 1, 0, 2),
+    s(0, 1, 12, 4), s(17, 0, 12, 4),
+    s(10, 2, 6, 4), s(16, 0, 8, 3),
+    s(13, 1, 10, 2), s(17, 0, 10, 1),
+    s(12, 1, 1, 4),
+    s(12, 1, 4, 1), s(21, 2, 2, 3),
+    s(3, 1, 12, 2), s(3, 1, 12, 3), s(3, 0, 12, 2),
+    s(0, 1, 12, 1), s(14, 1, 12, 1), s(0, 1, 12, 2),
+    s(2, 0, 11, 2), s(1, 0, 11, 4),
+    s(2, 1, 7, 2), s(2, 1, 7, 4), s(1, 1, 7, 1),
+    s(0, 3, 0, 4), s(0, 3, 0, 3), s(10, 0, 8, 1),
+    s(3, 0, 9, 1), s(3, 0, 9, 4), s(0, 3, 9, 1),
+    s(9, 2, 6, 1), s(19, 2, 6, 1),
+    s(19, 0, 3, 2), s(20, 0, 3, 4),
+    s(11, 0, 9, 1), s(7, 0, 9, 3),
+    s(8, 0, 7, 4), s(8, 0, 7, 2),
+    s(2, 1, 4, 4),
+    s(13, 1, 7, 2), s(12, 1, 7, 1),
+    s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 1, 7, 4), s(8, 0, 7, 3),
+    s(18, 0, 7, 2), s(18, 0, 7, 3), s(0, 1, 7, 3),
+    s(14, 1, 0, 1), s(14, 1, 0, 3),
+    s(14, 1, 9, 1), s(0, 1, 9, 3), s(12, 1, 10, 4),
+    s(17, 0, 9, 4), s(16, 0, 9, 2),
+    s(11, 2, 9, 2), s(14, 3, 9, 1), s(13, 3, 12, 2), s(14, 3, 9, 2),
+    s(0, 1, 0, 4), s(0, 1, 0, 3),
+    s(6, 1, 7, 3), s(5, 1, 7, 4),
+    s(18, 2, 0, 2), s(7, 0, 8, 4),
+    s(7, 0, 5, 3), s(0, 0, 13, 3), s(7, 1, 0, 4),
+    s(0, 1, 9, 1), s(0, 1, 9, 4),
+    s(3, 0, 1, 1), s(3, 0, 2, 2),
+    s(14, 1, 12, 4), s(14, 1, 12, 1),
+    s(7, 1, 11, 2), s(18, 0, 11, 2),
+    s(13, 1, 9, 1), s(14, 1, 9, 1),
+    s(8, 2, 9, 2), s(8, 1, 9, 4), s(8, 2, 9, 3),
+    s(15, 2, 0, 2), s(17, 2, 0, 2), s(16, 2, 2, 4),
+    s(19, 0, 0, 5), s(19, 0, 0, 3),
+    s(3, 0, 1, 1), s(19, 0, 0, 1),
+    s(20, 2, 10, 2),
+    s(21, 2, 10, 1),
+    s(1, 0, 6, 4), s(1, 0, 2, 2),
+    s(15, 2, 9, 3), s(7, 1, 0, 4),
+    s(21, 2, 10, 1), s(14, 3, 10, 4),
+    s(7, 0, 7, 1),
+    s(20, 0, 9, 4), s(16, 0, 9, 2), s(12, 1, 9, 1), s(15, 0, 9, 4),
+    s(7, 1, 0, 3), s(7, 1, 0, 4), s(0, 1, 0, 4),
+    s(14, 3, 4, 2), s(11, 2, 1, 2), s(12, 1, 7, 4),
+    s(1, 0, 2, 4),
+    s(7, 1, 12, 2), s(7, 1, 12, 4), s(15, 2, 0, 4),
+    s(6, 0, 1, 1), s(6, 2, 2, 2), s(0, 1, 0, 2),
+    s(15, 0, 5, 2), s(16, 0, 3, 4), s(5, 2, 0, 4),
+    s(17, 0, 8, 3), s(17, 0, 8, 4),
+    s(6, 2, 0, 1), s(12, 1, 1, 1),
+    s(0, 2, 9, 2), s(10, 2, 9, 1),
+    s(18, 0, 8, 4),
+    s(0, 1, 7, 3), s(0, 1, 7, 1),
+    s(3, 1, 0, 4), s(4, 2, 0, 2),
+    s(0, 2, 9, 3), s(0, 3, 9, 1), s(0, 3, 10, 3), s(0, 3, 0, 4),
+    s(16, 2, 12, 3),
+    s(10, 0, 3, 4), s(13, 1, 1, 4),
+    s(14, 1, 9, 4), s(14, 3, 12, 4),
+    s(1, 0, 7, 3), s(17, 0, 0, 2),
+    s(11, 0, 5, 4), s(11, 0, 3, 2),
+    s(12, 1, 1, 1), s(12, 1, 1, 5), s(12, 1, 1, 4), s(12, 1, 4, 5), s(9, 2, 0, 1),
+    s(18, 2, 12, 2), s(0, 3, 12, 3),
+    s(10, 2, 9, 1),
+    s(21, 2, 0, 4), s(14, 1, 8, 3), s(14, 1, 8, 4), s(13, 1, 0, 1),
+    s(20, 0, 5, 3), s(20, 0, 5, 4),
+    s(15, 0, 0, 4), s(6, 1, 9, 2),
+    s(16, 0, 1, 2), s(20, 2, 6, 4),
+    s(12, 3, 0, 4), s(8, 3, 0, 4), s(8, 0, 8, 2),
+    s(7, 1, 12, 2), s(7, 1, 12, 4),
+    s(15, 0, 5, 4), s(21, 0, 3, 4), s(13, 1, 9, 1),
+    s(17, 0, 10, 3), s(2, 0, 9, 2),
+    s(0, 2, 6, 3), s(0, 2, 6, 2),
+    s(11, 2, 9, 2), s(14, 1, 9, 4),
+    s(13, 1, 10, 1), s(13, 1, 10, 4),
+    s(16, 2, 12, 3), s(8, 2, 12, 2),
+    s(20, 2, 10, 4), s(20, 2, 10, 3),
+    s(14, 3, 10, 2), s(14, 1, 10, 2),
+    s(8, 3, 0, 4), s(8, 3, 4, 4),
+    s(4, 0, 12, 1), s(1, 1, 9, 3),
+    s(4, 2, 0, 1), s(1, 2, 0, 4), s(4, 2, 0, 3), s(2, 0, 2, 4),
+    s(17, 0, 3, 4), s(0, 1, 4, 4), s(0, 1, 0, 4),
+    s(12, 1, 11, 1), s(12, 1, 11, 4), s(13, 1, 11, 1),
+    s(12, 1, 11, 1), s(12, 1, 11, 4), s(13, 1, 11, 1), s(0, 1, 11, 2), s(12, 1, 11, 3),
+    s(15, 2, 9, 1), s(6, 2, 9, 2), s(17, 2, 9, 4),
+    s(0, 2, 6, 4), s(0, 3, 0, 4), s(0, 3, 10, 4),
+    s(17, 2, 0, 4), s(15, 2, 0, 4),
+    s(5, 0, 7, 3), s(5, 0, 7, 4),
+    s(17, 0, 7, 3), s(17, 0, 7, 4),
+    s(9, 0, 1, 3),
+    s(17, 2, 0, 1), s(17, 2, 0, 2),
+    s(17, 0, 11, 4), s(16, 0, 11, 2),
+    s(9, 0, 1, 2),
+    s(8, 1, 7, 4), s(8, 1, 7, 2),
+    s(10, 2, 10, 5),
+    s(0, 1, 8, 2), s(0, 2, 11, 1),
+    s(0, 2, 11, 1),
+    s(8, 1, 7, 4), s(7, 1, 7, 3),
+    s(3, 0, 11, 2), s(3, 0, 12, 2), s(2, 0, 11, 2),
+    s(9, 0, 1, 4),
+    s(10, 2, 6, 4), s(10, 2, 6, 3),
+    s(6, 2, 6, 2), s(15, 2, 5, 4),
+    s(0, 1, 10, 3), s(0, 3, 10, 2),
+    s(16, 0, 0, 3), s(16, 0, 3, 3),
+    s(0, 2, 6, 3), s(0, 1, 0, 3),
+    s(7, 1, 7, 4), s(21, 2, 6, 1),
+    s(12, 3, 0, 1), s(12, 1, 0, 1),
+    s(13, 3, 0, 1), s(12, 3, 4, 2), s(13, 3, 4, 4), s(12, 3, 0, 2),
+    s(17, 0, 0, 1), s(17, 0, 0, 4),
+    s(17, 0, 0, 3), s(14, 1, 0, 1),
+    s(2, 1, 12, 2), s(1, 1, 12, 3), s(1, 1, 12, 4), s(1, 1, 12, 1),
+    s(17, 2, 0, 3), s(15, 2, 0, 3),
+    s(14, 1, 4, 4), s(6, 1, 0, 4),
+    s(16, 0, 3, 4), s(20, 0, 7, 3),
+    s(6, 2, 10, 2), s(15, 2, 10, 1),
+    s(7, 1, 0, 4), s(2, 0, 2, 4), s(12, 1, 0, 2),
+    s(0, 0, 3, 4), s(0, 1, 9, 3),
+    s(8, 2, 12, 2), s(11, 2, 12, 2),
+    s(0, 1, 0, 4), s(9, 0, 3, 1),
+    s(13, 1, 0, 3), s(10, 0, 5, 3),
+    s(0, 1, 1, 2), s(14, 1, 1, 1),
+    s(0, 0, 3, 4), s(12, 1, 0, 2),
+    s(4, 0, 10, 2), s(16, 0, 1, 4),
+    s(9, 0, 11, 3), s(9, 0, 11, 1),
+    s(13, 3, 0, 1), s(12, 3, 0, 1), s(19, 2, 0, 3), s(12, 3, 0, 3),
+    s(12, 1, 1, 3), s(12, 1, 1, 2),
+    s(8, 1, 12, 3), s(8, 1, 12, 2),
+    s(1, 0, 1, 1), s(10, 0, 3, 4),
+    s(4, 2, 0, 4), s(7, 1, 4, 4),
+    s(5, 2, 12, 4), s(6, 2, 12, 2),
+    s(15, 0, 0, 4), s(17, 0, 0, 4),
+    s(8, 1, 0, 3), s(8, 1, 4, 4),
+    s(13, 1, 1, 3),
+    s(12, 1, 7, 4), s(13, 1, 7, 2),
+    s(1, 2, 0, 1),
+    s(0, 3, 0, 3), s(0, 2, 0, 2),
+    s(13, 1, 0, 2), s(13, 1, 0, 3), s(0, 1, 0, 1),
+    s(19, 2, 0, 2), s(20, 2, 6, 4),
+    s(8, 0, 12, 2), s(8, 1, 12, 2),
+    s(12, 3, 4, 2), s(0, 3, 0, 4),
+    s(5, 2, 2, 1), s(5, 2, 2, 3),
+    s(14, 1, 7, 2), s(0, 1, 7, 2),
+    s(2, 1, 0, 2), s(1, 1, 0, 3),
+    s(10, 2, 0, 1), s(12, 3, 4, 2),
+    s(0, 2, 5, 3), s(0, 2, 6, 1), s(0, 2, 6, 3),
+    s(0, 1, 11, 2), s(5, 0, 11, 4),
+    s(17, 0, 0, 4), s(5, 1, 4, 2),
+    s(0, 1, 7, 3), s(0, 1, 7, 4),
+    s(10, 0, 9, 1), s(15, 0, 9, 4),
+    s(12, 1, 0, 1), s(14, 1, 0, 2),
+    s(17, 0, 12, 4), s(16, 0, 12, 2),
+    s(13, 1, 9, 4), s(11, 0, 9, 3), s(10, 0, 9, 4),
+    s(0, 2, 0, 4), s(3, 0, 7, 2),
+    s(10, 0, 3, 3), s(12, 1, 4, 2),
+    s(0, 1, 9, 2), s(7, 1, 4, 4),
+    s(5, 0, 11, 4), s(6, 0, 11, 2),
+    s(18, 2, 12, 2), s(0, 1, 12, 2),
+    s(10, 0, 5, 3), s(0, 0, 5, 2),
+    s(13, 1, 7, 1), s(10, 0, 7, 1),
+    s(20, 2, 2, 2), s(20, 0, 0, 1),
+    s(0, 3, 12, 3), s(0, 3, 12, 2),
+    s(12, 1, 0, 3), s(12, 1, 0, 2),
+    s(8, 1, 7, 2), s(12, 1, 7, 1),
+    s(5, 1, 4, 2), s(5, 1, 0, 4),
+    s(20, 0, 10, 1), s(20, 0, 9, 1),
+    s(15, 0, 9, 3), s(16, 0, 9, 2),
+    s(0, 0, 7, 2), s(0, 0, 7, 4),
+    s(20, 2, 6, 1), s(19, 2, 6, 3),
+    s(2, 0, 6, 4), s(2, 1, 0, 3),
+    s(20, 0, 12, 2), s(15, 0, 12, 1),
+    s(8, 1, 10, 2), s(8, 1, 10, 3),
+    s(15, 0, 9, 1), s(17, 0, 9, 4),
+    s(0, 1, 0, 2), s(0, 1, 0, 3),
+    s(14, 1, 9, 3), s(0, 1, 9, 3),
+    s(14, 1, 4, 4), s(12, 1, 4, 4),
+    s(0, 1, 0, 2), s(7, 1, 0, 4),
+    s(8, 1, 12, 3),
+    s(0, 3, 0, 3), s(14, 3, 0, 4),
+    s(18, 2, 0, 5),
+    s(9, 2, 6, 1), s(14, 1, 0, 1), s(12, 3, 9, 4),
+    s(18, 2, 12, 2), s(11, 2, 12, 1), s(0, 1, 12, 2),
+    s(10, 2, 6, 1), s(10, 2, 6, 4), s(0, 2, 6, 3),
+    s(10, 2, 6, 2), s(7, 0, 7, 2),
+    s(16, 2, 9, 1), s(17, 2, 10, 4),
+    s(10, 2, 5, 4), s(11, 2, 9, 1),
+    s(12, 1, 12, 1), s(14, 1, 12, 2),
+    s(14, 3, 10, 2), s(0, 1, 9, 2), s(17, 2, 10, 4),
+    s(16, 0, 7, 2), s(16, 0, 7, 4),
+    s(16, 0, 7, 2),
+    s(12, 3, 0, 4), s(13, 3, 0, 2),
+    s(2, 2, 0, 5),
+    s(16, 0, 1, 4), s(16, 0, 1, 1), s(16, 0, 5, 1), s(20, 0, 0, 1), s(16, 0, 5, 4), s(20, 2, 2, 1), s(12, 1, 4, 1),
+    s(12, 1, 0, 3), s(13, 1, 0, 3),
+    s(21, 0, 0, 4), s(0, 1, 0, 3),
+    s(14, 1, 11, 4), s(11, 0, 11, 4),
+    s(14, 3, 10, 4), s(15, 2, 9, 4),
+    s(4, 2, 0, 2), s(2, 0, 2, 2),
+    s(1, 1, 0, 4), s(0, 1, 10, 4),
+    s(17, 0, 0, 4), s(4, 2, 0, 2),
+    s(4, 0, 9, 1), s(4, 0, 9, 2), s(4, 0, 9, 4),
+    s(2, 0, 1, 4), s(2, 0, 1, 1),
+    s(6, 0, 11, 3), s(7, 2, 0, 2),
+    s(3, 0, 2, 4), s(0, 2, 1, 4),
+    s(2, 0, 6, 4), s(2, 1, 0, 1),
+    s(2, 0, 1, 4), s(3, 0, 2, 4),
+    s(6, 1, 4, 1), s(6, 1, 4, 3), s(6, 1, 4, 4),
+    s(1, 0, 2, 1), s(4, 2, 0, 2),
+    s(8, 1, 9, 2), s(16, 0, 10, 2),
+    s(12, 3, 9, 3), s(12, 3, 9, 4),
+    s(15, 0, 10, 1), s(15, 0, 12, 4),
+    s(21, 0, 9, 4), s(12, 1, 9, 3), s(12, 1, 9, 1),
+    s(13, 1, 0, 2), s(12, 1, 0, 4),
+    s(4, 2, 0, 2), s(1, 1, 0, 1),
+    s(0, 1, 0, 4), s(10, 0, 5, 4),
+    s(17, 0, 9, 1), s(17, 0, 10, 1), s(13, 1, 7, 1),
+    s(3, 2, 0, 4), s(3, 0, 9, 4),
+    s(11, 2, 0, 1), s(0, 2, 0, 2),
+    s(16, 0, 9, 1), s(16, 0, 9, 4),
+    s(3, 0, 12, 2), s(3, 0, 12, 3),
+    s(9, 0, 9, 4), s(9, 0, 9, 1), s(0, 0, 9, 4),
+    s(2, 1, 12, 2), s(2, 1, 9, 2), s(1, 1, 12, 4), s(1, 0, 12, 1),
+    s(7, 1, 9, 2), s(7, 1, 12, 4),
+    s(1, 1, 12, 4), s(1, 1, 12, 1),
+    s(14, 1, 12, 4), s(7, 1, 4, 4),
+    s(9, 0, 9, 4), s(9, 0, 9, 1), s(11, 0, 9, 2), s(9, 2, 9, 3),
+    s(0, 1, 7, 1), s(3, 1, 0, 4),
+    s(0, 1, 8, 4), s(0, 1, 7, 4),
+    s(12, 1, 0, 3), s(12, 1, 0, 1), s(12, 1, 0, 4), s(13, 1, 0, 2),
+    s(9, 2, 11, 3), s(0, 1, 9, 3), s(0, 0, 9, 1),
+    s(9, 2, 11, 3),
+    s(15, 2, 11, 1), s(2, 0, 12, 2),
+    s(1, 1, 0, 4), s(2, 1, 0, 2), s(2, 1, 0, 3),
+    s(9, 2, 6, 3), s(9, 2, 6, 4),
+    s(0, 1, 1, 3), s(0, 1, 1, 2),
+    s(1, 0, 5, 4), s(14, 1, 10, 4), s(6, 1, 12, 1),
+    s(5, 1, 0, 3), s(5, 0, 3, 5),
+    s(3, 1, 7, 4),
+    s(5, 2, 0, 4), s(5, 2, 2, 2), s(15, 0, 5, 2),
+    s(0, 1, 8, 3), s(0, 1, 8, 2),
+    s(16, 0, 12, 3),
+    s(1, 1, 0, 4), s(2, 1, 0, 2),
+    s(12, 1, 0, 2), s(12, 1, 0, 1),
+    s(17, 2, 0, 4), s(15, 2, 0, 4), s(15, 0, 3, 1),
+    s(0, 3, 12, 1), s(0, 3, 12, 2),
+    s(20, 0, 3, 4), s(20, 0, 0, 4), s(19, 0, 3, 4), s(21, 0, 0, 5),
+    s(9, 2, 6, 1), s(0, 2, 6, 3), s(11, 2, 6, 4),
+    s(13, 1, 12, 3), s(13, 1, 12, 4), s(13, 1, 12, 1),
+    s(12, 1, 10, 3), s(13, 1, 10, 2),
+    s(14, 1, 10, 1), s(13, 1, 9, 4),
+    s(9, 2, 11, 3), s(9, 2, 11, 4), s(10, 2, 11, 4), s(9, 2, 11, 1),
+    s(13, 1, 11, 2), s(21, 0, 3, 4),
+    s(8, 1, 10, 3), s(8, 0, 9, 3),
+    s(8, 2, 0, 2), s(8, 3, 0, 2),
+    s(0, 1, 10, 3), s(0, 1, 10, 4),
+    s(2, 0, 5, 3), s(2, 0, 2, 4),
+    s(0, 1, 0, 4), s(0, 1, 0, 2),
+    s(7, 2, 12, 4), s(8, 2, 12, 4),
+    s(12, 3, 0, 3), s(13, 3, 0, 3),
+    s(0, 1, 9, 3), s(0, 1, 9, 1), s(7, 0, 9, 2),
+    s(17, 0, 0, 4), s(6, 0, 3, 4),
+    s(5, 1, 7, 4), s(5, 1, 0, 4),
+    s(5, 1, 0, 4), s(6, 1, 0, 4), s(6, 2, 6, 2),
+    s(12, 3, 4, 2), s(15, 0, 11, 1),
+    s(13, 3, 9, 1), s(12, 3, 9, 4),
+    s(14, 3, 9, 1), s(0, 3, 9, 1),
+    s(15, 0, 11, 1), s(15, 0, 11, 4),
+    s(13, 1, 11, 2), s(12, 1, 11, 4), s(13, 1, 11, 3),
+    s(9, 0, 8, 4), s(10, 0, 8, 1),
+    s(4, 0, 1, 1),
+    s(12, 1, 11, 4), s(13, 1, 11, 2), s(13, 1, 11, 3), s(12, 1, 11, 1),
+    s(3, 1, 0, 2), s(3, 1, 0, 3), s(7, 1, 0, 2),
+    s(5, 0, 11, 1), s(5, 0, 11, 4),
+    s(6, 2, 9, 4), s(17, 0, 0, 3),
+    s(11, 2, 6, 4), s(21, 2, 6, 4),
+    s(0, 3, 4, 1),
+    s(17, 0, 9, 1), s(14, 1, 9, 3),
+    s(0, 1, 9, 4), s(2, 0, 9, 2),
+    s(2, 0, 12, 2), s(2, 0, 11, 2), s(1, 0, 11, 1), s(2, 0, 12, 1),
+    s(2, 1, 7, 1), s(2, 1, 7, 4), s(3, 1, 7, 3),
+    s(16, 0, 0, 4), s(4, 2, 0, 2),
+    s(15, 2, 2, 2), s(1, 0, 2, 2),
+    s(6, 2, 2, 3), s(0, 1, 0, 2),
+    s(2, 0, 11, 2), s(4, 0, 11, 3), s(4, 0, 11, 2),
+    s(0, 2, 11, 3), s(0, 2, 11, 4),
+    s(5, 0, 5, 4), s(5, 0, 5, 1),
+    s(11, 0, 10, 3),
+    s(11, 2, 5, 2), s(11, 2, 6, 2),
+    s(0, 2, 11, 3), s(0, 2, 1, 1),
+    s(16, 0, 12, 3), s(15, 0, 12, 4),
+    s(12, 1, 12, 4), s(12, 1, 12, 1),
+    s(15, 0, 0, 5),
+    s(5, 0, 3, 2), s(5, 0, 3, 5), s(5, 0, 6, 3),
+    s(14, 1, 0, 3), s(21, 0, 0, 1),
+    s(20, 2, 12, 2), s(19, 2, 12, 4), s(19, 2, 12, 1), s(20, 2, 12, 1), s(19, 2, 12, 3),
+    s(0, 3, 0, 4), s(0, 1, 1, 4),
+    s(17, 0, 0, 4), s(6, 1, 0, 3),
+    s(1, 1, 9, 4), s(2, 1, 9, 2), s(2, 1, 9, 1),
+    s(14, 1, 0, 1), s(14, 1, 0, 2),
+    s(6, 1, 0, 2), s(16, 0, 0, 2),
+    s(15, 0, 12, 1), s(15, 0, 0, 3), s(16, 0, 12, 2),
+    s(16, 2, 12, 1), s(15, 2, 12, 1), s(15, 2, 12, 3),
+    s(12, 1, 7, 3), s(12, 1, 7, 4), s(12, 1, 7, 1), s(0, 1, 7, 1),
+    s(8, 2, 12, 4), s(8, 2, 12, 3),
+    s(14, 1, 11, 1), s(18, 0, 11, 3),
+    s(13, 3, 0, 2), s(12, 3, 0, 4),
+    s(14, 1, 10, 5),
+    s(5, 1, 12, 4), s(6, 1, 12, 4),
+    s(9, 0, 5, 3),
+    s(18, 0, 10, 3), s(18, 0, 10, 4),
+    s(16, 0, 9, 4), s(13, 1, 9, 3), s(13, 1, 9, 1),
+    s(6, 0, 9, 3), s(10, 0, 12, 3),
+    s(6, 0, 3, 4), s(5, 0, 7, 3),
+    s(6, 0, 3, 4), s(6, 2, 6, 1), s(6, 0, 6, 1),
+    s(9, 0, 9, 1), s(11, 0, 9, 4),
+    s(17, 0, 0, 4), s(6, 0, 5, 4),
+    s(14, 1, 0, 1), s(8, 1, 4, 3),
+    s(0, 2, 0, 3), s(0, 2, 0, 4),
+    s(0, 1, 8, 1), s(0, 1, 8, 4),
+    s(0, 2, 9, 4), s(0, 2, 9, 2),
+    s(21, 2, 12, 1), s(15, 2, 12, 1),
+    s(0, 3, 0, 4), s(17, 2, 0, 1),
+    s(15, 0, 0, 4), s(13, 1, 0, 2),
+    s(13, 1, 0, 2), s(17, 0, 0, 4),
+    s(16, 0, 10, 2), s(5, 0, 9, 4),
+    s(6, 2, 10, 2), s(15, 2, 10, 1), s(5, 2, 10, 4),
+    s(13, 1, 9, 2), s(13, 1, 10, 2),
+    s(10, 2, 11, 2), s(0, 2, 11, 3),
+    s(10, 0, 11, 1), s(11, 0, 11, 1), s(11, 0, 11, 4),
+    s(10, 0, 5, 4), s(13, 1, 0, 4),
+    s(11, 2, 5, 2), s(4, 2, 0, 4),
+    s(1, 0, 7, 3), s(1, 0, 7, 4),
+    s(19, 2, 2, 4), s(15, 0, 1, 4),
+    s(19, 0, 10, 3),
+    s(0, 1, 11, 4), s(0, 1, 11, 1),
+    s(12, 3, 0, 4), s(10, 0, 8, 4),
+    s(7, 0, 7, 2), s(7, 1, 8, 2),
+    s(15, 0, 12, 1), s(15, 0, 12, 4),
+    s(2, 0, 1, 4), s(1, 0, 2, 2),
+    s(6, 1, 4, 1), s(15, 0, 9, 1),
+    s(11, 2, 0, 4), s(9, 2, 0, 4),
+    s(11, 2, 0, 4), s(6, 1, 4, 1),
+    s(12, 3, 0, 4), s(13, 3, 0, 1), s(20, 2, 0, 1), s(19, 2, 0, 1),
+    s(5, 0, 1, 2), s(5, 0, 9, 4),
+    s(8, 1, 9, 2), s(8, 1, 12, 2), s(8, 1, 12, 3),
+    s(21, 0, 0, 1), s(21, 0, 5, 1),
+    s(16, 0, 8, 2), s(0, 1, 8, 2),
+    s(5, 0, 5, 4), s(0, 1, 0, 2),
+    s(6, 2, 0, 1), s(5, 1, 4, 2), s(6, 2, 6, 4),
+    s(0, 3, 9, 4), s(0, 3, 10, 4),
+    s(4, 2, 0, 2), s(4, 0, 6, 4), s(1, 0, 6, 4),
+    s(0, 1, 8, 1), s(0, 1, 7, 4),
+    s(14, 3, 0, 4), s(14, 3, 4, 4),
+    s(16, 2, 0, 4), s(14, 3, 0, 4),
+    s(1, 1, 0, 4), s(2, 1, 0, 1),
+    s(3, 0, 2, 5),
+    s(7, 0, 10, 4), s(18, 0, 10, 4), s(7, 1, 10, 2),
+    s(14, 3, 10, 2), s(17, 2, 10, 4),
+    s(17, 0, 0, 4), s(15, 0, 0, 4),
+    s(11, 0, 12, 2), s(9, 0, 12, 4),
+    s(6, 1, 7, 1), s(0, 1, 7, 2),
+    s(11, 2, 11, 3), s(9, 2, 11, 1),
+    s(9, 2, 6, 3), s(0, 2, 6, 2),
+    s(14, 1, 0, 1), s(13, 1, 0, 1), s(14, 3, 0, 4),
+    s(12, 1, 7, 3), s(14, 1, 7, 4),
+    s(11, 2, 0, 1), s(10, 2, 1, 1),
+    s(12, 1, 1, 2), s(13, 1, 0, 4),
+    s(11, 0, 10, 4),
+    s(5, 2, 12, 4), s(6, 2, 12, 1),
+    s(13, 3, 9, 1), s(15, 2, 9, 1),
+    s(0, 0, 3, 4), s(0, 0, 3, 3), s(0, 2, 0, 4), s(0, 2, 0, 1),
+    s(14, 1, 7, 5),
+    s(6, 2, 12, 4),
+    s(0, 3, 12, 3), s(6, 2, 12, 1),
+    s(0, 3, 9, 1), s(12, 3, 9, 4),
+    s(2, 1, 0, 1), s(1, 1, 0, 1), s(2, 1, 0, 3),
+    s(13, 1, 7, 1), s(13, 1, 7, 3), s(13, 1, 7, 4),
+    s(6, 2, 0, 2), s(0, 3, 0, 2),
+    s(12, 1, 4, 4), s(10, 0, 3, 4),
+    s(11, 0, 7, 4), s(12, 1, 7, 4),
+    s(1, 0, 6, 4), s(1, 0, 6, 3),
+    s(3, 0, 9, 2), s(3, 0, 10, 4),
+    s(11, 0, 12, 1), s(11, 0, 12, 4),
+    s(13, 3, 9, 1), s(14, 3, 10, 2),
+    s(10, 2, 6, 1), s(8, 1, 0, 3),
+    s(3, 0, 10, 4), s(3, 0, 10, 1),
+    s(16, 0, 8, 2), s(13, 1, 8, 1), s(5, 0, 7, 1),
+    s(12, 1, 12, 1), s(8, 1, 11, 2),
+    s(0, 3, 9, 1), s(0, 2, 9, 3), s(0, 3, 0, 4),
+    s(8, 0, 9, 2), s(8, 1, 10, 2),
+    s(16, 2, 2, 4), s(16, 2, 6, 4),
+    s(11, 2, 10, 1), s(11, 2, 10, 3), s(3, 0, 10, 4),
+    s(16, 0, 11, 3), s(6, 0, 11, 3),
+    s(0, 0, 3, 4), s(0, 2, 0, 4), s(0, 2, 0, 1), s(0, 0, 3, 3), s(11, 2, 0, 1),
+    s(21, 2, 2, 3), s(18, 2, 6, 3),
+    s(5, 2, 2, 4), s(6, 2, 2, 2),
+    s(15, 2, 6, 4), s(16, 2, 9, 3), s(9, 2, 1, 4),
+    s(5, 1, 4, 2), s(6, 1, 4, 1),
+    s(18, 0, 3, 3), s(18, 2, 2, 4),
+    s(3, 1, 10, 3), s(11, 2, 10, 1),
+    s(6, 1, 0, 2), s(17, 0, 0, 4),
+    s(13, 1, 7, 3), s(13, 1, 8, 4),
+    s(16, 0, 8, 2), s(13, 1, 7, 3), s(12, 1, 8, 1),
+    s(0, 3, 0, 2), s(6, 0, 8, 1), s(0, 3, 0, 3),
+    s(3, 1, 10, 3), s(4, 0, 10, 1),
+    s(10, 0, 5, 4), s(13, 1, 0, 4), s(11, 0, 3, 4),
+    s(5, 0, 11, 4), s(17, 0, 11, 1), s(6, 0, 11, 2), s(0, 1, 11, 2),
+    s(16, 0, 10, 2), s(5, 0, 9, 1), s(14, 1, 10, 4),
+    s(13, 1, 1, 4), s(13, 1, 1, 1), s(10, 0, 3, 4),
+    s(0, 3, 10, 4), s(0, 3, 10, 3), s(0, 2, 10, 3),
+    s(21, 0, 5, 1), s(21, 0, 0, 1), s(21, 0, 0, 3),
+    s(9, 2, 12, 1), s(9, 2, 12, 4), s(11, 2, 12, 3),
+    s(21, 2, 0, 4), s(21, 0, 3, 4),
+    s(16, 2, 11, 4), s(16, 2, 11, 3),
+    s(10, 0, 5, 4), s(14, 1, 0, 4), s(13, 1, 0, 4),
+    s(14, 1, 0, 4), s(14, 1, 4, 2),
+    s(21, 0, 7, 1), s(20, 0, 7, 3),
+    s(14, 3, 0, 4), s(16, 2, 0, 4),
+    s(13, 1, 9, 4), s(13, 1, 4, 4), s(14, 1, 9, 2), s(13, 1, 9, 3),
+    s(11, 2, 11, 1), s(11, 2, 11, 3), s(11, 2, 11, 5),
+    s(17, 0, 10, 4), s(15, 0, 10, 4),
+    s(9, 2, 12, 5),
+    s(20, 2, 12, 2), s(20, 0, 7, 2),
+    s(2, 1, 7, 1), s(2, 1, 7, 4),
+    s(6, 0, 3, 4), s(7, 1, 0, 4),
+    s(3, 0, 9, 4), s(3, 0, 9, 2),
+    s(19, 0, 7, 4), s(20, 0, 7, 4),
+    s(0, 0, 8, 4), s(0, 0, 8, 1),
+    s(13, 1, 10, 2), s(12, 1, 10, 4), s(12, 1, 10, 3),
+    s(8, 3, 0, 4), s(8, 3, 0, 2),
+    s(13, 1, 9, 1), s(14, 1, 9, 2),
+    s(17, 0, 3, 4), s(15, 0, 3, 2),
+    s(13, 1, 12, 4), s(13, 1, 12, 1), s(13, 1, 11, 1),
+    s(5, 1, 0, 4), s(16, 0, 0, 4),
+    s(20, 2, 12, 4), s(21, 2, 12, 1),
+    s(16, 0, 12, 2), s(15, 0, 12, 4), s(5, 0, 12, 4),
+    s(0, 1, 10, 4), s(14, 1, 10, 4), s(0, 1, 10, 2),
+    s(14, 1, 0, 3), s(14, 1, 0, 1),
+    s(5, 0, 9, 4), s(5, 0, 1, 2), s(16, 0, 9, 3),
+    s(5, 2, 6, 4), s(5, 2, 10, 4), s(6, 2, 10, 1),
+    s(14, 1, 7, 1), s(12, 1, 7, 1),
+    s(16, 2, 12, 1), s(15, 2, 11, 4),
+    s(14, 1, 9, 2), s(14, 1, 9, 4),
+    s(8, 1, 7, 3), s(8, 1, 7, 2),
+    s(7, 0, 7, 2), s(7, 2, 12, 2), s(7, 0, 11, 2), s(7, 0, 7, 3),
+    s(11, 0, 9, 4), s(5, 0, 9, 4),
+    s(12, 1, 7, 3), s(12, 1, 0, 1),
+    s(14, 3, 9, 1), s(11, 2, 9, 1),
+    s(20, 0, 7, 3), s(21, 0, 7, 1), s(21, 0, 7, 4),
+    s(0, 0, 7, 4), s(0, 3, 0, 4),
+    s(0, 1, 9, 1), s(0, 1, 9, 4), s(0, 1, 4, 4),
+    s(5, 0, 5, 1),
+    s(3, 0, 12, 3), s(3, 0, 12, 4), s(3, 0, 12, 2),
+    s(0, 0, 5, 4), s(7, 1, 0, 4), s(7, 1, 0, 3),
+    s(13, 1, 0, 2), s(12, 1, 0, 4), s(12, 1, 0, 1),
+    s(8, 0, 9, 2), s(14, 1, 9, 4),
+    s(0, 1, 8, 3), s(0, 1, 8, 1),
+    s(11, 2, 6, 5),
+    s(8, 0, 9, 3), s(8, 0, 5, 4),
+    s(18, 0, 11, 4),
+    s(11, 2, 9, 1), s(9, 2, 9, 4),
+    s(9, 0, 11, 4),
+    s(9, 0, 11, 4), s(15, 2, 11, 4),
+    s(15, 2, 11, 4), s(9, 0, 11, 4),
+    s(14, 3, 0, 1), s(13, 3, 0, 5),
+    s(18, 2, 12, 2), s(18, 0, 12, 1),
+    s(14, 1, 0, 4), s(11, 2, 0, 1),
+    s(12, 1, 9, 1), s(20, 0, 9, 2),
+    s(13, 1, 11, 1), s(19, 0, 11, 1),
+    s(13, 1, 0, 1), s(20, 2, 0, 4),
+    s(12, 1, 1, 2), s(9, 0, 1, 1),
+    s(19, 0, 6, 2),
+    s(15, 0, 0, 1), s(15, 0, 0, 2),
+    s(10, 0, 9, 1), s(15, 0, 10, 3),
+    s(13, 1, 11, 1), s(16, 2, 11, 1), s(13, 1, 11, 4),
+    s(0, 1, 9, 3), s(0, 1, 8, 3),
+    s(11, 2, 0, 1), s(14, 1, 0, 4),
+    s(14, 1, 0, 4), s(11, 2, 0, 1), s(14, 1, 0, 1), s(11, 2, 6, 1), s(21, 2, 2, 1), s(0, 1, 0, 1),
+    s(16, 2, 2, 1),
+    s(17, 0, 0, 4), s(0, 1, 0, 2),
+    s(4, 0, 11, 2), s(2, 0, 11, 2),
+    s(1, 1, 9, 3), s(2, 1, 9, 1), s(1, 1, 9, 1), s(2, 1, 9, 2),
+    s(17, 0, 9, 4), s(17, 0, 9, 1),
+    s(17, 0, 8, 5),
+    s(20, 0, 5, 2), s(19, 0, 5, 1),
+    s(15, 0, 1, 1), s(19, 0, 1, 1), s(15, 0, 1, 2), s(15, 0, 1, 3),
+    s(8, 0, 3, 4), s(8, 1, 0, 4), s(20, 0, 5, 2),
+    s(2, 2, 0, 1), s(2, 1, 0, 4),
+    s(1, 0, 1, 1), s(2, 0, 1, 2), s(1, 0, 5, 4), s(1, 1, 4, 2),
+    s(5, 0, 1, 3), s(5, 0, 1, 2),
+    s(18, 0, 12, 1), s(18, 0, 12, 4),
+    s(5, 1, 7, 3), s(5, 1, 0, 2), s(0, 3, 4, 1), s(8, 1, 0, 4),
+    s(10, 0, 11, 2), s(9, 0, 11, 1),
+    s(0, 3, 0, 1), s(0, 2, 0, 1),
+    s(10, 2, 0, 1), s(0, 2, 0, 1),
+    s(16, 0, 1, 1), s(16, 0, 5, 1), s(15, 0, 1, 3),
+    s(6, 2, 2, 1), s(0, 1, 0, 3), s(16, 0, 0, 3),
+    s(9, 2, 0, 3), s(13, 1, 0, 4), s(12, 1, 4, 2), s(9, 0, 3, 1),
+    s(21, 0, 7, 3), s(21, 0, 7, 4),
+    s(7, 1, 8, 3), s(16, 0, 8, 3), s(15, 0, 8, 3), s(15, 0, 8, 4),
+    s(1, 0, 9, 4), s(4, 0, 10, 3), s(4, 0, 10, 1), s(11, 2, 2, 3),
+    s(18, 0, 7, 3), s(0, 1, 8, 4),
+    s(14, 1, 0, 1), s(16, 0, 1, 1), s(13, 1, 0, 4),
+    s(1, 0, 9, 1), s(2, 0, 9, 1),
+    s(4, 2, 0, 2), s(2, 2, 0, 2),
+    s(14, 1, 0, 1), s(15, 0, 3, 2),
+    s(2, 1, 0, 1), s(2, 1, 0, 2),
+    s(15, 0, 0, 4), s(21, 2, 10, 3), s(10, 0, 9, 3),
+    s(15, 0, 7, 3), s(11, 2, 1, 2),
+    s(16, 0, 12, 2), s(15, 0, 12, 3), s(15, 0, 12, 4),
+    s(12, 1, 0, 4), s(13, 1, 0, 2),
+    s(16, 0, 7, 1), s(21, 2, 2, 1), s(16, 0, 7, 4), s(16, 0, 7, 3),
+    s(1, 0, 1, 3), s(1, 0, 1, 4), s(2, 0, 1, 2),
+    s(5, 0, 9, 3), s(17, 0, 10, 3),
+    s(7, 0, 3, 4), s(7, 1, 0, 4), s(7, 0, 1, 4), s(18, 2, 6, 4),
+    s(15, 2, 1, 1),
+    s(6, 0, 8, 2), s(5, 0, 8, 4),
+    s(10, 0, 11, 4), s(9, 0, 11, 1),
+    s(15, 0, 3, 2), s(17, 0, 3, 2), s(15, 0, 3, 1), s(6, 1, 0, 2),
+    s(0, 0, 7, 3), s(0, 0, 7, 4), s(7, 1, 8, 4),
+    s(8, 2, 10, 1), s(8, 2, 10, 2),
+    s(13, 1, 11, 3), s(13, 1, 11, 1),
+    s(0, 3, 10, 5),
+    s(15, 0, 0, 3), s(15, 0, 5, 3),
+    s(2, 0, 12, 1), s(1, 0, 12, 1),
+    s(1, 2, 0, 4), s(2, 2, 0, 1), s(1, 0, 1, 2),
+    s(6, 0, 5, 2), s(16, 0, 0, 1),
+    s(0, 1, 7, 3), s(6, 0, 7, 1),
+    s(1, 0, 7, 4), s(2, 0, 7, 1), s(2, 0, 8, 3),
+    s(11, 0, 3, 1), s(11, 0, 3, 4), s(13, 1, 1, 1),
+    s(7, 1, 0, 3), s(7, 1, 0, 2),
+    s(0, 1, 4, 4), s(17, 0, 3, 2),
+    s(5, 1, 0, 3), s(15, 0, 0, 3), s(13, 1, 0, 2),
+    s(2, 1, 0, 1), s(2, 0, 6, 1),
+    s(3, 0, 2, 3), s(3, 0, 1, 1), s(3, 0, 2, 4),
+    s(16, 0, 10, 1), s(17, 0, 10, 1),
+    s(0, 1, 1, 1), s(14, 1, 1, 2), s(12, 1, 1, 3),
+    s(12, 1, 1, 1), s(0, 1, 1, 2),
+    s(4, 2, 0, 2), s(1, 1, 0, 4), s(2, 1, 0, 4), s(4, 0, 6, 4),
+    s(15, 0, 1, 3), s(15, 0, 9, 3), s(15, 0, 1, 4), s(15, 0, 1, 2),
+    s(5, 0, 9, 1), s(5, 0, 9, 4), s(5, 0, 9, 3), s(12, 1, 4, 1),
+    s(16, 0, 5, 1), s(16, 0, 3, 4), s(16, 0, 0, 4), s(20, 0, 1, 1),
+    s(7, 1, 9, 1), s(7, 1, 9, 3), s(5, 1, 9, 1),
+    s(8, 0, 1, 1), s(8, 0, 1, 2), s(8, 0, 1, 3), s(8, 0, 1, 4), s(8, 0, 1, 5),
+    s(4, 2, 0, 3), s(4, 2, 0, 1), s(1, 2, 0, 3),
+    s(1, 0, 9, 4), s(2, 0, 9, 1),
+    s(2, 0, 5, 1), s(1, 0, 2, 2),
+    s(8, 1, 10, 1), s(8, 1, 12, 1),
+    s(9, 2, 5, 3),
+    s(12, 3, 0, 4), s(12, 3, 0, 3),
+    s(6, 2, 2, 4), s(6, 0, 1, 4), s(15, 0, 0, 2),
+    s(1, 0, 1, 2), s(1, 0, 2, 1), s(1, 1, 4, 2), s(4, 0, 1, 2), s(1, 0, 6, 4),
+    s(6, 2, 2, 1), s(16, 0, 0, 3),
+    s(0, 0, 7, 3), s(0, 0, 7, 4), s(7, 1, 8, 4), s(0, 3, 0, 4),
+    s(12, 3, 0, 1), s(9, 0, 8, 1), s(12, 3, 0, 3), s(12, 3, 0, 2),
+    s(2, 0, 9, 4), s(1, 1, 9, 4), s(4, 0, 10, 4), s(4, 0, 9, 1), s(2, 1, 10, 1),
+    s(15, 0, 7, 1), s(13, 1, 7, 2), s(17, 0, 7, 2),
+    s(1, 0, 5, 4), s(1, 0, 5, 2),
+    s(13, 1, 1, 2),
+    s(7, 1, 12, 2), s(7, 1, 12, 3), s(7, 1, 12, 4),
+    s(19, 0, 3, 2), s(15, 0, 5, 2),
+    s(10, 2, 2, 4), s(9, 2, 1, 1),
+    s(12, 1, 4, 2), s(12, 1, 1, 2),
+    s(7, 1, 10, 3),
+    s(9, 2, 12, 3), s(12, 3, 0, 2),
+    s(17, 2, 9, 1), s(13, 3, 9, 2),
+    s(20, 2, 10, 2), s(19, 2, 10, 4),
+    s(19, 0, 1, 1), s(19, 0, 9, 3),
+    s(10, 0, 7, 3),
+    s(0, 1, 0, 2), s(16, 0, 0, 3), s(11, 0, 5, 4),
+    s(20, 0, 3, 4), s(21, 0, 3, 4), s(16, 2, 2, 4),
+    s(2, 1, 10, 1), s(1, 1, 12, 4),
+    s(15, 2, 5, 1), s(15, 2, 5, 4), s(0, 1, 4, 4),
+    s(17, 0, 0, 2), s(17, 0, 3, 4), s(12, 1, 4, 4),
+    s(10, 2, 2, 4), s(9, 2, 11, 1),
+    s(15, 0, 0, 3), s(15, 0, 0, 1), s(15, 0, 0, 2),
+    s(13, 1, 4, 4), s(13, 1, 0, 4), s(12, 1, 1, 2), s(13, 1, 1, 4), s(17, 0, 0, 4),
+    s(7, 2, 12, 4),
+    s(12, 1, 7, 4), s(12, 1, 7, 1),
+    s(10, 2, 1, 4), s(10, 2, 0, 1), s(10, 0, 8, 1),
+    s(7, 0, 1, 2), s(18, 2, 0, 2), s(7, 2, 0, 2),
+    s(6, 1, 7, 1), s(6, 1, 7, 3), s(6, 0, 7, 2), s(5, 1, 7, 4), s(6, 1, 7, 2), s(6, 1, 7, 5),
+    s(5, 1, 4, 2), s(17, 0, 3, 4),
+    s(8, 1, 4, 3),
+    s(0, 2, 2, 1), s(15, 2, 1, 1),
+    s(14, 1, 4, 2), s(12, 1, 1, 1),
+    s(5, 0, 11, 3), s(5, 0, 11, 4),
+    s(0, 0, 5, 1), s(0, 0, 5, 2),
+    s(20, 2, 2, 4), s(19, 2, 2, 4),
+    s(6, 1, 0, 3), s(6, 1, 0, 4),
+    s(15, 0, 10, 4), s(15, 0, 10, 1), s(15, 0, 10, 3),
+    s(21, 0, 1, 1), s(17, 0, 1, 1), s(21, 2, 2, 1),
+    s(21, 0, 1, 1), s(21, 2, 2, 1), s(17, 0, 1, 1),
+    s(6, 1, 12, 3), s(6, 1, 12, 2),
+    s(18, 2, 1, 2), s(18, 2, 2, 2), s(21, 2, 6, 1), s(8, 2, 2, 4),
+    s(12, 1, 7, 3), s(10, 2, 0, 4),
+    s(12, 1, 8, 4), s(12, 3, 0, 1), s(13, 1, 8, 2),
+    s(10, 2, 10, 3), s(11, 2, 10, 2),
+    s(11, 2, 2, 4), s(16, 0, 0, 4),
+    s(6, 2, 0, 2), s(17, 2, 0, 1), s(16, 0, 1, 2),
+    s(2, 0, 8, 2), s(2, 0, 8, 1), s(4, 2, 0, 1),
+    s(8, 3, 0, 3), s(8, 2, 2, 1),
+    s(1, 0, 1, 1), s(1, 1, 4, 2),
+    s(11, 0, 9, 4), s(14, 1, 9, 4), s(9, 0, 9, 3),
+    s(17, 0, 7, 1), s(17, 0, 7, 4), s(17, 0, 7, 3), s(14, 1, 7, 1), s(13, 1, 7, 4),
+    s(7, 1, 4, 1),
+    s(12, 3, 9, 1), s(0, 3, 9, 2),
+    s(17, 2, 0, 4), s(21, 0, 8, 1), s(21, 2, 12, 3),
+    s(0, 1, 4, 2), s(0, 3, 0, 2),
+    s(12, 3, 4, 2), s(15, 2, 2, 2),
+    s(0, 2, 9, 2), s(9, 2, 1, 1),
+    s(1, 2, 0, 4), s(2, 2, 0, 2), s(15, 0, 0, 4),
+    s(19, 2, 10, 4),
+    s(6, 2, 2, 1), s(17, 2, 6, 4), s(0, 1, 9, 3),
+    s(8, 0, 7, 1),
+    s(0, 2, 9, 4), s(0, 2, 9, 1), s(0, 2, 9, 3), s(0, 3, 0, 4),
+    s(2, 0, 12, 3), s(4, 0, 12, 4),
+    s(4, 2, 0, 3), s(4, 2, 0, 4), s(1, 2, 0, 3),
+    s(1, 0, 5, 3), s(1, 0, 1, 1), s(1, 1, 0, 3),
+    s(5, 0, 7, 2), s(5, 0, 7, 3),
+    s(0, 2, 2, 3), s(8, 2, 2, 4), s(8, 2, 2, 3),
+    s(0, 0, 5, 2), s(0, 0, 5, 1),
+    s(12, 3, 9, 3), s(13, 3, 9, 2), s(12, 3, 9, 4),
+    s(16, 0, 10, 1), s(6, 1, 9, 3), s(7, 1, 9, 3),
+    s(16, 2, 6, 2), s(5, 2, 2, 3),
+    s(12, 1, 4, 2), s(13, 1, 4, 4), s(16, 0, 1, 1),
+    s(7, 1, 9, 3), s(7, 1, 4, 1), s(7, 1, 9, 1),
+    s(18, 2, 2, 2), s(0, 2, 2, 1), s(0, 2, 6, 3), s(18, 0, 3, 2),
+    s(19, 2, 2, 2), s(20, 2, 0, 4), s(21, 2, 0, 1), s(19, 2, 10, 4),
+    s(0, 2, 2, 4), s(14, 1, 1, 2),
+    s(14, 1, 9, 1), s(11, 0, 10, 2),
+    s(9, 0, 11, 1), s(9, 0, 11, 4),
+    s(5, 2, 2, 1), s(5, 2, 2, 2), s(15, 2, 2, 1),
+    s(5, 1, 7, 4), s(7, 2, 2, 2),
+    s(2, 0, 8, 2), s(2, 0, 8, 3), s(4, 2, 0, 4), s(2, 0, 6, 2),
+    s(12, 1, 0, 3), s(0, 1, 0, 3),
+    s(6, 0, 7, 1), s(6, 0, 7, 2),
+    s(13, 1, 1, 1),
+    s(2, 0, 5, 2), s(2, 0, 5, 3), s(1, 0, 5, 4),
+    s(13, 1, 9, 1), s(0, 2, 9, 4),
+    s(0, 1, 4, 1), s(0, 1, 4, 4),
+    s(0, 1, 1, 4), s(0, 1, 1, 3),
+    s(0, 1, 0, 4), s(7, 1, 0, 3), s(7, 0, 5, 2), s(7, 1, 4, 4),
+    s(11, 2, 2, 4), s(14, 3, 0, 4),
+    s(17, 0, 9, 4), s(0, 1, 9, 4), s(0, 1, 9, 3),
+    s(15, 0, 12, 3), s(5, 1, 12, 4),
+    s(8, 3, 4, 4), s(8, 3, 4, 3),
+    s(6, 0, 9, 4), s(14, 1, 9, 2),
+    s(12, 1, 4, 1), s(14, 1, 4, 2), s(17, 0, 1, 4), s(16, 0, 1, 1),
+    s(10, 2, 12, 4), s(10, 2, 12, 1), s(13, 1, 11, 1),
+    s(20, 2, 2, 4), s(19, 0, 3, 2), s(20, 0, 0, 4),
+    s(15, 0, 8, 1), s(19, 0, 8, 1), s(16, 0, 8, 1),
+    s(2, 0, 1, 2), s(17, 0, 8, 3),
+    s(9, 2, 5, 1), s(9, 2, 2, 2),
+    s(3, 1, 12, 5),
+    s(15, 0, 0, 4), s(15, 0, 0, 1),
+    s(5, 0, 9, 3), s(17, 0, 9, 4),
+    s(3, 0, 12, 5),
+    s(20, 0, 9, 4), s(16, 0, 9, 1), s(17, 0, 9, 3),
+    s(2, 0, 12, 4),
+    s(0, 3, 9, 4), s(16, 2, 9, 2),
+    s(15, 0, 12, 1), s(10, 0, 12, 1),
+    s(12, 1, 8, 1), s(0, 1, 8, 2),
+    s(12, 1, 9, 3), s(12, 1, 9, 1), s(13, 1, 9, 1),
+    s(0, 3, 0, 2), s(16, 0, 8, 1), s(0, 1, 8, 2), s(17, 2, 0, 1), s(0, 1, 7, 2),
+    s(11, 2, 12, 1), s(11, 2, 12, 2), s(14, 3, 9, 4), s(12, 3, 0, 1),
+    s(2, 1, 0, 4), s(16, 0, 3, 4),
+    s(21, 0, 5, 1), s(20, 0, 5, 1),
+    s(3, 1, 7, 2), s(3, 0, 7, 4),
+    s(6, 1, 0, 2), s(5, 1, 0, 1), s(16, 0, 0, 2), s(17, 0, 0, 2), s(5, 1, 0, 3),
+    s(16, 0, 1, 1), s(15, 0, 1, 3),
+    s(19, 2, 12, 3), s(21, 2, 12, 1),
+    s(15, 0, 10, 4), s(15, 0, 10, 1),
+    s(0, 1, 0, 1), s(12, 1, 0, 2),
+    s(0, 3, 12, 2), s(11, 2, 11, 2),
+    s(1, 1, 9, 1), s(1, 1, 9, 4),
+    s(19, 0, 9, 3), s(19, 2, 9, 4),
+    s(0, 0, 9, 3), s(0, 1, 9, 4), s(0, 1, 4, 4),
+    s(14, 3, 0, 1), s(12, 3, 0, 1),
+    s(0, 2, 2, 4), s(0, 0, 8, 4),
+    s(10, 0, 3, 2), s(13, 1, 1, 1),
+    s(16, 2, 5, 1), s(16, 2, 5, 3), s(16, 2, 5, 4), s(5, 2, 2, 3), s(15, 2, 6, 1), s(6, 2, 9, 2),
+    s(6, 1, 0, 4), s(5, 1, 0, 4),
+    s(10, 0, 5, 1), s(12, 1, 1, 2),
+    s(12, 1, 4, 1), s(13, 1, 0, 4), s(11, 0, 3, 2),
+    s(11, 2, 6, 1), s(11, 2, 10, 2),
+    s(16, 2, 12, 4), s(5, 2, 12, 3),
+    s(14, 1, 7, 1), s(17, 2, 2, 4), s(14, 1, 9, 1),
+    s(5, 1, 4, 2), s(17, 0, 3, 2), s(0, 1, 4, 4),
+    s(14, 1, 4, 1), s(14, 1, 4, 4), s(14, 1, 4, 2), s(12, 1, 1, 2),
+    s(0, 3, 9, 2), s(11, 2, 9, 4),
+    s(13, 1, 9, 2), s(12, 1, 9, 4), s(12, 1, 9, 3),
+    s(1, 0, 12, 5),
+    s(0, 2, 10, 4), s(0, 2, 0, 4),
+    s(9, 0, 3, 1), s(9, 0, 3, 2),
+    s(8, 0, 8, 3), s(8, 0, 8, 1),
+    s(13, 3, 4, 4), s(11, 2, 2, 1),
+    s(16, 0, 8, 1), s(19, 0, 8, 3), s(15, 2, 0, 1),
+    s(16, 2, 5, 1), s(16, 0, 0, 3), s(0, 1, 0, 2),
+    s(18, 2, 12, 2), s(7, 0, 11, 2), s(7, 0, 11, 3),
+    s(1, 0, 11, 4), s(2, 0, 12, 2), s(1, 0, 12, 1), s(1, 0, 11, 3),
+    s(20, 2, 2, 1), s(20, 2, 2, 3), s(16, 0, 5, 1),
+    s(21, 0, 7, 1), s(21, 0, 7, 4),
+    s(10, 0, 3, 1), s(0, 0, 3, 4),
+    s(7, 2, 0, 4), s(7, 2, 2, 4), s(7, 0, 8, 4),
+    s(8, 0, 1, 1), s(14, 1, 4, 2), s(14, 1, 9, 4),
+    s(12, 1, 9, 1), s(8, 1, 9, 2),
+    s(21, 0, 8, 1), s(14, 1, 7, 1), s(21, 0, 8, 4), s(17, 0, 7, 3),
+    s(13, 1, 8, 3),
+    s(9, 0, 7, 3), s(13, 1, 7, 1), s(10, 0, 7, 4),
+    s(14, 1, 9, 3), s(14, 1, 9, 1),
+    s(0, 0, 3, 4), s(0, 1, 0, 4),
+    s(16, 2, 6, 2), s(5, 2, 6, 1),
+    s(15, 0, 1, 3), s(12, 1, 4, 2),
+    s(2, 0, 9, 2), s(1, 0, 9, 1), s(2, 0, 2, 2),
+    s(1, 0, 9, 1), s(21, 2, 0, 4),
+    s(11, 2, 0, 2), s(10, 2, 0, 1),
+    s(15, 0, 0, 4), s(7, 0, 5, 2),
+    s(0, 2, 1, 1), s(0, 2, 1, 3), s(0, 2, 1, 4),
+    s(11, 2, 1, 2), s(14, 1, 1, 2), s(13, 1, 1, 1),
+    s(13, 1, 11, 3), s(13, 1, 11, 1), s(13, 1, 11, 4), s(16, 0, 12, 2), s(16, 0, 12, 1),
+    s(6, 1, 9, 2), s(17, 0, 10, 1),
+    s(10, 0, 11, 2),
+    s(1, 1, 10, 4),
+    s(21, 0, 1, 4), s(17, 0, 5, 3), s(17, 0, 1, 1),
+    s(16, 0, 9, 3), s(21, 2, 10, 4),
+    s(12, 1, 8, 1), s(8, 1, 8, 2), s(8, 1, 7, 2), s(12, 1, 7, 3), s(7, 0, 7, 2),
+    s(4, 0, 12, 2), s(2, 0, 12, 3),
+    s(5, 1, 0, 4), s(6, 2, 0, 2), s(15, 0, 0, 2),
+    s(13, 1, 0, 4), s(16, 0, 1, 2),
+    s(21, 0, 8, 1), s(21, 2, 12, 3),
+    s(8, 1, 9, 3), s(8, 1, 9, 4),
+    s(9, 0, 5, 4), s(14, 1, 0, 4),
+    s(11, 2, 0, 4), s(16, 2, 0, 1),
+    s(15, 0, 1, 1), s(15, 2, 1, 1),
+    s(20, 2, 6, 1), s(19, 2, 6, 4), s(20, 2, 2, 4),
+    s(7, 0, 5, 2), s(15, 0, 0, 4),
+    s(3, 0, 2, 2), s(3, 0, 1, 1), s(3, 1, 0, 2),
+    s(12, 1, 11, 1), s(13, 1, 11, 4),
+    s(0, 0, 7, 2), s(13, 1, 7, 1),
+    s(7, 1, 4, 4), s(16, 0, 3, 4),
+    s(16, 0, 9, 4), s(20, 0, 9, 2),
+    s(10, 0, 8, 1), s(0, 0, 8, 1),
+    s(16, 2, 0, 1), s(16, 0, 0, 1),
+    s(17, 0, 3, 4), s(21, 2, 0, 4), s(3, 1, 0, 2),
+    s(6, 2, 9, 2), s(15, 2, 9, 4), s(15, 2, 9, 1),
+    s(3, 0, 2, 1), s(3, 0, 2, 2),
+    s(3, 0, 2, 2), s(3, 0, 2, 1),
+    s(15, 0, 3, 2), s(8, 0, 1, 1), s(14, 1, 4, 2),
+    s(20, 0, 9, 4), s(17, 0, 9, 3), s(17, 0, 9, 1), s(16, 0, 9, 1), s(21, 0, 10, 1),
+    s(10, 0, 12, 1), s(13, 1, 9, 1),
+    s(1, 1, 7, 1), s(1, 1, 7, 4), s(2, 1, 7, 1), s(2, 0, 7, 1),
+    s(13, 1, 9, 1), s(13, 1, 9, 4),
+    s(12, 3, 4, 1), s(12, 3, 4, 4), s(12, 3, 4, 2), s(9, 2, 6, 4),
+    s(2, 1, 4, 1),
+    s(2, 1, 4, 1), s(2, 1, 4, 3), s(1, 1, 4, 1),
+    s(18, 2, 9, 2), s(18, 2, 6, 2), s(18, 2, 10, 2), s(18, 2, 2, 2), s(21, 2, 6, 1),
+    s(19, 0, 9, 3), s(19, 0, 9, 1), s(19, 0, 10, 1), s(13, 1, 9, 2),
+    s(14, 1, 9, 2), s(14, 3, 10, 2),
+    s(21, 0, 1, 1), s(21, 0, 1, 3),
+    s(7, 0, 7, 2), s(14, 1, 7, 1), s(18, 0, 7, 4),
+    s(21, 0, 0, 1), s(14, 1, 0, 1),
+    s(11, 0, 9, 4), s(13, 1, 9, 3),
+    s(15, 0, 12, 3), s(16, 0, 12, 2),
+    s(11, 2, 6, 1), s(0, 2, 6, 2),
+    s(12, 1, 7, 3), s(12, 1, 7, 1), s(10, 0, 7, 3),
+    s(5, 0, 9, 3), s(6, 0, 9, 4), s(5, 0, 9, 4), s(14, 1, 10, 2),
+    s(5, 0, 9, 3), s(5, 0, 9, 4), s(16, 0, 9, 2), s(6, 0, 9, 1), s(15, 0, 9, 3), s(17, 0, 9, 4), s(6, 1, 9, 2),
+    s(1, 0, 2, 1), s(4, 0, 1, 2),
+    s(4, 2, 0, 3), s(14, 1, 7, 1), s(21, 0, 8, 1),
+    s(8, 1, 7, 1), s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 0, 7, 4), s(8, 1, 7, 4),
+    s(4, 2, 0, 3), s(3, 0, 2, 2),
+    s(1, 0, 2, 1), s(1, 0, 2, 3),
+    s(20, 2, 2, 1), s(19, 2, 2, 3), s(19, 2, 6, 4), s(19, 2, 9, 1), s(16, 2, 1, 1),
+    s(15, 2, 9, 4), s(14, 3, 9, 3), s(21, 2, 9, 4),
+    s(0, 2, 6, 3), s(6, 2, 2, 3),
+    s(2, 2, 0, 1), s(1, 2, 0, 3),
+    s(20, 2, 9, 1),
+    s(13, 1, 7, 4), s(0, 1, 7, 1), s(12, 1, 0, 1),
+    s(12, 1, 9, 3), s(8, 1, 9, 4),
+    s(8, 0, 6, 2), s(8, 0, 6, 4), s(8, 0, 6, 1),
+    s(19, 0, 3, 2), s(15, 0, 5, 2), s(0, 1, 0, 4),
+    s(12, 1, 0, 1), s(12, 1, 0, 4), s(14, 1, 0, 2),
+    s(13, 1, 12, 2), s(12, 1, 12, 3), s(12, 1, 12, 4),
+    s(11, 2, 9, 4), s(12, 3, 9, 3), s(14, 3, 9, 1),
+    s(10, 0, 1, 1), s(13, 1, 1, 1), s(12, 1, 1, 1), s(15, 0, 1, 2), s(9, 2, 1, 1), s(0, 1, 4, 4), s(9, 0, 3, 1), s(8, 1, 4, 4),
+    s(2, 1, 0, 3), s(1, 0, 2, 4),
+    s(1, 0, 5, 1), s(1, 0, 2, 4),
+    s(3, 0, 12, 1),
+    s(21, 0, 8, 3), s(21, 0, 8, 4),
+    s(5, 0, 7, 3), s(16, 0, 8, 2),
+    s(18, 2, 0, 3), s(7, 2, 0, 3), s(18, 2, 0, 4), s(7, 0, 8, 4), s(18, 2, 9, 2),
+    s(0, 2, 2, 4), s(11, 2, 2, 4), s(11, 2, 0, 4),
+    s(15, 0, 0, 4), s(12, 1, 4, 2),
+    s(10, 2, 2, 4), s(6, 0, 11, 3), s(9, 2, 11, 4),
+    s(8, 3, 4, 4), s(8, 1, 0, 4), s(0, 3, 4, 4),
+    s(6, 1, 0, 1), s(15, 0, 0, 4), s(15, 0, 5, 1),
+    s(20, 0, 1, 1), s(21, 0, 1, 3),
+    s(17, 2, 0, 1), s(8, 2, 0, 4),
+    s(12, 3, 10, 4), s(2, 0, 6, 4),
+    s(11, 2, 2, 1), s(11, 2, 2, 4), s(13, 3, 4, 4),
+    s(8, 0, 1, 4), s(8, 0, 5, 4),
+    s(8, 2, 0, 2), s(8, 2, 2, 2),
+    s(19, 0, 9, 3), s(20, 2, 9, 2),
+    s(14, 1, 9, 1), s(12, 1, 9, 1),
+    s(18, 0, 11, 3), s(18, 0, 11, 4), s(7, 1, 12, 2), s(14, 1, 11, 3),
+    s(16, 0, 9, 1), s(17, 0, 9, 4),
+    s(17, 0, 3, 4), s(15, 0, 3, 2), s(7, 1, 4, 4), s(17, 0, 1, 4),
+    s(8, 2, 2, 2), s(8, 2, 2, 3),
+    s(19, 0, 9, 3), s(20, 2, 9, 2), s(19, 2, 9, 1), s(19, 0, 9, 4),
+    s(8, 2, 9, 2), s(8, 1, 9, 4),
+    s(6, 0, 9, 1), s(7, 0, 9, 4),
+    s(8, 1, 0, 4), s(17, 0, 5, 4),
+    s(5, 0, 11, 3), s(6, 0, 11, 3),
+    s(15, 0, 0, 1), s(15, 0, 0, 4), s(13, 1, 0, 2),
+    s(13, 1, 0, 1), s(12, 1, 0, 1),
+    s(1, 0, 9, 1), s(1, 1, 10, 1),
+    s(4, 0, 11, 4), s(4, 0, 11, 3), s(4, 0, 11, 1),
+    s(15, 0, 12, 4), s(15, 0, 12, 1),
+    s(0, 2, 0, 4), s(3, 0, 8, 2),
+    s(16, 2, 0, 4), s(17, 0, 8, 1),
+    s(5, 1, 0, 2), s(11, 2, 1, 2),
+    s(12, 1, 8, 4), s(12, 1, 8, 1),
+    s(16, 0, 0, 4), s(21, 0, 8, 1),
+    s(16, 0, 11, 3), s(16, 0, 12, 4), s(15, 0, 12, 4),
+    s(5, 2, 2, 1), s(13, 3, 4, 4),
+    s(21, 0, 9, 4), s(21, 0, 9, 3), s(21, 0, 9, 1),
+    s(0, 1, 9, 4), s(12, 1, 7, 3),
+    s(5, 2, 10, 1), s(5, 2, 6, 1), s(6, 2, 9, 2), s(5, 1, 7, 1), s(5, 2, 10, 4), s(5, 0, 7, 4), s(15, 2, 10, 3), s(6, 2, 10, 1), s(5, 2, 6, 4), s(6, 2, 10, 2),
+    s(14, 1, 7, 4), s(14, 3, 4, 2),
+    s(12, 1, 7, 3), s(13, 1, 7, 1), s(12, 1, 7, 4),
+    s(17, 2, 0, 4), s(17, 2, 0, 3), s(17, 2, 2, 4),
+    s(0, 0, 5, 2), s(15, 2, 0, 2),
+    s(14, 3, 12, 4),
+    s(15, 2, 2, 2), s(15, 2, 0, 2),
+    s(0, 1, 0, 4), s(5, 2, 0, 4), s(6, 2, 0, 2),
+    s(8, 1, 9, 3), s(8, 1, 9, 2),
+    s(8, 1, 0, 2), s(6, 0, 5, 2),
+    s(1, 0, 9, 1),
+    s(5, 0, 8, 4), s(5, 0, 8, 3), s(15, 2, 0, 3),
+    s(14, 1, 4, 2), s(14, 1, 1, 2), s(16, 0, 1, 2), s(0, 1, 4, 2),
+    s(0, 2, 2, 4), s(9, 2, 9, 3),
+    s(6, 1, 7, 3), s(6, 0, 8, 3),
+    s(16, 0, 0, 4), s(16, 0, 3, 4), s(15, 0, 3, 4),
+    s(0, 1, 10, 2), s(15, 0, 0, 4),
+    s(15, 2, 2, 2), s(16, 2, 2, 4),
+    s(20, 2, 2, 4), s(15, 2, 2, 2),
+    s(21, 0, 0, 1), s(17, 0, 0, 3),
+    s(13, 1, 10, 2), s(12, 1, 10, 3),
+    s(15, 2, 0, 3), s(15, 2, 0, 2),
+    s(4, 0, 11, 1), s(4, 0, 11, 2), s(4, 0, 11, 3), s(2, 0, 11, 2), s(0, 2, 11, 3), s(4, 0, 12, 1),
+    s(16, 0, 9, 3), s(12, 1, 4, 4),
+    s(0, 3, 0, 2), s(0, 3, 0, 1), s(0, 2, 0, 1),
+    s(17, 0, 0, 1), s(0, 1, 0, 4), s(17, 0, 0, 3),
+    s(0, 1, 8, 2), s(8, 1, 8, 2),
+    s(2, 0, 11, 2), s(2, 0, 12, 1), s(1, 0, 12, 1), s(1, 0, 11, 4),
+    s(3, 0, 7, 2), s(3, 0, 7, 4), s(0, 2, 0, 4),
+    s(2, 1, 0, 1), s(1, 1, 0, 4),
+    s(14, 3, 9, 2), s(14, 3, 9, 4),
+    s(19, 2, 0, 2), s(21, 0, 8, 3), s(20, 0, 8, 4), s(19, 0, 8, 4),
+    s(2, 1, 7, 1),
+    s(0, 2, 0, 2), s(3, 0, 2, 2),
+    s(14, 3, 10, 2), s(12, 3, 10, 1),
+    s(9, 0, 1, 1), s(14, 3, 0, 4),
+    s(8, 0, 1, 2),
+    s(9, 0, 9, 4), s(11, 0, 9, 4),
+    s(6, 0, 5, 2), s(0, 1, 12, 1),
+    s(6, 2, 10, 1), s(15, 2, 10, 4),
+    s(0, 0, 11, 2), s(0, 1, 11, 4),
+    s(10, 2, 10, 1), s(11, 2, 10, 2), s(10, 2, 10, 4),
+    s(9, 2, 6, 4), s(12, 3, 12, 3),
+    s(16, 0, 11, 1), s(16, 0, 11, 4),
+    s(3, 1, 12, 2), s(3, 0, 12, 4),
+    s(11, 2, 10, 1), s(11, 2, 10, 4),
+    s(14, 1, 0, 1), s(20, 2, 2, 4),
+    s(14, 1, 10, 1), s(14, 3, 9, 1),
+    s(6, 0, 9, 2), s(0, 3, 0, 4),
+    s(1, 1, 12, 3), s(4, 0, 11, 3),
+    s(0, 1, 12, 4), s(0, 1, 11, 3),
+    s(2, 0, 2, 4), s(2, 0, 6, 4),
+    s(16, 2, 10, 1), s(16, 2, 10, 3),
+    s(3, 0, 6, 4), s(0, 2, 10, 3), s(3, 0, 2, 4),
+    s(14, 3, 0, 4), s(14, 3, 12, 3),
+    s(15, 0, 7, 1), s(15, 0, 7, 4),
+    s(17, 0, 0, 4), s(6, 1, 0, 2),
+    s(17, 0, 0, 4), s(14, 1, 1, 4),
+    s(5, 1, 4, 2), s(5, 1, 4, 4), s(0, 1, 0, 4),
+    s(7, 1, 0, 4), s(7, 1, 0, 3), s(15, 0, 0, 4),
+    s(16, 0, 7, 2), s(15, 0, 7, 1), s(16, 0, 7, 4),
+    s(11, 2, 11, 3), s(11, 2, 11, 4),
+    s(14, 3, 0, 1), s(10, 2, 1, 1),
+    s(17, 0, 11, 3),
+    s(0, 3, 10, 1), s(0, 3, 10, 4),
+    s(16, 0, 12, 2), s(17, 0, 12, 4), s(12, 1, 12, 1),
+    s(12, 1, 12, 3), s(0, 1, 12, 3),
+    s(13, 1, 0, 3), s(5, 2, 0, 4),
+    s(0, 0, 9, 4), s(0, 0, 9, 3), s(0, 1, 9, 3),
+    s(9, 0, 12, 4), s(14, 3, 9, 3),
+    s(14, 1, 1, 2), s(14, 1, 1, 4), s(12, 1, 1, 3),
+    s(3, 1, 10, 3), s(3, 1, 10, 2),
+    s(17, 2, 0, 3), s(5, 2, 0, 3),
+    s(12, 1, 9, 3), s(8, 0, 9, 2),
+    s(7, 2, 9, 3), s(14, 3, 9, 1),
+    s(9, 0, 7, 3), s(11, 0, 7, 4),
+    s(0, 0, 5, 4), s(7, 2, 9, 3),
+    s(12, 1, 0, 4), s(12, 1, 4, 4),
+    s(1, 0, 7, 4), s(2, 2, 0, 4), s(1, 0, 2, 2),
+    s(14, 1, 11, 3), s(14, 1, 11, 4), s(17, 0, 11, 3),
+    s(2, 2, 0, 4), s(1, 0, 7, 4),
+    s(13, 3, 0, 1), s(13, 3, 0, 3),
+    s(11, 0, 3, 2), s(0, 0, 3, 4), s(11, 0, 3, 4),
+    s(20, 0, 12, 1),
+    s(20, 0, 12, 2), s(19, 0, 12, 1),
+    s(19, 2, 6, 4), s(20, 2, 2, 1),
+    s(20, 0, 9, 3), s(13, 1, 9, 2), s(12, 1, 9, 4),
+    s(11, 2, 6, 4), s(10, 2, 5, 4), s(10, 2, 2, 4),
+    s(0, 3, 4, 4), s(18, 2, 0, 4),
+    s(0, 1, 8, 3), s(0, 1, 8, 4), s(0, 2, 6, 3),
+    s(18, 2, 9, 3), s(0, 2, 9, 3),
+    s(4, 0, 10, 2), s(1, 0, 9, 1),
+    s(4, 2, 0, 2), s(4, 2, 0, 4), s(1, 1, 0, 4), s(1, 0, 2, 2),
+    s(4, 0, 6, 3), s(10, 2, 0, 1),
+    s(13, 3, 0, 2), s(14, 3, 0, 1), s(14, 3, 0, 4), s(16, 2, 10, 3),
+    s(6, 1, 7, 3), s(6, 1, 7, 4), s(0, 1, 8, 2),
+    s(19, 2, 6, 1), s(12, 3, 9, 1),
+    s(11, 2, 11, 1), s(3, 0, 11, 2), s(0, 2, 11, 2), s(3, 0, 12, 4),
+    s(16, 0, 7, 2), s(15, 0, 7, 1), s(15, 2, 0, 1),
+    s(12, 1, 0, 1), s(13, 1, 0, 1),
+    s(6, 2, 12, 2), s(16, 2, 11, 2),
+    s(3, 0, 12, 2), s(3, 0, 11, 3),
+    s(8, 2, 12, 2), s(8, 2, 12, 3),
+    s(3, 0, 2, 4), s(3, 0, 3, 5),
+    s(1, 0, 10, 3), s(1, 0, 10, 1),
+    s(15, 0, 1, 2), s(0, 1, 1, 4),
+    s(17, 2, 0, 4), s(15, 2, 0, 2),
+    s(17, 2, 0, 4), s(15, 2, 0, 2), s(17, 2, 0, 2),
+    s(15, 2, 0, 1), s(17, 2, 0, 1),
+    s(2, 2, 0, 3), s(2, 1, 7, 2), s(2, 0, 2, 4), s(2, 2, 0, 1), s(2, 0, 2, 1),
+    s(5, 0, 7, 1), s(3, 2, 0, 4), s(6, 1, 7, 2),
+    s(9, 2, 6, 3), s(13, 1, 8, 2),
+    s(12, 1, 0, 1), s(0, 2, 6, 4),
+    s(14, 1, 8, 3),
+    s(16, 0, 12, 2), s(15, 0, 12, 1), s(16, 0, 12, 1), s(6, 1, 12, 1),
+    s(19, 0, 1, 2), s(5, 2, 2, 3),
+    s(0, 3, 0, 2), s(0, 2, 0, 1),
+    s(9, 0, 9, 1), s(9, 0, 9, 3), s(9, 0, 9, 4),
+    s(16, 0, 1, 1), s(16, 0, 1, 4),
+    s(17, 0, 9, 1), s(17, 0, 1, 1),
+    s(0, 2, 0, 4), s(0, 2, 2, 4),
+    s(20, 2, 10, 1),
+    s(18, 0, 10, 4), s(0, 0, 13, 2),
+    s(1, 1, 7, 1), s(17, 0, 7, 2), s(17, 2, 2, 2), s(5, 1, 0, 2), s(15, 2, 2, 2),
+    s(5, 1, 0, 4), s(5, 2, 2, 4),
+    s(9, 0, 5, 4), s(9, 0, 3, 2),
+    s(5, 2, 0, 4), s(5, 2, 0, 3), s(6, 2, 0, 2),
+    s(8, 1, 0, 2), s(15, 0, 0, 4), s(0, 1, 0, 2), s(6, 2, 2, 4), s(5, 2, 2, 4),
+    s(9, 0, 11, 1), s(9, 0, 11, 4), s(9, 2, 12, 1),
+    s(12, 1, 11, 5),
+    s(21, 0, 0, 4), s(15, 0, 0, 3), s(14, 1, 0, 3),
+    s(0, 3, 9, 2), s(0, 3, 9, 4),
+    s(11, 0, 11, 2), s(10, 0, 11, 4), s(10, 0, 11, 1),
+    s(4, 0, 6, 4), s(1, 0, 6, 4),
+    s(14, 1, 9, 1), s(13, 1, 9, 1),
+    s(2, 0, 1, 2), s(1, 0, 1, 4),
+    s(17, 2, 0, 1), s(5, 2, 6, 4),
+    s(16, 0, 8, 3), s(7, 1, 8, 3),
+    s(15, 2, 0, 4), s(17, 2, 0, 4),
+    s(0, 2, 2, 4), s(0, 3, 4, 4),
+    s(2, 1, 0, 2), s(1, 1, 0, 3), s(1, 1, 0, 4), s(2, 1, 0, 1),
+    s(0, 2, 11, 3), s(10, 2, 11, 2),
+    s(4, 0, 11, 1), s(4, 0, 11, 3), s(1, 1, 12, 3),
+    s(4, 2, 0, 2), s(4, 2, 0, 1),
+    s(14, 1, 0, 1), s(21, 0, 0, 1),
+    s(0, 1, 1, 1), s(0, 1, 4, 1), s(0, 1, 1, 2), s(0, 1, 1, 4),
+    s(5, 0, 8, 3), s(15, 2, 0, 3),
+    s(15, 0, 10, 3), s(16, 0, 10, 2),
+    s(0, 1, 7, 1), s(0, 1, 7, 3),
+    s(18, 2, 6, 4), s(7, 0, 10, 4),
+    s(0, 0, 3, 3), s(0, 0, 3, 4),
+    s(9, 2, 2, 3), s(8, 2, 2, 3), s(9, 2, 9, 4),
+    s(15, 0, 0, 1), s(13, 1, 0, 2),
+    s(19, 2, 6, 5),
+    s(14, 1, 9, 1), s(15, 0, 10, 1),
+    s(6, 0, 5, 2), s(21, 0, 0, 4), s(20, 0, 0, 2),
+    s(12, 1, 1, 1), s(12, 1, 1, 4),
+    s(9, 0, 8, 3), s(9, 0, 8, 1), s(12, 3, 0, 3), s(13, 3, 0, 2),
+    s(1, 0, 7, 1), s(4, 2, 0, 2),
+    s(0, 1, 0, 4), s(14, 1, 4, 4),
+    s(5, 2, 2, 4), s(6, 2, 2, 2), s(6, 2, 2, 3),
+    s(0, 1, 0, 2), s(5, 2, 2, 4), s(8, 1, 0, 2),
+    s(7, 1, 0, 3), s(16, 0, 0, 4),
+    s(1, 0, 9, 4), s(2, 0, 9, 2), s(2, 0, 9, 4),
+    s(0, 1, 11, 3), s(0, 1, 11, 4), s(0, 1, 12, 1),
+    s(4, 2, 0, 1), s(4, 2, 0, 3), s(4, 2, 0, 4),
+    s(1, 0, 5, 3), s(1, 0, 2, 2), s(1, 0, 2, 4),
+    s(3, 0, 8, 3), s(3, 0, 6, 2),
+    s(9, 0, 9, 1), s(13, 1, 9, 2),
+    s(17, 0, 7, 2), s(17, 0, 7, 4),
+    s(14, 1, 1, 2), s(12, 1, 1, 3),
+    s(0, 1, 8, 4), s(0, 1, 8, 2), s(15, 0, 8, 2),
+    s(9, 2, 6, 4), s(12, 3, 0, 3),
+    s(15, 0, 1, 4), s(19, 2, 2, 4), s(19, 0, 3, 2),
+    s(7, 0, 9, 2), s(18, 0, 9, 2),
+    s(7, 1, 12, 2), s(16, 2, 0, 3), s(15, 2, 0, 4),
+    s(5, 1, 0, 3), s(5, 1, 0, 4), s(16, 0, 0, 2),
+    s(15, 0, 1, 1), s(19, 2, 0, 3), s(19, 2, 0, 1),
+    s(16, 0, 1, 2), s(15, 0, 1, 1), s(16, 0, 5, 2),
+    s(1, 2, 0, 4), s(2, 2, 0, 1),
+    s(1, 0, 1, 1), s(4, 2, 0, 2), s(1, 0, 2, 2), s(1, 1, 4, 1), s(2, 0, 6, 4),
+    s(5, 2, 2, 4), s(19, 2, 2, 2), s(0, 2, 0, 4),
+    s(15, 2, 0, 4), s(15, 2, 0, 3),
+    s(16, 0, 5, 2), s(20, 0, 0, 1), s(15, 0, 5, 4), s(19, 0, 0, 4),
+    s(17, 0, 9, 1), s(15, 0, 1, 4),
+    s(16, 2, 0, 4), s(15, 2, 0, 4),
+    s(2, 0, 6, 1), s(1, 0, 6, 1),
+    s(17, 0, 0, 4), s(4, 0, 6, 4),
+    s(16, 0, 12, 1), s(12, 3, 4, 2),
+    s(15, 0, 1, 4), s(17, 0, 9, 1), s(20, 0, 3, 4),
+    s(8, 1, 0, 4), s(0, 3, 4, 4),
+    s(17, 2, 9, 1), s(17, 2, 9, 4), s(13, 3, 9, 2),
+    s(21, 0, 3, 4), s(20, 0, 0, 4),
+    s(9, 2, 1, 1), s(6, 1, 9, 3), s(10, 2, 2, 4),
+    s(1, 0, 10, 1), s(1, 1, 12, 1),
+    s(14, 1, 7, 4), s(12, 1, 7, 4), s(12, 1, 7, 3), s(13, 1, 7, 1),
+    s(14, 3, 0, 3), s(0, 3, 0, 3),
+    s(12, 1, 9, 4), s(19, 2, 10, 4),
+    s(0, 1, 8, 3), s(0, 3, 0, 4),
+    s(0, 1, 11, 4), s(0, 1, 11, 2),
+    s(11, 0, 3, 2), s(11, 2, 0, 2), s(9, 0, 5, 1), s(10, 0, 5, 4),
+    s(9, 0, 10, 1),
+    s(0, 1, 0, 4), s(15, 0, 0, 1), s(15, 0, 0, 3),
+    s(9, 0, 3, 2), s(8, 2, 2, 4), s(11, 0, 3, 4), s(9, 0, 3, 1),
+    s(19, 0, 5, 1), s(19, 0, 5, 4),
+    s(11, 0, 12, 2), s(11, 0, 11, 2), s(11, 0, 11, 4),
+    s(6, 0, 7, 2), s(6, 1, 7, 1), s(15, 0, 7, 4),
+    s(0, 2, 6, 2), s(9, 2, 6, 3),
+    s(10, 2, 11, 1), s(10, 2, 11, 4), s(10, 2, 11, 2),
+    s(0, 0, 9, 1), s(0, 0, 9, 4),
+    s(12, 3, 9, 4), s(13, 3, 9, 1),
+    s(6, 2, 12, 2), s(6, 2, 12, 1), s(5, 2, 12, 4),
+    s(21, 0, 11, 1),
+    s(12, 3, 0, 2), s(12, 1, 4, 2), s(14, 1, 4, 2),
+    s(0, 3, 0, 2), s(3, 0, 8, 2),
+    s(9, 2, 6, 4), s(11, 2, 6, 4),
+    s(15, 0, 10, 1), s(16, 0, 10, 2), s(15, 0, 10, 4),
+    s(2, 0, 2, 5), s(1, 0, 2, 2),
+    s(4, 0, 12, 1), s(4, 0, 12, 4),
+    s(21, 2, 0, 4), s(0, 1, 10, 4),
+    s(6, 1, 12, 3), s(6, 1, 12, 4),
+    s(14, 3, 9, 1), s(12, 3, 9, 1), s(14, 1, 4, 2),
+    s(6, 2, 0, 2), s(16, 0, 1, 2), s(6, 2, 0, 1),
+    s(0, 0, 7, 1), s(0, 1, 8, 4),
+    s(9, 2, 0, 4), s(12, 3, 4, 2),
+    s(12, 1, 9, 3), s(14, 1, 9, 4), s(12, 1, 9, 4),
+    s(6, 1, 7, 2), s(6, 1, 7, 1),
+    s(11, 2, 10, 2), s(10, 2, 9, 3),
+    s(17, 0, 7, 1), s(17, 0, 7, 4), s(14, 1, 7, 1), s(21, 0, 7, 4),
+    s(20, 0, 10, 2), s(16, 0, 10, 2), s(13, 1, 10, 2),
+    s(0, 2, 0, 2), s(0, 2, 0, 4), s(0, 3, 0, 3),
+    s(8, 1, 0, 2), s(21, 0, 0, 4), s(13, 1, 0, 3),
+    s(13, 1, 10, 3), s(13, 1, 10, 1),
+    s(21, 2, 2, 1), s(14, 3, 10, 4),
+    s(6, 1, 0, 1), s(6, 1, 0, 2),
+    s(1, 1, 10, 1), s(1, 1, 12, 1),
+    s(15, 2, 2, 1), s(6, 2, 2, 1), s(18, 2, 6, 4),
+    s(13, 1, 0, 2), s(12, 1, 0, 1),
+    s(9, 2, 10, 4), s(11, 2, 10, 4), s(0, 0, 7, 1), s(9, 2, 10, 3),
+    s(5, 0, 3, 2), s(15, 0, 3, 2),
+    s(4, 0, 6, 3), s(4, 0, 6, 2),
+    s(2, 0, 5, 2), s(1, 0, 6, 4), s(2, 0, 6, 4),
+    s(1, 0, 11, 4), s(1, 0, 6, 4), s(2, 0, 8, 3), s(2, 0, 6, 2), s(1, 0, 6, 1),
+    s(16, 0, 12, 2), s(16, 0, 11, 2),
+    s(5, 1, 0, 4), s(6, 1, 0, 4), s(5, 0, 5, 4),
+    s(15, 0, 9, 4), s(15, 0, 9, 3), s(16, 0, 10, 2),
+    s(13, 3, 9, 1), s(12, 3, 9, 4), s(13, 3, 9, 2),
+    s(21, 0, 10, 1),
+    s(18, 0, 10, 3), s(17, 0, 10, 3),
+    s(8, 0, 12, 2), s(8, 0, 12, 1), s(8, 1, 12, 2), s(8, 0, 12, 4), s(16, 0, 12, 1),
+    s(4, 2, 0, 2), s(21, 2, 0, 4),
+    s(10, 0, 3, 1), s(10, 2, 9, 3), s(10, 0, 3, 3),
+    s(19, 0, 8, 1), s(21, 0, 8, 3),
+    s(15, 0, 7, 4), s(15, 2, 2, 1),
+    s(4, 0, 10, 2), s(4, 0, 10, 4), s(4, 0, 10, 1),
+    s(17, 0, 10, 1), s(16, 0, 10, 1),
+    s(7, 1, 0, 2), s(7, 1, 4, 4),
+    s(12, 1, 4, 1), s(12, 1, 4, 2), s(13, 1, 4, 4),
+    s(0, 1, 0, 3), s(0, 1, 0, 1),
+    s(16, 0, 8, 2), s(15, 0, 8, 4), s(5, 1, 7, 1),
+    s(19, 2, 2, 2), s(20, 2, 6, 4),
+    s(13, 1, 11, 1), s(10, 2, 12, 1),
+    s(16, 2, 6, 2), s(15, 2, 6, 1),
+    s(0, 1, 1, 1), s(0, 0, 3, 3),
+    s(1, 0, 6, 1), s(2, 1, 0, 2), s(1, 1, 0, 4), s(2, 0, 5, 2),
+    s(3, 0, 10, 1),
+    s(13, 3, 9, 5),
+    s(5, 2, 2, 3), s(16, 2, 9, 2),
+    s(0, 2, 6, 3), s(11, 2, 6, 1),
+    s(12, 1, 1, 3), s(12, 1, 1, 1),
+    s(12, 1, 9, 1), s(11, 0, 9, 2),
+    s(17, 0, 10, 4), s(15, 0, 10, 1),
+    s(0, 1, 9, 4), s(0, 1, 1, 4),
+    s(12, 1, 9, 1), s(15, 0, 9, 4),
+    s(15, 0, 1, 1), s(16, 0, 1, 2),
+    s(11, 2, 2, 4), s(9, 2, 2, 1), s(10, 2, 1, 3),
+    s(4, 0, 12, 1), s(4, 0, 9, 2),
+    s(14, 1, 4, 1), s(14, 1, 4, 4),
+    s(15, 0, 0, 4), s(12, 1, 0, 2),
+    s(12, 1, 9, 4), s(12, 1, 9, 3),
+    s(11, 2, 0, 4), s(10, 2, 0, 3),
+    s(8, 0, 12, 2), s(8, 0, 12, 4),
+    s(0, 1, 8, 2), s(0, 1, 8, 3),
+    s(3, 0, 6, 2), s(3, 0, 6, 3),
+    s(21, 2, 12, 3), s(20, 2, 12, 1),
+    s(14, 3, 9, 4), s(0, 3, 9, 2),
+    s(0, 1, 4, 4), s(5, 1, 4, 2),
+    s(12, 1, 4, 2), s(13, 1, 1, 4),
+    s(16, 2, 0, 3), s(15, 2, 0, 1),
+    s(5, 2, 10, 4), s(17, 2, 10, 3), s(16, 2, 10, 1),
+    s(19, 0, 8, 4), s(20, 0, 8, 1),
+    s(6, 1, 0, 4), s(5, 1, 0, 3), s(17, 0, 0, 4),
+    s(12, 1, 0, 2), s(12, 1, 0, 3),
+    s(10, 0, 5, 3), s(12, 1, 4, 1), s(12, 1, 4, 4),
+    s(18, 0, 8, 3), s(18, 0, 8, 4),
+    s(9, 0, 5, 4), s(9, 2, 6, 4), s(12, 1, 4, 2),
+    s(0, 2, 10, 1), s(0, 3, 10, 4),
+    s(16, 2, 0, 5),
+    s(15, 0, 9, 3), s(16, 0, 9, 3), s(7, 1, 9, 4), s(15, 0, 10, 4),
+    s(4, 2, 0, 2), s(4, 2, 0, 4), s(1, 0, 2, 2),
+    s(12, 1, 9, 4), s(12, 1, 10, 4),
+    s(1, 0, 11, 3), s(1, 0, 12, 1), s(1, 0, 11, 4), s(2, 0, 11, 2), s(2, 0, 12, 2),
+    s(17, 0, 1, 1), s(14, 1, 4, 4),
+    s(9, 0, 9, 4), s(11, 0, 9, 2),
+    s(9, 0, 7, 3), s(10, 0, 7, 4),
+    s(9, 0, 7, 3), s(10, 0, 7, 4), s(9, 0, 7, 1),
+    s(5, 1, 9, 1), s(15, 0, 10, 3), s(15, 0, 10, 1),
+    s(9, 0, 8, 4), s(12, 3, 4, 2),
+    s(16, 2, 6, 2), s(15, 2, 6, 4), s(5, 2, 6, 1),
+    s(13, 1, 11, 1), s(16, 0, 12, 1), s(13, 1, 11, 3),
+    s(13, 1, 9, 3), s(14, 1, 9, 4), s(8, 1, 9, 2),
+    s(10, 0, 9, 3), s(12, 1, 9, 4),
+    s(14, 1, 0, 2), s(5, 1, 4, 2),
+    s(12, 1, 0, 1), s(9, 2, 6, 1),
+    s(6, 2, 9, 2), s(17, 2, 9, 4), s(13, 3, 9, 2),
+    s(13, 1, 0, 1), s(13, 1, 0, 4), s(19, 2, 0, 2), s(21, 0, 3, 4),
+    s(21, 0, 10, 1), s(17, 0, 10, 3),
+    s(0, 1, 8, 3), s(16, 0, 7, 3),
+    s(12, 1, 11, 3), s(12, 1, 11, 1),
+    s(7, 1, 4, 4), s(14, 1, 4, 4), s(0, 1, 0, 4),
+    s(3, 0, 9, 4), s(0, 2, 9, 4), s(3, 0, 9, 2),
+    s(5, 1, 0, 2), s(15, 0, 0, 2), s(15, 0, 3, 2), s(5, 1, 0, 1),
+    s(15, 2, 11, 1), s(16, 2, 12, 1),
+    s(8, 0, 3, 4), s(0, 3, 4, 4), s(0, 1, 7, 4), s(8, 2, 2, 4), s(8, 1, 7, 2),
+    s(4, 0, 9, 2), s(4, 0, 9, 4),
+    s(8, 0, 6, 3), s(8, 0, 6, 2),
+    s(16, 0, 7, 2), s(16, 0, 7, 1), s(12, 1, 7, 3),
+    s(6, 0, 11, 2), s(16, 0, 12, 1),
+    s(1, 1, 7, 1), s(1, 1, 7, 4),
+    s(8, 2, 0, 4), s(5, 2, 0, 2),
+    s(12, 1, 8, 1), s(8, 1, 7, 2),
+    s(17, 2, 0, 1), s(0, 0, 8, 1),
+    s(3, 0, 9, 2), s(8, 0, 11, 3),
+    s(3, 0, 2, 2), s(3, 2, 0, 2),
+    s(7, 1, 7, 3), s(3, 2, 0, 4),
+    s(0, 1, 11, 4), s(14, 1, 11, 4),
+    s(11, 0, 12, 2), s(11, 0, 12, 4), s(9, 2, 11, 1), s(9, 2, 11, 4), s(11, 2, 11, 2), s(11, 2, 11, 4),
+    s(0, 0, 13, 4), s(15, 0, 0, 4),
+    s(2, 2, 0, 3), s(2, 2, 0, 2),
+    s(4, 0, 1, 2), s(4, 0, 6, 4),
+    s(18, 0, 7, 2), s(7, 0, 7, 2),
+    s(13, 1, 7, 2), s(12, 1, 7, 1), s(12, 1, 7, 4), s(13, 1, 7, 1), s(12, 1, 7, 3),
+    s(15, 0, 9, 3), s(12, 1, 9, 3),
+    s(6, 2, 2, 2), s(5, 2, 0, 4), s(8, 2, 2, 4),
+    s(5, 2, 10, 1), s(6, 2, 6, 2),
+    s(16, 0, 12, 2), s(5, 0, 12, 4), s(16, 0, 10, 2),
+    s(21, 2, 0, 4), s(14, 1, 7, 1), s(13, 1, 8, 1),
+    s(5, 1, 9, 4), s(6, 0, 9, 2), s(14, 1, 10, 2),
+    s(7, 2, 2, 3), s(7, 2, 2, 2),
+    s(6, 2, 2, 3), s(5, 2, 2, 3),
+    s(7, 1, 12, 3),
+    s(18, 2, 6, 3),
+    s(6, 2, 12, 2), s(16, 2, 11, 2), s(15, 2, 12, 1), s(16, 2, 12, 1),
+    s(19, 0, 12, 1), s(20, 0, 12, 2),
+    s(4, 0, 10, 2), s(4, 0, 10, 4), s(4, 0, 6, 4),
+    s(18, 0, 9, 3), s(0, 1, 9, 1),
+    s(12, 1, 9, 5),
+    s(17, 2, 0, 1), s(13, 1, 7, 1), s(21, 0, 7, 1),
+    s(6, 0, 9, 2), s(17, 0, 9, 4),
+    s(0, 1, 9, 2), s(5, 0, 9, 1),
+    s(8, 0, 6, 2), s(8, 0, 6, 4),
+    s(1, 0, 2, 4), s(1, 1, 0, 4),
+    s(2, 1, 12, 2), s(1, 0, 2, 4),
+    s(9, 2, 6, 4), s(10, 2, 5, 4), s(11, 2, 6, 4),
+    s(12, 1, 4, 3), s(14, 1, 4, 4),
+    s(13, 1, 12, 2), s(12, 1, 12, 4),
+    s(15, 0, 5, 2), s(17, 0, 0, 4), s(6, 2, 0, 2),
+    s(16, 0, 1, 2), s(21, 0, 1, 4),
+    s(6, 0, 7, 2), s(16, 0, 8, 2), s(5, 0, 7, 4),
+    s(11, 2, 2, 4), s(11, 2, 1, 4),
+    s(7, 1, 0, 3), s(3, 1, 0, 2),
+    s(7, 0, 8, 4), s(18, 2, 9, 3), s(18, 2, 0, 2),
+    s(15, 0, 7, 4), s(5, 1, 0, 2),
+    s(12, 1, 0, 1), s(12, 1, 0, 4),
+    s(11, 2, 11, 3), s(9, 2, 11, 4), s(9, 2, 2, 3), s(9, 2, 0, 3),
+    s(8, 3, 0, 4), s(16, 2, 0, 1),
+    s(3, 1, 4, 4), s(3, 0, 6, 4),
+    s(8, 1, 0, 4), s(8, 2, 2, 4), s(0, 3, 4, 4),
+    s(15, 2, 2, 2), s(15, 2, 0, 4),
+    s(16, 0, 10, 4), s(13, 1, 10, 4), s(9, 2, 9, 4),
+    s(12, 1, 4, 2), s(12, 1, 0, 4),
+    s(11, 2, 5, 2), s(9, 2, 6, 1),
+    s(12, 1, 7, 5),
+    s(14, 1, 7, 1), s(13, 1, 8, 1), s(14, 1, 8, 1),
+    s(12, 1, 9, 1), s(17, 0, 9, 1),
+    s(0, 1, 8, 2), s(0, 1, 8, 4),
+    s(14, 1, 11, 1), s(18, 0, 11, 4),
+    s(8, 0, 9, 2), s(8, 1, 9, 4),
+    s(0, 2, 6, 2), s(19, 2, 6, 4),
+    s(13, 3, 9, 2), s(9, 2, 9, 4),
+    s(8, 1, 0, 4), s(8, 1, 0, 3),
+    s(20, 2, 9, 2), s(19, 2, 9, 4),
+    s(8, 0, 6, 2), s(8, 2, 2, 3),
+    s(9, 2, 11, 5),
+    s(20, 0, 0, 4), s(19, 0, 0, 1), s(20, 0, 0, 2),
+    s(0, 1, 0, 4), s(11, 2, 9, 1), s(0, 3, 0, 4),
+    s(13, 1, 9, 1), s(11, 0, 9, 1), s(14, 1, 9, 1), s(13, 1, 9, 2),
+    s(16, 2, 0, 4), s(14, 1, 0, 4), s(13, 3, 0, 4),
+    s(10, 0, 5, 4), s(0, 0, 5, 4),
+    s(0, 1, 0, 4), s(0, 1, 10, 1),
+    s(14, 1, 0, 4), s(10, 0, 5, 4),
+    s(11, 0, 3, 1), s(14, 1, 1, 2),
+    s(17, 2, 2, 4), s(21, 0, 8, 4),
+    s(0, 0, 5, 1), s(0, 0, 5, 3), s(14, 1, 4, 4), s(0, 0, 6, 2), s(0, 0, 6, 3), s(0, 0, 6, 4), s(0, 0, 6, 1),
+    s(16, 2, 1, 1), s(14, 3, 0, 1),
+    s(16, 0, 0, 3), s(16, 2, 5, 4),
+    s(13, 1, 10, 1), s(13, 1, 10, 4), s(0, 1, 10, 2),
+    s(10, 2, 9, 3), s(14, 1, 10, 1),
+    s(10, 0, 9, 3), s(13, 1, 9, 4), s(5, 0, 9, 4),
+    s(10, 0, 9, 3), s(10, 0, 3, 4), s(13, 1, 9, 3),
+    s(16, 2, 9, 3), s(16, 2, 9, 2),
+    s(17, 0, 1, 4), s(14, 1, 1, 2),
+    s(14, 1, 4, 1), s(0, 1, 1, 4),
+    s(0, 2, 0, 1), s(0, 1, 11, 1),
+    s(12, 1, 10, 4), s(13, 3, 10, 1),
+    s(0, 0, 8, 1), s(0, 0, 8, 3),
+    s(6, 1, 0, 4), s(14, 1, 7, 1),
+    s(2, 0, 10, 1),
+    s(14, 1, 0, 3), s(0, 1, 0, 3),
+    s(17, 0, 3, 4), s(14, 1, 0, 1), s(14, 1, 4, 2),
+    s(11, 0, 9, 1), s(8, 1, 9, 3),
+    s(0, 2, 5, 1), s(0, 2, 5, 3),
+    s(16, 0, 0, 2), s(15, 0, 0, 4),
+    s(21, 0, 3, 4), s(17, 0, 1, 4),
+    s(21, 2, 6, 4), s(21, 2, 2, 4),
+    s(9, 2, 6, 1), s(10, 2, 6, 4), s(10, 2, 6, 2),
+    s(5, 0, 5, 3), s(0, 0, 3, 4), s(5, 0, 5, 1),
+    s(21, 0, 0, 3),
+    s(3, 0, 2, 4), s(0, 2, 10, 3),
+    s(2, 1, 7, 3), s(1, 1, 0, 4),
+    s(13, 1, 12, 2), s(12, 1, 12, 1), s(12, 1, 12, 4),
+    s(4, 0, 8, 3), s(0, 1, 4, 4), s(1, 0, 2, 2),
+    s(15, 0, 0, 2), s(17, 0, 0, 5), s(17, 0, 0, 4),
+    s(0, 1, 4, 4), s(0, 1, 9, 4), s(0, 1, 9, 1),
+    s(11, 2, 10, 1), s(3, 0, 10, 4),
+    s(11, 2, 6, 4), s(10, 2, 6, 4),
+    s(0, 1, 10, 1), s(0, 1, 9, 1), s(0, 1, 10, 3),
+    s(13, 1, 12, 4), s(10, 0, 12, 1), s(17, 0, 12, 1),
+    s(17, 0, 1, 1), s(17, 0, 5, 4), s(21, 0, 1, 4), s(14, 1, 4, 4), s(17, 0, 0, 4),
+    s(14, 1, 7, 2), s(0, 1, 7, 2), s(14, 1, 7, 4),
+    s(11, 2, 6, 3), s(11, 2, 6, 4),
+    s(9, 2, 0, 3), s(9, 2, 0, 1),
+    s(0, 0, 8, 1), s(10, 0, 8, 1), s(13, 3, 0, 1),
+    s(0, 2, 0, 2), s(3, 0, 8, 2),
+    s(3, 2, 0, 3), s(3, 2, 0, 2), s(0, 2, 0, 3), s(0, 2, 0, 2),
+    s(5, 2, 0, 2), s(5, 0, 5, 4),
+    s(1, 1, 0, 3), s(1, 1, 0, 4), s(2, 1, 0, 2), s(2, 1, 0, 3),
+    s(3, 0, 7, 2), s(3, 0, 7, 4),
+    s(3, 2, 0, 4), s(3, 0, 7, 4),
+    s(14, 3, 10, 2), s(14, 3, 10, 4),
+    s(5, 0, 8, 4), s(7, 2, 2, 4),
+    s(20, 2, 6, 4), s(13, 1, 7, 1), s(14, 1, 1, 1),
+    s(21, 0, 5, 1), s(21, 2, 6, 1),
+    s(17, 2, 0, 1), s(0, 3, 0, 2),
+    s(11, 0, 3, 2), s(10, 0, 3, 3), s(5, 0, 1, 1),
+    s(8, 3, 0, 2), s(17, 2, 0, 1), s(0, 3, 0, 2), s(5, 0, 8, 1),
+    s(3, 0, 7, 2), s(8, 1, 0, 2),
+    s(18, 2, 12, 3), s(18, 2, 12, 2),
+    s(8, 2, 0, 5),
+    s(17, 0, 0, 4), s(15, 0, 0, 1), s(12, 1, 12, 1),
+    s(5, 1, 0, 1), s(5, 1, 0, 3), s(15, 0, 0, 1),
+    s(13, 1, 0, 4), s(13, 1, 0, 3),
+    s(0, 1, 11, 2), s(18, 0, 0, 4),
+    s(1, 1, 10, 5),
+    s(13, 1, 0, 4), s(14, 1, 0, 4),
+    s(0, 1, 10, 1), s(0, 1, 9, 2),
+    s(0, 3, 10, 1), s(0, 3, 10, 2),
+    s(17, 2, 6, 3),
+    s(17, 2, 6, 5),
+    s(15, 0, 12, 3), s(16, 0, 12, 2), s(15, 0, 12, 4),
+    s(7, 1, 0, 4), s(3, 0, 6, 4),
+    s(6, 2, 10, 3), s(13, 1, 8, 2),
+    s(9, 2, 6, 3), s(12, 1, 8, 3), s(13, 1, 8, 2),
+    s(6, 1, 12, 1), s(6, 1, 12, 4), s(5, 1, 12, 4),
+    s(15, 0, 0, 1), s(14, 1, 4, 2), s(17, 0, 0, 2),
+    s(1, 1, 10, 1), s(2, 0, 1, 4), s(2, 0, 1, 1),
+    s(15, 2, 2, 2), s(0, 3, 4, 4), s(13, 3, 4, 4), s(17, 2, 2, 4),
+    s(16, 2, 9, 4),
+    s(0, 2, 9, 2), s(11, 2, 9, 2),
+    s(4, 0, 9, 4), s(4, 0, 1, 2),
+    s(5, 0, 1, 4), s(6, 0, 5, 4),
+    s(3, 0, 11, 2), s(3, 0, 11, 3),
+    s(13, 1, 8, 2), s(0, 1, 8, 2),
+    s(17, 0, 9, 4), s(17, 2, 9, 4),
+    s(2, 1, 10, 4), s(16, 0, 0, 2),
+    s(11, 0, 9, 4), s(11, 0, 9, 2), s(9, 0, 9, 1),
+    s(0, 2, 0, 1), s(0, 3, 0, 2), s(0, 2, 1, 1), s(0, 3, 0, 1),
+    s(16, 0, 0, 2), s(6, 2, 2, 2), s(16, 0, 3, 4),
+    s(6, 2, 0, 5),
+    s(6, 0, 11, 1), s(17, 0, 11, 1),
+    s(15, 0, 0, 1), s(12, 1, 0, 4),
+    s(13, 1, 9, 1), s(0, 1, 9, 2),
+    s(9, 2, 0, 3), s(0, 3, 0, 4), s(11, 2, 0, 2),
+    s(0, 2, 11, 1), s(0, 2, 11, 3), s(11, 2, 12, 2),
+    s(18, 2, 6, 4), s(6, 2, 10, 1),
+    s(13, 3, 9, 3), s(4, 2, 0, 2),
+    s(9, 0, 9, 4), s(11, 0, 9, 2), s(20, 0, 10, 2),
+    s(0, 2, 10, 4), s(0, 2, 10, 2), s(3, 1, 10, 2), s(3, 0, 10, 2),
+    s(4, 0, 11, 1), s(2, 0, 11, 1),
+    s(12, 3, 4, 2), s(13, 3, 4, 1), s(14, 3, 4, 4),
+    s(11, 2, 0, 3), s(11, 2, 11, 3),
+    s(7, 1, 8, 2), s(0, 1, 8, 2),
+    s(13, 1, 0, 4), s(9, 0, 5, 4), s(0, 1, 0, 3),
+    s(4, 0, 10, 2), s(2, 0, 10, 2), s(4, 0, 10, 1),
+    s(0, 1, 0, 2), s(0, 1, 10, 2),
+    s(11, 0, 11, 4), s(11, 0, 11, 2), s(10, 0, 11, 4),
+    s(0, 1, 9, 3), s(0, 2, 6, 3),
+    s(17, 0, 10, 3), s(16, 0, 10, 2), s(6, 0, 9, 2),
+    s(5, 2, 10, 4), s(15, 2, 9, 4), s(6, 2, 10, 2), s(16, 2, 10, 2),
+    s(11, 2, 0, 4), s(11, 2, 0, 2),
+    s(13, 1, 0, 1), s(13, 1, 4, 4),
+    s(7, 3, 0, 4), s(7, 1, 8, 3),
+    s(5, 0, 1, 2), s(6, 0, 1, 4),
+    s(3, 1, 0, 4), s(0, 2, 0, 4), s(4, 2, 0, 1),
+    s(2, 0, 11, 1), s(6, 1, 9, 1),
+    s(17, 0, 1, 1), s(17, 0, 1, 4), s(21, 2, 2, 1),
+    s(15, 2, 6, 3), s(19, 0, 0, 3),
+    s(3, 0, 6, 2), s(3, 0, 2, 4), s(3, 0, 3, 5),
+    s(0, 0, 8, 1), s(0, 0, 8, 4),
+    s(3, 0, 6, 4), s(11, 2, 6, 4),
+    s(12, 3, 0, 3), s(12, 3, 0, 1), s(12, 3, 0, 4), s(12, 1, 9, 1), s(19, 2, 0, 3),
+    s(6, 2, 2, 1), s(5, 2, 2, 2),
+    s(6, 2, 2, 2), s(5, 2, 2, 4), s(16, 0, 0, 2),
+    s(8, 1, 0, 4), s(15, 0, 10, 3),
+    s(0, 1, 0, 2), s(16, 0, 0, 2), s(17, 0, 0, 4),
+    s(4, 0, 6, 4), s(4, 2, 0, 2),
+    s(15, 0, 0, 4), s(16, 0, 0, 2),
+    s(9, 2, 0, 1), s(9, 2, 0, 3),
+    s(15, 0, 9, 1), s(6, 1, 9, 1), s(5, 1, 9, 4), s(16, 0, 9, 1),
+    s(0, 1, 9, 2), s(0, 1, 9, 3), s(0, 1, 9, 4),
+    s(12, 3, 12, 3), s(0, 1, 12, 2), s(0, 1, 12, 3), s(12, 3, 12, 1),
+    s(12, 3, 0, 1), s(9, 0, 8, 1),
+    s(14, 1, 4, 4), s(0, 1, 0, 4),
+    s(13, 1, 8, 2), s(0, 1, 8, 1),
+    s(2, 0, 2, 1), s(1, 0, 2, 2), s(2, 0, 2, 4),
+    s(11, 2, 6, 4), s(11, 2, 6, 3),
+    s(3, 1, 0, 4), s(1, 1, 0, 4),
+    s(1, 0, 10, 1), s(1, 0, 10, 4),
+    s(15, 2, 0, 2), s(10, 2, 0, 1),
+    s(0, 1, 8, 1), s(0, 0, 7, 1),
+    s(2, 1, 12, 2), s(2, 0, 12, 1),
+    s(20, 0, 0, 3), s(19, 0, 0, 3),
+    s(4, 0, 9, 4), s(4, 0, 12, 3), s(4, 0, 1, 2),
+    s(15, 0, 0, 1), s(16, 0, 0, 2), s(15, 0, 0, 4),
+    s(7, 1, 12, 4), s(15, 2, 0, 4),
+    s(2, 0, 7, 4), s(2, 0, 7, 1), s(2, 0, 7, 2),
+    s(1, 0, 2, 1), s(1, 0, 6, 1), s(1, 1, 0, 4),
+    s(13, 1, 0, 4), s(8, 1, 0, 4), s(21, 0, 3, 4),
+    s(7, 1, 0, 2), s(7, 1, 0, 4), s(7, 1, 0, 3), s(7, 1, 4, 4), s(7, 1, 12, 4),
+    s(21, 0, 1, 4), s(14, 3, 4, 4),
+    s(15, 2, 0, 4), s(15, 0, 8, 4),
+    s(14, 3, 9, 4), s(14, 3, 9, 2), s(12, 3, 9, 1),
+    s(3, 1, 10, 3), s(3, 1, 9, 4),
+    s(1, 0, 12, 4), s(2, 1, 10, 4), s(8, 1, 8, 2),
+    s(8, 2, 12, 2), s(17, 2, 11, 1),
+    s(8, 2, 2, 4), s(2, 0, 2, 1),
+    s(2, 0, 9, 2), s(15, 0, 8, 1),
+    s(11, 2, 6, 2), s(11, 2, 6, 4),
+    s(0, 1, 10, 1), s(0, 1, 9, 1), s(0, 1, 4, 1),
+    s(12, 1, 9, 4), s(20, 2, 10, 2),
+    s(0, 1, 11, 2), s(14, 1, 11, 2), s(0, 1, 11, 3),
+    s(21, 0, 1, 3), s(14, 1, 0, 3), s(14, 1, 9, 3), s(21, 0, 10, 3), s(20, 2, 6, 3), s(14, 3, 10, 4),
+    s(21, 0, 3, 4), s(13, 1, 0, 4), s(19, 0, 0, 4),
+    s(14, 1, 0, 3), s(14, 1, 9, 3),
+    s(12, 1, 11, 4), s(11, 2, 12, 2),
+    s(0, 0, 9, 4), s(0, 1, 9, 4), s(0, 0, 3, 4),
+    s(8, 0, 6, 4), s(8, 0, 6, 3),
+    s(14, 3, 0, 4), s(0, 1, 0, 4),
+    s(21, 2, 0, 4), s(17, 2, 2, 4),
+    s(6, 0, 7, 2), s(0, 1, 7, 2), s(5, 0, 7, 4),
+    s(13, 1, 4, 4), s(12, 1, 4, 2),
+    s(18, 2, 0, 4), s(18, 2, 0, 2),
+    s(9, 2, 11, 1), s(11, 2, 11, 4),
+    s(11, 2, 2, 2), s(9, 2, 2, 1),
+    s(0, 2, 1, 1), s(9, 2, 6, 1),
+    s(13, 1, 1, 4), s(11, 0, 3, 2),
+    s(2, 0, 5, 4), s(3, 0, 5, 4), s(1, 0, 5, 4), s(2, 0, 1, 1),
+    s(0, 2, 0, 1), s(11, 2, 0, 4),
+    s(13, 1, 9, 3), s(12, 1, 9, 1),
+    s(12, 1, 11, 1), s(12, 1, 11, 4),
+    s(11, 2, 0, 3), s(14, 3, 0, 3),
+    s(12, 3, 10, 4), s(14, 3, 10, 4), s(20, 2, 10, 2),
+    s(11, 0, 9, 2), s(11, 0, 9, 4), s(9, 0, 9, 1),
+    s(1, 0, 11, 1), s(1, 1, 10, 1),
+    s(0, 1, 8, 2), s(5, 1, 0, 2),
+    s(0, 1, 12, 3), s(16, 0, 12, 2), s(0, 1, 12, 2), s(15, 0, 12, 4), s(0, 1, 12, 4),
+    s(11, 0, 7, 4), s(9, 0, 7, 3), s(9, 0, 3, 2),
+    s(8, 0, 11, 4), s(8, 0, 11, 2),
+    s(8, 1, 0, 3), s(11, 0, 5, 3),
+    s(8, 1, 9, 4), s(8, 1, 0, 4),
+    s(4, 0, 12, 2), s(11, 2, 12, 2),
+    s(12, 1, 10, 4), s(13, 1, 10, 1),
+    s(21, 2, 6, 1), s(7, 0, 6, 3),
+    s(14, 1, 9, 4), s(12, 1, 9, 3),
+    s(6, 2, 0, 2), s(16, 2, 0, 2), s(0, 1, 4, 2),
+    s(12, 1, 12, 1), s(13, 1, 12, 3),
+    s(17, 0, 3, 4), s(5, 1, 4, 2),
+    s(7, 1, 9, 3), s(18, 0, 10, 3),
+    s(0, 3, 12, 3), s(16, 2, 12, 1),
+    s(14, 1, 9, 2), s(0, 1, 9, 4), s(5, 1, 9, 4),
+    s(21, 2, 0, 4), s(21, 0, 8, 1), s(17, 2, 0, 4),
+    s(6, 2, 10, 1), s(0, 3, 10, 1),
+    s(12, 3, 9, 1), s(0, 3, 9, 4), s(14, 3, 9, 4),
+    s(20, 0, 10, 2), s(13, 1, 9, 2), s(19, 0, 9, 4),
+    s(0, 2, 2, 1), s(9, 2, 2, 1),
+    s(11, 2, 9, 4), s(11, 2, 6, 4),
+    s(15, 0, 11, 3), s(15, 0, 11, 4),
+    s(4, 2, 0, 2), s(2, 0, 8, 2),
+    s(17, 0, 8, 4), s(6, 0, 7, 1),
+    s(17, 2, 9, 4), s(17, 2, 1, 1),
+    s(0, 1, 4, 4), s(17, 0, 0, 4),
+    s(10, 2, 12, 1), s(7, 0, 11, 2),
+    s(0, 2, 2, 4), s(0, 3, 9, 1), s(0, 2, 9, 3),
+    s(6, 2, 2, 1), s(6, 2, 2, 4),
+    s(0, 2, 2, 1),
+    s(15, 2, 2, 1), s(15, 2, 2, 2),
+    s(13, 1, 4, 4), s(12, 1, 0, 2),
+    s(9, 2, 0, 3), s(11, 2, 0, 4),
+    s(9, 2, 2, 3), s(9, 2, 9, 4),
+    s(0, 1, 9, 1), s(11, 0, 9, 4), s(0, 1, 9, 3), s(11, 0, 9, 2),
+    s(8, 1, 10, 2), s(8, 1, 10, 4),
+    s(6, 0, 11, 3), s(16, 0, 11, 4), s(16, 0, 11, 3),
+    s(15, 0, 8, 1), s(5, 1, 7, 1),
+    s(17, 2, 0, 1), s(16, 2, 0, 4),
+    s(13, 1, 0, 1), s(13, 1, 9, 4),
+    s(7, 0, 7, 4), s(15, 0, 7, 4), s(15, 2, 2, 1), s(16, 2, 2, 4),
+    s(20, 2, 12, 2), s(17, 2, 11, 4),
+    s(8, 0, 6, 4), s(8, 1, 0, 4),
+    s(2, 1, 12, 2), s(2, 0, 12, 2),
+    s(4, 0, 6, 2),
+    s(2, 1, 0, 4), s(2, 0, 6, 4),
+    s(5, 0, 9, 4), s(0, 1, 9, 4), s(6, 0, 9, 2),
+    s(12, 1, 12, 4), s(16, 0, 12, 2),
+    s(8, 2, 10, 2), s(8, 2, 10, 3), s(9, 2, 9, 1),
+    s(0, 1, 10, 2), s(0, 1, 9, 4), s(0, 1, 7, 2),
+    s(7, 1, 9, 3), s(17, 0, 10, 3), s(7, 0, 1, 4),
+    s(1, 1, 7, 1), s(11, 2, 0, 1), s(11, 2, 0, 3),
+    s(16, 2, 10, 2), s(15, 2, 10, 1), s(15, 2, 10, 3),
+    s(11, 2, 10, 4), s(9, 2, 10, 3), s(11, 2, 10, 2), s(10, 2, 10, 1),
+    s(0, 1, 9, 1), s(0, 1, 9, 3),
+    s(13, 1, 9, 3), s(12, 1, 9, 1), s(12, 1, 9, 4), s(20, 0, 9, 2), s(19, 0, 9, 4),
+    s(6, 1, 9, 1), s(6, 1, 9, 4),
+    s(0, 2, 10, 4), s(3, 1, 10, 2),
+    s(13, 1, 12, 1), s(13, 1, 12, 4),
+    s(12, 1, 9, 4), s(12, 1, 9, 1),
+    s(3, 1, 9, 3), s(17, 0, 12, 2),
+    s(7, 2, 9, 3), s(7, 2, 9, 2),
+    s(0, 3, 0, 2), s(0, 3, 0, 1),
+    s(13, 1, 8, 2), s(0, 2, 0, 4),
+    s(6, 1, 12, 2), s(6, 1, 12, 1),
+    s(4, 0, 9, 2), s(4, 0, 12, 2),
+    s(0, 2, 2, 4), s(0, 0, 8, 4), s(0, 2, 0, 1),
+    s(5, 1, 0, 4), s(6, 1, 0, 2), s(5, 1, 0, 1),
+    s(14, 1, 4, 4), s(5, 1, 4, 2), s(15, 0, 1, 2), s(0, 1, 0, 4), s(13, 1, 4, 4),
+    s(9, 0, 11, 3), s(11, 2, 12, 4),
+    s(11, 2, 12, 2), s(9, 2, 12, 4),
+    s(10, 0, 3, 3), s(12, 1, 4, 2), s(10, 0, 5, 4), s(11, 0, 3, 2),
+    s(11, 2, 12, 1), s(13, 1, 12, 4),
+    s(11, 2, 10, 2), s(11, 2, 10, 4), s(9, 2, 10, 3),
+    s(2, 0, 5, 4), s(1, 0, 1, 2),
+    s(8, 1, 9, 4), s(8, 0, 9, 4),
+    s(6, 2, 9, 1), s(15, 2, 9, 1),
+    s(11, 2, 6, 4), s(3, 1, 10, 3),
+    s(14, 3, 0, 1), s(14, 3, 0, 4), s(14, 3, 0, 3),
+    s(2, 0, 10, 2), s(2, 0, 10, 4),
+    s(12, 1, 9, 1), s(19, 0, 9, 4), s(15, 0, 9, 3), s(13, 1, 9, 2), s(12, 1, 9, 4),
+    s(15, 0, 9, 4), s(16, 0, 10, 2), s(5, 0, 9, 1), s(6, 0, 9, 2), s(12, 1, 10, 4), s(0, 1, 10, 3), s(16, 0, 10, 3), s(0, 1, 10, 2), s(17, 0, 10, 4),
+    s(12, 1, 4, 1), s(14, 1, 4, 2),
+    s(15, 0, 10, 1), s(16, 0, 12, 1),
+    s(11, 2, 11, 2), s(10, 2, 11, 4),
+    s(3, 1, 10, 3), s(11, 2, 10, 1), s(3, 1, 9, 4),
+    s(5, 2, 12, 4), s(5, 2, 12, 3), s(6, 2, 12, 2),
+    s(6, 2, 9, 4), s(7, 2, 9, 3),
+    s(12, 1, 7, 3), s(13, 1, 8, 1), s(12, 1, 8, 4), s(12, 1, 8, 1), s(12, 1, 7, 1),
+    s(6, 0, 11, 1), s(6, 0, 11, 4), s(17, 0, 11, 1), s(0, 1, 11, 2),
+    s(1, 0, 9, 4), s(2, 0, 9, 2),
+    s(15, 2, 11, 1), s(11, 2, 10, 2),
+    s(9, 0, 5, 4), s(14, 1, 4, 4),
+    s(0, 1, 9, 4), s(9, 2, 6, 4),
+    s(8, 1, 9, 2), s(8, 1, 9, 3), s(14, 1, 9, 2), s(14, 1, 9, 4), s(7, 1, 9, 2), s(8, 1, 10, 2),
+    s(15, 2, 10, 3), s(15, 2, 2, 2),
+    s(10, 0, 3, 4), s(10, 0, 5, 4),
+    s(6, 0, 1, 3), s(5, 0, 1, 2),
+    s(8, 1, 8, 1), s(8, 1, 8, 4), s(8, 1, 8, 2),
+    s(9, 0, 8, 1), s(9, 0, 11, 3), s(10, 0, 8, 4),
+    s(3, 1, 12, 2), s(3, 1, 12, 3), s(3, 1, 0, 4),
+    s(15, 0, 1, 4), s(15, 0, 1, 1),
+    s(8, 2, 10, 4),
+    s(2, 2, 0, 3), s(4, 2, 0, 1), s(1, 2, 0, 4), s(1, 0, 2, 2), s(2, 0, 2, 4),
+    s(0, 2, 6, 1), s(3, 0, 6, 2),
+    s(14, 1, 0, 1), s(13, 1, 0, 1),
+    s(13, 1, 10, 2), s(15, 0, 10, 1),
+    s(21, 0, 8, 1), s(21, 0, 8, 3), s(17, 0, 7, 1),
+    s(0, 3, 10, 2), s(0, 3, 10, 3),
+    s(14, 1, 8, 4), s(16, 0, 8, 4),
+    s(21, 2, 2, 4), s(21, 2, 2, 3), s(21, 0, 3, 4),
+    s(7, 1, 0, 4), s(18, 2, 2, 4), s(7, 1, 7, 4),
+    s(2, 0, 11, 1), s(2, 0, 12, 1),
+    s(5, 1, 9, 1), s(6, 1, 9, 2), s(15, 0, 10, 1),
+    s(11, 0, 7, 4), s(14, 3, 4, 4),
+    s(14, 1, 0, 4), s(14, 1, 4, 1), s(13, 1, 0, 4),
+    s(19, 0, 0, 1), s(20, 0, 0, 2), s(14, 3, 9, 2),
+    s(14, 1, 12, 2), s(0, 1, 12, 1), s(0, 1, 12, 2),
+    s(11, 2, 1, 2), s(9, 2, 0, 3),
+    s(0, 1, 7, 2), s(14, 1, 7, 4),
+    s(15, 0, 0, 4), s(16, 0, 0, 4),
+    s(17, 0, 10, 4), s(21, 0, 10, 1), s(13, 1, 10, 1), s(8, 1, 10, 2),
+    s(11, 2, 0, 1), s(11, 2, 0, 3),
+    s(3, 0, 9, 3), s(3, 0, 10, 4),
+    s(13, 1, 12, 3), s(13, 1, 12, 1),
+    s(2, 1, 7, 1), s(2, 1, 7, 4), s(2, 1, 7, 3), s(1, 1, 7, 1),
+    s(8, 0, 8, 2), s(8, 3, 0, 3), s(8, 0, 8, 3),
+    s(20, 2, 12, 2), s(21, 2, 12, 3),
+    s(8, 0, 8, 4), s(8, 0, 8, 2),
+    s(20, 0, 7, 2), s(20, 0, 7, 4),
+    s(6, 2, 9, 2), s(15, 2, 9, 1),
+    s(17, 0, 11, 1), s(6, 0, 11, 4),
+    s(11, 0, 9, 4), s(6, 0, 9, 1),
+    s(8, 1, 9, 2), s(8, 0, 9, 2),
+    s(16, 0, 0, 2), s(6, 0, 5, 1),
+    s(4, 0, 12, 2), s(2, 0, 12, 2), s(1, 0, 12, 3),
+    s(19, 0, 0, 4), s(21, 0, 3, 4), s(13, 1, 0, 4),
+    s(8, 2, 2, 4), s(6, 0, 1, 4), s(8, 0, 6, 3),
+    s(15, 0, 11, 3), s(15, 0, 11, 4), s(15, 0, 11, 1),
+    s(15, 2, 11, 4), s(16, 2, 12, 2), s(17, 2, 11, 1), s(16, 2, 11, 2),
+    s(11, 2, 2, 3), s(10, 2, 2, 4), s(11, 2, 2, 4),
+    s(12, 1, 9, 4), s(12, 1, 9, 1), s(13, 1, 9, 2), s(16, 0, 9, 2),
+    s(17, 2, 11, 3), s(16, 2, 11, 3),
+    s(8, 1, 7, 2), s(14, 1, 7, 4), s(8, 1, 8, 2),
+    s(20, 2, 6, 3), s(20, 2, 6, 1),
+    s(19, 2, 12, 5),
+    s(2, 1, 0, 4), s(2, 1, 4, 1), s(2, 1, 7, 4),
+    s(0, 3, 0, 4), s(12, 3, 4, 2), s(17, 2, 0, 4),
+    s(12, 1, 7, 4), s(12, 1, 7, 3), s(13, 1, 7, 2),
+    s(2, 0, 2, 1), s(1, 0, 2, 1),
+    s(5, 0, 11, 4), s(14, 1, 11, 4), s(0, 1, 11, 3),
+    s(2, 0, 1, 2),
+    s(2, 0, 9, 1), s(2, 0, 9, 4), s(1, 0, 2, 1), s(2, 0, 9, 2), s(4, 0, 9, 1),
+    s(0, 2, 6, 2), s(9, 2, 6, 1),
+    s(21, 2, 0, 4), s(14, 1, 7, 1), s(21, 0, 8, 1),
+    s(14, 1, 0, 1), s(0, 1, 1, 4),
+    s(14, 3, 10, 4), s(21, 2, 10, 4),
+    s(11, 2, 11, 2), s(11, 2, 11, 4), s(9, 2, 11, 1),
+    s(8, 0, 7, 3), s(8, 1, 7, 2), s(8, 0, 7, 4), s(8, 0, 7, 2), s(8, 1, 7, 3),
+    s(20, 2, 12, 2), s(19, 2, 12, 1),
+    s(15, 0, 3, 4), s(15, 0, 0, 4),
+    s(17, 0, 9, 4), s(6, 0, 9, 1),
+    s(6, 0, 9, 2), s(14, 3, 10, 2), s(0, 1, 10, 3), s(5, 0, 9, 4),
+    s(14, 3, 10, 2), s(0, 1, 10, 2),
+    s(10, 2, 6, 4), s(14, 1, 4, 4),
+    s(6, 2, 0, 2), s(15, 0, 1, 1),
+    s(21, 0, 1, 3), s(21, 0, 9, 4),
+    s(11, 0, 6, 1),
+    s(6, 2, 12, 2), s(16, 2, 12, 1), s(15, 2, 12, 1),
+    s(2, 2, 0, 1), s(2, 2, 0, 3),
+    s(16, 0, 12, 2), s(5, 0, 12, 4),
+    s(12, 1, 7, 1), s(0, 0, 7, 4), s(7, 0, 7, 4),
+    s(20, 2, 10, 1), s(20, 2, 10, 2),
+    s(2, 0, 12, 1), s(2, 0, 12, 2),
+    s(0, 3, 0, 5),
+    s(19, 0, 7, 3), s(20, 0, 7, 1),
+    s(11, 2, 9, 4), s(11, 0, 9, 4),
+    s(19, 0, 3, 2), s(17, 0, 0, 4), s(0, 1, 0, 4), s(5, 2, 2, 2),
+    s(17, 0, 0, 4), s(20, 2, 2, 2),
+    s(14, 3, 4, 2), s(14, 1, 7, 4),
+    s(0, 2, 9, 4), s(3, 0, 9, 4), s(0, 0, 8, 3),
+    s(11, 2, 6, 4), s(10, 2, 5, 4), s(11, 2, 1, 2),
+    s(11, 2, 9, 2), s(14, 3, 9, 4),
+    s(16, 0, 9, 2), s(5, 0, 9, 4), s(15, 0, 9, 1),
+    s(5, 0, 9, 4), s(6, 0, 9, 2), s(5, 0, 9, 1), s(17, 0, 9, 4),
+    s(12, 1, 0, 1), s(12, 1, 7, 4), s(12, 1, 7, 1),
+    s(8, 1, 9, 2), s(14, 1, 9, 3),
+    s(4, 0, 10, 2), s(2, 0, 10, 1),
+    s(12, 1, 0, 2), s(17, 0, 1, 4),
+    s(11, 2, 6, 4), s(0, 2, 6, 4), s(11, 2, 2, 4),
+    s(5, 1, 12, 3), s(6, 1, 12, 4),
+    s(3, 1, 0, 3), s(3, 1, 0, 2), s(7, 1, 0, 3),
+    s(17, 0, 0, 1), s(6, 0, 1, 4), s(14, 1, 0, 2),
+    s(7, 1, 12, 4), s(7, 1, 12, 2), s(7, 1, 0, 4),
+    s(12, 1, 10, 4), s(12, 1, 10, 1),
+    s(1, 1, 0, 4), s(2, 1, 0, 4),
+    s(12, 1, 0, 4), s(12, 1, 0, 3), s(13, 1, 0, 2),
+    s(18, 2, 0, 2), s(18, 2, 9, 3), s(0, 0, 13, 2), s(7, 2, 9, 2), s(7, 2, 2, 4),
+    s(20, 2, 6, 4), s(19, 2, 6, 3),
+    s(6, 0, 7, 1), s(16, 0, 7, 2), s(17, 0, 8, 4), s(5, 0, 7, 4),
+    s(11, 2, 2, 4), s(11, 2, 0, 4),
+    s(8, 0, 9, 4), s(12, 1, 9, 4), s(8, 0, 9, 3), s(8, 0, 9, 2),
+    s(12, 3, 10, 4), s(14, 3, 10, 4),
+    s(0, 0, 5, 3), s(10, 0, 5, 4), s(10, 0, 3, 4),
+    s(15, 2, 2, 2), s(17, 2, 2, 4), s(15, 0, 7, 4),
+    s(3, 0, 3, 5),
+    s(12, 1, 9, 4), s(12, 1, 9, 1), s(19, 0, 9, 4),
+    s(8, 2, 2, 4), s(2, 0, 2, 1), s(8, 1, 0, 4),
+    s(0, 1, 11, 4), s(0, 1, 11, 3),
+    s(0, 1, 12, 2), s(0, 1, 12, 4), s(12, 3, 12, 1),
+    s(5, 2, 0, 2), s(5, 0, 8, 4),
+    s(17, 0, 10, 3), s(16, 0, 10, 4), s(2, 0, 9, 2),
+    s(3, 0, 2, 4), s(3, 1, 4, 4),
+    s(8, 1, 8, 2), s(8, 1, 8, 1),
+    s(2, 2, 0, 4), s(1, 0, 7, 4), s(1, 0, 2, 2),
+    s(0, 2, 6, 3), s(5, 2, 6, 4),
+    s(11, 2, 5, 2), s(0, 2, 5, 1),
+    s(8, 1, 12, 5),
+    s(3, 1, 0, 2), s(3, 1, 0, 3), s(7, 1, 0, 3),
+    s(0, 1, 12, 2), s(0, 1, 12, 3), s(0, 1, 12, 4),
+    s(0, 3, 4, 4), s(0, 1, 7, 4),
+    s(18, 0, 11, 2), s(18, 0, 11, 4), s(7, 0, 11, 3),
+    s(15, 2, 2, 2), s(19, 0, 3, 2), s(12, 1, 7, 4),
+    s(17, 0, 3, 4), s(7, 1, 0, 4),
+    s(9, 2, 9, 4), s(11, 2, 9, 4),
+    s(21, 0, 1, 3), s(14, 1, 9, 3), s(14, 1, 0, 3), s(8, 1, 0, 2), s(17, 0, 0, 1),
+    s(19, 0, 9, 4), s(20, 2, 9, 2), s(13, 1, 9, 2), s(19, 0, 1, 1),
+    s(8, 2, 9, 2), s(8, 2, 9, 4),
+    s(6, 0, 9, 1), s(11, 0, 9, 4), s(7, 0, 9, 4),
+    s(8, 0, 9, 3), s(8, 0, 9, 4),
+    s(14, 3, 10, 2), s(13, 3, 9, 2), s(13, 3, 9, 4),
+    s(11, 2, 2, 3), s(11, 2, 2, 1),
+    s(1, 1, 7, 1), s(11, 2, 2, 3),
+    s(5, 0, 12, 1), s(5, 1, 12, 1),
+    s(15, 0, 1, 4), s(0, 3, 0, 4),
+    s(4, 0, 10, 2), s(1, 0, 10, 4),
+    s(12, 3, 12, 3), s(9, 2, 6, 4),
+    s(16, 2, 6, 1),
+    s(0, 1, 9, 2), s(0, 1, 9, 4), s(6, 0, 9, 2),
+    s(10, 0, 5, 4), s(0, 1, 9, 2),
+    s(2, 0, 11, 4), s(4, 0, 12, 1),
+    s(9, 2, 6, 4), s(13, 3, 4, 1), s(14, 3, 4, 4),
+    s(10, 0, 11, 4), s(11, 0, 11, 1),
+    s(1, 1, 9, 5),
+    s(15, 0, 7, 4), s(15, 0, 7, 1), s(15, 0, 7, 3),
+    s(15, 2, 2, 1), s(16, 2, 0, 4),
+    s(2, 0, 7, 4), s(1, 0, 7, 1), s(2, 0, 7, 2),
+    s(2, 0, 7, 2), s(4, 0, 8, 3),
+    s(15, 0, 1, 4), s(15, 0, 1, 2),
+    s(12, 1, 7, 3), s(0, 1, 7, 4),
+    s(0, 1, 11, 2), s(0, 1, 11, 4),
+    s(14, 1, 8, 1), s(14, 1, 7, 1),
+    s(0, 2, 0, 1), s(0, 1, 1, 1), s(0, 2, 0, 4),
+    s(8, 0, 7, 4), s(8, 2, 2, 4),
+    s(15, 2, 0, 2), s(16, 2, 12, 2),
+    s(14, 3, 9, 3), s(14, 3, 9, 1), s(11, 2, 6, 3),
+    s(11, 2, 6, 2), s(11, 2, 6, 3), s(0, 0, 5, 3),
+    s(18, 0, 3, 4),
+    s(14, 1, 4, 4), s(16, 0, 3, 4),
+    s(6, 1, 12, 1), s(12, 1, 12, 3),
+    s(0, 1, 9, 4), s(17, 0, 9, 1),
+    s(14, 3, 10, 1), s(11, 2, 10, 1),
+    s(10, 2, 0, 4), s(10, 0, 7, 4),
+    s(12, 3, 9, 1), s(0, 1, 4, 4), s(0, 3, 4, 4), s(0, 3, 9, 1),
+    s(0, 1, 9, 1), s(0, 1, 0, 2),
+    s(12, 3, 10, 4), s(13, 3, 0, 1),
+    s(5, 0, 7, 4), s(6, 0, 7, 1),
+    s(6, 2, 10, 1), s(6, 2, 6, 1), s(12, 3, 10, 4),
+    s(16, 0, 7, 3), s(12, 3, 0, 4),
+    s(0, 2, 2, 4), s(0, 0, 5, 4),
+    s(12, 1, 7, 1), s(13, 1, 7, 2),
+    s(16, 0, 7, 1), s(15, 2, 2, 1), s(15, 2, 2, 2), s(16, 2, 2, 4),
+    s(0, 1, 9, 4), s(0, 1, 0, 4),
+    s(14, 3, 12, 4), s(0, 1, 12, 1), s(9, 2, 0, 3),
+    s(16, 0, 11, 4), s(9, 2, 1, 1),
+    s(14, 3, 12, 3),
+    s(0, 2, 6, 5),
+    s(14, 1, 1, 1), s(14, 1, 1, 4),
+    s(11, 2, 6, 1), s(11, 2, 10, 2), s(0, 3, 10, 4), s(14, 3, 10, 1), s(14, 3, 9, 4),
+    s(12, 1, 9, 1), s(12, 1, 9, 4), s(12, 1, 9, 3),
+    s(17, 0, 9, 3), s(13, 1, 9, 2), s(17, 0, 9, 1),
+    s(17, 0, 1, 1), s(17, 0, 1, 4),
+    s(18, 0, 8, 2), s(18, 0, 8, 3),
+    s(14, 3, 0, 4), s(14, 1, 8, 1),
+    s(0, 2, 6, 1), s(0, 3, 0, 4),
+    s(0, 3, 10, 1), s(0, 3, 10, 4), s(0, 2, 10, 3),
+    s(8, 1, 11, 5),
+    s(1, 1, 9, 1),
+    s(0, 0, 10, 1), s(0, 3, 10, 1),
+    s(11, 2, 11, 3), s(0, 1, 4, 4),
+    s(11, 0, 3, 4), s(14, 1, 7, 1), s(10, 0, 7, 3), s(10, 0, 7, 4),
+    s(8, 1, 9, 2), s(13, 1, 9, 1),
+    s(0, 1, 12, 2), s(14, 1, 12, 2), s(12, 3, 12, 3),
+    s(8, 1, 8, 1),
+    s(14, 1, 0, 1), s(0, 1, 0, 2),
+    s(8, 2, 0, 4), s(0, 0, 7, 1),
+    s(17, 2, 0, 2), s(17, 0, 8, 2),
+    s(2, 0, 12, 2), s(1, 0, 12, 4), s(4, 0, 12, 1),
+    s(21, 2, 6, 1), s(20, 2, 6, 3),
+    s(6, 0, 12, 1), s(6, 2, 12, 1),
+    s(0, 3, 10, 4), s(0, 3, 0, 4), s(0, 2, 6, 4),
+    s(0, 0, 7, 2), s(0, 0, 7, 1),
+    s(11, 0, 9, 4), s(18, 0, 9, 3),
+    s(11, 2, 11, 2), s(11, 2, 11, 3),
+    s(16, 0, 9, 3), s(5, 0, 9, 3), s(16, 0, 9, 4),
+    s(6, 0, 9, 2), s(14, 3, 10, 2), s(13, 1, 9, 2),
+    s(13, 1, 7, 1), s(14, 1, 7, 1),
+    s(5, 2, 10, 4), s(6, 2, 10, 2), s(5, 2, 10, 1),
+    s(12, 1, 7, 1), s(13, 1, 7, 2), s(12, 3, 4, 2), s(15, 2, 2, 2),
+    s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 1, 7, 4),
+    s(17, 0, 7, 1), s(17, 0, 7, 4),
+    s(4, 0, 9, 2), s(4, 0, 10, 2),
+    s(14, 3, 10, 2), s(13, 1, 9, 2),
+    s(6, 0, 11, 4), s(5, 0, 11, 4),
+    s(12, 1, 12, 5),
+    s(0, 1, 12, 2), s(20, 2, 2, 1),
+    s(0, 3, 0, 4), s(0, 0, 7, 4),
+    s(6, 0, 5, 4), s(8, 1, 4, 4),
+    s(19, 0, 7, 4), s(21, 0, 7, 4),
+    s(15, 2, 0, 2), s(10, 2, 2, 4),
+    s(18, 2, 0, 2), s(18, 2, 9, 3),
+    s(14, 1, 9, 3), s(1, 1, 12, 4),
+    s(0, 1, 7, 4), s(17, 2, 2, 4), s(17, 0, 7, 4),
+    s(1, 0, 7, 4), s(1, 0, 2, 2),
+    s(10, 2, 11, 4), s(11, 2, 11, 3), s(10, 2, 11, 3),
+    s(17, 2, 2, 4), s(8, 2, 2, 4), s(0, 3, 4, 4),
+    s(0, 1, 9, 4), s(14, 3, 10, 2),
+    s(16, 2, 12, 2), s(6, 2, 12, 2),
+    s(12, 3, 0, 5),
+    s(15, 0, 7, 3), s(15, 2, 1, 3),
+    s(16, 0, 12, 1), s(16, 0, 12, 4),
+    s(4, 2, 0, 4), s(4, 2, 0, 3),
+    s(17, 2, 11, 3), s(17, 2, 11, 1),
+    s(0, 0, 13, 3), s(3, 1, 0, 3), s(7, 1, 0, 3),
+    s(2, 0, 9, 2), s(13, 1, 11, 2),
+    s(2, 1, 9, 4), s(2, 1, 9, 1), s(2, 0, 9, 4),
+    s(1, 1, 9, 1), s(3, 1, 9, 4),
+    s(1, 0, 11, 3), s(2, 0, 11, 1),
+    s(0, 1, 1, 2), s(0, 1, 1, 4),
+    s(2, 1, 10, 4),
+    s(12, 1, 8, 1), s(8, 0, 3, 4),
+    s(3, 0, 8, 2), s(3, 2, 0, 4), s(3, 0, 7, 4),
+    s(8, 0, 7, 2), s(8, 0, 7, 4), s(8, 0, 8, 2),
+    s(3, 0, 11, 1),
+    s(9, 0, 3, 1), s(13, 1, 8, 2), s(19, 0, 11, 1),
+    s(0, 1, 8, 4), s(16, 0, 8, 1),
+    s(5, 1, 0, 3), s(5, 1, 0, 1), s(15, 0, 5, 1),
+    s(12, 1, 0, 1), s(0, 1, 0, 1),
+    s(15, 0, 0, 2), s(6, 0, 3, 4),
+    s(1, 0, 10, 1),
+    s(12, 1, 9, 1), s(13, 1, 9, 2), s(12, 1, 9, 3),
+    s(2, 1, 7, 1), s(2, 0, 7, 4),
+    s(14, 1, 0, 1), s(21, 2, 2, 1),
+    s(10, 2, 6, 2), s(18, 0, 7, 2),
+    s(0, 0, 9, 4), s(11, 0, 9, 1), s(0, 0, 9, 2), s(12, 1, 9, 4),
+    s(9, 0, 3, 1), s(11, 0, 3, 2),
+    s(10, 0, 11, 4), s(9, 0, 11, 3),
+    s(1, 0, 2, 2), s(2, 0, 6, 4), s(4, 0, 6, 4),
+    s(11, 2, 9, 1), s(4, 0, 9, 1),
+    s(10, 2, 11, 2), s(12, 3, 4, 2),
+    s(7, 1, 8, 3), s(7, 3, 0, 4),
+    s(5, 1, 0, 2), s(6, 1, 0, 4),
+    s(0, 1, 0, 2), s(13, 3, 9, 2), s(16, 0, 0, 2),
+    s(17, 0, 12, 1), s(14, 1, 12, 1),
+    s(2, 1, 0, 2), s(2, 1, 0, 1),
+    s(10, 2, 0, 3),
+    s(11, 2, 9, 2), s(14, 3, 9, 1), s(11, 0, 12, 2),
+    s(11, 0, 10, 3), s(0, 1, 9, 2), s(10, 0, 10, 3), s(11, 0, 11, 3),
+    s(11, 0, 3, 2), s(3, 0, 2, 4),
+    s(12, 1, 0, 2), s(12, 1, 4, 2), s(10, 2, 5, 4),
+    s(6, 0, 1, 4), s(17, 0, 0, 4),
+    s(3, 0, 11, 2), s(15, 2, 2, 2),
+    s(14, 1, 0, 1), s(17, 0, 0, 3),
+    s(21, 2, 9, 1), s(14, 3, 10, 4), s(12, 3, 10, 4),
+    s(8, 0, 11, 2), s(8, 0, 11, 3), s(8, 0, 11, 4), s(11, 0, 11, 3),
+    s(8, 1, 4, 4), s(14, 1, 0, 1), s(13, 3, 4, 4),
+    s(9, 2, 2, 3), s(8, 2, 2, 3),
+    s(0, 1, 0, 1), s(0, 1, 0, 3), s(12, 1, 0, 4), s(0, 0, 3, 1), s(0, 2, 6, 1),
+    s(0, 1, 1, 2), s(0, 2, 6, 4),
+    s(3, 0, 7, 1), s(3, 1, 7, 2), s(3, 0, 7, 2),
+    s(16, 2, 9, 1), s(16, 2, 9, 4), s(17, 0, 9, 1),
+    s(0, 1, 1, 4), s(12, 1, 1, 2), s(13, 1, 4, 4),
+    s(14, 1, 4, 1), s(11, 0, 3, 4), s(9, 0, 3, 2), s(11, 0, 5, 4),
+    s(1, 1, 9, 1), s(2, 1, 9, 4),
+    s(0, 1, 8, 2), s(0, 1, 7, 2),
+    s(1, 0, 2, 2), s(2, 0, 2, 4),
+    s(5, 0, 5, 1), s(0, 0, 5, 2),
+    s(11, 0, 7, 2), s(9, 0, 7, 1),
+    s(3, 0, 2, 4), s(3, 2, 0, 2),
+    s(1, 0, 10, 4), s(4, 0, 10, 4), s(4, 0, 10, 2),
+    s(14, 3, 0, 4), s(0, 3, 0, 4),
+    s(6, 2, 12, 2), s(15, 2, 11, 4),
+    s(0, 1, 7, 4), s(14, 1, 7, 1),
+    s(8, 1, 7, 2), s(8, 0, 7, 3),
+    s(9, 0, 3, 2), s(14, 1, 4, 1), s(8, 1, 4, 4),
+    s(10, 2, 5, 4), s(11, 2, 1, 2),
+    s(14, 1, 4, 4), s(11, 0, 1, 3), s(12, 1, 4, 3),
+    s(14, 1, 9, 3), s(3, 1, 0, 2),
+    s(7, 0, 8, 4), s(18, 2, 0, 2),
+    s(3, 0, 12, 3), s(3, 0, 12, 2),
+    s(7, 0, 7, 3), s(0, 1, 8, 1), s(7, 0, 7, 2),
+    s(9, 2, 11, 3), s(12, 1, 12, 3),
+    s(14, 1, 9, 4), s(21, 2, 2, 1), s(14, 1, 0, 1),
+    s(11, 2, 9, 1), s(13, 3, 9, 2),
+    s(7, 0, 7, 3), s(7, 0, 7, 2),
+    s(8, 2, 2, 2), s(0, 0, 3, 3),
+    s(3, 1, 7, 4), s(0, 1, 7, 1),
+    s(19, 0, 0, 1), s(14, 3, 9, 2),
+    s(8, 3, 0, 4), s(17, 2, 5, 4), s(8, 3, 4, 4),
+    s(0, 2, 11, 2), s(0, 2, 11, 4), s(0, 3, 0, 4),
+    s(16, 0, 11, 4), s(0, 1, 11, 2),
+    s(0, 2, 10, 2), s(3, 1, 10, 2),
+    s(1, 1, 10, 1), s(4, 0, 10, 1),
+    s(3, 0, 10, 2), s(0, 3, 10, 3),
+    s(12, 1, 9, 1), s(0, 1, 10, 2), s(13, 1, 9, 2), s(8, 1, 10, 2),
+    s(0, 0, 9, 2), s(9, 0, 9, 1),
+    s(5, 1, 9, 4), s(5, 1, 9, 1),
+    s(14, 3, 9, 2), s(14, 3, 9, 4), s(14, 1, 9, 2),
+    s(20, 0, 0, 3), s(20, 0, 0, 1), s(20, 2, 2, 1),
+    s(0, 1, 0, 2), s(6, 0, 5, 1),
+    s(19, 2, 0, 3), s(12, 3, 0, 4),
+    s(8, 2, 2, 4), s(8, 1, 0, 4),
+    s(11, 0, 11, 2), s(11, 0, 12, 2),
+    s(16, 0, 12, 2), s(6, 1, 12, 3),
+    s(14, 3, 9, 2), s(13, 3, 12, 2),
+    s(8, 0, 11, 2), s(8, 0, 11, 4),
+    s(2, 1, 10, 2), s(1, 1, 12, 3),
+    s(21, 0, 3, 4), s(20, 2, 6, 4),
+    s(17, 0, 12, 4), s(0, 2, 11, 4),
+    s(0, 2, 6, 2), s(0, 3, 0, 4),
+    s(6, 1, 9, 3), s(6, 1, 9, 4),
+    s(19, 2, 2, 2), s(15, 2, 2, 2),
+    s(1, 0, 12, 3), s(2, 0, 6, 3),
+    s(9, 2, 9, 3), s(9, 2, 10, 4), s(9, 2, 9, 1), s(9, 2, 9, 4),
+    s(11, 2, 10, 2), s(11, 2, 6, 1),
+    s(16, 0, 11, 4), s(0, 1, 11, 2), s(5, 0, 11, 4),
+    s(15, 0, 10, 4), s(6, 1, 9, 4),
+    s(13, 1, 11, 1), s(16, 0, 12, 1), s(20, 0, 11, 1),
+    s(20, 2, 2, 1),
+    s(17, 2, 0, 1), s(6, 2, 0, 1),
+    s(21, 2, 2, 3), s(19, 0, 7, 3),
+    s(0, 2, 6, 4), s(15, 0, 0, 4),
+    s(13, 3, 12, 2), s(12, 3, 4, 2),
+    s(11, 2, 9, 2), s(11, 2, 9, 4),
+    s(8, 2, 0, 2), s(4, 2, 0, 1),
+    s(1, 1, 10, 1), s(2, 1, 9, 2),
+    s(19, 0, 0, 1), s(12, 1, 0, 4),
+    s(8, 1, 0, 2), s(8, 1, 0, 5),
+    s(13, 3, 12, 2), s(14, 3, 9, 2),
+    s(3, 1, 0, 2), s(14, 1, 0, 3),
+    s(13, 3, 12, 2), s(0, 2, 6, 3), s(0, 2, 6, 4),
+    s(11, 2, 9, 2), s(0, 1, 4, 4), s(0, 1, 9, 3),
+    s(1, 0, 2, 2), s(2, 0, 7, 2),
+    s(11, 2, 0, 4), s(11, 2, 0, 2), s(11, 2, 2, 4), s(9, 2, 0, 1),
+    s(15, 0, 0, 2), s(11, 2, 0, 2),
+    s(0, 2, 1, 3), s(0, 2, 1, 4),
+    s(14, 1, 11, 2), s(11, 2, 12, 2),
+    s(13, 1, 9, 1), s(0, 2, 1, 3),
+    s(2, 0, 10, 2),
+    s(5, 0, 9, 3), s(5, 0, 9, 1),
+    s(0, 2, 12, 4),
+    s(0, 2, 1, 5),
+    s(12, 3, 9, 1), s(12, 3, 9, 4),
+    s(1, 2, 0, 4), s(2, 0, 8, 3),
+    s(15, 0, 10, 1), s(15, 0, 10, 4), s(12, 3, 9, 4),
+    s(15, 2, 9, 1), s(16, 2, 9, 2),
+    s(5, 0, 9, 1), s(5, 0, 9, 4),
+    s(9, 0, 9, 1), s(11, 0, 9, 1),
+    s(17, 0, 10, 4), s(17, 0, 10, 2),
+    s(17, 2, 5, 3),
+    s(4, 2, 0, 3), s(4, 2, 0, 1), s(2, 2, 0, 3),
+    s(0, 3, 12, 3), s(5, 2, 12, 4),
+    s(1, 0, 12, 2), s(13, 1, 0, 4),
+    s(0, 1, 8, 2), s(0, 1, 7, 1),
+    s(15, 0, 1, 2), s(0, 1, 8, 2),
+    s(5, 1, 9, 1), s(5, 1, 9, 4), s(6, 1, 9, 2), s(17, 0, 12, 4), s(0, 1, 12, 4),
+    s(6, 1, 12, 1), s(5, 1, 12, 1), s(6, 1, 12, 3), s(15, 0, 12, 4), s(6, 1, 9, 3),
+    s(15, 0, 10, 4), s(13, 3, 9, 3), s(15, 2, 10, 4),
+    s(19, 0, 5, 1), s(19, 0, 0, 1),
+    s(3, 0, 12, 2), s(3, 0, 11, 2),
+    s(0, 3, 10, 2), s(6, 1, 9, 2),
+    s(0, 2, 6, 4), s(0, 2, 6, 1), s(0, 2, 6, 3),
+    s(4, 2, 0, 2), s(1, 1, 0, 4),
+    s(5, 0, 1, 2), s(4, 2, 0, 2),
+    s(8, 1, 8, 2), s(8, 1, 8, 4), s(8, 1, 8, 3),
+    s(3, 2, 0, 3), s(3, 0, 8, 3),
+    s(20, 0, 3, 4), s(12, 1, 0, 4),
+    s(15, 0, 0, 4), s(16, 0, 8, 2), s(17, 0, 0, 4),
+    s(2, 0, 9, 1), s(4, 0, 9, 1),
+    s(4, 0, 9, 1), s(2, 0, 9, 1), s(4, 0, 9, 2), s(1, 0, 2, 1), s(2, 0, 2, 2), s(2, 0, 9, 2), s(2, 0, 9, 4), s(2, 1, 0, 2),
+    s(17, 0, 3, 1), s(0, 3, 0, 2),
+    s(5, 0, 11, 1), s(5, 0, 11, 4), s(5, 0, 11, 5),
+    s(0, 2, 9, 3), s(0, 3, 9, 3),
+    s(6, 2, 10, 3), s(6, 2, 9, 3),
+    s(8, 1, 10, 4), s(8, 1, 10, 2),
+    s(2, 1, 0, 3), s(17, 2, 0, 1), s(0, 1, 1, 3),
+    s(0, 1, 0, 2), s(7, 1, 12, 2),
+    s(5, 1, 12, 1), s(7, 0, 3, 4),
+    s(9, 0, 11, 1), s(9, 2, 12, 1),
+    s(7, 3, 4, 4), s(0, 1, 7, 4),
+    s(14, 1, 1, 1), s(0, 1, 1, 2),
+    s(10, 0, 3, 1), s(0, 0, 3, 1), s(13, 1, 1, 4),
+    s(20, 0, 0, 1), s(19, 0, 0, 1), s(15, 0, 5, 4), s(12, 1, 0, 4),
+    s(5, 0, 9, 3), s(5, 0, 1, 5),
+    s(15, 0, 10, 3), s(16, 0, 10, 4),
+    s(4, 0, 1, 2), s(1, 1, 9, 3),
+    s(12, 3, 0, 1), s(12, 3, 0, 3),
+    s(7, 1, 4, 4), s(7, 1, 0, 4), s(7, 1, 9, 3),
+    s(6, 0, 12, 2), s(16, 2, 12, 2),
+    s(6, 2, 12, 1), s(6, 2, 12, 2),
+    s(0, 2, 6, 3), s(0, 1, 8, 4), s(0, 3, 0, 4),
+    s(6, 0, 9, 1), s(17, 0, 0, 3),
+    s(0, 1, 11, 3), s(0, 1, 11, 2),
+    s(11, 0, 10, 2), s(9, 0, 10, 4),
+    s(4, 2, 0, 1), s(2, 2, 0, 1), s(2, 2, 0, 4),
+    s(0, 2, 0, 4), s(2, 1, 0, 1),
+    s(11, 2, 9, 4), s(6, 2, 9, 3),
+    s(1, 0, 12, 1), s(2, 0, 12, 2), s(1, 1, 12, 4),
+    s(4, 0, 6, 4), s(4, 0, 6, 2), s(4, 0, 6, 3),
+    s(6, 1, 9, 3), s(5, 1, 9, 3),
+    s(0, 0, 9, 1), s(0, 1, 4, 4), s(0, 0, 3, 4),
+    s(0, 0, 3, 1), s(10, 0, 3, 1),
+    s(16, 0, 0, 4), s(15, 0, 0, 4),
+    s(12, 1, 1, 3), s(14, 1, 1, 1),
+    s(0, 1, 10, 1), s(0, 1, 10, 4),
+    s(0, 2, 10, 1), s(0, 2, 2, 4), s(0, 3, 10, 1),
+    s(11, 2, 6, 4), s(8, 0, 6, 3),
+    s(16, 0, 5, 4), s(20, 2, 2, 2),
+    s(5, 1, 9, 1), s(16, 0, 10, 1),
+    s(5, 0, 1, 1), s(5, 0, 1, 5), s(5, 0, 1, 2),
+    s(1, 1, 4, 3), s(1, 1, 4, 1),
+    s(16, 0, 8, 1), s(8, 2, 0, 4),
+    s(15, 0, 5, 4), s(12, 1, 0, 4),
+    s(21, 0, 8, 4),
+    s(13, 3, 4, 2),
+    s(8, 0, 8, 4), s(8, 3, 0, 2),
+    s(8, 1, 7, 2), s(8, 1, 7, 4), s(17, 2, 2, 4),
+    s(5, 0, 9, 1), s(5, 0, 9, 4), s(5, 0, 9, 3), s(6, 0, 9, 2),
+    s(0, 0, 5, 2), s(0, 1, 9, 2),
+    s(9, 2, 6, 4), s(0, 2, 6, 1),
+    s(7, 2, 12, 4), s(7, 2, 12, 2),
+    s(8, 1, 10, 3), s(1, 1, 12, 3),
+    s(1, 1, 4, 3), s(1, 1, 4, 2), s(1, 1, 4, 1),
+    s(5, 0, 12, 1), s(5, 0, 3, 2),
+    s(4, 0, 1, 1), s(1, 0, 2, 1),
+    s(1, 0, 5, 2), s(1, 0, 2, 2),
+    s(1, 0, 5, 3), s(1, 0, 2, 2), s(3, 0, 2, 4),
+    s(13, 1, 4, 2), s(1, 1, 4, 2),
+    s(12, 1, 0, 2), s(14, 1, 11, 1), s(1, 1, 0, 1),
+    s(5, 0, 3, 5), s(5, 1, 0, 1), s(5, 1, 0, 2), s(5, 1, 0, 4),
+    s(11, 2, 11, 2), s(0, 2, 11, 3),
+    s(9, 0, 7, 1), s(11, 0, 7, 2), s(9, 2, 0, 1),
+    s(11, 0, 7, 4), s(11, 2, 6, 1),
+    s(0, 2, 9, 3), s(11, 2, 9, 4),
+    s(14, 1, 7, 3), s(12, 1, 7, 3), s(2, 0, 2, 4),
+    s(2, 0, 2, 2), s(2, 0, 9, 2),
+    s(8, 1, 0, 4), s(8, 2, 2, 4), s(1, 0, 2, 1),
+    s(15, 0, 1, 1), s(20, 2, 0, 3),
+    s(15, 0, 7, 1), s(15, 0, 9, 3), s(5, 0, 9, 3),
+    s(3, 1, 10, 3), s(3, 1, 12, 3),
+    s(15, 2, 12, 1), s(16, 2, 12, 1),
+    s(11, 0, 3, 2), s(10, 0, 3, 3),
+    s(12, 1, 9, 1), s(12, 1, 9, 4),
+    s(11, 0, 3, 2), s(0, 0, 9, 1),
+    s(9, 0, 5, 4), s(9, 0, 3, 3),
+    s(3, 0, 12, 2), s(3, 0, 12, 4), s(3, 1, 12, 2),
+    s(12, 1, 9, 1), s(12, 1, 9, 4), s(10, 0, 9, 4),
+    s(2, 0, 9, 2), s(14, 3, 9, 2),
+    s(8, 2, 0, 2), s(8, 3, 0, 2), s(8, 0, 6, 2),
+    s(15, 0, 8, 1), s(16, 0, 8, 2),
+    s(9, 2, 0, 3), s(9, 2, 0, 4), s(9, 2, 0, 1),
+    s(5, 1, 12, 1), s(16, 0, 12, 2),
+    s(0, 2, 11, 4), s(3, 0, 11, 2),
+    s(6, 1, 9, 2), s(14, 1, 9, 2), s(3, 1, 10, 2),
+    s(14, 1, 11, 1), s(14, 1, 11, 4),
+    s(5, 2, 10, 3), s(15, 2, 10, 1),
+    s(14, 1, 0, 4), s(2, 0, 9, 3),
+    s(2, 0, 9, 4), s(4, 0, 10, 2),
+    s(5, 2, 10, 4), s(17, 2, 10, 3), s(0, 3, 10, 3),
+    s(17, 0, 12, 3), s(14, 1, 12, 3), s(14, 1, 9, 3),
+    s(0, 3, 10, 2), s(11, 2, 10, 4),
+    s(3, 1, 9, 3), s(3, 1, 9, 4),
+    s(3, 1, 7, 3), s(3, 1, 7, 4),
+    s(5, 0, 9, 1), s(16, 0, 10, 3),
+    s(3, 0, 7, 4), s(3, 0, 6, 4),
+    s(10, 0, 9, 4), s(10, 0, 9, 1),
+    s(0, 1, 11, 1), s(0, 1, 11, 3), s(0, 1, 12, 4),
+    s(0, 1, 7, 3), s(0, 0, 7, 1), s(0, 0, 7, 3),
+    s(12, 3, 0, 1), s(14, 3, 0, 1), s(10, 0, 8, 1),
+    s(0, 1, 0, 2), s(16, 0, 0, 4),
+    s(3, 1, 9, 2), s(3, 1, 9, 3), s(3, 1, 10, 3),
+    s(5, 1, 4, 2), s(16, 0, 8, 1),
+    s(14, 3, 9, 4), s(11, 2, 9, 4), s(12, 3, 9, 4),
+    s(3, 1, 0, 3), s(2, 0, 9, 4),
+    s(21, 2, 6, 1), s(11, 2, 6, 1), s(14, 1, 4, 2), s(0, 2, 6, 4),
+    s(3, 1, 0, 1), s(3, 1, 0, 2), s(3, 1, 0, 3), s(3, 1, 0, 4),
+    s(5, 1, 4, 4), s(15, 0, 0, 4),
+    s(14, 3, 9, 4), s(17, 2, 10, 4), s(14, 3, 10, 2),
+    s(6, 1, 7, 4),
+    s(0, 1, 9, 3), s(0, 2, 10, 3),
+    s(3, 0, 2, 4), s(3, 1, 0, 4),
+    s(15, 0, 3, 5), s(15, 0, 7, 1), s(15, 0, 7, 2), s(15, 2, 2, 2),
+    s(21, 2, 2, 1), s(12, 3, 10, 4), s(12, 3, 9, 1),
+    s(17, 0, 7, 4), s(13, 1, 7, 2), s(14, 1, 7, 1),
+    s(5, 1, 0, 4), s(6, 1, 0, 1), s(6, 1, 0, 2),
+    s(20, 2, 2, 2), s(15, 2, 5, 4),
+    s(9, 2, 10, 4), s(11, 2, 9, 2), s(8, 2, 10, 3),
+    s(0, 2, 9, 3), s(0, 2, 9, 4), s(0, 2, 9, 1),
+    s(8, 0, 12, 4), s(16, 0, 12, 1),
+    s(12, 1, 12, 1), s(12, 1, 12, 3),
+    s(21, 2, 6, 4), s(19, 2, 6, 4),
+    s(21, 2, 6, 1), s(11, 2, 6, 1), s(0, 2, 6, 3),
+    s(0, 1, 0, 4), s(19, 0, 3, 2), s(5, 2, 0, 4), s(9, 0, 7, 1),
+    s(12, 1, 4, 2), s(17, 0, 3, 4),
+    s(14, 1, 12, 3), s(14, 1, 12, 4),
+    s(10, 2, 6, 2), s(10, 2, 6, 4), s(12, 1, 0, 4),
+    s(9, 0, 7, 1), s(11, 0, 7, 4),
+    s(3, 0, 7, 4), s(0, 2, 0, 2),
+    s(3, 1, 0, 1), s(3, 1, 0, 3), s(3, 1, 0, 4),
+    s(10, 0, 8, 4), s(12, 1, 0, 4),
+    s(16, 0, 10, 1), s(6, 1, 9, 2), s(6, 1, 9, 4), s(17, 0, 10, 4),
+    s(13, 3, 12, 2), s(11, 2, 9, 2),
+    s(3, 1, 12, 2), s(3, 0, 12, 2), s(3, 1, 9, 2),
+    s(5, 1, 7, 1), s(5, 0, 8, 1),
+    s(8, 0, 8, 1), s(8, 0, 8, 2), s(8, 3, 0, 2),
+    s(3, 0, 9, 2), s(3, 0, 10, 2), s(3, 0, 10, 4),
+    s(2, 1, 7, 3), s(2, 1, 7, 4), s(2, 1, 7, 1),
+    s(16, 0, 12, 1), s(15, 0, 12, 4),
+    s(3, 0, 12, 2), s(3, 0, 11, 2), s(3, 0, 12, 4),
+    s(18, 2, 10, 2), s(17, 2, 10, 4),
+    s(2, 1, 4, 1), s(1, 1, 0, 4),
+    s(8, 1, 7, 3), s(8, 1, 7, 4),
+    s(14, 1, 9, 2), s(12, 1, 9, 4),
+    s(3, 0, 8, 2), s(0, 2, 0, 3), s(3, 1, 0, 2),
+    s(8, 1, 10, 2), s(8, 1, 10, 4), s(8, 1, 9, 2),
+    s(9, 2, 6, 4), s(0, 2, 6, 4), s(10, 2, 6, 4),
+    s(12, 1, 9, 4), s(14, 1, 9, 2),
+    s(13, 3, 0, 2), s(12, 3, 0, 4), s(12, 1, 0, 2),
+    s(7, 1, 12, 3), s(16, 0, 12, 1),
+    s(11, 2, 2, 4), s(14, 3, 4, 1), s(0, 3, 4, 4), s(0, 2, 2, 4),
+    s(3, 0, 12, 2), s(3, 0, 12, 3), s(3, 0, 12, 1),
+    s(10, 2, 11, 4), s(9, 2, 2, 1),
+    s(14, 3, 9, 1), s(14, 3, 9, 4),
+    s(9, 2, 9, 4), s(13, 3, 9, 2),
+    s(3, 0, 9, 3), s(3, 0, 9, 2),
+    s(14, 1, 0, 3), s(8, 1, 0, 2),
+    s(12, 1, 10, 1), s(13, 1, 10, 2), s(9, 2, 9, 1),
+    s(0, 3, 0, 4), s(12, 3, 4, 2), s(14, 3, 0, 4),
+    s(19, 0, 3, 2), s(15, 2, 2, 2),
+    s(0, 1, 0, 3), s(14, 1, 9, 2),
+    s(12, 1, 7, 3), s(12, 1, 7, 2),
+    s(5, 2, 9, 3),
+    s(12, 1, 7, 3), s(12, 1, 7, 1), s(12, 1, 7, 2),
+    s(17, 0, 0, 2), s(5, 0, 9, 4),
+    s(13, 1, 0, 4), s(5, 1, 7, 1),
+    s(9, 0, 9, 1), s(9, 0, 9, 4), s(9, 0, 9, 3), s(11, 0, 9, 4),
+    s(15, 0, 3, 2), s(5, 0, 1, 1),
+    s(10, 2, 0, 1), s(13, 1, 1, 4),
+    s(9, 0, 11, 1), s(10, 2, 12, 4), s(13, 1, 11, 1),
+    s(0, 0, 3, 3),
+    s(11, 2, 2, 4), s(11, 2, 1, 1), s(14, 3, 0, 1),
+    s(13, 1, 0, 4), s(13, 1, 4, 4),
+    s(1, 1, 10, 1), s(4, 0, 10, 1), s(2, 1, 10, 1),
+    s(0, 1, 9, 2), s(0, 1, 9, 4), s(14, 1, 12, 2),
+    s(4, 0, 1, 2), s(4, 0, 1, 3), s(12, 1, 4, 2), s(9, 0, 3, 2),
+    s(15, 0, 1, 3), s(15, 0, 1, 4), s(19, 2, 2, 2),
+    s(12, 3, 0, 1), s(19, 2, 0, 1),
+    s(10, 0, 3, 1), s(8, 2, 2, 3),
+    s(4, 2, 0, 2), s(4, 0, 6, 4),
+    s(8, 0, 1, 2), s(8, 1, 0, 4), s(8, 0, 1, 1),
+    s(7, 2, 0, 3), s(7, 2, 0, 2),
+    s(2, 0, 12, 1), s(2, 1, 12, 1), s(2, 0, 12, 4),
+    s(2, 0, 7, 4), s(1, 0, 7, 2), s(2, 2, 0, 1),
+    s(1, 0, 2, 1), s(0, 0, 3, 4),
+    s(10, 2, 11, 1), s(9, 2, 11, 1),
+    s(9, 2, 6, 1), s(11, 0, 3, 4),
+    s(0, 2, 6, 3), s(11, 2, 6, 4), s(9, 2, 6, 4),
+    s(9, 0, 3, 4), s(8, 2, 2, 4), s(8, 1, 0, 4),
+    s(14, 1, 9, 4), s(0, 1, 10, 2), s(10, 0, 10, 4), s(10, 0, 12, 1), s(0, 1, 10, 3),
+    s(14, 1, 12, 2), s(10, 0, 12, 1),
+    s(5, 2, 12, 4), s(6, 2, 12, 2), s(8, 1, 8, 2),
+    s(2, 0, 12, 1), s(2, 1, 12, 2),
+    s(11, 2, 12, 2), s(9, 2, 12, 3),
+    s(13, 1, 12, 5),
+    s(10, 0, 12, 1), s(13, 1, 12, 4),
+    s(14, 1, 7, 1), s(13, 1, 7, 4),
+    s(13, 3, 4, 4), s(10, 0, 3, 4), s(10, 2, 0, 4),
+    s(3, 0, 11, 3), s(1, 0, 11, 4),
+    s(8, 2, 2, 4), s(8, 2, 12, 4),
+    s(0, 3, 12, 3), s(6, 2, 12, 2),
+    s(0, 2, 2, 4), s(0, 0, 3, 2), s(0, 1, 0, 3),
+    s(8, 1, 8, 2), s(16, 2, 0, 4),
+    s(0, 1, 12, 4), s(9, 0, 12, 3),
+    s(16, 0, 10, 3), s(20, 0, 10, 2),
+    s(10, 0, 12, 1), s(10, 0, 12, 3),
+    s(15, 2, 6, 4), s(5, 2, 2, 3),
+    s(10, 2, 12, 1), s(10, 2, 12, 4),
+    s(19, 2, 12, 4), s(20, 2, 12, 2),
+    s(3, 1, 10, 2), s(11, 2, 10, 1),
+    s(8, 2, 0, 4), s(8, 1, 8, 4), s(8, 2, 2, 4),
+    s(5, 2, 6, 4), s(5, 2, 6, 1),
+    s(13, 1, 0, 2), s(13, 1, 0, 1), s(13, 1, 0, 3),
+    s(8, 2, 10, 3), s(8, 2, 10, 4), s(8, 2, 10, 2),
+    s(7, 0, 7, 2), s(9, 0, 11, 1),
+    s(5, 1, 4, 2), s(17, 0, 3, 2),
+    s(5, 2, 0, 2), s(15, 0, 8, 2),
+    s(12, 1, 4, 2), s(10, 0, 3, 3), s(0, 1, 1, 4),
+    s(0, 2, 6, 4), s(0, 2, 6, 3),
+    s(15, 0, 10, 1), s(0, 0, 9, 3), s(10, 0, 9, 4),
+    s(6, 1, 0, 2), s(5, 1, 0, 1),
+    s(12, 1, 9, 3), s(14, 1, 9, 2),
+    s(16, 0, 1, 2), s(16, 0, 1, 1),
+    s(21, 2, 0, 4), s(14, 1, 4, 4),
+    s(8, 1, 8, 2), s(8, 1, 8, 4),
+    s(21, 0, 0, 1), s(6, 1, 0, 2),
+    s(1, 0, 11, 4), s(2, 0, 11, 2), s(2, 0, 11, 1),
+    s(11, 2, 1, 2), s(10, 0, 3, 3), s(9, 2, 0, 1),
+    s(0, 2, 6, 3), s(10, 2, 6, 3),
+    s(14, 1, 1, 2), s(13, 1, 1, 4), s(0, 1, 1, 4),
+    s(0, 2, 6, 2), s(0, 2, 6, 4), s(0, 0, 5, 2), s(9, 0, 5, 4),
+    s(5, 2, 6, 1), s(15, 2, 6, 4),
+    s(10, 0, 3, 1), s(10, 0, 3, 3),
+    s(8, 0, 1, 2), s(8, 0, 1, 1),
+    s(15, 2, 9, 1), s(6, 2, 9, 2), s(6, 2, 2, 2),
+    s(13, 1, 0, 4), s(13, 1, 0, 1),
+    s(16, 0, 10, 3), s(20, 0, 1, 4),
+    s(0, 1, 10, 3), s(0, 1, 10, 1),
+    s(3, 0, 2, 2), s(3, 0, 2, 4),
+    s(13, 1, 0, 4), s(15, 2, 0, 2),
+    s(13, 1, 12, 4), s(13, 1, 12, 3),
+    s(8, 1, 10, 2), s(8, 1, 10, 4), s(8, 1, 10, 3), s(8, 1, 12, 2),
+    s(6, 0, 9, 2), s(5, 1, 9, 4),
+    s(11, 2, 11, 2), s(10, 2, 11, 4), s(9, 2, 12, 3),
+    s(2, 0, 9, 2), s(1, 0, 2, 1),
+    s(19, 0, 1, 2), s(17, 0, 3, 2),
+    s(13, 1, 7, 1), s(13, 1, 7, 3), s(13, 1, 7, 4), s(0, 0, 7, 2),
+    s(12, 1, 9, 3), s(14, 1, 9, 3),
+    s(11, 0, 3, 2), s(13, 1, 7, 1), s(13, 1, 7, 4), s(0, 0, 7, 2),
+    s(10, 0, 3, 4), s(11, 2, 2, 4),
+    s(8, 0, 6, 2), s(8, 0, 6, 4), s(8, 0, 6, 3),
+    s(0, 0, 3, 4), s(13, 1, 0, 4),
+    s(0, 0, 5, 4), s(0, 1, 0, 2),
+    s(20, 0, 1, 3),
+    s(14, 1, 9, 2), s(14, 1, 10, 2),
+    s(10, 2, 11, 4), s(9, 2, 12, 3),
+    s(8, 1, 0, 4), s(8, 2, 2, 4),
+    s(17, 0, 0, 4), s(13, 1, 0, 2), s(15, 0, 0, 4), s(17, 0, 0, 2),
+    s(13, 1, 0, 2), s(15, 0, 0, 3),
+    s(13, 1, 0, 2), s(16, 0, 0, 2), s(15, 0, 0, 1), s(15, 0, 0, 3),
+    s(13, 1, 0, 2), s(9, 2, 6, 3),
+    s(1, 0, 12, 1), s(4, 0, 11, 1),
+    s(15, 2, 12, 4), s(16, 2, 12, 1),
+    s(19, 2, 0, 3), s(12, 1, 4, 1),
+    s(21, 2, 9, 4),
+    s(17, 0, 10, 2), s(17, 0, 10, 1),
+    s(20, 0, 0, 2), s(21, 0, 0, 4),
+    s(3, 1, 0, 2), s(7, 1, 0, 3),
+    s(12, 1, 0, 4), s(15, 0, 5, 4),
+    s(17, 2, 6, 4), s(8, 0, 6, 4),
+    s(16, 0, 9, 2), s(17, 0, 9, 4),
+    s(0, 1, 11, 2), s(17, 0, 11, 1),
+    s(0, 3, 12, 3), s(0, 1, 12, 2),
+    s(12, 1, 0, 1), s(12, 1, 0, 4), s(13, 1, 0, 2),
+    s(16, 0, 9, 2), s(17, 0, 9, 4), s(6, 0, 9, 2),
+    s(3, 1, 0, 2), s(7, 1, 0, 3), s(14, 1, 9, 3),
+    s(0, 3, 0, 2), s(0, 3, 0, 4),
+    s(19, 0, 0, 3), s(19, 0, 0, 4),
+    s(16, 0, 1, 2), s(7, 0, 1, 2),
+    s(0, 1, 0, 4), s(15, 0, 0, 2),
+    s(15, 2, 12, 3), s(16, 2, 12, 2), s(15, 2, 12, 4),
+    s(11, 0, 7, 4), s(3, 0, 7, 4),
+    s(10, 0, 3, 1), s(10, 0, 3, 4),
+    s(3, 1, 0, 4), s(1, 1, 0, 4), s(1, 1, 4, 2),
+    s(7, 1, 0, 2), s(7, 1, 0, 4),
+    s(19, 2, 0, 1), s(12, 3, 0, 1),
+    s(16, 0, 12, 4), s(16, 0, 12, 1), s(2, 1, 12, 2),
+    s(12, 1, 0, 1), s(15, 0, 0, 3),
+    s(16, 0, 12, 1), s(16, 0, 10, 4), s(16, 0, 12, 4),
+    s(11, 2, 2, 2), s(10, 2, 2, 4),
+    s(11, 0, 3, 2), s(9, 0, 3, 2),
+    s(12, 1, 4, 1), s(12, 1, 0, 2),
+    s(0, 1, 0, 2), s(16, 0, 0, 3), s(0, 1, 0, 4),
+    s(4, 2, 0, 4), s(1, 2, 0, 1), s(2, 2, 0, 1),
+    s(14, 3, 10, 4), s(19, 0, 3, 4),
+    s(6, 2, 0, 2), s(17, 2, 0, 3),
+    s(17, 2, 6, 4), s(6, 2, 2, 1), s(6, 2, 6, 4), s(6, 2, 9, 4),
+    s(8, 2, 10, 3),
+    s(15, 2, 10, 4), s(15, 2, 10, 3),
+    s(8, 0, 12, 2), s(8, 0, 12, 4), s(8, 1, 12, 2),
+    s(10, 0, 3, 1), s(11, 2, 1, 4),
+    s(1, 1, 12, 3), s(8, 1, 10, 3),
+    s(16, 0, 8, 2), s(6, 1, 7, 2), s(5, 1, 7, 4),
+    s(19, 2, 6, 4), s(19, 2, 0, 2), s(21, 2, 0, 1),
+    s(14, 1, 0, 4), s(13, 1, 4, 4),
+    s(2, 1, 0, 4), s(1, 1, 0, 4),
+    s(19, 2, 12, 1), s(19, 2, 12, 3),
+    s(14, 1, 9, 2), s(12, 1, 9, 1), s(8, 1, 9, 4), s(8, 1, 9, 3),
+    s(19, 0, 0, 1), s(12, 1, 8, 1),
+    s(12, 1, 0, 4), s(19, 0, 3, 4),
+    s(15, 0, 10, 3), s(15, 0, 10, 1), s(1, 1, 9, 1),
+    s(9, 0, 7, 3), s(10, 0, 7, 4), s(9, 0, 7, 4), s(12, 1, 7, 4),
+    s(18, 2, 12, 5),
+    s(20, 0, 9, 3), s(17, 0, 9, 1), s(20, 0, 10, 1),
+    s(3, 0, 6, 2), s(3, 0, 10, 2), s(3, 1, 0, 2),
+    s(15, 0, 0, 4), s(16, 0, 0, 2), s(6, 1, 0, 2),
+    s(12, 1, 0, 3), s(12, 1, 0, 4),
+    s(14, 1, 7, 1), s(18, 0, 7, 4),
+    s(15, 2, 2, 1), s(1, 0, 2, 2),
+    s(15, 2, 12, 3), s(6, 2, 12, 2), s(15, 2, 12, 4),
+    s(0, 3, 0, 4), s(0, 3, 0, 3),
+    s(12, 1, 0, 4), s(19, 0, 0, 4),
+    s(16, 0, 12, 1), s(1, 1, 4, 2),
+    s(1, 1, 7, 1), s(2, 0, 7, 1),
+    s(18, 0, 11, 2), s(18, 0, 11, 3), s(18, 0, 12, 2),
+    s(15, 2, 2, 1), s(12, 3, 4, 2),
+    s(20, 2, 9, 2), s(19, 0, 9, 4),
+    s(14, 3, 4, 2), s(12, 3, 4, 2),
+    s(0, 2, 1, 1), s(0, 1, 1, 4),
+    s(13, 3, 12, 2), s(13, 3, 12, 1), s(10, 2, 12, 1),
+    s(10, 2, 12, 1), s(10, 2, 12, 4), s(10, 2, 12, 3),
+    s(0, 3, 0, 1), s(0, 3, 0, 3),
+    s(0, 1, 7, 4), s(0, 1, 7, 3),
+    s(16, 2, 9, 1), s(16, 2, 9, 4), s(0, 3, 9, 1),
+    s(15, 2, 10, 1), s(6, 2, 10, 2),
+    s(12, 1, 7, 4), s(2, 0, 7, 4), s(8, 1, 7, 2), s(8, 1, 8, 4),
+    s(6, 1, 7, 3), s(6, 1, 7, 1),
+    s(12, 1, 7, 4), s(19, 0, 7, 4),
+    s(16, 2, 11, 1), s(20, 2, 12, 1),
+    s(15, 2, 2, 5),
+    s(14, 3, 10, 1), s(0, 1, 10, 4), s(0, 1, 10, 1),
+    s(0, 1, 1, 4), s(0, 1, 4, 1),
+    s(0, 3, 0, 2), s(5, 0, 8, 1),
+    s(0, 1, 7, 2), s(0, 1, 7, 4), s(13, 1, 7, 1),
+    s(12, 3, 0, 4), s(8, 0, 8, 2),
+    s(10, 2, 6, 1), s(10, 2, 6, 3),
+    s(15, 0, 7, 1), s(10, 0, 3, 1),
+    s(10, 2, 9, 3), s(20, 2, 9, 4),
+    s(20, 2, 9, 4), s(20, 2, 9, 1),
+    s(5, 0, 8, 4), s(5, 2, 0, 2),
+    s(8, 1, 0, 4), s(0, 2, 6, 4),
+    s(13, 3, 0, 3), s(10, 0, 8, 3),
+    s(15, 0, 9, 4), s(15, 0, 9, 1),
+    s(8, 2, 12, 2), s(7, 0, 12, 2),
+    s(6, 2, 12, 2), s(15, 2, 12, 1),
+    s(12, 1, 12, 4), s(15, 0, 10, 3),
+    s(0, 2, 5, 1), s(11, 2, 1, 1),
+    s(15, 2, 0, 2), s(5, 2, 0, 3),
+    s(8, 0, 3, 4), s(12, 1, 10, 1),
+    s(9, 0, 9, 1), s(9, 0, 9, 4), s(9, 0, 9, 3),
+    s(15, 2, 6, 4), s(18, 2, 6, 4),
+    s(11, 2, 0, 4), s(0, 2, 10, 3), s(0, 2, 0, 4),
+    s(11, 0, 11, 2), s(11, 0, 11, 4),
+    s(20, 0, 10, 2), s(12, 1, 10, 4), s(11, 0, 9, 2),
+    s(1, 1, 0, 4), s(2, 1, 0, 2), s(1, 1, 0, 1),
+    s(5, 0, 1, 1), s(14, 1, 1, 2), s(7, 0, 1, 4),
+    s(6, 1, 7, 2), s(17, 0, 7, 4),
+    s(20, 0, 3, 4), s(17, 0, 9, 4),
+    s(19, 0, 3, 2), s(19, 2, 2, 2), s(15, 0, 1, 4),
+    s(6, 1, 0, 5),
+    s(14, 1, 7, 2), s(12, 1, 7, 3), s(12, 1, 7, 4),
+    s(12, 1, 10, 1), s(13, 1, 9, 2),
+    s(21, 2, 10, 3), s(0, 3, 10, 2), s(14, 3, 10, 4),
+    s(15, 2, 0, 4), s(15, 2, 0, 2),
+    s(6, 2, 12, 3), s(5, 2, 12, 4), s(6, 2, 12, 2),
+    s(0, 3, 10, 2), s(12, 3, 10, 1),
+    s(6, 2, 12, 2), s(6, 2, 12, 3), s(0, 3, 12, 3), s(5, 2, 12, 4),
+    s(15, 0, 0, 4), s(15, 0, 0, 3),
+    s(20, 0, 3, 4), s(12, 1, 1, 1), s(12, 1, 1, 2),
+    s(9, 0, 7, 4), s(9, 0, 7, 3),
+    s(0, 0, 8, 1), s(0, 2, 0, 2),
+    s(2, 0, 8, 2), s(1, 2, 0, 4), s(4, 2, 0, 2), s(2, 2, 0, 2),
+    s(1, 1, 0, 3), s(1, 0, 6, 1), s(1, 1, 0, 1), s(1, 1, 0, 4), s(2, 0, 5, 2),
+    s(6, 0, 5, 2), s(16, 0, 0, 2),
+    s(9, 2, 5, 3), s(5, 0, 5, 4),
+    s(5, 0, 7, 4), s(15, 0, 7, 4),
+    s(16, 0, 0, 2), s(11, 2, 0, 3),
+    s(13, 1, 12, 4), s(12, 1, 12, 1), s(13, 1, 11, 1),
+    s(17, 0, 1, 4), s(15, 0, 1, 2),
+    s(12, 3, 10, 4), s(13, 3, 10, 1),
+    s(0, 3, 9, 1), s(0, 2, 9, 3),
+    s(18, 2, 2, 4), s(7, 0, 1, 4),
+    s(15, 0, 10, 1), s(12, 1, 9, 3),
+    s(14, 1, 12, 1), s(14, 1, 12, 3), s(17, 0, 12, 3),
+    s(0, 1, 0, 2), s(17, 0, 0, 1),
+    s(15, 2, 0, 4), s(15, 2, 2, 2),
+    s(0, 3, 4, 1), s(0, 1, 7, 4), s(16, 2, 2, 4),
+    s(17, 2, 2, 4), s(14, 1, 7, 1), s(13, 1, 7, 4),
+    s(12, 1, 4, 2), s(12, 1, 4, 1),
+    s(16, 2, 9, 2), s(5, 2, 9, 1),
+    s(11, 2, 11, 2), s(11, 2, 12, 2),
+    s(0, 3, 10, 2), s(14, 3, 10, 1),
+    s(8, 2, 12, 3), s(9, 2, 12, 1), s(9, 0, 9, 3),
+    s(2, 0, 12, 2), s(2, 0, 11, 2),
+    s(15, 2, 2, 2), s(11, 2, 2, 4),
+    s(17, 0, 5, 1), s(17, 0, 0, 1),
+    s(13, 1, 11, 1), s(20, 0, 11, 1),
+    s(5, 1, 0, 2), s(15, 2, 0, 2),
+    s(20, 0, 9, 3), s(20, 0, 10, 1), s(19, 0, 9, 1),
+    s(15, 2, 9, 4), s(21, 2, 9, 3), s(19, 2, 9, 3),
+    s(2, 1, 7, 3), s(1, 1, 7, 1),
+    s(19, 0, 3, 2), s(15, 0, 5, 4),
+    s(9, 2, 6, 4), s(9, 2, 2, 2),
+    s(20, 2, 0, 4), s(16, 2, 2, 4), s(20, 0, 8, 4),
+    s(19, 0, 7, 4), s(16, 0, 8, 4),
+    s(8, 0, 8, 3), s(8, 3, 0, 3), s(12, 3, 0, 4),
+    s(20, 0, 3, 4), s(12, 1, 0, 2),
+    s(17, 0, 5, 1), s(21, 0, 0, 1),
+    s(5, 0, 11, 4), s(6, 0, 11, 1),
+    s(21, 2, 10, 3), s(15, 2, 9, 4),
+    s(19, 0, 9, 1), s(19, 0, 9, 3),
+    s(14, 1, 7, 1), s(14, 1, 7, 3),
+    s(13, 1, 0, 5),
+    s(9, 0, 9, 3), s(9, 0, 9, 4),
+    s(1, 0, 2, 3), s(1, 0, 2, 4),
+    s(15, 2, 1, 1), s(10, 0, 3, 1),
+    s(1, 2, 0, 4), s(1, 0, 2, 2),
+    s(15, 0, 10, 1), s(12, 1, 9, 1),
+    s(7, 1, 4, 4), s(3, 1, 0, 2),
+    s(16, 0, 8, 2), s(6, 0, 7, 2),
+    s(12, 1, 0, 2), s(12, 1, 4, 4),
+    s(4, 0, 9, 1), s(1, 0, 9, 1), s(2, 0, 9, 1),
+    s(21, 0, 8, 3), s(17, 2, 0, 3),
+    s(13, 1, 9, 5),
+    s(12, 1, 9, 3), s(12, 1, 9, 1),
+    s(13, 3, 0, 2), s(12, 3, 0, 3),
+    s(5, 1, 0, 2), s(19, 0, 1, 2),
+    s(7, 3, 0, 3),
+    s(20, 2, 10, 4),
+    s(1, 1, 0, 3), s(2, 1, 0, 1),
+    s(17, 0, 1, 1), s(16, 0, 7, 3),
+    s(10, 0, 11, 1), s(12, 1, 12, 1),
+    s(0, 1, 10, 5),
+    s(6, 0, 5, 5),
+    s(20, 2, 0, 1),
+    s(15, 0, 9, 1), s(7, 1, 9, 2),
+    s(19, 0, 0, 1), s(20, 0, 0, 2), s(12, 1, 0, 4),
+    s(15, 0, 8, 1), s(0, 3, 0, 4),
+    s(20, 2, 6, 4), s(21, 2, 6, 4),
+    s(12, 1, 12, 1), s(13, 1, 12, 2), s(12, 1, 12, 4),
+    s(9, 2, 2, 3), s(11, 2, 1, 4),
+    s(21, 0, 9, 3), s(17, 0, 10, 1),
+    s(1, 1, 9, 3), s(1, 1, 9, 1),
+    s(11, 2, 0, 2), s(11, 2, 0, 1), s(11, 2, 0, 4),
+    s(3, 1, 0, 2), s(3, 0, 6, 2),
+    s(21, 0, 9, 3), s(21, 0, 9, 1), s(17, 0, 10, 1),
+    s(12, 1, 11, 4), s(12, 1, 11, 1),
+    s(6, 1, 7, 4), s(5, 1, 7, 4),
+    s(3, 1, 0, 4), s(21, 0, 0, 1),
+    s(12, 1, 8, 1), s(12, 1, 8, 3),
+    s(14, 1, 0, 4), s(12, 1, 0, 4),
+    s(15, 0, 12, 3), s(15, 0, 12, 1),
+    s(12, 1, 8, 1), s(12, 1, 7, 3),
+    s(0, 3, 4, 1), s(0, 1, 7, 1), s(0, 1, 7, 4), s(5, 1, 0, 4),
+    s(11, 2, 12, 2), s(9, 2, 12, 1), s(12, 1, 11, 4),
+    s(0, 3, 0, 1), s(0, 0, 8, 1),
+    s(11, 0, 3, 2), s(9, 0, 3, 1), s(12, 1, 4, 2),
+    s(0, 2, 10, 3), s(0, 2, 10, 4),
+    s(0, 2, 10, 2), s(0, 2, 10, 4),
+    s(4, 0, 8, 2),
+    s(12, 1, 0, 4), s(12, 1, 4, 2), s(12, 1, 4, 4),
+    s(16, 2, 10, 2), s(15, 2, 10, 3), s(6, 2, 10, 2), s(13, 3, 9, 2), s(19, 0, 0, 1), s(15, 2, 10, 1),
+    s(2, 1, 0, 1), s(2, 1, 0, 2), s(1, 1, 0, 3), s(1, 1, 0, 1), s(1, 1, 0, 4), s(16, 0, 0, 3),
+    s(17, 0, 1, 1), s(3, 1, 7, 3),
+    s(4, 0, 11, 3), s(1, 0, 11, 3), s(4, 0, 11, 4),
+    s(19, 0, 1, 1), s(15, 0, 1, 1),
+    s(1, 0, 1, 5),
+    s(4, 2, 0, 1), s(4, 2, 0, 4),
+    s(16, 0, 8, 2), s(16, 0, 8, 1), s(15, 0, 8, 4),
+    s(8, 0, 6, 4), s(8, 0, 6, 2), s(8, 0, 6, 3), s(8, 3, 0, 4), s(8, 1, 4, 4),
+    s(1, 0, 2, 1), s(1, 1, 0, 4),
+    s(15, 2, 0, 4), s(17, 2, 0, 1),
+    s(17, 0, 7, 4), s(16, 0, 7, 1),
+    s(15, 0, 10, 3), s(6, 1, 9, 3), s(12, 1, 10, 3),
+    s(14, 1, 9, 2), s(14, 3, 9, 4),
+    s(19, 2, 0, 3), s(13, 3, 0, 1),
+    s(12, 1, 4, 2), s(12, 1, 0, 4), s(12, 1, 4, 1),
+    s(9, 2, 1, 4), s(10, 2, 1, 1),
+    s(11, 2, 9, 2), s(9, 0, 12, 1),
+    s(12, 1, 4, 2), s(14, 1, 4, 2), s(13, 1, 1, 4), s(12, 1, 1, 2), s(13, 1, 0, 4),
+    s(12, 1, 7, 3), s(14, 1, 7, 2), s(12, 1, 7, 4),
+    s(9, 0, 5, 3), s(0, 0, 5, 3),
+    s(8, 2, 2, 4), s(8, 0, 7, 4),
+    s(1, 0, 12, 1), s(1, 1, 12, 1), s(2, 0, 12, 1),
+    s(9, 0, 6, 3), s(12, 1, 0, 3), s(14, 1, 1, 2),
+    s(5, 2, 12, 4), s(6, 2, 12, 2), s(6, 2, 12, 1),
+    s(6, 1, 7, 4), s(5, 1, 7, 4), s(5, 0, 7, 4),
+    s(14, 3, 0, 4), s(16, 2, 0, 4), s(7, 3, 0, 4), s(7, 0, 1, 4),
+    s(12, 3, 9, 4), s(14, 3, 9, 4),
+    s(16, 0, 0, 1), s(15, 0, 0, 3),
+    s(3, 1, 9, 3), s(0, 2, 10, 4), s(3, 0, 9, 2), s(0, 2, 9, 4),
+    s(14, 1, 7, 1), s(17, 0, 7, 1),
+    s(11, 2, 9, 2), s(11, 2, 9, 4), s(0, 2, 9, 4),
+    s(13, 1, 10, 1), s(14, 1, 9, 1),
+    s(9, 0, 12, 3), s(1, 1, 12, 3),
+    s(6, 1, 0, 2), s(6, 1, 0, 4),
+    s(6, 0, 8, 4), s(14, 1, 8, 4),
+    s(21, 2, 6, 1), s(21, 2, 6, 2), s(17, 2, 5, 1), s(18, 2, 6, 2), s(6, 2, 2, 3),
+    s(12, 1, 12, 1), s(12, 1, 12, 4),
+    s(0, 1, 9, 2), s(14, 1, 9, 4),
+    s(19, 2, 12, 1), s(19, 0, 12, 4), s(19, 2, 12, 4),
+    s(16, 0, 10, 1), s(17, 0, 10, 1), s(8, 1, 10, 2),
+    s(16, 0, 8, 2), s(6, 0, 7, 1), s(5, 1, 7, 4),
+    s(13, 1, 0, 2), s(13, 1, 0, 4),
+    s(15, 2, 10, 3), s(15, 2, 10, 4),
+    s(13, 1, 9, 4), s(13, 1, 12, 1), s(15, 0, 12, 1),
+    s(0, 2, 6, 2), s(0, 1, 0, 2),
+    s(13, 1, 0, 3), s(13, 1, 12, 4), s(13, 1, 12, 3),
+    s(8, 2, 10, 2), s(9, 2, 9, 1),
+    s(13, 1, 0, 3), s(0, 1, 0, 3),
+    s(16, 2, 2, 4), s(16, 0, 7, 1),
+    s(6, 1, 9, 2), s(6, 0, 9, 3), s(16, 0, 9, 1),
+    s(9, 2, 10, 3), s(11, 2, 10, 4), s(11, 2, 10, 2),
+    s(8, 3, 0, 4), s(8, 2, 0, 4),
+    s(18, 2, 9, 3), s(18, 2, 9, 4),
+    s(12, 1, 0, 1), s(13, 1, 0, 4), s(13, 1, 0, 1), s(12, 1, 0, 2),
+    s(16, 2, 12, 2), s(15, 2, 12, 4),
+    s(3, 1, 10, 2), s(3, 1, 10, 3), s(3, 1, 9, 2), s(11, 2, 10, 2),
+    s(3, 1, 7, 2), s(3, 0, 7, 2),
+    s(14, 1, 4, 4), s(0, 1, 4, 4),
+    s(1, 1, 9, 1), s(1, 1, 9, 3), s(1, 1, 9, 4),
+    s(9, 0, 12, 1), s(9, 0, 12, 4),
+    s(6, 0, 8, 2), s(14, 3, 0, 1), s(0, 3, 0, 2),
+    s(19, 2, 12, 1), s(19, 2, 12, 4),
+    s(1, 1, 9, 4), s(2, 1, 9, 2), s(1, 1, 9, 3),
+    s(0, 3, 10, 4), s(9, 2, 10, 3),
+    s(0, 3, 10, 4), s(0, 2, 10, 1),
+    s(1, 0, 11, 1), s(1, 0, 11, 4),
+    s(9, 2, 0, 3), s(11, 2, 0, 2),
+    s(15, 0, 8, 4), s(16, 0, 7, 4), s(20, 2, 0, 4), s(15, 0, 8, 1),
+    s(20, 0, 0, 1), s(20, 2, 2, 4), s(21, 2, 2, 3),
+    s(20, 2, 6, 1), s(21, 2, 6, 1), s(17, 2, 5, 1),
+    s(18, 2, 12, 2), s(18, 2, 12, 3), s(18, 2, 12, 4),
+    s(16, 0, 10, 1), s(15, 0, 10, 3),
+    s(19, 0, 5, 4), s(19, 0, 12, 1),
+    s(14, 1, 9, 4), s(14, 3, 9, 2),
+    s(4, 0, 12, 4), s(4, 0, 12, 2),
+    s(21, 2, 2, 1), s(21, 2, 0, 4),
+    s(0, 1, 9, 3), s(0, 1, 10, 3),
+    s(19, 2, 12, 4), s(20, 2, 12, 2), s(19, 2, 12, 3),
+    s(12, 3, 9, 4), s(15, 2, 9, 4),
+    s(13, 1, 9, 4), s(13, 1, 9, 1),
+    s(3, 2, 0, 4), s(3, 0, 2, 4),
+    s(2, 1, 7, 3), s(2, 1, 7, 1),
+    s(19, 2, 12, 3), s(19, 2, 12, 1), s(20, 2, 12, 1),
+    s(17, 0, 9, 1), s(14, 1, 9, 1), s(14, 1, 7, 1), s(21, 0, 7, 1), s(20, 0, 9, 3),
+    s(21, 2, 6, 4), s(20, 2, 6, 3),
+    s(4, 0, 9, 2), s(2, 0, 2, 2), s(2, 0, 9, 2),
+    s(1, 0, 12, 3), s(1, 0, 12, 1), s(1, 0, 12, 4),
+    s(21, 0, 7, 1), s(19, 0, 7, 3),
+    s(3, 0, 8, 2), s(12, 1, 8, 1), s(3, 1, 8, 4), s(3, 2, 0, 4), s(3, 1, 7, 4), s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 1, 7, 4), s(8, 2, 0, 4),
+    s(0, 1, 7, 2), s(0, 1, 8, 2), s(15, 0, 8, 4),
+    s(17, 0, 1, 5),
+    s(13, 1, 7, 1), s(12, 3, 4, 1),
+    s(0, 3, 0, 4), s(12, 3, 4, 2),
+    s(8, 1, 7, 2), s(18, 0, 7, 3),
+    s(18, 0, 7, 4), s(18, 0, 7, 3),
+    s(16, 0, 9, 3), s(16, 0, 9, 2),
+    s(11, 2, 6, 4), s(11, 2, 6, 2),
+    s(17, 0, 12, 2), s(0, 1, 12, 4), s(3, 1, 10, 3), s(17, 0, 12, 4),
+    s(11, 2, 6, 4), s(9, 2, 6, 4),
+    s(19, 0, 7, 3), s(21, 0, 7, 1), s(13, 1, 7, 1),
+    s(12, 1, 7, 3), s(15, 2, 2, 2), s(12, 1, 7, 4), s(11, 0, 3, 2),
+    s(5, 0, 9, 4), s(6, 0, 9, 2), s(16, 0, 9, 2),
+    s(17, 0, 5, 3),
+    s(14, 3, 0, 1), s(18, 2, 0, 2),
+    s(2, 2, 0, 2), s(4, 2, 0, 2),
+    s(0, 1, 7, 4), s(8, 1, 0, 4),
+    s(8, 0, 6, 2), s(8, 0, 6, 3), s(8, 0, 6, 4),
+    s(20, 0, 5, 2), s(17, 0, 9, 1),
+    s(18, 0, 11, 3), s(14, 1, 11, 1), s(21, 0, 11, 1),
+    s(8, 1, 0, 2), s(14, 1, 0, 3), s(8, 1, 0, 3), s(21, 0, 1, 3),
+    s(5, 0, 7, 4), s(5, 2, 0, 2),
+    s(11, 2, 12, 2), s(9, 2, 12, 1),
+    s(14, 1, 9, 1), s(13, 1, 9, 4),
+    s(0, 3, 4, 1), s(0, 1, 7, 1),
+    s(9, 0, 6, 3), s(12, 1, 0, 3),
+    s(1, 0, 12, 1), s(1, 0, 12, 3), s(1, 0, 12, 4),
+    s(19, 2, 12, 1), s(19, 0, 12, 4),
+    s(1, 1, 9, 4), s(2, 1, 9, 2),
+    s(2, 1, 7, 1), s(2, 1, 7, 3),
+    s(3, 0, 8, 2), s(3, 1, 7, 4), s(3, 1, 8, 4),
+    s(19, 0, 12, 1), s(19, 0, 12, 4),
+    s(13, 1, 7, 1), s(21, 0, 7, 1),
+    s(12, 1, 7, 3), s(15, 2, 2, 2),
+    s(4, 0, 8, 3),
+    s(13, 3, 4, 1), s(10, 2, 6, 3),
+    s(15, 0, 7, 5),
+    s(0, 2, 11, 3), s(0, 2, 11, 2),
+    s(11, 0, 9, 3), s(11, 0, 9, 4),
+    s(8, 2, 2, 2), s(8, 2, 2, 1),
+    s(15, 2, 0, 3), s(5, 2, 0, 2),
+    s(12, 3, 0, 1), s(12, 1, 4, 1),
+    s(1, 0, 1, 4), s(1, 0, 1, 5),
+    s(6, 1, 0, 2), s(10, 2, 10, 1),
+    s(9, 2, 1, 4), s(11, 2, 1, 4), s(9, 2, 5, 3),
+    s(8, 0, 9, 3), s(7, 0, 9, 3),
+    s(1, 0, 1, 4), s(2, 1, 0, 2), s(2, 1, 0, 4), s(1, 1, 0, 3), s(1, 0, 1, 5), s(1, 0, 5, 3),
+    s(8, 2, 2, 2), s(8, 2, 2, 1), s(8, 2, 2, 5),
+    s(3, 1, 0, 3), s(3, 1, 4, 1),
+    s(0, 1, 11, 2), s(14, 1, 11, 2),
+    s(13, 1, 11, 1), s(0, 1, 8, 3),
+    s(9, 0, 7, 1), s(3, 0, 6, 3),
+    s(0, 1, 0, 4), s(14, 1, 0, 1),
+    s(14, 1, 9, 4), s(0, 1, 9, 2), s(0, 1, 0, 2),
+    s(13, 1, 11, 3), s(13, 1, 9, 1),
+    s(21, 2, 2, 1), s(19, 2, 6, 1),
+    s(0, 1, 0, 4), s(0, 1, 0, 2), s(14, 1, 0, 1),
+    s(0, 1, 11, 5),
+    s(13, 1, 11, 1), s(10, 0, 11, 4),
+    s(13, 1, 9, 2), s(14, 1, 9, 2), s(0, 1, 9, 2),
+    s(8, 0, 6, 2), s(8, 1, 9, 2),
+    s(9, 0, 12, 1), s(8, 0, 11, 2),
+    s(0, 3, 0, 3), s(11, 2, 0, 4),
+    s(2, 1, 0, 1), s(1, 1, 0, 4), s(2, 0, 2, 1),
+    s(10, 0, 3, 2),
+    s(13, 1, 7, 4), s(13, 1, 7, 2),
+    s(14, 1, 7, 1), s(17, 2, 0, 1),
+    s(5, 1, 0, 2), s(15, 0, 5, 2),
+    s(5, 0, 7, 4), s(15, 0, 8, 1),
+    s(0, 2, 9, 2), s(0, 2, 9, 4),
+    s(11, 0, 3, 2), s(8, 1, 0, 4),
+    s(11, 0, 3, 4), s(11, 0, 7, 4),
+    s(11, 0, 8, 2), s(13, 3, 0, 2),
+    s(13, 1, 0, 2), s(15, 0, 0, 3), s(17, 0, 0, 4),
+    s(0, 0, 13, 2), s(7, 0, 12, 2),
+    s(17, 2, 1, 3),
+    s(18, 2, 9, 3), s(7, 2, 2, 4),
+    s(7, 0, 5, 4), s(0, 0, 13, 2),
+    s(7, 0, 5, 4), s(7, 0, 12, 2),
+    s(5, 2, 9, 1), s(15, 2, 9, 1),
+    s(11, 0, 7, 4), s(3, 0, 7, 2), s(3, 0, 7, 4),
+    s(1, 0, 1, 4), s(2, 0, 1, 2),
+    s(0, 1, 0, 2), s(16, 0, 0, 2),
+    s(13, 3, 0, 4), s(16, 2, 0, 2),
+    s(8, 2, 10, 3), s(8, 2, 10, 2),
+    s(6, 0, 11, 1), s(6, 0, 11, 3),
+    s(15, 0, 1, 2), s(19, 0, 3, 2),
+    s(8, 0, 8, 2), s(8, 0, 8, 3),
+    s(0, 0, 13, 3), s(18, 0, 12, 2),
+    s(0, 1, 4, 2), s(0, 1, 4, 1), s(14, 1, 4, 2),
+    s(5, 0, 1, 1), s(15, 0, 3, 2),
+    s(0, 3, 10, 2), s(0, 1, 12, 2),
+    s(0, 2, 1, 4), s(6, 2, 6, 3), s(15, 2, 2, 2),
+    s(8, 1, 7, 2), s(8, 1, 8, 2),
+    s(0, 0, 13, 2), s(7, 3, 0, 4),
+    s(6, 1, 4, 1), s(15, 0, 3, 2),
+    s(9, 2, 1, 1), s(9, 2, 2, 1),
+    s(2, 1, 10, 4), s(2, 1, 12, 4),
+    s(0, 0, 7, 2), s(0, 1, 8, 2),
+    s(7, 1, 4, 4), s(15, 0, 3, 2), s(17, 0, 3, 4), s(0, 1, 4, 4),
+    s(7, 1, 0, 3), s(12, 1, 9, 4),
+    s(6, 1, 12, 1), s(6, 1, 12, 4),
+    s(0, 1, 0, 4), s(21, 0, 0, 4),
+    s(21, 0, 0, 4), s(6, 1, 0, 4),
+    s(18, 0, 8, 4), s(18, 2, 0, 4),
+    s(8, 0, 3, 1), s(8, 0, 6, 4), s(12, 1, 10, 1),
+    s(9, 0, 3, 1), s(13, 1, 0, 4),
+    s(1, 0, 2, 2), s(5, 1, 0, 2),
+    s(14, 1, 7, 4), s(14, 1, 7, 1),
+    s(5, 2, 0, 4), s(5, 2, 0, 3),
+    s(18, 2, 12, 2), s(16, 0, 10, 1),
+    s(15, 0, 0, 1), s(17, 0, 0, 4),
+    s(11, 0, 9, 2), s(11, 0, 9, 4), s(13, 1, 10, 2),
+    s(4, 0, 6, 2), s(1, 1, 0, 3),
+    s(2, 0, 11, 4), s(2, 0, 11, 1), s(4, 0, 12, 1),
+    s(15, 2, 10, 1), s(16, 2, 10, 2), s(6, 2, 10, 2), s(15, 2, 2, 1),
+    s(7, 0, 1, 4), s(7, 2, 0, 4),
+    s(0, 0, 11, 1), s(11, 0, 11, 2), s(9, 0, 11, 1),
+    s(0, 3, 0, 4), s(15, 0, 8, 4), s(0, 1, 2, 1),
+    s(2, 1, 0, 2), s(1, 1, 0, 4),
+    s(13, 1, 9, 3), s(14, 3, 0, 4),
+    s(14, 1, 0, 1), s(1, 1, 0, 4),
+    s(4, 0, 6, 4), s(2, 0, 6, 4),
+    s(17, 0, 10, 4), s(17, 0, 10, 1), s(16, 0, 10, 1),
+    s(5, 0, 9, 3), s(6, 0, 9, 2), s(6, 0, 9, 3), s(5, 0, 1, 2),
+    s(4, 0, 6, 4), s(1, 1, 0, 4), s(4, 0, 6, 3),
+    s(1, 0, 6, 4), s(1, 0, 6, 1),
+    s(9, 2, 1, 1), s(9, 2, 0, 1), s(11, 2, 0, 4),
+    s(19, 0, 0, 3), s(4, 0, 6, 4),
+    s(19, 0, 0, 4), s(20, 0, 0, 2), s(12, 1, 0, 2),
+    s(4, 2, 0, 3), s(4, 2, 0, 1), s(4, 2, 0, 2), s(15, 0, 8, 3),
+    s(2, 0, 11, 4), s(2, 0, 9, 2), s(2, 0, 9, 4),
+    s(15, 0, 10, 1), s(15, 0, 10, 3), s(15, 2, 10, 1),
+    s(17, 0, 12, 4), s(14, 1, 12, 1), s(13, 1, 12, 4), s(17, 0, 12, 1),
+    s(15, 0, 0, 1), s(16, 0, 0, 1), s(5, 1, 0, 4),
+    s(1, 0, 7, 1), s(2, 0, 7, 2), s(2, 0, 7, 4),
+    s(14, 3, 0, 1), s(14, 3, 0, 3),
+    s(7, 2, 0, 3), s(7, 3, 0, 3),
+    s(10, 2, 1, 4), s(10, 2, 1, 3),
+    s(11, 0, 5, 3), s(9, 0, 5, 1), s(9, 0, 5, 3),
+    s(9, 0, 3, 1), s(9, 0, 3, 2), s(9, 0, 1, 1),
+    s(12, 1, 7, 1), s(14, 1, 7, 2),
+    s(0, 0, 9, 4), s(0, 0, 3, 4),
+    s(7, 0, 12, 2), s(6, 0, 5, 2), s(7, 0, 5, 2), s(7, 0, 5, 4), s(14, 3, 12, 2),
+    s(9, 2, 6, 1), s(10, 2, 6, 4),
+    s(16, 0, 12, 2), s(15, 0, 12, 1), s(15, 0, 12, 4),
+    s(15, 0, 0, 1), s(15, 0, 0, 3),
+    s(14, 1, 4, 2), s(14, 1, 9, 4), s(14, 1, 0, 1),
+    s(3, 0, 5, 4), s(3, 0, 2, 4),
+    s(7, 1, 10, 5),
+    s(19, 0, 11, 4), s(19, 0, 11, 1),
+    s(8, 2, 9, 2), s(12, 1, 0, 1),
+    s(0, 2, 9, 4), s(0, 2, 10, 4),
+    s(1, 0, 2, 2), s(1, 0, 2, 1),
+    s(12, 1, 7, 3), s(12, 3, 4, 2),
+    s(12, 1, 12, 4), s(10, 0, 12, 1),
+    s(20, 2, 2, 3), s(13, 1, 4, 1),
+    s(8, 1, 4, 4), s(8, 2, 9, 2), s(2, 0, 7, 1),
+    s(17, 0, 9, 1), s(16, 0, 9, 1),
+    s(13, 1, 9, 3), s(13, 3, 0, 1), s(12, 1, 4, 2),
+    s(5, 0, 3, 5), s(6, 0, 3, 4), s(6, 0, 3, 5),
+    s(12, 3, 9, 1), s(19, 2, 6, 1),
+    s(20, 2, 0, 4), s(12, 1, 0, 2),
+    s(14, 1, 8, 1), s(0, 1, 8, 3), s(6, 1, 7, 2), s(14, 1, 7, 1),
+    s(14, 1, 10, 4), s(16, 0, 0, 1),
+    s(7, 0, 6, 3), s(6, 2, 6, 3),
+    s(2, 2, 0, 2), s(4, 2, 0, 3),
+    s(6, 2, 2, 1), s(6, 2, 6, 4),
+    s(15, 0, 11, 4), s(16, 0, 11, 2),
+    s(8, 1, 11, 3), s(8, 0, 11, 3),
+    s(17, 2, 6, 2),
+    s(2, 1, 0, 2), s(2, 0, 5, 2), s(1, 1, 0, 4), s(2, 1, 0, 4),
+    s(1, 1, 7, 1), s(1, 1, 7, 3), s(1, 1, 7, 4),
+    s(8, 0, 6, 3), s(9, 2, 2, 4), s(11, 2, 1, 4),
+    s(10, 2, 6, 4), s(13, 3, 9, 1), s(13, 3, 9, 2), s(12, 3, 9, 4),
+    s(16, 2, 6, 2), s(11, 0, 8, 2), s(16, 2, 5, 2),
+    s(8, 0, 1, 4), s(14, 1, 0, 1),
+    s(0, 1, 9, 1), s(0, 0, 1, 1), s(0, 0, 11, 1),
+    s(18, 0, 10, 4), s(5, 1, 9, 4),
+    s(16, 2, 2, 4), s(15, 2, 6, 4),
+    s(13, 1, 11, 1), s(10, 2, 12, 4),
+    s(7, 1, 0, 2), s(18, 2, 9, 3), s(7, 0, 7, 4), s(7, 0, 10, 4), s(0, 0, 13, 2),
+    s(15, 2, 9, 4), s(5, 2, 10, 4), s(6, 2, 0, 2),
+    s(7, 0, 7, 3), s(7, 0, 7, 4),
+    s(17, 2, 0, 4), s(0, 3, 0, 2),
+    s(6, 2, 0, 2), s(5, 2, 10, 4),
+    s(12, 1, 9, 4), s(13, 1, 9, 2),
+    s(1, 0, 11, 3), s(2, 0, 11, 1), s(2, 0, 11, 2), s(1, 0, 11, 4), s(2, 0, 11, 3),
+    s(13, 1, 9, 3), s(14, 1, 9, 4), s(0, 1, 9, 2),
+    s(1, 0, 2, 2), s(2, 0, 2, 4), s(8, 1, 4, 4),
+    s(9, 0, 7, 1), s(9, 0, 7, 4),
+    s(8, 2, 0, 4), s(1, 1, 7, 1),
+    s(1, 1, 7, 1), s(2, 1, 7, 3),
+    s(4, 2, 0, 1), s(8, 2, 0, 2),
+    s(6, 0, 11, 2), s(6, 0, 11, 1),
+    s(15, 2, 9, 1), s(15, 2, 9, 3), s(16, 2, 9, 3), s(16, 2, 10, 2),
+    s(12, 1, 7, 1), s(12, 1, 7, 3), s(11, 0, 7, 2), s(7, 0, 7, 3),
+    s(8, 1, 10, 4), s(8, 1, 9, 3),
+    s(2, 0, 12, 2), s(2, 0, 12, 4),
+    s(16, 2, 5, 4), s(15, 0, 1, 4), s(15, 0, 5, 4),
+    s(8, 1, 7, 2), s(8, 1, 7, 3),
+    s(9, 2, 6, 1), s(10, 2, 6, 4), s(5, 2, 6, 4),
+    s(6, 0, 12, 1), s(6, 2, 10, 2),
+    s(4, 0, 9, 2), s(2, 0, 9, 2),
+    s(11, 2, 0, 1), s(0, 2, 0, 3), s(3, 0, 6, 2),
+    s(21, 2, 6, 3), s(0, 2, 6, 3),
+    s(17, 0, 9, 1), s(5, 0, 9, 4),
+    s(1, 1, 0, 4), s(1, 0, 6, 5),
+    s(12, 3, 4, 2), s(12, 3, 0, 1),
+    s(8, 0, 1, 4), s(9, 0, 3, 2),
+    s(7, 0, 7, 4), s(18, 2, 0, 2), s(0, 0, 13, 2), s(7, 0, 10, 4), s(7, 2, 9, 3),
+    s(8, 2, 0, 2), s(8, 3, 0, 3),
+    s(7, 1, 0, 2), s(8, 2, 9, 2),
+    s(19, 0, 1, 1), s(19, 0, 9, 1),
+    s(13, 1, 9, 1), s(14, 1, 9, 2), s(13, 1, 10, 4),
+    s(9, 2, 11, 4), s(12, 3, 12, 3),
+    s(19, 0, 11, 1), s(20, 0, 11, 2), s(19, 0, 11, 4),
+    s(9, 2, 11, 3), s(12, 3, 12, 3),
+    s(16, 0, 8, 4), s(14, 1, 8, 4),
+    s(3, 1, 9, 2), s(1, 1, 9, 1),
+    s(15, 0, 0, 4), s(15, 2, 6, 4),
+    s(5, 1, 4, 2), s(15, 0, 0, 2),
+    s(15, 0, 0, 1), s(12, 1, 10, 4),
+    s(0, 3, 0, 2), s(0, 3, 0, 3), s(0, 3, 12, 3), s(10, 2, 6, 4),
+    s(16, 2, 12, 1), s(16, 2, 11, 1), s(15, 2, 12, 1),
+    s(14, 1, 0, 4), s(13, 3, 4, 4), s(6, 2, 2, 1),
+    s(0, 3, 0, 3), s(0, 3, 0, 2), s(0, 3, 0, 4),
+    s(14, 1, 12, 4), s(14, 1, 12, 1), s(14, 1, 10, 4),
+    s(17, 0, 3, 2), s(9, 2, 1, 1),
+    s(17, 0, 3, 3), s(17, 0, 3, 4), s(17, 0, 0, 4),
+    s(17, 2, 0, 1), s(0, 3, 0, 4),
+    s(6, 1, 9, 3), s(6, 0, 9, 1),
+    s(2, 2, 0, 4),
+    s(11, 2, 1, 4), s(13, 1, 0, 4),
+    s(16, 2, 9, 2), s(14, 1, 11, 1),
+    s(16, 2, 9, 2), s(4, 0, 9, 2),
+    s(1, 0, 9, 1), s(2, 0, 9, 2), s(1, 0, 9, 3), s(1, 0, 2, 1),
+    s(15, 2, 0, 2), s(15, 0, 8, 3),
+    s(13, 3, 12, 2), s(11, 2, 12, 2),
+    s(2, 0, 11, 2), s(4, 0, 12, 2),
+    s(7, 1, 9, 4), s(13, 1, 9, 4),
+    s(10, 0, 3, 4), s(12, 1, 4, 4), s(19, 2, 12, 1),
+    s(0, 1, 7, 4), s(6, 1, 7, 4),
+    s(16, 2, 12, 1), s(15, 2, 11, 4), s(6, 2, 12, 2),
+    s(11, 2, 2, 4), s(0, 2, 2, 4),
+    s(9, 0, 10, 3), s(9, 0, 10, 4), s(11, 0, 10, 2),
+    s(8, 1, 11, 2), s(8, 1, 11, 3),
+    s(21, 0, 3, 4), s(17, 0, 5, 3),
+    s(4, 2, 0, 2), s(1, 0, 2, 2), s(2, 0, 6, 4),
+    s(8, 0, 3, 4), s(12, 1, 0, 2),
+    s(6, 1, 12, 1), s(5, 1, 12, 3),
+    s(12, 1, 7, 1), s(13, 1, 8, 2),
+    s(0, 0, 5, 4), s(0, 1, 0, 4),
+    s(7, 0, 5, 3), s(18, 0, 12, 2), s(18, 0, 12, 4),
+    s(16, 0, 5, 1), s(16, 0, 1, 1),
+    s(0, 3, 0, 4), s(0, 3, 0, 2), s(14, 3, 0, 1), s(0, 3, 0, 3),
+    s(17, 0, 7, 2), s(14, 1, 7, 4), s(13, 3, 4, 4), s(5, 1, 0, 4),
+    s(13, 3, 12, 1), s(14, 3, 12, 1),
+    s(11, 2, 0, 4), s(14, 1, 1, 4),
+    s(3, 0, 11, 2), s(11, 2, 11, 1), s(11, 2, 11, 3), s(0, 2, 11, 2),
+    s(19, 0, 0, 4), s(19, 0, 0, 3), s(19, 0, 0, 1),
+    s(11, 2, 6, 4), s(11, 2, 0, 1),
+    s(2, 1, 0, 2), s(1, 1, 0, 3), s(1, 1, 0, 4),
+    s(6, 2, 10, 2), s(16, 2, 10, 1),
+    s(17, 0, 9, 1), s(0, 2, 6, 3),
+    s(4, 2, 0, 2), s(4, 0, 8, 3), s(4, 2, 0, 1),
+    s(12, 1, 4, 4), s(9, 0, 5, 4),
+    s(8, 2, 0, 2), s(8, 2, 0, 3), s(11, 2, 0, 4),
+    s(14, 3, 0, 4), s(15, 2, 0, 4),
+    s(13, 1, 10, 2), s(0, 1, 10, 2),
+    s(13, 1, 0, 2), s(16, 0, 0, 2),
+    s(0, 1, 9, 2), s(0, 3, 9, 2),
+    s(1, 0, 1, 1), s(2, 0, 1, 1),
+    s(18, 2, 6, 4), s(18, 2, 2, 4),
+    s(14, 1, 10, 1), s(14, 1, 10, 4),
+    s(0, 2, 0, 4), s(11, 2, 0, 1),
+    s(0, 3, 10, 2), s(0, 3, 10, 4),
+    s(18, 0, 12, 4),
+    s(13, 1, 4, 1),
+    s(0, 3, 9, 4), s(0, 3, 9, 1), s(0, 3, 0, 4), s(0, 3, 10, 4),
+    s(8, 1, 12, 2), s(8, 1, 9, 2),
+    s(6, 0, 5, 2), s(6, 0, 5, 1),
+    s(17, 0, 7, 2), s(6, 1, 7, 2),
+    s(8, 1, 0, 4), s(12, 1, 0, 1),
+    s(10, 0, 3, 1), s(11, 0, 3, 1),
+    s(1, 0, 7, 1), s(2, 0, 7, 2), s(1, 1, 7, 1),
+    s(9, 0, 8, 3), s(9, 0, 8, 1),
+    s(12, 3, 0, 4), s(13, 3, 0, 3),
+    s(2, 1, 4, 3), s(2, 1, 0, 1),
+    s(18, 2, 2, 4), s(18, 0, 3, 2), s(18, 0, 3, 4), s(18, 0, 3, 3),
+    s(10, 2, 0, 3), s(9, 2, 0, 3), s(11, 2, 0, 4),
+    s(7, 1, 12, 2), s(15, 2, 0, 4),
+    s(1, 0, 2, 2), s(2, 0, 1, 1),
+    s(17, 0, 9, 1), s(17, 0, 9, 4), s(6, 1, 9, 1), s(16, 0, 9, 1),
+    s(14, 1, 8, 2),
+    s(0, 1, 12, 1), s(0, 1, 11, 1),
+    s(12, 3, 0, 1), s(16, 0, 1, 2), s(15, 0, 1, 3), s(19, 2, 0, 1), s(12, 1, 4, 1), s(1, 0, 7, 1), s(14, 1, 4, 2),
+    s(7, 1, 4, 2), s(7, 1, 4, 4),
+    s(17, 0, 12, 1), s(18, 2, 6, 2),
+    s(9, 2, 0, 1), s(9, 2, 1, 1),
+    s(1, 1, 0, 4), s(1, 1, 4, 2), s(3, 1, 0, 4),
+    s(4, 2, 0, 2), s(1, 0, 2, 2), s(4, 0, 6, 4), s(1, 0, 6, 4), s(1, 1, 0, 4),
+    s(12, 1, 1, 1), s(13, 1, 4, 2),
+    s(3, 0, 7, 2), s(3, 0, 7, 3),
+    s(1, 0, 1, 2), s(2, 0, 6, 4), s(4, 0, 6, 4),
+    s(20, 0, 0, 2), s(19, 0, 0, 3), s(20, 0, 0, 3), s(16, 0, 5, 2),
+    s(15, 0, 0, 3), s(5, 1, 0, 3),
+    s(9, 0, 3, 2), s(8, 2, 2, 4),
+    s(9, 0, 10, 4), s(12, 1, 9, 4),
+    s(13, 1, 9, 4), s(14, 1, 0, 1),
+    s(16, 0, 5, 3), s(15, 0, 0, 3),
+    s(0, 3, 0, 4), s(0, 2, 6, 3),
+    s(11, 0, 7, 1), s(14, 1, 8, 1), s(10, 0, 8, 4),
+    s(12, 1, 0, 4), s(5, 2, 2, 1),
+    s(12, 1, 9, 3), s(16, 2, 12, 2),
+    s(3, 0, 11, 2), s(11, 2, 11, 3),
+    s(16, 0, 1, 2), s(16, 0, 0, 2),
+    s(12, 1, 7, 1), s(14, 1, 7, 4), s(13, 1, 7, 4),
+    s(12, 1, 7, 1), s(7, 1, 7, 3),
+    s(4, 0, 1, 2), s(2, 0, 6, 4), s(1, 0, 2, 2), s(1, 0, 1, 2),
+    s(18, 2, 12, 1), s(18, 2, 12, 2), s(18, 2, 12, 3),
+    s(3, 0, 11, 3), s(3, 2, 0, 3),
+    s(13, 3, 9, 2), s(16, 2, 2, 4),
+    s(5, 0, 1, 1), s(5, 0, 1, 2), s(6, 0, 1, 4),
+    s(20, 0, 7, 3), s(19, 0, 7, 4),
+    s(6, 1, 0, 2), s(0, 1, 0, 2),
+    s(11, 2, 11, 1), s(11, 2, 11, 3), s(10, 0, 11, 1), s(11, 2, 11, 2),
+    s(8, 0, 7, 3), s(16, 0, 1, 1),
+    s(11, 2, 10, 1), s(14, 3, 10, 1),
+    s(14, 1, 12, 2), s(0, 1, 12, 2),
+    s(11, 0, 3, 2), s(11, 0, 3, 4), s(11, 0, 3, 1),
+    s(1, 2, 0, 4), s(2, 2, 0, 2),
+    s(0, 3, 10, 3), s(0, 3, 10, 2),
+    s(6, 2, 0, 2), s(16, 0, 1, 2), s(0, 1, 4, 2), s(17, 2, 0, 1),
+    s(21, 2, 6, 1), s(0, 2, 6, 3),
+    s(16, 0, 10, 2), s(7, 2, 12, 2),
+    s(8, 1, 0, 4), s(8, 1, 0, 2), s(16, 0, 0, 2),
+    s(19, 2, 2, 2), s(12, 1, 0, 2),
+    s(17, 0, 1, 1), s(21, 2, 2, 1), s(21, 2, 6, 1),
+    s(2, 0, 12, 2), s(4, 0, 12, 1),
+    s(3, 0, 12, 2), s(14, 1, 0, 2), s(13, 1, 12, 3),
+    s(12, 1, 12, 1), s(0, 1, 12, 1),
+    s(17, 0, 10, 1), s(14, 1, 10, 1),
+    s(0, 1, 8, 2), s(5, 1, 7, 4), s(5, 1, 0, 2),
+    s(9, 2, 9, 3), s(0, 2, 9, 3), s(9, 2, 9, 1),
+    s(0, 1, 8, 3), s(14, 1, 8, 4),
+    s(21, 2, 0, 4), s(0, 1, 8, 2),
+    s(7, 1, 8, 3), s(18, 0, 8, 4),
+    s(17, 0, 7, 1), s(14, 1, 7, 1),
+    s(14, 1, 9, 4), s(0, 2, 9, 4),
+    s(8, 0, 11, 4), s(8, 1, 11, 2), s(8, 0, 11, 2),
+    s(4, 2, 0, 2), s(2, 1, 7, 3),
+    s(3, 0, 2, 4), s(3, 2, 0, 4),
+    s(0, 2, 10, 4), s(0, 2, 9, 3), s(3, 1, 9, 3),
+    s(17, 0, 0, 2), s(17, 0, 0, 4),
+    s(0, 2, 9, 3), s(0, 3, 0, 4), s(0, 3, 10, 4),
+    s(12, 1, 9, 1), s(9, 2, 9, 1),
+    s(19, 0, 8, 1), s(20, 2, 9, 2), s(16, 2, 0, 4), s(20, 2, 12, 2),
+    s(8, 2, 0, 4), s(8, 3, 0, 4),
+    s(12, 3, 10, 1), s(12, 3, 10, 4),
+    s(7, 1, 4, 4), s(18, 0, 10, 3),
+    s(11, 0, 3, 2), s(9, 0, 3, 1),
+    s(19, 0, 5, 1), s(19, 0, 0, 1), s(19, 0, 0, 4),
+    s(6, 2, 0, 2), s(6, 2, 0, 4),
+    s(12, 1, 4, 1), s(17, 0, 1, 4),
+    s(2, 2, 0, 2), s(1, 0, 6, 4), s(1, 0, 2, 2),
+    s(13, 1, 7, 2), s(15, 0, 7, 3),
+    s(6, 0, 5, 2), s(15, 0, 0, 1), s(16, 0, 0, 2),
+    s(11, 2, 1, 2), s(11, 2, 1, 1), s(11, 2, 1, 4), s(10, 2, 1, 1),
+    s(4, 0, 6, 1), s(4, 0, 6, 3), s(4, 0, 6, 4),
+    s(13, 1, 10, 2), s(13, 1, 10, 1), s(12, 1, 10, 1),
+    s(0, 0, 9, 1), s(0, 1, 9, 3),
+    s(0, 1, 9, 1), s(0, 3, 0, 1), s(0, 3, 0, 4),
+    s(12, 3, 0, 1), s(19, 2, 0, 1), s(12, 3, 0, 4),
+    s(17, 2, 0, 1), s(12, 1, 7, 1),
+    s(6, 1, 9, 2), s(6, 1, 9, 4),
+    s(1, 1, 0, 4), s(2, 1, 0, 4), s(1, 0, 6, 1), s(1, 0, 1, 2),
+    s(4, 0, 6, 4), s(4, 0, 6, 2), s(4, 2, 0, 2),
+    s(3, 0, 12, 2), s(3, 1, 12, 2),
+    s(0, 2, 6, 1), s(0, 2, 6, 3), s(0, 2, 6, 4),
+    s(11, 2, 9, 2), s(15, 2, 6, 1),
+    s(0, 1, 9, 3), s(12, 3, 9, 4),
+    s(6, 1, 4, 1),
+    s(1, 0, 2, 5),
+    s(13, 1, 8, 1), s(12, 1, 7, 1),
+    s(0, 3, 0, 3), s(12, 3, 0, 3),
+    s(0, 3, 0, 2), s(0, 3, 0, 3), s(0, 3, 0, 4),
+    s(4, 2, 0, 4), s(1, 0, 6, 4),
+    s(7, 0, 9, 3), s(7, 0, 9, 2),
+    s(14, 1, 7, 1), s(17, 0, 7, 1), s(17, 2, 2, 4),
+    s(1, 1, 9, 3), s(1, 1, 9, 1), s(2, 1, 9, 2),
+    s(3, 0, 7, 4), s(3, 2, 0, 4),
+    s(8, 2, 2, 4), s(8, 0, 1, 4), s(8, 0, 7, 4), s(8, 2, 2, 1),
+    s(10, 2, 2, 4), s(11, 2, 2, 2),
+    s(19, 2, 2, 4), s(19, 0, 3, 2),
+    s(19, 2, 0, 1),
+    s(1, 0, 7, 3), s(1, 0, 7, 1),
+    s(0, 1, 4, 4), s(17, 0, 3, 4),
+    s(2, 0, 10, 2), s(4, 0, 10, 2),
+    s(15, 2, 0, 4), s(15, 0, 3, 5), s(15, 2, 2, 2), s(16, 2, 0, 2), s(15, 0, 7, 1), s(15, 0, 7, 2),
+    s(18, 0, 10, 4), s(17, 0, 10, 4),
+    s(9, 0, 3, 2), s(9, 0, 3, 3),
+    s(17, 0, 10, 1), s(17, 0, 9, 1),
+    s(2, 2, 0, 2), s(1, 0, 6, 4),
+    s(5, 2, 12, 3), s(15, 2, 12, 3),
+    s(21, 2, 6, 1), s(12, 3, 10, 4), s(21, 2, 2, 3),
+    s(15, 0, 10, 1), s(13, 1, 9, 2),
+    s(6, 1, 12, 2), s(5, 1, 12, 3),
+    s(0, 2, 6, 3), s(11, 2, 1, 1), s(10, 2, 6, 1), s(0, 0, 3, 2),
+    s(0, 1, 8, 5),
+    s(21, 0, 8, 1), s(11, 2, 6, 4),
+    s(18, 2, 0, 2), s(7, 0, 1, 2),
+    s(3, 0, 12, 2), s(3, 0, 12, 1), s(3, 0, 12, 3),
+    s(13, 3, 4, 1),
+    s(0, 3, 9, 2), s(11, 2, 9, 2),
+    s(1, 0, 11, 4), s(2, 0, 11, 2),
+    s(14, 3, 0, 2), s(17, 2, 0, 2),
+    s(19, 2, 0, 1), s(12, 3, 0, 4), s(12, 1, 0, 2),
+    s(10, 2, 5, 3), s(10, 2, 5, 4),
+    s(2, 2, 0, 2), s(1, 0, 2, 2),
+    s(20, 0, 11, 1), s(20, 0, 11, 3),
+    s(11, 0, 7, 1), s(9, 0, 7, 3),
+    s(15, 0, 10, 1), s(13, 1, 10, 2),
+    s(3, 1, 12, 2), s(3, 1, 0, 4),
+    s(21, 2, 2, 1), s(21, 2, 6, 1),
+    s(0, 1, 7, 3), s(15, 2, 2, 2),
+    s(8, 0, 9, 2), s(8, 0, 1, 5),
+    s(5, 1, 7, 4), s(6, 1, 7, 2), s(5, 1, 0, 2),
+    s(6, 1, 7, 2), s(14, 1, 8, 1),
+    s(16, 2, 0, 4), s(15, 2, 0, 2),
+    s(20, 2, 12, 1), s(19, 2, 12, 3), s(21, 2, 12, 3),
+    s(13, 1, 8, 1), s(0, 0, 8, 1), s(14, 3, 0, 1), s(4, 2, 0, 1),
+    s(16, 2, 10, 2), s(6, 2, 9, 2),
+    s(19, 0, 0, 2), s(12, 3, 0, 2),
+    s(8, 1, 7, 3), s(8, 2, 0, 4), s(8, 0, 7, 3), s(8, 1, 8, 3),
+    s(14, 3, 0, 5), s(21, 2, 0, 4),
+    s(13, 1, 11, 2), s(12, 1, 11, 4),
+    s(1, 1, 7, 1), s(2, 1, 7, 4), s(2, 1, 7, 3), s(1, 1, 7, 4),
+    s(8, 2, 0, 4), s(20, 2, 0, 1),
+    s(3, 0, 9, 4), s(3, 0, 9, 2), s(0, 2, 9, 4),
+    s(1, 0, 2, 2), s(1, 0, 2, 5),
+    s(5, 1, 0, 4), s(5, 0, 5, 4), s(16, 0, 5, 4),
+    s(20, 2, 2, 2), s(20, 2, 0, 3), s(15, 0, 1, 1),
+    s(17, 0, 10, 1), s(21, 0, 9, 1), s(21, 0, 9, 3),
+    s(8, 0, 8, 2), s(8, 3, 0, 3), s(12, 3, 0, 4), s(8, 1, 8, 3),
+    s(20, 2, 0, 4), s(20, 0, 8, 4), s(16, 2, 2, 4),
+    s(20, 0, 5, 4), s(21, 0, 1, 4), s(20, 0, 1, 1),
+    s(2, 0, 2, 2), s(1, 0, 2, 4),
+    s(12, 3, 9, 4), s(12, 3, 10, 4),
+    s(12, 1, 9, 4), s(12, 1, 9, 1), s(17, 0, 9, 1),
+    s(7, 1, 9, 1), s(0, 1, 9, 1), s(0, 1, 9, 4),
+    s(17, 2, 0, 1), s(17, 2, 0, 3),
+    s(1, 1, 0, 4), s(1, 1, 4, 1), s(2, 1, 4, 1),
+    s(13, 1, 9, 2), s(6, 0, 9, 2), s(14, 3, 10, 2),
+    s(4, 0, 9, 1), s(1, 0, 2, 1), s(4, 0, 9, 2), s(2, 1, 0, 2),
+    s(14, 3, 10, 4), s(6, 0, 9, 2),
+    s(12, 1, 7, 1), s(13, 1, 7, 2), s(13, 1, 7, 1),
+    s(18, 2, 6, 3), s(12, 3, 9, 3),
+    s(18, 0, 7, 2), s(0, 1, 7, 2),
+    s(12, 3, 4, 2), s(19, 2, 6, 4),
+    s(19, 2, 6, 4), s(12, 3, 4, 2), s(15, 2, 2, 2),
+    s(4, 0, 1, 4), s(4, 0, 6, 4),
+    s(4, 0, 10, 2), s(4, 0, 6, 4),
+    s(5, 0, 11, 4), s(6, 0, 11, 1), s(6, 0, 11, 4),
+    s(0, 2, 0, 2), s(0, 2, 0, 3),
+    s(0, 2, 12, 4), s(0, 3, 12, 1),
+    s(12, 1, 0, 2), s(13, 1, 4, 4),
+    s(11, 2, 12, 2), s(11, 2, 12, 4),
+    s(14, 1, 0, 2), s(14, 1, 7, 4),
+    s(1, 0, 7, 2), s(1, 0, 2, 2), s(1, 0, 2, 4), s(1, 2, 0, 4),
+    s(20, 0, 0, 2), s(19, 0, 0, 1),
+    s(14, 1, 11, 1), s(14, 1, 11, 3),
+    s(0, 2, 9, 4), s(8, 2, 9, 4),
+    s(1, 1, 0, 4), s(1, 0, 2, 4), s(1, 0, 2, 2), s(1, 0, 5, 4), s(2, 1, 0, 4),
+    s(14, 1, 9, 1), s(8, 1, 9, 3), s(0, 1, 9, 2), s(10, 0, 9, 4),
+    s(17, 2, 0, 3), s(15, 2, 0, 2),
+    s(11, 0, 7, 1), s(10, 0, 7, 3),
+    s(0, 2, 6, 3), s(0, 3, 9, 3),
+    s(16, 0, 8, 2), s(15, 0, 8, 4), s(5, 0, 7, 4),
+    s(3, 0, 5, 2), s(0, 2, 2, 1),
+    s(12, 1, 0, 4), s(20, 0, 0, 2), s(13, 1, 0, 4), s(13, 1, 0, 2),
+    s(20, 2, 12, 2), s(20, 2, 12, 4),
+    s(14, 1, 7, 1), s(11, 0, 7, 4), s(11, 0, 3, 4),
+    s(14, 3, 0, 4), s(0, 3, 0, 3), s(0, 3, 0, 2), s(0, 3, 0, 4), s(14, 3, 0, 1),
+    s(5, 1, 7, 4), s(5, 1, 0, 2), s(15, 2, 2, 2),
+    s(20, 0, 11, 2), s(19, 0, 11, 4), s(19, 0, 11, 1),
+    s(3, 1, 7, 3), s(3, 0, 2, 4),
+    s(14, 1, 9, 2), s(13, 1, 9, 1),
+    s(8, 0, 1, 3),
+    s(0, 1, 7, 4), s(17, 2, 2, 4), s(8, 3, 4, 4),
+    s(3, 0, 2, 4), s(3, 0, 2, 2),
+    s(21, 0, 8, 3), s(17, 2, 0, 3), s(20, 0, 8, 4),
+    s(17, 2, 0, 3), s(15, 2, 0, 1),
+    s(16, 2, 0, 2), s(15, 2, 0, 1), s(15, 0, 1, 1),
+    s(11, 2, 2, 4), s(11, 0, 3, 2),
+    s(6, 2, 2, 4), s(19, 0, 3, 2),
+    s(0, 2, 0, 4), s(0, 0, 3, 4),
+    s(13, 1, 0, 2), s(12, 1, 0, 1), s(13, 1, 10, 2),
+    s(8, 2, 0, 2), s(8, 2, 0, 3),
+    s(2, 1, 12, 2), s(2, 1, 10, 2),
+    s(7, 1, 4, 4), s(1, 0, 2, 4),
+    s(18, 0, 11, 2), s(14, 1, 11, 1), s(7, 0, 11, 1),
+    s(8, 1, 9, 3), s(14, 1, 9, 1),
+    s(8, 2, 12, 2), s(8, 2, 12, 3), s(8, 2, 12, 4),
+    s(11, 0, 9, 4), s(8, 0, 9, 4),
+    s(10, 2, 6, 1), s(11, 2, 6, 3), s(9, 2, 6, 1),
+    s(4, 0, 12, 1), s(21, 2, 12, 1),
+    s(11, 2, 0, 3), s(11, 2, 0, 4),
+    s(8, 3, 0, 4), s(1, 1, 0, 4),
+    s(21, 0, 0, 1), s(14, 1, 0, 1), s(6, 1, 0, 2), s(15, 0, 0, 4),
+    s(16, 2, 0, 4), s(16, 2, 0, 3), s(12, 3, 0, 4),
+    s(11, 2, 0, 1), s(11, 2, 0, 2), s(11, 2, 0, 4),
+    s(11, 0, 7, 4), s(11, 0, 7, 2),
+    s(12, 1, 7, 1), s(11, 0, 7, 2),
+    s(14, 1, 0, 4), s(21, 0, 3, 4),
+    s(16, 2, 12, 2), s(11, 2, 6, 3),
+    s(5, 1, 7, 1), s(5, 0, 7, 1),
+    s(5, 1, 12, 1), s(16, 0, 12, 1),
+    s(17, 0, 3, 2), s(0, 1, 4, 3),
+    s(11, 0, 9, 2), s(9, 0, 9, 1),
+    s(11, 2, 12, 2), s(12, 1, 11, 4), s(11, 2, 12, 4), s(9, 2, 12, 4),
+    s(11, 2, 6, 1), s(11, 2, 6, 3),
+    s(14, 1, 1, 1), s(11, 0, 1, 2),
+    s(3, 0, 1, 3), s(3, 0, 1, 4), s(3, 0, 1, 1),
+    s(4, 0, 11, 1), s(1, 0, 11, 4),
+    s(1, 0, 11, 4), s(1, 0, 12, 4), s(2, 1, 0, 2), s(4, 0, 12, 1),
+    s(20, 0, 9, 2), s(6, 1, 9, 3),
+    s(12, 3, 4, 2), s(13, 3, 4, 1),
+    s(11, 2, 6, 2), s(11, 2, 6, 4), s(0, 1, 8, 2),
+    s(13, 1, 10, 2), s(13, 1, 9, 2),
+    s(11, 0, 7, 2), s(20, 0, 0, 4),
+    s(9, 2, 12, 1), s(15, 2, 12, 1),
+    s(19, 0, 7, 3), s(15, 0, 7, 3),
+    s(1, 0, 7, 4), s(2, 0, 7, 2),
+    s(0, 1, 8, 2), s(15, 2, 0, 2),
+    s(16, 0, 0, 2), s(16, 0, 0, 1), s(5, 1, 0, 4),
+    s(0, 1, 8, 4), s(0, 1, 8, 3), s(7, 1, 8, 4),
+    s(11, 0, 3, 2), s(10, 0, 3, 4),
+    s(11, 0, 9, 1), s(11, 0, 9, 2),
+    s(18, 0, 9, 2), s(6, 1, 9, 4),
+    s(9, 0, 8, 3), s(13, 3, 0, 2), s(14, 3, 0, 4),
+    s(2, 1, 0, 2), s(1, 0, 2, 3),
+    s(13, 3, 0, 1), s(12, 3, 0, 1),
+    s(17, 0, 3, 2), s(0, 1, 0, 2), s(6, 2, 2, 2), s(16, 0, 0, 2),
+    s(11, 2, 6, 2), s(11, 2, 6, 3),
+    s(3, 0, 11, 2), s(1, 0, 11, 4),
+    s(0, 1, 11, 2), s(0, 1, 11, 3),
+    s(0, 2, 1, 1), s(12, 3, 4, 2),
+    s(10, 2, 2, 4), s(17, 0, 3, 2),
+    s(0, 1, 0, 4), s(14, 3, 0, 3),
+    s(13, 1, 0, 1), s(12, 1, 4, 2), s(13, 1, 4, 4),
+    s(11, 0, 1, 2), s(9, 0, 3, 2), s(11, 0, 1, 1), s(0, 0, 3, 2),
+    s(13, 3, 12, 2), s(9, 2, 12, 3),
+    s(13, 1, 4, 4), s(7, 1, 0, 2),
+    s(0, 0, 3, 2), s(0, 1, 0, 3),
+    s(4, 2, 0, 3), s(2, 2, 0, 2),
+    s(15, 0, 3, 1), s(15, 0, 3, 2),
+    s(8, 0, 11, 2), s(8, 1, 11, 2),
+    s(16, 2, 0, 2), s(0, 3, 0, 2),
+    s(0, 3, 9, 1), s(14, 3, 9, 1),
+    s(0, 1, 9, 2), s(0, 1, 9, 4), s(5, 0, 9, 4),
+    s(6, 1, 12, 2), s(5, 1, 9, 4),
+    s(6, 2, 6, 4), s(0, 3, 4, 4),
+    s(4, 0, 6, 1), s(4, 0, 6, 3), s(2, 0, 6, 4), s(1, 0, 6, 4),
+    s(8, 0, 1, 4), s(13, 3, 0, 4), s(15, 0, 1, 4), s(12, 1, 0, 2),
+    s(8, 2, 10, 2), s(8, 2, 10, 3),
+    s(6, 1, 7, 2), s(5, 1, 7, 4),
+    s(0, 3, 0, 4), s(9, 2, 2, 1),
+    s(4, 0, 6, 2), s(4, 0, 6, 4),
+    s(2, 1, 0, 2), s(3, 1, 7, 2),
+    s(5, 2, 10, 1), s(6, 2, 10, 1),
+    s(0, 1, 0, 4), s(14, 1, 0, 2),
+    s(0, 3, 9, 1), s(0, 3, 10, 1),
+    s(18, 2, 6, 4), s(0, 2, 6, 4),
+    s(13, 1, 12, 1), s(12, 1, 12, 1),
+    s(0, 2, 6, 4), s(0, 2, 6, 3), s(6, 2, 12, 2),
+    s(0, 2, 9, 1), s(0, 2, 9, 3),
+    s(5, 1, 0, 4), s(15, 2, 2, 1),
+    s(14, 1, 4, 1), s(11, 0, 3, 2),
+    s(14, 3, 0, 1), s(14, 1, 4, 4),
+    s(17, 0, 0, 2), s(8, 1, 0, 4), s(8, 2, 12, 2),
+    s(1, 1, 9, 1), s(2, 1, 9, 2),
+    s(18, 0, 8, 2), s(7, 0, 7, 2),
+    s(0, 3, 9, 2), s(0, 3, 9, 1),
+    s(13, 1, 8, 2), s(0, 1, 8, 2), s(12, 1, 8, 1),
+    s(3, 0, 7, 2), s(0, 2, 0, 2), s(0, 2, 0, 4),
+    s(14, 1, 1, 1), s(11, 0, 1, 2), s(12, 1, 1, 3),
+    s(16, 2, 12, 2), s(15, 2, 12, 1),
+    s(8, 1, 0, 4), s(14, 1, 0, 2),
+    s(0, 2, 2, 1), s(8, 2, 2, 2), s(9, 2, 2, 3),
+    s(0, 3, 10, 1), s(0, 0, 7, 3),
+    s(13, 3, 0, 3), s(0, 3, 0, 3),
+    s(1, 0, 9, 5),
+    s(2, 0, 11, 2), s(1, 0, 11, 3),
+    s(14, 1, 0, 1), s(20, 0, 0, 4),
+    s(1, 0, 9, 1), s(2, 0, 9, 2), s(11, 2, 9, 4),
+    s(0, 1, 0, 2), s(21, 0, 0, 1),
+    s(1, 1, 0, 1), s(2, 1, 0, 1),
+    s(7, 0, 5, 4), s(7, 0, 5, 2), s(7, 0, 12, 3),
+    s(11, 0, 3, 2), s(14, 1, 1, 2),
+    s(9, 2, 6, 1), s(11, 2, 6, 3),
+    s(3, 0, 1, 3), s(3, 0, 1, 1), s(3, 0, 1, 4),
+    s(6, 0, 3, 4), s(6, 0, 12, 2),
+    s(6, 1, 7, 5),
+    s(17, 0, 0, 4), s(15, 0, 3, 1),
+    s(5, 1, 0, 4), s(5, 0, 5, 4),
+    s(12, 1, 9, 4), s(16, 0, 9, 2),
+    s(0, 1, 10, 3), s(0, 1, 10, 2),
+    s(3, 0, 1, 2), s(3, 0, 2, 4),
+    s(9, 2, 2, 1), s(0, 3, 0, 4),
+    s(8, 1, 8, 2), s(8, 1, 7, 4),
+    s(3, 0, 7, 2), s(3, 0, 12, 2),
+    s(16, 0, 11, 5),
+    s(3, 0, 11, 3), s(3, 0, 12, 3),
+    s(12, 1, 7, 3), s(13, 1, 7, 2),
+    s(1, 1, 4, 1), s(1, 1, 4, 2),
+    s(2, 0, 9, 2), s(4, 0, 9, 2),
+    s(12, 1, 0, 3), s(13, 1, 0, 2),
+    s(4, 0, 6, 2), s(1, 0, 10, 1),
+    s(8, 0, 7, 2), s(8, 1, 7, 2),
+    s(0, 1, 10, 2), s(14, 3, 10, 2),
+    s(16, 0, 9, 2), s(6, 1, 0, 2), s(17, 0, 9, 4),
+    s(7, 0, 7, 2), s(18, 0, 7, 4),
+    s(16, 2, 12, 2), s(15, 2, 12, 4), s(6, 2, 12, 2),
+    s(17, 0, 9, 4), s(5, 0, 9, 4), s(16, 0, 9, 2), s(6, 2, 2, 2),
+    s(0, 1, 0, 3), s(12, 1, 0, 3),
+    s(13, 1, 0, 4), s(12, 1, 0, 4),
+    s(20, 0, 0, 1), s(12, 1, 0, 2),
+    s(8, 2, 2, 3), s(8, 2, 2, 2), s(9, 2, 2, 3),
+    s(11, 2, 2, 4), s(0, 3, 4, 4),
+    s(3, 1, 10, 3), s(3, 1, 9, 2),
+    s(8, 1, 0, 2), s(8, 1, 0, 3), s(8, 2, 2, 3), s(8, 2, 2, 2), s(8, 1, 0, 4),
+    s(4, 0, 12, 1), s(2, 0, 11, 2),
+    s(7, 0, 11, 2), s(17, 0, 11, 4), s(18, 0, 11, 3),
+    s(9, 2, 0, 3), s(0, 1, 4, 3),
+    s(13, 3, 9, 2), s(11, 2, 9, 4),
+    s(13, 3, 0, 2), s(12, 3, 4, 2),
+    s(15, 2, 0, 2), s(17, 2, 0, 2),
+    s(14, 3, 4, 4), s(14, 1, 4, 3),
+    s(2, 0, 6, 1), s(4, 0, 8, 3),
+    s(15, 2, 12, 4), s(15, 2, 12, 1),
+    s(14, 1, 12, 2), s(11, 0, 11, 2), s(11, 0, 12, 2), s(14, 1, 12, 4), s(11, 0, 11, 4),
+    s(10, 0, 9, 4), s(10, 0, 9, 3),
+    s(6, 2, 12, 4), s(6, 2, 12, 2), s(5, 2, 12, 4),
+    s(14, 1, 11, 4), s(8, 2, 12, 4),
+    s(14, 1, 9, 2), s(0, 3, 0, 4),
+    s(0, 1, 1, 2), s(0, 3, 0, 2), s(0, 3, 0, 4),
+    s(16, 2, 12, 1), s(16, 2, 12, 3), s(16, 2, 12, 4),
+    s(16, 0, 1, 3), s(16, 0, 1, 4),
+    s(4, 0, 10, 1), s(2, 0, 10, 2),
+    s(17, 2, 5, 1), s(21, 2, 2, 1), s(20, 2, 6, 1),
+    s(15, 0, 0, 3), s(6, 1, 0, 3), s(15, 0, 0, 1), s(13, 1, 0, 2),
+    s(12, 1, 10, 1), s(13, 1, 10, 4),
+    s(3, 0, 6, 4), s(0, 1, 0, 4),
+    s(6, 2, 2, 2), s(6, 2, 2, 3),
+    s(1, 0, 7, 4), s(2, 0, 7, 2), s(2, 0, 7, 4),
+    s(6, 0, 9, 3), s(15, 0, 9, 4),
+    s(12, 3, 0, 4), s(12, 1, 4, 3),
+    s(11, 0, 3, 4), s(10, 0, 3, 4), s(10, 2, 1, 3),
+    s(0, 1, 0, 2), s(0, 1, 0, 4), s(6, 2, 2, 2),
+    s(2, 0, 1, 4),
+    s(0, 2, 1, 4), s(3, 0, 2, 4),
+    s(2, 0, 9, 4), s(4, 0, 9, 2),
+    s(3, 0, 7, 4), s(3, 0, 8, 2),
+    s(7, 0, 1, 4), s(12, 3, 4, 2),
+    s(15, 0, 3, 2), s(16, 0, 9, 1),
+    s(1, 0, 6, 4), s(1, 1, 0, 4), s(2, 1, 0, 1), s(2, 1, 0, 4),
+    s(16, 0, 0, 3), s(7, 2, 2, 3),
+    s(16, 0, 0, 3), s(13, 1, 0, 3), s(5, 2, 2, 3), s(7, 2, 2, 3),
+    s(12, 1, 1, 2), s(13, 1, 1, 1), s(12, 1, 1, 1), s(12, 1, 4, 2),
+    s(1, 0, 2, 2), s(3, 0, 2, 4),
+    s(9, 0, 3, 1), s(8, 2, 2, 4),
+    s(9, 2, 6, 1), s(9, 2, 1, 4),
+    s(8, 1, 4, 4), s(8, 1, 4, 3),
+    s(12, 1, 1, 2), s(12, 1, 1, 1), s(14, 1, 4, 2),
+    s(16, 0, 12, 2), s(16, 0, 12, 3),
+    s(2, 0, 8, 2), s(1, 0, 7, 1),
+    s(9, 0, 3, 2), s(12, 1, 4, 1),
+    s(8, 1, 9, 2), s(17, 0, 7, 1),
+    s(16, 0, 9, 1), s(16, 0, 9, 4), s(6, 0, 9, 3),
+    s(16, 0, 8, 2), s(5, 0, 7, 1),
+    s(17, 0, 11, 5), s(16, 0, 11, 2),
+    s(2, 0, 6, 2), s(4, 0, 6, 2),
+    s(0, 3, 9, 1), s(9, 2, 10, 3),
+    s(0, 1, 9, 3), s(0, 0, 9, 1), s(0, 1, 9, 4),
+    s(12, 3, 0, 1), s(12, 3, 0, 4),
+    s(0, 1, 0, 3), s(13, 1, 0, 3),
+    s(16, 2, 12, 2), s(16, 2, 12, 1), s(15, 2, 12, 4),
+    s(1, 1, 9, 3), s(2, 1, 9, 2),
+    s(5, 2, 9, 1), s(6, 2, 9, 1),
+    s(14, 1, 8, 4), s(0, 1, 8, 4),
+    s(0, 3, 0, 2), s(6, 0, 8, 2),
+    s(6, 2, 9, 4),
+    s(11, 2, 6, 1), s(0, 1, 0, 1),
+    s(16, 2, 0, 3), s(15, 0, 3, 3), s(15, 2, 0, 3),
+    s(0, 3, 10, 3), s(0, 2, 10, 1),
+    s(18, 2, 0, 4), s(7, 2, 0, 4),
+    s(21, 2, 2, 3), s(16, 0, 1, 2),
+    s(6, 2, 6, 4), s(6, 2, 10, 4),
+    s(15, 0, 3, 3), s(5, 1, 4, 2), s(14, 1, 0, 2),
+    s(0, 1, 0, 4), s(7, 1, 4, 4),
+    s(21, 0, 10, 1), s(17, 0, 9, 1),
+    s(1, 0, 7, 1), s(2, 0, 8, 2),
+    s(15, 2, 9, 4), s(12, 3, 9, 4),
+    s(20, 2, 6, 4), s(20, 2, 2, 1),
+    s(4, 0, 9, 2), s(1, 0, 2, 4),
+    s(19, 0, 3, 2), s(5, 2, 2, 2), s(0, 1, 0, 4),
+    s(16, 0, 9, 1), s(16, 0, 9, 4), s(5, 0, 9, 1),
+    s(8, 1, 9, 3), s(16, 0, 9, 1),
+    s(17, 2, 0, 3), s(5, 2, 0, 2),
+    s(6, 0, 9, 3), s(15, 0, 9, 4), s(16, 0, 9, 2), s(15, 0, 9, 1),
+    s(1, 0, 5, 3), s(1, 0, 6, 1),
+    s(14, 1, 9, 1), s(17, 0, 9, 1),
+    s(15, 0, 3, 3), s(15, 0, 3, 2),
+    s(19, 0, 9, 4), s(20, 2, 9, 2),
+    s(0, 1, 7, 4), s(0, 1, 7, 1), s(0, 1, 7, 3),
+    s(4, 0, 12, 3), s(1, 0, 9, 3),
+    s(6, 0, 9, 2), s(13, 1, 10, 2), s(0, 1, 9, 3),
+    s(4, 1, 7, 4),
+    s(12, 1, 9, 4), s(14, 1, 9, 4),
+    s(9, 2, 6, 1), s(9, 2, 6, 4), s(14, 3, 0, 4),
+    s(2, 1, 9, 3),
+    s(3, 1, 4, 4), s(2, 1, 4, 1),
+    s(16, 0, 9, 1), s(5, 0, 9, 1), s(12, 1, 0, 1),
+    s(12, 1, 9, 4), s(1, 1, 9, 3),
+    s(8, 2, 2, 2), s(8, 2, 9, 3),
+    s(5, 1, 0, 2), s(12, 1, 0, 2),
+    s(12, 1, 7, 3), s(12, 3, 4, 2), s(8, 2, 0, 4), s(9, 2, 0, 3),
+    s(20, 2, 0, 1), s(16, 2, 0, 4), s(16, 0, 12, 2),
+    s(12, 3, 4, 2), s(10, 2, 6, 4), s(9, 2, 6, 4),
+    s(19, 0, 0, 1), s(19, 2, 6, 3),
+    s(11, 2, 1, 4), s(14, 1, 4, 4),
+    s(12, 1, 4, 3), s(12, 1, 4, 4), s(14, 1, 4, 4),
+    s(12, 1, 4, 3), s(12, 1, 4, 4),
+    s(12, 1, 0, 1), s(13, 1, 0, 3), s(13, 1, 0, 2),
+    s(15, 0, 1, 1), s(5, 0, 1, 3), s(15, 0, 1, 3),
+    s(11, 2, 0, 2), s(13, 3, 4, 4), s(12, 3, 4, 2),
+    s(14, 3, 4, 2), s(11, 2, 0, 4),
+    s(8, 1, 0, 4), s(8, 2, 0, 4),
+    s(14, 1, 0, 1), s(0, 2, 6, 2),
+    s(0, 1, 9, 2), s(0, 1, 9, 4), s(0, 1, 10, 2),
+    s(13, 1, 8, 2), s(10, 0, 7, 1),
+    s(11, 2, 12, 1), s(12, 3, 10, 4), s(11, 0, 12, 1),
+    s(16, 0, 5, 4), s(16, 0, 1, 1), s(16, 0, 1, 4),
+    s(0, 1, 0, 2), s(5, 0, 9, 4), s(17, 0, 0, 1), s(6, 2, 2, 2), s(6, 2, 2, 3),
+    s(4, 0, 10, 1), s(1, 1, 10, 1),
+    s(14, 1, 10, 1), s(14, 1, 0, 1), s(0, 1, 10, 2),
+    s(14, 1, 7, 2), s(7, 0, 1, 2),
+    s(16, 0, 7, 1), s(3, 1, 7, 3), s(16, 0, 7, 3),
+    s(21, 0, 1, 3), s(21, 0, 1, 4),
+    s(15, 2, 10, 4), s(15, 2, 10, 1),
+    s(14, 3, 0, 3), s(11, 2, 0, 3),
+    s(16, 0, 0, 1), s(16, 0, 0, 4),
+    s(19, 0, 0, 1), s(19, 0, 0, 3),
+    s(15, 0, 9, 1), s(16, 0, 3, 4), s(5, 1, 9, 1), s(15, 0, 9, 4), s(6, 1, 4, 1),
+    s(5, 1, 0, 3), s(6, 1, 0, 4),
+    s(7, 0, 7, 2), s(7, 0, 1, 2), s(7, 2, 0, 4),
+    s(0, 1, 0, 2), s(6, 2, 2, 2), s(5, 2, 2, 4), s(0, 1, 0, 1), s(14, 1, 0, 1),
+    s(0, 1, 0, 2), s(5, 0, 5, 4), s(6, 0, 5, 2),
+    s(15, 0, 7, 4), s(15, 0, 7, 1),
+    s(13, 3, 0, 1), s(16, 2, 0, 4),
+    s(1, 0, 1, 2), s(1, 0, 2, 2),
+    s(16, 0, 1, 2), s(13, 1, 4, 4),
+    s(16, 0, 1, 4), s(5, 2, 0, 4),
+    s(9, 0, 8, 4), s(11, 0, 8, 4),
+    s(14, 1, 11, 2), s(0, 1, 11, 2),
+    s(15, 0, 8, 4), s(16, 0, 8, 2),
+    s(15, 0, 9, 1), s(5, 0, 9, 4),
+    s(0, 0, 3, 4), s(8, 3, 4, 4), s(8, 2, 2, 4),
+    s(0, 0, 13, 4), s(16, 0, 0, 3),
+    s(0, 1, 0, 2), s(16, 0, 0, 3), s(16, 0, 0, 4), s(5, 2, 2, 4),
+    s(10, 2, 1, 1), s(13, 3, 0, 4),
+    s(8, 0, 11, 3), s(8, 0, 11, 4),
+    s(0, 0, 3, 2), s(0, 0, 3, 3),
+    s(0, 0, 6, 2), s(14, 1, 0, 1), s(0, 1, 0, 4), s(0, 0, 6, 3), s(0, 0, 6, 4), s(0, 0, 6, 1),
+    s(17, 2, 1, 4),
+    s(17, 2, 2, 1),
+    s(17, 2, 2, 1), s(17, 2, 6, 4), s(0, 3, 4, 4), s(6, 2, 2, 1),
+    s(17, 2, 6, 2), s(17, 0, 6, 2),
+    s(13, 3, 0, 1), s(12, 3, 4, 4),
+    s(16, 0, 0, 1), s(8, 0, 5, 4),
+    s(7, 1, 0, 4), s(7, 0, 1, 2),
+    s(5, 1, 7, 4), s(6, 1, 7, 2), s(15, 0, 8, 1),
+    s(2, 1, 0, 3), s(1, 0, 6, 1),
+    s(13, 1, 12, 3), s(13, 1, 12, 4), s(13, 1, 12, 2),
+    s(19, 0, 3, 2), s(20, 2, 2, 4), s(19, 2, 2, 4), s(15, 0, 1, 3), s(12, 1, 4, 4),
+    s(19, 0, 8, 1), s(15, 0, 8, 1),
+    s(16, 2, 0, 4), s(12, 1, 0, 2),
+    s(11, 0, 7, 2), s(14, 1, 1, 4), s(11, 2, 2, 4),
+    s(8, 2, 10, 4), s(8, 2, 10, 2),
+    s(17, 0, 10, 3), s(7, 1, 4, 4),
+    s(5, 1, 4, 2), s(14, 1, 4, 4),
+    s(2, 1, 9, 3), s(2, 1, 9, 2),
+    s(17, 0, 0, 4), s(5, 1, 0, 4),
+    s(12, 1, 9, 4), s(8, 0, 9, 4),
+    s(0, 3, 0, 4), s(6, 0, 8, 3),
+    s(14, 1, 0, 3), s(17, 0, 5, 1), s(0, 0, 5, 1),
+    s(0, 0, 9, 1), s(6, 0, 8, 3),
+    s(14, 1, 9, 2), s(9, 0, 9, 1),
+    s(7, 0, 9, 2), s(7, 0, 9, 4),
+    s(4, 0, 12, 3), s(4, 0, 12, 4),
+    s(13, 1, 0, 1), s(14, 3, 0, 3),
+    s(0, 1, 4, 4), s(0, 0, 5, 3),
+    s(15, 0, 8, 1), s(16, 0, 8, 1), s(16, 0, 7, 3),
+    s(2, 0, 2, 2), s(2, 0, 7, 2),
+    s(10, 2, 6, 4), s(5, 2, 6, 3), s(6, 2, 6, 2), s(9, 2, 6, 3),
+    s(0, 1, 12, 2), s(0, 1, 12, 1), s(11, 2, 12, 1),
+    s(14, 1, 7, 3), s(21, 0, 8, 3), s(21, 0, 8, 4),
+    s(14, 1, 0, 3), s(14, 1, 1, 4), s(14, 1, 0, 2),
+    s(16, 0, 10, 1), s(15, 0, 10, 4),
+    s(6, 1, 0, 2), s(21, 0, 0, 1),
+    s(13, 1, 9, 1), s(15, 0, 9, 4),
+    s(17, 0, 0, 4), s(0, 1, 0, 4), s(14, 1, 0, 4),
+    s(0, 3, 9, 2), s(13, 3, 9, 2),
+    s(11, 0, 3, 4), s(14, 1, 7, 1),
+    s(0, 3, 0, 2), s(14, 3, 0, 1),
+    s(1, 1, 7, 1), s(2, 1, 7, 1),
+    s(20, 2, 12, 4),
+    s(15, 0, 3, 2), s(19, 0, 3, 2),
+    s(3, 1, 8, 4),
+    s(12, 1, 4, 1), s(15, 0, 1, 1), s(15, 0, 1, 3), s(19, 2, 0, 3),
+    s(20, 0, 9, 4), s(19, 0, 7, 4), s(21, 0, 9, 1), s(16, 0, 10, 3),
+    s(0, 0, 8, 1), s(14, 3, 0, 2),
+    s(14, 1, 0, 2), s(16, 0, 3, 4),
+    s(15, 2, 2, 2), s(17, 2, 0, 4), s(15, 0, 3, 1),
+    s(11, 2, 0, 1), s(14, 1, 7, 1),
+    s(11, 0, 9, 4), s(14, 1, 9, 4),
+    s(11, 2, 1, 2), s(0, 2, 1, 4),
+    s(16, 0, 9, 3), s(5, 0, 9, 4),
+    s(7, 0, 7, 2), s(14, 1, 7, 1),
+    s(0, 0, 3, 2), s(0, 2, 1, 2), s(9, 2, 6, 3),
+    s(12, 1, 7, 3), s(13, 1, 7, 4),
+    s(15, 2, 9, 4), s(13, 3, 9, 2),
+    s(19, 0, 10, 4), s(12, 1, 9, 4),
+    s(17, 0, 0, 2), s(17, 0, 0, 4), s(15, 0, 0, 4),
+    s(19, 0, 10, 4),
+    s(15, 0, 9, 4), s(8, 1, 9, 2),
+    s(7, 0, 11, 2), s(7, 0, 8, 2),
+    s(11, 2, 1, 4), s(14, 1, 4, 4), s(11, 2, 6, 4),
+    s(0, 0, 5, 4), s(0, 1, 0, 3), s(7, 1, 0, 3), s(0, 1, 0, 4), s(0, 1, 0, 2),
+    s(0, 1, 0, 2), s(6, 2, 6, 1),
+    s(16, 0, 10, 4), s(16, 0, 9, 4),
+    s(15, 0, 9, 2), s(15, 0, 9, 1),
+    s(17, 2, 2, 1), s(17, 2, 6, 4), s(0, 3, 4, 4),
+    s(0, 0, 6, 2),
+    s(5, 1, 7, 4), s(6, 1, 7, 2),
+    s(3, 1, 0, 2), s(3, 0, 6, 4),
+    s(2, 1, 9, 2), s(2, 1, 9, 3),
+    s(9, 2, 0, 3), s(8, 2, 0, 4), s(0, 3, 0, 4),
+    s(11, 2, 12, 1), s(8, 2, 12, 2),
+    s(14, 1, 0, 1), s(12, 1, 0, 1),
+    s(11, 0, 9, 3), s(9, 0, 9, 3),
+    s(17, 0, 0, 4), s(16, 0, 0, 3),
+    s(8, 1, 0, 3), s(4, 0, 12, 1),
+    s(1, 1, 0, 1), s(1, 1, 9, 3),
+    s(6, 2, 10, 2), s(5, 2, 10, 1), s(5, 2, 10, 4),
+    s(12, 1, 9, 1), s(0, 1, 9, 4),
+    s(10, 0, 10, 3), s(10, 2, 10, 1),
+    s(4, 2, 0, 1), s(4, 2, 0, 4), s(2, 2, 0, 1),
+    s(1, 1, 10, 1), s(11, 2, 9, 1),
+    s(0, 3, 0, 4), s(14, 1, 4, 4), s(17, 2, 0, 1),
+    s(1, 0, 2, 2), s(11, 2, 0, 4), s(11, 2, 2, 4), s(9, 0, 8, 4),
+    s(15, 0, 0, 4), s(15, 0, 5, 4),
+    s(7, 0, 1, 4), s(5, 2, 2, 4),
+    s(11, 2, 9, 2), s(11, 2, 9, 1),
+    s(10, 2, 10, 1), s(3, 0, 7, 4), s(10, 0, 10, 3),
+    s(11, 0, 7, 2), s(11, 0, 3, 2), s(3, 0, 2, 4), s(3, 0, 1, 4),
+    s(3, 0, 2, 4), s(3, 0, 1, 2),
+    s(8, 1, 0, 2), s(3, 0, 5, 2), s(0, 3, 0, 4),
+    s(6, 2, 9, 1), s(6, 2, 9, 4),
+    s(3, 0, 7, 1), s(3, 0, 7, 2),
+    s(12, 3, 0, 4), s(0, 3, 0, 2),
+    s(0, 1, 0, 2), s(0, 1, 0, 4),
+    s(9, 2, 9, 4), s(0, 2, 9, 1),
+    s(1, 1, 9, 3), s(4, 0, 1, 2),
+    s(5, 0, 5, 4), s(6, 0, 3, 4),
+    s(4, 0, 6, 4), s(4, 2, 0, 2), s(1, 1, 0, 4),
+    s(1, 1, 0, 4), s(4, 0, 10, 2), s(1, 0, 10, 1), s(4, 0, 10, 4), s(4, 0, 6, 2), s(1, 0, 9, 1), s(8, 2, 0, 4), s(2, 0, 9, 1),
+    s(12, 1, 1, 3), s(9, 2, 0, 3), s(12, 1, 1, 4),
+    s(17, 0, 3, 1), s(17, 0, 1, 1),
+    s(15, 2, 9, 4), s(19, 2, 9, 4),
+    s(0, 3, 10, 1), s(1, 1, 10, 1),
+    s(17, 0, 9, 4), s(5, 0, 9, 4),
+    s(17, 2, 0, 2), s(17, 2, 0, 4),
+    s(9, 0, 9, 4), s(9, 2, 12, 4), s(15, 2, 11, 4),
+    s(1, 0, 10, 1), s(1, 1, 0, 4),
+    s(12, 1, 1, 3), s(9, 2, 0, 3),
+    s(17, 0, 3, 4), s(20, 0, 3, 4),
+    s(6, 2, 12, 2), s(14, 3, 12, 2),
+    s(11, 0, 3, 4), s(17, 0, 0, 4),
+    s(19, 0, 8, 3),
+    s(9, 0, 9, 3), s(13, 1, 9, 2),
+    s(0, 1, 10, 3), s(13, 1, 10, 3),
+    s(12, 3, 4, 2), s(9, 2, 6, 4),
+    s(16, 0, 10, 4), s(15, 0, 10, 1), s(16, 0, 10, 2), s(7, 1, 9, 3), s(15, 0, 10, 3),
+    s(5, 1, 4, 2), s(6, 2, 0, 2),
+    s(12, 3, 0, 1), s(13, 1, 4, 4),
+    s(16, 0, 7, 1), s(16, 0, 7, 3), s(16, 0, 7, 4), s(6, 1, 7, 4),
+    s(12, 3, 4, 2), s(12, 3, 0, 2),
+    s(0, 3, 4, 4), s(11, 2, 2, 2),
+    s(16, 0, 0, 2), s(13, 3, 0, 1),
+    s(9, 2, 1, 1), s(11, 2, 2, 2),
+    s(14, 3, 4, 4), s(16, 0, 0, 4),
+    s(19, 0, 0, 1), s(20, 0, 0, 4),
+    s(6, 1, 7, 2), s(6, 1, 7, 4), s(6, 1, 7, 3),
+    s(15, 0, 7, 4), s(5, 1, 7, 4),
+    s(12, 3, 0, 2), s(13, 3, 0, 1), s(13, 1, 8, 2),
+    s(13, 3, 4, 4), s(13, 1, 0, 4), s(12, 1, 0, 2),
+    s(6, 0, 11, 4), s(15, 0, 12, 1), s(15, 0, 12, 4), s(16, 0, 12, 2), s(6, 0, 11, 1),
+    s(16, 2, 2, 4), s(16, 0, 7, 4), s(6, 1, 7, 4), s(15, 2, 2, 2),
+    s(20, 2, 6, 3), s(0, 2, 6, 3), s(12, 3, 0, 4),
+    s(13, 3, 0, 4), s(20, 2, 0, 4), s(13, 3, 0, 1), s(20, 0, 8, 3), s(19, 0, 8, 1),
+    s(13, 3, 0, 1), s(20, 2, 0, 4), s(13, 3, 0, 4), s(20, 0, 8, 3),
+    s(11, 2, 11, 2), s(9, 2, 11, 1),
+    s(13, 1, 7, 2), s(12, 1, 7, 4), s(16, 0, 7, 3),
+    s(6, 1, 0, 4), s(0, 3, 4, 4), s(0, 1, 7, 4),
+    s(19, 0, 9, 3), s(19, 2, 0, 1),
+    s(19, 2, 0, 2), s(12, 3, 0, 4),
+    s(1, 0, 7, 4), s(1, 0, 2, 1), s(15, 2, 2, 2), s(16, 2, 2, 4), s(2, 0, 7, 2),
+    s(10, 2, 0, 4), s(0, 2, 0, 1),
+    s(5, 2, 10, 3),
+    s(12, 1, 9, 3), s(0, 1, 9, 4), s(0, 1, 9, 2), s(12, 1, 9, 1),
+    s(4, 0, 11, 4), s(2, 0, 11, 2), s(4, 0, 11, 1),
+    s(6, 0, 1, 1), s(21, 0, 1, 4), s(13, 1, 0, 4),
+    s(13, 1, 0, 2), s(13, 1, 0, 3), s(13, 1, 0, 4), s(12, 1, 0, 1), s(15, 0, 0, 1),
+    s(6, 2, 2, 4), s(16, 0, 0, 4),
+    s(7, 1, 9, 3), s(12, 1, 9, 4), s(16, 0, 10, 2), s(6, 1, 9, 4),
+    s(1, 0, 1, 2), s(1, 0, 6, 4),
+    s(5, 1, 4, 1), s(5, 1, 4, 2), s(6, 2, 0, 2),
+    s(20, 0, 0, 1), s(20, 0, 0, 3), s(19, 0, 0, 3),
+    s(2, 0, 7, 3), s(2, 0, 7, 2), s(1, 0, 2, 2),
+    s(12, 3, 0, 1), s(13, 3, 0, 3),
+    s(5, 1, 9, 3), s(6, 1, 4, 1), s(5, 1, 4, 2), s(15, 0, 9, 4), s(5, 1, 4, 1),
+    s(2, 0, 9, 2), s(1, 0, 9, 4),
+    s(12, 3, 0, 4), s(13, 3, 0, 1), s(13, 1, 4, 3), s(15, 2, 0, 4), s(13, 1, 4, 4),
+    s(1, 0, 2, 3), s(1, 1, 0, 4), s(2, 0, 2, 1),
+    s(5, 2, 2, 4), s(5, 0, 5, 4), s(5, 2, 2, 1), s(16, 0, 0, 2),
+    s(14, 1, 9, 3), s(14, 1, 9, 1), s(21, 2, 10, 3),
+    s(12, 1, 7, 1), s(13, 1, 7, 1),
+    s(13, 3, 9, 2), s(19, 2, 10, 1),
+    s(10, 2, 1, 4), s(10, 2, 0, 4), s(10, 2, 1, 1), s(10, 2, 1, 3),
+    s(15, 2, 5, 3), s(17, 0, 0, 4),
+    s(13, 3, 12, 2), s(13, 1, 11, 1), s(13, 3, 12, 1),
+    s(10, 2, 6, 3), s(14, 1, 4, 4),
+    s(16, 0, 0, 4), s(5, 1, 4, 2),
+    s(8, 2, 0, 4), s(8, 2, 2, 4),
+    s(2, 1, 9, 2), s(1, 0, 12, 4), s(1, 1, 12, 3),
+    s(12, 1, 1, 2), s(12, 1, 4, 2),
+    s(6, 1, 7, 4), s(5, 1, 7, 4), s(6, 0, 7, 2),
+    s(5, 0, 1, 2), s(5, 0, 1, 5),
+    s(12, 3, 0, 2), s(13, 3, 0, 4),
+    s(17, 2, 0, 1), s(16, 0, 8, 1),
+    s(6, 2, 0, 2), s(5, 2, 2, 2), s(16, 2, 2, 1),
+    s(16, 2, 0, 4), s(20, 2, 0, 4),
+    s(14, 3, 4, 2), s(16, 0, 0, 4),
+    s(20, 2, 10, 1), s(13, 3, 10, 1), s(20, 2, 10, 2), s(19, 2, 10, 1), s(13, 1, 8, 4), s(15, 2, 10, 1),
+    s(8, 1, 11, 2), s(8, 1, 11, 4), s(8, 0, 11, 2), s(8, 0, 11, 4),
+    s(6, 0, 1, 4), s(6, 0, 1, 1),
+    s(0, 2, 2, 1), s(0, 2, 6, 1), s(18, 2, 6, 2),
+    s(0, 2, 6, 3), s(20, 2, 0, 4),
+    s(16, 2, 2, 1), s(5, 1, 7, 4), s(15, 2, 2, 1), s(6, 1, 7, 4), s(16, 2, 2, 4),
+    s(12, 1, 0, 2), s(13, 1, 0, 4), s(13, 3, 4, 4),
+    s(8, 0, 12, 4), s(8, 0, 12, 2), s(16, 0, 12, 3),
+    s(6, 1, 0, 1), s(5, 1, 4, 2),
+    s(1, 0, 2, 2), s(2, 0, 8, 4),
+    s(0, 1, 0, 3), s(13, 1, 0, 1), s(12, 1, 0, 1), s(12, 1, 0, 3), s(0, 1, 0, 4),
+    s(20, 2, 0, 4), s(5, 1, 0, 2),
+    s(20, 0, 5, 3), s(10, 2, 6, 2),
+    s(2, 0, 12, 4), s(2, 0, 9, 2),
+    s(0, 3, 0, 2), s(0, 1, 7, 2), s(16, 2, 0, 1),
+    s(5, 2, 2, 2), s(16, 2, 2, 4), s(5, 2, 2, 4),
+    s(16, 2, 9, 3), s(16, 2, 10, 3), s(16, 2, 10, 1),
+    s(15, 2, 12, 3), s(15, 2, 12, 4),
+    s(5, 1, 0, 4), s(15, 0, 0, 4), s(6, 1, 0, 2), s(16, 0, 0, 2), s(17, 0, 0, 4),
+    s(16, 2, 5, 4), s(17, 2, 9, 4), s(5, 2, 9, 4), s(16, 2, 9, 3),
+    s(9, 2, 1, 1), s(9, 2, 1, 3), s(6, 2, 2, 2),
+    s(6, 0, 11, 2), s(6, 0, 11, 3), s(17, 0, 11, 1),
+    s(7, 2, 2, 4), s(18, 0, 3, 4), s(7, 0, 1, 4),
+    s(16, 0, 1, 3), s(15, 0, 1, 1),
+    s(7, 1, 9, 3), s(15, 0, 9, 3), s(16, 0, 9, 2),
+    s(2, 0, 9, 2), s(3, 0, 9, 2),
+    s(8, 1, 8, 1), s(8, 1, 8, 4),
+    s(19, 0, 9, 4), s(20, 0, 9, 2),
+    s(6, 0, 11, 1), s(6, 0, 11, 4), s(16, 0, 12, 1),
+    s(5, 0, 5, 4), s(5, 1, 4, 1), s(5, 0, 9, 1), s(15, 0, 0, 4),
+    s(5, 1, 0, 2), s(15, 0, 0, 2),
+    s(2, 0, 9, 2), s(3, 0, 9, 2), s(8, 1, 11, 3),
+    s(7, 1, 9, 3), s(18, 0, 9, 3),
+    s(5, 0, 12, 1), s(5, 0, 12, 4),
+    s(20, 0, 12, 4), s(20, 0, 12, 2),
+    s(15, 2, 12, 1), s(16, 2, 12, 4),
+    s(5, 2, 10, 1), s(19, 2, 10, 2), s(20, 2, 10, 2), s(19, 2, 10, 1), s(20, 2, 10, 3), s(20, 2, 9, 2), s(13, 3, 10, 3),
+    s(20, 2, 0, 4), s(19, 2, 0, 2), s(12, 1, 8, 5),
+    s(12, 3, 4, 2), s(12, 3, 4, 3), s(9, 2, 6, 4),
+    s(6, 0, 1, 2),
+    s(12, 3, 4, 1), s(13, 1, 7, 1), s(12, 1, 7, 3), s(12, 3, 4, 2), s(12, 3, 0, 2), s(14, 3, 4, 4),
+    s(8, 1, 7, 1),
+    s(15, 2, 0, 2), s(15, 2, 2, 2),
+    s(16, 2, 0, 2), s(16, 2, 2, 4),
+    s(0, 3, 4, 4), s(6, 1, 0, 4),
+    s(8, 1, 0, 4), s(0, 3, 4, 4), s(8, 2, 2, 4),
+    s(21, 0, 1, 3), s(14, 1, 4, 4),
+    s(20, 2, 2, 2), s(19, 2, 9, 1),
+    s(12, 3, 4, 2), s(13, 1, 0, 4),
+    s(17, 0, 10, 1), s(12, 3, 9, 1),
+    s(6, 0, 11, 3), s(6, 0, 11, 4),
+    s(5, 2, 2, 3), s(6, 2, 2, 3),
+    s(0, 1, 1, 4), s(15, 0, 1, 2), s(9, 0, 1, 2),
+    s(14, 1, 10, 4), s(14, 1, 9, 4),
+    s(14, 3, 9, 1), s(14, 1, 9, 3), s(14, 1, 9, 4), s(11, 0, 9, 3), s(12, 1, 9, 1),
+    s(16, 0, 10, 2), s(13, 1, 0, 2),
+    s(13, 3, 0, 2), s(9, 0, 8, 1), s(9, 0, 8, 4), s(12, 3, 0, 1),
+    s(5, 1, 0, 3), s(16, 0, 0, 2),
+    s(4, 0, 9, 4), s(1, 0, 10, 4),
+    s(18, 2, 12, 3), s(4, 2, 0, 3), s(4, 2, 0, 4), s(18, 2, 12, 2),
+    s(15, 0, 8, 2), s(15, 2, 0, 2), s(15, 0, 8, 4),
+    s(0, 1, 7, 2), s(5, 1, 7, 1),
+    s(0, 1, 0, 4), s(5, 1, 4, 2), s(15, 0, 3, 2),
+    s(15, 0, 0, 4), s(13, 1, 12, 1),
+    s(12, 1, 7, 4), s(12, 3, 4, 2), s(14, 1, 7, 4),
+    s(11, 0, 3, 2), s(8, 2, 0, 4), s(0, 1, 1, 4),
+    s(10, 0, 5, 3), s(10, 0, 5, 4),
+    s(13, 3, 9, 2), s(16, 2, 10, 1),
+    s(19, 0, 5, 4), s(19, 0, 5, 3), s(5, 0, 5, 4), s(19, 0, 5, 1), s(19, 0, 0, 1),
+    s(0, 2, 9, 4), s(0, 3, 9, 3),
+    s(0, 1, 10, 3), s(13, 3, 10, 1),
+    s(7, 1, 0, 2), s(0, 1, 0, 4),
+    s(8, 0, 12, 2), s(8, 1, 12, 2), s(8, 0, 12, 4),
+    s(9, 2, 2, 3), s(11, 2, 1, 4), s(11, 2, 6, 4),
+    s(7, 1, 9, 3), s(8, 1, 9, 3),
+    s(18, 2, 9, 3), s(0, 0, 13, 2),
+    s(14, 1, 9, 2), s(10, 0, 9, 4),
+    s(16, 2, 10, 1), s(17, 2, 10, 3),
+    s(9, 0, 3, 2), s(0, 1, 1, 4), s(0, 0, 3, 4), s(13, 1, 4, 4),
+    s(17, 2, 0, 1), s(17, 2, 0, 4),
+    s(4, 2, 0, 4), s(1, 2, 0, 2),
+    s(0, 2, 10, 1), s(0, 3, 10, 1),
+    s(15, 0, 9, 3), s(7, 1, 9, 3),
+    s(6, 0, 7, 1), s(10, 0, 9, 3),
+    s(14, 1, 1, 2), s(11, 0, 3, 2),
+    s(12, 1, 7, 1), s(14, 1, 7, 3),
+    s(15, 2, 9, 3), s(15, 2, 9, 4), s(15, 2, 5, 3),
+    s(1, 2, 0, 2),
+    s(8, 0, 7, 3), s(8, 0, 7, 2), s(8, 1, 7, 2), s(8, 1, 7, 3), s(8, 1, 7, 4),
+    s(15, 0, 8, 2), s(15, 0, 8, 4),
+    s(19, 0, 5, 4), s(19, 0, 5, 3),
+    s(7, 1, 9, 3), s(15, 0, 9, 3),
+    s(2, 1, 0, 4), s(1, 1, 0, 4), s(3, 1, 0, 3), s(2, 1, 0, 1),
+    s(19, 2, 6, 4), s(19, 2, 6, 1),
+    s(14, 3, 4, 1), s(0, 1, 0, 4),
+    s(1, 1, 9, 4), s(1, 1, 9, 3), s(1, 0, 9, 4), s(2, 1, 9, 4),
+    s(1, 1, 9, 4), s(2, 1, 9, 2), s(1, 1, 9, 3), s(1, 0, 9, 4),
+    s(16, 0, 9, 3), s(15, 0, 10, 3),
+    s(1, 1, 9, 1), s(1, 1, 9, 5),
+    s(5, 0, 1, 2), s(6, 1, 0, 4), s(6, 0, 1, 4),
+    s(16, 0, 9, 1), s(16, 0, 9, 2),
+    s(0, 1, 0, 2), s(0, 1, 0, 3), s(6, 2, 2, 2),
+    s(9, 2, 2, 4), s(9, 2, 2, 1),
+    s(0, 2, 11, 4), s(9, 2, 11, 4), s(10, 2, 11, 2),
+    s(0, 1, 12, 2), s(0, 1, 12, 4),
+    s(0, 3, 10, 4), s(0, 3, 10, 3),
+    s(11, 0, 11, 2), s(14, 1, 11, 2),
+    s(11, 0, 5, 2), s(11, 2, 9, 2), s(4, 2, 0, 2),
+    s(15, 0, 3, 4), s(15, 0, 6, 4),
+    s(7, 1, 0, 4), s(16, 0, 0, 2),
+    s(12, 1, 1, 1), s(14, 1, 4, 4),
+    s(2, 0, 2, 4), s(2, 0, 5, 3),
+    s(15, 2, 0, 4), s(0, 2, 11, 3),
+    s(5, 1, 4, 2), s(0, 1, 0, 4), s(5, 0, 1, 2),
+    s(19, 0, 3, 2), s(19, 2, 2, 4),
+    s(13, 3, 0, 5),
+    s(3, 1, 0, 2), s(3, 1, 0, 4),
+    s(15, 2, 6, 1), s(5, 2, 6, 1), s(6, 2, 6, 1),
+    s(17, 0, 0, 4), s(10, 2, 2, 4),
+    s(6, 0, 8, 4), s(17, 2, 0, 1),
+    s(15, 2, 0, 2), s(5, 1, 0, 2), s(15, 0, 8, 4), s(6, 2, 10, 2),
+    s(5, 0, 8, 4), s(15, 2, 0, 4), s(6, 0, 8, 2), s(13, 1, 0, 2),
+    s(15, 0, 3, 4), s(0, 1, 9, 4), s(15, 0, 6, 4),
+    s(6, 2, 12, 1), s(6, 2, 12, 4),
+    s(9, 2, 11, 4), s(10, 2, 11, 2),
+    s(16, 0, 12, 3), s(0, 1, 12, 2),
+    s(19, 0, 7, 4), s(20, 0, 7, 4), s(20, 0, 7, 1),
+    s(13, 3, 10, 1), s(14, 3, 10, 4), s(21, 2, 2, 1),
+    s(4, 0, 12, 2), s(2, 0, 12, 2), s(2, 0, 11, 2),
+    s(8, 1, 9, 2), s(8, 1, 9, 3), s(8, 1, 9, 4), s(8, 0, 9, 4),
+    s(12, 3, 4, 2), s(15, 2, 0, 2),
+    s(1, 0, 12, 4), s(2, 0, 12, 1),
+    s(5, 0, 5, 3), s(5, 0, 5, 4), s(5, 1, 0, 4),
+    s(8, 2, 0, 4), s(5, 0, 5, 4),
+    s(16, 2, 2, 1), s(16, 2, 2, 4),
+    s(0, 3, 0, 2), s(5, 0, 8, 4),
+    s(5, 2, 10, 4), s(13, 3, 10, 1), s(14, 3, 10, 2),
+    s(21, 2, 6, 4), s(21, 2, 6, 2),
+    s(0, 1, 9, 3), s(0, 0, 9, 4),
+    s(0, 3, 0, 4), s(0, 3, 12, 2), s(0, 0, 8, 3),
+    s(9, 2, 2, 4), s(9, 2, 2, 1), s(9, 2, 2, 5), s(11, 2, 2, 4),
+    s(13, 1, 8, 2), s(13, 1, 8, 1),
+    s(0, 2, 6, 2), s(11, 2, 6, 2),
+    s(0, 1, 0, 2), s(0, 2, 6, 4),
+    s(5, 1, 0, 4), s(17, 0, 0, 4), s(5, 0, 5, 4),
+    s(16, 0, 0, 2), s(15, 0, 0, 4), s(14, 1, 0, 1),
+    s(0, 3, 9, 3), s(0, 3, 9, 4),
+    s(17, 0, 0, 4), s(5, 1, 0, 2), s(6, 1, 0, 4), s(15, 0, 3, 2),
+    s(18, 0, 7, 4),
+    s(14, 3, 9, 3), s(14, 3, 9, 4), s(21, 2, 9, 4), s(17, 2, 1, 1),
+    s(0, 1, 0, 2), s(0, 2, 6, 4), s(21, 2, 6, 2),
+    s(11, 0, 5, 2), s(11, 2, 9, 2), s(14, 3, 9, 2),
+    s(15, 0, 9, 1), s(15, 0, 9, 4),
+    s(3, 1, 7, 3), s(3, 1, 7, 2),
+    s(8, 0, 1, 1), s(8, 1, 4, 4),
+    s(8, 1, 0, 2), s(16, 0, 0, 2),
+    s(8, 2, 2, 2), s(8, 2, 2, 4),
+    s(5, 0, 12, 4), s(17, 0, 9, 1),
+    s(18, 2, 0, 2), s(4, 2, 0, 4),
+    s(10, 0, 11, 4), s(11, 0, 11, 2), s(10, 0, 11, 1),
+    s(14, 1, 12, 2), s(9, 0, 12, 3),
+    s(7, 0, 1, 4), s(7, 0, 1, 1), s(7, 2, 2, 2), s(7, 2, 2, 4), s(7, 0, 6, 4), s(7, 0, 1, 3), s(7, 0, 6, 3), s(7, 0, 3, 2), s(7, 0, 5, 3), s(7, 0, 3, 4),
+    s(14, 1, 4, 2), s(0, 1, 1, 2), s(0, 1, 4, 2), s(14, 3, 0, 2), s(17, 0, 3, 2),
+    s(1, 1, 0, 4), s(1, 1, 9, 4),
+    s(0, 1, 4, 4), s(13, 1, 8, 1),
+    s(9, 0, 5, 1), s(11, 0, 5, 2),
+    s(13, 1, 4, 4), s(14, 1, 0, 4),
+    s(11, 2, 9, 2), s(14, 3, 10, 2),
+    s(7, 2, 2, 2), s(7, 0, 1, 3), s(4, 2, 0, 2),
+    s(14, 1, 0, 1), s(16, 0, 0, 1),
+    s(11, 0, 7, 3), s(17, 0, 0, 4),
+    s(0, 1, 12, 3), s(16, 0, 12, 2),
+    s(14, 1, 7, 2), s(0, 0, 7, 3),
+    s(9, 2, 2, 1), s(9, 2, 2, 2),
+    s(19, 0, 8, 1), s(12, 3, 0, 3),
+    s(8, 0, 5, 2), s(8, 0, 6, 3),
+    s(16, 0, 10, 1), s(8, 0, 9, 2),
+    s(0, 1, 8, 2), s(16, 2, 6, 2),
+    s(5, 0, 8, 1), s(5, 2, 0, 1),
+    s(14, 1, 11, 1), s(14, 1, 11, 3), s(14, 1, 11, 4),
+    s(19, 0, 8, 1), s(12, 3, 0, 4),
+    s(11, 0, 7, 4), s(13, 1, 7, 1), s(12, 1, 7, 1),
+    s(4, 2, 0, 1), s(8, 2, 0, 4),
+    s(3, 0, 9, 4), s(0, 2, 9, 4),
+    s(2, 0, 2, 2), s(2, 1, 0, 2), s(2, 0, 9, 2),
+    s(5, 0, 9, 1), s(5, 2, 2, 1),
+    s(20, 0, 7, 4), s(21, 0, 7, 1),
+    s(10, 2, 11, 4), s(10, 2, 2, 4),
+    s(20, 2, 2, 2), s(19, 0, 9, 4),
+    s(11, 2, 9, 1), s(13, 3, 9, 1),
+    s(19, 0, 9, 4), s(20, 2, 2, 2),
+    s(8, 1, 0, 4), s(8, 1, 0, 2), s(15, 0, 0, 2),
+    s(0, 1, 0, 3), s(0, 1, 0, 2),
+    s(9, 0, 9, 1), s(11, 0, 11, 4),
+    s(0, 1, 9, 3), s(0, 1, 9, 4), s(0, 1, 10, 3),
+    s(15, 0, 10, 4), s(5, 0, 9, 1),
+    s(4, 2, 0, 5),
+    s(6, 2, 2, 2), s(5, 2, 2, 4),
+    s(20, 2, 0, 4), s(19, 2, 2, 4),
+    s(2, 0, 2, 4), s(2, 0, 2, 1), s(4, 0, 1, 1),
+    s(19, 0, 5, 4), s(19, 2, 6, 4),
+    s(8, 0, 7, 4), s(8, 2, 2, 4), s(8, 2, 0, 4),
+    s(6, 2, 12, 2), s(5, 2, 12, 4), s(16, 2, 12, 2),
+    s(19, 2, 6, 4), s(4, 2, 0, 2),
+    s(7, 1, 11, 4), s(7, 1, 11, 2),
+    s(0, 2, 6, 2), s(15, 2, 6, 4),
+    s(0, 1, 9, 1), s(0, 0, 11, 1),
+    s(6, 1, 0, 2), s(6, 1, 0, 3),
+    s(14, 1, 9, 2), s(12, 1, 9, 3),
+    s(14, 1, 12, 3), s(16, 0, 12, 2), s(12, 1, 12, 1),
+    s(13, 1, 8, 2), s(16, 0, 8, 1),
+    s(20, 2, 2, 1), s(20, 2, 2, 3),
+    s(16, 0, 10, 3), s(16, 0, 9, 3),
+    s(0, 3, 0, 4), s(0, 0, 8, 1),
+    s(12, 1, 7, 4), s(13, 1, 7, 2), s(15, 0, 9, 4),
+    s(4, 0, 1, 1), s(2, 0, 2, 4), s(2, 0, 2, 1),
+    s(0, 1, 9, 4), s(8, 1, 9, 3), s(14, 1, 9, 1), s(12, 1, 9, 3),
+    s(0, 1, 0, 4), s(0, 1, 0, 3), s(0, 0, 5, 4),
+    s(16, 0, 8, 2), s(17, 0, 8, 4), s(5, 0, 7, 4),
+    s(17, 0, 5, 1), s(17, 0, 0, 1), s(8, 1, 0, 2),
+    s(1, 1, 9, 4), s(1, 1, 9, 3),
+    s(8, 1, 0, 3), s(8, 1, 0, 5),
+    s(15, 2, 12, 4), s(16, 2, 12, 2), s(6, 2, 12, 2),
+    s(0, 1, 4, 3), s(17, 2, 0, 4),
+    s(8, 1, 0, 2), s(14, 1, 0, 1), s(8, 0, 5, 2), s(6, 0, 5, 1), s(8, 0, 5, 4), s(14, 1, 0, 3),
+    s(13, 1, 8, 2), s(9, 0, 1, 2),
+    s(5, 1, 12, 1), s(5, 1, 12, 4), s(8, 1, 12, 2),
+    s(2, 0, 2, 4), s(2, 0, 2, 1),
+    s(17, 0, 9, 4), s(17, 0, 9, 1), s(14, 1, 9, 1),
+    s(13, 1, 7, 3), s(12, 1, 7, 3),
+    s(16, 2, 9, 4), s(16, 2, 9, 1),
+    s(4, 0, 9, 3), s(4, 0, 9, 4), s(4, 0, 9, 2),
+    s(11, 2, 1, 2), s(0, 3, 0, 2),
+    s(11, 2, 1, 2), s(0, 2, 0, 1),
+    s(11, 0, 9, 4), s(9, 0, 9, 1),
+    s(18, 0, 0, 4), s(18, 0, 10, 4), s(12, 1, 9, 4),
+    s(17, 0, 0, 1), s(0, 1, 0, 2), s(0, 1, 4, 3),
+    s(2, 1, 0, 4), s(2, 1, 0, 1), s(15, 0, 7, 1),
+    s(0, 1, 4, 2), s(0, 1, 1, 2),
+    s(12, 1, 10, 1), s(0, 1, 10, 3), s(0, 1, 10, 2),
+    s(1, 0, 1, 3), s(1, 0, 1, 1), s(2, 0, 1, 2),
+    s(16, 0, 10, 2), s(13, 1, 10, 2), s(15, 0, 10, 4),
+    s(13, 1, 9, 1), s(15, 2, 12, 1),
+    s(4, 2, 0, 1), s(4, 2, 0, 3),
+    s(2, 1, 0, 1), s(1, 2, 0, 4),
+    s(7, 0, 1, 4), s(18, 2, 6, 4),
+    s(14, 1, 10, 1), s(13, 1, 10, 4),
+    s(13, 1, 9, 2), s(11, 0, 9, 2),
+    s(21, 0, 1, 4), s(14, 1, 0, 4),
+    s(16, 0, 7, 1), s(16, 0, 7, 3),
+    s(7, 1, 8, 3), s(16, 0, 8, 3),
+    s(0, 3, 10, 3), s(5, 2, 6, 4),
+    s(2, 1, 0, 1), s(1, 1, 0, 1), s(1, 1, 0, 3),
+    s(9, 2, 0, 1), s(2, 1, 0, 4),
+    s(21, 0, 0, 4), s(20, 0, 0, 2), s(6, 0, 5, 2),
+    s(9, 2, 0, 3), s(11, 2, 0, 2), s(9, 2, 0, 4),
+    s(0, 1, 8, 2), s(15, 0, 8, 4),
+    s(6, 1, 9, 2), s(5, 1, 9, 4),
+    s(12, 1, 1, 3), s(9, 0, 3, 2),
+    s(15, 0, 10, 1), s(15, 0, 10, 4),
+    s(17, 0, 0, 4), s(19, 2, 0, 2),
+    s(15, 0, 0, 2), s(6, 1, 4, 3),
+    s(16, 0, 9, 1), s(13, 1, 9, 2), s(6, 1, 4, 1),
+    s(17, 0, 0, 1), s(0, 1, 0, 2),
+    s(17, 0, 0, 1), s(17, 0, 3, 2), s(0, 1, 0, 2), s(6, 2, 2, 2), s(6, 0, 1, 1),
+    s(1, 0, 7, 4), s(2, 0, 7, 2), s(1, 0, 7, 2),
+    s(1, 1, 0, 4), s(21, 0, 3, 4),
+    s(16, 2, 0, 2), s(19, 2, 0, 1), s(15, 2, 0, 4), s(12, 3, 0, 3), s(16, 0, 1, 2), s(14, 3, 0, 2),
+    s(17, 0, 0, 2), s(19, 2, 0, 2),
+    s(2, 0, 2, 1), s(2, 0, 2, 3),
+    s(13, 1, 9, 2), s(0, 0, 9, 1),
+    s(8, 1, 8, 3), s(3, 0, 7, 3),
+    s(9, 0, 8, 1), s(9, 0, 8, 4), s(13, 3, 0, 2),
+    s(17, 2, 0, 4), s(14, 3, 0, 4),
+    s(14, 1, 0, 3), s(7, 1, 0, 3), s(7, 1, 4, 3),
+    s(14, 1, 0, 3), s(7, 1, 4, 4),
+    s(11, 2, 9, 2), s(17, 0, 10, 2), s(17, 0, 10, 1),
+    s(6, 2, 12, 2), s(15, 2, 2, 2),
+    s(0, 0, 13, 4), s(10, 0, 12, 1), s(0, 0, 13, 3),
+    s(8, 2, 2, 4), s(9, 0, 3, 1), s(9, 0, 3, 4),
+    s(1, 1, 12, 3), s(2, 1, 12, 2),
+    s(12, 1, 1, 1), s(9, 0, 3, 1), s(10, 0, 3, 1), s(11, 0, 1, 1),
+    s(14, 1, 11, 3), s(12, 3, 12, 1),
+    s(0, 3, 0, 4), s(21, 0, 0, 4),
+    s(14, 3, 0, 4), s(11, 2, 6, 4),
+    s(18, 0, 10, 2), s(18, 0, 10, 3),
+    s(13, 3, 12, 2), s(13, 3, 12, 1),
+    s(17, 0, 9, 4), s(17, 2, 2, 4),
+    s(16, 0, 0, 4), s(8, 1, 0, 4),
+    s(14, 1, 9, 3), s(14, 1, 9, 1), s(14, 1, 0, 3),
+    s(14, 1, 12, 2), s(12, 1, 9, 1),
+    s(6, 1, 4, 3), s(0, 1, 0, 2),
+    s(14, 1, 11, 4), s(11, 0, 8, 2),
+    s(0, 1, 7, 2), s(5, 1, 7, 4), s(6, 1, 7, 2), s(13, 1, 7, 1), s(0, 1, 7, 4),
+    s(14, 1, 9, 1), s(6, 1, 9, 3), s(9, 2, 1, 1),
+    s(15, 0, 3, 2), s(7, 1, 4, 4),
+    s(6, 1, 0, 2), s(6, 1, 0, 1),
+    s(11, 2, 12, 4), s(9, 2, 12, 3),
+    s(15, 2, 12, 1), s(0, 3, 12, 1),
+    s(6, 0, 8, 1), s(5, 0, 8, 4), s(6, 2, 0, 4),
+    s(8, 3, 0, 3), s(8, 3, 0, 4),
+    s(3, 0, 6, 2), s(3, 0, 12, 2),
+    s(14, 1, 10, 1), s(19, 0, 0, 3),
+    s(16, 0, 9, 2), s(0, 1, 9, 2),
+    s(5, 1, 12, 4), s(6, 1, 12, 3),
+    s(20, 2, 9, 1), s(12, 1, 9, 1), s(12, 3, 9, 1),
+    s(21, 0, 0, 1), s(6, 2, 2, 2),
+    s(14, 3, 9, 1), s(12, 3, 9, 1), s(12, 3, 9, 4),
+    s(11, 2, 1, 2), s(0, 2, 0, 2), s(11, 2, 0, 2),
+    s(0, 3, 0, 3), s(0, 3, 0, 2), s(0, 2, 0, 2),
+    s(14, 1, 12, 2), s(14, 1, 12, 4), s(12, 1, 12, 1),
+    s(13, 1, 10, 3), s(13, 1, 9, 1), s(13, 1, 10, 1), s(12, 1, 10, 4),
+    s(16, 2, 0, 2), s(12, 3, 0, 3),
+    s(12, 3, 0, 1), s(12, 3, 0, 2),
+    s(6, 1, 4, 3), s(0, 0, 3, 2),
+    s(2, 2, 0, 4), s(2, 2, 0, 1),
+    s(18, 2, 6, 4), s(5, 2, 6, 4), s(0, 3, 4, 4),
+    s(19, 2, 6, 4), s(7, 1, 4, 4),
+    s(0, 3, 9, 3), s(0, 3, 9, 1), s(0, 2, 9, 3), s(0, 2, 9, 1),
+    s(8, 2, 10, 2), s(4, 0, 10, 1),
+    s(9, 2, 2, 3), s(10, 2, 1, 3), s(10, 0, 3, 4),
+    s(2, 0, 8, 2), s(4, 2, 0, 2), s(2, 0, 6, 2),
+    s(19, 2, 6, 4), s(19, 2, 0, 1),
+    s(2, 0, 12, 2), s(1, 0, 12, 4),
+    s(2, 1, 0, 1), s(1, 0, 6, 1), s(1, 1, 0, 1), s(2, 1, 0, 2),
+    s(14, 1, 9, 4), s(9, 0, 9, 4), s(13, 1, 9, 4),
+    s(10, 0, 3, 1), s(0, 0, 1, 1),
+    s(10, 2, 10, 1), s(9, 2, 10, 3),
+    s(7, 0, 6, 4), s(15, 2, 6, 4), s(0, 2, 6, 4),
+    s(16, 2, 10, 2), s(5, 2, 6, 4), s(5, 2, 2, 4),
+    s(6, 0, 9, 2), s(14, 1, 9, 1), s(0, 1, 9, 3),
+    s(13, 1, 0, 2), s(0, 1, 0, 3),
+    s(6, 1, 9, 3), s(6, 2, 10, 3),
+    s(14, 1, 0, 1), s(6, 1, 0, 4),
+    s(6, 0, 7, 2), s(5, 1, 7, 1),
+    s(20, 2, 2, 4), s(20, 2, 0, 4), s(14, 1, 0, 1),
+    s(0, 2, 0, 5),
+    s(0, 2, 9, 4), s(12, 1, 9, 3),
+    s(10, 0, 5, 3), s(12, 1, 4, 1), s(12, 1, 4, 3),
+    s(5, 1, 0, 1), s(16, 0, 0, 2), s(5, 1, 0, 2), s(17, 0, 0, 4),
+    s(8, 1, 9, 4), s(12, 1, 9, 4),
+    s(9, 2, 2, 1), s(9, 2, 2, 3),
+    s(16, 0, 10, 3), s(15, 0, 10, 1),
+    s(17, 0, 0, 1), s(17, 0, 3, 2),
+    s(6, 1, 9, 3), s(7, 2, 2, 4),
+    s(21, 2, 12, 1), s(21, 0, 0, 1),
+    s(5, 2, 2, 3), s(5, 2, 0, 3), s(15, 0, 3, 3),
+    s(21, 2, 2, 3), s(16, 0, 1, 1),
+    s(1, 0, 11, 4), s(2, 0, 11, 1),
+    s(0, 1, 7, 2), s(19, 2, 0, 2),
+    s(0, 1, 4, 4), s(6, 0, 1, 4), s(9, 0, 3, 2),
+    s(7, 0, 8, 4), s(11, 0, 7, 1),
+    s(13, 1, 11, 1), s(16, 0, 12, 1), s(13, 1, 11, 4),
+    s(16, 2, 6, 2), s(5, 2, 6, 1), s(15, 2, 6, 4),
+    s(5, 0, 1, 1), s(21, 0, 1, 4),
+    s(0, 1, 12, 2), s(0, 1, 12, 1), s(12, 3, 12, 3),
+    s(17, 0, 1, 1), s(17, 0, 0, 4), s(21, 0, 3, 4),
+    s(11, 0, 7, 4), s(9, 0, 7, 3),
+    s(15, 0, 10, 4), s(15, 0, 10, 1), s(6, 1, 9, 2),
+    s(8, 0, 11, 2), s(8, 2, 2, 3),
+    s(0, 0, 8, 1), s(10, 0, 8, 1),
+    s(19, 2, 0, 2), s(16, 2, 2, 4),
+    s(14, 3, 9, 4), s(14, 3, 9, 2),
+    s(17, 0, 8, 4), s(21, 0, 8, 1),
+    s(8, 1, 8, 2), s(8, 1, 8, 4), s(8, 1, 7, 2),
+    s(5, 1, 0, 2), s(5, 1, 0, 1),
+    s(21, 0, 9, 3), s(13, 1, 7, 1), s(20, 0, 9, 4),
+    s(8, 2, 0, 4), s(0, 0, 7, 2),
+    s(0, 0, 7, 2), s(1, 1, 7, 1),
+    s(6, 0, 11, 1), s(6, 0, 11, 2),
+    s(15, 0, 11, 5),
+    s(11, 2, 6, 4), s(21, 2, 6, 4), s(18, 2, 6, 4),
+    s(21, 0, 9, 3), s(14, 1, 9, 4), s(21, 0, 1, 4),
+    s(7, 0, 7, 2), s(7, 0, 7, 4),
+    s(5, 2, 6, 4), s(5, 2, 6, 1), s(5, 2, 10, 1),
+    s(5, 2, 6, 1), s(5, 2, 10, 1),
+    s(19, 0, 9, 1), s(6, 1, 0, 4),
+    s(12, 1, 9, 1), s(12, 1, 9, 3),
+    s(13, 1, 7, 1), s(21, 0, 7, 4), s(20, 0, 7, 2),
+    s(12, 1, 9, 4), s(13, 1, 9, 1),
+    s(15, 2, 2, 2), s(17, 2, 0, 3),
+    s(1, 1, 0, 4), s(1, 0, 6, 4),
+    s(6, 1, 4, 3), s(5, 1, 4, 2),
+    s(9, 2, 2, 3), s(9, 2, 2, 1),
+    s(5, 0, 11, 1), s(16, 0, 12, 1), s(6, 0, 11, 1),
+    s(4, 0, 10, 2), s(1, 0, 10, 1),
+    s(7, 1, 4, 4), s(7, 1, 0, 3),
+    s(14, 3, 0, 1), s(18, 2, 0, 2), s(18, 0, 8, 2),
+    s(16, 0, 1, 3),
+    s(17, 2, 2, 4), s(0, 3, 4, 4), s(8, 1, 0, 4),
+    s(14, 1, 9, 5),
+    s(14, 1, 10, 1), s(14, 3, 10, 4),
+    s(0, 1, 7, 4), s(0, 3, 4, 4),
+    s(14, 1, 11, 1), s(18, 0, 11, 2),
+    s(14, 1, 0, 1), s(11, 2, 6, 1),
+    s(20, 2, 9, 1), s(20, 2, 9, 4),
+    s(19, 2, 9, 1), s(19, 2, 9, 4),
+    s(19, 0, 7, 2), s(19, 2, 2, 4), s(19, 2, 0, 2), s(19, 0, 7, 4),
+    s(7, 1, 4, 4), s(0, 1, 0, 3),
+    s(5, 1, 12, 1), s(5, 1, 12, 4),
+    s(17, 0, 9, 1), s(17, 0, 9, 4),
+    s(16, 0, 5, 1),
+    s(6, 0, 8, 3), s(5, 0, 8, 3),
+    s(1, 0, 1, 3), s(2, 0, 1, 2),
+    s(5, 0, 11, 1), s(16, 0, 12, 1),
+    s(11, 0, 1, 1),
+    s(5, 1, 7, 4), s(0, 1, 7, 2),
+    s(0, 2, 0, 2), s(0, 3, 0, 3),
+    s(12, 1, 9, 3), s(12, 1, 9, 4),
+    s(0, 0, 1, 1),
+    s(6, 0, 9, 2), s(14, 1, 9, 1),
+    s(5, 1, 0, 1), s(5, 1, 0, 2),
+    s(16, 0, 9, 2), s(6, 0, 9, 2), s(14, 1, 10, 2),
+    s(5, 2, 6, 4), s(5, 2, 10, 1),
+    s(13, 1, 11, 1), s(13, 1, 11, 3),
+    s(15, 0, 11, 3), s(16, 0, 11, 2), s(15, 0, 11, 4),
+    s(15, 0, 11, 3), s(16, 0, 11, 2),
+    s(17, 2, 9, 1),
+    s(11, 2, 2, 4), s(17, 0, 9, 3),
+    s(11, 0, 9, 4), s(1, 1, 0, 4),
+    s(10, 0, 5, 1), s(13, 1, 9, 1),
+    s(14, 1, 9, 2), s(12, 1, 9, 4), s(12, 1, 9, 1), s(12, 1, 9, 3),
+    s(14, 1, 1, 1), s(14, 1, 1, 3),
+    s(15, 0, 1, 2), s(0, 1, 1, 1), s(9, 0, 3, 1),
+    s(7, 0, 7, 4),
+    s(2, 0, 12, 1), s(2, 0, 12, 4),
+    s(14, 1, 1, 3), s(0, 0, 3, 3),
+    s(1, 1, 9, 4), s(9, 2, 9, 1),
+    s(0, 0, 5, 4), s(11, 0, 3, 2), s(11, 0, 5, 4), s(9, 0, 5, 1), s(10, 0, 5, 3),
+    s(9, 0, 3, 2), s(9, 0, 3, 1), s(11, 0, 3, 2),
+    s(11, 2, 12, 4), s(14, 1, 11, 4),
+    s(21, 0, 3, 1),
+    s(8, 0, 11, 4), s(8, 0, 11, 3), s(8, 1, 11, 3),
+    s(16, 0, 11, 1), s(6, 0, 11, 1),
+    s(17, 0, 5, 4), s(17, 0, 1, 1),
+    s(0, 0, 3, 4), s(0, 3, 0, 4), s(0, 1, 9, 1),
+    s(11, 2, 12, 4), s(14, 1, 11, 4), s(12, 3, 9, 3),
+    s(1, 0, 9, 3), s(2, 0, 9, 3),
+    s(0, 0, 9, 4), s(0, 0, 9, 3), s(0, 0, 9, 1), s(0, 1, 10, 1), s(0, 1, 10, 3),
+    s(13, 3, 4, 4), s(12, 3, 4, 2), s(10, 2, 6, 2),
+    s(5, 2, 0, 1), s(17, 0, 3, 2),
+    s(13, 3, 4, 4), s(13, 3, 4, 1), s(12, 3, 4, 2),
+    s(16, 2, 11, 3), s(16, 0, 10, 4),
+    s(6, 0, 11, 2), s(6, 0, 11, 1), s(16, 0, 11, 1),
+    s(9, 2, 9, 1), s(0, 2, 9, 1), s(0, 2, 9, 3),
+    s(10, 0, 9, 4), s(11, 0, 9, 3), s(14, 1, 9, 4),
+    s(14, 1, 0, 4), s(21, 0, 3, 4), s(6, 0, 1, 4),
+    s(2, 1, 0, 4), s(2, 1, 0, 1),
+    s(0, 0, 3, 4), s(0, 1, 9, 1),
+    s(13, 3, 4, 1), s(13, 3, 4, 4),
+    s(11, 0, 9, 3), s(10, 0, 9, 4),
+    s(0, 2, 0, 4), s(0, 2, 6, 2),
+    s(9, 0, 5, 4), s(0, 1, 0, 4),
+    s(15, 0, 0, 4), s(0, 1, 0, 2), s(6, 2, 2, 2),
+    s(0, 0, 3, 4), s(0, 0, 5, 4),
+    s(10, 0, 12, 1), s(10, 0, 11, 4), s(9, 0, 11, 1),
+    s(0, 3, 10, 3), s(0, 1, 9, 3),
+    s(18, 2, 9, 3), s(0, 3, 9, 2),
+    s(19, 2, 0, 3), s(15, 2, 0, 4),
+    s(5, 1, 9, 4), s(0, 1, 9, 2),
+    s(0, 0, 1, 1), s(0, 0, 3, 1), s(0, 0, 3, 3), s(0, 0, 1, 3), s(0, 0, 1, 4), s(0, 0, 1, 5),
+    s(6, 2, 2, 2), s(15, 0, 0, 4), s(0, 1, 0, 3),
+    s(1, 0, 6, 1), s(2, 1, 0, 2), s(1, 1, 0, 4), s(2, 0, 2, 1),
+    s(4, 2, 0, 4), s(1, 2, 0, 4), s(4, 2, 0, 1),
+    s(8, 2, 0, 4), s(8, 1, 8, 4),
+    s(12, 1, 11, 4), s(14, 1, 11, 2), s(14, 1, 11, 4),
+    s(5, 2, 2, 4), s(21, 2, 6, 2),
+    s(14, 1, 9, 4), s(0, 2, 10, 3),
+    s(14, 1, 12, 2), s(12, 1, 12, 4),
+    s(4, 2, 0, 4), s(0, 2, 0, 3),
+    s(15, 0, 0, 4), s(5, 0, 3, 2),
+    s(2, 2, 0, 1), s(1, 2, 0, 1), s(1, 2, 0, 4),
+    s(16, 2, 0, 2), s(15, 2, 0, 4), s(17, 2, 0, 1),
+    s(4, 0, 6, 4), s(2, 0, 6, 2),
+    s(19, 0, 8, 1), s(15, 0, 3, 2),
+    s(0, 1, 10, 1), s(0, 1, 10, 4), s(0, 0, 9, 1),
+    s(16, 0, 10, 2), s(15, 0, 10, 4),
+    s(15, 2, 0, 3), s(5, 2, 0, 3),
+    s(18, 0, 12, 2), s(0, 0, 13, 2),
+    s(17, 2, 0, 4), s(0, 3, 0, 2), s(0, 1, 7, 2),
+    s(5, 1, 0, 1), s(6, 1, 0, 2),
+    s(8, 2, 12, 2), s(8, 2, 12, 1),
+    s(5, 2, 6, 4), s(15, 2, 6, 4), s(21, 2, 6, 4),
+    s(21, 2, 6, 2), s(5, 2, 2, 4), s(6, 2, 2, 3), s(6, 2, 2, 1),
+    s(9, 0, 5, 4), s(9, 0, 5, 1), s(0, 0, 5, 2), s(13, 1, 0, 2),
+    s(11, 2, 6, 1), s(5, 2, 2, 4),
+    s(9, 0, 3, 2), s(18, 2, 12, 3), s(12, 1, 0, 1),
+    s(10, 2, 6, 2), s(0, 2, 6, 3), s(9, 2, 6, 1),
+    s(0, 0, 5, 4), s(0, 0, 3, 4),
+    s(15, 0, 11, 4), s(15, 0, 11, 1),
+    s(12, 1, 7, 3), s(2, 1, 0, 2),
+    s(21, 2, 6, 4), s(15, 2, 6, 4),
+    s(14, 1, 9, 3), s(12, 1, 9, 3), s(0, 1, 9, 2),
+    s(14, 1, 0, 2), s(14, 1, 4, 4),
+    s(8, 1, 0, 4), s(5, 0, 5, 4), s(0, 1, 0, 4), s(5, 1, 0, 4),
+    s(15, 2, 6, 1), s(20, 2, 6, 1), s(0, 2, 6, 2),
+    s(11, 2, 0, 2), s(11, 0, 3, 4), s(13, 3, 4, 4),
+    s(15, 0, 0, 1), s(11, 2, 2, 4),
+    s(13, 3, 4, 4), s(13, 1, 7, 1), s(13, 1, 7, 3),
+    s(13, 1, 9, 1), s(12, 1, 4, 4),
+    s(0, 1, 1, 3), s(0, 1, 1, 1), s(0, 1, 1, 2),
+    s(9, 2, 0, 4), s(11, 2, 0, 4),
+    s(15, 0, 0, 4), s(10, 0, 5, 3), s(0, 1, 0, 3), s(21, 0, 0, 4),
+    s(12, 3, 9, 4), s(12, 3, 10, 4), s(19, 2, 6, 4),
+    s(9, 2, 9, 4), s(11, 2, 9, 2),
+    s(16, 2, 0, 2), s(12, 3, 0, 2), s(12, 3, 0, 4),
+    s(8, 1, 0, 2), s(8, 1, 0, 4), s(8, 1, 0, 3), s(16, 0, 0, 1), s(9, 2, 0, 3),
+    s(7, 0, 9, 2), s(7, 0, 9, 4), s(7, 2, 2, 2),
+    s(0, 3, 0, 2), s(0, 3, 0, 4), s(14, 3, 0, 1),
+    s(21, 0, 3, 4), s(14, 1, 0, 2),
+    s(2, 0, 11, 1), s(2, 0, 11, 2), s(4, 0, 11, 1),
+    s(0, 0, 9, 2),
+    s(0, 2, 0, 4), s(3, 0, 12, 2),
+    s(11, 2, 0, 4), s(11, 2, 0, 1),
+    s(14, 3, 0, 1), s(7, 2, 2, 4), s(18, 2, 0, 2), s(18, 2, 9, 3),
+    s(15, 0, 1, 4), s(15, 0, 1, 2), s(17, 0, 1, 4), s(21, 0, 1, 4), s(0, 1, 0, 4),
+    s(15, 0, 10, 4), s(17, 0, 10, 1),
+    s(11, 2, 2, 4), s(11, 0, 3, 4), s(21, 2, 2, 3),
+    s(6, 0, 12, 4),
+    s(14, 1, 9, 4), s(21, 0, 9, 3),
+    s(8, 2, 0, 4), s(8, 0, 8, 4),
+    s(1, 0, 1, 4), s(2, 0, 2, 4),
+    s(3, 0, 5, 2), s(8, 1, 0, 2),
+    s(3, 0, 12, 2), s(3, 0, 7, 4), s(0, 2, 0, 4),
+    s(11, 2, 2, 4), s(21, 2, 6, 3), s(21, 2, 2, 3),
+    s(8, 2, 12, 2), s(8, 1, 12, 2),
+    s(0, 0, 5, 4), s(0, 1, 0, 3),
+    s(13, 1, 12, 4), s(13, 1, 12, 1), s(12, 1, 12, 4),
+    s(12, 1, 12, 4), s(8, 1, 11, 2),
+    s(4, 0, 6, 1), s(4, 0, 6, 3),
+    s(3, 1, 0, 2), s(3, 1, 0, 3), s(3, 0, 1, 2),
+    s(6, 1, 9, 3), s(3, 1, 9, 3),
+    s(0, 1, 4, 4), s(0, 1, 9, 3),
+    s(9, 0, 3, 2), s(12, 1, 0, 2),
+    s(13, 1, 9, 2), s(12, 1, 9, 1), s(10, 0, 9, 1), s(11, 0, 9, 4),
+    s(1, 0, 1, 3), s(1, 0, 1, 4),
+    s(21, 0, 1, 3), s(6, 0, 1, 1),
+    s(1, 0, 6, 4), s(1, 0, 5, 4), s(1, 1, 0, 4),
+    s(0, 1, 11, 1), s(0, 1, 11, 4), s(0, 1, 11, 3),
+    s(9, 0, 3, 2), s(21, 0, 1, 3), s(6, 0, 1, 4),
+    s(14, 1, 4, 2), s(0, 2, 1, 1),
+    s(0, 1, 12, 4), s(1, 1, 9, 1),
+    s(13, 1, 7, 4), s(17, 0, 7, 1),
+    s(14, 3, 9, 4), s(12, 3, 9, 1),
+    s(17, 0, 11, 4), s(15, 0, 11, 3),
+    s(1, 1, 12, 3), s(1, 1, 0, 4), s(2, 1, 0, 2), s(1, 0, 6, 1),
+    s(12, 3, 0, 1), s(13, 3, 0, 1), s(13, 3, 12, 1),
+    s(8, 0, 1, 5),
+    s(14, 1, 4, 4), s(15, 0, 1, 2), s(5, 1, 4, 2),
+    s(0, 0, 12, 1),
+    s(11, 0, 3, 2), s(17, 0, 3, 2), s(3, 0, 2, 4),
+    s(3, 2, 0, 4), s(3, 0, 8, 2),
+    s(12, 3, 0, 1), s(13, 3, 0, 1),
+    s(1, 0, 6, 4), s(4, 2, 0, 2), s(1, 2, 0, 4), s(1, 0, 5, 4),
+    s(1, 1, 0, 4), s(1, 1, 12, 3),
+    s(13, 1, 7, 2), s(13, 1, 7, 1), s(12, 3, 4, 1),
+    s(13, 3, 9, 4), s(12, 3, 9, 4),
+    s(18, 0, 8, 3),
+    s(1, 0, 5, 4), s(4, 2, 0, 2),
+    s(6, 0, 7, 1), s(6, 0, 7, 4),
+    s(0, 2, 6, 3), s(14, 3, 4, 1),
+    s(21, 2, 6, 4), s(11, 2, 6, 4),
+    s(11, 2, 11, 2), s(0, 1, 12, 1),
+    s(13, 1, 12, 3), s(13, 1, 12, 1), s(10, 2, 6, 3),
+    s(11, 0, 9, 1), s(0, 0, 9, 4),
+    s(21, 2, 12, 4), s(18, 2, 12, 2),
+    s(13, 1, 0, 2), s(10, 0, 10, 3),
+    s(11, 0, 11, 2), s(9, 0, 11, 1), s(11, 0, 11, 4),
+    s(0, 2, 9, 2), s(10, 2, 10, 1),
+    s(1, 0, 9, 1), s(4, 0, 10, 2),
+    s(5, 2, 10, 4), s(5, 2, 0, 2),
+    s(5, 0, 9, 1), s(5, 1, 9, 4),
+    s(2, 0, 2, 1), s(2, 0, 2, 3), s(2, 0, 2, 4), s(2, 1, 0, 2),
+    s(11, 0, 3, 2), s(11, 0, 9, 2), s(13, 1, 10, 1), s(9, 0, 3, 2),
+    s(14, 1, 4, 2), s(12, 1, 1, 2), s(12, 1, 4, 2),
+    s(11, 0, 5, 2), s(10, 0, 3, 1), s(10, 0, 3, 2),
+    s(5, 2, 6, 3), s(5, 2, 6, 1),
+    s(0, 3, 0, 3), s(1, 1, 9, 4),
+    s(2, 1, 12, 1), s(2, 1, 12, 3),
+    s(4, 2, 0, 3), s(6, 0, 7, 1), s(6, 1, 7, 4),
+    s(8, 2, 2, 1),
+    s(0, 1, 12, 3), s(12, 1, 12, 3),
+    s(12, 1, 12, 3), s(9, 0, 12, 3),
+    s(2, 1, 10, 2), s(1, 1, 10, 1),
+    s(5, 1, 12, 4), s(5, 1, 12, 3),
+    s(6, 0, 9, 2), s(17, 0, 9, 3),
+    s(10, 0, 3, 1), s(10, 0, 3, 3), s(10, 2, 9, 3),
+    s(14, 3, 9, 3), s(12, 3, 12, 1), s(12, 3, 12, 3), s(14, 1, 9, 4),
+    s(0, 2, 10, 4), s(11, 2, 10, 2), s(11, 2, 10, 4),
+    s(10, 0, 9, 3), s(0, 1, 9, 4),
+    s(0, 1, 9, 2), s(0, 1, 1, 2),
+    s(0, 3, 9, 4), s(0, 3, 9, 3),
+    s(5, 1, 9, 1), s(6, 1, 9, 2), s(6, 1, 9, 4),
+    s(10, 2, 6, 1), s(10, 2, 1, 3),
+    s(0, 2, 5, 4), s(15, 2, 5, 4),
+    s(0, 1, 7, 2), s(13, 1, 7, 4),
+    s(0, 1, 9, 3), s(13, 1, 10, 4), s(11, 0, 9, 4), s(13, 1, 9, 3),
+    s(16, 0, 9, 4), s(15, 0, 9, 4), s(17, 0, 9, 1),
+    s(8, 0, 9, 3), s(8, 1, 10, 3),
+    s(2, 0, 2, 3), s(2, 0, 2, 1),
+    s(12, 1, 4, 2), s(14, 1, 4, 2),
+    s(10, 0, 3, 1), s(10, 0, 3, 2),
+    s(3, 0, 9, 1),
+    s(16, 0, 9, 4), s(15, 0, 9, 4),
+    s(4, 0, 12, 1), s(4, 0, 12, 4), s(4, 0, 12, 3),
+    s(1, 1, 7, 1), s(5, 1, 8, 1),
+    s(1, 1, 7, 1), s(2, 0, 7, 2),
+    s(21, 0, 1, 4), s(8, 1, 0, 4),
+    s(1, 0, 1, 2), s(4, 2, 0, 2),
+    s(9, 2, 1, 1), s(12, 1, 0, 3),
+    s(8, 1, 8, 4), s(8, 1, 7, 2),
+    s(17, 0, 0, 2), s(21, 0, 0, 4), s(0, 1, 0, 4),
+    s(15, 0, 9, 1), s(9, 0, 9, 1),
+    s(6, 2, 10, 2), s(15, 2, 10, 4),
+    s(12, 3, 9, 4), s(0, 3, 12, 3),
+    s(16, 0, 0, 4), s(17, 0, 0, 4),
+    s(21, 2, 10, 1), s(20, 0, 9, 1),
+    s(6, 0, 8, 3), s(15, 2, 0, 4),
+    s(0, 1, 0, 2), s(21, 0, 0, 4),
+    s(17, 0, 0, 4), s(16, 0, 0, 4),
+    s(6, 1, 9, 3), s(6, 1, 9, 2),
+    s(12, 1, 7, 3), s(12, 1, 7, 4),
+    s(12, 1, 1, 2), s(11, 0, 3, 2),
+    s(0, 1, 11, 3), s(0, 1, 11, 4),
+    s(8, 0, 3, 5),
+    s(20, 0, 9, 1), s(21, 2, 10, 1),
+    s(1, 2, 0, 4), s(1, 2, 0, 1),
+    s(0, 3, 0, 2), s(0, 1, 4, 2),
+    s(17, 0, 0, 4), s(14, 1, 0, 1),
+    s(11, 2, 10, 2), s(10, 2, 10, 1),
+    s(14, 1, 9, 4), s(10, 0, 9, 4),
+    s(0, 2, 6, 4), s(7, 0, 6, 3),
+    s(7, 2, 9, 3), s(7, 2, 9, 4),
+    s(0, 3, 10, 4), s(11, 2, 10, 2),
+    s(12, 1, 9, 1), s(15, 0, 9, 1),
+    s(0, 0, 5, 4), s(11, 0, 3, 2),
+    s(6, 0, 11, 2), s(14, 1, 12, 2),
+    s(8, 1, 8, 4), s(8, 1, 8, 2),
+    s(6, 0, 7, 2), s(6, 0, 7, 1), s(14, 1, 9, 4),
+    s(10, 2, 6, 4), s(6, 2, 6, 2),
+    s(15, 2, 9, 4), s(14, 3, 9, 3),
+    s(0, 1, 0, 4), s(0, 1, 4, 1), s(0, 0, 10, 4),
+    s(7, 0, 11, 2), s(7, 0, 11, 3),
+    s(8, 0, 3, 5), s(9, 0, 3, 1),
+    s(16, 0, 1, 1), s(15, 0, 1, 5),
+    s(10, 2, 6, 2), s(13, 1, 8, 2),
+    s(4, 2, 0, 4), s(1, 1, 0, 4),
+    s(0, 3, 10, 1), s(0, 2, 2, 4),
+    s(11, 0, 9, 4), s(13, 1, 9, 2), s(11, 0, 9, 2),
+    s(6, 2, 2, 2), s(5, 2, 2, 4), s(5, 0, 5, 4),
+    s(15, 0, 3, 2), s(6, 2, 2, 1),
+    s(14, 1, 10, 4), s(12, 1, 10, 4),
+    s(5, 0, 9, 4), s(5, 0, 9, 3),
+    s(2, 0, 6, 2), s(2, 0, 12, 1),
+    s(19, 0, 11, 3), s(19, 2, 0, 4), s(19, 2, 0, 3),
+    s(12, 1, 1, 4), s(12, 1, 1, 1),
+    s(6, 0, 5, 2), s(5, 0, 5, 4), s(15, 0, 5, 4), s(6, 0, 5, 1),
+    s(14, 3, 10, 1), s(14, 3, 9, 4),
+    s(8, 2, 2, 4), s(12, 1, 1, 4),
+    s(0, 0, 3, 3), s(0, 0, 3, 2),
+    s(0, 0, 5, 2), s(21, 0, 0, 4), s(6, 0, 5, 3),
+    s(15, 0, 8, 1), s(5, 2, 12, 4),
+    s(10, 2, 6, 2), s(12, 3, 4, 2),
+    s(9, 2, 6, 1), s(6, 2, 6, 2),
+    s(13, 1, 9, 1), s(12, 1, 9, 3),
+    s(2, 0, 12, 2), s(1, 0, 11, 3),
+    s(19, 0, 8, 1), s(15, 2, 0, 1), s(15, 0, 8, 4), s(13, 3, 0, 1),
+    s(21, 0, 7, 1), s(21, 0, 7, 3), s(14, 1, 7, 1),
+    s(3, 0, 2, 4), s(3, 0, 1, 4),
+    s(0, 0, 7, 4), s(0, 1, 7, 4),
+    s(1, 1, 7, 1), s(2, 1, 7, 4),
+    s(12, 1, 7, 1), s(14, 1, 7, 1), s(12, 3, 0, 1), s(13, 1, 7, 2),
+    s(8, 2, 12, 2), s(19, 0, 11, 3),
+    s(19, 0, 11, 3),
+    s(5, 0, 5, 4), s(6, 0, 5, 2),
+    s(9, 2, 0, 3), s(9, 2, 0, 1), s(9, 2, 0, 2),
+    s(0, 2, 6, 3), s(0, 2, 9, 2),
+    s(0, 0, 11, 1), s(10, 0, 11, 3),
+    s(6, 0, 8, 2), s(9, 2, 0, 3),
+    s(12, 1, 4, 4), s(12, 1, 1, 2), s(14, 1, 4, 4),
+    s(20, 0, 0, 1), s(15, 0, 5, 4),
+    s(11, 0, 5, 2), s(9, 0, 5, 1),
+    s(13, 1, 7, 1), s(12, 1, 7, 1), s(14, 1, 7, 1),
+    s(10, 0, 3, 1), s(10, 2, 1, 4),
+    s(13, 1, 1, 4), s(9, 0, 3, 2),
+    s(1, 0, 11, 3), s(2, 0, 11, 2), s(2, 0, 11, 3),
+    s(1, 0, 2, 2), s(12, 3, 4, 2),
+    s(20, 0, 0, 1), s(20, 2, 2, 1),
+    s(19, 0, 11, 1), s(19, 0, 11, 3),
+    s(6, 1, 0, 3), s(6, 1, 0, 1),
+    s(1, 1, 7, 1), s(2, 1, 7, 4), s(17, 0, 9, 1),
+    s(4, 0, 1, 4), s(4, 0, 1, 3),
+    s(3, 0, 7, 2), s(18, 0, 8, 2), s(3, 0, 12, 2),
+    s(18, 2, 12, 2), s(0, 0, 13, 4),
+    s(10, 2, 2, 4), s(0, 3, 4, 4),
+    s(12, 1, 0, 4), s(12, 1, 4, 2),
+    s(17, 0, 7, 1), s(17, 0, 7, 3), s(17, 0, 7, 4),
+    s(21, 2, 12, 1), s(21, 2, 12, 4), s(21, 2, 12, 2),
+    s(6, 2, 2, 3), s(16, 2, 6, 2), s(5, 2, 2, 3),
+    s(21, 0, 9, 1), s(21, 0, 9, 4),
+    s(7, 0, 11, 2), s(7, 0, 11, 4),
+    s(10, 2, 2, 4), s(10, 2, 5, 4),
+    s(18, 0, 11, 2), s(7, 1, 12, 2),
+    s(14, 1, 0, 4), s(11, 0, 3, 4),
+    s(9, 0, 3, 2), s(8, 1, 0, 4), s(0, 0, 3, 4),
+    s(4, 2, 0, 3), s(8, 1, 0, 4),
+    s(13, 1, 10, 2), s(14, 1, 10, 2),
+    s(19, 2, 12, 1), s(19, 0, 12, 3),
+    s(9, 2, 6, 1), s(14, 1, 4, 2),
+    s(0, 3, 0, 4), s(15, 0, 8, 1), s(12, 3, 0, 1),
+    s(10, 2, 6, 2), s(10, 2, 6, 3), s(10, 2, 5, 4),
+    s(2, 0, 2, 4), s(1, 0, 2, 2), s(6, 2, 2, 4),
+    s(6, 2, 6, 2), s(16, 2, 6, 2),
+    s(0, 2, 6, 4), s(0, 2, 6, 2), s(0, 2, 6, 1),
+    s(16, 0, 8, 3), s(16, 0, 8, 2),
+    s(5, 1, 7, 4), s(5, 1, 0, 2),
+    s(14, 3, 0, 4), s(0, 3, 0, 2),
+    s(0, 0, 3, 2), s(11, 2, 1, 4),
+    s(0, 0, 3, 4), s(13, 1, 4, 4), s(12, 1, 0, 4),
+    s(17, 0, 1, 1), s(21, 2, 2, 1),
+    s(0, 3, 9, 2), s(0, 2, 9, 3),
+    s(8, 2, 0, 3), s(8, 3, 0, 3),
+    s(11, 2, 0, 2), s(11, 2, 0, 4),
+    s(19, 0, 1, 1), s(17, 0, 0, 1),
+    s(14, 1, 1, 2), s(14, 1, 1, 1),
+    s(13, 3, 0, 1), s(14, 1, 4, 2),
+    s(11, 0, 9, 2), s(11, 0, 9, 1),
+    s(8, 1, 12, 2), s(8, 1, 10, 2),
+    s(1, 0, 2, 1), s(1, 0, 1, 4),
+    s(20, 0, 0, 3), s(12, 1, 0, 4),
+    s(12, 3, 0, 1), s(13, 3, 0, 2), s(9, 0, 8, 3),
+    s(1, 0, 1, 4), s(1, 0, 2, 2),
+    s(0, 1, 8, 2), s(16, 0, 8, 2),
+    s(2, 1, 0, 1), s(2, 1, 0, 2), s(12, 3, 0, 4),
+    s(14, 1, 12, 1), s(15, 0, 12, 1),
+    s(1, 0, 7, 4), s(1, 0, 7, 1), s(2, 0, 7, 1),
+    s(15, 0, 1, 3), s(15, 0, 1, 4),
+    s(5, 0, 5, 5),
+    s(14, 1, 11, 3), s(15, 0, 10, 4),
+    s(0, 0, 9, 4), s(0, 0, 9, 1),
+    s(6, 1, 0, 3), s(0, 1, 0, 2),
+    s(0, 1, 0, 4), s(13, 1, 0, 2),
+    s(1, 1, 12, 4), s(1, 1, 0, 4),
+    s(10, 2, 0, 1), s(10, 2, 0, 4),
+    s(9, 2, 6, 1), s(14, 1, 4, 2), s(11, 2, 1, 4), s(0, 2, 1, 1), s(10, 2, 6, 2),
+    s(14, 1, 9, 1), s(14, 1, 9, 3), s(14, 1, 9, 4),
+    s(17, 0, 9, 1), s(17, 0, 10, 3),
+    s(2, 2, 0, 1), s(1, 2, 0, 1),
+    s(6, 1, 7, 2), s(0, 1, 8, 2), s(16, 0, 8, 2),
+    s(15, 0, 12, 1), s(13, 1, 12, 1),
+    s(4, 0, 6, 4), s(4, 0, 6, 1),
+    s(12, 1, 8, 4), s(0, 0, 5, 3),
+    s(12, 1, 12, 1), s(13, 1, 12, 2),
+    s(19, 0, 6, 2), s(12, 1, 0, 4),
+    s(19, 0, 6, 2), s(19, 0, 3, 2),
+    s(5, 1, 4, 2), s(13, 1, 4, 4), s(15, 0, 1, 2),
+    s(21, 0, 5, 1), s(14, 1, 0, 2),
+    s(9, 0, 9, 3), s(12, 1, 9, 1), s(14, 1, 9, 2),
+    s(6, 2, 2, 3), s(0, 2, 6, 3),
+    s(9, 2, 9, 1), s(9, 2, 9, 4), s(10, 2, 10, 1), s(9, 2, 10, 3),
+    s(6, 0, 1, 3), s(5, 1, 4, 2),
+    s(0, 0, 3, 2), s(9, 0, 3, 2),
+    s(17, 0, 10, 1), s(21, 0, 7, 1), s(20, 0, 9, 1),
+    s(14, 1, 8, 1), s(13, 1, 8, 1),
+    s(15, 2, 9, 1), s(6, 2, 9, 2), s(8, 1, 9, 4),
+    s(19, 2, 10, 1), s(19, 2, 10, 4),
+    s(17, 0, 9, 4), s(6, 2, 2, 2),
+    s(0, 1, 12, 4), s(17, 0, 12, 2), s(3, 0, 12, 3),
+    s(8, 1, 0, 4), s(8, 2, 0, 4), s(8, 2, 2, 4),
+    s(8, 1, 0, 2), s(8, 1, 0, 3),
+    s(1, 0, 1, 4), s(1, 0, 2, 1),
+    s(7, 1, 7, 3), s(5, 1, 7, 3), s(5, 0, 7, 3), s(13, 3, 4, 4),
+    s(12, 1, 8, 1), s(13, 1, 8, 2), s(15, 0, 0, 4),
+    s(5, 1, 7, 1), s(15, 0, 7, 1),
+    s(9, 0, 9, 1), s(11, 0, 9, 4), s(0, 1, 9, 4),
+    s(15, 0, 0, 1), s(16, 0, 0, 4),
+    s(4, 2, 0, 1), s(9, 2, 6, 1),
+    s(12, 1, 9, 1), s(13, 1, 9, 1), s(15, 0, 9, 1),
+    s(0, 1, 7, 1), s(0, 0, 7, 3),
+    s(12, 3, 4, 2), s(9, 2, 6, 1),
+    s(0, 1, 1, 1), s(0, 1, 1, 3),
+    s(4, 0, 11, 1), s(4, 0, 11, 3),
+    s(3, 1, 4, 4), s(1, 1, 0, 4),
+    s(3, 1, 10, 2), s(0, 2, 10, 2),
+    s(13, 3, 0, 2), s(9, 0, 8, 1), s(9, 0, 8, 4),
+    s(0, 1, 7, 3), s(0, 0, 7, 1),
+    s(6, 1, 4, 3), s(11, 2, 0, 2),
+    s(0, 1, 9, 4), s(0, 0, 9, 1), s(0, 0, 3, 4),
+    s(12, 1, 7, 1), s(14, 1, 7, 1),
+    s(15, 0, 8, 1), s(5, 1, 7, 3),
+    s(11, 2, 9, 1), s(12, 3, 9, 1), s(9, 2, 9, 4),
+    s(1, 2, 0, 3), s(1, 2, 0, 1), s(2, 2, 0, 1), s(2, 2, 0, 2),
+    s(11, 2, 0, 2), s(9, 2, 0, 3), s(11, 0, 3, 4),
+    s(8, 0, 5, 4), s(16, 0, 0, 4),
+    s(0, 0, 9, 1), s(0, 1, 1, 1),
+    s(2, 0, 12, 2), s(4, 0, 12, 4),
+    s(12, 1, 12, 1), s(13, 1, 12, 1),
+    s(12, 3, 4, 2), s(13, 3, 0, 1),
+    s(12, 1, 4, 1), s(12, 1, 4, 4),
+    s(2, 1, 9, 1), s(1, 1, 9, 3),
+    s(16, 2, 9, 4), s(15, 0, 0, 4),
+    s(0, 2, 0, 4), s(3, 2, 0, 4),
+    s(20, 0, 11, 1), s(13, 1, 11, 1),
+    s(11, 0, 3, 4), s(11, 2, 0, 2),
+    s(9, 2, 0, 2), s(11, 2, 0, 2),
+    s(21, 2, 10, 3), s(14, 3, 10, 4),
+    s(0, 1, 7, 4), s(0, 1, 7, 2),
+    s(6, 1, 0, 1), s(21, 0, 0, 1),
+    s(0, 1, 7, 3), s(14, 1, 7, 4),
+    s(15, 0, 0, 4), s(15, 0, 3, 2),
+    s(8, 3, 0, 2), s(8, 3, 0, 3),
+    s(17, 2, 11, 1), s(17, 2, 11, 3),
+    s(6, 1, 0, 2), s(6, 0, 9, 2),
+    s(0, 3, 0, 4), s(17, 2, 0, 4),
+    s(16, 2, 0, 3), s(15, 2, 0, 2), s(16, 2, 0, 4),
+    s(9, 2, 9, 4), s(11, 2, 9, 1), s(13, 3, 9, 2),
+    s(7, 1, 7, 3), s(5, 1, 7, 3),
+    s(8, 2, 0, 3), s(8, 2, 0, 2),
+    s(8, 2, 0, 4), s(8, 3, 0, 2),
+    s(2, 0, 7, 2), s(1, 1, 7, 1), s(2, 1, 7, 3),
+    s(15, 2, 0, 4), s(20, 2, 0, 1),
+    s(12, 3, 10, 1), s(13, 3, 10, 2),
+    s(8, 1, 0, 4), s(8, 1, 0, 2), s(8, 1, 0, 3), s(21, 0, 0, 1),
+    s(14, 1, 9, 2), s(0, 1, 9, 2),
+    s(14, 1, 9, 4), s(0, 1, 9, 4),
+    s(16, 0, 0, 5),
+    s(3, 0, 1, 2), s(3, 0, 1, 1),
+    s(3, 0, 2, 2), s(3, 0, 1, 2), s(3, 0, 1, 5), s(3, 0, 3, 5),
+    s(7, 2, 10, 2),
+    s(13, 1, 9, 2), s(12, 1, 9, 1),
+    s(0, 1, 8, 3), s(0, 1, 0, 1),
+    s(5, 1, 9, 3), s(15, 0, 9, 1), s(5, 2, 2, 4),
+    s(0, 3, 4, 4), s(0, 1, 4, 4),
+    s(8, 1, 0, 2), s(8, 0, 5, 2),
+    s(5, 0, 11, 3), s(6, 0, 11, 3), s(16, 0, 12, 4),
+    s(0, 1, 9, 2), s(0, 1, 9, 3), s(12, 1, 9, 1),
+    s(5, 0, 9, 3), s(6, 0, 9, 4), s(15, 0, 10, 4), s(17, 0, 10, 4),
+    s(15, 0, 10, 3), s(0, 1, 9, 1),
+    s(5, 0, 5, 4), s(15, 0, 10, 4),
+    s(0, 1, 0, 1), s(0, 2, 1, 1),
+    s(15, 0, 0, 3), s(14, 1, 9, 4),
+    s(3, 1, 9, 3), s(3, 0, 12, 3), s(3, 1, 10, 3), s(3, 0, 12, 2),
+    s(3, 1, 10, 3), s(3, 1, 9, 3), s(3, 0, 12, 3),
+    s(5, 1, 12, 3), s(15, 0, 10, 1),
+    s(5, 2, 12, 1), s(6, 2, 12, 2),
+    s(13, 1, 0, 4), s(20, 0, 7, 4),
+    s(6, 0, 12, 1),
+    s(12, 3, 0, 2), s(14, 1, 0, 2),
+    s(0, 2, 0, 4), s(11, 2, 6, 1),
+    s(11, 0, 8, 1), s(10, 2, 0, 4),
+    s(0, 0, 3, 4), s(11, 0, 3, 4),
+    s(13, 1, 0, 2), s(12, 1, 0, 1), s(12, 1, 0, 4), s(19, 0, 0, 1), s(15, 0, 5, 1), s(12, 1, 9, 3),
+    s(0, 1, 10, 2), s(0, 1, 10, 3), s(0, 1, 9, 3),
+    s(14, 1, 4, 4), s(17, 0, 0, 4),
+    s(16, 0, 5, 2), s(19, 0, 0, 1),
+    s(12, 3, 0, 3), s(15, 0, 1, 1),
+    s(16, 2, 0, 1), s(16, 0, 0, 3),
+    s(10, 0, 10, 3), s(13, 1, 9, 3), s(0, 1, 10, 2), s(10, 2, 10, 3),
+    s(19, 0, 3, 2), s(20, 0, 3, 4), s(15, 0, 1, 4),
+    s(19, 0, 8, 1), s(16, 2, 2, 4),
+    s(0, 0, 8, 2), s(0, 3, 0, 2),
+    s(20, 0, 0, 1), s(20, 2, 2, 2),
+    s(0, 1, 1, 4), s(0, 0, 3, 4),
+    s(8, 2, 12, 2), s(3, 0, 11, 2),
+    s(9, 2, 12, 1), s(0, 2, 2, 4),
+    s(2, 0, 11, 2), s(8, 2, 12, 2),
+    s(8, 2, 12, 3), s(8, 2, 12, 2),
+    s(10, 0, 9, 1), s(10, 0, 3, 4),
+    s(9, 2, 6, 1), s(13, 1, 8, 1), s(12, 3, 10, 1),
+    s(9, 2, 6, 1), s(12, 3, 10, 1), s(13, 1, 8, 1),
+    s(19, 2, 2, 3), s(0, 1, 8, 3),
+    s(15, 2, 9, 1), s(15, 2, 9, 2), s(16, 2, 9, 3), s(16, 2, 10, 2),
+    s(13, 1, 4, 3), s(12, 1, 0, 1),
+    s(13, 1, 9, 2), s(14, 1, 1, 4),
+    s(17, 0, 0, 4), s(11, 0, 3, 4),
+    s(12, 1, 4, 2), s(6, 1, 9, 3),
+    s(12, 3, 4, 2), s(15, 2, 6, 4),
+    s(16, 2, 6, 2), s(17, 0, 1, 1),
+    s(0, 1, 1, 4), s(3, 0, 2, 3),
+    s(5, 1, 4, 4),
+    s(9, 2, 1, 3), s(15, 2, 1, 3),
+    s(7, 1, 4, 3),
+    s(0, 3, 0, 4), s(14, 1, 9, 2),
+    s(14, 3, 10, 4), s(13, 3, 12, 2),
+    s(21, 0, 8, 3), s(15, 0, 8, 4),
+    s(7, 2, 12, 4), s(18, 2, 12, 4),
+    s(14, 1, 9, 1), s(8, 1, 12, 2),
+    s(1, 1, 0, 3), s(1, 0, 1, 4),
+    s(9, 2, 6, 4), s(9, 2, 6, 1),
+    s(7, 0, 6, 2),
+    s(7, 1, 7, 3), s(7, 1, 0, 2),
+    s(11, 2, 6, 1), s(5, 0, 9, 1),
+    s(1, 1, 0, 4), s(16, 0, 12, 2),
+    s(12, 1, 0, 1), s(14, 1, 10, 4),
+    s(6, 1, 7, 1), s(5, 1, 7, 1),
+    s(0, 1, 9, 1), s(0, 3, 9, 1),
+    s(8, 1, 4, 4), s(19, 0, 1, 1),
+    s(10, 0, 3, 4), s(11, 0, 3, 2),
+    s(15, 0, 1, 2), s(15, 0, 3, 2),
+    s(15, 0, 3, 2), s(15, 0, 1, 2),
+    s(6, 1, 0, 1), s(16, 0, 0, 3),
+    s(16, 0, 1, 1), s(16, 0, 5, 1), s(16, 0, 1, 2),
+    s(16, 0, 0, 4), s(17, 2, 5, 4),
+    s(8, 0, 8, 2), s(8, 0, 8, 4),
+    s(15, 0, 7, 4), s(18, 0, 0, 4), s(15, 0, 0, 4),
+    s(15, 0, 9, 3), s(16, 0, 9, 4),
+    s(4, 2, 0, 3), s(2, 0, 8, 3),
+    s(19, 0, 3, 2), s(1, 0, 5, 4),
+    s(1, 1, 0, 4), s(4, 2, 0, 2),
+    s(5, 2, 6, 3),
+    s(8, 1, 0, 4), s(12, 1, 0, 2),
+    s(10, 0, 5, 3), s(14, 1, 4, 4),
+    s(0, 1, 0, 2), s(14, 1, 9, 2),
+    s(20, 2, 12, 2), s(14, 3, 9, 2),
+    s(14, 1, 0, 4), s(17, 2, 0, 4),
+    s(18, 0, 12, 2), s(7, 0, 5, 3),
+    s(6, 1, 7, 2), s(0, 1, 8, 3),
+    s(0, 3, 4, 4), s(12, 3, 0, 2),
+    s(0, 1, 10, 2), s(0, 0, 9, 3), s(10, 0, 9, 3),
+    s(7, 1, 9, 2), s(17, 0, 0, 4),
+    s(14, 1, 12, 1), s(7, 1, 9, 2),
+    s(0, 1, 1, 4), s(0, 1, 10, 1),
+    s(9, 2, 1, 3), s(1, 0, 5, 3),
+    s(3, 0, 11, 4),
+    s(21, 0, 1, 5), s(21, 0, 9, 5),
+    s(11, 0, 8, 1), s(14, 3, 0, 3),
+    s(5, 0, 5, 1), s(5, 0, 5, 3), s(0, 0, 3, 4),
+    s(11, 2, 0, 4), s(0, 2, 10, 3),
+    s(11, 2, 0, 4), s(9, 0, 7, 4),
+    s(0, 2, 5, 4), s(0, 2, 5, 5),
+    s(15, 0, 8, 1), s(0, 3, 0, 4), s(12, 1, 0, 4), s(20, 2, 0, 4),
+    s(21, 0, 1, 4), s(17, 0, 5, 4),
+    s(0, 0, 3, 4), s(11, 2, 6, 4), s(19, 0, 1, 2),
+    s(4, 0, 1, 5), s(4, 0, 6, 4), s(4, 0, 1, 2), s(0, 2, 1, 5),
+    s(12, 1, 0, 4), s(20, 2, 0, 4), s(0, 3, 0, 4), s(15, 2, 0, 4),
+    s(15, 0, 10, 2), s(16, 2, 10, 2),
+    s(5, 0, 3, 5),
+    s(0, 0, 11, 1),
+    s(11, 0, 9, 1), s(3, 1, 0, 2),
+    s(7, 1, 4, 4), s(19, 0, 1, 2),
+    s(18, 2, 10, 3),
+    s(17, 0, 7, 1), s(21, 2, 0, 4), s(17, 0, 8, 4),
+    s(8, 3, 0, 4), s(8, 1, 4, 4),
+    s(12, 1, 0, 1), s(19, 0, 3, 2),
+    s(16, 0, 8, 2), s(17, 0, 8, 4),
+    s(12, 1, 12, 4), s(12, 1, 11, 4),
+    s(0, 0, 3, 2), s(0, 3, 12, 2),
+    s(19, 0, 1, 3),
+    s(15, 0, 10, 1), s(16, 2, 10, 2),
+    s(7, 0, 12, 3),
+    s(16, 2, 1, 3),
+    s(2, 0, 10, 4),
+    s(8, 1, 8, 1), s(8, 1, 7, 2),
+    s(3, 2, 0, 3), s(0, 1, 12, 1),
+    s(17, 2, 6, 4), s(8, 3, 0, 4), s(21, 2, 0, 1),
+    s(12, 1, 11, 4), s(13, 1, 11, 4),
+    s(16, 2, 5, 4), s(20, 2, 6, 4), s(16, 2, 2, 4),
+    s(2, 0, 10, 1), s(2, 2, 0, 3),
+    s(11, 0, 9, 2), s(9, 0, 9, 3), s(0, 0, 9, 3), s(11, 0, 9, 3),
+    s(8, 2, 0, 4), s(8, 0, 8, 5),
+    s(0, 1, 4, 4), s(11, 0, 3, 4),
+    s(0, 1, 4, 4), s(10, 0, 5, 4),
+    s(8, 0, 5, 4), s(6, 0, 1, 4),
+    s(0, 0, 8, 1), s(0, 0, 8, 5),
+    s(8, 0, 3, 1),
+    s(11, 0, 1, 2),
+    s(15, 0, 3, 5),
+    s(21, 0, 1, 3),
+    s(0, 0, 3, 2), s(0, 0, 6, 2),
+    s(7, 1, 4, 4), s(5, 1, 0, 2),
+    s(0, 1, 7, 1), s(12, 3, 12, 3),
+    s(18, 0, 0, 4), s(9, 2, 2, 2),
+    s(9, 2, 2, 2), s(7, 1, 4, 4),
+    s(5, 0, 11, 4), s(6, 2, 2, 2),
+    s(0, 2, 0, 1), s(18, 0, 0, 4),
+    s(14, 3, 9, 2), s(18, 2, 0, 3),
+    s(6, 1, 12, 3), s(6, 1, 12, 2), s(15, 0, 12, 1), s(15, 0, 0, 3),
+    s(19, 0, 5, 4), s(10, 2, 0, 1),
+    s(11, 2, 11, 2), s(4, 0, 12, 1),
+    s(8, 2, 0, 2), s(11, 2, 0, 4),
+    s(4, 0, 10, 4), s(1, 1, 9, 4),
+    s(2, 1, 12, 2), s(2, 1, 12, 4),
+    s(6, 0, 1, 1), s(5, 0, 1, 2),
+    s(15, 0, 1, 2), s(13, 1, 0, 4),
+    s(15, 2, 1, 3),
+    s(11, 0, 7, 2), s(11, 0, 7, 4),
+    s(11, 2, 6, 1), s(10, 2, 6, 4),
+    s(0, 1, 10, 2), s(0, 1, 10, 1),
+    s(12, 3, 0, 3), s(4, 0, 12, 2),
+    s(3, 1, 9, 3), s(3, 0, 9, 3),
+    s(5, 1, 4, 3),
+    s(7, 2, 2, 2), s(7, 2, 2, 3),
+    s(10, 2, 1, 1), s(1, 0, 10, 3),
+    s(12, 1, 4, 4), s(1, 0, 10, 1),
+    s(15, 0, 1, 4), s(10, 2, 1, 1),
+    s(11, 2, 9, 2), s(13, 1, 4, 2),
+    s(12, 1, 7, 3), s(3, 1, 7, 3),
+    s(21, 0, 9, 3), s(0, 1, 0, 4),
+    s(11, 2, 9, 1), s(10, 0, 9, 4),
+    s(18, 0, 9, 3), s(18, 0, 9, 4),
+    s(10, 0, 12, 3),
+    s(8, 3, 0, 3), s(8, 0, 8, 2),
+    s(19, 0, 9, 1), s(20, 0, 9, 1),
+    s(8, 2, 9, 3), s(8, 2, 9, 4),
+    s(0, 3, 10, 4), s(0, 1, 12, 2),
+    s(16, 0, 12, 4), s(10, 2, 12, 3),
+    s(20, 0, 0, 2), s(19, 0, 0, 3),
+    s(12, 1, 0, 4), s(1, 0, 6, 4),
+    s(7, 1, 0, 3), s(12, 1, 10, 4),
+    s(12, 3, 0, 4), s(18, 2, 0, 2),
+    s(14, 1, 7, 1), s(14, 1, 7, 2),
+    s(3, 1, 9, 4), s(1, 1, 10, 1),
+    s(14, 1, 7, 2), s(17, 0, 8, 3),
+    s(9, 2, 6, 4), s(9, 2, 6, 3),
+    s(11, 2, 11, 3), s(11, 2, 11, 1),
+    s(14, 1, 1, 4), s(21, 0, 5, 1),
+    s(0, 1, 7, 3), s(14, 1, 11, 3),
+    s(14, 3, 12, 4), s(11, 2, 10, 4),
+    s(12, 1, 1, 1), s(15, 2, 9, 4),
+    s(13, 1, 0, 1), s(16, 0, 10, 4),
+    s(18, 2, 0, 3), s(0, 3, 0, 4),
+    s(3, 1, 0, 2), s(8, 2, 12, 2), s(3, 1, 0, 3),
+    s(0, 1, 0, 2), s(3, 1, 0, 2),
+    s(21, 0, 12, 4),
+    s(14, 3, 9, 1), s(17, 0, 8, 4),
+    s(17, 2, 6, 3), s(15, 2, 6, 3),
+    s(6, 2, 6, 3), s(10, 2, 6, 4),
+    s(1, 0, 2, 4), s(10, 0, 8, 4),
+    s(8, 0, 6, 2), s(8, 2, 9, 2),
+    s(18, 0, 10, 2), s(0, 1, 0, 2),
+    s(17, 0, 0, 3), s(5, 1, 7, 3), s(1, 0, 6, 3),
+    s(15, 0, 0, 3), s(13, 1, 0, 4),
+    s(10, 0, 3, 4), s(10, 2, 1, 4),
+    s(11, 2, 0, 4), s(12, 1, 4, 2),
+    s(12, 1, 4, 2), s(13, 1, 0, 3),
+    s(1, 0, 12, 1), s(0, 3, 12, 4),
+    s(11, 2, 9, 4), s(11, 2, 9, 2),
+    s(10, 0, 12, 1), s(14, 1, 12, 2),
+    s(6, 2, 0, 1), s(6, 2, 0, 2),
+    s(14, 1, 9, 3), s(12, 1, 9, 3),
+    s(0, 0, 9, 4), s(0, 1, 9, 3),
+    s(13, 1, 11, 1), s(11, 2, 1, 4),
+    s(11, 0, 9, 4), s(0, 1, 9, 2),
+    s(8, 1, 7, 2), s(8, 1, 8, 4),
+    s(12, 3, 4, 2), s(11, 2, 2, 4),
+    s(7, 1, 4, 4), s(0, 1, 1, 4),
+    s(11, 2, 6, 1), s(19, 2, 2, 3),
+    s(17, 0, 0, 3), s(11, 0, 5, 4),
+    s(14, 1, 9, 3), s(9, 0, 9, 4),
+    s(10, 0, 8, 1), s(13, 3, 0, 2),
+    s(2, 0, 2, 1), s(8, 2, 0, 4),
+    s(9, 2, 10, 3), s(12, 3, 9, 3),
+    s(1, 0, 1, 3),
+    s(3, 1, 0, 4), s(14, 3, 9, 2),
+    s(0, 1, 8, 1), s(19, 0, 0, 1),
+    s(0, 1, 7, 1), s(3, 1, 7, 4),
+    s(12, 1, 0, 1), s(5, 2, 6, 4),
+    s(16, 0, 3, 4), s(16, 0, 0, 3),
+    s(4, 0, 12, 3),
+    s(10, 0, 3, 1), s(0, 2, 1, 1),
+    s(20, 2, 0, 4), s(8, 0, 1, 4),
+    s(0, 1, 0, 4), s(21, 0, 0, 1),
+    s(5, 2, 6, 1), s(6, 2, 6, 2),
+    s(20, 0, 8, 1),
+    s(5, 2, 0, 4), s(6, 2, 0, 2),
+    s(12, 3, 10, 3),
+    s(8, 0, 9, 2), s(8, 1, 9, 2),
+    s(21, 0, 0, 1), s(3, 0, 1, 4),
+    s(13, 1, 0, 2), s(1, 1, 0, 4),
+    s(0, 3, 9, 1), s(19, 0, 11, 4),
+    s(0, 1, 0, 4), s(19, 0, 3, 2),
+    s(11, 2, 0, 2), s(17, 0, 0, 3),
+    s(18, 0, 11, 3), s(18, 2, 12, 3),
+    s(6, 2, 6, 4), s(7, 0, 1, 4),
+    s(6, 2, 12, 2), s(6, 0, 7, 1),
+    s(9, 2, 11, 4), s(0, 2, 11, 3),
+    s(0, 2, 0, 2), s(11, 2, 0, 2),
+    s(11, 0, 9, 4), s(12, 1, 0, 2),
+    s(18, 0, 8, 3), s(7, 1, 8, 3),
+    s(20, 2, 12, 1), s(21, 2, 12, 3),
+    s(21, 0, 9, 3), s(21, 0, 9, 4),
+    s(14, 1, 4, 4), s(12, 1, 1, 2),
+    s(3, 2, 0, 2), s(0, 2, 0, 3),
+    s(3, 0, 7, 4), s(18, 0, 8, 2),
+    s(3, 1, 0, 3), s(3, 1, 0, 2),
+    s(15, 2, 11, 3),
+    s(5, 0, 1, 2), s(16, 0, 3, 4),
+    s(14, 1, 4, 4), s(5, 1, 4, 2),
+    s(3, 2, 0, 2), s(3, 0, 8, 2), s(3, 2, 0, 3),
+    s(18, 0, 3, 3),
+    s(2, 1, 0, 1), s(1, 1, 0, 1),
+    s(3, 1, 0, 4), s(3, 1, 0, 2),
+    s(0, 0, 5, 4), s(14, 1, 0, 4),
+    s(15, 0, 3, 2), s(17, 0, 0, 4),
+    s(21, 2, 0, 1), s(21, 2, 0, 4),
+    s(13, 1, 0, 4), s(12, 1, 0, 4), s(10, 2, 5, 4),
+    s(0, 0, 5, 4), s(16, 0, 0, 1),
+    s(8, 1, 0, 4), s(8, 0, 1, 4),
+    s(20, 2, 2, 1), s(19, 2, 2, 3),
+    s(11, 2, 12, 3), s(15, 2, 11, 4),
+    s(0, 1, 0, 3), s(15, 0, 0, 2),
+    s(17, 0, 7, 2), s(13, 1, 0, 1),
+    s(13, 1, 7, 3), s(14, 1, 8, 3),
+    s(2, 0, 12, 3), s(4, 0, 12, 2), s(1, 0, 11, 4),
+    s(12, 1, 4, 2), s(12, 1, 1, 1),
+    s(11, 2, 0, 2), s(9, 2, 0, 3),
+    s(17, 0, 9, 4), s(13, 3, 9, 2),
+    s(18, 2, 12, 3), s(18, 0, 12, 1),
+    s(21, 0, 1, 4), s(20, 2, 2, 1), s(17, 0, 1, 1),
+    s(0, 2, 0, 3), s(0, 2, 0, 1),
+    s(6, 1, 12, 4),
+    s(15, 0, 3, 2), s(7, 1, 4, 4), s(5, 1, 4, 2),
+    s(2, 1, 10, 1), s(2, 0, 9, 1), s(1, 1, 9, 4), s(4, 0, 9, 1),
+    s(5, 2, 9, 3), s(5, 0, 8, 4),
+    s(13, 1, 1, 1), s(12, 1, 4, 2),
+    s(13, 3, 12, 4),
+    s(17, 2, 9, 4), s(6, 2, 9, 2),
+    s(8, 2, 0, 4), s(12, 3, 4, 2),
+    s(5, 2, 9, 4), s(0, 2, 9, 3),
+    s(0, 3, 4, 3),
+    s(0, 2, 5, 3),
+    s(16, 0, 9, 3), s(16, 0, 0, 1),
+    s(1, 0, 9, 4), s(1, 0, 9, 1), s(2, 0, 9, 1),
+    s(16, 2, 5, 1),
+    s(15, 2, 2, 2), s(19, 2, 0, 2),
+    s(15, 0, 5, 1), s(15, 0, 0, 4), s(16, 0, 0, 4),
+    s(14, 1, 4, 2), s(12, 1, 4, 2),
+    s(12, 1, 9, 3), s(15, 0, 9, 3),
+    s(15, 2, 5, 4), s(16, 2, 5, 2),
+    s(19, 0, 1, 2), s(21, 0, 1, 4),
+    s(13, 1, 7, 4), s(12, 1, 7, 3),
+    s(21, 2, 2, 4), s(20, 0, 3, 4),
+    s(1, 0, 2, 2), s(12, 1, 7, 4),
+    s(14, 1, 1, 2), s(9, 2, 6, 1),
+    s(12, 1, 4, 2), s(4, 2, 0, 2),
+    s(1, 0, 6, 4), s(8, 2, 0, 4),
+    s(17, 0, 0, 1), s(6, 2, 2, 2),
+    s(9, 0, 3, 2), s(9, 2, 2, 2), s(0, 0, 3, 4),
+    s(0, 2, 6, 1), s(0, 2, 6, 2),
+    s(2, 0, 7, 3),
+    s(1, 1, 7, 4), s(2, 0, 7, 1),
+    s(16, 0, 8, 2), s(5, 0, 7, 3),
+    s(16, 2, 9, 4), s(16, 2, 2, 4),
+    s(8, 1, 0, 3), s(8, 1, 0, 2),
+    s(6, 1, 7, 1), s(13, 1, 7, 1),
+    s(5, 0, 8, 1), s(6, 0, 8, 2),
+    s(20, 2, 6, 4), s(16, 0, 1, 4),
+    s(16, 0, 9, 3), s(16, 2, 11, 2),
+    s(0, 1, 9, 3), s(0, 1, 4, 4),
+    s(21, 2, 6, 4), s(0, 2, 6, 2),
+    s(3, 1, 9, 4), s(1, 1, 12, 1),
+    s(7, 0, 1, 4), s(7, 1, 8, 3),
+    s(9, 2, 1, 3), s(12, 3, 12, 1),
+    s(7, 2, 2, 3), s(16, 0, 0, 3),
+    s(0, 0, 9, 4), s(0, 2, 9, 3),
+    s(14, 1, 4, 1), s(12, 1, 4, 1),
+    s(21, 0, 11, 4),
+    s(8, 0, 11, 3), s(15, 0, 7, 4),
+    s(2, 1, 7, 3), s(1, 0, 7, 4),
+    s(11, 2, 6, 5), s(5, 0, 1, 2),
+    s(10, 0, 10, 3), s(0, 2, 6, 3),
+    s(0, 1, 0, 4), s(11, 2, 9, 1),
+    s(12, 3, 0, 2), s(14, 3, 4, 4),
+    s(5, 2, 10, 3), s(0, 0, 3, 4), s(0, 0, 5, 4),
+    s(1, 0, 6, 4), s(2, 0, 2, 1),
+    s(10, 2, 10, 4), s(10, 2, 10, 3),
+    s(10, 0, 7, 3), s(12, 3, 0, 2),
+    s(12, 1, 8, 5),
+    s(17, 0, 1, 4), s(12, 1, 4, 2),
+    s(9, 0, 7, 1), s(12, 3, 0, 2),
+    s(6, 2, 0, 2), s(16, 0, 9, 2),
+    s(16, 0, 8, 2), s(1, 1, 0, 4),
+    s(2, 0, 5, 1),
+    s(5, 2, 10, 4), s(19, 0, 1, 1),
+    s(9, 0, 11, 4), s(9, 0, 11, 1),
+    s(17, 0, 10, 1), s(15, 0, 10, 1),
+    s(0, 0, 3, 3), s(0, 0, 3, 1),
+    s(16, 0, 1, 2), s(6, 2, 0, 2),
+    s(21, 0, 0, 4), s(20, 0, 0, 2),
+    s(5, 1, 0, 1), s(17, 0, 0, 4),
+    s(5, 0, 9, 4), s(8, 0, 9, 3),
+    s(16, 2, 9, 2), s(16, 2, 5, 3),
+    s(16, 2, 6, 4),
+    s(12, 1, 8, 1), s(13, 1, 7, 1),
+    s(15, 0, 3, 2), s(17, 0, 3, 4),
+    s(11, 0, 5, 1), s(14, 1, 0, 1),
+    s(13, 1, 10, 1), s(10, 0, 12, 1),
+    s(0, 2, 1, 2),
+    s(11, 0, 9, 2), s(14, 1, 9, 2),
+    s(19, 0, 0, 4), s(21, 0, 0, 4),
+    s(0, 1, 7, 2), s(0, 1, 7, 3),
+    s(16, 2, 5, 4), s(16, 0, 0, 3), s(16, 2, 2, 4),
+    s(14, 1, 7, 4), s(0, 1, 8, 3),
+    s(19, 2, 0, 2), s(19, 0, 1, 1), s(19, 0, 9, 3),
+    s(0, 0, 3, 4), s(15, 0, 10, 1),
+    s(16, 2, 9, 3), s(3, 0, 2, 4),
+    s(14, 3, 4, 4), s(14, 3, 0, 4),
+    s(12, 3, 4, 4),
+    s(10, 0, 3, 4), s(0, 0, 5, 4),
+    s(20, 2, 2, 2), s(19, 2, 2, 1),
+    s(0, 0, 5, 4), s(10, 0, 3, 1),
+    s(6, 0, 8, 2), s(5, 2, 6, 4),
+    s(12, 1, 0, 1), s(12, 1, 0, 4), s(13, 1, 0, 4),
+    s(12, 3, 9, 4), s(16, 2, 2, 4),
+    s(21, 0, 9, 1), s(17, 0, 9, 1),
+    s(12, 1, 4, 4), s(9, 0, 1, 4),
+    s(13, 1, 8, 2), s(13, 3, 0, 2),
+    s(20, 2, 6, 3), s(21, 2, 6, 1),
+    s(8, 1, 4, 4), s(11, 0, 3, 2),
+    s(18, 0, 9, 2), s(9, 0, 9, 1),
+    s(18, 0, 11, 2), s(7, 0, 11, 3),
+    s(0, 1, 10, 4), s(15, 0, 0, 4),
+    s(14, 1, 7, 4), s(11, 0, 7, 4),
+    s(16, 2, 5, 3),
+    s(11, 2, 0, 4), s(16, 0, 0, 2), s(11, 0, 3, 2), s(11, 2, 0, 2),
+    s(8, 1, 0, 4), s(8, 0, 3, 4),
+    s(2, 0, 5, 4), s(8, 1, 8, 2), s(9, 2, 0, 1),
+    s(17, 0, 1, 1), s(12, 1, 0, 2), s(12, 1, 4, 2),
+    s(11, 0, 5, 3), s(3, 2, 0, 3),
+    s(14, 1, 0, 4), s(7, 0, 7, 2),
+    s(3, 0, 7, 4), s(11, 2, 9, 3),
+    s(2, 0, 5, 4), s(2, 1, 0, 4),
+    s(11, 0, 9, 2), s(9, 0, 9, 4),
+    s(0, 2, 11, 3), s(3, 0, 11, 3),
+    s(12, 1, 4, 1), s(5, 1, 4, 1),
+    s(21, 2, 0, 3),
+    s(13, 1, 7, 4), s(14, 1, 7, 4),
+    s(5, 0, 11, 4), s(14, 1, 11, 4),
+    s(17, 0, 1, 1), s(17, 0, 5, 4),
+    s(18, 2, 0, 2), s(18, 2, 9, 2),
+    s(9, 0, 7, 1), s(19, 0, 3, 2), s(11, 0, 7, 2),
+    s(21, 2, 2, 1), s(17, 0, 9, 4), s(17, 2, 5, 4),
+    s(20, 0, 1, 1), s(19, 0, 1, 2),
+    s(0, 1, 7, 4), s(17, 2, 2, 4),
+    s(11, 2, 2, 3), s(19, 0, 5, 1),
+    s(0, 0, 10, 1), s(0, 0, 7, 1),
+    s(11, 2, 6, 3), s(3, 0, 6, 2),
+    s(4, 0, 8, 1),
+    s(8, 1, 7, 4), s(8, 1, 7, 3),
+    s(6, 0, 9, 3), s(16, 0, 9, 1),
+    s(8, 1, 10, 3), s(0, 1, 10, 3),
+    s(8, 1, 7, 3), s(15, 0, 7, 1),
+    s(8, 1, 9, 4), s(0, 1, 9, 4),
+    s(15, 2, 2, 4),
+    s(15, 0, 11, 3), s(12, 3, 0, 2),
+    s(0, 3, 0, 1), s(0, 2, 0, 4),
+    s(12, 1, 7, 4), s(1, 0, 2, 2),
+    s(12, 1, 11, 1), s(15, 2, 11, 4),
+    s(13, 1, 8, 2), s(4, 2, 0, 3),
+    s(16, 0, 3, 4), s(6, 2, 2, 4),
+    s(14, 1, 1, 4), s(14, 1, 1, 1),
+    s(3, 0, 7, 1),
+    s(20, 2, 0, 3),
+    s(11, 2, 5, 2), s(11, 2, 5, 4),
+    s(17, 0, 0, 4), s(17, 0, 3, 2),
+    s(4, 2, 0, 4), s(16, 0, 5, 2),
+    s(0, 2, 1, 2), s(10, 2, 11, 2),
+    s(15, 0, 5, 4), s(15, 0, 5, 1),
+    s(11, 2, 6, 1), s(14, 3, 10, 1),
+    s(19, 2, 12, 4), s(19, 2, 12, 1),
+    s(8, 1, 4, 4), s(0, 2, 10, 3),
+    s(7, 0, 7, 2), s(7, 0, 1, 4), s(18, 2, 0, 2),
+    s(21, 2, 6, 4), s(0, 2, 6, 3),
+    s(17, 2, 2, 4), s(14, 1, 0, 1), s(13, 3, 4, 4),
+    s(21, 0, 7, 1), s(17, 0, 9, 1),
+    s(8, 0, 5, 4), s(0, 0, 5, 4),
+    s(12, 1, 10, 1), s(12, 1, 10, 4), s(5, 2, 6, 1),
+    s(14, 1, 0, 3), s(6, 0, 7, 1),
+    s(5, 1, 9, 4), s(6, 1, 9, 4),
+    s(15, 2, 9, 4), s(16, 2, 10, 1),
+    s(11, 0, 7, 3),
+    s(10, 2, 2, 4), s(9, 2, 2, 2),
+    s(11, 2, 2, 3), s(9, 2, 2, 3), s(8, 2, 2, 3),
+    s(5, 2, 0, 4), s(10, 0, 9, 1),
+    s(11, 0, 9, 2), s(13, 1, 9, 4),
+    s(20, 0, 6, 4), s(21, 2, 6, 4),
+    s(15, 2, 9, 1), s(9, 2, 9, 4),
+    s(1, 0, 6, 4), s(4, 2, 0, 2),
+    s(10, 0, 3, 1), s(9, 0, 3, 2),
+    s(8, 0, 1, 1), s(8, 0, 1, 2),
+    s(2, 1, 0, 1), s(2, 0, 2, 3),
+    s(8, 2, 9, 2), s(7, 1, 7, 3),
+    s(14, 3, 0, 4), s(19, 0, 0, 1),
+    s(1, 0, 9, 1), s(4, 0, 9, 1),
+    s(8, 0, 6, 2), s(11, 2, 6, 3),
+    s(9, 2, 11, 3), s(13, 1, 7, 1),
+    s(17, 2, 0, 1), s(14, 3, 0, 1),
+    s(0, 1, 8, 4), s(0, 1, 8, 3),
+    s(13, 1, 4, 4), s(20, 0, 0, 2),
+    s(20, 0, 7, 2), s(15, 0, 8, 3),
+    s(8, 2, 12, 2), s(2, 0, 11, 1),
+    s(17, 0, 11, 4), s(17, 0, 11, 1),
+    s(0, 1, 0, 4), s(0, 1, 1, 2),
+    s(7, 0, 1, 4), s(7, 1, 4, 4),
+    s(2, 1, 0, 2), s(1, 1, 0, 4), s(1, 0, 6, 1),
+    s(14, 1, 0, 1), s(7, 3, 4, 4),
+    s(10, 0, 3, 3), s(11, 0, 5, 4),
+    s(0, 0, 3, 4), s(10, 0, 3, 4), s(10, 0, 5, 4), s(0, 1, 1, 4),
+    s(14, 1, 7, 1), s(12, 1, 7, 1), s(0, 1, 7, 1),
+    s(15, 2, 12, 3), s(6, 2, 12, 2),
+    s(5, 0, 9, 3), s(5, 0, 9, 4), s(6, 0, 9, 2),
+    s(10, 0, 5, 4), s(0, 0, 3, 4),
+    s(0, 2, 6, 3), s(11, 2, 1, 4),
+    s(15, 0, 0, 4), s(2, 1, 0, 2),
+    s(9, 2, 6, 4), s(13, 1, 0, 2),
+    s(1, 0, 12, 3), s(1, 0, 11, 1),
+    s(14, 3, 4, 2), s(13, 1, 7, 4),
+    s(7, 0, 8, 2), s(18, 0, 9, 3),
+    s(12, 3, 9, 4), s(13, 3, 9, 2), s(13, 3, 9, 1),
+    s(16, 2, 0, 4), s(16, 2, 2, 4),
+    s(16, 0, 8, 1), s(12, 1, 7, 3), s(0, 1, 7, 3),
+    s(9, 0, 10, 4), s(0, 1, 9, 3),
+    s(16, 0, 0, 1), s(11, 2, 10, 1),
+    s(2, 0, 6, 4), s(2, 0, 2, 4),
+    s(0, 2, 2, 4), s(7, 0, 5, 4),
+    s(3, 1, 4, 2),
+    s(14, 3, 4, 4), s(12, 3, 4, 2),
+    s(0, 1, 0, 2), s(5, 1, 0, 4),
+    s(15, 0, 10, 3), s(3, 1, 0, 2),
+    s(3, 0, 8, 4),
+    s(18, 2, 10, 2),
+    s(11, 0, 9, 4), s(13, 1, 1, 4),
+    s(12, 3, 0, 1), s(12, 3, 0, 4), s(14, 1, 0, 4),
+    s(15, 2, 10, 1), s(9, 2, 2, 1),
+    s(2, 0, 9, 2), s(2, 0, 9, 1),
+    s(0, 3, 0, 4), s(11, 0, 3, 4),
+    s(17, 0, 9, 1), s(21, 0, 9, 3),
+    s(3, 0, 5, 4), s(0, 1, 1, 2), s(17, 2, 0, 4),
+    s(14, 1, 4, 1), s(3, 1, 4, 4),
+    s(19, 2, 6, 1), s(14, 1, 4, 1), s(11, 2, 6, 3),
+    s(10, 0, 9, 4), s(0, 1, 9, 3),
+    s(4, 0, 12, 2), s(4, 0, 12, 1),
+    s(2, 0, 1, 1), s(1, 0, 1, 1),
+    s(13, 3, 12, 1),
+    s(7, 0, 1, 3), s(10, 0, 12, 1),
+    s(10, 0, 9, 1), s(10, 0, 9, 3),
+    s(7, 0, 7, 3), s(8, 1, 0, 4),
+    s(5, 0, 1, 3),
+    s(13, 1, 4, 1), s(12, 3, 0, 1),
+    s(16, 0, 7, 2), s(21, 2, 2, 3),
+    s(15, 0, 8, 2), s(5, 2, 0, 2),
+    s(12, 1, 8, 4), s(17, 0, 3, 4),
+    s(14, 1, 9, 4), s(15, 0, 0, 1),
+    s(21, 0, 0, 4), s(6, 0, 5, 2),
+    s(15, 2, 10, 4),
+    s(7, 1, 0, 3), s(14, 1, 9, 3),
+    s(0, 3, 0, 4), s(0, 2, 11, 2),
+    s(11, 0, 3, 2), s(14, 1, 4, 2),
+    s(21, 0, 0, 1), s(14, 1, 8, 4),
+    s(16, 2, 6, 4), s(17, 2, 0, 4),
+    s(1, 0, 12, 1), s(11, 0, 3, 2),
+    s(3, 0, 2, 4), s(3, 1, 0, 3),
+    s(8, 2, 2, 2), s(21, 2, 6, 4),
+    s(11, 2, 0, 4), s(21, 2, 6, 4),
+    s(12, 3, 0, 2), s(0, 1, 0, 4),
+    s(15, 0, 8, 4), s(20, 2, 12, 4),
+    s(18, 0, 9, 4),
+    s(8, 0, 6, 4), s(8, 0, 6, 2),
+    s(10, 0, 9, 3), s(11, 0, 9, 1),
+    s(12, 1, 8, 1), s(20, 2, 9, 4),
+    s(11, 2, 12, 2), s(0, 2, 12, 4),
+    s(17, 0, 10, 1), s(17, 0, 10, 4),
+    s(6, 2, 0, 1), s(1, 0, 1, 2),
+    s(0, 0, 1, 1), s(14, 1, 11, 4),
+    s(10, 2, 9, 1), s(3, 1, 0, 4),
+    s(10, 0, 3, 1), s(16, 0, 7, 2),
+    s(6, 1, 9, 2), s(5, 1, 9, 1), s(0, 1, 9, 3), s(16, 0, 9, 3),
+    s(12, 1, 10, 4), s(12, 1, 10, 3),
+    s(20, 2, 6, 4), s(20, 2, 9, 4),
+    s(2, 0, 1, 3),
+    s(10, 2, 6, 3), s(12, 3, 4, 2),
+    s(0, 1, 7, 4), s(13, 1, 7, 2),
+    s(15, 2, 1, 1), s(5, 2, 2, 4),
+    s(15, 0, 3, 4), s(7, 1, 4, 4),
+    s(13, 1, 9, 2), s(15, 0, 9, 1),
+    s(14, 3, 0, 1), s(12, 1, 0, 2),
+    s(20, 0, 9, 3), s(19, 0, 9, 1),
+    s(12, 1, 9, 4), s(17, 0, 1, 4),
+    s(16, 0, 8, 1), s(21, 0, 8, 3),
+    s(20, 2, 2, 1), s(15, 0, 1, 3), s(20, 0, 0, 1),
+    s(11, 2, 6, 4), s(21, 2, 6, 4), s(14, 1, 0, 2),
+    s(16, 0, 0, 4), s(6, 2, 0, 2),
+    s(2, 0, 5, 2), s(2, 1, 0, 4),
+    s(19, 0, 9, 4), s(19, 0, 9, 1),
+    s(21, 2, 6, 4), s(5, 1, 0, 2),
+    s(6, 2, 10, 2), s(5, 1, 9, 4),
+    s(15, 2, 2, 2), s(15, 0, 7, 1),
+    s(4, 0, 9, 2), s(1, 0, 2, 3), s(1, 2, 0, 3),
+    s(15, 0, 1, 1), s(19, 2, 2, 4),
+    s(13, 3, 9, 3), s(11, 2, 9, 2),
+    s(7, 1, 9, 1),
+    s(0, 0, 8, 3), s(8, 1, 0, 4),
+    s(20, 2, 0, 4), s(3, 1, 0, 4),
+    s(14, 1, 9, 2), s(12, 1, 9, 1),
+    s(4, 0, 12, 1), s(8, 1, 0, 3),
+    s(9, 0, 12, 3), s(5, 0, 9, 3),
+    s(3, 1, 4, 4), s(3, 1, 9, 3),
+    s(14, 3, 10, 2), s(12, 1, 0, 1),
+    s(9, 2, 9, 1), s(8, 2, 10, 2),
+    s(15, 0, 9, 3), s(15, 0, 10, 3),
+    s(3, 1, 0, 2), s(0, 1, 0, 4), s(0, 2, 6, 4),
+    s(19, 0, 12, 1), s(12, 1, 4, 1),
+    s(15, 2, 9, 4), s(12, 3, 9, 4), s(17, 2, 9, 4),
+    s(16, 0, 10, 1), s(16, 0, 10, 2), s(15, 0, 10, 3),
+    s(14, 1, 12, 1), s(14, 1, 0, 3),
+    s(0, 0, 8, 2),
+    s(11, 2, 10, 3),
+    s(2, 0, 2, 2), s(6, 0, 7, 1),
+    s(13, 3, 10, 3),
+    s(10, 2, 10, 3), s(3, 1, 0, 2),
+    s(19, 2, 9, 3), s(19, 2, 6, 2),
+    s(21, 0, 0, 1), s(16, 0, 0, 1),
+    s(10, 0, 5, 1), s(9, 2, 0, 3),
+    s(8, 1, 8, 3), s(8, 0, 8, 2),
+    s(14, 1, 11, 2), s(0, 1, 11, 4),
+    s(14, 1, 11, 2), s(14, 1, 4, 4),
+    s(16, 2, 10, 2), s(5, 2, 10, 1), s(5, 2, 10, 4),
+    s(0, 1, 11, 3), s(16, 0, 5, 4),
+    s(0, 1, 0, 4), s(16, 0, 0, 2),
+    s(13, 3, 0, 2), s(0, 3, 0, 4),
+    s(14, 3, 9, 1), s(8, 1, 12, 2),
+    s(16, 0, 0, 2), s(16, 0, 0, 1),
+    s(15, 0, 5, 2), s(11, 2, 2, 4),
+    s(0, 0, 13, 2), s(8, 1, 4, 4),
+    s(0, 0, 13, 2), s(14, 3, 0, 1),
+    s(7, 3, 0, 2),
+    s(8, 2, 10, 3), s(10, 2, 10, 3),
+    s(13, 1, 0, 2), s(21, 0, 0, 2),
+    s(16, 0, 0, 4), s(0, 1, 0, 4),
+    s(17, 0, 1, 4), s(14, 3, 0, 1),
+    s(5, 2, 2, 5),
+    s(14, 1, 11, 3), s(9, 0, 7, 1),
+    s(17, 0, 12, 1), s(0, 2, 10, 2),
+    s(6, 1, 12, 1), s(6, 0, 3, 4),
+    s(0, 2, 5, 4), s(0, 2, 1, 4),
+    s(17, 0, 9, 4), s(0, 3, 4, 4),
+    s(6, 2, 2, 4), s(5, 2, 0, 4),
+    s(10, 2, 11, 1), s(10, 2, 11, 4),
+    s(14, 1, 4, 4), s(3, 0, 5, 4),
+    s(11, 0, 8, 2), s(0, 1, 8, 2),
+    s(10, 2, 0, 2),
+    s(11, 0, 3, 2), s(11, 0, 9, 2),
+    s(8, 0, 7, 5),
+    s(15, 2, 9, 3), s(17, 2, 9, 4),
+    s(16, 0, 1, 1), s(15, 0, 1, 2),
+    s(8, 3, 0, 2), s(8, 0, 8, 2),
+    s(14, 1, 10, 4), s(19, 0, 0, 3),
+    s(11, 2, 2, 4), s(11, 0, 3, 4),
+    s(12, 1, 10, 1), s(12, 1, 9, 4),
+    s(14, 1, 0, 1), s(0, 2, 6, 4), s(12, 1, 0, 2),
+    s(21, 0, 11, 3), s(21, 0, 7, 4),
+    s(7, 2, 2, 2), s(7, 1, 4, 2),
+    s(0, 1, 7, 4), s(17, 0, 7, 4),
+    s(16, 0, 8, 2), s(15, 0, 8, 3),
+    s(1, 0, 7, 2), s(1, 0, 2, 2),
+    s(16, 0, 9, 3), s(13, 1, 9, 1), s(14, 1, 9, 1),
+    s(14, 1, 7, 4), s(21, 0, 8, 1),
+    s(14, 1, 10, 4), s(14, 1, 12, 4),
+    s(0, 2, 6, 3), s(12, 3, 9, 3),
+    s(7, 0, 1, 4), s(7, 1, 4, 4), s(15, 0, 3, 2),
+    s(17, 0, 8, 3), s(1, 0, 5, 3),
+    s(11, 0, 5, 4), s(11, 0, 3, 4), s(0, 0, 5, 4),
+    s(1, 1, 0, 4), s(1, 1, 0, 2),
+    s(12, 3, 0, 2), s(12, 3, 0, 3), s(2, 0, 8, 2),
+    s(12, 3, 9, 4), s(4, 0, 9, 4),
+    s(0, 1, 9, 3), s(6, 1, 9, 4),
+    s(12, 1, 10, 1), s(0, 2, 6, 2),
+    s(5, 0, 12, 4), s(6, 0, 12, 2),
+    s(11, 2, 11, 2), s(11, 0, 12, 2),
+    s(14, 3, 12, 4), s(11, 2, 11, 3),
+    s(9, 2, 5, 1), s(10, 2, 1, 1),
+    s(7, 0, 5, 4), s(7, 0, 1, 4),
+    s(6, 0, 11, 1), s(5, 0, 11, 4),
+    s(14, 3, 12, 1), s(9, 2, 12, 1),
+    s(19, 0, 7, 1), s(13, 1, 8, 2),
+    s(19, 2, 0, 4),
+    s(18, 2, 12, 3), s(18, 2, 9, 3),
+    s(6, 0, 7, 1), s(6, 1, 7, 2),
+    s(13, 1, 4, 4), s(11, 0, 3, 2),
+    s(9, 0, 9, 4), s(9, 0, 9, 3),
+    s(8, 1, 4, 1),
+    s(3, 1, 4, 4), s(3, 0, 2, 4),
+    s(12, 1, 9, 4), s(17, 0, 9, 3),
+    s(5, 2, 0, 2), s(17, 2, 0, 3),
+    s(6, 0, 9, 2), s(14, 3, 10, 2),
+    s(15, 2, 9, 4), s(21, 2, 10, 1),
+    s(3, 0, 10, 2), s(0, 2, 6, 3),
+    s(19, 0, 9, 4), s(19, 0, 1, 1),
+    s(11, 2, 2, 4), s(21, 2, 6, 3),
+    s(15, 2, 12, 1), s(5, 2, 12, 1),
+    s(21, 2, 6, 1), s(3, 0, 12, 2),
+    s(14, 1, 0, 1), s(12, 1, 0, 2),
+    s(4, 0, 8, 2), s(4, 2, 0, 2),
+    s(13, 3, 0, 3), s(12, 1, 4, 2),
+    s(17, 0, 3, 2), s(0, 1, 9, 2), s(0, 1, 0, 2),
+    s(5, 0, 5, 4), s(5, 0, 3, 2),
+    s(0, 3, 0, 2), s(17, 2, 0, 1),
+    s(9, 0, 3, 2), s(0, 0, 3, 4),
+    s(17, 0, 1, 1), s(17, 2, 2, 1),
+    s(5, 1, 0, 4), s(14, 3, 4, 2),
+    s(6, 2, 12, 2), s(17, 0, 0, 4),
+    s(0, 0, 3, 4), s(0, 1, 4, 4),
+    s(14, 1, 4, 4), s(17, 0, 3, 4),
+    s(17, 0, 0, 1), s(8, 1, 0, 3),
+    s(16, 2, 9, 3), s(16, 2, 5, 3),
+    s(17, 0, 12, 3), s(7, 1, 12, 4),
+    s(0, 1, 9, 2), s(0, 1, 10, 3),
+    s(3, 0, 8, 2), s(0, 2, 0, 4),
+    s(14, 3, 0, 4), s(0, 0, 8, 4),
+    s(14, 1, 0, 2), s(0, 1, 0, 4),
+    s(8, 1, 9, 2), s(8, 1, 9, 4),
+    s(8, 1, 0, 4), s(16, 0, 5, 4),
+    s(8, 1, 0, 2), s(17, 0, 0, 1),
+    s(14, 1, 4, 4), s(0, 2, 10, 2),
+    s(7, 1, 0, 4), s(7, 3, 0, 4),
+    s(0, 3, 0, 4), s(13, 3, 0, 2),
+    s(17, 2, 0, 4), s(0, 3, 0, 4),
+    s(14, 1, 10, 4), s(14, 1, 9, 1),
+    s(15, 2, 2, 2), s(1, 0, 7, 4),
+    s(15, 0, 0, 4), s(19, 0, 0, 1), s(12, 1, 0, 4), s(2, 1, 0, 1),
+    s(0, 3, 12, 4), s(5, 0, 9, 3),
+    s(14, 1, 8, 4), s(0, 1, 8, 3),
+    s(16, 0, 3, 2),
+    s(8, 2, 12, 2), s(6, 2, 12, 3),
+    s(0, 2, 9, 3), s(0, 2, 10, 4),
+    s(13, 1, 0, 4), s(17, 0, 1, 4), s(13, 1, 4, 4),
+    s(13, 1, 9, 4), s(12, 1, 12, 1),
+    s(17, 0, 5, 4), s(17, 0, 1, 1), s(17, 0, 5, 3),
+    s(16, 2, 12, 1), s(16, 2, 11, 2), s(16, 2, 12, 2),
+    s(20, 2, 12, 3),
+    s(8, 0, 1, 4), s(8, 1, 4, 2),
+    s(0, 2, 6, 2), s(21, 2, 6, 4),
+    s(15, 0, 0, 4), s(5, 1, 0, 2), s(16, 0, 0, 4),
+    s(0, 0, 5, 3), s(0, 1, 1, 2),
+    s(13, 1, 0, 4), s(13, 1, 10, 1),
+    s(20, 0, 8, 2),
+    s(10, 2, 6, 1), s(10, 2, 6, 2), s(9, 2, 6, 4),
+    s(17, 0, 10, 1), s(12, 1, 10, 1),
+    s(7, 2, 2, 4), s(16, 2, 2, 4),
+    s(16, 0, 0, 4), s(6, 1, 0, 4),
+    s(7, 0, 3, 4), s(8, 1, 0, 4),
+    s(9, 2, 2, 2), s(0, 3, 4, 4),
+    s(0, 3, 4, 4), s(12, 1, 9, 4),
+    s(8, 1, 0, 2), s(17, 0, 0, 3),
+    s(14, 1, 0, 1), s(14, 1, 4, 1),
+    s(11, 2, 0, 4), s(5, 1, 0, 3),
+    s(12, 3, 10, 4), s(0, 3, 10, 4),
+    s(11, 2, 0, 1), s(11, 0, 7, 4),
+    s(0, 1, 0, 2), s(6, 2, 2, 1),
+    s(6, 2, 2, 1), s(14, 1, 0, 1),
+    s(17, 0, 0, 4), s(12, 1, 9, 4),
+    s(18, 0, 3, 4), s(18, 0, 3, 3),
+    s(21, 2, 2, 1), s(19, 2, 2, 4),
+    s(0, 2, 0, 1), s(11, 2, 11, 3),
+    s(8, 1, 12, 2), s(0, 2, 0, 1),
+    s(17, 0, 9, 2),
+    s(15, 0, 1, 1), s(16, 0, 1, 4),
+    s(17, 0, 0, 1), s(0, 1, 0, 3),
+    s(16, 0, 0, 4), s(15, 0, 0, 3),
+    s(16, 0, 5, 4), s(20, 2, 2, 3), s(12, 1, 4, 1),
+    s(14, 3, 0, 1), s(11, 2, 1, 2),
+    s(14, 1, 0, 4), s(21, 0, 0, 2),
+    s(1, 0, 7, 4), s(1, 0, 7, 2),
+    s(15, 0, 10, 1), s(12, 3, 4, 2),
+    s(16, 0, 8, 2), s(14, 1, 7, 1),
+    s(5, 0, 8, 4), s(5, 0, 8, 1),
+    s(0, 2, 9, 1), s(0, 3, 4, 4),
+    s(5, 2, 0, 1), s(5, 2, 0, 2), s(15, 2, 2, 2),
+    s(15, 0, 0, 1), s(15, 2, 2, 1),
+    s(12, 3, 4, 2), s(12, 3, 10, 4),
+    s(0, 2, 9, 2), s(11, 0, 3, 2),
+    s(13, 1, 8, 1), s(16, 2, 0, 1),
+    s(1, 1, 7, 4), s(7, 0, 7, 3),
+    s(17, 0, 0, 4), s(17, 0, 0, 3),
+    s(11, 2, 6, 1), s(14, 1, 0, 1),
+    s(1, 1, 0, 4), s(1, 1, 0, 3),
+    s(0, 1, 8, 2), s(12, 1, 8, 1), s(13, 1, 8, 2), s(0, 1, 8, 4),
+    s(11, 0, 9, 1), s(11, 0, 9, 4), s(6, 0, 9, 4),
+    s(14, 3, 12, 1), s(3, 1, 10, 2),
+    s(20, 0, 5, 2), s(15, 2, 0, 4),
+    s(3, 1, 0, 4), s(17, 0, 10, 4),
+    s(17, 0, 11, 1), s(17, 0, 11, 3),
+    s(5, 2, 9, 3), s(15, 2, 9, 4),
+    s(15, 2, 9, 4), s(8, 1, 9, 2),
+    s(8, 1, 9, 4), s(1, 1, 9, 3), s(12, 1, 9, 3),
+    s(13, 3, 9, 3), s(14, 3, 9, 4),
+    s(7, 0, 9, 3), s(7, 1, 9, 3),
+    s(14, 3, 0, 4), s(11, 2, 2, 4),
+    s(6, 0, 8, 4), s(0, 1, 0, 4),
+    s(13, 1, 8, 2), s(12, 3, 0, 1),
+    s(2, 0, 9, 3),
+    s(0, 1, 9, 2), s(13, 3, 0, 4),
+    s(3, 0, 2, 4), s(2, 0, 2, 4),
+    s(9, 2, 6, 4), s(10, 2, 6, 3),
+    s(11, 2, 0, 2), s(19, 0, 7, 4),
+    s(11, 0, 5, 2), s(10, 2, 6, 1),
+    s(9, 2, 11, 1), s(10, 2, 11, 1),
+    s(0, 3, 10, 3), s(13, 3, 10, 1),
+    s(12, 1, 4, 2), s(12, 3, 4, 2),
+    s(16, 2, 12, 4), s(5, 2, 12, 4),
+    s(21, 2, 0, 4), s(21, 0, 8, 1), s(21, 0, 8, 3), s(13, 1, 8, 4),
+    s(4, 2, 0, 2), s(1, 1, 0, 2),
+    s(4, 0, 2, 2), s(15, 0, 0, 4),
+    s(5, 1, 9, 1), s(5, 1, 9, 4),
+    s(21, 0, 9, 1), s(20, 2, 10, 2),
+    s(15, 0, 9, 1), s(15, 0, 9, 4), s(16, 0, 9, 2),
+    s(10, 2, 0, 4), s(0, 2, 0, 4),
+    s(11, 2, 0, 4), s(5, 1, 0, 4),
+    s(15, 2, 6, 4), s(1, 0, 2, 2),
+    s(7, 1, 0, 3), s(7, 1, 9, 3),
+    s(11, 0, 11, 2), s(9, 0, 12, 1),
+    s(16, 0, 12, 2), s(12, 1, 12, 4),
+    s(8, 2, 0, 4), s(8, 1, 0, 4),
+    s(16, 2, 0, 4), s(15, 2, 2, 2),
+    s(15, 0, 0, 2), s(14, 3, 4, 3),
+    s(14, 3, 0, 1), s(8, 3, 0, 3),
+    s(19, 0, 8, 1), s(13, 3, 0, 1),
+    s(13, 1, 7, 1), s(10, 0, 7, 4),
+    s(17, 2, 0, 4), s(16, 2, 0, 2),
+    s(15, 0, 1, 3), s(5, 0, 1, 2),
+    s(6, 1, 9, 3), s(0, 1, 9, 3),
+    s(6, 0, 1, 4), s(5, 0, 1, 4),
+    s(5, 1, 10, 4),
+    s(14, 1, 4, 4), s(15, 0, 0, 1),
+    s(3, 1, 9, 4), s(6, 1, 0, 3),
+    s(9, 2, 12, 1), s(13, 3, 12, 1),
+    s(17, 0, 10, 1), s(13, 3, 0, 1),
+    s(11, 2, 6, 4), s(17, 0, 7, 2),
+    s(10, 0, 12, 1), s(12, 3, 0, 2),
+    s(0, 1, 11, 4), s(0, 0, 11, 3),
+    s(7, 1, 9, 3), s(18, 2, 9, 3),
+    s(15, 0, 12, 3), s(16, 0, 12, 4),
+    s(11, 2, 12, 1), s(16, 2, 10, 1),
+    s(0, 2, 9, 3), s(0, 2, 9, 4),
+    s(0, 2, 2, 4), s(11, 2, 2, 4),
+    s(16, 0, 12, 2), s(16, 0, 12, 4),
+    s(14, 1, 9, 4), s(12, 1, 9, 4),
+    s(9, 0, 3, 2), s(8, 1, 0, 4),
+    s(11, 2, 0, 2), s(9, 2, 10, 3),
+    s(10, 0, 11, 1), s(8, 1, 11, 2),
+    s(6, 0, 11, 3), s(16, 0, 11, 3),
+    s(11, 2, 10, 2), s(14, 3, 9, 1),
+    s(8, 2, 2, 4), s(8, 0, 6, 2),
+    s(18, 0, 11, 3), s(7, 1, 9, 3),
+    s(17, 0, 10, 1), s(20, 0, 0, 2),
+    s(1, 0, 9, 4), s(1, 1, 9, 4),
+    s(15, 0, 0, 4), s(15, 2, 6, 4), s(21, 2, 6, 4),
+    s(6, 2, 2, 1), s(11, 0, 8, 4),
+    s(0, 1, 11, 2), s(7, 1, 0, 4),
+    s(3, 1, 0, 2), s(14, 3, 4, 4),
+    s(19, 0, 7, 4), s(21, 2, 2, 1),
+    s(14, 1, 10, 3),
+    s(13, 3, 0, 3), s(20, 0, 8, 4),
+    s(5, 2, 10, 4), s(6, 2, 10, 2), s(16, 2, 9, 4), s(16, 2, 9, 2),
+    s(12, 1, 9, 1), s(12, 1, 10, 1),
+    s(19, 0, 3, 2), s(12, 1, 0, 1),
+    s(19, 0, 9, 3), s(15, 0, 0, 4),
+    s(1, 1, 9, 3), s(1, 1, 9, 4),
+    s(0, 3, 0, 4), s(12, 3, 0, 2),
+    s(6, 2, 10, 2), s(20, 2, 10, 1),
+    s(20, 0, 5, 2), s(19, 0, 5, 4),
+    s(13, 1, 0, 2), s(15, 0, 0, 1),
+    s(1, 1, 0, 4), s(4, 0, 6, 4), s(4, 2, 0, 2),
+    s(17, 2, 0, 1), s(17, 0, 3, 4),
+    s(14, 1, 0, 2), s(12, 1, 0, 2),
+    s(18, 0, 8, 2), s(17, 0, 8, 4),
+    s(17, 0, 3, 4), s(14, 1, 0, 4),
+    s(12, 3, 0, 4), s(19, 0, 8, 1),
+    s(15, 2, 0, 2), s(16, 2, 0, 4),
+    s(13, 3, 9, 1), s(13, 3, 4, 4), s(12, 3, 4, 2),
+    s(0, 1, 0, 2), s(6, 2, 2, 2),
+    s(21, 2, 12, 1), s(7, 2, 12, 2),
+    s(10, 2, 0, 4), s(5, 1, 12, 3),
+    s(3, 1, 0, 4), s(0, 1, 10, 1),
+    s(17, 0, 5, 4), s(15, 0, 1, 4),
+    s(15, 2, 2, 2), s(6, 2, 0, 2),
+    s(9, 0, 9, 3), s(12, 1, 9, 3),
+    s(20, 0, 11, 2), s(19, 0, 1, 1),
+    s(21, 0, 8, 1), s(19, 0, 7, 1),
+    s(15, 0, 8, 3), s(15, 2, 0, 4),
+    s(0, 2, 9, 3), s(4, 0, 9, 4), s(1, 1, 9, 1),
+    s(0, 3, 12, 2), s(0, 1, 11, 2),
+    s(18, 2, 6, 4), s(15, 2, 6, 4),
+    s(20, 0, 8, 4), s(15, 0, 8, 4),
+    s(18, 2, 9, 4),
+    s(17, 2, 2, 4), s(14, 3, 4, 1),
+    s(13, 3, 9, 1), s(12, 3, 9, 1),
+    s(5, 0, 8, 4), s(6, 0, 8, 1),
+    s(15, 0, 0, 4), s(14, 1, 4, 4),
+    s(5, 2, 2, 4), s(5, 2, 6, 4),
+    s(8, 0, 1, 4), s(9, 0, 3, 3),
+    s(0, 1, 7, 3), s(0, 0, 7, 3),
+    s(3, 1, 8, 3),
+    s(5, 2, 2, 3), s(6, 2, 2, 3), s(17, 0, 12, 4),
+    s(14, 1, 4, 4), s(4, 0, 10, 1),
+    s(6, 1, 12, 3), s(18, 2, 10, 4),
+    s(1, 1, 9, 4), s(1, 1, 0, 4),
+    s(12, 1, 9, 1), s(14, 1, 0, 4), s(3, 0, 1, 3),
+    s(13, 3, 4, 4), s(9, 2, 9, 1),
+    s(5, 0, 8, 4), s(0, 1, 8, 4),
+    s(9, 2, 1, 1), s(4, 2, 0, 3), s(0, 3, 4, 4),
+    s(14, 1, 0, 1), s(13, 1, 0, 2),
+    s(5, 0, 8, 4), s(5, 0, 8, 3),
+    s(12, 1, 7, 3), s(8, 1, 8, 2),
+    s(13, 1, 0, 2), s(0, 2, 6, 2),
+    s(0, 1, 9, 2), s(0, 3, 10, 3),
+    s(6, 1, 9, 4), s(7, 1, 9, 3),
+    s(17, 0, 9, 3), s(0, 1, 11, 2),
+    s(1, 0, 12, 1), s(1, 0, 12, 4),
+    s(20, 2, 6, 1), s(19, 2, 6, 1), s(5, 2, 6, 4),
+    s(0, 1, 4, 4), s(9, 0, 3, 2),
+    s(19, 0, 8, 1), s(20, 2, 12, 2),
+    s(8, 2, 0, 4), s(0, 1, 11, 2),
+    s(1, 1, 10, 1), s(2, 1, 10, 2),
+    s(0, 1, 0, 4), s(8, 1, 0, 4),
+    s(4, 0, 11, 4),
+    s(10, 2, 6, 2), s(14, 1, 4, 2),
+    s(9, 2, 6, 1), s(4, 2, 0, 1),
+    s(16, 0, 0, 3), s(13, 1, 0, 2),
+    s(21, 2, 6, 1), s(11, 2, 11, 3),
+    s(15, 0, 0, 4), s(14, 1, 0, 1),
+    s(10, 0, 8, 4), s(9, 2, 0, 3),
+    s(0, 2, 6, 2), s(11, 2, 6, 1), s(3, 1, 0, 2),
+    s(4, 0, 8, 3), s(4, 2, 0, 4),
+    s(14, 3, 0, 1), s(16, 0, 10, 1),
+    s(17, 0, 5, 4), s(0, 1, 12, 1),
+    s(0, 1, 10, 2), s(0, 0, 5, 2),
+    s(15, 0, 1, 2), s(15, 0, 1, 3),
+    s(5, 1, 9, 4), s(15, 0, 0, 2),
+    s(15, 2, 11, 4), s(16, 2, 12, 2),
+    s(0, 2, 9, 1), s(5, 0, 9, 1),
+    s(20, 0, 10, 2), s(17, 0, 10, 4),
+    s(11, 2, 6, 4), s(0, 2, 6, 4),
+    s(15, 0, 9, 4), s(12, 1, 9, 1),
+    s(21, 0, 0, 1), s(14, 1, 9, 4),
+    s(15, 0, 10, 1), s(16, 0, 12, 4), s(16, 0, 12, 1),
+    s(0, 0, 7, 3), s(0, 1, 8, 3),
+    s(0, 3, 0, 4), s(16, 0, 0, 4),
+    s(14, 1, 9, 1), s(11, 0, 9, 1),
+    s(12, 1, 0, 1), s(11, 0, 11, 4),
+    s(10, 2, 1, 3), s(10, 2, 0, 4),
+    s(4, 0, 12, 1), s(4, 0, 12, 2),
+    s(0, 1, 7, 2), s(6, 0, 7, 2),
+    s(14, 3, 9, 4), s(0, 3, 10, 4),
+    s(14, 1, 4, 2), s(10, 0, 5, 4),
+    s(4, 0, 12, 3), s(1, 0, 11, 1),
+    s(0, 2, 6, 2), s(0, 2, 6, 3), s(14, 3, 4, 1),
+    s(11, 2, 5, 2), s(9, 2, 6, 4),
+    s(16, 0, 8, 3), s(15, 0, 8, 1),
+    s(1, 0, 2, 2), s(4, 2, 0, 2), s(1, 2, 0, 4), s(4, 2, 0, 4),
+    s(11, 0, 3, 2), s(12, 3, 4, 1),
+    s(15, 0, 0, 1), s(16, 0, 9, 4),
+    s(1, 0, 2, 2), s(4, 2, 0, 4),
+    s(11, 0, 9, 4), s(12, 1, 9, 1),
+    s(3, 0, 6, 4), s(3, 0, 2, 4), s(0, 2, 1, 4),
+    s(10, 0, 10, 1),
+    s(1, 1, 12, 3), s(1, 1, 0, 4),
+    s(9, 2, 6, 4), s(0, 2, 6, 3),
+    s(15, 0, 5, 2),
+    s(1, 2, 0, 3), s(1, 2, 0, 4),
+    s(12, 1, 8, 1), s(12, 1, 7, 4),
+    s(10, 2, 0, 1), s(0, 1, 1, 4),
+    s(1, 0, 12, 3), s(8, 0, 6, 4),
+    s(10, 0, 9, 1), s(13, 1, 9, 1),
+    s(10, 2, 0, 1), s(9, 0, 10, 3),
+    s(2, 0, 6, 2), s(1, 0, 5, 1),
+    s(4, 2, 0, 3), s(9, 2, 6, 1),
+    s(1, 1, 0, 4), s(2, 0, 2, 2),
+    s(7, 1, 4, 4), s(2, 0, 2, 4), s(0, 0, 3, 4),
+    s(4, 2, 0, 3), s(9, 2, 6, 4),
+    s(12, 1, 8, 4), s(14, 1, 10, 4),
+    s(16, 0, 3, 1), s(18, 2, 12, 3),
+    s(3, 0, 10, 2), s(3, 1, 10, 2), s(11, 2, 10, 1),
+    s(2, 1, 0, 3), s(14, 1, 10, 4),
+    s(21, 0, 10, 3),
+    s(9, 0, 7, 3), s(11, 0, 7, 2),
+    s(2, 0, 2, 2), s(4, 0, 9, 2),
+    s(7, 1, 4, 4), s(0, 1, 1, 2),
+    s(14, 1, 10, 2), s(1, 0, 1, 2),
+    s(0, 1, 12, 3), s(0, 1, 12, 1),
+    s(14, 3, 9, 3), s(12, 3, 9, 1),
+    s(11, 0, 8, 1),
+    s(14, 3, 9, 2), s(17, 0, 0, 1),
+    s(11, 2, 9, 2), s(14, 3, 9, 1),
+    s(0, 1, 10, 3), s(13, 1, 11, 1),
+    s(12, 1, 0, 1), s(7, 1, 0, 4),
+    s(1, 0, 7, 3), s(7, 0, 6, 3), s(2, 1, 7, 3),
+    s(0, 3, 9, 1), s(3, 0, 9, 2),
+    s(8, 0, 11, 2), s(7, 0, 11, 2),
+    s(19, 0, 5, 4), s(20, 0, 9, 1),
+    s(19, 0, 9, 4), s(19, 2, 9, 3), s(15, 0, 9, 1),
+    s(15, 2, 6, 4), s(5, 2, 6, 1),
+    s(10, 0, 9, 3), s(21, 0, 9, 3),
+    s(0, 1, 8, 4), s(7, 1, 8, 4),
+    s(10, 0, 7, 4), s(9, 0, 7, 1),
+    s(21, 0, 9, 3), s(16, 0, 10, 3), s(20, 0, 9, 4),
+    s(17, 0, 11, 3), s(14, 1, 11, 3),
+    s(5, 2, 0, 2), s(0, 1, 0, 4),
+    s(0, 3, 9, 4), s(14, 3, 9, 4),
+    s(17, 2, 6, 4), s(12, 3, 9, 3),
+    s(16, 0, 9, 4), s(12, 1, 4, 2),
+    s(13, 1, 0, 3), s(17, 0, 8, 3),
+    s(1, 1, 4, 2), s(11, 0, 9, 1),
+    s(6, 0, 9, 2), s(14, 1, 11, 1),
+    s(4, 0, 10, 1), s(4, 0, 6, 4),
+    s(21, 2, 2, 2),
+    s(2, 1, 0, 1), s(1, 1, 0, 3),
+    s(0, 1, 0, 4), s(21, 0, 1, 4),
+    s(19, 2, 6, 1), s(15, 2, 0, 4),
+    s(11, 2, 1, 2), s(6, 0, 7, 2),
+    s(16, 0, 0, 1), s(0, 0, 13, 2),
+    s(0, 0, 7, 1), s(12, 1, 7, 1),
+    s(0, 0, 8, 4), s(5, 2, 0, 2),
+    s(11, 0, 9, 2), s(13, 1, 9, 1),
+    s(21, 0, 11, 1), s(17, 2, 11, 1),
+    s(17, 0, 9, 4), s(11, 2, 2, 1),
+    s(11, 2, 0, 2), s(11, 2, 1, 2),
+    s(13, 1, 0, 4), s(9, 0, 3, 1),
+    s(14, 1, 9, 3), s(21, 0, 9, 3),
+    s(10, 2, 0, 1), s(5, 2, 0, 1),
+    s(12, 1, 4, 1), s(11, 0, 5, 2),
+    s(13, 3, 0, 1), s(17, 2, 0, 1),
+    s(3, 0, 2, 3), s(3, 0, 2, 2),
+    s(9, 2, 2, 1), s(0, 3, 12, 1),
+    s(5, 1, 9, 1), s(16, 0, 9, 1),
+    s(13, 1, 9, 2), s(9, 0, 9, 4),
+    s(1, 1, 12, 4), s(4, 0, 11, 3),
+    s(3, 0, 7, 2), s(3, 0, 9, 2), s(3, 1, 9, 2),
+    s(15, 2, 6, 3),
+    s(0, 2, 10, 2), s(10, 2, 10, 1),
+    s(19, 2, 12, 3), s(19, 2, 12, 1),
+    s(1, 1, 0, 1), s(1, 0, 9, 1),
+    s(19, 2, 12, 1), s(19, 2, 12, 3), s(21, 2, 12, 1),
+    s(1, 0, 11, 4), s(2, 0, 12, 2), s(4, 0, 11, 3),
+    s(2, 1, 7, 4), s(2, 1, 7, 3), s(2, 1, 4, 1),
+    s(21, 0, 8, 1), s(7, 0, 1, 4),
+    s(4, 0, 6, 4), s(1, 1, 0, 4),
+    s(19, 0, 9, 4), s(19, 2, 9, 3), s(19, 0, 1, 1),
+    s(9, 2, 2, 1), s(0, 2, 5, 1),
+    s(8, 1, 0, 4), s(4, 0, 6, 4),
+    s(11, 0, 5, 2), s(1, 0, 10, 4),
+    s(0, 0, 13, 2), s(14, 1, 9, 4),
+    s(9, 0, 12, 1), s(2, 0, 12, 1),
+    s(3, 0, 6, 4), s(0, 2, 6, 2),
+    s(1, 1, 0, 4), s(3, 0, 6, 4),
+    s(6, 2, 6, 4), s(6, 1, 0, 4),
+    s(8, 0, 11, 4), s(16, 0, 11, 1),
+    s(18, 0, 10, 4), s(5, 0, 7, 1),
+    s(7, 1, 8, 2), s(0, 2, 6, 3),
+    s(11, 0, 3, 4), s(19, 0, 1, 1),
+    s(17, 0, 1, 1), s(14, 1, 7, 3),
+    s(12, 1, 7, 1), s(13, 1, 8, 1),
+    s(3, 0, 1, 4), s(11, 0, 11, 2),
+    s(11, 2, 6, 3), s(11, 2, 12, 2),
+    s(18, 2, 0, 4), s(14, 3, 4, 4),
+    s(4, 0, 10, 4), s(2, 0, 10, 4),
+    s(0, 0, 3, 4), s(0, 1, 1, 1),
+    s(0, 1, 9, 4), s(13, 1, 0, 2),
+    s(0, 3, 0, 2), s(0, 2, 0, 2),
+    s(20, 0, 10, 2), s(12, 1, 10, 1),
+    s(0, 0, 7, 4), s(0, 1, 8, 3),
+    s(5, 0, 11, 1), s(11, 0, 9, 1),
+    s(11, 2, 0, 5),
+    s(5, 1, 7, 1), s(12, 1, 7, 1),
+    s(11, 0, 9, 4), s(0, 1, 9, 4),
+    s(0, 1, 0, 4), s(0, 3, 9, 1),
+    s(19, 0, 3, 4), s(0, 1, 9, 4),
+    s(4, 0, 10, 2), s(4, 0, 10, 1),
+    s(13, 3, 0, 2), s(5, 2, 2, 2),
+    s(9, 0, 3, 1), s(10, 0, 3, 3),
+    s(12, 3, 0, 4), s(12, 1, 8, 1),
+    s(0, 0, 3, 4), s(0, 3, 9, 1),
+    s(13, 1, 0, 2), s(5, 0, 9, 4), s(16, 2, 0, 2),
+    s(14, 3, 10, 2), s(14, 1, 10, 1),
+    s(15, 2, 11, 1), s(15, 2, 11, 4),
+    s(0, 0, 9, 1), s(11, 0, 9, 4),
+    s(15, 0, 10, 4), s(16, 0, 10, 2),
+    s(16, 0, 11, 2), s(16, 0, 11, 3),
+    s(0, 0, 7, 3), s(0, 2, 2, 4),
+    s(0, 2, 9, 3), s(0, 3, 9, 1),
+    s(15, 0, 9, 1), s(12, 1, 9, 1),
+    s(16, 2, 12, 1), s(15, 2, 11, 1),
+    s(12, 1, 1, 3), s(15, 0, 9, 1),
+    s(10, 0, 8, 4), s(10, 2, 0, 1),
+    s(16, 2, 0, 4), s(5, 2, 0, 2),
+    s(9, 2, 1, 2),
+    s(12, 1, 9, 4), s(12, 1, 9, 3), s(9, 0, 9, 4),
+    s(9, 0, 9, 4), s(6, 0, 9, 4),
+    s(17, 0, 0, 2), s(17, 0, 0, 3),
+    s(7, 2, 9, 4),
+    s(13, 3, 0, 1), s(16, 0, 7, 3),
+    s(3, 0, 2, 4), s(16, 0, 7, 3), s(3, 0, 5, 4),
+    s(12, 1, 9, 1), s(3, 1, 9, 3),
+    s(7, 1, 0, 4), s(8, 1, 0, 2),
+    s(7, 1, 0, 3), s(16, 0, 0, 1),
+    s(15, 0, 3, 2), s(15, 0, 0, 2),
+    s(0, 1, 0, 4), s(0, 1, 9, 1),
+    s(5, 0, 5, 4), s(6, 0, 5, 4),
+    s(8, 0, 5, 2), s(8, 1, 0, 2),
+    s(0, 2, 0, 1), s(0, 2, 2, 4),
+    s(3, 1, 0, 4), s(0, 1, 9, 1),
+    s(0, 1, 9, 3), s(0, 0, 9, 3), s(0, 0, 9, 4),
+    s(5, 2, 6, 4), s(5, 0, 5, 4),
+    s(7, 1, 11, 3),
+    s(2, 0, 11, 1), s(2, 0, 12, 2),
+    s(6, 0, 1, 4), s(8, 2, 12, 2),
+    s(18, 2, 12, 3), s(16, 0, 10, 2),
+    s(14, 1, 0, 2), s(14, 1, 4, 2),
+    s(0, 1, 7, 4), s(0, 1, 1, 4),
+    s(10, 2, 2, 4), s(11, 2, 1, 2),
+    s(6, 0, 1, 4), s(14, 1, 1, 2),
+    s(0, 1, 10, 2), s(7, 1, 4, 4),
+    s(0, 3, 10, 3), s(10, 2, 10, 3),
+    s(0, 1, 0, 2), s(0, 1, 1, 4), s(0, 1, 1, 2),
+    s(0, 1, 1, 2), s(0, 1, 0, 2), s(0, 1, 1, 4), s(20, 0, 0, 1),
+    s(12, 1, 4, 4), s(12, 1, 1, 2),
+    s(12, 3, 4, 1), s(13, 1, 7, 1),
+    s(1, 2, 0, 4), s(18, 0, 8, 2),
+    s(0, 0, 8, 1), s(13, 3, 0, 1),
+    s(2, 0, 6, 4), s(1, 0, 6, 4),
+    s(9, 2, 12, 1), s(8, 2, 12, 3),
+    s(8, 1, 10, 2), s(16, 0, 10, 1),
+    s(0, 2, 6, 4), s(11, 2, 6, 4),
+    s(13, 1, 0, 2), s(9, 0, 5, 4),
+    s(1, 2, 0, 1), s(1, 2, 0, 3),
+    s(1, 1, 11, 2),
+];
@@ -0,0 +1,191 @@
+//! Runs the `bpmf` binary as a user would
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// The exit code, stdout and stderr of `bpmf` run with the args and the stdin
+fn bpmf(args: &[&str], stdin: &str) -> (i32, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bpmf"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    //bpmf may exit without reading stdin, e.g. with TEXT args, so a broken pipe is fine
+    let written = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    if let Err(err) = written {
+        assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+    }
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn converts_the_args_to_pinyin_by_default() {
+    let (code, out, _) = bpmf(&["ni3 hao3", "ㄓㄨㄥ ㄨㄣˊ"], "ignored");
+    assert_eq!(code, 0);
+    assert_eq!(out, "nǐ hǎo\nzhōng wén\n");
+}
+
+#[test]
+fn options_take_their_values_separately_or_after_an_equal_sign() {
+    let expected = (0, "ㄋㄧˇ-ㄏㄠˇ\n".to_owned(), String::new());
+    assert_eq!(
+        bpmf(&["--to", "bopomofo", "-s", "-", "ni3hao3"], ""),
+        expected
+    );
+    assert_eq!(
+        bpmf(&["--to=bpmf", "--separator=-", "ni3hao3"], ""),
+        expected
+    );
+    assert_eq!(
+        bpmf(&["-t", "zhuyin", "-s", "-", "--", "ni3hao3"], ""),
+        expected
+    );
+}
+
+#[test]
+fn from_restricts_the_notation_of_the_input() {
+    //"ma" alone is pinyin in the neutral tone, "ma3" is ascii pinyin
+    assert_eq!(bpmf(&["-t", "bpmf", "ma ma3"], "").1, "˙ㄇㄚ ㄇㄚˇ\n");
+    //ma3 isn't pinyin, so it's reported instead of converted
+    assert_eq!(
+        bpmf(&["--from", "pinyin", "--check", "mǎ ma3"], ""),
+        (
+            1,
+            "<arg>:1:4: invalid syllable 'ma3'\n".to_owned(),
+            String::new()
+        )
+    );
+    let (code, out, err) = bpmf(
+        &["--from", "pinyin", "--strict", "-t", "ascii", "mǎ ma3"],
+        "",
+    );
+    assert_eq!((code, out.as_str()), (2, ""));
+    assert_eq!(err, "bpmf: <arg>:1:4: invalid syllable 'ma3'\n");
+    assert_eq!(
+        bpmf(&["--from", "auto", "-t", "ascii", "ㄇㄚˇ mǎ"], "").1,
+        "ma3 ma3\n"
+    );
+    assert_eq!(
+        bpmf(&["--from=bopomofo", "-t", "py", "ㄇㄚˇ mǎ"], "").1,
+        "mǎ mǎ\n"
+    );
+}
+
+#[test]
+fn converts_from_and_to_braille() {
+    assert_eq!(
+        bpmf(&["-t", "braille-mainland", "zhong1guo2de5"], "").1,
+        "⠌⠲⠁ ⠛⠕⠂ ⠙⠢\n"
+    );
+    //the two systems share cells, so the input system has to be given
+    let args = ["-f", "braille-taiwan", "-t", "ascii", "-s", "", "⠁⠯⠄⠅⠒⠂ 中"];
+    assert_eq!(bpmf(&args, "").1, "zhong1guo2 中\n");
+    assert_eq!(bpmf(&args[2..], "").1, "zhong1kun2 中\n");
+}
+
+#[test]
+fn converts_from_and_to_palladius() {
+    assert_eq!(
+        bpmf(&["--to", "palladius", "chang2an1 zi4ji3"], "").1,
+        "чан ань цзы цзи\n"
+    );
+    //Palladius has no tones
+    assert_eq!(
+        bpmf(&["--from=cyrillic", "-t", "bpmf", "Чанъань, 中"], "").1,
+        "ㄔㄤ ㄢ, 中\n"
+    );
+}
+
+#[test]
+fn writes_hangul_and_katakana_but_doesnt_read_them() {
+    assert_eq!(
+        bpmf(&["--to", "hangul", "-s", "", "zhong1guo2"], "").1,
+        "중궈\n"
+    );
+    assert_eq!(bpmf(&["-t", "katakana", "bei3 jing1"], "").1, "ペイ チン\n");
+    let (code, _, err) = bpmf(&["--from", "hangul", "중궈"], "");
+    assert_eq!(code, 2);
+    assert!(err.starts_with("bpmf: hangul can't be read, only written\n"));
+}
+
+#[test]
+fn reads_stdin_without_args_or_with_the_dash_file() {
+    let stdin = "ni3 hao3\nzai4jian4, 中文\n";
+    let expected = "nǐ hǎo\nzài jiàn, 中文\n";
+    assert_eq!(bpmf(&[], stdin), (0, expected.to_owned(), String::new()));
+    assert_eq!(
+        bpmf(&["-i", "-"], stdin),
+        (0, expected.to_owned(), String::new())
+    );
+
+    let file = temp_file("cli_dash.txt", "xie4xie5\n");
+    let file = file.to_str().unwrap();
+    let (code, out, _) = bpmf(&["--file", file, "--file", "-", "-i", file], stdin);
+    assert_eq!(code, 0);
+    assert_eq!(out, format!("xiè xie\n{expected}xiè xie\n"));
+}
+
+#[test]
+fn check_reports_the_invalid_words_with_their_positions() {
+    let file = temp_file("cli_check.txt", "ni3 hao3\nni3 haox3 ma\n");
+    let file = file.to_str().unwrap();
+    let (code, out, _) = bpmf(&["--check", "-i", file], "");
+    assert_eq!(code, 1);
+    assert_eq!(out, format!("{file}:2:5: invalid syllable 'haox3'\n"));
+
+    let (code, out, _) = bpmf(&["--check"], "ni3 hao3\n");
+    assert_eq!((code, out.as_str()), (0, ""));
+    let (code, out, _) = bpmf(&["--check", "xx1"], "");
+    assert_eq!(
+        (code, out.as_str()),
+        (1, "<arg>:1:1: invalid syllable 'xx1'\n")
+    );
+}
+
+#[test]
+fn errors_exit_with_2() {
+    let (code, out, err) = bpmf(&["--strict"], "ni3\nni3 haox3\nhao3\n");
+    assert_eq!((code, out.as_str()), (2, "nǐ\n"));
+    assert_eq!(err, "bpmf: <stdin>:2:5: invalid syllable 'haox3'\n");
+    //without --strict the invalid words are kept as they are
+    assert_eq!(
+        bpmf(&[], "ni3 haox3\n"),
+        (0, "nǐ haox3\n".to_owned(), String::new())
+    );
+
+    let (code, out, err) = bpmf(&["--to", "wade-giles", "ni3"], "");
+    assert_eq!((code, out.as_str()), (2, ""));
+    assert!(err.starts_with("bpmf: unknown notation wade-giles\n\nUsage: bpmf"));
+    let (code, _, err) = bpmf(&["--verbose"], "");
+    assert_eq!(code, 2);
+    assert!(err.starts_with("bpmf: unknown option --verbose\n"));
+    let (code, _, err) = bpmf(&["--from"], "");
+    assert_eq!(code, 2);
+    assert!(err.starts_with("bpmf: missing the value of --from\n"));
+    let (code, _, err) = bpmf(&["-i", "notes.txt", "ni3"], "");
+    assert_eq!(code, 2);
+    assert!(err.starts_with("bpmf: TEXT and --file can't be given together\n"));
+    let (code, _, err) = bpmf(&["-i", "no/such/file.txt"], "");
+    assert_eq!(code, 2);
+    assert!(err.starts_with("bpmf: no/such/file.txt: "));
+}
+
+#[test]
+fn help_is_printed_to_stdout() {
+    let (code, out, err) = bpmf(&["--to", "bpmf", "-h"], "");
+    assert_eq!((code, err.as_str()), (0, ""));
+    assert!(out.starts_with("Usage: bpmf [OPTIONS] [TEXT]..."));
+}